@@ -0,0 +1,226 @@
+//! `.errors.txt` baseline rendering
+//!
+//! Reproduces the layout of the TypeScript harness error baselines: the
+//! formatted diagnostic list, then every test file with its source lines
+//! indented by four spaces, `~` squiggles under each error span, and the
+//! `!!! error TS....` messages after the line where the error ends.
+
+use super::BaselineSourceFile;
+
+/// A diagnostic in baseline form, parsed from `tsz --format json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaselineDiagnostic {
+    /// Test file name, or `None` for global diagnostics.
+    pub file: Option<String>,
+    /// Byte offset of the error start in the file.
+    pub start: u32,
+    /// Byte length of the error span.
+    pub length: u32,
+    /// 1-based line and column of the start.
+    pub line: u32,
+    pub column: u32,
+    /// `error`, `warning`, `suggestion` or `message`.
+    pub category: String,
+    pub code: u32,
+    /// Flattened message chain.
+    pub message: String,
+    pub related: Vec<BaselineRelatedInfo>,
+}
+
+/// A related-information entry of a [`BaselineDiagnostic`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaselineRelatedInfo {
+    /// `(file, line, column)`, 1-based, when the entry has a location.
+    pub location: Option<(String, u32, u32)>,
+    pub code: u32,
+    pub message: String,
+}
+
+/// Parse `tsz --format json` output (one diagnostic object per line).
+///
+/// `resolve_file` maps a reported path to the test file name it belongs to;
+/// diagnostics in files outside the test (e.g. harness libs) are dropped, as
+/// the harness does not list them either.
+pub fn parse_json_diagnostics(
+    output: &str,
+    resolve_file: impl Fn(&str) -> Option<String>,
+) -> Vec<BaselineDiagnostic> {
+    let location = |value: &serde_json::Value| -> Option<Option<(String, u32, u32, u32)>> {
+        let Some(file) = value["file"].as_str() else {
+            return Some(None);
+        };
+        let file = resolve_file(file)?;
+        let start = &value["start"];
+        Some(Some((
+            file,
+            start["offset"].as_u64().unwrap_or(0) as u32,
+            start["line"].as_u64().unwrap_or(1) as u32,
+            start["column"].as_u64().unwrap_or(1) as u32,
+        )))
+    };
+
+    let mut diagnostics = Vec::new();
+    for line in output.lines() {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(line.trim()) else {
+            continue;
+        };
+        let Some(code) = value["code"].as_u64() else {
+            continue;
+        };
+        let Some(position) = location(&value) else {
+            continue;
+        };
+        let related = value["relatedInformation"]
+            .as_array()
+            .map(|entries| {
+                entries
+                    .iter()
+                    .map(|entry| BaselineRelatedInfo {
+                        location: location(entry)
+                            .flatten()
+                            .map(|(file, _, line, column)| (file, line, column)),
+                        code: entry["code"].as_u64().unwrap_or(0) as u32,
+                        message: entry["message"].as_str().unwrap_or_default().to_string(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let (file, start, line, column) = match position {
+            Some((file, start, line, column)) => (Some(file), start, line, column),
+            None => (None, 0, 0, 0),
+        };
+        diagnostics.push(BaselineDiagnostic {
+            file,
+            start,
+            length: value["length"].as_u64().unwrap_or(0) as u32,
+            line,
+            column,
+            category: value["category"].as_str().unwrap_or("error").to_string(),
+            code: code as u32,
+            message: value["message"].as_str().unwrap_or_default().to_string(),
+            related,
+        });
+    }
+    diagnostics
+}
+
+/// Render the `.errors.txt` baseline, or `None` when there are no
+/// diagnostics (the harness writes no file in that case).
+pub fn render_errors_baseline(
+    files: &[BaselineSourceFile],
+    diagnostics: &[BaselineDiagnostic],
+) -> Option<String> {
+    if diagnostics.is_empty() {
+        return None;
+    }
+
+    let mut lines: Vec<String> = Vec::new();
+    for diag in diagnostics {
+        let prefix = match &diag.file {
+            Some(file) => format!("{file}({},{}): ", diag.line, diag.column),
+            None => String::new(),
+        };
+        lines.push(format!(
+            "{prefix}{} TS{}: {}",
+            diag.category, diag.code, diag.message
+        ));
+    }
+    lines.push(String::new());
+    lines.push(String::new());
+
+    for diag in diagnostics.iter().filter(|diag| diag.file.is_none()) {
+        lines.extend(error_message_lines(diag));
+    }
+
+    for file in files {
+        let file_errors: Vec<&BaselineDiagnostic> = diagnostics
+            .iter()
+            .filter(|diag| diag.file.as_deref() == Some(file.name.as_str()))
+            .collect();
+        lines.push(format!(
+            "==== {} ({} errors) ====",
+            file.name,
+            file_errors.len()
+        ));
+        render_file_errors(&file.content, &file_errors, &mut lines);
+    }
+
+    Some(lines.join("\n"))
+}
+
+/// Source lines of one file with squiggles and messages interleaved.
+fn render_file_errors(content: &str, errors: &[&BaselineDiagnostic], lines: &mut Vec<String>) {
+    let source_lines: Vec<(usize, &str)> = split_lines(content);
+    let last_index = source_lines.len().saturating_sub(1);
+
+    for (index, &(line_start, line)) in source_lines.iter().enumerate() {
+        lines.push(format!("    {line}"));
+        let next_line_start = source_lines
+            .get(index + 1)
+            .map_or(content.len(), |&(start, _)| start);
+
+        for error in errors {
+            let start = error.start as usize;
+            let end = start + error.length as usize;
+            if end < line_start || (start >= next_line_start && index != last_index) {
+                continue;
+            }
+            // Clamp the span to this line, then measure it in characters.
+            let squiggle_start = floor_char_boundary(line, start.saturating_sub(line_start));
+            let squiggle_end =
+                floor_char_boundary(line, end.saturating_sub(line_start)).max(squiggle_start);
+            let padding: String = line[..squiggle_start]
+                .chars()
+                .map(|ch| if ch.is_whitespace() { ch } else { ' ' })
+                .collect();
+            let squiggle = "~".repeat(line[squiggle_start..squiggle_end].chars().count());
+            lines.push(format!("    {padding}{squiggle}"));
+
+            if index == last_index || next_line_start > end {
+                lines.extend(error_message_lines(error));
+            }
+        }
+    }
+}
+
+/// `!!! error TS....: ...` lines for a diagnostic, then its related info.
+fn error_message_lines(diag: &BaselineDiagnostic) -> Vec<String> {
+    let mut lines: Vec<String> = diag
+        .message
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| format!("!!! {} TS{}: {line}", diag.category, diag.code))
+        .collect();
+    for related in &diag.related {
+        let location = related
+            .location
+            .as_ref()
+            .map(|(file, line, column)| format!(" {file}:{line}:{column}"))
+            .unwrap_or_default();
+        lines.push(format!(
+            "!!! related TS{}{location}: {}",
+            related.code, related.message
+        ));
+    }
+    lines
+}
+
+/// Lines of `content` with their byte start offsets, split on `\n` and
+/// `\r\n` like the harness's `/\r?\n/`.
+fn split_lines(content: &str) -> Vec<(usize, &str)> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for line in content.split('\n') {
+        lines.push((start, line.strip_suffix('\r').unwrap_or(line)));
+        start += line.len() + 1;
+    }
+    lines
+}
+
+fn floor_char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
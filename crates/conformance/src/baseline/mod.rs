@@ -0,0 +1,322 @@
+//! tsc-format baseline generation
+//!
+//! `--baselines` compiles each conformance test with tsz and writes the
+//! baselines the TypeScript harness produces for it:
+//!
+//! - `<name>.errors.txt` from `tsz --format json` diagnostics (only when the
+//!   test has diagnostics, like the harness);
+//! - `<name>.types` and `<name>.symbols` from the `tsz_lsp` type-writer walk.
+//!
+//! The output can be diffed line by line against
+//! `TypeScript/tests/baselines/reference` instead of comparing error-code
+//! multisets. Sources are shown as compiled (without the `// @option`
+//! directive lines the runner strips) and lines end in `\n`, so compare with
+//! line-ending normalization.
+
+pub mod errors;
+pub mod types;
+
+use crate::cli::Args;
+use crate::test_filter::{is_conformance_source_file, matches_path_filter};
+use crate::test_parser::{
+    expand_option_variants, filter_incompatible_module_resolution_variants, parse_test_file,
+    should_skip_test,
+};
+use crate::text_decode::{decode_source_text, DecodedSourceText};
+use crate::tsz_wrapper;
+use rayon::prelude::*;
+use std::io::{Read, Seek};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use errors::{parse_json_diagnostics, render_errors_baseline};
+use types::{render_type_baseline, TypeBaselineKind};
+
+/// One test file as the harness displays it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaselineSourceFile {
+    /// Unit name: the `@filename` value, or the test file name for
+    /// single-file tests.
+    pub name: String,
+    pub content: String,
+}
+
+/// The baselines generated for one test.
+#[derive(Debug, Clone, Default)]
+pub struct TestBaselines {
+    pub errors: Option<String>,
+    pub types: String,
+    pub symbols: String,
+}
+
+/// Counts for a baseline generation run.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BaselineStats {
+    pub written: usize,
+    pub skipped: usize,
+    pub failed: usize,
+}
+
+/// Generate baselines for every selected test into `--baseline-dir`.
+pub fn run_baseline_generation(args: &Args) -> anyhow::Result<BaselineStats> {
+    let test_dir = Path::new(&args.test_dir);
+    let output_dir = Path::new(&args.baseline_dir);
+    std::fs::create_dir_all(output_dir)?;
+    let tests = collect_baseline_tests(args);
+    let tsz_binary = resolve_tsz_binary(&args.tsz_binary);
+    let lib_dir = tsz_wrapper::tests_lib_dir_for_cases_dir(test_dir);
+
+    let start = Instant::now();
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(args.workers.max(1))
+        .build()?;
+    let results: Vec<(PathBuf, anyhow::Result<Option<TestBaselines>>)> = pool.install(|| {
+        tests
+            .par_iter()
+            .map(|path| {
+                let result =
+                    generate_test_baselines(path, test_dir, &lib_dir, &tsz_binary, args.timeout);
+                (path.clone(), result)
+            })
+            .collect()
+    });
+
+    let mut stats = BaselineStats::default();
+    for (path, result) in results {
+        let rel_path = path.strip_prefix(test_dir).unwrap_or(&path).display();
+        match result {
+            Ok(Some(baselines)) => {
+                write_baselines(output_dir, &path, &baselines)?;
+                stats.written += 1;
+                if args.is_verbose() {
+                    println!("WROTE {rel_path}");
+                }
+            }
+            Ok(None) => stats.skipped += 1,
+            Err(err) => {
+                stats.failed += 1;
+                println!("FAIL {rel_path} ({err})");
+            }
+        }
+    }
+
+    println!();
+    println!("{}", "=".repeat(60));
+    println!(
+        "BASELINES: {} written to {}",
+        stats.written,
+        output_dir.display()
+    );
+    println!("  Skipped: {}", stats.skipped);
+    println!("  Failed: {}", stats.failed);
+    println!("  Time: {:.1}s", start.elapsed().as_secs_f64());
+    println!("{}", "=".repeat(60));
+    Ok(stats)
+}
+
+/// Conformance tests selected by `--filter`, `--offset` and `--max`.
+fn collect_baseline_tests(args: &Args) -> Vec<PathBuf> {
+    let mut tests: Vec<PathBuf> = walkdir::WalkDir::new(&args.test_dir)
+        .follow_links(true)
+        .into_iter()
+        .filter_map(Result::ok)
+        .map(walkdir::DirEntry::into_path)
+        .filter(|path| path.is_file() && is_conformance_source_file(path))
+        .filter(|path| matches_path_filter(path, args.filter.as_deref()))
+        .collect();
+    tests.sort();
+    let max = if args.all { usize::MAX } else { args.max };
+    tests.into_iter().skip(args.offset).take(max).collect()
+}
+
+/// `tsz` resolves like the main runner: prefer the local dist-fast build.
+fn resolve_tsz_binary(tsz_binary: &str) -> String {
+    let local = Path::new("./.target/dist-fast/tsz");
+    if tsz_binary == "tsz" && local.is_file() {
+        local.display().to_string()
+    } else {
+        tsz_binary.to_string()
+    }
+}
+
+/// Compile one test and render its baselines; `Ok(None)` for skipped tests.
+pub fn generate_test_baselines(
+    path: &Path,
+    test_dir: &Path,
+    lib_dir: &Path,
+    tsz_binary: &str,
+    timeout_secs: u64,
+) -> anyhow::Result<Option<TestBaselines>> {
+    let bytes = std::fs::read(path)?;
+    let content = match decode_source_text(&bytes) {
+        DecodedSourceText::Text(text) | DecodedSourceText::TextWithOriginalBytes(text, _) => text,
+        DecodedSourceText::Binary(_) => return Ok(None),
+    };
+    let parsed = parse_test_file(&content)?;
+    if should_skip_test(&parsed.directives).is_some() {
+        return Ok(None);
+    }
+
+    // Like the comparison runner, only the first option variant is used.
+    let options = parsed.directives.options.clone();
+    let variant = filter_incompatible_module_resolution_variants(expand_option_variants(&options))
+        .into_iter()
+        .next()
+        .unwrap_or(options);
+    let extension = path.extension().and_then(|ext| ext.to_str());
+    let prepared = tsz_wrapper::prepare_test_dir_with_lib_dir(
+        &content,
+        &parsed.directives.filenames,
+        &variant,
+        extension,
+        &parsed.directives.option_order,
+        None,
+        Some(lib_dir),
+    )?;
+
+    // Unit names paired with the path each unit was written to.
+    let test_file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let units: Vec<(String, PathBuf)> = if parsed.directives.filenames.is_empty() {
+        vec![(
+            test_file_name,
+            PathBuf::from(format!("test.{}", extension.unwrap_or("ts"))),
+        )]
+    } else {
+        parsed
+            .directives
+            .filenames
+            .iter()
+            .map(|(name, _)| {
+                let written = name.replace("..", "_").trim_start_matches('/').to_string();
+                (name.clone(), PathBuf::from(written))
+            })
+            .collect()
+    };
+    let root = prepared.temp_dir.path();
+    let files: Vec<(BaselineSourceFile, PathBuf)> = units
+        .into_iter()
+        .filter_map(|(name, rel)| {
+            let content = std::fs::read_to_string(root.join(&rel)).ok()?;
+            Some((BaselineSourceFile { name, content }, rel))
+        })
+        .collect();
+
+    let output = run_tsz_json(tsz_binary, &prepared.project_dir, timeout_secs)?;
+    let resolve_file = |reported: &str| -> Option<String> {
+        let reported = Path::new(reported);
+        files
+            .iter()
+            .filter(|(_, rel)| reported.ends_with(rel))
+            .max_by_key(|(_, rel)| rel.components().count())
+            .map(|(file, _)| file.name.clone())
+    };
+    let diagnostics = parse_json_diagnostics(&output, resolve_file);
+    let source_files: Vec<BaselineSourceFile> =
+        files.iter().map(|(file, _)| file.clone()).collect();
+    let errors = render_errors_baseline(&source_files, &diagnostics);
+
+    let mut project = tsz_lsp::Project::new();
+    project.set_strict(
+        variant
+            .get("strict")
+            .is_some_and(|value| value.eq_ignore_ascii_case("true")),
+    );
+    for file in &source_files {
+        project.set_file(file.name.clone(), file.content.clone());
+    }
+    let walked: Vec<_> = source_files
+        .iter()
+        .map(|file| {
+            let entries = project
+                .get_type_writer_entries(&file.name)
+                .unwrap_or_default();
+            (file.clone(), entries)
+        })
+        .collect();
+
+    let test_path = Path::new("tests/cases").join(path.strip_prefix(test_dir).unwrap_or(path));
+    let test_path = test_path.to_string_lossy().replace('\\', "/");
+    Ok(Some(TestBaselines {
+        errors,
+        types: render_type_baseline(&test_path, &walked, TypeBaselineKind::Types),
+        symbols: render_type_baseline(&test_path, &walked, TypeBaselineKind::Symbols),
+    }))
+}
+
+/// Run `tsz --format json` on a prepared project and return its stdout.
+fn run_tsz_json(tsz_binary: &str, project_dir: &Path, timeout_secs: u64) -> anyhow::Result<String> {
+    // Stdout goes to a temp file so a chatty compile cannot fill the pipe
+    // while we poll for the timeout.
+    let mut stdout = tempfile::tempfile()?;
+    let mut child = Command::new(tsz_binary)
+        .arg("--project")
+        .arg(project_dir)
+        .arg("--noEmit")
+        .arg("--pretty")
+        .arg("false")
+        .arg("--format")
+        .arg("json")
+        .current_dir(project_dir)
+        .stdout(stdout.try_clone()?)
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if timeout_secs > 0 && start.elapsed() > Duration::from_secs(timeout_secs) {
+            let _ = child.kill();
+            let _ = child.wait();
+            anyhow::bail!("timed out after {timeout_secs}s");
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    };
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            anyhow::bail!("tsz crashed (signal {signal})");
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = status;
+
+    let mut output = String::new();
+    stdout.rewind()?;
+    stdout.read_to_string(&mut output)?;
+    Ok(output)
+}
+
+fn write_baselines(
+    output_dir: &Path,
+    test: &Path,
+    baselines: &TestBaselines,
+) -> anyhow::Result<()> {
+    let stem = test
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    if let Some(errors) = &baselines.errors {
+        std::fs::write(output_dir.join(format!("{stem}.errors.txt")), errors)?;
+    }
+    for (kind, text) in [
+        (TypeBaselineKind::Types, &baselines.types),
+        (TypeBaselineKind::Symbols, &baselines.symbols),
+    ] {
+        std::fs::write(
+            output_dir.join(format!("{stem}.{}", kind.extension())),
+            text,
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "../../tests/baseline_tests.rs"]
+mod tests;
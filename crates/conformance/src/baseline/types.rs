@@ -0,0 +1,58 @@
+//! `.types` and `.symbols` baseline rendering
+//!
+//! Both baselines list every test file's source lines, each followed by the
+//! `>text : type` (or `>text : Symbol(...)`) entries of the nodes that start
+//! on that line, in the layout of the TypeScript harness `TypeWriterWalker`.
+
+use super::BaselineSourceFile;
+use tsz_lsp::type_writer::TypeWriterEntry;
+
+/// Which of the two type-writer baselines to render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeBaselineKind {
+    Types,
+    Symbols,
+}
+
+impl TypeBaselineKind {
+    /// Baseline file extension, without the leading dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            TypeBaselineKind::Types => "types",
+            TypeBaselineKind::Symbols => "symbols",
+        }
+    }
+}
+
+/// Render a `.types` or `.symbols` baseline.
+///
+/// `test_path` is the test's path inside the TypeScript repository (e.g.
+/// `tests/cases/compiler/foo.ts`), printed in the header line.
+pub fn render_type_baseline(
+    test_path: &str,
+    files: &[(BaselineSourceFile, Vec<TypeWriterEntry>)],
+    kind: TypeBaselineKind,
+) -> String {
+    let mut out = format!("//// [{test_path}] ////\n\n");
+    for (file_index, (file, entries)) in files.iter().enumerate() {
+        if file_index > 0 {
+            out.push('\n');
+        }
+        out.push_str(&format!("=== {} ===\n", file.name));
+        let mut entries = entries.iter().peekable();
+        for (line_index, line) in file.content.split('\n').enumerate() {
+            out.push_str(line.strip_suffix('\r').unwrap_or(line));
+            out.push('\n');
+            while let Some(entry) = entries.next_if(|entry| entry.line as usize <= line_index) {
+                let value = match kind {
+                    TypeBaselineKind::Types => entry.type_text.as_deref(),
+                    TypeBaselineKind::Symbols => entry.symbol_text.as_deref(),
+                };
+                if let Some(value) = value {
+                    out.push_str(&format!(">{} : {value}\n", entry.source_text));
+                }
+            }
+        }
+    }
+    out
+}
@@ -150,6 +150,20 @@ pub struct Args {
     /// Directory for parity diff artifacts.
     #[arg(long, default_value = "./artifacts/conformance/diffs")]
     pub diff_artifacts_dir: String,
+
+    /// Write tsc-format baselines (`.errors.txt`, `.types`, `.symbols`) from
+    /// tsz output instead of comparing error codes against the cache.
+    #[arg(long)]
+    pub baselines: bool,
+
+    /// Directory for generated baselines.
+    #[arg(long, default_value = "./artifacts/conformance/baselines")]
+    pub baseline_dir: String,
+
+    /// Run TypeScript's fourslash language-service tests (`<test-dir>/fourslash`)
+    /// against the tsz LSP providers instead of the compiler conformance suite.
+    #[arg(long)]
+    pub fourslash: bool,
 }
 
 impl Args {
@@ -215,6 +229,19 @@ mod tests {
         assert_eq!(args.timeout, 90);
     }
 
+    #[test]
+    fn baselines_mode_defaults_to_artifacts_dir() {
+        let args = parse_args(&["tsz-conformance", "--baselines"]);
+        assert!(args.baselines);
+        assert_eq!(args.baseline_dir, "./artifacts/conformance/baselines");
+    }
+
+    #[test]
+    fn fourslash_mode_is_opt_in() {
+        assert!(!parse_args(&["tsz-conformance"]).fourslash);
+        assert!(parse_args(&["tsz-conformance", "--fourslash"]).fourslash);
+    }
+
     #[test]
     fn validate_accepts_all_mode_without_extra_post_processing() {
         let args = parse_args(&["tsz-conformance", "--all"]);
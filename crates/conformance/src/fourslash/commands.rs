@@ -0,0 +1,530 @@
+//! Fourslash script command parser
+//!
+//! Fourslash scripts are JavaScript, but the subset the runner executes is a
+//! flat list of `goTo.*(...)` / `verify.*(...)` calls whose arguments are
+//! literals. This module parses that subset into [`FourslashCommand`]s. Any
+//! statement it does not understand (declarations, loops, non-literal
+//! arguments, unknown `verify` methods) becomes
+//! [`FourslashCommand::Unsupported`], which makes the runner skip the test
+//! rather than report a misleading pass.
+
+/// A JavaScript literal as written in a fourslash script.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsValue {
+    Str(String),
+    Num(f64),
+    Bool(bool),
+    Null,
+    Undefined,
+    Array(Vec<JsValue>),
+    Object(Vec<(String, JsValue)>),
+    /// Any expression that is not a plain literal.
+    Opaque,
+}
+
+impl JsValue {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            JsValue::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// A string, or an array of strings, as a list.
+    fn as_string_list(&self) -> Option<Vec<String>> {
+        match self {
+            JsValue::Str(s) => Some(vec![s.clone()]),
+            JsValue::Array(items) => items
+                .iter()
+                .map(|item| item.as_str().map(str::to_string))
+                .collect(),
+            _ => None,
+        }
+    }
+
+    fn is_literal(&self) -> bool {
+        match self {
+            JsValue::Opaque => false,
+            JsValue::Array(items) => items.iter().all(JsValue::is_literal),
+            JsValue::Object(props) => props.iter().all(|(_, value)| value.is_literal()),
+            _ => true,
+        }
+    }
+}
+
+/// Expectations of one `verify.completions({...})` argument.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompletionsExpectation {
+    /// Markers to query; empty means the current cursor position.
+    pub markers: Vec<String>,
+    /// Entry names that must be present.
+    pub includes: Vec<String>,
+    /// Entry names that must be absent.
+    pub excludes: Vec<String>,
+    /// The complete set of expected entry names, when given.
+    pub exact: Option<Vec<String>>,
+}
+
+/// One executable fourslash command.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FourslashCommand {
+    /// `goTo.marker("name")`; `goTo.marker()` selects the anonymous marker.
+    GoToMarker(String),
+    /// `goTo.file("name")`
+    GoToFile(String),
+    /// `verify.quickInfoAt(marker, text?, documentation?)` and the entries of
+    /// `verify.quickInfos({...})`.
+    QuickInfoAt {
+        marker: String,
+        expected: Option<String>,
+        documentation: Option<String>,
+    },
+    /// `verify.quickInfoIs(text, documentation?)` at the current marker.
+    QuickInfoIs {
+        expected: String,
+        documentation: Option<String>,
+    },
+    /// `verify.quickInfoExists()` / `verify.not.quickInfoExists()`
+    QuickInfoExists { negated: bool },
+    /// `verify.completions({...}, ...)`
+    Completions(Vec<CompletionsExpectation>),
+    /// `verify.goToDefinition(...)`: source marker → expected target markers.
+    GoToDefinition(Vec<(String, Vec<String>)>),
+    /// `verify.rangesAreRenameLocations()`
+    RangesAreRenameLocations,
+    /// `verify.noErrors()`
+    NoErrors,
+    /// A statement the runner cannot execute, kept as source text.
+    Unsupported(String),
+}
+
+/// Parse a fourslash script into commands, in source order.
+pub fn parse_commands(script: &str) -> Vec<FourslashCommand> {
+    let mut parser = ScriptParser::new(script);
+    let mut commands = Vec::new();
+    loop {
+        parser.skip_trivia();
+        if parser.at_end() {
+            break;
+        }
+        let start = parser.pos;
+        match parser.parse_call_statement() {
+            Some((path, args)) => {
+                let text = parser.text_since(start);
+                commands.extend(lower_call(&path, args, text));
+            }
+            None => {
+                parser.pos = start;
+                parser.skip_statement();
+                let text = parser.text_since(start);
+                if !text.is_empty() {
+                    commands.push(FourslashCommand::Unsupported(text.to_string()));
+                }
+            }
+        }
+    }
+    commands
+}
+
+/// Map a parsed `path(args)` call onto commands.
+fn lower_call(path: &str, args: Vec<JsValue>, text: &str) -> Vec<FourslashCommand> {
+    let unsupported = || vec![FourslashCommand::Unsupported(text.to_string())];
+    if !args.iter().all(JsValue::is_literal) {
+        return unsupported();
+    }
+    let optional_str = |idx: usize| -> Option<Option<String>> {
+        match args.get(idx) {
+            None | Some(JsValue::Undefined) => Some(None),
+            Some(JsValue::Str(s)) => Some(Some(s.clone())),
+            Some(_) => None,
+        }
+    };
+
+    let command = match (path, args.as_slice()) {
+        ("goTo.marker", []) => FourslashCommand::GoToMarker(String::new()),
+        ("goTo.marker", [JsValue::Str(name)]) => FourslashCommand::GoToMarker(name.clone()),
+        ("goTo.file", [JsValue::Str(name)]) => FourslashCommand::GoToFile(name.clone()),
+        ("verify.quickInfoAt", [JsValue::Str(marker), ..]) if args.len() <= 3 => {
+            let (Some(expected), Some(documentation)) = (optional_str(1), optional_str(2)) else {
+                return unsupported();
+            };
+            FourslashCommand::QuickInfoAt {
+                marker: marker.clone(),
+                expected,
+                documentation,
+            }
+        }
+        ("verify.quickInfoIs", [JsValue::Str(expected), ..]) if args.len() <= 2 => {
+            let Some(documentation) = optional_str(1) else {
+                return unsupported();
+            };
+            FourslashCommand::QuickInfoIs {
+                expected: expected.clone(),
+                documentation,
+            }
+        }
+        ("verify.quickInfos", [JsValue::Object(entries)]) => {
+            let mut commands = Vec::with_capacity(entries.len());
+            for (marker, value) in entries {
+                let (expected, documentation) = match value {
+                    JsValue::Str(text) => (text.clone(), None),
+                    JsValue::Array(parts) => match parts.as_slice() {
+                        [JsValue::Str(text)] => (text.clone(), None),
+                        [JsValue::Str(text), JsValue::Str(docs)] => {
+                            (text.clone(), Some(docs.clone()))
+                        }
+                        _ => return unsupported(),
+                    },
+                    _ => return unsupported(),
+                };
+                commands.push(FourslashCommand::QuickInfoAt {
+                    marker: marker.clone(),
+                    expected: Some(expected),
+                    documentation,
+                });
+            }
+            return commands;
+        }
+        ("verify.quickInfoExists", []) => FourslashCommand::QuickInfoExists { negated: false },
+        ("verify.not.quickInfoExists", []) => FourslashCommand::QuickInfoExists { negated: true },
+        ("verify.completions", [_, ..]) => {
+            let Some(expectations) = args
+                .iter()
+                .map(completions_expectation)
+                .collect::<Option<Vec<_>>>()
+            else {
+                return unsupported();
+            };
+            FourslashCommand::Completions(expectations)
+        }
+        ("verify.goToDefinition", [JsValue::Object(entries)]) => {
+            let Some(pairs) = entries
+                .iter()
+                .map(|(from, to)| Some((from.clone(), to.as_string_list()?)))
+                .collect::<Option<Vec<_>>>()
+            else {
+                return unsupported();
+            };
+            FourslashCommand::GoToDefinition(pairs)
+        }
+        ("verify.goToDefinition", [from, to]) => {
+            let (Some(from), Some(to)) = (from.as_string_list(), to.as_string_list()) else {
+                return unsupported();
+            };
+            FourslashCommand::GoToDefinition(
+                from.into_iter()
+                    .map(|marker| (marker, to.clone()))
+                    .collect(),
+            )
+        }
+        ("verify.rangesAreRenameLocations", []) => FourslashCommand::RangesAreRenameLocations,
+        ("verify.noErrors", []) => FourslashCommand::NoErrors,
+        _ => return unsupported(),
+    };
+    vec![command]
+}
+
+/// Lower one `verify.completions` argument.
+///
+/// Entry objects are matched by `name` only; `isNewIdentifierLocation` and
+/// `isGlobalCompletion` are not observable through the LSP provider and are
+/// ignored. Any other option (e.g. `preferences`) changes what the provider
+/// must return, so it makes the expectation unsupported.
+fn completions_expectation(value: &JsValue) -> Option<CompletionsExpectation> {
+    let JsValue::Object(props) = value else {
+        return None;
+    };
+    let mut expectation = CompletionsExpectation::default();
+    for (key, value) in props {
+        match key.as_str() {
+            "marker" => expectation.markers = value.as_string_list()?,
+            "includes" => expectation.includes = completion_entry_names(value)?,
+            "excludes" => expectation.excludes = completion_entry_names(value)?,
+            "exact" => expectation.exact = Some(completion_entry_names(value)?),
+            "isNewIdentifierLocation" | "isGlobalCompletion"
+                if matches!(value, JsValue::Bool(true | false)) => {}
+            _ => return None,
+        }
+    }
+    Some(expectation)
+}
+
+/// Entry names from a completion entry, or an array of entries; each entry is
+/// a string or an object with a `name` property.
+fn completion_entry_names(value: &JsValue) -> Option<Vec<String>> {
+    let entry_name = |entry: &JsValue| match entry {
+        JsValue::Str(name) => Some(name.clone()),
+        JsValue::Object(props) => props
+            .iter()
+            .find(|(key, _)| key == "name")
+            .and_then(|(_, name)| name.as_str().map(str::to_string)),
+        _ => None,
+    };
+    match value {
+        JsValue::Array(entries) => entries.iter().map(entry_name).collect(),
+        entry => Some(vec![entry_name(entry)?]),
+    }
+}
+
+struct ScriptParser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> ScriptParser<'a> {
+    fn new(src: &'a str) -> Self {
+        Self { src, pos: 0 }
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.src.len()
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn text_since(&self, start: usize) -> &'a str {
+        self.src[start..self.pos].trim()
+    }
+
+    /// Skip whitespace and comments.
+    fn skip_trivia(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if trimmed.starts_with("//") {
+                self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else if trimmed.starts_with("/*") {
+                self.pos += trimmed[2..].find("*/").map_or(trimmed.len(), |end| end + 4);
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        self.skip_trivia();
+        if self.peek() == Some(expected) {
+            self.pos += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_identifier(&mut self) -> Option<&'a str> {
+        self.skip_trivia();
+        let rest = self.rest();
+        let len = rest
+            .char_indices()
+            .find(|&(idx, ch)| {
+                !(ch == '_' || ch == '$' || ch.is_alphabetic() || (idx > 0 && ch.is_numeric()))
+            })
+            .map_or(rest.len(), |(idx, _)| idx);
+        if len == 0 {
+            return None;
+        }
+        self.pos += len;
+        Some(&rest[..len])
+    }
+
+    /// `a.b.c(args);` at statement level.
+    fn parse_call_statement(&mut self) -> Option<(String, Vec<JsValue>)> {
+        let mut path = self.parse_identifier()?.to_string();
+        while self.eat('.') {
+            path.push('.');
+            path.push_str(self.parse_identifier()?);
+        }
+        if !self.eat('(') {
+            return None;
+        }
+        let args = self.parse_list(')')?;
+        let call_end = self.pos;
+        // A call whose result is used further (`f().x`, `f() + 1`) is not a
+        // plain command statement. A line break after the call ends the
+        // statement through automatic semicolon insertion.
+        self.skip_trivia();
+        match self.peek() {
+            None => {}
+            Some(';') => self.pos += 1,
+            Some(_) if self.src[call_end..self.pos].contains('\n') => {}
+            Some(_) => return None,
+        }
+        Some((path, args))
+    }
+
+    /// Comma-separated values up to `close`, allowing a trailing comma.
+    fn parse_list(&mut self, close: char) -> Option<Vec<JsValue>> {
+        let mut values = Vec::new();
+        loop {
+            if self.eat(close) {
+                return Some(values);
+            }
+            values.push(self.parse_value()?);
+            if !self.eat(',') {
+                return self.eat(close).then_some(values);
+            }
+        }
+    }
+
+    fn parse_value(&mut self) -> Option<JsValue> {
+        self.skip_trivia();
+        let start = self.pos;
+        let value = match self.peek()? {
+            '"' | '\'' | '`' => self.parse_string().map(JsValue::Str),
+            '[' => {
+                self.pos += 1;
+                self.parse_list(']').map(JsValue::Array)
+            }
+            '{' => {
+                self.pos += 1;
+                self.parse_object()
+            }
+            ch if ch.is_ascii_digit() || ch == '-' => self.parse_number(),
+            _ => match self.parse_identifier() {
+                Some("true") => Some(JsValue::Bool(true)),
+                Some("false") => Some(JsValue::Bool(false)),
+                Some("null") => Some(JsValue::Null),
+                Some("undefined") => Some(JsValue::Undefined),
+                _ => None,
+            },
+        };
+        // Literals followed by more expression (`"a" + b`), and anything that
+        // is not a literal, are captured as opaque source text.
+        self.skip_trivia();
+        match (value, self.peek()) {
+            (Some(value), None | Some(',' | ')' | ']' | '}')) => Some(value),
+            _ => {
+                self.pos = start;
+                self.parse_opaque()
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Option<JsValue> {
+        let mut props = Vec::new();
+        loop {
+            if self.eat('}') {
+                return Some(JsValue::Object(props));
+            }
+            self.skip_trivia();
+            let key = match self.peek()? {
+                '"' | '\'' => self.parse_string()?,
+                ch if ch.is_ascii_digit() => match self.parse_number()? {
+                    JsValue::Num(n) => n.to_string(),
+                    _ => return None,
+                },
+                _ => self.parse_identifier()?.to_string(),
+            };
+            if !self.eat(':') {
+                return None;
+            }
+            props.push((key, self.parse_value()?));
+            if !self.eat(',') {
+                return self.eat('}').then_some(JsValue::Object(props));
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Option<String> {
+        let quote = self.peek()?;
+        self.pos += 1;
+        let mut out = String::new();
+        let mut chars = self.rest().char_indices();
+        while let Some((idx, ch)) = chars.next() {
+            match ch {
+                c if c == quote => {
+                    self.pos += idx + 1;
+                    return Some(out);
+                }
+                '$' if quote == '`' && self.rest()[idx..].starts_with("${") => return None,
+                '\\' => {
+                    let (_, escaped) = chars.next()?;
+                    match escaped {
+                        'n' => out.push('\n'),
+                        'r' => out.push('\r'),
+                        't' => out.push('\t'),
+                        '0' => out.push('\0'),
+                        'u' => {
+                            let hex: String = (0..4)
+                                .filter_map(|_| chars.next())
+                                .map(|(_, c)| c)
+                                .collect();
+                            out.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+                        }
+                        // Line continuation.
+                        '\n' => {}
+                        other => out.push(other),
+                    }
+                }
+                '\n' if quote != '`' => return None,
+                other => out.push(other),
+            }
+        }
+        None
+    }
+
+    fn parse_number(&mut self) -> Option<JsValue> {
+        let rest = self.rest();
+        let len = rest
+            .char_indices()
+            .find(|&(idx, ch)| !(ch.is_ascii_digit() || ch == '.' || (idx == 0 && ch == '-')))
+            .map_or(rest.len(), |(idx, _)| idx);
+        let value = rest[..len].parse().ok()?;
+        self.pos += len;
+        Some(JsValue::Num(value))
+    }
+
+    /// Consume an arbitrary expression up to the next `,` or closing bracket
+    /// at nesting depth zero.
+    fn parse_opaque(&mut self) -> Option<JsValue> {
+        let start = self.pos;
+        let mut depth = 0usize;
+        while let Some(ch) = self.peek() {
+            match ch {
+                '"' | '\'' | '`' => {
+                    self.parse_string()?;
+                    continue;
+                }
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' if depth == 0 => break,
+                ')' | ']' | '}' => depth -= 1,
+                ',' if depth == 0 => break,
+                ';' if depth == 0 => return None,
+                _ => {}
+            }
+            self.pos += ch.len_utf8();
+        }
+        (!self.src[start..self.pos].trim().is_empty()).then_some(JsValue::Opaque)
+    }
+
+    /// Skip one statement: up to a `;` or line break at nesting depth zero.
+    fn skip_statement(&mut self) {
+        let mut depth = 0usize;
+        while let Some(ch) = self.peek() {
+            match ch {
+                '"' | '\'' | '`' => {
+                    if self.parse_string().is_none() {
+                        self.pos += 1;
+                    }
+                    continue;
+                }
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth = depth.saturating_sub(1),
+                ';' if depth == 0 => {
+                    self.pos += 1;
+                    return;
+                }
+                '\n' if depth == 0 => return,
+                _ => {}
+            }
+            self.pos += ch.len_utf8();
+        }
+    }
+}
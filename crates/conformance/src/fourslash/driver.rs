@@ -0,0 +1,341 @@
+//! Fourslash command execution against the tsz LSP providers
+//!
+//! Loads a parsed fourslash test into a `tsz_lsp::Project` and runs its
+//! commands the way an editor would: every query is a plain file name plus
+//! LSP position derived from a marker offset.
+
+use super::commands::{CompletionsExpectation, FourslashCommand};
+use super::parser::{offset_to_line_character, FourslashMarker, FourslashTestFile};
+use tsz_lsp::{DiagnosticSeverity, Location, Position, Project};
+
+/// Executes one fourslash test and collects assertion failures.
+pub struct FourslashDriver<'t> {
+    test: &'t FourslashTestFile,
+    project: Project,
+    /// Current cursor, set by `goTo.marker`.
+    current_marker: Option<FourslashMarker>,
+    failures: Vec<String>,
+}
+
+impl<'t> FourslashDriver<'t> {
+    pub fn new(test: &'t FourslashTestFile) -> Self {
+        let mut project = Project::new();
+        if let Some(strict) = test.options.get("strict") {
+            project.set_strict(strict.eq_ignore_ascii_case("true"));
+        }
+        for file in &test.files {
+            project.set_file(file.name.clone(), file.content.clone());
+        }
+        Self {
+            test,
+            project,
+            current_marker: None,
+            failures: Vec::new(),
+        }
+    }
+
+    /// Run every command, returning the failure messages (empty on success).
+    pub fn run(mut self, commands: &[FourslashCommand]) -> Vec<String> {
+        for command in commands {
+            self.execute(command);
+        }
+        self.failures
+    }
+
+    fn fail(&mut self, message: String) {
+        self.failures.push(message);
+    }
+
+    fn execute(&mut self, command: &FourslashCommand) {
+        match command {
+            FourslashCommand::GoToMarker(name) => {
+                if let Some(marker) = self.lookup_marker(name) {
+                    self.current_marker = Some(marker);
+                }
+            }
+            FourslashCommand::GoToFile(name) => {
+                // Marker-less commands are not supported, so switching files
+                // only has to drop the cursor of the previous file.
+                if self.test.file_content(name).is_some() {
+                    self.current_marker = None;
+                } else {
+                    self.fail(format!("goTo.file: unknown file '{name}'"));
+                }
+            }
+            FourslashCommand::QuickInfoAt {
+                marker,
+                expected,
+                documentation,
+            } => {
+                if let Some(marker) = self.lookup_marker(marker) {
+                    self.verify_quick_info(&marker, expected.as_deref(), documentation.as_deref());
+                }
+            }
+            FourslashCommand::QuickInfoIs {
+                expected,
+                documentation,
+            } => {
+                if let Some(marker) = self.require_current_marker("verify.quickInfoIs") {
+                    self.verify_quick_info(&marker, Some(expected), documentation.as_deref());
+                }
+            }
+            FourslashCommand::QuickInfoExists { negated } => {
+                if let Some(marker) = self.require_current_marker("verify.quickInfoExists") {
+                    let pos = self.position(&marker);
+                    let found = self
+                        .project
+                        .get_hover(&marker.file, pos)
+                        .is_some_and(|info| !info.display_string.is_empty());
+                    if found == *negated {
+                        self.fail(format!(
+                            "quickInfoExists at marker '{}': expected {}",
+                            marker.name,
+                            if *negated { "none" } else { "quick info" }
+                        ));
+                    }
+                }
+            }
+            FourslashCommand::Completions(expectations) => {
+                for expectation in expectations {
+                    self.verify_completions(expectation);
+                }
+            }
+            FourslashCommand::GoToDefinition(pairs) => {
+                for (from, to) in pairs {
+                    self.verify_definition(from, to);
+                }
+            }
+            FourslashCommand::RangesAreRenameLocations => self.verify_rename_locations(),
+            FourslashCommand::NoErrors => self.verify_no_errors(),
+            FourslashCommand::Unsupported(text) => {
+                self.fail(format!("unsupported command: {text}"));
+            }
+        }
+    }
+
+    fn lookup_marker(&mut self, name: &str) -> Option<FourslashMarker> {
+        let marker = self.test.marker(name).cloned();
+        if marker.is_none() {
+            self.fail(format!("unknown marker '{name}'"));
+        }
+        marker
+    }
+
+    fn require_current_marker(&mut self, command: &str) -> Option<FourslashMarker> {
+        let marker = self.current_marker.clone();
+        if marker.is_none() {
+            self.fail(format!(
+                "{command}: no current marker (missing goTo.marker)"
+            ));
+        }
+        marker
+    }
+
+    fn position_in(&self, file: &str, offset: usize) -> Position {
+        let text = self.test.file_content(file).unwrap_or_default();
+        let (line, character) = offset_to_line_character(text, offset);
+        Position::new(line, character)
+    }
+
+    fn position(&self, marker: &FourslashMarker) -> Position {
+        self.position_in(&marker.file, marker.offset)
+    }
+
+    fn verify_quick_info(
+        &mut self,
+        marker: &FourslashMarker,
+        expected: Option<&str>,
+        documentation: Option<&str>,
+    ) {
+        let pos = self.position(marker);
+        let Some(info) = self.project.get_hover(&marker.file, pos) else {
+            self.fail(format!(
+                "quickInfo at marker '{}': no quick info",
+                marker.name
+            ));
+            return;
+        };
+        if let Some(expected) = expected {
+            if info.display_string != expected {
+                self.fail(format!(
+                    "quickInfo at marker '{}':\n  expected: {expected}\n  actual:   {}",
+                    marker.name, info.display_string
+                ));
+            }
+        }
+        if let Some(documentation) = documentation {
+            if info.documentation != documentation {
+                self.fail(format!(
+                    "quickInfo documentation at marker '{}':\n  expected: {documentation}\n  actual:   {}",
+                    marker.name, info.documentation
+                ));
+            }
+        }
+    }
+
+    fn verify_completions(&mut self, expectation: &CompletionsExpectation) {
+        let markers: Vec<FourslashMarker> = if expectation.markers.is_empty() {
+            match self.require_current_marker("verify.completions") {
+                Some(marker) => vec![marker],
+                None => return,
+            }
+        } else {
+            let names = expectation.markers.clone();
+            names
+                .iter()
+                .filter_map(|name| self.lookup_marker(name))
+                .collect()
+        };
+
+        for marker in markers {
+            let pos = self.position(&marker);
+            let labels: Vec<String> = self
+                .project
+                .get_completions(&marker.file, pos)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| item.label)
+                .collect();
+            let missing: Vec<&String> = expectation
+                .includes
+                .iter()
+                .filter(|name| !labels.contains(name))
+                .collect();
+            if !missing.is_empty() {
+                self.fail(format!(
+                    "completions at marker '{}': missing {missing:?}",
+                    marker.name
+                ));
+            }
+            let unexpected: Vec<&String> = expectation
+                .excludes
+                .iter()
+                .filter(|name| labels.contains(name))
+                .collect();
+            if !unexpected.is_empty() {
+                self.fail(format!(
+                    "completions at marker '{}': unexpected {unexpected:?}",
+                    marker.name
+                ));
+            }
+            // `exact` is compared as a set of names: the LSP provider orders
+            // entries by sort text, not in tsserver's response order.
+            if let Some(exact) = &expectation.exact {
+                let mut expected_sorted = exact.clone();
+                expected_sorted.sort();
+                let mut actual_sorted = labels.clone();
+                actual_sorted.sort();
+                if expected_sorted != actual_sorted {
+                    self.fail(format!(
+                        "completions at marker '{}':\n  expected exactly: {expected_sorted:?}\n  actual:           {actual_sorted:?}",
+                        marker.name
+                    ));
+                }
+            }
+        }
+    }
+
+    fn verify_definition(&mut self, from: &str, to: &[String]) {
+        let Some(from_marker) = self.lookup_marker(from) else {
+            return;
+        };
+        let pos = self.position(&from_marker);
+        let locations = self
+            .project
+            .get_definition(&from_marker.file, pos)
+            .unwrap_or_default();
+        for target in to {
+            let Some(target_marker) = self.lookup_marker(target) else {
+                continue;
+            };
+            let target_pos = self.position(&target_marker);
+            if !locations
+                .iter()
+                .any(|loc| location_contains(loc, &target_marker.file, target_pos))
+            {
+                self.fail(format!(
+                    "goToDefinition from marker '{from}': expected marker '{target}', got {locations:?}"
+                ));
+            }
+        }
+    }
+
+    /// Renaming at any `[| |]` range must edit exactly the set of ranges.
+    fn verify_rename_locations(&mut self) {
+        let mut expected: Vec<(String, Position, Position)> = self
+            .test
+            .ranges
+            .iter()
+            .map(|range| {
+                (
+                    range.file.clone(),
+                    self.position_in(&range.file, range.start),
+                    self.position_in(&range.file, range.end),
+                )
+            })
+            .collect();
+        expected.sort_by_key(|(file, start, _)| (file.clone(), start.line, start.character));
+
+        for (file, start, _) in expected.clone() {
+            let edit = match self
+                .project
+                .get_rename_edits(&file, start, "__tszRename".to_string())
+            {
+                Ok(edit) => edit,
+                Err(err) => {
+                    self.fail(format!(
+                        "rename at {file}:{}:{} failed: {err}",
+                        start.line, start.character
+                    ));
+                    continue;
+                }
+            };
+            let mut actual: Vec<(String, Position, Position)> = edit
+                .changes
+                .into_iter()
+                .flat_map(|(file, edits)| {
+                    edits
+                        .into_iter()
+                        .map(move |edit| (file.clone(), edit.range.start, edit.range.end))
+                })
+                .collect();
+            actual.sort_by_key(|(file, start, _)| (file.clone(), start.line, start.character));
+            if actual != expected {
+                self.fail(format!(
+                    "rename at {file}:{}:{}:\n  expected locations: {expected:?}\n  actual locations:   {actual:?}",
+                    start.line, start.character
+                ));
+            }
+        }
+    }
+
+    fn verify_no_errors(&mut self) {
+        for file in &self.test.files {
+            let errors: Vec<String> = self
+                .project
+                .get_diagnostics(&file.name)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|diag| diag.severity == Some(DiagnosticSeverity::Error))
+                .map(|diag| match diag.code {
+                    Some(code) => format!("TS{code}: {}", diag.message),
+                    None => diag.message,
+                })
+                .collect();
+            if !errors.is_empty() {
+                self.failures
+                    .push(format!("noErrors: {} has errors: {errors:?}", file.name));
+            }
+        }
+    }
+}
+
+/// Whether `pos` in `file` falls inside `loc` (inclusive of both ends, so a
+/// declaration span starting at the `function` keyword still contains the
+/// marker on its name).
+fn location_contains(loc: &Location, file: &str, pos: Position) -> bool {
+    let at = (pos.line, pos.character);
+    loc.file_path == file
+        && (loc.range.start.line, loc.range.start.character) <= at
+        && at <= (loc.range.end.line, loc.range.end.character)
+}
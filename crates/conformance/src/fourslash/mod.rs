@@ -0,0 +1,193 @@
+//! Fourslash test runner
+//!
+//! Runs TypeScript's `tests/cases/fourslash/*.ts` service tests against the
+//! tsz LSP providers (`--fourslash`). Each test is parsed into virtual files
+//! (`////` lines with `/*marker*/` and `[|range|]` annotations) and a script of
+//! `goTo.*` / `verify.*` commands, then executed in-process on a fresh
+//! `tsz_lsp::Project`.
+//!
+//! Supported verifications: quick info (`quickInfoAt`, `quickInfoIs`,
+//! `quickInfos`, `quickInfoExists`), `completions` (`includes` / `excludes` /
+//! `exact` by entry name), `goToDefinition`, `rangesAreRenameLocations`, and
+//! `noErrors`. A test containing any other statement is reported as skipped,
+//! never as passed.
+
+pub mod commands;
+pub mod driver;
+pub mod parser;
+
+use crate::cli::Args;
+use crate::test_filter::matches_path_filter;
+use crate::text_decode::{decode_source_text, DecodedSourceText};
+use rayon::prelude::*;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use commands::{parse_commands, FourslashCommand};
+use driver::FourslashDriver;
+use parser::parse_fourslash_test;
+
+/// Result of running one fourslash test.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FourslashOutcome {
+    Pass,
+    /// Assertion failures, in command order.
+    Fail(Vec<String>),
+    /// The test uses commands the runner cannot execute.
+    Skipped(String),
+    /// An LSP provider panicked.
+    Crashed(String),
+}
+
+/// Aggregate counts for a fourslash run.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FourslashStats {
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub crashed: usize,
+}
+
+impl FourslashStats {
+    /// Tests that were actually executed (not skipped).
+    pub fn evaluated(&self) -> usize {
+        self.passed + self.failed + self.crashed
+    }
+
+    pub fn pass_rate(&self) -> f64 {
+        if self.evaluated() == 0 {
+            0.0
+        } else {
+            self.passed as f64 / self.evaluated() as f64 * 100.0
+        }
+    }
+
+    fn record(&mut self, outcome: &FourslashOutcome) {
+        match outcome {
+            FourslashOutcome::Pass => self.passed += 1,
+            FourslashOutcome::Fail(_) => self.failed += 1,
+            FourslashOutcome::Skipped(_) => self.skipped += 1,
+            FourslashOutcome::Crashed(_) => self.crashed += 1,
+        }
+    }
+}
+
+/// Parse and run a single fourslash test.
+pub fn run_fourslash_test(content: &str, file_name: &str) -> FourslashOutcome {
+    let test = parse_fourslash_test(content, file_name);
+    let commands = parse_commands(&test.script);
+    if let Some(FourslashCommand::Unsupported(text)) = commands
+        .iter()
+        .find(|command| matches!(command, FourslashCommand::Unsupported(_)))
+    {
+        let first_line = text.lines().next().unwrap_or_default();
+        return FourslashOutcome::Skipped(format!("unsupported: {first_line}"));
+    }
+    if commands.is_empty() {
+        return FourslashOutcome::Skipped("no commands".to_string());
+    }
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        FourslashDriver::new(&test).run(&commands)
+    })) {
+        Ok(failures) if failures.is_empty() => FourslashOutcome::Pass,
+        Ok(failures) => FourslashOutcome::Fail(failures),
+        Err(payload) => FourslashOutcome::Crashed(
+            payload
+                .downcast_ref::<String>()
+                .cloned()
+                .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+                .unwrap_or_else(|| "panic".to_string()),
+        ),
+    }
+}
+
+/// Collect the fourslash tests under `<test_dir>/fourslash`, applying the
+/// runner's `--filter`, `--offset` and `--max` selection.
+///
+/// Only top-level files are collected: the `server/` and `shims*/`
+/// subdirectories drive tsserver and the language-service shims directly.
+pub fn collect_fourslash_tests(args: &Args) -> anyhow::Result<Vec<PathBuf>> {
+    let dir = Path::new(&args.test_dir).join("fourslash");
+    let mut tests: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .map_err(|err| anyhow::anyhow!("cannot read {}: {err}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "ts"))
+        .filter(|path| matches_path_filter(path, args.filter.as_deref()))
+        .collect();
+    tests.sort();
+    let max = if args.all { usize::MAX } else { args.max };
+    Ok(tests.into_iter().skip(args.offset).take(max).collect())
+}
+
+/// Run the fourslash suite and print a summary.
+pub fn run_fourslash_suite(args: &Args) -> anyhow::Result<FourslashStats> {
+    let tests = collect_fourslash_tests(args)?;
+    let start = Instant::now();
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(args.workers.max(1))
+        .build()?;
+    let outcomes: Vec<(PathBuf, FourslashOutcome)> = pool.install(|| {
+        tests
+            .par_iter()
+            .map(|path| (path.clone(), run_fourslash_file(path)))
+            .collect()
+    });
+
+    let mut stats = FourslashStats::default();
+    for (path, outcome) in &outcomes {
+        stats.record(outcome);
+        let rel_path = path.strip_prefix(&args.test_dir).unwrap_or(path).display();
+        match outcome {
+            FourslashOutcome::Pass if args.is_verbose() => println!("PASS {rel_path}"),
+            FourslashOutcome::Fail(failures) => {
+                println!("FAIL {rel_path}");
+                if args.is_verbose() {
+                    for failure in failures {
+                        println!("  {}", failure.replace('\n', "\n  "));
+                    }
+                }
+            }
+            FourslashOutcome::Skipped(reason) if args.is_verbose() => {
+                println!("SKIP {rel_path} ({reason})")
+            }
+            FourslashOutcome::Crashed(message) => println!("CRASH {rel_path} ({message})"),
+            _ => {}
+        }
+    }
+
+    println!();
+    println!("{}", "=".repeat(60));
+    println!(
+        "FOURSLASH RESULTS: {}/{} passed ({:.1}%)",
+        stats.passed,
+        stats.evaluated(),
+        stats.pass_rate()
+    );
+    println!("  Skipped: {}", stats.skipped);
+    println!("  Crashed: {}", stats.crashed);
+    println!("  Time: {:.1}s", start.elapsed().as_secs_f64());
+    println!("{}", "=".repeat(60));
+    Ok(stats)
+}
+
+fn run_fourslash_file(path: &Path) -> FourslashOutcome {
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(err) => return FourslashOutcome::Skipped(format!("unreadable: {err}")),
+    };
+    let content = match decode_source_text(&bytes) {
+        DecodedSourceText::Text(text) | DecodedSourceText::TextWithOriginalBytes(text, _) => text,
+        DecodedSourceText::Binary(_) => return FourslashOutcome::Skipped("binary".to_string()),
+    };
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "test.ts".to_string());
+    run_fourslash_test(&content, &file_name)
+}
+
+#[cfg(test)]
+#[path = "../../tests/fourslash_tests.rs"]
+mod tests;
@@ -0,0 +1,223 @@
+//! Fourslash test file parser
+//!
+//! Splits a TypeScript fourslash test into its virtual source files (`////`
+//! lines), `// @key: value` directives, and the remaining script text.
+//! Markers (`/*name*/`, `/**/`, `{| ... |}`) and ranges (`[| ... |]`) are
+//! stripped from the source and recorded as offsets into the cleaned text.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Matches: // @key: value
+static DIRECTIVE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\s*//\s*@(\w+)\s*:\s*([^\r\n]*)").unwrap());
+
+/// A named cursor position inside a fourslash source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FourslashMarker {
+    /// Marker name; empty for anonymous `/**/` markers.
+    pub name: String,
+    /// File the marker belongs to.
+    pub file: String,
+    /// Byte offset into the cleaned file text.
+    pub offset: usize,
+}
+
+/// A `[| ... |]` span inside a fourslash source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FourslashRange {
+    /// File the range belongs to.
+    pub file: String,
+    /// Byte offset of the range start in the cleaned file text.
+    pub start: usize,
+    /// Byte offset of the range end in the cleaned file text.
+    pub end: usize,
+}
+
+/// One virtual file declared with `////` lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FourslashSourceFile {
+    pub name: String,
+    /// File text with markers and range delimiters removed.
+    pub content: String,
+}
+
+/// A fully parsed fourslash test.
+#[derive(Debug, Clone, Default)]
+pub struct FourslashTestFile {
+    /// Virtual files, in declaration order.
+    pub files: Vec<FourslashSourceFile>,
+    /// Markers in source order.
+    pub markers: Vec<FourslashMarker>,
+    /// Ranges in source order (by start offset within each file).
+    pub ranges: Vec<FourslashRange>,
+    /// Global `// @key: value` directives (keys lowercased).
+    pub options: HashMap<String, String>,
+    /// Everything that is not file content or a directive: the test script.
+    pub script: String,
+}
+
+impl FourslashTestFile {
+    /// Look up a marker by name.
+    pub fn marker(&self, name: &str) -> Option<&FourslashMarker> {
+        self.markers.iter().find(|marker| marker.name == name)
+    }
+
+    /// Cleaned content of a virtual file.
+    pub fn file_content(&self, name: &str) -> Option<&str> {
+        self.files
+            .iter()
+            .find(|file| file.name == name)
+            .map(|file| file.content.as_str())
+    }
+}
+
+/// Parse a fourslash test.
+///
+/// `default_file_name` names the implicit file used when content lines appear
+/// before any `// @Filename:` directive (tsc uses the test's own file name).
+pub fn parse_fourslash_test(content: &str, default_file_name: &str) -> FourslashTestFile {
+    let content = content.strip_prefix('\u{FEFF}').unwrap_or(content);
+    let mut test = FourslashTestFile::default();
+    let mut script_lines: Vec<&str> = Vec::new();
+
+    // Raw (marker-bearing) content per file, in declaration order.
+    let mut raw_files: Vec<(String, Vec<&str>)> = Vec::new();
+    let mut current_file: Option<String> = None;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if let Some(source_line) = trimmed.strip_prefix("////") {
+            let name = current_file
+                .clone()
+                .unwrap_or_else(|| default_file_name.to_string());
+            match raw_files.last_mut() {
+                Some((last_name, lines)) if *last_name == name => lines.push(source_line),
+                _ => raw_files.push((name, vec![source_line])),
+            }
+            continue;
+        }
+        if let Some(cap) = DIRECTIVE_RE.captures(line) {
+            let key = cap.get(1).unwrap().as_str().to_lowercase();
+            let value = cap.get(2).unwrap().as_str().trim().to_string();
+            if key == "filename" {
+                // An empty file is still a file: `// @Filename: a.ts` followed
+                // directly by another directive declares `a.ts` as "".
+                raw_files.push((value.clone(), Vec::new()));
+                current_file = Some(value);
+            } else {
+                test.options.insert(key, value);
+            }
+            continue;
+        }
+        script_lines.push(line);
+    }
+
+    for (name, lines) in raw_files {
+        let raw = lines.join("\n");
+        let (cleaned, markers, ranges) = strip_markers(&name, &raw);
+        test.markers.extend(markers);
+        test.ranges.extend(ranges);
+        test.files.push(FourslashSourceFile {
+            name,
+            content: cleaned,
+        });
+    }
+    test.script = script_lines.join("\n");
+    test
+}
+
+/// Remove markers and range delimiters from `raw`, returning the cleaned text
+/// and the markers/ranges found, in cleaned-text offsets.
+fn strip_markers(file: &str, raw: &str) -> (String, Vec<FourslashMarker>, Vec<FourslashRange>) {
+    let mut cleaned = String::with_capacity(raw.len());
+    let mut markers = Vec::new();
+    let mut ranges = Vec::new();
+    let mut open_ranges: Vec<usize> = Vec::new();
+    let mut rest = raw;
+
+    while let Some(ch) = rest.chars().next() {
+        if rest.starts_with("[|") {
+            open_ranges.push(cleaned.len());
+            rest = &rest[2..];
+            continue;
+        }
+        if rest.starts_with("|]") {
+            if let Some(start) = open_ranges.pop() {
+                ranges.push(FourslashRange {
+                    file: file.to_string(),
+                    start,
+                    end: cleaned.len(),
+                });
+                rest = &rest[2..];
+                continue;
+            }
+        }
+        if rest.starts_with("/*") {
+            if let Some(end) = rest[2..].find("*/") {
+                let name = &rest[2..2 + end];
+                if is_marker_name(name) {
+                    markers.push(FourslashMarker {
+                        name: name.to_string(),
+                        file: file.to_string(),
+                        offset: cleaned.len(),
+                    });
+                    rest = &rest[2 + end + 2..];
+                    continue;
+                }
+            }
+        }
+        if rest.starts_with("{|") {
+            if let Some(end) = rest[2..].find("|}") {
+                let body = &rest[2..2 + end];
+                markers.push(FourslashMarker {
+                    name: object_marker_name(body).unwrap_or_default(),
+                    file: file.to_string(),
+                    offset: cleaned.len(),
+                });
+                rest = &rest[2 + end + 2..];
+                continue;
+            }
+        }
+        cleaned.push(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+
+    ranges.sort_by_key(|range| range.start);
+    (cleaned, markers, ranges)
+}
+
+/// Marker names are identifiers or digits; anything else is a real comment.
+fn is_marker_name(name: &str) -> bool {
+    name.chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '$')
+}
+
+/// The `name` property of an object marker such as `{| "name": "a" |}`.
+fn object_marker_name(body: &str) -> Option<String> {
+    static NAME_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"["']?name["']?\s*:\s*["']([^"']*)["']"#).unwrap());
+    NAME_RE
+        .captures(body)
+        .map(|cap| cap.get(1).unwrap().as_str().to_string())
+}
+
+/// Convert a byte offset in `text` into a zero-based (line, UTF-16 column)
+/// pair, the coordinate system LSP positions use.
+pub fn offset_to_line_character(text: &str, offset: usize) -> (u32, u32) {
+    let mut line = 0u32;
+    let mut character = 0u32;
+    for (idx, ch) in text.char_indices() {
+        if idx >= offset {
+            break;
+        }
+        if ch == '\n' {
+            line += 1;
+            character = 0;
+        } else {
+            character += ch.len_utf16() as u32;
+        }
+    }
+    (line, character)
+}
@@ -1,9 +1,11 @@
 //! TSZ Conformance Test Runner Library
 
+pub mod baseline;
 pub(crate) mod batch_pool;
 pub mod cache;
 pub mod cli;
 pub mod compiler_options;
+pub mod fourslash;
 pub(crate) mod options_convert;
 pub(crate) mod parity;
 pub(crate) mod process_rss;
@@ -2,10 +2,12 @@
 //!
 //! High-performance Rust implementation for testing tsz TypeScript compiler.
 
+mod baseline;
 mod batch_pool;
 mod cache;
 mod cli;
 mod compiler_options;
+mod fourslash;
 mod options_convert;
 mod parity;
 mod process_rss;
@@ -45,6 +47,22 @@ async fn main() -> anyhow::Result<()> {
         return handle_cache_clear(&args.cache_file);
     }
 
+    if args.baselines {
+        let stats = baseline::run_baseline_generation(&args)?;
+        if stats.failed > 0 {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if args.fourslash {
+        let stats = fourslash::run_fourslash_suite(&args)?;
+        if stats.failed + stats.crashed > 0 {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     // Run tests
     let runner = Runner::new(args.clone())?;
     let stats = runner.run().await?;
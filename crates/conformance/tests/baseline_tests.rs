@@ -0,0 +1,161 @@
+use super::errors::{
+    parse_json_diagnostics, render_errors_baseline, BaselineDiagnostic, BaselineRelatedInfo,
+};
+use super::types::{render_type_baseline, TypeBaselineKind};
+use super::BaselineSourceFile;
+use tsz_lsp::type_writer::TypeWriterEntry;
+
+fn source(name: &str, content: &str) -> BaselineSourceFile {
+    BaselineSourceFile {
+        name: name.to_string(),
+        content: content.to_string(),
+    }
+}
+
+fn error(file: &str, start: u32, length: u32, line: u32, column: u32) -> BaselineDiagnostic {
+    BaselineDiagnostic {
+        file: Some(file.to_string()),
+        start,
+        length,
+        line,
+        column,
+        category: "error".to_string(),
+        code: 2322,
+        message: "Type 'string' is not assignable to type 'number'.".to_string(),
+        related: Vec::new(),
+    }
+}
+
+#[test]
+fn parses_json_diagnostics_and_maps_files() {
+    let output = concat!(
+        r#"{"file":"src/a.ts","start":{"line":2,"column":5,"offset":12},"end":{"line":2,"column":6,"offset":13},"length":1,"category":"error","code":2322,"message":"Bad.","messageChain":{},"relatedInformation":[{"file":"src/a.ts","start":{"line":1,"column":7,"offset":6},"end":null,"length":1,"category":"message","code":6500,"message":"Declared here."}]}"#,
+        "\n",
+        r#"{"file":"lib/react.d.ts","start":{"line":1,"column":1,"offset":0},"end":null,"length":1,"category":"error","code":1005,"message":"Lib.","relatedInformation":[]}"#,
+        "\n",
+        r#"{"file":null,"start":null,"end":null,"length":0,"category":"error","code":5023,"message":"Unknown option.","relatedInformation":[]}"#,
+        "\n",
+        "Found 3 errors.\n",
+    );
+    let diagnostics = parse_json_diagnostics(output, |file| {
+        (file == "src/a.ts").then(|| "/src/a.ts".to_string())
+    });
+
+    assert_eq!(diagnostics.len(), 2);
+    let first = &diagnostics[0];
+    assert_eq!(first.file.as_deref(), Some("/src/a.ts"));
+    assert_eq!(
+        (first.start, first.length, first.line, first.column),
+        (12, 1, 2, 5)
+    );
+    assert_eq!(
+        first.related,
+        vec![BaselineRelatedInfo {
+            location: Some(("/src/a.ts".to_string(), 1, 7)),
+            code: 6500,
+            message: "Declared here.".to_string(),
+        }]
+    );
+    assert_eq!(diagnostics[1].file, None);
+    assert_eq!(diagnostics[1].code, 5023);
+}
+
+#[test]
+fn no_errors_baseline_without_diagnostics() {
+    assert_eq!(
+        render_errors_baseline(&[source("a.ts", "let x = 1;")], &[]),
+        None
+    );
+}
+
+#[test]
+fn renders_squiggles_under_error_spans() {
+    let files = [source("a.ts", "let x: number = 1;\n\tx = \"s\";\n")];
+    let baseline = render_errors_baseline(&files, &[error("a.ts", 20, 1, 2, 2)]).unwrap();
+    assert_eq!(
+        baseline,
+        [
+            "a.ts(2,2): error TS2322: Type 'string' is not assignable to type 'number'.",
+            "",
+            "",
+            "==== a.ts (1 errors) ====",
+            "    let x: number = 1;",
+            "    \tx = \"s\";",
+            "    \t~",
+            "!!! error TS2322: Type 'string' is not assignable to type 'number'.",
+            "    ",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn multi_line_errors_report_after_their_last_line() {
+    let files = [
+        source("a.ts", "foo(\n  1);\n"),
+        source("b.ts", "export {};"),
+    ];
+    let mut diag = error("a.ts", 0, 9, 1, 1);
+    diag.related.push(BaselineRelatedInfo {
+        location: Some(("b.ts".to_string(), 1, 1)),
+        code: 2728,
+        message: "Declared here.".to_string(),
+    });
+    let baseline = render_errors_baseline(&files, &[diag]).unwrap();
+    let expected = [
+        "==== a.ts (1 errors) ====",
+        "    foo(",
+        "    ~~~~",
+        "      1);",
+        "    ~~~~",
+        "!!! error TS2322: Type 'string' is not assignable to type 'number'.",
+        "!!! related TS2728 b.ts:1:1: Declared here.",
+        "    ",
+        "==== b.ts (0 errors) ====",
+        "    export {};",
+    ]
+    .join("\n");
+    assert!(baseline.ends_with(&expected), "{baseline}");
+}
+
+#[test]
+fn renders_types_and_symbols_under_their_lines() {
+    let entry = |text: &str, line: u32, ty: Option<&str>, symbol: Option<&str>| TypeWriterEntry {
+        source_text: text.to_string(),
+        line,
+        type_text: ty.map(str::to_string),
+        symbol_text: symbol.map(str::to_string),
+    };
+    let files = vec![(
+        source("a.ts", "const x = 1;\nx;"),
+        vec![
+            entry("x", 0, Some("1"), Some("Symbol(x, Decl(a.ts, 0, 5))")),
+            entry("1", 0, Some("1"), None),
+            entry("x", 1, Some("1"), Some("Symbol(x, Decl(a.ts, 0, 5))")),
+        ],
+    )];
+
+    assert_eq!(
+        render_type_baseline("tests/cases/compiler/a.ts", &files, TypeBaselineKind::Types),
+        "//// [tests/cases/compiler/a.ts] ////\n\n\
+         === a.ts ===\n\
+         const x = 1;\n\
+         >x : 1\n\
+         >1 : 1\n\
+         x;\n\
+         >x : 1\n"
+    );
+    assert_eq!(
+        render_type_baseline(
+            "tests/cases/compiler/a.ts",
+            &files,
+            TypeBaselineKind::Symbols
+        ),
+        "//// [tests/cases/compiler/a.ts] ////\n\n\
+         === a.ts ===\n\
+         const x = 1;\n\
+         >x : Symbol(x, Decl(a.ts, 0, 5))\n\
+         x;\n\
+         >x : Symbol(x, Decl(a.ts, 0, 5))\n"
+    );
+}
@@ -0,0 +1,171 @@
+use super::commands::{parse_commands, CompletionsExpectation, FourslashCommand};
+use super::parser::{offset_to_line_character, parse_fourslash_test};
+use super::{run_fourslash_test, FourslashOutcome};
+
+#[test]
+fn parses_files_markers_and_ranges() {
+    let test = parse_fourslash_test(
+        "/// <reference path='fourslash.ts' />\n\
+         // @strict: true\n\
+         // @Filename: a.ts\n\
+         ////export const /*def*/value = 1;\n\
+         // @Filename: b.ts\n\
+         ////import { [|value|] } from './a';\n\
+         ////{| \"name\": \"obj\" |}value/**/;\n\
+         verify.noErrors();\n",
+        "test.ts",
+    );
+
+    assert_eq!(test.options.get("strict").map(String::as_str), Some("true"));
+    assert_eq!(test.files.len(), 2);
+    assert_eq!(test.file_content("a.ts"), Some("export const value = 1;"));
+    assert_eq!(
+        test.file_content("b.ts"),
+        Some("import { value } from './a';\nvalue;")
+    );
+
+    let def = test.marker("def").expect("def marker");
+    assert_eq!((def.file.as_str(), def.offset), ("a.ts", 13));
+    let obj = test.marker("obj").expect("object marker");
+    assert_eq!((obj.file.as_str(), obj.offset), ("b.ts", 29));
+    let anonymous = test.marker("").expect("anonymous marker");
+    assert_eq!(anonymous.offset, 34);
+
+    assert_eq!(test.ranges.len(), 1);
+    assert_eq!((test.ranges[0].start, test.ranges[0].end), (9, 14));
+    assert!(test.script.contains("verify.noErrors();"));
+}
+
+#[test]
+fn keeps_regular_comments_in_file_content() {
+    let test = parse_fourslash_test("////let a = 1; /* not a marker */\n", "test.ts");
+    assert_eq!(
+        test.file_content("test.ts"),
+        Some("let a = 1; /* not a marker */")
+    );
+    assert!(test.markers.is_empty());
+}
+
+#[test]
+fn offset_to_line_character_uses_utf16_columns() {
+    let text = "a\n\u{1F600}b";
+    assert_eq!(offset_to_line_character(text, 0), (0, 0));
+    assert_eq!(offset_to_line_character(text, 2), (1, 0));
+    assert_eq!(offset_to_line_character(text, 6), (1, 2));
+}
+
+#[test]
+fn parses_quick_info_commands() {
+    let commands = parse_commands(
+        "goTo.marker('a');\n\
+         verify.quickInfoIs(\"const a: 1\")\n\
+         verify.quickInfoAt(\"b\", \"let b: number\", \"docs\");\n\
+         verify.quickInfos({ c: \"var c: string\", d: [\"var d: any\", \"more docs\"] });\n\
+         verify.not.quickInfoExists();\n",
+    );
+    assert_eq!(
+        commands,
+        vec![
+            FourslashCommand::GoToMarker("a".to_string()),
+            FourslashCommand::QuickInfoIs {
+                expected: "const a: 1".to_string(),
+                documentation: None,
+            },
+            FourslashCommand::QuickInfoAt {
+                marker: "b".to_string(),
+                expected: Some("let b: number".to_string()),
+                documentation: Some("docs".to_string()),
+            },
+            FourslashCommand::QuickInfoAt {
+                marker: "c".to_string(),
+                expected: Some("var c: string".to_string()),
+                documentation: None,
+            },
+            FourslashCommand::QuickInfoAt {
+                marker: "d".to_string(),
+                expected: Some("var d: any".to_string()),
+                documentation: Some("more docs".to_string()),
+            },
+            FourslashCommand::QuickInfoExists { negated: true },
+        ]
+    );
+}
+
+#[test]
+fn parses_completions_and_definitions() {
+    let commands = parse_commands(
+        "verify.completions({\n\
+             marker: [\"1\", \"2\"],\n\
+             includes: [\"foo\", { name: \"bar\", kind: \"method\" }],\n\
+             excludes: \"baz\",\n\
+             isNewIdentifierLocation: true,\n\
+         });\n\
+         verify.goToDefinition(\"use\", \"def\");\n\
+         verify.goToDefinition({ a: \"x\", b: [\"y\", \"z\"] });\n",
+    );
+    assert_eq!(
+        commands,
+        vec![
+            FourslashCommand::Completions(vec![CompletionsExpectation {
+                markers: vec!["1".to_string(), "2".to_string()],
+                includes: vec!["foo".to_string(), "bar".to_string()],
+                excludes: vec!["baz".to_string()],
+                exact: None,
+            }]),
+            FourslashCommand::GoToDefinition(vec![("use".to_string(), vec!["def".to_string()])]),
+            FourslashCommand::GoToDefinition(vec![
+                ("a".to_string(), vec!["x".to_string()]),
+                ("b".to_string(), vec!["y".to_string(), "z".to_string()]),
+            ]),
+        ]
+    );
+}
+
+#[test]
+fn unknown_statements_are_unsupported() {
+    let commands = parse_commands(
+        "const r = test.ranges();\n\
+         verify.completions({ marker: \"a\", preferences: { includeInsertTextCompletions: true } });\n\
+         verify.quickInfoAt(\"a\", someVariable);\n",
+    );
+    assert_eq!(commands.len(), 3);
+    assert!(commands
+        .iter()
+        .all(|command| matches!(command, FourslashCommand::Unsupported(_))));
+}
+
+#[test]
+fn tests_with_unsupported_commands_are_skipped() {
+    let outcome = run_fourslash_test(
+        "////const x = 1;\nverify.baselineFindAllReferences();\n",
+        "test.ts",
+    );
+    assert!(matches!(outcome, FourslashOutcome::Skipped(_)));
+
+    let outcome = run_fourslash_test("////const x = 1;\n", "test.ts");
+    assert_eq!(
+        outcome,
+        FourslashOutcome::Skipped("no commands".to_string())
+    );
+}
+
+#[test]
+fn runs_go_to_definition_and_no_errors() {
+    let outcome = run_fourslash_test(
+        "////const /*def*/value = 1;\n\
+         /////*use*/value;\n\
+         verify.goToDefinition(\"use\", \"def\");\n\
+         verify.noErrors();\n",
+        "test.ts",
+    );
+    assert_eq!(outcome, FourslashOutcome::Pass);
+}
+
+#[test]
+fn reports_unexpected_errors_as_failures() {
+    let outcome = run_fourslash_test(
+        "////const value: number = \"text\";\nverify.noErrors();\n",
+        "test.ts",
+    );
+    assert!(matches!(outcome, FourslashOutcome::Fail(failures) if failures.len() == 1));
+}
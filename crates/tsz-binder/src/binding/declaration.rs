@@ -101,16 +101,16 @@ impl BinderState {
     ) {
         if let Some(decl) = arena.get_variable_declaration(node) {
             let mut decl_flags = u32::from(node.flags);
-            if !node_flags::is_let_or_const(decl_flags)
+            if !node_flags::is_block_scoped(decl_flags)
                 && let Some(ext) = arena.get_extended(idx)
                 && let Some(parent_node) = arena.get(ext.parent)
                 && parent_node.kind == syntax_kind_ext::VARIABLE_DECLARATION_LIST
             {
                 decl_flags |= u32::from(parent_node.flags);
             }
-            let is_block_scoped = node_flags::is_let_or_const(decl_flags);
+            let is_block_scoped = node_flags::is_block_scoped(decl_flags);
             if let Some(name) = Self::get_identifier_name(arena, decl.name) {
-                // Determine if block-scoped (let/const) or function-scoped (var)
+                // Determine if block-scoped (let/const/using) or function-scoped (var)
                 let flags = if is_block_scoped {
                     symbol_flags::BLOCK_SCOPED_VARIABLE
                 } else {
@@ -660,6 +660,10 @@ impl BinderState {
         }
     }
 
+    /// Seed the current (non-arrow) function scope with the implicit
+    /// `arguments` object. The symbol has no declaration node; an explicit
+    /// parameter or `var arguments` merges into it. Arrow functions never call
+    /// this, so `arguments` inside them resolves through the enclosing scopes.
     pub(crate) fn declare_arguments_symbol(&mut self, arena: &NodeArena) {
         self.declare_symbol(
             arena,
@@ -26,4 +26,7 @@ pub use state::{
     FileReexports, FileReexportsMap, GlobalAugmentation, LibContext, ModuleAugmentation,
     ReexportTarget, SemanticDefEntry, SemanticDefKind, SymToDeclIndicesMap, ValidationError,
 };
-pub use symbols::{StableLocation, Symbol, SymbolArena, SymbolId, SymbolTable, symbol_flags};
+pub use symbols::{
+    StableLocation, StableSymbolId, Symbol, SymbolArena, SymbolId, SymbolTable,
+    internal_symbol_name, symbol_flags,
+};
@@ -5,7 +5,7 @@
 
 use crate::binding::SemanticDefDetails;
 use crate::state::BinderState;
-use crate::{ContainerKind, Symbol, SymbolId, SymbolTable, symbol_flags};
+use crate::{ContainerKind, Symbol, SymbolId, SymbolTable, internal_symbol_name, symbol_flags};
 use std::sync::Arc;
 use tsz_parser::parser::node::{Node, NodeArena};
 use tsz_parser::parser::syntax_kind_ext;
@@ -561,7 +561,10 @@ impl BinderState {
                                 )
                             {
                                 if assign.is_export_equals {
-                                    exported_symbols.push(("export=".to_string(), sym_id));
+                                    exported_symbols.push((
+                                        internal_symbol_name::EXPORT_EQUALS.to_string(),
+                                        sym_id,
+                                    ));
 
                                     // Also expose members of the export-assignment target for
                                     // named import compatibility (e.g. `export = alias; import { f }`).
@@ -607,8 +610,10 @@ impl BinderState {
                                              exported_symbols: &mut Vec<(String, SymbolId)>| {
                                                 if let Some(exports) = symbol.exports.as_ref() {
                                                     for (export_name, &export_sym_id) in exports.iter() {
-                                                        if export_name != "export="
-                                                            && export_name != "default"
+                                                        if export_name
+                                                            != internal_symbol_name::EXPORT_EQUALS
+                                                            && export_name
+                                                                != internal_symbol_name::DEFAULT
                                                         {
                                                             exported_symbols.push((
                                                                 export_name.clone(),
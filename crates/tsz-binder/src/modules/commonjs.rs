@@ -0,0 +1,118 @@
+//! CommonJS export binding for JS files.
+//!
+//! Records top-level `module.exports = ...`, `exports.foo = ...` and
+//! `module.exports.foo = ...` assignments in `BinderState::commonjs_exports`
+//! so consumers can read a JS module's export surface from binder facts
+//! instead of re-walking the file.
+
+use crate::internal_symbol_name;
+use crate::state::BinderState;
+use rustc_hash::FxHashMap;
+use std::sync::Arc;
+use tsz_parser::NodeIndex;
+use tsz_parser::parser::node::NodeArena;
+use tsz_parser::parser::syntax_kind_ext;
+use tsz_scanner::SyntaxKind;
+
+impl BinderState {
+    /// Record the CommonJS export assignments among `statements`.
+    ///
+    /// Only top-level expression statements are considered, matching how
+    /// `tsc` binds CommonJS exports. Chained assignments
+    /// (`exports.a = exports.b = value`) record every exported name. Names
+    /// declared at file level as `exports` / `module` shadow the CommonJS
+    /// bindings and are skipped.
+    pub(crate) fn bind_commonjs_exports(&mut self, arena: &NodeArena, statements: &[NodeIndex]) {
+        let exports_shadowed = self.current_scope.has("exports");
+        let module_shadowed = self.current_scope.has("module");
+        if exports_shadowed && module_shadowed {
+            return;
+        }
+
+        let mut recorded: FxHashMap<String, Vec<NodeIndex>> = FxHashMap::default();
+        for &stmt_idx in statements {
+            let Some(stmt) = arena.get(stmt_idx) else {
+                continue;
+            };
+            if stmt.kind != syntax_kind_ext::EXPRESSION_STATEMENT {
+                continue;
+            }
+            let Some(expr_stmt) = arena.get_expression_statement(stmt) else {
+                continue;
+            };
+
+            let mut expr_idx = expr_stmt.expression;
+            while let Some(expr) = arena.get(expr_idx)
+                && expr.kind == syntax_kind_ext::BINARY_EXPRESSION
+                && let Some(binary) = arena.get_binary_expr(expr)
+                && binary.operator_token == SyntaxKind::EqualsToken as u16
+            {
+                if let Some(name) =
+                    commonjs_export_name(arena, binary.left, exports_shadowed, module_shadowed)
+                {
+                    recorded.entry(name).or_default().push(expr_idx);
+                }
+                expr_idx = binary.right;
+            }
+        }
+
+        if !recorded.is_empty() {
+            self.commonjs_exports = Arc::new(recorded);
+        }
+    }
+}
+
+/// The export name assigned by `target`, if it is a CommonJS export target.
+///
+/// `module.exports` itself maps to `internal_symbol_name::EXPORT_EQUALS`.
+fn commonjs_export_name(
+    arena: &NodeArena,
+    target: NodeIndex,
+    exports_shadowed: bool,
+    module_shadowed: bool,
+) -> Option<String> {
+    let node = arena.get(target)?;
+    let access = arena.get_access_expr(node)?;
+    let name = if node.kind == syntax_kind_ext::PROPERTY_ACCESS_EXPRESSION {
+        arena
+            .get_identifier_at(access.name_or_argument)?
+            .escaped_text
+            .clone()
+    } else if node.kind == syntax_kind_ext::ELEMENT_ACCESS_EXPRESSION {
+        let arg = arena.get(access.name_or_argument)?;
+        if arg.kind != SyntaxKind::StringLiteral as u16
+            && arg.kind != SyntaxKind::NoSubstitutionTemplateLiteral as u16
+        {
+            return None;
+        }
+        arena.get_literal(arg)?.text.clone()
+    } else {
+        return None;
+    };
+
+    let base_is = |idx: NodeIndex, expected: &str| {
+        arena
+            .get_identifier_at(idx)
+            .is_some_and(|ident| ident.escaped_text == expected)
+    };
+
+    // `module.exports = ...`
+    if !module_shadowed && name == "exports" && base_is(access.expression, "module") {
+        return Some(internal_symbol_name::EXPORT_EQUALS.to_string());
+    }
+    // `exports.foo = ...`
+    if !exports_shadowed && base_is(access.expression, "exports") {
+        return Some(name);
+    }
+    // `module.exports.foo = ...`
+    if !module_shadowed
+        && let Some(base) = arena.get(access.expression)
+        && base.kind == syntax_kind_ext::PROPERTY_ACCESS_EXPRESSION
+        && let Some(base_access) = arena.get_access_expr(base)
+        && base_is(base_access.expression, "module")
+        && base_is(base_access.name_or_argument, "exports")
+    {
+        return Some(name);
+    }
+    None
+}
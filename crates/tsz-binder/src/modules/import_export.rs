@@ -214,7 +214,10 @@ impl BinderState {
                         sym.set_value_declaration(idx, span);
                     }
 
-                    // Track module for cross-file resolution and unresolved import detection
+                    // Track module for cross-file resolution and unresolved import detection.
+                    // `import_name` stays `None`: that is what marks a require alias
+                    // (see `Symbol::is_require_alias`), which resolves to the module's
+                    // `export =` entity rather than to a named export.
                     if let Some(ref specifier) = module_specifier {
                         // If multiple declarations have different specifiers, we might overwrite here.
                         // For valid merges (if any), this logic might need refinement,
@@ -299,7 +302,7 @@ impl BinderState {
         &mut self,
         arena: &NodeArena,
         node: &Node,
-        _idx: NodeIndex,
+        idx: NodeIndex,
     ) {
         if let Some(export) = arena.get_export_decl(node) {
             // Export clause can be:
@@ -720,6 +723,9 @@ impl BinderState {
                 else if let Some(name) = Self::get_identifier_name(arena, export.export_clause) {
                     let is_umd = export.module_specifier.is_none()
                         && node.kind == syntax_kind_ext::NAMESPACE_EXPORT_DECLARATION;
+                    // Like tsc, only a valid `export as namespace` declares the global;
+                    // the checker reports the others (TS1314/TS1315/TS1316).
+                    let declares_umd_global = is_umd && self.declares_umd_global(arena, idx);
                     let container_sym = self
                         .scope_chain
                         .get(self.current_scope_idx)
@@ -768,12 +774,12 @@ impl BinderState {
                     });
                     if let Some(type_alias_id) = existing_type_alias_id {
                         Arc::make_mut(&mut self.alias_partners).insert(type_alias_id, sym_id);
-                    } else if is_umd {
+                    } else if declares_umd_global {
                         self.current_scope.set(name.to_string(), sym_id);
                     }
                     Arc::make_mut(&mut self.node_symbols).insert(export.export_clause.0, sym_id);
 
-                    if is_umd {
+                    if declares_umd_global {
                         // UMD namespace exports register a global name.
                         // Add to file_locals + root scope so the name is visible cross-file.
                         self.file_locals.set(name.to_string(), sym_id);
@@ -782,7 +788,7 @@ impl BinderState {
                         {
                             root_scope.table.set(name.to_string(), sym_id);
                         }
-                    } else {
+                    } else if !is_umd {
                         // Regular namespace re-export — add to module exports
                         let current_file = self.debugger.current_file.clone();
                         Arc::make_mut(&mut self.module_exports)
@@ -822,6 +828,20 @@ impl BinderState {
         }
     }
 
+    /// Whether the `export as namespace` at `idx` declares a UMD global: it
+    /// must be at the top level of a module declaration file.
+    fn declares_umd_global(&self, arena: &NodeArena, idx: NodeIndex) -> bool {
+        self.is_external_module
+            && arena
+                .get_extended(idx)
+                .and_then(|ext| arena.get(ext.parent))
+                .is_some_and(|parent| parent.kind == syntax_kind_ext::SOURCE_FILE)
+            && arena
+                .source_files
+                .first()
+                .is_some_and(|sf| sf.is_declaration_file)
+    }
+
     /// Check if a node kind is a declaration that should be bound
     pub(crate) const fn is_declaration(kind: u16) -> bool {
         kind == syntax_kind_ext::FUNCTION_DECLARATION
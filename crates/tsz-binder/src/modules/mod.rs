@@ -2,9 +2,11 @@
 //!
 //! Groups all module-related binder logic:
 //! - `binding` — module/namespace declaration binding, augmentation, export population
+//! - `commonjs` — CommonJS `module.exports` / `exports.x` assignment recording in JS files
 //! - `import_export` — import/export declaration binding and symbol resolution
 //! - `resolution_debug` — debugging infrastructure for module resolution
 
 mod binding;
+mod commonjs;
 mod import_export;
 pub(crate) mod resolution_debug;
@@ -2,7 +2,7 @@
 
 use std::sync::Arc;
 
-use crate::{ContainerKind, SymbolId, flow_flags, symbol_flags};
+use crate::{ContainerKind, SymbolId, flow_flags, internal_symbol_name, symbol_flags};
 use tsz_parser::parser::node::{Node, NodeArena};
 use tsz_parser::parser::node_flags;
 use tsz_parser::parser::syntax_kind_ext;
@@ -438,8 +438,8 @@ impl BinderState {
         if let Some(node) = arena.get(decl_list_idx)
             && let Some(list) = arena.get_variable(node)
         {
-            // Check if this is a var declaration (not let/const)
-            let is_var = !node_flags::is_let_or_const(u32::from(node.flags));
+            // Check if this is a var declaration (not let/const/using)
+            let is_var = !node_flags::is_block_scoped(u32::from(node.flags));
             if is_var {
                 for &decl_idx in &list.declarations.nodes {
                     if let Some(decl) = arena.get_variable_declaration_at(decl_idx) {
@@ -754,33 +754,7 @@ impl BinderState {
                     if let Some(sym_id) =
                         self.resolve_export_assignment_target_symbol(arena, assign.expression)
                     {
-                        // Track the explicit `export =` target so require-import resolution
-                        // can recover the assigned symbol directly.
-                        self.file_locals.set("export=".to_string(), sym_id);
-
-                        // Copy the symbol's exports to the current module's exports.
-                        // This makes export = Namespace; work correctly.
-                        // Only add names that don't already exist in file_locals to
-                        // avoid shadowing global/ambient declarations (e.g., DOM types
-                        // like ClipboardEvent should not be shadowed by React.ClipboardEvent
-                        // when `export = React` appears inside `declare module "react"`).
-                        if let Some(symbol) = self.symbols.get(sym_id)
-                            && let Some(ref exports) = symbol.exports
-                        {
-                            for (export_name, &export_sym_id) in exports.iter() {
-                                // Skip "default" and "export=" — the `export =` target
-                                // itself IS the default export. Copying a static member
-                                // named `default` would shadow the `export=` symbol and
-                                // cause default-import resolution to pick up the member
-                                // (e.g. `static default: "foo"`) instead of the class.
-                                if export_name == "default" || export_name == "export=" {
-                                    continue;
-                                }
-                                if self.file_locals.get(export_name).is_none() {
-                                    self.file_locals.set(export_name.clone(), export_sym_id);
-                                }
-                            }
-                        }
+                        self.bind_export_equals_target(sym_id);
                     }
                 }
             }
@@ -1162,6 +1136,43 @@ impl BinderState {
         }
     }
 
+    /// Record `sym_id` as the module's `export =` entity.
+    ///
+    /// The `export=` entry of `file_locals` (and so of the module's exports
+    /// table) holds the assigned entity itself, so `import x = require()`
+    /// resolves to it directly. The entity's own exports are also exposed
+    /// at file level so named imports like `import { Component } from
+    /// 'react'` work. Only names that are not already declared are added,
+    /// to avoid shadowing global/ambient declarations (e.g., DOM types like
+    /// `ClipboardEvent` should not be shadowed by `React.ClipboardEvent`
+    /// when `export = React` appears inside `declare module "react"`).
+    pub(crate) fn bind_export_equals_target(&mut self, sym_id: SymbolId) {
+        self.file_locals
+            .set(internal_symbol_name::EXPORT_EQUALS.to_string(), sym_id);
+
+        let Some(exports) = self
+            .symbols
+            .get(sym_id)
+            .and_then(|symbol| symbol.exports.as_ref())
+        else {
+            return;
+        };
+        for (export_name, &export_sym_id) in exports.iter() {
+            // Skip `default` and `export=`: the `export =` target itself IS
+            // the default export. Copying a static member named `default`
+            // would shadow it and make default imports pick up the member
+            // (e.g. `static default: "foo"`) instead of the class.
+            if export_name == internal_symbol_name::DEFAULT
+                || export_name == internal_symbol_name::EXPORT_EQUALS
+            {
+                continue;
+            }
+            if !self.file_locals.has(export_name) {
+                self.file_locals.set(export_name.clone(), export_sym_id);
+            }
+        }
+    }
+
     pub(crate) fn resolve_export_assignment_target_symbol(
         &self,
         arena: &NodeArena,
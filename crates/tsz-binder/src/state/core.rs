@@ -7,7 +7,7 @@ use crate::lib_loader;
 use crate::modules::resolution_debug::ModuleResolutionDebugger;
 use crate::{
     ContainerKind, FlowNodeArena, FlowNodeId, Scope, ScopeContext, ScopeId, Symbol, SymbolArena,
-    SymbolId, SymbolTable, flow_flags, symbol_flags,
+    SymbolId, SymbolTable, flow_flags, internal_symbol_name, symbol_flags,
 };
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::sync::Arc;
@@ -123,6 +123,7 @@ impl BinderState {
             scope_stack: Vec::with_capacity(16),
             file_locals: SymbolTable::new(),
             expando_properties: Arc::new(FxHashMap::default()),
+            commonjs_exports: Arc::new(FxHashMap::default()),
             declared_modules: Arc::new(FxHashSet::default()),
             is_external_module: false,
             is_strict_scope: false,
@@ -191,6 +192,7 @@ impl BinderState {
         self.scope_stack.clear();
         self.file_locals.clear();
         Arc::make_mut(&mut self.expando_properties).clear();
+        self.commonjs_exports = Arc::new(FxHashMap::default());
         Arc::make_mut(&mut self.declared_modules).clear();
         self.is_external_module = false;
         self.is_strict_scope = false;
@@ -369,6 +371,7 @@ impl BinderState {
             scope_stack: Vec::new(),
             file_locals,
             expando_properties: Arc::new(FxHashMap::default()),
+            commonjs_exports: Arc::new(FxHashMap::default()),
             declared_modules: Arc::new(FxHashSet::default()),
             is_external_module: false,
             is_strict_scope: false,
@@ -494,6 +497,7 @@ impl BinderState {
             scope_stack: Vec::new(),
             file_locals,
             expando_properties,
+            commonjs_exports: Arc::new(FxHashMap::default()),
             declared_modules: Arc::new(FxHashSet::default()),
             is_external_module: false,
             is_strict_scope: false,
@@ -672,8 +676,9 @@ impl BinderState {
             match stmt.kind {
                 syntax_kind_ext::IMPORT_DECLARATION
                 | syntax_kind_ext::IMPORT_EQUALS_DECLARATION
+                // `export as namespace X` is not an indicator: on its own it
+                // leaves the file a script, where it is an error (TS1314).
                 | syntax_kind_ext::EXPORT_DECLARATION
-                | syntax_kind_ext::NAMESPACE_EXPORT_DECLARATION
                 | syntax_kind_ext::EXPORT_ASSIGNMENT => {
                     return true;
                 }
@@ -1128,6 +1133,12 @@ impl BinderState {
             // are bound now, so we can mark them as exported.
             self.resolve_deferred_named_exports(arena, &sf.statements.nodes);
 
+            // Record CommonJS `module.exports` / `exports.x` assignments so the
+            // JS export surface is available without re-walking the file.
+            if is_js_like_file_name(&sf.file_name) {
+                self.bind_commonjs_exports(arena, &sf.statements.nodes);
+            }
+
             // Populate module_exports for cross-file import resolution
             // This enables type-only import elision and proper import validation
             let file_name = sf.file_name.clone();
@@ -1253,7 +1264,7 @@ impl BinderState {
             if self.lib_symbol_ids.contains(&sym_id) {
                 continue;
             }
-            if name == "export=" {
+            if name == internal_symbol_name::EXPORT_EQUALS {
                 export_equals_target = Some(sym_id);
             }
             if let Some(symbol) = self.symbols.get(sym_id) {
@@ -1287,7 +1298,7 @@ impl BinderState {
                 // Also collect symbols that are explicitly exported via `export { X }`
                 // or `export` modifier. These may not be module/namespace symbols but
                 // need to be in module_exports for cross-file import resolution.
-                if symbol.is_exported || name == "export=" {
+                if symbol.is_exported || name == internal_symbol_name::EXPORT_EQUALS {
                     file_exports.set(name.clone(), sym_id);
                 }
             }
@@ -1299,7 +1310,9 @@ impl BinderState {
         {
             if let Some(target_exports) = target_symbol.exports.as_ref() {
                 for (export_name, &export_sym_id) in target_exports.iter() {
-                    if export_name != "default" && !file_exports.has(export_name) {
+                    if export_name != internal_symbol_name::DEFAULT
+                        && !file_exports.has(export_name)
+                    {
                         file_exports.set(export_name.clone(), export_sym_id);
                     }
                 }
@@ -1314,7 +1327,9 @@ impl BinderState {
             let target_is_class = (target_symbol.flags & symbol_flags::CLASS) != 0;
             if !target_is_class && let Some(target_members) = target_symbol.members.as_ref() {
                 for (member_name, &member_sym_id) in target_members.iter() {
-                    if member_name != "default" && !file_exports.has(member_name) {
+                    if member_name != internal_symbol_name::DEFAULT
+                        && !file_exports.has(member_name)
+                    {
                         file_exports.set(member_name.clone(), member_sym_id);
                     }
                 }
@@ -1336,7 +1351,7 @@ impl BinderState {
     /// `export = <Identifier>` statement and resolve it now.
     fn resolve_deferred_export_assignment(&mut self, arena: &NodeArena, statements: &[NodeIndex]) {
         // Fast path: already resolved during the main binding pass.
-        if self.file_locals.has("export=") {
+        if self.file_locals.has_export_equals() {
             return;
         }
 
@@ -1364,21 +1379,7 @@ impl BinderState {
                 continue;
             };
 
-            self.file_locals.set("export=".to_string(), sym_id);
-
-            // Also expose the namespace's own exports at file level so that
-            // named imports like `import { Component } from 'react'` work.
-            if let Some(symbol) = self.symbols.get(sym_id)
-                && let Some(ref exports) = symbol.exports.clone()
-            {
-                let entries: Vec<(String, SymbolId)> =
-                    exports.iter().map(|(k, &v)| (k.clone(), v)).collect();
-                for (export_name, export_sym_id) in entries {
-                    if self.file_locals.get(&export_name).is_none() {
-                        self.file_locals.set(export_name, export_sym_id);
-                    }
-                }
-            }
+            self.bind_export_equals_target(sym_id);
 
             break; // Only process the first `export =` statement.
         }
@@ -1542,7 +1543,7 @@ impl BinderState {
             targets
         };
 
-        let export_equals_sym_id = exports.get("export=")?;
+        let export_equals_sym_id = exports.export_equals()?;
 
         let export_equals_symbol = self.symbols.get(export_equals_sym_id)?;
 
@@ -1557,7 +1558,8 @@ impl BinderState {
         }
 
         let has_distinct_named_exports = exports.iter().any(|(name, _)| {
-            name != "export=" && !target_names.iter().any(|target| target == name)
+            name != internal_symbol_name::EXPORT_EQUALS
+                && !target_names.iter().any(|target| target == name)
         });
 
         let mut candidate_ids = Vec::new();
@@ -209,10 +209,7 @@ impl ExportSurface {
         // populated anywhere in the codebase, so this lookup also
         // unblocks the assertion that `from_binder()` reports a
         // truthful flag (rather than always returning `false`).
-        surface.has_export_equals = binder
-            .module_exports
-            .get(file_name)
-            .is_some_and(|exports| exports.has("export="));
+        surface.has_export_equals = binder.module_export_equals(file_name).is_some();
 
         // 7. Overload pre-scan + public-API scope detection from AST
         surface.scan_ast_structure(arena, root_idx);
@@ -287,6 +287,15 @@ pub struct BinderState {
     /// go through `Arc::make_mut` (free when refcount=1, the case during a
     /// single file's bind); read-only post-bind.
     pub expando_properties: Arc<FxHashMap<String, FxHashSet<String>>>,
+    /// CommonJS export assignments in a JS file: export name → the
+    /// assignment expressions that write it, in source order.
+    ///
+    /// `exports.foo = ...` and `module.exports.foo = ...` are recorded under
+    /// `foo`; direct `module.exports = ...` assignments are recorded under
+    /// `internal_symbol_name::EXPORT_EQUALS`, where the last entry is the one
+    /// in effect. Only populated for JS files. `Arc`-wrapped like
+    /// `expando_properties`; read-only post-bind.
+    pub commonjs_exports: Arc<FxHashMap<String, Vec<NodeIndex>>>,
     /// Ambient module declarations by specifier (e.g. "pkg", "./types").
     ///
     /// `Arc`-wrapped so per-file binders constructed by the CLI driver
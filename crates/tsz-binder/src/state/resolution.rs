@@ -4,7 +4,7 @@
 //! lookup, filtered name resolution, private identifier resolution, import
 //! resolution with re-export chain following, and scope discovery.
 
-use crate::{ContainerKind, ScopeId, SymbolId, symbol_flags};
+use crate::{ContainerKind, ScopeId, SymbolId, internal_symbol_name, symbol_flags};
 use rustc_hash::FxHashSet;
 use std::sync::Arc;
 use tracing::{Level, debug, span};
@@ -553,7 +553,10 @@ impl BinderState {
         // `import X = require("m")`), import_name is None. These resolve to the
         // module namespace, NOT to a specific named export. Only try `export=`.
         if sym.import_name.is_none() {
-            return self.resolve_import_with_reexports(module_specifier, "export=");
+            return self.resolve_import_with_reexports(
+                module_specifier,
+                internal_symbol_name::EXPORT_EQUALS,
+            );
         }
 
         // Determine the export name:
@@ -667,7 +670,7 @@ impl BinderState {
         }
         if export_name == "default"
             && let Some(module_table) = self.module_exports.get(module_specifier)
-            && let Some(sym_id) = module_table.get("export=")
+            && let Some(sym_id) = module_table.export_equals()
         {
             let sym_is_type_only = if let Some(sym) = self.symbols.get(sym_id) {
                 is_type_only || sym.is_type_only
@@ -749,6 +752,100 @@ impl BinderState {
         self.resolve_import_if_needed(sym_id)
     }
 
+    /// The entity `module_specifier` assigns with `export =`, if any.
+    #[must_use]
+    pub fn module_export_equals(&self, module_specifier: &str) -> Option<SymbolId> {
+        self.module_exports.get(module_specifier)?.export_equals()
+    }
+
+    /// Resolve the alias of `import x = require("m")` to the `export =`
+    /// entity of `"m"`. When that entity is itself a require alias (`import
+    /// y = require("n"); export = y;`), the chain is followed to its end.
+    ///
+    /// Returns `None` when `sym_id` is not a require alias or its module has
+    /// no `export =`; the alias then stands for the module namespace.
+    #[must_use]
+    pub fn resolve_require_alias(&self, sym_id: SymbolId) -> Option<SymbolId> {
+        let module_of = |id: SymbolId| {
+            self.symbols
+                .get(id)
+                .filter(|symbol| symbol.is_require_alias())
+                .and_then(|symbol| symbol.import_module.as_deref())
+        };
+
+        let mut visited = FxHashSet::default();
+        visited.insert(sym_id);
+        let mut target = self.module_export_equals(module_of(sym_id)?)?;
+        while let Some(module_specifier) = module_of(target) {
+            if !visited.insert(target) {
+                return None;
+            }
+            let Some(next) = self.module_export_equals(module_specifier) else {
+                break;
+            };
+            target = next;
+        }
+        Some(target)
+    }
+
+    // =========================================================================
+    // globalThis Members
+    // =========================================================================
+
+    /// Whether `sym_id` is a property of `typeof globalThis`.
+    ///
+    /// Mirrors tsc's synthetic `globalThis` symbol: its members are the global
+    /// value declarations minus block-scoped ones. Script-level `var`s,
+    /// functions and namespaces are members; `let`/`const`/`using`, classes
+    /// and enums are not. Top-level declarations of an external module are
+    /// module-local, so only its `declare global { ... }` augmentations reach
+    /// `globalThis`. `arena` is the arena this binder's file was parsed into.
+    #[must_use]
+    pub fn is_global_this_member(&self, arena: &NodeArena, sym_id: SymbolId) -> bool {
+        let Some(symbol) = self.get_symbol(sym_id) else {
+            return false;
+        };
+        if !symbol.has_any_flags(symbol_flags::VALUE)
+            || (symbol.has_any_flags(symbol_flags::BLOCK_SCOPED)
+                && !symbol.has_any_flags(symbol_flags::FUNCTION_SCOPED_VARIABLE))
+        {
+            return false;
+        }
+        if !self.is_external_module {
+            return true;
+        }
+
+        let declared_in_module = self
+            .scopes
+            .first()
+            .filter(|root| root.kind == ContainerKind::SourceFile)
+            .is_some_and(|root| root.table.get(&symbol.escaped_name) == Some(sym_id));
+        !declared_in_module
+            || symbol
+                .declarations
+                .iter()
+                .any(|&decl_idx| Self::is_inside_global_augmentation(arena, decl_idx))
+    }
+
+    fn is_inside_global_augmentation(arena: &NodeArena, node_idx: NodeIndex) -> bool {
+        use tsz_parser::parser::syntax_kind_ext;
+
+        let mut current = node_idx;
+        for _ in 0..MAX_SCOPE_WALK_ITERATIONS {
+            let Some(ext) = arena.get_extended(current) else {
+                return false;
+            };
+            current = ext.parent;
+            let Some(node) = arena.get(current) else {
+                return false;
+            };
+            if node.kind == syntax_kind_ext::MODULE_DECLARATION && node.is_global_augmentation() {
+                return true;
+            }
+        }
+        false
+    }
+
     // =========================================================================
     // Scope Discovery
     // =========================================================================
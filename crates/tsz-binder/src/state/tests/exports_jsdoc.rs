@@ -696,6 +696,87 @@ declare module "renameModule" {
     assert!(exports.has("c"), "expected export= target member c");
 }
 
+#[test]
+fn require_alias_resolves_through_export_equals_chain() {
+    let source = r#"
+declare class Target {}
+import inner = require("inner");
+import outer = require("test.ts");
+import named = require("missing");
+import { other } from "inner";
+export = inner;
+"#;
+    let (mut binder, _parser) = parse_and_bind(source);
+    let local = |binder: &BinderState, name: &str| {
+        binder
+            .file_locals
+            .get(name)
+            .unwrap_or_else(|| panic!("expected symbol for {name}"))
+    };
+    let (target, inner, outer) = (
+        local(&binder, "Target"),
+        local(&binder, "inner"),
+        local(&binder, "outer"),
+    );
+    let mut inner_exports = SymbolTable::new();
+    inner_exports.set("export=".to_string(), target);
+    Arc::make_mut(&mut binder.module_exports).insert("inner".to_string(), inner_exports);
+
+    assert_eq!(binder.module_export_equals("test.ts"), Some(inner));
+    assert!(binder.symbols.get(outer).unwrap().is_require_alias());
+    assert!(
+        !binder
+            .symbols
+            .get(local(&binder, "other"))
+            .unwrap()
+            .is_require_alias()
+    );
+
+    // `outer` -> `export = inner` of test.ts -> `export = Target` of "inner".
+    assert_eq!(binder.resolve_require_alias(outer), Some(target));
+    assert_eq!(binder.resolve_require_alias(inner), Some(target));
+    // A module without `export =` and a named import alias do not resolve.
+    assert_eq!(binder.resolve_require_alias(local(&binder, "named")), None);
+    assert_eq!(binder.resolve_require_alias(local(&binder, "other")), None);
+}
+
+#[test]
+fn require_alias_of_self_exporting_module_does_not_loop() {
+    let (binder, _parser) = parse_and_bind(
+        r#"
+import me = require("test.ts");
+export = me;
+"#,
+    );
+    let me = binder.file_locals.get("me").expect("expected alias me");
+    assert_eq!(binder.module_export_equals("test.ts"), Some(me));
+    assert_eq!(binder.resolve_require_alias(me), None);
+}
+
+#[test]
+fn deferred_export_equals_static_default_not_in_file_locals() {
+    // Same as `export_equals_class_static_default_not_in_file_locals`, but
+    // with `export =` before the class it names, which may only resolve
+    // once all statements are bound.
+    let source = r#"
+export = Point;
+declare class Point {
+    static default: "foo";
+}
+"#;
+    let mut parser = ParserState::new("point.d.ts".to_string(), source.to_string());
+    let root = parser.parse_source_file();
+
+    let mut binder = BinderState::new();
+    binder.bind_source_file(parser.get_arena(), root);
+
+    assert!(binder.file_locals.has_export_equals());
+    assert!(
+        !binder.file_locals.has("default"),
+        "static member named 'default' must not leak into file_locals from export= target"
+    );
+}
+
 #[test]
 fn iife_no_flow_start_node() {
     // For a non-async, non-generator IIFE, the binder should NOT create a
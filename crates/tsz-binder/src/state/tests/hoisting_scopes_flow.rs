@@ -113,6 +113,37 @@ function foo() {
     );
 }
 
+#[test]
+fn using_not_hoisted_across_blocks() {
+    // `using` declarations are lexical like `let`/`const`; they must not be
+    // hoisted to the enclosing function scope as `var`s.
+    let (binder, _parser) = parse_and_bind(
+        r"
+{
+    using res = null;
+}
+",
+    );
+
+    assert!(
+        !binder.file_locals.has("res"),
+        "using res should not be hoisted to file_locals"
+    );
+    let res_sym = binder
+        .symbols
+        .find_by_name("res")
+        .expect("expected symbol for res");
+    let res_symbol = binder.symbols.get(res_sym).expect("expected symbol data");
+    assert!(
+        res_symbol.flags & symbol_flags::BLOCK_SCOPED_VARIABLE != 0,
+        "using res should have BLOCK_SCOPED_VARIABLE flag"
+    );
+    assert!(
+        res_symbol.flags & symbol_flags::FUNCTION_SCOPED_VARIABLE == 0,
+        "using res should NOT have FUNCTION_SCOPED_VARIABLE flag"
+    );
+}
+
 #[test]
 fn function_declaration_hoisted_to_containing_scope() {
     // Function declarations at the top level should be hoisted and visible
@@ -385,6 +385,50 @@ function publish() {
     );
 }
 
+#[test]
+fn commonjs_export_assignments_are_recorded_for_js_files() {
+    let source = r#"
+module.exports = function () {};
+exports.a = exports.b = 1;
+module.exports.c = 2;
+exports["d"] = 3;
+module.exports = { e: 4 };
+function nested() {
+    exports.f = 5;
+}
+"#;
+    let mut parser = ParserState::new("test.js".to_string(), source.to_string());
+    let root = parser.parse_source_file();
+    let mut binder = BinderState::new();
+    binder.bind_source_file(parser.get_arena(), root);
+
+    let mut names: Vec<&str> = binder.commonjs_exports.keys().map(String::as_str).collect();
+    names.sort_unstable();
+    assert_eq!(names, ["a", "b", "c", "d", "export="]);
+    assert_eq!(
+        binder.commonjs_exports["export="].len(),
+        2,
+        "every direct module.exports assignment should be recorded in source order"
+    );
+}
+
+#[test]
+fn commonjs_export_assignments_skip_ts_files_and_shadowed_exports() {
+    let (binder, _parser) = parse_and_bind("declare const exports: any;\nexports.a = 1;");
+    assert!(binder.commonjs_exports.is_empty());
+
+    let mut parser = ParserState::new(
+        "test.js".to_string(),
+        "var exports = {};\nexports.a = 1;\nmodule.exports.b = 2;".to_string(),
+    );
+    let root = parser.parse_source_file();
+    let mut binder = BinderState::new();
+    binder.bind_source_file(parser.get_arena(), root);
+
+    assert!(!binder.commonjs_exports.contains_key("a"));
+    assert!(binder.commonjs_exports.contains_key("b"));
+}
+
 // =============================================================================
 // 7. FLOW GRAPH ADVANCED PATTERNS
 // =============================================================================
@@ -970,3 +1014,35 @@ fn symbol_has_flags_checks() {
 // =============================================================================
 // 18. EXPORT RESOLUTION TESTS
 // =============================================================================
+
+#[test]
+fn global_this_members_exclude_block_scoped_and_module_locals() {
+    let (binder, parser) =
+        parse_and_bind("var v = 1;\nlet l = 1;\nfunction f() {}\nclass C {}\nenum E { A }");
+    let member = |name: &str| {
+        let sym_id = binder
+            .file_locals
+            .get(name)
+            .expect("symbol should be bound");
+        binder.is_global_this_member(parser.get_arena(), sym_id)
+    };
+    assert!(member("v"));
+    assert!(member("f"));
+    assert!(!member("l"));
+    assert!(!member("C"));
+    assert!(!member("E"));
+
+    let (binder, parser) = parse_and_bind(
+        "export {};\nvar local = 1;\ndeclare global { var shared: number; function sharedFn(): void; }",
+    );
+    let member = |name: &str| {
+        let sym_id = binder
+            .file_locals
+            .get(name)
+            .expect("symbol should be bound");
+        binder.is_global_this_member(parser.get_arena(), sym_id)
+    };
+    assert!(!member("local"));
+    assert!(member("shared"));
+    assert!(member("sharedFn"));
+}
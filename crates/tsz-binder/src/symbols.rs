@@ -51,6 +51,7 @@ pub mod symbol_flags {
     // Composite flags
     pub const ENUM: u32 = REGULAR_ENUM | CONST_ENUM;
     pub const VARIABLE: u32 = FUNCTION_SCOPED_VARIABLE | BLOCK_SCOPED_VARIABLE;
+    pub const BLOCK_SCOPED: u32 = BLOCK_SCOPED_VARIABLE | CLASS | ENUM;
     pub const VALUE: u32 = VARIABLE
         | PROPERTY
         | ENUM_MEMBER
@@ -98,6 +99,17 @@ pub mod symbol_flags {
     pub const ALIAS_EXCLUDES: u32 = ALIAS;
 }
 
+/// Names of symbols that do not come from an identifier in the source.
+/// Matches TypeScript's `InternalSymbolName` enum in src/compiler/types.ts
+pub mod internal_symbol_name {
+    /// The `export =` assignment of a module. Its entry in a module's
+    /// exports table holds the assigned entity, so `import x = require()`
+    /// and default imports of CommonJS-style modules resolve to it.
+    pub const EXPORT_EQUALS: &str = "export=";
+    /// The `export default` declaration of a module.
+    pub const DEFAULT: &str = "default";
+}
+
 // =============================================================================
 // Stable Location
 // =============================================================================
@@ -215,6 +227,39 @@ impl Default for StableLocation {
     }
 }
 
+/// Content-stable identity of a symbol across program builds.
+///
+/// Program-wide `SymbolId`s are assigned in merge order, so adding, removing
+/// or reordering files shifts every later ID. A `StableSymbolId` instead
+/// pairs the content hash of the declaring file with the symbol's index in
+/// that file's own binder. Binding is deterministic, so the pair is the same
+/// on every run that sees the same file content, regardless of which other
+/// files are in the program. Caches and serialized state keyed by
+/// `StableSymbolId` stay valid across incremental runs; the merged program
+/// maps them back to the current run's `SymbolId`s.
+///
+/// Symbols merged across files (e.g. interface or namespace merging) take the
+/// smallest contributing identity, which is independent of merge order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct StableSymbolId {
+    /// `state::snapshot::content_hash(file_name, source_text)` of the
+    /// declaring file.
+    pub file_hash: u64,
+    /// The symbol's `SymbolId` in the declaring file's binder.
+    pub local_index: u32,
+}
+
+impl StableSymbolId {
+    #[inline]
+    #[must_use]
+    pub const fn new(file_hash: u64, local_index: u32) -> Self {
+        Self {
+            file_hash,
+            local_index,
+        }
+    }
+}
+
 // =============================================================================
 // Symbol
 // =============================================================================
@@ -336,6 +381,16 @@ impl Symbol {
         (self.flags & flags) != 0
     }
 
+    /// Whether this is the alias of `import x = require("m")`: the only
+    /// import alias with a module specifier and no imported name. It
+    /// stands for the module's `export =` entity, or for the module itself.
+    #[must_use]
+    pub const fn is_require_alias(&self) -> bool {
+        self.has_any_flags(symbol_flags::ALIAS)
+            && self.import_module.is_some()
+            && self.import_name.is_none()
+    }
+
     /// Whether a top-level declaration of this symbol is visible in the
     /// cross-file global scope.
     ///
@@ -509,6 +564,19 @@ impl SymbolTable {
         self.symbols.contains_key(name)
     }
 
+    /// The entity assigned with `export =`, when this is a module's exports
+    /// table.
+    #[must_use]
+    pub fn export_equals(&self) -> Option<SymbolId> {
+        self.get(internal_symbol_name::EXPORT_EQUALS)
+    }
+
+    /// Whether this module's exports table has an `export =` assignment.
+    #[must_use]
+    pub fn has_export_equals(&self) -> bool {
+        self.has(internal_symbol_name::EXPORT_EQUALS)
+    }
+
     /// Get number of symbols.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -158,6 +158,7 @@ impl<'a> CheckerContext<'a> {
             all_parse_error_positions: Vec::new(),
             nullable_type_parse_error_positions: Vec::new(),
             diagnostics: Vec::new(),
+            suggestion_diagnostics: Vec::new(),
             diagnostic_indices: DiagnosticIndices::default(),
             no_overload_call_nodes: FxHashSet::default(),
             callback_return_type_errors: Vec::new(),
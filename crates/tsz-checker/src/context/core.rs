@@ -1477,6 +1477,29 @@ impl<'a> CheckerContext<'a> {
         self.rebuild_diagnostic_aux_indices();
     }
 
+    /// Add a suggestion diagnostic (TS80xxx) to `suggestion_diagnostics`.
+    ///
+    /// No-op unless `report_suggestions` is enabled. The message template and
+    /// category come from the diagnostic table. Deduplicated by (start, code),
+    /// since speculative re-checks can visit the same node more than once.
+    pub fn suggestion(&mut self, start: u32, length: u32, code: u32, args: &[&str]) {
+        if !self.compiler_options.report_suggestions
+            || self
+                .suggestion_diagnostics
+                .iter()
+                .any(|diag| diag.start == start && diag.code == code)
+        {
+            return;
+        }
+        self.suggestion_diagnostics.push(Diagnostic::from_code(
+            code,
+            self.file_name.clone(),
+            start,
+            length,
+            args,
+        ));
+    }
+
     /// Add an error diagnostic (with deduplication).
     /// Diagnostics with the same (start, code) are only emitted once.
     /// Exceptions:
@@ -1773,14 +1796,14 @@ impl<'a> CheckerContext<'a> {
     }
 
     /// Consume one unit of type resolution fuel.
-    /// Returns true if fuel is still available, false if exhausted.
-    /// When exhausted, type resolution should return ERROR to prevent timeout.
+    /// Returns true if fuel is still available, false if exhausted (or the
+    /// request was cancelled). Callers then return ERROR to prevent timeout.
     /// Also tracks a thread-local global fuel counter that is NOT reset when
     /// child contexts are created for cross-arena delegation, preventing
     /// unbounded total work across multiple contexts.
     pub fn consume_fuel(&self) -> bool {
         let fuel = self.type_resolution_fuel.get();
-        if fuel == 0 {
+        if fuel == 0 || self.eval_session.is_cancelled() {
             return false;
         }
         self.type_resolution_fuel.set(fuel - 1);
@@ -1307,6 +1307,7 @@ impl<'a> CheckerContext<'a> {
     ) -> crate::query_boundaries::common::TypeFormatter<'_> {
         self.create_type_formatter()
             .with_diagnostic_mode()
+            .with_no_truncation(self.compiler_options.no_error_truncation)
             .with_strict_null_checks(self.compiler_options.strict_null_checks)
             .with_builtin_iterator_return_type(
                 if self.compiler_options.strict_builtin_iterator_return {
@@ -99,6 +99,7 @@ impl<'a> CheckerContext<'a> {
 
         // Diagnostic buffers.
         self.diagnostics.clear();
+        self.suggestion_diagnostics.clear();
         self.diagnostic_indices.clear();
         self.callback_return_type_errors.clear();
         self.modules_with_ts2307_emitted.clear();
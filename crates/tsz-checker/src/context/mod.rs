@@ -774,6 +774,10 @@ pub struct CheckerContext<'a> {
     /// Excludes `!T` / `T!` errors which should not trigger widening.
     pub nullable_type_parse_error_positions: Vec<u32>,
     pub diagnostics: Vec<Diagnostic>,
+    /// Suggestion-category diagnostics (TS80xxx), kept apart from
+    /// `diagnostics` so they never count as errors or go through error
+    /// post-processing. Only populated when `report_suggestions` is set.
+    pub suggestion_diagnostics: Vec<Diagnostic>,
     pub(crate) diagnostic_indices: DiagnosticIndices,
     /// Call-expression nodes that resolved to TS2769 during the current
     /// speculative context. Used so overload resolution can reject outer
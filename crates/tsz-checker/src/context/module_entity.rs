@@ -209,7 +209,7 @@ impl<'a> CheckerContext<'a> {
         let export_equals_is_non_module = |binder: &BinderState,
                                            exports: &tsz_binder::SymbolTable|
          -> Option<bool> {
-            let export_equals_sym_id = exports.get("export=")?;
+            let export_equals_sym_id = exports.export_equals()?;
             let has_named_exports = exports.iter().any(|(name, _)| name != "export=");
             tracing::trace!(
                 module_specifier = module_specifier,
@@ -1098,7 +1098,7 @@ impl<'a, 'ctx> DeclarationChecker<'a, 'ctx> {
         }
 
         if export_name == "default"
-            && let Some(sym_id) = binder.file_locals.get("export=")
+            && let Some(sym_id) = binder.file_locals.export_equals()
         {
             return Some((sym_id, file_idx));
         }
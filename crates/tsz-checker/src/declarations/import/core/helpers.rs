@@ -366,7 +366,7 @@ impl<'a> CheckerState<'a> {
         &self,
         exports_table: &tsz_binder::SymbolTable,
     ) -> bool {
-        let Some(export_equals_sym) = exports_table.get("export=") else {
+        let Some(export_equals_sym) = exports_table.export_equals() else {
             return false;
         };
 
@@ -535,7 +535,7 @@ impl<'a> CheckerState<'a> {
                     .is_some_and(|members| members.has(member_name))
         };
 
-        let Some(export_equals_sym) = exports_table.get("export=") else {
+        let Some(export_equals_sym) = exports_table.export_equals() else {
             return false;
         };
         if import_name == "default" {
@@ -958,7 +958,8 @@ impl<'a> CheckerState<'a> {
 
                 // For `export =` modules, the export target is in file_locals but
                 // accessible via the `export =` binding. Don't filter these out.
-                let has_export_equals = exports_table.is_some_and(|exports| exports.has("export="));
+                let has_export_equals =
+                    exports_table.is_some_and(|exports| exports.has_export_equals());
 
                 if !in_module_exports && !has_export_equals {
                     return false;
@@ -981,7 +982,7 @@ impl<'a> CheckerState<'a> {
     ) -> bool {
         use crate::query_boundaries::common::PropertyAccessResult;
 
-        let Some(export_equals_sym) = exports_table.get("export=") else {
+        let Some(export_equals_sym) = exports_table.export_equals() else {
             return false;
         };
         if import_name == "default" {
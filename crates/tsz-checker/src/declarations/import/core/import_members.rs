@@ -243,7 +243,7 @@ impl<'a> CheckerState<'a> {
             || self.module_has_default_binding_fast_path(module_name, resolution_mode)
             || exports_table.as_ref().is_some_and(|table| {
                 table.has("default")
-                    || table.has("export=")
+                    || table.has_export_equals()
                     || (has_module_exports_binding && table.has("module.exports"))
             });
         let resolved_target_has_js_esm_syntax = resolved_target
@@ -266,7 +266,7 @@ impl<'a> CheckerState<'a> {
         if (has_namespace_import || has_non_default_named_imports)
             && !clause.is_type_only
             && let Some(ref table) = exports_table
-            && table.has("export=")
+            && table.has_export_equals()
             && self.export_equals_target_is_not_module_or_variable(table)
             && !named_imports_resolve_via_export_equals_target
         {
@@ -719,7 +719,7 @@ impl<'a> CheckerState<'a> {
                         // TS2616/TS2595/TS2597 path earlier in this function
                         // already reports the import-style mismatch. Skip the
                         // duplicate "declares 'X' locally" TS2459/TS2460.
-                        let module_uses_export_equals = exports_table.has("export=");
+                        let module_uses_export_equals = exports_table.has_export_equals();
                         let suppress_for_export_equals =
                             exists_locally && module_uses_export_equals;
                         if exists_locally && !suppress_for_export_equals {
@@ -753,7 +753,7 @@ impl<'a> CheckerState<'a> {
                         } else if json_default_only
                             || has_module_exports_binding
                             || exports_table.has("default")
-                            || exports_table.has("export=")
+                            || exports_table.has_export_equals()
                         {
                             if json_default_only
                                 && self.ctx.compiler_options.module.is_node_module()
@@ -771,7 +771,7 @@ impl<'a> CheckerState<'a> {
                                 continue;
                             }
 
-                            let found_via_type = exports_table.has("export=")
+                            let found_via_type = exports_table.has_export_equals()
                                 && self.has_named_export_via_export_equals_type(
                                     &exports_table,
                                     import_name,
@@ -797,8 +797,12 @@ impl<'a> CheckerState<'a> {
                                     &message,
                                     diagnostic_codes::HAS_NO_EXPORTED_MEMBER_NAMED_DID_YOU_MEAN,
                                 );
+                                self.attach_spelling_suggestion(
+                                    diagnostic_codes::HAS_NO_EXPORTED_MEMBER_NAMED_DID_YOU_MEAN,
+                                    suggestion,
+                                );
                             } else {
-                                let has_export_equals = exports_table.has("export=");
+                                let has_export_equals = exports_table.has_export_equals();
                                 let has_interop = self.ctx.compiler_options.es_module_interop
                                     || self.ctx.compiler_options.allow_synthetic_default_imports;
                                 let suppress_for_interop = has_export_equals && has_interop;
@@ -835,6 +839,10 @@ impl<'a> CheckerState<'a> {
                                     &message,
                                     diagnostic_codes::HAS_NO_EXPORTED_MEMBER_NAMED_DID_YOU_MEAN,
                                 );
+                                self.attach_spelling_suggestion(
+                                    diagnostic_codes::HAS_NO_EXPORTED_MEMBER_NAMED_DID_YOU_MEAN,
+                                    suggestion,
+                                );
                             } else {
                                 let message = format_message(
                                     diagnostic_messages::MODULE_HAS_NO_EXPORTED_MEMBER,
@@ -1437,6 +1445,20 @@ impl<'a> CheckerState<'a> {
                 return true;
             }
         }
+        // `import x = require("m"); export default x;` stands for the `export =`
+        // entity of "m", which the binder resolves without walking the alias chain.
+        if sym.is_require_alias()
+            && let Some(target) = owner_binder
+                .resolve_require_alias(sym_id)
+                .and_then(|target_id| {
+                    owner_binder
+                        .get_symbol(target_id)
+                        .or_else(|| self.ctx.binder.get_symbol(target_id))
+                })
+        {
+            return target.is_type_only
+                || (target.has_any_flags(PURE_TYPE) && !target.has_any_flags(VALUE));
+        }
         if sym.has_any_flags(symbol_flags::ALIAS) {
             let mut visited_aliases = AliasCycleTracker::new();
             if let Some(resolved_sym_id) = self.resolve_alias_symbol(sym_id, &mut visited_aliases) {
@@ -1791,17 +1813,10 @@ impl<'a> CheckerState<'a> {
         // In that case it IS exported (just not as a named export), so don't report
         // it as "locally declared but not exported" (TS2459). Let the caller fall
         // through to the appropriate `export =` diagnostic (TS2616/TS2595/TS2597).
-        let has_export_equals = module_keys.iter().any(|key| {
-            binder
-                .module_exports
-                .get(*key)
-                .is_some_and(|exports| exports.has("export="))
-        }) || file_name.is_some_and(|fname| {
-            binder
-                .module_exports
-                .get(fname)
-                .is_some_and(|exports| exports.has("export="))
-        });
+        let has_export_equals = module_keys
+            .iter()
+            .any(|key| binder.module_export_equals(key).is_some())
+            || file_name.is_some_and(|fname| binder.module_export_equals(fname).is_some());
         if has_export_equals {
             return None;
         }
@@ -286,7 +286,7 @@ impl<'a> CheckerState<'a> {
         // EXPORT_ASSIGNMENT nodes (covers non-identifier forms like `export = {}`).
         let has_export_equals = self
             .resolve_effective_module_exports(&module_name)
-            .is_some_and(|exports| exports.has("export="))
+            .is_some_and(|exports| exports.has_export_equals())
             || self.target_file_has_export_assignment(&module_name);
 
         if has_export_equals {
@@ -570,7 +570,7 @@ impl<'a> CheckerState<'a> {
             };
 
             if export_name == "default"
-                && module_exports.has("export=")
+                && module_exports.has_export_equals()
                 && self.ctx.allow_synthetic_default_imports()
             {
                 continue;
@@ -583,7 +583,7 @@ impl<'a> CheckerState<'a> {
             if export_name != "*" && !module_exports.has(&export_name) {
                 let has_default_like_export = has_json_default_export
                     || module_exports.has("default")
-                    || module_exports.has("export=")
+                    || module_exports.has_export_equals()
                     || module_exports.has("module.exports");
                 if module_exports.has("module.exports") && has_default_like_export {
                     let message = format_message(
@@ -617,6 +617,10 @@ impl<'a> CheckerState<'a> {
                         &message,
                         diagnostic_codes::HAS_NO_EXPORTED_MEMBER_NAMED_DID_YOU_MEAN,
                     );
+                    self.attach_spelling_suggestion(
+                        diagnostic_codes::HAS_NO_EXPORTED_MEMBER_NAMED_DID_YOU_MEAN,
+                        suggestion,
+                    );
                 } else if has_default_like_export {
                     // TS2614: Symbol doesn't exist but a default export does
                     let message = format_message(
@@ -710,7 +714,7 @@ impl<'a> CheckerState<'a> {
             // Get export= type if this is a CommonJS module.
             // Also check for `export { X as "module.exports" }` which acts like export=.
             let export_equals_type = exports_table
-                .get("export=")
+                .export_equals()
                 .or_else(|| exports_table.get("module.exports"))
                 .map(|export_equals_sym| self.get_type_of_symbol(export_equals_sym));
             let ordered_exports = self.ordered_namespace_export_entries(&exports_table);
@@ -1511,7 +1515,7 @@ impl<'a> CheckerState<'a> {
                                     current_file_idx = target_idx;
                                     current_sym_id = target_sym_id;
                                     found = true;
-                                } else if let Some(target_sym_id) = exports.get("export=") {
+                                } else if let Some(target_sym_id) = exports.export_equals() {
                                     current_binder = target_binder;
                                     current_file_idx = target_idx;
                                     current_sym_id = target_sym_id;
@@ -1540,7 +1544,7 @@ impl<'a> CheckerState<'a> {
                     }
                     if !found
                         && let Some(exports) = current_binder.module_exports.get(module_name)
-                        && let Some(target_sym_id) = exports.get("export=")
+                        && let Some(target_sym_id) = exports.export_equals()
                     {
                         current_sym_id = target_sym_id;
                         found = true;
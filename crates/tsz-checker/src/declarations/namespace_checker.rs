@@ -642,7 +642,7 @@ impl<'a> CheckerState<'a> {
                 return Some(sym_id);
             }
 
-            let export_equals_sym_id = module_exports.get("export=")?;
+            let export_equals_sym_id = module_exports.export_equals()?;
             let export_equals_symbol = binder.get_symbol(export_equals_sym_id)?;
 
             if let Some(exports) = export_equals_symbol.exports.as_ref()
@@ -380,6 +380,7 @@ impl<'a> CheckerState<'a> {
             tsz_solver::TypeFormatter::with_symbols(self.ctx.types, &self.ctx.binder.symbols)
                 .with_def_store(&self.ctx.definition_store)
                 .with_diagnostic_mode()
+                .with_no_truncation(self.ctx.compiler_options.no_error_truncation)
                 .with_preserve_optional_parameter_surface_syntax(true)
                 .with_strict_null_checks(self.ctx.compiler_options.strict_null_checks)
                 .with_exact_optional_property_types(
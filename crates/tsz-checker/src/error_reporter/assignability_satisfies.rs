@@ -51,7 +51,8 @@ impl<'a> CheckerState<'a> {
                     self.ctx.file_name.as_str(),
                 )
                 .with_def_store(&self.ctx.definition_store)
-                .with_namespace_module_names(&self.ctx.namespace_module_names);
+                .with_namespace_module_names(&self.ctx.namespace_module_names)
+                .with_no_truncation(self.ctx.compiler_options.no_error_truncation);
                 let diag = builder.type_not_assignable(source, target, anchor.start, anchor.length);
                 diag.to_checker_diagnostic(&self.ctx.file_name)
             }
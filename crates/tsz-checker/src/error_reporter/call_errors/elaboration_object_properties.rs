@@ -223,6 +223,8 @@ impl<'a> CheckerState<'a> {
         }
         let mut emitted_duplicate_primary: rustc_hash::FxHashSet<String> =
             rustc_hash::FxHashSet::default();
+        let property_diagnostics_start = self.ctx.diagnostics.len();
+        let mut checked_properties: Vec<(NodeIndex, String)> = Vec::new();
 
         for &elem_idx in &obj.elements.nodes {
             let Some(elem_node) = self.ctx.arena.get(elem_idx) else {
@@ -289,6 +291,7 @@ impl<'a> CheckerState<'a> {
             else {
                 continue;
             };
+            checked_properties.push((prop_name_idx, prop_name.clone()));
 
             if self.target_has_never_indexed_access_surface(target_prop_type)
                 || self.target_has_never_indexed_access_surface(target_prop_type_for_diagnostic)
@@ -866,6 +869,12 @@ impl<'a> CheckerState<'a> {
             }
         }
 
+        self.attach_expected_type_property_locations(
+            property_diagnostics_start,
+            effective_param_type,
+            &checked_properties,
+        );
+
         // When the object literal has properties that all matched the target (elaborated
         // == false), but the only missing properties are Object.prototype methods
         // (valueOf, toString, etc.), suppress the error — those methods are implicitly
@@ -882,6 +891,45 @@ impl<'a> CheckerState<'a> {
         elaborated
     }
 
+    /// Point the property errors reported since `diagnostics_start` back at the
+    /// target property they were checked against, as tsc's
+    /// `elaborateElementwise` does with TS6500.
+    fn attach_expected_type_property_locations(
+        &mut self,
+        diagnostics_start: usize,
+        target_type: TypeId,
+        properties: &[(NodeIndex, String)],
+    ) {
+        for (name_idx, prop_name) in properties {
+            let Some((start, end)) = self.get_node_span(*name_idx) else {
+                continue;
+            };
+            let (start, _) =
+                self.normalized_anchor_span(*name_idx, start, end.saturating_sub(start));
+            let diagnostics_start = diagnostics_start.min(self.ctx.diagnostics.len());
+            let Some(offset) = self.ctx.diagnostics[diagnostics_start..]
+                .iter()
+                .position(|diag| {
+                    diag.start == start
+                        && diag.file == self.ctx.file_name
+                        && matches!(
+                            diag.code,
+                            diagnostic_codes::TYPE_IS_NOT_ASSIGNABLE_TO_TYPE
+                                | diagnostic_codes::TYPE_IS_NOT_ASSIGNABLE_TO_TYPE_DID_YOU_MEAN
+                        )
+                })
+            else {
+                continue;
+            };
+            if let Some(related) = self.expected_type_from_property_related(target_type, prop_name)
+            {
+                self.ctx.diagnostics[diagnostics_start + offset]
+                    .related_information
+                    .push(related);
+            }
+        }
+    }
+
     fn object_literal_numeric_members_assign_to_mapped_target(
         &mut self,
         object_idx: NodeIndex,
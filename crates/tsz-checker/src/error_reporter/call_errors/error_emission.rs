@@ -1028,6 +1028,14 @@ impl<'a> CheckerState<'a> {
             }
         }
 
+        // Like tsc, point at the last overload once there are too many
+        // candidates to list each one's error.
+        if failures.len() > 3
+            && let Some(last_overload) = self.last_overload_declared_here_related(idx)
+        {
+            related.push(last_overload);
+        }
+
         self.emit_render_request_at_anchor(
             anchor,
             DiagnosticRenderRequest::with_related(
@@ -1152,7 +1160,8 @@ impl<'a> CheckerState<'a> {
                 &self.ctx.binder.symbols,
                 self.ctx.file_name.as_str(),
             )
-            .with_def_store(&self.ctx.definition_store);
+            .with_def_store(&self.ctx.definition_store)
+            .with_no_truncation(self.ctx.compiler_options.no_error_truncation);
             let diag = builder.not_callable(type_id, loc.start, loc.length());
             self.ctx
                 .diagnostics
@@ -613,6 +613,7 @@ impl<'a> CheckerState<'a> {
             tsz_solver::TypeFormatter::with_symbols(self.ctx.types, &self.ctx.binder.symbols)
                 .with_def_store(&self.ctx.definition_store)
                 .with_diagnostic_mode()
+                .with_no_truncation(self.ctx.compiler_options.no_error_truncation)
                 .with_strict_null_checks(self.ctx.compiler_options.strict_null_checks)
                 .with_display_properties();
         formatter.format(ty).into_owned()
@@ -702,9 +703,8 @@ impl<'a> CheckerState<'a> {
             } else {
                 formatter = formatter.with_long_property_receiver_object_elision_end_depth(0);
             }
-            return Self::truncate_property_receiver_display(
-                formatter.format(display_ty).into_owned(),
-            );
+            return self
+                .truncate_property_receiver_display(formatter.format(display_ty).into_owned());
         }
         let has_object_shape =
             crate::query_boundaries::common::object_shape_for_type(self.ctx.types, ty).is_some();
@@ -738,7 +738,7 @@ impl<'a> CheckerState<'a> {
                 } else {
                     ty
                 };
-            return Self::truncate_property_receiver_display(
+            return self.truncate_property_receiver_display(
                 self.format_type_diagnostic_widened(display_ty),
             );
         }
@@ -770,7 +770,7 @@ impl<'a> CheckerState<'a> {
         if assignability_display.len() > 320 && assignability_display.starts_with("Omit<") {
             assignability_display = self.format_long_property_receiver_type_for_diagnostic(ty);
         }
-        let assignability_display = Self::truncate_property_receiver_display(assignability_display);
+        let assignability_display = self.truncate_property_receiver_display(assignability_display);
         if let Some(name) = self.synthesized_object_parent_display_name(ty) {
             let generic_prefix = format!("{name}<");
             if assignability_display.starts_with(&generic_prefix) {
@@ -1765,6 +1765,7 @@ impl<'a> CheckerState<'a> {
                 tsz_solver::TypeFormatter::with_symbols(self.ctx.types, &self.ctx.binder.symbols)
                     .with_def_store(&self.ctx.definition_store)
                     .with_diagnostic_mode()
+                    .with_no_truncation(self.ctx.compiler_options.no_error_truncation)
                     .with_strict_null_checks(self.ctx.compiler_options.strict_null_checks)
                     .with_exact_optional_property_types(
                         self.ctx.compiler_options.exact_optional_property_types,
@@ -33,14 +33,19 @@ impl<'a> CheckerState<'a> {
             .create_diagnostic_type_formatter()
             .with_skip_application_alias_names()
             .with_expand_scalar_mapped_alias_applications()
-            .with_preserve_optional_parameter_surface_syntax(true);
+            .with_preserve_optional_parameter_surface_syntax(true)
+            // Long receiver displays are elided by `truncate_property_receiver_display`.
+            .with_uncapped_display_length();
         formatter.format(type_id).into_owned()
     }
 
-    pub(crate) fn truncate_property_receiver_display(display: String) -> String {
+    pub(crate) fn truncate_property_receiver_display(&self, display: String) -> String {
         const MAX_PROPERTY_RECEIVER_DISPLAY_CHARS: usize = 320;
         let should_truncate = display.starts_with("Omit<") || display.starts_with("merge<");
-        if display.len() <= MAX_PROPERTY_RECEIVER_DISPLAY_CHARS || !should_truncate {
+        if self.ctx.compiler_options.no_error_truncation
+            || display.len() <= MAX_PROPERTY_RECEIVER_DISPLAY_CHARS
+            || !should_truncate
+        {
             return display;
         }
         let display =
@@ -65,6 +70,7 @@ impl<'a> CheckerState<'a> {
         tsz_solver::TypeFormatter::with_symbols(self.ctx.types, &self.ctx.binder.symbols)
             .with_def_store(&self.ctx.definition_store)
             .with_diagnostic_mode()
+            .with_no_truncation(self.ctx.compiler_options.no_error_truncation)
             .with_long_property_receiver_display()
             .with_skip_application_alias_names()
             .with_strict_null_checks(self.ctx.compiler_options.strict_null_checks)
@@ -202,6 +208,7 @@ impl<'a> CheckerState<'a> {
                 tsz_solver::TypeFormatter::with_symbols(state.ctx.types, &state.ctx.binder.symbols)
                     .with_def_store(&state.ctx.definition_store)
                     .with_diagnostic_mode()
+                    .with_no_truncation(state.ctx.compiler_options.no_error_truncation)
                     // Match tsc: optional parameters display as `(a?: T)`.
                     .with_preserve_optional_parameter_surface_syntax(true)
                     .with_strict_null_checks(state.ctx.compiler_options.strict_null_checks)
@@ -440,9 +447,8 @@ impl<'a> CheckerState<'a> {
                     .with_display_properties()
                     .with_skip_application_alias_names()
                     .with_long_property_receiver_object_elision_end_depth(0);
-                return Self::truncate_property_receiver_display(
-                    formatter.format(normalized).into_owned(),
-                );
+                return self
+                    .truncate_property_receiver_display(formatter.format(normalized).into_owned());
             }
             if normalized != application_display {
                 return self.format_type_diagnostic_widened_for_assignability_display(normalized);
@@ -960,6 +966,7 @@ impl<'a> CheckerState<'a> {
             tsz_solver::TypeFormatter::with_symbols(self.ctx.types, &self.ctx.binder.symbols)
                 .with_def_store(&self.ctx.definition_store)
                 .with_diagnostic_mode()
+                .with_no_truncation(self.ctx.compiler_options.no_error_truncation)
                 .with_skip_application_display_alias_chase()
                 .with_preserve_optional_parameter_surface_syntax(true)
                 .with_strict_null_checks(self.ctx.compiler_options.strict_null_checks)
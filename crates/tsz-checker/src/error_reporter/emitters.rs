@@ -1,7 +1,7 @@
 //! Fundamental error emission helpers: node-anchored, position-anchored,
 //! and templated diagnostic emitters.
 
-use crate::diagnostics::{Diagnostic, format_message};
+use crate::diagnostics::{Diagnostic, SpellingSuggestion, format_message};
 use crate::error_reporter::fingerprint_policy::{
     DiagnosticAnchorKind, DiagnosticRenderRequest, RelatedInfoStrategy, ResolvedDiagnosticAnchor,
 };
@@ -84,6 +84,45 @@ impl<'a> CheckerState<'a> {
         self.error_at_node(node_idx, &message, code);
     }
 
+    /// Record `suggestion` on the `code` diagnostic just reported, as the
+    /// replacement for its misspelled name. The name is the diagnostic's
+    /// span, without the quotes when it is a string literal.
+    pub(crate) fn attach_spelling_suggestion(&mut self, code: u32, suggestion: &str) {
+        let arena = self.ctx.arena;
+        let text = arena.source_files.first().map(|sf| sf.text.as_ref());
+        let Some(diag) = self
+            .ctx
+            .diagnostics
+            .iter_mut()
+            .rev()
+            .find(|d| d.code == code)
+        else {
+            return;
+        };
+        if diag.spelling_suggestion.is_some() {
+            return;
+        }
+        let (mut start, mut length) = (diag.start, diag.length);
+        let span_text =
+            text.and_then(|text| text.get(start as usize..start.saturating_add(length) as usize));
+        if let Some(span_text) = span_text
+            && span_text.len() >= 2
+            && let Some(quote) = span_text
+                .chars()
+                .next()
+                .filter(|c| matches!(c, '"' | '\'' | '`'))
+            && span_text.ends_with(quote)
+        {
+            start += 1;
+            length -= 2;
+        }
+        diag.spelling_suggestion = Some(SpellingSuggestion {
+            start,
+            length,
+            name: suggestion.to_string(),
+        });
+    }
+
     /// Get the source text for a node by extracting from the source file text.
     pub(crate) fn get_source_text_for_node(&self, node_idx: NodeIndex) -> String {
         if let Some((start, end)) = self.get_node_span(node_idx)
@@ -486,7 +486,7 @@ impl<'a> CheckerState<'a> {
                 property_name,
                 source_property_type,
                 target_property_type,
-                ..
+                nested_reason,
             } => {
                 let target_property_type = if self.should_strip_nullish_for_property_display(target)
                 {
@@ -513,20 +513,48 @@ impl<'a> CheckerState<'a> {
                     target_str,
                 );
 
-                vec![
-                    DiagnosticRelatedInformation {
-                        category: DiagnosticCategory::Error,
-                        code: diagnostic_codes::TYPES_OF_PROPERTY_ARE_INCOMPATIBLE,
-                        file: self.ctx.file_name.clone(),
-                        start,
-                        length,
-                        message_text: format_message(
-                            diagnostic_messages::TYPES_OF_PROPERTY_ARE_INCOMPATIBLE,
-                            &[&self.ctx.types.resolve_atom_ref(*property_name)],
-                        ),
-                        depth: 0,
-                    },
-                    DiagnosticRelatedInformation {
+                let mut items = vec![DiagnosticRelatedInformation {
+                    category: DiagnosticCategory::Error,
+                    code: diagnostic_codes::TYPES_OF_PROPERTY_ARE_INCOMPATIBLE,
+                    file: self.ctx.file_name.clone(),
+                    start,
+                    length,
+                    message_text: format_message(
+                        diagnostic_messages::TYPES_OF_PROPERTY_ARE_INCOMPATIBLE,
+                        &[&self.ctx.types.resolve_atom_ref(*property_name)],
+                    ),
+                    depth: 0,
+                }];
+                // A structural nested failure (a deeper property, a missing
+                // property, ...) continues the chain one level below the
+                // property header, rendered the same way TS2322 renders it.
+                // Plain leaf mismatches keep the direct relation line.
+                match nested_reason.as_deref() {
+                    Some(nested) if !Self::nested_reason_is_plain_type_mismatch(nested) => {
+                        let (nested_source, nested_target) = Self::nested_failure_display_types(
+                            nested,
+                            *source_property_type,
+                            target_property_type,
+                        );
+                        let nested_diag = self.render_failure_reason(
+                            nested,
+                            nested_source,
+                            nested_target,
+                            anchor_idx,
+                            1,
+                        );
+                        items.push(DiagnosticRelatedInformation {
+                            category: DiagnosticCategory::Message,
+                            code: nested_diag.code,
+                            file: nested_diag.file,
+                            start: nested_diag.start,
+                            length: nested_diag.length,
+                            message_text: nested_diag.message_text,
+                            depth: 1,
+                        });
+                        items.extend(nested_diag.related_information);
+                    }
+                    _ => items.push(DiagnosticRelatedInformation {
                         category: DiagnosticCategory::Message,
                         code: diagnostic_codes::TYPE_IS_NOT_ASSIGNABLE_TO_TYPE,
                         file: self.ctx.file_name.clone(),
@@ -536,9 +564,10 @@ impl<'a> CheckerState<'a> {
                             diagnostic_messages::TYPE_IS_NOT_ASSIGNABLE_TO_TYPE,
                             &[&source_str, &target_str],
                         ),
-                        depth: 0,
-                    },
-                ]
+                        depth: 1,
+                    }),
+                }
+                items
             }
             SubtypeFailureReason::OptionalPropertyRequired { property_name } => {
                 // Present-but-optional source property assigned to a required
@@ -627,7 +656,11 @@ impl<'a> CheckerState<'a> {
                         anchor_idx,
                     )
                 {
-                    items.extend(nested_related);
+                    // The nested chain elaborates the return-type line above.
+                    items.extend(nested_related.into_iter().map(|mut item| {
+                        item.depth = item.depth.saturating_add(1);
+                        item
+                    }));
                 }
                 items
             }
@@ -673,7 +706,7 @@ impl<'a> CheckerState<'a> {
                             diagnostic_messages::TYPE_IS_NOT_ASSIGNABLE_TO_TYPE,
                             &[&source_str, &target_str],
                         ),
-                        depth: 0,
+                        depth: 1,
                     },
                 ]
             }
@@ -717,7 +750,7 @@ impl<'a> CheckerState<'a> {
                             diagnostic_messages::TYPE_IS_NOT_ASSIGNABLE_TO_TYPE,
                             &[&source_str, &target_str],
                         ),
-                        depth: 0,
+                        depth: 1,
                     },
                 ]
             }
@@ -814,10 +847,13 @@ impl<'a> CheckerState<'a> {
 
         // Sort related information for consistent, deterministic fingerprint ordering.
         // This ensures fingerprint-only tests match tsc's expected diagnostic output.
+        // Entries sharing a span are ordered by elaboration depth first so a
+        // message chain keeps its parent-before-child order.
         normalized.sort_by(|a, b| {
             a.file
                 .cmp(&b.file)
                 .then(a.start.cmp(&b.start))
+                .then(a.depth.cmp(&b.depth))
                 .then(a.message_text.cmp(&b.message_text))
         });
 
@@ -40,6 +40,7 @@ mod primitive_intersection_display;
 mod properties;
 mod property_receiver_formatting;
 mod recursive_alias_display;
+mod related_locations;
 mod render_failure;
 mod suggestions;
 mod ts2820_display;
@@ -1383,6 +1383,9 @@ impl<'a> CheckerState<'a> {
         };
 
         self.error_at_node(idx, &message, code);
+        if let [suggestion] = suggestions.as_slice() {
+            self.attach_spelling_suggestion(code, suggestion);
+        }
     }
 
     /// Report error 2552: Cannot find name 'X'. Did you mean 'Y'?
@@ -1398,6 +1401,10 @@ impl<'a> CheckerState<'a> {
             &message,
             diagnostic_codes::CANNOT_FIND_NAME_DID_YOU_MEAN,
         );
+        self.attach_spelling_suggestion(
+            diagnostic_codes::CANNOT_FIND_NAME_DID_YOU_MEAN,
+            suggestion,
+        );
     }
 
     /// Report error 2662: Cannot find name 'X'. Did you mean the static member 'C.X'?
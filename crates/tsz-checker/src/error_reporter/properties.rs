@@ -1679,6 +1679,9 @@ impl<'a> CheckerState<'a> {
                         )
                     };
                     self.error_at_anchor(idx, DiagnosticAnchorKind::PropertyToken, &message, code);
+                    if let Some(suggestion) = &suggestion {
+                        self.attach_spelling_suggestion(code, suggestion);
+                    }
                     return;
                 }
             }
@@ -1708,6 +1711,9 @@ impl<'a> CheckerState<'a> {
                         )
                     };
                     self.error_at_anchor(idx, DiagnosticAnchorKind::PropertyToken, &message, code);
+                    if let Some(suggestion) = &suggestion {
+                        self.attach_spelling_suggestion(code, suggestion);
+                    }
                     return;
                 }
 
@@ -1730,6 +1736,9 @@ impl<'a> CheckerState<'a> {
                     )
                 };
                 self.error_at_anchor(idx, DiagnosticAnchorKind::PropertyToken, &message, code);
+                if let Some(suggestion) = &suggestion {
+                    self.attach_spelling_suggestion(code, suggestion);
+                }
                 return;
             }
 
@@ -1756,6 +1765,9 @@ impl<'a> CheckerState<'a> {
                     )
                 };
                 self.error_at_anchor(idx, DiagnosticAnchorKind::PropertyToken, &message, code);
+                if let Some(suggestion) = &suggestion {
+                    self.attach_spelling_suggestion(code, suggestion);
+                }
                 return;
             }
 
@@ -1777,6 +1789,9 @@ impl<'a> CheckerState<'a> {
                     )
                 };
                 self.error_at_anchor(idx, DiagnosticAnchorKind::PropertyToken, &message, code);
+                if let Some(suggestion) = &suggestion {
+                    self.attach_spelling_suggestion(code, suggestion);
+                }
                 return;
             }
 
@@ -1811,6 +1826,9 @@ impl<'a> CheckerState<'a> {
                 )
             };
             self.error_at_anchor(idx, DiagnosticAnchorKind::PropertyToken, &message, code);
+            if let Some(suggestion) = &suggestion {
+                self.attach_spelling_suggestion(code, suggestion);
+            }
         }
     }
 }
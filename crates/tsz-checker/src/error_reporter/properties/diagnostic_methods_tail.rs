@@ -111,6 +111,10 @@ impl<'a> CheckerState<'a> {
                 &message,
                 diagnostic_codes::PROPERTY_DOES_NOT_EXIST_ON_TYPE_DID_YOU_MEAN,
             );
+            self.attach_spelling_suggestion(
+                diagnostic_codes::PROPERTY_DOES_NOT_EXIST_ON_TYPE_DID_YOU_MEAN,
+                suggestion,
+            );
         } else {
             self.error_property_not_exist_with_apparent_type(prop_name, &type_str, idx);
         }
@@ -0,0 +1,194 @@
+//! Related-location helpers: secondary spans such as "'x' is declared here."
+//! that point the user at another place in the program.
+//!
+//! Unlike elaboration entries (which share the primary span and render as
+//! the indented message chain), related locations carry their own span and
+//! one of the dedicated related-location codes (see
+//! `tsz_common::diagnostics::is_related_location_diagnostic`).
+
+use crate::diagnostics::{
+    Diagnostic, DiagnosticCategory, DiagnosticRelatedInformation, diagnostic_codes,
+    diagnostic_messages, format_message,
+};
+use crate::state::CheckerState;
+use tsz_binder::SymbolId;
+use tsz_parser::parser::NodeIndex;
+use tsz_parser::parser::syntax_kind_ext;
+use tsz_solver::TypeId;
+
+impl<'a> CheckerState<'a> {
+    /// Build a related location spanning `node_idx`.
+    ///
+    /// The span is normalized the same way primary anchors are, and the file
+    /// is taken from the source file that owns the node.
+    pub(crate) fn related_location_at_node(
+        &self,
+        node_idx: NodeIndex,
+        code: u32,
+        message_text: String,
+    ) -> Option<DiagnosticRelatedInformation> {
+        let (start, end) = self.get_node_span(node_idx)?;
+        let (start, length) =
+            self.normalized_anchor_span(node_idx, start, end.saturating_sub(start));
+        let file = self
+            .source_file_data_for_node(node_idx)
+            .map(|sf| sf.file_name.clone())
+            .unwrap_or_else(|| self.ctx.file_name.clone());
+        Some(DiagnosticRelatedInformation {
+            category: DiagnosticCategory::Message,
+            code,
+            file,
+            start,
+            length,
+            message_text,
+            depth: 0,
+        })
+    }
+
+    /// Build a TS2728 "'{name}' is declared here." location for a declaration.
+    ///
+    /// Anchors at the declaration's name when it has one, as tsc does.
+    pub(crate) fn declared_here_related(
+        &self,
+        decl_idx: NodeIndex,
+        name: &str,
+    ) -> Option<DiagnosticRelatedInformation> {
+        let anchor = self
+            .get_declaration_name_node(decl_idx)
+            .filter(|name_idx| name_idx.is_some())
+            .unwrap_or(decl_idx);
+        self.related_location_at_node(
+            anchor,
+            diagnostic_codes::IS_DECLARED_HERE,
+            format_message(diagnostic_messages::IS_DECLARED_HERE, &[name]),
+        )
+    }
+
+    /// Build a TS6500 "The expected type comes from property '{name}' which is
+    /// declared here on type '{target}'" location for a property of
+    /// `target_type`.
+    ///
+    /// Only declarations in the current file are reported, since their spans
+    /// are resolved against the current arena.
+    pub(crate) fn expected_type_from_property_related(
+        &self,
+        target_type: TypeId,
+        prop_name: &str,
+    ) -> Option<DiagnosticRelatedInformation> {
+        let name_atom = self.ctx.types.intern_string(prop_name);
+        let owner = self
+            .property_info_for_display(target_type, name_atom)?
+            .parent_id
+            .or_else(|| {
+                crate::query_boundaries::common::object_shape_for_type(self.ctx.types, target_type)
+                    .and_then(|shape| shape.symbol)
+            })?;
+        let prop_sym_id = self
+            .ctx
+            .binder
+            .get_symbol(owner)?
+            .members
+            .as_ref()?
+            .get(prop_name)?;
+        let decl_idx = self.current_file_declaration(prop_sym_id)?;
+        let anchor = self
+            .get_declaration_name_node(decl_idx)
+            .filter(|name_idx| name_idx.is_some())
+            .unwrap_or(decl_idx);
+        let target_text = self.format_type_diagnostic(target_type);
+        self.related_location_at_node(
+            anchor,
+            diagnostic_codes::THE_EXPECTED_TYPE_COMES_FROM_PROPERTY_WHICH_IS_DECLARED_HERE_ON_TYPE,
+            format_message(
+                diagnostic_messages::THE_EXPECTED_TYPE_COMES_FROM_PROPERTY_WHICH_IS_DECLARED_HERE_ON_TYPE,
+                &[prop_name, &target_text],
+            ),
+        )
+    }
+
+    /// Build a TS2771 "The last overload is declared here." location for the
+    /// function called by `call_idx`, anchored at the overload's name.
+    ///
+    /// Only identifier callees whose overload signatures are declared in the
+    /// current file are handled.
+    pub(crate) fn last_overload_declared_here_related(
+        &self,
+        call_idx: NodeIndex,
+    ) -> Option<DiagnosticRelatedInformation> {
+        let arena = self.ctx.arena;
+        let call = arena.get_call_expr(arena.get(call_idx)?)?;
+        let callee = arena.skip_parenthesized(call.expression);
+        let sym_id = self.resolve_identifier_symbol_without_tracking(callee)?;
+        let symbol = self.ctx.binder.get_symbol(sym_id)?;
+        let last_overload = symbol
+            .declarations
+            .iter()
+            .copied()
+            .rev()
+            .find(|&decl_idx| {
+                std::ptr::eq(
+                    self.ctx
+                        .binder
+                        .arena_for_declaration_or(sym_id, decl_idx, arena),
+                    arena,
+                ) && arena.get(decl_idx).is_some_and(|node| {
+                    node.kind == syntax_kind_ext::FUNCTION_DECLARATION
+                        && arena
+                            .get_function(node)
+                            .is_some_and(|func| func.body.is_none())
+                })
+            })?;
+        let anchor = self
+            .get_declaration_name_node(last_overload)
+            .filter(|name_idx| name_idx.is_some())
+            .unwrap_or(last_overload);
+        self.related_location_at_node(
+            anchor,
+            diagnostic_codes::THE_LAST_OVERLOAD_IS_DECLARED_HERE,
+            diagnostic_messages::THE_LAST_OVERLOAD_IS_DECLARED_HERE.to_string(),
+        )
+    }
+
+    /// The value declaration (or first declaration) of `sym_id`, when it lives
+    /// in the current file's arena.
+    fn current_file_declaration(&self, sym_id: SymbolId) -> Option<NodeIndex> {
+        let symbol = self.ctx.binder.get_symbol(sym_id)?;
+        let decl_idx = symbol
+            .value_declaration
+            .into_option()
+            .or_else(|| symbol.declarations.first().copied())?;
+        std::ptr::eq(
+            self.ctx
+                .binder
+                .arena_for_declaration_or(sym_id, decl_idx, self.ctx.arena),
+            self.ctx.arena,
+        )
+        .then_some(decl_idx)
+    }
+
+    /// Report an error at a node with related locations attached.
+    ///
+    /// Spans and deduplication match `error_at_node`.
+    pub(crate) fn error_at_node_with_related(
+        &mut self,
+        node_idx: NodeIndex,
+        message: &str,
+        code: u32,
+        related: Vec<DiagnosticRelatedInformation>,
+    ) {
+        let Some((start, end)) = self.get_node_span(node_idx) else {
+            return;
+        };
+        let (start, length) =
+            self.normalized_anchor_span(node_idx, start, end.saturating_sub(start));
+        let mut diag = Diagnostic::error(
+            self.ctx.file_name.clone(),
+            start,
+            length,
+            message.to_string(),
+            code,
+        );
+        diag.related_information = related;
+        self.ctx.push_diagnostic(diag);
+    }
+}
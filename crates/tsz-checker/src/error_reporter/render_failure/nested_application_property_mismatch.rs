@@ -78,7 +78,7 @@ impl<'a> CheckerState<'a> {
             || structural_display_type(self.ctx.types, target_eval)
     }
 
-    const fn nested_reason_is_plain_type_mismatch(
+    pub(in crate::error_reporter) const fn nested_reason_is_plain_type_mismatch(
         reason: &tsz_solver::SubtypeFailureReason,
     ) -> bool {
         matches!(
@@ -90,7 +90,7 @@ impl<'a> CheckerState<'a> {
         )
     }
 
-    pub(super) const fn nested_failure_display_types(
+    pub(in crate::error_reporter) const fn nested_failure_display_types(
         reason: &tsz_solver::SubtypeFailureReason,
         fallback_source: TypeId,
         fallback_target: TypeId,
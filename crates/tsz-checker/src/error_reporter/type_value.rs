@@ -154,6 +154,10 @@ impl<'a> CheckerState<'a> {
                 &message,
                 diagnostic_codes::HAS_NO_EXPORTED_MEMBER_NAMED_DID_YOU_MEAN,
             );
+            self.attach_spelling_suggestion(
+                diagnostic_codes::HAS_NO_EXPORTED_MEMBER_NAMED_DID_YOU_MEAN,
+                &suggestion,
+            );
         } else {
             let message =
                 format!("Namespace '{namespace_name}' has no exported member '{member_name}'.");
@@ -624,7 +628,7 @@ impl<'a> CheckerState<'a> {
                 if let Some(file_name) = target_arena.source_files.first().map(|f| &f.file_name)
                     && let Some(exports) =
                         self.ctx.module_exports_for_module(target_binder, file_name)
-                    && let Some(export_eq_sym) = exports.get("export=")
+                    && let Some(export_eq_sym) = exports.export_equals()
                 {
                     // Check if the export= symbol itself is type-only
                     if let Some(eq_sym) = target_binder.get_symbol(export_eq_sym) {
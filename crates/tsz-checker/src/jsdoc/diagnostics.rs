@@ -1907,6 +1907,10 @@ impl<'a> CheckerState<'a> {
                 &message,
                 diagnostic_codes::CANNOT_FIND_NAME_DID_YOU_MEAN,
             );
+            self.attach_spelling_suggestion(
+                diagnostic_codes::CANNOT_FIND_NAME_DID_YOU_MEAN,
+                &suggestion,
+            );
             return;
         }
 
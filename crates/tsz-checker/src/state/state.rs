@@ -824,6 +824,20 @@ impl<'a> CheckerState<'a> {
         }
     }
 
+    /// Attach the cancellation token of the request driving this checker.
+    ///
+    /// Once cancelled, type resolution and evaluation bail out and
+    /// `check_source_file` stops between statements. Diagnostics and caches
+    /// from a cancelled run are incomplete and should be discarded.
+    pub fn set_cancellation_token(&self, token: Option<tsz_common::CancellationToken>) {
+        self.ctx.eval_session.set_cancellation_token(token);
+    }
+
+    /// Whether the attached cancellation token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.ctx.eval_session.is_cancelled()
+    }
+
     /// Extract the persistent cache from this checker.
     /// This allows saving type checking results for future queries.
     pub fn extract_cache(self) -> crate::TypeCache {
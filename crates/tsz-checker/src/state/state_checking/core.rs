@@ -47,7 +47,7 @@ impl<'a> CheckerState<'a> {
         match node.kind {
             syntax_kind_ext::VARIABLE_DECLARATION => {
                 let decl_flags = arena.get_variable_declaration_flags(decl_idx);
-                if node_flags::is_let_or_const(decl_flags) {
+                if node_flags::is_block_scoped(decl_flags) {
                     Some(symbol_flags::BLOCK_SCOPED_VARIABLE)
                 } else {
                     Some(symbol_flags::FUNCTION_SCOPED_VARIABLE)
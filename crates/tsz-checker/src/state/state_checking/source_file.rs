@@ -9,7 +9,7 @@ use crate::statements::StatementChecker;
 use rustc_hash::FxHashSet;
 use tracing::{Level, span};
 use tsz_parser::parser::NodeIndex;
-use tsz_parser::parser::syntax_kind_ext;
+use tsz_parser::parser::{script_kind, syntax_kind_ext};
 use tsz_scanner::SyntaxKind;
 
 impl<'a> CheckerState<'a> {
@@ -329,6 +329,10 @@ impl<'a> CheckerState<'a> {
         let Some(sf) = self.ctx.arena.get_source_file(node) else {
             return;
         };
+        // As in tsc, JSON files are not checked; importers read their value.
+        if sf.script_kind == script_kind::JSON {
+            return;
+        }
 
         // Type-environment prewarming may construct large alias bodies before
         // statement checking reaches a concrete diagnostic site. Start the
@@ -385,26 +389,16 @@ impl<'a> CheckerState<'a> {
         let mut seen_dts_ambient_violation = false;
         let statement_timing_enabled = tsz_common::perf_counters::enabled_fast();
         for &stmt_idx in &sf.statements.nodes {
+            // A cancelled request only needs to unwind; its results are discarded.
+            if self.ctx.eval_session.is_cancelled() {
+                break;
+            }
             let stmt_timing_start = statement_timing_enabled.then(web_time::Instant::now);
             let stmt_timing_node = self
                 .ctx
                 .arena
                 .get(stmt_idx)
                 .map(|node| (node.kind, node.pos, node.end));
-            if !is_dts
-                && !suppress_grammar
-                && let Some(stmt_node) = self.ctx.arena.get(stmt_idx)
-                && stmt_node.kind == syntax_kind_ext::NAMESPACE_EXPORT_DECLARATION
-            {
-                use crate::diagnostics::{diagnostic_codes, diagnostic_messages};
-                self.ctx.error(
-                    stmt_node.pos,
-                    stmt_node.end.saturating_sub(stmt_node.pos),
-                    diagnostic_messages::GLOBAL_MODULE_EXPORTS_MAY_ONLY_APPEAR_IN_DECLARATION_FILES
-                        .to_string(),
-                    diagnostic_codes::GLOBAL_MODULE_EXPORTS_MAY_ONLY_APPEAR_IN_DECLARATION_FILES,
-                );
-            }
             if is_dts && !suppress_grammar && !seen_dts_ambient_violation {
                 seen_dts_ambient_violation = self.check_dts_statement_in_ambient_context(stmt_idx);
             }
@@ -424,6 +418,9 @@ impl<'a> CheckerState<'a> {
         }
         self.ctx.is_unreachable = prev_unreachable;
         self.ctx.has_reported_unreachable = prev_reported;
+        if self.ctx.eval_session.is_cancelled() {
+            return;
+        }
 
         if self.is_js_file() && self.ctx.should_resolve_jsdoc() {
             self.recheck_checked_js_import_diagnostics(&sf.statements.nodes);
@@ -553,6 +550,9 @@ impl<'a> CheckerState<'a> {
 
             // TS2304: Check for @typedef base types that can't be resolved
             self.check_jsdoc_typedef_base_types();
+
+            // TS80001: suggest converting top-level CommonJS exports to ESM
+            self.suggest_convert_to_es_module();
         }
 
         // Emit deferred TS2875 (JSX import source not found) if set.
@@ -1518,6 +1518,54 @@ impl<'a> CheckerState<'a> {
         })
     }
 
+    /// TS1314/TS1315/TS1316: `export as namespace` declares a UMD global only
+    /// at the top level of a module declaration file. Anywhere else the
+    /// binder declares nothing, and the first unmet requirement is reported.
+    pub(crate) fn check_namespace_export_declaration(&mut self, stmt_idx: NodeIndex) {
+        use crate::diagnostics::{diagnostic_codes, diagnostic_messages};
+
+        if self.has_syntax_parse_errors()
+            || self.ctx.diagnostics.iter().any(|diag| {
+                diag.code == diagnostic_codes::IS_NOT_ALLOWED_AS_A_VARIABLE_DECLARATION_NAME
+            })
+        {
+            return;
+        }
+        let Some(stmt_node) = self.ctx.arena.get(stmt_idx) else {
+            return;
+        };
+        let at_top_level = self
+            .ctx
+            .arena
+            .get_extended(stmt_idx)
+            .and_then(|ext| self.ctx.arena.get(ext.parent))
+            .is_some_and(|parent| parent.kind == syntax_kind_ext::SOURCE_FILE);
+        let (message, code) = if !at_top_level {
+            (
+                diagnostic_messages::GLOBAL_MODULE_EXPORTS_MAY_ONLY_APPEAR_AT_TOP_LEVEL,
+                diagnostic_codes::GLOBAL_MODULE_EXPORTS_MAY_ONLY_APPEAR_AT_TOP_LEVEL,
+            )
+        } else if !self.ctx.binder.is_external_module() {
+            (
+                diagnostic_messages::GLOBAL_MODULE_EXPORTS_MAY_ONLY_APPEAR_IN_MODULE_FILES,
+                diagnostic_codes::GLOBAL_MODULE_EXPORTS_MAY_ONLY_APPEAR_IN_MODULE_FILES,
+            )
+        } else if !self.ctx.is_declaration_file() {
+            (
+                diagnostic_messages::GLOBAL_MODULE_EXPORTS_MAY_ONLY_APPEAR_IN_DECLARATION_FILES,
+                diagnostic_codes::GLOBAL_MODULE_EXPORTS_MAY_ONLY_APPEAR_IN_DECLARATION_FILES,
+            )
+        } else {
+            return;
+        };
+        self.ctx.error(
+            stmt_node.pos,
+            stmt_node.end.saturating_sub(stmt_node.pos),
+            message.to_string(),
+            code,
+        );
+    }
+
     /// Check a statement and produce type errors.
     ///
     /// This method delegates to `StatementChecker` for dispatching logic,
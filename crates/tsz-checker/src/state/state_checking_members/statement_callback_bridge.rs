@@ -119,6 +119,10 @@ impl<'a> StatementCheckCallbacks for CheckerState<'a> {
         CheckerState::check_import_equals_declaration(self, import_idx);
     }
 
+    fn check_namespace_export_declaration(&mut self, stmt_idx: NodeIndex) {
+        CheckerState::check_namespace_export_declaration(self, stmt_idx);
+    }
+
     fn check_export_declaration(&mut self, export_idx: NodeIndex) {
         if let Some(export_decl) = self.ctx.arena.get_export_decl_at(export_idx) {
             // When the export is inside a non-module-element context (e.g., a block
@@ -155,7 +155,7 @@ impl<'a> CheckerState<'a> {
             // `module.exports` as the default, and `export = X` sets
             // `module.exports = X`. See
             // `nodeNextEsmImportsOfPackagesWithExtensionlessMains.ts`.
-            if let Some(export_equals_sym_id) = exports_table.get("export=") {
+            if let Some(export_equals_sym_id) = exports_table.export_equals() {
                 return Some(self.get_type_of_symbol(export_equals_sym_id));
             }
 
@@ -379,7 +379,7 @@ impl<'a> CheckerState<'a> {
             self.resolve_effective_module_exports_from_file(module_name, Some(source_file_idx))?;
         let is_node_esm_importing_cjs =
             self.ctx.compiler_options.module.is_node_module() && source_is_esm && !target_is_esm;
-        let has_export_equals = exports_table.has("export=");
+        let has_export_equals = exports_table.has_export_equals();
         if !(is_node_esm_importing_cjs
             || has_export_equals && self.ctx.allow_synthetic_default_imports())
         {
@@ -422,7 +422,7 @@ impl<'a> CheckerState<'a> {
             });
         }
 
-        if has_export_equals && let Some(export_equals_sym_id) = exports_table.get("export=") {
+        if has_export_equals && let Some(export_equals_sym_id) = exports_table.export_equals() {
             let export_equals_type = self.get_type_of_symbol(export_equals_sym_id);
             let default_atom = self.ctx.types.intern_string("default");
             if let Some(existing_default) = props.iter_mut().find(|p| p.name == default_atom) {
@@ -611,7 +611,7 @@ impl<'a> CheckerState<'a> {
         exports_table: &tsz_binder::SymbolTable,
         props: &mut Vec<PropertyInfo>,
     ) -> Option<String> {
-        let export_equals_sym_id = exports_table.get("export=")?;
+        let export_equals_sym_id = exports_table.export_equals()?;
         let mut export_equals_symbol = self
             .get_symbol_globally(export_equals_sym_id)
             .or_else(|| self.get_cross_file_symbol(export_equals_sym_id))?;
@@ -632,19 +632,19 @@ impl<'a> CheckerState<'a> {
                     target_binder
                         .module_exports
                         .get(file_name)
-                        .and_then(|table| table.get("export="))
+                        .and_then(|table| table.export_equals())
                 })
                 .or_else(|| {
                     target_binder
                         .module_exports
                         .get(module_name)
-                        .and_then(|table| table.get("export="))
+                        .and_then(|table| table.export_equals())
                 })
                 .or_else(|| {
                     target_binder
                         .module_exports
                         .values()
-                        .find_map(|table| table.get("export="))
+                        .find_map(|table| table.export_equals())
                 });
             if let Some(target_export_equals_sym) = target_export_equals_sym
                 && let Some(target_symbol) = target_binder.get_symbol(target_export_equals_sym)
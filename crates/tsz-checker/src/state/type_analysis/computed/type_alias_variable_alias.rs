@@ -812,7 +812,7 @@ impl<'a> CheckerState<'a> {
                                 self.ctx.resolve_symbol_file_index(*export_sym_id)
                             });
                         let mut export_equals_type =
-                            exports_table.get("export=").map(|export_equals_sym| {
+                            exports_table.export_equals().map(|export_equals_sym| {
                                 let export_equals_type = self.get_type_of_symbol(export_equals_sym);
                                 self.widen_type_for_display(export_equals_type)
                             });
@@ -1175,7 +1175,7 @@ impl<'a> CheckerState<'a> {
                                 self.ctx.resolve_symbol_file_index(*export_sym_id)
                             });
                         let mut export_equals_type =
-                            exports_table.get("export=").map(|export_equals_sym| {
+                            exports_table.export_equals().map(|export_equals_sym| {
                                 let export_equals_type = self.get_type_of_symbol(export_equals_sym);
                                 self.widen_type_for_display(export_equals_type)
                             });
@@ -1545,8 +1545,8 @@ impl<'a> CheckerState<'a> {
                         Some(self.ctx.current_file_idx),
                     ) {
                         let ordered_exports = self.ordered_namespace_export_entries(&exports_table);
-                        if exports_table.has("export=")
-                            && let Some(export_eq_sym) = exports_table.get("export=")
+                        if exports_table.has_export_equals()
+                            && let Some(export_eq_sym) = exports_table.export_equals()
                         {
                             let export_eq_type = self.get_type_of_symbol(export_eq_sym);
                             let export_eq_type =
@@ -8,12 +8,16 @@ use tsz_scanner::SyntaxKind;
 use tsz_solver::{PropertyInfo, TypeId, Visibility};
 
 impl<'a> CheckerState<'a> {
+    /// Collect `exports.x = ...` style assignments in `expr_idx`, including
+    /// chained ones. With `aliases_only`, only assignments through local
+    /// aliases of `exports` are collected.
     pub(super) fn collect_direct_commonjs_assignment_exports(
         arena: &tsz_parser::parser::NodeArena,
         expr_idx: NodeIndex,
         pending_props: &mut FxHashMap<String, Vec<(NodeIndex, Option<String>)>>,
         ordered_names: &mut Vec<String>,
         export_aliases: &FxHashSet<String>,
+        aliases_only: bool,
     ) {
         let Some(expr_node) = arena.get(expr_idx) else {
             return;
@@ -39,6 +43,7 @@ impl<'a> CheckerState<'a> {
             };
             let direct_exports = arena
                 .get_identifier_at(left_access.expression)
+                .filter(|_| !aliases_only)
                 .and_then(|ident| {
                     (ident.escaped_text == "exports").then(|| {
                         Self::commonjs_static_member_name_in_arena(
@@ -49,33 +54,39 @@ impl<'a> CheckerState<'a> {
                     })
                 })
                 .flatten();
-            let module_exports = arena.get(left_access.expression).and_then(|target_node| {
-                let target_access = arena.get_access_expr(target_node)?;
-                let is_module_exports =
-                    if target_node.kind == syntax_kind_ext::PROPERTY_ACCESS_EXPRESSION {
-                        arena
-                            .get_identifier_at(target_access.expression)
-                            .is_some_and(|ident| ident.escaped_text == "module")
-                            && arena
-                                .get_identifier_at(target_access.name_or_argument)
-                                .is_some_and(|ident| ident.escaped_text == "exports")
-                    } else if target_node.kind == syntax_kind_ext::ELEMENT_ACCESS_EXPRESSION {
-                        arena
-                            .get_identifier_at(target_access.expression)
-                            .is_some_and(|ident| ident.escaped_text == "module")
-                            && Self::commonjs_static_member_name_in_arena(
-                                arena,
-                                target_access.name_or_argument,
-                            )
-                            .is_some_and(|name| name == "exports")
-                    } else {
-                        false
-                    };
-                is_module_exports.then(|| {
-                    Self::commonjs_static_member_name_in_arena(arena, left_access.name_or_argument)
+            let module_exports = arena
+                .get(left_access.expression)
+                .filter(|_| !aliases_only)
+                .and_then(|target_node| {
+                    let target_access = arena.get_access_expr(target_node)?;
+                    let is_module_exports =
+                        if target_node.kind == syntax_kind_ext::PROPERTY_ACCESS_EXPRESSION {
+                            arena
+                                .get_identifier_at(target_access.expression)
+                                .is_some_and(|ident| ident.escaped_text == "module")
+                                && arena
+                                    .get_identifier_at(target_access.name_or_argument)
+                                    .is_some_and(|ident| ident.escaped_text == "exports")
+                        } else if target_node.kind == syntax_kind_ext::ELEMENT_ACCESS_EXPRESSION {
+                            arena
+                                .get_identifier_at(target_access.expression)
+                                .is_some_and(|ident| ident.escaped_text == "module")
+                                && Self::commonjs_static_member_name_in_arena(
+                                    arena,
+                                    target_access.name_or_argument,
+                                )
+                                .is_some_and(|name| name == "exports")
+                        } else {
+                            false
+                        };
+                    is_module_exports.then(|| {
+                        Self::commonjs_static_member_name_in_arena(
+                            arena,
+                            left_access.name_or_argument,
+                        )
                         .map(|name| (name.clone(), Some(format!("module.exports.{name}"))))
-                })?
-            });
+                    })?
+                });
 
             // Also check if the expression is a known alias for exports/module.exports
             let alias_exports = if direct_exports.is_none() && module_exports.is_none() {
@@ -116,6 +127,7 @@ impl<'a> CheckerState<'a> {
             pending_props,
             ordered_names,
             export_aliases,
+            aliases_only,
         );
     }
 
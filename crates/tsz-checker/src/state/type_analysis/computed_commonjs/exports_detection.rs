@@ -80,6 +80,50 @@ impl<'a> CheckerState<'a> {
         (symbol_type != TypeId::ERROR && symbol_type != TypeId::UNKNOWN).then_some(symbol_type)
     }
 
+    /// TS80001: suggest converting a JS file with top-level CommonJS exports
+    /// to an ES module.
+    ///
+    /// Mirrors tsc's `convertToEsModule` suggestion: only offered when the
+    /// options already point at ES modules (`module` set explicitly or an
+    /// ES2015+ target) and the file has no ESM syntax of its own. Anchored at
+    /// the first export assignment the binder recorded.
+    pub(crate) fn suggest_convert_to_es_module(&mut self) {
+        use crate::diagnostics::diagnostic_codes;
+
+        let options = &self.ctx.compiler_options;
+        if !options.report_suggestions
+            || !(options.module_explicitly_set || options.target.supports_es2015())
+            || !self.is_js_file()
+            || self.current_source_file_has_esm_syntax()
+        {
+            return;
+        }
+        let Some(first_export) = self
+            .ctx
+            .binder
+            .commonjs_exports
+            .values()
+            .flatten()
+            .filter_map(|&idx| {
+                self.ctx
+                    .arena
+                    .get(idx)
+                    .map(|node| (node.pos, node.end, idx))
+            })
+            .min_by_key(|&(pos, _, _)| pos)
+        else {
+            return;
+        };
+        let (pos, end, idx) = first_export;
+        let (start, length) = self.normalized_anchor_span(idx, pos, end.saturating_sub(pos));
+        self.ctx.suggestion(
+            start,
+            length,
+            diagnostic_codes::FILE_IS_A_COMMONJS_MODULE_IT_MAY_BE_CONVERTED_TO_AN_ES_MODULE,
+            &[],
+        );
+    }
+
     pub(crate) fn check_commonjs_export_property_redeclarations(
         &mut self,
         statements: &[NodeIndex],
@@ -1,6 +1,7 @@
 use crate::query_boundaries::common::{callable_shape_for_type, function_shape_for_type};
 use crate::state::CheckerState;
 use rustc_hash::FxHashMap;
+use std::sync::Arc;
 use tsz_binder::symbol_flags;
 use tsz_parser::parser::NodeIndex;
 use tsz_parser::parser::syntax_kind_ext;
@@ -941,15 +942,62 @@ impl<'a> CheckerState<'a> {
             FxHashMap::default();
         let mut ordered_names: Vec<String> = Vec::new();
 
-        // Collect statements to scan: top-level statements + IIFE body statements.
+        // The binder already recorded the top-level `exports.X` /
+        // `module.exports.X` assignments; only alias assignments and IIFE
+        // bodies still need a scan. Files bound without the table fall back to
+        // scanning every statement.
+        let binder_exports = self
+            .ctx
+            .get_binder_for_file(target_file_idx)
+            .map(|binder| Arc::clone(&binder.commonjs_exports))
+            .filter(|exports| !exports.is_empty());
+        let start_pos = match start_statement_ordinal {
+            Some(start) => match source_file.statements.nodes.get(start) {
+                Some(&stmt_idx) => target_arena.get(stmt_idx).map_or(u32::MAX, |n| n.pos),
+                None => u32::MAX,
+            },
+            None => 0,
+        };
+        let mut assignments: Vec<(u32, String, NodeIndex, Option<String>)> = Vec::new();
+        if let Some(binder_exports) = &binder_exports {
+            for (name, exprs) in binder_exports.iter() {
+                if name == tsz_binder::internal_symbol_name::EXPORT_EQUALS {
+                    continue;
+                }
+                for &expr_idx in exprs {
+                    let Some(expr_node) = target_arena.get(expr_idx) else {
+                        continue;
+                    };
+                    let Some(binary) = target_arena.get_binary_expr(expr_node) else {
+                        continue;
+                    };
+                    if expr_node.pos < start_pos {
+                        continue;
+                    }
+                    let expando_root = target_arena
+                        .get(binary.left)
+                        .and_then(|left| target_arena.get_access_expr(left))
+                        .and_then(|access| target_arena.get_identifier_at(access.expression))
+                        .map_or_else(
+                            || format!("module.exports.{name}"),
+                            |_| format!("exports.{name}"),
+                        );
+                    let rhs_pos = target_arena
+                        .get(binary.right)
+                        .map_or(expr_node.end, |n| n.pos);
+                    assignments.push((rhs_pos, name.clone(), binary.right, Some(expando_root)));
+                }
+            }
+        }
+
         // In CommonJS files, `exports.X = value` inside IIFEs like `(function() { ... })()`
         // are valid export declarations (tsc recognizes them regardless of scope).
-        let mut all_stmts: Vec<NodeIndex> = Vec::new();
+        let mut scan_stmts: Vec<(NodeIndex, bool)> = Vec::new();
         for (stmt_ordinal, &stmt_idx) in source_file.statements.nodes.iter().enumerate() {
             if start_statement_ordinal.is_some_and(|start| stmt_ordinal < start) {
                 continue;
             }
-            all_stmts.push(stmt_idx);
+            scan_stmts.push((stmt_idx, binder_exports.is_some()));
             // Check if this statement is an IIFE and extract its body statements
             if let Some(stmt_node) = target_arena.get(stmt_idx)
                 && stmt_node.kind == syntax_kind_ext::EXPRESSION_STATEMENT
@@ -957,12 +1005,12 @@ impl<'a> CheckerState<'a> {
                 && let Some(iife_stmts) =
                     Self::get_iife_body_statements(&target_arena, stmt.expression)
             {
-                all_stmts.extend_from_slice(iife_stmts);
+                scan_stmts.extend(iife_stmts.iter().map(|&idx| (idx, false)));
             }
         }
 
-        for stmt_idx in &all_stmts {
-            let Some(stmt_node) = target_arena.get(*stmt_idx) else {
+        for (stmt_idx, aliases_only) in scan_stmts {
+            let Some(stmt_node) = target_arena.get(stmt_idx) else {
                 continue;
             };
             if stmt_node.kind != syntax_kind_ext::EXPRESSION_STATEMENT {
@@ -977,9 +1025,29 @@ impl<'a> CheckerState<'a> {
                 &mut pending_props,
                 &mut ordered_names,
                 &export_aliases,
+                aliases_only,
             );
         }
 
+        for name_text in ordered_names.drain(..) {
+            for (rhs_expr, expando_root) in pending_props.remove(&name_text).unwrap_or_default() {
+                let rhs_pos = target_arena.get(rhs_expr).map_or(u32::MAX, |n| n.pos);
+                assignments.push((rhs_pos, name_text.clone(), rhs_expr, expando_root));
+            }
+        }
+
+        // Regroup by name in source order so later assignments win.
+        assignments.sort_by_key(|&(pos, ..)| pos);
+        for (_, name_text, rhs_expr, expando_root) in assignments {
+            if !pending_props.contains_key(&name_text) {
+                ordered_names.push(name_text.clone());
+            }
+            pending_props
+                .entry(name_text)
+                .or_default()
+                .push((rhs_expr, expando_root));
+        }
+
         for name_text in ordered_names {
             let name_atom = self.ctx.types.intern_string(&name_text);
             let Some(assignments) = pending_props.remove(&name_text) else {
@@ -1233,7 +1301,7 @@ impl<'a> CheckerState<'a> {
                 .iter()
                 .find_map(|(_, sym_id)| self.ctx.resolve_symbol_file_index(*sym_id));
 
-            let mut export_equals_type = exports_table.get("export=").map(|export_equals_sym| {
+            let mut export_equals_type = exports_table.export_equals().map(|export_equals_sym| {
                 // When `export = C.B` resolves to a type-only symbol (e.g., `interface B` from a
                 // merged class+namespace), the binder puts the namespace export in the exports
                 // table. The actual runtime VALUE lives in the parent's members table. Substitute
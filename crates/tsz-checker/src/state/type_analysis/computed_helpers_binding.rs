@@ -86,7 +86,7 @@ impl<'a> CheckerState<'a> {
         fallback: &str,
     ) -> String {
         exports_table
-            .get("export=")
+            .export_equals()
             .or_else(|| self.resolve_cross_file_export(fallback, "export="))
             .and_then(|export_eq_sym| self.namespace_display_module_name_for_symbol(export_eq_sym))
             .unwrap_or_else(|| self.imported_namespace_display_module_name(fallback))
@@ -48,7 +48,7 @@ impl<'a> CheckerState<'a> {
         use crate::query_boundaries::common::PropertyAccessResult;
 
         let exports_table = self.resolve_effective_module_exports(module_name)?;
-        let export_equals_sym = exports_table.get("export=")?;
+        let export_equals_sym = exports_table.export_equals()?;
 
         let export_type = self.get_type_of_symbol(export_equals_sym);
         if export_type == tsz_solver::TypeId::ERROR || export_type == tsz_solver::TypeId::ANY {
@@ -428,7 +428,7 @@ fn resolve_import_with_reexports_for_attribution_no_cache(
             return Some(sym_id);
         }
         if export_name == "default"
-            && let Some(sym_id) = module_table.get("export=")
+            && let Some(sym_id) = module_table.export_equals()
         {
             return Some(sym_id);
         }
@@ -318,6 +318,7 @@ impl<'a> CheckerState<'a> {
         let mut formatter =
             tsz_solver::TypeFormatter::with_symbols(self.ctx.types, &self.ctx.binder.symbols)
                 .with_diagnostic_mode()
+                .with_no_truncation(self.ctx.compiler_options.no_error_truncation)
                 .with_strict_null_checks(self.ctx.compiler_options.strict_null_checks)
                 .with_exact_optional_property_types(
                     self.ctx.compiler_options.exact_optional_property_types,
@@ -384,6 +385,7 @@ impl<'a> CheckerState<'a> {
                     &self.ctx.binder.symbols,
                 )
                 .with_diagnostic_mode()
+                .with_no_truncation(self.ctx.compiler_options.no_error_truncation)
                 .with_strict_null_checks(self.ctx.compiler_options.strict_null_checks)
                 .with_exact_optional_property_types(
                     self.ctx.compiler_options.exact_optional_property_types,
@@ -438,6 +440,7 @@ impl<'a> CheckerState<'a> {
                     &self.ctx.binder.symbols,
                 )
                 .with_diagnostic_mode()
+                .with_no_truncation(self.ctx.compiler_options.no_error_truncation)
                 .with_strict_null_checks(self.ctx.compiler_options.strict_null_checks)
                 .with_exact_optional_property_types(
                     self.ctx.compiler_options.exact_optional_property_types,
@@ -471,6 +474,7 @@ impl<'a> CheckerState<'a> {
                     &self.ctx.binder.symbols,
                 )
                 .with_diagnostic_mode()
+                .with_no_truncation(self.ctx.compiler_options.no_error_truncation)
                 .with_strict_null_checks(self.ctx.compiler_options.strict_null_checks)
                 .with_exact_optional_property_types(
                     self.ctx.compiler_options.exact_optional_property_types,
@@ -566,6 +570,7 @@ impl<'a> CheckerState<'a> {
             .ctx
             .create_type_formatter()
             .with_diagnostic_mode()
+            .with_no_truncation(self.ctx.compiler_options.no_error_truncation)
             .with_strict_null_checks(self.ctx.compiler_options.strict_null_checks);
         formatter.format(type_id).into_owned()
     }
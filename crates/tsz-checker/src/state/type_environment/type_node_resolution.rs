@@ -785,8 +785,10 @@ impl<'a> CheckerState<'a> {
                 return false;
             }
 
-            if symbol.has_any_flags(symbol_flags::BLOCK_SCOPED_VARIABLE)
-                && !symbol.has_any_flags(symbol_flags::FUNCTION_SCOPED_VARIABLE)
+            if !self
+                .ctx
+                .binder
+                .is_global_this_member(self.ctx.arena, sym_id)
             {
                 return self.resolve_lib_global_var_symbol(name).is_some();
             }
@@ -970,10 +972,13 @@ impl<'a> CheckerState<'a> {
                     return TypeId::ERROR;
                 }
                 // In TypeScript, `typeof globalThis` only exposes `var`-declared
-                // globals (FUNCTION_SCOPED_VARIABLE) and function/class declarations.
-                // Block-scoped variables (let/const) are NOT properties of globalThis.
-                if symbol.has_any_flags(symbol_flags::BLOCK_SCOPED_VARIABLE)
-                    && !symbol.has_any_flags(symbol_flags::FUNCTION_SCOPED_VARIABLE)
+                // globals, functions and namespaces. Block-scoped declarations
+                // (let/const, classes, enums) and module-local declarations are
+                // NOT properties of globalThis.
+                if !self
+                    .ctx
+                    .binder
+                    .is_global_this_member(self.ctx.arena, sym_id)
                 {
                     // Before erroring, check if a lib `var` declaration exists.
                     // E.g. `const Symbol = globalThis.Symbol` — the local const shadows
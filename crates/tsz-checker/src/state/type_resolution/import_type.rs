@@ -237,7 +237,7 @@ impl<'a> CheckerState<'a> {
         use crate::query_boundaries::common::PropertyAccessResult;
 
         let exports_table = self.resolve_effective_module_exports(module_specifier)?;
-        let export_equals_sym = exports_table.get("export=")?;
+        let export_equals_sym = exports_table.export_equals()?;
         let export_type = self.get_type_of_symbol(export_equals_sym);
         if export_type == TypeId::ERROR || export_type == TypeId::ANY {
             return None;
@@ -781,7 +781,7 @@ impl<'a> CheckerState<'a> {
                 .binder
                 .module_exports
                 .get(&module_name)
-                .and_then(|exports| exports.get("export="))
+                .and_then(|exports| exports.export_equals())
                 .or_else(|| {
                     self.ctx
                         .global_module_exports_index
@@ -808,7 +808,7 @@ impl<'a> CheckerState<'a> {
                     binder
                         .module_exports
                         .get(file_name)
-                        .and_then(|exports| exports.get("export="))
+                        .and_then(|exports| exports.export_equals())
                 });
             let has_export_equals =
                 ambient_export_equals_sym.is_some() || file_export_equals.is_some();
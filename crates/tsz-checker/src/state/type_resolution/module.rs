@@ -386,17 +386,9 @@ impl<'a> CheckerState<'a> {
 
         // Find the export= symbol in the module
         let export_equals_sym = {
-            let mut found = None;
-            for candidate in module_specifier_candidates(module_name) {
-                if let Some(exports) = self
-                    .ctx
-                    .module_exports_for_module(self.ctx.binder, &candidate)
-                    && let Some(sym_id) = exports.get("export=")
-                {
-                    found = Some(sym_id);
-                    break;
-                }
-            }
+            let mut found = module_specifier_candidates(module_name)
+                .iter()
+                .find_map(|candidate| self.ctx.binder.module_export_equals(candidate));
             if found.is_none()
                 && let Some(all_binders) = &self.ctx.all_binders
             {
@@ -404,7 +396,7 @@ impl<'a> CheckerState<'a> {
                     for candidate in module_specifier_candidates(module_name) {
                         if let Some(exports) =
                             self.ctx.module_exports_for_module(binder, &candidate)
-                            && let Some(sym_id) = exports.get("export=")
+                            && let Some(sym_id) = exports.export_equals()
                         {
                             found = Some(sym_id);
                             break;
@@ -747,7 +739,7 @@ impl<'a> CheckerState<'a> {
         // been copied from the target's static exports (e.g. `static default: "foo"`).
         // Check `export =` first for "default" lookups.
         if export_name == "default"
-            && let Some(export_equals_sym_id) = exports_table.get("export=")
+            && let Some(export_equals_sym_id) = exports_table.export_equals()
             && binder.get_symbol(export_equals_sym_id).is_some()
         {
             return Some(export_equals_sym_id);
@@ -759,7 +751,7 @@ impl<'a> CheckerState<'a> {
             return Some(sym_id);
         }
 
-        let export_equals_sym_id = exports_table.get("export=")?;
+        let export_equals_sym_id = exports_table.export_equals()?;
         let export_equals_symbol = binder.get_symbol(export_equals_sym_id)?;
 
         // For non-"default" exports, the `export =` target's members are
@@ -906,7 +898,7 @@ impl<'a> CheckerState<'a> {
         if let Some(exports) = direct_exports {
             let mut combined = exports.clone();
             self.merge_export_equals_members(target_binder, exports, &mut combined);
-            if let Some(export_equals_sym_id) = exports.get("export=")
+            if let Some(export_equals_sym_id) = exports.export_equals()
                 && let Some(export_equals_symbol) = target_binder.get_symbol(export_equals_sym_id)
             {
                 let _ = self.merge_export_equals_import_type_members(
@@ -1203,7 +1195,7 @@ impl<'a> CheckerState<'a> {
             {
                 let mut combined = exports.clone();
                 self.merge_export_equals_members(self.ctx.binder, exports, &mut combined);
-                if let Some(export_equals_sym_id) = exports.get("export=")
+                if let Some(export_equals_sym_id) = exports.export_equals()
                     && let Some(export_equals_symbol) =
                         self.ctx.binder.get_symbol(export_equals_sym_id)
                 {
@@ -1234,7 +1226,7 @@ impl<'a> CheckerState<'a> {
                 {
                     let mut combined = exports.clone();
                     self.merge_export_equals_members(binder, exports, &mut combined);
-                    if let Some(export_equals_sym_id) = exports.get("export=")
+                    if let Some(export_equals_sym_id) = exports.export_equals()
                         && let Some(export_equals_symbol) = binder.get_symbol(export_equals_sym_id)
                     {
                         let _ = self.merge_export_equals_import_type_members(
@@ -1252,7 +1244,7 @@ impl<'a> CheckerState<'a> {
                 {
                     let mut combined = exports.clone();
                     self.merge_export_equals_members(binder, exports, &mut combined);
-                    if let Some(export_equals_sym_id) = exports.get("export=")
+                    if let Some(export_equals_sym_id) = exports.export_equals()
                         && let Some(export_equals_symbol) = binder.get_symbol(export_equals_sym_id)
                     {
                         let _ = self.merge_export_equals_import_type_members(
@@ -1274,7 +1266,7 @@ impl<'a> CheckerState<'a> {
         exports: &tsz_binder::SymbolTable,
         combined: &mut tsz_binder::SymbolTable,
     ) {
-        let Some(export_equals_sym_id) = exports.get("export=") else {
+        let Some(export_equals_sym_id) = exports.export_equals() else {
             return;
         };
         let Some(export_equals_symbol) = binder.get_symbol(export_equals_sym_id) else {
@@ -2015,16 +2007,15 @@ impl<'a> CheckerState<'a> {
         if self
             .ctx
             .binder
-            .module_exports
-            .get(module_specifier)
-            .is_some_and(|exports| exports.has("export="))
+            .module_export_equals(module_specifier)
+            .is_some()
         {
             return true;
         }
 
         if self
             .resolve_cross_file_namespace_exports(module_specifier)
-            .is_some_and(|exports| exports.has("export="))
+            .is_some_and(|exports| exports.has_export_equals())
         {
             return true;
         }
@@ -2291,7 +2282,7 @@ impl<'a> CheckerState<'a> {
         let resolve_from_exports = |exports: &tsz_binder::SymbolTable,
                                     visited_aliases: &mut AliasCycleTracker|
          -> Option<tsz_binder::SymbolId> {
-            let export_equals_sym = exports.get("export=")?;
+            let export_equals_sym = exports.export_equals()?;
             resolve_from_export_equals_sym(export_equals_sym, visited_aliases)
         };
 
@@ -2484,7 +2475,7 @@ impl<'a> CheckerState<'a> {
         let has_default =
             if let Some(exports_table) = self.resolve_effective_module_exports(module_specifier) {
                 exports_table.has("default")
-                    || exports_table.has("export=")
+                    || exports_table.has_export_equals()
                     || self.module_uses_module_exports_interop(
                         module_specifier,
                         Some(self.current_file_emit_resolution_mode()),
@@ -2516,6 +2507,10 @@ impl<'a> CheckerState<'a> {
                 message,
                 diagnostic_codes::HAS_NO_EXPORTED_MEMBER_NAMED_DID_YOU_MEAN,
             );
+            self.attach_spelling_suggestion(
+                diagnostic_codes::HAS_NO_EXPORTED_MEMBER_NAMED_DID_YOU_MEAN,
+                &suggestion,
+            );
         } else if has_default && member_name != "default" {
             let message = format_message(
                 diagnostic_messages::MODULE_HAS_NO_EXPORTED_MEMBER_DID_YOU_MEAN_TO_USE_IMPORT_FROM_INSTEAD,
@@ -129,7 +129,7 @@ impl<'a> CheckerState<'a> {
         }
         if export_name == "default"
             && !default_skips_export_equals
-            && let Some(sym_id) = target_binder.file_locals.get("export=")
+            && let Some(sym_id) = target_binder.file_locals.export_equals()
         {
             return Some((sym_id, file_idx));
         }
@@ -50,12 +50,12 @@ impl<'a> CheckerState<'a> {
         use tsz_binder::symbol_flags;
         use tsz_parser::parser::node_flags;
 
-        // Skip block-scoped variables (let/const) and parameters — only var triggers TS2481
+        // Skip block-scoped variables (let/const/using) and parameters — only var triggers TS2481
         if let Some(ext) = self.ctx.arena.get_extended(decl_idx)
             && let Some(parent_node) = self.ctx.arena.get(ext.parent)
         {
             let parent_flags = parent_node.flags as u32;
-            if node_flags::is_let_or_const(parent_flags) {
+            if node_flags::is_block_scoped(parent_flags) {
                 return;
             }
         } else {
@@ -383,7 +383,7 @@ impl<'a> CheckerState<'a> {
             .is_some_and(|parent| {
                 let flags = parent.flags as u32;
                 parent.kind == tsz_parser::parser::syntax_kind_ext::VARIABLE_DECLARATION_LIST
-                    && !node_flags::is_let_or_const(flags)
+                    && !node_flags::is_block_scoped(flags)
             });
         if !is_var {
             return false;
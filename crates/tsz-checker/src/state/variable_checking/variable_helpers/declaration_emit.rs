@@ -653,7 +653,7 @@ impl<'a> CheckerState<'a> {
             let Some(exports) = self.ctx.module_exports_for_module(target_binder, key) else {
                 return false;
             };
-            let Some(export_equals_sym_id) = exports.get("export=") else {
+            let Some(export_equals_sym_id) = exports.export_equals() else {
                 return false;
             };
 
@@ -111,6 +111,9 @@ pub trait StatementCheckCallbacks {
     /// Check an export declaration.
     fn check_export_declaration(&mut self, export_idx: NodeIndex);
 
+    /// Check an `export as namespace` declaration (TS1314/TS1315/TS1316).
+    fn check_namespace_export_declaration(&mut self, stmt_idx: NodeIndex);
+
     /// Check a type alias declaration.
     fn check_type_alias_declaration(&mut self, type_alias_idx: NodeIndex);
 
@@ -1013,6 +1016,9 @@ impl StatementChecker {
                 state.check_grammar_module_element_context(stmt_idx);
                 state.check_export_declaration(stmt_idx);
             }
+            syntax_kind_ext::NAMESPACE_EXPORT_DECLARATION => {
+                state.check_namespace_export_declaration(stmt_idx);
+            }
             syntax_kind_ext::TYPE_ALIAS_DECLARATION => {
                 state.check_type_alias_declaration(stmt_idx);
             }
@@ -997,6 +997,30 @@ impl<'a> CheckerState<'a> {
         false
     }
 
+    /// Returns true if `sym_id` has an explicit `arguments` declaration (a
+    /// parameter or `var`/`let`/`const`) in the same function as `idx`.
+    ///
+    /// The binder seeds every non-arrow function scope with an implicit
+    /// `arguments` symbol that has no declaration node; explicit declarations
+    /// merge into it. Only those explicit declarations shadow the built-in
+    /// `IArguments` object.
+    pub(crate) fn declares_local_arguments(
+        &self,
+        sym_id: tsz_binder::SymbolId,
+        idx: NodeIndex,
+    ) -> bool {
+        let Some(symbol) = self.ctx.binder.get_symbol(sym_id) else {
+            return false;
+        };
+        let Some(current_fn) = self.find_enclosing_function(idx) else {
+            return false;
+        };
+        symbol
+            .declarations
+            .iter()
+            .any(|&decl| decl.is_some() && self.find_enclosing_function(decl) == Some(current_fn))
+    }
+
     /// Returns true if there is any enclosing regular (non-arrow) function.
     /// Unlike `is_in_regular_function_body`, this walks through arrow functions
     /// since they are transparent for `arguments` capture. Returns false only if
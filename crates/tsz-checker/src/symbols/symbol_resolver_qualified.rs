@@ -1296,7 +1296,7 @@ impl<'a> CheckerState<'a> {
             return Some(sym_id);
         }
 
-        let export_equals_sym = exports_table.get("export=")?;
+        let export_equals_sym = exports_table.export_equals()?;
         let mut candidate_symbol_ids = vec![export_equals_sym];
         if can_resolve_aliases {
             let resolved_export_equals = self
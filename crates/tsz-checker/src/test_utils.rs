@@ -23,6 +23,22 @@ pub fn check_source(source: &str, file_name: &str, options: CheckerOptions) -> V
     check_source_with_file_is_esm(source, file_name, options, None)
 }
 
+/// Parse, bind, and type-check a source string with suggestion reporting on,
+/// returning the suggestion-category diagnostics (TS80xxx) only.
+pub fn check_source_suggestions(
+    source: &str,
+    file_name: &str,
+    options: CheckerOptions,
+) -> Vec<Diagnostic> {
+    let options = CheckerOptions {
+        report_suggestions: true,
+        ..options
+    };
+    with_checked_source(source, file_name, options, None, |checker| {
+        checker.ctx.suggestion_diagnostics.clone()
+    })
+}
+
 /// Parse, bind, and type-check a TypeScript source string, returning every
 /// recovery fallback site recorded by [`crate::context::CheckerContext::recover_any`]
 /// during the check. Each entry is `(node_index, reason)`, sorted by node index.
@@ -147,7 +147,54 @@ impl<'a> CheckerState<'a> {
 
         // Mirror tsc's `Awaited<T>`: distribute over top-level unions and
         // recursively unwrap thenables on each branch, then rejoin.
-        self.compute_awaited_type(expr_type, 0)
+        let awaited_type = self.compute_awaited_type(expr_type, 0);
+        self.suggest_await_has_no_effect(idx, expr_type, awaited_type);
+        awaited_type
+    }
+
+    /// TS80007: `'await' has no effect on the type of this expression.`
+    ///
+    /// Mirrors tsc's `awaitedType === operandType` check. Generic operands and
+    /// operands with a `then` member are skipped: tsc gives those an
+    /// `Awaited<T>` type, while our eager unwrapping may leave them unchanged.
+    fn suggest_await_has_no_effect(
+        &mut self,
+        idx: NodeIndex,
+        expr_type: TypeId,
+        awaited_type: TypeId,
+    ) {
+        use crate::diagnostics::diagnostic_codes;
+        use crate::query_boundaries::property_access::resolve_property_access;
+
+        if !self.ctx.compiler_options.report_suggestions
+            || awaited_type != expr_type
+            || matches!(expr_type, TypeId::ANY | TypeId::UNKNOWN | TypeId::ERROR)
+            || self.type_contains_error(expr_type)
+            || query::contains_type_parameters(self.ctx.types, expr_type)
+        {
+            return;
+        }
+        let members =
+            query::union_members(self.ctx.types, expr_type).unwrap_or_else(|| vec![expr_type]);
+        for member in members {
+            let resolved = self.evaluate_type_with_env(member);
+            if resolve_property_access(self.ctx.types, resolved, "then")
+                .success_type()
+                .is_some()
+            {
+                return;
+            }
+        }
+        if let Some((start, end)) = self.get_node_span(idx) {
+            let (start, length) =
+                self.normalized_anchor_span(idx, start, end.saturating_sub(start));
+            self.ctx.suggestion(
+                start,
+                length,
+                diagnostic_codes::AWAIT_HAS_NO_EFFECT_ON_THE_TYPE_OF_THIS_EXPRESSION,
+                &[],
+            );
+        }
     }
 
     /// Fold `Awaited<X>` eagerly when X reaches this point still wrapped in an
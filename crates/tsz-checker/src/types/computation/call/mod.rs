@@ -424,7 +424,7 @@ impl<'a> CheckerState<'a> {
         ) else {
             return false;
         };
-        let Some(export_equals_sym_id) = exports.get("export=") else {
+        let Some(export_equals_sym_id) = exports.export_equals() else {
             return false;
         };
         let Some(target_binder) = self.ctx.get_binder_for_file(target_file_idx) else {
@@ -210,7 +210,21 @@ impl<'a> CheckerState<'a> {
                 )
             };
             let message = format_message(msg_template, &[name]);
-            self.error_at_node(idx, &message, code);
+            // tsc points back at the declaration with TS2728 "'x' is declared here."
+            let related = self
+                .ctx
+                .binder
+                .symbols
+                .get(sym_id)
+                .and_then(|sym| {
+                    if sym.value_declaration.is_some() {
+                        Some(sym.value_declaration)
+                    } else {
+                        sym.declarations.iter().copied().find(|decl| decl.is_some())
+                    }
+                })
+                .and_then(|decl_idx| self.declared_here_related(decl_idx, name));
+            self.error_at_node_with_related(idx, &message, code, related.into_iter().collect());
 
             // TypeScript also reports TS2454 ("used before being assigned") as a
             // companion to TDZ errors in strict-null mode, but ONLY for pure
@@ -218,7 +218,7 @@ impl<'a> CheckerState<'a> {
         let import_decl = self.ctx.arena.get_import_decl(decl_node)?;
         let module_specifier = self.get_require_module_specifier(import_decl.module_specifier)?;
         let exports = self.resolve_effective_module_exports(&module_specifier)?;
-        let export_equals_sym = exports.get("export=")?;
+        let export_equals_sym = exports.export_equals()?;
         let resolved_export_equals_sym = self
             .ctx
             .binder
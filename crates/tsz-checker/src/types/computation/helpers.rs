@@ -1210,19 +1210,9 @@ impl<'a> CheckerState<'a> {
                 // Check if this is "arguments" in a function body with a local declaration
                 if let Some(ident) = self.ctx.arena.get_identifier(node) {
                     if ident.escaped_text == "arguments" && self.is_in_regular_function_body(idx) {
-                        // Check if the declaration is local to the current function
-                        if let Some(symbol) = self.ctx.binder.get_symbol(sym_id)
-                            && !symbol.declarations.is_empty()
-                        {
-                            let decl_node = symbol.declarations[0];
-                            if let Some(current_fn) = self.find_enclosing_function(idx)
-                                && let Some(decl_fn) = self.find_enclosing_function(decl_node)
-                                && current_fn == decl_fn
-                            {
-                                // Local "arguments" declaration - use it
-                                let declared_type = self.get_type_of_symbol(sym_id);
-                                return declared_type;
-                            }
+                        // Local "arguments" declaration - use it
+                        if self.declares_local_arguments(sym_id, idx) {
+                            return self.get_type_of_symbol(sym_id);
                         }
                         // Symbol found but not local - fall through to IArguments check below
                     } else {
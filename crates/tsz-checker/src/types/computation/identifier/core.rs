@@ -87,7 +87,7 @@ impl<'a> CheckerState<'a> {
                     Some(self.ctx.current_file_idx),
                 )
                 .or_else(|| self.resolve_effective_module_exports(&module_specifier))
-                && let Some(export_equals_sym) = exports.get("export=")
+                && let Some(export_equals_sym) = exports.export_equals()
             {
                 let mut candidates = Vec::new();
 
@@ -394,27 +394,16 @@ impl<'a> CheckerState<'a> {
             // If so, fall through to normal resolution.
             let has_local_shadow = if self.is_in_regular_function_body(idx) {
                 if let Some(sym_id) = self.resolve_identifier_symbol(idx) {
-                    if let Some(symbol) = self.ctx.binder.get_symbol(sym_id)
-                        && !symbol.declarations.is_empty()
-                    {
-                        let decl_node = symbol.declarations[0];
-                        if let Some(current_fn) = self.find_enclosing_function(idx)
-                            && let Some(decl_fn) = self.find_enclosing_function(decl_node)
-                            && current_fn == decl_fn
-                        {
-                            trace!(
-                                name = name,
-                                idx = ?idx,
-                                sym_id = ?sym_id,
-                                "get_type_of_identifier: local 'arguments' variable shadows built-in IArguments"
-                            );
-                            true
-                        } else {
-                            false
-                        }
-                    } else {
-                        false
+                    let is_local = self.declares_local_arguments(sym_id, idx);
+                    if is_local {
+                        trace!(
+                            name = name,
+                            idx = ?idx,
+                            sym_id = ?sym_id,
+                            "get_type_of_identifier: local 'arguments' variable shadows built-in IArguments"
+                        );
                     }
+                    is_local
                 } else {
                     false
                 }
@@ -61,7 +61,7 @@ impl<'a> CheckerState<'a> {
         exports: &tsz_binder::SymbolTable,
         module_specifier: &str,
     ) -> Option<tsz_binder::SymbolId> {
-        if let Some(target_sym_id) = exports.get("export=") {
+        if let Some(target_sym_id) = exports.export_equals() {
             return Some(target_sym_id);
         }
         if self.current_file_uses_module_exports_require_interop(module_specifier) {
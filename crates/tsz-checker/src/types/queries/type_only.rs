@@ -149,7 +149,7 @@ impl<'a> CheckerState<'a> {
             if let Some(exports) = self
                 .ctx
                 .module_exports_for_module(self.ctx.binder, candidate)
-                && let Some(sym_id) = exports.get("export=")
+                && let Some(sym_id) = exports.export_equals()
             {
                 return Some((self.ctx.binder, sym_id));
             }
@@ -171,7 +171,7 @@ impl<'a> CheckerState<'a> {
             for binder in all_binders.iter() {
                 for candidate in candidates {
                     if let Some(exports) = self.ctx.module_exports_for_module(binder, candidate)
-                        && let Some(sym_id) = exports.get("export=")
+                        && let Some(sym_id) = exports.export_equals()
                     {
                         return Some((binder, sym_id));
                     }
@@ -1368,7 +1368,7 @@ impl<'a> CheckerState<'a> {
         let Some(exports) = self.ctx.module_exports_for_module(target_binder, file_name) else {
             return false;
         };
-        let Some(export_eq_sym_id) = exports.get("export=") else {
+        let Some(export_eq_sym_id) = exports.export_equals() else {
             return false;
         };
 
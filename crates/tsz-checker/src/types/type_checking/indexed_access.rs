@@ -72,8 +72,11 @@ impl<'a> CheckerState<'a> {
             return true;
         };
         self.ctx.binder.get_symbol(sym_id).is_some_and(|symbol| {
-            symbol.has_any_flags(tsz_binder::symbol_flags::BLOCK_SCOPED_VARIABLE)
-                && !symbol.has_any_flags(tsz_binder::symbol_flags::FUNCTION_SCOPED_VARIABLE)
+            symbol.has_any_flags(tsz_binder::symbol_flags::VALUE)
+                && !self
+                    .ctx
+                    .binder
+                    .is_global_this_member(self.ctx.arena, sym_id)
         })
     }
 
@@ -1183,23 +1183,18 @@ impl<'a, 'ctx> TypeNodeChecker<'a, 'ctx> {
     }
 
     fn global_this_surface_symbol(&self, name: &str) -> Option<tsz_binder::SymbolId> {
-        use tsz_binder::symbol_flags;
-
         if let Some(sym_id) = self.ctx.binder.file_locals.get(name)
-            && let Some(symbol) = self.ctx.binder.get_symbol(sym_id)
-            && symbol.has_any_flags(symbol_flags::VALUE)
-            && (!symbol.has_any_flags(symbol_flags::BLOCK_SCOPED_VARIABLE)
-                || symbol.has_any_flags(symbol_flags::FUNCTION_SCOPED_VARIABLE))
+            && self
+                .ctx
+                .binder
+                .is_global_this_member(self.ctx.arena, sym_id)
         {
             return Some(sym_id);
         }
 
         for lib_ctx in self.ctx.lib_contexts.iter() {
             if let Some(sym_id) = lib_ctx.binder.file_locals.get(name)
-                && let Some(symbol) = lib_ctx.binder.get_symbol(sym_id)
-                && symbol.has_any_flags(symbol_flags::VALUE)
-                && (!symbol.has_any_flags(symbol_flags::BLOCK_SCOPED_VARIABLE)
-                    || symbol.has_any_flags(symbol_flags::FUNCTION_SCOPED_VARIABLE))
+                && lib_ctx.binder.is_global_this_member(&lib_ctx.arena, sym_id)
             {
                 return Some(sym_id);
             }
@@ -0,0 +1,112 @@
+//! Tests for the implicit `arguments` object.
+//!
+//! Non-arrow functions get an implicit `arguments` binding typed as
+//! `IArguments`; arrow functions see the binding of the nearest enclosing
+//! non-arrow function, and outside any function `arguments` is unresolved
+//! (TS2304). An explicit parameter or `var arguments` shadows the implicit
+//! object, and in strict mode `arguments`/`eval` cannot be assigned or used
+//! as binding names (TS1100).
+
+use tsz_checker::test_utils::{
+    check_source_code_messages as get_diagnostics, check_with_options_code_messages,
+};
+use tsz_common::CheckerOptions;
+
+fn has_code(diags: &[(u32, String)], code: u32) -> bool {
+    diags.iter().any(|(c, _)| *c == code)
+}
+
+#[test]
+fn arguments_resolves_inside_regular_function() {
+    let diags = get_diagnostics("function f() { return arguments.length; }");
+    assert!(
+        !has_code(&diags, 2304),
+        "`arguments` must resolve inside a regular function; got: {diags:#?}"
+    );
+}
+
+#[test]
+fn arrow_function_captures_arguments_from_enclosing_function() {
+    let diags = get_diagnostics("function f() { const g = () => arguments; return g; }");
+    assert!(
+        !has_code(&diags, 2304),
+        "arrow functions must see the enclosing function's `arguments`; got: {diags:#?}"
+    );
+}
+
+#[test]
+fn arguments_outside_any_function_is_unresolved() {
+    let diags = get_diagnostics("const a = arguments;");
+    assert!(
+        diags
+            .iter()
+            .any(|(code, msg)| *code == 2304 && msg.contains("'arguments'")),
+        "expected TS2304 for top-level `arguments`; got: {diags:#?}"
+    );
+}
+
+#[test]
+fn arguments_in_top_level_arrow_is_unresolved() {
+    let diags = get_diagnostics("const g = () => arguments;");
+    assert!(
+        diags
+            .iter()
+            .any(|(code, msg)| *code == 2304 && msg.contains("'arguments'")),
+        "arrow functions do not bind `arguments`; expected TS2304; got: {diags:#?}"
+    );
+}
+
+#[test]
+fn parameter_named_arguments_shadows_implicit_object() {
+    let diags = get_diagnostics("function f(arguments: number) { const s: string = arguments; }");
+    assert!(
+        has_code(&diags, 2322),
+        "a parameter named `arguments` must keep its declared type; got: {diags:#?}"
+    );
+}
+
+#[test]
+fn local_var_named_arguments_shadows_implicit_object() {
+    let diags = get_diagnostics("function f() { var arguments = 1; const s: string = arguments; }");
+    assert!(
+        has_code(&diags, 2322),
+        "a local `var arguments` must keep its declared type; got: {diags:#?}"
+    );
+}
+
+#[test]
+fn strict_mode_increment_of_arguments_emits_ts1100() {
+    let diags = get_diagnostics("\"use strict\";\nfunction f() { arguments++; }");
+    assert!(
+        diags
+            .iter()
+            .any(|(code, msg)| *code == 1100 && msg.contains("'arguments'")),
+        "expected TS1100 for `arguments++` in strict mode; got: {diags:#?}"
+    );
+}
+
+#[test]
+fn strict_mode_catch_variable_named_eval_emits_ts1100() {
+    let diags = get_diagnostics("\"use strict\";\ntry {} catch (eval) {}");
+    assert!(
+        diags
+            .iter()
+            .any(|(code, msg)| *code == 1100 && msg.contains("'eval'")),
+        "expected TS1100 for catch variable `eval` in strict mode; got: {diags:#?}"
+    );
+}
+
+#[test]
+fn sloppy_mode_parameter_named_eval_is_allowed() {
+    let diags = check_with_options_code_messages(
+        "function f(eval: number) { return eval; }",
+        CheckerOptions {
+            always_strict: false,
+            ..CheckerOptions::default()
+        },
+    );
+    assert!(
+        !has_code(&diags, 1100),
+        "TS1100 only applies in strict mode; got: {diags:#?}"
+    );
+}
@@ -214,6 +214,34 @@ fn test_ts2454_still_emitted_for_tdz_with_non_circular_annotation() {
     );
 }
 
+/// `using` bindings are lexical, so a read before the declaration is a TDZ
+/// violation just like `const`. They were previously bound as `var`s and
+/// escaped both TS2448 and the TS2454 companion.
+#[test]
+fn test_using_declaration_used_before_declaration_emits_ts2448() {
+    let source = r"
+        function test(make: () => any) {
+          res;
+          using res = make();
+        }
+    ";
+    let diags = diagnostics_with_options(
+        source,
+        CheckerOptions {
+            strict_null_checks: true,
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        count_code(
+            &diags,
+            diagnostic_codes::BLOCK_SCOPED_VARIABLE_USED_BEFORE_ITS_DECLARATION
+        ),
+        1,
+        "Expected TS2448 for `using` read before its declaration, got: {diags:?}"
+    );
+}
+
 /// Regression for `unionAndIntersectionInference1.ts`. When a property access
 /// receiver is a composite expression (e.g. a `CallExpression`) whose
 /// sub-expression is an uninitialized variable that fires TS2454, tsc
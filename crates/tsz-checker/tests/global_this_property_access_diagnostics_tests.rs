@@ -293,6 +293,71 @@ declare var window: Window & typeof globalThis;
     );
 }
 
+/// `typeof globalThis` only carries non-block-scoped globals: classes and
+/// enums are block-scoped like `let`/`const` and must report TS2339, while
+/// script-level `var`s and functions resolve.
+#[test]
+fn globalthis_excludes_block_scoped_classes_and_enums() {
+    let source = r#"
+class C {}
+enum E { A }
+var v = 1;
+function f() {}
+globalThis.C;
+globalThis.E;
+const n: number = globalThis.v;
+globalThis.f();
+"#;
+    let diags = check_with_no_implicit_any(source);
+    let ts2339: Vec<_> = diags.iter().filter(|diag| diag.code == 2339).collect();
+    assert_eq!(
+        ts2339.len(),
+        2,
+        "classes and enums are not globalThis members; got: {diags:#?}"
+    );
+    assert!(
+        ts2339
+            .iter()
+            .any(|diag| diag.message_text.contains("Property 'C'"))
+            && ts2339
+                .iter()
+                .any(|diag| diag.message_text.contains("Property 'E'")),
+        "TS2339 must name the class and the enum; got: {diags:#?}"
+    );
+}
+
+/// Top-level declarations of a module are module-local; only its
+/// `declare global` augmentations are visible through `globalThis`.
+#[test]
+fn globalthis_in_module_sees_global_augmentations_only() {
+    let source = r#"
+export {};
+var local = 1;
+declare global {
+    var shared: number;
+    function sharedFn(): void;
+}
+globalThis.local;
+const s: string = globalThis.shared;
+globalThis.sharedFn();
+"#;
+    let diags = check_with_no_implicit_any(source);
+    let ts2339: Vec<_> = diags.iter().filter(|diag| diag.code == 2339).collect();
+    assert_eq!(
+        ts2339.len(),
+        1,
+        "only the module-local var should be missing on globalThis; got: {diags:#?}"
+    );
+    assert!(
+        ts2339[0].message_text.contains("Property 'local'"),
+        "TS2339 must name the module-local var; got: {diags:#?}"
+    );
+    assert!(
+        count(&diags, 2322) >= 1,
+        "globalThis.shared should be typed from the augmentation; got: {diags:#?}"
+    );
+}
+
 fn check_with_no_implicit_any(source: &str) -> Vec<tsz_checker::diagnostics::Diagnostic> {
     use tsz_checker::context::CheckerOptions;
     tsz_checker::test_utils::check_source(
@@ -0,0 +1,95 @@
+//! Tests for related locations attached to checker diagnostics.
+//!
+//! Use-before-declaration errors (TS2448/TS2449/TS2450) point back at the
+//! declaration with TS2728 "'x' is declared here.", anchored at the
+//! declaration name. Object literal property mismatches point at the target
+//! property (TS6500), and calls with many failing overloads at the last
+//! overload (TS2771).
+
+use tsz_checker::test_utils::{check_source_diagnostics, diagnostics_with_code};
+
+fn declared_here_offset(source: &str, code: u32) -> u32 {
+    let diags = check_source_diagnostics(source);
+    let diag = diagnostics_with_code(&diags, code)
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| panic!("expected TS{code}; got: {diags:#?}"));
+    let related = diag
+        .related_information
+        .iter()
+        .find(|related| related.code == 2728)
+        .unwrap_or_else(|| panic!("expected a TS2728 related location; got: {diag:#?}"));
+    assert!(
+        related.is_related_location(),
+        "TS2728 must be classified as a related location"
+    );
+    related.start
+}
+
+#[test]
+fn block_scoped_variable_used_before_declaration_points_at_declaration() {
+    let source = "x;\nlet x = 1;\n";
+    let start = declared_here_offset(source, 2448);
+    assert_eq!(start, source.find("x = 1").expect("declaration") as u32);
+}
+
+#[test]
+fn class_used_before_declaration_points_at_class_name() {
+    let source = "new C();\nclass C {}\n";
+    let start = declared_here_offset(source, 2449);
+    assert_eq!(start, source.find("C {}").expect("declaration") as u32);
+}
+
+#[test]
+fn enum_used_before_declaration_points_at_enum_name() {
+    let source = "const a = E.A;\nenum E { A }\n";
+    let start = declared_here_offset(source, 2450);
+    assert_eq!(start, source.find("E { A }").expect("declaration") as u32);
+}
+
+#[test]
+fn object_literal_property_mismatch_points_at_target_property() {
+    let source = "interface Opts {\n    size: number;\n}\nconst o: Opts = { size: \"big\" };\n";
+    let diags = check_source_diagnostics(source);
+    let diag = diagnostics_with_code(&diags, 2322)
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| panic!("expected TS2322; got: {diags:#?}"));
+    let related = diag
+        .related_information
+        .iter()
+        .find(|related| related.code == 6500)
+        .unwrap_or_else(|| panic!("expected a TS6500 related location; got: {diag:#?}"));
+    assert_eq!(
+        related.start,
+        source.find("size:").expect("property") as u32
+    );
+    assert!(
+        related.message_text.contains("'size'") && related.message_text.contains("'Opts'"),
+        "unexpected message: {}",
+        related.message_text
+    );
+}
+
+#[test]
+fn call_with_many_failing_overloads_points_at_last_overload() {
+    let source = "declare function f(a: number): void;\n\
+declare function f(a: boolean): void;\n\
+declare function f(a: symbol): void;\n\
+declare function f(a: bigint): void;\n\
+f(\"x\");\n";
+    let diags = check_source_diagnostics(source);
+    let diag = diagnostics_with_code(&diags, 2769)
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| panic!("expected TS2769; got: {diags:#?}"));
+    let related = diag
+        .related_information
+        .iter()
+        .find(|related| related.code == 2771)
+        .unwrap_or_else(|| panic!("expected a TS2771 related location; got: {diag:#?}"));
+    assert_eq!(
+        related.start,
+        source.find("f(a: bigint)").expect("last overload") as u32
+    );
+}
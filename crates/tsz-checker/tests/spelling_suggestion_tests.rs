@@ -0,0 +1,39 @@
+//! "Did you mean" diagnostics carry their suggestion as data: the suggested
+//! name and the span of the misspelled name it replaces.
+
+use tsz_checker::test_utils::check_source_diagnostics;
+
+/// The suggested name and the source text it replaces, for the first `code`
+/// diagnostic.
+fn suggestion(source: &str, code: u32) -> (String, String) {
+    let diagnostics = check_source_diagnostics(source);
+    let diagnostic = diagnostics
+        .iter()
+        .find(|diag| diag.code == code)
+        .unwrap_or_else(|| panic!("expected TS{code}, got {diagnostics:?}"));
+    let suggestion = diagnostic
+        .spelling_suggestion
+        .as_ref()
+        .expect("spelling suggestion recorded");
+    let start = suggestion.start as usize;
+    let replaced = &source[start..start + suggestion.length as usize];
+    (suggestion.name.clone(), replaced.to_string())
+}
+
+#[test]
+fn cannot_find_name_suggestion_replaces_the_identifier() {
+    let source = "const value = 1;\nvalu;\n";
+    assert_eq!(
+        suggestion(source, 2552),
+        ("value".to_string(), "valu".to_string())
+    );
+}
+
+#[test]
+fn missing_property_suggestion_replaces_the_property_name() {
+    let source = "const obj = { value: 1 };\nobj.valu;\n";
+    assert_eq!(
+        suggestion(source, 2551),
+        ("value".to_string(), "valu".to_string())
+    );
+}
@@ -0,0 +1,103 @@
+use tsz_checker::context::CheckerOptions;
+use tsz_checker::diagnostics::DiagnosticCategory;
+use tsz_checker::test_utils::{check_source, check_source_suggestions};
+
+const AWAIT_HAS_NO_EFFECT: u32 = 80007;
+const CONVERT_TO_ES_MODULE: u32 = 80001;
+
+fn suggestion_codes(source: &str, file_name: &str) -> Vec<u32> {
+    check_source_suggestions(source, file_name, CheckerOptions::default())
+        .into_iter()
+        .map(|diag| diag.code)
+        .collect()
+}
+
+#[test]
+fn await_on_non_promise_value_is_a_suggestion() {
+    let source = r#"
+async function f(n: number) {
+    const x = await n;
+    return x;
+}
+"#;
+    let suggestions = check_source_suggestions(source, "test.ts", CheckerOptions::default());
+    assert_eq!(
+        suggestions
+            .iter()
+            .map(|diag| (diag.code, diag.category))
+            .collect::<Vec<_>>(),
+        vec![(AWAIT_HAS_NO_EFFECT, DiagnosticCategory::Suggestion)]
+    );
+    let await_start = source.find("await n").unwrap() as u32;
+    assert_eq!(suggestions[0].start, await_start);
+    assert_eq!(suggestions[0].length, "await n".len() as u32);
+}
+
+#[test]
+fn suggestions_never_reach_the_error_stream() {
+    let source = r#"
+async function f(n: number) {
+    return await n;
+}
+"#;
+    assert!(
+        !check_source(
+            source,
+            "test.ts",
+            CheckerOptions {
+                report_suggestions: true,
+                ..CheckerOptions::default()
+            },
+        )
+        .iter()
+        .any(|diag| diag.code == AWAIT_HAS_NO_EFFECT)
+    );
+}
+
+#[test]
+fn await_on_generic_or_any_operand_is_not_suggested() {
+    let codes = suggestion_codes(
+        r#"
+async function f<T>(value: T, loose: any) {
+    await value;
+    await loose;
+}
+"#,
+        "test.ts",
+    );
+    assert!(codes.is_empty(), "unexpected suggestions: {codes:?}");
+}
+
+#[test]
+fn await_on_thenable_is_not_suggested() {
+    let codes = suggestion_codes(
+        r#"
+declare const thenable: { then(onfulfilled: (value: number) => void): void };
+async function f() {
+    await thenable;
+}
+"#,
+        "test.ts",
+    );
+    assert!(!codes.contains(&AWAIT_HAS_NO_EFFECT), "got: {codes:?}");
+}
+
+#[test]
+fn commonjs_js_file_suggests_es_module_conversion() {
+    let source = "const helper = 1;\nmodule.exports.helper = helper;\n";
+    let suggestions = check_source_suggestions(source, "test.js", CheckerOptions::default());
+    assert_eq!(
+        suggestions.iter().map(|diag| diag.code).collect::<Vec<_>>(),
+        vec![CONVERT_TO_ES_MODULE]
+    );
+    assert_eq!(
+        suggestions[0].start,
+        source.find("module.exports").unwrap() as u32
+    );
+}
+
+#[test]
+fn es_module_js_file_is_not_offered_conversion() {
+    let codes = suggestion_codes("export const a = 1;\nexports.b = 2;\n", "test.js");
+    assert!(!codes.contains(&CONVERT_TO_ES_MODULE), "got: {codes:?}");
+}
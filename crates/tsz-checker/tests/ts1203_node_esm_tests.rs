@@ -189,6 +189,46 @@ fn export_as_namespace_in_declaration_file_does_not_emit_ts1315() {
     );
 }
 
+#[test]
+fn export_as_namespace_in_script_declaration_file_emits_ts1314() {
+    let codes = get_diagnostics_with_file_name(
+        "export as namespace MyLib;\n",
+        ModuleKind::CommonJS,
+        None,
+        "test.d.ts",
+    )
+    .into_iter()
+    .map(|d| d.code)
+    .collect::<Vec<_>>();
+    assert!(
+        codes.contains(&1314),
+        "TS1314 should fire for `export as namespace` in a non-module file, got: {codes:?}"
+    );
+    assert!(
+        !codes.contains(&1315),
+        "TS1315 should not fire in a declaration file, got: {codes:?}"
+    );
+}
+
+#[test]
+fn export_as_namespace_inside_ambient_module_emits_ts1316() {
+    let source = r#"
+declare module "lib" {
+    export const x: number;
+    export as namespace MyLib;
+}
+export {};
+"#;
+    let codes = get_diagnostics_with_file_name(source, ModuleKind::CommonJS, None, "test.d.ts")
+        .into_iter()
+        .map(|d| d.code)
+        .collect::<Vec<_>>();
+    assert!(
+        codes.contains(&1316),
+        "TS1316 should fire for `export as namespace` below the top level, got: {codes:?}"
+    );
+}
+
 #[test]
 fn export_assignment_in_d_dot_directory_is_not_ambient() {
     let diagnostics = get_diagnostics_with_file_name(
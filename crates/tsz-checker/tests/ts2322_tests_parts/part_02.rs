@@ -155,6 +155,46 @@ fn test_ts2345_property_type_mismatch_includes_related_property_detail() {
     );
 }
 
+#[test]
+fn test_ts2345_property_mismatch_elaboration_nests_as_message_chain() {
+    let source = r#"
+        declare function takes(value: { outer: { inner: number } }): void;
+        declare const arg: { outer: { inner: string } };
+        takes(arg);
+    "#;
+
+    let diagnostics = diagnostics_for_source(source);
+    let ts2345 = diagnostics
+        .iter()
+        .find(|diag| {
+            diag.code == diagnostic_codes::ARGUMENT_OF_TYPE_IS_NOT_ASSIGNABLE_TO_PARAMETER_OF_TYPE
+        })
+        .expect("expected TS2345 for nested property mismatch");
+
+    // Each elaboration sits one level below the line it explains.
+    let chain = ts2345.message_chain();
+    assert_eq!(chain.next.len(), 1, "got: {ts2345:?}");
+    let header = &chain.next[0];
+    assert!(
+        header
+            .message_text
+            .contains("Types of property 'outer' are incompatible."),
+        "got: {ts2345:?}"
+    );
+    let flattened = chain.flatten("\n");
+    assert!(
+        flattened.contains("\n    Types of property 'inner' are incompatible."),
+        "nested property must be indented beneath 'outer', got:\n{flattened}"
+    );
+    assert!(
+        flattened
+            .lines()
+            .last()
+            .is_some_and(|line| line.trim() == "Type 'string' is not assignable to type 'number'."),
+        "leaf relation must close the chain, got:\n{flattened}"
+    );
+}
+
 #[test]
 fn test_ts2345_missing_many_properties_formats_related_detail_once() {
     let source = r#"
@@ -11,7 +11,7 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use tsz::checker::diagnostics::DiagnosticCategory;
-use tsz_cli::args::CliArgs;
+use tsz_cli::args::{CliArgs, DiagnosticFormat};
 use tsz_cli::help::{self, TSC_VERSION};
 use tsz_cli::{driver, locale, reporter::Reporter, watch};
 use tsz_common::diagnostics::{diagnostic_codes, diagnostic_messages};
@@ -102,6 +102,9 @@ fn actual_main(mut args: CliArgs, cwd: std::path::PathBuf) -> Result<()> {
     reject_tsconfig_only_cli_options(&args);
     reject_build_only_cli_options(&args);
 
+    // `tsz -` compiles source text piped on stdin.
+    let stdin_input = StdinInput::materialize(&mut args)?;
+
     // Handle --showConfig: print resolved configuration
     if args.show_config {
         return handle_show_config(&args, &cwd);
@@ -109,6 +112,7 @@ fn actual_main(mut args: CliArgs, cwd: std::path::PathBuf) -> Result<()> {
 
     if should_report_ts5112_for_command_line_files(&args, &cwd) {
         println!("error TS5112: {TS5112_COMMAND_LINE_FILES_MESSAGE}");
+        drop(stdin_input);
         std::process::exit(EXIT_DIAGNOSTICS_OUTPUTS_SKIPPED);
     }
 
@@ -122,6 +126,7 @@ fn actual_main(mut args: CliArgs, cwd: std::path::PathBuf) -> Result<()> {
     {
         println!("Version {TSC_VERSION}");
         println!("{}", help::colorize_help(&help::render_help(TSC_VERSION)));
+        drop(stdin_input);
         std::process::exit(1);
     }
 
@@ -136,6 +141,7 @@ fn actual_main(mut args: CliArgs, cwd: std::path::PathBuf) -> Result<()> {
     }
 
     if args.watch {
+        merge_output_only_options_from_tsconfig(&mut args, &cwd);
         return watch::run(&args, &cwd);
     }
 
@@ -145,6 +151,7 @@ fn actual_main(mut args: CliArgs, cwd: std::path::PathBuf) -> Result<()> {
     if args.files.is_empty() && args.project.is_none() && driver::find_tsconfig(&cwd).is_none() {
         println!("Version {TSC_VERSION}");
         println!("{}", help::colorize_help(&help::render_help(TSC_VERSION)));
+        drop(stdin_input);
         std::process::exit(1);
     }
 
@@ -166,6 +173,7 @@ fn actual_main(mut args: CliArgs, cwd: std::path::PathBuf) -> Result<()> {
         println!(
             "error TS5042: Option 'project' cannot be mixed with source files on a command line."
         );
+        drop(stdin_input);
         std::process::exit(1);
     }
 
@@ -189,6 +197,7 @@ fn actual_main(mut args: CliArgs, cwd: std::path::PathBuf) -> Result<()> {
             "error: --generateCpuProfile is not supported by tsz; requested profile '{}' was not created. Use --generateTrace for native trace output.",
             profile_path.display()
         );
+        drop(stdin_input);
         std::process::exit(EXIT_DIAGNOSTICS_OUTPUTS_SKIPPED);
     }
 
@@ -245,6 +254,64 @@ fn actual_main(mut args: CliArgs, cwd: std::path::PathBuf) -> Result<()> {
             args
         });
 
+        let phase = |ms: f64| Duration::from_secs_f64(ms.max(0.0) / 1000.0);
+        let timings = &result.phase_timings;
+        tracer.sequential_phases(
+            start_time,
+            [
+                ("readFiles", categories::IO, phase(timings.io_read_ms)),
+                ("loadLibs", categories::PROGRAM, phase(timings.load_libs_ms)),
+                (
+                    "parseAndBind",
+                    categories::BIND,
+                    phase(timings.parse_bind_ms),
+                ),
+                (
+                    "checkSourceFiles",
+                    categories::CHECK,
+                    phase(timings.check_ms),
+                ),
+                ("emit", categories::EMIT, phase(timings.emit_ms)),
+            ],
+        );
+
+        // Solver and checker cache counters, to spot hot queries.
+        tracer.instant_with_args("solverQueries", categories::CHECK, {
+            let counters = &result.request_cache_counters;
+            let mut args = FxHashMap::default();
+            args.insert(
+                "internedTypes".to_string(),
+                serde_json::json!(result.interned_types_count),
+            );
+            args.insert(
+                "requestCacheHits".to_string(),
+                serde_json::json!(counters.request_cache_hits),
+            );
+            args.insert(
+                "requestCacheMisses".to_string(),
+                serde_json::json!(counters.request_cache_misses),
+            );
+            if let Some(stats) = &result.query_cache_stats {
+                args.insert(
+                    "applicationEvalCacheHits".to_string(),
+                    serde_json::json!(stats.application_eval_cache_hits),
+                );
+                args.insert(
+                    "applicationEvalCacheMisses".to_string(),
+                    serde_json::json!(stats.application_eval_cache_misses),
+                );
+                args.insert(
+                    "intersectionMergeCacheHits".to_string(),
+                    serde_json::json!(stats.intersection_merge_cache_hits),
+                );
+                args.insert(
+                    "intersectionMergeCacheMisses".to_string(),
+                    serde_json::json!(stats.intersection_merge_cache_misses),
+                );
+            }
+            args
+        });
+
         // Add per-file events for files read
         for file in &result.files_read {
             let mut args = FxHashMap::default();
@@ -256,11 +323,14 @@ fn actual_main(mut args: CliArgs, cwd: std::path::PathBuf) -> Result<()> {
         }
 
         // Write the trace file
-        let trace_file = if trace_path.is_dir() {
-            trace_path.join("trace.json")
-        } else {
-            trace_path.to_path_buf()
-        };
+        // Like tsc, the argument names a directory that receives `trace.json`;
+        // an explicit `.json` path is still written as-is.
+        let trace_file =
+            if trace_path.extension().is_some_and(|ext| ext == "json") && !trace_path.is_dir() {
+                trace_path.to_path_buf()
+            } else {
+                trace_path.join("trace.json")
+            };
 
         if let Err(e) = tracer.write_to_file(&trace_file) {
             println!("Warning: Failed to write trace file: {e}");
@@ -332,16 +402,10 @@ fn actual_main(mut args: CliArgs, cwd: std::path::PathBuf) -> Result<()> {
         );
     }
 
-    if !result.diagnostics.is_empty() {
-        let pretty = args
-            .pretty
-            .unwrap_or_else(|| std::io::stdout().is_terminal());
-        // When --pretty true is explicitly passed, force ANSI colors even
-        // when piped (not a TTY), matching tsc v6 behavior.
-        if args.pretty == Some(true) {
-            Reporter::force_colors(true);
-        }
-        let mut reporter = Reporter::new(pretty);
+    // A SARIF log is written even for a clean run so upload steps always
+    // have a file to consume.
+    if !result.diagnostics.is_empty() || args.format == Some(DiagnosticFormat::Sarif) {
+        let mut reporter = diagnostic_reporter(&args);
         let output = reporter.render(&result.diagnostics);
         if !output.is_empty() {
             // tsc writes all diagnostics to stdout
@@ -349,6 +413,9 @@ fn actual_main(mut args: CliArgs, cwd: std::path::PathBuf) -> Result<()> {
         }
     }
 
+    // `process::exit` skips destructors, so remove the stdin copy explicitly.
+    drop(stdin_input);
+
     if args.sound_report_only {
         std::process::exit(EXIT_SUCCESS);
     }
@@ -359,25 +426,75 @@ fn actual_main(mut args: CliArgs, cwd: std::path::PathBuf) -> Result<()> {
         .any(|diag| diag.category == DiagnosticCategory::Error);
 
     if has_errors {
-        // Match tsc exit codes:
-        // Exit code 1 (DiagnosticsPresent_OutputsSkipped): emit was suppressed due to errors
-        //   (--noEmitOnError with errors means no outputs were generated).
-        // Exit code 2 (DiagnosticsPresent_OutputsGenerated): errors exist but outputs were
-        //   still generated (or --noEmit where there's nothing to emit regardless).
-        // `result.no_emit` reflects the resolved option (CLI + tsconfig.json),
-        // so a tsconfig-only `noEmit` selects exit 2 just like the CLI flag.
-        if args.no_emit_on_error {
-            std::process::exit(EXIT_DIAGNOSTICS_OUTPUTS_SKIPPED);
-        } else if result.no_emit || !result.emitted_files.is_empty() {
-            std::process::exit(EXIT_DIAGNOSTICS_OUTPUTS_GENERATED);
-        } else {
-            std::process::exit(EXIT_DIAGNOSTICS_OUTPUTS_SKIPPED);
-        }
+        std::process::exit(exit_status_for_errors(&result));
     }
 
     std::process::exit(EXIT_SUCCESS);
 }
 
+/// Exit code for a compilation that reported errors, matching tsc:
+/// `DiagnosticsPresent_OutputsSkipped` (1) when emit was skipped — e.g. by
+/// `noEmitOnError` from the CLI or tsconfig.json — and
+/// `DiagnosticsPresent_OutputsGenerated` (2) when emit ran, even if it wrote
+/// nothing. `noEmit` is not a skip: tsc handles it before `noEmitOnError` and
+/// reports 2.
+const fn exit_status_for_errors(result: &driver::CompilationResult) -> i32 {
+    if !result.no_emit && result.emit_skipped {
+        EXIT_DIAGNOSTICS_OUTPUTS_SKIPPED
+    } else {
+        EXIT_DIAGNOSTICS_OUTPUTS_GENERATED
+    }
+}
+
+/// Source text read from stdin for a `-` file argument.
+///
+/// The driver reads root files from disk, so the text is written to
+/// `stdin.ts` in a private temp directory that is removed on drop.
+/// Diagnostics name that file; relative imports resolve from it, not cwd.
+struct StdinInput {
+    _dir: tempfile::TempDir,
+}
+
+impl StdinInput {
+    fn materialize(args: &mut CliArgs) -> Result<Option<Self>> {
+        let Some(slot) = args.files.iter_mut().find(|file| file.as_os_str() == "-") else {
+            return Ok(None);
+        };
+        let mut text = String::new();
+        std::io::Read::read_to_string(&mut std::io::stdin(), &mut text)
+            .context("failed to read source from stdin")?;
+        let dir = tempfile::Builder::new()
+            .prefix("tsz-stdin-")
+            .tempdir()
+            .context("failed to create a temp directory for stdin")?;
+        let path = dir.path().join("stdin.ts");
+        std::fs::write(&path, text)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        *slot = path;
+        Ok(Some(Self { _dir: dir }))
+    }
+}
+
+/// Build the diagnostic reporter for `--pretty` / `--format`.
+///
+/// A machine-readable `--format` takes precedence: its output is never colored.
+fn diagnostic_reporter(args: &CliArgs) -> Reporter {
+    if let Some(format) = args.format.filter(|&f| f != DiagnosticFormat::Text) {
+        let mut reporter = Reporter::new(false);
+        reporter.set_format(format);
+        return reporter;
+    }
+    let pretty = args
+        .pretty
+        .unwrap_or_else(|| std::io::stdout().is_terminal());
+    // When --pretty true is explicitly passed, force ANSI colors even
+    // when piped (not a TTY), matching tsc v6 behavior.
+    if args.pretty == Some(true) {
+        Reporter::force_colors(true);
+    }
+    Reporter::new(pretty)
+}
+
 fn should_report_ts5112_for_command_line_files(args: &CliArgs, cwd: &std::path::Path) -> bool {
     !args.ignore_config
         && !args.build
@@ -818,8 +935,11 @@ fn canonicalize_long_flag(flag: &str) -> Option<&'static str> {
         "buildverbose" | "verbose" => Some("--build-verbose"),
         "batch" => Some("--batch"),
         "diagnosticsjson" => Some("--diagnostics-json"),
+        "format" => Some("--format"),
         "perfcountersjson" => Some("--perf-counters-json"),
         "tracedependencies" => Some("--traceDependencies"),
+        "watchevents" => Some("--watchEvents"),
+        "watcheventport" => Some("--watchEventPort"),
         "__explicitlydisabledboolflag" => Some("--__explicitly-disabled-bool-flag"),
         _ => None,
     }
@@ -946,6 +1066,7 @@ const BOOLEAN_FLAGS: &[&str] = &[
     "--suppressImplicitAnyIndexErrors",
     "--disableSizeLimit",
     "--batch",
+    "--watchEvents",
 ];
 
 fn is_boolean_flag(flag: &str) -> bool {
@@ -955,6 +1076,8 @@ fn is_boolean_flag(flag: &str) -> bool {
 /// Flags that take a mandatory value argument (not boolean flags).
 const VALUED_FLAGS: &[&str] = &[
     "--locale",
+    "--format",
+    "--watchEventPort",
     "--project",
     "--target",
     "--module",
@@ -1502,6 +1625,9 @@ fn find_closest_option(unknown: &str) -> Option<&'static str> {
 /// flags into `args`. tsc honors `listFiles`, `listEmittedFiles`,
 /// `explainFiles`, `diagnostics`, `extendedDiagnostics`, and
 /// `traceResolution` from tsconfig; tsz used to ignore them. See #3860.
+/// `assumeChangesOnlyAffectDirectDependencies` and `preserveWatchOutput` are
+/// merged the same way since only the watch driver reads them, straight from
+/// `args`.
 ///
 /// This is a best-effort merge: the full config resolver runs later
 /// (with extends-resolution, JSONC, etc.). For these output-only flags,
@@ -1561,6 +1687,11 @@ fn merge_output_only_options_from_tsconfig(args: &mut CliArgs, cwd: &std::path::
     take_bool("diagnostics", &mut args.diagnostics);
     take_bool("extendedDiagnostics", &mut args.extended_diagnostics);
     take_bool("traceResolution", &mut args.trace_resolution);
+    take_bool(
+        "assumeChangesOnlyAffectDirectDependencies",
+        &mut args.assume_changes_only_affect_direct_dependencies,
+    );
+    take_bool("preserveWatchOutput", &mut args.preserve_watch_output);
 }
 
 /// Line counts categorized by source-file type, matching tsc's `--diagnostics` output.
@@ -1619,6 +1750,9 @@ struct DiagnosticsReport {
     files_count: usize,
     lines: FileLinesStats,
     error_count: usize,
+    // AST nodes and bound symbols (0 when residency stats were not collected)
+    node_count: usize,
+    symbol_count: usize,
     has_phase_timings: bool,
     io_read_secs: f64,
     parse_bind_secs: f64,
@@ -1705,6 +1839,14 @@ fn build_diagnostics_report(
         files_count: result.files_read.len(),
         lines: file_lines,
         error_count,
+        node_count: result
+            .residency_stats
+            .as_ref()
+            .map_or(0, |rs| rs.node_count),
+        symbol_count: result
+            .residency_stats
+            .as_ref()
+            .map_or(0, |rs| rs.symbol_count),
         has_phase_timings: pt.total_ms > 0.0,
         io_read_secs: pt.io_read_ms / 1000.0,
         parse_bind_secs: (pt.load_libs_ms + pt.parse_bind_ms) / 1000.0,
@@ -1819,6 +1961,14 @@ fn render_diagnostics_report(report: &DiagnosticsReport, extended: bool) -> Stri
     );
     let _ = writeln!(out, "Lines of JSON:                 {}", report.lines.json);
     let _ = writeln!(out, "Lines of Other:                {}", report.lines.other);
+    if report.node_count > 0 {
+        let _ = writeln!(out, "Nodes:                         {}", report.node_count);
+        let _ = writeln!(
+            out,
+            "Symbols:                       {}",
+            report.symbol_count
+        );
+    }
     let _ = writeln!(out, "Errors:                        {}", report.error_count);
 
     if report.has_phase_timings {
@@ -2108,6 +2258,23 @@ mod diagnostics_report_tests {
         assert!(out.contains("Total time:                    1.23s"), "time");
     }
 
+    #[test]
+    fn node_and_symbol_counts_shown_when_collected() {
+        let out = render_diagnostics_report(&basic_report(), false);
+        assert!(!out.contains("Nodes:"), "no node count without stats");
+
+        let report = DiagnosticsReport {
+            node_count: 420,
+            symbol_count: 17,
+            ..basic_report()
+        };
+        let out = render_diagnostics_report(&report, false);
+        assert!(
+            out.contains("Lines of Other:                0\nNodes:                         420\nSymbols:                       17\n"),
+            "counts follow the line stats: {out}"
+        );
+    }
+
     #[test]
     fn basic_output_excludes_extended_fields() {
         let report = basic_report();
@@ -3060,13 +3227,7 @@ fn handle_show_config(args: &CliArgs, cwd: &std::path::Path) -> Result<()> {
             || d.code
                 == diagnostic_codes::OPTION_HAS_BEEN_REMOVED_PLEASE_REMOVE_IT_FROM_YOUR_CONFIGURATION
     }) {
-        let pretty = args
-            .pretty
-            .unwrap_or_else(|| std::io::stdout().is_terminal());
-        if args.pretty == Some(true) {
-            Reporter::force_colors(true);
-        }
-        let mut reporter = Reporter::new(pretty);
+        let mut reporter = diagnostic_reporter(args);
         let output = reporter.render(&config_diagnostics);
         if !output.is_empty() {
             print!("{output}");
@@ -3102,7 +3263,7 @@ fn handle_list_files_only(args: &CliArgs, cwd: &std::path::Path) -> Result<()> {
     use tsz::checker::diagnostics::diagnostic_codes;
     use tsz_cli::config::{load_tsconfig_with_diagnostics, resolve_compiler_options};
     use tsz_cli::driver::apply_cli_overrides;
-    use tsz_cli::fs::{FileDiscoveryOptions, discover_ts_files};
+    use tsz_cli::fs::{FileDiscoveryOptions, discover_ts_files, use_case_sensitive_file_names};
 
     if args.ignore_config && args.files.is_empty() {
         println!("Version {TSC_VERSION}");
@@ -3145,13 +3306,7 @@ fn handle_list_files_only(args: &CliArgs, cwd: &std::path::Path) -> Result<()> {
             || d.code
                 == diagnostic_codes::OPTION_HAS_BEEN_REMOVED_PLEASE_REMOVE_IT_FROM_YOUR_CONFIGURATION
     }) {
-        let pretty = args
-            .pretty
-            .unwrap_or_else(|| std::io::stdout().is_terminal());
-        if args.pretty == Some(true) {
-            Reporter::force_colors(true);
-        }
-        let mut reporter = Reporter::new(pretty);
+        let mut reporter = diagnostic_reporter(args);
         let output = reporter.render(&config_diagnostics);
         if !output.is_empty() {
             print!("{output}");
@@ -3195,6 +3350,7 @@ fn handle_list_files_only(args: &CliArgs, cwd: &std::path::Path) -> Result<()> {
         follow_links: false,
         allow_js: resolved.allow_js,
         resolve_json_module: resolved.resolve_json_module,
+        case_insensitive: !use_case_sensitive_file_names(),
     };
 
     let files = discover_ts_files(&discovery)?;
@@ -3206,13 +3362,7 @@ fn handle_list_files_only(args: &CliArgs, cwd: &std::path::Path) -> Result<()> {
     let unsupported_js_root_diagnostics =
         list_files_only_unsupported_js_root_diagnostics(&discovery, &files, files_from_config);
     if !unsupported_js_root_diagnostics.is_empty() {
-        let pretty = args
-            .pretty
-            .unwrap_or_else(|| std::io::stdout().is_terminal());
-        if args.pretty == Some(true) {
-            Reporter::force_colors(true);
-        }
-        let mut reporter = Reporter::new(pretty);
+        let mut reporter = diagnostic_reporter(args);
         let output = reporter.render(&unsupported_js_root_diagnostics);
         if !output.is_empty() {
             print!("{output}");
@@ -3220,6 +3370,15 @@ fn handle_list_files_only(args: &CliArgs, cwd: &std::path::Path) -> Result<()> {
         std::process::exit(EXIT_DIAGNOSTICS_OUTPUTS_SKIPPED);
     }
 
+    // Follow imports, references and `types` from the root files so the
+    // listing covers the whole program like tsc's. Nothing is bound or
+    // checked, which keeps this fast on large repos.
+    let program = driver::list_program_files(&files, base_dir, &resolved)?;
+    let canonical_roots: std::collections::HashSet<std::path::PathBuf> = files
+        .iter()
+        .map(|file| std::fs::canonicalize(file).unwrap_or_else(|_| file.clone()))
+        .collect();
+
     // Print lib files first (matching tsc --listFilesOnly order)
     if !resolved.checker.no_lib {
         for lib_file in &resolved.lib_files {
@@ -3227,10 +3386,39 @@ fn handle_list_files_only(args: &CliArgs, cwd: &std::path::Path) -> Result<()> {
         }
     }
 
-    for file in files {
+    for file in &files {
+        println!("{}", file.display());
+    }
+    for file in program
+        .files
+        .iter()
+        .filter(|file| !canonical_roots.contains(*file))
+    {
         println!("{}", file.display());
     }
 
+    // Resolution failures go to stderr so stdout stays the plain file list.
+    if !program.unresolved_imports.is_empty() {
+        let diagnostics: Vec<_> = program
+            .unresolved_imports
+            .iter()
+            .map(|unresolved| {
+                tsz::checker::diagnostics::Diagnostic::error(
+                    unresolved.containing_file.display().to_string(),
+                    unresolved.start,
+                    unresolved.length,
+                    unresolved.message.clone(),
+                    unresolved.code,
+                )
+            })
+            .collect();
+        let mut reporter = diagnostic_reporter(args);
+        let output = reporter.render(&diagnostics);
+        if !output.is_empty() {
+            eprint!("{output}");
+        }
+    }
+
     Ok(())
 }
 
@@ -3373,16 +3561,29 @@ fn handle_build(args: &CliArgs, cwd: &std::path::Path) -> Result<()> {
     let mut total_errors = 0;
     let mut built_count = 0;
     let mut skipped_count = 0;
-    let pretty = args
-        .pretty
-        .unwrap_or_else(|| std::io::stdout().is_terminal());
-    if args.pretty == Some(true) {
-        Reporter::force_colors(true);
-    }
-    let mut reporter = Reporter::new(pretty);
+    let mut reporter = diagnostic_reporter(args);
+    // SARIF is a single log document, so collect across projects and render once.
+    let collect_sarif = args.format == Some(DiagnosticFormat::Sarif);
+    let mut sarif_diagnostics = Vec::new();
+    // Projects that had errors, or were skipped because a dependency did.
+    let mut failed_projects: rustc_hash::FxHashSet<tsz_cli::project_refs::ProjectId> =
+        rustc_hash::FxHashSet::default();
+
+    // JSON and SARIF keep stdout machine-readable, so progress goes to stderr.
+    let machine_readable = matches!(
+        args.format,
+        Some(DiagnosticFormat::Json | DiagnosticFormat::Sarif)
+    );
+    let status = |line: &str| {
+        if machine_readable {
+            eprintln!("{line}");
+        } else {
+            println!("{line}");
+        }
+    };
 
     if args.build_verbose {
-        println!("Checking {} project(s)...", build_order.len());
+        status(&format!("Checking {} project(s)...", build_order.len()));
     }
 
     for project_id in &build_order {
@@ -3390,17 +3591,34 @@ fn handle_build(args: &CliArgs, cwd: &std::path::Path) -> Result<()> {
             continue;
         };
 
+        if let Some(failed_ref) =
+            build::find_failed_reference(&graph, *project_id, &failed_projects)
+        {
+            if args.build_verbose {
+                let dependency = graph
+                    .get_project(failed_ref)
+                    .map(|p| p.config_path.display().to_string())
+                    .unwrap_or_default();
+                status(&tsz_common::diagnostics::format_message(
+                    diagnostic_messages::SKIPPING_BUILD_OF_PROJECT_BECAUSE_ITS_DEPENDENCY_HAS_ERRORS,
+                    &[&project.config_path.display().to_string(), &dependency],
+                ));
+            }
+            failed_projects.insert(*project_id);
+            continue;
+        }
+
         // Check if project is up-to-date (unless --force is set)
         if !args.force && build::is_project_up_to_date(project, args) {
             if args.build_verbose {
-                println!("✓ Up to date: {}", project.config_path.display());
+                status(&format!("✓ Up to date: {}", project.config_path.display()));
             }
             skipped_count += 1;
             continue;
         }
 
         if args.build_verbose {
-            println!("\nBuilding: {}", project.config_path.display());
+            status(&format!("\nBuilding: {}", project.config_path.display()));
         }
 
         // Compile the project using the project-specific tsconfig
@@ -3418,7 +3636,10 @@ fn handle_build(args: &CliArgs, cwd: &std::path::Path) -> Result<()> {
 
         if error_count > 0 {
             total_errors += error_count;
-            if !result.diagnostics.is_empty() {
+            failed_projects.insert(*project_id);
+            if collect_sarif {
+                sarif_diagnostics.extend(result.diagnostics);
+            } else if !result.diagnostics.is_empty() {
                 let output = reporter.render(&result.diagnostics);
                 if !output.is_empty() {
                     print!("{output}");
@@ -3427,10 +3648,13 @@ fn handle_build(args: &CliArgs, cwd: &std::path::Path) -> Result<()> {
 
             // Stop on first error if --stopBuildOnErrors is set
             if args.stop_build_on_errors {
-                println!(
+                if collect_sarif {
+                    print!("{}", reporter.render(&sarif_diagnostics));
+                }
+                status(&format!(
                     "\nBuild stopped due to errors in {}",
                     project.config_path.display()
-                );
+                ));
                 std::process::exit(EXIT_DIAGNOSTICS_OUTPUTS_SKIPPED);
             }
         }
@@ -3438,10 +3662,14 @@ fn handle_build(args: &CliArgs, cwd: &std::path::Path) -> Result<()> {
         built_count += 1;
     }
 
+    if collect_sarif {
+        print!("{}", reporter.render(&sarif_diagnostics));
+    }
+
     if args.build_verbose {
-        println!(
+        status(&format!(
             "\nBuilt {built_count} project(s), skipped {skipped_count} up-to-date project(s), {total_errors} error(s)"
-        );
+        ));
     }
 
     if total_errors > 0 {
@@ -3529,13 +3757,7 @@ fn handle_build_single_project(
     }
 
     if !result.diagnostics.is_empty() {
-        let pretty = args
-            .pretty
-            .unwrap_or_else(|| std::io::stdout().is_terminal());
-        if args.pretty == Some(true) {
-            Reporter::force_colors(true);
-        }
-        let mut reporter = Reporter::new(pretty);
+        let mut reporter = diagnostic_reporter(args);
         let output = reporter.render(&result.diagnostics);
         if !output.is_empty() {
             print!("{output}");
@@ -3548,11 +3770,7 @@ fn handle_build_single_project(
         .any(|d| d.category == DiagnosticCategory::Error);
 
     if has_errors {
-        std::process::exit(if result.emitted_files.is_empty() {
-            EXIT_DIAGNOSTICS_OUTPUTS_SKIPPED
-        } else {
-            EXIT_DIAGNOSTICS_OUTPUTS_GENERATED
-        });
+        std::process::exit(exit_status_for_errors(&result));
     }
 
     Ok(())
@@ -43,7 +43,7 @@ pub(super) fn collect_files_and_excludes(
     base_dir: &Path,
     compiler_options_map: &serde_json::Map<String, serde_json::Value>,
 ) -> (Vec<String>, Vec<String>) {
-    use tsz_cli::fs::{FileDiscoveryOptions, discover_ts_files};
+    use tsz_cli::fs::{FileDiscoveryOptions, discover_ts_files, use_case_sensitive_file_names};
 
     let allow_js = compiler_options_map
         .get("allowJs")
@@ -88,6 +88,7 @@ pub(super) fn collect_files_and_excludes(
                 .get("resolveJsonModule")
                 .and_then(|v| v.as_bool())
                 .unwrap_or(false),
+            case_insensitive: !use_case_sensitive_file_names(),
         };
         discover_ts_files(&discovery)
             .unwrap_or_default()
@@ -443,6 +444,15 @@ fn compiler_options_to_json(
         }
         map.insert("paths".into(), Value::Object(paths_obj));
     }
+
+    // Options without a normalising mapping above keep their serialized form.
+    if let Ok(Value::Object(serialized)) = serde_json::to_value(opts) {
+        for (key, value) in serialized {
+            if !value.is_null() && !map.contains_key(&key) {
+                map.insert(key, value);
+            }
+        }
+    }
     map
 }
 
@@ -1162,6 +1172,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compiler_options_to_json_includes_options_without_explicit_mapping() {
+        let cfg = TsConfig {
+            compiler_options: Some(CoreCompilerOptions {
+                downlevel_iteration: Some(true),
+                no_error_truncation: Some(false),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let map = build_compiler_options_map(Some(&cfg), &empty_args(), &base_dir());
+        assert_eq!(
+            map.get("downlevelIteration"),
+            Some(&serde_json::Value::Bool(true))
+        );
+        assert_eq!(
+            map.get("noErrorTruncation"),
+            Some(&serde_json::Value::Bool(false))
+        );
+        assert!(!map.contains_key("invalidatedOptions"));
+        assert!(!map.contains_key("noImplicitAny"));
+    }
+
     #[test]
     fn cli_override_target_wins_over_tsconfig() {
         let cfg = TsConfig {
@@ -54,19 +54,23 @@
 //! - workspace/didChangeConfiguration
 //! - workspace/didChangeWatchedFiles
 //! - workspace/executeCommand
+//! - $/cancelRequest (also interrupts completions, references and diagnostics
+//!   while they run)
 
 use anyhow::{Context, Result};
 use clap::Parser;
-use rustc_hash::FxHashSet;
+use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::io::{BufRead, BufReader, Read, Write};
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, mpsc};
 use tracing::{debug, info, trace};
 use tsz_common::limits;
 
 use tsz::lsp::{
-    CompletionItemData, CompletionItemKind, FormattingOptions, Position, Project, Range,
+    CancellationToken, CompletionItemData, CompletionItemKind, FormattingOptions, Position,
+    Project, Range,
 };
 
 #[path = "tsz_lsp/tsz_lsp_dispatch.rs"]
@@ -151,6 +155,61 @@ struct JsonRpcServerRequest {
 // LSP Server State
 // =============================================================================
 
+/// JSON-RPC error code for a request the client cancelled.
+const REQUEST_CANCELLED: i32 = -32800;
+
+/// Key used to track a request id (numbers and strings are both allowed).
+fn request_id_key(id: &Value) -> Option<String> {
+    match id {
+        Value::Number(n) => Some(n.to_string()),
+        Value::String(s) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+/// Cancellation tokens of requests that have been read but not yet answered.
+///
+/// Shared between the stdin reader thread and the request loop: the reader
+/// registers each request as it arrives and flips its token on
+/// `$/cancelRequest`, so a request that is already running sees the
+/// cancellation at its next check.
+#[derive(Default)]
+struct CancellationRegistry {
+    tokens: Mutex<FxHashMap<String, CancellationToken>>,
+}
+
+impl CancellationRegistry {
+    /// Token for request `key`, registering it on first use.
+    fn token_for(&self, key: &str) -> CancellationToken {
+        self.tokens
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(key.to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// Cancel request `key` if it is still pending.
+    fn cancel(&self, key: &str) {
+        if let Some(token) = self
+            .tokens
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+        {
+            token.cancel();
+        }
+    }
+
+    /// Forget request `key` once it has been answered.
+    fn finish(&self, key: &str) {
+        self.tokens
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(key);
+    }
+}
+
 /// Counter for generating unique progress tokens.
 static PROGRESS_TOKEN_COUNTER: AtomicU64 = AtomicU64::new(1);
 
@@ -168,8 +227,8 @@ struct LspServer {
     pending_server_requests: Vec<JsonRpcServerRequest>,
     /// Counter for generating unique server-side request ids. Issue #3545.
     next_server_request_id: u64,
-    /// Request IDs that have been cancelled by the client.
-    cancelled_requests: FxHashSet<String>,
+    /// Cancellation tokens of pending requests.
+    cancellation: Arc<CancellationRegistry>,
     /// Workspace folder URIs.
     workspace_folders: Vec<WorkspaceFolder>,
     /// Whether the client supports workspace folder change notifications.
@@ -192,27 +251,13 @@ impl LspServer {
             pending_notifications: Vec::new(),
             pending_server_requests: Vec::new(),
             next_server_request_id: 1,
-            cancelled_requests: FxHashSet::default(),
+            cancellation: Arc::new(CancellationRegistry::default()),
             workspace_folders: Vec::new(),
             client_supports_workspace_folders: false,
             client_supports_progress: false,
         }
     }
 
-    /// Check if a request has been cancelled.
-    fn is_cancelled(&self, id: &Option<Value>) -> bool {
-        if let Some(id) = id {
-            let id_str = match id {
-                Value::Number(n) => n.to_string(),
-                Value::String(s) => s.clone(),
-                _ => return false,
-            };
-            self.cancelled_requests.contains(&id_str)
-        } else {
-            false
-        }
-    }
-
     /// Generate a unique progress token.
     fn next_progress_token() -> String {
         format!(
@@ -554,13 +599,12 @@ impl LspServer {
     // ─── Cancel Request ─────────────────────────────────────────────────
 
     fn handle_cancel_request(&mut self, params: Option<Value>) {
-        if let Some(id) = params.as_ref().and_then(|p| p.get("id")) {
-            let id_str = match id {
-                Value::Number(n) => n.to_string(),
-                Value::String(s) => s.clone(),
-                _ => return,
-            };
-            self.cancelled_requests.insert(id_str);
+        if let Some(key) = params
+            .as_ref()
+            .and_then(|p| p.get("id"))
+            .and_then(request_id_key)
+        {
+            self.cancellation.cancel(&key);
         }
     }
 
@@ -762,54 +806,47 @@ impl LspServer {
                 return;
             }
 
-            // Try incremental sync first: apply each change as a text edit
-            let has_range_changes = changes.iter().any(|c| c.get("range").is_some());
+            // Ranged changes are applied in order against the stored source;
+            // a change without a range replaces the whole document.
+            let content_changes: Vec<tsz::lsp::TextDocumentContentChange> = changes
+                .iter()
+                .filter_map(|change| {
+                    let text = change.get("text")?.as_str()?.to_string();
+                    let range = match change.get("range") {
+                        Some(range_val) => {
+                            let start = range_val.get("start")?;
+                            let end = range_val.get("end")?;
+                            Some(Range::new(
+                                Position::new(
+                                    start.get("line")?.as_u64()? as u32,
+                                    start.get("character")?.as_u64()? as u32,
+                                ),
+                                Position::new(
+                                    end.get("line")?.as_u64()? as u32,
+                                    end.get("character")?.as_u64()? as u32,
+                                ),
+                            ))
+                        }
+                        None => None,
+                    };
+                    Some(tsz::lsp::TextDocumentContentChange { range, text })
+                })
+                .collect();
 
-            if has_range_changes {
-                // Incremental: apply text edits via Project::update_file
-                let edits: Vec<tsz::lsp::FormattingTextEdit> = changes
+            if self
+                .project
+                .apply_content_changes(&file_name, &content_changes)
+                .is_none()
+            {
+                // Unknown file or out-of-range edit: fall back to the last
+                // full-text change, if the client sent one.
+                if let Some(text) = content_changes
                     .iter()
-                    .filter_map(|change| {
-                        let range_val = change.get("range")?;
-                        let start = range_val.get("start")?;
-                        let end = range_val.get("end")?;
-                        let range = Range::new(
-                            Position::new(
-                                start.get("line")?.as_u64()? as u32,
-                                start.get("character")?.as_u64()? as u32,
-                            ),
-                            Position::new(
-                                end.get("line")?.as_u64()? as u32,
-                                end.get("character")?.as_u64()? as u32,
-                            ),
-                        );
-                        let text = change.get("text")?.as_str()?.to_string();
-                        Some(tsz::lsp::FormattingTextEdit {
-                            range,
-                            new_text: text,
-                        })
-                    })
-                    .collect();
-
-                if !edits.is_empty() {
-                    // Convert FormattingTextEdit to TextEdit for update_file
-                    let text_edits: Vec<tsz::lsp::TextEdit> = edits
-                        .into_iter()
-                        .map(|e| tsz::lsp::TextEdit {
-                            range: e.range,
-                            new_text: e.new_text,
-                        })
-                        .collect();
-                    self.project.update_file(&file_name, &text_edits);
-                }
-            } else {
-                // Full sync fallback: take the last content change
-                if let Some(text) = changes
-                    .last()
-                    .and_then(|c| c.get("text"))
-                    .and_then(|t| t.as_str())
+                    .rev()
+                    .find(|change| change.range.is_none())
+                    .map(|change| change.text.clone())
                 {
-                    self.project.set_file(file_name.clone(), text.to_string());
+                    self.project.set_file(file_name.clone(), text);
                 }
             }
 
@@ -1010,31 +1047,54 @@ impl LspServer {
     fn handle_document_diagnostic(&mut self, params: Option<Value>) -> Result<Value> {
         let uri = Self::extract_uri(&params).ok_or_else(|| anyhow::anyhow!("Missing uri"))?;
         let file_name = Self::uri_to_file_name(&uri);
+        let previous_result_id = params
+            .as_ref()
+            .and_then(|p| p.get("previousResultId"))
+            .and_then(Value::as_str);
 
-        let diagnostics = self.project.get_diagnostics(&file_name).unwrap_or_default();
-
-        let lsp_diags: Vec<Value> = diagnostics.iter().map(Self::diagnostic_to_json).collect();
-
-        Ok(serde_json::json!({
-            "kind": "full",
-            "items": lsp_diags,
-        }))
+        match self
+            .project
+            .get_document_diagnostic_report(&file_name, previous_result_id)
+        {
+            Some(report) => Ok(Self::document_diagnostic_report_to_json(report)),
+            None => Ok(serde_json::json!({
+                "kind": "full",
+                "items": [],
+            })),
+        }
     }
 
-    fn handle_workspace_diagnostic(&mut self, _params: Option<Value>) -> Result<Value> {
+    fn handle_workspace_diagnostic(&mut self, params: Option<Value>) -> Result<Value> {
+        let previous_result_ids: FxHashMap<String, String> = params
+            .as_ref()
+            .and_then(|p| p.get("previousResultIds"))
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|previous| {
+                let uri = previous.get("uri")?.as_str()?;
+                let value = previous.get("value")?.as_str()?;
+                Some((Self::uri_to_file_name(uri), value.to_string()))
+            })
+            .collect();
         let mut items = Vec::new();
 
         let file_names: Vec<String> = self.project.file_names().map(|s| s.to_string()).collect();
         for file_name in &file_names {
-            let diagnostics = self.project.get_diagnostics(file_name).unwrap_or_default();
-
-            let lsp_diags: Vec<Value> = diagnostics.iter().map(Self::diagnostic_to_json).collect();
+            if self.project.is_cancelled() {
+                break;
+            }
+            let previous = previous_result_ids.get(file_name).map(String::as_str);
+            let Some(report) = self
+                .project
+                .get_document_diagnostic_report(file_name, previous)
+            else {
+                continue;
+            };
 
-            items.push(serde_json::json!({
-                "kind": "full",
-                "uri": Self::file_name_to_uri(file_name),
-                "items": lsp_diags,
-            }));
+            let mut item = Self::document_diagnostic_report_to_json(report);
+            item["uri"] = Value::from(Self::file_name_to_uri(file_name));
+            items.push(item);
         }
 
         Ok(serde_json::json!({
@@ -1042,6 +1102,27 @@ impl LspServer {
         }))
     }
 
+    fn document_diagnostic_report_to_json(report: DocumentDiagnosticReport) -> Value {
+        match report {
+            DocumentDiagnosticReport::Full(full) => {
+                let lsp_diags: Vec<Value> =
+                    full.items.iter().map(Self::diagnostic_to_json).collect();
+                let mut value = serde_json::json!({
+                    "kind": "full",
+                    "items": lsp_diags,
+                });
+                if let Some(result_id) = full.result_id {
+                    value["resultId"] = Value::from(result_id);
+                }
+                value
+            }
+            DocumentDiagnosticReport::Unchanged(unchanged) => serde_json::json!({
+                "kind": "unchanged",
+                "resultId": unchanged.result_id,
+            }),
+        }
+    }
+
     // ─── Hover ──────────────────────────────────────────────────────────
 
     fn handle_hover(&mut self, params: Option<Value>) -> Result<Value> {
@@ -1097,10 +1178,18 @@ impl LspServer {
             .ok_or_else(|| anyhow::anyhow!("Missing position params"))?;
         let file_name = Self::uri_to_file_name(&uri);
 
-        let items = self
+        let mut items = self
             .project
             .get_completions(&file_name, position)
             .unwrap_or_default();
+        // Snippet variants replace the plain entries for the same members.
+        let snippets = self
+            .project
+            .get_class_member_override_snippets(&file_name, position);
+        if !snippets.is_empty() {
+            items.retain(|item| !snippets.iter().any(|snippet| snippet.label == item.label));
+            items.extend(snippets);
+        }
 
         let lsp_items: Vec<Value> = items
             .iter()
@@ -1440,57 +1529,20 @@ fn lsp_main(args: Args) -> Result<()> {
     info!("tsz-lsp: Mode: {}", args.mode);
 
     let mut server = LspServer::new();
-    let stdin = std::io::stdin();
     let mut stdout = std::io::stdout();
 
-    // Use a single BufReader for all reads to avoid losing buffered data
-    let mut reader = BufReader::new(stdin.lock());
-    let mut header_line = String::new();
-
-    loop {
-        // Read headers
-        let mut content_length: Option<usize> = None;
-
-        loop {
-            header_line.clear();
-            let bytes_read = reader
-                .read_line(&mut header_line)
-                .context("Failed to read header line")?;
-
-            if bytes_read == 0 {
-                debug!("tsz-lsp: EOF reached");
-                return Ok(());
-            }
-
-            let line = header_line.trim_end_matches(['\r', '\n']);
-
-            if line.is_empty() {
-                break;
-            }
-
-            if let Some(len) = line.strip_prefix("Content-Length: ") {
-                content_length = Some(len.trim().parse().context("Invalid content length")?);
-            }
-        }
-
-        let content_length =
-            content_length.ok_or_else(|| anyhow::anyhow!("Missing Content-Length header"))?;
-
-        // Read content from the same BufReader to preserve buffered data
-        let mut content = vec![0u8; content_length];
-        reader
-            .read_exact(&mut content)
-            .context("Failed to read content")?;
-
-        let content_str = String::from_utf8(content).context("Invalid UTF-8")?;
-
-        if args.verbose {
-            trace!("tsz-lsp: Received: {}", content_str);
-        }
+    // Messages are read on a separate thread so that `$/cancelRequest` can
+    // reach a request while the loop below is still working on it.
+    let (sender, receiver) = mpsc::channel();
+    let cancellation = Arc::clone(&server.cancellation);
+    let verbose = args.verbose;
+    std::thread::Builder::new()
+        .name("tsz-lsp-reader".to_string())
+        .spawn(move || read_messages(&sender, &cancellation, verbose))
+        .context("Failed to spawn LSP reader thread")?;
 
-        // Parse JSON-RPC message
-        let msg: JsonRpcMessage =
-            serde_json::from_str(&content_str).context("Failed to parse JSON-RPC message")?;
+    for msg in receiver {
+        let msg = msg?;
 
         // Handle message
         if let Some(response) = server.handle_message(msg) {
@@ -1546,6 +1598,100 @@ fn lsp_main(args: Args) -> Result<()> {
             stdout.flush()?;
         }
     }
+
+    debug!("tsz-lsp: EOF reached");
+    Ok(())
+}
+
+/// Read JSON-RPC messages from stdin and forward them to the request loop.
+///
+/// Requests are registered with `cancellation` as soon as they are read, and
+/// `$/cancelRequest` is applied here directly, so a cancellation does not have
+/// to wait behind the request it targets. The channel is closed on EOF; read
+/// errors are forwarded so the loop can shut down with them.
+fn read_messages(
+    sender: &mpsc::Sender<Result<JsonRpcMessage>>,
+    cancellation: &CancellationRegistry,
+    verbose: bool,
+) {
+    let stdin = std::io::stdin();
+    // Use a single BufReader for all reads to avoid losing buffered data
+    let mut reader = BufReader::new(stdin.lock());
+    loop {
+        let msg = match read_message(&mut reader, verbose) {
+            Ok(Some(msg)) => msg,
+            Ok(None) => return,
+            Err(err) => {
+                let _ = sender.send(Err(err));
+                return;
+            }
+        };
+
+        if let Some(key) = msg.id.as_ref().and_then(request_id_key) {
+            if msg.method.is_some() {
+                cancellation.token_for(&key);
+            }
+        } else if msg.method.as_deref() == Some("$/cancelRequest")
+            && let Some(key) = msg
+                .params
+                .as_ref()
+                .and_then(|p| p.get("id"))
+                .and_then(request_id_key)
+        {
+            cancellation.cancel(&key);
+        }
+
+        if sender.send(Ok(msg)).is_err() {
+            return;
+        }
+    }
+}
+
+/// Read one `Content-Length`-framed message. Returns `None` on EOF.
+fn read_message(reader: &mut impl BufRead, verbose: bool) -> Result<Option<JsonRpcMessage>> {
+    let mut header_line = String::new();
+    let mut content_length: Option<usize> = None;
+
+    // Read headers
+    loop {
+        header_line.clear();
+        let bytes_read = reader
+            .read_line(&mut header_line)
+            .context("Failed to read header line")?;
+
+        if bytes_read == 0 {
+            return Ok(None);
+        }
+
+        let line = header_line.trim_end_matches(['\r', '\n']);
+
+        if line.is_empty() {
+            break;
+        }
+
+        if let Some(len) = line.strip_prefix("Content-Length: ") {
+            content_length = Some(len.trim().parse().context("Invalid content length")?);
+        }
+    }
+
+    let content_length =
+        content_length.ok_or_else(|| anyhow::anyhow!("Missing Content-Length header"))?;
+
+    // Read content from the same BufReader to preserve buffered data
+    let mut content = vec![0u8; content_length];
+    reader
+        .read_exact(&mut content)
+        .context("Failed to read content")?;
+
+    let content_str = String::from_utf8(content).context("Invalid UTF-8")?;
+
+    if verbose {
+        trace!("tsz-lsp: Received: {}", content_str);
+    }
+
+    // Parse JSON-RPC message
+    let msg = serde_json::from_str(&content_str).context("Failed to parse JSON-RPC message")?;
+    Ok(Some(msg))
 }
 
 #[cfg(test)]
@@ -1633,6 +1779,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cancelled_request_is_answered_with_request_cancelled_error() {
+        let mut server = LspServer::new();
+        // The reader thread registers the request before the cancel arrives.
+        let _token = server.cancellation.token_for("7");
+        server.handle_cancel_request(Some(json!({ "id": 7 })));
+
+        let response = server
+            .handle_message(JsonRpcMessage {
+                id: Some(json!(7)),
+                method: Some("textDocument/hover".to_string()),
+                params: None,
+            })
+            .expect("cancelled request still gets a response");
+        let error = response.error.expect("response should be an error");
+        assert_eq!(error.code, REQUEST_CANCELLED);
+        assert!(response.result.is_none());
+    }
+
     // Issue #3545: tsz.applyCodeAction must enqueue workspace/applyEdit as a
     // server-to-client REQUEST (with `id`), not a notification. LSP spec
     // requires the client to respond with `ApplyWorkspaceEditResponse`.
@@ -4,31 +4,43 @@ impl LspServer {
     // ─── Message dispatch ───────────────────────────────────────────────
 
     pub(super) fn handle_message(&mut self, msg: JsonRpcMessage) -> Option<JsonRpcResponse> {
-        let method = msg.method.as_deref();
-        let id = msg.id.clone();
-
-        if let Some(method) = method
+        if let Some(method) = msg.method.as_deref()
             && self.handle_notification_method(method, msg.params.clone())
         {
             return None;
         }
 
-        // Check if this request was already cancelled
-        if self.is_cancelled(&id)
-            && let Some(id_val) = id
-        {
-            let id_str = match &id_val {
-                Value::Number(n) => n.to_string(),
-                Value::String(s) => s.clone(),
-                _ => String::new(),
-            };
-            self.cancelled_requests.remove(&id_str);
+        let Some(key) = msg.id.as_ref().and_then(request_id_key) else {
+            return self.dispatch_request(msg);
+        };
+        let id = msg.id.clone();
+
+        // Requests cancelled before they started are answered without
+        // running; running ones observe the token through the project.
+        let token = self.cancellation.token_for(&key);
+        let response = if token.is_cancelled() {
+            None
+        } else {
+            self.project.set_cancellation_token(Some(token.clone()));
+            let response = self.dispatch_request(msg);
+            self.project.set_cancellation_token(None);
+            response
+        };
+        self.cancellation.finish(&key);
+
+        if token.is_cancelled() {
             return Some(self.error_response(
-                Some(id_val),
-                -32800,
+                id,
+                REQUEST_CANCELLED,
                 "Request cancelled".to_string(),
             ));
         }
+        response
+    }
+
+    fn dispatch_request(&mut self, msg: JsonRpcMessage) -> Option<JsonRpcResponse> {
+        let method = msg.method.as_deref();
+        let id = msg.id.clone();
 
         match method {
             Some("initialize") => {
@@ -362,18 +362,32 @@ impl LspServer {
                 let lsp_hints: Vec<Value> = hints
                     .iter()
                     .map(|h| {
-                        let kind = match h.kind {
-                            tsz::lsp::InlayHintKind::Type | tsz::lsp::InlayHintKind::Generic => 1,
-                            tsz::lsp::InlayHintKind::Parameter => 2,
-                        };
                         let mut hint = serde_json::json!({
                             "position": Self::position_to_json(&h.position),
                             "label": h.label,
-                            "kind": kind,
                         });
+                        // LSP only defines Type (1) and Parameter (2); enum
+                        // value hints are sent without a kind.
+                        match h.kind {
+                            tsz::lsp::InlayHintKind::Type | tsz::lsp::InlayHintKind::Generic => {
+                                hint["kind"] = Value::from(1);
+                            }
+                            tsz::lsp::InlayHintKind::Parameter => {
+                                hint["kind"] = Value::from(2);
+                            }
+                            tsz::lsp::InlayHintKind::Enum => {
+                                hint["paddingLeft"] = Value::from(true);
+                            }
+                        }
                         if let Some(ref tooltip) = h.tooltip {
                             hint["tooltip"] = Value::from(tooltip.as_str());
                         }
+                        if let Some(ref insert_text) = h.insert_text {
+                            hint["textEdits"] = serde_json::json!([{
+                                "range": Self::range_to_json(&h.to_range()),
+                                "newText": insert_text,
+                            }]);
+                        }
                         hint
                     })
                     .collect();
@@ -842,6 +856,20 @@ impl LspServer {
                 self.project.set_strict(strict);
             }
 
+            if let Some(inlay_hints) = settings.get("inlayHints") {
+                let mut options = self.project.inlay_hints_options();
+                options.apply_settings(inlay_hints);
+                self.project.set_inlay_hints_options(options);
+            }
+
+            if let Some(quote) = settings
+                .get("preferences")
+                .and_then(|prefs| prefs.get("quoteStyle"))
+                .and_then(|v| v.as_str())
+            {
+                self.project.set_quote_preference(Some(quote.to_string()));
+            }
+
             debug!("Configuration updated: {:?}", settings);
         }
     }
@@ -0,0 +1,94 @@
+//! Per-file parse/bind reuse for tsz-server's checker.
+//!
+//! Every semantic query binds the whole program, but between two requests
+//! usually only the file being edited has changed. Each file's `BindResult`
+//! is kept keyed by a hash of its text and the lib binders it was bound
+//! against, so an `updateOpen` batch that touches one file re-parses only that
+//! file on the next query. This mirrors the CLI driver's watch-mode cache.
+
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use tsz::lib_loader::LibFile;
+use tsz::parallel::{self, BindResult, MergedProgram};
+
+struct BindCacheEntry {
+    hash: u64,
+    bind_result: BindResult,
+}
+
+#[derive(Default)]
+pub(crate) struct BindCache {
+    entries: FxHashMap<String, BindCacheEntry>,
+}
+
+impl BindCache {
+    /// Parse and bind `files`, reusing cached results for files whose text
+    /// and lib set are unchanged, and merge them in `files` order. Entries for
+    /// files outside this program are dropped.
+    pub(crate) fn bind_program(
+        &mut self,
+        files: Vec<(String, String)>,
+        lib_files: &[Arc<LibFile>],
+    ) -> MergedProgram {
+        // Cached results hold their lib binders alive, so a pointer match
+        // means the same lib symbols rather than a reused allocation.
+        let mut lib_hasher = FxHasher::default();
+        for lib in lib_files {
+            lib.file_name.hash(&mut lib_hasher);
+            Arc::as_ptr(&lib.binder).hash(&mut lib_hasher);
+        }
+        let lib_hash = lib_hasher.finish();
+
+        let mut order = Vec::with_capacity(files.len());
+        let mut to_bind = Vec::new();
+        let mut to_bind_hashes = Vec::new();
+        for (path, text) in files {
+            let mut hasher = FxHasher::default();
+            text.hash(&mut hasher);
+            lib_hash.hash(&mut hasher);
+            let hash = hasher.finish();
+            if !self
+                .entries
+                .get(&path)
+                .is_some_and(|entry| entry.hash == hash)
+            {
+                to_bind.push((path.clone(), text));
+                to_bind_hashes.push(hash);
+            }
+            order.push(path);
+        }
+
+        if !to_bind.is_empty() {
+            let results = parallel::parse_and_bind_parallel_with_libs(to_bind, lib_files);
+            for (bind_result, hash) in results.into_iter().zip(to_bind_hashes) {
+                self.entries.insert(
+                    bind_result.file_name.clone(),
+                    BindCacheEntry { hash, bind_result },
+                );
+            }
+        }
+
+        let current: FxHashSet<&str> = order.iter().map(String::as_str).collect();
+        self.entries
+            .retain(|path, _| current.contains(path.as_str()));
+
+        let ordered: Vec<&BindResult> = order
+            .iter()
+            .filter_map(|path| self.entries.get(path).map(|entry| &entry.bind_result))
+            .collect();
+        parallel::merge_bind_results_ref(&ordered)
+    }
+
+    /// The parsed arena cached for `path`, to observe reuse across requests.
+    #[cfg(test)]
+    pub(crate) fn cached_arena(&self, path: &str) -> Option<Arc<tsz::parser::node::NodeArena>> {
+        self.entries
+            .get(path)
+            .map(|entry| Arc::clone(&entry.bind_result.arena))
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
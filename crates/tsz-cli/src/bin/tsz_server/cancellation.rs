@@ -0,0 +1,74 @@
+//! Request cancellation through `--cancellationPipeName`.
+//!
+//! tsserver's semaphore protocol: when the pipe name ends in `*`, the editor
+//! cancels request `N` by creating the file `<name without *>N`; otherwise the
+//! file named by the option cancels whatever request is running. The server
+//! polls the semaphore between units of checker work — before dispatching a
+//! request, before the checker runs for a diagnostics query, and between the
+//! files and diagnostic kinds of a `geterr` batch — and answers a cancelled
+//! request with `{ "canceled": true }`, as tsserver does.
+
+use super::{Server, TsServerResponse};
+use std::path::PathBuf;
+
+#[derive(Debug, Default)]
+pub(crate) struct CancellationToken {
+    pipe_name: Option<String>,
+    /// Sequence number of the request currently being served.
+    request_seq: Option<u64>,
+    /// Latched once the semaphore has been seen for `request_seq`.
+    cancelled: bool,
+}
+
+impl CancellationToken {
+    pub(crate) const fn new(pipe_name: Option<String>) -> Self {
+        Self {
+            pipe_name,
+            request_seq: None,
+            cancelled: false,
+        }
+    }
+
+    pub(crate) const fn begin_request(&mut self, request_seq: u64) {
+        self.request_seq = Some(request_seq);
+        self.cancelled = false;
+    }
+
+    pub(crate) const fn end_request(&mut self) {
+        self.request_seq = None;
+        self.cancelled = false;
+    }
+
+    /// Whether the request being served has been cancelled.
+    pub(crate) fn is_cancelled(&mut self) -> bool {
+        if !self.cancelled
+            && let Some(request_seq) = self.request_seq
+        {
+            self.cancelled = self
+                .semaphore_path(request_seq)
+                .is_some_and(|path| path.exists());
+        }
+        self.cancelled
+    }
+
+    fn semaphore_path(&self, request_seq: u64) -> Option<PathBuf> {
+        let pipe_name = self.pipe_name.as_deref()?;
+        Some(match pipe_name.strip_suffix('*') {
+            Some(prefix) => PathBuf::from(format!("{prefix}{request_seq}")),
+            None => PathBuf::from(pipe_name),
+        })
+    }
+}
+
+impl Server {
+    /// Turn a handler's response into tsserver's answer for a cancelled
+    /// request.
+    pub(crate) fn cancelled_response(response: TsServerResponse) -> TsServerResponse {
+        TsServerResponse {
+            success: true,
+            message: None,
+            body: Some(serde_json::json!({ "canceled": true })),
+            ..response
+        }
+    }
+}
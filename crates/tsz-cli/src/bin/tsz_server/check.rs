@@ -88,11 +88,24 @@ impl Server {
         content: &str,
         category: DiagnosticCategory,
     ) -> Vec<tsz::checker::diagnostics::Diagnostic> {
-        let mut options = self.inferred_check_options.clone();
-        if self.inferred_module_is_none_for_projects
-            && !self.auto_imports_allowed_for_inferred_projects
-        {
-            options.module = Some("none".to_string());
+        if self.cancellation.is_cancelled() {
+            return Vec::new();
+        }
+        let configured_project = self.project_for_file(file_path);
+        let mut options = match configured_project.as_ref() {
+            Some(project) => project.options.clone(),
+            None => {
+                let mut options = self.inferred_check_options.clone();
+                if self.inferred_module_is_none_for_projects
+                    && !self.auto_imports_allowed_for_inferred_projects
+                {
+                    options.module = Some("none".to_string());
+                }
+                options
+            }
+        };
+        if self.is_single_file_mode() {
+            options.no_resolve = true;
         }
 
         let binding_lib_files = match if options.no_lib {
@@ -114,11 +127,17 @@ impl Server {
 
         // Client-supplied `open_files` content is never normalized — preserves
         // tsc-equivalent behavior for paths like `/fourslash.ts`. See #3799.
-        let mut files: Vec<(String, String)> = self
-            .open_files
-            .iter()
-            .map(|(path, raw)| (path.clone(), raw.clone()))
-            .collect();
+        let mut files: Vec<(String, String)> = match configured_project.as_ref() {
+            Some(project) => self.configured_project_program_files(project),
+            None => self
+                .open_files
+                .iter()
+                .map(|(path, raw)| (path.clone(), raw.clone()))
+                .collect(),
+        };
+        if self.is_single_file_mode() {
+            files.clear();
+        }
         if let Some((_, existing)) = files.iter_mut().find(|(path, _)| path == file_path) {
             *existing = content.to_string();
         } else {
@@ -126,11 +145,13 @@ impl Server {
         }
         files.retain(|(path, _)| Self::is_checkable_file(path));
 
-        let program = parallel::merge_bind_results(parallel::parse_and_bind_parallel_with_libs(
-            files,
-            &binding_lib_files,
-        ));
-        let checker_options = self.build_checker_options(&options);
+        // Unchanged files reuse their previous parse/bind; only files edited
+        // since the last query are re-parsed.
+        let program = self.bind_cache.bind_program(files, &binding_lib_files);
+        let mut checker_options = self.build_checker_options(&options);
+        // Suggestion requests ask the checker for its TS80xxx stream; the
+        // error path leaves it off so checking stays as cheap as before.
+        checker_options.report_suggestions = category == DiagnosticCategory::Suggestion;
         let lib_contexts: Vec<LibContext> = checker_lib_files
             .iter()
             .map(|lib| LibContext {
@@ -214,6 +235,12 @@ impl Server {
         };
         program_context.build_global_indices();
 
+        // Parsing and binding the program is the first unit of work; checking
+        // the requested file is the second.
+        if self.cancellation.is_cancelled() {
+            return Vec::new();
+        }
+
         let mut diagnostics: Vec<tsz::checker::diagnostics::Diagnostic> = Vec::new();
         for (file_idx, file) in program.files.iter().enumerate() {
             // Only run the checker for the file we need diagnostics from.
@@ -261,6 +288,9 @@ impl Server {
             checker.ctx.set_current_file_idx(file_idx);
             checker.check_source_file(file.source_file);
 
+            if category == DiagnosticCategory::Suggestion {
+                diagnostics.extend(checker.ctx.suggestion_diagnostics.drain(..));
+            }
             diagnostics.extend(
                 checker
                     .ctx
@@ -335,7 +365,7 @@ impl Server {
                 .and_then(|v| v.as_str())
                 .map(std::string::ToString::to_string)
                 .or_else(|| self.open_files.get(&file).cloned())
-                .or_else(|| std::fs::read_to_string(&file).ok())?;
+                .or_else(|| tsz::compiler_host::read_file(std::path::Path::new(&file)).ok())?;
 
             let mut files = FxHashMap::default();
             files.insert(file.clone(), content);
@@ -983,6 +1013,8 @@ impl Server {
                 .unwrap_or(options.strict),
             erasable_syntax_only: options.erasable_syntax_only,
             no_fallthrough_cases_in_switch: options.no_fallthrough_cases_in_switch,
+            no_error_truncation: options.no_error_truncation,
+            report_suggestions: false,
         }
     }
 }
@@ -0,0 +1,387 @@
+//! Configured projects for tsz-server.
+//!
+//! A file that lives under a `tsconfig.json`/`jsconfig.json` and is matched by
+//! its `files`/`include`/`exclude` selectors belongs to that config's project.
+//! Semantic requests for such a file are answered against the whole project
+//! (root files read from disk when they are not open) with the config's
+//! `compilerOptions`, loaded by the shared tsconfig loader (`extends`,
+//! `${configDir}` and path anchoring included) with open configs read from
+//! their editor snapshots. Files outside any config keep using the inferred
+//! project built from the open files.
+//!
+//! Loaded projects are cached by config path together with the disk text of
+//! their unopened root files. An entry is rebuilt when the open files it was
+//! built from change (an opened, closed or edited config, or a source file
+//! opened or closed under its include root); on-disk changes are reported by
+//! the file watchers, which drop the entry.
+
+use super::{CheckOptions, Server};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+use tracing::warn;
+use tsz::compiler_host::{self, CompilerHostScope, MemoryCompilerHost, RealCompilerHost};
+use tsz::config::TsConfig;
+
+/// A project rooted at a tsconfig/jsconfig file.
+#[derive(Clone, Debug)]
+pub(crate) struct ConfiguredProject {
+    pub(crate) config_path: String,
+    /// The config and every config it extends, nearest first.
+    pub(crate) config_files: Vec<String>,
+    pub(crate) options: CheckOptions,
+    /// The config with its `extends` chain applied.
+    pub(crate) config: TsConfig,
+    /// `config.compilerOptions` as JSON, for requests that echo options.
+    pub(crate) compiler_options: serde_json::Map<String, serde_json::Value>,
+    pub(crate) root_files: Vec<String>,
+}
+
+/// Modification time of a file or directory, `None` when it does not exist.
+pub(crate) fn modified_time(path: &str) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Configured projects loaded so far, keyed by config path.
+#[derive(Default)]
+pub(crate) struct ConfiguredProjectCache {
+    entries: RefCell<FxHashMap<String, CachedProject>>,
+    /// Why the last attempt to load a config failed, by config path.
+    errors: RefCell<FxHashMap<String, String>>,
+}
+
+struct CachedProject {
+    project: ConfiguredProject,
+    /// Directory the root-file selectors are relative to.
+    include_root: PathBuf,
+    /// The open files the project was built from: open configs in its
+    /// `extends` chain (with their text) and open sources under the include
+    /// root (path only).
+    open_inputs: Vec<(String, Option<String>)>,
+    /// Modification times of the unopened config chain, root files and the
+    /// directories holding them, taken when the project was built.
+    input_stamps: FxHashMap<String, Option<SystemTime>>,
+    /// Disk text of unopened root files, read on first use.
+    disk_texts: FxHashMap<String, Option<String>>,
+}
+
+impl ConfiguredProjectCache {
+    /// Drop the cached project for `config_path`.
+    pub(crate) fn invalidate(&self, config_path: &str) {
+        self.entries.borrow_mut().remove(config_path);
+    }
+
+    pub(crate) fn clear(&self) {
+        self.entries.borrow_mut().clear();
+        self.errors.borrow_mut().clear();
+    }
+
+    /// Why `config_path` could not be loaded, when its last load failed.
+    pub(crate) fn error(&self, config_path: &str) -> Option<String> {
+        self.errors.borrow().get(config_path).cloned()
+    }
+
+    /// The input stamps recorded when `config_path`'s project was built, if
+    /// it is cached.
+    pub(crate) fn input_stamps(
+        &self,
+        config_path: &str,
+    ) -> Option<FxHashMap<String, Option<SystemTime>>> {
+        self.entries
+            .borrow()
+            .get(config_path)
+            .map(|entry| entry.input_stamps.clone())
+    }
+}
+
+impl Server {
+    /// The configured project that owns `file`, if any. Files under a config
+    /// that its selectors do not match stay in the inferred project, as in
+    /// tsserver.
+    pub(crate) fn configured_project_for_file(&self, file: &str) -> Option<ConfiguredProject> {
+        let config_path = self.find_project_config_file(file)?;
+        let project = self.load_configured_project(&config_path)?;
+        project
+            .root_files
+            .iter()
+            .any(|root| root == file)
+            .then_some(project)
+    }
+
+    pub(crate) fn load_configured_project(&self, config_path: &str) -> Option<ConfiguredProject> {
+        if let Some(project) = self.cached_configured_project(config_path) {
+            return Some(project);
+        }
+
+        let (project, include_root) = match self.build_configured_project(config_path) {
+            Ok(built) => built,
+            Err(err) => {
+                warn!("failed to load project config {config_path}: {err}");
+                self.configured_projects
+                    .errors
+                    .borrow_mut()
+                    .insert(config_path.to_string(), err);
+                return None;
+            }
+        };
+        self.configured_projects
+            .errors
+            .borrow_mut()
+            .remove(config_path);
+        let open_inputs = self.configured_project_open_inputs(&project, &include_root);
+        let input_stamps = self.configured_project_input_stamps(&project, &include_root);
+        self.configured_projects.entries.borrow_mut().insert(
+            config_path.to_string(),
+            CachedProject {
+                project: project.clone(),
+                include_root,
+                open_inputs,
+                input_stamps,
+                disk_texts: FxHashMap::default(),
+            },
+        );
+        Some(project)
+    }
+
+    /// The cached project for `config_path`, if one is loaded and the open
+    /// files it was built from are unchanged. Never reads the disk.
+    pub(crate) fn cached_configured_project(&self, config_path: &str) -> Option<ConfiguredProject> {
+        let entries = self.configured_projects.entries.borrow();
+        let entry = entries.get(config_path)?;
+        self.is_cached_project_current(entry)
+            .then(|| entry.project.clone())
+    }
+
+    pub(crate) fn has_cached_configured_project(&self, config_path: &str) -> bool {
+        self.configured_projects
+            .entries
+            .borrow()
+            .get(config_path)
+            .is_some_and(|entry| self.is_cached_project_current(entry))
+    }
+
+    fn is_cached_project_current(&self, entry: &CachedProject) -> bool {
+        entry.open_inputs
+            == self.configured_project_open_inputs(&entry.project, &entry.include_root)
+    }
+
+    fn build_configured_project(
+        &self,
+        config_path: &str,
+    ) -> Result<(ConfiguredProject, PathBuf), String> {
+        let (config, config_files) = self.load_tsconfig(config_path)?;
+        let compiler_options = match &config.compiler_options {
+            Some(options) => match serde_json::to_value(options).map_err(|err| err.to_string())? {
+                serde_json::Value::Object(mut map) => {
+                    map.retain(|_, value| !value.is_null());
+                    map
+                }
+                _ => serde_json::Map::new(),
+            },
+            None => serde_json::Map::new(),
+        };
+        let options = serde_json::from_value(serde_json::Value::Object(compiler_options.clone()))
+            .map_err(|err| format!("invalid compilerOptions: {err}"))?;
+
+        let config_dir = Path::new(config_path)
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("/"));
+        let selectors = Self::config_selectors(&config, &config_dir);
+        // A config that only exists as an open file describes a virtual
+        // project; walking the real directory tree under it would pull in
+        // unrelated files (or the whole disk for `/tsconfig.json`).
+        let scan_disk = !self.open_files.contains_key(config_path);
+        let root_files = self.tsconfig_project_files(&selectors, &config_dir, scan_disk);
+
+        Ok((
+            ConfiguredProject {
+                config_path: config_path.to_string(),
+                config_files,
+                options,
+                config,
+                compiler_options,
+                root_files,
+            },
+            config_dir,
+        ))
+    }
+
+    /// Load `config_path` with the shared tsconfig loader, reading open
+    /// configs from their editor snapshots. Returns the merged config and
+    /// every config file in its `extends` chain, nearest first.
+    pub(crate) fn load_tsconfig(
+        &self,
+        config_path: &str,
+    ) -> Result<(TsConfig, Vec<String>), String> {
+        let host = MemoryCompilerHost::overlay("/", Arc::new(RealCompilerHost));
+        for (path, text) in &self.open_files {
+            if path.ends_with(".json") {
+                host.add_file(path, text.as_str());
+            }
+        }
+        let _scope = CompilerHostScope::enter(Some(Arc::new(host)));
+        let (config, chain) = tsz::config::load_tsconfig_with_chain(Path::new(config_path))
+            .map_err(|err| format!("{err:#}"))?;
+        let config_files = chain
+            .iter()
+            .map(|path| Self::normalize_path_string(path))
+            .collect();
+        Ok((config, config_files))
+    }
+
+    /// The merged config's root-file selectors as `tsconfig_project_files`
+    /// expects them. Selectors inherited from a base config come back
+    /// anchored to its directory, so `include`/`exclude` are made relative
+    /// to `config_dir` again.
+    fn config_selectors(config: &TsConfig, config_dir: &Path) -> serde_json::Value {
+        let relative = |patterns: &[String]| -> Vec<String> {
+            patterns
+                .iter()
+                .map(|pattern| {
+                    let path = Path::new(pattern);
+                    if !path.is_absolute() {
+                        return pattern.clone();
+                    }
+                    match path.strip_prefix(config_dir) {
+                        Ok(rest) if rest.as_os_str().is_empty() => "**/*".to_string(),
+                        Ok(rest) => Self::normalize_path_string(rest),
+                        Err(_) => pattern.trim_start_matches('/').to_string(),
+                    }
+                })
+                .collect()
+        };
+        let mut selectors = serde_json::Map::new();
+        if let Some(files) = &config.files {
+            selectors.insert("files".to_string(), serde_json::json!(files));
+        }
+        if let Some(include) = &config.include {
+            selectors.insert("include".to_string(), serde_json::json!(relative(include)));
+        }
+        if let Some(exclude) = &config.exclude {
+            selectors.insert("exclude".to_string(), serde_json::json!(relative(exclude)));
+        }
+        serde_json::Value::Object(selectors)
+    }
+
+    fn configured_project_open_inputs(
+        &self,
+        project: &ConfiguredProject,
+        include_root: &Path,
+    ) -> Vec<(String, Option<String>)> {
+        let mut inputs: Vec<(String, Option<String>)> = self
+            .open_files
+            .iter()
+            .filter_map(|(path, text)| {
+                if project.config_files.contains(path) {
+                    Some((path.clone(), Some(text.clone())))
+                } else if Self::path_is_under(path, include_root)
+                    && Self::is_supported_project_source_file(path)
+                {
+                    Some((path.clone(), None))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        inputs.sort();
+        inputs
+    }
+
+    /// Stamp the project's unopened config chain and root files, plus every
+    /// directory from the include root down to each root file so that added
+    /// or removed sources change a stamp too.
+    fn configured_project_input_stamps(
+        &self,
+        project: &ConfiguredProject,
+        include_root: &Path,
+    ) -> FxHashMap<String, Option<SystemTime>> {
+        let mut paths: FxHashSet<String> = project
+            .config_files
+            .iter()
+            .chain(&project.root_files)
+            .filter(|path| !self.open_files.contains_key(*path))
+            .cloned()
+            .collect();
+        // A virtual project (open config) never scans the disk, so only
+        // disk-backed projects need their directories stamped.
+        if !self.open_files.contains_key(&project.config_path) {
+            paths.insert(Self::normalize_path_string(include_root));
+            for root in &project.root_files {
+                let mut dir = Path::new(root).parent();
+                while let Some(current) = dir
+                    && current.starts_with(include_root)
+                {
+                    if !paths.insert(Self::normalize_path_string(current)) {
+                        break;
+                    }
+                    dir = current.parent();
+                }
+            }
+        }
+        paths
+            .into_iter()
+            .map(|path| {
+                let modified = modified_time(&path);
+                (path, modified)
+            })
+            .collect()
+    }
+
+    /// Source texts for a configured project's program: its root files (open
+    /// snapshot first, then disk) plus any other open file governed by the
+    /// same config, such as an opened declaration file under `node_modules`.
+    pub(crate) fn configured_project_program_files(
+        &self,
+        project: &ConfiguredProject,
+    ) -> Vec<(String, String)> {
+        let mut seen: FxHashSet<&str> = FxHashSet::default();
+        let mut files = Vec::new();
+        for path in &project.root_files {
+            if !seen.insert(path.as_str()) {
+                continue;
+            }
+            let text = self
+                .open_files
+                .get(path)
+                .cloned()
+                .or_else(|| self.configured_project_disk_text(&project.config_path, path));
+            if let Some(text) = text {
+                files.push((path.clone(), text));
+            }
+        }
+
+        let mut open_members: Vec<(&String, &String)> = self
+            .open_files
+            .iter()
+            .filter(|(path, _)| !seen.contains(path.as_str()))
+            .filter(|(path, _)| {
+                self.find_project_config_file(path).as_deref() == Some(project.config_path.as_str())
+            })
+            .collect();
+        open_members.sort_by_key(|(path, _)| *path);
+        files.extend(
+            open_members
+                .into_iter()
+                .map(|(path, text)| (path.clone(), text.clone())),
+        );
+        files
+    }
+
+    /// Disk text of a root file, memoized in the project's cache entry.
+    fn configured_project_disk_text(&self, config_path: &str, path: &str) -> Option<String> {
+        let mut entries = self.configured_projects.entries.borrow_mut();
+        let Some(entry) = entries.get_mut(config_path) else {
+            return compiler_host::read_file(Path::new(path)).ok();
+        };
+        entry
+            .disk_texts
+            .entry(path.to_string())
+            .or_insert_with(|| compiler_host::read_file(Path::new(path)).ok())
+            .clone()
+    }
+}
@@ -0,0 +1,336 @@
+//! Single-file emit for tsz-server's `emit-output` and compile-on-save.
+//!
+//! A file is emitted the way `tsz` would emit it as part of its project: the
+//! owning config's `compilerOptions` (merged along `extends`) select the
+//! module format, output locations, source maps and declarations. Files
+//! outside any config use the inferred project's `target` and `module`.
+//! Outputs are returned in memory; callers decide whether to write them.
+
+use super::Server;
+use std::path::{Path, PathBuf};
+use tsz::binder::BinderState;
+use tsz::checker::diagnostics::{Diagnostic, diagnostic_codes};
+use tsz::config::{CompilerOptions, ResolvedCompilerOptions, resolve_compiler_options};
+use tsz::declaration_emitter::DeclarationEmitter;
+use tsz::emitter::{ModuleKind, Printer, PrinterOptions};
+use tsz::parser::base::NodeIndex;
+use tsz::parser::node::NodeArena;
+use tsz_cli::driver::emit::{
+    append_inline_source_mapping_url, append_source_mapping_url, declaration_map_source_name,
+    declaration_output_path, js_output_path, map_output_info, new_line_str,
+};
+use tsz_cli::driver::resolution::implied_resolution_mode_for_file;
+
+pub(crate) struct EmittedFile {
+    pub(crate) name: String,
+    pub(crate) text: String,
+}
+
+pub(crate) struct FileEmitOutput {
+    pub(crate) output_files: Vec<EmittedFile>,
+    pub(crate) emit_skipped: bool,
+    pub(crate) write_byte_order_mark: bool,
+    /// Config errors and declaration emit errors, for `richResponse`.
+    pub(crate) diagnostics: Vec<Diagnostic>,
+}
+
+/// Emit options for one file, with output directories made absolute.
+struct FileEmitOptions {
+    resolved: ResolvedCompilerOptions,
+    base_dir: PathBuf,
+    root_dir: Option<PathBuf>,
+    out_dir: Option<PathBuf>,
+    declaration_dir: Option<PathBuf>,
+}
+
+impl Server {
+    /// Emit `file` with its project's compiler options. `None` when the file
+    /// cannot be read.
+    pub(crate) fn emit_file_outputs(&self, file: &str) -> Option<FileEmitOutput> {
+        let (options, config_error) = self.file_emit_options(file);
+        let (arena, binder, root, source_text) = self.parse_and_bind_file(file)?;
+        let mut output_files = Vec::new();
+        let mut diagnostics: Vec<Diagnostic> = config_error.into_iter().collect();
+        if !options.resolved.no_emit {
+            let input_path = Path::new(file);
+            if !options.resolved.emit_declaration_only
+                && let Some(js_path) = js_output_path(
+                    &options.base_dir,
+                    options.root_dir.as_deref(),
+                    options.out_dir.as_deref(),
+                    options.resolved.jsx,
+                    input_path,
+                )
+            {
+                output_files.extend(Self::emit_js_file(
+                    &arena,
+                    root,
+                    file,
+                    &source_text,
+                    &options,
+                    &js_path,
+                ));
+            }
+            if options.resolved.emit_declarations
+                && let Some(dts_path) = declaration_output_path(
+                    &options.base_dir,
+                    options.root_dir.as_deref(),
+                    options.declaration_dir.as_deref(),
+                    input_path,
+                )
+            {
+                let (files, declaration_diagnostics) = Self::emit_declaration_file(
+                    &arena,
+                    &binder,
+                    root,
+                    file,
+                    &source_text,
+                    &options,
+                    &dts_path,
+                );
+                output_files.extend(files);
+                diagnostics.extend(declaration_diagnostics);
+            }
+        }
+        Some(FileEmitOutput {
+            emit_skipped: output_files.is_empty(),
+            output_files,
+            write_byte_order_mark: options.resolved.emit_bom,
+            diagnostics,
+        })
+    }
+
+    /// Emit options for `file`, from its project's resolved config. A config
+    /// that fails to load or resolve is reported as an error diagnostic, and
+    /// the file is emitted with the inferred project's options instead.
+    fn file_emit_options(&self, file: &str) -> (FileEmitOptions, Option<Diagnostic>) {
+        let mut config_error = None;
+        let project = self.external_project_for_file(file).or_else(|| {
+            let config_path = self.find_project_config_file(file)?;
+            let project = self.load_configured_project(&config_path);
+            if project.is_none() {
+                config_error = self
+                    .configured_projects
+                    .error(&config_path)
+                    .map(|error| Self::config_error_diagnostic(&config_path, error));
+            }
+            project
+        });
+        let (compiler_options, base_dir) = match project {
+            Some(project) => {
+                let config_dir = Path::new(&project.config_path)
+                    .parent()
+                    .map_or_else(|| PathBuf::from("/"), Path::to_path_buf);
+                (
+                    project.config.compiler_options.unwrap_or_default(),
+                    config_dir,
+                )
+            }
+            None => {
+                let inferred = &self.inferred_check_options;
+                let options = CompilerOptions {
+                    target: inferred.target.clone(),
+                    module: inferred.module.clone(),
+                    declaration: inferred.declaration.then_some(true),
+                    ..CompilerOptions::default()
+                };
+                let base_dir = Path::new(file)
+                    .parent()
+                    .map_or_else(|| PathBuf::from("/"), Path::to_path_buf);
+                (options, base_dir)
+            }
+        };
+        // Emit never reads the lib files, so skip resolving them.
+        let compiler_options = CompilerOptions {
+            lib: None,
+            no_lib: Some(true),
+            ..compiler_options
+        };
+        let resolved = match resolve_compiler_options(Some(&compiler_options)) {
+            Ok(resolved) => resolved,
+            Err(err) => {
+                let config_path = self
+                    .find_project_config_file(file)
+                    .unwrap_or_else(|| file.to_string());
+                config_error.get_or_insert_with(|| {
+                    Self::config_error_diagnostic(&config_path, err.to_string())
+                });
+                let mut resolved = ResolvedCompilerOptions::default();
+                resolved.printer.always_strict = true;
+                resolved
+            }
+        };
+
+        // Inherited path options are already absolute; the config's own are
+        // relative to its directory.
+        let absolute = |dir: &Option<PathBuf>| dir.as_deref().map(|dir| base_dir.join(dir));
+        let out_dir = absolute(&resolved.out_dir);
+        let options = FileEmitOptions {
+            root_dir: absolute(&resolved.root_dir),
+            declaration_dir: absolute(&resolved.declaration_dir).or_else(|| out_dir.clone()),
+            out_dir,
+            base_dir,
+            resolved,
+        };
+        (options, config_error)
+    }
+
+    fn config_error_diagnostic(config_path: &str, message: String) -> Diagnostic {
+        Diagnostic::error(
+            config_path.to_string(),
+            0,
+            0,
+            message,
+            diagnostic_codes::COMPILER_OPTION_REQUIRES_A_VALUE_OF_TYPE,
+        )
+    }
+
+    fn emit_js_file(
+        arena: &NodeArena,
+        root: NodeIndex,
+        file: &str,
+        source_text: &str,
+        options: &FileEmitOptions,
+        js_path: &Path,
+    ) -> Vec<EmittedFile> {
+        let new_line = new_line_str(options.resolved.printer.new_line);
+        let mut printer_options = options.resolved.printer.clone();
+        Self::apply_file_module_format(&mut printer_options, Path::new(file), &options.base_dir);
+
+        let mut ctx = tsz::context::emit::EmitContext::with_options(printer_options.clone());
+        ctx.auto_detect_module = true;
+        let emit_plan = tsz::lowering::LoweringPass::new(arena, &ctx).run_plan(root);
+        let mut printer = Printer::with_emit_plan_and_options(arena, emit_plan, printer_options);
+        printer.set_auto_detect_module(true);
+        printer.set_source_text(source_text);
+        printer.set_source_map_text(source_text);
+
+        let map_info = if options.resolved.source_map || options.resolved.inline_source_map {
+            map_output_info(js_path)
+        } else {
+            None
+        };
+        if let Some((_, _, output_name)) = map_info.as_ref() {
+            printer.enable_source_map(output_name, file);
+        }
+        printer.emit(root);
+        let map_json = map_info
+            .as_ref()
+            .and_then(|_| printer.generate_source_map_json());
+        let mut contents = printer.take_output();
+
+        let mut map_file = None;
+        if let Some((map_path, map_name, _)) = map_info
+            && let Some(map_json) = map_json
+        {
+            if options.resolved.inline_source_map {
+                append_inline_source_mapping_url(&mut contents, &map_json, new_line);
+            } else {
+                append_source_mapping_url(&mut contents, &map_name, new_line);
+                map_file = Some(EmittedFile {
+                    name: Self::normalize_path_string(&map_path),
+                    text: map_json,
+                });
+            }
+        }
+
+        let mut files = vec![EmittedFile {
+            name: Self::normalize_path_string(js_path),
+            text: contents,
+        }];
+        files.extend(map_file);
+        files
+    }
+
+    fn emit_declaration_file(
+        arena: &NodeArena,
+        binder: &BinderState,
+        root: NodeIndex,
+        file: &str,
+        source_text: &str,
+        options: &FileEmitOptions,
+        dts_path: &Path,
+    ) -> (Vec<EmittedFile>, Vec<Diagnostic>) {
+        let new_line = new_line_str(options.resolved.printer.new_line);
+        let mut emitter = DeclarationEmitter::new(arena);
+        emitter.set_binder(Some(binder));
+        emitter.set_remove_comments(options.resolved.printer.remove_comments);
+        emitter.set_strip_internal(options.resolved.strip_internal);
+        emitter.set_strict_null_checks(options.resolved.checker.strict_null_checks);
+
+        let map_info = if options.resolved.declaration_map {
+            map_output_info(dts_path)
+        } else {
+            None
+        };
+        if let Some((map_path, _, output_name)) = map_info.as_ref() {
+            emitter.set_source_map_text(source_text);
+            let source_name = declaration_map_source_name(map_path, Path::new(file));
+            emitter.enable_source_map_without_sources_content(output_name, &source_name);
+        }
+        let mut contents = emitter.emit(root);
+        let diagnostics = emitter
+            .take_diagnostics()
+            .into_iter()
+            .map(|mut diag| {
+                diag.file = file.to_string();
+                diag
+            })
+            .collect();
+        let map_json = map_info
+            .as_ref()
+            .and_then(|_| emitter.generate_source_map_json());
+
+        let mut map_file = None;
+        if let Some((map_path, map_name, _)) = map_info
+            && let Some(map_json) = map_json
+        {
+            append_source_mapping_url(&mut contents, &map_name, new_line);
+            map_file = Some(EmittedFile {
+                name: Self::normalize_path_string(&map_path),
+                text: map_json,
+            });
+        }
+
+        let mut files = vec![EmittedFile {
+            name: Self::normalize_path_string(dts_path),
+            text: contents,
+        }];
+        files.extend(map_file);
+        (files, diagnostics)
+    }
+
+    /// Per-file module format, as in the CLI driver: node module kinds follow
+    /// the file's implied format, `.cts`/`.cjs` are always CommonJS and
+    /// `.mts`/`.mjs` always ESM.
+    fn apply_file_module_format(
+        printer_options: &mut PrinterOptions,
+        input_path: &Path,
+        base_dir: &Path,
+    ) {
+        let file_name = input_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        let is_cts_or_cjs = file_name.ends_with(".cts") || file_name.ends_with(".cjs");
+        let is_mts_or_mjs = file_name.ends_with(".mts") || file_name.ends_with(".mjs");
+
+        if printer_options.module.is_node_module() {
+            if implied_resolution_mode_for_file(input_path, base_dir) == "import" {
+                printer_options.module = ModuleKind::ESNext;
+                printer_options.resolved_node_module_to_esm = true;
+            } else {
+                printer_options.module = ModuleKind::CommonJS;
+                printer_options.resolved_node_module_to_cjs = true;
+            }
+        } else if is_cts_or_cjs && !printer_options.module.is_commonjs() {
+            printer_options.module = ModuleKind::CommonJS;
+        } else if is_mts_or_mjs && !printer_options.module.is_es_module() {
+            printer_options.module = ModuleKind::ESNext;
+        }
+        if is_cts_or_cjs || is_mts_or_mjs {
+            printer_options.module_detection_force = true;
+        }
+    }
+}
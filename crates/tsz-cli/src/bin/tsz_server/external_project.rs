@@ -0,0 +1,159 @@
+//! External projects for tsz-server.
+//!
+//! Hosts that do not use tsconfig files (Visual Studio, custom IDEs) describe
+//! projects with `openExternalProject`: a project name, its root files and the
+//! compiler options to check them with. A root file belongs to that project
+//! rather than to a configured or inferred one; its text is the snapshot sent
+//! in the request, an open-file snapshot, or the disk contents, in that order.
+
+use super::configured_project::ConfiguredProject;
+use super::{CheckOptions, Server};
+use tsz::config::TsConfig;
+
+/// Per-project state for a project opened with `openExternalProject`. The
+/// root files themselves live in `Server::external_project_files`.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ExternalProject {
+    /// The raw `options` payload from the request.
+    pub(crate) compiler_options: serde_json::Map<String, serde_json::Value>,
+    /// Root files whose text was supplied inline and stored as a snapshot.
+    /// These are dropped again when the last project owning them closes.
+    pub(crate) snapshot_files: Vec<String>,
+}
+
+impl Server {
+    /// Create (or replace) the external project described by one
+    /// `openExternalProject` payload.
+    pub(crate) fn open_external_project(&mut self, project: &serde_json::Value) {
+        let Some(project_name) = project
+            .get("projectFileName")
+            .and_then(serde_json::Value::as_str)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+        else {
+            return;
+        };
+        // Re-opening a project updates it in place, as in tsserver.
+        self.close_external_project(&project_name);
+
+        let mut root_files = Vec::new();
+        let mut snapshot_files = Vec::new();
+        for entry in project
+            .get("rootFiles")
+            .and_then(serde_json::Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let Some(file_name) = entry.get("fileName").and_then(serde_json::Value::as_str) else {
+                continue;
+            };
+            // A file the client opened itself keeps the client's text.
+            if let Some(content) = entry.get("content").and_then(serde_json::Value::as_str)
+                && (!self.open_files.contains_key(file_name)
+                    || self.is_external_project_snapshot(file_name))
+            {
+                self.open_files
+                    .insert(file_name.to_string(), content.to_string());
+                snapshot_files.push(file_name.to_string());
+            }
+            root_files.push(file_name.to_string());
+        }
+
+        let compiler_options = project
+            .get("options")
+            .and_then(serde_json::Value::as_object)
+            .cloned()
+            .unwrap_or_default();
+        self.external_project_files
+            .insert(project_name.clone(), root_files);
+        self.external_projects.insert(
+            project_name,
+            ExternalProject {
+                compiler_options,
+                snapshot_files,
+            },
+        );
+    }
+
+    /// Drop an external project and any inline snapshots no other external
+    /// project still owns. Files the client opened directly stay open.
+    pub(crate) fn close_external_project(&mut self, project_name: &str) {
+        self.external_project_files.remove(project_name);
+        let Some(project) = self.external_projects.remove(project_name) else {
+            return;
+        };
+        for file in project.snapshot_files {
+            if !self.is_external_project_snapshot(&file) {
+                self.open_files.remove(&file);
+            }
+        }
+    }
+
+    /// The external project that lists `file` as a root, if any. Projects are
+    /// searched by name so a file shared between projects resolves stably.
+    pub(crate) fn external_project_for_file(&self, file: &str) -> Option<ConfiguredProject> {
+        let mut projects: Vec<(&String, &Vec<String>)> = self
+            .external_project_files
+            .iter()
+            .filter(|(_, root_files)| root_files.iter().any(|root| root == file))
+            .collect();
+        projects.sort_by_key(|(project_name, _)| *project_name);
+        let (project_name, root_files) = projects.into_iter().next()?;
+
+        let mut compiler_options = self
+            .external_projects
+            .get(project_name)
+            .map(|project| project.compiler_options.clone())
+            .unwrap_or_default();
+        let options: CheckOptions =
+            serde_json::from_value(serde_json::Value::Object(compiler_options.clone()))
+                .unwrap_or_default();
+        // Hosts send `target`/`module` as enum numbers; keep the names so the
+        // raw options read like a tsconfig's for emit.
+        for (key, value) in [("target", &options.target), ("module", &options.module)] {
+            if let Some(value) = value {
+                compiler_options.insert(key.to_string(), serde_json::json!(value));
+            }
+        }
+        // Options still spelled as host enum numbers do not read as a
+        // tsconfig's; emit then falls back to the names recorded above.
+        let config = TsConfig {
+            compiler_options: serde_json::from_value(serde_json::Value::Object(
+                compiler_options.clone(),
+            ))
+            .ok(),
+            ..TsConfig::default()
+        };
+        Some(ConfiguredProject {
+            config_path: project_name.clone(),
+            config_files: Vec::new(),
+            config,
+            options,
+            compiler_options,
+            root_files: root_files.clone(),
+        })
+    }
+
+    /// The explicit project that owns `file`: an external project listing it
+    /// as a root, otherwise the configured project matching it. Single-file
+    /// server modes have no explicit projects.
+    pub(crate) fn project_for_file(&self, file: &str) -> Option<ConfiguredProject> {
+        if self.is_single_file_mode() {
+            return None;
+        }
+        self.external_project_for_file(file)
+            .or_else(|| self.configured_project_for_file(file))
+    }
+
+    /// Whether `project` came from `openExternalProject` rather than a
+    /// config file.
+    pub(crate) fn is_external_project(&self, project: &ConfiguredProject) -> bool {
+        self.external_projects.contains_key(&project.config_path)
+    }
+
+    fn is_external_project_snapshot(&self, file: &str) -> bool {
+        self.external_projects
+            .values()
+            .any(|project| project.snapshot_files.iter().any(|path| path == file))
+    }
+}
@@ -0,0 +1,285 @@
+//! File watching for configured projects.
+//!
+//! A watcher only has to notice that a project's inputs changed, drop the
+//! cached project so the next semantic request reloads it, and tell the
+//! client through `projectsUpdatedInBackground`, listing the open files whose
+//! diagnostics may now be stale.
+//!
+//! - By default the server polls, while idle, the modification times of each
+//!   watched project's config chain, unopened root files and the directories
+//!   holding them, as recorded when the project was loaded. Only those paths
+//!   are stat'ed; a change drops the cached project and the stamps are taken
+//!   again once a request has reloaded it. Added or removed root files show
+//!   up as a changed directory stamp.
+//! - With `--canUseWatchEvents` the editor does the watching: the server asks
+//!   for watchers with `createFileWatcher`/`createDirectoryWatcher` events,
+//!   releases them with `closeFileWatcher`, and learns about changes from
+//!   `watchChange` requests.
+
+use super::configured_project::{ConfiguredProject, modified_time};
+use super::{Server, TsServerRequest, TsServerResponse};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// How long the protocol runner waits for a request before polling watchers.
+pub(crate) const FILE_WATCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Watched configured projects, keyed by config path.
+#[derive(Default)]
+pub(crate) struct FileWatchState {
+    projects: FxHashMap<String, WatchedProject>,
+    next_watcher_id: u64,
+    /// The sorted open-file paths the projects were grouped from.
+    open_files: Vec<String>,
+    /// Configs governing open files whose project was not loaded yet.
+    pending_configs: FxHashSet<String>,
+}
+
+struct WatchedProject {
+    /// Modification times of the project's inputs (polling mode), `None`
+    /// until the project is loaded again after a change.
+    stamps: Option<FxHashMap<String, Option<SystemTime>>>,
+    /// Open files belonging to the project (polling mode).
+    open_files: Vec<String>,
+    /// Client-side watcher ids (watch-events mode).
+    watcher_ids: Vec<u64>,
+}
+
+impl Server {
+    /// Bring the watched set in line with the open files and, in polling
+    /// mode, report projects whose inputs changed since the last poll.
+    pub(crate) fn poll_file_watchers(&mut self) {
+        if self.can_use_watch_events {
+            self.sync_client_file_watchers();
+            return;
+        }
+
+        self.refresh_watched_projects();
+        let mut affected: BTreeSet<String> = BTreeSet::new();
+        let mut changed: Vec<String> = Vec::new();
+        for (config_path, watched) in &mut self.file_watch.projects {
+            let Some(stamps) = &watched.stamps else {
+                continue;
+            };
+            if stamps
+                .iter()
+                .any(|(path, stamp)| modified_time(path) != *stamp)
+            {
+                affected.extend(watched.open_files.iter().cloned());
+                watched.stamps = None;
+                changed.push(config_path.clone());
+            }
+        }
+        for config_path in &changed {
+            self.configured_projects.invalidate(config_path);
+        }
+
+        self.emit_projects_updated(affected);
+    }
+
+    /// Regroup open files by project when the open set changed or a project
+    /// they wait on has been reloaded, and take stamps for watched projects
+    /// reloaded since the last poll.
+    fn refresh_watched_projects(&mut self) {
+        let mut open_files: Vec<String> = self.open_files.keys().cloned().collect();
+        open_files.sort();
+        let reloaded = |config_path: &String| self.has_cached_configured_project(config_path);
+        let regroup = open_files != self.file_watch.open_files
+            || self.file_watch.pending_configs.iter().any(reloaded)
+            || self
+                .file_watch
+                .projects
+                .iter()
+                .any(|(config_path, watched)| watched.stamps.is_none() && reloaded(config_path));
+        if regroup {
+            let (groups, pending_configs) = self.open_files_by_watched_project(&open_files);
+            let mut projects = FxHashMap::default();
+            for (config_path, members) in groups {
+                let stamps = self
+                    .file_watch
+                    .projects
+                    .remove(&config_path)
+                    .and_then(|previous| previous.stamps);
+                projects.insert(
+                    config_path,
+                    WatchedProject {
+                        stamps,
+                        open_files: members,
+                        watcher_ids: Vec::new(),
+                    },
+                );
+            }
+            self.file_watch.projects = projects;
+            self.file_watch.pending_configs = pending_configs;
+            self.file_watch.open_files = open_files;
+        }
+
+        for (config_path, watched) in &mut self.file_watch.projects {
+            if watched.stamps.is_none() {
+                watched.stamps = self.configured_projects.input_stamps(config_path);
+            }
+        }
+    }
+
+    /// `watchChange`: the client saw files change under watchers it created
+    /// for us. Accepts a single change object or an array of them.
+    pub(crate) fn handle_watch_change(
+        &mut self,
+        seq: u64,
+        request: &TsServerRequest,
+    ) -> TsServerResponse {
+        let changes = match &request.arguments {
+            serde_json::Value::Array(changes) => changes.iter().collect::<Vec<_>>(),
+            change => vec![change],
+        };
+        let changed_ids: FxHashSet<u64> = changes
+            .into_iter()
+            .filter_map(|change| change.get("id").and_then(serde_json::Value::as_u64))
+            .collect();
+
+        let changed_configs: FxHashSet<&str> = self
+            .file_watch
+            .projects
+            .iter()
+            .filter(|(_, watched)| {
+                watched
+                    .watcher_ids
+                    .iter()
+                    .any(|id| changed_ids.contains(id))
+            })
+            .map(|(config_path, _)| config_path.as_str())
+            .collect();
+        for config_path in &changed_configs {
+            self.configured_projects.invalidate(config_path);
+        }
+        let affected: BTreeSet<String> = self
+            .open_files
+            .keys()
+            .filter(|file| {
+                self.find_project_config_file(file)
+                    .is_some_and(|config_path| changed_configs.contains(config_path.as_str()))
+            })
+            .cloned()
+            .collect();
+
+        self.emit_projects_updated(affected);
+        self.acknowledge_response(seq, request)
+    }
+
+    /// Watch-events mode: ask the client to watch each config (and the
+    /// directory tree it governs) that has open files, and close watchers for
+    /// configs that no longer do. Membership is not resolved here, so this
+    /// stays cheap enough to run after every request.
+    pub(crate) fn sync_client_file_watchers(&mut self) {
+        let configs: BTreeSet<String> = self
+            .open_files
+            .keys()
+            .filter_map(|file| self.find_project_config_file(file))
+            .collect();
+
+        let stale: Vec<String> = self
+            .file_watch
+            .projects
+            .keys()
+            .filter(|config_path| !configs.contains(*config_path))
+            .cloned()
+            .collect();
+        for config_path in stale {
+            let Some(watched) = self.file_watch.projects.remove(&config_path) else {
+                continue;
+            };
+            for id in watched.watcher_ids {
+                self.emit_event("closeFileWatcher", serde_json::json!({ "id": id }));
+            }
+        }
+
+        for config_path in configs {
+            if self.file_watch.projects.contains_key(&config_path) {
+                continue;
+            }
+            let file_watcher_id = self.next_file_watcher_id();
+            self.emit_event(
+                "createFileWatcher",
+                serde_json::json!({ "id": file_watcher_id, "path": &config_path }),
+            );
+            let mut watcher_ids = vec![file_watcher_id];
+            if let Some(config_dir) = Path::new(&config_path).parent() {
+                let directory_watcher_id = self.next_file_watcher_id();
+                self.emit_event(
+                    "createDirectoryWatcher",
+                    serde_json::json!({
+                        "id": directory_watcher_id,
+                        "path": Self::normalize_path_string(config_dir),
+                        "recursive": true,
+                    }),
+                );
+                watcher_ids.push(directory_watcher_id);
+            }
+            self.file_watch.projects.insert(
+                config_path,
+                WatchedProject {
+                    stamps: None,
+                    open_files: Vec::new(),
+                    watcher_ids,
+                },
+            );
+        }
+    }
+
+    const fn next_file_watcher_id(&mut self) -> u64 {
+        self.file_watch.next_watcher_id += 1;
+        self.file_watch.next_watcher_id
+    }
+
+    /// Open files grouped by the configured project that owns them, plus the
+    /// configs of open files whose project is waiting to be reloaded. A
+    /// project seen for the first time is loaded here to take its baseline;
+    /// one dropped after a change is only read back from the cache, so the
+    /// poll itself never re-walks its tree.
+    fn open_files_by_watched_project(
+        &self,
+        open_files: &[String],
+    ) -> (Vec<(String, Vec<String>)>, FxHashSet<String>) {
+        let mut projects: FxHashMap<String, Option<ConfiguredProject>> = FxHashMap::default();
+        let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+        let mut pending_configs = FxHashSet::default();
+        for file in open_files {
+            let Some(config_path) = self.find_project_config_file(file) else {
+                continue;
+            };
+            let project = projects.entry(config_path.clone()).or_insert_with(|| {
+                if self.file_watch.projects.contains_key(&config_path)
+                    || self.file_watch.pending_configs.contains(&config_path)
+                {
+                    self.cached_configured_project(&config_path)
+                } else {
+                    self.load_configured_project(&config_path)
+                }
+            });
+            let Some(project) = project else {
+                pending_configs.insert(config_path);
+                continue;
+            };
+            if !project.root_files.contains(file) {
+                continue;
+            }
+            match groups.iter_mut().find(|(path, _)| *path == config_path) {
+                Some((_, members)) => members.push(file.clone()),
+                None => groups.push((config_path, vec![file.clone()])),
+            }
+        }
+        (groups, pending_configs)
+    }
+
+    fn emit_projects_updated(&mut self, open_files: BTreeSet<String>) {
+        if open_files.is_empty() {
+            return;
+        }
+        self.emit_event(
+            "projectsUpdatedInBackground",
+            serde_json::json!({ "openFiles": open_files }),
+        );
+    }
+}
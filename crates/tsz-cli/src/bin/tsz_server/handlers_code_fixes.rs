@@ -1141,6 +1141,7 @@ impl Server {
                 "Class '{class_name}' incorrectly implements interface '{interface_name}'."
             ),
             related_information: Vec::new(),
+            spelling_suggestion: None,
         }]
     }
 
@@ -1163,6 +1164,7 @@ impl Server {
                 "'await' expressions are only allowed within async functions and at the top levels of modules."
                     .to_string(),
             related_information: Vec::new(),
+            spelling_suggestion: None,
         })
     }
 
@@ -1180,6 +1182,7 @@ impl Server {
             length: 1,
             message_text: "Parameter implicitly has an 'any' type.".to_string(),
             related_information: Vec::new(),
+            spelling_suggestion: None,
         })
     }
 
@@ -1197,6 +1200,7 @@ impl Server {
             length: 1,
             message_text: "Type '{}' is missing the following properties.".to_string(),
             related_information: Vec::new(),
+            spelling_suggestion: None,
         })
     }
 
@@ -1226,6 +1230,7 @@ impl Server {
             message_text: "Value of type is not callable. Did you mean to include 'new'?"
                 .to_string(),
             related_information: Vec::new(),
+            spelling_suggestion: None,
         })
     }
 
@@ -1298,6 +1303,7 @@ impl Server {
                         length: (name_end - name_rel) as u32,
                         message_text: format!("Cannot find name '{name}'."),
                         related_information: Vec::new(),
+                        spelling_suggestion: None,
                     });
                 }
             }
@@ -1325,6 +1331,7 @@ impl Server {
                         length: (name_end - name_rel) as u32,
                         message_text: format!("Cannot find name '{name}'."),
                         related_information: Vec::new(),
+                        spelling_suggestion: None,
                     });
                 }
             }
@@ -477,7 +477,7 @@ impl Server {
         }
     }
 
-    fn apply_text_edits_to_source(
+    pub(super) fn apply_text_edits_to_source(
         source: &str,
         line_map: &LineMap,
         edits: &[tsz::lsp::rename::TextEdit],
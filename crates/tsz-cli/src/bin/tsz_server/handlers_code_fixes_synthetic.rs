@@ -80,6 +80,7 @@ impl Server {
                         length: name_len as u32,
                         message_text: "JSDoc types may be moved to TypeScript types.".to_string(),
                         related_information: Vec::new(),
+                        spelling_suggestion: None,
                     });
                 }
             }
@@ -99,6 +100,7 @@ impl Server {
                         length: name_len as u32,
                         message_text: "JSDoc types may be moved to TypeScript types.".to_string(),
                         related_information: Vec::new(),
+                        spelling_suggestion: None,
                     });
                 }
             }
@@ -120,6 +122,7 @@ impl Server {
                         length: name_len as u32,
                         message_text: "JSDoc types may be moved to TypeScript types.".to_string(),
                         related_information: Vec::new(),
+                        spelling_suggestion: None,
                     });
                 }
             }
@@ -141,6 +144,7 @@ impl Server {
                             message_text: "JSDoc types may be moved to TypeScript types."
                                 .to_string(),
                             related_information: Vec::new(),
+                            spelling_suggestion: None,
                         });
                     }
                 }
@@ -229,6 +233,7 @@ impl Server {
                                 "Variable '{name}' implicitly has an 'any' type, but a better type may be inferred from usage."
                             ),
                             related_information: Vec::new(),
+                            spelling_suggestion: None,
                         });
                     }
                 }
@@ -261,6 +266,7 @@ impl Server {
                             "Parameter '{param_name}' implicitly has an 'any' type, but a better type may be inferred from usage."
                         ),
                         related_information: Vec::new(),
+                        spelling_suggestion: None,
                     });
                 }
             }
@@ -320,6 +326,7 @@ impl Server {
                                 length: name.len() as u32,
                                 message_text: format!("Cannot find name '{name}'."),
                                 related_information: Vec::new(),
+                                spelling_suggestion: None,
                             });
                         }
                     }
@@ -417,6 +424,7 @@ impl Server {
                         length: name.len() as u32,
                         message_text: format!("Cannot find name '{name}'."),
                         related_information: Vec::new(),
+                        spelling_suggestion: None,
                     });
                 }
             }
@@ -434,6 +442,7 @@ impl Server {
                     length: name.len() as u32,
                     message_text: format!("Cannot find name '{name}'."),
                     related_information: Vec::new(),
+                    spelling_suggestion: None,
                 });
             }
             if !skip_scanning {
@@ -494,6 +503,7 @@ impl Server {
                         length: name.len() as u32,
                         message_text: format!("Cannot find name '{name}'."),
                         related_information: Vec::new(),
+                        spelling_suggestion: None,
                     });
                 }
             }
@@ -532,6 +542,7 @@ impl Server {
                 length: ident.len() as u32,
                 message_text: format!("Cannot find name '{ident}'."),
                 related_information: Vec::new(),
+                spelling_suggestion: None,
             });
         }
     }
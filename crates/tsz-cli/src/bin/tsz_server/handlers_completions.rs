@@ -1829,6 +1829,7 @@ impl Server {
             Self::string_array_pref(preferences, "autoImportSpecifierExcludeRegexes")
                 .unwrap_or_default(),
         );
+        project.set_quote_preference(Self::string_pref(preferences, "quotePreference"));
         for (path, text) in files {
             project.set_file(path, text);
         }
@@ -2801,6 +2802,17 @@ impl Server {
             if let Some(default_commit_characters) = default_commit_characters {
                 response["defaultCommitCharacters"] = default_commit_characters;
             }
+            if let Some((start, end)) = completion_result
+                .as_ref()
+                .and_then(|r| r.optional_replacement_span)
+            {
+                let start_pos = line_map.offset_to_position(start, &source_text);
+                let end_pos = line_map.offset_to_position(end, &source_text);
+                response["optionalReplacementSpan"] = serde_json::json!({
+                    "start": Self::lsp_to_tsserver_position(start_pos),
+                    "end": Self::lsp_to_tsserver_position(end_pos),
+                });
+            }
 
             Some(response)
         })();
@@ -2,11 +2,23 @@
 //!
 //! Handles semantic, syntactic, and suggestion diagnostic commands.
 
-use super::{Server, TsServerRequest, TsServerResponse};
+use super::{Server, ServerMode, TsServerRequest, TsServerResponse};
 
-use tsz::checker::diagnostics::DiagnosticCategory;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use tsz::checker::diagnostics::{DiagnosticCategory, DiagnosticRelatedInformation};
 use tsz::lsp::position::LineMap;
 use tsz::parser::ParserState;
+use tsz_cli::locale;
+
+/// A `geterr`/`geterrForProject` request whose events have not all been sent.
+pub(crate) struct PendingGeterr {
+    pub(crate) request_seq: u64,
+    pub(crate) files: VecDeque<String>,
+    /// When the first event may be sent (`delay` after the request).
+    pub(crate) due: Instant,
+}
 
 pub(crate) struct DiagnosticFormatInput<'a> {
     pub(crate) start_offset: u32,
@@ -17,6 +29,12 @@ pub(crate) struct DiagnosticFormatInput<'a> {
     pub(crate) line_map: &'a LineMap,
     pub(crate) content: &'a str,
     pub(crate) include_line_position: bool,
+    /// Path of the file the diagnostic belongs to.
+    pub(crate) file: &'a str,
+    /// Related locations to report alongside the diagnostic.
+    pub(crate) related_information: &'a [DiagnosticRelatedInformation],
+    /// Text of another file a related location points into.
+    pub(crate) related_file_text: &'a dyn Fn(&str) -> Option<String>,
 }
 
 impl Server {
@@ -165,6 +183,7 @@ impl Server {
                         self.synthetic_implements_interface_diagnostics(file_path, &content),
                     );
                 }
+                let related_file_text = |file: &str| self.related_file_text(file);
                 full_diags
                     .iter()
                     .map(|diag| {
@@ -179,6 +198,9 @@ impl Server {
                             line_map: &line_map,
                             content: &content,
                             include_line_position,
+                            file: file_path,
+                            related_information: &diag.related_information,
+                            related_file_text: &related_file_text,
                         })
                     })
                     .collect()
@@ -233,6 +255,9 @@ impl Server {
                             line_map: &line_map,
                             content: &content,
                             include_line_position,
+                            file: file_path,
+                            related_information: &[],
+                            related_file_text: &|_: &str| None,
                         })
                     })
                     .collect()
@@ -265,7 +290,11 @@ impl Server {
             _ => "suggestion",
         };
 
-        if input.include_line_position {
+        let related_information = Self::format_related_information(&input);
+        // `--locale` selects the catalog; untranslated codes stay English.
+        let message = locale::translate(input.code, input.message);
+
+        let mut value = if input.include_line_position {
             let start = Self::utf16_offset_for_byte_offset(input.content, input.start_offset);
             let end = Self::utf16_offset_for_byte_offset(
                 input.content,
@@ -290,7 +319,7 @@ impl Server {
                     "line": end_pos.line + 1,
                     "offset": end_pos.character + 1,
                 },
-                "message": input.message,
+                "message": message,
                 "code": input.code,
                 "category": cat_str,
             })
@@ -305,11 +334,71 @@ impl Server {
                     "line": end_pos.line + 1,
                     "offset": end_pos.character + 1,
                 },
-                "text": input.message,
+                "text": message,
                 "code": input.code,
                 "category": cat_str,
             })
+        };
+        if !related_information.is_empty() {
+            value["relatedInformation"] = serde_json::Value::Array(related_information);
         }
+        value
+    }
+
+    /// Format the related locations of a diagnostic as tsserver
+    /// `DiagnosticRelatedInformation` entries (`span` as a `FileSpan`).
+    ///
+    /// Elaboration entries are part of the message chain rather than
+    /// locations, so only genuine related locations are reported.
+    fn format_related_information(input: &DiagnosticFormatInput<'_>) -> Vec<serde_json::Value> {
+        let mut other_files: rustc_hash::FxHashMap<&str, Option<(String, LineMap)>> =
+            rustc_hash::FxHashMap::default();
+        let mut formatted = Vec::new();
+        for related in input.related_information {
+            if !related.is_related_location() {
+                continue;
+            }
+            let (content, line_map) = if related.file == input.file {
+                (input.content, input.line_map)
+            } else {
+                let Some((content, line_map)) = other_files
+                    .entry(related.file.as_str())
+                    .or_insert_with(|| {
+                        (input.related_file_text)(&related.file).map(|content| {
+                            let line_map = LineMap::build(&content);
+                            (content, line_map)
+                        })
+                    })
+                    .as_ref()
+                else {
+                    continue;
+                };
+                (content.as_str(), line_map)
+            };
+            let start = line_map.offset_to_position(related.start, content);
+            let end =
+                line_map.offset_to_position(related.start.saturating_add(related.length), content);
+            formatted.push(serde_json::json!({
+                "span": {
+                    "start": { "line": start.line + 1, "offset": start.character + 1 },
+                    "end": { "line": end.line + 1, "offset": end.character + 1 },
+                    "file": related.file,
+                },
+                "message": locale::translate(related.code, &related.message_text),
+                "code": related.code,
+                "category": "message",
+            }));
+        }
+        formatted
+    }
+
+    /// Text of a file a related location points into: the open buffer when
+    /// the client has one, otherwise the file on disk.
+    pub(crate) fn related_file_text(&self, file: &str) -> Option<String> {
+        self.open_files
+            .get(file)
+            .cloned()
+            .or_else(|| tsz::compiler_host::read_file(std::path::Path::new(file)).ok())
     }
 
     fn utf16_offset_for_byte_offset(content: &str, byte_offset: u32) -> u32 {
@@ -419,6 +508,7 @@ impl Server {
                         file_path, &content,
                     ));
                 }
+                let related_file_text = |file: &str| self.related_file_text(file);
                 diags
                     .iter()
                     .map(|d| {
@@ -431,6 +521,9 @@ impl Server {
                             line_map: &line_map,
                             content: &content,
                             include_line_position,
+                            file: file_path,
+                            related_information: &d.related_information,
+                            related_file_text: &related_file_text,
                         })
                     })
                     .collect()
@@ -450,7 +543,7 @@ impl Server {
         request: &TsServerRequest,
     ) -> TsServerResponse {
         // tsserver acknowledges immediately, then asynchronously fires
-        // `syntaxDiag`, `semanticDiag`, `suggestionDiag`, and finally
+        // `syntaxDiag`, `semanticDiag`, `suggestionDiag` per file, and finally
         // `requestCompleted`. Without these events, clients see no
         // diagnostics. See https://github.com/mohsen1/tsz/issues/3544.
         let files: Vec<String> = request
@@ -459,20 +552,18 @@ impl Server {
             .and_then(|v| v.as_array())
             .map(|arr| {
                 arr.iter()
-                    .filter_map(|f| f.as_str().map(std::string::ToString::to_string))
+                    .filter_map(|f| {
+                        // VS Code sends either plain paths or
+                        // `{ file, projectFileName }` objects.
+                        f.as_str()
+                            .or_else(|| f.get("file").and_then(|file| file.as_str()))
+                            .map(std::string::ToString::to_string)
+                    })
                     .collect()
             })
             .unwrap_or_default();
 
-        for file in &files {
-            self.emit_geterr_events_for_file(file);
-        }
-
-        self.emit_event(
-            "requestCompleted",
-            serde_json::json!({"request_seq": request.seq}),
-        );
-
+        self.schedule_geterr(request, files);
         self.acknowledge_response(seq, request)
     }
 
@@ -481,20 +572,101 @@ impl Server {
         seq: u64,
         request: &TsServerRequest,
     ) -> TsServerResponse {
-        // Without a real project graph, behave like `geterr` over all
-        // currently-open files: emit per-file events plus a final
-        // `requestCompleted`. See #3544.
-        let files: Vec<String> = self.open_files.keys().cloned().collect();
-        for file in &files {
-            self.emit_geterr_events_for_file(file);
+        // Check every open file, starting with the one the request names.
+        let requested = request
+            .arguments
+            .get("file")
+            .and_then(|v| v.as_str())
+            .map(std::string::ToString::to_string);
+        let mut files: Vec<String> = self
+            .open_files
+            .keys()
+            .filter(|file| Some(*file) != requested.as_ref())
+            .cloned()
+            .collect();
+        files.sort();
+        if let Some(requested) = requested {
+            files.insert(0, requested);
         }
-        self.emit_event(
-            "requestCompleted",
-            serde_json::json!({"request_seq": request.seq}),
-        );
+
+        self.schedule_geterr(request, files);
         self.acknowledge_response(seq, request)
     }
 
+    /// Queue the diagnostics events for `files`. A newer `geterr` replaces
+    /// one that has not finished yet, which completes at once as in tsserver.
+    fn schedule_geterr(&mut self, request: &TsServerRequest, files: Vec<String>) {
+        self.cancel_pending_geterr();
+        let delay_ms = request
+            .arguments
+            .get("delay")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(0);
+        self.pending_geterr = Some(PendingGeterr {
+            request_seq: request.seq,
+            files: files.into(),
+            due: Instant::now() + Duration::from_millis(delay_ms),
+        });
+    }
+
+    /// How long until the pending `geterr` may send its next event; zero
+    /// once its delay has passed, `None` when nothing is pending.
+    pub(crate) fn pending_geterr_wait(&self) -> Option<Duration> {
+        self.pending_geterr
+            .as_ref()
+            .map(|pending| pending.due.saturating_duration_since(Instant::now()))
+    }
+
+    /// Drop the remaining files of the pending `geterr` and send its
+    /// `requestCompleted`. Edits to open files do this too, since the
+    /// diagnostics still queued would describe the old text.
+    pub(crate) fn cancel_pending_geterr(&mut self) {
+        if let Some(pending) = self.pending_geterr.take() {
+            self.emit_event(
+                "requestCompleted",
+                serde_json::json!({"request_seq": pending.request_seq}),
+            );
+        }
+    }
+
+    /// Run one step of the pending `geterr`: queue the diagnostics events for
+    /// its next file, or `requestCompleted` once every file is done.
+    ///
+    /// Returns `false` when there was nothing left to do. The protocol
+    /// runner writes the queued events after every step so clients see each
+    /// file's diagnostics as soon as they are ready.
+    ///
+    /// A cancelled `geterr` drops its remaining files and completes at once.
+    pub(crate) fn run_geterr_step(&mut self) -> bool {
+        let Some(request_seq) = self
+            .pending_geterr
+            .as_ref()
+            .map(|pending| pending.request_seq)
+        else {
+            return false;
+        };
+        self.cancellation.begin_request(request_seq);
+        let next_file = if self.cancellation.is_cancelled() {
+            None
+        } else {
+            self.pending_geterr
+                .as_mut()
+                .and_then(|pending| pending.files.pop_front())
+        };
+        match next_file {
+            Some(file) => self.emit_geterr_events_for_file(&file),
+            None => {
+                self.pending_geterr = None;
+                self.emit_event(
+                    "requestCompleted",
+                    serde_json::json!({"request_seq": request_seq}),
+                );
+            }
+        }
+        self.cancellation.end_request();
+        true
+    }
+
     /// Compute and emit `syntaxDiag`, `semanticDiag`, `suggestionDiag`
     /// events for a single file. Reuses the existing `*-Sync` handlers'
     /// diagnostic shapes (each returns a JSON body of `{file, diagnostics}`)
@@ -520,9 +692,18 @@ impl Server {
             serde_json::json!({"file": file, "diagnostics": syntax}),
         );
 
+        // A cancelled query returns no diagnostics, which must not be
+        // reported as the file's real (empty) diagnostics. A syntactic
+        // server has nothing beyond the parse to report.
+        if self.cancellation.is_cancelled() || self.server_mode == ServerMode::Syntactic {
+            return;
+        }
         let semantic = body_of(
             self.handle_semantic_diagnostics_sync(0, &synth_request("semanticDiagnosticsSync")),
         );
+        if self.cancellation.is_cancelled() {
+            return;
+        }
         self.emit_event(
             "semanticDiag",
             serde_json::json!({"file": file, "diagnostics": semantic}),
@@ -531,6 +712,9 @@ impl Server {
         let suggestion = body_of(
             self.handle_suggestion_diagnostics_sync(0, &synth_request("suggestionDiagnosticsSync")),
         );
+        if self.cancellation.is_cancelled() {
+            return;
+        }
         self.emit_event(
             "suggestionDiag",
             serde_json::json!({"file": file, "diagnostics": suggestion}),
@@ -2262,6 +2262,16 @@ impl Server {
                     "code": 5083,
                     "category": "error",
                 }));
+            } else if self.load_configured_project(&config_path).is_none()
+                && let Some(error) = self.configured_projects.error(&config_path)
+            {
+                diagnostics.push(serde_json::json!({
+                    "start": { "line": 1, "offset": 1 },
+                    "end": { "line": 1, "offset": 1 },
+                    "text": error,
+                    "code": 5024,
+                    "category": "error",
+                }));
             }
 
             Some(serde_json::json!(diagnostics))
@@ -3,6 +3,7 @@
 //! Handles commands for opening, closing, changing, and updating open files.
 
 use super::{Server, TsServerRequest, TsServerResponse};
+use rustc_hash::FxHashMap;
 
 impl Server {
     pub(crate) fn handle_open(&mut self, seq: u64, request: &TsServerRequest) -> TsServerResponse {
@@ -16,7 +17,7 @@ impl Server {
             let text = if let Some(c) = content {
                 c.to_string()
             } else {
-                std::fs::read_to_string(file_path).unwrap_or_default()
+                tsz::compiler_host::read_file(std::path::Path::new(file_path)).unwrap_or_default()
             };
             self.open_files.insert(file_path.to_string(), text);
         }
@@ -45,7 +46,7 @@ impl Server {
                 .open_files
                 .get(file)
                 .cloned()
-                .or_else(|| std::fs::read_to_string(file).ok())?;
+                .or_else(|| tsz::compiler_host::read_file(std::path::Path::new(file)).ok())?;
             if let Some(parent) = std::path::Path::new(tmpfile).parent() {
                 std::fs::create_dir_all(parent).ok()?;
             }
@@ -95,6 +96,7 @@ impl Server {
                 let new_content =
                     Self::apply_change(&content, line, offset, end_line, end_offset, insert_string);
                 self.open_files.insert(file_path.to_string(), new_content);
+                self.cancel_pending_geterr();
             }
         }
 
@@ -205,6 +207,7 @@ impl Server {
                 };
                 let updated = Self::apply_span_changes(&content, changes);
                 self.open_files.insert(file.to_string(), updated);
+                self.cancel_pending_geterr();
             }
         }
         if let Some(opened) = request
@@ -281,12 +284,22 @@ impl Server {
         bytes
     }
 
+    /// `updateOpen`: open, edit and close files as one batch.
+    ///
+    /// The whole batch is staged before any file changes, so an entry that
+    /// cannot be applied (an edit to a file that is not open) fails the
+    /// request and leaves every snapshot as it was. Each file's `textChanges`
+    /// are applied last-to-first, as tsserver does, so editors that batch
+    /// keystrokes can send positions relative to the pre-edit text. Only the
+    /// edited files are re-parsed on the next semantic query; the rest reuse
+    /// their cached parse/bind results.
     pub(crate) fn handle_update_open(
         &mut self,
         seq: u64,
         request: &TsServerRequest,
     ) -> TsServerResponse {
-        // Process opened, changed, and closed files
+        // `None` marks a file the batch closes.
+        let mut staged: FxHashMap<String, Option<String>> = FxHashMap::default();
         if let Some(opened) = request
             .arguments
             .get("openFiles")
@@ -297,8 +310,7 @@ impl Server {
                     entry.get("file").and_then(|v| v.as_str()),
                     entry.get("fileContent").and_then(|v| v.as_str()),
                 ) {
-                    self.open_files
-                        .insert(file.to_string(), content.to_string());
+                    staged.insert(file.to_string(), Some(content.to_string()));
                 }
             }
         }
@@ -314,11 +326,23 @@ impl Server {
                 let Some(text_changes) = entry.get("textChanges").and_then(|v| v.as_array()) else {
                     continue;
                 };
-                let Some(mut content) = self.open_files.get(file).cloned() else {
-                    continue;
+                let current = match staged.get(file) {
+                    Some(content) => content.clone(),
+                    None => self.open_files.get(file).cloned(),
+                };
+                let Some(mut content) = current else {
+                    return self.build_response(
+                        seq,
+                        request,
+                        false,
+                        Some(format!(
+                            "Could not apply changes to '{file}': file is not open"
+                        )),
+                        None,
+                    );
                 };
 
-                for change in text_changes {
+                for change in text_changes.iter().rev() {
                     let Some(start) = change.get("start") else {
                         continue;
                     };
@@ -346,7 +370,7 @@ impl Server {
                         Self::apply_change(&content, line, offset, end_line, end_offset, new_text);
                 }
 
-                self.open_files.insert(file.to_string(), content);
+                staged.insert(file.to_string(), Some(content));
             }
         }
         if let Some(closed) = request
@@ -356,12 +380,26 @@ impl Server {
         {
             for entry in closed {
                 if let Some(file) = entry.as_str() {
-                    self.open_files.remove(file);
+                    staged.insert(file.to_string(), None);
                 }
             }
         }
 
-        self.success_response(seq, request, None)
+        if !staged.is_empty() {
+            self.cancel_pending_geterr();
+        }
+        for (file, content) in staged {
+            match content {
+                Some(content) => {
+                    self.open_files.insert(file, content);
+                }
+                None => {
+                    self.open_files.remove(&file);
+                }
+            }
+        }
+
+        self.success_response(seq, request, Some(serde_json::Value::Bool(true)))
     }
 }
 
@@ -1,6 +1,7 @@
 //! Navigation, definition, and reference handlers for tsz-server.
 
 use super::{Server, TsServerRequest, TsServerResponse};
+use rustc_hash::FxHashMap;
 use tsz::binder::SymbolId;
 use tsz::lsp::definition::GoToDefinition;
 use tsz::lsp::highlighting::DocumentHighlightProvider;
@@ -43,7 +44,7 @@ fn import_context_for_range(source_text: &str, range: Range) -> Option<(Position
 }
 
 /// Map a `DocumentSymbol`'s kind + `kind_modifiers` to the tsserver `ScriptElementKind` string.
-fn symbol_kind_to_tsserver(
+pub(super) fn symbol_kind_to_tsserver(
     kind: tsz::lsp::symbols::document_symbols::SymbolKind,
     kind_modifiers: &str,
 ) -> &'static str {
@@ -341,6 +342,16 @@ fn sort_symbols_deep(symbols: &mut [tsz::lsp::symbols::document_symbols::Documen
 
 impl Server {
     fn build_project_for_file(&self, file_name: &str) -> Option<Project> {
+        if self.is_single_file_mode() {
+            let content = self
+                .open_files
+                .get(file_name)
+                .cloned()
+                .or_else(|| std::fs::read_to_string(file_name).ok())?;
+            let mut files = FxHashMap::default();
+            files.insert(file_name.to_string(), content);
+            return Some(self.project_from_files(files));
+        }
         let mut files = self.open_files.clone();
         for project_files in self.external_project_files.values() {
             for path in project_files {
@@ -352,6 +363,12 @@ impl Server {
                 }
             }
         }
+        let explicit_project = self.project_for_file(file_name);
+        if let Some(project) = &explicit_project {
+            for (path, content) in self.configured_project_program_files(project) {
+                files.entry(path).or_insert(content);
+            }
+        }
         if !files.contains_key(file_name)
             && let Ok(content) = std::fs::read_to_string(file_name)
         {
@@ -361,7 +378,20 @@ impl Server {
         if files.is_empty() {
             return None;
         }
+        let mut project = self.project_from_files(files);
+        // An external project's files follow its own options, not the
+        // inferred-project ones the session was configured with.
+        if let Some(external) = explicit_project.filter(|p| self.is_external_project(p)) {
+            project
+                .set_allow_importing_ts_extensions(external.options.allow_importing_ts_extensions);
+            project.set_auto_imports_allowed_without_tsconfig(true);
+        }
+        Some(project)
+    }
 
+    /// A `Project` over the given files, configured from the session's
+    /// import and auto-import preferences.
+    pub(super) fn project_from_files(&self, files: FxHashMap<String, String>) -> Project {
         let mut project = Project::new();
         project.set_allow_importing_ts_extensions(self.allow_importing_ts_extensions);
         project.set_auto_imports_allowed_without_tsconfig(
@@ -381,7 +411,7 @@ impl Server {
         for (path, text) in files {
             project.set_file(path, text);
         }
-        Some(project)
+        project
     }
 
     pub(super) fn find_ancestor_of_kind(
@@ -2721,93 +2751,6 @@ impl Server {
         )
     }
 
-    pub(crate) fn handle_navto(&mut self, seq: u64, request: &TsServerRequest) -> TsServerResponse {
-        let result = (|| -> Option<serde_json::Value> {
-            let search_value = request
-                .arguments
-                .get("searchValue")
-                .and_then(|v| v.as_str())?;
-            if search_value.is_empty() {
-                return Some(serde_json::json!([]));
-            }
-            if let Some(native) = self.try_native_typescript_operation(serde_json::json!({
-                "op": "navto",
-                "searchValue": search_value,
-                "file": request.arguments.get("file").and_then(serde_json::Value::as_str).unwrap_or(""),
-            })) {
-                return Some(native);
-            }
-            let search_lower = search_value.to_lowercase();
-            let mut nav_items: Vec<serde_json::Value> = Vec::new();
-            let file_paths: Vec<String> = self.open_files.keys().cloned().collect();
-            for file_path in &file_paths {
-                if let Some((arena, _binder, root, source_text)) =
-                    self.parse_and_bind_file(file_path)
-                {
-                    let line_map = LineMap::build(&source_text);
-                    let provider = DocumentSymbolProvider::new(&arena, &line_map, &source_text);
-                    let symbols = provider.get_document_symbols(root);
-                    Self::collect_navto_items(
-                        &symbols,
-                        search_value,
-                        &search_lower,
-                        file_path,
-                        &mut nav_items,
-                    );
-                }
-            }
-            Some(serde_json::json!(nav_items))
-        })();
-        self.success_response(seq, request, Some(result.unwrap_or(serde_json::json!([]))))
-    }
-
-    pub(crate) fn collect_navto_items(
-        symbols: &[tsz::lsp::symbols::document_symbols::DocumentSymbol],
-        search_value: &str,
-        search_lower: &str,
-        file_path: &str,
-        result: &mut Vec<serde_json::Value>,
-    ) {
-        for sym in symbols {
-            let name_lower = sym.name.to_lowercase();
-            if name_lower.contains(search_lower) {
-                let is_case_sensitive = sym.name.contains(search_value);
-                let kind = symbol_kind_to_tsserver(sym.kind, &sym.kind_modifiers);
-                let match_kind = if name_lower == *search_lower {
-                    "exact"
-                } else if name_lower.starts_with(search_lower) {
-                    "prefix"
-                } else {
-                    "substring"
-                };
-                // Filter out internal "let" modifier from kind_modifiers
-                let kind_mods = sym
-                    .kind_modifiers
-                    .split(',')
-                    .filter(|m| !m.is_empty() && *m != "let")
-                    .collect::<Vec<_>>()
-                    .join(",");
-                result.push(serde_json::json!({
-                    "name": sym.name,
-                    "kind": kind,
-                    "kindModifiers": kind_mods,
-                    "matchKind": match_kind,
-                    "isCaseSensitive": is_case_sensitive,
-                    "file": file_path,
-                    "start": {
-                        "line": sym.range.start.line + 1,
-                        "offset": sym.range.start.character + 1,
-                    },
-                    "end": {
-                        "line": sym.range.end.line + 1,
-                        "offset": sym.range.end.character + 1,
-                    },
-                }));
-            }
-            Self::collect_navto_items(&sym.children, search_value, search_lower, file_path, result);
-        }
-    }
-
     pub(crate) fn handle_implementation(
         &mut self,
         seq: u64,
@@ -2,6 +2,7 @@
 //!
 //! Handles the legacy JSON-per-line protocol used for fast conformance testing.
 
+use super::memory_budget::resident_memory_mb;
 use super::{
     CheckOptions, CheckResponse, ErrorResponse, LegacyRequest, LegacyResponse, OkResponse, Server,
     StatusResponse,
@@ -28,7 +29,7 @@ impl Server {
         options: CheckOptions,
     ) -> LegacyResponse {
         let start = Instant::now();
-        match self.run_check(files, options) {
+        let response = match self.run_check(files, options) {
             Ok(result) => {
                 self.checks_completed += 1;
                 LegacyResponse::Check(CheckResponse {
@@ -41,36 +42,24 @@ impl Server {
                 id,
                 error: e.to_string(),
             }),
-        }
+        };
+        self.enforce_memory_budget();
+        response
     }
 
     pub(crate) fn handle_legacy_status(&self, id: u64) -> LegacyResponse {
-        let memory_mb = {
-            #[cfg(target_os = "linux")]
-            {
-                std::fs::read_to_string("/proc/self/statm")
-                    .ok()
-                    .and_then(|s| s.split_whitespace().next()?.parse::<u64>().ok())
-                    .map(|pages| pages * 4096 / 1024 / 1024)
-                    .unwrap_or(0)
-            }
-            #[cfg(not(target_os = "linux"))]
-            {
-                0
-            }
-        };
-
         LegacyResponse::Status(StatusResponse {
             id,
-            memory_mb,
+            memory_mb: resident_memory_mb().unwrap_or(0),
             checks_completed: self.checks_completed,
             cached_libs: self.lib_cache.len(),
+            max_memory_mb: self.memory_budget.max_memory_mb(),
+            memory_recycles: self.memory_budget.recycles(),
         })
     }
 
     pub(crate) fn handle_legacy_recycle(&mut self, id: u64) -> LegacyResponse {
-        self.lib_cache.clear();
-        self.unified_lib_cache = None;
+        self.recycle_check_caches();
         self.checks_completed = 0;
         LegacyResponse::Ok(OkResponse { id, ok: true })
     }
@@ -0,0 +1,278 @@
+//! Workspace-wide `navto` for tsz-server.
+//!
+//! The search covers every file of the open projects: the open files, the
+//! root files of the configured projects that own them, and external project
+//! files. Top-level declarations come from the project's workspace symbol
+//! index, ranked by the fuzzy matcher shared with LSP `workspace/symbol`
+//! (exact > prefix > camel-case > substring, nearer to `file` first on ties).
+//! Nested declarations such as class members are not in the index; they are
+//! taken from each file's document symbols and scored with the same matcher.
+//!
+//! Besides `searchValue`, the request honors `file`, `currentFileOnly` and
+//! `maxResultCount`, plus a tsz-specific `kinds` list that keeps only items
+//! whose tsserver `kind` is listed.
+
+use super::handlers_info::symbol_kind_to_tsserver;
+use super::{Server, TsServerRequest, TsServerResponse};
+use rustc_hash::{FxHashMap, FxHashSet};
+use tsz::lsp::position::Range;
+use tsz::lsp::symbols::{DocumentSymbol, MatchKind, score_match};
+
+/// Identifies one declaration: overloads share a name but not a position.
+type NavtoKey<'a> = (&'a str, &'a str, u32, u32);
+
+fn navto_key<'a>(file: &'a str, name: &'a str, range: Range) -> NavtoKey<'a> {
+    (file, name, range.start.line, range.start.character)
+}
+
+struct NavtoItem<'a> {
+    match_kind: MatchKind,
+    name: &'a str,
+    kind: &'static str,
+    kind_modifiers: &'a str,
+    file: &'a str,
+    range: Range,
+    container: Option<&'a DocumentSymbol>,
+}
+
+impl<'a> NavtoItem<'a> {
+    fn from_symbol(
+        match_kind: MatchKind,
+        symbol: &'a DocumentSymbol,
+        file: &'a str,
+        container: Option<&'a DocumentSymbol>,
+    ) -> Self {
+        Self {
+            match_kind,
+            name: &symbol.name,
+            kind: symbol_kind_to_tsserver(symbol.kind, &symbol.kind_modifiers),
+            kind_modifiers: &symbol.kind_modifiers,
+            file,
+            range: symbol.range,
+            container,
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        let match_kind = match self.match_kind {
+            MatchKind::ExactCaseSensitive | MatchKind::ExactCaseInsensitive => "exact",
+            MatchKind::PrefixCaseSensitive | MatchKind::PrefixCaseInsensitive => "prefix",
+            MatchKind::CamelCaseContiguousFromStart
+            | MatchKind::CamelCaseContiguous
+            | MatchKind::CamelCaseAnywhere => "camelCase",
+            MatchKind::SubstringCaseSensitive | MatchKind::SubstringCaseInsensitive => "substring",
+        };
+        // Filter out internal "let" modifier from kind_modifiers
+        let kind_modifiers = self
+            .kind_modifiers
+            .split(',')
+            .filter(|m| !m.is_empty() && *m != "let")
+            .collect::<Vec<_>>()
+            .join(",");
+        let mut item = serde_json::json!({
+            "name": self.name,
+            "kind": self.kind,
+            "kindModifiers": kind_modifiers,
+            "matchKind": match_kind,
+            "isCaseSensitive": self.match_kind.is_case_sensitive(),
+            "file": self.file,
+            "start": Server::lsp_to_tsserver_position(self.range.start),
+            "end": Server::lsp_to_tsserver_position(self.range.end),
+        });
+        // tsserver omits the container fields for top-level declarations.
+        if let Some(container) = self.container {
+            item["containerName"] = serde_json::json!(container.name);
+            item["containerKind"] = serde_json::json!(symbol_kind_to_tsserver(
+                container.kind,
+                &container.kind_modifiers
+            ));
+        }
+        item
+    }
+}
+
+impl Server {
+    pub(crate) fn handle_navto(&mut self, seq: u64, request: &TsServerRequest) -> TsServerResponse {
+        let result = (|| -> Option<serde_json::Value> {
+            let search_value = request
+                .arguments
+                .get("searchValue")
+                .and_then(|v| v.as_str())?;
+            if search_value.is_empty() {
+                return Some(serde_json::json!([]));
+            }
+            if let Some(native) = self.try_native_typescript_operation(serde_json::json!({
+                "op": "navto",
+                "searchValue": search_value,
+                "file": request.arguments.get("file").and_then(serde_json::Value::as_str).unwrap_or(""),
+            })) {
+                return Some(native);
+            }
+
+            let active_file = request
+                .arguments
+                .get("file")
+                .and_then(serde_json::Value::as_str);
+            let current_file_only = request
+                .arguments
+                .get("currentFileOnly")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false);
+            let max_result_count = request
+                .arguments
+                .get("maxResultCount")
+                .and_then(serde_json::Value::as_u64)
+                .map_or(usize::MAX, |count| count as usize);
+            let kinds: Option<FxHashSet<&str>> = request
+                .arguments
+                .get("kinds")
+                .and_then(serde_json::Value::as_array)
+                .map(|kinds| kinds.iter().filter_map(serde_json::Value::as_str).collect());
+
+            let files = self.navto_workspace_files(active_file);
+            let search_paths: Vec<String> = files
+                .keys()
+                .filter(|path| {
+                    !path.ends_with(".json")
+                        && (!current_file_only || active_file == Some(path.as_str()))
+                })
+                .cloned()
+                .collect();
+            let mut project = self.project_from_files(files);
+            if let Some(active_file) = active_file {
+                project.set_focused_file(active_file);
+            }
+            let symbols_by_file: FxHashMap<&str, Vec<DocumentSymbol>> = search_paths
+                .iter()
+                .filter_map(|path| Some((path.as_str(), project.get_document_symbols(path)?)))
+                .collect();
+
+            // Index hits first, in the index's ranking order. Every
+            // declaration of a hit is listed, so overloads keep their own
+            // entries.
+            let hits = project.get_workspace_symbols_with_limit(search_value, usize::MAX);
+            let mut items: Vec<NavtoItem<'_>> = Vec::new();
+            let mut indexed: FxHashSet<NavtoKey<'_>> = FxHashSet::default();
+            for info in &hits {
+                let Some((&file, symbols)) =
+                    symbols_by_file.get_key_value(info.location.file_path.as_str())
+                else {
+                    continue;
+                };
+                let Some(match_kind) = score_match(&info.name, search_value) else {
+                    continue;
+                };
+                let mut declarations = symbols
+                    .iter()
+                    .filter(|symbol| symbol.name == info.name)
+                    .peekable();
+                if declarations.peek().is_none() {
+                    if indexed.insert(navto_key(file, &info.name, info.location.range)) {
+                        items.push(NavtoItem {
+                            match_kind,
+                            name: &info.name,
+                            kind: symbol_kind_to_tsserver(info.kind, ""),
+                            kind_modifiers: "",
+                            file,
+                            range: info.location.range,
+                            container: None,
+                        });
+                    }
+                    continue;
+                }
+                for symbol in declarations {
+                    if indexed.insert(navto_key(file, &symbol.name, symbol.range)) {
+                        items.push(NavtoItem::from_symbol(match_kind, symbol, file, None));
+                    }
+                }
+            }
+
+            // Then everything the index does not cover: nested declarations,
+            // and top-level ones the binder keeps outside the file's locals.
+            let mut paths: Vec<&str> = symbols_by_file.keys().copied().collect();
+            paths.sort_unstable();
+            for file in paths {
+                for symbol in &symbols_by_file[file] {
+                    if !indexed.contains(&navto_key(file, &symbol.name, symbol.range))
+                        && let Some(match_kind) = score_match(&symbol.name, search_value)
+                    {
+                        items.push(NavtoItem::from_symbol(match_kind, symbol, file, None));
+                    }
+                    Self::collect_nested_navto_items(symbol, search_value, file, &mut items);
+                }
+            }
+
+            items.sort_by_key(|item| item.match_kind);
+            let body: Vec<serde_json::Value> = items
+                .iter()
+                .filter(|item| kinds.as_ref().is_none_or(|kinds| kinds.contains(item.kind)))
+                .take(max_result_count)
+                .map(NavtoItem::to_json)
+                .collect();
+            Some(serde_json::json!(body))
+        })();
+        self.success_response(seq, request, Some(result.unwrap_or(serde_json::json!([]))))
+    }
+
+    fn collect_nested_navto_items<'a>(
+        container: &'a DocumentSymbol,
+        search_value: &str,
+        file: &'a str,
+        items: &mut Vec<NavtoItem<'a>>,
+    ) {
+        for symbol in &container.children {
+            if let Some(match_kind) = score_match(&symbol.name, search_value) {
+                items.push(NavtoItem::from_symbol(
+                    match_kind,
+                    symbol,
+                    file,
+                    Some(container),
+                ));
+            }
+            Self::collect_nested_navto_items(symbol, search_value, file, items);
+        }
+    }
+
+    /// Files of every open project: open files, the program files of the
+    /// configured projects that own them, and external project files.
+    fn navto_workspace_files(&self, active_file: Option<&str>) -> FxHashMap<String, String> {
+        let mut files = self.open_files.clone();
+        for project_files in self.external_project_files.values() {
+            for path in project_files {
+                if files.contains_key(path) {
+                    continue;
+                }
+                if let Ok(content) = std::fs::read_to_string(path) {
+                    files.insert(path.clone(), content);
+                }
+            }
+        }
+
+        let mut loaded_configs: FxHashSet<String> = FxHashSet::default();
+        let mut members: Vec<&str> = self.open_files.keys().map(String::as_str).collect();
+        members.extend(active_file);
+        for file in members {
+            if self
+                .find_project_config_file(file)
+                .is_some_and(|config_path| loaded_configs.contains(&config_path))
+            {
+                continue;
+            }
+            let Some(project) = self.configured_project_for_file(file) else {
+                continue;
+            };
+            for (path, content) in self.configured_project_program_files(&project) {
+                files.entry(path).or_insert(content);
+            }
+            loaded_configs.insert(project.config_path);
+        }
+
+        if let Some(active_file) = active_file
+            && !files.contains_key(active_file)
+            && let Ok(content) = std::fs::read_to_string(active_file)
+        {
+            files.insert(active_file.to_string(), content);
+        }
+        files
+    }
+}
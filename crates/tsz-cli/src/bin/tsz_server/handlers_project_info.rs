@@ -236,15 +236,19 @@ impl Server {
             .map(std::path::Path::to_path_buf)
             .unwrap_or_else(|| std::path::PathBuf::from("/"));
 
-        let project_files = self.tsconfig_project_files(&config_json, &config_dir);
+        let project_files = self.tsconfig_project_files(&config_json, &config_dir, true);
 
         (lib_names, no_lib, project_files)
     }
 
-    fn tsconfig_project_files(
+    /// Resolve a config's `files`/`include`/`exclude` selectors against
+    /// `config_dir`. Open files are always candidates; `scan_disk` also walks
+    /// the directory tree, which virtual (open-file-only) configs skip.
+    pub(super) fn tsconfig_project_files(
         &self,
         config_json: &serde_json::Value,
         config_dir: &std::path::Path,
+        scan_disk: bool,
     ) -> Vec<String> {
         if let Some(files) = config_json
             .get("files")
@@ -286,7 +290,7 @@ impl Server {
             }
         }
 
-        if config_dir.exists() {
+        if scan_disk && config_dir.exists() {
             for entry in walkdir::WalkDir::new(config_dir)
                 .into_iter()
                 .filter_map(Result::ok)
@@ -369,7 +373,7 @@ impl Server {
             .unwrap_or_else(|_| file.trim_start_matches('/').to_string())
     }
 
-    fn path_is_under(path: &str, dir: &std::path::Path) -> bool {
+    pub(super) fn path_is_under(path: &str, dir: &std::path::Path) -> bool {
         std::path::Path::new(path).starts_with(dir)
     }
 
@@ -3,58 +3,37 @@
 //! Handles formatting, inlay hints, selection ranges, call hierarchy,
 //! outlining spans, brace matching, refactoring stubs, and related commands.
 
-use super::text_edits::narrow_indentation_only_edit;
+use super::emit_output::EmittedFile;
+use super::handlers_code_fixes_utils::resolve_module_path;
+use super::handlers_diagnostics::DiagnosticFormatInput;
+use super::text_edits::{minimal_replacement_edit, narrow_indentation_only_edit};
 use super::{Server, TsServerRequest, TsServerResponse};
-use tsz::emitter::{ModuleKind, Printer, PrinterOptions};
-
+use rustc_hash::{FxHashMap, FxHashSet};
 struct CompileOnSaveProject {
     config_path: String,
-    config_dir: std::path::PathBuf,
     enabled: bool,
     file_names: Vec<String>,
     uses_out_file: bool,
-    out_dir: Option<String>,
-    module: ModuleKind,
 }
-
-impl CompileOnSaveProject {
-    fn output_path_for(&self, file: &str) -> std::path::PathBuf {
-        let input = std::path::Path::new(file);
-        let mut relative = input
-            .strip_prefix(&self.config_dir)
-            .map(std::path::Path::to_path_buf)
-            .unwrap_or_else(|_| {
-                input
-                    .file_name()
-                    .map(std::path::PathBuf::from)
-                    .unwrap_or_else(|| std::path::PathBuf::from(file))
-            });
-        relative.set_extension("js");
-        if let Some(out_dir) = self.out_dir.as_deref() {
-            let out_dir = std::path::Path::new(out_dir);
-            let out_dir = if out_dir.is_absolute() {
-                out_dir.to_path_buf()
-            } else {
-                self.config_dir.join(out_dir)
-            };
-            out_dir.join(relative)
-        } else {
-            input.with_extension("js")
-        }
-    }
-}
-use tsz::lsp::code_actions::CodeActionProvider;
-use tsz::lsp::editor_decorations::inlay_hints::{InlayHintKind, InlayHintsProvider};
+use tsz::binder::BinderState;
+use tsz::lsp::code_actions::{CodeActionProvider, ReactJsxFactories};
+use tsz::lsp::dependency_graph::DependencyGraph;
+use tsz::lsp::editor_decorations::inlay_hints::{
+    InlayHintKind, InlayHintsOptions, InlayHintsProvider, ParameterNameHints,
+};
 use tsz::lsp::editor_ranges::folding::FoldingRangeProvider;
 use tsz::lsp::editor_ranges::selection_range::SelectionRangeProvider;
 use tsz::lsp::hierarchy::call_hierarchy::{
     CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, CallHierarchyProvider,
     ImportResolutionRequest,
 };
-use tsz::lsp::highlighting::semantic_tokens::SemanticTokensProvider;
+use tsz::lsp::highlighting::semantic_tokens::{
+    SemanticTokenType, SemanticTokensProvider, semantic_token_modifiers,
+};
 use tsz::lsp::position::{LineMap, Position, Range};
 use tsz::lsp::rename::file_rename::FileRenameProvider;
 use tsz::lsp::rename::linked_editing::LinkedEditingProvider;
+use tsz::parser::ParserState;
 use tsz_solver::construction::TypeInterner;
 
 impl Server {
@@ -162,48 +141,63 @@ impl Server {
             })) {
                 return Some(native);
             }
+            let format_2020 = request
+                .arguments
+                .get("format")
+                .and_then(serde_json::Value::as_str)
+                == Some("2020");
+            let span_start = request
+                .arguments
+                .get("start")
+                .and_then(serde_json::Value::as_u64)
+                .unwrap_or(0) as u32;
+            let span_end = request
+                .arguments
+                .get("length")
+                .and_then(serde_json::Value::as_u64)
+                .map_or(u32::MAX, |length| span_start.saturating_add(length as u32));
+
             let (arena, binder, root, source_text) = self.parse_and_bind_file(file)?;
             let line_map = LineMap::build(&source_text);
+            let utf16_offsets = Self::utf16_offset_table(&source_text);
             let mut provider =
                 SemanticTokensProvider::new(&arena, &binder, &line_map, &source_text);
             let tokens = provider.get_semantic_tokens(root);
-            // Provider emits the LSP 5-tuple delta encoding
-            // (deltaLine, deltaChar, length, tokenType, tokenModifiers).
-            // tsserver's `encodedSemanticClassifications-full` expects the
-            // "2020" format: triples of (absStart, length, classId) with
-            // `classId = (modifierBits << 8) | tokenType`. Convert in
-            // place so the fourslash harness's span-length assertions
-            // match tsc.
-            let mut converted: Vec<u32> = Vec::with_capacity(tokens.len() / 5 * 3);
-            let mut prev_line: u32 = 0;
-            let mut prev_char: u32 = 0;
-            let mut i = 0;
-            while i + 4 < tokens.len() {
-                let delta_line = tokens[i];
-                let delta_char = tokens[i + 1];
-                let length = tokens[i + 2];
-                let token_type = tokens[i + 3];
-                let token_modifiers = tokens[i + 4];
-                let line = prev_line + delta_line;
-                let char = if delta_line == 0 {
-                    prev_char + delta_char
+            // The provider emits the LSP delta encoding (deltaLine,
+            // deltaChar, length, tokenType, tokenModifiers); tsserver wants
+            // (start, length, classification) triples with UTF-16 starts.
+            let mut spans: Vec<u32> = Vec::with_capacity(tokens.len() / 5 * 3);
+            let mut line: u32 = 0;
+            let mut character: u32 = 0;
+            for token in tokens.chunks_exact(5) {
+                let [delta_line, delta_char, length, token_type, modifiers] = token else {
+                    continue;
+                };
+                character = if *delta_line == 0 {
+                    character + delta_char
                 } else {
-                    delta_char
+                    *delta_char
                 };
-                let position = tsz_common::position::Position::new(line, char);
-                let abs_start = line_map
-                    .position_to_offset(position, &source_text)
-                    .unwrap_or(0);
-                let class_id = (token_modifiers << 8) | token_type;
-                converted.push(abs_start);
-                converted.push(length);
-                converted.push(class_id);
-                prev_line = line;
-                prev_char = char;
-                i += 5;
+                line += delta_line;
+                let Some(classification) =
+                    Self::encode_semantic_classification(*token_type, *modifiers, format_2020)
+                else {
+                    continue;
+                };
+                let Some(byte_offset) = line_map.position_to_offset(
+                    tsz_common::position::Position::new(line, character),
+                    &source_text,
+                ) else {
+                    continue;
+                };
+                let start = utf16_offsets[(byte_offset as usize).min(utf16_offsets.len() - 1)];
+                if start >= span_end || start + length <= span_start {
+                    continue;
+                }
+                spans.extend([start, *length, classification]);
             }
             Some(serde_json::json!({
-                "spans": converted,
+                "spans": spans,
                 "endOfLineState": 0,
             }))
         })();
@@ -214,6 +208,65 @@ impl Server {
         )
     }
 
+    /// Classification for one semantic token. `format: "2020"` packs
+    /// `(tokenType + 1) << 8 | modifiers` over tsserver's 2020 legend
+    /// (class, enum, interface, namespace, typeParameter, type, parameter,
+    /// variable, enumMember, property, function, member); the original format
+    /// only classifies type-like names, with `ClassificationType` ids.
+    fn encode_semantic_classification(
+        token_type: u32,
+        modifiers: u32,
+        format_2020: bool,
+    ) -> Option<u32> {
+        const TYPES: [(SemanticTokenType, u32, Option<u32>); 12] = [
+            (SemanticTokenType::Class, 0, Some(11)),
+            (SemanticTokenType::Enum, 1, Some(12)),
+            (SemanticTokenType::Interface, 2, Some(13)),
+            (SemanticTokenType::Namespace, 3, Some(14)),
+            (SemanticTokenType::TypeParameter, 4, Some(15)),
+            (SemanticTokenType::Type, 5, Some(16)),
+            (SemanticTokenType::Parameter, 6, None),
+            (SemanticTokenType::Variable, 7, None),
+            (SemanticTokenType::EnumMember, 8, None),
+            (SemanticTokenType::Property, 9, None),
+            (SemanticTokenType::Function, 10, None),
+            (SemanticTokenType::Method, 11, None),
+        ];
+        const MODIFIERS: [(u32, u32); 5] = [
+            (semantic_token_modifiers::DECLARATION, 1 << 0),
+            (semantic_token_modifiers::STATIC, 1 << 1),
+            (semantic_token_modifiers::ASYNC, 1 << 2),
+            (semantic_token_modifiers::READONLY, 1 << 3),
+            (semantic_token_modifiers::DEFAULT_LIBRARY, 1 << 4),
+        ];
+        let &(_, type_2020, original) = TYPES
+            .iter()
+            .find(|(lsp_type, _, _)| *lsp_type as u32 == token_type)?;
+        if !format_2020 {
+            return original;
+        }
+        let modifier_set = MODIFIERS
+            .iter()
+            .filter(|(lsp_modifier, _)| modifiers & lsp_modifier != 0)
+            .fold(0, |set, (_, bit)| set | bit);
+        Some(((type_2020 + 1) << 8) | modifier_set)
+    }
+
+    /// UTF-16 offset of every byte position in `text` (plus the end), so
+    /// byte offsets translate to tsserver offsets in O(1).
+    fn utf16_offset_table(text: &str) -> Vec<u32> {
+        let mut offsets: Vec<u32> = Vec::with_capacity(text.len() + 1);
+        offsets.push(0);
+        let mut count: u32 = 0;
+        for ch in text.chars() {
+            count = count.saturating_add(ch.len_utf16() as u32);
+            for _ in 0..ch.len_utf8() {
+                offsets.push(count);
+            }
+        }
+        offsets
+    }
+
     /// Implement the `encodedSyntacticClassifications-full` tsserver
     /// command. Walks the source text via the scanner and emits
     /// `(start, length, classificationId)` triples for every non-trivia
@@ -245,17 +298,7 @@ impl Server {
 
             let source_text = self.open_files.get(file)?.clone();
 
-            // UTF-16 prefix counts so each token's byte offset translates to
-            // a UTF-16 offset in O(1) after a single pass.
-            let mut utf16_prefix: Vec<u32> = Vec::with_capacity(source_text.len() + 1);
-            utf16_prefix.push(0);
-            let mut count: u32 = 0;
-            for ch in source_text.chars() {
-                count = count.saturating_add(ch.len_utf16() as u32);
-                for _ in 0..ch.len_utf8() {
-                    utf16_prefix.push(count);
-                }
-            }
+            let utf16_prefix = Self::utf16_offset_table(&source_text);
             let to_utf16 = |byte: usize| -> u32 {
                 let idx = byte.min(utf16_prefix.len().saturating_sub(1));
                 utf16_prefix[idx]
@@ -389,55 +432,81 @@ impl Server {
         seq: u64,
         request: &TsServerRequest,
     ) -> TsServerResponse {
-        let result = (|| -> Option<serde_json::Value> {
-            let file = request.arguments.get("file")?.as_str()?;
-            let (arena, _binder, root, source_text) = self.parse_and_bind_file(file)?;
-
-            // Issue #3784: tsc honors the owning project's `module` and
-            // `outDir` for emit-output. Reuse the compile-on-save project
-            // lookup (ignoring the `compileOnSave` flag) so the printer
-            // module kind and output path match tsserver's behavior.
-            let project = self.compile_on_save_project(file);
-
-            let module = project
-                .as_ref()
-                .map_or_else(|| self.emit_output_module_kind(), |p| p.module);
-
-            let mut printer = Printer::with_source_text_len_and_options(
-                &arena,
-                source_text.len(),
-                PrinterOptions {
-                    module,
-                    ..Default::default()
-                },
-            );
-            printer.set_source_text(&source_text);
-            printer.emit(root);
-            let output = printer.take_output();
-
-            let out_name = if let Some(ref project) = project {
-                project.output_path_for(file).to_string_lossy().into_owned()
-            } else {
-                file.strip_suffix(".ts")
-                    .or_else(|| file.strip_suffix(".tsx"))
-                    .map(|base| format!("{base}.js"))
-                    .unwrap_or_else(|| format!("{file}.js"))
-            };
+        let rich_response = request
+            .arguments
+            .get("richResponse")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let output = request
+            .arguments
+            .get("file")
+            .and_then(serde_json::Value::as_str)
+            .and_then(|file| self.emit_file_outputs(file));
+        let diagnostics = output.as_ref().map_or_else(Vec::new, |output| {
+            self.emit_diagnostics_json(&output.diagnostics, request)
+        });
+        let mut body = match output {
+            Some(output) => serde_json::json!({
+                "outputFiles": output
+                    .output_files
+                    .iter()
+                    .map(|file| serde_json::json!({
+                        "name": file.name,
+                        "text": file.text,
+                        "writeByteOrderMark": output.write_byte_order_mark,
+                    }))
+                    .collect::<Vec<_>>(),
+                "emitSkipped": output.emit_skipped,
+            }),
+            None => serde_json::json!({"outputFiles": [], "emitSkipped": true}),
+        };
+        if rich_response {
+            body["diagnostics"] = serde_json::json!(diagnostics);
+        }
+        self.success_response(seq, request, Some(body))
+    }
 
-            Some(serde_json::json!({
-                "outputFiles": [{
-                    "name": out_name,
-                    "text": output,
-                    "writeByteOrderMark": false,
-                }],
-                "emitSkipped": false,
-            }))
-        })();
-        self.success_response(
-            seq,
-            request,
-            Some(result.unwrap_or(serde_json::json!({"outputFiles": [], "emitSkipped": true}))),
-        )
+    /// Emit diagnostics in protocol form, located in their own file's text.
+    fn emit_diagnostics_json(
+        &self,
+        diagnostics: &[tsz::checker::diagnostics::Diagnostic],
+        request: &TsServerRequest,
+    ) -> Vec<serde_json::Value> {
+        let include_line_position = request
+            .arguments
+            .get("includeLinePosition")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let mut texts: FxHashMap<&str, (String, LineMap)> = FxHashMap::default();
+        let related_file_text = |file: &str| self.related_file_text(file);
+        diagnostics
+            .iter()
+            .map(|diag| {
+                let (content, line_map) = texts.entry(diag.file.as_str()).or_insert_with(|| {
+                    let content = self
+                        .open_files
+                        .get(&diag.file)
+                        .cloned()
+                        .or_else(|| std::fs::read_to_string(&diag.file).ok())
+                        .unwrap_or_default();
+                    let line_map = LineMap::build(&content);
+                    (content, line_map)
+                });
+                Self::format_diagnostic(DiagnosticFormatInput {
+                    start_offset: diag.start,
+                    length: diag.length,
+                    message: &diag.message_text,
+                    code: diag.code,
+                    category: diag.category,
+                    line_map,
+                    content,
+                    include_line_position,
+                    file: &diag.file,
+                    related_information: &diag.related_information,
+                    related_file_text: &related_file_text,
+                })
+            })
+            .collect()
     }
 
     pub(crate) fn handle_compile_on_save_affected_file_list(
@@ -453,7 +522,7 @@ impl Server {
             }
             Some(serde_json::json!([{
                 "projectFileName": project.config_path,
-                "fileNames": project.file_names,
+                "fileNames": self.compile_on_save_affected_files(file, &project),
                 "projectUsesOutFile": project.uses_out_file,
             }]))
         })();
@@ -470,20 +539,30 @@ impl Server {
             .get("richResponse")
             .and_then(serde_json::Value::as_bool)
             .unwrap_or(false);
+        let forced = request
+            .arguments
+            .get("forced")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let mut diagnostics = Vec::new();
         let emitted = (|| -> Option<bool> {
             let file = request.arguments.get("file")?.as_str()?;
             let project = self.compile_on_save_project(file)?;
-            if !project.enabled {
+            if !project.enabled && !forced {
                 return Some(false);
             }
-            self.emit_compile_on_save_file(file, &project).ok()?;
-            Some(true)
+            let output = self.emit_file_outputs(file)?;
+            diagnostics = self.emit_diagnostics_json(&output.diagnostics, request);
+            for output_file in &output.output_files {
+                Self::write_emitted_file(output_file, output.write_byte_order_mark).ok()?;
+            }
+            Some(!output.emit_skipped)
         })()
         .unwrap_or(false);
         let body = if rich_response {
             serde_json::json!({
                 "emitSkipped": !emitted,
-                "diagnostics": [],
+                "diagnostics": diagnostics,
             })
         } else {
             serde_json::json!(emitted)
@@ -491,114 +570,110 @@ impl Server {
         self.success_response(seq, request, Some(body))
     }
 
-    fn emit_output_module_kind(&self) -> ModuleKind {
-        self.inferred_check_options
-            .module
-            .as_deref()
-            .map(str::to_ascii_lowercase)
-            .map(|module| match module.as_str() {
-                "none" => ModuleKind::None,
-                "commonjs" => ModuleKind::CommonJS,
-                "amd" => ModuleKind::AMD,
-                "umd" => ModuleKind::UMD,
-                "system" => ModuleKind::System,
-                "es2015" => ModuleKind::ES2015,
-                "es2020" => ModuleKind::ES2020,
-                "es2022" => ModuleKind::ES2022,
-                "node16" => ModuleKind::Node16,
-                "node18" => ModuleKind::Node18,
-                "node20" => ModuleKind::Node20,
-                "nodenext" => ModuleKind::NodeNext,
-                "preserve" => ModuleKind::Preserve,
-                _ => ModuleKind::ESNext,
-            })
-            .unwrap_or(ModuleKind::ESNext)
-    }
-
-    fn module_kind_from_config(config_json: &serde_json::Value) -> ModuleKind {
-        config_json
-            .get("compilerOptions")
-            .and_then(|opts| opts.get("module"))
-            .and_then(serde_json::Value::as_str)
-            .map(str::to_ascii_lowercase)
-            .map(|module| match module.as_str() {
-                "none" => ModuleKind::None,
-                "commonjs" => ModuleKind::CommonJS,
-                "amd" => ModuleKind::AMD,
-                "umd" => ModuleKind::UMD,
-                "system" => ModuleKind::System,
-                "es2015" | "es6" => ModuleKind::ES2015,
-                "es2020" => ModuleKind::ES2020,
-                "es2022" => ModuleKind::ES2022,
-                "node16" => ModuleKind::Node16,
-                "node18" => ModuleKind::Node18,
-                "node20" => ModuleKind::Node20,
-                "nodenext" => ModuleKind::NodeNext,
-                "preserve" => ModuleKind::Preserve,
-                _ => ModuleKind::ESNext,
-            })
-            .unwrap_or(ModuleKind::ESNext)
-    }
-
     fn compile_on_save_project(&self, file: &str) -> Option<CompileOnSaveProject> {
         let config_path = self.find_project_config_file(file)?;
-        let config_json = self.read_config_json(&config_path)?;
-        let enabled = config_json
-            .get("compileOnSave")
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
-        let compiler_options = config_json.get("compilerOptions");
-        let uses_out_file = compiler_options
-            .and_then(|opts| opts.get("outFile").or_else(|| opts.get("out")))
-            .and_then(serde_json::Value::as_str)
-            .is_some();
-        let out_dir = compiler_options
-            .and_then(|opts| opts.get("outDir"))
-            .and_then(serde_json::Value::as_str)
-            .map(str::to_string);
-        let config_dir = std::path::Path::new(&config_path)
-            .parent()
-            .map(std::path::Path::to_path_buf)
-            .unwrap_or_else(|| std::path::PathBuf::from("/"));
+        // Both settings may come from an extended config.
+        let (config, _) = self.load_tsconfig(&config_path).ok()?;
+        let enabled = config.compile_on_save.unwrap_or(false);
+        let uses_out_file = config
+            .compiler_options
+            .as_ref()
+            .and_then(|opts| opts.out_file.as_deref())
+            .is_some_and(|out_file| !out_file.is_empty());
         let (_, _, mut file_names) = self.parse_tsconfig_for_project_info(&config_path);
         if file_names.is_empty() {
             file_names.push(Self::normalize_path_string(std::path::Path::new(file)));
         }
         Some(CompileOnSaveProject {
             config_path,
-            config_dir,
             enabled,
             file_names,
             uses_out_file,
-            out_dir,
-            module: Self::module_kind_from_config(&config_json),
         })
     }
 
-    fn emit_compile_on_save_file(
+    /// Files whose output a save of `file` can change, in project order: the
+    /// file itself and every project file that imports it, directly or
+    /// transitively. Saving a global script, or any file of an `outFile`
+    /// project, affects the whole project, as in tsserver.
+    fn compile_on_save_affected_files(
         &self,
         file: &str,
         project: &CompileOnSaveProject,
-    ) -> std::io::Result<()> {
-        let (arena, _binder, root, source_text) = self
-            .parse_and_bind_file(file)
-            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, file.to_string()))?;
-        let mut printer = Printer::with_source_text_len_and_options(
-            &arena,
-            source_text.len(),
-            PrinterOptions {
-                module: project.module,
-                ..Default::default()
-            },
-        );
-        printer.set_source_text(&source_text);
-        printer.emit(root);
-        let output = printer.take_output();
-        let out_path = project.output_path_for(file);
-        if let Some(parent) = out_path.parent() {
+    ) -> Vec<String> {
+        let sources: FxHashMap<String, String> = project
+            .file_names
+            .iter()
+            .filter_map(|path| {
+                let text = self
+                    .open_files
+                    .get(path)
+                    .cloned()
+                    .or_else(|| std::fs::read_to_string(path).ok())?;
+                Some((path.clone(), text))
+            })
+            .collect();
+
+        let mut graph = DependencyGraph::new();
+        let mut saved_file_is_module = true;
+        for (path, text) in &sources {
+            let mut parser = ParserState::new(path.clone(), text.clone());
+            let root = parser.parse_source_file();
+            let arena = parser.into_arena();
+            let mut binder = BinderState::new();
+            binder.bind_source_file(&arena, root);
+            if path == file {
+                saved_file_is_module = binder.is_external_module;
+            }
+            let resolve = |specifier: &str| {
+                resolve_module_path(path, specifier, &sources)
+                    .filter(|resolved| sources.contains_key(resolved))
+            };
+            let imports: Vec<String> = binder
+                .file_import_sources
+                .iter()
+                .filter(|specifier| specifier.starts_with('.'))
+                .filter_map(|specifier| {
+                    // `./a.js` in TypeScript source names `./a.ts`.
+                    resolve(specifier).or_else(|| {
+                        let (stem, extension) = specifier.rsplit_once('.')?;
+                        match extension {
+                            "js" | "jsx" => resolve(stem),
+                            "mjs" => resolve(&format!("{stem}.mts")),
+                            "cjs" => resolve(&format!("{stem}.cts")),
+                            _ => None,
+                        }
+                    })
+                })
+                .collect();
+            graph.update_file(path, &imports);
+        }
+
+        let affects_all = project.uses_out_file || !saved_file_is_module;
+        let affected: FxHashSet<String> = graph.get_affected_files(file).into_iter().collect();
+        project
+            .file_names
+            .iter()
+            .filter(|path| affects_all || path.as_str() == file || affected.contains(*path))
+            .filter(|path| {
+                ![".json", ".d.ts", ".d.mts", ".d.cts"]
+                    .iter()
+                    .any(|extension| path.ends_with(extension))
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn write_emitted_file(file: &EmittedFile, write_byte_order_mark: bool) -> std::io::Result<()> {
+        let path = std::path::Path::new(&file.name);
+        if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
-        std::fs::write(out_path, output)
+        if write_byte_order_mark {
+            std::fs::write(path, format!("\u{feff}{}", file.text))
+        } else {
+            std::fs::write(path, &file.text)
+        }
     }
 
     pub(crate) fn handle_get_applicable_refactors(
@@ -635,8 +710,12 @@ impl Server {
 
             let mut refactors = Vec::new();
 
-            // Check if extract variable is applicable
-            if provider.extract_variable(root, range).is_some() {
+            // Extract function applies to expressions and to whole statements;
+            // extract constant only to expressions.
+            let can_extract_constant = provider.extract_variable(root, range).is_some();
+            let can_extract_function =
+                can_extract_constant || provider.extract_function(root, range).is_some();
+            if can_extract_function {
                 // Issue #3803: tsc emits one extract action per *applicable*
                 // scope and attaches a range. Approximate "applicable scopes"
                 // by detecting whether the request's expression has an
@@ -698,10 +777,47 @@ impl Server {
                     "description": "Extract function",
                     "actions": function_actions,
                 }));
+                if can_extract_constant {
+                    refactors.push(serde_json::json!({
+                        "name": "Extract Symbol",
+                        "description": "Extract constant",
+                        "actions": constant_actions,
+                    }));
+                }
+            }
+
+            if provider.move_to_new_file(root, range).is_some() {
                 refactors.push(serde_json::json!({
-                    "name": "Extract Symbol",
-                    "description": "Extract constant",
-                    "actions": constant_actions,
+                    "name": "Move to a new file",
+                    "description": "Move to a new file",
+                    "actions": [{
+                        "name": "Move to a new file",
+                        "description": "Move to a new file",
+                        "kind": "refactor.move.newFile",
+                    }],
+                }));
+            }
+
+            let mut export_actions = Vec::new();
+            if provider.convert_to_named_export(root, range).is_some() {
+                export_actions.push(serde_json::json!({
+                    "name": "Convert default export to named export",
+                    "description": "Convert default export to named export",
+                    "kind": "refactor.rewrite.export.named",
+                }));
+            }
+            if provider.convert_to_default_export(root, range).is_some() {
+                export_actions.push(serde_json::json!({
+                    "name": "Convert named export to default export",
+                    "description": "Convert named export to default export",
+                    "kind": "refactor.rewrite.export.default",
+                }));
+            }
+            if !export_actions.is_empty() {
+                refactors.push(serde_json::json!({
+                    "name": "Convert export",
+                    "description": "Convert export",
+                    "actions": export_actions,
                 }));
             }
 
@@ -745,6 +861,42 @@ impl Server {
         Some((start_line, start_offset, end_line, end_offset))
     }
 
+    /// Translate an LSP workspace edit into tsserver `FileCodeEdits`, sorted
+    /// by file name. Files that are neither open nor on disk are marked
+    /// `isNewFile` so the client creates them.
+    pub(super) fn file_code_edits(
+        &self,
+        edit: tsz::lsp::rename::WorkspaceEdit,
+    ) -> Vec<serde_json::Value> {
+        let mut changes: Vec<_> = edit.changes.into_iter().collect();
+        changes.sort_by(|(a, _), (b, _)| a.cmp(b));
+        changes
+            .into_iter()
+            .map(|(file_name, edits)| {
+                let text_changes: Vec<serde_json::Value> = edits
+                    .into_iter()
+                    .map(|e| {
+                        serde_json::json!({
+                            "start": Self::lsp_to_tsserver_position(e.range.start),
+                            "end": Self::lsp_to_tsserver_position(e.range.end),
+                            "newText": e.new_text,
+                        })
+                    })
+                    .collect();
+                let mut file_edit = serde_json::json!({
+                    "fileName": file_name,
+                    "textChanges": text_changes,
+                });
+                if !self.open_files.contains_key(&file_name)
+                    && !std::path::Path::new(&file_name).exists()
+                {
+                    file_edit["isNewFile"] = serde_json::json!(true);
+                }
+                file_edit
+            })
+            .collect()
+    }
+
     /// Walk the AST upward from the request range looking for an
     /// enclosing function-like node (function/method/arrow/constructor/
     /// accessor). Returns `true` when one is found, `false` when the
@@ -807,34 +959,27 @@ impl Server {
             let provider =
                 CodeActionProvider::new(&arena, &binder, &line_map, file.to_string(), &content);
 
-            if refactor == "Extract Symbol" {
-                let action = provider.extract_variable(root, range)?;
-                let edit = action.edit?;
-                let mut file_edits = Vec::new();
-                for (fname, edits) in edit.changes {
-                    let mut text_changes = Vec::new();
-                    for e in edits {
-                        text_changes.push(serde_json::json!({
-                            "start": {
-                                "line": e.range.start.line + 1,
-                                "offset": e.range.start.character + 1
-                            },
-                            "end": {
-                                "line": e.range.end.line + 1,
-                                "offset": e.range.end.character + 1
-                            },
-                            "newText": e.new_text
-                        }));
-                    }
-                    file_edits.push(serde_json::json!({
-                        "fileName": fname,
-                        "textChanges": text_changes
-                    }));
+            let action_name = request
+                .arguments
+                .get("action")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default();
+            let action = match (refactor, action_name) {
+                ("Extract Symbol", name) if name.starts_with("function_") => {
+                    provider.extract_function(root, range)
                 }
-                return Some(serde_json::json!({ "edits": file_edits }));
-            }
-
-            None
+                ("Extract Symbol", _) => provider.extract_variable(root, range),
+                ("Move to a new file", _) => provider.move_to_new_file(root, range),
+                ("Convert export", "Convert default export to named export") => {
+                    provider.convert_to_named_export(root, range)
+                }
+                ("Convert export", "Convert named export to default export") => {
+                    provider.convert_to_default_export(root, range)
+                }
+                _ => None,
+            }?;
+            let edit = action.edit?;
+            Some(serde_json::json!({ "edits": self.file_code_edits(edit) }))
         })();
 
         self.success_response(
@@ -844,6 +989,40 @@ impl Server {
         )
     }
 
+    /// The JSX factories of `file`'s project when its `jsx` option is
+    /// `react`, whose root names count as used by the file's JSX.
+    fn react_jsx_factories_for_file(&self, file: &str) -> Option<ReactJsxFactories> {
+        let project = self.external_project_for_file(file).or_else(|| {
+            let config_path = self.find_project_config_file(file)?;
+            self.load_configured_project(&config_path)
+        });
+        let options = match project {
+            Some(project) => project.config.compiler_options.unwrap_or_default(),
+            None => tsz::config::CompilerOptions {
+                jsx: self.inferred_check_options.jsx.clone(),
+                ..Default::default()
+            },
+        };
+        if !options.jsx?.eq_ignore_ascii_case("react") {
+            return None;
+        }
+        let defaults = ReactJsxFactories::default();
+        let factory = options
+            .jsx_factory
+            .or_else(|| {
+                options
+                    .react_namespace
+                    .map(|namespace| format!("{namespace}.createElement"))
+            })
+            .unwrap_or(defaults.factory);
+        Some(ReactJsxFactories {
+            factory,
+            fragment_factory: options
+                .jsx_fragment_factory
+                .unwrap_or(defaults.fragment_factory),
+        })
+    }
+
     pub(crate) fn handle_organize_imports(
         &mut self,
         seq: u64,
@@ -858,7 +1037,28 @@ impl Server {
                 .and_then(|v| v.as_str())
                 .or_else(|| request.arguments.get("file").and_then(|v| v.as_str()))?;
 
-            let (arena, binder, root, content) = self.parse_and_bind_file(file)?;
+            let (_, _, _, content) = self.parse_and_bind_file(file)?;
+
+            // `mode` supersedes the deprecated `skipDestructiveCodeActions`,
+            // which only ever meant "sort and combine, but remove nothing".
+            let mode = request
+                .arguments
+                .get("mode")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_else(|| {
+                    let skip_destructive = request
+                        .arguments
+                        .get("skipDestructiveCodeActions")
+                        .and_then(serde_json::Value::as_bool)
+                        .unwrap_or(false);
+                    if skip_destructive {
+                        "SortAndCombine"
+                    } else {
+                        "All"
+                    }
+                });
+            let remove_unused = mode != "SortAndCombine";
+            let sort = mode != "RemoveUnused";
 
             let parse_organize_imports_ignore_case = |value: &serde_json::Value| {
                 value
@@ -890,32 +1090,85 @@ impl Server {
                 })
                 .map(ToOwned::to_owned)
                 .or_else(|| self.organize_imports_type_order.clone());
+            let react_jsx_factories = self.react_jsx_factories_for_file(file);
+
+            let organize_pass = |text: &str, remove_unused: bool| {
+                let mut parser = ParserState::new(file.to_string(), text.to_string());
+                let root = parser.parse_source_file();
+                let arena = parser.into_arena();
+                let mut binder = BinderState::new();
+                binder.bind_source_file(&arena, root);
+                let line_map = LineMap::build(text);
+                let provider =
+                    CodeActionProvider::new(&arena, &binder, &line_map, file.to_string(), text)
+                        .with_organize_imports_ignore_case(organize_imports_ignore_case)
+                        .with_organize_imports_type_order(organize_imports_type_order.clone())
+                        .with_react_jsx_factories(react_jsx_factories.clone());
+                if remove_unused {
+                    provider.remove_unused_imports(root)
+                } else {
+                    provider
+                        .organize_imports(root)
+                        .and_then(|action| action.edit)
+                        .and_then(|mut edit| edit.changes.remove(file))
+                        .unwrap_or_default()
+                }
+            };
 
-            let line_map = LineMap::build(&content);
-            let provider =
-                CodeActionProvider::new(&arena, &binder, &line_map, file.to_string(), &content)
-                    .with_organize_imports_ignore_case(organize_imports_ignore_case)
-                    .with_organize_imports_type_order(organize_imports_type_order);
-
-            let action = provider.organize_imports(root)?;
+            // Removal runs first and sorting re-parses its output, so the
+            // sort never sees declarations that are about to disappear.
+            let content_line_map = LineMap::build(&content);
+            let removal_edits = if remove_unused {
+                organize_pass(&content, true)
+            } else {
+                Vec::new()
+            };
+            let pruned = if removal_edits.is_empty() {
+                content.clone()
+            } else {
+                Self::apply_text_edits_to_source(&content, &content_line_map, &removal_edits)?
+            };
+            let sort_edits = if sort {
+                organize_pass(&pruned, false)
+            } else {
+                Vec::new()
+            };
 
-            let mut text_changes = Vec::new();
-            if let Some(edit) = action.edit {
-                for (_fname, edits) in edit.changes {
-                    for e in edits {
-                        text_changes.push(serde_json::json!({
-                            "start": {
-                                "line": e.range.start.line + 1,
-                                "offset": e.range.start.character + 1
-                            },
-                            "end": {
-                                "line": e.range.end.line + 1,
-                                "offset": e.range.end.character + 1
-                            },
-                            "newText": e.new_text
-                        }));
-                    }
-                }
+            let to_text_change = |range: Range, new_text: &str| {
+                serde_json::json!({
+                    "start": {
+                        "line": range.start.line + 1,
+                        "offset": range.start.character + 1
+                    },
+                    "end": {
+                        "line": range.end.line + 1,
+                        "offset": range.end.character + 1
+                    },
+                    "newText": new_text
+                })
+            };
+            // Either pass alone already has edits against the original text;
+            // when both ran, the sort edits are relative to the pruned text,
+            // so report the combined change as one edit over the original.
+            let text_changes: Vec<serde_json::Value> = if removal_edits.is_empty() {
+                sort_edits
+                    .iter()
+                    .map(|edit| to_text_change(edit.range, &edit.new_text))
+                    .collect()
+            } else if sort_edits.is_empty() {
+                removal_edits
+                    .iter()
+                    .map(|edit| to_text_change(edit.range, &edit.new_text))
+                    .collect()
+            } else {
+                let pruned_line_map = LineMap::build(&pruned);
+                let organized =
+                    Self::apply_text_edits_to_source(&pruned, &pruned_line_map, &sort_edits)?;
+                let edit = minimal_replacement_edit(&content, &organized, &content_line_map);
+                vec![to_text_change(edit.range, &edit.new_text)]
+            };
+            if text_changes.is_empty() {
+                return None;
             }
 
             Some(serde_json::json!([{
@@ -1348,82 +1601,16 @@ impl Server {
         request: &TsServerRequest,
     ) -> TsServerResponse {
         match request.command.as_str() {
-            "openExternalProject" => {
-                self.apply_inferred_project_options(request.arguments.get("options"));
-                let project_name = request
-                    .arguments
-                    .get("projectFileName")
-                    .and_then(serde_json::Value::as_str)
-                    .unwrap_or("")
-                    .to_string();
-
-                let mut tracked_files = Vec::new();
-                if let Some(root_files) = request
-                    .arguments
-                    .get("rootFiles")
-                    .and_then(serde_json::Value::as_array)
-                {
-                    for entry in root_files {
-                        let Some(file_name) = entry.get("fileName").and_then(|v| v.as_str()) else {
-                            continue;
-                        };
-                        let content = entry
-                            .get("content")
-                            .and_then(serde_json::Value::as_str)
-                            .map(std::string::ToString::to_string)
-                            .or_else(|| std::fs::read_to_string(file_name).ok());
-                        if let Some(content) = content {
-                            self.open_files.insert(file_name.to_string(), content);
-                        }
-                        tracked_files.push(file_name.to_string());
-                    }
-                }
-                if !project_name.is_empty() {
-                    self.external_project_files
-                        .insert(project_name, tracked_files);
-                }
-            }
+            "openExternalProject" => self.open_external_project(&request.arguments),
             "openExternalProjects" => {
-                if let Some(projects) = request
+                for project in request
                     .arguments
                     .get("projects")
                     .and_then(serde_json::Value::as_array)
+                    .into_iter()
+                    .flatten()
                 {
-                    for project in projects {
-                        self.apply_inferred_project_options(project.get("options"));
-                        let project_name = project
-                            .get("projectFileName")
-                            .and_then(serde_json::Value::as_str)
-                            .unwrap_or("")
-                            .to_string();
-
-                        let mut tracked_files = Vec::new();
-                        if let Some(root_files) = project
-                            .get("rootFiles")
-                            .and_then(serde_json::Value::as_array)
-                        {
-                            for entry in root_files {
-                                let Some(file_name) =
-                                    entry.get("fileName").and_then(|v| v.as_str())
-                                else {
-                                    continue;
-                                };
-                                let content = entry
-                                    .get("content")
-                                    .and_then(serde_json::Value::as_str)
-                                    .map(std::string::ToString::to_string)
-                                    .or_else(|| std::fs::read_to_string(file_name).ok());
-                                if let Some(content) = content {
-                                    self.open_files.insert(file_name.to_string(), content);
-                                }
-                                tracked_files.push(file_name.to_string());
-                            }
-                        }
-                        if !project_name.is_empty() {
-                            self.external_project_files
-                                .insert(project_name, tracked_files);
-                        }
-                    }
+                    self.open_external_project(project);
                 }
             }
             "closeExternalProject" => {
@@ -1431,17 +1618,8 @@ impl Server {
                     .arguments
                     .get("projectFileName")
                     .and_then(serde_json::Value::as_str)
-                    && let Some(files) = self.external_project_files.remove(project_name)
                 {
-                    for file in files {
-                        let still_owned_elsewhere = self
-                            .external_project_files
-                            .values()
-                            .any(|other_files| other_files.iter().any(|p| p == &file));
-                        if !still_owned_elsewhere {
-                            self.open_files.remove(&file);
-                        }
-                    }
+                    self.close_external_project(project_name);
                 }
             }
             _ => {}
@@ -1471,10 +1649,15 @@ impl Server {
         projects.sort_by_key(|(left, _)| *left);
 
         for (project_name, files) in projects {
+            let options = self
+                .external_projects
+                .get(project_name)
+                .map(|project| serde_json::Value::Object(project.compiler_options.clone()))
+                .unwrap_or_else(|| serde_json::json!({}));
             body.push(Self::synchronize_project_list_entry(
                 project_name,
                 false,
-                serde_json::json!({}),
+                options,
                 files.clone(),
                 include_redirect_info,
             ));
@@ -1629,6 +1812,15 @@ impl Server {
             let (arena, binder, root, source_text) = self.parse_and_bind_file(file)?;
             let line_map = LineMap::build(&source_text);
             let interner = TypeInterner::new();
+            // tsserver default for `includeInlayParameterNameHints` is `"none"`:
+            // parameter hints are suppressed unless the client explicitly opts
+            // in via `configure`. Type hints are unaffected by this
+            // preference. See #3793.
+            let parameter_names = self
+                .include_inlay_parameter_name_hints
+                .as_deref()
+                .and_then(ParameterNameHints::from_setting)
+                .unwrap_or(ParameterNameHints::None);
             let provider = InlayHintsProvider::new(
                 &arena,
                 &binder,
@@ -1636,7 +1828,11 @@ impl Server {
                 &source_text,
                 &interner,
                 file.to_string(),
-            );
+            )
+            .with_options(InlayHintsOptions {
+                parameter_names,
+                ..InlayHintsOptions::default()
+            });
 
             let protocol_span = request
                 .arguments
@@ -1693,20 +1889,9 @@ impl Server {
             };
 
             let hints = provider.provide_inlay_hints(root, range);
-            // tsserver default for `includeInlayParameterNameHints` is `"none"`:
-            // parameter hints are suppressed unless the client explicitly opts
-            // in via `configure`. Type/Generic hints are unaffected by this
-            // preference. See #3793.
-            let parameter_hints_enabled = matches!(
-                self.include_inlay_parameter_name_hints.as_deref(),
-                Some("literals") | Some("all")
-            );
             let body: Vec<serde_json::Value> = hints
                 .iter()
                 .filter(|hint| {
-                    if matches!(hint.kind, InlayHintKind::Parameter) && !parameter_hints_enabled {
-                        return false;
-                    }
                     protocol_span.is_none_or(|(start, end)| {
                         line_map
                             .position_to_offset(hint.position, &source_text)
@@ -1717,7 +1902,7 @@ impl Server {
                     let kind = match hint.kind {
                         InlayHintKind::Parameter => "Parameter",
                         InlayHintKind::Type => "Type",
-                        InlayHintKind::Generic => "Enum",
+                        InlayHintKind::Generic | InlayHintKind::Enum => "Enum",
                     };
                     // tsserver-shape parameter hints carry no trailing space in
                     // `text` and don't include `whitespaceBefore` (the default
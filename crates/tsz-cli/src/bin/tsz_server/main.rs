@@ -7,10 +7,13 @@
 //! tsserver Protocol (default):
 //! - Input: Content-Length framed JSON on stdin
 //! - Output: Content-Length framed JSON on stdout
+//! - Events: on stdout, or on a localhost TCP socket with `--eventPort`
 //!
 //! Legacy Protocol (--protocol legacy):
 //! - Input: JSON objects on stdin (one per line)
 //! - Output: JSON objects on stdout (one per line)
+//! - `--max-memory-mb <MB>`: recycle the lib and bind caches after a check
+//!   once resident memory exceeds the budget
 //!
 //! tsserver-compatible CLI flags:
 //!   --syntaxOnly, --useSingleInferredProject, --useInferredProjectPerProjectRoot,
@@ -29,7 +32,13 @@
 //! echo '{"type":"check","id":1,"files":{"main.ts":"const x: string = 1;"}}' | tsz-server --protocol legacy
 //! ```
 
+mod bind_cache;
+mod cancellation;
 mod check;
+mod configured_project;
+mod emit_output;
+mod external_project;
+mod file_watching;
 mod handlers_code_fixes;
 mod handlers_code_fixes_enum_member;
 mod handlers_code_fixes_fallbacks;
@@ -48,10 +57,13 @@ mod handlers_files;
 mod handlers_info;
 mod handlers_info_alias;
 mod handlers_legacy;
+mod handlers_navto;
 mod handlers_project_info;
 mod handlers_quickinfo;
 mod handlers_quickinfo_text;
 mod handlers_structure;
+mod memory_budget;
+mod server_mode;
 mod text_edits;
 
 use anyhow::{Context, Result};
@@ -61,7 +73,8 @@ use serde::{Deserialize, Serialize};
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tracing::{debug, info};
+use std::time::Duration;
+use tracing::{debug, info, warn};
 use tsz_common::limits;
 
 use tsz::binder::BinderState;
@@ -257,7 +270,7 @@ struct ServerArgs {
     #[arg(long = "serverMode", alias = "server-mode")]
     server_mode: Option<String>,
 
-    /// TCP port for delivering events (if not specified, events go to stdout).
+    /// TCP port on localhost to deliver events to (if not specified, events go to stdout).
     #[arg(long = "eventPort", alias = "event-port")]
     event_port: Option<u16>,
 
@@ -312,6 +325,11 @@ struct ServerArgs {
     /// Protocol mode: 'tsserver' (Content-Length framed, default) or 'legacy' (JSON per line).
     #[arg(long, default_value = "tsserver")]
     protocol: Protocol,
+
+    /// Legacy protocol: recycle the lib and bind caches after a check once
+    /// the resident set exceeds this many MiB.
+    #[arg(long = "max-memory-mb", value_name = "MB")]
+    max_memory_mb: Option<u64>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, clap::ValueEnum)]
@@ -414,6 +432,7 @@ struct CheckOptions {
     no_implicit_override: bool,
     downlevel_iteration: bool,
     no_fallthrough_cases_in_switch: bool,
+    no_error_truncation: bool,
     strict_builtin_iterator_return: Option<bool>,
     declaration: bool,
     // Server-protocol checker options that were previously hardcoded to false
@@ -453,6 +472,8 @@ struct StatusResponse {
     memory_mb: u64,
     checks_completed: u64,
     cached_libs: usize,
+    max_memory_mb: Option<u64>,
+    memory_recycles: u64,
 }
 
 #[derive(Debug, Serialize)]
@@ -567,6 +588,10 @@ pub(crate) struct Server {
     pub(crate) open_files: FxHashMap<String, String>,
     /// Files registered by each external project (`openExternalProject`).
     pub(crate) external_project_files: FxHashMap<String, Vec<String>>,
+    /// Compiler options and inline snapshots of each external project.
+    pub(crate) external_projects: FxHashMap<String, self::external_project::ExternalProject>,
+    /// Parse/bind results reused across semantic queries for unchanged files.
+    pub(crate) bind_cache: self::bind_cache::BindCache,
     /// Completion preference: import module specifier ending (e.g. "js")
     pub(crate) completion_import_module_specifier_ending: Option<String>,
     /// Completion/codefix preference: import module specifier preference.
@@ -608,8 +633,9 @@ pub(crate) struct Server {
     pub(crate) auto_imports_allowed_for_inferred_projects: bool,
     /// Whether inferred projects should be checked as `module:none`.
     pub(crate) inferred_module_is_none_for_projects: bool,
-    /// Server mode
-    pub(crate) _server_mode: ServerMode,
+    /// `--serverMode`: which commands are served and whether requests see
+    /// the whole project or only the requested file.
+    pub(crate) server_mode: ServerMode,
     /// Log configuration
     pub(crate) _log_config: LogConfig,
     /// Whether telemetry responses should be emitted.
@@ -628,6 +654,18 @@ pub(crate) struct Server {
     /// `semanticDiag`, `suggestionDiag`, and `requestCompleted` events.
     /// See #3544.
     pub(crate) pending_events: Vec<serde_json::Value>,
+    /// `geterr` whose per-file diagnostics events are still to be streamed.
+    pub(crate) pending_geterr: Option<self::handlers_diagnostics::PendingGeterr>,
+    /// `--canUseWatchEvents`: the client watches files on our behalf.
+    pub(crate) can_use_watch_events: bool,
+    /// Configured projects being watched for on-disk changes.
+    pub(crate) file_watch: self::file_watching::FileWatchState,
+    /// Loaded configured projects, keyed by config path.
+    pub(crate) configured_projects: self::configured_project::ConfiguredProjectCache,
+    /// `--cancellationPipeName` semaphore for the request being served.
+    pub(crate) cancellation: self::cancellation::CancellationToken,
+    /// `--max-memory-mb` budget for the legacy check protocol.
+    pub(crate) memory_budget: self::memory_budget::MemoryBudget,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -646,9 +684,12 @@ impl Server {
         let server_mode = if args.syntax_only {
             ServerMode::Syntactic
         } else {
+            // tsserver matches the mode name case-insensitively.
             match args.server_mode.as_deref() {
-                Some("partialSemantic") => ServerMode::PartialSemantic,
-                Some("syntactic") => ServerMode::Syntactic,
+                Some(mode) if mode.eq_ignore_ascii_case("partialSemantic") => {
+                    ServerMode::PartialSemantic
+                }
+                Some(mode) if mode.eq_ignore_ascii_case("syntactic") => ServerMode::Syntactic,
                 _ => ServerMode::Semantic,
             }
         };
@@ -679,6 +720,8 @@ impl Server {
             response_seq: 0,
             open_files: FxHashMap::default(),
             external_project_files: FxHashMap::default(),
+            external_projects: FxHashMap::default(),
+            bind_cache: Default::default(),
             completion_import_module_specifier_ending: None,
             import_module_specifier_preference: None,
             organize_imports_type_order: None,
@@ -694,13 +737,21 @@ impl Server {
             inferred_projectinfo_options: None,
             auto_imports_allowed_for_inferred_projects: true,
             inferred_module_is_none_for_projects: false,
-            _server_mode: server_mode,
+            server_mode,
             _log_config: log_config,
             enable_telemetry: args.enable_telemetry,
             plugin_configs: FxHashMap::default(),
             native_ts_worker: self::handlers_info_alias::NativeTsWorker::spawn()
                 .map(std::sync::Mutex::new),
             pending_events: Vec::new(),
+            pending_geterr: None,
+            can_use_watch_events: args.can_use_watch_events,
+            file_watch: self::file_watching::FileWatchState::default(),
+            configured_projects: Default::default(),
+            cancellation: self::cancellation::CancellationToken::new(
+                args.cancellation_pipe_name.clone(),
+            ),
+            memory_budget: self::memory_budget::MemoryBudget::new(args.max_memory_mb),
         })
     }
 
@@ -729,7 +780,12 @@ impl Server {
 
     fn reset_session_state(&mut self) {
         self.open_files.clear();
+        self.pending_geterr = None;
+        self.file_watch = self::file_watching::FileWatchState::default();
+        self.configured_projects.clear();
         self.external_project_files.clear();
+        self.external_projects.clear();
+        self.bind_cache.clear();
         self.completion_import_module_specifier_ending = None;
         self.import_module_specifier_preference = None;
         self.organize_imports_type_order = None;
@@ -1028,7 +1084,27 @@ impl Server {
     // =========================================================================
 
     fn handle_tsserver_request(&mut self, request: TsServerRequest) -> TsServerResponse {
+        self.cancellation.begin_request(request.seq);
+        let response = if self.cancellation.is_cancelled() {
+            let seq = self.next_seq();
+            Self::cancelled_response(self.build_response(seq, &request, true, None, None))
+        } else {
+            let response = self.dispatch_tsserver_request(request);
+            if self.cancellation.is_cancelled() {
+                Self::cancelled_response(response)
+            } else {
+                response
+            }
+        };
+        self.cancellation.end_request();
+        response
+    }
+
+    fn dispatch_tsserver_request(&mut self, request: TsServerRequest) -> TsServerResponse {
         let seq = self.next_seq();
+        if !self.server_mode.allows_command(&request.command) {
+            return self.server_mode_rejection_response(seq, &request);
+        }
         match request.command.as_str() {
             "open" => self.handle_open(seq, &request),
             "close" => self.handle_close(seq, &request),
@@ -1132,6 +1208,7 @@ impl Server {
             }
             "reload" => self.handle_reload(seq, &request),
             "reloadProjects" => self.handle_reload_projects(seq, &request),
+            "watchChange" if self.can_use_watch_events => self.handle_watch_change(seq, &request),
             "status" => self.success_response(
                 seq,
                 &request,
@@ -1724,7 +1801,7 @@ fn read_content_length_message<R: BufRead>(reader: &mut R) -> Result<Option<Stri
 }
 
 /// Write a Content-Length framed message to stdout (tsserver protocol)
-fn write_content_length_message<W: Write>(stdout: &mut W, message: &str) -> Result<()> {
+fn write_content_length_message<W: Write + ?Sized>(stdout: &mut W, message: &str) -> Result<()> {
     write!(
         stdout,
         "Content-Length: {}\r\n\r\n{}",
@@ -1758,34 +1835,137 @@ fn main() -> Result<()> {
 }
 
 fn server_main(args: ServerArgs) -> Result<()> {
+    tsz_cli::locale::init_locale(args.locale.as_deref());
     let mut server = Server::new(&args).context("failed to initialize server")?;
 
     info!("tsz-server ready (protocol: {:?})", args.protocol);
 
     match args.protocol {
-        Protocol::Tsserver => run_tsserver_protocol(&mut server)?,
+        Protocol::Tsserver => run_tsserver_protocol(&mut server, args.event_port)?,
         Protocol::Legacy => run_legacy_protocol(&mut server)?,
     }
 
     Ok(())
 }
 
-fn run_tsserver_protocol(server: &mut Server) -> Result<()> {
-    let mut stdin = BufReader::new(std::io::stdin());
+fn run_tsserver_protocol(server: &mut Server, event_port: Option<u16>) -> Result<()> {
     let mut stdout = std::io::stdout();
+    let mut event_socket = event_port.and_then(connect_event_socket);
+    let events = event_socket.as_mut().map(|socket| socket as &mut dyn Write);
+
+    // stdin is read on its own thread so the message loop can wait for the
+    // next request with a timeout: idle gaps are spent streaming a pending
+    // `geterr` one file at a time and polling the watched projects.
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let mut stdin = BufReader::new(std::io::stdin());
+        loop {
+            let message = read_content_length_message(&mut stdin);
+            let done = !matches!(message, Ok(Some(_)));
+            if sender.send(message).is_err() || done {
+                break;
+            }
+        }
+    });
+    run_tsserver_message_loop(server, &mut stdout, events, |wait| {
+        let timeout = wait.map_or(self::file_watching::FILE_WATCH_POLL_INTERVAL, |wait| {
+            wait.min(self::file_watching::FILE_WATCH_POLL_INTERVAL)
+        });
+        match receiver.recv_timeout(timeout) {
+            Ok(message) => Ok(message?.map_or(IncomingMessage::Eof, IncomingMessage::Request)),
+            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => Ok(IncomingMessage::Idle),
+            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => Ok(IncomingMessage::Eof),
+        }
+    })
+}
 
-    run_tsserver_protocol_with_io(server, &mut stdin, &mut stdout)
+/// Connect to the host's `--eventPort` listener. Like tsserver, the server
+/// is the client here: the host listens on localhost and reads
+/// Content-Length framed events from the socket. When the connection
+/// fails, events stay on stdout next to the responses.
+fn connect_event_socket(port: u16) -> Option<std::net::TcpStream> {
+    match std::net::TcpStream::connect((std::net::Ipv4Addr::LOCALHOST, port)) {
+        Ok(socket) => {
+            let _ = socket.set_nodelay(true);
+            info!("Delivering events on port {port}");
+            Some(socket)
+        }
+        Err(err) => {
+            warn!("failed to connect to event port {port}: {err}; events go to stdout");
+            None
+        }
+    }
 }
 
+/// What the protocol runner got while waiting for the next request.
+enum IncomingMessage {
+    Request(String),
+    /// No request arrived within the time the runner was willing to wait.
+    Idle,
+    Eof,
+}
+
+/// Run the protocol over an in-memory reader. Once the input is exhausted
+/// the pending `geterr` is still streamed to completion before EOF.
+#[cfg(test)]
 fn run_tsserver_protocol_with_io<R: BufRead, W: Write>(
     server: &mut Server,
     stdin: &mut R,
     stdout: &mut W,
+    events: Option<&mut dyn Write>,
+) -> Result<()> {
+    run_tsserver_message_loop(server, stdout, events, |wait| {
+        if let Some(wait) = wait
+            && stdin.fill_buf()?.is_empty()
+        {
+            std::thread::sleep(wait);
+            return Ok(IncomingMessage::Idle);
+        }
+        Ok(read_content_length_message(stdin)?
+            .map_or(IncomingMessage::Eof, IncomingMessage::Request))
+    })
+}
+
+/// Write the server's queued async events to the `--eventPort` socket when
+/// there is one, otherwise to stdout after the responses.
+fn write_pending_events<W: Write>(
+    server: &mut Server,
+    stdout: &mut W,
+    events: &mut Option<&mut dyn Write>,
+) -> Result<()> {
+    for event in server.drain_pending_events() {
+        let json = serde_json::to_string(&event)?;
+        match events {
+            Some(socket) => write_content_length_message(&mut **socket, &json)
+                .context("failed to write event to event port")?,
+            None => write_content_length_message(stdout, &json)?,
+        }
+    }
+    Ok(())
+}
+
+fn run_tsserver_message_loop<W: Write>(
+    server: &mut Server,
+    stdout: &mut W,
+    mut events: Option<&mut dyn Write>,
+    mut next_message: impl FnMut(Option<Duration>) -> Result<IncomingMessage>,
 ) -> Result<()> {
     loop {
-        let message = match read_content_length_message(stdin)? {
-            Some(msg) => msg,
-            None => break, // EOF
+        // A pending `geterr` bounds the wait: once its delay has passed it
+        // streams one file per idle turn, so requests that arrive meanwhile
+        // are served first and a newer `geterr` or an edit can replace it.
+        let message = match next_message(server.pending_geterr_wait())? {
+            IncomingMessage::Request(msg) => msg,
+            IncomingMessage::Idle => {
+                if server.pending_geterr_wait() == Some(Duration::ZERO) {
+                    server.run_geterr_step();
+                } else {
+                    server.poll_file_watchers();
+                }
+                write_pending_events(server, stdout, &mut events)?;
+                continue;
+            }
+            IncomingMessage::Eof => break,
         };
 
         if message.trim().is_empty() {
@@ -1821,9 +2001,13 @@ fn run_tsserver_protocol_with_io<R: BufRead, W: Write>(
         // Async events queued by the handler (e.g. `geterr` → `syntaxDiag`
         // / `semanticDiag` / `suggestionDiag` / `requestCompleted`) write
         // after the originating response. See #3544.
-        for event in server.drain_pending_events() {
-            let json = serde_json::to_string(&event)?;
-            write_content_length_message(stdout, &json)?;
+        write_pending_events(server, stdout, &mut events)?;
+
+        // Editors watching on our behalf need watcher requests as soon as a
+        // file under a new config opens.
+        if server.can_use_watch_events {
+            server.sync_client_file_watchers();
+            write_pending_events(server, stdout, &mut events)?;
         }
     }
 
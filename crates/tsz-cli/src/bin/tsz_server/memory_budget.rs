@@ -0,0 +1,141 @@
+//! Resident-memory budget for the legacy check protocol.
+//!
+//! Conformance runners keep one `tsz-server --protocol legacy` alive for
+//! thousands of `check` requests. The parsed lib cache, the unified lib
+//! binder and the bind cache all pin arenas and interner shards, so the
+//! process only ever grows. With `--max-memory-mb` the server samples its
+//! resident set after each check and drops those caches once it is over
+//! budget; the next check rebuilds them.
+//!
+//! Freed pages usually stay with the allocator, so a recycle need not bring
+//! RSS back under budget. When it does not, the next recycle waits until RSS
+//! has grown by a tenth of the budget past what the last one left, rather
+//! than dropping and rebuilding the caches after every check.
+
+use super::Server;
+use tracing::{info, warn};
+
+#[derive(Debug, Default)]
+pub(crate) struct MemoryBudget {
+    max_memory_mb: Option<u64>,
+    /// Number of recycles forced by the budget so far.
+    recycles: u64,
+    /// RSS right after the last recycle, when that recycle left it over
+    /// budget.
+    unrecovered_rss_mb: Option<u64>,
+}
+
+impl MemoryBudget {
+    pub(crate) const fn new(max_memory_mb: Option<u64>) -> Self {
+        Self {
+            max_memory_mb,
+            recycles: 0,
+            unrecovered_rss_mb: None,
+        }
+    }
+
+    pub(crate) const fn max_memory_mb(&self) -> Option<u64> {
+        self.max_memory_mb
+    }
+
+    pub(crate) const fn recycles(&self) -> u64 {
+        self.recycles
+    }
+
+    /// Whether a resident set of `rss_mb` calls for a recycle: it is over
+    /// budget and, after a recycle that did not recover memory, has grown
+    /// past what that recycle left. An unknown RSS (non-Linux hosts) never
+    /// does.
+    pub(crate) fn is_exceeded_by(&self, rss_mb: Option<u64>) -> bool {
+        let (Some(max), Some(rss)) = (self.max_memory_mb, rss_mb) else {
+            return false;
+        };
+        let threshold = self
+            .unrecovered_rss_mb
+            .map_or(max, |floor| max.max(floor + max / 10));
+        rss > threshold
+    }
+
+    /// Count a recycle that left the resident set at `rss_after_mb`.
+    pub(crate) fn record_recycle(&mut self, rss_after_mb: Option<u64>) {
+        self.recycles += 1;
+        self.unrecovered_rss_mb =
+            rss_after_mb.filter(|&rss| self.max_memory_mb.is_some_and(|max| rss > max));
+    }
+
+    /// RSS left over budget by the last recycle, if it was.
+    pub(crate) const fn unrecovered_rss_mb(&self) -> Option<u64> {
+        self.unrecovered_rss_mb
+    }
+}
+
+/// Current resident set size in MiB, or `None` where it can't be read.
+pub(crate) fn resident_memory_mb() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        std::fs::read_to_string("/proc/self/status")
+            .ok()
+            .and_then(|status| parse_vm_rss_mb(&status))
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// Parse the `VmRSS:` line of `/proc/<pid>/status`, which is reported in kB.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub(crate) fn parse_vm_rss_mb(status: &str) -> Option<u64> {
+    let kb = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .split_whitespace()
+        .next()?
+        .parse::<u64>()
+        .ok()?;
+    Some(kb / 1024)
+}
+
+impl Server {
+    /// Drop every cache that outlives a single legacy check, including the
+    /// shared parsed-lib cache and the checker and solver thread-local memos.
+    pub(crate) fn recycle_check_caches(&mut self) {
+        self.lib_cache.clear();
+        self.unified_lib_cache = None;
+        self.bind_cache.clear();
+        tsz::clear_lib_file_cache();
+        tsz_solver::construction::clear_thread_local_cache();
+        tsz::checker::clear_all_thread_local_state();
+    }
+
+    /// Sample RSS and recycle if it is over `--max-memory-mb`. Without a
+    /// budget this does not touch `/proc` at all.
+    pub(crate) fn enforce_memory_budget(&mut self) -> bool {
+        if self.memory_budget.max_memory_mb().is_none() {
+            return false;
+        }
+        self.recycle_if_over_memory_budget(resident_memory_mb())
+    }
+
+    /// Recycle when `rss_mb` is over budget, then sample RSS again to see
+    /// whether that helped. Returns whether it recycled.
+    pub(crate) fn recycle_if_over_memory_budget(&mut self, rss_mb: Option<u64>) -> bool {
+        if !self.memory_budget.is_exceeded_by(rss_mb) {
+            return false;
+        }
+        let max_memory_mb = self.memory_budget.max_memory_mb().unwrap_or(0);
+        info!(
+            "RSS {} MB exceeds --max-memory-mb {max_memory_mb}; recycling lib and bind caches",
+            rss_mb.unwrap_or(0),
+        );
+        self.recycle_check_caches();
+        self.memory_budget.record_recycle(resident_memory_mb());
+        if let Some(rss_after_mb) = self.memory_budget.unrecovered_rss_mb() {
+            warn!(
+                "RSS is still {rss_after_mb} MB after recycling, over --max-memory-mb \
+                 {max_memory_mb}; the allocator kept the freed pages"
+            );
+        }
+        true
+    }
+}
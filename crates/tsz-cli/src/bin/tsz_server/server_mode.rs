@@ -0,0 +1,135 @@
+//! `--serverMode` / `--syntaxOnly` command gating.
+//!
+//! tsserver runs in one of three language-service modes. `semantic` is the
+//! full server. `partialSemantic` answers from the requested file alone: no
+//! configured or external projects and no cross-file resolution, but
+//! single-file semantic features such as quick info and completions still
+//! work. `syntactic` only parses. Commands a mode cannot serve are rejected
+//! with the same error tsserver reports.
+
+use super::{Server, ServerMode, TsServerRequest, TsServerResponse};
+
+/// Commands that need a whole program (or project bookkeeping) and are
+/// rejected outside `semantic` mode.
+const INVALID_PARTIAL_SEMANTIC_MODE_COMMANDS: &[&str] = &[
+    "openExternalProject",
+    "openExternalProjects",
+    "closeExternalProject",
+    "synchronizeProjectList",
+    "emit-output",
+    // This server's alias of `emit-output`.
+    "emitOutput",
+    "compileOnSaveAffectedFileList",
+    "compileOnSaveEmitFile",
+    "compilerOptionsDiagnostics-full",
+    "encodedSemanticClassifications-full",
+    "semanticDiagnosticsSync",
+    "suggestionDiagnosticsSync",
+    "geterrForProject",
+    "reload",
+    "reloadProjects",
+    "getCodeFixes",
+    "getCodeFixes-full",
+    "getCombinedCodeFix",
+    "getCombinedCodeFix-full",
+    "applyCodeActionCommand",
+    "getSupportedCodeFixes",
+    "getApplicableRefactors",
+    "getMoveToRefactoringFileSuggestions",
+    "getEditsForRefactor",
+    "getEditsForRefactor-full",
+    "organizeImports",
+    "organizeImports-full",
+    "getEditsForFileRename",
+    "getEditsForFileRename-full",
+    "prepareCallHierarchy",
+    "provideCallHierarchyIncomingCalls",
+    "provideCallHierarchyOutgoingCalls",
+    "getPasteEdits",
+    "copilotRelated",
+];
+
+/// Single-file semantic commands, additionally rejected in `syntactic` mode.
+const INVALID_SYNTACTIC_MODE_COMMANDS: &[&str] = &[
+    "definition",
+    "definition-full",
+    "findSourceDefinition",
+    "definitionAndBoundSpan",
+    "definitionAndBoundSpan-full",
+    "typeDefinition",
+    "typeDefinition-full",
+    "implementation",
+    "implementation-full",
+    "references",
+    "references-full",
+    "fileReferences",
+    "fileReferences-full",
+    "rename",
+    "rename-full",
+    "quickinfo",
+    "quickinfo-full",
+    "completions",
+    "completionInfo",
+    "completions-full",
+    "completionEntryDetails",
+    "completionEntryDetails-full",
+    "signatureHelp",
+    "signatureHelp-full",
+    "navto",
+    "navTo",
+    "navto-full",
+    "navTo-full",
+    "documentHighlights",
+    "inlayHints",
+    "provideInlayHints",
+    "preparePasteEdits",
+];
+
+impl ServerMode {
+    /// The `LanguageServiceMode` member name tsserver uses in messages.
+    const fn language_service_mode_name(self) -> &'static str {
+        match self {
+            Self::Semantic => "Semantic",
+            Self::PartialSemantic => "PartialSemantic",
+            Self::Syntactic => "Syntactic",
+        }
+    }
+
+    pub(crate) fn allows_command(self, command: &str) -> bool {
+        match self {
+            Self::Semantic => true,
+            Self::PartialSemantic => !INVALID_PARTIAL_SEMANTIC_MODE_COMMANDS.contains(&command),
+            Self::Syntactic => {
+                !INVALID_PARTIAL_SEMANTIC_MODE_COMMANDS.contains(&command)
+                    && !INVALID_SYNTACTIC_MODE_COMMANDS.contains(&command)
+            }
+        }
+    }
+}
+
+impl Server {
+    /// Whether requests are answered from the requested file alone, without
+    /// project membership or cross-file resolution.
+    pub(crate) fn is_single_file_mode(&self) -> bool {
+        self.server_mode != ServerMode::Semantic
+    }
+
+    /// The error tsserver returns for a command the server mode disallows.
+    pub(crate) fn server_mode_rejection_response(
+        &self,
+        seq: u64,
+        request: &TsServerRequest,
+    ) -> TsServerResponse {
+        self.build_response(
+            seq,
+            request,
+            false,
+            Some(format!(
+                "Error processing request. Request: {} not allowed in LanguageServiceMode.{}",
+                request.command,
+                self.server_mode.language_service_mode_name()
+            )),
+            None,
+        )
+    }
+}
@@ -1148,3 +1148,45 @@ fn legacy_completions_inside_line_comment_returns_empty_array() {
         "legacy completions inside a line comment must be an empty array, got {entries:?}"
     );
 }
+
+#[test]
+fn test_completion_info_reports_member_metadata_and_optional_replacement_span() {
+    let mut server = make_server();
+    server.open_files.insert(
+        "/members.ts".to_string(),
+        "const obj = { foo: 1, fob: 2 };\nobj.fo".to_string(),
+    );
+
+    let resp = server.handle_tsserver_request(make_request(
+        "completionInfo",
+        serde_json::json!({ "file": "/members.ts", "line": 2, "offset": 7 }),
+    ));
+    assert!(resp.success);
+    let body = resp.body.expect("completionInfo should return a body");
+    assert_eq!(body["isMemberCompletion"], serde_json::json!(true));
+    assert_eq!(body["isNewIdentifierLocation"], serde_json::json!(false));
+    assert_eq!(
+        body["optionalReplacementSpan"],
+        serde_json::json!({
+            "start": { "line": 2, "offset": 5 },
+            "end": { "line": 2, "offset": 7 },
+        })
+    );
+    let foo = body["entries"]
+        .as_array()
+        .and_then(|entries| entries.iter().find(|entry| entry["name"] == "foo"))
+        .expect("foo entry");
+    assert!(foo["sortText"].is_string());
+    assert!(foo["kindModifiers"].is_string());
+
+    server.open_files.insert(
+        "/members.ts".to_string(),
+        "const obj = { foo: 1, fob: 2 };\nobj.".to_string(),
+    );
+    let resp = server.handle_tsserver_request(make_request(
+        "completionInfo",
+        serde_json::json!({ "file": "/members.ts", "line": 2, "offset": 5 }),
+    ));
+    let body = resp.body.expect("completionInfo should return a body");
+    assert!(body.get("optionalReplacementSpan").is_none(), "got {body}");
+}
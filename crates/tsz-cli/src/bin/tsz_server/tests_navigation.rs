@@ -2042,3 +2042,108 @@ fn test_document_highlights_depth_guard_prevents_stacker_runaway() {
         "documentHighlights must not crash on deeply-nested AST; response: {resp:?}"
     );
 }
+
+fn navto_items(server: &mut Server, arguments: serde_json::Value) -> Vec<serde_json::Value> {
+    let response = server.handle_tsserver_request(make_request("navto", arguments));
+    assert!(response.success);
+    response
+        .body
+        .and_then(|body| body.as_array().cloned())
+        .expect("navto should return an array")
+}
+
+#[test]
+fn test_navto_searches_unopened_files_of_configured_project() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let root = temp.path();
+    std::fs::write(
+        root.join("tsconfig.json"),
+        r#"{"compilerOptions":{"module":"esnext"},"include":["*.ts"]}"#,
+    )
+    .expect("write config");
+    let a = root.join("a.ts");
+    std::fs::write(&a, "export const answer = 42;\n").expect("write a");
+    std::fs::write(
+        root.join("b.ts"),
+        "export function computeTotal() { return 0; }\n",
+    )
+    .expect("write b");
+
+    let mut server = make_server();
+    let a_str = a.to_string_lossy().to_string();
+    let open =
+        server.handle_tsserver_request(make_request("open", serde_json::json!({ "file": &a_str })));
+    assert!(open.success);
+
+    let items = navto_items(
+        &mut server,
+        serde_json::json!({ "searchValue": "cT", "file": &a_str }),
+    );
+    let item = items
+        .iter()
+        .find(|item| item["name"] == "computeTotal")
+        .unwrap_or_else(|| panic!("expected computeTotal from b.ts, got {items:?}"));
+    assert_eq!(item["kind"], "function");
+    assert_eq!(item["matchKind"], "camelCase");
+    assert!(item["file"].as_str().unwrap().ends_with("b.ts"));
+
+    let items = navto_items(
+        &mut server,
+        serde_json::json!({ "searchValue": "cT", "file": &a_str, "currentFileOnly": true }),
+    );
+    assert!(items.is_empty(), "got {items:?}");
+}
+
+#[test]
+fn test_navto_ranks_members_and_honors_kinds_and_max_result_count() {
+    let mut server = make_server();
+    server.open_files.insert(
+        "/navto.ts".to_string(),
+        "class Widget {\n  widgetSize = 1;\n}\nfunction makeWidget() {}\n".to_string(),
+    );
+
+    let items = navto_items(&mut server, serde_json::json!({ "searchValue": "widget" }));
+    let names: Vec<&str> = items
+        .iter()
+        .filter_map(|item| item["name"].as_str())
+        .collect();
+    assert_eq!(names, ["Widget", "widgetSize", "makeWidget"]);
+    assert_eq!(items[0]["matchKind"], "exact");
+    assert_eq!(items[0]["isCaseSensitive"], false);
+    assert!(items[0].get("containerName").is_none());
+    assert_eq!(items[1]["matchKind"], "prefix");
+    assert_eq!(items[1]["containerName"], "Widget");
+    assert_eq!(items[1]["containerKind"], "class");
+    assert_eq!(items[2]["matchKind"], "substring");
+
+    let items = navto_items(
+        &mut server,
+        serde_json::json!({ "searchValue": "widget", "maxResultCount": 1 }),
+    );
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["name"], "Widget");
+
+    let items = navto_items(
+        &mut server,
+        serde_json::json!({ "searchValue": "widget", "kinds": ["function"] }),
+    );
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["name"], "makeWidget");
+}
+
+#[test]
+fn test_navto_lists_each_overload_signature() {
+    let mut server = make_server();
+    server.open_files.insert(
+        "/overloads.ts".to_string(),
+        "function parse(value: string): number;\nfunction parse(value: number): number;\nfunction parse(value: any) { return 0; }\n".to_string(),
+    );
+
+    let items = navto_items(&mut server, serde_json::json!({ "searchValue": "parse" }));
+    let lines: Vec<u64> = items
+        .iter()
+        .filter(|item| item["name"] == "parse")
+        .filter_map(|item| item["start"]["line"].as_u64())
+        .collect();
+    assert_eq!(lines, [1, 2, 3], "got {items:?}");
+}
@@ -49,7 +49,7 @@ fn tsserver_exit_request_does_not_write_response() {
     let mut stdin = BufReader::new(input.as_bytes());
     let mut stdout = Vec::new();
 
-    run_tsserver_protocol_with_io(&mut server, &mut stdin, &mut stdout)
+    run_tsserver_protocol_with_io(&mut server, &mut stdin, &mut stdout, None)
         .expect("exit request should terminate cleanly");
 
     assert!(
@@ -60,6 +60,44 @@ fn tsserver_exit_request_does_not_write_response() {
     );
 }
 
+#[test]
+fn tsserver_events_go_to_event_port_instead_of_stdout() {
+    let mut server = make_server();
+    server
+        .open_files
+        .insert("/test.ts".to_string(), "const x = 1;\n".to_string());
+    let request = r#"{"seq":1,"type":"request","command":"geterr","arguments":{"files":["/test.ts"],"delay":0}}"#;
+    let input = format!("Content-Length: {}\r\n\r\n{}", request.len(), request);
+    let mut stdin = BufReader::new(input.as_bytes());
+    let mut stdout = Vec::new();
+    let mut events = Vec::new();
+
+    run_tsserver_protocol_with_io(&mut server, &mut stdin, &mut stdout, Some(&mut events))
+        .expect("geterr request should run cleanly");
+
+    let stdout = String::from_utf8(stdout).expect("stdout should be UTF-8");
+    let events = String::from_utf8(events).expect("events should be UTF-8");
+    assert_eq!(
+        stdout.matches("Content-Length:").count(),
+        1,
+        "only the geterr response belongs on stdout: {stdout}"
+    );
+    assert!(stdout.contains(r#""type":"response""#), "{stdout}");
+    assert!(events.starts_with("Content-Length: "), "{events}");
+    for event in [
+        "syntaxDiag",
+        "semanticDiag",
+        "suggestionDiag",
+        "requestCompleted",
+    ] {
+        assert!(
+            events.contains(&format!(r#""event":"{event}""#)),
+            "missing {event} on the event port: {events}"
+        );
+    }
+    assert!(!events.contains(r#""type":"response""#), "{events}");
+}
+
 #[test]
 fn test_provide_inlay_hints_respects_protocol_start_length_span() {
     let mut server = make_server();
@@ -457,6 +495,109 @@ fn compile_on_save_reports_affected_files_and_emits_file() {
     );
 }
 
+#[test]
+fn compile_on_save_follows_the_module_graph_and_forced_emit() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let root = temp.path();
+    std::fs::write(
+        root.join("tsconfig.json"),
+        r#"{"compilerOptions":{"module":"esnext","declaration":true},"files":["a.ts","b.ts","c.ts","other.ts","global.ts"]}"#,
+    )
+    .expect("write config");
+    let files = [
+        ("a.ts", "export const a = 1;\n"),
+        (
+            "b.ts",
+            "import { a } from \"./a.js\";\nexport const b: number = a + 1;\n",
+        ),
+        ("c.ts", "export { b } from \"./b\";\n"),
+        ("other.ts", "export const other = 4;\n"),
+        ("global.ts", "const shared = 1;\n"),
+    ];
+    for (name, text) in files {
+        std::fs::write(root.join(name), text).expect("write source");
+    }
+    let path = |name: &str| root.join(name).to_string_lossy().to_string();
+
+    let mut server = make_server();
+    let affected = |server: &mut Server, file: &str| {
+        let response = server.handle_tsserver_request(make_request(
+            "compileOnSaveAffectedFileList",
+            serde_json::json!({ "file": path(file) }),
+        ));
+        response.body.expect("affected files body")
+    };
+    // compileOnSave is off: nothing is affected until the config enables it.
+    assert_eq!(affected(&mut server, "a.ts"), serde_json::json!([]));
+    // ...but a forced emit still writes the outputs.
+    let emit = server.handle_tsserver_request(make_request(
+        "compileOnSaveEmitFile",
+        serde_json::json!({ "file": path("b.ts"), "forced": true }),
+    ));
+    assert_eq!(emit.body, Some(serde_json::json!(true)));
+    let js = std::fs::read_to_string(root.join("b.js")).expect("b.js written");
+    assert!(js.contains("export const b = a + 1;"), "{js}");
+    let dts = std::fs::read_to_string(root.join("b.d.ts")).expect("b.d.ts written");
+    assert!(dts.contains("export declare const b: number;"), "{dts}");
+
+    std::fs::write(
+        root.join("tsconfig.json"),
+        r#"{"compileOnSave":true,"compilerOptions":{"module":"esnext","declaration":true},"files":["a.ts","b.ts","c.ts","other.ts","global.ts"]}"#,
+    )
+    .expect("rewrite config");
+    assert_eq!(
+        affected(&mut server, "a.ts")[0]["fileNames"],
+        serde_json::json!([path("a.ts"), path("b.ts"), path("c.ts")])
+    );
+    assert_eq!(
+        affected(&mut server, "other.ts")[0]["fileNames"],
+        serde_json::json!([path("other.ts")])
+    );
+    // A global script can affect every file in the program.
+    assert_eq!(
+        affected(&mut server, "global.ts")[0]["fileNames"],
+        serde_json::json!([
+            path("a.ts"),
+            path("b.ts"),
+            path("c.ts"),
+            path("other.ts"),
+            path("global.ts")
+        ])
+    );
+}
+
+#[test]
+fn compile_on_save_reads_settings_inherited_through_extends() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let root = temp.path();
+    std::fs::write(
+        root.join("base.json"),
+        r#"{"compileOnSave":true,"compilerOptions":{"module":"amd","outFile":"out.js"}}"#,
+    )
+    .expect("write base config");
+    std::fs::write(
+        root.join("tsconfig.json"),
+        r#"{"extends":"./base.json","files":["a.ts","b.ts"]}"#,
+    )
+    .expect("write config");
+    std::fs::write(root.join("a.ts"), "const a = 1;\n").expect("write a");
+    std::fs::write(root.join("b.ts"), "const b = 2;\n").expect("write b");
+    let path = |name: &str| root.join(name).to_string_lossy().to_string();
+
+    let mut server = make_server();
+    let affected = server.handle_tsserver_request(make_request(
+        "compileOnSaveAffectedFileList",
+        serde_json::json!({ "file": path("a.ts") }),
+    ));
+    assert!(affected.success);
+    let body = affected.body.expect("affected files body");
+    assert_eq!(
+        body[0]["fileNames"],
+        serde_json::json!([path("a.ts"), path("b.ts")])
+    );
+    assert_eq!(body[0]["projectUsesOutFile"], true);
+}
+
 #[test]
 fn file_rename_updates_extensionless_relative_import() {
     let mut server = make_server();
@@ -1074,6 +1215,105 @@ fn project_info_uses_external_project_identity_and_files() {
     );
 }
 
+#[test]
+fn external_project_scopes_compiler_options_to_its_root_files() {
+    let mut server = make_server();
+    let response = server.handle_tsserver_request(make_request(
+        "openExternalProject",
+        serde_json::json!({
+            "projectFileName": "/vs/app.csproj",
+            "rootFiles": [
+                { "fileName": "/vs/main.ts", "content": "export const main = 1;\n" },
+            ],
+            "options": { "target": 2, "module": 1, "noLib": true },
+        }),
+    ));
+    assert!(response.success);
+
+    let project = server
+        .external_project_for_file("/vs/main.ts")
+        .expect("root file should belong to the external project");
+    assert_eq!(project.config_path, "/vs/app.csproj");
+    assert!(project.options.no_lib);
+    assert_eq!(project.compiler_options["target"], "es2015");
+    assert_eq!(project.compiler_options["module"], "commonjs");
+    assert!(server.external_project_for_file("/other.ts").is_none());
+    // Inferred projects keep their own options.
+    assert!(!server.inferred_check_options.no_lib);
+    assert!(server.inferred_check_options.target.is_none());
+
+    let response = server.handle_tsserver_request(make_request(
+        "emit-output",
+        serde_json::json!({ "file": "/vs/main.ts" }),
+    ));
+    assert!(response.success);
+    let body = response.body.expect("emit-output should return a body");
+    let js = body["outputFiles"][0]["text"]
+        .as_str()
+        .expect("emit-output should return JS text");
+    assert!(
+        js.contains("exports.main"),
+        "expected CommonJS output: {js}"
+    );
+
+    let response = server.handle_tsserver_request(make_request(
+        "synchronizeProjectList",
+        serde_json::json!({ "knownProjects": [] }),
+    ));
+    let body = response
+        .body
+        .expect("synchronizeProjectList should return a body");
+    assert_eq!(body[0]["info"]["projectName"], "/vs/app.csproj");
+    assert_eq!(body[0]["info"]["options"]["noLib"], true);
+}
+
+#[test]
+fn close_external_project_keeps_files_the_client_opened() {
+    let mut server = make_server();
+    server.open_files.insert(
+        "/vs/open.ts".to_string(),
+        "let fromClient = 1;\n".to_string(),
+    );
+    let response = server.handle_tsserver_request(make_request(
+        "openExternalProjects",
+        serde_json::json!({
+            "projects": [
+                {
+                    "projectFileName": "/vs/a.csproj",
+                    "rootFiles": [
+                        { "fileName": "/vs/open.ts", "content": "let fromHost = 1;\n" },
+                        { "fileName": "/vs/shared.ts", "content": "let shared = 1;\n" },
+                    ],
+                },
+                {
+                    "projectFileName": "/vs/b.csproj",
+                    "rootFiles": [
+                        { "fileName": "/vs/shared.ts", "content": "let shared = 1;\n" },
+                    ],
+                },
+            ],
+        }),
+    ));
+    assert!(response.success);
+    assert_eq!(server.open_files["/vs/open.ts"], "let fromClient = 1;\n");
+
+    let response = server.handle_tsserver_request(make_request(
+        "closeExternalProject",
+        serde_json::json!({ "projectFileName": "/vs/a.csproj" }),
+    ));
+    assert!(response.success);
+    assert!(server.open_files.contains_key("/vs/open.ts"));
+    assert!(server.open_files.contains_key("/vs/shared.ts"));
+
+    server.handle_tsserver_request(make_request(
+        "closeExternalProject",
+        serde_json::json!({ "projectFileName": "/vs/b.csproj" }),
+    ));
+    assert!(!server.open_files.contains_key("/vs/shared.ts"));
+    assert!(server.external_project_files.is_empty());
+    assert!(server.external_projects.is_empty());
+}
+
 #[test]
 fn comment_edit_full_commands_return_text_changes() {
     let mut server = make_server();
@@ -1764,4 +2004,3 @@ fn test_update_open_changed_files_edits_open_snapshot() {
         "semantic diagnostics should use the edited open-file snapshot, got {diagnostics:?}"
     );
 }
-
@@ -1216,6 +1216,64 @@ fn test_encoded_syntactic_classifications_command_is_routed() {
     );
 }
 
+#[test]
+fn test_encoded_semantic_classifications_use_tsserver_encodings() {
+    let mut server = make_server();
+    // The emoji is two UTF-16 units: `Box` starts at 15, `b` at 28.
+    server.open_files.insert(
+        "/a.ts".to_string(),
+        "/* 😀 */ class Box {}\nconst b: Box = new Box();\n".to_string(),
+    );
+    let mut classify = |arguments: serde_json::Value| -> Vec<[u64; 3]> {
+        let response = server.handle_tsserver_request(make_request(
+            "encodedSemanticClassifications-full",
+            arguments,
+        ));
+        assert!(response.success);
+        let body = response.body.expect("classifications body");
+        let spans: Vec<u64> = body["spans"]
+            .as_array()
+            .expect("spans")
+            .iter()
+            .filter_map(serde_json::Value::as_u64)
+            .collect();
+        spans
+            .chunks_exact(3)
+            .map(|span| [span[0], span[1], span[2]])
+            .collect()
+    };
+
+    let encoded = classify(serde_json::json!({
+        "file": "/a.ts", "start": 0, "length": 100, "format": "2020"
+    }));
+    let at = |start: u64| {
+        encoded
+            .iter()
+            .find(|span| span[0] == start)
+            .unwrap_or_else(|| panic!("no span at {start}: {encoded:?}"))[2]
+    };
+    // (tokenType + 1) << 8 | modifiers: class = 0, variable = 7, and
+    // declaration is modifier bit 0.
+    assert_eq!(at(15) >> 8, 1, "{encoded:?}");
+    assert_eq!(at(15) & 1, 1, "{encoded:?}");
+    assert_eq!(at(31) >> 8, 1, "{encoded:?}");
+    assert_eq!(at(31) & 1, 0, "{encoded:?}");
+    assert_eq!(at(28) >> 8, 8, "{encoded:?}");
+
+    let original = classify(serde_json::json!({ "file": "/a.ts", "start": 0, "length": 100 }));
+    assert!(original.contains(&[15, 3, 11]), "{original:?}");
+    assert!(
+        original.iter().all(|span| span[0] != 28),
+        "the original format does not classify variables: {original:?}"
+    );
+
+    let ranged = classify(serde_json::json!({
+        "file": "/a.ts", "start": 22, "length": 100, "format": "2020"
+    }));
+    assert!(ranged.iter().all(|span| span[0] >= 22), "{ranged:?}");
+    assert!(ranged.iter().any(|span| span[0] == 31), "{ranged:?}");
+}
+
 #[test]
 fn test_geterr_for_project_emits_events_for_open_files() {
     // `geterrForProject` mirrors `geterr` but covers all open files when
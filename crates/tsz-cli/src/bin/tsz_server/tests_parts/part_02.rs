@@ -1752,3 +1752,57 @@ fn test_references_full_quoted_alias_returns_multiple_symbol_groups() {
 // longer shows up as its own definition span in references-full. Keeping the test as
 // #[ignore] until the LSP resolver is updated to follow EXPORT_VALUE alias symbols through
 // `node_symbols` and re-emit per-specifier definition spans for quoted re-exports.
+
+#[test]
+fn test_parse_vm_rss_mb_reads_resident_set_not_virtual_size() {
+    let status = "Name:\ttsz-server\nVmSize:\t 4194304 kB\nVmRSS:\t  524288 kB\nThreads:\t8\n";
+    assert_eq!(memory_budget::parse_vm_rss_mb(status), Some(512));
+    assert_eq!(memory_budget::parse_vm_rss_mb("VmSize:\t1024 kB\n"), None);
+}
+
+#[test]
+fn test_memory_budget_recycles_only_when_rss_exceeds_limit() {
+    let mut server = make_server();
+    assert!(!server.recycle_if_over_memory_budget(Some(10_000)));
+    assert!(!server.enforce_memory_budget());
+
+    server.memory_budget = memory_budget::MemoryBudget::new(Some(256));
+    assert!(!server.recycle_if_over_memory_budget(None));
+    assert!(!server.recycle_if_over_memory_budget(Some(256)));
+    assert_eq!(server.memory_budget.recycles(), 0);
+
+    assert!(server.recycle_if_over_memory_budget(Some(257)));
+    assert!(server.lib_cache.is_empty());
+    assert!(server.unified_lib_cache.is_none());
+    assert_eq!(server.memory_budget.recycles(), 1);
+}
+
+#[test]
+fn test_memory_budget_waits_for_growth_after_an_unrecovered_recycle() {
+    let mut budget = memory_budget::MemoryBudget::new(Some(256));
+    budget.record_recycle(Some(300));
+    assert_eq!(budget.unrecovered_rss_mb(), Some(300));
+    assert!(!budget.is_exceeded_by(Some(310)));
+    assert!(!budget.is_exceeded_by(Some(325)));
+    assert!(budget.is_exceeded_by(Some(326)));
+
+    budget.record_recycle(Some(200));
+    assert_eq!(budget.unrecovered_rss_mb(), None);
+    assert!(budget.is_exceeded_by(Some(257)));
+    assert_eq!(budget.recycles(), 2);
+}
+
+#[test]
+fn test_legacy_status_reports_memory_budget() {
+    let mut server = make_server();
+    server.memory_budget = memory_budget::MemoryBudget::new(Some(128));
+    server.recycle_if_over_memory_budget(Some(129));
+
+    let LegacyResponse::Status(status) = server.handle_legacy_status(7) else {
+        panic!("expected a status response");
+    };
+    let json = serde_json::to_value(&status).unwrap();
+    assert_eq!(json["id"], 7);
+    assert_eq!(json["max_memory_mb"], 128);
+    assert_eq!(json["memory_recycles"], 1);
+}
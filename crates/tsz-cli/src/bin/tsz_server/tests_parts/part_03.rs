@@ -921,6 +921,159 @@ fn emit_output_honors_tsconfig_module_and_out_dir() {
     );
 }
 
+#[test]
+fn emit_output_returns_js_map_and_declaration_outputs() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let root = temp.path();
+    std::fs::write(
+        root.join("tsconfig.json"),
+        r#"{"compilerOptions":{"module":"esnext","outDir":"dist","declaration":true,"declarationDir":"types","sourceMap":true},"files":["a.ts"]}"#,
+    )
+    .expect("write config");
+    let a = root.join("a.ts");
+    std::fs::write(
+        &a,
+        "export function twice(n: number): number { return n * 2; }\n",
+    )
+    .expect("write a");
+    let a_str = a.to_string_lossy().to_string();
+
+    let mut server = make_server();
+    let response = server.handle_tsserver_request(make_request(
+        "emit-output",
+        serde_json::json!({ "file": &a_str, "richResponse": true }),
+    ));
+    assert!(response.success);
+    let body = response.body.expect("emit-output body");
+    assert_eq!(body["emitSkipped"], false, "{body:#}");
+    assert_eq!(body["diagnostics"], serde_json::json!([]), "{body:#}");
+
+    let outputs: Vec<(&str, &str)> = body["outputFiles"]
+        .as_array()
+        .expect("outputFiles")
+        .iter()
+        .map(|file| {
+            (
+                file["name"].as_str().unwrap(),
+                file["text"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    let path = |parts: &[&str]| {
+        parts
+            .iter()
+            .fold(root.to_path_buf(), |path, part| path.join(part))
+            .to_string_lossy()
+            .to_string()
+    };
+    let names: Vec<&str> = outputs.iter().map(|(name, _)| *name).collect();
+    assert_eq!(
+        names,
+        [
+            path(&["dist", "a.js"]),
+            path(&["dist", "a.js.map"]),
+            path(&["types", "a.d.ts"]),
+        ],
+        "{body:#}"
+    );
+
+    let (_, js) = outputs[0];
+    assert!(js.contains("export function twice(n)"), "{js}");
+    assert!(js.ends_with("//# sourceMappingURL=a.js.map"), "{js}");
+    let (_, map) = outputs[1];
+    let map: serde_json::Value = serde_json::from_str(map).expect("source map json");
+    assert_eq!(map["file"], "a.js", "{map:#}");
+    let (_, dts) = outputs[2];
+    assert!(
+        dts.contains("export declare function twice(n: number): number;"),
+        "{dts}"
+    );
+}
+
+#[test]
+fn emit_output_anchors_inherited_out_dir_and_reports_config_errors() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let root = temp.path();
+    std::fs::write(
+        root.join("tsconfig.base.json"),
+        r#"{"compilerOptions":{"outDir":"out"}}"#,
+    )
+    .expect("write base config");
+    let app = root.join("app");
+    std::fs::create_dir_all(&app).expect("create app");
+    std::fs::write(
+        app.join("tsconfig.json"),
+        r#"{"extends":"../tsconfig.base.json","files":["a.ts"]}"#,
+    )
+    .expect("write config");
+    let a = app.join("a.ts");
+    std::fs::write(&a, "export const value = 1;\n").expect("write a");
+
+    let mut server = make_server();
+    let response = server.handle_tsserver_request(make_request(
+        "emit-output",
+        serde_json::json!({ "file": a.to_string_lossy(), "richResponse": true }),
+    ));
+    let body = response.body.expect("emit-output body");
+    assert_eq!(
+        body["outputFiles"][0]["name"],
+        root.join("out").join("a.js").to_string_lossy().as_ref(),
+        "outDir is relative to the config that sets it: {body:#}"
+    );
+    assert_eq!(body["diagnostics"], serde_json::json!([]), "{body:#}");
+
+    std::fs::write(
+        app.join("tsconfig.json"),
+        r#"{"extends":"../missing.json","files":["a.ts"]}"#,
+    )
+    .expect("write broken config");
+    server.configured_projects.clear();
+    let response = server.handle_tsserver_request(make_request(
+        "emit-output",
+        serde_json::json!({ "file": a.to_string_lossy(), "richResponse": true }),
+    ));
+    let body = response.body.expect("emit-output body");
+    let diagnostics = body["diagnostics"].as_array().expect("diagnostics");
+    assert_eq!(diagnostics.len(), 1, "{body:#}");
+    assert_eq!(diagnostics[0]["category"], "error", "{body:#}");
+}
+
+#[test]
+fn emit_output_skips_declaration_inputs_and_no_emit_projects() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let root = temp.path();
+    let types = root.join("types.d.ts");
+    std::fs::write(&types, "declare const shared: number;\n").expect("write types");
+
+    let mut server = make_server();
+    let response = server.handle_tsserver_request(make_request(
+        "emit-output",
+        serde_json::json!({ "file": types.to_string_lossy() }),
+    ));
+    let body = response.body.expect("emit-output body");
+    assert_eq!(
+        body,
+        serde_json::json!({ "outputFiles": [], "emitSkipped": true })
+    );
+
+    std::fs::write(
+        root.join("tsconfig.json"),
+        r#"{"compilerOptions":{"noEmit":true}}"#,
+    )
+    .expect("write config");
+    let a = root.join("a.ts");
+    std::fs::write(&a, "export const value = 1;\n").expect("write a");
+    let response = server.handle_tsserver_request(make_request(
+        "emit-output",
+        serde_json::json!({ "file": a.to_string_lossy() }),
+    ));
+    let body = response.body.expect("emit-output body");
+    assert_eq!(
+        body,
+        serde_json::json!({ "outputFiles": [], "emitSkipped": true })
+    );
+}
+
 // Issue #3731: tsserver's jsxClosingTag returns a closing tag for ALL JSX
 // elements including intrinsic HTML void elements like <input>; tsz used
 // to suppress them.
@@ -1205,3 +1358,709 @@ fn move_to_file_suggestions_derive_new_file_name_from_declaration() {
         "files must not include the source file itself, got: {files:?}"
     );
 }
+
+fn semantic_diagnostic_codes(server: &mut Server, file: &str) -> Vec<u64> {
+    let response = server.handle_tsserver_request(make_request(
+        "semanticDiagnosticsSync",
+        serde_json::json!({ "file": file }),
+    ));
+    assert!(response.success);
+    response
+        .body
+        .expect("semanticDiagnosticsSync should return a body")
+        .as_array()
+        .expect("semanticDiagnosticsSync body should be an array")
+        .iter()
+        .filter_map(|diag| diag.get("code").and_then(serde_json::Value::as_u64))
+        .collect()
+}
+
+#[test]
+fn semantic_diagnostics_use_configured_project_files_and_extended_options() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let root = temp.path();
+    std::fs::create_dir_all(root.join("src")).expect("create src");
+    std::fs::write(
+        root.join("tsconfig.base.json"),
+        r#"{ "compilerOptions": { "noImplicitAny": true } }"#,
+    )
+    .expect("write base config");
+    std::fs::write(
+        root.join("tsconfig.json"),
+        r#"{
+  // comments are allowed
+  "extends": "./tsconfig.base",
+  "compilerOptions": { "strictNullChecks": true },
+  "include": ["src"]
+}"#,
+    )
+    .expect("write config");
+    std::fs::write(root.join("src/dep.ts"), "export const value = \"text\";\n").expect("write dep");
+    let main = root.join("src/main.ts");
+    let main_str = main.to_string_lossy().to_string();
+    let main_text =
+        "import { value } from \"./dep\";\nconst n: number = value;\nfunction f(x) { return x; }\n";
+    std::fs::write(&main, main_text).expect("write main");
+
+    let mut server = make_server();
+    let open = server.handle_tsserver_request(make_request(
+        "open",
+        serde_json::json!({ "file": &main_str, "fileContent": main_text }),
+    ));
+    assert!(open.success);
+
+    let codes = semantic_diagnostic_codes(&mut server, &main_str);
+    assert!(
+        !codes.contains(&2307),
+        "unopened project file should resolve, got {codes:?}"
+    );
+    assert!(
+        codes.contains(&2322),
+        "types from the unopened project file should flow into the check, got {codes:?}"
+    );
+    assert!(
+        codes.contains(&7006),
+        "noImplicitAny from the extended config should apply, got {codes:?}"
+    );
+}
+
+#[test]
+fn files_outside_config_selectors_stay_in_inferred_project() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let root = temp.path();
+    std::fs::create_dir_all(root.join("src")).expect("create src");
+    std::fs::create_dir_all(root.join("scripts")).expect("create scripts");
+    std::fs::write(
+        root.join("tsconfig.json"),
+        r#"{ "compilerOptions": { "noImplicitAny": true }, "include": ["src"] }"#,
+    )
+    .expect("write config");
+    let tool = root.join("scripts/tool.ts");
+    let tool_str = tool.to_string_lossy().to_string();
+    let tool_text = "function f(x) { return x; }\n";
+    std::fs::write(&tool, tool_text).expect("write tool");
+
+    let mut server = make_server();
+    assert!(server.configured_project_for_file(&tool_str).is_none());
+    let open = server.handle_tsserver_request(make_request(
+        "open",
+        serde_json::json!({ "file": &tool_str, "fileContent": tool_text }),
+    ));
+    assert!(open.success);
+
+    let codes = semantic_diagnostic_codes(&mut server, &tool_str);
+    assert!(
+        !codes.contains(&7006),
+        "config options must not apply to files its include does not match, got {codes:?}"
+    );
+}
+
+#[test]
+fn configured_project_is_cached_until_its_inputs_change() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let root = temp.path();
+    std::fs::create_dir_all(root.join("src")).expect("create src");
+    let config = root.join("tsconfig.json");
+    let config_str = config.to_string_lossy().to_string();
+    std::fs::write(
+        &config,
+        r#"{ "compilerOptions": { "strict": true }, "include": ["src"] }"#,
+    )
+    .expect("write config");
+    let main = root.join("src/main.ts");
+    let main_str = main.to_string_lossy().to_string();
+    std::fs::write(&main, "export const a = 1;\n").expect("write main");
+    let other = root.join("src/other.ts");
+    let other_str = other.to_string_lossy().to_string();
+
+    let mut server = make_server();
+    let first = server
+        .configured_project_for_file(&main_str)
+        .expect("main.ts is in the configured project");
+    assert_eq!(first.root_files, vec![main_str.clone()]);
+
+    // The second request is answered from the cache: neither the rewritten
+    // config nor the new source file on disk is seen.
+    std::fs::write(&config, r#"{ "include": ["src"] }"#).expect("rewrite config");
+    std::fs::write(&other, "export const b = 2;\n").expect("write other");
+    let second = server
+        .configured_project_for_file(&main_str)
+        .expect("cached project");
+    assert_eq!(second.compiler_options, first.compiler_options);
+    assert_eq!(second.root_files, first.root_files);
+
+    // Opening a source under the include root rebuilds the entry.
+    server
+        .open_files
+        .insert(other_str.clone(), "export const b = 2;\n".to_string());
+    let reopened = server
+        .configured_project_for_file(&main_str)
+        .expect("rebuilt project");
+    assert!(reopened.root_files.contains(&other_str));
+    assert!(!reopened.compiler_options.contains_key("strict"));
+
+    // So does opening (and then editing) the config.
+    server.open_files.insert(
+        config_str.clone(),
+        r#"{ "compilerOptions": { "noImplicitAny": true }, "include": ["src"] }"#.to_string(),
+    );
+    let edited = server
+        .configured_project_for_file(&other_str)
+        .expect("rebuilt project");
+    assert!(edited.compiler_options.contains_key("noImplicitAny"));
+}
+
+#[test]
+fn configured_project_cache_is_dropped_by_watch_change() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let root = temp.path();
+    std::fs::create_dir_all(root.join("src")).expect("create src");
+    let config = root.join("tsconfig.json");
+    std::fs::write(&config, r#"{ "include": ["src"] }"#).expect("write config");
+    let main = root.join("src/main.ts");
+    let main_str = main.to_string_lossy().to_string();
+    let main_text = "export const a = 1;\n";
+    std::fs::write(&main, main_text).expect("write main");
+
+    let mut server = make_server();
+    server.can_use_watch_events = true;
+    let open = server.handle_tsserver_request(make_request(
+        "open",
+        serde_json::json!({ "file": &main_str, "fileContent": main_text }),
+    ));
+    assert!(open.success);
+    server.sync_client_file_watchers();
+    let config_watcher = drained_event_names(&mut server)
+        .into_iter()
+        .find(|(name, _)| name == "createFileWatcher")
+        .and_then(|(_, body)| body["id"].as_u64())
+        .expect("config watcher");
+    assert!(server.configured_project_for_file(&main_str).is_some());
+
+    std::fs::write(
+        &config,
+        r#"{ "compilerOptions": { "strict": true }, "include": ["src"] }"#,
+    )
+    .expect("rewrite config");
+    let response = server.handle_tsserver_request(make_request(
+        "watchChange",
+        serde_json::json!([{ "id": config_watcher, "updated": [config.to_string_lossy()] }]),
+    ));
+    assert!(response.success);
+    let project = server
+        .configured_project_for_file(&main_str)
+        .expect("reloaded project");
+    assert!(project.compiler_options.contains_key("strict"));
+}
+
+fn drained_event_names(server: &mut Server) -> Vec<(String, serde_json::Value)> {
+    server
+        .drain_pending_events()
+        .into_iter()
+        .map(|event| {
+            (
+                event["event"].as_str().unwrap_or_default().to_string(),
+                event["body"].clone(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn polling_watcher_reports_projects_updated_in_background_for_new_root_files() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let root = temp.path();
+    std::fs::create_dir_all(root.join("src")).expect("create src");
+    std::fs::write(root.join("tsconfig.json"), r#"{ "include": ["src"] }"#).expect("write config");
+    std::fs::write(root.join("src/b.ts"), "export const b = 1;\n").expect("write b");
+    let a = root.join("src/a.ts");
+    let a_str = a.to_string_lossy().to_string();
+    std::fs::write(&a, "export const a = 1;\n").expect("write a");
+
+    let mut server = make_server();
+    let open = server.handle_tsserver_request(make_request(
+        "open",
+        serde_json::json!({ "file": &a_str, "fileContent": "export const a = 1;\n" }),
+    ));
+    assert!(open.success);
+    server.drain_pending_events();
+
+    server.poll_file_watchers();
+    assert!(
+        drained_event_names(&mut server).is_empty(),
+        "the first poll only records a baseline"
+    );
+    server.poll_file_watchers();
+    assert!(drained_event_names(&mut server).is_empty());
+
+    std::fs::write(root.join("src/c.ts"), "export const c = 1;\n").expect("write c");
+    server.poll_file_watchers();
+    let events = drained_event_names(&mut server);
+    assert_eq!(
+        events,
+        vec![(
+            "projectsUpdatedInBackground".to_string(),
+            serde_json::json!({ "openFiles": [a_str] }),
+        )]
+    );
+}
+
+#[test]
+fn polling_watcher_drops_changed_projects_without_reloading_them() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let root = temp.path();
+    std::fs::create_dir_all(root.join("src")).expect("create src");
+    let config = root.join("tsconfig.json");
+    let config_str = config.to_string_lossy().to_string();
+    std::fs::write(&config, r#"{ "include": ["src"] }"#).expect("write config");
+    let a = root.join("src/a.ts");
+    let a_str = a.to_string_lossy().to_string();
+    std::fs::write(&a, "export const a = 1;\n").expect("write a");
+    let b = root.join("src/b.ts");
+    std::fs::write(&b, "export const b = 1;\n").expect("write b");
+
+    let mut server = make_server();
+    server
+        .open_files
+        .insert(a_str.clone(), "export const a = 1;\n".to_string());
+    server.poll_file_watchers();
+    assert!(server.has_cached_configured_project(&config_str));
+
+    // Let the coarse mtime clock tick between writes.
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    std::fs::write(&b, "export const b = 2;\n").expect("rewrite b");
+    server.poll_file_watchers();
+    assert_eq!(drained_event_names(&mut server).len(), 1);
+    assert!(!server.has_cached_configured_project(&config_str));
+
+    // Idle polls leave the dropped project alone until a request needs it.
+    server.poll_file_watchers();
+    assert!(drained_event_names(&mut server).is_empty());
+    assert!(!server.has_cached_configured_project(&config_str));
+
+    assert!(server.configured_project_for_file(&a_str).is_some());
+    server.poll_file_watchers();
+    assert!(drained_event_names(&mut server).is_empty());
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    std::fs::write(&b, "export const b = 3;\n").expect("rewrite b again");
+    server.poll_file_watchers();
+    assert_eq!(drained_event_names(&mut server).len(), 1);
+}
+
+#[test]
+fn client_watch_events_create_watchers_and_handle_watch_change() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let root = temp.path();
+    std::fs::write(root.join("tsconfig.json"), "{}").expect("write config");
+    let a = root.join("a.ts");
+    let a_str = a.to_string_lossy().to_string();
+    let config_str = root.join("tsconfig.json").to_string_lossy().to_string();
+    std::fs::write(&a, "export const a = 1;\n").expect("write a");
+
+    let mut server = make_server();
+    server.can_use_watch_events = true;
+    let open = server.handle_tsserver_request(make_request(
+        "open",
+        serde_json::json!({ "file": &a_str, "fileContent": "export const a = 1;\n" }),
+    ));
+    assert!(open.success);
+    server.drain_pending_events();
+
+    server.sync_client_file_watchers();
+    let events = drained_event_names(&mut server);
+    assert_eq!(events.len(), 2, "got {events:?}");
+    assert_eq!(events[0].0, "createFileWatcher");
+    assert_eq!(events[0].1["path"], config_str.as_str());
+    assert_eq!(events[1].0, "createDirectoryWatcher");
+    assert_eq!(events[1].1["recursive"], true);
+    let directory_watcher_id = events[1].1["id"].clone();
+
+    server.sync_client_file_watchers();
+    assert!(drained_event_names(&mut server).is_empty());
+
+    let change = server.handle_tsserver_request(make_request(
+        "watchChange",
+        serde_json::json!([{ "id": directory_watcher_id, "created": [root.join("b.ts")] }]),
+    ));
+    assert!(change.success);
+    assert_eq!(
+        drained_event_names(&mut server),
+        vec![(
+            "projectsUpdatedInBackground".to_string(),
+            serde_json::json!({ "openFiles": [a_str] }),
+        )]
+    );
+
+    let close = server
+        .handle_tsserver_request(make_request("close", serde_json::json!({ "file": &a_str })));
+    assert!(close.success);
+    server.drain_pending_events();
+    server.sync_client_file_watchers();
+    let closed: Vec<String> = drained_event_names(&mut server)
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+    assert_eq!(closed, vec!["closeFileWatcher", "closeFileWatcher"]);
+}
+
+fn applicable_refactor_actions(
+    server: &mut Server,
+    file: &str,
+    line: u32,
+    offset: u32,
+) -> Vec<String> {
+    let response = server.handle_tsserver_request(make_request(
+        "getApplicableRefactors",
+        serde_json::json!({ "file": file, "line": line, "offset": offset }),
+    ));
+    assert!(response.success);
+    response
+        .body
+        .expect("refactors should return a body")
+        .as_array()
+        .expect("refactors must be an array")
+        .iter()
+        .flat_map(|refactor| refactor["actions"].as_array().cloned().unwrap_or_default())
+        .filter_map(|action| action["name"].as_str().map(str::to_string))
+        .collect()
+}
+
+#[test]
+fn edits_for_refactor_extracts_selected_statements_into_function() {
+    let mut server = make_server();
+    server.open_files.insert(
+        "/src/stmts.ts".to_string(),
+        "const a = 1;\nconsole.log(a);\nconst b = 2;\n".to_string(),
+    );
+    let range = serde_json::json!({
+        "file": "/src/stmts.ts",
+        "startLine": 1, "startOffset": 1, "endLine": 2, "endOffset": 16,
+    });
+
+    let response =
+        server.handle_tsserver_request(make_request("getApplicableRefactors", range.clone()));
+    let body = response.body.expect("refactors body");
+    assert!(
+        body.as_array()
+            .expect("refactors array")
+            .iter()
+            .any(|refactor| refactor["description"] == "Extract function"),
+        "statement selections should offer extract function, got {body:#}"
+    );
+
+    let mut arguments = range;
+    arguments["refactor"] = serde_json::json!("Extract Symbol");
+    arguments["action"] = serde_json::json!("function_scope_0");
+    let response = server.handle_tsserver_request(make_request("getEditsForRefactor", arguments));
+    let edits = response.body.expect("edits body")["edits"].clone();
+    assert_eq!(edits[0]["fileName"], "/src/stmts.ts");
+    let inserted: String = edits[0]["textChanges"]
+        .as_array()
+        .expect("text changes")
+        .iter()
+        .filter_map(|change| change["newText"].as_str())
+        .collect();
+    assert!(
+        inserted.contains("function "),
+        "extract function edits should declare a function, got {edits:#}"
+    );
+}
+
+#[test]
+fn edits_for_refactor_moves_declaration_to_new_file() {
+    let mut server = make_server();
+    server.open_files.insert(
+        "/src/main.ts".to_string(),
+        "function helper() {\n  return 1;\n}\nhelper();\n".to_string(),
+    );
+
+    let actions = applicable_refactor_actions(&mut server, "/src/main.ts", 1, 10);
+    assert!(
+        actions.iter().any(|name| name == "Move to a new file"),
+        "got {actions:?}"
+    );
+
+    let response = server.handle_tsserver_request(make_request(
+        "getEditsForRefactor",
+        serde_json::json!({
+            "file": "/src/main.ts",
+            "line": 1,
+            "offset": 10,
+            "refactor": "Move to a new file",
+            "action": "Move to a new file",
+        }),
+    ));
+    let edits = response.body.expect("edits body")["edits"]
+        .as_array()
+        .cloned()
+        .expect("edits array");
+    let new_file = edits
+        .iter()
+        .find(|edit| edit["isNewFile"] == true)
+        .expect("moving a declaration should create a new file");
+    assert_eq!(new_file["fileName"], "/src/helper.ts");
+    let main_edit = edits
+        .iter()
+        .find(|edit| edit["fileName"] == "/src/main.ts")
+        .expect("source file edits");
+    assert!(main_edit.get("isNewFile").is_none());
+}
+
+#[test]
+fn edits_for_refactor_converts_default_export_to_named() {
+    let mut server = make_server();
+    server.open_files.insert(
+        "/src/exp.ts".to_string(),
+        "export default function foo() {}\n".to_string(),
+    );
+
+    let actions = applicable_refactor_actions(&mut server, "/src/exp.ts", 1, 26);
+    assert!(
+        actions
+            .iter()
+            .any(|name| name == "Convert default export to named export"),
+        "got {actions:?}"
+    );
+
+    let response = server.handle_tsserver_request(make_request(
+        "getEditsForRefactor",
+        serde_json::json!({
+            "file": "/src/exp.ts",
+            "line": 1,
+            "offset": 26,
+            "refactor": "Convert export",
+            "action": "Convert default export to named export",
+        }),
+    ));
+    let edits = response.body.expect("edits body")["edits"].clone();
+    assert_eq!(
+        edits[0]["textChanges"][0]["newText"],
+        "export function foo() {}"
+    );
+}
+
+fn organized_text(server: &mut Server, file: &str, extra_args: serde_json::Value) -> String {
+    let mut arguments = serde_json::json!({
+        "scope": { "type": "file", "args": { "file": file } },
+    });
+    if let (Some(arguments), Some(extra)) = (arguments.as_object_mut(), extra_args.as_object()) {
+        arguments.extend(extra.clone());
+    }
+    let response = server.handle_tsserver_request(make_request("organizeImports", arguments));
+    assert!(response.success);
+    let body = response.body.expect("organizeImports should return a body");
+
+    let mut text = server.open_files[file].clone();
+    let Some(file_changes) = body.as_array().and_then(|files| files.first()) else {
+        return text;
+    };
+    assert_eq!(file_changes["fileName"], file);
+    let changes = file_changes["textChanges"]
+        .as_array()
+        .expect("textChanges should be an array");
+    for change in changes.iter().rev() {
+        let position = |key: &str, field: &str| change[key][field].as_u64().unwrap() as usize;
+        text = Server::apply_change(
+            &text,
+            position("start", "line"),
+            position("start", "offset"),
+            position("end", "line"),
+            position("end", "offset"),
+            change["newText"].as_str().unwrap(),
+        );
+    }
+    text
+}
+
+#[test]
+fn organize_imports_all_removes_unused_and_sorts() {
+    let mut server = make_server();
+    server.open_files.insert(
+        "/organize.ts".to_string(),
+        "import { z } from \"./z\";\nimport D, { b, unused } from \"./b\";\nimport { a } from \"./a\";\nuse(a, b);\n"
+            .to_string(),
+    );
+
+    let organized = organized_text(&mut server, "/organize.ts", serde_json::json!({}));
+    assert_eq!(
+        organized,
+        "import { a } from \"./a\";\nimport { b } from \"./b\";\nuse(a, b);\n"
+    );
+}
+
+#[test]
+fn organize_imports_skip_destructive_keeps_unused_imports() {
+    let mut server = make_server();
+    server.open_files.insert(
+        "/organize.ts".to_string(),
+        "import { z } from \"./z\";\nimport { a } from \"./a\";\nuse(a);\n".to_string(),
+    );
+
+    let organized = organized_text(
+        &mut server,
+        "/organize.ts",
+        serde_json::json!({ "skipDestructiveCodeActions": true }),
+    );
+    assert_eq!(
+        organized,
+        "import { a } from \"./a\";\nimport { z } from \"./z\";\nuse(a);\n"
+    );
+
+    let organized = organized_text(
+        &mut server,
+        "/organize.ts",
+        serde_json::json!({ "mode": "RemoveUnused" }),
+    );
+    assert_eq!(organized, "import { a } from \"./a\";\nuse(a);\n");
+}
+
+#[test]
+fn organize_imports_keeps_react_jsx_factory_and_jsdoc_references() {
+    let mut server = make_server();
+    server.inferred_check_options.jsx = Some("react".to_string());
+    server.open_files.insert(
+        "/organize.tsx".to_string(),
+        "import React from \"react\";\nimport { Props, unused } from \"./props\";\n/** @type {Props} */\nexport const el = <div />;\n"
+            .to_string(),
+    );
+
+    let organized = organized_text(
+        &mut server,
+        "/organize.tsx",
+        serde_json::json!({ "mode": "RemoveUnused" }),
+    );
+    assert_eq!(
+        organized,
+        "import React from \"react\";\nimport { Props } from \"./props\";\n/** @type {Props} */\nexport const el = <div />;\n"
+    );
+
+    // `react-jsx` calls the automatic runtime, not `React`.
+    server.inferred_check_options.jsx = Some("react-jsx".to_string());
+    let organized = organized_text(
+        &mut server,
+        "/organize.tsx",
+        serde_json::json!({ "mode": "RemoveUnused" }),
+    );
+    assert!(!organized.contains("import React"), "{organized}");
+}
+
+#[test]
+fn test_update_open_applies_batched_text_changes_last_to_first() {
+    // Both positions refer to the pre-edit text; tsserver applies them in
+    // reverse so the earlier edit does not shift the later one.
+    let mut server = make_server();
+    let file = "/a.ts";
+    server
+        .open_files
+        .insert(file.to_string(), "let a = 1;\nlet b = 2;\n".to_string());
+
+    let response = server.handle_tsserver_request(make_request(
+        "updateOpen",
+        serde_json::json!({
+            "changedFiles": [{
+                "fileName": file,
+                "textChanges": [
+                    {
+                        "start": { "line": 1, "offset": 9 },
+                        "end": { "line": 1, "offset": 10 },
+                        "newText": "100"
+                    },
+                    {
+                        "start": { "line": 2, "offset": 9 },
+                        "end": { "line": 2, "offset": 10 },
+                        "newText": "200"
+                    }
+                ]
+            }]
+        }),
+    ));
+    assert!(response.success, "updateOpen should succeed: {response:?}");
+    assert_eq!(response.body, Some(serde_json::Value::Bool(true)));
+    assert_eq!(
+        server.open_files.get(file).map(String::as_str),
+        Some("let a = 100;\nlet b = 200;\n")
+    );
+}
+
+#[test]
+fn test_update_open_rejects_whole_batch_when_a_change_targets_unopened_file() {
+    let mut server = make_server();
+    server
+        .open_files
+        .insert("/a.ts".to_string(), "let a = 1;".to_string());
+
+    let response = server.handle_tsserver_request(make_request(
+        "updateOpen",
+        serde_json::json!({
+            "openFiles": [{ "file": "/b.ts", "fileContent": "let b = 2;" }],
+            "changedFiles": [{
+                "fileName": "/missing.ts",
+                "textChanges": [{
+                    "start": { "line": 1, "offset": 1 },
+                    "end": { "line": 1, "offset": 1 },
+                    "newText": "x"
+                }]
+            }],
+            "closedFiles": ["/a.ts"]
+        }),
+    ));
+    assert!(!response.success, "batch should be rejected: {response:?}");
+    assert_eq!(
+        server.open_files.get("/a.ts").map(String::as_str),
+        Some("let a = 1;"),
+        "a rejected batch must not close files"
+    );
+    assert!(
+        !server.open_files.contains_key("/b.ts"),
+        "a rejected batch must not open files"
+    );
+}
+
+#[test]
+fn test_update_open_reparses_only_edited_files_on_next_query() {
+    let mut server = make_server();
+    server
+        .open_files
+        .insert("/a.ts".to_string(), "export const a = 1;\n".to_string());
+    server
+        .open_files
+        .insert("/b.ts".to_string(), "export const b = 2;\n".to_string());
+
+    let diagnostics = |server: &mut Server| {
+        let response = server.handle_tsserver_request(make_request(
+            "semanticDiagnosticsSync",
+            serde_json::json!({ "file": "/a.ts" }),
+        ));
+        assert!(response.success, "diagnostics should succeed: {response:?}");
+    };
+    diagnostics(&mut server);
+    let a_before = server.bind_cache.cached_arena("/a.ts").expect("a bound");
+    let b_before = server.bind_cache.cached_arena("/b.ts").expect("b bound");
+
+    let response = server.handle_tsserver_request(make_request(
+        "updateOpen",
+        serde_json::json!({
+            "changedFiles": [{
+                "fileName": "/a.ts",
+                "textChanges": [{
+                    "start": { "line": 1, "offset": 18 },
+                    "end": { "line": 1, "offset": 19 },
+                    "newText": "3"
+                }]
+            }]
+        }),
+    ));
+    assert!(response.success);
+    diagnostics(&mut server);
+
+    let a_after = server.bind_cache.cached_arena("/a.ts").expect("a bound");
+    let b_after = server.bind_cache.cached_arena("/b.ts").expect("b bound");
+    assert!(
+        !Arc::ptr_eq(&a_before, &a_after),
+        "the edited file should be re-parsed"
+    );
+    assert!(
+        Arc::ptr_eq(&b_before, &b_after),
+        "the untouched file should reuse its parse"
+    );
+}
@@ -92,12 +92,89 @@ fn response_taxonomy_geterr_returns_acknowledge_after_emitting_events() {
     assert!(resp.success);
     assert!(resp.body.is_none());
     assert!(resp.message.is_none());
+    assert!(
+        server.pending_events.is_empty(),
+        "geterr events are streamed after the acknowledgement"
+    );
+    while server.run_geterr_step() {}
     let has_request_completed = server.pending_events.iter().any(|event| {
         event.get("event").and_then(|value| value.as_str()) == Some("requestCompleted")
     });
     assert!(has_request_completed, "geterr must emit requestCompleted");
 }
 
+fn geterr_event_sequence(server: &mut Server) -> Vec<(String, Option<String>)> {
+    let mut events = Vec::new();
+    while server.run_geterr_step() {
+        for event in server.drain_pending_events() {
+            let name = event["event"].as_str().unwrap_or_default().to_string();
+            let file = event["body"]["file"].as_str().map(str::to_string);
+            events.push((name, file));
+        }
+    }
+    events
+}
+
+#[test]
+fn response_taxonomy_geterr_streams_events_per_file_in_order() {
+    let mut server = make_server();
+    server
+        .open_files
+        .insert("/a.ts".to_string(), "const a: string = 1;".to_string());
+    server
+        .open_files
+        .insert("/b.ts".to_string(), "const b = 1;".to_string());
+    let request = make_request(
+        "geterr",
+        serde_json::json!({"files": ["/b.ts", "/a.ts"], "delay": 25}),
+    );
+    server.handle_tsserver_request(request);
+    assert!(
+        server
+            .pending_geterr_wait()
+            .is_some_and(|wait| !wait.is_zero() && wait <= std::time::Duration::from_millis(25))
+    );
+
+    let events = geterr_event_sequence(&mut server);
+    let expected: Vec<(String, Option<String>)> = [
+        ("syntaxDiag", Some("/b.ts")),
+        ("semanticDiag", Some("/b.ts")),
+        ("suggestionDiag", Some("/b.ts")),
+        ("syntaxDiag", Some("/a.ts")),
+        ("semanticDiag", Some("/a.ts")),
+        ("suggestionDiag", Some("/a.ts")),
+        ("requestCompleted", None),
+    ]
+    .into_iter()
+    .map(|(name, file)| (name.to_string(), file.map(str::to_string)))
+    .collect();
+    assert_eq!(events, expected);
+    assert!(server.pending_geterr_wait().is_none());
+}
+
+#[test]
+fn response_taxonomy_geterr_for_project_checks_requested_file_first() {
+    let mut server = make_server();
+    for name in ["/a.ts", "/b.ts", "/c.ts"] {
+        server
+            .open_files
+            .insert(name.to_string(), "const x = 1;".to_string());
+    }
+    let request = make_request(
+        "geterrForProject",
+        serde_json::json!({"file": "/c.ts", "delay": 0}),
+    );
+    let resp = server.handle_tsserver_request(request);
+    assert!(resp.success);
+
+    let files: Vec<String> = geterr_event_sequence(&mut server)
+        .into_iter()
+        .filter(|(name, _)| name == "syntaxDiag")
+        .filter_map(|(_, file)| file)
+        .collect();
+    assert_eq!(files, ["/c.ts", "/a.ts", "/b.ts"]);
+}
+
 #[test]
 fn response_taxonomy_get_code_fixes_returns_empty_success_for_no_matches() {
     let mut server = make_server();
@@ -121,3 +198,253 @@ fn response_taxonomy_get_code_fixes_returns_empty_success_for_no_matches() {
     assert!(resp.message.is_none());
     assert_eq!(resp.body, Some(serde_json::json!([])));
 }
+
+fn server_with_cancellation_pipe(pipe_prefix: &std::path::Path) -> Server {
+    let mut server = make_server();
+    server.cancellation =
+        crate::cancellation::CancellationToken::new(Some(format!("{}*", pipe_prefix.display())));
+    server
+}
+
+#[test]
+fn response_taxonomy_cancelled_request_answers_canceled_body() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let pipe_prefix = temp.path().join("cancel-");
+    let mut server = server_with_cancellation_pipe(&pipe_prefix);
+    server
+        .open_files
+        .insert("/a.ts".to_string(), "const a: string = 1;".to_string());
+    std::fs::write(temp.path().join("cancel-5"), "").expect("create semaphore");
+
+    let mut cancelled = make_request(
+        "semanticDiagnosticsSync",
+        serde_json::json!({"file": "/a.ts"}),
+    );
+    cancelled.seq = 5;
+    let resp = server.handle_tsserver_request(cancelled);
+    assert!(resp.success);
+    assert_eq!(resp.request_seq, 5);
+    assert_eq!(resp.body, Some(serde_json::json!({"canceled": true})));
+
+    let mut next = make_request(
+        "semanticDiagnosticsSync",
+        serde_json::json!({"file": "/a.ts"}),
+    );
+    next.seq = 6;
+    let resp = server.handle_tsserver_request(next);
+    let diagnostics = resp.body.expect("diagnostics body");
+    assert!(
+        diagnostics
+            .as_array()
+            .is_some_and(|diagnostics| !diagnostics.is_empty()),
+        "only the request named by the semaphore is cancelled, got {diagnostics}"
+    );
+}
+
+#[test]
+fn response_taxonomy_cancelled_geterr_drops_remaining_files() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let pipe_prefix = temp.path().join("cancel-");
+    let mut server = server_with_cancellation_pipe(&pipe_prefix);
+    server
+        .open_files
+        .insert("/a.ts".to_string(), "const a = 1;".to_string());
+    server
+        .open_files
+        .insert("/b.ts".to_string(), "const b = 1;".to_string());
+    let mut request = make_request("geterr", serde_json::json!({"files": ["/a.ts", "/b.ts"]}));
+    request.seq = 9;
+    server.handle_tsserver_request(request);
+
+    assert!(server.run_geterr_step());
+    let first: Vec<String> = server
+        .drain_pending_events()
+        .iter()
+        .map(|event| event["event"].as_str().unwrap_or_default().to_string())
+        .collect();
+    assert_eq!(first, vec!["syntaxDiag", "semanticDiag", "suggestionDiag"]);
+
+    std::fs::write(temp.path().join("cancel-9"), "").expect("create semaphore");
+    assert_eq!(
+        geterr_event_sequence(&mut server),
+        vec![("requestCompleted".to_string(), None)]
+    );
+}
+
+#[test]
+fn response_taxonomy_newer_geterr_completes_the_pending_one() {
+    let mut server = make_server();
+    for name in ["/a.ts", "/b.ts"] {
+        server
+            .open_files
+            .insert(name.to_string(), "const x = 1;".to_string());
+    }
+    let mut first = make_request("geterr", serde_json::json!({"files": ["/a.ts", "/b.ts"]}));
+    first.seq = 3;
+    server.handle_tsserver_request(first);
+    assert!(server.run_geterr_step());
+    server.drain_pending_events();
+
+    let mut second = make_request("geterr", serde_json::json!({"files": ["/b.ts"]}));
+    second.seq = 4;
+    server.handle_tsserver_request(second);
+    let replaced = server.drain_pending_events();
+    assert_eq!(replaced.len(), 1, "{replaced:?}");
+    assert_eq!(replaced[0]["event"], "requestCompleted");
+    assert_eq!(replaced[0]["body"]["request_seq"], 3);
+
+    let events = geterr_event_sequence(&mut server);
+    assert_eq!(
+        events.first().map(|(name, _)| name.as_str()),
+        Some("syntaxDiag")
+    );
+    assert_eq!(events.last(), Some(&("requestCompleted".to_string(), None)));
+}
+
+#[test]
+fn response_taxonomy_change_cancels_pending_geterr() {
+    let mut server = make_server();
+    server
+        .open_files
+        .insert("/a.ts".to_string(), "const a = 1;".to_string());
+    server.handle_tsserver_request(make_request(
+        "geterr",
+        serde_json::json!({"files": ["/a.ts"], "delay": 1000}),
+    ));
+    server.handle_tsserver_request(make_request(
+        "change",
+        serde_json::json!({
+            "file": "/a.ts", "line": 1, "offset": 1, "endLine": 1, "endOffset": 1,
+            "insertString": "// edit\n",
+        }),
+    ));
+    assert!(server.pending_geterr_wait().is_none());
+    let events = server.drain_pending_events();
+    assert_eq!(events.len(), 1, "{events:?}");
+    assert_eq!(events[0]["event"], "requestCompleted");
+}
+
+#[test]
+fn response_taxonomy_requests_are_served_before_pending_geterr_steps() {
+    let mut server = make_server();
+    server
+        .open_files
+        .insert("/a.ts".to_string(), "const a = 1;".to_string());
+    let input: String = [
+        r#"{"seq":1,"type":"request","command":"geterr","arguments":{"files":["/a.ts"],"delay":0}}"#,
+        r#"{"seq":2,"type":"request","command":"navtree","arguments":{"file":"/a.ts"}}"#,
+    ]
+    .iter()
+    .map(|request| format!("Content-Length: {}\r\n\r\n{}", request.len(), request))
+    .collect();
+    let mut stdin = std::io::BufReader::new(input.as_bytes());
+    let mut stdout = Vec::new();
+    run_tsserver_protocol_with_io(&mut server, &mut stdin, &mut stdout, None)
+        .expect("protocol should run cleanly");
+
+    let stdout = String::from_utf8(stdout).expect("stdout should be UTF-8");
+    let navtree = stdout
+        .find(r#""command":"navtree""#)
+        .expect("navtree response");
+    let first_event = stdout
+        .find(r#""event":"syntaxDiag""#)
+        .expect("geterr events");
+    assert!(navtree < first_event, "{stdout}");
+    assert!(stdout.contains(r#""event":"requestCompleted""#), "{stdout}");
+}
+
+#[test]
+fn response_taxonomy_syntactic_mode_rejects_semantic_commands() {
+    let mut server = make_server();
+    server.server_mode = ServerMode::Syntactic;
+    server
+        .open_files
+        .insert("/a.ts".to_string(), "const a = 1;\na;\n".to_string());
+
+    let resp = server.handle_tsserver_request(make_request(
+        "quickinfo",
+        serde_json::json!({"file": "/a.ts", "line": 2, "offset": 1}),
+    ));
+    assert!(!resp.success);
+    assert_eq!(
+        resp.message.as_deref(),
+        Some(
+            "Error processing request. Request: quickinfo not allowed in LanguageServiceMode.Syntactic"
+        )
+    );
+
+    let resp = server.handle_tsserver_request(make_request(
+        "navtree",
+        serde_json::json!({"file": "/a.ts"}),
+    ));
+    assert!(resp.success, "syntactic commands stay available");
+
+    server.handle_tsserver_request(make_request(
+        "geterr",
+        serde_json::json!({"files": ["/a.ts"], "delay": 0}),
+    ));
+    assert_eq!(
+        geterr_event_sequence(&mut server),
+        vec![
+            ("syntaxDiag".to_string(), Some("/a.ts".to_string())),
+            ("requestCompleted".to_string(), None),
+        ]
+    );
+}
+
+#[test]
+fn response_taxonomy_partial_semantic_mode_rejects_full_variants() {
+    for command in [
+        "compilerOptionsDiagnostics-full",
+        "getCodeFixes-full",
+        "getCombinedCodeFix-full",
+        "getEditsForRefactor-full",
+        "organizeImports-full",
+        "getEditsForFileRename-full",
+    ] {
+        assert!(
+            !ServerMode::PartialSemantic.allows_command(command),
+            "{command} needs the whole program"
+        );
+    }
+    assert!(ServerMode::PartialSemantic.allows_command("quickinfo"));
+}
+
+#[test]
+fn response_taxonomy_partial_semantic_mode_checks_the_file_alone() {
+    let mut server = make_server_with_real_libs();
+    server.server_mode = ServerMode::PartialSemantic;
+    let source = "import { b } from \"./b\";\nconst a: string = b;\n";
+    server
+        .open_files
+        .insert("/a.ts".to_string(), source.to_string());
+    server
+        .open_files
+        .insert("/b.ts".to_string(), "export const b = 1;\n".to_string());
+
+    let resp = server.handle_tsserver_request(make_request(
+        "semanticDiagnosticsSync",
+        serde_json::json!({"file": "/a.ts"}),
+    ));
+    assert!(!resp.success);
+    assert_eq!(
+        resp.message.as_deref(),
+        Some(
+            "Error processing request. Request: semanticDiagnosticsSync not allowed in LanguageServiceMode.PartialSemantic"
+        )
+    );
+
+    // `./b` is not resolved, so `b` never gets its `number` type.
+    let diagnostics = server.get_semantic_diagnostics_full("/a.ts", source);
+    assert!(
+        diagnostics.iter().all(|diagnostic| diagnostic.code != 2322),
+        "cross-file types must not leak into a partial semantic check: {diagnostics:?}"
+    );
+
+    server.server_mode = ServerMode::Semantic;
+    let diagnostics = server.get_semantic_diagnostics_full("/a.ts", source);
+    assert!(
+        diagnostics.iter().any(|diagnostic| diagnostic.code == 2322),
+        "the full server resolves ./b: {diagnostics:?}"
+    );
+}
@@ -15,7 +15,9 @@ pub(super) fn make_server() -> Server {
         response_seq: 0,
         open_files: FxHashMap::default(),
         external_project_files: FxHashMap::default(),
-        _server_mode: ServerMode::Semantic,
+        external_projects: FxHashMap::default(),
+        bind_cache: Default::default(),
+        server_mode: ServerMode::Semantic,
         _log_config: LogConfig {
             level: LogLevel::Off,
             file: None,
@@ -35,6 +37,12 @@ pub(super) fn make_server() -> Server {
         plugin_configs: FxHashMap::default(),
         native_ts_worker: None,
         pending_events: Vec::new(),
+        pending_geterr: None,
+        can_use_watch_events: false,
+        file_watch: Default::default(),
+        configured_projects: Default::default(),
+        cancellation: Default::default(),
+        memory_budget: Default::default(),
     }
 }
 
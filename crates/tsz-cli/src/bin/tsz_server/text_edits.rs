@@ -87,6 +87,37 @@ pub(crate) fn narrow_indentation_only_edit(
     }
 }
 
+/// The single edit that turns `old_text` into `new_text`, trimmed to the span
+/// between their common prefix and suffix.
+pub(crate) fn minimal_replacement_edit(
+    old_text: &str,
+    new_text: &str,
+    line_map: &LineMap,
+) -> NarrowedTextEdit {
+    let prefix = old_text
+        .char_indices()
+        .zip(new_text.chars())
+        .find(|((_, old_ch), new_ch)| old_ch != new_ch)
+        .map_or_else(|| old_text.len().min(new_text.len()), |((idx, _), _)| idx);
+    let old_after_prefix = &old_text[prefix..];
+    let new_after_prefix = &new_text[prefix..];
+    let suffix = old_after_prefix
+        .chars()
+        .rev()
+        .zip(new_after_prefix.chars().rev())
+        .take_while(|(old_ch, new_ch)| old_ch == new_ch)
+        .map(|(old_ch, _)| old_ch.len_utf8())
+        .sum::<usize>();
+
+    let old_end = old_text.len() - suffix;
+    let start_pos = line_map.offset_to_position(prefix as u32, old_text);
+    let end_pos = line_map.offset_to_position(old_end as u32, old_text);
+    NarrowedTextEdit {
+        range: Range::new(start_pos, end_pos),
+        new_text: new_text[prefix..new_text.len() - suffix].to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -156,4 +187,19 @@ mod tests {
         assert_eq!(narrowed.range, edit.range);
         assert_eq!(narrowed.new_text, edit.new_text);
     }
+
+    #[test]
+    fn minimal_replacement_spans_only_the_changed_lines() {
+        let old_text = "import { b } from \"b\";\nimport { a } from \"a\";\nuse(a);\n";
+        let new_text = "import { a } from \"a\";\nuse(a);\n";
+        let line_map = LineMap::build(old_text);
+
+        let edit = minimal_replacement_edit(old_text, new_text, &line_map);
+
+        assert_eq!(
+            edit.range,
+            Range::new(Position::new(0, 9), Position::new(1, 9))
+        );
+        assert_eq!(edit.new_text, "");
+    }
 }
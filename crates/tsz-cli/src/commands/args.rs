@@ -576,6 +576,10 @@ pub struct CliArgs {
     #[arg(long)]
     pub pretty: Option<bool>,
 
+    /// Diagnostic output format: tsc-style text, JSON lines, or a SARIF 2.1.0 log.
+    #[arg(long, value_enum)]
+    pub format: Option<DiagnosticFormat>,
+
     /// Disable truncating types in error messages.
     #[arg(long = "noErrorTruncation", alias = "no-error-truncation")]
     pub no_error_truncation: bool,
@@ -584,6 +588,16 @@ pub struct CliArgs {
     #[arg(long = "preserveWatchOutput", alias = "preserve-watch-output")]
     pub preserve_watch_output: bool,
 
+    /// Report watch-mode rechecks and diagnostic changes as JSON lines,
+    /// for editor compile-on-save integrations.
+    #[arg(long = "watchEvents", alias = "watch-events")]
+    pub watch_events: bool,
+
+    /// Deliver watch events to this TCP port on localhost instead of stdout.
+    /// Implies `--watchEvents`.
+    #[arg(long = "watchEventPort", alias = "watch-event-port")]
+    pub watch_event_port: Option<u16>,
+
     // ==================== Watch Mode Options ====================
     /// Specify how the TypeScript watch mode works.
     #[arg(long = "watchFile", alias = "watch-file", value_enum)]
@@ -727,6 +741,11 @@ pub struct CliArgs {
     )]
     pub types_versions_compiler_version: Option<String>,
 
+    /// Also report suggestion diagnostics (TS80xxx), such as `await` on a
+    /// non-Promise value. Suggestions never affect the exit code.
+    #[arg(long)]
+    pub suggestions: bool,
+
     // ==================== Batch Mode ====================
     /// Enter batch mode: read project paths from stdin, one per line,
     /// compile each, and print diagnostics followed by a sentinel line.
@@ -749,7 +768,8 @@ pub struct CliArgs {
     pub explicitly_disabled_bool_flags: Vec<String>,
 
     // ==================== Input Files ====================
-    /// Input files to compile.
+    /// Input files to compile. Quoted glob patterns are expanded like
+    /// tsconfig `include` entries, and `-` reads a file from stdin.
     #[arg(value_name = "FILE")]
     pub files: Vec<PathBuf>,
 }
@@ -905,6 +925,17 @@ pub enum NewLine {
     Lf,
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum DiagnosticFormat {
+    /// tsc-style human-readable text.
+    #[default]
+    Text,
+    /// One JSON object per diagnostic per line, for tooling.
+    Json,
+    /// A SARIF 2.1.0 log, for code scanning and other SARIF consumers.
+    Sarif,
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
 pub enum WatchFileKind {
     /// Poll files at fixed intervals.
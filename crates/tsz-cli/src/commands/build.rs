@@ -1,12 +1,13 @@
 // Copyright 2025 tsz authors. All rights reserved.
 // MIT License.
 
+use rustc_hash::FxHashSet;
 use std::path::PathBuf;
 use tracing::{info, warn};
 
 use crate::args::CliArgs;
 use crate::incremental::BuildInfo;
-use crate::project_refs::{ResolvedProject, load_project};
+use crate::project_refs::{ProjectId, ProjectReferenceGraph, ResolvedProject, load_project};
 
 /// Check if a project is up-to-date by examining its .tsbuildinfo file
 /// and the outputs of its referenced projects.
@@ -48,6 +49,18 @@ pub fn is_project_up_to_date(project: &ResolvedProject, args: &CliArgs) -> bool
         }
     };
 
+    // Sources alone are not enough: if the recorded declaration output was
+    // deleted (e.g. `outDir` was wiped), downstream projects have nothing to
+    // consume and the project must be rebuilt.
+    if let Some(latest_dts) = build_info.latest_changed_dts_file.as_deref()
+        && !project.root_dir.join(latest_dts).is_file()
+    {
+        if args.build_verbose {
+            info!("Output file '{}' does not exist", latest_dts);
+        }
+        return false;
+    }
+
     // Check if source files have changed using ChangeTracker
     let root_dir = &project.root_dir;
 
@@ -241,6 +254,23 @@ fn are_referenced_projects_uptodate(
     true
 }
 
+/// Find a reference of `project_id` whose build failed earlier in this run.
+///
+/// Like `tsc --build`, a project is not built against the stale outputs of a
+/// dependency that has errors (TS6362). Callers add skipped projects to
+/// `failed` as well, so the skip propagates to transitive dependents.
+pub fn find_failed_reference(
+    graph: &ProjectReferenceGraph,
+    project_id: ProjectId,
+    failed: &FxHashSet<ProjectId>,
+) -> Option<ProjectId> {
+    graph
+        .get_references(project_id)
+        .iter()
+        .copied()
+        .find(|id| failed.contains(id))
+}
+
 /// Get the path to the .tsbuildinfo file for a project
 pub fn get_build_info_path(project: &ResolvedProject) -> Option<PathBuf> {
     use crate::incremental::default_build_info_path;
@@ -406,6 +436,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn is_project_up_to_date_returns_false_when_own_dts_output_is_missing() {
+        let temp = create_project_dir("missing_own_dts");
+        let root_dir = temp.path().to_path_buf();
+        let config_path = write_project_config(&root_dir);
+        let source_path = write_source_file(&root_dir, "src/index.ts", "export const x = 1;");
+        write_root_build_info(&root_dir, &source_path, Some("dist/index.d.ts"), None);
+
+        let mut project = make_project(config_path, root_dir.clone(), Vec::new(), None);
+        // Keep the emitted declaration out of the discovered sources.
+        project.config = serde_json::from_str(r#"{"include":["src"]}"#).unwrap();
+        assert!(!is_project_up_to_date(&project, &cli_args()));
+
+        write_source_file(&root_dir, "dist/index.d.ts", "export declare const x = 1;");
+        assert!(is_project_up_to_date(&project, &cli_args()));
+    }
+
+    #[test]
+    fn find_failed_reference_reports_failed_dependency() {
+        let temp = create_project_dir("failed_reference");
+        let core_dir = temp.path().join("core");
+        let app_dir = temp.path().join("app");
+        fs::create_dir_all(&core_dir).unwrap();
+        fs::create_dir_all(&app_dir).unwrap();
+        fs::write(
+            core_dir.join("tsconfig.json"),
+            r#"{"compilerOptions":{"composite":true}}"#,
+        )
+        .unwrap();
+        let app_config = app_dir.join("tsconfig.json");
+        fs::write(&app_config, r#"{"references":[{"path":"../core"}]}"#).unwrap();
+
+        let graph = ProjectReferenceGraph::load(&app_config).unwrap();
+        let app_id = graph
+            .get_project_id(&fs::canonicalize(&app_config).unwrap())
+            .unwrap();
+        let core_id = graph.get_references(app_id)[0];
+
+        let mut failed = FxHashSet::default();
+        assert_eq!(find_failed_reference(&graph, app_id, &failed), None);
+        failed.insert(core_id);
+        assert_eq!(
+            find_failed_reference(&graph, app_id, &failed),
+            Some(core_id)
+        );
+    }
+
     #[test]
     fn is_project_up_to_date_returns_false_for_root_buildinfo_version_mismatch() {
         let temp = create_project_dir("version_mismatch");
@@ -9,6 +9,7 @@ use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
 use tsz::checker::diagnostics::DiagnosticCategory;
+use tsz_common::diagnostics::{diagnostic_codes, diagnostic_messages, format_message};
 
 use crate::args::{CliArgs, PollingWatchKind, WatchFileKind};
 use crate::config::{ResolvedCompilerOptions, resolve_compiler_options};
@@ -16,6 +17,7 @@ use crate::driver::resolution::canonicalize_or_owned;
 use crate::driver::{self, CompilationCache};
 use crate::fs::{DEFAULT_EXCLUDES, is_ts_file};
 use crate::reporter::Reporter;
+use crate::watch_events::WatchEventStream;
 
 /// Format a timestamp in tsc's `h:mm:ss tt` format (12-hour clock with AM/PM).
 ///
@@ -91,36 +93,102 @@ pub(crate) fn format_12h(hour24: u32, min: u32, sec: u32) -> String {
     format!("{hour12}:{min:02}:{sec:02} {period}")
 }
 
-/// Format a watch timestamp, optionally with gray ANSI coloring (matching tsc v6 pretty output).
-fn format_colored_timestamp(color: bool) -> String {
-    let ts = format_watch_timestamp();
+/// Clear screen + scrollback (ANSI escape sequence, matches tsc v6).
+const CLEAR_SCREEN: &str = "\x1B[2J\x1B[3J\x1B[H";
+
+/// The messages that begin a new compilation "screen" (TS6031, TS6032).
+const fn starts_screen(code: u32) -> bool {
+    matches!(
+        code,
+        diagnostic_codes::STARTING_COMPILATION_IN_WATCH_MODE
+            | diagnostic_codes::FILE_CHANGE_DETECTED_STARTING_INCREMENTAL_COMPILATION
+    )
+}
+
+/// Whether screen-starting status messages wipe the console first. Like tsc's
+/// `clearScreenIfNotWatchingForFileChanges`, `--preserveWatchOutput` keeps the
+/// history, and so do `--diagnostics`/`--extendedDiagnostics` so their
+/// per-build reports stay readable.
+pub(crate) const fn clears_screen(args: &CliArgs) -> bool {
+    !args.preserve_watch_output && !args.diagnostics && !args.extended_diagnostics
+}
+
+/// Format a watch status line the way tsc's watch status reporter does.
+///
+/// Pretty output is `[<gray time>] message` followed by a blank line. Plain
+/// output is `time - message`; the screen-starting messages (TS6031, TS6032)
+/// are followed by a blank line and the others by a newline, and every
+/// message is preceded by a newline unless the screen was just cleared for it.
+pub(crate) fn format_watch_status(
+    timestamp: &str,
+    code: u32,
+    message: &str,
+    color: bool,
+    cleared: bool,
+) -> String {
     if color {
-        format!("[\x1b[90m{ts}\x1b[0m]")
-    } else {
-        format!("[{ts}]")
+        return format!("[\x1b[90m{timestamp}\x1b[0m] {message}\n\n");
+    }
+    let leading = if cleared { "" } else { "\n" };
+    let trailing = if starts_screen(code) { "\n\n" } else { "\n" };
+    format!("{leading}{timestamp} - {message}{trailing}")
+}
+
+fn print_watch_status(code: u32, message: &str, color: bool, clear_screen: bool) {
+    let cleared = clear_screen && starts_screen(code);
+    if cleared {
+        print!("{CLEAR_SCREEN}");
     }
+    print!(
+        "{}",
+        format_watch_status(&format_watch_timestamp(), code, message, color, cleared)
+    );
 }
 
 /// Print the TS6031 watch start message to stdout.
-fn print_watch_start(color: bool) {
-    let ts = format_colored_timestamp(color);
-    println!("{ts} Starting compilation in watch mode...");
+fn print_watch_start(color: bool, clear_screen: bool) {
+    print_watch_status(
+        diagnostic_codes::STARTING_COMPILATION_IN_WATCH_MODE,
+        diagnostic_messages::STARTING_COMPILATION_IN_WATCH_MODE,
+        color,
+        clear_screen,
+    );
 }
 
 /// Print the TS6032 file change detected message to stdout.
-fn print_watch_change(color: bool) {
-    let ts = format_colored_timestamp(color);
-    println!("{ts} File change detected. Starting incremental compilation...");
+fn print_watch_change(color: bool, clear_screen: bool) {
+    print_watch_status(
+        diagnostic_codes::FILE_CHANGE_DETECTED_STARTING_INCREMENTAL_COMPILATION,
+        diagnostic_messages::FILE_CHANGE_DETECTED_STARTING_INCREMENTAL_COMPILATION,
+        color,
+        clear_screen,
+    );
 }
 
-/// Print the TS6194 watch completion message to stdout.
+/// Print the TS6193/TS6194 watch completion message to stdout.
 fn print_watch_complete(error_count: usize, color: bool) {
-    let ts = format_colored_timestamp(color);
-    let error_word = if error_count == 1 { "error" } else { "errors" };
-    println!("{ts} Found {error_count} {error_word}. Watching for file changes.");
+    if error_count == 1 {
+        print_watch_status(
+            diagnostic_codes::FOUND_1_ERROR_WATCHING_FOR_FILE_CHANGES,
+            diagnostic_messages::FOUND_1_ERROR_WATCHING_FOR_FILE_CHANGES,
+            color,
+            false,
+        );
+    } else {
+        print_watch_status(
+            diagnostic_codes::FOUND_ERRORS_WATCHING_FOR_FILE_CHANGES,
+            &format_message(
+                diagnostic_messages::FOUND_ERRORS_WATCHING_FOR_FILE_CHANGES,
+                &[&error_count.to_string()],
+            ),
+            color,
+            false,
+        );
+    }
 }
 
-const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(200);
+/// tsc waits 250ms after the last change before recompiling.
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(250);
 const DEBOUNCE_TICK: Duration = Duration::from_millis(50);
 
 /// Polling intervals for different strategies (matching tsc)
@@ -153,9 +221,16 @@ pub fn run(args: &CliArgs, cwd: &Path) -> Result<()> {
         Reporter::force_colors(true);
     }
     let mut reporter = Reporter::new(color);
+    reporter.set_format(args.format.unwrap_or_default());
     let mut state = WatchState::new(args, &cwd);
+    if args.watch_events || args.watch_event_port.is_some() {
+        state.events = Some(WatchEventStream::connect(args.watch_event_port, &cwd)?);
+    }
 
-    print_watch_start(color);
+    let clear_screen = clears_screen(args);
+    if state.console_output() {
+        print_watch_start(color, clear_screen);
+    }
     state.compile_and_report(args, &cwd, &mut reporter, None, color)?;
 
     let (tx, rx) = mpsc::channel();
@@ -170,7 +245,8 @@ pub fn run(args: &CliArgs, cwd: &Path) -> Result<()> {
     loop {
         match rx.recv_timeout(DEBOUNCE_TICK) {
             Ok(Ok(event)) => state.handle_event(event),
-            Ok(Err(err)) => println!("watch error: {err}"),
+            Ok(Err(err)) if state.console_output() => println!("watch error: {err}"),
+            Ok(Err(err)) => eprintln!("watch error: {err}"),
             Err(mpsc::RecvTimeoutError::Timeout) => {}
             Err(mpsc::RecvTimeoutError::Disconnected) => {
                 bail!("watch channel disconnected");
@@ -178,7 +254,9 @@ pub fn run(args: &CliArgs, cwd: &Path) -> Result<()> {
         }
 
         if let Some(changed) = state.debouncer.flush_ready(Instant::now()) {
-            print_watch_change(color);
+            if state.console_output() {
+                print_watch_change(color, clear_screen);
+            }
             state.compile_and_report(args, &cwd, &mut reporter, Some(changed), color)?;
         }
     }
@@ -231,6 +309,8 @@ struct WatchState {
     filter: WatchFilter,
     debouncer: Debouncer,
     type_cache: CompilationCache,
+    /// `--watchEvents` stream, if requested.
+    events: Option<WatchEventStream>,
 }
 
 impl WatchState {
@@ -290,9 +370,18 @@ impl WatchState {
             filter: WatchFilter::new(explicit_files, ignore_dirs, project_config, exclude_files),
             debouncer: Debouncer::new(DEFAULT_DEBOUNCE),
             type_cache: CompilationCache::default(),
+            events: None,
         }
     }
 
+    /// False when watch events are written to stdout in place of the usual
+    /// status lines and diagnostics.
+    fn console_output(&self) -> bool {
+        self.events
+            .as_ref()
+            .is_none_or(|events| !events.replaces_console())
+    }
+
     fn handle_event(&mut self, event: Event) {
         if !is_relevant_event(event.kind) {
             return;
@@ -322,6 +411,10 @@ impl WatchState {
             self.type_cache.clear();
         }
 
+        if let Some(events) = self.events.as_mut() {
+            events.recheck_start(changed_paths_ref.filter(|_| !needs_full_rebuild))?;
+        }
+
         let result = if needs_full_rebuild || changed_paths_ref.is_none() {
             driver::compile_with_cache(args, cwd, &mut self.type_cache)
         } else if let Some(changed_paths) = changed_paths_ref {
@@ -330,12 +423,7 @@ impl WatchState {
             driver::compile_with_cache(args, cwd, &mut self.type_cache)
         };
 
-        // Clear console unless --preserveWatchOutput is set
-        if !args.preserve_watch_output {
-            // Clear screen + scrollback (ANSI escape sequence, matches tsc v6)
-            print!("\x1B[2J\x1B[3J\x1B[H");
-        }
-
+        let console_output = self.console_output();
         let error_count = match result {
             Ok(result) => {
                 let count = result
@@ -344,22 +432,32 @@ impl WatchState {
                     .filter(|d| d.category == DiagnosticCategory::Error)
                     .count();
 
-                if !result.diagnostics.is_empty() {
+                if console_output && !result.diagnostics.is_empty() {
                     let output = reporter.render(&result.diagnostics);
                     if !output.is_empty() {
                         println!("{output}");
                     }
                 }
+                if let Some(events) = self.events.as_mut() {
+                    events.recheck_end(reporter, &result.diagnostics, &result.emitted_files)?;
+                }
                 self.update_emitted(result.emitted_files);
                 count
             }
             Err(err) => {
-                println!("{err}");
+                if console_output {
+                    println!("{err}");
+                }
+                if let Some(events) = self.events.as_mut() {
+                    events.recheck_failed(&err.to_string())?;
+                }
                 1
             }
         };
 
-        print_watch_complete(error_count, color);
+        if console_output {
+            print_watch_complete(error_count, color);
+        }
 
         if let Ok(project) = load_project_state(args, cwd) {
             self.filter.ignore_dirs = compute_ignore_dirs(&project.base_dir, &project.resolved);
@@ -641,6 +739,78 @@ mod tests {
         assert_eq!(format_12h(23, 59, 59), "11:59:59 PM");
     }
 
+    #[test]
+    fn format_watch_status_matches_tsc_layout() {
+        let start = format_watch_status(
+            "1:02:03 PM",
+            diagnostic_codes::STARTING_COMPILATION_IN_WATCH_MODE,
+            "Starting compilation in watch mode...",
+            false,
+            true,
+        );
+        assert_eq!(
+            start,
+            "1:02:03 PM - Starting compilation in watch mode...\n\n"
+        );
+
+        // With `--preserveWatchOutput` nothing is cleared, so the screen
+        // starter is separated from the previous build's output instead.
+        let preserved = format_watch_status(
+            "1:02:03 PM",
+            diagnostic_codes::FILE_CHANGE_DETECTED_STARTING_INCREMENTAL_COMPILATION,
+            "File change detected. Starting incremental compilation...",
+            false,
+            false,
+        );
+        assert_eq!(
+            preserved,
+            "\n1:02:03 PM - File change detected. Starting incremental compilation...\n\n"
+        );
+
+        let found = format_watch_status(
+            "1:02:03 PM",
+            diagnostic_codes::FOUND_ERRORS_WATCHING_FOR_FILE_CHANGES,
+            "Found 2 errors. Watching for file changes.",
+            false,
+            false,
+        );
+        assert_eq!(
+            found,
+            "\n1:02:03 PM - Found 2 errors. Watching for file changes.\n"
+        );
+
+        let pretty = format_watch_status(
+            "1:02:03 PM",
+            diagnostic_codes::FOUND_1_ERROR_WATCHING_FOR_FILE_CHANGES,
+            "Found 1 error. Watching for file changes.",
+            true,
+            false,
+        );
+        assert_eq!(
+            pretty,
+            "[\x1b[90m1:02:03 PM\x1b[0m] Found 1 error. Watching for file changes.\n\n"
+        );
+    }
+
+    #[test]
+    fn clears_screen_unless_output_is_preserved_or_diagnostics_are_reported() {
+        use clap::Parser;
+        let parse = |argv: &[&str]| CliArgs::try_parse_from(argv).expect("args should parse");
+
+        assert!(clears_screen(&parse(&["tsz", "--watch"])));
+        assert!(!clears_screen(&parse(&[
+            "tsz",
+            "--watch",
+            "--preserveWatchOutput"
+        ])));
+        assert!(!clears_screen(&parse(&["tsz", "--watch", "--diagnostics"])));
+        assert!(!clears_screen(&parse(&[
+            "tsz",
+            "--watch",
+            "--extendedDiagnostics"
+        ])));
+    }
+
     #[test]
     fn normalize_event_path_preserves_absolute_paths_and_joins_relative_paths() {
         let base_dir = Path::new("/repo/project");
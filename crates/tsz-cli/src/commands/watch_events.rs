@@ -0,0 +1,298 @@
+//! JSON-lines event stream for `--watch --watchEvents`.
+//!
+//! Lets editor plugins build compile-on-save without running the language
+//! server. Every compilation in watch mode writes, one JSON object per line:
+//!
+//! - `{"event":"recheckStart","changedFiles":[...]}` — `changedFiles` is
+//!   empty for the initial compilation and after a config change;
+//! - `{"event":"fileDiagnostics","file":...,"added":[...],"removed":[...]}` —
+//!   once per file whose diagnostics differ from the previous compilation,
+//!   with entries in the `--format json` shape (`file` is `null` for global
+//!   diagnostics);
+//! - `{"event":"recheckEnd","errorCount":n,"emittedFiles":[...],"durationMs":ms}`,
+//!   or `{"event":"recheckEnd","errorCount":1,"error":message}` when the
+//!   compilation itself failed.
+//!
+//! Events go to stdout, replacing the human-readable watch output, or to a
+//! TCP port on localhost with `--watchEventPort`.
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use crate::reporter::Reporter;
+use tsz::checker::diagnostics::{Diagnostic, DiagnosticCategory};
+
+pub struct WatchEventStream {
+    out: Box<dyn Write>,
+    replaces_console: bool,
+    base_dir: PathBuf,
+    /// Diagnostics of the previous compilation, keyed by file.
+    previous: BTreeMap<String, Vec<serde_json::Value>>,
+    recheck_started: Option<Instant>,
+}
+
+impl WatchEventStream {
+    /// Stream to `port` on localhost, or to stdout when `port` is `None`.
+    pub fn connect(port: Option<u16>, base_dir: &Path) -> Result<Self> {
+        let Some(port) = port else {
+            return Ok(Self::new(Box::new(std::io::stdout()), true, base_dir));
+        };
+        let socket = std::net::TcpStream::connect((std::net::Ipv4Addr::LOCALHOST, port))
+            .with_context(|| format!("failed to connect to watch event port {port}"))?;
+        let _ = socket.set_nodelay(true);
+        Ok(Self::new(Box::new(socket), false, base_dir))
+    }
+
+    pub fn new(out: Box<dyn Write>, replaces_console: bool, base_dir: &Path) -> Self {
+        Self {
+            out,
+            replaces_console,
+            base_dir: base_dir.to_path_buf(),
+            previous: BTreeMap::new(),
+            recheck_started: None,
+        }
+    }
+
+    /// Whether events are written to stdout, so the usual watch output must
+    /// be suppressed to keep one JSON object per line.
+    pub const fn replaces_console(&self) -> bool {
+        self.replaces_console
+    }
+
+    pub fn recheck_start(&mut self, changed_files: Option<&[PathBuf]>) -> Result<()> {
+        self.recheck_started = Some(Instant::now());
+        let mut changed: Vec<String> = changed_files
+            .unwrap_or_default()
+            .iter()
+            .map(|path| self.display_path(path))
+            .collect();
+        changed.sort();
+        self.write_event(serde_json::json!({
+            "event": "recheckStart",
+            "changedFiles": changed,
+        }))
+    }
+
+    pub fn recheck_end(
+        &mut self,
+        reporter: &mut Reporter,
+        diagnostics: &[Diagnostic],
+        emitted_files: &[PathBuf],
+    ) -> Result<()> {
+        let mut current: BTreeMap<String, Vec<serde_json::Value>> = BTreeMap::new();
+        for diagnostic in diagnostics {
+            current
+                .entry(diagnostic.file.clone())
+                .or_default()
+                .push(reporter.diagnostic_json(diagnostic));
+        }
+
+        let previous = std::mem::take(&mut self.previous);
+        let files: Vec<&String> = {
+            let mut files: Vec<&String> = previous.keys().chain(current.keys()).collect();
+            files.sort();
+            files.dedup();
+            files
+        };
+        for file in files {
+            let old = previous.get(file).map_or(&[][..], Vec::as_slice);
+            let new = current.get(file).map_or(&[][..], Vec::as_slice);
+            let added: Vec<&serde_json::Value> =
+                new.iter().filter(|value| !old.contains(value)).collect();
+            let removed: Vec<&serde_json::Value> =
+                old.iter().filter(|value| !new.contains(value)).collect();
+            if added.is_empty() && removed.is_empty() {
+                continue;
+            }
+            let file = if file.is_empty() {
+                serde_json::Value::Null
+            } else {
+                self.display_path(Path::new(file)).into()
+            };
+            self.write_event(serde_json::json!({
+                "event": "fileDiagnostics",
+                "file": file,
+                "added": added,
+                "removed": removed,
+            }))?;
+        }
+        self.previous = current;
+
+        let error_count = diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.category == DiagnosticCategory::Error)
+            .count();
+        let mut emitted: Vec<String> = emitted_files
+            .iter()
+            .map(|path| self.display_path(path))
+            .collect();
+        emitted.sort();
+        let duration_ms = self.take_recheck_duration_ms();
+        self.write_event(serde_json::json!({
+            "event": "recheckEnd",
+            "errorCount": error_count,
+            "emittedFiles": emitted,
+            "durationMs": duration_ms,
+        }))
+    }
+
+    /// Report a compilation that failed before producing diagnostics. The
+    /// previous diagnostics are kept, so the next delta is against them.
+    pub fn recheck_failed(&mut self, message: &str) -> Result<()> {
+        let duration_ms = self.take_recheck_duration_ms();
+        self.write_event(serde_json::json!({
+            "event": "recheckEnd",
+            "errorCount": 1,
+            "error": message,
+            "durationMs": duration_ms,
+        }))
+    }
+
+    fn take_recheck_duration_ms(&mut self) -> f64 {
+        self.recheck_started
+            .take()
+            .map_or(0.0, |start| start.elapsed().as_secs_f64() * 1000.0)
+    }
+
+    fn display_path(&self, path: &Path) -> String {
+        path.strip_prefix(&self.base_dir)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+
+    fn write_event(&mut self, event: serde_json::Value) -> Result<()> {
+        writeln!(self.out, "{event}").context("failed to write watch event")?;
+        self.out.flush().context("failed to flush watch events")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedBuffer {
+        fn take_events(&self) -> Vec<serde_json::Value> {
+            let bytes = std::mem::take(&mut *self.0.lock().unwrap());
+            String::from_utf8(bytes)
+                .unwrap()
+                .lines()
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect()
+        }
+    }
+
+    #[test]
+    fn recheck_reports_changed_files_and_diagnostic_deltas() {
+        let base = Path::new("/project");
+        let buffer = SharedBuffer::default();
+        let mut stream = WatchEventStream::new(Box::new(buffer.clone()), false, base);
+        let mut reporter = Reporter::new(false);
+        let unused = Diagnostic::error("/project/a.ts", 0, 1, "Cannot find name 'x'.", 2304);
+        let global = Diagnostic::error("", 0, 0, "Cannot find global type 'Array'.", 2318);
+
+        stream.recheck_start(None).unwrap();
+        stream
+            .recheck_end(
+                &mut reporter,
+                &[unused.clone(), global.clone()],
+                &[PathBuf::from("/project/a.js")],
+            )
+            .unwrap();
+        let events = buffer.take_events();
+        let kinds: Vec<&str> = events
+            .iter()
+            .map(|event| event["event"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                "recheckStart",
+                "fileDiagnostics",
+                "fileDiagnostics",
+                "recheckEnd"
+            ]
+        );
+        assert_eq!(events[0]["changedFiles"], serde_json::json!([]));
+        assert_eq!(events[1]["file"], serde_json::Value::Null);
+        assert_eq!(events[2]["file"], "a.ts");
+        assert_eq!(events[2]["added"][0]["code"], 2304);
+        assert_eq!(events[3]["errorCount"], 2);
+        assert_eq!(events[3]["emittedFiles"], serde_json::json!(["a.js"]));
+
+        // Fixing `a.ts` only reports its removed diagnostic; the unchanged
+        // global diagnostic produces no event.
+        stream
+            .recheck_start(Some(&[PathBuf::from("/project/a.ts")]))
+            .unwrap();
+        stream.recheck_end(&mut reporter, &[global], &[]).unwrap();
+        let events = buffer.take_events();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0]["changedFiles"], serde_json::json!(["a.ts"]));
+        assert_eq!(events[1]["event"], "fileDiagnostics");
+        assert_eq!(events[1]["file"], "a.ts");
+        assert_eq!(events[1]["added"], serde_json::json!([]));
+        assert_eq!(events[1]["removed"][0]["code"], 2304);
+        assert_eq!(events[2]["errorCount"], 1);
+    }
+
+    #[test]
+    fn failed_recheck_reports_error_and_keeps_previous_diagnostics() {
+        let buffer = SharedBuffer::default();
+        let mut stream =
+            WatchEventStream::new(Box::new(buffer.clone()), true, Path::new("/project"));
+        let mut reporter = Reporter::new(false);
+        let diagnostic = Diagnostic::error("/project/a.ts", 0, 1, "Cannot find name 'x'.", 2304);
+
+        stream.recheck_start(None).unwrap();
+        stream
+            .recheck_end(&mut reporter, std::slice::from_ref(&diagnostic), &[])
+            .unwrap();
+        stream.recheck_start(None).unwrap();
+        stream
+            .recheck_failed("failed to read tsconfig.json")
+            .unwrap();
+        stream.recheck_start(None).unwrap();
+        stream
+            .recheck_end(&mut reporter, &[diagnostic], &[])
+            .unwrap();
+
+        let events = buffer.take_events();
+        let kinds: Vec<&str> = events
+            .iter()
+            .map(|event| event["event"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                "recheckStart",
+                "fileDiagnostics",
+                "recheckEnd",
+                "recheckStart",
+                "recheckEnd",
+                "recheckStart",
+                "recheckEnd"
+            ]
+        );
+        assert_eq!(events[4]["error"], "failed to read tsconfig.json");
+        assert!(stream.replaces_console());
+    }
+}
@@ -287,6 +287,9 @@ pub(super) struct CollectDiagnosticsInput<'a> {
     pub(super) typescript_dom_replacement_globals: (bool, bool, bool),
     pub(super) has_deprecation_diagnostics: bool,
     pub(super) collect_compile_stats: bool,
+    /// Re-check the direct importers of files whose export signature changed
+    /// without cascading to their importers (`--assumeChangesOnlyAffectDirectDependencies`).
+    pub(super) assume_changes_only_affect_direct_dependencies: bool,
 }
 
 type CachedModuleSpecifier = (
@@ -328,6 +331,7 @@ pub(super) fn collect_diagnostics_with_source_resolutions(
         typescript_dom_replacement_globals,
         has_deprecation_diagnostics,
         collect_compile_stats,
+        assume_changes_only_affect_direct_dependencies,
     } = input;
     let _collect_span =
         tracing::info_span!("collect_diagnostics", files = program.files.len()).entered();
@@ -1112,17 +1116,39 @@ pub(super) fn collect_diagnostics_with_source_resolutions(
 
     let mut work_queue: VecDeque<usize> = VecDeque::new();
     let mut checked_files: FxHashSet<usize> = FxHashSet::default();
+    // Files queued only because a dependency's export signature changed.
+    let mut cascaded_files: FxHashSet<usize> = FxHashSet::default();
+
+    // Unchanged files restored from a .tsbuildinfo keep their persisted
+    // diagnostics. This only holds while the program has exactly the recorded
+    // files: an added or removed file can change what unchanged files resolve
+    // their imports to.
+    let reusable_files: FxHashSet<PathBuf> = cache
+        .as_deref()
+        .filter(|c| {
+            !c.unchanged_persisted_files.is_empty()
+                && c.persisted_files.len() == program.files.len()
+                && program
+                    .files
+                    .iter()
+                    .all(|file| c.persisted_files.contains(Path::new(&file.file_name)))
+        })
+        .map(|c| c.unchanged_persisted_files.clone())
+        .unwrap_or_default();
 
     // Mark all files as used for cache cleanup
     for (idx, file) in program.files.iter().enumerate() {
         let file_path = PathBuf::from(&file.file_name);
         used_paths.insert(file_path.clone());
 
-        // Check if file has cached type information
-        // If no cache or cache miss, file needs to be checked
-        let needs_check = cache
-            .as_deref()
-            .is_none_or(|c| !c.type_caches.contains_key(&file_path)); // No cache at all -> check everything
+        // Check if file has cached type information and diagnostics
+        // If no cache or cache miss, file needs to be checked. Symbol-level
+        // invalidation keeps a dependent's type cache but drops its
+        // diagnostics, so those files are re-checked too.
+        let needs_check = cache.as_deref().is_none_or(|c| {
+            !c.type_caches.contains_key(&file_path) || !c.diagnostics.contains_key(&file_path)
+        }) // No cache at all -> check everything
+            && !reusable_files.contains(&file_path);
 
         if needs_check {
             work_queue.push_back(idx);
@@ -1703,6 +1729,12 @@ pub(super) fn collect_diagnostics_with_source_resolutions(
                 );
             }
 
+            // Suggestions (only collected under `--suggestions`) bypass the
+            // error filters above and never fail the build.
+            if !options.no_check {
+                file_diagnostics.append(&mut checker.ctx.suggestion_diagnostics);
+            }
+
             // Update the cache and check for export signature changes.
             // Uses the unified binder-level ExportSignature (shared with LSP)
             // so body-only/comment-only/private-symbol edits produce the same
@@ -1728,7 +1760,14 @@ pub(super) fn collect_diagnostics_with_source_resolutions(
                     .insert(file_path.clone(), file_diagnostics.clone());
                 c.export_hashes.insert(file_path.clone(), new_hash);
 
-                if old_hash != Some(new_hash)
+                // Direct importers of a file whose exports changed are always
+                // re-checked (files reused from a .tsbuildinfo would otherwise
+                // keep stale diagnostics); assuming changes only affect direct
+                // dependencies stops the cascade after that one level.
+                let cascades = !(assume_changes_only_affect_direct_dependencies
+                    && cascaded_files.contains(&file_idx));
+                if cascades
+                    && old_hash != Some(new_hash)
                     && let Some(dependents) = c.reverse_dependencies.get(&file_path)
                 {
                     for dep_path in dependents {
@@ -1736,6 +1775,7 @@ pub(super) fn collect_diagnostics_with_source_resolutions(
                             && checked_files.insert(dep_idx)
                         {
                             work_queue.push_back(dep_idx);
+                            cascaded_files.insert(dep_idx);
                             c.type_caches.remove(dep_path);
                             c.diagnostics.remove(dep_path);
                         }
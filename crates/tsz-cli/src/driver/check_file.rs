@@ -254,6 +254,7 @@ fn run_check_on_existing_checker<'a>(
     // declaration emit; in the latter case the produced diagnostics are
     // discarded so `--noCheck` still suppresses type errors.
     let run_checker_for_decl_emit = no_check && compiler_options.emit_declarations;
+    let mut suggestion_diagnostics = Vec::new();
     if !no_check || run_checker_for_decl_emit {
         let check_start = tsz_common::perf_counters::enabled_fast().then(std::time::Instant::now);
         tsz::checker::reset_stack_overflow_flag();
@@ -282,6 +283,7 @@ fn run_check_on_existing_checker<'a>(
 
         if !no_check {
             file_diagnostics.extend(checker_diagnostics);
+            suggestion_diagnostics = std::mem::take(&mut checker.ctx.suggestion_diagnostics);
         } else if compiler_options.isolated_declarations {
             // `--noCheck` suppresses type errors, but the
             // `--isolatedDeclarations` family (TS9007–TS9039) gates
@@ -313,6 +315,9 @@ fn run_check_on_existing_checker<'a>(
         );
     }
 
+    // Suggestions (only collected under `--suggestions`) bypass the error
+    // filters above; they are informational and never fail the build.
+    file_diagnostics.extend(suggestion_diagnostics);
     file_diagnostics
 }
 
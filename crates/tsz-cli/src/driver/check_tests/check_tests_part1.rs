@@ -23,6 +23,7 @@
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -52,6 +53,7 @@
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -788,6 +790,7 @@ interface Window {
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -831,6 +834,7 @@ interface Window {
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -1506,6 +1510,7 @@ declare namespace Intl {
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -1801,6 +1806,7 @@ const elem = <div className={class1, class2}/>;
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -104,6 +104,7 @@ async function main() {
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -181,6 +182,7 @@ const q: PromiseLike<number> = p;
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -265,6 +267,7 @@ async function f() {
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -352,6 +355,7 @@ type Recurse2 = {
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -573,6 +577,7 @@ interface Constraint<A extends Runtype<any>> extends Runtype<A['witness']> {
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -596,6 +601,7 @@ interface Constraint<A extends Runtype<any>> extends Runtype<A['witness']> {
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -703,6 +709,7 @@ export const x = foo();
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -809,6 +816,7 @@ export type RowToColumns<TColumns> = {
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
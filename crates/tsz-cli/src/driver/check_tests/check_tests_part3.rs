@@ -98,6 +98,7 @@
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -220,6 +221,7 @@ const onSomeEvent = <T extends keyof TypesMap>(p: P<T>) => typeHandlers[p.t]?.(p
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -322,6 +324,7 @@ const nestedTuple = type([["ark", "|>", (x) => x.length]])
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -414,6 +417,7 @@ m(item => item.id < 5);
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -500,6 +504,7 @@ interface Buzz { id: number; buzz: string }
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -593,6 +598,7 @@ const obj: {field: Rule} = {
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -718,6 +724,7 @@ function foo() {
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -953,6 +960,7 @@ interface Node {
                 typescript_dom_replacement_globals: (false, false, false),
                 has_deprecation_diagnostics: false,
                 collect_compile_stats: false,
+                assume_changes_only_affect_direct_dependencies: false,
             },
             None,
             &type_cache_output,
@@ -3,6 +3,7 @@
 //! parse diagnostic conversion, and pragma detection.
 
 use super::*;
+use tsz::compiler_host;
 use tsz_common::file_extensions::is_ts_declaration_file;
 
 #[derive(Clone, Copy)]
@@ -342,17 +343,17 @@ fn emit_tslib_helper_diagnostics_from_counts(
 
 fn tslib_declaration_in_dir(dir: &Path) -> Option<std::path::PathBuf> {
     let candidate = dir.join("node_modules").join("tslib");
-    if !candidate.is_dir() {
+    if !compiler_host::directory_exists(&candidate) {
         return None;
     }
 
     let tslib_d_ts = candidate.join("tslib.d.ts");
-    if tslib_d_ts.is_file() {
+    if compiler_host::file_exists(&tslib_d_ts) {
         return Some(tslib_d_ts);
     }
 
     let index_d_ts = candidate.join("index.d.ts");
-    if index_d_ts.is_file() {
+    if compiler_host::file_exists(&index_d_ts) {
         return Some(index_d_ts);
     }
 
@@ -377,7 +378,7 @@ fn filesystem_tslib_declaration(base_dir: &Path) -> Option<std::path::PathBuf> {
 fn filesystem_tslib_helper_parameter_counts(
     tslib_path: &Path,
 ) -> Option<rustc_hash::FxHashMap<String, usize>> {
-    let source = std::fs::read_to_string(tslib_path).ok()?;
+    let source = compiler_host::read_file(tslib_path).ok()?;
     source_tslib_helper_parameter_counts(&source)
 }
 
@@ -580,10 +581,9 @@ fn source_offset_is_in_comment_or_string(source: &str, target: usize) -> bool {
 }
 
 fn program_appears_filesystem_backed(program: &MergedProgram) -> bool {
-    program
-        .files
-        .iter()
-        .any(|file| !file.file_name.ends_with(".d.ts") && Path::new(&file.file_name).exists())
+    program.files.iter().any(|file| {
+        !file.file_name.ends_with(".d.ts") && compiler_host::path_exists(Path::new(&file.file_name))
+    })
 }
 
 pub(super) fn required_helpers(
@@ -1897,6 +1897,7 @@ pub(super) fn create_binder_from_bound_file_with_augmentations(
     // Restore is_external_module from BoundFile to preserve per-file state
     binder.is_external_module = file.is_external_module;
     binder.file_features = file.file_features;
+    binder.commonjs_exports = Arc::clone(&file.commonjs_exports);
     binder.lib_symbol_reverse_remap = file.lib_symbol_reverse_remap.clone();
     // Only the file-local semantic_defs are stored on the reconstructed
     // binder. The cross-file / program-wide entries live in the shared
@@ -2024,6 +2025,7 @@ pub(super) fn create_cross_file_lookup_binder_with_augmentations(
     binder.declared_modules = Default::default();
     binder.is_external_module = file.is_external_module;
     binder.file_features = file.file_features;
+    binder.commonjs_exports = Arc::clone(&file.commonjs_exports);
     binder.lib_symbol_reverse_remap = file.lib_symbol_reverse_remap.clone();
     // See `create_binder_from_bound_file_with_augmentations` for the
     // rationale: the cross-file semantic_defs live in the shared
@@ -15,13 +15,15 @@ use crate::config::{
 };
 use tsz::binder::BinderOptions;
 use tsz::binder::BinderState;
-use tsz::binder::{SymbolId, SymbolTable};
+use tsz::binder::{StableSymbolId, SymbolId, SymbolTable};
 use tsz::checker::TypeCache;
 use tsz::checker::context::LibContext;
 use tsz::checker::diagnostics::{
     Diagnostic, DiagnosticCategory, DiagnosticRelatedInformation, diagnostic_codes,
+    sort_and_deduplicate_diagnostics,
 };
 use tsz::checker::state::CheckerState;
+use tsz::compiler_host;
 use tsz::lib_loader::LibFile;
 use tsz::module_resolver::{ImportKind, ImportingModuleKind, ModuleResolver};
 use tsz::span::Span;
@@ -88,8 +90,13 @@ pub enum FileInclusionReason {
     FilesListEntry,
     /// File matched by include pattern in tsconfig
     IncludePattern(String),
-    /// File imported from another file
-    ImportedFrom(PathBuf),
+    /// File imported by a module specifier in another file
+    ImportedVia { specifier: String, from: PathBuf },
+    /// File pulled in by a `/// <reference path="..." />` directive
+    ReferencedVia { reference: String, from: PathBuf },
+    /// Type package entry pulled in by a `/// <reference types="..." />`
+    /// directive
+    TypeLibraryReferencedVia { name: String, from: PathBuf },
     /// File is a default library for the configured target (e.g.
     /// `lib.es2020.d.ts`). The target string matches tsc's display
     /// (`es2018`, `esnext`, ...).
@@ -102,13 +109,31 @@ pub enum FileInclusionReason {
 impl std::fmt::Display for FileInclusionReason {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::RootFile => write!(f, "Root file specified"),
+            Self::RootFile => write!(f, "Root file specified for compilation"),
             Self::FilesListEntry => write!(f, "Part of 'files' list in tsconfig.json"),
             Self::IncludePattern(pattern) => {
                 write!(f, "Matched by include pattern '{pattern}'")
             }
-            Self::ImportedFrom(path) => {
-                write!(f, "Imported from '{}'", path.display())
+            Self::ImportedVia { specifier, from } => {
+                write!(
+                    f,
+                    "Imported via \"{specifier}\" from file '{}'",
+                    from.display()
+                )
+            }
+            Self::ReferencedVia { reference, from } => {
+                write!(
+                    f,
+                    "Referenced via '{reference}' from file '{}'",
+                    from.display()
+                )
+            }
+            Self::TypeLibraryReferencedVia { name, from } => {
+                write!(
+                    f,
+                    "Type library referenced via '{name}' from file '{}'",
+                    from.display()
+                )
             }
             Self::DefaultLibrary(target) => write!(f, "Default library for target '{target}'"),
             Self::LibFile => write!(f, "Library file"),
@@ -191,6 +216,12 @@ pub struct CompilationResult {
     /// where the option originated, since emit was disabled by configuration
     /// rather than skipped due to errors.
     pub no_emit: bool,
+    /// Emit did not run even though `noEmit` is off: `noEmitOnError` blocked
+    /// it, or compilation stopped before reaching emit. Mirrors tsc's
+    /// `EmitResult.emitSkipped`, which selects
+    /// `DiagnosticsPresent_OutputsSkipped` (1) over
+    /// `DiagnosticsPresent_OutputsGenerated` (2).
+    pub emit_skipped: bool,
     pub request_cache_counters: tsz::checker::context::RequestCacheCounters,
     /// Number of interned types in the shared `TypeInterner` after checking.
     pub interned_types_count: usize,
@@ -273,7 +304,17 @@ pub(crate) struct CompilationCache {
     diagnostics: FxHashMap<PathBuf, Vec<Diagnostic>>,
     export_hashes: FxHashMap<PathBuf, u64>,
     import_symbol_ids: FxHashMap<PathBuf, FxHashMap<PathBuf, Vec<SymbolId>>>,
+    /// Content-stable identity of each `SymbolId` in the program the cached
+    /// type caches were computed against. `SymbolId`s follow merge order, so
+    /// after a rebuild a cached entry is only reused while its id still names
+    /// the same stable symbol.
+    stable_symbol_ids: Arc<FxHashMap<SymbolId, StableSymbolId>>,
     star_export_dependencies: FxHashMap<PathBuf, FxHashSet<PathBuf>>,
+    /// Every file recorded in the `.tsbuildinfo` this cache was restored from.
+    persisted_files: FxHashSet<PathBuf>,
+    /// Restored files whose content still matches the recorded version; their
+    /// persisted diagnostics are reused instead of re-checking them.
+    unchanged_persisted_files: FxHashSet<PathBuf>,
 }
 
 struct BindCacheEntry {
@@ -311,6 +352,11 @@ impl CompilationCache {
             .map(|cache| cache.node_types.len())
     }
 
+    #[cfg(test)]
+    pub(crate) fn stable_symbol_ids_len(&self) -> usize {
+        self.stable_symbol_ids.len()
+    }
+
     #[cfg(test)]
     pub(crate) fn invalidate_paths_with_dependents<I>(&mut self, paths: I)
     where
@@ -335,6 +381,14 @@ impl CompilationCache {
     {
         let changed: FxHashSet<PathBuf> = paths.into_iter().collect();
         let affected = self.collect_dependents(changed.iter().cloned());
+        self.invalidate_affected_symbols(&changed, affected);
+    }
+
+    fn invalidate_affected_symbols(
+        &mut self,
+        changed: &FxHashSet<PathBuf>,
+        affected: FxHashSet<PathBuf>,
+    ) {
         for path in affected {
             if changed.contains(&path) {
                 self.type_caches.remove(&path);
@@ -352,7 +406,7 @@ impl CompilationCache {
 
             let mut roots = Vec::new();
             if let Some(dep_map) = self.import_symbol_ids.get(&path) {
-                for changed_path in &changed {
+                for changed_path in changed {
                     if let Some(symbols) = dep_map.get(changed_path) {
                         roots.extend(symbols.iter().copied());
                     }
@@ -400,6 +454,8 @@ impl CompilationCache {
     }
 
     pub(crate) fn clear(&mut self) {
+        self.persisted_files.clear();
+        self.unchanged_persisted_files.clear();
         self.type_caches.clear();
         self.bind_cache.clear();
         self.dependencies.clear();
@@ -408,9 +464,45 @@ impl CompilationCache {
         self.diagnostics.clear();
         self.export_hashes.clear();
         self.import_symbol_ids.clear();
+        self.stable_symbol_ids = Arc::default();
         self.star_export_dependencies.clear();
     }
 
+    /// Move the cache onto a freshly merged `program`.
+    ///
+    /// Cached symbol types whose `SymbolId` now names a different stable
+    /// symbol (or none) are invalidated along with their dependents, so
+    /// entries keyed by ids that shifted in the new merge are not reused.
+    pub(crate) fn invalidate_shifted_symbols(&mut self, program: &MergedProgram) {
+        let shifted: FxHashSet<SymbolId> = self
+            .stable_symbol_ids
+            .iter()
+            .filter(|&(&sym_id, &stable)| program.stable_symbol_id(sym_id) != Some(stable))
+            .map(|(&sym_id, _)| sym_id)
+            .collect();
+        self.stable_symbol_ids = Arc::clone(&program.stable_symbol_ids);
+        if shifted.is_empty() {
+            return;
+        }
+        for cache in self.type_caches.values_mut() {
+            let mut roots: Vec<SymbolId> = cache
+                .symbol_dependencies
+                .values()
+                .flatten()
+                .copied()
+                .filter(|sym_id| shifted.contains(sym_id))
+                .collect();
+            roots.extend(shifted.iter().copied().filter(|sym_id| {
+                cache.symbol_types.contains_key(sym_id)
+                    || cache.symbol_instance_types.contains_key(sym_id)
+                    || cache.symbol_dependencies.contains_key(sym_id)
+            }));
+            roots.sort_by_key(|sym_id| sym_id.0);
+            roots.dedup();
+            cache.invalidate_symbols(&roots);
+        }
+    }
+
     pub(crate) fn update_dependencies(
         &mut self,
         dependencies: FxHashMap<PathBuf, FxHashSet<PathBuf>>,
@@ -456,6 +548,21 @@ impl CompilationCache {
 
         affected
     }
+
+    /// `paths` plus the files that import them directly.
+    fn collect_direct_dependents<I>(&self, paths: I) -> FxHashSet<PathBuf>
+    where
+        I: IntoIterator<Item = PathBuf>,
+    {
+        let mut affected = FxHashSet::default();
+        for path in paths {
+            if let Some(dependents) = self.reverse_dependencies.get(&path) {
+                affected.extend(dependents.iter().cloned());
+            }
+            affected.insert(path);
+        }
+        affected
+    }
 }
 
 /// Convert `CompilationCache` to `BuildInfo` for persistence
@@ -601,8 +708,9 @@ fn compilation_cache_to_build_info(
         dependencies,
         semantic_diagnostics_per_file,
         emit_signatures,
-        latest_changed_dts_file: None, // TODO: Track most recently changed .d.ts file
+        latest_changed_dts_file: None, // Set by the caller from the emitted files
         options: build_options,
+        options_hash: None,
         build_time: std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .map(|d| d.as_secs())
@@ -612,22 +720,36 @@ fn compilation_cache_to_build_info(
 
 /// Load `BuildInfo` and create an initial `CompilationCache` from it
 fn build_info_to_compilation_cache(build_info: &BuildInfo, base_dir: &Path) -> CompilationCache {
+    use crate::incremental::compute_file_version;
+
     let mut cache = CompilationCache::default();
+    // Program file names are canonical paths; key the restored state the same
+    // way so it lines up with the files of the new program.
+    let resolve = |path_str: &str| canonicalize_or_owned(&base_dir.join(path_str));
 
     // Convert string paths back to PathBuf and populate export_hashes
     for (path_str, file_info) in &build_info.file_infos {
-        let full_path = base_dir.join(path_str);
-
-        // Parse version hash back to u64
-        if let Ok(hash) = u64::from_str_radix(&file_info.version, 16) {
+        let full_path = resolve(path_str);
+        cache.persisted_files.insert(full_path.clone());
+
+        // `signature` is the export signature hash the dependency cascade
+        // compares against; `version` is the content hash of the source.
+        if let Some(hash) = file_info
+            .signature
+            .as_deref()
+            .and_then(|signature| u64::from_str_radix(signature, 16).ok())
+        {
             cache.export_hashes.insert(full_path.clone(), hash);
         }
+        if compute_file_version(&full_path).is_ok_and(|version| version == file_info.version) {
+            cache.unchanged_persisted_files.insert(full_path.clone());
+        }
 
         // Convert dependencies
         if let Some(deps) = build_info.get_dependencies(path_str) {
             let mut dep_paths = FxHashSet::default();
             for dep in deps {
-                let dep_path = base_dir.join(dep);
+                let dep_path = resolve(dep);
                 cache
                     .reverse_dependencies
                     .entry(dep_path.clone())
@@ -641,7 +763,7 @@ fn build_info_to_compilation_cache(build_info: &BuildInfo, base_dir: &Path) -> C
 
     // Load diagnostics from BuildInfo
     for (path_str, cached_diagnostics) in &build_info.semantic_diagnostics_per_file {
-        let full_path = base_dir.join(path_str);
+        let full_path = resolve(path_str);
 
         let diagnostics: Vec<Diagnostic> = cached_diagnostics
             .iter()
@@ -670,6 +792,7 @@ fn build_info_to_compilation_cache(build_info: &BuildInfo, base_dir: &Path) -> C
                         depth: 0,
                     })
                     .collect(),
+                spelling_suggestion: None,
             })
             .collect();
 
@@ -726,6 +849,21 @@ pub fn compile(args: &CliArgs, cwd: &Path) -> Result<CompilationResult> {
     compile_inner(args, cwd, None, None, None, None)
 }
 
+/// Compile through an embedder-supplied `CompilerHost` instead of the real
+/// file system: config and source reads, module resolution probes and emitted
+/// outputs all go to `host`, and relative paths resolve against its current
+/// directory. Default lib files still come from the compiler's own libs.
+pub fn compile_with_host(
+    args: &CliArgs,
+    host: Arc<dyn compiler_host::CompilerHost>,
+) -> Result<CompilationResult> {
+    let cwd = host
+        .current_directory()
+        .context("failed to read the compiler host's current directory")?;
+    let _host = compiler_host::CompilerHostScope::enter(Some(host));
+    compile_inner(args, &cwd, None, None, None, None)
+}
+
 /// Compile a specific project by config path (used for --build mode with project references)
 pub fn compile_project(
     args: &CliArgs,
@@ -762,6 +900,24 @@ pub(crate) fn compile_with_cache_and_changes(
         }
     }
 
+    // A deleted file has no new export signature to compare, and re-resolving
+    // its importers drops the dependency edge that would find them afterwards.
+    // Invalidate its dependents up front so they re-check and report the
+    // missing module.
+    let removed_paths: Vec<PathBuf> = canonical_paths
+        .iter()
+        .filter(|path| !compiler_host::path_exists(path))
+        .cloned()
+        .collect();
+    if !removed_paths.is_empty() {
+        let removed_dependents = if args.assume_changes_only_affect_direct_dependencies {
+            cache.collect_direct_dependents(removed_paths.iter().cloned())
+        } else {
+            cache.collect_dependents(removed_paths.iter().cloned())
+        };
+        cache.invalidate_paths(removed_dependents);
+    }
+
     cache.invalidate_paths(canonical_paths.iter().cloned());
     let mut result = compile_inner(args, cwd, Some(cache), Some(&canonical_paths), None, None)?;
 
@@ -797,13 +953,7 @@ pub(crate) fn compile_with_cache_and_changes(
     // If --assumeChangesOnlyAffectDirectDependencies is set, only recompile direct dependents
     let dependents = if args.assume_changes_only_affect_direct_dependencies {
         // Only get direct dependents (one level deep)
-        let mut direct_dependents = FxHashSet::default();
-        for path in &canonical_paths {
-            if let Some(deps) = cache.reverse_dependencies.get(path) {
-                direct_dependents.extend(deps.iter().cloned());
-            }
-        }
-        direct_dependents
+        cache.collect_direct_dependents(canonical_paths.iter().cloned())
     } else {
         // Get all transitive dependents (default behavior)
         cache.collect_dependents(canonical_paths.iter().cloned())
@@ -817,7 +967,12 @@ pub(crate) fn compile_with_cache_and_changes(
         }
     }
 
-    cache.invalidate_paths_with_dependents_symbols(canonical_paths);
+    // The check above already re-checked the direct importers of every file
+    // whose exports changed. Assuming changes stop there, their diagnostics
+    // stay cached and the pass below only emits them.
+    if !args.assume_changes_only_affect_direct_dependencies {
+        cache.invalidate_paths_with_dependents_symbols(canonical_paths);
+    }
     let mut result = compile_inner(
         args,
         cwd,
@@ -1060,6 +1215,7 @@ fn compile_inner(
             files_read: Vec::new(),
             file_infos: Vec::new(),
             no_emit: args.no_emit,
+            emit_skipped: true,
             request_cache_counters: tsz::checker::context::RequestCacheCounters::default(),
             interned_types_count: 0,
             interner_estimated_bytes: 0,
@@ -1094,6 +1250,7 @@ fn compile_inner(
                     files_read: Vec::new(),
                     file_infos: Vec::new(),
                     no_emit: args.no_emit,
+                    emit_skipped: true,
                     request_cache_counters: tsz::checker::context::RequestCacheCounters::default(),
                     interned_types_count: 0,
                     interner_estimated_bytes: 0,
@@ -1170,6 +1327,7 @@ fn compile_inner(
             files_read: Vec::new(),
             file_infos: Vec::new(),
             no_emit: resolved.no_emit,
+            emit_skipped: true,
             request_cache_counters: tsz::checker::context::RequestCacheCounters::default(),
             interned_types_count: 0,
             interner_estimated_bytes: 0,
@@ -1222,6 +1380,7 @@ fn compile_inner(
             files_read: Vec::new(),
             file_infos: Vec::new(),
             no_emit: resolved.no_emit,
+            emit_skipped: true,
             request_cache_counters: tsz::checker::context::RequestCacheCounters::default(),
             interned_types_count: 0,
             interner_estimated_bytes: 0,
@@ -1241,14 +1400,27 @@ fn compile_inner(
     // Only create when loading from BuildInfo (not when a cache is provided)
     let mut local_cache: Option<CompilationCache> = None;
 
+    // A .tsbuildinfo written under different compiler options is not reused.
+    let options_fingerprint = compiler_options_fingerprint(&resolved);
+
     // Load BuildInfo only when incremental compilation is enabled and no cache was provided.
     // A standalone `tsBuildInfoFile` path does not activate build info reads/writes.
     if cache.is_none() && resolved.incremental {
         let tsconfig_path_ref = tsconfig_path.as_deref();
         if let Some(build_info_path) = get_build_info_path(tsconfig_path_ref, &resolved, &base_dir)
         {
-            if build_info_path.exists() {
+            if compiler_host::file_exists(&build_info_path) {
                 match BuildInfo::load(&build_info_path) {
+                    Ok(Some(build_info))
+                        if build_info.options_hash.as_deref()
+                            != Some(options_fingerprint.as_str()) =>
+                    {
+                        tracing::info!(
+                            "Compiler options changed since {} was written, starting fresh",
+                            build_info_path.display()
+                        );
+                        local_cache = Some(CompilationCache::default());
+                    }
                     Ok(Some(build_info)) => {
                         // Create a local cache from BuildInfo
                         local_cache = Some(build_info_to_compilation_cache(&build_info, &base_dir));
@@ -1299,6 +1471,7 @@ fn compile_inner(
             files_read: Vec::new(),
             file_infos: Vec::new(),
             no_emit: resolved.no_emit,
+            emit_skipped: true,
             request_cache_counters: tsz::checker::context::RequestCacheCounters::default(),
             interned_types_count: 0,
             interner_estimated_bytes: 0,
@@ -1365,6 +1538,8 @@ fn compile_inner(
         module_resolutions,
         type_reference_errors,
         resolution_mode_errors,
+        mut inclusion_reasons,
+        unresolved_imports: _,
     } = {
         read_source_files(
             &file_paths,
@@ -1509,6 +1684,7 @@ fn compile_inner(
     let file_infos = build_file_infos(
         &sources,
         &file_paths,
+        &mut inclusion_reasons,
         args,
         config.as_ref(),
         &base_dir,
@@ -1554,7 +1730,7 @@ fn compile_inner(
         diagnostics.extend(config_diagnostics);
         diagnostics.extend(binary_file_diagnostics);
         diagnostics.extend(type_file_diagnostics);
-        diagnostics.sort_by(|left, right| left.compare(right));
+        sort_and_deduplicate_diagnostics(&mut diagnostics);
 
         return Ok(CompilationResult {
             diagnostics,
@@ -1562,6 +1738,7 @@ fn compile_inner(
             files_read: user_files_read,
             file_infos,
             no_emit: resolved.no_emit,
+            emit_skipped: true,
             request_cache_counters: tsz::checker::context::RequestCacheCounters::default(),
             interned_types_count: 0,
             interner_estimated_bytes: 0,
@@ -1649,7 +1826,7 @@ fn compile_inner(
         diagnostics.extend(config_diagnostics);
         diagnostics.extend(binary_file_diagnostics);
         diagnostics.extend(type_file_diagnostics);
-        diagnostics.sort_by(|left, right| left.compare(right));
+        sort_and_deduplicate_diagnostics(&mut diagnostics);
 
         return Ok(CompilationResult {
             diagnostics,
@@ -1657,6 +1834,7 @@ fn compile_inner(
             files_read: user_files_read,
             file_infos,
             no_emit: resolved.no_emit,
+            emit_skipped: true,
             request_cache_counters: tsz::checker::context::RequestCacheCounters::default(),
             interned_types_count: 0,
             interner_estimated_bytes: 0,
@@ -1747,6 +1925,7 @@ fn compile_inner(
 
     // Update import symbol IDs if we have a cache
     if let Some(ref mut c) = effective_cache {
+        c.invalidate_shifted_symbols(&program);
         update_import_symbol_ids(&program, &resolved, &base_dir, c);
     }
 
@@ -1776,6 +1955,8 @@ fn compile_inner(
             typescript_dom_replacement_globals,
             has_deprecation_diagnostics,
             collect_compile_stats,
+            assume_changes_only_affect_direct_dependencies: args
+                .assume_changes_only_affect_direct_dependencies,
         },
         effective_cache,
         &parallel_type_caches,
@@ -1891,7 +2072,7 @@ fn compile_inner(
         }
     }
 
-    diagnostics.sort_by(|left, right| left.compare(right));
+    sort_and_deduplicate_diagnostics(&mut diagnostics);
 
     let has_error = diagnostics
         .iter()
@@ -1965,8 +2146,9 @@ fn compile_inner(
     // Recompute has_error after emit diagnostics (e.g., TS2883) are added.
     // The initial has_error was computed before emit for should_emit gating.
     normalize_ts2883_diagnostics_in_place(&mut diagnostics);
-    // Re-sort since emit diagnostics were appended after the initial sort.
-    diagnostics.sort_by(|left, right| left.compare(right));
+    // Re-sort (and de-duplicate) since emit diagnostics were appended after
+    // the initial sort.
+    sort_and_deduplicate_diagnostics(&mut diagnostics);
     let has_error = diagnostics
         .iter()
         .any(|diag| diag.category == DiagnosticCategory::Error);
@@ -2007,6 +2189,7 @@ fn compile_inner(
 
             // Set the most recent .d.ts file for cross-project invalidation
             build_info.latest_changed_dts_file = latest_changed_dts_file;
+            build_info.options_hash = Some(options_fingerprint);
 
             if let Err(e) = build_info.save(&build_info_path) {
                 let build_info_path_text = build_info_path.display().to_string();
@@ -2048,6 +2231,7 @@ fn compile_inner(
         files_read,
         file_infos,
         no_emit: resolved.no_emit,
+        emit_skipped: !should_emit,
         request_cache_counters: collected.request_cache_counters,
         interned_types_count: program.type_interner.len(),
         interner_estimated_bytes: program.type_interner.estimated_size_bytes(),
@@ -2251,6 +2435,7 @@ fn config_error_result(file_path: Option<&Path>, message: String, code: u32) ->
         files_read: Vec::new(),
         file_infos: Vec::new(),
         no_emit: false,
+        emit_skipped: true,
         request_cache_counters: tsz::checker::context::RequestCacheCounters::default(),
         interned_types_count: 0,
         interner_estimated_bytes: 0,
@@ -2423,10 +2608,15 @@ fn check_module_resolution_compatibility(
     ))
 }
 
-/// Build file info with inclusion reasons
+/// Build file info with inclusion reasons.
+///
+/// How a file became a root (CLI argument, lib, `files`, `include`) comes
+/// first, followed by every import or reference that discovery recorded for
+/// it in `inclusion_reasons`.
 fn build_file_infos(
     sources: &[SourceEntry],
     root_file_paths: &[PathBuf],
+    inclusion_reasons: &mut FxHashMap<PathBuf, Vec<FileInclusionReason>>,
     args: &CliArgs,
     config: Option<&crate::config::TsConfig>,
     base_dir: &Path,
@@ -2489,10 +2679,7 @@ fn build_file_infos(
                     include_patterns.clone(),
                 ));
             }
-            // Otherwise it was likely imported (we don't track precise imports yet)
-            else {
-                reasons.push(FileInclusionReason::ImportedFrom(PathBuf::from("<import>")));
-            }
+            reasons.extend(inclusion_reasons.remove(&source.path).unwrap_or_default());
 
             FileInfo {
                 path: source.path.clone(),
@@ -2622,7 +2809,7 @@ fn append_unique_lib_paths(
 }
 
 fn typescript_lib_replacement_root_exists(base_dir: &Path) -> bool {
-    base_dir.join("node_modules").join("@typescript").is_dir()
+    compiler_host::directory_exists(&base_dir.join("node_modules").join("@typescript"))
 }
 
 fn collect_source_reference_libs(sources: &[SourceEntry]) -> Vec<String> {
@@ -2635,7 +2822,7 @@ fn collect_source_reference_libs(sources: &[SourceEntry]) -> Vec<String> {
                 Vec::new()
             }
         } else {
-            std::fs::read_to_string(&source.path)
+            compiler_host::read_file(&source.path)
                 .map(|text| {
                     if source_may_contain_reference_lib_directives(&text) {
                         tsz::config::extract_lib_references(&text)
@@ -2681,7 +2868,7 @@ fn collect_source_reference_lib_diagnostics(
                 Vec::new()
             }
         } else {
-            std::fs::read_to_string(&source.path)
+            compiler_host::read_file(&source.path)
                 .map(|text| {
                     if source_may_contain_reference_lib_directives(&text) {
                         tsz::config::extract_lib_references_with_positions(&text)
@@ -2796,7 +2983,7 @@ fn resolve_typescript_lib_replacement_path(base_dir: &Path, lib_name: &str) -> O
         .join("@typescript")
         .join(format!("lib-{root}"))
         .join(relative);
-    candidate.is_file().then_some(candidate)
+    compiler_host::file_exists(&candidate).then_some(candidate)
 }
 
 fn scan_typescript_dom_replacement_globals(lib_paths: &[PathBuf]) -> (bool, bool, bool) {
@@ -2821,7 +3008,7 @@ fn scan_typescript_dom_replacement_globals(lib_paths: &[PathBuf]) -> (bool, bool
 }
 
 fn replacement_file_declares_global(path: &Path, name: &str) -> bool {
-    let Ok(text) = std::fs::read_to_string(path) else {
+    let Ok(text) = compiler_host::read_file(path) else {
         return false;
     };
 
@@ -2947,6 +3134,7 @@ fn build_program_with_cache(
                     switch_clause_to_switch: Default::default(),
                     is_external_module: false, // Default to false for missing files
                     expando_properties: Default::default(),
+                    commonjs_exports: Default::default(),
                     alias_partners: Default::default(),
                     file_features: Default::default(),
                     semantic_defs: Default::default(),
@@ -3081,6 +3269,138 @@ fn update_import_symbol_ids(
     cache.star_export_dependencies = star_export_dependencies;
 }
 
+/// Hash of the resolved compiler options that affect checking, emit or module
+/// resolution, recorded in the `.tsbuildinfo`. Options that only change how
+/// the build runs (`incremental`, `tsBuildInfoFile`, `traceResolution`) and
+/// per-build emit state are left out.
+fn compiler_options_fingerprint(options: &ResolvedCompilerOptions) -> String {
+    let mut hasher = FxHasher::default();
+    let checker = &options.checker;
+    [
+        checker.strict,
+        checker.no_implicit_any,
+        checker.no_implicit_returns,
+        checker.strict_null_checks,
+        checker.strict_function_types,
+        checker.strict_property_initialization,
+        checker.no_implicit_this,
+        checker.use_unknown_in_catch_variables,
+        checker.isolated_modules,
+        checker.no_unchecked_indexed_access,
+        checker.strict_bind_call_apply,
+        checker.exact_optional_property_types,
+        checker.no_lib,
+        checker.no_types_and_symbols,
+        checker.no_property_access_from_index_signature,
+        checker.sound_mode,
+        checker.sound_check_declarations,
+        checker.sound_report_only,
+        checker.sound_pedantic,
+        checker.experimental_decorators,
+        checker.no_unused_locals,
+        checker.no_unused_parameters,
+        checker.always_strict,
+        checker.no_implicit_use_strict,
+        checker.no_unchecked_side_effect_imports,
+        checker.no_implicit_override,
+        checker.downlevel_iteration,
+        checker.suppress_excess_property_errors,
+        checker.suppress_implicit_any_index_errors,
+        checker.verbatim_module_syntax,
+        checker.allow_umd_global_access,
+        checker.preserve_const_enums,
+        checker.strict_builtin_iterator_return,
+        checker.erasable_syntax_only,
+        checker.no_fallthrough_cases_in_switch,
+    ]
+    .hash(&mut hasher);
+    [checker.allow_unreachable_code, checker.allow_unused_labels].hash(&mut hasher);
+    [
+        &checker.jsx_factory,
+        &checker.jsx_fragment_factory,
+        &checker.jsx_import_source,
+    ]
+    .hash(&mut hasher);
+    [
+        checker.target as u32,
+        checker.module as u32,
+        checker.jsx_mode as u32,
+    ]
+    .hash(&mut hasher);
+
+    let printer = &options.printer;
+    [
+        printer.remove_comments,
+        printer.no_emit_helpers,
+        printer.use_define_for_class_fields,
+        printer.legacy_decorators,
+        printer.emit_decorator_metadata,
+        printer.module_detection_force,
+        printer.module_detection_legacy,
+        printer.jsx_preserve_explicit,
+    ]
+    .hash(&mut hasher);
+    [printer.new_line as u32, printer.jsx as u32].hash(&mut hasher);
+
+    [
+        options.resolve_package_json_exports,
+        options.resolve_package_json_imports,
+        options.resolve_json_module,
+        options.allow_arbitrary_extensions,
+        options.allow_importing_ts_extensions,
+        options.rewrite_relative_import_extensions,
+        options.composite,
+        options.emit_declarations,
+        options.emit_declaration_only,
+        options.source_map,
+        options.inline_source_map,
+        options.declaration_map,
+        options.no_emit,
+        options.emit_bom,
+        options.no_emit_on_error,
+        options.no_resolve,
+        options.preserve_symlinks,
+        options.isolated_declarations,
+        options.import_helpers,
+        options.no_check,
+        options.es_module_interop,
+        options.allow_synthetic_default_imports,
+        options.allow_js,
+        options.check_js,
+        options.explicit_check_js_false,
+        options.skip_lib_check,
+        options.skip_default_lib_check,
+        options.strip_internal,
+    ]
+    .hash(&mut hasher);
+    options.jsx.map(|jsx| jsx as u32).hash(&mut hasher);
+    options
+        .module_resolution
+        .map(|kind| kind as u32)
+        .hash(&mut hasher);
+    options.max_node_module_js_depth.hash(&mut hasher);
+    options.lib_files.hash(&mut hasher);
+    options.module_suffixes.hash(&mut hasher);
+    options.custom_conditions.hash(&mut hasher);
+    options.types_versions_compiler_version.hash(&mut hasher);
+    options.types.hash(&mut hasher);
+    options.type_roots.hash(&mut hasher);
+    options.root_dirs.hash(&mut hasher);
+    [
+        &options.base_url,
+        &options.root_dir,
+        &options.out_dir,
+        &options.out_file,
+        &options.declaration_dir,
+    ]
+    .hash(&mut hasher);
+    for mapping in options.paths.iter().flatten() {
+        mapping.pattern.hash(&mut hasher);
+        mapping.targets.hash(&mut hasher);
+    }
+    format!("{:016x}", hasher.finish())
+}
+
 fn hash_text_with_language_version(text: &str, language_version: ScriptTarget) -> u64 {
     let mut hasher = FxHasher::default();
     text.hash(&mut hasher);
@@ -3090,7 +3410,10 @@ fn hash_text_with_language_version(text: &str, language_version: ScriptTarget) -
 
 #[path = "sources.rs"]
 mod sources;
-pub use sources::{FileReadResult, find_tsconfig, read_source_file};
+pub use sources::{
+    FileReadResult, ProgramFileList, UnresolvedImport, find_tsconfig, list_program_files,
+    read_source_file,
+};
 pub(crate) use sources::{
     ResolveTsconfigError, config_base_dir, load_config, load_config_with_diagnostics,
     resolve_tsconfig_path,
@@ -3154,6 +3477,35 @@ mod explain_files_reason_tests {
         );
     }
 
+    #[test]
+    fn import_and_reference_reasons_render_tsc_phrasing() {
+        let from = PathBuf::from("src/main.ts");
+        assert_eq!(
+            FileInclusionReason::ImportedVia {
+                specifier: "./dep".to_string(),
+                from: from.clone(),
+            }
+            .to_string(),
+            "Imported via \"./dep\" from file 'src/main.ts'"
+        );
+        assert_eq!(
+            FileInclusionReason::ReferencedVia {
+                reference: "globals.d.ts".to_string(),
+                from: from.clone(),
+            }
+            .to_string(),
+            "Referenced via 'globals.d.ts' from file 'src/main.ts'"
+        );
+        assert_eq!(
+            FileInclusionReason::TypeLibraryReferencedVia {
+                name: "node".to_string(),
+                from,
+            }
+            .to_string(),
+            "Type library referenced via 'node' from file 'src/main.ts'"
+        );
+    }
+
     /// `is_default_lib_for_target` matches both the `lib.<target>.full.d.ts`
     /// and `lib.<target>.d.ts` shapes that the lib resolver produces.
     #[test]
@@ -7,12 +7,14 @@ use super::resolution::{
     is_declaration_file,
 };
 use crate::config::{JsxEmit, ResolvedCompilerOptions};
+use tsz::compiler_host::{self, CompilerHostScope};
 use tsz::declaration_emitter::DeclarationEmitter;
 use tsz::emitter::Printer;
 use tsz::enums::evaluator::{EnumEvaluator, EnumValue};
 use tsz::parallel::{BoundFile, MergedProgram};
 use tsz_common::common::ModuleKind;
 use tsz_common::diagnostics::DiagnosticCategory;
+use tsz_common::source_map::SourceMapGenerator;
 use tsz_parser::parser::NodeIndex;
 use tsz_parser::parser::node::NodeArena;
 use tsz_parser::parser::syntax_kind_ext;
@@ -20,6 +22,11 @@ use tsz_scanner::SyntaxKind;
 
 mod emit_output_helpers;
 use self::emit_output_helpers::*;
+// Output naming and source-map plumbing shared with tsz-server's `emit-output`.
+pub use self::emit_output_helpers::{
+    append_inline_source_mapping_url, append_source_mapping_url, declaration_map_source_name,
+    declaration_output_path, js_output_path, map_output_info, new_line_str,
+};
 
 #[derive(Debug, Clone)]
 pub(crate) struct OutputFile {
@@ -32,20 +39,6 @@ pub(crate) fn write_outputs(outputs: &[OutputFile], emit_bom: bool) -> Result<Ve
     emit_output_helpers::write_outputs_impl(outputs, emit_bom)
 }
 
-fn type_cache_view(cache: &tsz::checker::TypeCache) -> tsz_emitter::type_cache_view::TypeCacheView {
-    tsz_emitter::type_cache_view::TypeCacheView {
-        node_types: cache.node_types.to_hash_map(),
-        symbol_types: cache.symbol_types.to_hash_map(),
-        def_to_symbol: cache.def_to_symbol.clone(),
-        def_types: cache.def_types.clone(),
-        def_type_params: cache.def_type_params.clone(),
-        boxed_types: cache.boxed_types.clone(),
-        boxed_def_ids: cache.boxed_def_ids.clone(),
-        well_known_symbol_names: cache.well_known_symbol_names.clone(),
-        def_to_name: cache.def_to_name.clone(),
-    }
-}
-
 pub(crate) struct EmitOutputsContext<'a> {
     pub(crate) program: &'a MergedProgram,
     pub(crate) options: &'a ResolvedCompilerOptions,
@@ -126,6 +119,28 @@ fn source_file_has_external_module_syntax(arena: &NodeArena, source_file: NodeIn
     false
 }
 
+/// Everything one source file contributes to the emit, collected per file so
+/// files can be emitted in parallel and merged back in program order.
+#[derive(Default)]
+struct FileEmit {
+    outputs: Vec<OutputFile>,
+    diagnostics: Vec<tsz_common::diagnostics::Diagnostic>,
+    js_bundle_chunks: Vec<JsBundleChunk>,
+    declaration_bundle_chunks: Vec<DeclarationBundleChunk>,
+    declaration_bundle_blocked: bool,
+}
+
+/// State threaded through the files of an `--outFile` bundle in order.
+#[derive(Default)]
+struct BundleEmitState {
+    /// AMD factory-parameter counters accumulated across files in the same
+    /// outFile bundle.  Each file's printer seeds its counter map from here and
+    /// returns updated counters so the next file uses unique names (e.g.
+    /// `m1_1` in the first file, `m1_2` in the second), matching tsc behavior.
+    amd_counters: FxHashMap<String, u32>,
+    seen_duplicate_global_var_types: FxHashMap<String, String>,
+}
+
 pub(crate) fn emit_outputs(
     context: EmitOutputsContext<'_>,
 ) -> Result<(Vec<OutputFile>, Vec<tsz_common::diagnostics::Diagnostic>)> {
@@ -153,18 +168,11 @@ pub(crate) fn emit_outputs(
         }
     });
     let mut js_bundle_chunks: Vec<JsBundleChunk> = Vec::new();
-    // AMD factory-parameter counters accumulated across files in the same
-    // outFile bundle.  Each file's printer seeds its counter map from here and
-    // returns updated counters so the next file uses unique names (e.g.
-    // `m1_1` in the first file, `m1_2` in the second), matching tsc behavior.
-    let mut bundle_amd_counters: rustc_hash::FxHashMap<String, u32> =
-        rustc_hash::FxHashMap::default();
     let duplicate_global_var_names = if declaration_bundle_path.is_some() {
         build_duplicate_global_var_names(context.program)
     } else {
         FxHashSet::default()
     };
-    let mut seen_duplicate_global_var_types: FxHashMap<String, String> = FxHashMap::default();
 
     // Build mapping from arena address to file path for module resolution
     let arena_to_path: rustc_hash::FxHashMap<usize, String> = context
@@ -189,7 +197,7 @@ pub(crate) fn emit_outputs(
     let root_file_paths: FxHashSet<String> = context
         .root_file_paths
         .iter()
-        .map(|path| std::fs::canonicalize(path).unwrap_or_else(|_| path.clone()))
+        .map(|path| compiler_host::canonicalize(path).unwrap_or_else(|_| path.clone()))
         .map(|path| path.to_string_lossy().replace('\\', "/"))
         .collect();
     let file_lookup = build_program_file_lookup(context.program);
@@ -247,508 +255,572 @@ pub(crate) fn emit_outputs(
         })
         .collect();
 
-    for (file_idx, file) in context.program.files.iter().enumerate() {
-        let input_path = PathBuf::from(&file.file_name);
-        if let Some(dirty_paths) = context.dirty_paths
-            && !dirty_paths.contains(&input_path)
-        {
-            continue;
-        }
-
-        // Skip JS input files whose output path would collide with a TS file's
-        // output (tsc's "emit blocked" behavior for --allowJs).
-        let is_js_input = input_path
-            .extension()
-            .and_then(|e| e.to_str())
-            .is_some_and(|ext| matches!(ext, "js" | "jsx" | "mjs" | "cjs"));
-        if is_js_input
-            && let Some(js_path) = js_output_path(
-                context.base_dir,
-                context.root_dir,
-                context.out_dir,
-                context.options.jsx,
-                &input_path,
-            )
-            && ts_output_paths.contains(&js_path)
-        {
-            continue;
-        }
-
-        let skip_js_outfile_node_modules_source =
-            js_bundle_path.is_some() && path_has_node_modules_segment(&input_path);
-        let skip_js_outfile_external_module = js_bundle_path.is_some()
-            && !matches!(
-                context.options.printer.module,
-                ModuleKind::AMD | ModuleKind::System
-            )
-            && source_file_has_external_module_syntax(&file.arena, file.source_file);
-
-        if !context.options.emit_declaration_only
-            && !skip_js_outfile_node_modules_source
-            && !skip_js_outfile_external_module
-            && let Some(js_path) = js_output_path(
-                context.base_dir,
-                context.root_dir,
-                context.out_dir,
-                context.options.jsx,
-                &input_path,
-            )
-        {
-            if js_bundle_path.is_some()
-                && matches!(context.options.printer.module, ModuleKind::None)
-                && context
-                    .outfile_bundle_paths
-                    .is_some_and(|paths| !paths.contains(&input_path))
+    // Without --outFile every file emits independently, so spread the
+    // per-file work across the Rayon pool. Bundles thread AMD counters and
+    // duplicate-global state from one file to the next and stay sequential.
+    let emit_file = |file_idx: usize,
+                     file: &BoundFile,
+                     bundle_state: &mut BundleEmitState|
+     -> Result<FileEmit> {
+        let mut emit = FileEmit::default();
+        'file: {
+            let input_path = PathBuf::from(&file.file_name);
+            if let Some(dirty_paths) = context.dirty_paths
+                && !dirty_paths.contains(&input_path)
             {
-                continue;
+                break 'file;
             }
 
+            // Skip JS input files whose output path would collide with a TS file's
+            // output (tsc's "emit blocked" behavior for --allowJs).
+            let is_js_input = input_path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|ext| matches!(ext, "js" | "jsx" | "mjs" | "cjs"));
             if is_js_input
-                && js_input_skipped_by_node_modules_depth(
+                && let Some(js_path) = js_output_path(
+                    context.base_dir,
+                    context.root_dir,
+                    context.out_dir,
+                    context.options.jsx,
                     &input_path,
-                    context.options.max_node_module_js_depth,
                 )
+                && ts_output_paths.contains(&js_path)
             {
-                let contents = std::fs::read_to_string(&input_path).with_context(|| {
-                    format!("failed to read skipped JS source {}", input_path.display())
-                })?;
-                if js_bundle_path.is_some() {
-                    js_bundle_chunks.push(JsBundleChunk {
-                        path_key: normalized_path_key(&input_path),
-                        referenced_path_keys: js_bundle_reference_path_keys(
-                            &input_path,
-                            context.outfile_bundle_dependencies,
-                        ),
-                        contents,
-                    });
-                } else {
-                    outputs.push(OutputFile {
-                        path: js_path,
-                        contents,
-                        source_path: Some(input_path.clone()),
-                    });
-                }
-                continue;
+                break 'file;
             }
 
-            let mut printer_options = context.options.printer.clone();
-            let mut type_only_nodes = context
-                .type_caches
-                .get(&input_path)
-                .map_or_else(rustc_hash::FxHashSet::default, |cache| {
-                    cache.type_only_nodes.clone()
-                });
-            mark_ambient_global_type_only_export_specifiers(
-                &file.arena,
-                file.source_file,
-                &ambient_global_type_only_names,
-                &mut type_only_nodes,
-            );
-            printer_options.type_only_nodes = std::sync::Arc::new(type_only_nodes);
-            printer_options.type_only_export_equals_modules =
-                type_only_export_equals_modules.clone();
-
-            printer_options.no_lib = context.options.checker.no_lib;
-            printer_options.isolated_modules = context.options.checker.isolated_modules;
-            // Wire JSX options from resolved compiler options to printer
-            if let Some(jsx) = context.options.jsx {
-                printer_options.jsx = config_jsx_to_emitter_jsx(jsx);
-                if matches!(jsx, JsxEmit::Preserve) {
-                    printer_options.jsx_preserve_explicit = true;
-                }
-            }
-            if !context.options.checker.jsx_factory.is_empty() {
-                printer_options.jsx_factory = Some(context.options.checker.jsx_factory.clone());
-            }
-            if !context.options.checker.jsx_fragment_factory.is_empty() {
-                printer_options.jsx_fragment_factory =
-                    Some(context.options.checker.jsx_fragment_factory.clone());
-            }
-            if !context.options.checker.jsx_import_source.is_empty() {
-                printer_options.jsx_import_source =
-                    Some(context.options.checker.jsx_import_source.clone());
-            }
+            let skip_js_outfile_node_modules_source =
+                js_bundle_path.is_some() && path_has_node_modules_segment(&input_path);
+            let skip_js_outfile_external_module = js_bundle_path.is_some()
+                && !matches!(
+                    context.options.printer.module,
+                    ModuleKind::AMD | ModuleKind::System
+                )
+                && source_file_has_external_module_syntax(&file.arena, file.source_file);
 
-            // Per-file module kind resolution.
-            //
-            // tsc's module emit behavior depends on:
-            // 1. The --module setting (global)
-            // 2. The file extension (.cts/.cjs -> CJS, .mts/.mjs -> ESM)
-            // 3. For node modules, the nearest package.json "type" field
-            // 4. The effective moduleDetection mode (determines if all files are modules)
-            //
-            // The config-level module_detection_force is already set correctly:
-            // - true when moduleDetection=force (explicit or tsc default for node modules)
-            // - false when moduleDetection=auto or legacy
-            //
-            // Here we handle per-file overrides.
-            let file_name_lower = input_path
-                .file_name()
-                .and_then(|n| n.to_str())
-                .unwrap_or("")
-                .to_ascii_lowercase();
-            let is_cts_or_cjs =
-                file_name_lower.ends_with(".cts") || file_name_lower.ends_with(".cjs");
-            let is_mts_or_mjs =
-                file_name_lower.ends_with(".mts") || file_name_lower.ends_with(".mjs");
-
-            if printer_options.module.is_node_module() {
-                // For Node16/NodeNext, resolve the per-file module format based on
-                // file extension and nearest package.json "type" field.
-                let mode = implied_resolution_mode_for_file(&input_path, context.base_dir);
-                if mode == "import" {
-                    printer_options.module = ModuleKind::ESNext;
-                    printer_options.resolved_node_module_to_esm = true;
-                } else {
-                    printer_options.module = ModuleKind::CommonJS;
-                    printer_options.resolved_node_module_to_cjs = true;
+            if !context.options.emit_declaration_only
+                && !skip_js_outfile_node_modules_source
+                && !skip_js_outfile_external_module
+                && let Some(js_path) = js_output_path(
+                    context.base_dir,
+                    context.root_dir,
+                    context.out_dir,
+                    context.options.jsx,
+                    &input_path,
+                )
+            {
+                if js_bundle_path.is_some()
+                    && matches!(context.options.printer.module, ModuleKind::None)
+                    && context
+                        .outfile_bundle_paths
+                        .is_some_and(|paths| !paths.contains(&input_path))
+                {
+                    break 'file;
                 }
-                // module_detection_force is already set at config level
-                // (true by default for node modules when moduleDetection not explicit)
-            } else if is_cts_or_cjs {
-                // .cts/.cjs files always emit as CJS regardless of --module setting.
-                // This handles cases like module=esnext with .cts files.
-                let is_cjs_only = file_name_lower.ends_with(".cjs");
-                if !printer_options.module.is_commonjs() {
-                    // For .cjs (JavaScript) files under ESM/preserve module settings,
-                    // tsc emits them as plain CJS passthrough without adding "use strict".
-                    // .cts (TypeScript) files still get "use strict" since they are
-                    // compiled output, not passthrough.
-                    if matches!(printer_options.module, ModuleKind::Preserve) || is_cjs_only {
-                        printer_options.suppress_use_strict = true;
+
+                if is_js_input
+                    && js_input_skipped_by_node_modules_depth(
+                        &input_path,
+                        context.options.max_node_module_js_depth,
+                    )
+                {
+                    let contents = compiler_host::read_file(&input_path).with_context(|| {
+                        format!("failed to read skipped JS source {}", input_path.display())
+                    })?;
+                    if js_bundle_path.is_some() {
+                        emit.js_bundle_chunks.push(JsBundleChunk {
+                            path_key: normalized_path_key(&input_path),
+                            referenced_path_keys: js_bundle_reference_path_keys(
+                                &input_path,
+                                context.outfile_bundle_dependencies,
+                            ),
+                            contents,
+                            source_map: None,
+                        });
+                    } else {
+                        emit.outputs.push(OutputFile {
+                            path: js_path,
+                            contents,
+                            source_path: Some(input_path.clone()),
+                        });
                     }
-                    printer_options.module = ModuleKind::CommonJS;
+                    break 'file;
                 }
-            }
 
-            if is_mts_or_mjs && !printer_options.module.is_es_module() {
-                // .mts/.mjs files always emit as ESM regardless of --module setting.
-                printer_options.module = ModuleKind::ESNext;
-            }
-
-            let is_amd_system_bundle = js_bundle_path.is_some()
-                && matches!(printer_options.module, ModuleKind::AMD | ModuleKind::System);
-            if is_amd_system_bundle {
-                printer_options.bundled_module_name =
-                    bundled_module_name(context.base_dir, context.root_dir, &input_path);
-                printer_options.bundle_module_counters = bundle_amd_counters.clone();
-            }
-
-            // tsc's isFileForcedToBeModuleByFormat: .cjs/.cts/.mjs/.mts files are
-            // always modules in "auto" mode. This applies even when the config-level
-            // module_detection_force is false (e.g., explicit moduleDetection=auto).
-            if !printer_options.module_detection_force && (is_cts_or_cjs || is_mts_or_mjs) {
-                printer_options.module_detection_force = true;
-            }
-            apply_external_const_enum_values(
-                &mut printer_options,
-                context.program,
-                file,
-                &declaration_const_enum_exports,
-            );
-
-            // Run the lowering pass to generate transform directives
-            let module_none_out_file =
-                js_bundle_path.is_some() && matches!(printer_options.module, ModuleKind::None);
-            let mut ctx = tsz::context::emit::EmitContext::with_options(printer_options.clone());
-            // Enable auto-detect module: when module is None and file has imports/exports,
-            // the emitter should switch to CommonJS (matching tsc behavior)
-            ctx.auto_detect_module = true;
-            ctx.module_none_out_file = module_none_out_file;
-            let emit_plan =
-                tsz::lowering::LoweringPass::new(&file.arena, &ctx).run_plan(file.source_file);
-
-            let mut printer =
-                Printer::with_emit_plan_and_options(&file.arena, emit_plan, printer_options);
-            printer.set_auto_detect_module(true);
-            printer.set_module_none_out_file(module_none_out_file);
-            // Always set source text for comment preservation and single-line detection
-            if let Some(source_text) = file
-                .arena
-                .get(file.source_file)
-                .and_then(|node| file.arena.get_source_file(node))
-                .map(|source| source.text.as_ref())
-            {
-                printer.set_source_text(source_text);
-            }
+                let mut printer_options = context.options.printer.clone();
+                let mut type_only_nodes = context
+                    .type_caches
+                    .get(&input_path)
+                    .map_or_else(rustc_hash::FxHashSet::default, |cache| {
+                        cache.type_only_nodes.clone()
+                    });
+                mark_ambient_global_type_only_export_specifiers(
+                    &file.arena,
+                    file.source_file,
+                    &ambient_global_type_only_names,
+                    &mut type_only_nodes,
+                );
+                printer_options.type_only_nodes = std::sync::Arc::new(type_only_nodes);
+                printer_options.type_only_export_equals_modules =
+                    type_only_export_equals_modules.clone();
+
+                printer_options.no_lib = context.options.checker.no_lib;
+                printer_options.isolated_modules = context.options.checker.isolated_modules;
+                // Wire JSX options from resolved compiler options to printer
+                if let Some(jsx) = context.options.jsx {
+                    printer_options.jsx = config_jsx_to_emitter_jsx(jsx);
+                    if matches!(jsx, JsxEmit::Preserve) {
+                        printer_options.jsx_preserve_explicit = true;
+                    }
+                }
+                if !context.options.checker.jsx_factory.is_empty() {
+                    printer_options.jsx_factory = Some(context.options.checker.jsx_factory.clone());
+                }
+                if !context.options.checker.jsx_fragment_factory.is_empty() {
+                    printer_options.jsx_fragment_factory =
+                        Some(context.options.checker.jsx_fragment_factory.clone());
+                }
+                if !context.options.checker.jsx_import_source.is_empty() {
+                    printer_options.jsx_import_source =
+                        Some(context.options.checker.jsx_import_source.clone());
+                }
 
-            let map_info = if context.options.source_map || context.options.inline_source_map {
-                map_output_info(&js_path)
-            } else {
-                None
-            };
+                // Per-file module kind resolution.
+                //
+                // tsc's module emit behavior depends on:
+                // 1. The --module setting (global)
+                // 2. The file extension (.cts/.cjs -> CJS, .mts/.mjs -> ESM)
+                // 3. For node modules, the nearest package.json "type" field
+                // 4. The effective moduleDetection mode (determines if all files are modules)
+                //
+                // The config-level module_detection_force is already set correctly:
+                // - true when moduleDetection=force (explicit or tsc default for node modules)
+                // - false when moduleDetection=auto or legacy
+                //
+                // Here we handle per-file overrides.
+                let file_name_lower = input_path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("")
+                    .to_ascii_lowercase();
+                let is_cts_or_cjs =
+                    file_name_lower.ends_with(".cts") || file_name_lower.ends_with(".cjs");
+                let is_mts_or_mjs =
+                    file_name_lower.ends_with(".mts") || file_name_lower.ends_with(".mjs");
+
+                if printer_options.module.is_node_module() {
+                    // For Node16/NodeNext, resolve the per-file module format based on
+                    // file extension and nearest package.json "type" field.
+                    let mode = implied_resolution_mode_for_file(&input_path, context.base_dir);
+                    if mode == "import" {
+                        printer_options.module = ModuleKind::ESNext;
+                        printer_options.resolved_node_module_to_esm = true;
+                    } else {
+                        printer_options.module = ModuleKind::CommonJS;
+                        printer_options.resolved_node_module_to_cjs = true;
+                    }
+                    // module_detection_force is already set at config level
+                    // (true by default for node modules when moduleDetection not explicit)
+                } else if is_cts_or_cjs {
+                    // .cts/.cjs files always emit as CJS regardless of --module setting.
+                    // This handles cases like module=esnext with .cts files.
+                    let is_cjs_only = file_name_lower.ends_with(".cjs");
+                    if !printer_options.module.is_commonjs() {
+                        // For .cjs (JavaScript) files under ESM/preserve module settings,
+                        // tsc emits them as plain CJS passthrough without adding "use strict".
+                        // .cts (TypeScript) files still get "use strict" since they are
+                        // compiled output, not passthrough.
+                        if matches!(printer_options.module, ModuleKind::Preserve) || is_cjs_only {
+                            printer_options.suppress_use_strict = true;
+                        }
+                        printer_options.module = ModuleKind::CommonJS;
+                    }
+                }
 
-            // Always set source text for formatting decisions (single-line vs multi-line)
-            // This is needed even when source maps are disabled
-            if let Some(source_text) = file
-                .arena
-                .get(file.source_file)
-                .and_then(|node| file.arena.get_source_file(node))
-                .map(|source| source.text.as_ref())
-            {
-                printer.set_source_map_text(source_text);
-            }
+                if is_mts_or_mjs && !printer_options.module.is_es_module() {
+                    // .mts/.mjs files always emit as ESM regardless of --module setting.
+                    printer_options.module = ModuleKind::ESNext;
+                }
 
-            if let Some((_, _, output_name)) = map_info.as_ref() {
-                printer.enable_source_map(output_name, &file.file_name);
-            }
+                let is_amd_system_bundle = js_bundle_path.is_some()
+                    && matches!(printer_options.module, ModuleKind::AMD | ModuleKind::System);
+                if is_amd_system_bundle {
+                    printer_options.bundled_module_name =
+                        bundled_module_name(context.base_dir, context.root_dir, &input_path);
+                    printer_options.bundle_module_counters = bundle_state.amd_counters.clone();
+                }
 
-            printer.emit(file.source_file);
-            // Capture AMD counters before consuming printer output so the next
-            // file in this bundle starts numbering where this file left off.
-            if is_amd_system_bundle {
-                bundle_amd_counters = printer.bundle_module_counters().clone();
-            }
-            let map_json = map_info
-                .as_ref()
-                .and_then(|_| printer.generate_source_map_json());
-            let mut contents = printer.take_output();
-            let mut map_output = None;
-
-            if let Some((map_path, map_name, _)) = map_info
-                && let Some(map_json) = map_json
-            {
-                if context.options.inline_source_map {
-                    append_inline_source_mapping_url(&mut contents, &map_json, new_line);
-                } else {
-                    append_source_mapping_url(&mut contents, &map_name, new_line);
-                    map_output = Some(OutputFile {
-                        path: map_path,
-                        contents: map_json,
-                        source_path: Some(input_path.clone()),
-                    });
+                // tsc's isFileForcedToBeModuleByFormat: .cjs/.cts/.mjs/.mts files are
+                // always modules in "auto" mode. This applies even when the config-level
+                // module_detection_force is false (e.g., explicit moduleDetection=auto).
+                if !printer_options.module_detection_force && (is_cts_or_cjs || is_mts_or_mjs) {
+                    printer_options.module_detection_force = true;
                 }
-            }
+                apply_external_const_enum_values(
+                    &mut printer_options,
+                    context.program,
+                    file,
+                    &declaration_const_enum_exports,
+                );
 
-            // When --outFile is set, collect content for bundling.
-            if js_bundle_path.is_some() {
-                js_bundle_chunks.push(JsBundleChunk {
-                    path_key: normalized_path_key(&input_path),
-                    referenced_path_keys: js_bundle_reference_path_keys(
-                        &input_path,
-                        context.outfile_bundle_dependencies,
-                    ),
-                    contents,
-                });
-            } else {
-                outputs.push(OutputFile {
-                    path: js_path,
-                    contents,
-                    source_path: Some(input_path.clone()),
-                });
-                if let Some(map_output) = map_output {
-                    outputs.push(map_output);
+                // Run the lowering pass to generate transform directives
+                let module_none_out_file =
+                    js_bundle_path.is_some() && matches!(printer_options.module, ModuleKind::None);
+                let mut ctx =
+                    tsz::context::emit::EmitContext::with_options(printer_options.clone());
+                // Enable auto-detect module: when module is None and file has imports/exports,
+                // the emitter should switch to CommonJS (matching tsc behavior)
+                ctx.auto_detect_module = true;
+                ctx.module_none_out_file = module_none_out_file;
+                let emit_plan =
+                    tsz::lowering::LoweringPass::new(&file.arena, &ctx).run_plan(file.source_file);
+
+                let mut printer =
+                    Printer::with_emit_plan_and_options(&file.arena, emit_plan, printer_options);
+                printer.set_auto_detect_module(true);
+                printer.set_module_none_out_file(module_none_out_file);
+                // Always set source text for comment preservation and single-line detection
+                if let Some(source_text) = file
+                    .arena
+                    .get(file.source_file)
+                    .and_then(|node| file.arena.get_source_file(node))
+                    .map(|source| source.text.as_ref())
+                {
+                    printer.set_source_text(source_text);
                 }
-            }
-        }
 
-        if context.options.emit_declarations {
-            let decl_base = context.declaration_dir.or(context.out_dir);
-            if let Some(dts_path) =
-                declaration_output_path(context.base_dir, context.root_dir, decl_base, &input_path)
-            {
-                // Get type cache for this file if available
-                let file_path = PathBuf::from(&file.file_name);
-                let type_cache = context.type_caches.get(&file_path).cloned();
-
-                // Reconstruct BinderState for this file to enable usage analysis
-                let binder =
-                    tsz::parallel::create_binder_from_bound_file(file, context.program, file_idx);
-
-                // Create emitter with type information and binder
-                let mut emitter = if let Some(ref cache) = type_cache {
-                    let cache_view = type_cache_view(cache);
-                    let mut emitter = DeclarationEmitter::with_type_info(
-                        &file.arena,
-                        cache_view,
-                        &context.program.type_interner,
-                        &binder,
-                    );
-                    // Set current arena and file path for foreign symbol tracking
-                    emitter.set_current_arena(
-                        std::sync::Arc::clone(&file.arena),
-                        file.file_name.clone(),
-                    );
-                    // Set arena to path mapping for module resolution
-                    emitter.set_arena_to_path(arena_to_path.clone());
-                    emitter.set_file_idx_to_path(file_idx_to_path.clone());
-                    emitter.set_root_file_paths(root_file_paths.clone());
-                    emitter.set_global_symbol_arenas(global_symbol_arenas.clone());
-                    emitter.set_remove_comments(context.options.printer.remove_comments);
-                    emitter.set_strip_internal(context.options.strip_internal);
-                    emitter.set_strict_null_checks(context.options.checker.strict_null_checks);
-                    emitter
-                        .set_isolated_declarations(context.options.checker.isolated_declarations);
-                    emitter.set_files_with_augmentations(files_with_augmentations.clone());
-                    emitter
+                // `--outFile` chunks map into the bundle, so their maps are named
+                // after it and list sources relative to the bundle map.
+                let map_info = if context.options.source_map || context.options.inline_source_map {
+                    map_output_info(js_bundle_path.as_deref().unwrap_or(&js_path))
                 } else {
-                    let mut emitter = DeclarationEmitter::new(&file.arena);
-                    // Still set binder and current file context without cache for
-                    // declaration paths that consult program-level export facts.
-                    emitter.set_binder(Some(&binder));
-                    emitter.set_current_arena(
-                        std::sync::Arc::clone(&file.arena),
-                        file.file_name.clone(),
-                    );
-                    emitter.set_arena_to_path(arena_to_path.clone());
-                    emitter.set_file_idx_to_path(file_idx_to_path.clone());
-                    emitter.set_root_file_paths(root_file_paths.clone());
-                    emitter.set_global_symbol_arenas(global_symbol_arenas.clone());
-                    emitter.set_remove_comments(context.options.printer.remove_comments);
-                    emitter.set_strip_internal(context.options.strip_internal);
-                    emitter.set_strict_null_checks(context.options.checker.strict_null_checks);
-                    emitter
-                        .set_isolated_declarations(context.options.checker.isolated_declarations);
-                    emitter.set_files_with_augmentations(files_with_augmentations.clone());
-                    emitter
+                    None
                 };
 
-                // NOTE: tsc still emits TS2883 for non-portable inferred type
-                // references even in node16/nodenext mode. The exports map blocks
-                // direct imports (TS2307) but doesn't suppress the portability
-                // check on inferred types in declaration emit.
-
-                // Precompute the export surface summary for this file.
-                // This seeds the overload pre-scan so the emitter doesn't
-                // need to discover overloads incrementally during the walk.
-                let surface = tsz_binder::ExportSurface::from_binder(
-                    &binder,
-                    &file.arena,
-                    &file.file_name,
-                    file.source_file,
-                );
-                emitter.set_export_surface(surface);
-                if !duplicate_global_var_names.is_empty() {
-                    emitter.set_bundled_duplicate_global_var_types(
-                        bundled_duplicate_global_var_types_for_file(
-                            file,
-                            &duplicate_global_var_names,
-                            &seen_duplicate_global_var_types,
-                        ),
-                    );
+                // Always set source text for formatting decisions (single-line vs multi-line)
+                // This is needed even when source maps are disabled
+                if let Some(source_text) = file
+                    .arena
+                    .get(file.source_file)
+                    .and_then(|node| file.arena.get_source_file(node))
+                    .map(|source| source.text.as_ref())
+                {
+                    printer.set_source_map_text(source_text);
                 }
-                let map_info =
-                    if declaration_bundle_path.is_none() && context.options.declaration_map {
-                        map_output_info(&dts_path)
-                    } else {
-                        None
-                    };
 
                 if let Some((map_path, _, output_name)) = map_info.as_ref() {
-                    if let Some(source_text) = file
-                        .arena
-                        .get(file.source_file)
-                        .and_then(|node| file.arena.get_source_file(node))
-                        .map(|source| source.text.as_ref())
+                    if js_bundle_path.is_some() {
+                        printer.enable_source_map(
+                            output_name,
+                            &declaration_map_source_name(map_path, &input_path),
+                        );
+                    } else {
+                        printer.enable_source_map(output_name, &file.file_name);
+                    }
+                }
+
+                printer.emit(file.source_file);
+                // Capture AMD counters before consuming printer output so the next
+                // file in this bundle starts numbering where this file left off.
+                if is_amd_system_bundle {
+                    bundle_state.amd_counters = printer.bundle_module_counters().clone();
+                }
+                let source_map = map_info.as_ref().and_then(|_| printer.take_source_map());
+                let mut contents = printer.take_output();
+
+                // When --outFile is set, collect content (and its map) for bundling.
+                if js_bundle_path.is_some() {
+                    emit.js_bundle_chunks.push(JsBundleChunk {
+                        path_key: normalized_path_key(&input_path),
+                        referenced_path_keys: js_bundle_reference_path_keys(
+                            &input_path,
+                            context.outfile_bundle_dependencies,
+                        ),
+                        contents,
+                        source_map,
+                    });
+                } else {
+                    let mut map_output = None;
+                    if let Some((map_path, map_name, _)) = map_info
+                        && let Some(mut source_map) = source_map
                     {
-                        emitter.set_source_map_text(source_text);
+                        let map_json = source_map.generate_json();
+                        if context.options.inline_source_map {
+                            append_inline_source_mapping_url(&mut contents, &map_json, new_line);
+                        } else {
+                            append_source_mapping_url(&mut contents, &map_name, new_line);
+                            map_output = Some(OutputFile {
+                                path: map_path,
+                                contents: map_json,
+                                source_path: Some(input_path.clone()),
+                            });
+                        }
+                    }
+                    emit.outputs.push(OutputFile {
+                        path: js_path,
+                        contents,
+                        source_path: Some(input_path.clone()),
+                    });
+                    if let Some(map_output) = map_output {
+                        emit.outputs.push(map_output);
                     }
-                    let source_name = declaration_map_source_name(map_path, &input_path);
-                    emitter.enable_source_map_without_sources_content(output_name, &source_name);
                 }
+            }
+
+            if context.options.emit_declarations {
+                let decl_base = context.declaration_dir.or(context.out_dir);
+                if let Some(dts_path) = declaration_output_path(
+                    context.base_dir,
+                    context.root_dir,
+                    decl_base,
+                    &input_path,
+                ) {
+                    // Get type cache for this file if available
+                    let file_path = PathBuf::from(&file.file_name);
+                    let type_cache = context.type_caches.get(&file_path).cloned();
+
+                    // Reconstruct BinderState for this file to enable usage analysis
+                    let binder = tsz::parallel::create_binder_from_bound_file(
+                        file,
+                        context.program,
+                        file_idx,
+                    );
 
-                // Run usage analysis and calculate required imports if we have type cache
-                if let Some(ref cache) = type_cache {
-                    use rustc_hash::FxHashMap;
-                    use tsz::declaration_emitter::usage_analyzer::{
-                        UsageAnalyzer, UsageAnalyzerSourceFlags,
+                    // Create emitter with type information and binder
+                    let mut emitter = if let Some(ref cache) = type_cache {
+                        let cache_view = tsz::parallel::type_cache_view(cache);
+                        let mut emitter = DeclarationEmitter::with_type_info(
+                            &file.arena,
+                            cache_view,
+                            &context.program.type_interner,
+                            &binder,
+                        );
+                        // Set current arena and file path for foreign symbol tracking
+                        emitter.set_current_arena(
+                            std::sync::Arc::clone(&file.arena),
+                            file.file_name.clone(),
+                        );
+                        // Set arena to path mapping for module resolution
+                        emitter.set_arena_to_path(arena_to_path.clone());
+                        emitter.set_file_idx_to_path(file_idx_to_path.clone());
+                        emitter.set_root_file_paths(root_file_paths.clone());
+                        emitter.set_global_symbol_arenas(global_symbol_arenas.clone());
+                        emitter.set_remove_comments(context.options.printer.remove_comments);
+                        emitter.set_strip_internal(context.options.strip_internal);
+                        emitter.set_strict_null_checks(context.options.checker.strict_null_checks);
+                        emitter.set_isolated_declarations(
+                            context.options.checker.isolated_declarations,
+                        );
+                        emitter.set_files_with_augmentations(files_with_augmentations.clone());
+                        emitter
+                    } else {
+                        let mut emitter = DeclarationEmitter::new(&file.arena);
+                        // Still set binder and current file context without cache for
+                        // declaration paths that consult program-level export facts.
+                        emitter.set_binder(Some(&binder));
+                        emitter.set_current_arena(
+                            std::sync::Arc::clone(&file.arena),
+                            file.file_name.clone(),
+                        );
+                        emitter.set_arena_to_path(arena_to_path.clone());
+                        emitter.set_file_idx_to_path(file_idx_to_path.clone());
+                        emitter.set_root_file_paths(root_file_paths.clone());
+                        emitter.set_global_symbol_arenas(global_symbol_arenas.clone());
+                        emitter.set_remove_comments(context.options.printer.remove_comments);
+                        emitter.set_strip_internal(context.options.strip_internal);
+                        emitter.set_strict_null_checks(context.options.checker.strict_null_checks);
+                        emitter.set_isolated_declarations(
+                            context.options.checker.isolated_declarations,
+                        );
+                        emitter.set_files_with_augmentations(files_with_augmentations.clone());
+                        emitter
                     };
 
-                    // Empty import_name_map for this usage (not needed for auto-import calculation)
-                    let import_name_map = FxHashMap::default();
-                    let cache_view = type_cache_view(cache);
+                    // NOTE: tsc still emits TS2883 for non-portable inferred type
+                    // references even in node16/nodenext mode. The exports map blocks
+                    // direct imports (TS2307) but doesn't suppress the portability
+                    // check on inferred types in declaration emit.
 
-                    let mut analyzer = UsageAnalyzer::new(
-                        &file.arena,
+                    // Precompute the export surface summary for this file.
+                    // This seeds the overload pre-scan so the emitter doesn't
+                    // need to discover overloads incrementally during the walk.
+                    let surface = tsz_binder::ExportSurface::from_binder(
                         &binder,
-                        &cache_view,
-                        &context.program.type_interner,
-                        std::sync::Arc::clone(&file.arena),
-                        Some(file.file_name.clone()),
-                        &import_name_map,
-                        UsageAnalyzerSourceFlags {
-                            source_is_js_file: is_js_input,
-                            source_is_declaration_file: file
-                                .arena
-                                .get(file.source_file)
-                                .and_then(|node| file.arena.get_source_file(node))
-                                .is_some_and(|source_file| source_file.is_declaration_file),
-                        },
+                        &file.arena,
+                        &file.file_name,
+                        file.source_file,
                     );
+                    emitter.set_export_surface(surface);
+                    if !duplicate_global_var_names.is_empty() {
+                        emitter.set_bundled_duplicate_global_var_types(
+                            bundled_duplicate_global_var_types_for_file(
+                                file,
+                                &duplicate_global_var_names,
+                                &bundle_state.seen_duplicate_global_var_types,
+                            ),
+                        );
+                    }
+                    let map_info =
+                        if declaration_bundle_path.is_none() && context.options.declaration_map {
+                            map_output_info(&dts_path)
+                        } else {
+                            None
+                        };
+
+                    if let Some((map_path, _, output_name)) = map_info.as_ref() {
+                        if let Some(source_text) = file
+                            .arena
+                            .get(file.source_file)
+                            .and_then(|node| file.arena.get_source_file(node))
+                            .map(|source| source.text.as_ref())
+                        {
+                            emitter.set_source_map_text(source_text);
+                        }
+                        let source_name = declaration_map_source_name(map_path, &input_path);
+                        emitter
+                            .enable_source_map_without_sources_content(output_name, &source_name);
+                    }
 
-                    // Clone used_symbols before calling another method on analyzer
-                    let used_symbols = analyzer.analyze(file.source_file).clone();
-                    let foreign_symbols = analyzer.get_foreign_symbols().clone();
+                    // Run usage analysis and calculate required imports if we have type cache
+                    if let Some(ref cache) = type_cache {
+                        use rustc_hash::FxHashMap;
+                        use tsz::declaration_emitter::usage_analyzer::{
+                            UsageAnalyzer, UsageAnalyzerSourceFlags,
+                        };
 
-                    // Set used symbols and foreign symbols on emitter
-                    emitter.set_used_symbols(used_symbols);
-                    emitter.set_foreign_symbols(foreign_symbols);
-                }
+                        // Empty import_name_map for this usage (not needed for auto-import calculation)
+                        let import_name_map = FxHashMap::default();
+                        let cache_view = tsz::parallel::type_cache_view(cache);
 
-                let mut contents = emitter.emit(file.source_file);
-                record_seen_duplicate_global_var_types(
-                    file,
-                    &duplicate_global_var_names,
-                    &mut seen_duplicate_global_var_types,
-                );
-                let emitter_diagnostics = normalize_ts2883_diagnostics(emitter.take_diagnostics());
-                let declaration_emit_blocked = emitter_diagnostics
-                    .iter()
-                    .any(|diagnostic| diagnostic.category == DiagnosticCategory::Error);
-                emit_diagnostics.extend(emitter_diagnostics);
-                if declaration_emit_blocked {
-                    declaration_bundle_blocked = true;
-                    continue;
-                }
-                let map_json = map_info
-                    .as_ref()
-                    .and_then(|_| emitter.generate_source_map_json());
-                let mut map_output = None;
+                        let mut analyzer = UsageAnalyzer::new(
+                            &file.arena,
+                            &binder,
+                            &cache_view,
+                            &context.program.type_interner,
+                            std::sync::Arc::clone(&file.arena),
+                            Some(file.file_name.clone()),
+                            &import_name_map,
+                            UsageAnalyzerSourceFlags {
+                                source_is_js_file: is_js_input,
+                                source_is_declaration_file: file
+                                    .arena
+                                    .get(file.source_file)
+                                    .and_then(|node| file.arena.get_source_file(node))
+                                    .is_some_and(|source_file| source_file.is_declaration_file),
+                            },
+                        );
+
+                        // Clone used_symbols before calling another method on analyzer
+                        let used_symbols = analyzer.analyze(file.source_file).clone();
+                        let foreign_symbols = analyzer.get_foreign_symbols().clone();
+
+                        // Set used symbols and foreign symbols on emitter
+                        emitter.set_used_symbols(used_symbols);
+                        emitter.set_foreign_symbols(foreign_symbols);
+                    }
 
-                if let Some((map_path, map_name, _)) = map_info
-                    && let Some(map_json) = map_json
-                {
-                    append_source_mapping_url(&mut contents, &map_name, new_line);
-                    map_output = Some(OutputFile {
-                        path: map_path,
-                        contents: map_json,
-                        source_path: Some(input_path.clone()),
-                    });
-                }
+                    let mut contents = emitter.emit(file.source_file);
+                    record_seen_duplicate_global_var_types(
+                        file,
+                        &duplicate_global_var_names,
+                        &mut bundle_state.seen_duplicate_global_var_types,
+                    );
+                    let emitter_diagnostics =
+                        normalize_ts2883_diagnostics(emitter.take_diagnostics());
+                    let declaration_emit_blocked = emitter_diagnostics
+                        .iter()
+                        .any(|diagnostic| diagnostic.category == DiagnosticCategory::Error);
+                    emit.diagnostics.extend(emitter_diagnostics);
+                    if declaration_emit_blocked {
+                        emit.declaration_bundle_blocked = true;
+                        break 'file;
+                    }
+                    let map_json = map_info
+                        .as_ref()
+                        .and_then(|_| emitter.generate_source_map_json());
+                    let mut map_output = None;
 
-                if declaration_bundle_path.is_some() {
-                    let declaration_module_name = file
-                        .is_external_module
-                        .then(|| {
-                            bundled_module_name(context.base_dir, context.root_dir, &input_path)
-                        })
-                        .flatten();
-                    declaration_bundle_chunks.push(DeclarationBundleChunk {
-                        path_key: normalized_file_key(&file.file_name),
-                        referenced_path_keys: declaration_bundle_reference_path_keys(
-                            &file.file_name,
-                            &file.arena,
-                            file.source_file,
-                            &file_lookup,
-                        ),
-                        contents: bundle_declaration_output(
-                            &contents,
-                            context.options.printer.module,
-                            declaration_module_name.as_deref(),
-                        ),
-                    });
-                } else {
-                    outputs.push(OutputFile {
-                        path: dts_path,
-                        contents,
-                        source_path: Some(input_path.clone()),
-                    });
-                    if let Some(map_output) = map_output {
-                        outputs.push(map_output);
+                    if let Some((map_path, map_name, _)) = map_info
+                        && let Some(map_json) = map_json
+                    {
+                        append_source_mapping_url(&mut contents, &map_name, new_line);
+                        map_output = Some(OutputFile {
+                            path: map_path,
+                            contents: map_json,
+                            source_path: Some(input_path.clone()),
+                        });
+                    }
+
+                    if declaration_bundle_path.is_some() {
+                        let declaration_module_name = file
+                            .is_external_module
+                            .then(|| {
+                                bundled_module_name(context.base_dir, context.root_dir, &input_path)
+                            })
+                            .flatten();
+                        emit.declaration_bundle_chunks.push(DeclarationBundleChunk {
+                            path_key: normalized_file_key(&file.file_name),
+                            referenced_path_keys: declaration_bundle_reference_path_keys(
+                                &file.file_name,
+                                &file.arena,
+                                file.source_file,
+                                &file_lookup,
+                            ),
+                            contents: bundle_declaration_output(
+                                &contents,
+                                context.options.printer.module,
+                                declaration_module_name.as_deref(),
+                            ),
+                        });
+                    } else {
+                        emit.outputs.push(OutputFile {
+                            path: dts_path,
+                            contents,
+                            source_path: Some(input_path.clone()),
+                        });
+                        if let Some(map_output) = map_output {
+                            emit.outputs.push(map_output);
+                        }
                     }
                 }
             }
         }
+        Ok(emit)
+    };
+    let file_emits: Vec<FileEmit> = if context.options.out_file.is_none() {
+        use rayon::prelude::*;
+        #[cfg(not(target_arch = "wasm32"))]
+        tsz::parallel::ensure_rayon_global_pool();
+        let host = compiler_host::current_compiler_host();
+        context
+            .program
+            .files
+            .par_iter()
+            .enumerate()
+            .map(|(file_idx, file)| {
+                let _host = CompilerHostScope::enter(host.clone());
+                emit_file(file_idx, file, &mut BundleEmitState::default())
+            })
+            .collect::<Result<_>>()?
+    } else {
+        let mut bundle_state = BundleEmitState::default();
+        context
+            .program
+            .files
+            .iter()
+            .enumerate()
+            .map(|(file_idx, file)| emit_file(file_idx, file, &mut bundle_state))
+            .collect::<Result<_>>()?
+    };
+    // Merge in program file order so output is deterministic regardless of
+    // which worker finished first.
+    for emit in file_emits {
+        outputs.extend(emit.outputs);
+        emit_diagnostics.extend(emit.diagnostics);
+        js_bundle_chunks.extend(emit.js_bundle_chunks);
+        declaration_bundle_chunks.extend(emit.declaration_bundle_chunks);
+        declaration_bundle_blocked |= emit.declaration_bundle_blocked;
     }
 
     // Emit bundled JS output when --outFile is set
@@ -756,30 +828,47 @@ pub(crate) fn emit_outputs(
         && let Some(bundle_path) = js_bundle_path
         && !js_bundle_chunks.is_empty()
     {
+        let map_info = if context.options.source_map || context.options.inline_source_map {
+            map_output_info(&bundle_path)
+        } else {
+            None
+        };
+        let mut bundle_map = map_info
+            .as_ref()
+            .map(|(_, _, output_name)| SourceMapGenerator::new(output_name.clone()));
         let mut bundled = String::new();
+        let mut bundled_lines = 0u32;
         for (i, chunk) in js_bundle_chunk_order(&js_bundle_chunks)
             .into_iter()
             .filter_map(|idx| js_bundle_chunks.get(idx))
             .enumerate()
         {
             let mut trimmed = chunk.contents.trim_end_matches(['\r', '\n']);
+            let mut skipped_lines = 0;
             // Strip duplicate "use strict" directives from non-first files.
             // In bundled output, only the first file's prologue is kept.
             if i > 0 {
                 if let Some(rest) = trimmed.strip_prefix("\"use strict\";\n") {
                     trimmed = rest;
+                    skipped_lines = 1;
                 } else if let Some(rest) = trimmed.strip_prefix("\"use strict\";\r\n") {
                     trimmed = rest;
+                    skipped_lines = 1;
                 }
             }
             if trimmed.is_empty() {
                 continue;
             }
-            if !bundled.is_empty() && !bundled.ends_with(new_line) {
-                bundled.push_str(new_line);
+            if let Some(bundle_map) = bundle_map.as_mut()
+                && let Some(chunk_map) = chunk.source_map.as_ref()
+            {
+                bundle_map.append(chunk_map, bundled_lines, skipped_lines);
             }
             bundled.push_str(trimmed);
             bundled.push_str(new_line);
+            let chunk_lines = trimmed.bytes().filter(|&byte| byte == b'\n').count() + 1;
+            bundled_lines =
+                bundled_lines.saturating_add(u32::try_from(chunk_lines).unwrap_or(u32::MAX));
         }
         // Remove trailing newline to match tsc behavior
         if bundled.ends_with(new_line) {
@@ -798,7 +887,30 @@ pub(crate) fn emit_outputs(
                 !(trimmed.starts_with("define(") || trimmed.starts_with("System.register("))
             });
             if any_script_chunk {
+                let unprefixed_len = bundled.len();
                 prepend_use_strict_to_bundle(&mut bundled, new_line);
+                if bundled.len() != unprefixed_len
+                    && let Some(bundle_map) = bundle_map.as_mut()
+                {
+                    let directive_line = u32::from(bundled.starts_with("#!"));
+                    bundle_map.shift_generated_lines(directive_line, 1);
+                }
+            }
+        }
+        let mut map_output = None;
+        if let Some((map_path, map_name, _)) = map_info
+            && let Some(mut bundle_map) = bundle_map
+        {
+            let map_json = bundle_map.generate_json();
+            if context.options.inline_source_map {
+                append_inline_source_mapping_url(&mut bundled, &map_json, new_line);
+            } else {
+                append_source_mapping_url(&mut bundled, &map_name, new_line);
+                map_output = Some(OutputFile {
+                    path: map_path,
+                    contents: map_json,
+                    source_path: None,
+                });
             }
         }
         outputs.push(OutputFile {
@@ -806,6 +918,9 @@ pub(crate) fn emit_outputs(
             contents: bundled,
             source_path: None,
         });
+        if let Some(map_output) = map_output {
+            outputs.push(map_output);
+        }
     }
 
     if let Some(bundle_path) = declaration_bundle_path
@@ -1788,7 +1903,7 @@ pub(crate) fn normalize_type_roots(
         // Match tsc: absolute typeRoots paths are used as-is.
         // If the path doesn't exist on disk, it's simply skipped (no fallback).
         let resolved = canonicalize_or_owned(&resolved);
-        if resolved.is_dir() {
+        if compiler_host::directory_exists(&resolved) {
             normalized.push(resolved);
         }
     }
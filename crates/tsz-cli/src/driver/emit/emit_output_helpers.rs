@@ -6,10 +6,12 @@ use std::path::{Path, PathBuf};
 use super::OutputFile;
 use crate::config::JsxEmit;
 use crate::driver::resolution::{is_declaration_file, normalize_path};
+use tsz::compiler_host::{self, CompilerHostScope};
 use tsz::emitter::NewLineKind;
 use tsz::parallel::MergedProgram;
 use tsz_common::common::ModuleKind;
 use tsz_common::diagnostics::Diagnostic;
+use tsz_common::source_map::SourceMapGenerator;
 use tsz_parser::parser::NodeIndex;
 use tsz_parser::parser::node::NodeArena;
 
@@ -25,6 +27,8 @@ pub(super) struct JsBundleChunk {
     pub(super) path_key: String,
     pub(super) referenced_path_keys: Vec<String>,
     pub(super) contents: String,
+    /// Source map for `contents`, merged into the bundle map.
+    pub(super) source_map: Option<SourceMapGenerator>,
 }
 
 pub(super) fn build_program_file_lookup(program: &MergedProgram) -> FxHashMap<String, String> {
@@ -255,14 +259,14 @@ pub(super) fn looks_like_module_path(text: &str) -> bool {
         || text.contains("node_modules")
 }
 
-pub(super) fn map_output_info(output_path: &Path) -> Option<(PathBuf, String, String)> {
+pub fn map_output_info(output_path: &Path) -> Option<(PathBuf, String, String)> {
     let output_name = output_path.file_name()?.to_string_lossy().into_owned();
     let map_name = format!("{output_name}.map");
     let map_path = output_path.with_file_name(&map_name);
     Some((map_path, map_name, output_name))
 }
 
-pub(super) fn declaration_map_source_name(map_path: &Path, source_path: &Path) -> String {
+pub fn declaration_map_source_name(map_path: &Path, source_path: &Path) -> String {
     let map_dir = map_path.parent().unwrap_or_else(|| Path::new(""));
     relative_path_from_dir(map_dir, source_path)
         .unwrap_or_else(|| source_path.to_path_buf())
@@ -307,7 +311,7 @@ pub(super) fn normalized_path_components(path: &Path) -> Vec<std::ffi::OsString>
         .collect()
 }
 
-pub(super) fn append_source_mapping_url(contents: &mut String, map_name: &str, new_line: &str) {
+pub fn append_source_mapping_url(contents: &mut String, map_name: &str, new_line: &str) {
     if !contents.is_empty() && !contents.ends_with(new_line) {
         contents.push_str(new_line);
     }
@@ -315,11 +319,7 @@ pub(super) fn append_source_mapping_url(contents: &mut String, map_name: &str, n
     contents.push_str(map_name);
 }
 
-pub(super) fn append_inline_source_mapping_url(
-    contents: &mut String,
-    map_json: &str,
-    new_line: &str,
-) {
+pub fn append_inline_source_mapping_url(contents: &mut String, map_json: &str, new_line: &str) {
     if !contents.is_empty() && !contents.ends_with(new_line) {
         contents.push_str(new_line);
     }
@@ -353,7 +353,7 @@ pub(super) fn base64_encode(bytes: &[u8]) -> String {
     encoded
 }
 
-pub(super) const fn new_line_str(kind: NewLineKind) -> &'static str {
+pub const fn new_line_str(kind: NewLineKind) -> &'static str {
     match kind {
         NewLineKind::LineFeed => "\n",
         NewLineKind::CarriageReturnLineFeed => "\r\n",
@@ -361,25 +361,24 @@ pub(super) const fn new_line_str(kind: NewLineKind) -> &'static str {
 }
 
 pub(super) fn write_outputs_impl(outputs: &[OutputFile], emit_bom: bool) -> Result<Vec<PathBuf>> {
+    // Rayon workers don't inherit the caller's thread-local host, so each
+    // write re-enters its scope.
+    let host = compiler_host::current_compiler_host();
     outputs.par_iter().try_for_each(|output| -> Result<()> {
-        if let Some(parent) = output.path.parent() {
-            std::fs::create_dir_all::<&Path>(parent)
-                .with_context(|| format!("failed to create directory {}", parent.display()))?;
-        }
+        let _host = CompilerHostScope::enter(host.clone());
         let contents = if emit_bom && !output.contents.starts_with('\u{feff}') {
             format!("\u{feff}{}", output.contents)
         } else {
             output.contents.clone()
         };
-        std::fs::write(&output.path, contents)
-            .with_context(|| format!("failed to write {}", output.path.display()))?;
-        Ok(())
+        compiler_host::write_file(&output.path, &contents)
+            .with_context(|| format!("failed to write {}", output.path.display()))
     })?;
 
     Ok(outputs.iter().map(|output| output.path.clone()).collect())
 }
 
-pub(super) fn js_output_path(
+pub fn js_output_path(
     base_dir: &Path,
     root_dir: Option<&Path>,
     out_dir: Option<&Path>,
@@ -404,7 +403,7 @@ pub(super) fn js_output_path(
     Some(output)
 }
 
-pub(super) fn declaration_output_path(
+pub fn declaration_output_path(
     base_dir: &Path,
     root_dir: Option<&Path>,
     out_dir: Option<&Path>,
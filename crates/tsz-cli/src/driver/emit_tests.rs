@@ -182,16 +182,19 @@ fn test_join_declaration_bundle_chunks_orders_reference_paths_first() {
             path_key: "/project/a.ts".to_string(),
             referenced_path_keys: Vec::new(),
             contents: "declare class c {\n}".to_string(),
+            source_map: None,
         },
         DeclarationBundleChunk {
             path_key: "/project/b.js".to_string(),
             referenced_path_keys: vec!["/project/c.js".to_string()],
             contents: "declare function foo(): void;".to_string(),
+            source_map: None,
         },
         DeclarationBundleChunk {
             path_key: "/project/c.js".to_string(),
             referenced_path_keys: Vec::new(),
             contents: "declare function bar(): void;".to_string(),
+            source_map: None,
         },
     ];
 
@@ -210,16 +213,19 @@ fn test_js_bundle_chunk_order_orders_reference_paths_first() {
             path_key: "/project/a.ts".to_string(),
             referenced_path_keys: Vec::new(),
             contents: "class c {\n}".to_string(),
+            source_map: None,
         },
         JsBundleChunk {
             path_key: "/project/b.js".to_string(),
             referenced_path_keys: vec!["/project/c.js".to_string()],
             contents: "/// <reference path=\"c.js\"/>\nfunction foo() {\n}".to_string(),
+            source_map: None,
         },
         JsBundleChunk {
             path_key: "/project/c.js".to_string(),
             referenced_path_keys: Vec::new(),
             contents: "function bar() {\n}".to_string(),
+            source_map: None,
         },
     ];
 
@@ -146,6 +146,11 @@ pub(super) fn apply_cli_overrides_with_config_options(
     if args.incremental {
         options.incremental = true;
     }
+    if args.build {
+        // `tsc --build` records a .tsbuildinfo for every project it builds so
+        // the next build can tell which projects are up to date.
+        options.incremental = true;
+    }
     if args.import_helpers {
         options.import_helpers = true;
         options.printer.import_helpers = true;
@@ -282,6 +287,12 @@ pub(super) fn apply_cli_overrides_with_config_options(
     if args.no_fallthrough_cases_in_switch {
         options.checker.no_fallthrough_cases_in_switch = true;
     }
+    if args.no_error_truncation {
+        options.checker.no_error_truncation = true;
+    }
+    if args.suggestions {
+        options.checker.report_suggestions = true;
+    }
     if args.no_implicit_use_strict {
         options.checker.no_implicit_use_strict = true;
     }
@@ -516,8 +527,6 @@ fn apply_explicitly_disabled_bool_flags(options: &mut ResolvedCompilerOptions, a
             // run between can still win over the disable. See the
             // `else if` branch on `args.strict` above.
             "strict"
-                // CLI-only display flag; no compiler option to toggle.
-                | "noErrorTruncation"
                 // `inlineSources` has no corresponding `ResolvedCompilerOptions`
                 // field (the CLI flag is parsed for parity but never applied today).
                 | "inlineSources"
@@ -574,6 +583,7 @@ fn apply_explicitly_disabled_bool_flags(options: &mut ResolvedCompilerOptions, a
             "noUnusedParameters" => options.checker.no_unused_parameters = false,
             "noImplicitReturns" => options.checker.no_implicit_returns = false,
             "noFallthroughCasesInSwitch" => options.checker.no_fallthrough_cases_in_switch = false,
+            "noErrorTruncation" => options.checker.no_error_truncation = false,
             "noImplicitOverride" => options.checker.no_implicit_override = false,
             "noPropertyAccessFromIndexSignature" => {
                 options.checker.no_property_access_from_index_signature = false
@@ -1027,13 +1037,19 @@ const fn cli_module_resolution_value(module_resolution: ModuleResolution) -> &'s
 /// Selects the most recently modified `.d.ts` file among `emitted_files` and
 /// returns its path relative to `base_dir`, using forward slashes. Returns
 /// `None` if no `.d.ts` files exist or none have readable metadata.
+///
+/// A compiler host has no modification times, so under one the last
+/// declaration file emitted is taken instead.
 pub(super) fn find_latest_dts_file(emitted_files: &[PathBuf], base_dir: &Path) -> Option<String> {
-    let latest = emitted_files
-        .iter()
-        .filter(|p| p.extension().and_then(|s| s.to_str()) == Some("d.ts"))
-        .filter_map(|p| std::fs::metadata(p).ok()?.modified().ok().map(|t| (t, p)))
-        .max_by_key(|(t, _)| *t)
-        .map(|(_, p)| p)?;
+    let mut declarations = emitted_files.iter().filter(|p| is_declaration_file(p));
+    let latest = if tsz::compiler_host::has_compiler_host() {
+        declarations.next_back()?
+    } else {
+        declarations
+            .filter_map(|p| std::fs::metadata(p).ok()?.modified().ok().map(|t| (t, p)))
+            .max_by_key(|(t, _)| *t)
+            .map(|(_, p)| p)?
+    };
 
     let relative = latest
         .strip_prefix(base_dir)
@@ -4,6 +4,7 @@ use std::path::{Component, Path, PathBuf};
 // Imports kept in scope so the in-file test module can use them via `super::*`.
 #[allow(unused_imports)]
 use crate::config::{ModuleResolutionKind, PathMapping, ResolvedCompilerOptions};
+use tsz::compiler_host;
 use tsz::module_resolver::{ImportKind, ImportingModuleKind, PackageType};
 use tsz::parser::NodeIndex;
 
@@ -18,10 +19,11 @@ mod type_packages;
 pub(crate) use discovery::collect_module_specifiers;
 #[allow(unused_imports)]
 pub(crate) use discovery::{
+    ambient_module_name_matches, collect_ambient_module_names_from_text,
     collect_export_binding_nodes, collect_import_bindings, collect_module_requests_from_text,
     collect_module_specifiers_for_check, collect_module_specifiers_from_text,
     collect_star_export_specifiers, json_type_attribute_enables_json_module,
-    module_specifier_has_type_json_import_attribute,
+    module_specifier_has_type_json_import_attribute, module_specifier_span_in_text,
 };
 pub(crate) use path_resolution::{
     build_duplicate_package_redirects, normalize_path, normalize_resolved_path,
@@ -35,10 +37,11 @@ pub(crate) use type_packages::{
 #[cfg(test)]
 pub(crate) use type_packages::{resolve_type_package_entry, resolve_type_package_entry_with_mode};
 
-// `implied_resolution_mode_for_file*` are used by `super::core` etc.
-pub(super) use type_packages::{
-    implied_resolution_mode_for_file, implied_resolution_mode_for_file_with_cache,
-};
+// `implied_resolution_mode_for_file*` are used by `super::core` etc.; the
+// uncached form also picks the per-file module format for tsz-server's
+// `emit-output`.
+pub use type_packages::implied_resolution_mode_for_file;
+pub(super) use type_packages::implied_resolution_mode_for_file_with_cache;
 
 // Internal sharing: bring sibling-submodule items into the resolution
 // namespace so the in-file test module finds them via `super::*` and so
@@ -86,22 +89,25 @@ pub(crate) enum AmbientModuleDeclarationSpecifierPolicy {
 // wrapper here keeps its file-local identity because it bundles the
 // counter with the underlying syscall — that's intentional grouping —
 // while the gate-and-deref pattern lives in one place.
+//
+// The probes themselves go through `tsz::compiler_host`, so an embedder's
+// `CompilerHost` answers them when one is installed.
 #[inline]
 pub(super) fn count_is_file(path: &Path) -> bool {
     tsz_common::perf_counters::record_resolver_is_file();
-    path.is_file()
+    compiler_host::file_exists(path)
 }
 
 #[inline]
 pub(super) fn count_is_dir(path: &Path) -> bool {
     tsz_common::perf_counters::record_resolver_is_dir();
-    path.is_dir()
+    compiler_host::directory_exists(path)
 }
 
 #[inline]
-pub(super) fn count_read_dir(path: &Path) -> std::io::Result<std::fs::ReadDir> {
+pub(super) fn count_read_dir(path: &Path) -> std::io::Result<Vec<PathBuf>> {
     tsz_common::perf_counters::record_resolver_read_dir();
-    std::fs::read_dir(path)
+    compiler_host::read_directory(path)
 }
 
 /// Bump `resolver_candidate_paths_total` once per invocation. The
@@ -152,12 +158,17 @@ pub(super) fn package_relative_target_path(package_root: &Path, target: &str) ->
 
 impl ModuleResolutionCache {
     fn file_exists(&mut self, path: &Path) -> bool {
-        if let Some(&exists) = self.file_exists_by_path.get(path) {
-            return exists;
-        }
-
-        let exists = count_is_file(path);
-        self.file_exists_by_path.insert(path.to_path_buf(), exists);
+        let exists = match self.file_exists_by_path.get(path) {
+            Some(&exists) => exists,
+            None => {
+                let exists = count_is_file(path);
+                self.file_exists_by_path.insert(path.to_path_buf(), exists);
+                exists
+            }
+        };
+        // Fallback resolution runs inside `ModuleResolver::lookup`, so its
+        // probes belong in the `--traceResolution` output too.
+        tsz::module_resolver::trace_file_probe(path, exists);
         exists
     }
 
@@ -258,13 +269,13 @@ pub(crate) fn is_declaration_file(path: &Path) -> bool {
 }
 
 pub(crate) fn canonicalize_with_missing_tail(path: &Path) -> PathBuf {
-    if let Ok(canonical) = std::fs::canonicalize(path) {
+    if let Ok(canonical) = compiler_host::canonicalize(path) {
         return canonical;
     }
 
     let mut tail = Vec::new();
     let mut current = path;
-    while !current.exists() {
+    while !compiler_host::path_exists(current) {
         let Some(name) = current.file_name() else {
             return path.to_path_buf();
         };
@@ -275,7 +286,7 @@ pub(crate) fn canonicalize_with_missing_tail(path: &Path) -> PathBuf {
         current = parent;
     }
 
-    let Ok(mut canonical) = std::fs::canonicalize(current) else {
+    let Ok(mut canonical) = compiler_host::canonicalize(current) else {
         return path.to_path_buf();
     };
     for component in tail.iter().rev() {
@@ -285,7 +296,7 @@ pub(crate) fn canonicalize_with_missing_tail(path: &Path) -> PathBuf {
 }
 
 pub(crate) fn canonicalize_or_owned(path: &Path) -> PathBuf {
-    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+    compiler_host::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
 }
 
 pub(crate) fn env_flag(name: &str) -> bool {
@@ -185,6 +185,70 @@ pub(crate) fn strip_scanned_string_literal(text: &str) -> Option<String> {
     Some(value.to_string())
 }
 
+/// Names declared by top-level `declare module "name"` blocks in `text`,
+/// including wildcard patterns such as `"*.css"`.
+pub(crate) fn collect_ambient_module_names_from_text(text: &str) -> Vec<String> {
+    if !text.contains("declare module") {
+        return Vec::new();
+    }
+    let mut scanner = ScannerState::new(text.to_string(), true);
+    let mut names = Vec::new();
+    let mut brace_depth = 0usize;
+    let mut previous = [SyntaxKind::Unknown; 2];
+    loop {
+        let kind = scanner.scan();
+        match kind {
+            SyntaxKind::EndOfFileToken => break,
+            SyntaxKind::OpenBraceToken => brace_depth += 1,
+            SyntaxKind::CloseBraceToken => brace_depth = brace_depth.saturating_sub(1),
+            SyntaxKind::StringLiteral
+                if brace_depth == 0
+                    && previous == [SyntaxKind::DeclareKeyword, SyntaxKind::ModuleKeyword] =>
+            {
+                if let Some(name) = strip_scanned_string_literal(scanner.get_token_text_ref()) {
+                    names.push(name);
+                }
+            }
+            _ => {}
+        }
+        previous = [previous[1], kind];
+    }
+    names
+}
+
+/// Whether `specifier` is declared by the ambient module `name`, which may
+/// contain a single `*` wildcard.
+pub(crate) fn ambient_module_name_matches(name: &str, specifier: &str) -> bool {
+    match name.split_once('*') {
+        Some((prefix, suffix)) => {
+            specifier.len() >= prefix.len() + suffix.len()
+                && specifier.starts_with(prefix)
+                && specifier.ends_with(suffix)
+        }
+        None => name == specifier,
+    }
+}
+
+/// Start and length of the first string literal in `text` whose value is
+/// `specifier`, quotes included.
+pub(crate) fn module_specifier_span_in_text(text: &str, specifier: &str) -> Option<(u32, u32)> {
+    let mut scanner = ScannerState::new(text.to_string(), true);
+    loop {
+        match scanner.scan() {
+            SyntaxKind::EndOfFileToken => return None,
+            SyntaxKind::StringLiteral
+                if strip_scanned_string_literal(scanner.get_token_text_ref()).as_deref()
+                    == Some(specifier) =>
+            {
+                let start = scanner.get_token_start();
+                let length = scanner.get_token_end() - start;
+                return Some((u32::try_from(start).ok()?, u32::try_from(length).ok()?));
+            }
+            _ => {}
+        }
+    }
+}
+
 pub(crate) fn skip_ambient_module_body_without_dependencies(
     tokens: &[DiscoveryToken],
     open_brace: usize,
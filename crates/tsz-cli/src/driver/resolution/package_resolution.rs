@@ -4,6 +4,7 @@ use std::path::{Path, PathBuf};
 
 use crate::config::{ModuleResolutionKind, ResolvedCompilerOptions};
 use crate::fs::{is_valid_module_file, is_valid_module_or_js_file};
+use tsz::compiler_host;
 use tsz::emitter::ModuleKind;
 use tsz::module_resolver::PackageType;
 
@@ -794,7 +795,7 @@ pub(crate) fn try_remap_output_to_source(
             }
 
             let canonical = normalize_resolved_path(configured, options);
-            if canonical.exists() {
+            if compiler_host::path_exists(&canonical) {
                 return canonical;
             }
 
@@ -928,7 +929,7 @@ pub(crate) fn read_package_json_uncached(path: &Path) -> Option<PackageJson> {
     // PERF: see `docs/plan/PERFORMANCE_PLAN.md`. Resolver hot path
     // — package.json reads dominate sample profiles on full large-ts-repo.
     count_read_package_json();
-    let contents = std::fs::read_to_string(path).ok()?;
+    let contents = compiler_host::read_file(path).ok()?;
     serde_json::from_str(&contents).ok()
 }
 
@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 
 use crate::config::{ModuleResolutionKind, PathMapping, ResolvedCompilerOptions};
 use crate::fs::is_valid_module_or_js_file;
+use tsz::compiler_host;
 use tsz::module_resolver::{PackageType, is_path_relative};
 
 #[allow(unused_imports)]
@@ -544,7 +545,7 @@ pub(crate) fn is_root_alias_symlink(dir: &Path) -> bool {
     let Ok(relative_to_root) = dir.strip_prefix(Path::new("/")) else {
         return false;
     };
-    let Ok(canonical) = std::fs::canonicalize(dir) else {
+    let Ok(canonical) = compiler_host::canonicalize(dir) else {
         return false;
     };
     let Ok(canonical_relative_to_root) = canonical.strip_prefix(Path::new("/")) else {
@@ -578,7 +579,7 @@ pub(crate) fn build_duplicate_package_redirects(
     let mut root_redirects: FxHashMap<PathBuf, PathBuf> = FxHashMap::default();
     for pkg_root in &package_roots {
         let pkg_json_path = pkg_root.join("package.json");
-        let (name, version) = match std::fs::read_to_string(&pkg_json_path) {
+        let (name, version) = match compiler_host::read_file(&pkg_json_path) {
             Ok(content) => match serde_json::from_str::<serde_json::Value>(&content) {
                 Ok(val) => {
                     let name = val.get("name").and_then(|v| v.as_str()).unwrap_or("");
@@ -137,7 +137,7 @@ pub(crate) fn resolve_type_reference_from_node_modules_with_cache(
 /// - `.ts`/`.tsx`/`.js`/`.jsx` files → depends on nearest `package.json`:
 ///   - `"type": "module"` → "import"
 ///   - otherwise → "require"
-pub(crate) fn implied_resolution_mode_for_file(file: &Path, base_dir: &Path) -> String {
+pub fn implied_resolution_mode_for_file(file: &Path, base_dir: &Path) -> String {
     let mut cache = ModuleResolutionCache::default();
     implied_resolution_mode_for_file_with_cache(file, base_dir, &mut cache)
 }
@@ -284,32 +284,31 @@ pub(crate) fn collect_type_packages_from_root(root: &Path) -> Vec<PathBuf> {
         Err(_) => return packages,
     };
 
-    for entry in entries.flatten() {
-        let path = entry.path();
+    for path in entries {
         if !count_is_dir(&path) {
             continue;
         }
-        let name = entry.file_name();
+        let Some(name) = path.file_name() else {
+            continue;
+        };
         let name = name.to_string_lossy();
         if name.starts_with('.') {
             continue;
         }
         if name.starts_with('@') {
             if let Ok(scope_entries) = count_read_dir(&path) {
-                for scope_entry in scope_entries.flatten() {
-                    let scope_path = scope_entry.path();
-                    let scope_name = scope_entry.file_name();
+                for scope_path in scope_entries {
                     // Skip dot-prefixed entries (e.g., .DS_Store, .git)
                     // matching tsc behavior for type root discovery
-                    if scope_name.to_str().is_some_and(|n| n.starts_with('.')) {
+                    if scope_path
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|n| n.starts_with('.'))
+                    {
                         continue;
                     }
                     if count_is_dir(&scope_path) {
-                        let scope_name = scope_entry.file_name();
-                        let scope_name = scope_name.to_string_lossy();
-                        if !scope_name.starts_with('.') {
-                            packages.push(scope_path);
-                        }
+                        packages.push(scope_path);
                     }
                 }
             }
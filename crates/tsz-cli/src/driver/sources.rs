@@ -1,7 +1,12 @@
 //! Source file I/O, config helpers, and file reading for the compilation driver.
 
 use super::*;
-use crate::fs::is_ts_file;
+use crate::driver::resolution::{
+    ambient_module_name_matches, collect_ambient_module_names_from_text,
+    module_specifier_span_in_text,
+};
+use crate::fs::{is_glob_pattern, is_ts_file, use_case_sensitive_file_names};
+use tsz::compiler_host::{self, CompilerHostScope};
 
 /// Count how many `node_modules` segments appear in a file path.
 /// For example, `/a/node_modules/b/node_modules/c/index.js` has depth 2.
@@ -60,7 +65,7 @@ pub enum FileReadResult {
 /// - Files with many null bytes
 pub fn read_source_file(path: &Path) -> FileReadResult {
     // Read as bytes first
-    let bytes = match std::fs::read(path) {
+    let bytes = match compiler_host::read_file_bytes(path) {
         Ok(b) => b,
         Err(e) => return FileReadResult::Error(e.to_string()),
     };
@@ -106,9 +111,9 @@ pub fn read_source_file(path: &Path) -> FileReadResult {
         };
     }
 
-    // Try to decode as UTF-8
+    // Try to decode as UTF-8, dropping a UTF-8 BOM like tsc's `sys.readFile`
     match String::from_utf8(bytes) {
-        Ok(text) => FileReadResult::Text(text),
+        Ok(text) => FileReadResult::Text(compiler_host::strip_utf8_bom(text)),
         Err(err) => FileReadResult::Binary {
             text: String::from_utf8_lossy(err.as_bytes()).to_string(),
             suppress_parser_diagnostics: true,
@@ -194,7 +199,7 @@ pub(super) fn source_has_no_default_lib(source: &SourceEntry) -> bool {
         return text_may_contain_no_default_lib_directive(text)
             && has_no_default_lib_directive(text);
     }
-    let Ok(text) = std::fs::read_to_string(&source.path) else {
+    let Ok(text) = compiler_host::read_file(&source.path) else {
         return false;
     };
     text_may_contain_no_default_lib_directive(&text) && has_no_default_lib_directive(&text)
@@ -255,6 +260,123 @@ pub(super) struct SourceReadResult {
     /// TS1453: Invalid `resolution-mode` values in `/// <reference types="..." />` directives.
     /// Tuples of (`file_path`, `byte_offset`, `span_length`).
     pub(super) resolution_mode_errors: Vec<(PathBuf, usize, usize)>,
+    /// Imports and references that pulled each discovered file into the
+    /// program, for `--explainFiles`. Files reused from the watch cache keep
+    /// only the reasons of files that were re-read.
+    pub(super) inclusion_reasons: FxHashMap<PathBuf, Vec<FileInclusionReason>>,
+    /// Module specifiers that did not resolve to a file, in discovery order.
+    /// Tuples of (`containing_file`, `specifier`, `code`, `message`).
+    pub(super) unresolved_imports: Vec<(PathBuf, String, u32, String)>,
+}
+
+/// An import or export whose module specifier resolves neither to a file nor
+/// to an ambient `declare module` in the program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedImport {
+    pub containing_file: PathBuf,
+    pub specifier: String,
+    /// Start and length of the specifier literal in `containing_file`.
+    pub start: u32,
+    pub length: u32,
+    /// Diagnostic code the checker would report, e.g. 2307.
+    pub code: u32,
+    pub message: String,
+}
+
+/// Files of the program reachable from its root files, found by reading and
+/// resolving imports without binding or checking anything (`--listFilesOnly`).
+#[derive(Debug, Clone, Default)]
+pub struct ProgramFileList {
+    /// Root files first, then files pulled in by imports, references and
+    /// `types`, in discovery order. Paths are canonical.
+    pub files: Vec<PathBuf>,
+    pub unresolved_imports: Vec<UnresolvedImport>,
+}
+
+/// Build the module graph for `root_files` and return every file in it.
+pub fn list_program_files(
+    root_files: &[PathBuf],
+    base_dir: &Path,
+    options: &ResolvedCompilerOptions,
+) -> Result<ProgramFileList> {
+    // Resolve directory options against the project the same way a full
+    // compilation does before it reads sources.
+    let base_dir = if options.preserve_symlinks {
+        normalize_path(base_dir)
+    } else {
+        canonicalize_or_owned(base_dir)
+    };
+    let base_dir = base_dir.as_path();
+    let mut options = options.clone();
+    options.base_url = normalize_base_url(base_dir, options.base_url.take());
+    options.root_dirs = normalize_root_dirs(base_dir, std::mem::take(&mut options.root_dirs));
+    options.type_roots = normalize_type_roots(base_dir, options.type_roots.take());
+    let options = &options;
+
+    let (type_files, _) = collect_type_root_files(base_dir, options);
+    let mut paths = root_files.to_vec();
+    for type_file in type_files {
+        if !paths.contains(&type_file) {
+            paths.push(type_file);
+        }
+    }
+    let result = read_source_files(&paths, base_dir, options, None, None)?;
+
+    // Nothing is bound, so ambient module declarations are found by scanning
+    // the program's sources for `declare module "name"`.
+    let ambient_modules: Vec<String> = result
+        .sources
+        .iter()
+        .filter_map(|source| source.text.as_deref())
+        .flat_map(collect_ambient_module_names_from_text)
+        .collect();
+    let unresolved_imports = result
+        .unresolved_imports
+        .into_iter()
+        .filter(|(_, specifier, _, _)| {
+            !ambient_modules
+                .iter()
+                .any(|name| ambient_module_name_matches(name, specifier))
+        })
+        .map(|(containing_file, specifier, code, message)| {
+            let (start, length) = result
+                .sources
+                .iter()
+                .find(|source| source.path == containing_file)
+                .and_then(|source| source.text.as_deref())
+                .and_then(|text| module_specifier_span_in_text(text, &specifier))
+                .unwrap_or((0, 0));
+            UnresolvedImport {
+                containing_file,
+                specifier,
+                start,
+                length,
+                code,
+                message,
+            }
+        })
+        .collect();
+
+    Ok(ProgramFileList {
+        files: result
+            .sources
+            .into_iter()
+            .map(|source| source.path)
+            .collect(),
+        unresolved_imports,
+    })
+}
+
+/// Record why `path` was included, once per distinct reason.
+fn record_inclusion_reason(
+    inclusion_reasons: &mut FxHashMap<PathBuf, Vec<FileInclusionReason>>,
+    path: &Path,
+    reason: FileInclusionReason,
+) {
+    let reasons = inclusion_reasons.entry(path.to_path_buf()).or_default();
+    if !reasons.contains(&reason) {
+        reasons.push(reason);
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -281,7 +403,7 @@ pub fn find_tsconfig(cwd: &Path) -> Option<PathBuf> {
     let mut current = Some(cwd);
     while let Some(dir) = current {
         let candidate = dir.join("tsconfig.json");
-        if candidate.is_file() {
+        if compiler_host::file_exists(&candidate) {
             return Some(normalize_path(&candidate));
         }
         current = dir.parent();
@@ -337,9 +459,9 @@ pub(crate) fn resolve_tsconfig_path(
         cwd.join(project)
     };
 
-    if absolute.is_dir() {
+    if compiler_host::directory_exists(&absolute) {
         let candidate = absolute.join("tsconfig.json");
-        if !candidate.is_file() {
+        if !compiler_host::file_exists(&candidate) {
             return Err(ResolveTsconfigError::NoConfigInDirectory(
                 project.to_path_buf(),
             ));
@@ -347,13 +469,13 @@ pub(crate) fn resolve_tsconfig_path(
         return Ok(Some(normalize_path(&candidate)));
     }
 
-    if !absolute.exists() {
+    if !compiler_host::path_exists(&absolute) {
         return Err(ResolveTsconfigError::PathDoesNotExist(
             project.to_path_buf(),
         ));
     }
 
-    if !absolute.is_file() {
+    if !compiler_host::file_exists(&absolute) {
         return Err(ResolveTsconfigError::NotAFile(project.to_path_buf()));
     }
 
@@ -415,16 +537,29 @@ pub(super) fn build_discovery_options(
 ) -> Result<FileDiscoveryOptions> {
     let follow_links = env_flag("TSZ_FOLLOW_SYMLINKS") && !resolved.preserve_symlinks;
     if !args.files.is_empty() {
+        // Quoted glob arguments (`tsz 'src/**/*.ts'`) reach us unexpanded;
+        // walk them like tsconfig `include` patterns.
+        let (patterns, files): (Vec<&PathBuf>, Vec<&PathBuf>) = args
+            .files
+            .iter()
+            .partition(|file| is_glob_pattern(&file.to_string_lossy()));
+        let include = (!patterns.is_empty()).then(|| {
+            patterns
+                .iter()
+                .map(|pattern| pattern.to_string_lossy().into_owned())
+                .collect()
+        });
         return Ok(FileDiscoveryOptions {
             base_dir: base_dir.to_path_buf(),
-            files: args.files.clone(),
+            files: files.into_iter().cloned().collect(),
             files_explicitly_set: true,
-            include: None,
+            include,
             exclude: None,
             out_dir: out_dir.map(Path::to_path_buf),
             follow_links,
             allow_js: resolved.allow_js,
             resolve_json_module: resolved.resolve_json_module,
+            case_insensitive: !use_case_sensitive_file_names(),
         });
     }
 
@@ -623,6 +758,8 @@ pub(super) fn read_source_files(
     let mut module_resolver = ModuleResolver::new(options);
     let mut type_reference_errors = Vec::new();
     let mut resolution_mode_errors = Vec::new();
+    let mut inclusion_reasons: FxHashMap<PathBuf, Vec<FileInclusionReason>> = FxHashMap::default();
+    let mut unresolved_imports = Vec::new();
     let use_cache = cache.is_some() && changed_paths.is_some();
 
     // PERF: cache `normalize_resolved_path` results for the BFS lifetime.
@@ -712,13 +849,17 @@ pub(super) fn read_source_files(
         // benefit from saturating the disk queue and CPU cores in parallel.
         use rayon::prelude::*;
         let no_resolve = options.no_resolve;
+        let host = compiler_host::current_compiler_host();
         let parsed: Vec<Option<ParsedSource>> =
             tsz::parallel::run_with_rayon_pool_for_work_items(batch.len(), || {
                 batch
                     .par_iter()
                     .zip(actions.par_iter())
                     .map(|(path, action)| match action {
-                        BatchAction::Read => Some(parse_source_for_bfs(path, no_resolve)),
+                        BatchAction::Read => {
+                            let _host = CompilerHostScope::enter(host.clone());
+                            Some(parse_source_for_bfs(path, no_resolve))
+                        }
                         BatchAction::Cached | BatchAction::SkipJs => None,
                     })
                     .collect()
@@ -843,6 +984,14 @@ pub(super) fn read_source_files(
                     }
                     if let Some(resolved) = outcome.resolved_path {
                         let canonical = normalize(&resolved, options);
+                        record_inclusion_reason(
+                            &mut inclusion_reasons,
+                            &canonical,
+                            FileInclusionReason::ImportedVia {
+                                specifier: specifier.clone(),
+                                from: path.clone(),
+                            },
+                        );
                         if outcome.error.is_none() {
                             module_resolutions.insert(
                                 SourceModuleResolutionKey {
@@ -868,6 +1017,13 @@ pub(super) fn read_source_files(
                             next_discovery_order += 1;
                             pending.push_back(canonical);
                         }
+                    } else if let Some(error) = outcome.error {
+                        unresolved_imports.push((
+                            path.clone(),
+                            specifier,
+                            error.code,
+                            error.message,
+                        ));
                     }
                 }
             }
@@ -949,6 +1105,14 @@ pub(super) fn read_source_files(
                     });
                     if let Some(resolved) = resolved {
                         let canonical = normalize(&resolved, options);
+                        record_inclusion_reason(
+                            &mut inclusion_reasons,
+                            &canonical,
+                            FileInclusionReason::TypeLibraryReferencedVia {
+                                name: type_name.clone(),
+                                from: path.clone(),
+                            },
+                        );
                         entry.insert(canonical.clone());
                         outfile_bundle_paths.insert(canonical.clone());
                         bundle_entry.insert(canonical.clone());
@@ -981,6 +1145,14 @@ pub(super) fn read_source_files(
                                 )
                             {
                                 let canonical = normalize(&alt, options);
+                                record_inclusion_reason(
+                                    &mut inclusion_reasons,
+                                    &canonical,
+                                    FileInclusionReason::TypeLibraryReferencedVia {
+                                        name: type_name.clone(),
+                                        from: path.clone(),
+                                    },
+                                );
                                 entry.insert(canonical.clone());
                                 outfile_bundle_paths.insert(canonical.clone());
                                 bundle_entry.insert(canonical.clone());
@@ -1017,11 +1189,19 @@ pub(super) fn read_source_files(
 
                     let Some(resolved_reference) = candidates
                         .iter()
-                        .find(|candidate| candidate.is_file())
+                        .find(|candidate| compiler_host::file_exists(candidate))
                         .map(|candidate| normalize(candidate, options))
                     else {
                         continue;
                     };
+                    record_inclusion_reason(
+                        &mut inclusion_reasons,
+                        &resolved_reference,
+                        FileInclusionReason::ReferencedVia {
+                            reference: reference_path.clone(),
+                            from: path.clone(),
+                        },
+                    );
                     entry.insert(resolved_reference.clone());
                     outfile_bundle_paths.insert(resolved_reference.clone());
                     bundle_entry.insert(resolved_reference.clone());
@@ -1069,6 +1249,8 @@ pub(super) fn read_source_files(
         module_resolutions,
         type_reference_errors,
         resolution_mode_errors,
+        inclusion_reasons,
+        unresolved_imports,
     })
 }
 
@@ -1275,6 +1457,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn read_source_files_records_import_and_reference_provenance() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().join("src/main.ts");
+        let dep = dir.path().join("src/dep.ts");
+        let globals = dir.path().join("src/globals.d.ts");
+        std::fs::create_dir_all(root.parent().unwrap()).unwrap();
+        std::fs::write(
+            &root,
+            "/// <reference path=\"globals.d.ts\" />\nimport { x } from './dep';\nexport { x } from './dep';\n",
+        )
+        .unwrap();
+        std::fs::write(&dep, "export const x = 1;\n").unwrap();
+        std::fs::write(&globals, "declare var g: number;\n").unwrap();
+
+        let result = read_source_files(
+            &[root],
+            dir.path(),
+            &ResolvedCompilerOptions::default(),
+            None,
+            None,
+        )
+        .expect("read source files");
+
+        let reasons_for = |suffix: &str| {
+            result
+                .inclusion_reasons
+                .iter()
+                .find(|(path, _)| path.ends_with(suffix))
+                .map(|(_, reasons)| reasons.clone())
+                .unwrap_or_default()
+        };
+        let dep_reasons = reasons_for("src/dep.ts");
+        assert_eq!(
+            dep_reasons.len(),
+            1,
+            "one reason per distinct import: {dep_reasons:?}"
+        );
+        assert!(matches!(
+            &dep_reasons[0],
+            FileInclusionReason::ImportedVia { specifier, from }
+                if specifier == "./dep" && from.ends_with("src/main.ts")
+        ));
+        assert!(matches!(
+            reasons_for("src/globals.d.ts").as_slice(),
+            [FileInclusionReason::ReferencedVia { reference, .. }] if reference == "globals.d.ts"
+        ));
+    }
+
     // ---------------- should_skip_js_in_node_modules ----------------
 
     #[test]
@@ -1481,6 +1712,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn read_source_file_utf8_bom_is_stripped() {
+        let dir = tempdir().unwrap();
+        let path = write_temp(
+            dir.path(),
+            "bom.ts",
+            b"\xEF\xBB\xBF#!/usr/bin/env node\nlet x = 1;\n",
+        );
+        match read_source_file(&path) {
+            FileReadResult::Text(t) => assert_eq!(t, "#!/usr/bin/env node\nlet x = 1;\n"),
+            other => panic!("expected Text from UTF-8 BOM, got {other:?}"),
+        }
+    }
+
     #[test]
     fn read_source_file_binary_marks_suppression() {
         let dir = tempdir().unwrap();
@@ -1,9 +1,12 @@
 use super::CompilationCache;
 use super::FileReadResult;
+use super::build_info_to_compilation_cache;
+use super::canonicalize_or_owned;
 use super::check_module_resolution_compatibility;
 use super::check_module_resolution_compatibility_mut;
 use super::compilation_cache_to_build_info;
 use super::compile;
+use super::find_latest_dts_file;
 use super::find_tsconfig;
 use super::is_declaration_emit_blocking_diagnostic_code;
 use super::no_input_diagnostics_for_config;
@@ -84,6 +87,22 @@ fn find_tsconfig_walks_up_from_project_subdirectory() {
     assert_eq!(find_tsconfig(&nested), Some(config));
 }
 
+#[test]
+fn find_latest_dts_file_ignores_non_declaration_outputs() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let js = dir.path().join("dist/index.js");
+    let dts = dir.path().join("dist/index.d.ts");
+    fs::create_dir_all(js.parent().unwrap()).expect("create dist dir");
+    fs::write(&js, "export const value = 1;").expect("write js");
+    fs::write(&dts, "export declare const value = 1;").expect("write dts");
+
+    assert_eq!(
+        find_latest_dts_file(&[js.clone(), dts], dir.path()).as_deref(),
+        Some("dist/index.d.ts")
+    );
+    assert_eq!(find_latest_dts_file(&[js], dir.path()), None);
+}
+
 #[test]
 fn compilation_cache_build_info_uses_source_hash_for_file_version() {
     let dir = tempfile::tempdir().expect("temp dir");
@@ -111,6 +130,32 @@ fn compilation_cache_build_info_uses_source_hash_for_file_version() {
     assert_eq!(file_info.signature.as_deref(), Some("0000000000001234"));
 }
 
+#[test]
+fn build_info_restore_reuses_only_unchanged_files() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let source_path = canonicalize_or_owned(&dir.path().join("index.ts"));
+    fs::write(&source_path, "export const value = 1;").expect("write source");
+
+    let mut cache = CompilationCache::default();
+    cache.export_hashes.insert(source_path.clone(), 0x1234);
+    let build_info = compilation_cache_to_build_info(
+        &cache,
+        std::slice::from_ref(&source_path),
+        dir.path(),
+        &ResolvedCompilerOptions::default(),
+    );
+
+    let restored = build_info_to_compilation_cache(&build_info, dir.path());
+    assert_eq!(restored.export_hashes.get(&source_path), Some(&0x1234));
+    assert!(restored.persisted_files.contains(&source_path));
+    assert!(restored.unchanged_persisted_files.contains(&source_path));
+
+    fs::write(&source_path, "export const value = 2;").expect("rewrite source");
+    let restored = build_info_to_compilation_cache(&build_info, dir.path());
+    assert!(restored.persisted_files.contains(&source_path));
+    assert!(!restored.unchanged_persisted_files.contains(&source_path));
+}
+
 #[test]
 fn test_module_resolution_requires_matching_module() {
     let resolved = ResolvedCompilerOptions {
@@ -416,3 +416,19 @@ fn test_load_normalizes_alias_locale_id() {
     assert_eq!(locale.locale_id(), "ja");
     assert!(!locale.is_default());
 }
+
+#[test]
+fn test_normalize_locale_falls_back_from_territory_to_language() {
+    assert_eq!(normalize_locale("es-AR"), Some("es"));
+    assert_eq!(normalize_locale("fr-be"), Some("fr"));
+    assert_eq!(normalize_locale("ja_JP"), Some("ja"));
+    assert_eq!(normalize_locale("zh_TW"), Some("zh-tw"));
+    assert_eq!(normalize_locale("xx-de"), None);
+}
+
+#[test]
+fn test_load_territory_without_catalog_uses_language_messages() {
+    let locale = LocaleMessages::load("de-LU").expect("German catalog should load");
+    assert_eq!(locale.locale_id(), "de");
+    assert!(locale.has_translation(2304));
+}
@@ -327,9 +327,20 @@ fn extract_quoted_strings(message: &str) -> Vec<&str> {
 }
 
 /// Normalize a locale identifier to our supported format.
+///
+/// Like tsc, `language_territory` is accepted as a spelling of
+/// `language-territory`, and a territory without its own catalog falls back
+/// to the language catalog (`es-AR` uses `es`).
 fn normalize_locale(locale: &str) -> Option<&'static str> {
-    let lower = locale.to_lowercase();
-    match lower.as_str() {
+    let lower = locale.to_lowercase().replace('_', "-");
+    normalize_locale_tag(&lower).or_else(|| {
+        let (language, _territory) = lower.split_once('-')?;
+        normalize_locale_tag(language)
+    })
+}
+
+fn normalize_locale_tag(lower: &str) -> Option<&'static str> {
+    match lower {
         "cs" | "cs-cz" | "czech" => Some("cs"),
         "de" | "de-de" | "de-at" | "de-ch" | "german" => Some("de"),
         "es" | "es-es" | "es-mx" | "spanish" => Some("es"),
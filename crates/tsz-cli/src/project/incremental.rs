@@ -11,6 +11,8 @@
 //! - Version information for cache invalidation
 //! - File hashes for change detection
 //! - Dependency graphs between files
+//! - Semantic diagnostics per file, reused for files that did not change
+//! - A hash of the compiler options, so an options change starts fresh
 //! - Emitted file signatures for output caching
 
 use anyhow::{Context, Result};
@@ -19,9 +21,10 @@ use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
+use tsz::compiler_host;
 
 /// Version of the build info format
-pub const BUILD_INFO_VERSION: &str = "0.1.0";
+pub const BUILD_INFO_VERSION: &str = "0.2.0";
 
 /// Build information persisted between compilations
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,6 +55,10 @@ pub struct BuildInfo {
     /// Options that affect compilation
     #[serde(default)]
     pub options: BuildInfoOptions,
+    /// Hash of all resolved compiler options; the build info is only reused
+    /// by a compilation with the same options
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options_hash: Option<String>,
     /// Timestamp of when the build was completed
     pub build_time: u64,
 }
@@ -144,6 +151,7 @@ impl Default for BuildInfo {
             emit_signatures: BTreeMap::new(),
             latest_changed_dts_file: None,
             options: BuildInfoOptions::default(),
+            options_hash: None,
             build_time: SystemTime::now()
                 .duration_since(SystemTime::UNIX_EPOCH)
                 .map(|d| d.as_secs())
@@ -162,7 +170,7 @@ impl BuildInfo {
     /// Returns Ok(None) if the file exists but is incompatible (version mismatch)
     /// Returns `Ok(Some(build_info))` if the file is valid and compatible
     pub fn load(path: &Path) -> Result<Option<Self>> {
-        let content = std::fs::read_to_string(path)
+        let content = compiler_host::read_file(path)
             .with_context(|| format!("failed to read build info: {}", path.display()))?;
 
         let build_info: Self = serde_json::from_str(&content)
@@ -182,18 +190,12 @@ impl BuildInfo {
         Ok(Some(build_info))
     }
 
-    /// Save build info to a file
+    /// Save build info to a file, creating parent directories as needed
     pub fn save(&self, path: &Path) -> Result<()> {
-        // Create parent directories if needed
-        if let Some(parent) = path.parent() {
-            std::fs::create_dir_all(parent)
-                .with_context(|| format!("failed to create directory: {}", parent.display()))?;
-        }
-
         let content =
             serde_json::to_string_pretty(self).context("failed to serialize build info")?;
 
-        std::fs::write(path, content)
+        compiler_host::write_file(path, &content)
             .with_context(|| format!("failed to write build info: {}", path.display()))?;
 
         Ok(())
@@ -418,8 +420,8 @@ pub fn compute_file_version(path: &Path) -> Result<String> {
     use std::collections::hash_map::DefaultHasher;
     use std::hash::{Hash, Hasher};
 
-    let content =
-        std::fs::read(path).with_context(|| format!("failed to read file: {}", path.display()))?;
+    let content = compiler_host::read_file_bytes(path)
+        .with_context(|| format!("failed to read file: {}", path.display()))?;
 
     let mut hasher = DefaultHasher::new();
     content.hash(&mut hasher);
@@ -1,14 +1,23 @@
-use colored::Colorize;
+use colored::{Color, Colorize};
 use rustc_hash::FxHashMap;
 use std::path::Path;
 
+use crate::args::DiagnosticFormat;
 use crate::locale;
-use tsz::checker::diagnostics::{Diagnostic, DiagnosticCategory, DiagnosticRelatedInformation};
+use tsz::checker::diagnostics::{
+    Diagnostic, DiagnosticCategory, DiagnosticMessageChain, DiagnosticRelatedInformation,
+    SpellingSuggestion,
+};
 use tsz::lsp::position::LineMap;
+use tsz_common::diagnostics::{diagnostic_codes, diagnostic_messages, format_message};
+
+/// Gutter marker for the lines a long code span skips (tsc's `ellipsis`).
+const GUTTER_ELLIPSIS: &str = "...";
 
 pub struct Reporter {
     pretty: bool,
     color: bool,
+    format: DiagnosticFormat,
     cwd: Option<String>,
     sources: FxHashMap<String, String>,
     line_maps: FxHashMap<String, LineMap>,
@@ -19,6 +28,7 @@ impl Reporter {
         Self {
             pretty: color,
             color,
+            format: DiagnosticFormat::Text,
             cwd: std::env::current_dir()
                 .ok()
                 .map(|p| p.to_string_lossy().into_owned()),
@@ -33,6 +43,12 @@ impl Reporter {
         self.pretty = pretty;
     }
 
+    /// Set the output format (`--format`).
+    /// Machine-readable formats take precedence over pretty mode and are never colored.
+    pub const fn set_format(&mut self, format: DiagnosticFormat) {
+        self.format = format;
+    }
+
     /// Override the working directory used for computing relative paths.
     ///
     /// Canonicalises the path (resolving macOS-style `/tmp` → `/private/tmp`
@@ -59,10 +75,11 @@ impl Reporter {
     pub fn render(&mut self, diagnostics: &[Diagnostic]) -> String {
         let mut out = String::new();
 
-        if self.pretty {
-            self.render_pretty(&mut out, diagnostics);
-        } else {
-            self.render_plain(&mut out, diagnostics);
+        match self.format {
+            DiagnosticFormat::Json => self.render_json(&mut out, diagnostics),
+            DiagnosticFormat::Sarif => self.render_sarif(&mut out, diagnostics),
+            DiagnosticFormat::Text if self.pretty => self.render_pretty(&mut out, diagnostics),
+            DiagnosticFormat::Text => self.render_plain(&mut out, diagnostics),
         }
 
         out
@@ -84,6 +101,231 @@ impl Reporter {
         }
     }
 
+    /// Render diagnostics as JSON lines (--format json).
+    /// One object per diagnostic, one diagnostic per line, no summary line.
+    fn render_json(&mut self, out: &mut String, diagnostics: &[Diagnostic]) {
+        for diagnostic in diagnostics {
+            let value = self.diagnostic_json(diagnostic);
+            out.push_str(&value.to_string());
+            out.push('\n');
+        }
+    }
+
+    /// Build the JSON object for a single diagnostic.
+    /// ```text
+    /// {"file", "start", "end", "length", "category", "code", "message",
+    ///  "messageChain", "relatedInformation", "fixes"}
+    /// ```
+    /// `start`/`end` carry 1-based `line`/`column` plus the byte `offset`;
+    /// `message` is the flattened chain, as tsc's `flattenDiagnosticMessageText`.
+    pub fn diagnostic_json(&mut self, diagnostic: &Diagnostic) -> serde_json::Value {
+        let chain = self.translate_chain(&diagnostic.message_chain());
+        let related: Vec<serde_json::Value> = diagnostic
+            .related_locations()
+            .map(|related| self.related_json(related))
+            .collect();
+        let mut value = self.span_json(&diagnostic.file, diagnostic.start, diagnostic.length);
+        value["category"] = category_name(diagnostic.category).into();
+        value["code"] = diagnostic.code.into();
+        value["message"] = chain.flatten("\n").into();
+        value["messageChain"] = chain_json(&chain);
+        value["relatedInformation"] = related.into();
+        let fixes: Vec<serde_json::Value> = spelling_suggestion(diagnostic)
+            .map(|suggestion| {
+                let mut fix = self.span_json(&diagnostic.file, suggestion.start, suggestion.length);
+                fix["description"] = self.change_spelling_description(&suggestion.name).into();
+                fix["newText"] = suggestion.name.as_str().into();
+                fix
+            })
+            .into_iter()
+            .collect();
+        value["fixes"] = fixes.into();
+        value
+    }
+
+    fn change_spelling_description(&self, suggestion: &str) -> String {
+        self.translate_message(
+            diagnostic_codes::CHANGE_SPELLING_TO,
+            &format_message(diagnostic_messages::CHANGE_SPELLING_TO, &[suggestion]),
+        )
+    }
+
+    fn related_json(&mut self, related: &DiagnosticRelatedInformation) -> serde_json::Value {
+        let mut value = self.span_json(&related.file, related.start, related.length);
+        value["category"] = category_name(related.category).into();
+        value["code"] = related.code.into();
+        value["message"] = self
+            .translate_message(related.code, &related.message_text)
+            .into();
+        value
+    }
+
+    /// `{"file", "start", "end", "length"}` for a span; positions are `null`
+    /// when the file has no location (global diagnostics) or cannot be read.
+    fn span_json(&mut self, file: &str, start: u32, length: u32) -> serde_json::Value {
+        let end = start.saturating_add(length);
+        let position = |reporter: &mut Self, offset: u32| {
+            reporter
+                .position_for(file, offset)
+                .map_or(serde_json::Value::Null, |(line, column)| {
+                    serde_json::json!({ "line": line, "column": column, "offset": offset })
+                })
+        };
+        let file_display = if file.is_empty() {
+            serde_json::Value::Null
+        } else {
+            self.relative_path(file).into()
+        };
+        serde_json::json!({
+            "file": file_display,
+            "start": position(self, start),
+            "end": position(self, end),
+            "length": length,
+        })
+    }
+
+    /// Render diagnostics as a single SARIF 2.1.0 log (--format sarif).
+    ///
+    /// One run with one rule per distinct `TS{code}`; each diagnostic becomes
+    /// a result whose message is the flattened chain and whose related
+    /// locations carry their own messages. Always emits a complete log, even
+    /// when there are no results, so upload steps have a file to consume.
+    fn render_sarif(&mut self, out: &mut String, diagnostics: &[Diagnostic]) {
+        let mut codes: Vec<u32> = diagnostics.iter().map(|d| d.code).collect();
+        codes.sort_unstable();
+        codes.dedup();
+        let rules: Vec<serde_json::Value> = codes
+            .iter()
+            .map(|code| serde_json::json!({ "id": format!("TS{code}") }))
+            .collect();
+        let results: Vec<serde_json::Value> = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let rule_index = codes.binary_search(&diagnostic.code).unwrap_or_default();
+                self.sarif_result(diagnostic, rule_index)
+            })
+            .collect();
+        let log = serde_json::json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "tsz",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    }
+                },
+                "columnKind": "utf16CodeUnits",
+                "results": results,
+            }],
+        });
+        out.push_str(&serde_json::to_string_pretty(&log).unwrap_or_default());
+        out.push('\n');
+    }
+
+    fn sarif_result(&mut self, diagnostic: &Diagnostic, rule_index: usize) -> serde_json::Value {
+        let chain = self.translate_chain(&diagnostic.message_chain());
+        let level = match diagnostic.category {
+            DiagnosticCategory::Error => "error",
+            DiagnosticCategory::Warning => "warning",
+            DiagnosticCategory::Suggestion | DiagnosticCategory::Message => "note",
+        };
+        let locations: Vec<serde_json::Value> = self
+            .sarif_physical_location(&diagnostic.file, diagnostic.start, diagnostic.length)
+            .map(|location| serde_json::json!({ "physicalLocation": location }))
+            .into_iter()
+            .collect();
+        let related: Vec<serde_json::Value> = diagnostic
+            .related_locations()
+            .filter_map(|related| {
+                let location =
+                    self.sarif_physical_location(&related.file, related.start, related.length)?;
+                let message = self.translate_message(related.code, &related.message_text);
+                Some((location, message))
+            })
+            .enumerate()
+            .map(|(id, (location, message))| {
+                serde_json::json!({
+                    "id": id,
+                    "physicalLocation": location,
+                    "message": { "text": message },
+                })
+            })
+            .collect();
+        let mut result = serde_json::json!({
+            "ruleId": format!("TS{}", diagnostic.code),
+            "ruleIndex": rule_index,
+            "level": level,
+            "message": { "text": chain.flatten("\n") },
+            "locations": locations,
+        });
+        if !related.is_empty() {
+            result["relatedLocations"] = related.into();
+        }
+        if let Some(suggestion) = spelling_suggestion(diagnostic)
+            && let Some(location) =
+                self.sarif_physical_location(&diagnostic.file, suggestion.start, suggestion.length)
+            && location.get("region").is_some()
+        {
+            result["fixes"] = serde_json::json!([{
+                "description": { "text": self.change_spelling_description(&suggestion.name) },
+                "artifactChanges": [{
+                    "artifactLocation": location["artifactLocation"],
+                    "replacements": [{
+                        "deletedRegion": location["region"],
+                        "insertedContent": { "text": suggestion.name },
+                    }],
+                }],
+            }]);
+        }
+        result
+    }
+
+    /// SARIF `physicalLocation` for a span: a `%SRCROOT%`-relative URI with
+    /// forward slashes and a 1-based region whose `endColumn` is exclusive.
+    /// `None` for diagnostics without a file (e.g. global option errors).
+    fn sarif_physical_location(
+        &mut self,
+        file: &str,
+        start: u32,
+        length: u32,
+    ) -> Option<serde_json::Value> {
+        if file.is_empty() {
+            return None;
+        }
+        let uri = self.relative_path(file).replace('\\', "/");
+        let mut location = serde_json::json!({
+            "artifactLocation": { "uri": uri, "uriBaseId": "%SRCROOT%" },
+        });
+        if let (Some((start_line, start_column)), Some((end_line, end_column))) = (
+            self.position_for(file, start),
+            self.position_for(file, start.saturating_add(length)),
+        ) {
+            location["region"] = serde_json::json!({
+                "startLine": start_line,
+                "startColumn": start_column,
+                "endLine": end_line,
+                "endColumn": end_column,
+            });
+        }
+        Some(location)
+    }
+
+    /// Translate every node of a message chain using the current locale.
+    fn translate_chain(&self, chain: &DiagnosticMessageChain) -> DiagnosticMessageChain {
+        DiagnosticMessageChain {
+            category: chain.category,
+            code: chain.code,
+            message_text: self.translate_message(chain.code, &chain.message_text),
+            next: chain
+                .next
+                .iter()
+                .map(|next| self.translate_chain(next))
+                .collect(),
+        }
+    }
+
     /// Render diagnostics in pretty mode (--pretty true / default with terminal).
     /// Format: `file:line:col - error TScode: message` + source snippet + summary.
     fn render_pretty(&mut self, out: &mut String, diagnostics: &[Diagnostic]) {
@@ -124,15 +366,9 @@ impl Reporter {
             out.push_str(&self.format_code_label(diagnostic.code));
         }
         out.push_str(": ");
-        // Translate message using current locale if available
-        let message = self.translate_message(diagnostic.code, &diagnostic.message_text);
-        out.push_str(&message);
-
-        // Non-pretty: related info is shown inline
-        for related in &diagnostic.related_information {
-            out.push('\n');
-            self.format_related_plain(out, related);
-        }
+        // Non-pretty: the elaboration chain is shown inline. Related locations
+        // ("'x' is declared here.") are only shown in pretty mode, as in tsc.
+        self.format_message_chain(out, &diagnostic.message_chain(), 0);
     }
 
     /// Format a single diagnostic in pretty mode.
@@ -185,140 +421,159 @@ impl Reporter {
         } else {
             out.push_str(": ");
         }
-        // Translate message using current locale if available
-        let message = self.translate_message(diagnostic.code, &diagnostic.message_text);
-        out.push_str(&message);
+        self.format_message_chain(out, &diagnostic.message_chain(), 0);
 
         // Source snippet
-        if let Some(snippet) =
-            self.format_snippet_pretty(&diagnostic.file, diagnostic.start, diagnostic.length, 0)
-        {
+        if let Some(snippet) = self.format_code_span(
+            &diagnostic.file,
+            diagnostic.start,
+            diagnostic.length,
+            "",
+            Color::BrightRed,
+        ) {
             out.push('\n');
             out.push_str(&snippet);
         }
 
-        // Related information
-        for related in &diagnostic.related_information {
+        // Related locations, separated from the snippet by a blank line
+        let mut related_locations = diagnostic.related_locations().peekable();
+        if related_locations.peek().is_some() {
+            out.push('\n');
+        }
+        for related in related_locations {
             out.push('\n');
             self.format_related_pretty(out, related);
         }
     }
 
-    /// Format a source code snippet in pretty mode, matching tsc's format exactly.
+    /// Format the source lines covered by `start..start + length`, matching
+    /// tsc's `formatCodeSpan`.
     /// ```text
     /// {line_num} {source_line}
-    /// {spaces}{tildes}
+    /// {gutter}   {spaces}{tildes}
     /// ```
-    /// The `indent` parameter adds leading spaces (used for related info: 4 spaces).
-    fn format_snippet_pretty(
+    /// Every source line of a multi-line span is shown and underlined; spans of
+    /// five or more lines show only the first two and last two, separated by a
+    /// `...` gutter. Tabs are shown as single spaces and trailing whitespace is
+    /// trimmed. Each line, including the first, is preceded by a newline.
+    fn format_code_span(
         &mut self,
         file: &str,
         start: u32,
         length: u32,
-        indent: usize,
+        indent: &str,
+        squiggle_color: Color,
     ) -> Option<String> {
-        if file.is_empty() || length == 0 {
+        if file.is_empty() {
             return None;
         }
-
-        let (line_num, column) = self.position_for(file, start)?;
+        self.position_for(file, start)?;
         let source = self.sources.get(file)?;
+        let line_map = self.line_maps.get(file)?;
 
-        // Get the line containing the error
-        let lines: Vec<&str> = source.lines().collect();
-        let line_idx = (line_num - 1) as usize;
-        if line_idx >= lines.len() {
-            return None;
+        let end = start.saturating_add(length);
+        let first = line_map.offset_to_position(start, source);
+        let last = line_map.offset_to_position(end, source);
+        let (first_line, last_line) = (first.line as usize, last.line as usize);
+        let has_more_than_five_lines = last_line - first_line >= 4;
+        let mut gutter_width = (last_line + 1).to_string().len();
+        if has_more_than_five_lines {
+            gutter_width = gutter_width.max(GUTTER_ELLIPSIS.len());
         }
 
-        let line_text = lines[line_idx];
-        let indent_str = " ".repeat(indent);
-        let line_num_str = line_num.to_string();
-        let line_num_width = line_num_str.len();
-
-        // Source line: {indent}{line_num} {source_line}
         let mut snippet = String::new();
-        // Empty line before source (tsc always has a blank line between header and source)
-        snippet.push('\n');
-
-        if self.color {
-            snippet.push_str(&indent_str);
-            // tsc uses reverse video for line numbers
-            snippet.push_str(&line_num_str.reversed().to_string());
-            snippet.push(' ');
-            snippet.push_str(line_text);
+        let mut line = first_line;
+        while line <= last_line {
             snippet.push('\n');
+            if has_more_than_five_lines && first_line + 1 < line && line < last_line - 1 {
+                snippet.push_str(indent);
+                snippet.push_str(&self.format_gutter(&format!("{GUTTER_ELLIPSIS:>gutter_width$}")));
+                snippet.push_str(" \n");
+                line = last_line - 1;
+            }
 
-            // Underline line
-            snippet.push_str(&indent_str);
-            snippet.push_str(&" ".repeat(line_num_width).reversed().to_string());
-            snippet.push(' ');
-
-            let underline = self.build_underline(line_text, column, length);
-            snippet.push_str(&underline.bright_red().to_string());
-        } else {
-            snippet.push_str(&indent_str);
-            snippet.push_str(&line_num_str);
+            let line_start = line_map.line_start(line).unwrap_or(0) as usize;
+            let line_end = line_map
+                .line_start(line + 1)
+                .map_or(source.len(), |next| next as usize);
+            let line_content = source
+                .get(line_start..line_end)
+                .unwrap_or_default()
+                .trim_end()
+                .replace('\t', " ");
+
+            snippet.push_str(indent);
+            snippet.push_str(&self.format_gutter(&format!("{:>gutter_width$}", line + 1)));
             snippet.push(' ');
-            snippet.push_str(line_text);
+            snippet.push_str(&line_content);
             snippet.push('\n');
 
-            // Underline line: spaces matching line_num width + space + column offset + tildes
-            snippet.push_str(&indent_str);
-            snippet.push_str(&" ".repeat(line_num_width));
+            snippet.push_str(indent);
+            snippet.push_str(&self.format_gutter(&" ".repeat(gutter_width)));
             snippet.push(' ');
 
-            let underline = self.build_underline(line_text, column, length);
-            snippet.push_str(&underline);
-        }
-
-        Some(snippet)
-    }
-
-    /// Build the underline string (spaces + tildes) for a given column and length.
-    /// Column is 1-indexed. The underline aligns within the source line.
-    fn build_underline(&self, line_text: &str, column: u32, length: u32) -> String {
-        let mut underline = String::new();
-        let col_0 = (column - 1) as usize;
-
-        for (i, ch) in line_text.chars().enumerate() {
-            if i < col_0 {
-                // Before the error span - pad with spaces (tabs expand to spaces)
-                if ch == '\t' {
-                    underline.push_str("    ");
-                } else {
-                    underline.push(' ');
-                }
-            } else if i < col_0 + length as usize {
-                // Within the error span
-                if ch == '\t' {
-                    underline.push_str("~~~~");
+            // Columns are UTF-16 code units, like tsc's string slicing.
+            let units: Vec<u16> = line_content.encode_utf16().collect();
+            let clamp = |character: u32| (character as usize).min(units.len());
+            let (blank, tildes) = if line == first_line {
+                let squiggle_start = clamp(first.character);
+                let squiggle_end = if line == last_line {
+                    clamp(last.character).max(squiggle_start)
                 } else {
-                    underline.push('~');
-                }
+                    units.len()
+                };
+                (squiggle_start, squiggle_end - squiggle_start)
+            } else if line == last_line {
+                (0, clamp(last.character))
+            } else {
+                (0, units.len())
+            };
+            let mut underline: String = char::decode_utf16(units[..blank].iter().copied())
+                .map(|ch| match ch {
+                    Ok(ch) if ch.is_whitespace() => ch,
+                    _ => ' ',
+                })
+                .collect();
+            underline.push_str(&"~".repeat(tildes));
+            if self.color {
+                snippet.push_str(&underline.color(squiggle_color).to_string());
             } else {
-                break;
+                snippet.push_str(&underline);
             }
+            line += 1;
         }
 
-        // If underline is empty but we have a length, show at least one ~
-        if underline.trim().is_empty() && length > 0 {
-            underline = " ".repeat(col_0) + "~";
-        }
+        Some(snippet)
+    }
 
-        underline
+    /// Line-number gutter text; tsc draws it in reverse video.
+    fn format_gutter(&self, text: &str) -> String {
+        if self.color {
+            text.reversed().to_string()
+        } else {
+            text.to_string()
+        }
     }
 
-    /// Format related information in non-pretty mode.
-    /// tsc format: `  message` (no file/line/code prefix — just indented text).
-    fn format_related_plain(&mut self, out: &mut String, related: &DiagnosticRelatedInformation) {
-        // tsc indents each nested elaboration level by 2 more spaces than its
-        // parent. `depth == 0` is the first level (2 spaces); deeper chain
-        // links carry their nesting depth so the chain structure is visible.
-        let indent = 2 * (related.depth as usize + 1);
-        out.push_str(&" ".repeat(indent));
-        let message = self.translate_message(related.code, &related.message_text);
+    /// Format a message chain the way tsc's `flattenDiagnosticMessageText`
+    /// does: each nested elaboration on its own line, indented by 2 more spaces
+    /// than its parent (no file/line/code prefix).
+    fn format_message_chain(
+        &self,
+        out: &mut String,
+        chain: &DiagnosticMessageChain,
+        indent: usize,
+    ) {
+        if indent > 0 {
+            out.push('\n');
+            out.push_str(&"  ".repeat(indent));
+        }
+        // Translate message using current locale if available
+        let message = self.translate_message(chain.code, &chain.message_text);
         out.push_str(&message);
+        for next in &chain.next {
+            self.format_message_chain(out, next, indent + 1);
+        }
     }
 
     /// Format related information in pretty mode.
@@ -328,102 +583,48 @@ impl Reporter {
     ///     {spaces}{tildes}
     ///     message
     /// ```
+    /// Related information without a file is shown as the message line only.
     fn format_related_pretty(&mut self, out: &mut String, related: &DiagnosticRelatedInformation) {
-        let file_display = self.relative_path(&related.file);
+        if !related.file.is_empty() {
+            let file_display = self.relative_path(&related.file);
 
-        // Location line (2-space indent)
-        out.push_str("  ");
-        if let Some((line, col)) = self.position_for(&related.file, related.start) {
-            if self.color {
-                out.push_str(&file_display.bright_cyan().to_string());
-                out.push(':');
-                out.push_str(&line.to_string().bright_yellow().to_string());
-                out.push(':');
-                out.push_str(&col.to_string().bright_yellow().to_string());
-            } else {
-                out.push_str(&format!("{file_display}:{line}:{col}"));
-            }
-        } else if !related.file.is_empty() {
-            if self.color {
+            // Location line (2-space indent)
+            out.push_str("  ");
+            if let Some((line, col)) = self.position_for(&related.file, related.start) {
+                if self.color {
+                    out.push_str(&file_display.bright_cyan().to_string());
+                    out.push(':');
+                    out.push_str(&line.to_string().bright_yellow().to_string());
+                    out.push(':');
+                    out.push_str(&col.to_string().bright_yellow().to_string());
+                } else {
+                    out.push_str(&format!("{file_display}:{line}:{col}"));
+                }
+            } else if self.color {
                 out.push_str(&file_display.bright_cyan().to_string());
             } else {
                 out.push_str(&file_display);
             }
-        }
 
-        // Source snippet (4-space indent)
-        if let Some(snippet) =
-            self.format_snippet_pretty_related(&related.file, related.start, related.length)
-        {
-            out.push_str(&snippet);
+            // Source snippet (4-space indent, cyan underline)
+            if let Some(snippet) = self.format_code_span(
+                &related.file,
+                related.start,
+                related.length,
+                "    ",
+                Color::BrightCyan,
+            ) {
+                out.push_str(&snippet);
+            }
+            out.push('\n');
         }
 
         // Message (4-space indent)
-        out.push('\n');
         out.push_str("    ");
         let message = self.translate_message(related.code, &related.message_text);
         out.push_str(&message);
     }
 
-    /// Format snippet for related info with 4-space indent and cyan underline.
-    fn format_snippet_pretty_related(
-        &mut self,
-        file: &str,
-        start: u32,
-        length: u32,
-    ) -> Option<String> {
-        if file.is_empty() || length == 0 {
-            return None;
-        }
-
-        let (line_num, column) = self.position_for(file, start)?;
-        let source = self.sources.get(file)?;
-
-        let lines: Vec<&str> = source.lines().collect();
-        let line_idx = (line_num - 1) as usize;
-        if line_idx >= lines.len() {
-            return None;
-        }
-
-        let line_text = lines[line_idx];
-        let line_num_str = line_num.to_string();
-        let line_num_width = line_num_str.len();
-
-        let mut snippet = String::new();
-        snippet.push('\n');
-
-        if self.color {
-            snippet.push_str("    ");
-            snippet.push_str(&line_num_str.reversed().to_string());
-            snippet.push(' ');
-            snippet.push_str(line_text);
-            snippet.push('\n');
-
-            snippet.push_str("    ");
-            snippet.push_str(&" ".repeat(line_num_width).reversed().to_string());
-            snippet.push(' ');
-
-            // Related info uses cyan underline (not red)
-            let underline = self.build_underline(line_text, column, length);
-            snippet.push_str(&underline.bright_cyan().to_string());
-        } else {
-            snippet.push_str("    ");
-            snippet.push_str(&line_num_str);
-            snippet.push(' ');
-            snippet.push_str(line_text);
-            snippet.push('\n');
-
-            snippet.push_str("    ");
-            snippet.push_str(&" ".repeat(line_num_width));
-            snippet.push(' ');
-
-            let underline = self.build_underline(line_text, column, length);
-            snippet.push_str(&underline);
-        }
-
-        Some(snippet)
-    }
-
     /// Format the error summary line at the end of pretty output, matching tsc exactly.
     fn format_summary(&self, out: &mut String, diagnostics: &[Diagnostic]) {
         let error_count = diagnostics
@@ -599,12 +800,7 @@ impl Reporter {
     }
 
     fn format_category_label(&self, category: DiagnosticCategory) -> String {
-        let label = match category {
-            DiagnosticCategory::Error => "error",
-            DiagnosticCategory::Warning => "warning",
-            DiagnosticCategory::Suggestion => "suggestion",
-            DiagnosticCategory::Message => "message",
-        };
+        let label = category_name(category);
 
         if !self.color {
             return label.to_string();
@@ -640,6 +836,35 @@ impl Reporter {
     }
 }
 
+const fn category_name(category: DiagnosticCategory) -> &'static str {
+    match category {
+        DiagnosticCategory::Error => "error",
+        DiagnosticCategory::Warning => "warning",
+        DiagnosticCategory::Suggestion => "suggestion",
+        DiagnosticCategory::Message => "message",
+    }
+}
+
+/// `{"category", "code", "messageText", "next"}`, mirroring tsc's
+/// `DiagnosticMessageChain` shape.
+fn chain_json(chain: &DiagnosticMessageChain) -> serde_json::Value {
+    serde_json::json!({
+        "category": category_name(chain.category),
+        "code": chain.code,
+        "messageText": chain.message_text,
+        "next": chain.next.iter().map(chain_json).collect::<Vec<_>>(),
+    })
+}
+
+/// The "Change spelling" fix of a diagnostic, as tsc's code action applies
+/// it: replace the misspelled name with the suggested one.
+fn spelling_suggestion(diagnostic: &Diagnostic) -> Option<&SpellingSuggestion> {
+    diagnostic
+        .spelling_suggestion
+        .as_ref()
+        .filter(|suggestion| !diagnostic.file.is_empty() && !suggestion.name.is_empty())
+}
+
 /// Decode raw file bytes to a UTF-8 string, handling UTF-16 BOM-encoded files.
 ///
 /// TypeScript test files may be encoded as UTF-16 LE or UTF-16 BE with a BOM.
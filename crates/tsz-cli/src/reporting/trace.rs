@@ -207,6 +207,25 @@ impl Tracer {
         });
     }
 
+    /// Record phases as back-to-back complete events starting at `start`.
+    ///
+    /// The driver only reports per-phase totals, so the phases are laid out
+    /// in order on one timeline. Phases that took no time are skipped.
+    pub fn sequential_phases<'p>(
+        &mut self,
+        start: Instant,
+        phases: impl IntoIterator<Item = (&'p str, &'p str, Duration)>,
+    ) {
+        let mut phase_start = start;
+        for (name, category, duration) in phases {
+            if duration.is_zero() {
+                continue;
+            }
+            self.complete(name, category, phase_start, duration);
+            phase_start += duration;
+        }
+    }
+
     /// Record an instant event
     pub fn instant(&mut self, name: &str, category: &str) {
         let ts = self.timestamp();
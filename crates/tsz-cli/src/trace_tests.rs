@@ -154,3 +154,24 @@ fn test_write_to_file_creates_parent_dir_and_round_trips_json() {
     assert_eq!(events[0]["ph"], serde_json::json!("i"));
     assert!(path.exists());
 }
+
+#[test]
+fn test_sequential_phases_are_laid_out_back_to_back() {
+    let mut tracer = Tracer::new();
+    let start = Instant::now();
+    tracer.sequential_phases(
+        start,
+        [
+            ("parse", categories::PARSE, Duration::from_millis(2)),
+            ("bind", categories::BIND, Duration::ZERO),
+            ("check", categories::CHECK, Duration::from_millis(3)),
+        ],
+    );
+
+    let events = tracer.events();
+    assert_eq!(events.len(), 2, "zero-duration phases are skipped");
+    assert_eq!(events[0].name, "parse");
+    assert_eq!(events[1].name, "check");
+    assert_eq!(events[0].dur, Some(2000));
+    assert_eq!(events[1].ts, events[0].ts + 2000);
+}
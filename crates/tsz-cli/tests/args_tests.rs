@@ -2,8 +2,8 @@ use clap::Parser;
 use tsz::emitter::{ModuleKind, ScriptTarget};
 
 use super::args::{
-    CliArgs, ImportsNotUsedAsValues, JsxEmit, Module, ModuleDetection, ModuleResolution, NewLine,
-    PollingWatchKind, Target, WatchDirectoryKind, WatchFileKind,
+    CliArgs, DiagnosticFormat, ImportsNotUsedAsValues, JsxEmit, Module, ModuleDetection,
+    ModuleResolution, NewLine, PollingWatchKind, Target, WatchDirectoryKind, WatchFileKind,
 };
 
 fn option_len<T>(values: Option<&[T]>) -> Option<usize> {
@@ -571,12 +571,15 @@ fn parses_output_formatting_flags() {
         "true",
         "--noErrorTruncation",
         "--preserveWatchOutput",
+        "--format",
+        "json",
     ])
     .expect("output formatting flags should parse");
 
     assert_eq!(args.pretty, Some(true));
     assert!(args.no_error_truncation);
     assert!(args.preserve_watch_output);
+    assert_eq!(args.format, Some(DiagnosticFormat::Json));
 }
 
 #[test]
@@ -607,6 +610,15 @@ fn parses_watch_flags() {
     assert_eq!(option_len(args.exclude_files.as_deref()), Some(2));
 }
 
+#[test]
+fn parses_watch_event_flags() {
+    let args = CliArgs::try_parse_from(["tsz", "-w", "--watchEvents", "--watchEventPort", "9123"])
+        .expect("watch event flags should parse");
+
+    assert!(args.watch_events);
+    assert_eq!(args.watch_event_port, Some(9123));
+}
+
 #[test]
 fn parses_watch_file_variants() {
     let variants = [
@@ -1171,3 +1183,16 @@ fn end_to_end_target_module_resolution_wiring() {
         ModuleResolutionKind::Bundler
     );
 }
+
+#[test]
+fn parses_suggestions_flag() {
+    let args = CliArgs::try_parse_from(["tsz", "--suggestions", "main.ts"])
+        .expect("--suggestions should parse");
+
+    assert!(args.suggestions);
+    assert!(
+        !CliArgs::try_parse_from(["tsz", "main.ts"])
+            .unwrap()
+            .suggestions
+    );
+}
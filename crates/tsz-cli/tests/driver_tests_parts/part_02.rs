@@ -339,6 +339,75 @@ fn compile_single_source_amd_outfile_emits_bundle() {
     );
 }
 
+#[test]
+fn compile_amd_outfile_with_source_map_writes_merged_bundle_map() {
+    let temp = TempDir::new().expect("temp dir");
+    let base = &temp.path;
+
+    write_file(
+        &base.join("tsconfig.json"),
+        r#"{
+          "compilerOptions": {
+            "target": "es2022",
+            "module": "amd",
+            "outFile": "dist/bundle.js",
+            "sourceMap": true,
+            "ignoreDeprecations": "6.0"
+          },
+          "files": ["src/main.ts"]
+        }"#,
+    );
+    write_file(&base.join("src/dep.ts"), "export const dep = 1;");
+    write_file(
+        &base.join("src/main.ts"),
+        "import { dep } from \"./dep\";\nexport const value = dep;",
+    );
+
+    let args = default_args();
+    let result = with_types_versions_env(None, || {
+        compile(&args, base).expect("compile should succeed")
+    });
+    assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+
+    let bundle = std::fs::read_to_string(base.join("dist/bundle.js")).expect("read bundle");
+    assert_eq!(
+        bundle.matches("sourceMappingURL").count(),
+        1,
+        "chunks should not carry their own map comments:\n{bundle}"
+    );
+    assert!(bundle.ends_with("//# sourceMappingURL=bundle.js.map"));
+    let dep_define = bundle.find("define(\"dep\"").expect("dep chunk");
+    let main_define = bundle.find("define(\"main\"").expect("main chunk");
+    assert!(dep_define < main_define, "dependency first:\n{bundle}");
+
+    let map_contents =
+        std::fs::read_to_string(base.join("dist/bundle.js.map")).expect("read bundle map");
+    let map_json: Value = serde_json::from_str(&map_contents).expect("parse map json");
+    assert_eq!(map_json["file"], "bundle.js");
+    let sources: Vec<&str> = map_json["sources"]
+        .as_array()
+        .expect("sources")
+        .iter()
+        .filter_map(Value::as_str)
+        .collect();
+    assert_eq!(sources.len(), 2, "{sources:?}");
+    assert!(sources[0].ends_with("src/dep.ts") && !sources[0].starts_with('/'));
+    assert!(sources[1].ends_with("src/main.ts") && !sources[1].starts_with('/'));
+
+    // Each chunk's mappings land on its own lines of the bundle.
+    let main_line = bundle[..main_define].matches('\n').count() as u32;
+    let mappings = map_json["mappings"].as_str().expect("mappings");
+    let decoded = tsz_common::source_map::decode_mappings(mappings).expect("decode mappings");
+    assert!(decoded.iter().any(|mapping| mapping.source_index == 1));
+    for mapping in &decoded {
+        if mapping.source_index == 0 {
+            assert!(mapping.generated_line < main_line);
+        } else {
+            assert!(mapping.generated_line >= main_line);
+        }
+    }
+}
+
 #[test]
 fn compile_module_none_outfile_skips_dynamic_import_only_dependency() {
     let temp = TempDir::new().expect("temp dir");
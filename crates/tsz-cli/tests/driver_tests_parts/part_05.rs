@@ -1289,6 +1289,49 @@ fn compile_with_cache_updates_dependencies_for_changed_files() {
     );
 }
 
+#[test]
+fn compile_with_cache_and_changes_rechecks_importers_of_deleted_file() {
+    let temp = TempDir::new().expect("temp dir");
+    let base = &temp.path;
+
+    write_file(
+        &base.join("tsconfig.json"),
+        r#"{
+          "compilerOptions": {
+            "noEmit": true
+          },
+          "files": ["src/index.ts"]
+        }"#,
+    );
+
+    let index_path = base.join("src/index.ts");
+    let util_path = base.join("src/util.ts");
+    write_file(
+        &index_path,
+        "import { value } from './util'; export const doubled = value * 2;",
+    );
+    write_file(&util_path, "export const value = 1;");
+
+    let mut cache = CompilationCache::default();
+    let args = default_args();
+
+    let result = compile_with_cache(&args, base, &mut cache).expect("compile should succeed");
+    assert!(result.diagnostics.is_empty());
+
+    let canonical = std::fs::canonicalize(&util_path).unwrap_or(util_path.clone());
+    std::fs::remove_file(&util_path).expect("remove util.ts");
+    let result = compile_with_cache_and_changes(&args, base, &mut cache, &[canonical])
+        .expect("compile should succeed");
+    assert!(
+        result
+            .diagnostics
+            .iter()
+            .any(|diag| diag.file.contains("index.ts") && diag.code == 2307),
+        "expected TS2307 in index.ts, got: {:?}",
+        result.diagnostics
+    );
+}
+
 #[test]
 fn compile_with_cache_skips_dependents_when_exports_unchanged() {
     let temp = TempDir::new().expect("temp dir");
@@ -1574,6 +1617,119 @@ fn invalidate_paths_with_dependents_symbols_keeps_unrelated_cache() {
     assert!(cache.symbol_cache_len(&canonical_util).is_none());
 }
 
+#[test]
+fn invalidate_shifted_symbols_drops_types_whose_stable_identity_moved() {
+    let temp = TempDir::new().expect("temp dir");
+    let base = &temp.path;
+
+    write_file(
+        &base.join("tsconfig.json"),
+        r#"{
+          "compilerOptions": {
+            "outDir": "dist"
+          },
+          "files": ["src/index.ts"]
+        }"#,
+    );
+    let index_path = base.join("src/index.ts");
+    let util_path = base.join("src/util.ts");
+    write_file(
+        &index_path,
+        "import { value } from './util'; export const local = 1; export const uses = value;",
+    );
+    write_file(&util_path, "export const value = 1;");
+
+    let mut cache = CompilationCache::default();
+    let args = default_args();
+
+    let result = compile_with_cache(&args, base, &mut cache).expect("compile should succeed");
+    assert!(result.diagnostics.is_empty());
+    assert!(cache.stable_symbol_ids_len() > 0);
+
+    let canonical_index = std::fs::canonicalize(&index_path).unwrap_or(index_path);
+    let canonical_util = std::fs::canonicalize(&util_path).unwrap_or(util_path);
+    let before = cache.symbol_cache_len(&canonical_index).unwrap_or(0);
+    assert!(before > 0);
+
+    // Re-merging without the lib files renumbers every SymbolId, so the
+    // cached entries no longer line up with the stable symbols they named.
+    let program = tsz::parallel::merge_bind_results(tsz::parallel::parse_and_bind_parallel(vec![
+        (
+            canonical_index.to_string_lossy().into_owned(),
+            "import { value } from './util'; export const local = 1; export const uses = value;"
+                .to_string(),
+        ),
+        (
+            canonical_util.to_string_lossy().into_owned(),
+            "export const extra = 0; export const value = 1;".to_string(),
+        ),
+    ]));
+    cache.invalidate_shifted_symbols(&program);
+
+    let after = cache.symbol_cache_len(&canonical_index).unwrap_or(0);
+    assert!(after < before);
+    assert_eq!(cache.stable_symbol_ids_len(), program.stable_symbol_ids.len());
+}
+
+#[test]
+fn assume_changes_only_affect_direct_dependencies_rechecks_only_direct_importers() {
+    let temp = TempDir::new().expect("temp dir");
+    let base = &temp.path;
+
+    write_file(
+        &base.join("tsconfig.json"),
+        r#"{
+          "compilerOptions": {
+            "noEmit": true,
+            "assumeChangesOnlyAffectDirectDependencies": true
+          },
+          "files": ["src/index.ts"]
+        }"#,
+    );
+    let index_path = base.join("src/index.ts");
+    let mid_path = base.join("src/mid.ts");
+    let util_path = base.join("src/util.ts");
+    write_file(
+        &index_path,
+        "import { doubled } from './mid'; export const uses: number = doubled;",
+    );
+    write_file(
+        &mid_path,
+        "import { value } from './util'; export const doubled = value; const n: number = value;",
+    );
+    write_file(&util_path, "export const value = 1;");
+
+    let mut cache = CompilationCache::default();
+    let mut args = default_args();
+    args.assume_changes_only_affect_direct_dependencies = true;
+
+    let result = compile_with_cache(&args, base, &mut cache).expect("compile should succeed");
+    assert!(result.diagnostics.is_empty());
+
+    // `mid.ts` is re-checked against the new export type; `index.ts`, one
+    // level further, keeps its cached (empty) diagnostics.
+    write_file(&util_path, "export const value = 'one';");
+    let canonical = std::fs::canonicalize(&util_path).unwrap_or(util_path);
+    let result = compile_with_cache_and_changes(&args, base, &mut cache, &[canonical])
+        .expect("compile should succeed");
+    assert!(
+        result
+            .diagnostics
+            .iter()
+            .any(|diag| diag.file.contains("mid.ts") && diag.code == 2322),
+        "expected TS2322 in mid.ts, got: {:?}",
+        result.diagnostics
+    );
+    assert!(
+        !result
+            .diagnostics
+            .iter()
+            .any(|diag| diag.file.contains("index.ts")),
+        "index.ts should not be re-checked, got: {:?}",
+        result.diagnostics
+    );
+}
+
 #[test]
 fn invalidate_paths_with_dependents_symbols_handles_reexports() {
     let temp = TempDir::new().expect("temp dir");
@@ -823,3 +823,347 @@ fn ts1192_suppressed_for_js_default_import_without_check_js() {
         "TS1192 must not appear for unchecked JS, got: {codes:?}"
     );
 }
+
+#[test]
+fn compile_expands_quoted_glob_file_arguments() {
+    let temp = TempDir::new().expect("temp dir");
+    let base = &temp.path;
+
+    write_file(&base.join("src/a.ts"), "export const a: number = 1;\n");
+    write_file(&base.join("src/nested/b.ts"), "export const b: string = 1;\n");
+    write_file(&base.join("other/c.ts"), "export const c: string = 1;\n");
+
+    let args = parse_args(&["tsz", "--noEmit", "--pretty", "false", "src/**/*.ts"]);
+    let result = compile(&args, base).expect("compile should succeed");
+
+    let files: Vec<String> = result
+        .diagnostics
+        .iter()
+        .map(|d| d.file.replace('\\', "/"))
+        .collect();
+    assert!(
+        files.iter().any(|file| file.ends_with("src/nested/b.ts")),
+        "glob should include nested files, got: {:?}",
+        result.diagnostics
+    );
+    assert!(
+        !files.iter().any(|file| file.ends_with("other/c.ts")),
+        "files outside the glob must not be compiled, got: {:?}",
+        result.diagnostics
+    );
+}
+
+#[test]
+fn compile_reports_emit_skipped_only_when_no_emit_on_error_blocks_emit() {
+    let temp = TempDir::new().expect("temp dir");
+    let base = &temp.path;
+
+    write_file(&base.join("src/index.ts"), "export const value: number = \"x\";");
+    write_file(
+        &base.join("tsconfig.json"),
+        r#"{
+          "compilerOptions": { "outDir": "dist", "noEmitOnError": true },
+          "files": ["src/index.ts"]
+        }"#,
+    );
+    let result = compile(&default_args(), base).expect("compile should succeed");
+    assert!(!result.diagnostics.is_empty());
+    assert!(result.emit_skipped);
+    assert!(!base.join("dist/index.js").is_file());
+
+    // Without `noEmitOnError`, emit runs in the presence of errors.
+    write_file(
+        &base.join("tsconfig.json"),
+        r#"{
+          "compilerOptions": { "outDir": "dist" },
+          "files": ["src/index.ts"]
+        }"#,
+    );
+    let result = compile(&default_args(), base).expect("compile should succeed");
+    assert!(!result.diagnostics.is_empty());
+    assert!(!result.emit_skipped);
+    assert!(base.join("dist/index.js").is_file());
+}
+
+#[test]
+fn compile_emits_independent_files_in_program_order() {
+    let temp = TempDir::new().expect("temp dir");
+    let base = &temp.path;
+
+    let names: Vec<String> = (0..24).map(|idx| format!("f{idx:02}")).collect();
+    for (idx, name) in names.iter().enumerate() {
+        write_file(
+            &base.join(format!("src/{name}.ts")),
+            &format!("export const value{idx}: number = {idx};\n"),
+        );
+    }
+    let files: Vec<String> = names
+        .iter()
+        .map(|name| format!("\"src/{name}.ts\""))
+        .collect();
+    write_file(
+        &base.join("tsconfig.json"),
+        &format!(
+            r#"{{
+              "compilerOptions": {{ "outDir": "dist", "declaration": true, "sourceMap": true }},
+              "files": [{}]
+            }}"#,
+            files.join(", ")
+        ),
+    );
+
+    let expected: Vec<String> = names
+        .iter()
+        .flat_map(|name| {
+            [
+                format!("{name}.js"),
+                format!("{name}.js.map"),
+                format!("{name}.d.ts"),
+            ]
+        })
+        .collect();
+    // Files are emitted on a thread pool; repeated builds must still report
+    // outputs grouped per file, in program order.
+    for _ in 0..3 {
+        let result = compile(&default_args(), base).expect("compile should succeed");
+        assert!(
+            result.diagnostics.is_empty(),
+            "unexpected diagnostics: {:?}",
+            result.diagnostics
+        );
+        let emitted: Vec<String> = result
+            .emitted_files
+            .iter()
+            .filter_map(|path| path.file_name()?.to_str().map(str::to_string))
+            .collect();
+        assert_eq!(emitted, expected);
+    }
+    let js = std::fs::read_to_string(base.join("dist/f07.js")).expect("read f07.js");
+    assert!(
+        js.contains("exports.value7 = 7;"),
+        "unexpected f07.js: {js}"
+    );
+    assert!(
+        js.contains("//# sourceMappingURL=f07.js.map"),
+        "unexpected f07.js: {js}"
+    );
+}
+
+#[test]
+fn list_program_files_follows_imports_and_reports_unresolved_specifiers() {
+    let temp = TempDir::new().expect("temp dir");
+    let base = &temp.path;
+
+    write_file(
+        &base.join("index.ts"),
+        "import { a } from './a';\nimport { missing } from 'missing-package';\nexport const value = a + missing;\n",
+    );
+    write_file(&base.join("a.ts"), "export { b as a } from './nested/b';\n");
+    write_file(&base.join("nested/b.ts"), "export const b = 1;\n");
+    write_file(&base.join("unused.ts"), "export const unused = 1;\n");
+
+    let program = crate::driver::list_program_files(
+        &[base.join("index.ts")],
+        base,
+        &crate::config::ResolvedCompilerOptions::default(),
+    )
+    .expect("list program files");
+
+    let names: Vec<&str> = program
+        .files
+        .iter()
+        .filter_map(|path| path.file_name()?.to_str())
+        .collect();
+    assert_eq!(names, ["index.ts", "a.ts", "b.ts"]);
+    assert_eq!(program.unresolved_imports.len(), 1, "{program:?}");
+    let unresolved = &program.unresolved_imports[0];
+    assert_eq!(unresolved.specifier, "missing-package");
+    assert!(unresolved.containing_file.ends_with("index.ts"));
+    let index_text = std::fs::read_to_string(base.join("index.ts")).expect("read index.ts");
+    let start = unresolved.start as usize;
+    assert_eq!(
+        &index_text[start..start + unresolved.length as usize],
+        "'missing-package'"
+    );
+    assert!(
+        unresolved.message.contains("missing-package"),
+        "unexpected message: {}",
+        unresolved.message
+    );
+}
+
+#[test]
+fn list_program_files_does_not_report_ambient_module_imports() {
+    let temp = TempDir::new().expect("temp dir");
+    let base = &temp.path;
+
+    write_file(
+        &base.join("index.ts"),
+        "import { v } from 'virtual-module';\nimport styles from './app.css';\nimport { m } from 'missing-package';\nexport const value = v + m + styles;\n",
+    );
+    write_file(
+        &base.join("ambient.d.ts"),
+        "declare module 'virtual-module' {\n    export const v: number;\n}\ndeclare module '*.css' {\n    const styles: string;\n    export default styles;\n}\n",
+    );
+
+    let program = crate::driver::list_program_files(
+        &[base.join("index.ts"), base.join("ambient.d.ts")],
+        base,
+        &crate::config::ResolvedCompilerOptions::default(),
+    )
+    .expect("list program files");
+
+    let specifiers: Vec<&str> = program
+        .unresolved_imports
+        .iter()
+        .map(|unresolved| unresolved.specifier.as_str())
+        .collect();
+    assert_eq!(specifiers, ["missing-package"], "{program:?}");
+}
+
+#[test]
+fn compile_with_host_resolves_and_emits_entirely_in_memory() {
+    use tsz::compiler_host::MemoryCompilerHost;
+
+    let root = Path::new("/tsz-virtual-host-test");
+    let project = root.join("proj");
+    let host = Arc::new(MemoryCompilerHost::new(&project));
+    host.add_file(
+        "tsconfig.json",
+        r#"{
+  "compilerOptions": { "module": "commonjs", "outDir": "dist", "declaration": true, "strict": true },
+  "include": ["src"]
+}"#,
+    );
+    host.add_file(
+        "src/index.ts",
+        "import { double } from './util';\nimport { greet } from 'greeter';\nexport const result: string = greet(String(double(2)));\n",
+    );
+    host.add_file(
+        "src/util.ts",
+        "export function double(n: number): number {\n    return n * 2;\n}\n",
+    );
+    host.add_file(
+        "node_modules/greeter/package.json",
+        r#"{ "name": "greeter", "types": "index.d.ts" }"#,
+    );
+    host.add_file(
+        "node_modules/greeter/index.d.ts",
+        "export declare function greet(name: string): string;\n",
+    );
+
+    let result =
+        crate::driver::compile_with_host(&default_args(), host.clone()).expect("compile");
+
+    assert!(
+        result.diagnostics.is_empty(),
+        "unexpected diagnostics: {:?}",
+        result.diagnostics
+    );
+    let index_js = host
+        .file(project.join("dist/index.js"))
+        .unwrap_or_else(|| panic!("missing dist/index.js in {:?}", host.file_paths()));
+    assert!(index_js.contains("require(\"./util\")"), "{index_js}");
+    assert!(index_js.contains("require(\"greeter\")"), "{index_js}");
+    let util_dts = host
+        .file(project.join("dist/util.d.ts"))
+        .expect("missing dist/util.d.ts");
+    assert!(
+        util_dts.contains("export declare function double(n: number): number;"),
+        "{util_dts}"
+    );
+    assert!(!root.exists(), "virtual project leaked onto disk");
+}
+
+#[test]
+fn compile_with_host_overlay_shadows_disk_sources_and_keeps_outputs_in_memory() {
+    use tsz::compiler_host::{MemoryCompilerHost, RealCompilerHost};
+
+    let temp = TempDir::new().expect("temp dir");
+    let base = fs::canonicalize(&temp.path).expect("canonical temp dir");
+
+    write_file(
+        &base.join("tsconfig.json"),
+        r#"{ "compilerOptions": { "outDir": "out" }, "files": ["main.ts"] }"#,
+    );
+    write_file(
+        &base.join("main.ts"),
+        "export const value: number = \"not a number\";\n",
+    );
+
+    let host = Arc::new(MemoryCompilerHost::overlay(
+        &base,
+        Arc::new(RealCompilerHost),
+    ));
+    host.add_file("main.ts", "export const value: number = 1;\n");
+
+    let result =
+        crate::driver::compile_with_host(&default_args(), host.clone()).expect("compile");
+
+    assert!(
+        result.diagnostics.is_empty(),
+        "overlay contents should replace the on-disk source: {:?}",
+        result.diagnostics
+    );
+    let main_js = host
+        .file(base.join("out/main.js"))
+        .unwrap_or_else(|| panic!("missing out/main.js in {:?}", host.file_paths()));
+    assert!(main_js.contains("value = 1"), "{main_js}");
+    assert!(
+        !base.join("out").exists(),
+        "outputs should stay in the overlay"
+    );
+}
+
+#[test]
+fn compile_with_host_keeps_build_info_and_tslib_lookups_in_memory() {
+    use tsz::compiler_host::MemoryCompilerHost;
+
+    let root = Path::new("/tsz-virtual-host-incremental-test");
+    let project = root.join("proj");
+    let host = Arc::new(MemoryCompilerHost::new(&project));
+    host.add_file(
+        "tsconfig.json",
+        r#"{
+  "compilerOptions": {
+    "module": "commonjs",
+    "target": "es5",
+    "outDir": "dist",
+    "incremental": true,
+    "importHelpers": true
+  },
+  "include": ["src"]
+}"#,
+    );
+    host.add_file(
+        "src/index.ts",
+        "class Base {}\nexport class Derived extends Base {}\n",
+    );
+    // A tslib without `__extends`: finding it reports TS2343, while a lookup
+    // that missed the host would report TS2354 instead.
+    host.add_file(
+        "node_modules/tslib/package.json",
+        r#"{ "name": "tslib", "types": "tslib.d.ts" }"#,
+    );
+    host.add_file(
+        "node_modules/tslib/tslib.d.ts",
+        "export declare function __assign(t: any, ...sources: any[]): any;\n",
+    );
+
+    let result =
+        crate::driver::compile_with_host(&default_args(), host.clone()).expect("compile");
+    let codes: Vec<u32> = result.diagnostics.iter().map(|d| d.code).collect();
+    assert!(codes.contains(&2343), "expected TS2343, got {codes:?}");
+    assert!(!codes.contains(&2354), "tslib was looked up off-host: {codes:?}");
+
+    let build_info = host
+        .file_paths()
+        .into_iter()
+        .find(|path| path.extension().is_some_and(|ext| ext == "tsbuildinfo"))
+        .unwrap_or_else(|| panic!("missing .tsbuildinfo in {:?}", host.file_paths()));
+
+    // The second build reads the build info back through the host.
+    crate::driver::compile_with_host(&default_args(), host.clone()).expect("recompile");
+    assert!(host.file(&build_info).is_some());
+    assert!(!root.exists(), "virtual project leaked onto disk");
+}
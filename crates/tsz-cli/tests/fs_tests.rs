@@ -64,6 +64,7 @@ fn discover_files_defaults_exclude_common_dirs() {
         follow_links: false,
         allow_js: false,
         resolve_json_module: false,
+        case_insensitive: false,
     };
 
     let files = discover_ts_files(&options).expect("should discover files");
@@ -92,6 +93,7 @@ fn discover_files_with_include_exclude() {
         follow_links: false,
         allow_js: false,
         resolve_json_module: false,
+        case_insensitive: false,
     };
 
     let files = discover_ts_files(&options).expect("should discover files");
@@ -118,6 +120,7 @@ fn discover_files_includes_explicit_files() {
         follow_links: false,
         allow_js: false,
         resolve_json_module: false,
+        case_insensitive: false,
     };
 
     let files = discover_ts_files(&options).expect("should discover files");
@@ -140,6 +143,7 @@ fn discover_files_missing_explicit_file_errors() {
         follow_links: false,
         allow_js: false,
         resolve_json_module: false,
+        case_insensitive: false,
     };
 
     let err = discover_ts_files(&options).expect_err("missing file should error");
@@ -172,6 +176,7 @@ fn discover_files_follow_links_when_enabled() {
         follow_links: false,
         allow_js: false,
         resolve_json_module: false,
+        case_insensitive: false,
     };
 
     let files = discover_ts_files(&options).expect("discover without links");
@@ -183,3 +188,115 @@ fn discover_files_follow_links_when_enabled() {
         .unwrap_or_else(|_| external.path.join("linked.ts"));
     assert_eq!(files, vec![expected]);
 }
+
+#[test]
+fn discover_files_matches_patterns_case_insensitively_when_requested() {
+    let temp = TempDir::new().expect("temp dir");
+    let base = &temp.path;
+
+    write_file(&base.join("Src/Main.ts"), "export {};");
+    write_file(&base.join("Src/skip.ts"), "export {};");
+
+    let mut options = FileDiscoveryOptions {
+        base_dir: base.to_path_buf(),
+        files: Vec::new(),
+        files_explicitly_set: false,
+        include: Some(vec!["src/m?in.ts".to_string()]),
+        exclude: None,
+        out_dir: None,
+        follow_links: false,
+        allow_js: false,
+        resolve_json_module: false,
+        case_insensitive: false,
+    };
+    let files = discover_ts_files(&options).expect("case-sensitive discovery");
+    assert!(
+        files.is_empty(),
+        "case-sensitive match should miss: {files:?}"
+    );
+
+    options.case_insensitive = true;
+    let files = discover_ts_files(&options).expect("case-insensitive discovery");
+    assert_eq!(to_relative(base, &files), vec!["Src/Main.ts"]);
+}
+
+#[test]
+fn discover_files_custom_exclude_still_skips_package_and_dot_directories() {
+    let temp = TempDir::new().expect("temp dir");
+    let base = &temp.path;
+
+    write_file(&base.join("src/a.ts"), "export const a = 1;");
+    write_file(&base.join("src/.hidden.ts"), "export {};");
+    write_file(&base.join(".cache/cached.ts"), "export {};");
+    write_file(&base.join("node_modules/pkg/index.ts"), "export {};");
+    write_file(&base.join("src/bower_components/lib.ts"), "export {};");
+    write_file(&base.join("dist/out.ts"), "export {};");
+
+    let mut options = FileDiscoveryOptions {
+        base_dir: base.to_path_buf(),
+        files: Vec::new(),
+        files_explicitly_set: false,
+        include: Some(vec!["**/*".to_string()]),
+        exclude: Some(vec!["dist".to_string()]),
+        out_dir: None,
+        follow_links: false,
+        allow_js: false,
+        resolve_json_module: false,
+        case_insensitive: false,
+    };
+
+    let files = discover_ts_files(&options).expect("should discover files");
+    assert_eq!(to_relative(base, &files), vec!["src/a.ts"]);
+
+    // Naming the directory literally opts back in.
+    options.include = Some(vec!["src".to_string(), "node_modules/pkg".to_string()]);
+    let files = discover_ts_files(&options).expect("should discover files");
+    assert_eq!(
+        to_relative(base, &files),
+        vec!["node_modules/pkg/index.ts", "src/a.ts"]
+    );
+}
+
+#[test]
+fn discover_files_prefers_higher_priority_extension_for_wildcard_matches() {
+    let temp = TempDir::new().expect("temp dir");
+    let base = &temp.path;
+
+    write_file(&base.join("a.ts"), "export const a = 1;");
+    write_file(&base.join("a.tsx"), "export const a = 1;");
+    write_file(&base.join("a.d.ts"), "export declare const a: number;");
+    write_file(&base.join("b.js"), "export const b = 1;");
+    write_file(&base.join("b.jsx"), "export const b = 1;");
+    // Different extension groups do not shadow each other.
+    write_file(&base.join("c.mts"), "export const c = 1;");
+    write_file(&base.join("c.d.ts"), "export declare const c: number;");
+
+    let mut options = FileDiscoveryOptions {
+        base_dir: base.to_path_buf(),
+        files: Vec::new(),
+        files_explicitly_set: false,
+        include: None,
+        exclude: None,
+        out_dir: None,
+        follow_links: false,
+        allow_js: true,
+        resolve_json_module: false,
+        case_insensitive: false,
+    };
+
+    let files = discover_ts_files(&options).expect("should discover files");
+    assert_eq!(
+        to_relative(base, &files),
+        vec!["a.ts", "b.js", "c.d.ts", "c.mts"]
+    );
+
+    // Explicitly listed files are always kept.
+    options.files = vec![PathBuf::from("a.d.ts")];
+    options.files_explicitly_set = true;
+    options.include = Some(vec!["*".to_string()]);
+    let files = discover_ts_files(&options).expect("should discover files");
+    assert_eq!(
+        to_relative(base, &files),
+        vec!["a.d.ts", "a.ts", "b.js", "c.d.ts", "c.mts"]
+    );
+}
@@ -1,8 +1,9 @@
+use super::args::DiagnosticFormat;
 use super::reporter::Reporter;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
-use tsz_checker::diagnostics::{Diagnostic, DiagnosticCategory};
+use tsz_checker::diagnostics::{Diagnostic, DiagnosticCategory, SpellingSuggestion};
 
 static TEMP_DIR_COUNTER: AtomicU64 = AtomicU64::new(0);
 
@@ -335,6 +336,72 @@ fn pretty_mode_has_blank_line_between_header_and_snippet() {
     );
 }
 
+#[test]
+fn pretty_mode_underlines_every_line_of_a_multiline_span() {
+    let temp = TempDir::new().expect("temp dir");
+    let file_path = temp.path.join("test.ts");
+    let source = "let x = {\n\ta: 1,\n};\n";
+    write_file(&file_path, source);
+
+    let start = source.find('{').unwrap() as u32;
+    let end = source.find('}').unwrap() as u32 + 1;
+    let diagnostic = Diagnostic::error(
+        file_path.to_string_lossy().into_owned(),
+        start,
+        end - start,
+        "Type '{ a: number; }' is not assignable to type 'string'.".to_string(),
+        2322,
+    );
+
+    let mut reporter = Reporter::new(false);
+    reporter.set_pretty(true);
+    let output = reporter.render(&[diagnostic]);
+
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(
+        &lines[2..8],
+        &[
+            "1 let x = {",
+            "          ~",
+            "2  a: 1,",
+            "  ~~~~~~",
+            "3 };",
+            "  ~",
+        ],
+        "unexpected code span: {output}"
+    );
+}
+
+#[test]
+fn pretty_mode_elides_middle_lines_of_long_spans() {
+    let temp = TempDir::new().expect("temp dir");
+    let file_path = temp.path.join("test.ts");
+    let source = "f(\n  1,\n  2,\n  3,\n  4,\n);\n";
+    write_file(&file_path, source);
+
+    let diagnostic = Diagnostic::error(
+        file_path.to_string_lossy().into_owned(),
+        0,
+        source.rfind(')').unwrap() as u32 + 1,
+        "Expected 0 arguments, but got 4.".to_string(),
+        2554,
+    );
+
+    let mut reporter = Reporter::new(false);
+    reporter.set_pretty(true);
+    let output = reporter.render(&[diagnostic]);
+
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(
+        &lines[2..11],
+        &[
+            "  1 f(", "    ~~", "  2   1,", "    ~~~~", "... ", "  5   4,", "    ~~~~", "  6 );",
+            "    ~",
+        ],
+        "unexpected code span: {output}"
+    );
+}
+
 #[test]
 fn plain_mode_renders_related_information_as_indented_message_only() {
     let temp = TempDir::new().expect("temp dir");
@@ -371,6 +438,309 @@ fn plain_mode_renders_related_information_as_indented_message_only() {
     );
 }
 
+#[test]
+fn plain_mode_omits_related_locations_but_pretty_mode_shows_them() {
+    let temp = TempDir::new().expect("temp dir");
+    let file_path = temp.path.join("test.ts");
+    write_file(&file_path, "x;\nlet x = 1;\n");
+    let file_name = file_path.to_string_lossy().into_owned();
+
+    let mut diagnostic = Diagnostic::error(
+        file_name.clone(),
+        0,
+        1,
+        "Block-scoped variable 'x' used before its declaration.".to_string(),
+        2448,
+    );
+    diagnostic
+        .related_information
+        .push(Diagnostic::related_message(
+            2728,
+            file_name,
+            7,
+            1,
+            "'x' is declared here.",
+        ));
+
+    let mut reporter = Reporter::new(false);
+    let plain = reporter.render(std::slice::from_ref(&diagnostic));
+    assert_eq!(
+        plain.lines().count(),
+        1,
+        "plain output should not list related locations: {plain}"
+    );
+
+    reporter.set_pretty(true);
+    let pretty = reporter.render(&[diagnostic]);
+    assert!(
+        pretty.contains("test.ts:2:5") && pretty.contains("'x' is declared here."),
+        "pretty output should show the related location: {pretty}"
+    );
+}
+
+#[test]
+fn message_chain_is_indented_by_depth_in_plain_and_pretty_modes() {
+    let temp = TempDir::new().expect("temp dir");
+    let file_path = temp.path.join("test.ts");
+    write_file(&file_path, "let t: { a: number } = src;\n");
+    let file_name = file_path.to_string_lossy().into_owned();
+
+    let mut diagnostic = Diagnostic::error(
+        file_name.clone(),
+        4,
+        1,
+        "Type '{ a: string; }' is not assignable to type '{ a: number; }'.".to_string(),
+        2322,
+    );
+    for (depth, code, text) in [
+        (0, 2326, "Types of property 'a' are incompatible."),
+        (1, 2322, "Type 'string' is not assignable to type 'number'."),
+    ] {
+        let mut related = Diagnostic::related_message(code, file_name.clone(), 4, 1, text);
+        related.depth = depth;
+        diagnostic.related_information.push(related);
+    }
+
+    let mut reporter = Reporter::new(false);
+    let plain = reporter.render(std::slice::from_ref(&diagnostic));
+    let lines: Vec<&str> = plain.lines().collect();
+    assert_eq!(lines.len(), 3, "expected the chain on three lines: {plain}");
+    assert_eq!(lines[1], "  Types of property 'a' are incompatible.");
+    assert_eq!(
+        lines[2],
+        "    Type 'string' is not assignable to type 'number'."
+    );
+
+    reporter.set_pretty(true);
+    let pretty = reporter.render(&[diagnostic]);
+    let lines: Vec<&str> = pretty.lines().collect();
+    assert!(
+        lines[0].ends_with("is not assignable to type '{ a: number; }'."),
+        "header: {pretty}"
+    );
+    assert_eq!(lines[1], "  Types of property 'a' are incompatible.");
+    assert_eq!(
+        lines[2],
+        "    Type 'string' is not assignable to type 'number'."
+    );
+    assert!(
+        !pretty.contains("test.ts:1:5\n"),
+        "elaborations must not be rendered as related locations: {pretty}"
+    );
+}
+
+#[test]
+fn json_format_emits_one_object_per_diagnostic_with_chain_and_related() {
+    let temp = TempDir::new().expect("temp dir");
+    let file_path = temp.path.join("test.ts");
+    write_file(&file_path, "x;\nlet x = 1;\n");
+    let file_name = file_path.to_string_lossy().into_owned();
+
+    let mut tdz = Diagnostic::error(
+        file_name.clone(),
+        0,
+        1,
+        "Block-scoped variable 'x' used before its declaration.".to_string(),
+        2448,
+    );
+    tdz.related_information.push(Diagnostic::related_message(
+        2728,
+        file_name.clone(),
+        7,
+        1,
+        "'x' is declared here.",
+    ));
+    let mut assign = Diagnostic::error(
+        file_name.clone(),
+        7,
+        1,
+        "Type '{ a: string; }' is not assignable to type '{ a: number; }'.".to_string(),
+        2322,
+    );
+    assign.related_information.push(Diagnostic::related_message(
+        2326,
+        file_name,
+        7,
+        1,
+        "Types of property 'a' are incompatible.",
+    ));
+
+    let mut reporter = Reporter::new(true);
+    reporter.set_cwd(&temp.path);
+    reporter.set_format(DiagnosticFormat::Json);
+    let output = reporter.render(&[tdz, assign]);
+    assert!(!output.contains('\x1b'), "JSON output must not be colored");
+    let values: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is a JSON object"))
+        .collect();
+    assert_eq!(values.len(), 2, "one line per diagnostic: {output}");
+
+    let tdz = &values[0];
+    assert_eq!(tdz["file"], "test.ts");
+    assert_eq!(tdz["code"], 2448);
+    assert_eq!(tdz["category"], "error");
+    assert_eq!(tdz["start"]["line"], 1);
+    assert_eq!(tdz["start"]["column"], 1);
+    assert_eq!(tdz["end"]["offset"], 1);
+    let related = tdz["relatedInformation"].as_array().expect("related array");
+    assert_eq!(related.len(), 1);
+    assert_eq!(related[0]["code"], 2728);
+    assert_eq!(related[0]["start"]["line"], 2);
+    assert_eq!(related[0]["start"]["column"], 5);
+    assert_eq!(related[0]["message"], "'x' is declared here.");
+
+    let assign = &values[1];
+    assert_eq!(
+        assign["message"],
+        "Type '{ a: string; }' is not assignable to type '{ a: number; }'.\n  \
+         Types of property 'a' are incompatible."
+    );
+    assert_eq!(assign["messageChain"]["code"], 2322);
+    assert_eq!(assign["messageChain"]["next"][0]["code"], 2326);
+    assert_eq!(
+        assign["relatedInformation"].as_array().map(Vec::len),
+        Some(0),
+        "elaborations belong to the chain, not related information"
+    );
+}
+
+#[test]
+fn sarif_format_emits_single_log_with_rules_results_and_related_locations() {
+    let temp = TempDir::new().expect("temp dir");
+    let file_path = temp.path.join("src/test.ts");
+    write_file(&file_path, "x;\nlet x = 1;\n");
+    let file_name = file_path.to_string_lossy().into_owned();
+
+    let mut tdz = Diagnostic::error(
+        file_name.clone(),
+        0,
+        1,
+        "Block-scoped variable 'x' used before its declaration.".to_string(),
+        2448,
+    );
+    tdz.related_information.push(Diagnostic::related_message(
+        2728,
+        file_name.clone(),
+        7,
+        1,
+        "'x' is declared here.",
+    ));
+    let mut unused = Diagnostic::error(
+        file_name,
+        7,
+        1,
+        "'x' is declared but its value is never read.".to_string(),
+        6133,
+    );
+    unused.category = DiagnosticCategory::Suggestion;
+
+    let mut reporter = Reporter::new(true);
+    reporter.set_cwd(&temp.path);
+    reporter.set_format(DiagnosticFormat::Sarif);
+    let output = reporter.render(&[tdz, unused]);
+    let log: serde_json::Value =
+        serde_json::from_str(&output).expect("output is one JSON document");
+
+    assert_eq!(log["version"], "2.1.0");
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "tsz");
+    let rule_ids: Vec<&str> = run["tool"]["driver"]["rules"]
+        .as_array()
+        .expect("rules")
+        .iter()
+        .filter_map(|rule| rule["id"].as_str())
+        .collect();
+    assert_eq!(rule_ids, ["TS2448", "TS6133"]);
+
+    let results = run["results"].as_array().expect("results");
+    assert_eq!(results.len(), 2);
+    let tdz = &results[0];
+    assert_eq!(tdz["ruleId"], "TS2448");
+    assert_eq!(tdz["ruleIndex"], 0);
+    assert_eq!(tdz["level"], "error");
+    let location = &tdz["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "src/test.ts");
+    assert_eq!(location["region"]["startLine"], 1);
+    assert_eq!(location["region"]["startColumn"], 1);
+    assert_eq!(location["region"]["endColumn"], 2);
+    let related = &tdz["relatedLocations"][0];
+    assert_eq!(related["message"]["text"], "'x' is declared here.");
+    assert_eq!(related["physicalLocation"]["region"]["startLine"], 2);
+    assert_eq!(related["physicalLocation"]["region"]["startColumn"], 5);
+
+    assert_eq!(results[1]["level"], "note");
+    assert_eq!(results[1]["ruleIndex"], 1);
+    assert!(results[1].get("relatedLocations").is_none());
+}
+
+#[test]
+fn machine_readable_formats_carry_spelling_fixes() {
+    let temp = TempDir::new().expect("temp dir");
+    let file_path = temp.path.join("test.ts");
+    write_file(&file_path, "const value = 1;\nvalu;\n");
+    let file_name = file_path.to_string_lossy().into_owned();
+
+    let mut misspelled = Diagnostic::error(
+        file_name.clone(),
+        17,
+        4,
+        "Cannot find name 'valu'. Did you mean 'value'?".to_string(),
+        2552,
+    );
+    misspelled.spelling_suggestion = Some(SpellingSuggestion {
+        start: 17,
+        length: 4,
+        name: "value".to_string(),
+    });
+    let missing = Diagnostic::error(
+        file_name,
+        17,
+        4,
+        "Cannot find name 'valu'.".to_string(),
+        2304,
+    );
+
+    let mut reporter = Reporter::new(false);
+    reporter.set_cwd(&temp.path);
+    reporter.set_format(DiagnosticFormat::Json);
+    let output = reporter.render(&[misspelled.clone(), missing.clone()]);
+    let values: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is a JSON object"))
+        .collect();
+    let fix = &values[0]["fixes"][0];
+    assert_eq!(fix["description"], "Change spelling to 'value'");
+    assert_eq!(fix["newText"], "value");
+    assert_eq!(fix["start"]["line"], 2);
+    assert_eq!(fix["length"], 4);
+    assert_eq!(values[1]["fixes"], serde_json::json!([]));
+
+    reporter.set_format(DiagnosticFormat::Sarif);
+    let output = reporter.render(&[misspelled, missing]);
+    let log: serde_json::Value =
+        serde_json::from_str(&output).expect("output is one JSON document");
+    let results = &log["runs"][0]["results"];
+    let change = &results[0]["fixes"][0]["artifactChanges"][0];
+    assert_eq!(change["artifactLocation"]["uri"], "test.ts");
+    let replacement = &change["replacements"][0];
+    assert_eq!(replacement["deletedRegion"]["startLine"], 2);
+    assert_eq!(replacement["deletedRegion"]["startColumn"], 1);
+    assert_eq!(replacement["deletedRegion"]["endColumn"], 5);
+    assert_eq!(replacement["insertedContent"]["text"], "value");
+    assert!(results[1].get("fixes").is_none());
+}
+
+#[test]
+fn sarif_format_emits_empty_log_without_diagnostics() {
+    let mut reporter = Reporter::new(false);
+    reporter.set_format(DiagnosticFormat::Sarif);
+    let output = reporter.render(&[]);
+    let log: serde_json::Value =
+        serde_json::from_str(&output).expect("output is one JSON document");
+    assert_eq!(log["runs"][0]["results"], serde_json::json!([]));
+}
+
 #[test]
 fn plain_mode_uses_parent_segments_for_files_outside_cwd() {
     let temp = TempDir::new().expect("temp dir");
@@ -480,6 +850,7 @@ fn pretty_mode_summary_multiple_files_groups_only_errors() {
             length: 1,
             message_text: "This warning should not affect the summary.".to_string(),
             related_information: Vec::new(),
+            spelling_suggestion: None,
         },
     ];
 
@@ -517,6 +888,7 @@ fn pretty_mode_omits_summary_when_only_warnings_are_present() {
         length: 4,
         message_text: "A warning message.".to_string(),
         related_information: Vec::new(),
+        spelling_suggestion: None,
     };
 
     let mut reporter = Reporter::new(false);
@@ -0,0 +1,48 @@
+//! Cooperative cancellation for long-running requests.
+//!
+//! A `CancellationToken` is a cheap, clonable handle around a shared flag.
+//! The party that owns the request (e.g. the language server's message loop)
+//! keeps one clone and calls [`CancellationToken::cancel`]; the worker polls
+//! [`CancellationToken::is_cancelled`] at safe points and abandons its work.
+//! Checking is a single relaxed atomic load, so it is fine on hot paths.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Shared cancellation flag for one request.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a token that has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation. Every clone of this token observes it.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether cancellation has been requested.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_cancellation() {
+        let token = CancellationToken::new();
+        let observer = token.clone();
+        assert!(!observer.is_cancelled());
+        token.cancel();
+        assert!(observer.is_cancelled());
+    }
+}
@@ -12,6 +12,30 @@ pub fn is_js_grammar_diagnostic(code: u32) -> bool {
     (8000..9000).contains(&code)
 }
 
+/// True when `code` is a related-location message: a secondary span such as
+/// "'{0}' is declared here." that points somewhere else in the program rather
+/// than continuing the primary message's elaboration chain. `tsc` prints these
+/// only in `--pretty` output, each under its own location.
+pub const fn is_related_location_diagnostic(code: u32) -> bool {
+    use diagnostic_codes as codes;
+    matches!(
+        code,
+        codes::IS_DECLARED_HERE
+            | codes::PROPERTY_WAS_ALSO_DECLARED_HERE
+            | codes::THE_IMPLEMENTATION_SIGNATURE_IS_DECLARED_HERE
+            | codes::THE_FIRST_EXPORT_DEFAULT_IS_HERE
+            | codes::ANOTHER_EXPORT_DEFAULT_IS_HERE
+            | codes::THE_LAST_OVERLOAD_IS_DECLARED_HERE
+            | codes::THE_CALL_WOULD_HAVE_SUCCEEDED_AGAINST_THIS_IMPLEMENTATION_BUT_IMPLEMENTATION_SIG
+            | codes::WAS_ALSO_DECLARED_HERE
+            | codes::AND_HERE
+            | codes::AN_ARGUMENT_FOR_WAS_NOT_PROVIDED
+            | codes::THE_EXPECTED_TYPE_COMES_FROM_PROPERTY_WHICH_IS_DECLARED_HERE_ON_TYPE
+            | codes::THE_EXPECTED_TYPE_COMES_FROM_THIS_INDEX_SIGNATURE
+            | codes::THE_EXPECTED_TYPE_COMES_FROM_THE_RETURN_TYPE_OF_THIS_SIGNATURE
+    )
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum DiagnosticCategory {
     Warning,
@@ -64,6 +88,59 @@ pub struct DiagnosticRelatedInformation {
     pub depth: u8,
 }
 
+impl DiagnosticRelatedInformation {
+    /// Whether this entry points at another location (see
+    /// [`is_related_location_diagnostic`]) rather than elaborating the
+    /// primary message.
+    pub const fn is_related_location(&self) -> bool {
+        is_related_location_diagnostic(self.code)
+    }
+}
+
+/// A diagnostic message with its nested elaborations, mirroring tsc's
+/// `DiagnosticMessageChain`. Each level of `next` explains its parent one step
+/// further ("Type 'A' is not assignable to type 'B'." → "Types of property 'x'
+/// are incompatible." → ...).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticMessageChain {
+    pub category: DiagnosticCategory,
+    pub code: u32,
+    pub message_text: String,
+    pub next: Vec<DiagnosticMessageChain>,
+}
+
+impl DiagnosticMessageChain {
+    /// Flatten the chain into a single string, as tsc's
+    /// `flattenDiagnosticMessageText` does: each nested level goes on its own
+    /// line, indented by two more spaces than its parent.
+    pub fn flatten(&self, new_line: &str) -> String {
+        let mut out = String::new();
+        self.flatten_into(&mut out, new_line, 0);
+        out
+    }
+
+    fn flatten_into(&self, out: &mut String, new_line: &str, indent: usize) {
+        if indent > 0 {
+            out.push_str(new_line);
+            out.push_str(&"  ".repeat(indent));
+        }
+        out.push_str(&self.message_text);
+        for next in &self.next {
+            next.flatten_into(out, new_line, indent + 1);
+        }
+    }
+}
+
+/// The name a "Did you mean" diagnostic suggests, and the span it replaces:
+/// the misspelled identifier, or the contents of the string literal of an
+/// element access.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpellingSuggestion {
+    pub start: u32,
+    pub length: u32,
+    pub name: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Diagnostic {
     pub category: DiagnosticCategory,
@@ -73,6 +150,9 @@ pub struct Diagnostic {
     pub length: u32,
     pub message_text: String,
     pub related_information: Vec<DiagnosticRelatedInformation>,
+    /// The replacement for the "Change spelling" fix, if the message
+    /// suggests one.
+    pub spelling_suggestion: Option<SpellingSuggestion>,
 }
 
 impl Diagnostic {
@@ -91,6 +171,7 @@ impl Diagnostic {
             start,
             length,
             related_information: Vec::new(),
+            spelling_suggestion: None,
         }
     }
 
@@ -123,6 +204,7 @@ impl Diagnostic {
             length,
             message_text: format_message(info.message, args),
             related_information: Vec::new(),
+            spelling_suggestion: None,
         }
     }
 
@@ -207,6 +289,64 @@ impl Diagnostic {
         crate::span::Span::from_len(self.start, self.length)
     }
 
+    /// Whether `related` is a link in this diagnostic's elaboration chain
+    /// rather than a related location. Elaborations share the primary span and
+    /// use a non-location code; their nesting is carried by `depth`.
+    pub fn is_elaboration(&self, related: &DiagnosticRelatedInformation) -> bool {
+        !related.is_related_location()
+            && related.file == self.file
+            && related.start == self.start
+            && related.length == self.length
+    }
+
+    /// The related locations of this diagnostic: every related entry that is
+    /// not part of the elaboration chain (see [`Diagnostic::is_elaboration`]).
+    pub fn related_locations(&self) -> impl Iterator<Item = &DiagnosticRelatedInformation> {
+        self.related_information
+            .iter()
+            .filter(|related| !self.is_elaboration(related))
+    }
+
+    /// The structured message chain: the primary message with the
+    /// elaboration entries nested beneath it by `depth`.
+    ///
+    /// An entry at depth `d` is a child of the closest preceding entry at a
+    /// shallower depth (depth `0` entries are children of the primary
+    /// message), so a skipped level still attaches to its nearest ancestor.
+    pub fn message_chain(&self) -> DiagnosticMessageChain {
+        fn collect(
+            entries: &[&DiagnosticRelatedInformation],
+            pos: &mut usize,
+            min_depth: u8,
+        ) -> Vec<DiagnosticMessageChain> {
+            let mut chain = Vec::new();
+            while let Some(entry) = entries.get(*pos).filter(|e| e.depth >= min_depth) {
+                *pos += 1;
+                let next = collect(entries, pos, entry.depth.saturating_add(1));
+                chain.push(DiagnosticMessageChain {
+                    category: entry.category,
+                    code: entry.code,
+                    message_text: entry.message_text.clone(),
+                    next,
+                });
+            }
+            chain
+        }
+
+        let elaborations: Vec<&DiagnosticRelatedInformation> = self
+            .related_information
+            .iter()
+            .filter(|related| self.is_elaboration(related))
+            .collect();
+        let mut pos = 0;
+        DiagnosticMessageChain {
+            category: self.category,
+            code: self.code,
+            message_text: self.message_text.clone(),
+            next: collect(&elaborations, &mut pos, 0),
+        }
+    }
+
     /// Canonical total ordering for diagnostics, mirroring the TypeScript
     /// compiler's `compareDiagnostics`: by file, then start, then length, then
     /// code, then message text, then related information.
@@ -262,6 +402,27 @@ fn compare_related_information(
     })
 }
 
+/// Sort diagnostics into the order of [`Diagnostic::compare`] and drop
+/// duplicates, mirroring tsc's `sortAndDeduplicateDiagnostics`.
+///
+/// Diagnostics that agree on file, span, code and message text are
+/// duplicates; the copy carrying the most related information is kept. The
+/// same error can be produced more than once when files are checked in
+/// parallel or lib files are re-checked, so the final list reported for a
+/// program goes through here rather than a bare sort.
+pub fn sort_and_deduplicate_diagnostics(diagnostics: &mut Vec<Diagnostic>) {
+    diagnostics.sort_by(Diagnostic::compare);
+    diagnostics.dedup_by(|later, kept| {
+        if later.compare_skip_related_information(kept).is_ne() {
+            return false;
+        }
+        if later.related_information.len() > kept.related_information.len() {
+            std::mem::swap(later, kept);
+        }
+        true
+    });
+}
+
 /// Look up a `DiagnosticMessage` (code + category + template) by numeric code.
 /// Uses binary search over the sorted generated table — O(log n).
 pub fn lookup_diagnostic(code: u32) -> Option<DiagnosticMessage> {
@@ -390,6 +551,30 @@ mod tests {
         assert!(diagnostic.related_information.is_empty());
     }
 
+    #[test]
+    fn related_location_codes_are_distinguished_from_elaborations() {
+        let declared_here = Diagnostic::related_message(
+            diagnostic_codes::IS_DECLARED_HERE,
+            "test.ts",
+            0,
+            1,
+            "'x' is declared here.",
+        );
+        assert!(declared_here.is_related_location());
+        assert!(is_related_location_diagnostic(
+            diagnostic_codes::THE_EXPECTED_TYPE_COMES_FROM_PROPERTY_WHICH_IS_DECLARED_HERE_ON_TYPE
+        ));
+
+        let elaboration = Diagnostic::related_message(
+            diagnostic_codes::TYPE_IS_NOT_ASSIGNABLE_TO_TYPE,
+            "test.ts",
+            0,
+            1,
+            "Type 'string' is not assignable to type 'number'.",
+        );
+        assert!(!elaboration.is_related_location());
+    }
+
     #[test]
     fn diagnostic_from_code_uses_unknown_fallback_for_missing_code() {
         let result = std::panic::catch_unwind(|| {
@@ -743,6 +928,43 @@ mod tests {
         assert_eq!(lhs, rhs);
     }
 
+    #[test]
+    fn message_chain_nests_elaborations_by_depth_and_skips_related_locations() {
+        let mut diag = Diagnostic::error("a.ts", 10, 3, "Type 'A' is not assignable.", 2322);
+        for (depth, text) in [
+            (0, "Types of property 'x' are incompatible."),
+            (1, "Type 'X' is not assignable."),
+            (3, "Skipped a level."),
+            (0, "Second root elaboration."),
+        ] {
+            let mut related = Diagnostic::related_message(2322, "a.ts", 10, 3, text);
+            related.depth = depth;
+            diag.related_information.push(related);
+        }
+        diag.related_information.push(Diagnostic::related_message(
+            diagnostic_codes::IS_DECLARED_HERE,
+            "a.ts",
+            0,
+            1,
+            "'x' is declared here.",
+        ));
+
+        let chain = diag.message_chain();
+        assert_eq!(chain.next.len(), 2);
+        assert_eq!(
+            chain.next[0].next[0].next[0].message_text,
+            "Skipped a level."
+        );
+        assert_eq!(
+            chain.flatten("\n"),
+            "Type 'A' is not assignable.\n  Types of property 'x' are incompatible.\n    \
+             Type 'X' is not assignable.\n      Skipped a level.\n  Second root elaboration."
+        );
+        let locations: Vec<_> = diag.related_locations().collect();
+        assert_eq!(locations.len(), 1);
+        assert_eq!(locations[0].message_text, "'x' is declared here.");
+    }
+
     #[test]
     fn with_related_span_matches_with_related_start_length() {
         let main_span = crate::span::Span::new(0, 3);
@@ -756,4 +978,36 @@ mod tests {
             Diagnostic::error("a.ts", 0, 3, "x", 2322).with_related("b.ts", 20, 5, "see here");
         assert_eq!(lhs, rhs);
     }
+
+    #[test]
+    fn sort_and_deduplicate_orders_by_file_then_position_and_drops_duplicates() {
+        let mut diags = vec![
+            Diagnostic::error("b.ts", 4, 1, "late", 2322),
+            Diagnostic::error("a.ts", 9, 2, "dup", 2304),
+            Diagnostic::error("a.ts", 9, 2, "dup", 2304).with_related("a.ts", 0, 1, "here"),
+            Diagnostic::error("a.ts", 1, 2, "early", 2322),
+            Diagnostic::error("a.ts", 9, 2, "dup", 2304),
+            Diagnostic::error("a.ts", 9, 2, "other message", 2304),
+        ];
+        sort_and_deduplicate_diagnostics(&mut diags);
+
+        let keys: Vec<_> = diags
+            .iter()
+            .map(|d| (d.file.as_str(), d.start, d.message_text.as_str()))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                ("a.ts", 1, "early"),
+                ("a.ts", 9, "dup"),
+                ("a.ts", 9, "other message"),
+                ("b.ts", 4, "late"),
+            ]
+        );
+        assert_eq!(
+            diags[1].related_information.len(),
+            1,
+            "the copy carrying related information should be kept"
+        );
+    }
 }
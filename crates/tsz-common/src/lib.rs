@@ -56,3 +56,7 @@ pub mod file_extensions;
 
 // Centralized TypeScript lib symbol capability metadata.
 pub mod lib_capabilities;
+
+// Cooperative cancellation tokens for long-running language-service requests.
+pub mod cancellation;
+pub use cancellation::CancellationToken;
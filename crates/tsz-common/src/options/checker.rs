@@ -174,6 +174,13 @@ pub struct CheckerOptions {
     pub erasable_syntax_only: bool,
     /// When true, report errors for fallthrough cases in switch statements (TS7029).
     pub no_fallthrough_cases_in_switch: bool,
+    /// When true, type displays in diagnostic messages are never elided with `...`.
+    /// This corresponds to the --noErrorTruncation compiler flag.
+    pub no_error_truncation: bool,
+    /// When true, collect suggestion-category diagnostics (TS80xxx) into the
+    /// checker's separate suggestion stream. Off for plain CLI runs; enabled by
+    /// `tsz --suggestions` and by tsz-server for `suggestionDiagnosticsSync`.
+    pub report_suggestions: bool,
 }
 
 /// JSX emit mode controlling how JSX is transformed.
@@ -263,6 +270,8 @@ impl Default for CheckerOptions {
             strict_builtin_iterator_return: true,
             erasable_syntax_only: false,
             no_fallthrough_cases_in_switch: false,
+            no_error_truncation: false,
+            report_suggestions: false,
         }
     }
 }
@@ -43,6 +43,7 @@ pub struct SourceMap {
 }
 
 /// Builder for source maps
+#[derive(Debug, Clone)]
 pub struct SourceMapGenerator {
     file: String,
     source_root: String,
@@ -183,6 +184,45 @@ impl SourceMapGenerator {
         }
     }
 
+    /// Append the sources, names and mappings of `other`, placing its
+    /// generated line `skipped_lines` at line `line_offset` of this map.
+    /// Mappings on the lines of `other` before `skipped_lines` are dropped.
+    pub fn append(&mut self, other: &Self, line_offset: u32, skipped_lines: u32) {
+        let source_indices: Vec<u32> = other
+            .sources
+            .iter()
+            .zip(&other.sources_content)
+            .map(|(source, content)| match content {
+                Some(content) => self.add_source_with_content(source.clone(), content.clone()),
+                None => self.add_source(source.clone()),
+            })
+            .collect();
+        let name_indices: Vec<u32> = other
+            .names
+            .iter()
+            .map(|name| self.add_name(name.clone()))
+            .collect();
+
+        for mapping in &other.mappings {
+            if mapping.generated_line < skipped_lines {
+                continue;
+            }
+            let Some(&source_index) = source_indices.get(mapping.source_index as usize) else {
+                continue;
+            };
+            self.add_mapping(
+                mapping.generated_line - skipped_lines + line_offset,
+                mapping.generated_column,
+                source_index,
+                mapping.original_line,
+                mapping.original_column,
+                mapping
+                    .name_index
+                    .and_then(|idx| name_indices.get(idx as usize).copied()),
+            );
+        }
+    }
+
     /// Generate the source map
     ///
     /// # Panics
@@ -368,6 +408,60 @@ impl SourceMapGenerator {
     }
 }
 
+/// Decode a v3 `mappings` string into absolute mappings.
+///
+/// Segments without an original position (one field) are dropped, since
+/// [`Mapping`] always carries a source. Returns `None` on malformed input.
+#[must_use]
+pub fn decode_mappings(mappings: &str) -> Option<Vec<Mapping>> {
+    let mut decoded = Vec::new();
+    let (mut source_index, mut original_line, mut original_column, mut name_index) =
+        (0i32, 0i32, 0i32, 0i32);
+    for (generated_line, line) in mappings.split(';').enumerate() {
+        let mut generated_column = 0i32;
+        for segment in line.split(',').filter(|segment| !segment.is_empty()) {
+            let mut fields = [0i32; 5];
+            let mut count = 0;
+            let mut rest = segment;
+            while !rest.is_empty() {
+                if count == fields.len() {
+                    return None;
+                }
+                let (value, consumed) = vlq::decode(rest)?;
+                fields[count] = value;
+                count += 1;
+                rest = &rest[consumed..];
+            }
+            generated_column += fields[0];
+            if count == 1 {
+                continue;
+            }
+            if count < 4 {
+                return None;
+            }
+            source_index += fields[1];
+            original_line += fields[2];
+            original_column += fields[3];
+            if count == 5 {
+                name_index += fields[4];
+            }
+            decoded.push(Mapping {
+                generated_line: u32::try_from(generated_line).ok()?,
+                generated_column: u32::try_from(generated_column).ok()?,
+                source_index: u32::try_from(source_index).ok()?,
+                original_line: u32::try_from(original_line).ok()?,
+                original_column: u32::try_from(original_column).ok()?,
+                name_index: if count == 5 {
+                    Some(u32::try_from(name_index).ok()?)
+                } else {
+                    None
+                },
+            });
+        }
+    }
+    Some(decoded)
+}
+
 /// VLQ (Variable-Length Quantity) encoding module for source maps
 pub mod vlq {
     const VLQ_BASE_SHIFT: i32 = 5;
@@ -390,3 +390,85 @@ fn source_map_shift_generated_lines() {
         "expected at least 3 semicolons for shifted lines, got {semicolons}"
     );
 }
+
+#[test]
+fn append_offsets_mappings_and_reindexes_sources_and_names() {
+    let mut bundle = SourceMapGenerator::new("bundle.js".to_string());
+    let _ = bundle.add_source("a.ts".to_string());
+    let _ = bundle.add_name("first".to_string());
+    bundle.add_simple_mapping(0, 0, 0, 0, 0);
+
+    let mut chunk = SourceMapGenerator::new("bundle.js".to_string());
+    let source = chunk.add_source_with_content("b.ts".to_string(), "let b;".to_string());
+    let name = chunk.add_name("second".to_string());
+    chunk.add_simple_mapping(0, 0, source, 0, 0);
+    chunk.add_named_mapping(1, 4, source, 1, 4, name);
+
+    bundle.append(&chunk, 3, 1);
+    let map = bundle.generate();
+    assert_eq!(map.sources, vec!["a.ts", "b.ts"]);
+    assert_eq!(map.names, vec!["first", "second"]);
+    assert_eq!(
+        map.sources_content,
+        Some(vec![String::new(), "let b;".to_string()])
+    );
+
+    let decoded = decode_mappings(&map.mappings).expect("valid mappings");
+    let fields: Vec<_> = decoded
+        .iter()
+        .map(|m| {
+            (
+                m.generated_line,
+                m.generated_column,
+                m.source_index,
+                m.name_index,
+            )
+        })
+        .collect();
+    assert_eq!(fields, vec![(0, 0, 0, None), (3, 4, 1, Some(1))]);
+}
+
+#[test]
+fn decode_mappings_round_trips_generated_mappings() {
+    let mut generator = SourceMapGenerator::new("out.js".to_string());
+    let _ = generator.add_source("a.ts".to_string());
+    let _ = generator.add_source("b.ts".to_string());
+    let name = generator.add_name("value".to_string());
+    generator.add_simple_mapping(0, 0, 0, 0, 0);
+    generator.add_named_mapping(0, 4, 0, 0, 6, name);
+    generator.add_simple_mapping(2, 2, 1, 3, 1);
+    let map = generator.generate();
+
+    let decoded = decode_mappings(&map.mappings).expect("valid mappings");
+    let fields: Vec<_> = decoded
+        .iter()
+        .map(|m| {
+            (
+                m.generated_line,
+                m.generated_column,
+                m.source_index,
+                m.original_line,
+                m.original_column,
+                m.name_index,
+            )
+        })
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            (0, 0, 0, 0, 0, None),
+            (0, 4, 0, 0, 6, Some(0)),
+            (2, 2, 1, 3, 1, None),
+        ]
+    );
+}
+
+#[test]
+fn decode_mappings_skips_unmapped_segments_and_rejects_garbage() {
+    // `C` is a generated-column-only segment; `AAAA` maps to source 0.
+    let decoded = decode_mappings("C,AAAA").expect("valid mappings");
+    assert_eq!(decoded.len(), 1);
+    assert_eq!(decoded[0].generated_column, 1);
+    assert!(decode_mappings("A!").is_none());
+    assert!(decode_mappings("AA").is_none());
+}
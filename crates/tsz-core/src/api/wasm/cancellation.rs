@@ -0,0 +1,52 @@
+use wasm_bindgen::prelude::{JsValue, wasm_bindgen};
+
+use tsz_common::CancellationToken;
+
+/// A handle for abandoning a running check, e.g. when the user edits again.
+/// Pass it to `checkAllStreaming`, which polls it between files. The check
+/// runs synchronously, so cancel from the streaming callback, for instance
+/// when a flag the main thread sets in a `SharedArrayBuffer` is up.
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// const token = new WasmCancellationToken();
+/// const stale = new Int32Array(sharedBuffer); // set by the main thread on edit
+/// try {
+///   program.checkAllStreaming((file) => {
+///     postMessage({ type: "file", file });
+///     if (Atomics.load(stale, 0) !== 0) token.cancel();
+///   }, token);
+/// } catch (err) {
+///   // cancelled: the edit starts a new check
+/// }
+/// ```
+#[wasm_bindgen]
+#[derive(Clone, Default)]
+pub struct WasmCancellationToken {
+    pub(crate) inner: CancellationToken,
+}
+
+#[wasm_bindgen]
+impl WasmCancellationToken {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation of every check using this token.
+    #[wasm_bindgen]
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    /// Whether `cancel` has been called.
+    #[wasm_bindgen(js_name = isCancelled)]
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.is_cancelled()
+    }
+}
+
+/// The error a cancelled check throws.
+pub(crate) fn cancelled_error() -> JsValue {
+    JsValue::from_str("Operation was cancelled")
+}
@@ -1,6 +1,14 @@
+use rustc_hash::FxHashMap;
 use serde::Deserialize;
 use wasm_bindgen::prelude::JsValue;
 
+use std::path::Path;
+
+use crate::config::{
+    JsxEmit, ModuleResolutionKind, ResolvedCompilerOptions, build_path_mappings,
+    jsx_emit_from_ts_numeric, jsx_emit_ts_numeric_value,
+};
+
 /// Compiler options passed from JavaScript/WASM.
 /// Maps to TypeScript compiler options.
 #[derive(Deserialize, Clone, Debug, Default)]
@@ -77,6 +85,143 @@ pub(crate) struct CompilerOptions {
     /// Enable Sound Mode for stricter type checking beyond TypeScript's defaults.
     #[serde(default, deserialize_with = "deserialize_bool_option")]
     sound_mode: Option<bool>,
+
+    /// `@types` packages to include automatically (all of them when unset).
+    #[serde(default)]
+    types: Option<Vec<String>>,
+
+    /// Directories searched for `@types` packages.
+    #[serde(default)]
+    type_roots: Option<Vec<String>>,
+
+    /// Generate `.d.ts` files from `emitAll`.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    declaration: Option<bool>,
+
+    /// Only emit `.d.ts` files.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    emit_declaration_only: Option<bool>,
+
+    /// Generate a source map for each emitted JavaScript file.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    source_map: Option<bool>,
+
+    /// Embed source maps in the emitted JavaScript instead.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    inline_source_map: Option<bool>,
+
+    /// Strip comments from emitted output.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    remove_comments: Option<bool>,
+
+    /// Lib names to include (`"es2020"`, `"dom"`, ...); every added lib file
+    /// is used when unset.
+    #[serde(default)]
+    lib: Option<Vec<String>>,
+
+    /// JSX mode (accepts string like `react-jsx` or numeric).
+    #[serde(default, deserialize_with = "deserialize_jsx")]
+    jsx: Option<u32>,
+
+    /// Factory for the classic JSX transform.
+    #[serde(default)]
+    jsx_factory: Option<String>,
+
+    /// Fragment factory for the classic JSX transform.
+    #[serde(default)]
+    jsx_fragment_factory: Option<String>,
+
+    /// Module the automatic JSX transform imports its runtime from.
+    #[serde(default)]
+    jsx_import_source: Option<String>,
+
+    /// Namespace whose `createElement` is the JSX factory.
+    #[serde(default)]
+    react_namespace: Option<String>,
+
+    /// Emit interop helpers for importing `CommonJS` modules.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    es_module_interop: Option<bool>,
+
+    /// Allow default imports from modules without a default export.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    allow_synthetic_default_imports: Option<bool>,
+
+    /// Module resolution strategy (accepts string like `bundler` or numeric).
+    #[serde(default, deserialize_with = "deserialize_module_resolution")]
+    module_resolution: Option<u32>,
+
+    /// Base directory for non-relative module names.
+    #[serde(default)]
+    base_url: Option<String>,
+
+    /// Path mapping entries for non-relative module names.
+    #[serde(default)]
+    paths: Option<FxHashMap<String, Vec<String>>>,
+
+    /// Allow importing `.json` files.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    resolve_json_module: Option<bool>,
+
+    /// Emit class fields with `Object.defineProperty` semantics.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    use_define_for_class_fields: Option<bool>,
+
+    /// Enable legacy (experimental) decorators.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    experimental_decorators: Option<bool>,
+
+    /// Emit design-type metadata for decorated declarations.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    emit_decorator_metadata: Option<bool>,
+
+    /// Require every file to be transpilable on its own.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    isolated_modules: Option<bool>,
+
+    /// Keep imports and exports as written unless marked `type`.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    verbatim_module_syntax: Option<bool>,
+
+    /// Keep `const enum` declarations in emitted code.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    preserve_const_enums: Option<bool>,
+
+    /// Import emit helpers from `tslib` instead of inlining them.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    import_helpers: Option<bool>,
+
+    /// Parse in strict mode and emit `"use strict"`.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    always_strict: Option<bool>,
+
+    /// Report unused local declarations.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    no_unused_locals: Option<bool>,
+
+    /// Report unused parameters.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    no_unused_parameters: Option<bool>,
+
+    /// Require `override` on members that override a base member.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    no_implicit_override: Option<bool>,
+
+    /// Require bracket access for properties from index signatures.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    no_property_access_from_index_signature: Option<bool>,
+
+    /// Report fallthrough cases in `switch` statements.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    no_fallthrough_cases_in_switch: Option<bool>,
+
+    /// Allow JavaScript files in the program.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    allow_js: Option<bool>,
+
+    /// Type-check JavaScript files.
+    #[serde(default, deserialize_with = "deserialize_bool_option")]
+    check_js: Option<bool>,
 }
 
 /// Deserialize an optional boolean option.
@@ -94,6 +239,8 @@ where
 enum WasmCompilerOptionKind {
     Target,
     Module,
+    Jsx,
+    ModuleResolution,
 }
 
 fn deserialize_target<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
@@ -110,7 +257,44 @@ where
     deserialize_target_or_module(deserializer, WasmCompilerOptionKind::Module)
 }
 
-/// Deserialize target/module values that can be either strings or numbers.
+fn deserialize_jsx<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserialize_target_or_module(deserializer, WasmCompilerOptionKind::Jsx)
+}
+
+fn deserialize_module_resolution<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserialize_target_or_module(deserializer, WasmCompilerOptionKind::ModuleResolution)
+}
+
+/// tsc's numeric `ModuleResolutionKind` values.
+const fn module_resolution_from_ts_numeric(value: u32) -> Option<ModuleResolutionKind> {
+    match value {
+        1 => Some(ModuleResolutionKind::Classic),
+        2 => Some(ModuleResolutionKind::Node),
+        3 => Some(ModuleResolutionKind::Node16),
+        99 => Some(ModuleResolutionKind::NodeNext),
+        100 => Some(ModuleResolutionKind::Bundler),
+        _ => None,
+    }
+}
+
+const fn module_resolution_ts_numeric_value(kind: ModuleResolutionKind) -> u32 {
+    match kind {
+        ModuleResolutionKind::Classic => 1,
+        ModuleResolutionKind::Node => 2,
+        ModuleResolutionKind::Node16 => 3,
+        ModuleResolutionKind::NodeNext => 99,
+        ModuleResolutionKind::Bundler => 100,
+    }
+}
+
+/// Deserialize enum-valued options (target, module, jsx, moduleResolution)
+/// that can be either strings or numbers.
 /// TypeScript test files often use strings like "ES5", "ES2015", "CommonJS", etc.
 fn deserialize_target_or_module<'de, D>(
     deserializer: D,
@@ -129,7 +313,7 @@ where
         type Value = Option<u32>;
 
         fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-            formatter.write_str("a string or integer representing target/module")
+            formatter.write_str("a string or integer representing an enum-valued option")
         }
 
         fn visit_none<E>(self) -> Result<Self::Value, E>
@@ -169,6 +353,12 @@ where
                     .map(|target| u32::from(target.ts_numeric_value())),
                 WasmCompilerOptionKind::Module => crate::common::ModuleKind::from_ts_str(value)
                     .map(crate::common::ModuleKind::ts_numeric_value),
+                WasmCompilerOptionKind::Jsx => {
+                    crate::config::jsx_string_to_emit(value).map(jsx_emit_ts_numeric_value)
+                }
+                WasmCompilerOptionKind::ModuleResolution => {
+                    ModuleResolutionKind::from_ts_str(value).map(module_resolution_ts_numeric_value)
+                }
             };
             Ok(result)
         }
@@ -190,6 +380,15 @@ impl CompilerOptions {
             .unwrap_or(crate::common::ModuleKind::None)
     }
 
+    fn resolve_jsx(&self) -> Option<JsxEmit> {
+        self.jsx.and_then(jsx_emit_from_ts_numeric)
+    }
+
+    fn resolve_module_resolution(&self) -> Option<ModuleResolutionKind> {
+        self.module_resolution
+            .and_then(module_resolution_from_ts_numeric)
+    }
+
     const fn apply_strict_option(
         options: &mut crate::checker::context::CheckerOptions,
         strict: bool,
@@ -217,6 +416,136 @@ impl CompilerOptions {
         }
     }
 
+    /// Lib names from `lib`, when set.
+    pub(crate) fn lib(&self) -> Option<&[String]> {
+        self.lib.as_deref()
+    }
+
+    /// Whether no lib files are used at all.
+    pub(crate) fn no_lib(&self) -> bool {
+        self.no_lib.unwrap_or(false)
+    }
+
+    /// `baseUrl`, when set.
+    pub(crate) fn base_url(&self) -> Option<&str> {
+        self.base_url
+            .as_deref()
+            .filter(|url| !url.trim().is_empty())
+    }
+
+    /// Options for the shared `ModuleResolver`, with relative `baseUrl` and
+    /// `typeRoots` anchored at `project_dir`.
+    pub(crate) fn module_resolution_options(
+        &self,
+        project_dir: Option<&Path>,
+    ) -> ResolvedCompilerOptions {
+        let project_dir = project_dir.unwrap_or_else(|| Path::new("/"));
+        let anchor = |path: &str| project_dir.join(path);
+        let checker = self.to_checker_options();
+        let mut options = ResolvedCompilerOptions::default();
+        options.printer.module = checker.module;
+        // Without `module` or `moduleResolution`, embedders get node_modules
+        // lookup rather than classic resolution.
+        options.module_resolution = self
+            .resolve_module_resolution()
+            .or_else(|| self.module.is_none().then_some(ModuleResolutionKind::Node));
+        let package_json_maps = matches!(
+            options.effective_module_resolution(),
+            ModuleResolutionKind::Node16
+                | ModuleResolutionKind::NodeNext
+                | ModuleResolutionKind::Bundler
+        );
+        options.resolve_package_json_exports = package_json_maps;
+        options.resolve_package_json_imports = package_json_maps;
+        options.module_suffixes = vec![String::new()];
+        options.resolve_json_module = checker.resolve_json_module;
+        options.allow_js = checker.allow_js;
+        options.jsx = self.resolve_jsx();
+        options.types = self.types.clone();
+        options.type_roots = self
+            .type_roots
+            .as_ref()
+            .map(|roots| roots.iter().map(|root| anchor(root)).collect());
+        options.base_url = self.base_url().map(anchor);
+        if let Some(paths) = self.paths.as_ref().filter(|paths| !paths.is_empty()) {
+            options.paths = Some(build_path_mappings(paths));
+            // The resolver applies `paths` only under a `baseUrl`; without
+            // one, targets are relative to the project directory.
+            if options.base_url.is_none() {
+                options.base_url = Some(project_dir.to_path_buf());
+            }
+        }
+        options
+    }
+
+    /// The options of a loaded tsconfig.
+    pub(crate) fn from_config(
+        options: Option<&crate::config::CompilerOptions>,
+    ) -> Result<Self, String> {
+        let Some(options) = options else {
+            return Ok(Self::default());
+        };
+        let mut value = serde_json::to_value(options)
+            .map_err(|err| format!("Failed to parse compiler options: {err}"))?;
+        if let serde_json::Value::Object(map) = &mut value {
+            map.retain(|_, value| !value.is_null());
+        }
+        serde_json::from_value(value)
+            .map_err(|err| format!("Failed to parse compiler options: {err}"))
+    }
+
+    /// Whether `emitAll` produces declaration files.
+    pub(crate) fn declaration(&self) -> bool {
+        self.declaration.unwrap_or(false) || self.emit_declaration_only()
+    }
+
+    /// Whether `emitAll` skips JavaScript output.
+    pub(crate) fn emit_declaration_only(&self) -> bool {
+        self.emit_declaration_only.unwrap_or(false)
+    }
+
+    /// Whether emitted JavaScript gets a separate source map.
+    pub(crate) fn source_map(&self) -> bool {
+        self.source_map.unwrap_or(false)
+    }
+
+    /// Whether emitted JavaScript embeds its source map.
+    pub(crate) fn inline_source_map(&self) -> bool {
+        self.inline_source_map.unwrap_or(false)
+    }
+
+    /// Convert to `PrinterOptions` for JavaScript emit. Strictness follows
+    /// the checker options, so `"use strict"` is emitted when `strict` is.
+    pub(crate) fn to_printer_options(&self) -> crate::emitter::PrinterOptions {
+        let checker_options = self.to_checker_options();
+        crate::emitter::PrinterOptions {
+            target: checker_options.target,
+            module: checker_options.module,
+            remove_comments: self.remove_comments.unwrap_or(false),
+            downlevel_iteration: checker_options.downlevel_iteration,
+            always_strict: checker_options.always_strict,
+            strict_null_checks: checker_options.strict_null_checks,
+            no_lib: checker_options.no_lib,
+            // Defaults to on from ES2022, matching tsc.
+            use_define_for_class_fields: self
+                .use_define_for_class_fields
+                .unwrap_or_else(|| checker_options.target.supports_es2022()),
+            es_module_interop: checker_options.es_module_interop,
+            legacy_decorators: checker_options.experimental_decorators,
+            emit_decorator_metadata: self.emit_decorator_metadata.unwrap_or(false),
+            isolated_modules: checker_options.isolated_modules,
+            preserve_const_enums: checker_options.preserve_const_enums,
+            import_helpers: self.import_helpers.unwrap_or(false),
+            jsx: self
+                .resolve_jsx()
+                .map_or_else(Default::default, crate::config::jsx_emit_to_emitter),
+            jsx_factory: self.jsx_factory.clone(),
+            jsx_fragment_factory: self.jsx_fragment_factory.clone(),
+            jsx_import_source: self.jsx_import_source.clone(),
+            ..Default::default()
+        }
+    }
+
     /// Convert to `CheckerOptions` for type checking.
     pub(crate) fn to_checker_options(&self) -> crate::checker::context::CheckerOptions {
         let mut options = crate::checker::context::CheckerOptions::default();
@@ -274,9 +603,104 @@ impl CompilerOptions {
         if let Some(v) = self.downlevel_iteration {
             options.downlevel_iteration = v;
         }
+        self.apply_module_options(&mut options);
+        self.apply_jsx_options(&mut options);
+
+        if let Some(v) = self.always_strict {
+            options.always_strict = v;
+        }
+        if let Some(v) = self.isolated_modules {
+            options.isolated_modules = v;
+        }
+        if let Some(v) = self.verbatim_module_syntax {
+            options.verbatim_module_syntax = v;
+        }
+        if let Some(v) = self.preserve_const_enums {
+            options.preserve_const_enums = v;
+        }
+        if let Some(v) = self.experimental_decorators {
+            options.experimental_decorators = v;
+        }
+        if let Some(v) = self.no_unused_locals {
+            options.no_unused_locals = v;
+        }
+        if let Some(v) = self.no_unused_parameters {
+            options.no_unused_parameters = v;
+        }
+        if let Some(v) = self.no_implicit_override {
+            options.no_implicit_override = v;
+        }
+        if let Some(v) = self.no_property_access_from_index_signature {
+            options.no_property_access_from_index_signature = v;
+        }
+        if let Some(v) = self.no_fallthrough_cases_in_switch {
+            options.no_fallthrough_cases_in_switch = v;
+        }
+        if let Some(v) = self.allow_js {
+            options.allow_js = v;
+        }
+        if let Some(v) = self.check_js {
+            options.check_js = v;
+        }
+        options.emit_declarations = self.declaration();
 
         options
     }
+
+    /// Module resolution and interop options, with tsc's defaults: `module`
+    /// follows `moduleResolution` node16/nodenext when unset, and
+    /// `allowSyntheticDefaultImports` follows `esModuleInterop`, `system`
+    /// modules and bundler resolution.
+    fn apply_module_options(&self, options: &mut crate::checker::context::CheckerOptions) {
+        let module_resolution = self.resolve_module_resolution();
+        if self.module.is_none() {
+            match module_resolution {
+                Some(ModuleResolutionKind::Node16) => {
+                    options.module = crate::common::ModuleKind::Node16;
+                }
+                Some(ModuleResolutionKind::NodeNext) => {
+                    options.module = crate::common::ModuleKind::NodeNext;
+                }
+                _ => {}
+            }
+        }
+        let effective_resolution = module_resolution
+            .unwrap_or_else(|| crate::config::default_module_resolution_for_module(options.module));
+        options.implied_classic_resolution =
+            matches!(effective_resolution, ModuleResolutionKind::Classic);
+        options.resolve_json_module = self.resolve_json_module.unwrap_or(matches!(
+            effective_resolution,
+            ModuleResolutionKind::Bundler
+        ));
+
+        if let Some(v) = self.es_module_interop {
+            options.es_module_interop = v;
+        }
+        options.allow_synthetic_default_imports = self.allow_synthetic_default_imports.unwrap_or(
+            options.es_module_interop
+                || matches!(options.module, crate::common::ModuleKind::System)
+                || matches!(effective_resolution, ModuleResolutionKind::Bundler),
+        );
+    }
+
+    fn apply_jsx_options(&self, options: &mut crate::checker::context::CheckerOptions) {
+        if let Some(jsx) = self.resolve_jsx() {
+            options.jsx_mode = crate::config::jsx_emit_to_mode(jsx);
+        }
+        if let Some(factory) = &self.jsx_factory {
+            options.jsx_factory = factory.clone();
+            options.jsx_factory_from_config = true;
+        } else if let Some(namespace) = &self.react_namespace {
+            options.jsx_factory = format!("{namespace}.createElement");
+        }
+        if let Some(factory) = &self.jsx_fragment_factory {
+            options.jsx_fragment_factory = factory.clone();
+            options.jsx_fragment_factory_from_config = true;
+        }
+        if let Some(source) = &self.jsx_import_source {
+            options.jsx_import_source = source.clone();
+        }
+    }
 }
 
 pub(crate) fn parse_compiler_options_json(options_json: &str) -> Result<CompilerOptions, JsValue> {
@@ -390,6 +814,62 @@ mod tests {
         assert!(options.downlevel_iteration);
     }
 
+    #[test]
+    fn to_checker_options_plumbs_jsx_and_module_interop_options() {
+        let parsed = parse_compiler_options_json(
+            r#"{
+                "jsx": "react-jsx",
+                "jsxImportSource": "preact",
+                "moduleResolution": "bundler",
+                "module": "esnext",
+                "noUnusedLocals": true
+            }"#,
+        )
+        .unwrap();
+        let options = parsed.to_checker_options();
+
+        assert_eq!(
+            options.jsx_mode,
+            tsz_common::checker_options::JsxMode::ReactJsx
+        );
+        assert_eq!(options.jsx_import_source, "preact");
+        assert!(options.allow_synthetic_default_imports);
+        assert!(options.resolve_json_module);
+        assert!(!options.implied_classic_resolution);
+        assert!(options.no_unused_locals);
+        assert_eq!(
+            parsed.to_printer_options().jsx,
+            crate::emitter::JsxEmit::ReactJsx
+        );
+    }
+
+    #[test]
+    fn numeric_jsx_and_module_resolution_match_tsc_enums() {
+        let parsed = parse_compiler_options_json(r#"{"jsx":2,"moduleResolution":1}"#).unwrap();
+        let options = parsed.to_checker_options();
+
+        assert_eq!(
+            options.jsx_mode,
+            tsz_common::checker_options::JsxMode::React
+        );
+        assert!(options.implied_classic_resolution);
+    }
+
+    #[test]
+    fn use_define_for_class_fields_defaults_from_target() {
+        let es2022 = parse_compiler_options_json(r#"{"target":"ES2022"}"#).unwrap();
+        assert!(es2022.to_printer_options().use_define_for_class_fields);
+
+        let es2015 = parse_compiler_options_json(
+            r#"{"target":"ES2015","useDefineForClassFields":true,"esModuleInterop":true}"#,
+        )
+        .unwrap();
+        let printer = es2015.to_printer_options();
+        assert!(printer.use_define_for_class_fields);
+        assert!(printer.es_module_interop);
+        assert!(es2015.to_checker_options().allow_synthetic_default_imports);
+    }
+
     #[test]
     fn parse_compiler_options_json_ignores_no_types_and_symbols() {
         let parsed = parse_compiler_options_json(r#"{"noTypesAndSymbols":true}"#).unwrap();
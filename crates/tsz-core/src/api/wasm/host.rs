@@ -0,0 +1,232 @@
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use rustc_hash::FxHashMap;
+use tsz_common::file_extensions::KNOWN_MODULE_EXTENSIONS;
+use wasm_bindgen::prelude::{JsValue, wasm_bindgen};
+
+use crate::compiler_host::CompilerHost;
+
+#[wasm_bindgen(typescript_custom_section)]
+const MODULE_RESOLUTION_HOST_TS: &str = r#"
+/** File-system callbacks `WasmProgram` uses to resolve imports it was not given. */
+export interface ModuleResolutionHost {
+    fileExists(fileName: string): boolean;
+    readFile(fileName: string): string | undefined;
+    /** Optional; any path that is not a file is taken to be a directory when omitted. */
+    directoryExists?(directoryName: string): boolean;
+    /** Optional; paths are used as-is when omitted. */
+    realpath?(path: string): string;
+    /** Optional; enables automatic inclusion of every `@types` package and tsconfig `include` patterns. */
+    getDirectories?(path: string): string[];
+    /** Optional; enables tsconfig `include` patterns. Returns files under `rootDir`, recursively. Listings are reused until `setHost` is called again. */
+    readDirectory?(rootDir: string, extensions: string[]): string[];
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    /// A JavaScript object implementing `ModuleResolutionHost`.
+    #[wasm_bindgen(typescript_type = "ModuleResolutionHost")]
+    pub type JsModuleResolutionHost;
+
+    #[wasm_bindgen(method, catch, js_name = fileExists)]
+    fn js_file_exists(this: &JsModuleResolutionHost, file_name: &str) -> Result<bool, JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = readFile)]
+    fn js_read_file(
+        this: &JsModuleResolutionHost,
+        file_name: &str,
+    ) -> Result<Option<String>, JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = directoryExists)]
+    fn js_directory_exists(
+        this: &JsModuleResolutionHost,
+        directory_name: &str,
+    ) -> Result<bool, JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = realpath)]
+    fn js_realpath(this: &JsModuleResolutionHost, path: &str) -> Result<Option<String>, JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = getDirectories)]
+    fn js_get_directories(
+        this: &JsModuleResolutionHost,
+        path: &str,
+    ) -> Result<Option<Vec<String>>, JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = readDirectory)]
+    fn js_read_directory(
+        this: &JsModuleResolutionHost,
+        root_dir: &str,
+        extensions: Vec<String>,
+    ) -> Result<Option<Vec<String>>, JsValue>;
+}
+
+thread_local! {
+    /// JS hosts by id. `JsValue`s cannot leave the thread that created them,
+    /// so the hosts stay here and `JsCompilerHost` only carries the id.
+    static JS_HOSTS: RefCell<FxHashMap<u32, JsModuleResolutionHost>> =
+        RefCell::new(FxHashMap::default());
+}
+static NEXT_JS_HOST_ID: AtomicU32 = AtomicU32::new(1);
+
+/// A JS `ModuleResolutionHost` as a [`CompilerHost`], so the shared module
+/// resolver and tsconfig loading run against the embedder's file system.
+///
+/// The JS object is only reachable from the thread that called `setHost`;
+/// on any other thread the host behaves as an empty file system.
+///
+/// Optional callbacks that are missing throw when called; the `catch`
+/// bindings turn that into the fallback behavior.
+pub(crate) struct JsCompilerHost {
+    id: u32,
+    /// Recursive `readDirectory` results by the directory they were listed
+    /// from, so a walk down a tree asks the host once, at its root.
+    listings: Mutex<Vec<(PathBuf, Vec<PathBuf>)>>,
+}
+
+impl JsCompilerHost {
+    pub(crate) fn new(host: JsModuleResolutionHost) -> Self {
+        let id = NEXT_JS_HOST_ID.fetch_add(1, Ordering::Relaxed);
+        JS_HOSTS.with(|hosts| hosts.borrow_mut().insert(id, host));
+        Self {
+            id,
+            listings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The JS host, when called on the thread that owns it. The handle is
+    /// cloned out so callbacks that re-enter wasm can register hosts too.
+    fn js(&self) -> Option<JsModuleResolutionHost> {
+        JS_HOSTS.with(|hosts| hosts.borrow().get(&self.id).cloned())
+    }
+
+    /// Files under `path`, from the listing of `path` or of an ancestor
+    /// already listed. `None` when the host has no `readDirectory`.
+    fn files_under(&self, path: &Path) -> Option<Vec<PathBuf>> {
+        if let Some((_, files)) = self
+            .lock_listings()
+            .iter()
+            .find(|(root, _)| path.starts_with(root))
+        {
+            return Some(
+                files
+                    .iter()
+                    .filter(|file| file.starts_with(path))
+                    .cloned()
+                    .collect(),
+            );
+        }
+        let extensions = KNOWN_MODULE_EXTENSIONS
+            .iter()
+            .map(|ext| (*ext).to_string())
+            .collect();
+        let files: Vec<PathBuf> = self
+            .js()?
+            .js_read_directory(&js_path(path), extensions)
+            .ok()
+            .flatten()?
+            .into_iter()
+            .map(PathBuf::from)
+            .collect();
+        self.lock_listings()
+            .push((path.to_path_buf(), files.clone()));
+        Some(files)
+    }
+
+    fn lock_listings(&self) -> MutexGuard<'_, Vec<(PathBuf, Vec<PathBuf>)>> {
+        self.listings.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Drop for JsCompilerHost {
+    fn drop(&mut self) {
+        let _ = JS_HOSTS.try_with(|hosts| hosts.borrow_mut().remove(&self.id));
+    }
+}
+
+fn js_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+impl CompilerHost for JsCompilerHost {
+    fn read_file(&self, path: &Path) -> io::Result<String> {
+        self.js()
+            .and_then(|host| host.js_read_file(&js_path(path)).ok().flatten())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("file not found: {}", path.display()),
+                )
+            })
+    }
+
+    fn file_exists(&self, path: &Path) -> bool {
+        self.js()
+            .is_some_and(|host| host.js_file_exists(&js_path(path)).unwrap_or(false))
+    }
+
+    /// Without `directoryExists`, anything that is not a file is taken to be
+    /// a directory.
+    fn directory_exists(&self, path: &Path) -> bool {
+        let Some(host) = self.js() else {
+            return false;
+        };
+        host.js_directory_exists(&js_path(path))
+            .unwrap_or_else(|_| !self.file_exists(path))
+    }
+
+    /// Immediate entries of `path`, from `getDirectories` and the first
+    /// segment below `path` of each file `readDirectory` lists.
+    fn read_directory(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let dir = js_path(path);
+        let directories = self
+            .js()
+            .and_then(|host| host.js_get_directories(&dir).ok().flatten());
+        let files = self.files_under(path);
+        if directories.is_none() && files.is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!(
+                    "cannot list '{dir}': the host implements neither getDirectories nor readDirectory"
+                ),
+            ));
+        }
+
+        let mut entries = BTreeSet::new();
+        for name in directories.into_iter().flatten() {
+            entries.insert(path.join(name));
+        }
+        for file in files.into_iter().flatten() {
+            if let Ok(rest) = file.strip_prefix(path)
+                && let Some(first) = rest.components().next()
+            {
+                entries.insert(path.join(first));
+            }
+        }
+        Ok(entries.into_iter().collect())
+    }
+
+    fn current_directory(&self) -> io::Result<PathBuf> {
+        Ok(PathBuf::from("/"))
+    }
+
+    fn write_file(&self, path: &Path, _contents: &str) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("cannot write '{}': the host is read-only", path.display()),
+        ))
+    }
+
+    fn realpath(&self, path: &Path) -> Option<PathBuf> {
+        self.js()?
+            .js_realpath(&js_path(path))
+            .ok()
+            .flatten()
+            .map(PathBuf::from)
+    }
+}
@@ -0,0 +1,114 @@
+//! Module resolution for `WasmProgram`: the shared [`ModuleResolver`] run
+//! with the program's files layered over the embedder's host.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use rustc_hash::FxHashSet;
+
+use crate::compiler_host::{self, CompilerHost, MemoryCompilerHost};
+use crate::module_resolver::{ModuleExtension, ModuleResolver};
+use crate::span::Span;
+
+/// The host resolution runs against: `files` in memory, over `host` when
+/// the embedder set one.
+pub(crate) fn program_host(
+    host: Option<&Arc<dyn CompilerHost>>,
+    files: &[(String, String)],
+) -> Arc<dyn CompilerHost> {
+    let memory = match host {
+        Some(host) => MemoryCompilerHost::overlay("/", Arc::clone(host)),
+        None => MemoryCompilerHost::new("/"),
+    };
+    for (name, text) in files {
+        memory.add_file(name, text.as_str());
+    }
+    Arc::new(memory)
+}
+
+/// `path` spelled the way program file names are: with forward slashes.
+pub(crate) fn program_file_name(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Resolve `specifier` imported from `containing_file` to a TypeScript
+/// source or declaration file. Must run inside a scope with the
+/// [`program_host`] installed.
+pub(crate) fn resolve_import(
+    resolver: &mut ModuleResolver,
+    specifier: &str,
+    containing_file: &str,
+    known: &FxHashSet<String>,
+) -> Option<String> {
+    let resolved = resolver
+        .resolve(specifier, Path::new(containing_file), Span::dummy())
+        .ok()?;
+    matches!(
+        resolved.extension,
+        ModuleExtension::Ts
+            | ModuleExtension::Tsx
+            | ModuleExtension::Dts
+            | ModuleExtension::DmTs
+            | ModuleExtension::DCts
+            | ModuleExtension::Mts
+            | ModuleExtension::Cts
+    )
+    .then(|| realpath(&resolved.resolved_path, known))
+}
+
+/// Entry files of the `@types` packages a program includes without an
+/// import: those named by `types`, or every package under the type roots
+/// when `types` is unset and the host can list directories.
+pub(crate) fn automatic_type_entries(
+    resolver: &ModuleResolver,
+    type_roots: &[PathBuf],
+    types: Option<&[String]>,
+    known: &FxHashSet<String>,
+) -> Vec<String> {
+    let mut found_names = FxHashSet::default();
+    let mut entries = Vec::new();
+    for root in type_roots {
+        let names: Vec<String> = match types {
+            Some(types) => types.to_vec(),
+            None => compiler_host::read_directory(root)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|entry| compiler_host::directory_exists(entry))
+                .filter_map(|entry| Some(entry.file_name()?.to_string_lossy().into_owned()))
+                .filter(|name| !name.starts_with('.'))
+                .collect(),
+        };
+        for name in names {
+            if found_names.contains(&name) {
+                continue;
+            }
+            if let Some(entry) = resolver.resolve_type_package_directory(&root.join(&name)) {
+                let entry = realpath(&entry, known);
+                if !entries.contains(&entry) {
+                    entries.push(entry);
+                }
+                found_names.insert(name);
+            }
+        }
+    }
+    entries
+}
+
+/// `node_modules/@types` in `dir` and each of its ancestors, as tsc searches
+/// when `typeRoots` is unset.
+pub(crate) fn default_type_roots(dir: &Path) -> Vec<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join("node_modules").join("@types"))
+        .filter(|root| compiler_host::directory_exists(root))
+        .collect()
+}
+
+/// Program files keep the name they were added under; host files are
+/// followed through symlinks.
+fn realpath(path: &Path, known: &FxHashSet<String>) -> String {
+    let name = program_file_name(path);
+    if known.contains(&name) {
+        return name;
+    }
+    compiler_host::canonicalize(path).map_or(name, |real| program_file_name(&real))
+}
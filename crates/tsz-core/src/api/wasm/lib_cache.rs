@@ -6,6 +6,7 @@ use std::sync::{Arc, Mutex};
 
 use crate::binder::BinderState;
 use crate::lib_loader::LibFile;
+use crate::parallel::lib_snapshot;
 use crate::parser::ParserState;
 
 type LibFileCache = FxHashMap<(String, u64), Arc<LibFile>>;
@@ -83,6 +84,42 @@ pub(crate) fn get_or_create_lib_file(file_name: String, source_text: String) ->
     lib_file
 }
 
+/// Encode `lib_files` as a blob of parsed and bound lib files, parsing and
+/// binding through the cache any not seen yet. The blob is a lib-set
+/// snapshot, the format of the native lib cache.
+pub(crate) fn encode_preparsed_libs(lib_files: &[(String, String)]) -> Result<Vec<u8>, String> {
+    let libs: Vec<(&str, &str, Arc<LibFile>)> = lib_files
+        .iter()
+        .map(|(file_name, source_text)| {
+            let lib = get_or_create_lib_file(file_name.clone(), source_text.clone());
+            (file_name.as_str(), source_text.as_str(), lib)
+        })
+        .collect();
+    lib_snapshot::encode_lib_set(&libs)
+        .map_err(|err| format!("failed to encode lib files: {err:#}"))
+}
+
+/// Decode a blob from `encode_preparsed_libs` into the lib file cache and
+/// return each lib's `(file_name, source_text)`, so adding them to a program
+/// or parser afterwards skips parsing and binding.
+pub(crate) fn load_preparsed_libs(bytes: &[u8]) -> Result<Vec<(String, String)>, String> {
+    let libs = lib_snapshot::decode_lib_set(bytes)
+        .map_err(|err| format!("failed to decode lib files: {err:#}"))?;
+
+    let mut cache = LIB_FILE_CACHE
+        .lock()
+        .expect("LIB_FILE_CACHE mutex poisoned");
+    Ok(libs
+        .into_iter()
+        .map(|(source_text, lib)| {
+            let file_name = lib.file_name.clone();
+            let cache_key = (file_name.clone(), hash_lib_content(&source_text));
+            cache.entry(cache_key).or_insert(lib);
+            (file_name, source_text)
+        })
+        .collect())
+}
+
 pub(crate) fn lib_file_cache_statistics() -> LibFileCacheStatistics {
     let cache = LIB_FILE_CACHE
         .lock()
@@ -95,6 +132,10 @@ pub(crate) fn lib_file_cache_statistics() -> LibFileCacheStatistics {
 }
 
 pub(crate) fn lib_file_cache_statistics_json() -> String {
+    lib_file_cache_statistics_value().to_string()
+}
+
+pub(crate) fn lib_file_cache_statistics_value() -> serde_json::Value {
     let stats = lib_file_cache_statistics();
     serde_json::json!({
         "entries": stats.entries,
@@ -102,15 +143,16 @@ pub(crate) fn lib_file_cache_statistics_json() -> String {
         "misses": stats.misses,
         "estimatedSizeBytes": stats.estimated_size_bytes(),
     })
-    .to_string()
 }
 
-#[cfg(test)]
-fn clear_lib_file_cache_for_test() {
-    LIB_FILE_CACHE
+/// Drop every cached lib file and reset the hit/miss counters. Lib files
+/// still held by programs or parsers stay alive until those release them.
+pub fn clear_lib_file_cache() {
+    let mut cache = LIB_FILE_CACHE
         .lock()
-        .expect("LIB_FILE_CACHE mutex poisoned")
-        .clear();
+        .expect("LIB_FILE_CACHE mutex poisoned");
+    cache.clear();
+    cache.shrink_to_fit();
     LIB_FILE_CACHE_HITS.store(0, Ordering::Relaxed);
     LIB_FILE_CACHE_MISSES.store(0, Ordering::Relaxed);
 }
@@ -121,7 +163,7 @@ mod tests {
 
     #[test]
     fn lib_file_cache_statistics_track_entries_hits_and_misses() {
-        clear_lib_file_cache_for_test();
+        clear_lib_file_cache();
 
         let first = get_or_create_lib_file(
             "lib.test.d.ts".to_string(),
@@ -159,4 +201,17 @@ mod tests {
         assert_eq!(after_third.hits, 1);
         assert_eq!(after_third.misses, 2);
     }
+
+    #[test]
+    fn preparsed_libs_reject_foreign_bytes() {
+        assert!(load_preparsed_libs(b"not a lib blob").is_err());
+        assert!(load_preparsed_libs(b"TSZSNAP\x03garbage").is_err());
+        // A file name whose length prefix runs far past the end of the blob
+        // fails without allocating it.
+        let mut oversized = b"TSZSNAP\x03".to_vec();
+        oversized.extend_from_slice(&0u64.to_le_bytes());
+        oversized.extend_from_slice(&1u64.to_le_bytes());
+        oversized.extend_from_slice(&u64::MAX.to_le_bytes());
+        assert!(load_preparsed_libs(&oversized).is_err());
+    }
 }
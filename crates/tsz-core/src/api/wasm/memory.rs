@@ -0,0 +1,48 @@
+//! Memory statistics and cache recycling for long-running WASM sessions.
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::api::wasm::lib_cache::{clear_lib_file_cache, lib_file_cache_statistics_value};
+
+/// Bytes of WASM linear memory, or `None` outside WASM.
+fn wasm_memory_bytes() -> Option<usize> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        const WASM_PAGE_SIZE: usize = 64 * 1024;
+        Some(core::arch::wasm32::memory_size(0) * WASM_PAGE_SIZE)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Current memory statistics as JSON: `wasmMemoryBytes` (null outside WASM)
+/// and the parsed-lib cache statistics under `libFileCache`.
+pub(crate) fn memory_statistics_json() -> String {
+    serde_json::json!({
+        "wasmMemoryBytes": wasm_memory_bytes(),
+        "libFileCache": lib_file_cache_statistics_value(),
+    })
+    .to_string()
+}
+
+/// Return current memory statistics as JSON.
+///
+/// WASM linear memory never shrinks, so `wasmMemoryBytes` is the high-water
+/// mark; freed memory is reused by later allocations.
+#[wasm_bindgen(js_name = getMemoryStatistics)]
+pub fn get_memory_statistics() -> String {
+    memory_statistics_json()
+}
+
+/// Drop the compiler caches shared across programs and parsers (the parsed
+/// lib file cache) and return memory statistics afterwards as JSON.
+///
+/// Call after `WasmProgram.reset()` or `free()`: arenas, binders and
+/// interners are owned by programs and parsers and released with them.
+#[wasm_bindgen(js_name = recycleCompilerCaches)]
+pub fn recycle_compiler_caches() -> String {
+    clear_lib_file_cache();
+    memory_statistics_json()
+}
@@ -1,8 +1,15 @@
+pub(crate) mod cancellation;
 pub(crate) mod code_actions;
 pub(crate) mod compiler_options;
 pub(crate) mod core_utils;
+pub(crate) mod host;
+pub(crate) mod host_resolution;
 pub(crate) mod lib_cache;
+pub(crate) mod memory;
 pub(crate) mod parser;
 pub(crate) mod program;
+pub(crate) mod program_emit;
+pub(crate) mod program_graph;
 pub(crate) mod program_results;
+pub(crate) mod program_services;
 pub(crate) mod transforms;
@@ -9,6 +9,11 @@ use crate::ScriptTarget;
 use crate::WasmTransformContext;
 use crate::api::wasm::code_actions::{default_code_action_context, parse_code_action_context};
 use crate::api::wasm::compiler_options::{CompilerOptions, parse_compiler_options_json};
+use crate::api::wasm::program_emit::{base_name, js_output_name};
+use crate::api::wasm::program_results::{
+    CheckSourceFileResultJson, DiagnosticJson, EmitWithSourceMapResultJson, QuickInfoJson,
+};
+use crate::api::wasm::transforms::WasmCustomTransformers;
 use crate::binder::BinderState;
 use crate::checker;
 use crate::checker::context::LibContext;
@@ -24,12 +29,79 @@ use crate::lsp::provider_macro::FullProviderOptions;
 use crate::lsp::resolver::ScopeCache;
 use crate::lsp::{
     CodeActionProvider, Completions, DocumentSymbolProvider, FindReferences, GoToDefinition,
-    HoverProvider, RenameProvider, SemanticTokensProvider, SignatureHelpProvider,
+    HoverInfo, HoverProvider, RenameProvider, SemanticTokensProvider, SignatureHelpProvider,
 };
 use crate::parser;
 use crate::parser::ParserState;
+use crate::parser::node::{NodeAccess, NodeArena};
 use tsz_solver::construction::TypeInterner;
 
+#[wasm_bindgen]
+extern "C" {
+    /// A JavaScript function `forEachChild` calls with each child node
+    /// index. A truthy return value stops the walk.
+    #[wasm_bindgen(typescript_type = "(nodeIdx: number) => unknown")]
+    pub type ChildVisitor;
+
+    #[wasm_bindgen(method, catch, js_name = call)]
+    fn js_call(this: &ChildVisitor, this_arg: &JsValue, node_idx: u32) -> Result<JsValue, JsValue>;
+}
+
+/// Deepest level of the tree `getAstJson` returns. Building and
+/// serializing it both recurse, so deeper nodes (long operator chains such
+/// as `1+1+…+1`) are left out instead of overflowing the stack.
+const MAX_AST_JSON_DEPTH: usize = 256;
+
+/// A node of the tree `getAstJson` returns.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AstNodeJson {
+    idx: u32,
+    kind: u16,
+    kind_name: String,
+    pos: u32,
+    end: u32,
+    /// Identifier or literal text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    children: Vec<Self>,
+    /// Set when the node has children that are below `MAX_AST_JSON_DEPTH`
+    /// and were left out; `getChildren` still reaches them.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    children_omitted: bool,
+}
+
+impl AstNodeJson {
+    fn build(arena: &NodeArena, idx: parser::NodeIndex, depth: usize) -> Option<Self> {
+        let node = arena.get(idx)?;
+        let text = arena
+            .get_identifier_text(idx)
+            .or_else(|| arena.get_literal_text(idx))
+            .map(str::to_string);
+        let child_indices = arena.get_children(idx);
+        let children_omitted = depth >= MAX_AST_JSON_DEPTH && !child_indices.is_empty();
+        let children = if children_omitted {
+            Vec::new()
+        } else {
+            child_indices
+                .into_iter()
+                .filter_map(|child| Self::build(arena, child, depth + 1))
+                .collect()
+        };
+        Some(Self {
+            idx: idx.0,
+            kind: node.kind,
+            kind_name: parser::syntax_kind_ext::syntax_kind_name(node.kind),
+            pos: node.pos,
+            end: node.end,
+            text,
+            children,
+            children_omitted,
+        })
+    }
+}
+
 /// High-performance parser using Node architecture (16 bytes/node).
 /// This is the optimized path for Phase 8 test suite evaluation.
 #[wasm_bindgen]
@@ -117,6 +189,18 @@ impl Parser {
         self.type_cache = None;
     }
 
+    /// Add the lib files of a blob from `WasmProgram.serializeLibFiles`
+    /// without parsing or binding them again.
+    #[wasm_bindgen(js_name = addPreparsedLibs)]
+    pub fn add_preparsed_libs(&mut self, bytes: &[u8]) -> Result<(), JsValue> {
+        let libs = crate::api::wasm::lib_cache::load_preparsed_libs(bytes)
+            .map_err(|err| JsValue::from_str(&err))?;
+        for (file_name, source_text) in libs {
+            self.add_lib_file(file_name, source_text);
+        }
+        Ok(())
+    }
+
     /// Return global parsed-lib cache statistics as JSON.
     #[wasm_bindgen(js_name = getLibFileCacheStatisticsJson)]
     pub fn get_lib_file_cache_statistics_json(&self) -> String {
@@ -192,9 +276,13 @@ impl Parser {
         }
     }
 
-    /// Type check the source file and return diagnostics.
-    #[wasm_bindgen(js_name = checkSourceFile)]
-    pub fn check_source_file(&mut self) -> String {
+    /// Type check the source file and return a `CheckSourceFileResult`
+    /// with its diagnostics.
+    #[wasm_bindgen(
+        js_name = checkSourceFile,
+        unchecked_return_type = "CheckSourceFileResult"
+    )]
+    pub fn check_source_file(&mut self) -> Result<JsValue, JsValue> {
         if self.binder.is_none() {
             // Auto-bind if not done yet
             if self.source_file_idx.is_some() {
@@ -246,27 +334,18 @@ impl Parser {
                 .ctx
                 .diagnostics
                 .iter()
-                .map(|d| {
-                    serde_json::json!({
-                        "message_text": d.message_text.clone(),
-                        "code": d.code,
-                        "start": d.start,
-                        "length": d.length,
-                        "category": format!("{:?}", d.category),
-                    })
-                })
-                .collect::<Vec<_>>();
+                .map(DiagnosticJson::from_check)
+                .collect();
 
             self.type_cache = Some(checker.extract_cache());
 
-            let result = serde_json::json!({
-                "typeCount": self.type_interner.len(),
-                "diagnostics": diagnostics,
-            });
-
-            serde_json::to_string(&result).unwrap_or_else(|_| "{}".to_string())
+            let result = CheckSourceFileResultJson {
+                type_count: self.type_interner.len(),
+                diagnostics,
+            };
+            Ok(serde_wasm_bindgen::to_value(&result)?)
         } else {
-            r#"{"error": "Source file not parsed or bound"}"#.to_string()
+            Err(JsValue::from_str("Source file not parsed or bound"))
         }
     }
 
@@ -342,24 +421,38 @@ impl Parser {
         }
     }
 
-    fn emit_with_context(&self, root_idx: parser::NodeIndex, ctx: EmitContext) -> String {
-        let emit_plan = LoweringPass::new(self.parser.get_arena(), &ctx).run_plan(root_idx);
+    /// Emit the source file like `emit` and return an `EmitWithSourceMapResult`:
+    /// the JavaScript `code` and its source `map` as JSON. The map names the
+    /// output after the input file (`a.ts` -> `a.js`) and embeds the source
+    /// text; no `sourceMappingURL` comment is added to `code`.
+    #[wasm_bindgen(
+        js_name = emitWithSourceMap,
+        unchecked_return_type = "EmitWithSourceMapResult"
+    )]
+    pub fn emit_with_source_map(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(
+            &self.emit_with_source_map_result(),
+        )?)
+    }
 
-        let mut printer = Printer::with_emit_plan_and_options(
-            self.parser.get_arena(),
-            emit_plan,
-            ctx.options.clone(),
-        );
-        printer.set_target_es5(ctx.target_es5);
-        printer.set_auto_detect_module(ctx.auto_detect_module);
-        printer.set_source_text(self.parser.get_source_text());
-        printer.emit(root_idx);
-        printer.get_output().to_string()
+    fn emit_with_context(&self, root_idx: parser::NodeIndex, ctx: EmitContext) -> String {
+        self.print(root_idx, &ctx, false).0
     }
 
     /// Generate transform directives based on compiler options.
     #[wasm_bindgen(js_name = generateTransforms)]
     pub fn generate_transforms(&self, target: u32, module: u32) -> WasmTransformContext {
+        self.lower(target, module, TransformContext::new())
+    }
+
+    /// Run the built-in lowering for `target` and `module` on top of the
+    /// replacements already in `transforms`.
+    fn lower(
+        &self,
+        target: u32,
+        module: u32,
+        transforms: TransformContext,
+    ) -> WasmTransformContext {
         let options = PrinterOptions {
             target: ScriptTarget::from_ts_numeric(target).unwrap_or(ScriptTarget::ESNext),
             module: ModuleKind::from_ts_numeric(module).unwrap_or(ModuleKind::None),
@@ -368,10 +461,11 @@ impl Parser {
 
         let ctx = EmitContext::with_options(options);
         let transforms = if let Some(root_idx) = self.source_file_idx {
-            let lowering = LoweringPass::new(self.parser.get_arena(), &ctx);
-            lowering.run(root_idx)
+            LoweringPass::new(self.parser.get_arena(), &ctx)
+                .with_transforms(transforms)
+                .run(root_idx)
         } else {
-            TransformContext::new()
+            transforms
         };
 
         WasmTransformContext {
@@ -397,19 +491,91 @@ impl Parser {
         }
     }
 
-    /// Get the AST as JSON (for debugging).
+    /// Emit the source file with JS custom transformers applied around the
+    /// built-in transforms for `target` and `module`.
+    ///
+    /// # Example
+    /// ```javascript
+    /// const transformers = new WasmCustomTransformers();
+    /// transformers.addBefore((idx, kindName) =>
+    ///   kindName === "DebuggerStatement" ? "" : undefined);
+    /// const js = parser.emitWithCustomTransformers(1, 1, transformers);
+    /// ```
+    #[wasm_bindgen(js_name = emitWithCustomTransformers)]
+    pub fn emit_with_custom_transformers(
+        &self,
+        target: u32,
+        module: u32,
+        transformers: &WasmCustomTransformers,
+    ) -> Result<String, JsValue> {
+        let Some(root_idx) = self.source_file_idx else {
+            return Ok(String::new());
+        };
+        let arena = self.parser.get_arena();
+
+        let mut before = TransformContext::new();
+        transformers.apply_before(arena, root_idx, &mut before)?;
+
+        let mut context = self.lower(target, module, before);
+        transformers.apply_after(arena, root_idx, &mut context.inner)?;
+
+        Ok(self.emit_with_transforms(&context))
+    }
+
+    /// Get the AST as JSON: `nodeCount`, `rootIdx` and the `root` node, each
+    /// node carrying its index, kind, kind name, span, identifier or literal
+    /// text, and children. Nodes more than 256 levels deep are left out and
+    /// their parent is marked `childrenOmitted`.
     #[wasm_bindgen(js_name = getAstJson)]
     pub fn get_ast_json(&self) -> String {
-        if let Some(root_idx) = self.source_file_idx {
-            let arena = self.parser.get_arena();
-            format!(
-                "{{\"nodeCount\": {}, \"rootIdx\": {}}}",
-                arena.len(),
-                root_idx.0
-            )
-        } else {
-            "{}".to_string()
+        let Some(root_idx) = self.source_file_idx else {
+            return "{}".to_string();
+        };
+        let arena = self.parser.get_arena();
+        let result = serde_json::json!({
+            "nodeCount": arena.len(),
+            "rootIdx": root_idx.0,
+            "root": AstNodeJson::build(arena, root_idx, 0),
+        });
+        serde_json::to_string(&result).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Get the indices of a node's children in source order.
+    #[wasm_bindgen(js_name = getChildren)]
+    pub fn get_children(&self, node_idx: u32) -> Vec<u32> {
+        if self.source_file_idx.is_none() {
+            return Vec::new();
         }
+        self.parser
+            .get_arena()
+            .get_children(parser::NodeIndex(node_idx))
+            .into_iter()
+            .map(|child| child.0)
+            .collect()
+    }
+
+    /// Call `visitor` with each child of a node in source order, like
+    /// `ts.forEachChild`. Stops at and returns the first truthy result.
+    #[wasm_bindgen(js_name = forEachChild)]
+    pub fn for_each_child(
+        &self,
+        node_idx: u32,
+        visitor: &ChildVisitor,
+    ) -> Result<JsValue, JsValue> {
+        for child in self.get_children(node_idx) {
+            let result = visitor.js_call(&JsValue::UNDEFINED, child)?;
+            if result.is_truthy() {
+                return Ok(result);
+            }
+        }
+        Ok(JsValue::UNDEFINED)
+    }
+
+    /// Get the `SyntaxKind` name of a node.
+    #[wasm_bindgen(js_name = getNodeKindName)]
+    pub fn get_node_kind_name(&self, node_idx: u32) -> Option<String> {
+        let node = self.parser.get_arena().get(parser::NodeIndex(node_idx))?;
+        Some(parser::syntax_kind_ext::syntax_kind_name(node.kind))
     }
 
     /// Debug type lowering - trace what happens when lowering an interface type
@@ -896,48 +1062,25 @@ impl Parser {
     /// Hover: Returns `HoverInfo` object.
     #[wasm_bindgen(js_name = getHoverAtPosition)]
     pub fn get_hover_at_position(&mut self, line: u32, character: u32) -> Result<JsValue, JsValue> {
-        self.ensure_bound()?;
-        self.ensure_line_map();
-
-        let root = self
-            .source_file_idx
-            .ok_or_else(|| JsValue::from_str("Source file not available"))?;
-        let binder = self
-            .binder
-            .as_ref()
-            .ok_or_else(|| JsValue::from_str("Binder not available"))?;
-        let line_map = self
-            .line_map
-            .as_ref()
-            .ok_or_else(|| JsValue::from_str("Line map not available"))?;
-        let source_text = self.parser.get_source_text();
-        let file_name = self.parser.get_file_name().to_string();
-        let checker_options = self.compiler_options.to_checker_options();
-        let lib_contexts = self.lib_contexts();
-
-        let provider = HoverProvider::with_options_and_lib_contexts(
-            self.parser.get_arena(),
-            binder,
-            line_map,
-            &self.type_interner,
-            source_text,
-            file_name,
-            FullProviderOptions {
-                strict: checker_options.strict,
-                sound_mode: checker_options.sound_mode,
-                checker_options: Some(checker_options),
-                lib_contexts: &lib_contexts,
-            },
-        );
-        let pos = Position::new(line, character);
+        let result = self.hover_at(line, character)?;
+        Ok(serde_wasm_bindgen::to_value(&result)?)
+    }
 
-        let result = provider.get_hover_with_scope_cache(
-            root,
-            pos,
-            &mut self.type_cache,
-            &mut self.scope_cache,
-            None,
-        );
+    /// Quick info in tsserver's shape: a `QuickInfo` with the symbol's kind,
+    /// kind modifiers, display string, `JSDoc` documentation and tags, or
+    /// `undefined` when there is no symbol at the position.
+    #[wasm_bindgen(
+        js_name = getQuickInfoAtPosition,
+        unchecked_return_type = "QuickInfo | undefined"
+    )]
+    pub fn get_quick_info_at_position(
+        &mut self,
+        line: u32,
+        character: u32,
+    ) -> Result<JsValue, JsValue> {
+        let result = self
+            .hover_at(line, character)?
+            .map(QuickInfoJson::from_hover);
         Ok(serde_wasm_bindgen::to_value(&result)?)
     }
 
@@ -1266,3 +1409,109 @@ impl Parser {
         Ok(serde_wasm_bindgen::to_value(&lsp_diagnostics)?)
     }
 }
+
+impl Parser {
+    /// Hover at a 0-based `line` and UTF-16 `character`.
+    pub(crate) fn hover_at(
+        &mut self,
+        line: u32,
+        character: u32,
+    ) -> Result<Option<HoverInfo>, JsValue> {
+        self.ensure_bound()?;
+        self.ensure_line_map();
+
+        let root = self
+            .source_file_idx
+            .ok_or_else(|| JsValue::from_str("Source file not available"))?;
+        let binder = self
+            .binder
+            .as_ref()
+            .ok_or_else(|| JsValue::from_str("Binder not available"))?;
+        let line_map = self
+            .line_map
+            .as_ref()
+            .ok_or_else(|| JsValue::from_str("Line map not available"))?;
+        let source_text = self.parser.get_source_text();
+        let file_name = self.parser.get_file_name().to_string();
+        let checker_options = self.compiler_options.to_checker_options();
+        let lib_contexts = self.lib_contexts();
+
+        let provider = HoverProvider::with_options_and_lib_contexts(
+            self.parser.get_arena(),
+            binder,
+            line_map,
+            &self.type_interner,
+            source_text,
+            file_name,
+            FullProviderOptions {
+                strict: checker_options.strict,
+                sound_mode: checker_options.sound_mode,
+                checker_options: Some(checker_options),
+                lib_contexts: &lib_contexts,
+            },
+        );
+        let pos = Position::new(line, character);
+
+        Ok(provider.get_hover_with_scope_cache(
+            root,
+            pos,
+            &mut self.type_cache,
+            &mut self.scope_cache,
+            None,
+        ))
+    }
+
+    /// Output of `emitWithSourceMap`.
+    pub(crate) fn emit_with_source_map_result(&self) -> EmitWithSourceMapResultJson {
+        let Some(root_idx) = self.source_file_idx else {
+            return EmitWithSourceMapResultJson::default();
+        };
+        let options = PrinterOptions {
+            target: ScriptTarget::ES5,
+            ..Default::default()
+        };
+        let mut ctx = EmitContext::with_options(options);
+        ctx.auto_detect_module = true;
+
+        let (code, map) = self.print(root_idx, &ctx, true);
+        EmitWithSourceMapResultJson {
+            code,
+            map: map.unwrap_or_default(),
+        }
+    }
+
+    /// Lower and print the source file, with the source map JSON when
+    /// `source_map` is set.
+    fn print(
+        &self,
+        root_idx: parser::NodeIndex,
+        ctx: &EmitContext,
+        source_map: bool,
+    ) -> (String, Option<String>) {
+        let emit_plan = LoweringPass::new(self.parser.get_arena(), ctx).run_plan(root_idx);
+
+        let mut printer = Printer::with_emit_plan_and_options(
+            self.parser.get_arena(),
+            emit_plan,
+            ctx.options.clone(),
+        );
+        printer.set_target_es5(ctx.target_es5);
+        printer.set_auto_detect_module(ctx.auto_detect_module);
+        let source_text = self.parser.get_source_text();
+        printer.set_source_text(source_text);
+        if source_map {
+            let file_name = self.parser.get_file_name();
+            let output_name = js_output_name(file_name, ctx.options.jsx)
+                .unwrap_or_else(|| base_name(file_name).to_string());
+            printer.set_source_map_text(source_text);
+            printer.enable_source_map(&output_name, base_name(file_name));
+        }
+        printer.emit(root_idx);
+        let map = if source_map {
+            printer.generate_source_map_json()
+        } else {
+            None
+        };
+        (printer.take_output(), map)
+    }
+}
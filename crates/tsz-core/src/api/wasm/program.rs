@@ -1,17 +1,84 @@
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 use wasm_bindgen::prelude::{JsValue, wasm_bindgen};
 
+use crate::api::wasm::cancellation::{WasmCancellationToken, cancelled_error};
 use crate::api::wasm::compiler_options::{CompilerOptions, parse_compiler_options_json};
-use crate::api::wasm::lib_cache::get_or_create_lib_file;
+use crate::api::wasm::host::{JsCompilerHost, JsModuleResolutionHost};
+use crate::api::wasm::host_resolution::{
+    automatic_type_entries, default_type_roots, program_file_name, program_host, resolve_import,
+};
+use crate::api::wasm::lib_cache::{
+    encode_preparsed_libs, get_or_create_lib_file, load_preparsed_libs,
+};
+use crate::api::wasm::program_emit::{
+    emit_checked_declarations, emit_program_file, js_output_name,
+};
+use crate::api::wasm::program_graph::module_graph_json;
 use crate::api::wasm::program_results::{
-    CheckDiagnosticJson, FileCheckResultJson, ParseDiagnosticJson,
+    CheckAllResultJson, DiagnosticJson, EmitOutputJson, FileCheckResultJson, ModuleGraphJson,
 };
+use crate::api::wasm::program_services::{
+    completions_in_program, definition_in_program, hover_in_program,
+};
+use crate::checker::diagnostics::Diagnostic;
+use crate::checker::module_resolution::build_module_resolution_maps;
+use crate::compiler_host::{self, CompilerHost, CompilerHostScope};
+use crate::config::file_discovery::{FileDiscoveryOptions, discover_ts_files};
 use crate::lib_loader;
+use crate::lsp::position::{LineMap, Location, Position, Range};
+use crate::lsp::{CompletionItem, HoverInfo};
+use crate::module_resolver::ModuleResolver;
 use crate::parallel::{
-    BindResult, MergedProgram, check_files_parallel, merge_bind_results, parse_and_bind_parallel,
+    self, BindResult, CheckedFileContext, MergedProgram, check_file_for_language_service,
+    check_files_parallel_subset, check_files_parallel_with_resolutions,
+    check_files_streaming_cancellable, merge_bind_results_ref, parse_and_bind_parallel,
 };
+use crate::parser::ParseDiagnostic;
+use tsz_common::CancellationToken;
+
+/// A module specifier resolved through the host:
+/// `(importing file, specifier, resolved file)`.
+type HostResolution = (String, String, String);
+
+/// One ranged edit passed to `updateFile`, in LSP coordinates (0-based
+/// lines, UTF-16 columns) against the file's text before the update.
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FileTextEdit {
+    pub(crate) range: Range,
+    #[serde(alias = "text")]
+    pub(crate) new_text: String,
+}
+
+/// A file's bind result, reused until the file's text changes.
+struct CachedBind {
+    text_hash: u64,
+    bind_result: BindResult,
+}
+
+/// The files a bind pass produced, in merge order.
+struct BoundProgram {
+    file_names: Vec<String>,
+    host_resolutions: Vec<HostResolution>,
+}
+
+/// Parse and check diagnostics for one file.
+struct FileDiagnostics {
+    file_name: String,
+    parse_diagnostics: Vec<ParseDiagnostic>,
+    check_diagnostics: Vec<Diagnostic>,
+}
+
+/// Diagnostics for a whole program: its files (including those loaded
+/// through the host) and then its lib files.
+struct ProgramDiagnostics {
+    files: Vec<FileDiagnostics>,
+    lib_files: Vec<FileDiagnostics>,
+}
 
 /// Multi-file TypeScript program for cross-file type checking.
 ///
@@ -26,25 +93,61 @@ use crate::parallel::{
 /// const result = program.checkAll();
 /// console.log(result);
 /// ```
+#[wasm_bindgen]
+extern "C" {
+    /// A JavaScript function `checkAllStreaming` calls with each file's
+    /// `FileCheckResult`.
+    #[wasm_bindgen(typescript_type = "(result: FileCheckResult) => void")]
+    pub type FileCheckCallback;
+
+    #[wasm_bindgen(method, catch, js_name = call)]
+    fn js_call(
+        this: &FileCheckCallback,
+        this_arg: &JsValue,
+        result: &JsValue,
+    ) -> Result<JsValue, JsValue>;
+}
+
 #[wasm_bindgen]
 pub struct WasmProgram {
     /// Accumulated files before compilation
     files: Vec<(String, String)>,
     /// Merged program state after compilation (lazy)
     merged: Option<MergedProgram>,
-    /// Bind results (kept for diagnostics access)
-    bind_results: Option<Vec<BindResult>>,
+    /// Module specifiers of `merged` resolved through the host, keyed by
+    /// importing file index.
+    host_resolutions: FxHashMap<(usize, String), usize>,
+    /// Files of `merged` checked for hover and completions, by file index.
+    /// Dropped together with `merged`.
+    file_contexts: FxHashMap<usize, CheckedFileContext>,
+    /// Per-file bind results, keyed by file name, reused across checks for
+    /// files whose text is unchanged.
+    bound_files: FxHashMap<String, CachedBind>,
+    /// Check diagnostics from the last check, keyed by file name. Files an
+    /// edit cannot affect keep these instead of being re-checked; re-binding
+    /// a file drops its entry.
+    check_cache: FxHashMap<String, Vec<Diagnostic>>,
+    /// Set when a change can affect every file (new files, options, lib or
+    /// host changes, edits to global declarations), so the next check
+    /// cannot reuse `check_cache`.
+    full_check_required: bool,
     /// Lib files (lib.d.ts, lib.dom.d.ts, etc.) for global symbol resolution
     lib_files: Vec<(String, String)>,
     /// Compiler options for type checking
     compiler_options: CompilerOptions,
     /// Cached output of `checkAll()`, populated lazily on first call.
-    /// Invalidated by `addFile` / `addLibFile` / `setCompilerOptions` / `clear`.
-    check_all_cache: Option<String>,
+    /// Invalidated by `addFile` / `addLibFile` / `setCompilerOptions` /
+    /// `updateFile` / `clear`.
+    check_all_cache: Option<CheckAllResultJson>,
     /// Cached output of `getDiagnosticCodes()`, populated lazily on first call.
     diagnostic_codes_cache: Option<String>,
     /// Cached output of `getAllDiagnosticCodes()`, populated lazily on first call.
     all_diagnostic_codes_cache: Option<Vec<u32>>,
+    /// File-system host for files the program imports but was not given.
+    host: Option<Arc<dyn CompilerHost>>,
+    /// Directory of the tsconfig loaded by `loadTsconfig`; the default
+    /// `@types` search starts here.
+    project_dir: Option<PathBuf>,
 }
 
 impl Default for WasmProgram {
@@ -62,23 +165,38 @@ impl WasmProgram {
             files: Vec::new(),
             lib_files: Vec::new(),
             merged: None,
-            bind_results: None,
+            host_resolutions: FxHashMap::default(),
+            file_contexts: FxHashMap::default(),
+            bound_files: FxHashMap::default(),
+            check_cache: FxHashMap::default(),
+            full_check_required: true,
             compiler_options: CompilerOptions::default(),
             check_all_cache: None,
             diagnostic_codes_cache: None,
             all_diagnostic_codes_cache: None,
+            host: None,
+            project_dir: None,
         }
     }
 
     /// Drop all cached diagnostic outputs. Call from any mutator that
     /// changes program inputs (`addFile`, `addLibFile`, `setCompilerOptions`,
-    /// `clear`) so the next diagnostic query rebuilds from fresh sources.
+    /// `updateFile`, `clear`) so the next diagnostic query rebuilds from
+    /// fresh sources.
     fn invalidate_diagnostic_caches(&mut self) {
         self.check_all_cache = None;
         self.diagnostic_codes_cache = None;
         self.all_diagnostic_codes_cache = None;
     }
 
+    /// Invalidate every file's check results. Bind results stay cached and
+    /// are reused for files whose text is unchanged.
+    fn invalidate_program(&mut self) {
+        self.discard_merged();
+        self.full_check_required = true;
+        self.invalidate_diagnostic_caches();
+    }
+
     /// Add a file to the program.
     ///
     /// Files are accumulated and compiled together when `checkAll` is called.
@@ -88,9 +206,7 @@ impl WasmProgram {
     #[wasm_bindgen(js_name = addFile)]
     pub fn add_file(&mut self, file_name: String, source_text: String) {
         // Invalidate any previous compilation
-        self.merged = None;
-        self.bind_results = None;
-        self.invalidate_diagnostic_caches();
+        self.invalidate_program();
 
         // Skip package.json files - they're used for module resolution but not parsed
         if file_name.ends_with("package.json") {
@@ -116,14 +232,44 @@ impl WasmProgram {
     /// ```
     #[wasm_bindgen(js_name = addLibFile)]
     pub fn add_lib_file(&mut self, file_name: String, source_text: String) {
-        // Invalidate any previous compilation
-        self.merged = None;
-        self.bind_results = None;
-        self.invalidate_diagnostic_caches();
+        // Invalidate any previous compilation. Files are bound against the
+        // lib symbols, so no bind result can be reused.
+        self.bound_files.clear();
+        self.invalidate_program();
 
         self.lib_files.push((file_name, source_text));
     }
 
+    /// Serialize the lib files added so far, parsed and bound, into a blob
+    /// JS hosts can persist (e.g. in IndexedDB) and pass to
+    /// `addPreparsedLibs` on a later start.
+    #[wasm_bindgen(js_name = serializeLibFiles)]
+    pub fn serialize_lib_files(&self) -> Result<Vec<u8>, JsValue> {
+        encode_preparsed_libs(&self.lib_files).map_err(|err| JsValue::from_str(&err))
+    }
+
+    /// Add the lib files of a blob from `serializeLibFiles` without parsing
+    /// or binding them again.
+    ///
+    /// # Example (JavaScript)
+    /// ```javascript
+    /// const cached = await idb.get("tsz-libs");
+    /// if (cached) {
+    ///   program.addPreparsedLibs(cached);
+    /// } else {
+    ///   program.addLibFile("lib.d.ts", libContent);
+    ///   await idb.set("tsz-libs", program.serializeLibFiles());
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = addPreparsedLibs)]
+    pub fn add_preparsed_libs(&mut self, bytes: &[u8]) -> Result<(), JsValue> {
+        let libs = load_preparsed_libs(bytes).map_err(|err| JsValue::from_str(&err))?;
+        for (file_name, source_text) in libs {
+            self.add_lib_file(file_name, source_text);
+        }
+        Ok(())
+    }
+
     /// Set compiler options from JSON.
     ///
     /// # Arguments
@@ -131,14 +277,75 @@ impl WasmProgram {
     #[wasm_bindgen(js_name = setCompilerOptions)]
     pub fn set_compiler_options(&mut self, options_json: &str) -> Result<(), JsValue> {
         let options = parse_compiler_options_json(options_json)?;
-        self.compiler_options = options;
+        self.replace_compiler_options(options);
         // Invalidate any previous compilation since options affect typing
-        self.merged = None;
-        self.bind_results = None;
-        self.invalidate_diagnostic_caches();
+        self.invalidate_program();
         Ok(())
     }
 
+    /// Set the file-system host used for module resolution.
+    ///
+    /// With a host, imports of files the program was not given (relative
+    /// paths, `node_modules` packages and their `@types`) are resolved and
+    /// read through the host's callbacks, and `@types` packages are included
+    /// automatically, so programs work without pre-loading every dependency.
+    /// Directory listings are read once per host; call `setHost` again after
+    /// files are added on disk.
+    ///
+    /// # Example (JavaScript)
+    /// ```javascript
+    /// program.setHost({
+    ///     fileExists: (path) => fs.existsSync(path),
+    ///     readFile: (path) => fs.existsSync(path) ? fs.readFileSync(path, "utf8") : undefined,
+    ///     directoryExists: (path) => fs.existsSync(path),
+    ///     realpath: (path) => fs.realpathSync(path),
+    /// });
+    /// program.loadTsconfig("/project/tsconfig.json");
+    /// ```
+    #[wasm_bindgen(js_name = setHost)]
+    pub fn set_host(&mut self, host: JsModuleResolutionHost) {
+        self.set_compiler_host(Arc::new(JsCompilerHost::new(host)));
+    }
+
+    /// Load a tsconfig through the host.
+    ///
+    /// Its compiler options (following `extends`) replace the current ones,
+    /// and its root files (`files` and `include`, minus `exclude`) are read
+    /// through the host and added to the program.
+    #[wasm_bindgen(js_name = loadTsconfig)]
+    pub fn load_tsconfig(&mut self, config_path: &str) -> Result<(), JsValue> {
+        self.load_tsconfig_from_host(config_path)
+            .map_err(|err| JsValue::from_str(&err))
+    }
+
+    /// Apply ranged text edits to a file already in the program.
+    ///
+    /// `edits` is an array of `{ range: { start, end }, newText }` objects,
+    /// with `start`/`end` as `{ line, character }` (0-based lines, UTF-16
+    /// columns) against the file's current text. Edits may not overlap.
+    ///
+    /// The next diagnostic query re-parses only this file and re-checks only
+    /// it and the files that (transitively) import it; every other file keeps
+    /// its previous diagnostics. Edits to scripts or files that augment
+    /// global or module scope re-check the whole program.
+    ///
+    /// # Example (JavaScript)
+    /// ```javascript
+    /// program.updateFile("src/a.ts", [
+    ///     { range: { start: { line: 0, character: 13 }, end: { line: 0, character: 14 } }, newText: "y" },
+    /// ]);
+    /// ```
+    #[wasm_bindgen(js_name = updateFile)]
+    pub fn update_file(
+        &mut self,
+        file_name: &str,
+        #[wasm_bindgen(unchecked_param_type = "TextEdit[]")] edits: JsValue,
+    ) -> Result<(), JsValue> {
+        let edits: Vec<FileTextEdit> = serde_wasm_bindgen::from_value(edits)?;
+        self.apply_file_edits(file_name, &edits)
+            .map_err(|err| JsValue::from_str(&err))
+    }
+
     /// Get the number of files in the program.
     // wasm_bindgen cannot bind const fn.
     #[allow(clippy::missing_const_for_fn)]
@@ -147,17 +354,139 @@ impl WasmProgram {
         self.files.len()
     }
 
+    /// Emit JavaScript, source maps and declarations for every program file.
+    ///
+    /// Returns an array with one `{ fileName, jsText, sourceMap, dtsText }`
+    /// object per emitted input, honoring the compiler options: `target`,
+    /// `module`, `removeComments`, `sourceMap` (a separate map, referenced
+    /// from `jsText`), `inlineSourceMap` (embedded in `jsText`),
+    /// `declaration` and `emitDeclarationOnly`. Files are type checked for
+    /// declarations, so those without type annotations get their inferred
+    /// types. Outputs that were not requested are `undefined`. Nothing is
+    /// written anywhere; output names follow the input names (`a.ts` emits
+    /// `a.js`, `a.js.map` and `a.d.ts`).
+    ///
+    /// # Example (JavaScript)
+    /// ```javascript
+    /// program.setCompilerOptions(JSON.stringify({ module: "esnext", sourceMap: true }));
+    /// for (const { fileName, jsText, sourceMap } of program.emitAll()) { ... }
+    /// ```
+    #[wasm_bindgen(js_name = emitAll, unchecked_return_type = "EmitOutput[]")]
+    pub fn emit_all(&mut self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&self.emit_outputs())?)
+    }
+
+    /// The `.d.ts` text for one program file, or `undefined` for declaration
+    /// files and non-TypeScript inputs.
+    ///
+    /// As with `emitAll`, the file is type checked first, so declarations
+    /// without type annotations get their inferred types. Throws if
+    /// `fileName` is not in the program.
+    #[wasm_bindgen(js_name = getDeclarationOutput)]
+    pub fn get_declaration_output(&mut self, file_name: &str) -> Result<Option<String>, JsValue> {
+        self.declaration_output(file_name)
+            .map_err(|err| JsValue::from_str(&err))
+    }
+
+    /// The program's import structure as a `ModuleGraph`: each file's direct
+    /// dependencies and dependents, and the groups of files that import each
+    /// other in a cycle.
+    ///
+    /// Imports are resolved like `checkAll` resolves them, through the
+    /// module resolution host when one is set. Unresolved imports and lib
+    /// files are left out.
+    #[wasm_bindgen(js_name = getModuleGraph, unchecked_return_type = "ModuleGraph")]
+    pub fn get_module_graph(&mut self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&self.module_graph())?)
+    }
+
+    /// Quick info for the symbol at a position: a `HoverInfo` object, or
+    /// `undefined`.
+    ///
+    /// Runs on the whole program, so imported names show the types declared
+    /// in the files that export them. `line` and `character` are 0-based
+    /// (UTF-16 columns). Throws if `fileName` is not in the program.
+    #[wasm_bindgen(
+        js_name = getHoverAtPosition,
+        unchecked_return_type = "HoverInfo | undefined"
+    )]
+    pub fn get_hover_at_position(
+        &mut self,
+        file_name: &str,
+        line: u32,
+        character: u32,
+    ) -> Result<JsValue, JsValue> {
+        let hover = self
+            .hover_at(file_name, Position::new(line, character))
+            .map_err(|err| JsValue::from_str(&err))?;
+        Ok(serde_wasm_bindgen::to_value(&hover)?)
+    }
+
+    /// Go to definition: an array of `Location` objects, or `undefined`.
+    ///
+    /// Imported names resolve to their declarations in the exporting file,
+    /// following re-exports; namespace imports resolve to the start of the
+    /// imported file.
+    #[wasm_bindgen(
+        js_name = getDefinitionAtPosition,
+        unchecked_return_type = "Location[] | undefined"
+    )]
+    pub fn get_definition_at_position(
+        &mut self,
+        file_name: &str,
+        line: u32,
+        character: u32,
+    ) -> Result<JsValue, JsValue> {
+        let definitions = self
+            .definition_at(file_name, Position::new(line, character))
+            .map_err(|err| JsValue::from_str(&err))?;
+        Ok(serde_wasm_bindgen::to_value(&definitions)?)
+    }
+
+    /// Completions: an array of `CompletionItem` objects, or `undefined`.
+    ///
+    /// Member completions on imported values list the members of their
+    /// types from the exporting file.
+    #[wasm_bindgen(
+        js_name = getCompletionsAtPosition,
+        unchecked_return_type = "CompletionItem[] | undefined"
+    )]
+    pub fn get_completions_at_position(
+        &mut self,
+        file_name: &str,
+        line: u32,
+        character: u32,
+    ) -> Result<JsValue, JsValue> {
+        let completions = self
+            .completions_at(file_name, Position::new(line, character))
+            .map_err(|err| JsValue::from_str(&err))?;
+        Ok(serde_wasm_bindgen::to_value(&completions)?)
+    }
+
     /// Clear all files and reset the program state.
     #[wasm_bindgen]
     pub fn clear(&mut self) {
         self.files.clear();
         self.lib_files.clear();
-        self.merged = None;
-        self.bind_results = None;
-        self.invalidate_diagnostic_caches();
+        self.bound_files.clear();
+        self.check_cache.clear();
+        self.invalidate_program();
+    }
+
+    /// Drop every file, lib file, option, host and cached compilation,
+    /// releasing their arenas and interners, and return memory statistics
+    /// afterwards as JSON (see `getMemoryStatistics`).
+    ///
+    /// Unlike `clear`, nothing carries over: the program is as if newly
+    /// created. Pair with `recycleCompilerCaches()` to also drop the parsed
+    /// lib cache shared across programs.
+    #[wasm_bindgen]
+    pub fn reset(&mut self) -> String {
+        *self = Self::new();
+        crate::api::wasm::memory::memory_statistics_json()
     }
 
-    /// Compile all files and return diagnostics as JSON.
+    /// Compile all files and return their diagnostics.
     ///
     /// This performs:
     /// 1. Load lib files for global symbol resolution
@@ -166,11 +495,163 @@ impl WasmProgram {
     /// 4. Symbol merging (sequential)
     /// 5. Parallel type checking
     ///
-    /// Returns a JSON object with diagnostics per file.
-    #[wasm_bindgen(js_name = checkAll)]
-    pub fn check_all(&mut self) -> String {
+    /// Returns a `CheckAllResult`: parse and check diagnostics per file
+    /// (with category, code, span, file and related information) and totals.
+    #[wasm_bindgen(js_name = checkAll, unchecked_return_type = "CheckAllResult")]
+    pub fn check_all(&mut self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&self.check_all_result())?)
+    }
+
+    /// `checkAll`, calling `callback` with each file's `FileCheckResult` as
+    /// soon as its diagnostics are known instead of only at the end.
+    ///
+    /// Files whose cached diagnostics are still valid are reported first,
+    /// then each re-checked file as it finishes. A file whose diagnostics a
+    /// later cross-file pass changes is reported again; the later result
+    /// replaces the earlier one. Returns the complete `CheckAllResult`.
+    ///
+    /// Checking is synchronous, so run this in a worker and post each
+    /// result to show progress while it runs. If `callback` throws, no more
+    /// results are reported and the error is rethrown once checking ends.
+    ///
+    /// With a `cancellation` token, the check stops before the next file
+    /// once the token is cancelled, e.g. from `callback`, and throws; a later
+    /// call starts over.
+    ///
+    /// # Example (JavaScript)
+    /// ```javascript
+    /// program.checkAllStreaming((file) => postMessage({ type: "file", file }));
+    /// ```
+    #[wasm_bindgen(js_name = checkAllStreaming, unchecked_return_type = "CheckAllResult")]
+    pub fn check_all_streaming(
+        &mut self,
+        callback: &FileCheckCallback,
+        cancellation: Option<&WasmCancellationToken>,
+    ) -> Result<JsValue, JsValue> {
+        let mut callback_error = None;
+        let result = self.check_all_cancellable(
+            Some(&mut |file| {
+                if callback_error.is_some() {
+                    return;
+                }
+                let reported = serde_wasm_bindgen::to_value(file)
+                    .map_err(JsValue::from)
+                    .and_then(|value| callback.js_call(&JsValue::UNDEFINED, &value));
+                if let Err(err) = reported {
+                    callback_error = Some(err);
+                }
+            }),
+            cancellation.map(|token| &token.inner),
+        );
+        if let Some(err) = callback_error {
+            return Err(err);
+        }
+        let result = result.ok_or_else(cancelled_error)?;
+        Ok(serde_wasm_bindgen::to_value(&result)?)
+    }
+
+    /// Get diagnostic codes for all files (for conformance testing).
+    ///
+    /// Returns a JSON object mapping file names to arrays of error codes.
+    #[wasm_bindgen(js_name = getDiagnosticCodes)]
+    pub fn get_diagnostic_codes(&mut self) -> String {
+        if self.files.is_empty() && self.lib_files.is_empty() {
+            return "{}".to_string();
+        }
+
+        if let Some(cached) = self.diagnostic_codes_cache.as_ref() {
+            return cached.clone();
+        }
+
+        let diagnostics = self
+            .collect_diagnostics(None, None)
+            .expect("a check without a cancellation token runs to completion");
+
+        // Parse codes, then check codes, per file
+        let mut file_codes: FxHashMap<String, Vec<u32>> = FxHashMap::default();
+        for file in diagnostics.files.iter().chain(&diagnostics.lib_files) {
+            let entry = file_codes.entry(file.file_name.clone()).or_default();
+            entry.extend(file.parse_diagnostics.iter().map(|d| d.code));
+            entry.extend(file.check_diagnostics.iter().map(|d| d.code));
+        }
+
+        let serialized = serde_json::to_string(&file_codes).unwrap_or_else(|_| "{}".to_string());
+        self.diagnostic_codes_cache = Some(serialized.clone());
+        serialized
+    }
+
+    /// Get all diagnostic codes as a flat array (for simple conformance comparison).
+    ///
+    /// This combines all parse and check diagnostics from all files into a single
+    /// array of error codes, which can be compared against tsc output.
+    #[wasm_bindgen(js_name = getAllDiagnosticCodes)]
+    pub fn get_all_diagnostic_codes(&mut self) -> Vec<u32> {
+        self.all_diagnostic_codes()
+    }
+}
+
+impl WasmProgram {
+    /// Codes for `getAllDiagnosticCodes`.
+    pub(crate) fn all_diagnostic_codes(&mut self) -> Vec<u32> {
         if self.files.is_empty() && self.lib_files.is_empty() {
-            return r#"{"files":[],"stats":{"totalFiles":0,"totalDiagnostics":0}}"#.to_string();
+            return Vec::new();
+        }
+
+        if let Some(cached) = self.all_diagnostic_codes_cache.as_ref() {
+            return cached.clone();
+        }
+
+        let diagnostics = self
+            .collect_diagnostics(None, None)
+            .expect("a check without a cancellation token runs to completion");
+
+        // All parse codes first, then all check codes (lib files last)
+        let mut all_codes: Vec<u32> = diagnostics
+            .files
+            .iter()
+            .flat_map(|file| file.parse_diagnostics.iter().map(|d| d.code))
+            .collect();
+        all_codes.extend(
+            diagnostics
+                .files
+                .iter()
+                .chain(&diagnostics.lib_files)
+                .flat_map(|file| file.check_diagnostics.iter().map(|d| d.code)),
+        );
+
+        self.all_diagnostic_codes_cache = Some(all_codes.clone());
+        all_codes
+    }
+
+    /// Diagnostics for `checkAll`, cached until the program changes. Files
+    /// are checked in parallel.
+    pub(crate) fn check_all_result(&mut self) -> CheckAllResultJson {
+        self.check_all_cancellable(None, None)
+            .expect("a check without a cancellation token runs to completion")
+    }
+
+    /// Diagnostics for `checkAllStreaming`: `check_all_result`, reporting
+    /// each program file to `on_file` as its diagnostics become known. A
+    /// file can be reported twice; the later report replaces the earlier.
+    pub(crate) fn check_all_streaming_with(
+        &mut self,
+        on_file: &mut dyn FnMut(&FileCheckResultJson),
+    ) -> CheckAllResultJson {
+        self.check_all_cancellable(Some(on_file), None)
+            .expect("a check without a cancellation token runs to completion")
+    }
+
+    /// `check_all_result`, reporting each file to `on_file` when set and
+    /// polling `cancellation` between files. Either one checks the files one
+    /// at a time. `None` when the token was cancelled before the check
+    /// finished.
+    pub(crate) fn check_all_cancellable(
+        &mut self,
+        mut on_file: Option<&mut dyn FnMut(&FileCheckResultJson)>,
+        cancellation: Option<&CancellationToken>,
+    ) -> Option<CheckAllResultJson> {
+        if self.files.is_empty() && self.lib_files.is_empty() {
+            return Some(CheckAllResultJson::default());
         }
 
         // Reuse cached output when the program hasn't been mutated since the
@@ -178,230 +659,848 @@ impl WasmProgram {
         // parse + bind + merge + check pipeline on every diagnostic call,
         // even when the inputs were unchanged. Conformance harnesses, the
         // playground, and any caller asking for diagnostics in more than one
-        // form (e.g. JSON for display PLUS codes for comparison) paid for
-        // the entire pipeline twice or three times per program revision.
+        // form (e.g. diagnostics for display PLUS codes for comparison) paid
+        // for the entire pipeline twice or three times per program revision.
         if let Some(cached) = self.check_all_cache.as_ref() {
-            return cached.clone();
+            if let Some(on_file) = on_file {
+                for file in &cached.files {
+                    on_file(file);
+                }
+            }
+            return Some(cached.clone());
         }
 
-        // Load lib files for binding
-        // Use cache to avoid re-parsing lib.d.ts for every test
-        let lib_file_objects: Vec<Arc<lib_loader::LibFile>> = self
-            .lib_files
+        let mut report = on_file
+            .as_deref_mut()
+            .map(|on_file| move |file: &FileDiagnostics| on_file(&file_check_result_json(file)));
+        let diagnostics = self.collect_diagnostics(
+            report
+                .as_mut()
+                .map(|report| report as &mut dyn FnMut(&FileDiagnostics)),
+            cancellation,
+        )?;
+        let mut result = CheckAllResultJson::default();
+        for file in &diagnostics.files {
+            let file_result = file_check_result_json(file);
+            result.stats.total_diagnostics +=
+                file_result.parse_diagnostics.len() + file_result.check_diagnostics.len();
+            result.files.push(file_result);
+        }
+        result.stats.total_files = result.files.len();
+
+        self.check_all_cache = Some(result.clone());
+        Some(result)
+    }
+
+    /// Emit every program file (those added or loaded from a tsconfig, not
+    /// files only reached through the host), in order. Declaration files
+    /// and non-TypeScript inputs produce no output.
+    pub(crate) fn emit_outputs(&mut self) -> Vec<EmitOutputJson> {
+        self.ensure_merged();
+        let Some(merged) = self.merged.as_ref() else {
+            return Vec::new();
+        };
+        let file_index: FxHashMap<&str, usize> = merged
+            .files
             .iter()
-            .map(|(file_name, source_text)| {
-                get_or_create_lib_file(file_name.clone(), source_text.clone())
-            })
+            .enumerate()
+            .map(|(idx, file)| (file.file_name.as_str(), idx))
             .collect();
+        // Declarations print inferred types, so each file is checked first;
+        // contexts already kept for language-service queries are reused.
+        let checker_options = self.compiler_options.to_checker_options();
+        let lib_file_objects = self
+            .compiler_options
+            .declaration()
+            .then(|| self.lib_file_objects());
+        self.files
+            .iter()
+            .filter_map(|(file_name, source_text)| {
+                let file_idx = *file_index.get(file_name.as_str())?;
+                let checked;
+                let context = match (&lib_file_objects, self.file_contexts.get(&file_idx)) {
+                    (None, _) => None,
+                    (Some(_), Some(context)) => Some(context),
+                    (Some(lib_file_objects), None) => {
+                        checked = check_file_for_language_service(
+                            merged,
+                            &checker_options,
+                            lib_file_objects,
+                            &self.host_resolutions,
+                            file_idx,
+                        );
+                        checked.as_ref()
+                    }
+                };
+                emit_program_file(
+                    merged,
+                    file_idx,
+                    source_text,
+                    context,
+                    &self.compiler_options,
+                )
+            })
+            .collect()
+    }
 
-        // Parse and bind all files in parallel with lib symbols
-        let bind_results = if !lib_file_objects.is_empty() {
-            // Use lib-aware binding
-            use crate::parallel;
-            parallel::parse_and_bind_parallel_with_libs(self.files.clone(), &lib_file_objects)
-        } else {
-            // No lib files - use regular binding
-            parse_and_bind_parallel(self.files.clone())
+    /// Import structure for `getModuleGraph`.
+    pub(crate) fn module_graph(&mut self) -> ModuleGraphJson {
+        self.ensure_merged();
+        let Some(merged) = self.merged.as_ref() else {
+            return ModuleGraphJson::default();
         };
-
-        // Collect parse diagnostics before merging
-        let parse_diags: Vec<Vec<_>> = bind_results
+        let bind_results: Vec<&BindResult> = merged
+            .files
             .iter()
-            .map(|r| r.parse_diagnostics.clone())
+            .filter_map(|file| {
+                self.bound_files
+                    .get(&file.file_name)
+                    .map(|cached| &cached.bind_result)
+            })
             .collect();
-        let file_names: Vec<String> = bind_results.iter().map(|r| r.file_name.clone()).collect();
-
-        // Merge bind results into unified program
-        let merged = merge_bind_results(bind_results);
-
-        // Type check all files in parallel
-        let checker_options = self.compiler_options.to_checker_options();
-        let check_result = check_files_parallel(&merged, &checker_options, &lib_file_objects);
-
-        // Build JSON result.
-        //
-        // Build an O(1) file-name -> check_result index up front instead of
-        // doing a linear `file_results.iter().find(...)` per file. The
-        // previous pattern was O(N²) in `file_names.len()` and lived on the
-        // result-assembly path AFTER checking had completed, so it was
-        // entirely avoidable scaling cost. On a 6000-file project that's
-        // ~36M comparisons; on small projects it's a no-op-cheap helper.
-        let check_results_by_file: FxHashMap<&str, &_> = check_result
-            .file_results
+        let file_names: Vec<&str> = bind_results
             .iter()
-            .map(|r| (r.file_name.as_str(), r))
+            .map(|result| result.file_name.as_str())
             .collect();
+        let imports = resolved_imports(&bind_results, &self.host_resolutions);
+        module_graph_json(&file_names, &imports)
+    }
 
-        let mut file_results: Vec<FileCheckResultJson> = Vec::with_capacity(file_names.len());
-        let mut total_diagnostics = 0;
+    /// Declarations for `getDeclarationOutput`.
+    pub(crate) fn declaration_output(&mut self, file_name: &str) -> Result<Option<String>, String> {
+        let file_idx = self.checked_file_context(file_name)?;
+        let jsx = self.compiler_options.to_printer_options().jsx;
+        if js_output_name(file_name, jsx).is_none() {
+            return Ok(None);
+        }
+        let (Some(merged), Some(context)) =
+            (self.merged.as_ref(), self.file_contexts.get(&file_idx))
+        else {
+            return Ok(None);
+        };
+        Ok(Some(emit_checked_declarations(
+            merged,
+            file_idx,
+            context,
+            &self.compiler_options,
+        )))
+    }
 
-        for (i, file_name) in file_names.iter().enumerate() {
-            let parse_diagnostics: Vec<ParseDiagnosticJson> = parse_diags[i]
-                .iter()
-                .map(|d| ParseDiagnosticJson {
-                    message: d.message.clone(),
-                    start: d.start,
-                    length: d.length,
-                    code: d.code,
-                })
-                .collect();
-
-            let check_diagnostics: Vec<CheckDiagnosticJson> = check_results_by_file
-                .get(file_name.as_str())
-                .map(|r| {
-                    r.diagnostics
-                        .iter()
-                        .map(|d| CheckDiagnosticJson {
-                            message_text: d.message_text.clone(),
-                            code: d.code,
-                            start: d.start,
-                            length: d.length,
-                            category: format!("{:?}", d.category),
-                        })
-                        .collect()
-                })
-                .unwrap_or_default();
+    /// Hover at `position` in `file_name`, with types resolved across the
+    /// program.
+    pub(crate) fn hover_at(
+        &mut self,
+        file_name: &str,
+        position: Position,
+    ) -> Result<Option<HoverInfo>, String> {
+        let file_idx = self.checked_file_context(file_name)?;
+        let (Some(merged), Some(context)) =
+            (self.merged.as_ref(), self.file_contexts.get_mut(&file_idx))
+        else {
+            return Ok(None);
+        };
+        Ok(hover_in_program(
+            merged,
+            file_idx,
+            context,
+            &self.compiler_options,
+            position,
+        ))
+    }
+
+    /// Completions at `position` in `file_name`, with types resolved across
+    /// the program.
+    pub(crate) fn completions_at(
+        &mut self,
+        file_name: &str,
+        position: Position,
+    ) -> Result<Option<Vec<CompletionItem>>, String> {
+        let file_idx = self.checked_file_context(file_name)?;
+        let (Some(merged), Some(context)) =
+            (self.merged.as_ref(), self.file_contexts.get_mut(&file_idx))
+        else {
+            return Ok(None);
+        };
+        Ok(completions_in_program(
+            merged,
+            file_idx,
+            context,
+            &self.compiler_options,
+            position,
+        ))
+    }
 
-            total_diagnostics += parse_diagnostics.len() + check_diagnostics.len();
+    /// Definition locations at `position` in `file_name`; imported names
+    /// resolve into the files that export them.
+    pub(crate) fn definition_at(
+        &mut self,
+        file_name: &str,
+        position: Position,
+    ) -> Result<Option<Vec<Location>>, String> {
+        let file_idx = self.checked_file_context(file_name)?;
+        let (Some(merged), Some(context)) =
+            (self.merged.as_ref(), self.file_contexts.get(&file_idx))
+        else {
+            return Ok(None);
+        };
+        Ok(definition_in_program(
+            merged,
+            file_idx,
+            context,
+            &self.host_resolutions,
+            position,
+        ))
+    }
 
-            file_results.push(FileCheckResultJson {
-                file_name: file_name.clone(),
-                parse_diagnostics,
-                check_diagnostics,
-            });
+    /// Index of `file_name` in the merged program, with its check context in
+    /// `file_contexts`. Files loaded through the host can be queried too.
+    fn checked_file_context(&mut self, file_name: &str) -> Result<usize, String> {
+        self.ensure_merged();
+        let merged = self
+            .merged
+            .as_ref()
+            .ok_or_else(|| format!("File '{file_name}' is not part of the program."))?;
+        let file_idx = merged
+            .files
+            .iter()
+            .position(|file| file.file_name == file_name)
+            .ok_or_else(|| format!("File '{file_name}' is not part of the program."))?;
+        if !self.file_contexts.contains_key(&file_idx) {
+            let lib_file_objects = self.lib_file_objects();
+            let context = check_file_for_language_service(
+                merged,
+                &self.compiler_options.to_checker_options(),
+                &lib_file_objects,
+                &self.host_resolutions,
+                file_idx,
+            )
+            .ok_or_else(|| format!("File '{file_name}' is not part of the program."))?;
+            self.file_contexts.insert(file_idx, context);
         }
+        Ok(file_idx)
+    }
 
-        // Store merged program for potential future queries
-        self.merged = Some(merged);
+    pub(crate) fn set_compiler_host(&mut self, host: Arc<dyn CompilerHost>) {
+        self.host = Some(host);
+        self.invalidate_program();
+    }
 
-        let result = serde_json::json!({
-            "files": file_results,
-            "stats": {
-                "totalFiles": file_names.len(),
-                "totalDiagnostics": total_diagnostics,
+    pub(crate) fn load_tsconfig_from_host(&mut self, config_path: &str) -> Result<(), String> {
+        let host = self
+            .host
+            .clone()
+            .ok_or_else(|| "loadTsconfig requires a host; call setHost first".to_string())?;
+        let _scope = CompilerHostScope::enter(Some(host));
+        let config_path = Path::new(config_path);
+        let config = crate::config::load_tsconfig(config_path).map_err(|err| format!("{err:#}"))?;
+        let options = CompilerOptions::from_config(config.compiler_options.as_ref())?;
+        let discovery = FileDiscoveryOptions::from_tsconfig(config_path, &config, None);
+        let mut root_files = Vec::new();
+        for path in discover_ts_files(&discovery).map_err(|err| format!("{err:#}"))? {
+            let file_name = program_file_name(&path);
+            if self.files.iter().any(|(name, _)| *name == file_name) {
+                continue;
             }
-        });
+            let source_text = compiler_host::read_file(&path)
+                .map_err(|_| format!("Cannot read file '{file_name}'."))?;
+            root_files.push((file_name, source_text));
+        }
 
-        let serialized = serde_json::to_string(&result).unwrap_or_else(|_| "{}".to_string());
-        self.check_all_cache = Some(serialized.clone());
-        serialized
+        self.replace_compiler_options(options);
+        self.project_dir = Some(discovery.base_dir);
+        self.files.extend(root_files);
+        self.invalidate_program();
+        Ok(())
     }
 
-    /// Get diagnostic codes for all files (for conformance testing).
-    ///
-    /// Returns a JSON object mapping file names to arrays of error codes.
-    #[wasm_bindgen(js_name = getDiagnosticCodes)]
-    pub fn get_diagnostic_codes(&mut self) -> String {
-        if self.files.is_empty() && self.lib_files.is_empty() {
-            return "{}".to_string();
+    /// Files are bound against the lib symbols, so bind results are dropped
+    /// when the options select different lib files.
+    fn replace_compiler_options(&mut self, options: CompilerOptions) {
+        if options.lib() != self.compiler_options.lib()
+            || options.no_lib() != self.compiler_options.no_lib()
+        {
+            self.bound_files.clear();
         }
+        self.compiler_options = options;
+    }
 
-        if let Some(cached) = self.diagnostic_codes_cache.as_ref() {
-            return cached.clone();
+    /// Lib files as parsed `LibFile`s, cached across programs so lib.d.ts is
+    /// not re-parsed for every test. With `lib` set, only the named lib files
+    /// and the libs they reference are used; with `noLib`, none are.
+    fn lib_file_objects(&self) -> Vec<Arc<lib_loader::LibFile>> {
+        if self.compiler_options.no_lib() {
+            return Vec::new();
         }
-
-        // Load lib files for binding (enables global symbol resolution: console, Array, etc.)
-        // Use cache to avoid re-parsing lib.d.ts for every test
-        let lib_file_objects: Vec<Arc<lib_loader::LibFile>> = self
-            .lib_files
+        let selected = self
+            .compiler_options
+            .lib()
+            .map(|libs| selected_lib_files(&self.lib_files, libs));
+        self.lib_files
             .iter()
+            .filter(|(file_name, _)| {
+                selected
+                    .as_ref()
+                    .is_none_or(|selected| selected.contains(file_name.as_str()))
+            })
             .map(|(file_name, source_text)| {
                 get_or_create_lib_file(file_name.clone(), source_text.clone())
             })
-            .collect();
+            .collect()
+    }
 
-        // Parse and bind all files in parallel with lib symbols
-        let bind_results = if !lib_file_objects.is_empty() {
-            use crate::parallel;
-            parallel::parse_and_bind_parallel_with_libs(self.files.clone(), &lib_file_objects)
-        } else {
-            parse_and_bind_parallel(self.files.clone())
+    /// Apply `updateFile` edits to `file_name`'s text. All edits are
+    /// validated before any is applied, so a rejected batch changes nothing.
+    pub(crate) fn apply_file_edits(
+        &mut self,
+        file_name: &str,
+        edits: &[FileTextEdit],
+    ) -> Result<(), String> {
+        let Some(index) = self.files.iter().position(|(name, _)| name == file_name) else {
+            return Err(format!("File '{file_name}' is not part of the program."));
         };
-
-        // Collect parse diagnostic codes
-        let mut file_codes: FxHashMap<String, Vec<u32>> = FxHashMap::default();
-        for result in &bind_results {
-            let codes: Vec<u32> = result.parse_diagnostics.iter().map(|d| d.code).collect();
-            file_codes.insert(result.file_name.clone(), codes);
+        let source_text = &self.files[index].1;
+        let line_map = LineMap::build(source_text);
+        let mut spans = Vec::with_capacity(edits.len());
+        for edit in edits {
+            let span = line_map
+                .range_to_span(edit.range, source_text)
+                .filter(|span| span.start <= span.end)
+                .ok_or_else(|| {
+                    format!(
+                        "Invalid edit range {}:{}-{}:{} in '{file_name}'.",
+                        edit.range.start.line,
+                        edit.range.start.character,
+                        edit.range.end.line,
+                        edit.range.end.character,
+                    )
+                })?;
+            spans.push((
+                span.start as usize,
+                span.end as usize,
+                edit.new_text.as_str(),
+            ));
+        }
+        // A stable sort keeps insertions at the same offset in request order.
+        spans.sort_by_key(|&(start, end, _)| (start, end));
+        if spans.windows(2).any(|pair| pair[1].0 < pair[0].1) {
+            return Err(format!("Overlapping edits in '{file_name}'."));
         }
 
-        // Merge and check
-        let merged = merge_bind_results(bind_results);
-        let checker_options = self.compiler_options.to_checker_options();
-        let check_result = check_files_parallel(&merged, &checker_options, &lib_file_objects);
+        let mut new_text = source_text.clone();
+        for &(start, end, text) in spans.iter().rev() {
+            new_text.replace_range(start..end, text);
+        }
 
-        // Add check diagnostic codes
-        for file_result in &check_result.file_results {
-            let entry = file_codes.entry(file_result.file_name.clone()).or_default();
-            for diag in &file_result.diagnostics {
-                entry.push(diag.code);
-            }
+        // Dropping a global declaration can change any file's diagnostics;
+        // edits that add one are caught when the file is re-bound.
+        if self
+            .bound_files
+            .get(file_name)
+            .is_some_and(|cached| affects_global_scope(&cached.bind_result))
+        {
+            self.full_check_required = true;
         }
+        self.files[index].1 = new_text;
+        self.discard_merged();
+        self.invalidate_diagnostic_caches();
+        Ok(())
+    }
+
+    /// The parsed arena currently cached for `file_name`, to observe which
+    /// files an update re-parsed.
+    #[cfg(test)]
+    pub(crate) fn cached_arena(
+        &self,
+        file_name: &str,
+    ) -> Option<Arc<crate::parser::node::NodeArena>> {
+        self.bound_files
+            .get(file_name)
+            .map(|cached| Arc::clone(&cached.bind_result.arena))
+    }
 
-        // Store merged program
+    /// Bind and merge the program without checking it, unless a merged
+    /// program from the current inputs is already stored.
+    fn ensure_merged(&mut self) {
+        if self.merged.is_some() {
+            return;
+        }
+        let lib_file_objects = self.lib_file_objects();
+        let bound = self.bind_program_files(&lib_file_objects);
+        let bind_results: Vec<&BindResult> = bound
+            .file_names
+            .iter()
+            .filter_map(|name| self.bound_files.get(name).map(|cached| &cached.bind_result))
+            .collect();
+        let merged = merge_bind_results_ref(&bind_results);
+        self.host_resolutions = index_host_resolutions(&merged, &bound.host_resolutions);
         self.merged = Some(merged);
+    }
 
-        let serialized = serde_json::to_string(&file_codes).unwrap_or_else(|_| "{}".to_string());
-        self.diagnostic_codes_cache = Some(serialized.clone());
-        serialized
+    /// Drop the merged program and everything computed from it.
+    fn discard_merged(&mut self) {
+        self.merged = None;
+        self.host_resolutions.clear();
+        self.file_contexts.clear();
     }
 
-    /// Get all diagnostic codes as a flat array (for simple conformance comparison).
+    /// Bind, merge and check the program, re-checking only the files
+    /// affected since the last check, and store the merged program.
     ///
-    /// This combines all parse and check diagnostics from all files into a single
-    /// array of error codes, which can be compared against tsc output.
-    #[wasm_bindgen(js_name = getAllDiagnosticCodes)]
-    pub fn get_all_diagnostic_codes(&mut self) -> Vec<u32> {
-        if self.files.is_empty() && self.lib_files.is_empty() {
-            return Vec::new();
-        }
-
-        if let Some(cached) = self.all_diagnostic_codes_cache.as_ref() {
-            return cached.clone();
+    /// With `on_file`, program files are reported as their diagnostics
+    /// become known: files reusing cached diagnostics first, then each
+    /// re-checked file as it finishes. A file the cross-file passes change
+    /// afterwards is reported again with its final diagnostics.
+    fn collect_diagnostics(
+        &mut self,
+        mut on_file: Option<&mut dyn FnMut(&FileDiagnostics)>,
+        cancellation: Option<&CancellationToken>,
+    ) -> Option<ProgramDiagnostics> {
+        let is_cancelled = || cancellation.is_some_and(CancellationToken::is_cancelled);
+        if is_cancelled() {
+            return None;
         }
 
         // Load lib files for binding (enables global symbol resolution: console, Array, etc.)
-        // Use cache to avoid re-parsing lib.d.ts for every test
-        let lib_file_objects: Vec<Arc<lib_loader::LibFile>> = self
-            .lib_files
+        let lib_file_objects = self.lib_file_objects();
+
+        // Parse and bind all files in parallel with lib symbols, plus any
+        // files the host resolves imports to
+        let bound = self.bind_program_files(&lib_file_objects);
+        if is_cancelled() {
+            return None;
+        }
+        let bind_results: Vec<&BindResult> = bound
+            .file_names
             .iter()
-            .map(|(file_name, source_text)| {
-                get_or_create_lib_file(file_name.clone(), source_text.clone())
-            })
+            .filter_map(|name| self.bound_files.get(name).map(|cached| &cached.bind_result))
             .collect();
 
-        // Parse and bind all files in parallel with lib symbols
-        let bind_results = if !lib_file_objects.is_empty() {
-            use crate::parallel;
-            parallel::parse_and_bind_parallel_with_libs(self.files.clone(), &lib_file_objects)
+        // Merge bind results into unified program
+        let merged = merge_bind_results_ref(&bind_results);
+
+        // Type check the affected files in parallel
+        let checker_options = self.compiler_options.to_checker_options();
+        let host_resolutions = index_host_resolutions(&merged, &bound.host_resolutions);
+        let files_to_check = if self.full_check_required {
+            None
         } else {
-            parse_and_bind_parallel(self.files.clone())
+            affected_files(&bind_results, &self.check_cache, &host_resolutions)
+        };
+        let mut streamed: Vec<Option<Vec<Diagnostic>>> = vec![None; bind_results.len()];
+        // Checking file by file lets each result be reported, and lets a
+        // cancellation stop the check between files.
+        let check_result = if on_file.is_some() || cancellation.is_some() {
+            for (file_idx, result) in bind_results.iter().enumerate() {
+                if let Some(on_file) = on_file.as_deref_mut()
+                    && files_to_check
+                        .as_ref()
+                        .is_some_and(|files_to_check| !files_to_check.contains(&file_idx))
+                {
+                    on_file(&FileDiagnostics {
+                        file_name: result.file_name.clone(),
+                        parse_diagnostics: result.parse_diagnostics.clone(),
+                        check_diagnostics: self
+                            .check_cache
+                            .get(&result.file_name)
+                            .cloned()
+                            .unwrap_or_default(),
+                    });
+                }
+            }
+            check_files_streaming_cancellable(
+                &merged,
+                &checker_options,
+                &lib_file_objects,
+                &host_resolutions,
+                files_to_check.as_ref(),
+                &mut |checked| {
+                    let Some(on_file) = on_file.as_deref_mut() else {
+                        return;
+                    };
+                    let Some(result) = bind_results.get(checked.file_idx) else {
+                        return;
+                    };
+                    streamed[checked.file_idx] = Some(checked.diagnostics.clone());
+                    on_file(&FileDiagnostics {
+                        file_name: result.file_name.clone(),
+                        parse_diagnostics: result.parse_diagnostics.clone(),
+                        check_diagnostics: checked.diagnostics.clone(),
+                    });
+                },
+                cancellation,
+            )?
+        } else {
+            match &files_to_check {
+                Some(files_to_check) => check_files_parallel_subset(
+                    &merged,
+                    &checker_options,
+                    &lib_file_objects,
+                    &host_resolutions,
+                    files_to_check,
+                ),
+                None => check_files_parallel_with_resolutions(
+                    &merged,
+                    &checker_options,
+                    &lib_file_objects,
+                    &host_resolutions,
+                ),
+            }
         };
 
-        // Collect all parse diagnostic codes
-        let mut all_codes: Vec<u32> = Vec::new();
-        for result in &bind_results {
-            for diag in &result.parse_diagnostics {
-                all_codes.push(diag.code);
+        let mut file_checks: Vec<Option<Vec<Diagnostic>>> = vec![None; bind_results.len()];
+        let mut lib_files = Vec::new();
+        for result in check_result.file_results {
+            match file_checks.get_mut(result.file_idx) {
+                Some(slot) => *slot = Some(result.diagnostics),
+                None => lib_files.push(FileDiagnostics {
+                    file_name: result.file_name,
+                    parse_diagnostics: Vec::new(),
+                    check_diagnostics: result.diagnostics,
+                }),
             }
         }
 
-        // Merge and check
-        let merged = merge_bind_results(bind_results);
-        let checker_options = self.compiler_options.to_checker_options();
-        let check_result = check_files_parallel(&merged, &checker_options, &lib_file_objects);
-
-        // Add all check diagnostic codes
-        for file_result in &check_result.file_results {
-            for diag in &file_result.diagnostics {
-                all_codes.push(diag.code);
+        let mut files = Vec::with_capacity(bind_results.len());
+        let mut check_cache = FxHashMap::default();
+        for (file_idx, (result, checked)) in bind_results.iter().zip(file_checks).enumerate() {
+            let check_diagnostics = if files_to_check
+                .as_ref()
+                .is_some_and(|files_to_check| !files_to_check.contains(&file_idx))
+            {
+                self.check_cache
+                    .get(&result.file_name)
+                    .cloned()
+                    .unwrap_or_default()
+            } else {
+                checked.unwrap_or_default()
+            };
+            check_cache.insert(result.file_name.clone(), check_diagnostics.clone());
+            let file = FileDiagnostics {
+                file_name: result.file_name.clone(),
+                parse_diagnostics: result.parse_diagnostics.clone(),
+                check_diagnostics,
+            };
+            if let Some(on_file) = on_file.as_deref_mut()
+                && streamed[file_idx]
+                    .as_ref()
+                    .is_some_and(|streamed| *streamed != file.check_diagnostics)
+            {
+                on_file(&file);
             }
+            files.push(file);
         }
 
-        // Store merged program
+        self.check_cache = check_cache;
+        self.full_check_required = false;
+        // Store merged program for potential future queries
         self.merged = Some(merged);
+        self.host_resolutions = host_resolutions;
+        self.file_contexts.clear();
 
-        self.all_diagnostic_codes_cache = Some(all_codes.clone());
-        all_codes
+        Some(ProgramDiagnostics { files, lib_files })
+    }
+
+    /// Parse and bind the program's files, reusing the cached bind result
+    /// of every file whose text is unchanged.
+    ///
+    /// With a host, the files they import that the program was not given,
+    /// and the automatically included `@types` entries, are read through the
+    /// host and bound too, until no new file turns up. Returns the bound
+    /// file names (program files first) and every host resolution.
+    fn bind_program_files(
+        &mut self,
+        lib_file_objects: &[Arc<lib_loader::LibFile>],
+    ) -> BoundProgram {
+        let Self {
+            files,
+            bound_files,
+            check_cache,
+            compiler_options,
+            host,
+            project_dir,
+            ..
+        } = self;
+        // Without a host, only `baseUrl`/`paths` can map an import onto
+        // another program file.
+        let resolution_options = compiler_options.module_resolution_options(project_dir.as_deref());
+        let resolve = host.is_some()
+            || resolution_options.base_url.is_some()
+            || resolution_options.paths.is_some();
+        let _scope =
+            resolve.then(|| CompilerHostScope::enter(Some(program_host(host.as_ref(), files))));
+        let mut resolver = resolve.then(|| ModuleResolver::new(&resolution_options));
+
+        let mut known: FxHashSet<String> = files.iter().map(|(name, _)| name.clone()).collect();
+        let mut pending = files.clone();
+
+        if let Some(resolver) = &resolver {
+            let type_roots = match &resolution_options.type_roots {
+                Some(roots) => roots.clone(),
+                None => {
+                    let search_dir = project_dir
+                        .clone()
+                        .or_else(|| {
+                            files
+                                .first()
+                                .and_then(|(name, _)| Path::new(name).parent())
+                                .map(Path::to_path_buf)
+                        })
+                        .unwrap_or_default();
+                    default_type_roots(&search_dir)
+                }
+            };
+            let type_entries = automatic_type_entries(
+                resolver,
+                &type_roots,
+                resolution_options.types.as_deref(),
+                &known,
+            );
+            for entry in type_entries {
+                if !known.contains(&entry)
+                    && let Ok(source_text) = compiler_host::read_file(Path::new(&entry))
+                {
+                    known.insert(entry.clone());
+                    pending.push((entry, source_text));
+                }
+            }
+        }
+
+        let mut bound = BoundProgram {
+            file_names: Vec::new(),
+            host_resolutions: Vec::new(),
+        };
+        while !pending.is_empty() {
+            let batch = std::mem::take(&mut pending);
+            let batch_names: Vec<String> = batch.iter().map(|(name, _)| name.clone()).collect();
+            bind_batch(bound_files, check_cache, batch, lib_file_objects);
+
+            if let Some(resolver) = &mut resolver {
+                let first_new = bound.host_resolutions.len();
+                for file_name in &batch_names {
+                    let Some(cached) = bound_files.get(file_name) else {
+                        continue;
+                    };
+                    for specifier in &cached.bind_result.file_import_sources {
+                        if let Some(resolved) =
+                            resolve_import(resolver, specifier, file_name, &known)
+                        {
+                            bound.host_resolutions.push((
+                                file_name.clone(),
+                                specifier.clone(),
+                                resolved,
+                            ));
+                        }
+                    }
+                }
+                for (_, _, resolved) in &bound.host_resolutions[first_new..] {
+                    if !known.contains(resolved)
+                        && let Ok(source_text) = compiler_host::read_file(Path::new(resolved))
+                    {
+                        known.insert(resolved.clone());
+                        pending.push((resolved.clone(), source_text));
+                    }
+                }
+            }
+            bound.file_names.extend(batch_names);
+        }
+
+        // Files that left the program (or the host's import graph) are dropped.
+        bound_files.retain(|name, _| known.contains(name));
+        bound
+    }
+}
+
+/// Names of the lib files `libs` selects from `lib_files`, following
+/// `/// <reference lib="..." />` directives between them. A lib file
+/// `lib.es2020.d.ts` is named `es2020`; `lib.d.ts` is named `lib`.
+fn selected_lib_files<'a>(
+    lib_files: &'a [(String, String)],
+    libs: &[String],
+) -> FxHashSet<&'a str> {
+    let by_name: FxHashMap<String, &(String, String)> = lib_files
+        .iter()
+        .map(|entry| (lib_name_of(&entry.0), entry))
+        .collect();
+    let mut selected = FxHashSet::default();
+    let mut visited = FxHashSet::default();
+    let mut pending: Vec<String> = libs
+        .iter()
+        .map(|name| match name.trim().to_ascii_lowercase().as_str() {
+            // As with `--lib`, `es6`/`es7` name the ES2015/ES2016 libs
+            // without DOM.
+            "es6" => "es2015".to_string(),
+            "es7" => "es2016".to_string(),
+            name => name.strip_prefix("lib.").unwrap_or(name).to_string(),
+        })
+        .collect();
+    while let Some(name) = pending.pop() {
+        if !visited.insert(name.clone()) {
+            continue;
+        }
+        let Some((file_name, source_text)) = by_name.get(&name) else {
+            continue;
+        };
+        selected.insert(file_name.as_str());
+        pending.extend(crate::config::extract_lib_references(source_text));
+    }
+    selected
+}
+
+fn lib_name_of(file_name: &str) -> String {
+    let base = file_name.rsplit('/').next().unwrap_or(file_name);
+    let base = base.to_ascii_lowercase();
+    let stem = base.strip_suffix(".d.ts").unwrap_or(&base);
+    stem.strip_prefix("lib.").unwrap_or(stem).to_string()
+}
+
+/// Parse and bind the files in `files` whose text differs from their cached
+/// bind result, dropping their stale check diagnostics.
+fn bind_batch(
+    bound_files: &mut FxHashMap<String, CachedBind>,
+    check_cache: &mut FxHashMap<String, Vec<Diagnostic>>,
+    files: Vec<(String, String)>,
+    lib_file_objects: &[Arc<lib_loader::LibFile>],
+) {
+    let mut to_bind = Vec::new();
+    let mut text_hashes = Vec::new();
+    for (file_name, source_text) in files {
+        let mut hasher = FxHasher::default();
+        source_text.hash(&mut hasher);
+        let text_hash = hasher.finish();
+        if bound_files
+            .get(&file_name)
+            .is_some_and(|cached| cached.text_hash == text_hash)
+        {
+            continue;
+        }
+        to_bind.push((file_name, source_text));
+        text_hashes.push(text_hash);
+    }
+    if to_bind.is_empty() {
+        return;
     }
+
+    let results = if lib_file_objects.is_empty() {
+        parse_and_bind_parallel(to_bind)
+    } else {
+        parallel::parse_and_bind_parallel_with_libs(to_bind, lib_file_objects)
+    };
+    for (bind_result, text_hash) in results.into_iter().zip(text_hashes) {
+        check_cache.remove(&bind_result.file_name);
+        bound_files.insert(
+            bind_result.file_name.clone(),
+            CachedBind {
+                text_hash,
+                bind_result,
+            },
+        );
+    }
+}
+
+/// Whether a file's declarations are visible outside its own imports:
+/// scripts contribute to the global scope, and augmentations extend other
+/// modules or the global scope.
+fn affects_global_scope(bind_result: &BindResult) -> bool {
+    !bind_result.is_external_module
+        || !bind_result.global_augmentations.is_empty()
+        || !bind_result.module_augmentations.is_empty()
+}
+
+/// Indices of the files to re-check: files without cached diagnostics
+/// (re-bound or never checked), plus every file that imports one of them,
+/// transitively. Returns `None` when the whole program must be re-checked.
+fn affected_files(
+    bind_results: &[&BindResult],
+    check_cache: &FxHashMap<String, Vec<Diagnostic>>,
+    host_resolutions: &FxHashMap<(usize, String), usize>,
+) -> Option<FxHashSet<usize>> {
+    let mut pending: Vec<usize> = bind_results
+        .iter()
+        .enumerate()
+        .filter(|(_, result)| !check_cache.contains_key(&result.file_name))
+        .map(|(file_idx, _)| file_idx)
+        .collect();
+    if pending
+        .iter()
+        .any(|&file_idx| affects_global_scope(bind_results[file_idx]))
+    {
+        return None;
+    }
+
+    let mut importers: Vec<Vec<usize>> = vec![Vec::new(); bind_results.len()];
+    for (file_idx, target) in resolved_imports(bind_results, host_resolutions) {
+        importers[target].push(file_idx);
+    }
+
+    let mut affected = FxHashSet::default();
+    while let Some(file_idx) = pending.pop() {
+        if affected.insert(file_idx) {
+            pending.extend(&importers[file_idx]);
+        }
+    }
+    Some(affected)
+}
+
+/// Every resolved import in the program as `(importer, imported)` file
+/// indices, resolved through the host first, then by file name.
+fn resolved_imports(
+    bind_results: &[&BindResult],
+    host_resolutions: &FxHashMap<(usize, String), usize>,
+) -> Vec<(usize, usize)> {
+    let file_names: Vec<String> = bind_results
+        .iter()
+        .map(|result| result.file_name.clone())
+        .collect();
+    let (name_resolutions, _) = build_module_resolution_maps(&file_names);
+    let mut imports = Vec::new();
+    for (file_idx, result) in bind_results.iter().enumerate() {
+        for specifier in &result.file_import_sources {
+            let key = (file_idx, specifier.clone());
+            if let Some(&target) = host_resolutions
+                .get(&key)
+                .or_else(|| name_resolutions.get(&key))
+            {
+                imports.push((file_idx, target));
+            }
+        }
+    }
+    imports
+}
+
+fn file_check_result_json(file: &FileDiagnostics) -> FileCheckResultJson {
+    FileCheckResultJson {
+        file_name: file.file_name.clone(),
+        parse_diagnostics: file
+            .parse_diagnostics
+            .iter()
+            .map(|d| DiagnosticJson::from_parse(&file.file_name, d))
+            .collect(),
+        check_diagnostics: file
+            .check_diagnostics
+            .iter()
+            .map(DiagnosticJson::from_check)
+            .collect(),
+    }
+}
+
+/// Key host resolutions by file index in the merged program, the form the
+/// checker's module resolution map uses.
+fn index_host_resolutions(
+    merged: &MergedProgram,
+    resolutions: &[HostResolution],
+) -> FxHashMap<(usize, String), usize> {
+    let file_index: FxHashMap<&str, usize> = merged
+        .files
+        .iter()
+        .enumerate()
+        .map(|(idx, file)| (file.file_name.as_str(), idx))
+        .collect();
+    resolutions
+        .iter()
+        .filter_map(|(importer, specifier, resolved)| {
+            let importer = *file_index.get(importer.as_str())?;
+            let resolved = *file_index.get(resolved.as_str())?;
+            Some(((importer, specifier.clone()), resolved))
+        })
+        .collect()
 }
@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use crate::api::wasm::compiler_options::CompilerOptions;
+use crate::api::wasm::program_results::EmitOutputJson;
+use crate::context::emit::EmitContext;
+use crate::declaration_emitter::DeclarationEmitter;
+use crate::emitter::{JsxEmit, Printer, PrinterOptions};
+use crate::lowering::LoweringPass;
+use crate::parallel::{BoundFile, CheckedFileContext, MergedProgram, type_cache_view};
+use crate::source_map::base64_encode;
+
+/// Emit one program file: JavaScript (with its source map) and declarations,
+/// as `compiler_options` select. Declarations need the file's check
+/// `context`. Outputs are named as if written next to the input. `None` for
+/// inputs that produce no output (declaration files and anything that is not
+/// TypeScript).
+pub(crate) fn emit_program_file(
+    program: &MergedProgram,
+    file_idx: usize,
+    source_text: &str,
+    context: Option<&CheckedFileContext>,
+    compiler_options: &CompilerOptions,
+) -> Option<EmitOutputJson> {
+    let file = &program.files[file_idx];
+    let printer_options = compiler_options.to_printer_options();
+    let js_name = js_output_name(&file.file_name, printer_options.jsx)?;
+
+    let mut output = EmitOutputJson {
+        file_name: file.file_name.clone(),
+        js_text: None,
+        source_map: None,
+        dts_text: None,
+    };
+    if !compiler_options.emit_declaration_only() {
+        let (js_text, source_map) = emit_javascript(
+            file,
+            source_text,
+            &js_name,
+            printer_options,
+            compiler_options,
+        );
+        output.js_text = Some(js_text);
+        output.source_map = source_map;
+    }
+    if compiler_options.declaration()
+        && let Some(context) = context
+    {
+        output.dts_text = Some(emit_checked_declarations(
+            program,
+            file_idx,
+            context,
+            compiler_options,
+        ));
+    }
+    Some(output)
+}
+
+/// Returns the JavaScript text and, for `sourceMap`, the separate source
+/// map. An `inlineSourceMap` is appended to the text instead.
+fn emit_javascript(
+    file: &BoundFile,
+    source_text: &str,
+    js_name: &str,
+    printer_options: PrinterOptions,
+    compiler_options: &CompilerOptions,
+) -> (String, Option<String>) {
+    let new_line = printer_options.new_line.as_str();
+    let mut ctx = EmitContext::with_options(printer_options.clone());
+    ctx.auto_detect_module = true;
+    let emit_plan = LoweringPass::new(&file.arena, &ctx).run_plan(file.source_file);
+
+    let mut printer = Printer::with_emit_plan_and_options(&file.arena, emit_plan, printer_options);
+    printer.set_target_es5(ctx.target_es5);
+    printer.set_auto_detect_module(true);
+    printer.set_source_text(source_text);
+    let map_enabled = compiler_options.source_map() || compiler_options.inline_source_map();
+    if map_enabled {
+        printer.set_source_map_text(source_text);
+        printer.enable_source_map(js_name, base_name(&file.file_name));
+    }
+    printer.emit(file.source_file);
+    let map_json = if map_enabled {
+        printer.generate_source_map_json()
+    } else {
+        None
+    };
+    let mut js_text = printer.take_output();
+
+    let Some(map_json) = map_json else {
+        return (js_text, None);
+    };
+    if !js_text.is_empty() && !js_text.ends_with(new_line) {
+        js_text.push_str(new_line);
+    }
+    if compiler_options.inline_source_map() {
+        js_text.push_str("//# sourceMappingURL=data:application/json;base64,");
+        js_text.push_str(&base64_encode(map_json.as_bytes()));
+        (js_text, None)
+    } else {
+        js_text.push_str(&format!("//# sourceMappingURL={js_name}.map"));
+        (js_text, Some(map_json))
+    }
+}
+
+/// Declarations for one checked program file. Inferred types come from the
+/// checker, so `export const x = f()` is printed with the type `f` returns.
+pub(crate) fn emit_checked_declarations(
+    program: &MergedProgram,
+    file_idx: usize,
+    context: &CheckedFileContext,
+    compiler_options: &CompilerOptions,
+) -> String {
+    let file = &program.files[file_idx];
+    let printer_options = compiler_options.to_printer_options();
+    let mut emitter = DeclarationEmitter::with_type_info(
+        &file.arena,
+        type_cache_view(&context.type_cache),
+        &program.type_interner,
+        &context.binder,
+    );
+    emitter.set_current_arena(Arc::clone(&file.arena), file.file_name.clone());
+    emitter.set_remove_comments(printer_options.remove_comments);
+    emitter.set_strict_null_checks(printer_options.strict_null_checks);
+    emitter.emit(file.source_file)
+}
+
+/// The emitted JavaScript file name (`a.ts` -> `a.js`, `a.mts` -> `a.mjs`,
+/// and `a.tsx` -> `a.jsx` under `jsx: preserve`), without its directory.
+pub(crate) fn js_output_name(file_name: &str, jsx: JsxEmit) -> Option<String> {
+    if [".d.ts", ".d.mts", ".d.cts"]
+        .iter()
+        .any(|ext| file_name.ends_with(ext))
+    {
+        return None;
+    }
+    [
+        (".mts", ".mjs"),
+        (".cts", ".cjs"),
+        (
+            ".tsx",
+            if jsx == JsxEmit::Preserve {
+                ".jsx"
+            } else {
+                ".js"
+            },
+        ),
+        (".ts", ".js"),
+    ]
+    .iter()
+    .find_map(|(ext, js_ext)| {
+        let stem = file_name.strip_suffix(ext)?;
+        Some(format!("{}{js_ext}", base_name(stem)))
+    })
+}
+
+pub(crate) fn base_name(path: &str) -> &str {
+    path.rsplit_once('/').map_or(path, |(_, name)| name)
+}
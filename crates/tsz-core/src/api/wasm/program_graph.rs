@@ -0,0 +1,61 @@
+use std::path::Path;
+
+use crate::api::wasm::program_results::{ModuleGraphFileJson, ModuleGraphJson};
+use crate::module_graph::{ModuleGraph, ModuleId};
+
+/// Build the `getModuleGraph` result for `file_names` (in program order)
+/// and resolved `imports` given as `(importer, imported)` file indices.
+///
+/// Module ids are assigned in program order, so `ModuleId(i)` is file `i`.
+/// Every list of files, including each cycle, follows program order.
+pub(crate) fn module_graph_json(
+    file_names: &[&str],
+    imports: &[(usize, usize)],
+) -> ModuleGraphJson {
+    let mut graph = ModuleGraph::new();
+    for file_name in file_names {
+        graph.add_module(Path::new(file_name));
+    }
+    for &(from, to) in imports {
+        graph.add_simple_dependency(module_id(from), module_id(to), file_names[to]);
+    }
+
+    let names = |indices: Vec<usize>| -> Vec<String> {
+        indices
+            .into_iter()
+            .map(|idx| file_names[idx].to_string())
+            .collect()
+    };
+    let files = (0..file_names.len())
+        .filter_map(|idx| {
+            let module = graph.get_module(module_id(idx))?;
+            Some(ModuleGraphFileJson {
+                file_name: file_names[idx].to_string(),
+                dependencies: names(sorted_indices(&module.dependencies)),
+                dependents: names(sorted_indices(&module.dependents)),
+            })
+        })
+        .collect();
+
+    let mut cycles: Vec<Vec<usize>> = graph
+        .detect_circular_dependencies()
+        .iter()
+        .map(|cycle| sorted_indices(&cycle.cycle))
+        .collect();
+    cycles.sort_unstable();
+
+    ModuleGraphJson {
+        files,
+        cycles: cycles.into_iter().map(names).collect(),
+    }
+}
+
+fn sorted_indices<'a>(ids: impl IntoIterator<Item = &'a ModuleId>) -> Vec<usize> {
+    let mut indices: Vec<usize> = ids.into_iter().map(|id| id.0 as usize).collect();
+    indices.sort_unstable();
+    indices
+}
+
+const fn module_id(file_idx: usize) -> ModuleId {
+    ModuleId(file_idx as u32)
+}
@@ -1,27 +1,296 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::checker::diagnostics::Diagnostic;
+use crate::lsp::HoverInfo;
+use crate::lsp::hover::JsDocTag;
+use crate::lsp::position::Range;
+use crate::parser::ParseDiagnostic;
+
+#[wasm_bindgen(typescript_custom_section)]
+const PROGRAM_RESULTS_TS: &str = r#"
+/** A 0-based line and UTF-16 column. */
+export interface Position {
+    line: number;
+    character: number;
+}
+
+export interface Range {
+    start: Position;
+    end: Position;
+}
+
+/** One ranged edit passed to `WasmProgram.updateFile`. */
+export interface TextEdit {
+    range: Range;
+    newText: string;
+}
+
+export type DiagnosticCategory = "Error" | "Warning" | "Suggestion" | "Message";
+
+/** A location related to a diagnostic, such as an earlier declaration. */
+export interface DiagnosticRelatedInformation {
+    file: string;
+    /** Span start, as a UTF-8 byte offset into `file`. */
+    start: number;
+    length: number;
+    code: number;
+    category: DiagnosticCategory;
+    messageText: string;
+}
+
+export interface Diagnostic {
+    file: string;
+    /** Span start, as a UTF-8 byte offset into `file`. */
+    start: number;
+    length: number;
+    code: number;
+    category: DiagnosticCategory;
+    messageText: string;
+    relatedInformation: DiagnosticRelatedInformation[];
+}
+
+export interface FileCheckResult {
+    fileName: string;
+    parseDiagnostics: Diagnostic[];
+    checkDiagnostics: Diagnostic[];
+}
+
+/** Result of `WasmProgram.checkAll`. */
+export interface CheckAllResult {
+    files: FileCheckResult[];
+    stats: {
+        totalFiles: number;
+        totalDiagnostics: number;
+    };
+}
+
+/** Result of `Parser.checkSourceFile`. */
+export interface CheckSourceFileResult {
+    typeCount: number;
+    diagnostics: Diagnostic[];
+}
+
+/** Result of `Parser.emitWithSourceMap`; `map` is source map JSON. */
+export interface EmitWithSourceMapResult {
+    code: string;
+    map: string;
+}
+
+/** Outputs not requested by the compiler options are `undefined`. */
+export interface EmitOutput {
+    fileName: string;
+    jsText?: string;
+    sourceMap?: string;
+    dtsText?: string;
+}
+
+/** Direct imports of one file in `WasmProgram.getModuleGraph`. */
+export interface ModuleGraphFile {
+    fileName: string;
+    /** Files this file imports. */
+    dependencies: string[];
+    /** Files that import this file. */
+    dependents: string[];
+}
+
+/** Result of `WasmProgram.getModuleGraph`. */
+export interface ModuleGraph {
+    files: ModuleGraphFile[];
+    /** Groups of files that import each other, directly or transitively. */
+    cycles: string[][];
+}
+
+export interface Location {
+    uri: string;
+    range: Range;
+}
+
+export interface HoverInfo {
+    contents: string[];
+    range?: Range;
+    display_string: string;
+    kind: string;
+    kind_modifiers: string;
+    documentation: string;
+    tags: { name: string; text: string }[];
+}
+
+/** Result of `Parser.getQuickInfoAtPosition`, shaped like tsserver quick info. */
+export interface QuickInfo {
+    kind: string;
+    kindModifiers: string;
+    displayString: string;
+    documentation: string;
+    tags: { name: string; text: string }[];
+    range?: Range;
+}
+
+export interface CompletionItem {
+    label: string;
+    kind: string;
+    detail?: string;
+    documentation?: string;
+    sort_text?: string;
+    insert_text?: string;
+    kind_modifiers?: string;
+    [field: string]: unknown;
+}
+"#;
+
+/// Result of checking every file in a multi-file program.
+#[derive(Clone, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CheckAllResultJson {
+    pub(crate) files: Vec<FileCheckResultJson>,
+    pub(crate) stats: CheckStatsJson,
+}
+
+#[derive(Clone, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CheckStatsJson {
+    pub(crate) total_files: usize,
+    pub(crate) total_diagnostics: usize,
+}
+
 /// Result of checking a single file in a multi-file program.
-#[derive(serde::Serialize)]
+#[derive(Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct FileCheckResultJson {
     pub(crate) file_name: String,
-    pub(crate) parse_diagnostics: Vec<ParseDiagnosticJson>,
-    pub(crate) check_diagnostics: Vec<CheckDiagnosticJson>,
+    pub(crate) parse_diagnostics: Vec<DiagnosticJson>,
+    pub(crate) check_diagnostics: Vec<DiagnosticJson>,
 }
 
+/// Result of checking a single-file `Parser`.
 #[derive(serde::Serialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct ParseDiagnosticJson {
-    pub(crate) message: String,
+pub(crate) struct CheckSourceFileResultJson {
+    pub(crate) type_count: usize,
+    pub(crate) diagnostics: Vec<DiagnosticJson>,
+}
+
+/// JavaScript and source map JSON emitted from a single-file `Parser`.
+#[derive(Default, serde::Serialize)]
+pub(crate) struct EmitWithSourceMapResultJson {
+    pub(crate) code: String,
+    pub(crate) map: String,
+}
+
+/// A parse or check diagnostic, with its span in `file`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DiagnosticJson {
+    pub(crate) file: String,
     pub(crate) start: u32,
     pub(crate) length: u32,
     pub(crate) code: u32,
+    pub(crate) category: String,
+    pub(crate) message_text: String,
+    pub(crate) related_information: Vec<RelatedInformationJson>,
 }
 
-#[derive(serde::Serialize)]
+#[derive(Clone, Debug, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct CheckDiagnosticJson {
-    pub(crate) message_text: String,
-    pub(crate) code: u32,
+pub(crate) struct RelatedInformationJson {
+    pub(crate) file: String,
     pub(crate) start: u32,
     pub(crate) length: u32,
+    pub(crate) code: u32,
     pub(crate) category: String,
+    pub(crate) message_text: String,
+}
+
+impl DiagnosticJson {
+    /// Parse diagnostics carry no category; they are all errors.
+    pub(crate) fn from_parse(file_name: &str, diagnostic: &ParseDiagnostic) -> Self {
+        Self {
+            file: file_name.to_string(),
+            start: diagnostic.start,
+            length: diagnostic.length,
+            code: diagnostic.code,
+            category: "Error".to_string(),
+            message_text: diagnostic.message.clone(),
+            related_information: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_check(diagnostic: &Diagnostic) -> Self {
+        Self {
+            file: diagnostic.file.clone(),
+            start: diagnostic.start,
+            length: diagnostic.length,
+            code: diagnostic.code,
+            category: format!("{:?}", diagnostic.category),
+            message_text: diagnostic.message_text.clone(),
+            related_information: diagnostic
+                .related_information
+                .iter()
+                .map(|related| RelatedInformationJson {
+                    file: related.file.clone(),
+                    start: related.start,
+                    length: related.length,
+                    code: related.code,
+                    category: format!("{:?}", related.category),
+                    message_text: related.message_text.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Emit output for one input file of a multi-file program.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EmitOutputJson {
+    pub(crate) file_name: String,
+    pub(crate) js_text: Option<String>,
+    pub(crate) source_map: Option<String>,
+    pub(crate) dts_text: Option<String>,
+}
+
+/// Import structure of a multi-file program.
+#[derive(Default, serde::Serialize)]
+pub(crate) struct ModuleGraphJson {
+    pub(crate) files: Vec<ModuleGraphFileJson>,
+    pub(crate) cycles: Vec<Vec<String>>,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ModuleGraphFileJson {
+    pub(crate) file_name: String,
+    pub(crate) dependencies: Vec<String>,
+    pub(crate) dependents: Vec<String>,
+}
+
+/// Quick info for the symbol under the cursor, in tsserver's shape.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct QuickInfoJson {
+    pub(crate) kind: String,
+    pub(crate) kind_modifiers: String,
+    pub(crate) display_string: String,
+    pub(crate) documentation: String,
+    pub(crate) tags: Vec<JsDocTag>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) range: Option<Range>,
+}
+
+impl QuickInfoJson {
+    /// Symbols without a known kind are reported as `unknown`, as tsserver
+    /// does.
+    pub(crate) fn from_hover(info: HoverInfo) -> Self {
+        Self {
+            kind: if info.kind.is_empty() {
+                "unknown".to_string()
+            } else {
+                info.kind
+            },
+            kind_modifiers: info.kind_modifiers,
+            display_string: info.display_string,
+            documentation: info.documentation,
+            tags: info.tags,
+            range: info.range,
+        }
+    }
 }
@@ -0,0 +1,219 @@
+use rustc_hash::FxHashMap;
+
+use crate::api::wasm::compiler_options::CompilerOptions;
+use crate::binder::{SymbolId, symbol_flags};
+use crate::checker::module_resolution::build_module_resolution_maps;
+use crate::lsp::position::{LineMap, Location, Position, Range};
+use crate::lsp::provider_macro::FullProviderOptions;
+use crate::lsp::resolver::{ScopeCache, ScopeWalker};
+use crate::lsp::utils::find_node_at_or_before_offset;
+use crate::lsp::{CompletionItem, Completions, GoToDefinition, HoverInfo, HoverProvider};
+use crate::parallel::{BoundFile, CheckedFileContext, MergedProgram};
+
+/// Import aliases re-exported through more modules than this are not
+/// followed further.
+const MAX_ALIAS_HOPS: usize = 8;
+
+/// Hover for a program file, with the types its full check computed, so
+/// imported names show their declared types from the exporting file.
+pub(crate) fn hover_in_program(
+    program: &MergedProgram,
+    file_idx: usize,
+    context: &mut CheckedFileContext,
+    compiler_options: &CompilerOptions,
+    position: Position,
+) -> Option<HoverInfo> {
+    let file = &program.files[file_idx];
+    let source_text = file_text(file);
+    let line_map = LineMap::build(source_text);
+    let checker_options = compiler_options.to_checker_options();
+
+    let provider = HoverProvider::with_options_and_lib_contexts(
+        &file.arena,
+        &context.binder,
+        &line_map,
+        &program.type_interner,
+        source_text,
+        file.file_name.clone(),
+        FullProviderOptions {
+            strict: checker_options.strict,
+            sound_mode: checker_options.sound_mode,
+            checker_options: Some(checker_options),
+            lib_contexts: &context.lib_contexts,
+        },
+    );
+    let mut type_cache = Some(std::mem::take(&mut context.type_cache));
+    let hover = provider.get_hover_with_scope_cache(
+        file.source_file,
+        position,
+        &mut type_cache,
+        &mut ScopeCache::default(),
+        None,
+    );
+    context.type_cache = type_cache.unwrap_or_default();
+    hover
+}
+
+/// Completions for a program file; members of imported values come from
+/// their types in the exporting file.
+pub(crate) fn completions_in_program(
+    program: &MergedProgram,
+    file_idx: usize,
+    context: &mut CheckedFileContext,
+    compiler_options: &CompilerOptions,
+    position: Position,
+) -> Option<Vec<CompletionItem>> {
+    let file = &program.files[file_idx];
+    let source_text = file_text(file);
+    let line_map = LineMap::build(source_text);
+    let checker_options = compiler_options.to_checker_options();
+
+    let provider = Completions::with_options_and_lib_contexts(
+        &file.arena,
+        &context.binder,
+        &line_map,
+        &program.type_interner,
+        source_text,
+        file.file_name.clone(),
+        FullProviderOptions {
+            strict: checker_options.strict,
+            sound_mode: checker_options.sound_mode,
+            checker_options: Some(checker_options),
+            lib_contexts: &context.lib_contexts,
+        },
+    );
+    let mut type_cache = Some(std::mem::take(&mut context.type_cache));
+    let completions = provider.get_completions_with_caches(
+        file.source_file,
+        position,
+        &mut type_cache,
+        &mut ScopeCache::default(),
+        None,
+    );
+    context.type_cache = type_cache.unwrap_or_default();
+    completions
+}
+
+/// Definition for a program file. Imported names resolve to their
+/// declarations in the exporting file (a namespace import to the start of
+/// that file); everything else resolves within the file.
+pub(crate) fn definition_in_program(
+    program: &MergedProgram,
+    file_idx: usize,
+    context: &CheckedFileContext,
+    host_resolutions: &FxHashMap<(usize, String), usize>,
+    position: Position,
+) -> Option<Vec<Location>> {
+    let file = &program.files[file_idx];
+    let source_text = file_text(file);
+    let line_map = LineMap::build(source_text);
+
+    let offset = line_map.position_to_offset(position, source_text)?;
+    let node = find_node_at_or_before_offset(&file.arena, offset, source_text);
+    let symbol =
+        ScopeWalker::new(&file.arena, &context.binder).resolve_node(file.source_file, node);
+    if let Some(locations) = symbol.and_then(|sym_id| {
+        imported_definition(program, file_idx, context, host_resolutions, sym_id)
+    }) {
+        return Some(locations);
+    }
+
+    GoToDefinition::new(
+        &file.arena,
+        &context.binder,
+        &line_map,
+        file.file_name.clone(),
+        source_text,
+    )
+    .get_definition(file.source_file, position)
+}
+
+/// Follow an import alias to the declarations it names in another file.
+fn imported_definition(
+    program: &MergedProgram,
+    file_idx: usize,
+    context: &CheckedFileContext,
+    host_resolutions: &FxHashMap<(usize, String), usize>,
+    sym_id: SymbolId,
+) -> Option<Vec<Location>> {
+    if program.symbols.get(sym_id)?.flags & symbol_flags::ALIAS == 0 {
+        return None;
+    }
+    let file_names: Vec<String> = program
+        .files
+        .iter()
+        .map(|file| file.file_name.clone())
+        .collect();
+    let (name_resolutions, _) = build_module_resolution_maps(&file_names);
+
+    let (mut importer, mut sym_id) = (file_idx, sym_id);
+    for _ in 0..MAX_ALIAS_HOPS {
+        let symbol = program.symbols.get(sym_id)?;
+        if symbol.flags & symbol_flags::ALIAS == 0 {
+            let decl_file_idx = usize::try_from(symbol.decl_file_idx)
+                .ok()
+                .filter(|&idx| idx < program.files.len())
+                .unwrap_or(importer);
+            return symbol_locations(program, decl_file_idx, context, sym_id);
+        }
+
+        let module = symbol.import_module.as_ref()?;
+        let key = (importer, module.clone());
+        let target = *host_resolutions
+            .get(&key)
+            .or_else(|| name_resolutions.get(&key))?;
+        let target_file = &program.files[target];
+        let name = symbol
+            .import_name
+            .as_deref()
+            .unwrap_or(&symbol.escaped_name);
+        if name == "*" {
+            return Some(vec![Location::new(
+                target_file.file_name.clone(),
+                Range::new(Position::new(0, 0), Position::new(0, 0)),
+            )]);
+        }
+
+        sym_id = program
+            .module_exports
+            .get(&target_file.file_name)
+            .and_then(|exports| exports.get(name))
+            .or_else(|| program.file_locals.get(target)?.get(name))?;
+        importer = target;
+    }
+    None
+}
+
+/// Declaration locations of `sym_id` in the program file that declares it.
+fn symbol_locations(
+    program: &MergedProgram,
+    file_idx: usize,
+    context: &CheckedFileContext,
+    sym_id: SymbolId,
+) -> Option<Vec<Location>> {
+    let file = &program.files[file_idx];
+    let source_text = file_text(file);
+    let line_map = LineMap::build(source_text);
+    // The binder only supplies symbols here, and symbol ids are shared
+    // across the merged program.
+    let provider = GoToDefinition::new(
+        &file.arena,
+        &context.binder,
+        &line_map,
+        file.file_name.clone(),
+        source_text,
+    );
+    let locations: Vec<Location> = provider
+        .definition_infos_from_symbol(sym_id)?
+        .into_iter()
+        .map(|info| info.location)
+        .collect();
+    (!locations.is_empty()).then_some(locations)
+}
+
+fn file_text(file: &BoundFile) -> &str {
+    file.arena
+        .source_files
+        .first()
+        .map_or("", |source_file| &source_file.text)
+}
@@ -1,7 +1,11 @@
-use wasm_bindgen::prelude::wasm_bindgen;
+use std::sync::Arc;
+
+use wasm_bindgen::prelude::{JsValue, wasm_bindgen};
 
 use crate::common::ModuleKind;
 use crate::context::transform::TransformContext;
+use crate::parser::NodeIndex;
+use crate::parser::node::{NodeAccess, NodeArena};
 
 /// Opaque wrapper for transform directives across the wasm boundary.
 #[wasm_bindgen]
@@ -19,3 +23,126 @@ impl WasmTransformContext {
         self.inner.len()
     }
 }
+
+#[wasm_bindgen]
+extern "C" {
+    /// A JavaScript custom transformer. It is called with each node's index,
+    /// `SyntaxKind` name and whether a built-in transform applies to it, and
+    /// returns replacement JavaScript text for the node, or `undefined` to
+    /// keep it.
+    #[wasm_bindgen(
+        typescript_type = "(nodeIdx: number, kindName: string, hasBuiltInTransform: boolean) => string | undefined"
+    )]
+    pub type NodeTransformer;
+
+    #[wasm_bindgen(method, catch, js_name = call)]
+    fn js_call(
+        this: &NodeTransformer,
+        this_arg: &JsValue,
+        node_idx: u32,
+        kind_name: &str,
+        has_built_in_transform: bool,
+    ) -> Result<JsValue, JsValue>;
+}
+
+/// JS transformers run around the built-in lowering, like
+/// `ts.CustomTransformers`: `before` ones see the source AST before
+/// `LoweringPass` runs, which then leaves the nodes they replaced alone;
+/// `after` ones see it together with the directives lowering produced. A
+/// replaced node's subtree is not visited further.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct WasmCustomTransformers {
+    before: Vec<NodeTransformer>,
+    after: Vec<NodeTransformer>,
+}
+
+#[wasm_bindgen]
+impl WasmCustomTransformers {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a transformer that runs before the built-in transforms.
+    #[wasm_bindgen(js_name = addBefore)]
+    pub fn add_before(&mut self, transformer: NodeTransformer) {
+        self.before.push(transformer);
+    }
+
+    /// Add a transformer that runs after the built-in transforms.
+    #[wasm_bindgen(js_name = addAfter)]
+    pub fn add_after(&mut self, transformer: NodeTransformer) {
+        self.after.push(transformer);
+    }
+}
+
+impl WasmCustomTransformers {
+    pub(crate) fn apply_before(
+        &self,
+        arena: &NodeArena,
+        root: NodeIndex,
+        transforms: &mut TransformContext,
+    ) -> Result<(), JsValue> {
+        apply_node_transformers(arena, root, transforms, |idx, kind_name, has_transform| {
+            call_transformers(&self.before, idx, kind_name, has_transform)
+        })
+    }
+
+    pub(crate) fn apply_after(
+        &self,
+        arena: &NodeArena,
+        root: NodeIndex,
+        transforms: &mut TransformContext,
+    ) -> Result<(), JsValue> {
+        apply_node_transformers(arena, root, transforms, |idx, kind_name, has_transform| {
+            call_transformers(&self.after, idx, kind_name, has_transform)
+        })
+    }
+}
+
+fn call_transformers(
+    transformers: &[NodeTransformer],
+    idx: NodeIndex,
+    kind_name: &str,
+    has_transform: bool,
+) -> Result<Option<String>, JsValue> {
+    for transformer in transformers {
+        let result = transformer.js_call(&JsValue::UNDEFINED, idx.0, kind_name, has_transform)?;
+        if let Some(text) = result.as_string() {
+            return Ok(Some(text));
+        }
+    }
+    Ok(None)
+}
+
+/// Walk the AST below `root` in source order, recording the text `visit`
+/// returns as the node's replacement. Nodes already replaced, and the
+/// subtrees of replaced nodes, are skipped.
+pub(crate) fn apply_node_transformers<F>(
+    arena: &NodeArena,
+    root: NodeIndex,
+    transforms: &mut TransformContext,
+    mut visit: F,
+) -> Result<(), JsValue>
+where
+    F: FnMut(NodeIndex, &str, bool) -> Result<Option<String>, JsValue>,
+{
+    let mut stack: Vec<NodeIndex> = arena.get_children(root);
+    stack.reverse();
+    while let Some(idx) = stack.pop() {
+        if transforms.replacement(idx).is_some() {
+            continue;
+        }
+        let Some(kind) = arena.kind(idx) else {
+            continue;
+        };
+        let kind_name = crate::parser::syntax_kind_ext::syntax_kind_name(kind);
+        if let Some(text) = visit(idx, &kind_name, transforms.has_transform(idx))? {
+            transforms.replace_with_text(idx, Arc::from(text));
+            continue;
+        }
+        stack.extend(arena.get_children(idx).into_iter().rev());
+    }
+    Ok(())
+}
@@ -0,0 +1,473 @@
+//! Pluggable file-system access for the compiler pipeline.
+//!
+//! `CompilerHost` is the seam embedders use to compile against a virtual file
+//! system, overlay unsaved buffers on top of disk contents, or capture emitted
+//! outputs in memory. The module resolver, config loading, the CLI driver and
+//! emit do their I/O through the free functions in this module, which consult
+//! the host installed on the current thread by [`CompilerHostScope`] and fall
+//! back to `std::fs` when none is installed, so plain CLI runs keep issuing
+//! the same syscalls as before.
+//!
+//! The host is per-thread (like the `--traceResolution` switch in
+//! `resolution::helpers`) rather than threaded through every probe helper.
+//! Code that fans work out to rayon re-enters the scope on each worker with
+//! [`current_compiler_host`].
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+/// File-system operations the compiler needs from its environment.
+pub trait CompilerHost: Send + Sync {
+    /// Read a file as UTF-8 text.
+    fn read_file(&self, path: &Path) -> io::Result<String>;
+
+    /// Whether `path` names an existing file.
+    fn file_exists(&self, path: &Path) -> bool;
+
+    /// Whether `path` names an existing directory.
+    fn directory_exists(&self, path: &Path) -> bool;
+
+    /// List the immediate entries (files and directories) of a directory.
+    fn read_directory(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
+
+    /// The directory relative paths are resolved against.
+    fn current_directory(&self) -> io::Result<PathBuf>;
+
+    /// Write `contents` to `path`, creating missing parent directories.
+    fn write_file(&self, path: &Path, contents: &str) -> io::Result<()>;
+
+    /// `path` with symlinks resolved, for hosts that know about them. `None`
+    /// leaves [`canonicalize`] to its default handling.
+    fn realpath(&self, _path: &Path) -> Option<PathBuf> {
+        None
+    }
+}
+
+/// `CompilerHost` backed by the process file system.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RealCompilerHost;
+
+impl CompilerHost for RealCompilerHost {
+    fn read_file(&self, path: &Path) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+
+    fn file_exists(&self, path: &Path) -> bool {
+        path.is_file()
+    }
+
+    fn directory_exists(&self, path: &Path) -> bool {
+        path.is_dir()
+    }
+
+    fn read_directory(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        std::fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect()
+    }
+
+    fn current_directory(&self) -> io::Result<PathBuf> {
+        std::env::current_dir()
+    }
+
+    fn write_file(&self, path: &Path, contents: &str) -> io::Result<()> {
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, contents)
+    }
+}
+
+/// In-memory `CompilerHost`.
+///
+/// Without a base host it is a self-contained virtual file system whose
+/// directories are implied by the files it holds. Built with
+/// [`MemoryCompilerHost::overlay`], its files shadow the base host's and
+/// everything else is read through to the base. Writes always land in memory,
+/// so emitted outputs can be collected with [`MemoryCompilerHost::file`]
+/// without touching the base.
+pub struct MemoryCompilerHost {
+    current_directory: PathBuf,
+    files: RwLock<BTreeMap<PathBuf, String>>,
+    base: Option<Arc<dyn CompilerHost>>,
+}
+
+impl MemoryCompilerHost {
+    pub fn new(current_directory: impl Into<PathBuf>) -> Self {
+        Self {
+            current_directory: normalize_host_path(&current_directory.into()),
+            files: RwLock::new(BTreeMap::new()),
+            base: None,
+        }
+    }
+
+    /// Layer an in-memory file set over `base`.
+    pub fn overlay(current_directory: impl Into<PathBuf>, base: Arc<dyn CompilerHost>) -> Self {
+        Self {
+            base: Some(base),
+            ..Self::new(current_directory)
+        }
+    }
+
+    /// Add or replace a file. Relative paths resolve against the host's
+    /// current directory.
+    pub fn add_file(&self, path: impl AsRef<Path>, contents: impl Into<String>) {
+        let key = self.key(path.as_ref());
+        self.files
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(key, contents.into());
+    }
+
+    /// The in-memory contents of `path`, ignoring the base host.
+    pub fn file(&self, path: impl AsRef<Path>) -> Option<String> {
+        let key = self.key(path.as_ref());
+        self.read_files().get(&key).cloned()
+    }
+
+    /// Paths of every in-memory file, in sorted order.
+    pub fn file_paths(&self) -> Vec<PathBuf> {
+        self.read_files().keys().cloned().collect()
+    }
+
+    fn key(&self, path: &Path) -> PathBuf {
+        if path.is_absolute() {
+            normalize_host_path(path)
+        } else {
+            normalize_host_path(&self.current_directory.join(path))
+        }
+    }
+
+    fn read_files(&self) -> std::sync::RwLockReadGuard<'_, BTreeMap<PathBuf, String>> {
+        self.files
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn has_memory_directory(&self, dir: &Path) -> bool {
+        self.read_files()
+            .range(dir.to_path_buf()..)
+            .take_while(|(path, _)| path.starts_with(dir))
+            .any(|(path, _)| path != dir)
+    }
+}
+
+impl CompilerHost for MemoryCompilerHost {
+    fn read_file(&self, path: &Path) -> io::Result<String> {
+        if let Some(contents) = self.read_files().get(&self.key(path)) {
+            return Ok(contents.clone());
+        }
+        match &self.base {
+            Some(base) => base.read_file(path),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("file not found: {}", path.display()),
+            )),
+        }
+    }
+
+    fn file_exists(&self, path: &Path) -> bool {
+        self.read_files().contains_key(&self.key(path))
+            || self
+                .base
+                .as_ref()
+                .is_some_and(|base| base.file_exists(path))
+    }
+
+    fn directory_exists(&self, path: &Path) -> bool {
+        self.has_memory_directory(&self.key(path))
+            || self
+                .base
+                .as_ref()
+                .is_some_and(|base| base.directory_exists(path))
+    }
+
+    fn read_directory(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let dir = self.key(path);
+        let mut found = false;
+        let mut entries = Vec::new();
+        if let Some(base) = &self.base
+            && base.directory_exists(path)
+        {
+            entries = base.read_directory(path)?;
+            found = true;
+        }
+        for file in self.read_files().keys() {
+            let Ok(rest) = file.strip_prefix(&dir) else {
+                continue;
+            };
+            let Some(first) = rest.components().next() else {
+                continue;
+            };
+            found = true;
+            let entry = dir.join(first);
+            if !entries.contains(&entry) {
+                entries.push(entry);
+            }
+        }
+        if !found {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("directory not found: {}", path.display()),
+            ));
+        }
+        entries.sort();
+        Ok(entries)
+    }
+
+    fn current_directory(&self) -> io::Result<PathBuf> {
+        Ok(self.current_directory.clone())
+    }
+
+    fn write_file(&self, path: &Path, contents: &str) -> io::Result<()> {
+        self.add_file(path, contents);
+        Ok(())
+    }
+
+    fn realpath(&self, path: &Path) -> Option<PathBuf> {
+        if self.read_files().contains_key(&self.key(path)) {
+            return None;
+        }
+        self.base.as_ref()?.realpath(path)
+    }
+}
+
+/// Lexically normalize `path` (drop `.`, fold `..`) so in-memory keys match
+/// however the driver spells a path.
+fn normalize_host_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+thread_local! {
+    static CURRENT_HOST: RefCell<Option<Arc<dyn CompilerHost>>> = const { RefCell::new(None) };
+}
+
+/// Installs a `CompilerHost` on the current thread until dropped.
+///
+/// Entering with `None` restores direct `std::fs` access for the scope.
+pub struct CompilerHostScope {
+    previous: Option<Arc<dyn CompilerHost>>,
+}
+
+impl CompilerHostScope {
+    pub fn enter(host: Option<Arc<dyn CompilerHost>>) -> Self {
+        let previous = CURRENT_HOST.with(|current| current.replace(host));
+        Self { previous }
+    }
+}
+
+impl Drop for CompilerHostScope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT_HOST.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// The host installed on the current thread, if any. Capture this before
+/// handing work to rayon and re-enter it with [`CompilerHostScope::enter`]
+/// on the worker.
+pub fn current_compiler_host() -> Option<Arc<dyn CompilerHost>> {
+    CURRENT_HOST.with(|current| current.borrow().clone())
+}
+
+/// Whether a custom host is installed on the current thread. Callers that
+/// memoize probes in process-wide caches skip them when this is true.
+pub fn has_compiler_host() -> bool {
+    CURRENT_HOST.with(|current| current.borrow().is_some())
+}
+
+#[inline]
+fn with_host<T>(f: impl FnOnce(&dyn CompilerHost) -> T) -> Option<T> {
+    let host = current_compiler_host()?;
+    Some(f(host.as_ref()))
+}
+
+/// Read a file as text. A leading UTF-8 byte order mark is dropped, as
+/// tsc's `sys.readFile` does, so every reader sees the same positions
+/// whichever host supplied the file.
+pub fn read_file(path: &Path) -> io::Result<String> {
+    with_host(|host| host.read_file(path))
+        .unwrap_or_else(|| std::fs::read_to_string(path))
+        .map(strip_utf8_bom)
+}
+
+/// `text` without a leading UTF-8 byte order mark.
+pub fn strip_utf8_bom(mut text: String) -> String {
+    if text.starts_with('\u{FEFF}') {
+        text.drain(..'\u{FEFF}'.len_utf8());
+    }
+    text
+}
+
+/// Read a file's raw bytes. Hosts only deal in text, so a custom host's
+/// contents come back UTF-8 encoded.
+pub fn read_file_bytes(path: &Path) -> io::Result<Vec<u8>> {
+    with_host(|host| host.read_file(path).map(String::into_bytes))
+        .unwrap_or_else(|| std::fs::read(path))
+}
+
+pub fn file_exists(path: &Path) -> bool {
+    with_host(|host| host.file_exists(path)).unwrap_or_else(|| path.is_file())
+}
+
+pub fn directory_exists(path: &Path) -> bool {
+    with_host(|host| host.directory_exists(path)).unwrap_or_else(|| path.is_dir())
+}
+
+/// Whether `path` exists as either a file or a directory.
+pub fn path_exists(path: &Path) -> bool {
+    with_host(|host| host.file_exists(path) || host.directory_exists(path))
+        .unwrap_or_else(|| path.exists())
+}
+
+pub fn read_directory(path: &Path) -> io::Result<Vec<PathBuf>> {
+    with_host(|host| host.read_directory(path))
+        .unwrap_or_else(|| RealCompilerHost.read_directory(path))
+}
+
+pub fn current_directory() -> io::Result<PathBuf> {
+    with_host(|host| host.current_directory()).unwrap_or_else(std::env::current_dir)
+}
+
+pub fn write_file(path: &Path, contents: &str) -> io::Result<()> {
+    with_host(|host| host.write_file(path, contents))
+        .unwrap_or_else(|| RealCompilerHost.write_file(path, contents))
+}
+
+/// Whether `path` itself is a symbolic link. Custom hosts only expose
+/// `realpath`, so there it is a link when it resolves somewhere else.
+pub fn is_symlink(path: &Path) -> bool {
+    with_host(|host| {
+        host.realpath(path)
+            .is_some_and(|real| normalize_host_path(&real) != normalize_host_path(path))
+    })
+    .unwrap_or_else(|| path.is_symlink())
+}
+
+/// `std::fs::canonicalize` for paths the host knows about. Custom hosts
+/// resolve them with [`CompilerHost::realpath`] when they can; otherwise
+/// their paths canonicalize to a lexically normalized absolute form. Paths
+/// only the real file system has still go through `realpath`.
+pub fn canonicalize(path: &Path) -> io::Result<PathBuf> {
+    match current_compiler_host() {
+        Some(host) if host.file_exists(path) || host.directory_exists(path) => {
+            if let Some(real) = host.realpath(path) {
+                return Ok(normalize_host_path(&real));
+            }
+            std::fs::canonicalize(path).or_else(|_| {
+                let absolute = if path.is_absolute() {
+                    path.to_path_buf()
+                } else {
+                    host.current_directory()?.join(path)
+                };
+                Ok(normalize_host_path(&absolute))
+            })
+        }
+        _ => std::fs::canonicalize(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_host_derives_directories_from_files() {
+        let host = MemoryCompilerHost::new("/proj");
+        host.add_file("src/a.ts", "export {};");
+        host.add_file("/proj/src/nested/b.ts", "export {};");
+
+        assert!(host.file_exists(Path::new("/proj/src/a.ts")));
+        assert!(host.file_exists(Path::new("/proj/src/./nested/../a.ts")));
+        assert!(host.directory_exists(Path::new("/proj/src")));
+        assert!(host.directory_exists(Path::new("/proj")));
+        assert!(!host.directory_exists(Path::new("/proj/src/a.ts")));
+        assert!(!host.file_exists(Path::new("/proj/src")));
+        assert_eq!(
+            host.read_directory(Path::new("/proj/src")).unwrap(),
+            vec![
+                PathBuf::from("/proj/src/a.ts"),
+                PathBuf::from("/proj/src/nested")
+            ]
+        );
+        assert!(host.read_directory(Path::new("/other")).is_err());
+    }
+
+    #[test]
+    fn overlay_shadows_base_and_keeps_writes_in_memory() {
+        let base = Arc::new(MemoryCompilerHost::new("/proj"));
+        base.add_file("/proj/a.ts", "base");
+        base.add_file("/proj/b.ts", "base b");
+        let overlay = MemoryCompilerHost::overlay("/proj", base.clone());
+        overlay.add_file("/proj/a.ts", "overlay");
+
+        assert_eq!(
+            overlay.read_file(Path::new("/proj/a.ts")).unwrap(),
+            "overlay"
+        );
+        assert_eq!(
+            overlay.read_file(Path::new("/proj/b.ts")).unwrap(),
+            "base b"
+        );
+        assert_eq!(overlay.current_directory().unwrap(), PathBuf::from("/proj"));
+
+        overlay
+            .write_file(Path::new("/proj/out/a.js"), "emitted")
+            .unwrap();
+        assert_eq!(overlay.file("/proj/out/a.js").as_deref(), Some("emitted"));
+        assert!(!base.file_exists(Path::new("/proj/out/a.js")));
+    }
+
+    #[test]
+    fn read_file_drops_a_utf8_bom_from_any_host() {
+        let host = Arc::new(MemoryCompilerHost::new("/virtual"));
+        host.add_file("/virtual/bom.ts", "\u{FEFF}let x = 1;");
+        let _scope = CompilerHostScope::enter(Some(host));
+        assert_eq!(
+            read_file(Path::new("/virtual/bom.ts")).unwrap(),
+            "let x = 1;"
+        );
+    }
+
+    #[test]
+    fn scope_routes_free_functions_through_installed_host() {
+        let host = Arc::new(MemoryCompilerHost::new("/virtual"));
+        host.add_file("/virtual/a.ts", "const a = 1;");
+        assert!(!file_exists(Path::new("/virtual/a.ts")));
+        {
+            let _scope = CompilerHostScope::enter(Some(host.clone()));
+            assert!(file_exists(Path::new("/virtual/a.ts")));
+            assert!(directory_exists(Path::new("/virtual")));
+            assert_eq!(
+                read_file(Path::new("/virtual/a.ts")).unwrap(),
+                "const a = 1;"
+            );
+            assert_eq!(current_directory().unwrap(), PathBuf::from("/virtual"));
+            write_file(Path::new("/virtual/a.js"), "var a = 1;").unwrap();
+            {
+                let _real = CompilerHostScope::enter(None);
+                assert!(!has_compiler_host());
+            }
+            assert!(has_compiler_host());
+        }
+        assert!(!has_compiler_host());
+        assert_eq!(host.file("/virtual/a.js").as_deref(), Some("var a = 1;"));
+    }
+}
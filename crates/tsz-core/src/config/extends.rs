@@ -10,10 +10,10 @@
 //! The functions here are behavior-preserving moves from `mod.rs`. They
 //! intentionally remain `pub(super)` rather than `pub` so the public
 //! `config` API is still gated through `mod.rs`.
+use crate::compiler_host;
 use anyhow::{Result, anyhow};
 use std::path::{Path, PathBuf};
 
-#[cfg(not(target_arch = "wasm32"))]
 use crate::module_resolver_helpers::{
     PackageExports, PackageJson, find_best_export_pattern, match_export_pattern,
     parse_package_specifier, substitute_wildcard_in_exports,
@@ -59,14 +59,14 @@ pub(super) fn resolve_extends_path(current_path: &Path, extends: &str) -> Result
         if candidate.extension().is_none() {
             candidate.set_extension("json");
         }
-        if candidate.exists() {
+        if compiler_host::path_exists(&candidate) {
             return Ok(candidate);
         }
         // Also try the package's tsconfig.json if extends points to a directory
         let dir_candidate = search_dir.join("node_modules").join(extends);
-        if dir_candidate.is_dir() {
+        if compiler_host::directory_exists(&dir_candidate) {
             let tsconfig_in_dir = dir_candidate.join("tsconfig.json");
-            if tsconfig_in_dir.exists() {
+            if compiler_host::path_exists(&tsconfig_in_dir) {
                 return Ok(tsconfig_in_dir);
             }
         }
@@ -83,12 +83,6 @@ pub(super) fn resolve_extends_path(current_path: &Path, extends: &str) -> Result
     Ok(base_dir.join(candidate))
 }
 
-#[cfg(target_arch = "wasm32")]
-fn resolve_package_extends_path(_current_path: &Path, _extends: &str) -> Option<PathBuf> {
-    None
-}
-
-#[cfg(not(target_arch = "wasm32"))]
 fn resolve_package_extends_path(current_path: &Path, extends: &str) -> Option<PathBuf> {
     let base_dir = current_path.parent()?;
     let (package_name, subpath) = parse_package_specifier(extends);
@@ -101,7 +95,7 @@ fn resolve_package_extends_path(current_path: &Path, extends: &str) -> Option<Pa
     loop {
         let package_dir = search_dir.join("node_modules").join(&package_name);
         let package_json_path = package_dir.join("package.json");
-        if package_json_path.is_file()
+        if compiler_host::file_exists(&package_json_path)
             && let Some(package_json) = read_package_json_for_extends(&package_json_path)
             && let Some(exports) = &package_json.exports
             && let Some(resolved) =
@@ -118,13 +112,11 @@ fn resolve_package_extends_path(current_path: &Path, extends: &str) -> Option<Pa
     None
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn read_package_json_for_extends(path: &Path) -> Option<PackageJson> {
-    let source = std::fs::read_to_string(path).ok()?;
+    let source = compiler_host::read_file(path).ok()?;
     serde_json::from_str(&source).ok()
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn resolve_package_extends_exports(
     package_dir: &Path,
     exports: &PackageExports,
@@ -191,7 +183,6 @@ fn resolve_package_extends_exports(
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn resolve_package_extends_export_value(
     package_dir: &Path,
     value: &PackageExports,
@@ -228,27 +219,30 @@ fn resolve_package_extends_export_value(
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn resolve_config_export_target(package_dir: &Path, target: &str) -> Option<PathBuf> {
     let resolved = package_dir.join(target.trim_start_matches("./"));
-    if resolved.is_file() {
+    if compiler_host::file_exists(&resolved) {
         return Some(resolved);
     }
     if resolved.extension().is_none() {
         let json_path = resolved.with_extension("json");
-        if json_path.is_file() {
+        if compiler_host::file_exists(&json_path) {
             return Some(json_path);
         }
     }
-    if resolved.is_dir() {
+    if compiler_host::directory_exists(&resolved) {
         let tsconfig_path = resolved.join("tsconfig.json");
-        if tsconfig_path.is_file() {
+        if compiler_host::file_exists(&tsconfig_path) {
             return Some(tsconfig_path);
         }
     }
     None
 }
 
+/// Template variable that stands for the directory of the tsconfig being
+/// compiled, even when it appears in a config reached through `extends`.
+const CONFIG_DIR_TEMPLATE: &str = "${configDir}";
+
 /// Anchor relative path-like compiler options at the directory of the
 /// tsconfig that declared them. `tsc` resolves `baseUrl` relative to the
 /// config file where it is written, so when one config inherits from
@@ -270,10 +264,11 @@ pub(super) fn anchor_inherited_path_options(config: &mut TsConfig, config_path:
     anchor_relative_path_option(&mut opts.ts_build_info_file, parent);
 
     if let Some(root_dirs) = opts.root_dirs.as_mut() {
-        let parent_abs = std::fs::canonicalize(parent).unwrap_or_else(|_| parent.to_path_buf());
+        let parent_abs =
+            compiler_host::canonicalize(parent).unwrap_or_else(|_| parent.to_path_buf());
         for root_dir in root_dirs {
             let trimmed = root_dir.trim();
-            if trimmed.is_empty() {
+            if trimmed.is_empty() || trimmed.starts_with(CONFIG_DIR_TEMPLATE) {
                 continue;
             }
             let candidate = std::path::Path::new(trimmed);
@@ -281,16 +276,17 @@ pub(super) fn anchor_inherited_path_options(config: &mut TsConfig, config_path:
                 continue;
             }
             let joined = parent_abs.join(candidate);
-            let normalized = std::fs::canonicalize(&joined).unwrap_or(joined);
+            let normalized = compiler_host::canonicalize(&joined).unwrap_or(joined);
             *root_dir = normalized.to_string_lossy().into_owned();
         }
     }
 
     if let Some(type_roots) = opts.type_roots.as_mut() {
-        let parent_abs = std::fs::canonicalize(parent).unwrap_or_else(|_| parent.to_path_buf());
+        let parent_abs =
+            compiler_host::canonicalize(parent).unwrap_or_else(|_| parent.to_path_buf());
         for type_root in type_roots {
             let trimmed = type_root.trim();
-            if trimmed.is_empty() {
+            if trimmed.is_empty() || trimmed.starts_with(CONFIG_DIR_TEMPLATE) {
                 continue;
             }
             let candidate = std::path::Path::new(trimmed);
@@ -298,7 +294,7 @@ pub(super) fn anchor_inherited_path_options(config: &mut TsConfig, config_path:
                 continue;
             }
             let joined = parent_abs.join(candidate);
-            let normalized = std::fs::canonicalize(&joined).unwrap_or(joined);
+            let normalized = compiler_host::canonicalize(&joined).unwrap_or(joined);
             *type_root = normalized.to_string_lossy().into_owned();
         }
     }
@@ -309,7 +305,7 @@ fn anchor_relative_path_option(option: &mut Option<String>, base_dir: &Path) {
         return;
     };
     let trimmed = value.trim();
-    if trimmed.is_empty() {
+    if trimmed.is_empty() || trimmed.starts_with(CONFIG_DIR_TEMPLATE) {
         return;
     }
     let candidate = std::path::Path::new(trimmed);
@@ -317,9 +313,9 @@ fn anchor_relative_path_option(option: &mut Option<String>, base_dir: &Path) {
         return;
     }
 
-    let base_abs = std::fs::canonicalize(base_dir).unwrap_or_else(|_| base_dir.to_path_buf());
+    let base_abs = compiler_host::canonicalize(base_dir).unwrap_or_else(|_| base_dir.to_path_buf());
     let joined = base_abs.join(candidate);
-    let normalized = std::fs::canonicalize(&joined).unwrap_or(joined);
+    let normalized = compiler_host::canonicalize(&joined).unwrap_or(joined);
     *option = Some(normalized.to_string_lossy().into_owned());
 }
 
@@ -327,7 +323,7 @@ pub(super) fn anchor_inherited_root_selectors(config: &mut TsConfig, config_path
     let Some(parent) = config_path.parent() else {
         return;
     };
-    let parent_abs = std::fs::canonicalize(parent).unwrap_or_else(|_| parent.to_path_buf());
+    let parent_abs = compiler_host::canonicalize(parent).unwrap_or_else(|_| parent.to_path_buf());
 
     if let Some(files) = config.files.as_mut() {
         for file in files {
@@ -348,7 +344,7 @@ pub(super) fn anchor_inherited_root_selectors(config: &mut TsConfig, config_path
 
 fn anchor_relative_selector(selector: &mut String, base_dir: &Path) {
     let trimmed = selector.trim();
-    if trimmed.is_empty() {
+    if trimmed.is_empty() || trimmed.starts_with(CONFIG_DIR_TEMPLATE) {
         return;
     }
     let candidate = std::path::Path::new(trimmed);
@@ -358,6 +354,71 @@ fn anchor_relative_selector(selector: &mut String, base_dir: &Path) {
     *selector = base_dir.join(candidate).to_string_lossy().into_owned();
 }
 
+/// Replace a leading `${configDir}` in path-shaped options and root
+/// selectors with the directory of `config_path`.
+///
+/// Called once on the fully merged config, so templates inherited from a
+/// base config resolve against the consuming project like tsc does, not
+/// against the base config's own directory. Inherited values that use the
+/// template are left unanchored by `anchor_inherited_*` for this reason.
+pub(super) fn substitute_config_dir_templates(config: &mut TsConfig, config_path: &Path) {
+    let Some(parent) = config_path.parent() else {
+        return;
+    };
+    let parent_abs = compiler_host::canonicalize(parent).unwrap_or_else(|_| parent.to_path_buf());
+    let config_dir = parent_abs.to_string_lossy();
+
+    for selectors in [
+        config.files.as_mut(),
+        config.include.as_mut(),
+        config.exclude.as_mut(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        for selector in selectors {
+            substitute_config_dir(selector, &config_dir);
+        }
+    }
+
+    let Some(opts) = config.compiler_options.as_mut() else {
+        return;
+    };
+    for option in [
+        &mut opts.base_url,
+        &mut opts.root_dir,
+        &mut opts.out_dir,
+        &mut opts.out_file,
+        &mut opts.declaration_dir,
+        &mut opts.ts_build_info_file,
+    ] {
+        if let Some(value) = option.as_mut() {
+            substitute_config_dir(value, &config_dir);
+        }
+    }
+    for values in [opts.root_dirs.as_mut(), opts.type_roots.as_mut()]
+        .into_iter()
+        .flatten()
+    {
+        for value in values {
+            substitute_config_dir(value, &config_dir);
+        }
+    }
+    if let Some(paths) = opts.paths.as_mut() {
+        for targets in paths.values_mut() {
+            for target in targets {
+                substitute_config_dir(target, &config_dir);
+            }
+        }
+    }
+}
+
+fn substitute_config_dir(value: &mut String, config_dir: &str) {
+    if let Some(rest) = value.trim().strip_prefix(CONFIG_DIR_TEMPLATE) {
+        *value = format!("{config_dir}{rest}");
+    }
+}
+
 pub(super) fn merge_configs(base: TsConfig, mut child: TsConfig) -> TsConfig {
     let merged_compiler_options = match (base.compiler_options, child.compiler_options.take()) {
         (Some(base_opts), Some(child_opts)) => Some(merge_compiler_options(base_opts, child_opts)),
@@ -374,6 +435,7 @@ pub(super) fn merge_configs(base: TsConfig, mut child: TsConfig) -> TsConfig {
         files: child.files.or(base.files),
         // references are not inherited from extended configs (tsc behavior)
         references: child.references,
+        compile_on_save: child.compile_on_save.or(base.compile_on_save),
     }
 }
 
@@ -483,6 +545,7 @@ fn merge_compiler_options(base: CompilerOptions, child: CompilerOptions) -> Comp
             allow_umd_global_access,
             preserve_const_enums,
             erasable_syntax_only,
+            no_error_truncation,
             max_node_module_js_depth,
         }
     );
@@ -587,6 +650,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn merge_configs_compile_on_save_inherits_from_base() {
+        let base = TsConfig {
+            compile_on_save: Some(true),
+            ..Default::default()
+        };
+
+        let merged = merge_configs(base.clone(), TsConfig::default());
+        assert_eq!(merged.compile_on_save, Some(true));
+
+        let child = TsConfig {
+            compile_on_save: Some(false),
+            ..Default::default()
+        };
+        let merged = merge_configs(base, child);
+        assert_eq!(merged.compile_on_save, Some(false), "child value wins");
+    }
+
     #[test]
     fn anchor_inherited_root_selectors_makes_relative_paths_absolute() {
         let temp = tempdir().unwrap();
@@ -671,6 +752,44 @@ mod tests {
         assert_eq!(opts.base_url.as_deref(), Some(abs_path.as_str()));
     }
 
+    #[test]
+    fn config_dir_template_resolves_against_consuming_config() {
+        let temp = tempdir().unwrap();
+        let shared = temp.path().join("node_modules/@org/tsconfig");
+        let project = temp.path().join("app");
+        std::fs::create_dir_all(&shared).unwrap();
+        std::fs::create_dir_all(&project).unwrap();
+        std::fs::write(
+            shared.join("tsconfig.json"),
+            r#"{
+                "compilerOptions": {
+                    "outDir": "${configDir}/dist",
+                    "paths": { "@/*": ["${configDir}/src/*"] }
+                },
+                "include": ["${configDir}/src"]
+            }"#,
+        )
+        .unwrap();
+        let config_path = project.join("tsconfig.json");
+        std::fs::write(&config_path, r#"{ "extends": "@org/tsconfig" }"#).unwrap();
+
+        let config = super::super::load_tsconfig(&config_path).unwrap();
+        let project_abs = std::fs::canonicalize(&project).unwrap();
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(
+            opts.out_dir.as_deref(),
+            Some(project_abs.join("dist").to_string_lossy().as_ref())
+        );
+        assert_eq!(
+            opts.paths.unwrap()["@/*"],
+            vec![project_abs.join("src/*").to_string_lossy().into_owned()]
+        );
+        assert_eq!(
+            config.include.unwrap(),
+            vec![project_abs.join("src").to_string_lossy().into_owned()]
+        );
+    }
+
     #[test]
     fn resolve_extends_path_relative() {
         let temp = tempdir().unwrap();
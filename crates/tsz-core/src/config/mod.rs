@@ -1,21 +1,23 @@
 use anyhow::{Context, Result, anyhow, bail};
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize};
 
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::path::{Path, PathBuf};
 
 use crate::checker::context::ScriptTarget as CheckerScriptTarget;
 use crate::checker::diagnostics::Diagnostic;
+use crate::compiler_host;
 use crate::emitter::{ModuleKind, NewLineKind, PrinterOptions, ScriptTarget};
 use tsz_common::diagnostics::data::{diagnostic_codes, diagnostic_messages};
 use tsz_common::diagnostics::format_message;
 
 mod extends;
+pub mod file_discovery;
 mod lib_resolution;
 
 use extends::{
     anchor_inherited_path_options, anchor_inherited_root_selectors, merge_configs,
-    resolve_extends_path,
+    resolve_extends_path, substitute_config_dir_templates,
 };
 
 pub use lib_resolution::{
@@ -65,7 +67,7 @@ where
 
 /// Represents the `extends` field which can be a single string or an array of strings.
 /// tsc 5.0+ supports `"extends": ["./base1.json", "./base2.json"]`.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum ExtendsValue {
     /// A single config path to extend from.
@@ -74,7 +76,7 @@ pub enum ExtendsValue {
     Array(Vec<String>),
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct TsConfig {
     #[serde(default)]
@@ -90,10 +92,13 @@ pub struct TsConfig {
     /// Project references for composite project builds
     #[serde(default)]
     pub references: Option<Vec<TsConfigReference>>,
+    /// Whether the editor should emit a file's outputs when it is saved
+    #[serde(default)]
+    pub compile_on_save: Option<bool>,
 }
 
 /// A project reference entry in tsconfig.json
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct TsConfigReference {
     /// Path to the referenced project's tsconfig.json or directory
@@ -103,7 +108,7 @@ pub struct TsConfigReference {
     pub prepend: bool,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct CompilerOptions {
     #[serde(default)]
@@ -409,6 +414,9 @@ pub struct CompilerOptions {
     /// Only allow syntax that can be fully erased (no runtime emit).
     #[serde(default, deserialize_with = "deserialize_bool_or_string")]
     pub erasable_syntax_only: Option<bool>,
+    /// Do not truncate type displays in error messages.
+    #[serde(default, deserialize_with = "deserialize_bool_or_string")]
+    pub no_error_truncation: Option<bool>,
     /// Specify the maximum folder depth used for checking JavaScript files from `node_modules`.
     /// Only applicable with 'allowJs'. Defaults to 0.
     #[serde(default)]
@@ -1166,6 +1174,10 @@ pub fn resolve_compiler_options(
         resolved.checker.no_fallthrough_cases_in_switch = no_fallthrough;
     }
 
+    if let Some(no_truncation) = options.no_error_truncation {
+        resolved.checker.no_error_truncation = no_truncation;
+    }
+
     if let Some(ref custom_conditions) = options.custom_conditions {
         resolved.custom_conditions = custom_conditions.clone();
     }
@@ -3078,6 +3090,7 @@ fn compiler_option_expected_type(key: &str) -> &'static str {
         | "allowUnreachableCode"
         | "allowUnusedLabels"
         | "alwaysStrict"
+        | "assumeChangesOnlyAffectDirectDependencies"
         | "checkJs"
         | "composite"
         | "declaration"
@@ -3236,6 +3249,7 @@ const KNOWN_COMPILER_OPTION_CANONICAL_NAMES: &[&str] = &[
     "allowUnreachableCode",
     "allowUnusedLabels",
     "alwaysStrict",
+    "assumeChangesOnlyAffectDirectDependencies",
     "baseUrl",
     "charset",
     "checkJs",
@@ -3368,6 +3382,9 @@ fn known_compiler_option(key_lower: &str) -> Option<&'static str> {
         "allowunreachablecode" => Some("allowUnreachableCode"),
         "allowunusedlabels" => Some("allowUnusedLabels"),
         "alwaysstrict" => Some("alwaysStrict"),
+        "assumechangesonlyaffectdirectdependencies" => {
+            Some("assumeChangesOnlyAffectDirectDependencies")
+        }
         "baseurl" => Some("baseUrl"),
         "charset" => Some("charset"),
         "checkjs" => Some("checkJs"),
@@ -3496,14 +3513,25 @@ fn known_compiler_option(key_lower: &str) -> Option<&'static str> {
 }
 
 pub fn load_tsconfig(path: &Path) -> Result<TsConfig> {
+    load_tsconfig_with_chain(path).map(|(config, _)| config)
+}
+
+/// [`load_tsconfig`], plus the path of every config file read along the
+/// `extends` chain, nearest first.
+pub fn load_tsconfig_with_chain(path: &Path) -> Result<(TsConfig, Vec<PathBuf>)> {
     let mut visited = FxHashSet::default();
-    load_tsconfig_inner(path, &mut visited, false)
+    let mut chain = Vec::new();
+    let mut config = load_tsconfig_inner(path, &mut visited, &mut chain, false)?;
+    substitute_config_dir_templates(&mut config, path);
+    Ok((config, chain))
 }
 
 /// Load tsconfig.json and collect config-level diagnostics.
 pub fn load_tsconfig_with_diagnostics(path: &Path) -> Result<ParsedTsConfig> {
     let mut visited = FxHashSet::default();
-    load_tsconfig_inner_with_diagnostics(path, &mut visited, false)
+    let mut parsed = load_tsconfig_inner_with_diagnostics(path, &mut visited, false)?;
+    substitute_config_dir_templates(&mut parsed.config, path);
+    Ok(parsed)
 }
 
 fn config_ignore_deprecations_silences_6_0(config: &TsConfig) -> bool {
@@ -3525,15 +3553,17 @@ const fn is_ts60_deprecation_diagnostic_code(code: u32) -> bool {
 fn load_tsconfig_inner(
     path: &Path,
     visited: &mut FxHashSet<PathBuf>,
+    chain: &mut Vec<PathBuf>,
     inherited: bool,
 ) -> Result<TsConfig> {
-    let canonical = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let canonical = compiler_host::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
     if !visited.insert(canonical.clone()) {
         bail!("tsconfig extends cycle detected at {}", canonical.display());
     }
 
-    let source = std::fs::read_to_string(path)
+    let source = compiler_host::read_file(path)
         .with_context(|| format!("failed to read tsconfig: {}", path.display()))?;
+    chain.push(path.to_path_buf());
     let mut config = parse_tsconfig(&source)
         .with_context(|| format!("failed to parse tsconfig: {}", path.display()))?;
     anchor_inherited_path_options(&mut config, path);
@@ -3552,7 +3582,7 @@ fn load_tsconfig_inner(
         let mut accumulated: Option<TsConfig> = None;
         for extends_path_str in &extends_paths {
             let base_path = resolve_extends_path(path, extends_path_str)?;
-            let base_config = load_tsconfig_inner(&base_path, visited, true)?;
+            let base_config = load_tsconfig_inner(&base_path, visited, chain, true)?;
             accumulated = Some(match accumulated {
                 Some(acc) => merge_configs(acc, base_config),
                 None => base_config,
@@ -3572,12 +3602,12 @@ fn load_tsconfig_inner_with_diagnostics(
     visited: &mut FxHashSet<PathBuf>,
     inherited: bool,
 ) -> Result<ParsedTsConfig> {
-    let canonical = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let canonical = compiler_host::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
     if !visited.insert(canonical.clone()) {
         bail!("tsconfig extends cycle detected at {}", canonical.display());
     }
 
-    let source = std::fs::read_to_string(path)
+    let source = compiler_host::read_file(path)
         .with_context(|| format!("failed to read tsconfig: {}", path.display()))?;
     let file_display = path.display().to_string();
     let mut parsed = parse_tsconfig_with_diagnostics(&source, &file_display)
@@ -3679,7 +3709,7 @@ fn load_tsconfig_inner_with_diagnostics(
 /// Collect removed compiler option names from a config file (and its base configs).
 /// Used to detect removed options inherited via `extends` for TS5102 diagnostics.
 fn collect_removed_options_from_config(path: &Path, removed: &mut Vec<String>) {
-    let Ok(source) = std::fs::read_to_string(path) else {
+    let Ok(source) = compiler_host::read_file(path) else {
         return;
     };
     let normalized = normalize_jsonc(&source);
@@ -3772,7 +3802,48 @@ pub fn jsx_string_to_mode(value: &str) -> Option<tsz_common::checker_options::Js
     parse_jsx_emit(value).ok().map(jsx_emit_to_mode)
 }
 
-const fn jsx_emit_to_mode(emit: JsxEmit) -> tsz_common::checker_options::JsxMode {
+/// Parse a raw `jsx` compiler-option string into a [`JsxEmit`].
+/// Returns `None` when the string is unrecognised.
+pub fn jsx_string_to_emit(value: &str) -> Option<JsxEmit> {
+    parse_jsx_emit(value).ok()
+}
+
+/// tsc's numeric `JsxEmit` value as a [`JsxEmit`], or `None` when it is
+/// not one.
+pub const fn jsx_emit_from_ts_numeric(value: u32) -> Option<JsxEmit> {
+    match value {
+        1 => Some(JsxEmit::Preserve),
+        2 => Some(JsxEmit::React),
+        3 => Some(JsxEmit::ReactNative),
+        4 => Some(JsxEmit::ReactJsx),
+        5 => Some(JsxEmit::ReactJsxDev),
+        _ => None,
+    }
+}
+
+/// tsc's numeric `JsxEmit` value for `emit`.
+pub const fn jsx_emit_ts_numeric_value(emit: JsxEmit) -> u32 {
+    match emit {
+        JsxEmit::Preserve => 1,
+        JsxEmit::React => 2,
+        JsxEmit::ReactNative => 3,
+        JsxEmit::ReactJsx => 4,
+        JsxEmit::ReactJsxDev => 5,
+    }
+}
+
+/// The emitter's JSX transform for a `jsx` option value.
+pub const fn jsx_emit_to_emitter(emit: JsxEmit) -> crate::emitter::JsxEmit {
+    match emit {
+        JsxEmit::Preserve => crate::emitter::JsxEmit::Preserve,
+        JsxEmit::React => crate::emitter::JsxEmit::React,
+        JsxEmit::ReactJsx => crate::emitter::JsxEmit::ReactJsx,
+        JsxEmit::ReactJsxDev => crate::emitter::JsxEmit::ReactJsxDev,
+        JsxEmit::ReactNative => crate::emitter::JsxEmit::ReactNative,
+    }
+}
+
+pub const fn jsx_emit_to_mode(emit: JsxEmit) -> tsz_common::checker_options::JsxMode {
     use tsz_common::checker_options::JsxMode;
     match emit {
         JsxEmit::Preserve => JsxMode::Preserve,
@@ -3783,7 +3854,7 @@ const fn jsx_emit_to_mode(emit: JsxEmit) -> tsz_common::checker_options::JsxMode
     }
 }
 
-fn build_path_mappings(paths: &FxHashMap<String, Vec<String>>) -> Vec<PathMapping> {
+pub(crate) fn build_path_mappings(paths: &FxHashMap<String, Vec<String>>) -> Vec<PathMapping> {
     let mut mappings = Vec::new();
     for (pattern, targets) in paths {
         if targets.is_empty() {
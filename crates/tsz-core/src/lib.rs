@@ -101,11 +101,22 @@ mod core_public_helpers_tests;
 #[path = "../tests/module_resolution_tests.rs"]
 mod module_resolution_tests;
 
+// WasmProgram host and program-API tests
+#[cfg(test)]
+#[path = "../tests/wasm_program_tests.rs"]
+mod wasm_program_tests;
+
 pub use checker::state::{CheckerState, MAX_CALL_DEPTH, MAX_INSTANTIATION_DEPTH};
 
 // Emitter - re-exported from tsz-emitter workspace crate
 pub use tsz_emitter::emitter;
 #[cfg(test)]
+#[path = "../tests/parser_ast_api_tests.rs"]
+mod parser_ast_api_tests;
+#[cfg(test)]
+#[path = "../tests/parser_quick_info_tests.rs"]
+mod parser_quick_info_tests;
+#[cfg(test)]
 #[path = "../tests/transform_api_tests.rs"]
 mod transform_api_tests;
 
@@ -197,6 +208,9 @@ mod isolated_test_runner;
 // Compiler configuration types (shared between core and CLI)
 pub mod config;
 
+// File-system seam for embedders (virtual file systems, overlays, in-memory outputs)
+pub mod compiler_host;
+
 // Re-exports from tsz-cli crate (when available as a dependency)
 // CLI code has been moved to crates/tsz-cli/
 
@@ -205,17 +219,13 @@ pub mod config;
 
 mod module_tracking;
 
-// Module Resolution Infrastructure (non-wasm targets only - requires file system access)
-#[cfg(not(target_arch = "wasm32"))]
+// Module Resolution Infrastructure (file access goes through `compiler_host`)
 pub mod module_resolver;
-#[cfg(not(target_arch = "wasm32"))]
 mod resolution;
-#[cfg(not(target_arch = "wasm32"))]
 pub use module_resolver::{
     ModuleExtension, ModuleLookupError, ModuleLookupOutcome, ModuleLookupRequest,
     ModuleLookupResult, ModuleResolver, ResolutionFailure, ResolvedModule,
 };
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) use resolution::helpers as module_resolver_helpers;
 
 // Import/Export Tracking
@@ -246,7 +256,9 @@ pub fn create_scanner(text: String, skip_trivia: bool) -> ScannerState {
 // Parser WASM Interface (High-Performance Parser)
 // =============================================================================
 
-pub use crate::api::wasm::transforms::WasmTransformContext;
+pub use crate::api::wasm::transforms::{
+    NodeTransformer, WasmCustomTransformers, WasmTransformContext,
+};
 
 pub use crate::api::wasm::parser::Parser;
 
@@ -257,6 +269,10 @@ pub fn create_parser(file_name: String, source_text: String) -> Parser {
     Parser::new(file_name, source_text)
 }
 
+pub use crate::api::wasm::cancellation::WasmCancellationToken;
+pub use crate::api::wasm::host::JsModuleResolutionHost;
+pub use crate::api::wasm::lib_cache::clear_lib_file_cache;
+pub use crate::api::wasm::memory::{get_memory_statistics, recycle_compiler_caches};
 pub use crate::api::wasm::program::WasmProgram;
 
 /// Create a new multi-file program.
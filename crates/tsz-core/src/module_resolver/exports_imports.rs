@@ -7,6 +7,7 @@
 use super::{
     ImportingModuleKind, ModuleExtension, ModuleResolver, ResolutionFailure, ResolvedModule,
 };
+use crate::compiler_host;
 use crate::config::ModuleResolutionKind;
 use crate::module_resolver_helpers::*;
 use crate::span::Span;
@@ -93,7 +94,7 @@ impl ModuleResolver {
         loop {
             let package_json_path = current.join("package.json");
 
-            if package_json_path.is_file()
+            if compiler_host::file_exists(&package_json_path)
                 && let Ok(package_json) = self.read_package_json(&package_json_path)
                 && let Some(imports) = &package_json.imports
             {
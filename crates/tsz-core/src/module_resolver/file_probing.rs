@@ -6,6 +6,7 @@
 
 use super::ModuleResolver;
 use super::request_types::{ModuleExtension, PackageType};
+use crate::compiler_host;
 use crate::config::ModuleResolutionKind;
 use crate::module_resolver_helpers::*;
 use std::path::{Component, Path, PathBuf};
@@ -223,7 +224,7 @@ impl ModuleResolver {
         path: &Path,
         package_type: Option<PackageType>,
     ) -> Option<PathBuf> {
-        if !path.is_dir() {
+        if !compiler_host::directory_exists(&path) {
             return None;
         }
         // Collapse `.`/`..` segments up front. Without this, a relative
@@ -235,9 +236,13 @@ impl ModuleResolver {
         let path = normalized.as_path();
 
         let package_json_path = path.join("package.json");
-        if package_json_path.exists()
+        if compiler_host::path_exists(&package_json_path)
             && let Ok(pj) = self.read_package_json(&package_json_path)
         {
+            trace_resolution(format_args!(
+                "Found 'package.json' at '{}'.",
+                package_json_path.display()
+            ));
             let types = pj
                 .types
                 .clone()
@@ -259,12 +264,22 @@ impl ModuleResolver {
 
             if let Some(types) = types {
                 let types_path = path.join(&types);
+                trace_resolution(format_args!(
+                    "'package.json' has 'types' field '{}' that references '{}'.",
+                    types,
+                    types_path.display()
+                ));
                 if let Some(resolved) = self.try_types_entry(&types_path) {
                     return Some(resolved);
                 }
             }
             if let Some(main) = &pj.main {
                 let main_path = path.join(main);
+                trace_resolution(format_args!(
+                    "'package.json' has 'main' field '{}' that references '{}'.",
+                    main,
+                    main_path.display()
+                ));
                 if let Some(resolved) = self.try_file_with_package_type(&main_path, package_type) {
                     return Some(resolved);
                 }
@@ -339,7 +354,7 @@ impl ModuleResolver {
         if !skip_extension_probing && let Some(resolved) = self.try_file(path) {
             return Some(resolved);
         }
-        if path.is_dir() {
+        if compiler_host::directory_exists(&path) {
             let index = path.join("index");
             return self.try_file(&index);
         }
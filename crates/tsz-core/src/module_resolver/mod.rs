@@ -36,7 +36,7 @@ pub use request_types::*;
 use crate::config::{JsxEmit, ModuleResolutionKind, PathMapping, ResolvedCompilerOptions};
 use crate::diagnostics::DiagnosticBag;
 use crate::emitter::ModuleKind;
-use crate::module_resolver_helpers::PackageJson;
+use crate::module_resolver_helpers::{PackageJson, ResolutionTraceScope, trace_resolution};
 use crate::span::Span;
 use rustc_hash::FxHashMap;
 use std::cell::Cell;
@@ -398,6 +398,11 @@ impl ModuleResolver {
         );
         if let Some(cached) = self.resolution_cache.get(&cache_key) {
             Self::increment_counter(&self.resolution_cache_hits);
+            trace_resolution(format_args!(
+                "Resolution for module '{}' was found in cache from location '{}'.",
+                specifier,
+                cache_key.0.display()
+            ));
             return cached.clone();
         }
         Self::increment_counter(&self.resolution_cache_misses);
@@ -654,7 +659,75 @@ impl ModuleResolver {
     /// The `fallback_resolve` closure lets the driver provide its legacy resolution
     /// path. The `is_ambient_module` closure lets the driver check program-level
     /// ambient declarations. All diagnostic code selection stays here.
+    ///
+    /// With `--traceResolution`, the lookup is framed by tsc's
+    /// `======== Resolving module ... ========` header and result lines, and
+    /// every candidate probed in between is traced.
     pub fn lookup(
+        &mut self,
+        request: &ModuleLookupRequest<'_>,
+        fallback_resolve: impl FnOnce(&str, &Path) -> Option<PathBuf>,
+        is_ambient_module: impl Fn(&str) -> bool,
+        known_files: Option<&rustc_hash::FxHashSet<std::path::PathBuf>>,
+    ) -> ModuleLookupResult {
+        if !self.trace_resolution {
+            return self.lookup_untraced(request, fallback_resolve, is_ambient_module, known_files);
+        }
+
+        let _tracing = ResolutionTraceScope::enable();
+        let specifier = request.specifier;
+        let containing_file = request.containing_file;
+        trace_resolution(format_args!(
+            "======== Resolving module '{}' from '{}'. ========",
+            specifier,
+            containing_file.display()
+        ));
+        trace_resolution(format_args!(
+            "Explicitly specified module resolution kind: '{}'.",
+            trace_resolution_name(self.resolution_kind)
+        ));
+        if specifier.starts_with('.') || specifier.starts_with('/') {
+            let candidate = if Path::new(specifier).is_absolute() {
+                PathBuf::from(specifier)
+            } else {
+                containing_file
+                    .parent()
+                    .unwrap_or_else(|| Path::new(""))
+                    .join(specifier)
+            };
+            trace_resolution(format_args!(
+                "Loading module as file / folder, candidate module location '{}', target file types: {}.",
+                candidate.display(),
+                self.trace_target_file_types()
+            ));
+        }
+
+        let result =
+            self.lookup_untraced(request, fallback_resolve, is_ambient_module, known_files);
+        match &result.resolved_path {
+            Some(resolved_path) => trace_resolution(format_args!(
+                "======== Module name '{}' was successfully resolved to '{}'. ========",
+                specifier,
+                resolved_path.display()
+            )),
+            None => trace_resolution(format_args!(
+                "======== Module name '{specifier}' was not resolved. ========"
+            )),
+        }
+        result
+    }
+
+    /// tsc's "target file types" list for the current options.
+    const fn trace_target_file_types(&self) -> &'static str {
+        match (self.allow_js, self.resolve_json_module) {
+            (false, false) => "TypeScript, Declaration",
+            (true, false) => "TypeScript, JavaScript, Declaration",
+            (false, true) => "TypeScript, Declaration, JSON",
+            (true, true) => "TypeScript, JavaScript, Declaration, JSON",
+        }
+    }
+
+    fn lookup_untraced(
         &mut self,
         request: &ModuleLookupRequest<'_>,
         fallback_resolve: impl FnOnce(&str, &Path) -> Option<PathBuf>,
@@ -671,32 +744,6 @@ impl ModuleResolver {
         let ambient_match = is_ordinary_bare && is_ambient_module(specifier);
         let containing_is_declaration =
             ModuleExtension::from_path(containing_file).is_declaration();
-        if self.trace_resolution {
-            println!(
-                "======== Resolving module '{}' from '{}'. ========",
-                specifier,
-                containing_file.display()
-            );
-            println!(
-                "Explicitly specified module resolution kind: '{}'.",
-                trace_resolution_name(self.resolution_kind)
-            );
-            if specifier.starts_with('.') || specifier.starts_with('/') {
-                let candidate = if Path::new(specifier).is_absolute() {
-                    PathBuf::from(specifier)
-                } else {
-                    containing_file
-                        .parent()
-                        .unwrap_or_else(|| Path::new(""))
-                        .join(specifier)
-                };
-                println!(
-                    "Loading module as file / folder, candidate module location '{}', target file types: TypeScript, Declaration.",
-                    candidate.display()
-                );
-            }
-        }
-
         // Declaration files frequently stitch together sibling `declare module "..."`
         // blocks with bare imports (for example, react16.d.ts declares both
         // `"react"` and `"prop-types"` in the same file). If the ambient module is
@@ -736,17 +783,6 @@ impl ModuleResolver {
             request.resolution_mode_override,
         ) {
             Ok(resolved_module) => {
-                if self.trace_resolution {
-                    println!(
-                        "File '{}' exists - use it as a name resolution result.",
-                        resolved_module.resolved_path.display()
-                    );
-                    println!(
-                        "======== Module name '{}' was successfully resolved to '{}'. ========",
-                        specifier,
-                        resolved_module.resolved_path.display()
-                    );
-                }
                 // TS6263: Module resolved to a .d.*.ts arbitrary extension declaration
                 // file but --allowArbitraryExtensions is not set.
                 // Skip this check when the containing file is itself a declaration
@@ -888,13 +924,6 @@ impl ModuleResolver {
 
                     // Trust the fallback resolver's result. The fallback already validated
                     // that the path exists (either in known_files or on filesystem).
-                    if self.trace_resolution {
-                        println!(
-                            "======== Module name '{}' was successfully resolved to '{}'. ========",
-                            specifier,
-                            fallback_path.display()
-                        );
-                    }
                     return ModuleLookupResult::resolved(fallback_path);
                 }
 
@@ -1051,6 +1080,13 @@ impl ModuleResolver {
         }
     }
 
+    /// Entry file of the type package in `package_dir` (`types`/`typings`/
+    /// `main`, then `index`), for `@types` packages a program includes
+    /// without an import.
+    pub fn resolve_type_package_directory(&self, package_dir: &Path) -> Option<PathBuf> {
+        self.try_directory_with_package_type(package_dir, None)
+    }
+
     /// Clear module resolution caches owned by this resolver.
     ///
     /// This also clears the current thread's file-existence cache, which is
@@ -1141,6 +1177,14 @@ impl ModuleResolver {
     }
 }
 
+/// Trace a candidate file probed by a caller-supplied fallback resolver.
+///
+/// Only prints while a [`ModuleResolver::lookup`] with `--traceResolution`
+/// is running on the current thread.
+pub fn trace_file_probe(path: &Path, exists: bool) {
+    crate::module_resolver_helpers::trace_file_probe(path, exists);
+}
+
 const fn trace_resolution_name(resolution: ModuleResolutionKind) -> &'static str {
     match resolution {
         ModuleResolutionKind::Classic => "Classic",
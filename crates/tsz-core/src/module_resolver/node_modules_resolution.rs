@@ -8,6 +8,7 @@ use super::{
     ImportingModuleKind, ModuleExtension, ModuleResolver, ResolutionFailure, ResolvedModule,
     self_reference::SelfReferenceResultV2,
 };
+use crate::compiler_host;
 use crate::config::ModuleResolutionKind;
 use crate::module_resolver_helpers::*;
 use crate::span::Span;
@@ -44,7 +45,7 @@ impl ModuleResolver {
             return false;
         }
 
-        let Ok(text) = std::fs::read_to_string(path) else {
+        let Ok(text) = compiler_host::read_file(path) else {
             return false;
         };
 
@@ -129,7 +130,7 @@ impl ModuleResolver {
             if !package_name.starts_with("@types/") {
                 let types_package = types_package_name(&package_name);
                 let types_dir = current.join("node_modules").join(&types_package);
-                if types_dir.is_dir()
+                if compiler_host::directory_exists(&types_dir)
                     && let Ok(resolved) = self.resolve_package(
                         &types_dir,
                         subpath.as_deref(),
@@ -150,7 +151,7 @@ impl ModuleResolver {
                 } else {
                     type_root.join(&package_name)
                 };
-                if types_package.is_dir()
+                if compiler_host::directory_exists(&types_package)
                     && let Ok(resolved) = self.resolve_package(
                         &types_package,
                         subpath.as_deref(),
@@ -211,6 +212,12 @@ impl ModuleResolver {
             }
         }
 
+        trace_resolution(format_args!(
+            "Loading module '{}' from 'node_modules' folder, target file types: {}.",
+            specifier,
+            self.trace_target_file_types()
+        ));
+
         // Walk up directory tree looking for node_modules
         let mut current = containing_dir.to_path_buf();
         loop {
@@ -227,7 +234,7 @@ impl ModuleResolver {
                     exists
                 } else {
                     Self::increment_counter(&self.node_modules_dir_cache_misses);
-                    let exists = child_node_modules.is_dir();
+                    let exists = compiler_host::directory_exists(&child_node_modules);
                     cache.insert(child_node_modules.clone(), exists);
                     exists
                 }
@@ -242,12 +249,17 @@ impl ModuleResolver {
             }
             if child_nm_exists {
                 node_modules_roots.push(child_node_modules);
+            } else {
+                trace_resolution(format_args!(
+                    "Directory '{}' does not exist, skipping all lookups in it.",
+                    child_node_modules.display()
+                ));
             }
 
             for node_modules in node_modules_roots {
                 let package_dir = node_modules.join(&package_name);
 
-                if package_dir.is_dir() {
+                if compiler_host::directory_exists(&package_dir) {
                     match self.resolve_package(
                         &package_dir,
                         subpath.as_deref(),
@@ -298,7 +310,7 @@ impl ModuleResolver {
                 }
                 let types_package = types_package_name(&package_name);
                 let types_dir = node_modules.join(&types_package);
-                if types_dir.is_dir()
+                if compiler_host::directory_exists(&types_dir)
                     && let Ok(resolved) = self.resolve_package(
                         &types_dir,
                         subpath.as_deref(),
@@ -322,7 +334,7 @@ impl ModuleResolver {
         // Try type roots (for @types packages)
         for type_root in &self.type_roots {
             let types_package = type_root.join(types_package_name(&package_name));
-            if types_package.is_dir()
+            if compiler_host::directory_exists(&types_package)
                 && let Ok(resolved) = self.resolve_package(
                     &types_package,
                     subpath.as_deref(),
@@ -362,7 +374,7 @@ impl ModuleResolver {
         }
 
         let package_json_path = package_dir.join("package.json");
-        if !package_json_path.is_file() {
+        if !compiler_host::file_exists(&package_json_path) {
             return false;
         }
 
@@ -446,14 +458,14 @@ impl ModuleResolver {
                     exists
                 } else {
                     Self::increment_counter(&self.node_modules_dir_cache_misses);
-                    let exists = node_modules.is_dir();
+                    let exists = compiler_host::directory_exists(&node_modules);
                     cache.insert(node_modules.clone(), exists);
                     exists
                 }
             };
             if nm_exists {
                 let package_dir = node_modules.join(&package_name);
-                if package_dir.is_dir()
+                if compiler_host::directory_exists(&package_dir)
                     && self.should_stop_on_exports_failure(
                         &package_dir,
                         subpath.as_deref(),
@@ -466,7 +478,7 @@ impl ModuleResolver {
 
                 if !package_name.starts_with("@types/") {
                     let types_dir = node_modules.join(types_package_name(&package_name));
-                    if types_dir.is_dir()
+                    if compiler_host::directory_exists(&types_dir)
                         && self.should_stop_on_exports_failure(
                             &types_dir,
                             subpath.as_deref(),
@@ -489,7 +501,7 @@ impl ModuleResolver {
             let types_package = types_package_name(&package_name);
             for type_root in &self.type_roots {
                 let types_dir = type_root.join(&types_package);
-                if types_dir.is_dir()
+                if compiler_host::directory_exists(&types_dir)
                     && self.should_stop_on_exports_failure(
                         &types_dir,
                         subpath.as_deref(),
@@ -520,7 +532,11 @@ impl ModuleResolver {
     ) -> Result<ResolvedModule, ResolutionFailure> {
         // Read package.json
         let package_json_path = package_dir.join("package.json");
-        let package_json = if package_json_path.exists() {
+        let package_json = if compiler_host::path_exists(&package_json_path) {
+            trace_resolution(format_args!(
+                "Found 'package.json' at '{}'.",
+                package_json_path.display()
+            ));
             self.read_package_json(&package_json_path).map_err(|msg| {
                 ResolutionFailure::PackageJsonError {
                     message: msg,
@@ -529,8 +545,17 @@ impl ModuleResolver {
                 }
             })?
         } else {
+            trace_resolution(format_args!(
+                "File '{}' does not exist.",
+                package_json_path.display()
+            ));
             PackageJson::default()
         };
+        if package_json.types_versions.is_some() {
+            trace_resolution(format_args!(
+                "'package.json' has a 'typesVersions' field with version-specific path mappings."
+            ));
+        }
 
         // If there's a subpath, resolve it directly
         if let Some(subpath) = subpath {
@@ -549,6 +574,11 @@ impl ModuleResolver {
                         false,
                     )
                 {
+                    trace_resolution(format_args!(
+                        "Using 'exports' subpath '{}' with target '{}'.",
+                        subpath_key,
+                        resolved.display()
+                    ));
                     return Ok(ResolvedModule {
                         resolved_path: resolved.clone(),
                         resolved_using_ts_extension,
@@ -667,6 +697,10 @@ impl ModuleResolver {
                     false,
                 )
             {
+                trace_resolution(format_args!(
+                    "Using 'exports' subpath '.' with target '{}'.",
+                    resolved.display()
+                ));
                 return Ok(ResolvedModule {
                     resolved_path: resolved.clone(),
                     resolved_using_ts_extension,
@@ -709,12 +743,19 @@ impl ModuleResolver {
 
         // Try types/typings field. These entries are declaration-oriented and
         // must not resolve to runtime JS files directly.
-        if let Some(types) = package_json
-            .types
-            .clone()
-            .or_else(|| package_json.typings.clone())
-        {
+        let types_field = match (&package_json.types, &package_json.typings) {
+            (Some(types), _) => Some(("types", types.clone())),
+            (None, Some(typings)) => Some(("typings", typings.clone())),
+            (None, None) => None,
+        };
+        if let Some((field, types)) = types_field {
             let types_path = package_dir.join(&types);
+            trace_resolution(format_args!(
+                "'package.json' has '{}' field '{}' that references '{}'.",
+                field,
+                types,
+                types_path.display()
+            ));
             if let Some(resolved) = self.try_types_entry(&types_path) {
                 return Ok(ResolvedModule {
                     resolved_path: resolved.clone(),
@@ -730,6 +771,11 @@ impl ModuleResolver {
         // Try main field
         if let Some(main) = &package_json.main {
             let main_path = package_dir.join(main);
+            trace_resolution(format_args!(
+                "'package.json' has 'main' field '{}' that references '{}'.",
+                main,
+                main_path.display()
+            ));
             if let Some(resolved) = resolve_explicit_unknown_extension(&main_path) {
                 return Ok(ResolvedModule {
                     resolved_path: resolved.clone(),
@@ -741,7 +787,7 @@ impl ModuleResolver {
                 });
             }
             if let Some(declaration) = declaration_substitution_for_main(&main_path)
-                && declaration.is_file()
+                && compiler_host::file_exists(&declaration)
             {
                 return Ok(ResolvedModule {
                     resolved_path: declaration.clone(),
@@ -765,7 +811,7 @@ impl ModuleResolver {
             }
             // For main field targets that are directories, only try index files.
             // Do NOT read nested package.json — main field resolution is non-recursive.
-            if main_path.is_dir() {
+            if compiler_host::directory_exists(&main_path) {
                 let index = main_path.join("index");
                 if let Some(resolved) = self.try_file(&index) {
                     return Ok(ResolvedModule {
@@ -787,9 +833,7 @@ impl ModuleResolver {
         // tsc expects an explicit entry point and can report module-not-found when
         // none resolves; keep that behavior by skipping index fallback only when
         // the package.json actually declares entry point fields.
-        let is_symlinked_package_root = std::fs::symlink_metadata(package_dir)
-            .map(|meta| meta.file_type().is_symlink())
-            .unwrap_or(false);
+        let is_symlinked_package_root = compiler_host::is_symlink(package_dir);
         let has_entry_point_fields = package_json.exports.is_some()
             || package_json.main.is_some()
             || package_json.types.is_some()
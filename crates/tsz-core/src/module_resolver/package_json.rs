@@ -4,6 +4,7 @@
 //! package type (ESM vs CommonJS) for a given directory.
 
 use super::{ModuleResolver, PackageType};
+use crate::compiler_host;
 use std::path::Path;
 
 use crate::module_resolver_helpers::PackageJson;
@@ -31,7 +32,7 @@ impl ModuleResolver {
 
             // Check for package.json
             let package_json_path = current.join("package.json");
-            if package_json_path.is_file()
+            if compiler_host::file_exists(&package_json_path)
                 && let Ok(pj) = self.read_package_json(&package_json_path)
             {
                 let package_type = pj.package_type.as_deref().and_then(|t| match t {
@@ -82,7 +83,7 @@ impl ModuleResolver {
             return cached.clone();
         }
         Self::increment_counter(&self.package_json_cache_misses);
-        let result = std::fs::read_to_string(path)
+        let result = compiler_host::read_file(path)
             .map_err(|e| format!("Failed to read {}: {}", path.display(), e))
             .and_then(|content| {
                 serde_json::from_str::<PackageJson>(&content)
@@ -7,6 +7,7 @@ use super::{
     ImportKind, ImportingModuleKind, ModuleExtension, ModuleResolver, PackageType,
     ResolutionFailure, ResolvedModule,
 };
+use crate::compiler_host;
 use crate::config::ModuleResolutionKind;
 use crate::module_resolver_helpers::KNOWN_EXTENSIONS;
 use crate::span::Span;
@@ -79,7 +80,7 @@ impl ModuleResolver {
                 ModuleResolutionKind::Node16 | ModuleResolutionKind::NodeNext
             ) && uses_require_resolution
             {
-                let package_dir = if prefer_directory || path.is_dir() {
+                let package_dir = if prefer_directory || compiler_host::directory_exists(&path) {
                     path
                 } else {
                     path.parent().unwrap_or_else(|| Path::new("."))
@@ -194,7 +195,7 @@ impl ModuleResolver {
             // (they require `resolveJsonModule`), but tsc still suggests them.
             for candidate in &candidates {
                 let json_candidate = candidate.with_extension("json");
-                if json_candidate.is_file() {
+                if compiler_host::file_exists(&json_candidate) {
                     return Err(ResolutionFailure::ImportPathNeedsExtension {
                         specifier: specifier.to_string(),
                         suggested_extension: ".json".to_string(),
@@ -247,7 +248,7 @@ impl ModuleResolver {
         if js_json_extension_suggestion {
             for candidate in &candidates {
                 let json_candidate = candidate.with_extension("json");
-                if json_candidate.is_file() {
+                if compiler_host::file_exists(&json_candidate) {
                     return Err(ResolutionFailure::ImportPathNeedsExtension {
                         specifier: specifier.to_string(),
                         suggested_extension: ".json".to_string(),
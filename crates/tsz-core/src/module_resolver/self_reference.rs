@@ -4,6 +4,7 @@
 //! name if the package.json `exports` field matches.
 
 use super::{ModuleExtension, ModuleResolver, ResolvedModule};
+use crate::compiler_host;
 use crate::config::ModuleResolutionKind;
 use crate::span::Span;
 use std::path::Path;
@@ -52,7 +53,7 @@ impl ModuleResolver {
         loop {
             let package_json_path = current.join("package.json");
 
-            if package_json_path.is_file()
+            if compiler_host::file_exists(&package_json_path)
                 && let Ok(package_json) = self.read_package_json(&package_json_path)
             {
                 // Check if the package name matches - this is REQUIRED for a self-reference
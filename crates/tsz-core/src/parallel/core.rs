@@ -33,16 +33,15 @@ use crate::binder::state::{
     WildcardReexportsMap, WildcardReexportsTypeOnlyMap,
 };
 use crate::binder::{
-    FlowNodeArena, FlowNodeId, Scope, ScopeId, SymbolArena, SymbolId, SymbolTable,
+    FlowNodeArena, FlowNodeId, Scope, ScopeId, StableSymbolId, SymbolArena, SymbolId, SymbolTable,
 };
 #[cfg(not(target_arch = "wasm32"))]
 use crate::config::resolve_default_lib_files;
 use crate::emitter::ScriptTarget;
 use crate::lib_loader;
 use crate::parser::NodeIndex;
-use crate::parser::NodeList;
-use crate::parser::node::{NodeArena, SourceFileData};
-use crate::parser::{ParseDiagnostic, ParserState};
+use crate::parser::node::NodeArena;
+use crate::parser::{JsonParseOptions, ParseDiagnostic, ParserState};
 use anyhow::{Context, Result, bail};
 #[cfg(not(target_arch = "wasm32"))]
 use rayon::prelude::{
@@ -54,8 +53,8 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 #[cfg(not(target_arch = "wasm32"))]
 use std::sync::Once;
+use tsz_common::CancellationToken;
 use tsz_common::interner::{Atom, Interner};
-use tsz_scanner::SyntaxKind;
 
 include!("core/parse_and_libs.rs");
 include!("core/merge_support.rs");
@@ -15,6 +15,7 @@ struct BindResultReducer {
     symbol_arenas: FxHashMap<SymbolId, Arc<NodeArena>>,
     declaration_arenas: DeclarationArenaMap,
     global_lib_symbol_ids: FxHashSet<SymbolId>,
+    stable_symbol_ids: FxHashMap<SymbolId, StableSymbolId>,
 
     // Name-interning / merge-dedup
     name_interner: Interner,
@@ -107,6 +108,8 @@ impl BindResultReducer {
         let wildcard_reexports: WildcardReexportsMap = FxHashMap::default();
         let wildcard_reexports_type_only: WildcardReexportsTypeOnlyMap = FxHashMap::default();
         let global_lib_symbol_ids: FxHashSet<SymbolId> = FxHashSet::default();
+        let stable_symbol_ids: FxHashMap<SymbolId, StableSymbolId> =
+            FxHashMap::with_capacity_and_hasher(total_symbols, Default::default());
 
         // Use interned atoms to avoid repeated String hashing/cloning on hot merge paths.
         let name_interner = Interner::new();
@@ -126,6 +129,7 @@ impl BindResultReducer {
             symbol_arenas,
             declaration_arenas,
             global_lib_symbol_ids,
+            stable_symbol_ids,
             name_interner,
             merged_symbols,
             globals,
@@ -162,6 +166,10 @@ impl BindResultReducer {
         for lib_binder_idx in 0..self.lib_binders.len() {
             let lib_binder = Arc::clone(&self.lib_binders[lib_binder_idx]);
             let lib_binder_ptr = Arc::as_ptr(&lib_binder) as usize;
+            let lib_file_hash = self
+                .lib_binder_arena_map
+                .get(&lib_binder_ptr)
+                .and_then(|arena| arena_content_hash(arena));
 
             // Pre-build a set of top-level symbol IDs from file_locals for O(1) lookup.
             // This avoids an O(N*F) quadratic scan where each symbol would linearly
@@ -288,6 +296,12 @@ impl BindResultReducer {
                     self.lib_symbol_remap
                         .insert((lib_binder_ptr, local_id), global_id);
                     self.global_lib_symbol_ids.insert(global_id);
+                    if let Some(file_hash) = lib_file_hash {
+                        self.record_stable_symbol_id(
+                            global_id,
+                            StableSymbolId::new(file_hash, local_id.0),
+                        );
+                    }
 
                     // Set arena mappings for this lib symbol using the lib file's arena.
                     // The original lib binder's symbol_arenas/declaration_arenas are empty
@@ -614,6 +628,7 @@ impl BindResultReducer {
                 }
                 // Copy symbols from this file to global arena, getting new IDs
                 let mut id_remap: FxHashMap<SymbolId, SymbolId> = FxHashMap::default();
+                let file_hash = arena_content_hash(&result.arena);
                 for i in 0..result.symbols.len() {
                     let old_id = SymbolId(i as u32);
                     if let Some(sym) = result.symbols.get(old_id) {
@@ -742,6 +757,12 @@ impl BindResultReducer {
                             // NOTE: Don't add to merged_symbols - nested symbols should never be cross-file merged
                             new_id
                         };
+                        if let Some(file_hash) = file_hash {
+                            self.record_stable_symbol_id(
+                                new_id,
+                                StableSymbolId::new(file_hash, old_id.0),
+                            );
+                        }
                         id_remap.insert(old_id, new_id);
                     }
                 }
@@ -1411,6 +1432,7 @@ impl BindResultReducer {
                         &result.expando_properties,
                         &id_remap,
                     ),
+                    commonjs_exports: Arc::clone(&result.commonjs_exports),
                     file_features: result.file_features,
                     lib_symbol_reverse_remap: Arc::new(
                         result
@@ -1442,6 +1464,16 @@ impl BindResultReducer {
         }
     }
 
+    /// Record `stable` as the stable identity of `global_id`. A symbol merged
+    /// from several declarations keeps the smallest contributing identity so
+    /// the result does not depend on merge order.
+    fn record_stable_symbol_id(&mut self, global_id: SymbolId, stable: StableSymbolId) {
+        self.stable_symbol_ids
+            .entry(global_id)
+            .and_modify(|existing| *existing = (*existing).min(stable))
+            .or_insert(stable);
+    }
+
     fn finish(self, skeleton_index: SkeletonIndex, dep_graph: DepGraph) -> MergedProgram {
         // Validate skeleton data against legacy merge state before construction.
         // This runs only in debug builds and proves skeleton captures all
@@ -1473,6 +1505,15 @@ impl BindResultReducer {
         // filtering by `entry_sym_id == sym_id` use this to do a point lookup.
         let sym_to_decl_indices = build_sym_to_decl_indices(&self.declaration_arenas);
 
+        let mut symbols_by_stable_id: FxHashMap<StableSymbolId, SymbolId> =
+            FxHashMap::with_capacity_and_hasher(self.stable_symbol_ids.len(), Default::default());
+        for (&sym_id, &stable) in &self.stable_symbol_ids {
+            symbols_by_stable_id
+                .entry(stable)
+                .and_modify(|existing| *existing = (*existing).min(sym_id))
+                .or_insert(sym_id);
+        }
+
         MergedProgram {
             files: self.files,
             symbols: self.global_symbols,
@@ -1490,6 +1531,8 @@ impl BindResultReducer {
             wildcard_reexports_type_only: Arc::new(self.wildcard_reexports_type_only),
             lib_binders: Arc::new(self.lib_binders),
             lib_symbol_ids: Arc::new(self.global_lib_symbol_ids),
+            stable_symbol_ids: Arc::new(self.stable_symbol_ids),
+            symbols_by_stable_id: Arc::new(symbols_by_stable_id),
             type_interner,
             alias_partners: Arc::new(self.alias_partners),
             semantic_defs: Arc::new(self.semantic_defs),
@@ -1501,6 +1544,16 @@ impl BindResultReducer {
     }
 }
 
+/// Content hash of the file parsed into `arena`, used as the file half of
+/// `StableSymbolId`.
+fn arena_content_hash(arena: &NodeArena) -> Option<u64> {
+    let source_file = arena.source_files.first()?;
+    Some(crate::binder::state::snapshot::content_hash(
+        &source_file.file_name,
+        &source_file.text,
+    ))
+}
+
 fn merge_bind_results_from_source(results: &mut impl BindResultsSource) -> MergedProgram {
     let refs = results.refs();
     let skeletons = extract_skeletons_for_merge(&refs);
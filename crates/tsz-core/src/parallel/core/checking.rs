@@ -373,12 +373,266 @@ pub fn check_files_parallel(
     program: &MergedProgram,
     checker_options: &CheckerOptions,
     lib_files: &[Arc<LibFile>],
+) -> CheckResult {
+    check_files_parallel_with_resolutions(
+        program,
+        checker_options,
+        lib_files,
+        &FxHashMap::default(),
+    )
+}
+
+/// [`check_files_parallel`] with module resolutions computed by a host.
+///
+/// `host_resolutions` maps `(importing file index, specifier)` to the index of
+/// the resolved file in `program.files`. Entries are layered over the map
+/// derived from file names, so hosts that resolve through `package.json`
+/// `types` fields or `@types` packages can point bare specifiers at the files
+/// they loaded.
+pub fn check_files_parallel_with_resolutions(
+    program: &MergedProgram,
+    checker_options: &CheckerOptions,
+    lib_files: &[Arc<LibFile>],
+    host_resolutions: &FxHashMap<(usize, String), usize>,
+) -> CheckResult {
+    run_parallel_check(program, checker_options, lib_files, host_resolutions, None)
+}
+
+/// [`check_files_parallel_with_resolutions`] for an incremental re-check.
+///
+/// Only the files whose indices are in `files_to_check` are checked; every
+/// other file gets an empty result, for callers that kept the diagnostics of
+/// an earlier check and know those files are unaffected by the edit.
+pub fn check_files_parallel_subset(
+    program: &MergedProgram,
+    checker_options: &CheckerOptions,
+    lib_files: &[Arc<LibFile>],
+    host_resolutions: &FxHashMap<(usize, String), usize>,
+    files_to_check: &FxHashSet<usize>,
+) -> CheckResult {
+    run_parallel_check(
+        program,
+        checker_options,
+        lib_files,
+        host_resolutions,
+        Some(files_to_check),
+    )
+}
+
+/// A user file of a merged program after a full check, with what
+/// language-service providers (hover, completions) need to run their own
+/// checker over it and see the same cross-file types.
+pub struct CheckedFileContext {
+    /// The binder the file was checked with.
+    pub binder: Arc<BinderState>,
+    /// Types computed while checking the file, interned in the program's
+    /// `type_interner`.
+    pub type_cache: crate::checker::TypeCache,
+    /// Lib contexts for global types.
+    pub lib_contexts: Arc<Vec<LibContext>>,
+}
+
+/// The checker types declaration emit reads, copied out of `cache`.
+pub fn type_cache_view(cache: &crate::checker::TypeCache) -> tsz_emitter::type_cache_view::TypeCacheView {
+    tsz_emitter::type_cache_view::TypeCacheView {
+        node_types: cache.node_types.to_hash_map(),
+        symbol_types: cache.symbol_types.to_hash_map(),
+        def_to_symbol: cache.def_to_symbol.clone(),
+        def_types: cache.def_types.clone(),
+        def_type_params: cache.def_type_params.clone(),
+        boxed_types: cache.boxed_types.clone(),
+        boxed_def_ids: cache.boxed_def_ids.clone(),
+        well_known_symbol_names: cache.well_known_symbol_names.clone(),
+        def_to_name: cache.def_to_name.clone(),
+    }
+}
+
+/// Check the user file at `file_idx` with the same cross-file context as
+/// [`check_files_parallel_with_resolutions`] and keep its types for
+/// language-service queries. `None` when `file_idx` is out of range.
+pub fn check_file_for_language_service(
+    program: &MergedProgram,
+    checker_options: &CheckerOptions,
+    lib_files: &[Arc<LibFile>],
+    host_resolutions: &FxHashMap<(usize, String), usize>,
+    file_idx: usize,
+) -> Option<CheckedFileContext> {
+    let file = program.files.get(file_idx)?;
+    ensure_rayon_global_pool();
+
+    let plan = ParallelCheckPlan::build(program, checker_options, lib_files, host_resolutions);
+    let query_cache = plan.make_query_cache();
+    let mut checker = plan.file_checker(file_idx, file, &query_cache);
+    checker.check_source_file(file.source_file);
+    Some(CheckedFileContext {
+        binder: Arc::clone(&plan.all_binders[file_idx]),
+        type_cache: checker.extract_cache(),
+        lib_contexts: Arc::clone(&plan.lib_contexts),
+    })
+}
+
+/// Like [`check_files_parallel_subset`] (or a full check when
+/// `files_to_check` is `None`), but checks the user files one at a time on
+/// the calling thread and reports each to `on_file` as soon as it finishes,
+/// before lib checks and the cross-file passes run. Those passes can still
+/// change a file's diagnostics, so the returned result is authoritative.
+pub fn check_files_streaming(
+    program: &MergedProgram,
+    checker_options: &CheckerOptions,
+    lib_files: &[Arc<LibFile>],
+    host_resolutions: &FxHashMap<(usize, String), usize>,
+    files_to_check: Option<&FxHashSet<usize>>,
+    on_file: &mut dyn FnMut(&FileCheckResult),
+) -> CheckResult {
+    check_files_streaming_cancellable(
+        program,
+        checker_options,
+        lib_files,
+        host_resolutions,
+        files_to_check,
+        on_file,
+        None,
+    )
+    .expect("a check without a cancellation token runs to completion")
+}
+
+/// [`check_files_streaming`] that polls `cancellation` before each file and
+/// gives up, returning `None`, once it is cancelled.
+pub fn check_files_streaming_cancellable(
+    program: &MergedProgram,
+    checker_options: &CheckerOptions,
+    lib_files: &[Arc<LibFile>],
+    host_resolutions: &FxHashMap<(usize, String), usize>,
+    files_to_check: Option<&FxHashSet<usize>>,
+    on_file: &mut dyn FnMut(&FileCheckResult),
+    cancellation: Option<&CancellationToken>,
+) -> Option<CheckResult> {
+    let is_cancelled = || cancellation.is_some_and(CancellationToken::is_cancelled);
+    ensure_rayon_global_pool();
+
+    let plan = ParallelCheckPlan::build(program, checker_options, lib_files, host_resolutions);
+    let mut file_results = Vec::with_capacity(program.files.len());
+    for (file_idx, file) in program.files.iter().enumerate() {
+        if is_cancelled() {
+            return None;
+        }
+        let file_result = if files_to_check.is_some_and(|files| !files.contains(&file_idx)) {
+            FileCheckResult {
+                file_idx,
+                file_name: file.file_name.clone(),
+                function_results: Vec::new(),
+                diagnostics: Vec::new(),
+            }
+        } else {
+            let file_result = plan.check_one_file(file_idx, file);
+            on_file(&file_result);
+            file_result
+        };
+        file_results.push(file_result);
+    }
+    if is_cancelled() {
+        return None;
+    }
+    plan.run_lib_checks(&mut file_results);
+    Some(plan.aggregate(file_results))
+}
+
+/// A merged program prepared for checking its user files one at a time,
+/// from any number of threads, as [`check_files_parallel_with_resolutions`]
+/// would check them. The shared inputs are computed once by
+/// [`FileCheckSession::new`]; callers schedule [`FileCheckSession::check_file`]
+/// themselves (e.g. across Web Workers sharing wasm memory) and fold the
+/// results with [`FileCheckSession::finish`].
+pub struct FileCheckSession {
+    plan: ParallelCheckPlan<Arc<MergedProgram>, Arc<CheckerOptions>>,
+}
+
+impl FileCheckSession {
+    pub fn new(
+        program: Arc<MergedProgram>,
+        checker_options: CheckerOptions,
+        lib_files: &[Arc<LibFile>],
+        host_resolutions: &FxHashMap<(usize, String), usize>,
+    ) -> Self {
+        Self {
+            plan: ParallelCheckPlan::build(
+                program,
+                Arc::new(checker_options),
+                lib_files,
+                host_resolutions,
+            ),
+        }
+    }
+
+    /// Number of user files; `check_file` takes indices below this.
+    pub fn file_count(&self) -> usize {
+        self.plan.program.files.len()
+    }
+
+    /// Check the user file at `file_idx`. `None` when it is out of range.
+    pub fn check_file(&self, file_idx: usize) -> Option<FileCheckResult> {
+        let file = self.plan.program.files.get(file_idx)?;
+        Some(self.plan.check_one_file(file_idx, file))
+    }
+
+    /// Run the lib checks and cross-file passes over `file_results`, which
+    /// must hold one result per user file in file order.
+    pub fn finish(&self, mut file_results: Vec<FileCheckResult>) -> CheckResult {
+        self.plan.run_lib_checks(&mut file_results);
+        self.plan.aggregate(file_results)
+    }
+
+    /// The program being checked; types returned by queries are interned in
+    /// its `type_interner`.
+    pub fn program(&self) -> &MergedProgram {
+        &self.plan.program
+    }
+
+    /// The binder user file `file_idx` is checked with.
+    pub fn binder(&self, file_idx: usize) -> Option<&BinderState> {
+        self.plan.all_binders.get(file_idx).map(AsRef::as_ref)
+    }
+
+    /// Run `query` with a checker for the user file at `file_idx` and return
+    /// its result along with the checker's types, to pass back as `cache` on
+    /// the next query of the same file. Without a cache the file is fully
+    /// checked first, so queries see the types its check computed. `None`
+    /// when `file_idx` is out of range.
+    pub fn query_file<R>(
+        &self,
+        file_idx: usize,
+        cache: Option<crate::checker::TypeCache>,
+        query: impl FnOnce(&mut CheckerState<'_>) -> R,
+    ) -> Option<(R, crate::checker::TypeCache)> {
+        let file = self.plan.program.files.get(file_idx)?;
+        let query_cache = self.plan.make_query_cache();
+        let mut checker = match cache {
+            Some(cache) => self
+                .plan
+                .cached_file_checker(file_idx, file, &query_cache, cache),
+            None => {
+                let mut checker = self.plan.file_checker(file_idx, file, &query_cache);
+                checker.check_source_file(file.source_file);
+                checker
+            }
+        };
+        let result = query(&mut checker);
+        Some((result, checker.extract_cache()))
+    }
+}
+
+fn run_parallel_check(
+    program: &MergedProgram,
+    checker_options: &CheckerOptions,
+    lib_files: &[Arc<LibFile>],
+    host_resolutions: &FxHashMap<(usize, String), usize>,
+    files_to_check: Option<&FxHashSet<usize>>,
 ) -> CheckResult {
     // Ensure Rayon global pool has adequate stack size for deep type-checking recursion.
     ensure_rayon_global_pool();
 
-    let plan = ParallelCheckPlan::build(program, checker_options, lib_files);
-    let mut file_results = plan.run_file_checks();
+    let plan = ParallelCheckPlan::build(program, checker_options, lib_files, host_resolutions);
+    let mut file_results = plan.run_file_checks(files_to_check);
     plan.run_lib_checks(&mut file_results);
     plan.aggregate(file_results)
 }
@@ -409,9 +663,12 @@ fn lib_diagnostic_fingerprint(file_name: &str, diag: &Diagnostic) -> (String, u3
 /// scheduling/diagnostics boundary explicit: `build` produces the plan, the
 /// `check_one_*` workers consume it, and [`ParallelCheckPlan::aggregate`] folds
 /// the per-file results into the final [`CheckResult`].
-struct ParallelCheckPlan<'a> {
-    program: &'a MergedProgram,
-    checker_options: &'a CheckerOptions,
+///
+/// The program and options are usually borrowed for one check; a
+/// [`FileCheckSession`] owns them to keep the plan across calls.
+struct ParallelCheckPlan<P, O> {
+    program: P,
+    checker_options: O,
     resolved_module_paths: Arc<FxHashMap<(usize, String), usize>>,
     resolved_modules: Arc<FxHashSet<String>>,
     checker_lib_files: Vec<Arc<LibFile>>,
@@ -425,20 +682,30 @@ struct ParallelCheckPlan<'a> {
     affected_lib_extension_interfaces: FxHashSet<String>,
 }
 
-impl<'a> ParallelCheckPlan<'a> {
+impl<P, O> ParallelCheckPlan<P, O>
+where
+    P: std::ops::Deref<Target = MergedProgram> + Sync,
+    O: std::ops::Deref<Target = CheckerOptions> + Sync,
+{
     /// Compute every shared input the parallel workers need, exactly once.
     fn build(
-        program: &'a MergedProgram,
-        checker_options: &'a CheckerOptions,
+        program_handle: P,
+        checker_options: O,
         lib_files: &[Arc<LibFile>],
+        host_resolutions: &FxHashMap<(usize, String), usize>,
     ) -> Self {
+        let program: &MergedProgram = &program_handle;
         let file_names: Vec<String> = program
             .files
             .iter()
             .map(|file| file.file_name.clone())
             .collect();
-        let (resolved_module_paths, resolved_modules) =
+        let (mut resolved_module_paths, mut resolved_modules) =
             crate::checker::module_resolution::build_module_resolution_maps(&file_names);
+        for (key, &target_idx) in host_resolutions {
+            resolved_modules.insert(key.1.clone());
+            resolved_module_paths.insert(key.clone(), target_idx);
+        }
         let resolved_module_paths = Arc::new(resolved_module_paths);
         let resolved_modules = Arc::new(resolved_modules);
 
@@ -518,9 +785,9 @@ impl<'a> ParallelCheckPlan<'a> {
         let shared_declared_modules: Option<Arc<crate::checker::context::GlobalDeclaredModules>> =
             program.skeleton_index.as_ref().map(|skel| {
                 let (exact, patterns) = skel.build_declared_module_sets();
-                Arc::new(crate::checker::context::GlobalDeclaredModules::from_skeleton(
-                    exact, patterns,
-                ))
+                Arc::new(
+                    crate::checker::context::GlobalDeclaredModules::from_skeleton(exact, patterns),
+                )
             });
 
         // Initialize per-file delegation locks for parallel correctness.
@@ -551,7 +818,7 @@ impl<'a> ParallelCheckPlan<'a> {
         };
 
         Self {
-            program,
+            program: program_handle,
             checker_options,
             resolved_module_paths,
             resolved_modules,
@@ -574,7 +841,10 @@ impl<'a> ParallelCheckPlan<'a> {
     /// overhead) for memoized `evaluate_type` / `is_subtype_of` calls.
     fn make_query_cache(&self) -> tsz_solver::construction::QueryCache<'_> {
         if let Some(ref shared) = self.shared_query_cache {
-            tsz_solver::construction::QueryCache::new_with_shared(&self.program.type_interner, shared)
+            tsz_solver::construction::QueryCache::new_with_shared(
+                &self.program.type_interner,
+                shared,
+            )
         } else {
             tsz_solver::construction::QueryCache::new(&self.program.type_interner)
         }
@@ -605,18 +875,73 @@ impl<'a> ParallelCheckPlan<'a> {
 
     /// Check a single user file, returning its sorted, deduplicated diagnostics.
     fn check_one_file(&self, file_idx: usize, file: &BoundFile) -> FileCheckResult {
-        let binder = Arc::clone(&self.all_binders[file_idx]);
-
         let query_cache = self.make_query_cache();
+        let mut checker = self.file_checker(file_idx, file, &query_cache);
+        checker.check_source_file(file.source_file);
 
-        let mut checker = CheckerState::with_options_and_shared_def_store(
+        let mut diagnostics = std::mem::take(&mut checker.ctx.diagnostics);
+
+        // Sort diagnostics by position for deterministic output within each file.
+        diagnostics.sort_by(|a, b| a.compare(b));
+
+        suppress_parallel_ts2339_cascade_diagnostics(file.arena.as_ref(), &mut diagnostics);
+
+        // Deduplicate within each file: same (start, code) = same diagnostic.
+        diagnostics.dedup_by(|a, b| a.start == b.start && a.code == b.code);
+
+        FileCheckResult {
+            file_idx,
+            file_name: file.file_name.clone(),
+            function_results: Vec::new(),
+            diagnostics,
+        }
+    }
+
+    /// A checker for the user file at `file_idx`, wired to the program's
+    /// binders, arenas, module resolutions and lib contexts.
+    fn file_checker<'q>(
+        &'q self,
+        file_idx: usize,
+        file: &'q BoundFile,
+        query_cache: &'q tsz_solver::construction::QueryCache<'q>,
+    ) -> CheckerState<'q> {
+        let checker = CheckerState::with_options_and_shared_def_store(
             &file.arena,
-            binder.as_ref(),
-            &query_cache,
+            self.all_binders[file_idx].as_ref(),
+            query_cache,
+            file.file_name.clone(),
+            &self.checker_options,
+            std::sync::Arc::clone(&self.program.definition_store),
+        );
+        self.wire_file_checker(file_idx, checker)
+    }
+
+    /// Like [`Self::file_checker`], but starting from the types a previous
+    /// checker of the same file extracted.
+    fn cached_file_checker<'q>(
+        &'q self,
+        file_idx: usize,
+        file: &'q BoundFile,
+        query_cache: &'q tsz_solver::construction::QueryCache<'q>,
+        cache: crate::checker::TypeCache,
+    ) -> CheckerState<'q> {
+        let checker = CheckerState::with_cache_and_shared_def_store(
+            &file.arena,
+            self.all_binders[file_idx].as_ref(),
+            query_cache,
             file.file_name.clone(),
-            self.checker_options,
+            cache,
+            (*self.checker_options).clone(),
             std::sync::Arc::clone(&self.program.definition_store),
         );
+        self.wire_file_checker(file_idx, checker)
+    }
+
+    fn wire_file_checker<'q>(
+        &'q self,
+        file_idx: usize,
+        mut checker: CheckerState<'q>,
+    ) -> CheckerState<'q> {
         checker.ctx.set_all_arenas(Arc::clone(&self.all_arenas));
 
         // Use pre-computed skeleton-derived declared modules (shared via Arc::clone).
@@ -642,27 +967,11 @@ impl<'a> ParallelCheckPlan<'a> {
             checker
                 .ctx
                 .set_lib_contexts_shared(Arc::clone(&self.lib_contexts));
-            checker.ctx.set_actual_lib_file_count(self.lib_contexts.len());
-        }
-
-        checker.check_source_file(file.source_file);
-
-        let mut diagnostics = std::mem::take(&mut checker.ctx.diagnostics);
-
-        // Sort diagnostics by position for deterministic output within each file.
-        diagnostics.sort_by(|a, b| a.compare(b));
-
-        suppress_parallel_ts2339_cascade_diagnostics(file.arena.as_ref(), &mut diagnostics);
-
-        // Deduplicate within each file: same (start, code) = same diagnostic.
-        diagnostics.dedup_by(|a, b| a.start == b.start && a.code == b.code);
-
-        FileCheckResult {
-            file_idx,
-            file_name: file.file_name.clone(),
-            function_results: Vec::new(),
-            diagnostics,
+            checker
+                .ctx
+                .set_actual_lib_file_count(self.lib_contexts.len());
         }
+        checker
     }
 
     /// Check a single lib file against the merged program's affected interfaces.
@@ -674,15 +983,12 @@ impl<'a> ParallelCheckPlan<'a> {
         let query_cache = self.make_query_cache();
 
         let lib_bound_file = build_lib_bound_file_for_interface_checks(
-            self.program,
+            &self.program,
             lib_file,
             &self.affected_lib_interfaces,
         );
-        let mut binder = create_binder_from_bound_file(
-            &lib_bound_file,
-            self.program,
-            self.program.files.len(),
-        );
+        let mut binder =
+            create_binder_from_bound_file(&lib_bound_file, &self.program, self.program.files.len());
         // PERF: `build_lib_bound_file_for_interface_checks` always seeds
         // `lib_bound_file.semantic_defs` as empty, so the previous
         // clone-then-overlay collapsed to a deep clone of `program.semantic_defs`
@@ -705,7 +1011,7 @@ impl<'a> ParallelCheckPlan<'a> {
             &binder,
             &query_cache,
             lib_bound_file.file_name.clone(),
-            self.checker_options,
+            &self.checker_options,
         );
         checker.ctx.set_all_arenas(Arc::clone(&self.all_arenas));
         if let Some(ref modules) = self.shared_declared_modules {
@@ -732,7 +1038,9 @@ impl<'a> ParallelCheckPlan<'a> {
             .map(|(_, ctx)| ctx.clone())
             .collect();
         checker.ctx.set_lib_contexts(other_lib_contexts);
-        checker.ctx.set_actual_lib_file_count(self.lib_contexts.len());
+        checker
+            .ctx
+            .set_actual_lib_file_count(self.lib_contexts.len());
         checker.prime_boxed_types();
 
         checker.check_source_file_interfaces_only_filtered_post_merge(
@@ -764,7 +1072,7 @@ impl<'a> ParallelCheckPlan<'a> {
             lib_file.binder.as_ref(),
             &query_cache,
             lib_file.file_name.clone(),
-            self.checker_options,
+            &self.checker_options,
         );
         let other_lib_contexts: Vec<LibContext> = self
             .lib_contexts
@@ -774,7 +1082,9 @@ impl<'a> ParallelCheckPlan<'a> {
             .map(|(_, ctx)| ctx.clone())
             .collect();
         checker.ctx.set_lib_contexts(other_lib_contexts);
-        checker.ctx.set_actual_lib_file_count(self.lib_contexts.len());
+        checker
+            .ctx
+            .set_actual_lib_file_count(self.lib_contexts.len());
         checker.prime_boxed_types();
         checker.check_source_file_interfaces_only_filtered_post_merge(
             lib_file.root_index,
@@ -789,8 +1099,20 @@ impl<'a> ParallelCheckPlan<'a> {
     }
 
     /// Run the per-user-file checks, sequentially for a single file and in
-    /// parallel (preserving `file_idx` order) otherwise.
-    fn run_file_checks(&self) -> Vec<FileCheckResult> {
+    /// parallel (preserving `file_idx` order) otherwise. Files outside
+    /// `files_to_check`, when given, get an empty result.
+    fn run_file_checks(&self, files_to_check: Option<&FxHashSet<usize>>) -> Vec<FileCheckResult> {
+        let check = |file_idx: usize, file: &BoundFile| {
+            if files_to_check.is_some_and(|files| !files.contains(&file_idx)) {
+                return FileCheckResult {
+                    file_idx,
+                    file_name: file.file_name.clone(),
+                    function_results: Vec::new(),
+                    diagnostics: Vec::new(),
+                };
+            }
+            self.check_one_file(file_idx, file)
+        };
         // Single-file optimization: skip Rayon overhead when there's only one file.
         // For multi-file projects, use parallel iteration via Rayon's work-stealing
         // scheduler. `par_iter().enumerate()` preserves input ordering (file_idx) so
@@ -800,12 +1122,12 @@ impl<'a> ParallelCheckPlan<'a> {
                 .files
                 .iter()
                 .enumerate()
-                .map(|(file_idx, file)| self.check_one_file(file_idx, file))
+                .map(|(file_idx, file)| check(file_idx, file))
                 .collect()
         } else {
             maybe_parallel_iter!(self.program.files)
                 .enumerate()
-                .map(|(file_idx, file)| self.check_one_file(file_idx, file))
+                .map(|(file_idx, file)| check(file_idx, file))
                 .collect()
         }
     }
@@ -857,16 +1179,19 @@ impl<'a> ParallelCheckPlan<'a> {
     /// the final [`CheckResult`].
     fn aggregate(&self, mut file_results: Vec<FileCheckResult>) -> CheckResult {
         add_reexported_module_augmentation_enum_conflict_diagnostics(
-            self.program,
+            &self.program,
             self.resolved_module_paths.as_ref(),
             &mut file_results,
         );
         suppress_parallel_import_shadowing_namespace_type_diagnostics(
-            self.program,
+            &self.program,
             self.resolved_module_paths.as_ref(),
             &mut file_results,
         );
-        add_parallel_global_augmentation_member_conflict_diagnostics(self.program, &mut file_results);
+        add_parallel_global_augmentation_member_conflict_diagnostics(
+            &self.program,
+            &mut file_results,
+        );
 
         let diagnostic_count: usize = file_results.iter().map(|r| r.diagnostics.len()).sum();
 
@@ -1016,6 +1341,7 @@ pub fn create_binder_from_bound_file(
 
     binder.is_external_module = file.is_external_module;
     binder.file_features = file.file_features;
+    binder.commonjs_exports = Arc::clone(&file.commonjs_exports);
     binder.lib_symbol_reverse_remap = file.lib_symbol_reverse_remap.clone();
     binder.lib_binders = program.lib_binders.clone();
     binder.lib_symbol_ids = program.lib_symbol_ids.clone();
@@ -1112,6 +1438,7 @@ pub fn create_binder_from_bound_file_with_shared(
 
     binder.is_external_module = file.is_external_module;
     binder.file_features = file.file_features;
+    binder.commonjs_exports = Arc::clone(&file.commonjs_exports);
     binder.lib_symbol_reverse_remap = file.lib_symbol_reverse_remap.clone();
     binder.lib_binders = program.lib_binders.clone();
     binder.lib_symbol_ids = program.lib_symbol_ids.clone();
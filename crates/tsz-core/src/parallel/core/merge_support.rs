@@ -179,6 +179,9 @@ pub struct BoundFile {
     /// `Arc::clone` instead of deep-cloning the nested map. Read-only
     /// after `bind_source_file` completes.
     pub expando_properties: Arc<FxHashMap<String, FxHashSet<String>>>,
+    /// CommonJS export assignments recorded for JS files. Keyed by export
+    /// name, so no `SymbolId` remapping is needed.
+    pub commonjs_exports: Arc<FxHashMap<String, Vec<NodeIndex>>>,
     pub file_features: crate::binder::FileFeatures,
     /// Reverse mapping for merged lib symbols: remapped `SymbolId` ->
     /// (`lib_binder_idx`, original lib-local `SymbolId`).
@@ -399,6 +402,14 @@ pub struct MergedProgram {
     /// (cheap atomic increment) instead of deep-cloning the
     /// `FxHashSet` for each of N per-file binders.
     pub lib_symbol_ids: Arc<FxHashSet<SymbolId>>,
+    /// Content-stable identity of each merged symbol. Unlike `SymbolId`s,
+    /// which follow merge order, these survive changes to the file set; see
+    /// `StableSymbolId`. The CLI's incremental cache compares them across
+    /// rebuilds to drop cached symbol types whose `SymbolId` was reassigned.
+    pub stable_symbol_ids: Arc<FxHashMap<SymbolId, StableSymbolId>>,
+    /// Reverse of `stable_symbol_ids`, for translating persisted identities
+    /// back to this program's `SymbolId`s.
+    pub symbols_by_stable_id: Arc<FxHashMap<StableSymbolId, SymbolId>>,
     /// Global type interner - shared across all threads for type deduplication
     pub type_interner: TypeInterner,
     /// Alias partners: maps `TYPE_ALIAS` `SymbolId` → `ALIAS` `SymbolId` for merged type+namespace exports.
@@ -454,6 +465,19 @@ impl MergedProgram {
         self.dep_graph.as_ref().map(|dg| dg.topological_order())
     }
 
+    /// Content-stable identity of `sym_id`, if it was contributed by a parsed file.
+    #[must_use]
+    pub fn stable_symbol_id(&self, sym_id: SymbolId) -> Option<StableSymbolId> {
+        self.stable_symbol_ids.get(&sym_id).copied()
+    }
+
+    /// This program's `SymbolId` for a persisted `StableSymbolId`, or `None`
+    /// when the declaring file changed or left the program.
+    #[must_use]
+    pub fn symbol_for_stable_id(&self, stable: StableSymbolId) -> Option<SymbolId> {
+        self.symbols_by_stable_id.get(&stable).copied()
+    }
+
     /// Return the set of file indices that directly depend on the given file.
     ///
     /// These are files that `import` from the target file. Useful for
@@ -816,6 +840,7 @@ fn release_consumed_bind_result(result: &mut BindResult) {
     result.node_flow = Arc::new(FxHashMap::default());
     result.switch_clause_to_switch = Arc::new(FxHashMap::default());
     result.expando_properties = Arc::new(FxHashMap::default());
+    result.commonjs_exports = Arc::new(FxHashMap::default());
     result.alias_partners = Arc::new(FxHashMap::default());
     result.file_features = crate::binder::FileFeatures::default();
     result.semantic_defs = Arc::new(FxHashMap::default());
@@ -35,226 +35,13 @@ pub(crate) fn build_sym_to_decl_indices(
     sym_to_decl_indices
 }
 
-/// Validate JSON syntax and return parse diagnostics for violations.
-///
-/// TypeScript's JSON parser enforces strict JSON rules when parsing `.json` files.
-/// This validates property names must be double-quoted string literals (TS1327).
-/// Violations include single-quoted strings, computed property names (`[expr]`),
-/// and unquoted identifiers used as property names.
-fn validate_json_syntax(source: &str) -> Vec<ParseDiagnostic> {
-    let mut diagnostics = Vec::new();
-    let bytes = source.as_bytes();
-    let len = bytes.len();
-    let mut i = 0;
-
-    let is_ws = |b: u8| matches!(b, b' ' | b'\t' | b'\n' | b'\r');
-    let is_ident_start = |b: u8| b.is_ascii_alphabetic() || b == b'_' || b == b'$';
-    let is_ident_part = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || b == b'$';
-
-    // Root-level recovery for invalid bare identifier runs in JSON files.
-    // tsc emits a specific sequence:
-    //   first identifier  -> TS1005 "'{' expected." + TS1136
-    //   next identifiers  -> TS1005 "',' expected." + TS1136
-    //   end of run        -> TS1005 "'}' expected."
-    //
-    // Valid JSON roots `true` / `false` / `null` are explicitly allowed.
-    let mut j = 0usize;
-    while j < len && is_ws(bytes[j]) {
-        j += 1;
-    }
-    if j < len && is_ident_start(bytes[j]) {
-        let mut spans: Vec<(usize, usize)> = Vec::new();
-        let mut k = j;
-        loop {
-            let start = k;
-            while k < len && is_ident_part(bytes[k]) {
-                k += 1;
-            }
-            spans.push((start, k));
-
-            while k < len && is_ws(bytes[k]) {
-                k += 1;
-            }
-
-            if k < len && is_ident_start(bytes[k]) {
-                continue;
-            }
-            break;
-        }
-
-        if k >= len {
-            let single_keyword_root = spans.len() == 1
-                && std::str::from_utf8(&bytes[spans[0].0..spans[0].1])
-                    .map(|s| matches!(s, "true" | "false" | "null"))
-                    .unwrap_or(false);
-
-            if !single_keyword_root {
-                for (idx, (start, _end)) in spans.iter().enumerate() {
-                    let expected_msg = if idx == 0 {
-                        "'{' expected."
-                    } else {
-                        "',' expected."
-                    };
-                    diagnostics.push(ParseDiagnostic {
-                        start: *start as u32,
-                        length: 1,
-                        message: expected_msg.to_string(),
-                        code: tsz_common::diagnostics::diagnostic_codes::EXPECTED,
-                    });
-                    diagnostics.push(ParseDiagnostic {
-                        start: *start as u32,
-                        length: 1,
-                        message: tsz_common::diagnostics::diagnostic_messages::PROPERTY_ASSIGNMENT_EXPECTED.to_string(),
-                        code: tsz_common::diagnostics::diagnostic_codes::PROPERTY_ASSIGNMENT_EXPECTED,
-                    });
-                }
-                if let Some((_, end)) = spans.last() {
-                    diagnostics.push(ParseDiagnostic {
-                        start: *end as u32,
-                        length: 1,
-                        message: "'}' expected.".to_string(),
-                        code: tsz_common::diagnostics::diagnostic_codes::EXPECTED,
-                    });
-                }
-            }
-        }
-    }
-
-    // Track whether we're inside an object and expecting a property name.
-    // JSON property names must be double-quoted strings per the JSON spec.
-    // We use a simple state machine: after `{` or `,` inside an object,
-    // the next non-whitespace token must be `"` (property name) or `}` (end).
-    let mut object_depth: i32 = 0;
-    let mut array_depth: i32 = 0;
-    let mut expecting_property_name = false;
-
-    while i < len {
-        let b = bytes[i];
-
-        // Skip whitespace
-        if b == b' ' || b == b'\t' || b == b'\n' || b == b'\r' {
-            i += 1;
-            continue;
-        }
-
-        if b == b'{' {
-            object_depth += 1;
-            expecting_property_name = true;
-            i += 1;
-            continue;
-        }
-
-        if b == b'}' {
-            object_depth -= 1;
-            expecting_property_name = false;
-            i += 1;
-            continue;
-        }
-
-        if b == b'[' && !expecting_property_name {
-            array_depth += 1;
-            i += 1;
-            continue;
-        }
-
-        if b == b']' && array_depth > 0 {
-            array_depth -= 1;
-            i += 1;
-            continue;
-        }
-
-        if b == b',' {
-            // After a comma inside an object (not array), expect a property name
-            if object_depth > array_depth {
-                expecting_property_name = true;
-            }
-            i += 1;
-            continue;
-        }
-
-        if b == b':' {
-            expecting_property_name = false;
-            i += 1;
-            continue;
-        }
-
-        // When expecting a property name, check what we got
-        if expecting_property_name && object_depth > 0 {
-            if b == b'"' {
-                // Valid double-quoted property name - skip past the string
-                expecting_property_name = false;
-                i += 1;
-                while i < len {
-                    if bytes[i] == b'\\' {
-                        i += 2; // skip escape sequence
-                    } else if bytes[i] == b'"' {
-                        i += 1;
-                        break;
-                    } else {
-                        i += 1;
-                    }
-                }
-                continue;
-            }
-
-            // Not a double-quoted string in property name position → TS1327
-            diagnostics.push(ParseDiagnostic {
-                start: i as u32,
-                length: 1,
-                message: tsz_common::diagnostics::diagnostic_messages::STRING_LITERAL_WITH_DOUBLE_QUOTES_EXPECTED.to_string(),
-                code: tsz_common::diagnostics::diagnostic_codes::STRING_LITERAL_WITH_DOUBLE_QUOTES_EXPECTED,
-            });
-            expecting_property_name = false;
-        }
-
-        // Skip over strings (both single and double quoted) to avoid false matches
-        if b == b'"' || b == b'\'' {
-            i += 1;
-            while i < len {
-                if bytes[i] == b'\\' {
-                    i += 2;
-                } else if bytes[i] == b {
-                    i += 1;
-                    break;
-                } else {
-                    i += 1;
-                }
-            }
-            continue;
-        }
-
-        i += 1;
-    }
-
-    diagnostics
-}
-
-fn synthesize_json_bind_result(file_name: String, source_text: String) -> BindResult {
-    let parse_diagnostics = validate_json_syntax(&source_text);
-
-    let mut arena = NodeArena::new();
-    let end_pos = source_text.len() as u32;
-    let eof_token = arena.add_token(SyntaxKind::EndOfFileToken as u16, end_pos, end_pos);
-    let source_file = arena.add_source_file(
-        0,
-        end_pos,
-        SourceFileData {
-            statements: NodeList::default(),
-            end_of_file_token: eof_token,
-            file_name: file_name.clone(),
-            text: Arc::<str>::from(source_text),
-            language_version: 99,
-            language_variant: 0,
-            script_kind: 3,
-            is_declaration_file: false,
-            has_no_default_lib: false,
-            comments: Vec::new(),
-            parent: NodeIndex::NONE,
-            id: 0,
-            modifier_flags: 0,
-            transform_flags: 0,
-        },
-    );
+/// Parse a `.json` file as tsc's `parseJsonText` does and bind it. JSON
+/// files declare nothing; the checker reads their value when they are
+/// imported with `resolveJsonModule`.
+fn parse_and_bind_json_file(file_name: String, source_text: String) -> BindResult {
+    let mut parser = ParserState::new(file_name.clone(), source_text);
+    let source_file = parser.parse_json_text(JsonParseOptions::JSONC);
+    let (arena, parse_diagnostics) = parser.into_parts();
 
     let mut binder = BinderState::new();
     binder.set_debug_file(&file_name);
@@ -291,6 +78,7 @@ fn synthesize_json_bind_result(file_name: String, source_text: String) -> BindRe
         switch_clause_to_switch: std::mem::take(&mut binder.switch_clause_to_switch),
         is_external_module: binder.is_external_module,
         expando_properties: std::mem::take(&mut binder.expando_properties),
+        commonjs_exports: std::mem::take(&mut binder.commonjs_exports),
         alias_partners: binder.alias_partners,
         file_features: binder.file_features,
         semantic_defs: binder.semantic_defs,
@@ -616,6 +404,9 @@ pub struct BindResult {
     /// `Arc`-wrapped to mirror `BinderState.expando_properties` so the
     /// merge can move it into per-file binders without deep-cloning.
     pub expando_properties: Arc<FxHashMap<String, FxHashSet<String>>>,
+    /// CommonJS export assignments recorded for JS files; mirrors
+    /// `BinderState.commonjs_exports`.
+    pub commonjs_exports: Arc<FxHashMap<String, Vec<NodeIndex>>>,
     /// Per-file alias partners from binder (`TYPE_ALIAS` → `ALIAS` mapping, pre-remap)
     pub alias_partners: Arc<FxHashMap<SymbolId, SymbolId>>,
     pub file_features: crate::binder::FileFeatures,
@@ -848,7 +639,7 @@ pub fn parse_and_bind_parallel(files: Vec<(String, String)>) -> Vec<BindResult>
             // JSON module imports should be resolved during module resolution and
             // emit TS2732 if resolveJsonModule is false.
             if file_name.ends_with(".json") {
-                return synthesize_json_bind_result(file_name, source_text);
+                return parse_and_bind_json_file(file_name, source_text);
             }
 
             // Parse
@@ -893,6 +684,7 @@ pub fn parse_and_bind_parallel(files: Vec<(String, String)>) -> Vec<BindResult>
                 switch_clause_to_switch: std::mem::take(&mut binder.switch_clause_to_switch),
                 is_external_module: binder.is_external_module,
                 expando_properties: std::mem::take(&mut binder.expando_properties),
+                commonjs_exports: std::mem::take(&mut binder.commonjs_exports),
                 alias_partners: binder.alias_partners,
                 file_features: binder.file_features,
                 semantic_defs: binder.semantic_defs,
@@ -905,7 +697,7 @@ pub fn parse_and_bind_parallel(files: Vec<(String, String)>) -> Vec<BindResult>
 /// Bind a single file (for comparison/testing)
 pub fn parse_and_bind_single(file_name: String, source_text: String) -> BindResult {
     if file_name.ends_with(".json") {
-        return synthesize_json_bind_result(file_name, source_text);
+        return parse_and_bind_json_file(file_name, source_text);
     }
 
     let mut parser = ParserState::new(file_name.clone(), source_text);
@@ -948,6 +740,7 @@ pub fn parse_and_bind_single(file_name: String, source_text: String) -> BindResu
         switch_clause_to_switch: std::mem::take(&mut binder.switch_clause_to_switch),
         is_external_module: binder.is_external_module,
         expando_properties: std::mem::take(&mut binder.expando_properties),
+        commonjs_exports: std::mem::take(&mut binder.commonjs_exports),
         alias_partners: binder.alias_partners,
         file_features: binder.file_features,
         semantic_defs: binder.semantic_defs,
@@ -1611,7 +1404,7 @@ fn bind_file_with_libs_with_language_version(
     // JSON module imports should be resolved during module resolution and
     // emit TS2732 if resolveJsonModule is false.
     if file_name.ends_with(".json") {
-        return synthesize_json_bind_result(file_name, source_text);
+        return parse_and_bind_json_file(file_name, source_text);
     }
 
     // Parse
@@ -1672,6 +1465,7 @@ fn bind_file_with_libs_with_language_version(
         switch_clause_to_switch: std::mem::take(&mut binder.switch_clause_to_switch),
         is_external_module: binder.is_external_module,
         expando_properties: std::mem::take(&mut binder.expando_properties),
+        commonjs_exports: std::mem::take(&mut binder.commonjs_exports),
         alias_partners: binder.alias_partners,
         file_features: binder.file_features,
         semantic_defs: binder.semantic_defs,
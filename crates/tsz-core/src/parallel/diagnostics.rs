@@ -26,7 +26,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::binder::state::DeclarationArenaMap;
 use crate::binder::{FlowNodeArena, SymbolId};
-use crate::checker::diagnostics::Diagnostic;
+use crate::checker::diagnostics::{Diagnostic, sort_and_deduplicate_diagnostics};
 use crate::lib_loader::LibFile;
 use crate::parser::NodeIndex;
 use crate::parser::node::NodeArena;
@@ -263,8 +263,10 @@ pub(crate) fn add_reexported_module_augmentation_enum_conflict_diagnostics(
         }
     }
 
+    // Re-routed TS2567 diagnostics can repeat ones the target file already
+    // reported; drop exact duplicates while restoring order.
     for result in file_results {
-        result.diagnostics.sort_by(|a, b| a.compare(b));
+        sort_and_deduplicate_diagnostics(&mut result.diagnostics);
     }
 }
 
@@ -1280,6 +1282,7 @@ pub(crate) fn build_lib_bound_file_for_interface_checks(
         switch_clause_to_switch: Arc::new(FxHashMap::default()),
         is_external_module: lib_file.binder.is_external_module,
         expando_properties: Arc::new(FxHashMap::default()),
+        commonjs_exports: Arc::new(FxHashMap::default()),
         file_features: crate::binder::FileFeatures::NONE,
         lib_symbol_reverse_remap: Arc::new(FxHashMap::default()),
         semantic_defs: Arc::new(FxHashMap::default()),
@@ -36,6 +36,9 @@
 //! repopulate lazily on first lookup; this is the lazy-rebuild
 //! invariant established in PR #3.
 //!
+//! `encode_lib_set` / `decode_lib_set` write and read the same lib-set
+//! format in memory, for the WASM API to keep parsed libs in IndexedDB.
+//!
 //! # Enablement
 //!
 //! Cache reads + writes are enabled by default. Set `TSZ_LIB_CACHE=0`
@@ -43,6 +46,7 @@
 //! debugging, local bisects, or cache-behaviour comparisons.
 
 use anyhow::{Context, Result, anyhow};
+use bincode::Options;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -83,6 +87,27 @@ struct LibSnapshot {
     root_index: NodeIndex,
 }
 
+impl LibSnapshot {
+    fn new(file_name: &str, content_hash: u64, lib: &LibFile) -> Self {
+        Self {
+            file_name: file_name.to_string(),
+            content_hash,
+            arena: (*lib.arena).clone(),
+            binder: (*lib.binder).clone(),
+            root_index: lib.root_index,
+        }
+    }
+
+    fn into_lib_file(self) -> Arc<LibFile> {
+        Arc::new(LibFile::new(
+            self.file_name,
+            Arc::new(self.arena),
+            Arc::new(self.binder),
+            self.root_index,
+        ))
+    }
+}
+
 pub(super) fn content_hash(file_name: &str, source_text: &str) -> u64 {
     use std::hash::{Hash, Hasher};
     let mut hasher = rustc_hash::FxHasher::default();
@@ -189,12 +214,7 @@ pub(super) fn try_load(file_name: &str, source_text: &str) -> Option<Arc<LibFile
     if snapshot.content_hash != hash || snapshot.file_name != file_name {
         return None;
     }
-    Some(Arc::new(LibFile::new(
-        snapshot.file_name,
-        Arc::new(snapshot.arena),
-        Arc::new(snapshot.binder),
-        snapshot.root_index,
-    )))
+    Some(snapshot.into_lib_file())
 }
 
 /// Try to load an ordered set of cached lib snapshots from one cache file.
@@ -219,12 +239,7 @@ pub(super) fn try_load_many(keys: &[(&str, u64)]) -> Option<Vec<Arc<LibFile>>> {
         if snapshot.content_hash != *expected_hash || snapshot.file_name != *expected_name {
             return None;
         }
-        files.push(Arc::new(LibFile::new(
-            snapshot.file_name,
-            Arc::new(snapshot.arena),
-            Arc::new(snapshot.binder),
-            snapshot.root_index,
-        )));
+        files.push(snapshot.into_lib_file());
     }
     Some(files)
 }
@@ -243,15 +258,7 @@ pub(super) fn try_store(file_name: &str, source_text: &str, lib: &Arc<LibFile>)
     let hash = content_hash(file_name, source_text);
     let path = snapshot_path(&dir, hash);
 
-    let snapshot = LibSnapshot {
-        file_name: file_name.to_string(),
-        content_hash: hash,
-        arena: (*lib.arena).clone(),
-        binder: (*lib.binder).clone(),
-        root_index: lib.root_index,
-    };
-
-    let encoded = encode_snapshot(&snapshot)?;
+    let encoded = encode_snapshot(&LibSnapshot::new(file_name, hash, lib))?;
 
     // Atomic-rename pattern: write to a unique sibling temp file then
     // rename. Two concurrent processes that both miss may race here; the
@@ -281,16 +288,11 @@ pub(super) fn try_store_many(keys: &[(&str, u64)], libs: &[Arc<LibFile>]) -> Res
 
     let set_hash = lib_set_hash(keys);
     let path = snapshot_set_path(&dir, set_hash);
-    let mut files = Vec::with_capacity(libs.len());
-    for ((file_name, content_hash), lib) in keys.iter().zip(libs) {
-        files.push(LibSnapshot {
-            file_name: (*file_name).to_string(),
-            content_hash: *content_hash,
-            arena: (*lib.arena).clone(),
-            binder: (*lib.binder).clone(),
-            root_index: lib.root_index,
-        });
-    }
+    let files = keys
+        .iter()
+        .zip(libs)
+        .map(|((file_name, content_hash), lib)| LibSnapshot::new(file_name, *content_hash, lib))
+        .collect();
 
     let encoded = encode_snapshot_set(&LibSnapshotSet { set_hash, files })?;
     let tmp = snapshot_temp_path(&path);
@@ -306,6 +308,65 @@ pub(super) fn try_store_many(keys: &[(&str, u64)], libs: &[Arc<LibFile>]) -> Res
     Ok(())
 }
 
+/// Encode `(file_name, source_text, lib)` triples, in load order, as one
+/// lib-set snapshot without touching the disk cache.
+pub(crate) fn encode_lib_set(libs: &[(&str, &str, Arc<LibFile>)]) -> Result<Vec<u8>> {
+    let keys: Vec<(&str, u64)> = libs
+        .iter()
+        .map(|(file_name, source_text, _)| (*file_name, content_hash(file_name, source_text)))
+        .collect();
+    let files = keys
+        .iter()
+        .zip(libs)
+        .map(|((file_name, content_hash), (_, _, lib))| {
+            LibSnapshot::new(file_name, *content_hash, lib)
+        })
+        .collect();
+    encode_snapshot_set(&LibSnapshotSet {
+        set_hash: lib_set_hash(&keys),
+        files,
+    })
+}
+
+/// Decode a blob from [`encode_lib_set`] into each lib's source text and
+/// parsed file. Blobs from another format version, truncated ones and ones
+/// whose hashes do not match their contents are rejected.
+pub(crate) fn decode_lib_set(bytes: &[u8]) -> Result<Vec<(String, Arc<LibFile>)>> {
+    let snapshot = decode_snapshot_set(bytes)?;
+    let mut keys = Vec::with_capacity(snapshot.files.len());
+    let mut libs = Vec::with_capacity(snapshot.files.len());
+    for file in snapshot.files {
+        let source_text = file
+            .arena
+            .get(file.root_index)
+            .and_then(|root| file.arena.get_source_file(root))
+            .map(|source_file| source_file.text.to_string())
+            .ok_or_else(|| anyhow!("lib snapshot {} has no source file", file.file_name))?;
+        if file.content_hash != content_hash(&file.file_name, &source_text) {
+            return Err(anyhow!("lib snapshot {} hash mismatch", file.file_name));
+        }
+        keys.push((file.file_name.clone(), file.content_hash));
+        libs.push((source_text, file.into_lib_file()));
+    }
+    let keys: Vec<(&str, u64)> = keys
+        .iter()
+        .map(|(file_name, hash)| (file_name.as_str(), *hash))
+        .collect();
+    if snapshot.set_hash != lib_set_hash(&keys) {
+        return Err(anyhow!("lib snapshot set hash mismatch"));
+    }
+    Ok(libs)
+}
+
+/// Bincode options matching `bincode::serialize`, reading at most `limit`
+/// bytes so a corrupt length prefix fails instead of allocating.
+fn bincode_options(limit: usize) -> impl Options {
+    bincode::options()
+        .with_fixint_encoding()
+        .allow_trailing_bytes()
+        .with_limit(limit as u64)
+}
+
 fn encode_snapshot(snapshot: &LibSnapshot) -> Result<Vec<u8>> {
     let payload = bincode::serialize(snapshot).context("bincode serialize lib snapshot")?;
     let mut out = Vec::with_capacity(SNAPSHOT_MAGIC.len() + payload.len());
@@ -327,7 +388,9 @@ fn decode_snapshot(bytes: &[u8]) -> Result<LibSnapshot> {
         return Err(anyhow!("snapshot magic mismatch"));
     }
     let payload = &bytes[SNAPSHOT_MAGIC.len()..];
-    bincode::deserialize(payload).context("bincode deserialize lib snapshot")
+    bincode_options(payload.len())
+        .deserialize(payload)
+        .context("bincode deserialize lib snapshot")
 }
 
 fn decode_snapshot_set(bytes: &[u8]) -> Result<LibSnapshotSet> {
@@ -335,7 +398,9 @@ fn decode_snapshot_set(bytes: &[u8]) -> Result<LibSnapshotSet> {
         return Err(anyhow!("snapshot magic mismatch"));
     }
     let payload = &bytes[SNAPSHOT_MAGIC.len()..];
-    bincode::deserialize(payload).context("bincode deserialize lib snapshot set")
+    bincode_options(payload.len())
+        .deserialize(payload)
+        .context("bincode deserialize lib snapshot set")
 }
 
 #[cfg(test)]
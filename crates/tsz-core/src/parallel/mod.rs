@@ -6,7 +6,7 @@
 mod core;
 pub mod dep_graph;
 pub(crate) mod diagnostics;
-mod lib_snapshot;
+pub(crate) mod lib_snapshot;
 pub mod residency;
 pub mod skeleton;
 
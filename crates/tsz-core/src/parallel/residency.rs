@@ -21,6 +21,10 @@ pub struct MergedProgramResidencyStats {
     pub bound_file_arena_count: usize,
     /// Number of unique `NodeArena` allocations retained across all arena maps.
     pub unique_arena_count: usize,
+    /// Total AST nodes across the user-file arenas.
+    pub node_count: usize,
+    /// Number of symbols in the merged program symbol arena.
+    pub symbol_count: usize,
     /// Number of entries in the symbol -> arena lookup table.
     pub symbol_arena_count: usize,
     /// Number of declaration -> arena buckets retained for cross-file lookup.
@@ -131,6 +135,8 @@ impl MergedProgram {
             file_count: self.files.len(),
             bound_file_arena_count: self.files.len(),
             unique_arena_count,
+            node_count: self.files.iter().map(|f| f.arena.len()).sum(),
+            symbol_count: self.symbols.len(),
             symbol_arena_count: self.symbol_arenas.len(),
             declaration_arena_bucket_count: self.declaration_arenas.len(),
             declaration_arena_mapping_count: self
@@ -244,6 +250,8 @@ mod tests {
             file_count: 1,
             bound_file_arena_count: 1,
             unique_arena_count: 1,
+            node_count: 0,
+            symbol_count: 0,
             symbol_arena_count: 0,
             declaration_arena_bucket_count: 0,
             declaration_arena_mapping_count: 0,
@@ -274,6 +282,8 @@ mod tests {
             file_count: 100,
             bound_file_arena_count: 100,
             unique_arena_count: 50,
+            node_count: 0,
+            symbol_count: 0,
             symbol_arena_count: 100,
             declaration_arena_bucket_count: 50,
             declaration_arena_mapping_count: 200,
@@ -300,6 +310,8 @@ mod tests {
             file_count: 10,
             bound_file_arena_count: 10,
             unique_arena_count: 5,
+            node_count: 0,
+            symbol_count: 0,
             symbol_arena_count: 10,
             declaration_arena_bucket_count: 5,
             declaration_arena_mapping_count: 20,
@@ -327,6 +339,8 @@ mod tests {
             file_count: 10,
             bound_file_arena_count: 10,
             unique_arena_count: 5,
+            node_count: 0,
+            symbol_count: 0,
             symbol_arena_count: 10,
             declaration_arena_bucket_count: 5,
             declaration_arena_mapping_count: 20,
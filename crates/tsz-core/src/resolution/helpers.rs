@@ -5,7 +5,7 @@
 
 use indexmap::IndexMap;
 use rustc_hash::FxHashMap;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::path::{Path, PathBuf};
 
 // Per-thread file-existence cache for module-resolution hot loops.
@@ -23,6 +23,11 @@ thread_local! {
 
 #[inline]
 pub(crate) fn cached_is_file(path: &Path) -> bool {
+    // A custom `CompilerHost` owns its own state (and may change between
+    // compiles on the same thread), so its answers are never memoized here.
+    if crate::compiler_host::has_compiler_host() {
+        return crate::compiler_host::file_exists(path);
+    }
     FILE_EXISTS.with(|cache| {
         if let Some(&exists) = cache.borrow().get(path) {
             return exists;
@@ -42,6 +47,58 @@ pub(crate) fn clear_file_exists_cache() {
     FILE_EXISTS.with(|cache| cache.borrow_mut().clear());
 }
 
+// Per-thread `--traceResolution` switch. The probing helpers below are free
+// functions shared by every resolver, so `ModuleResolver::lookup` turns
+// tracing on for the duration of one traced lookup instead of threading the
+// flag through each helper.
+thread_local! {
+    static TRACE_RESOLUTION: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Enables resolution tracing on the current thread until dropped.
+pub(crate) struct ResolutionTraceScope {
+    previous: bool,
+}
+
+impl ResolutionTraceScope {
+    pub(crate) fn enable() -> Self {
+        let previous = TRACE_RESOLUTION.with(|enabled| enabled.replace(true));
+        Self { previous }
+    }
+}
+
+impl Drop for ResolutionTraceScope {
+    fn drop(&mut self) {
+        TRACE_RESOLUTION.with(|enabled| enabled.set(self.previous));
+    }
+}
+
+pub(crate) fn resolution_tracing_enabled() -> bool {
+    TRACE_RESOLUTION.with(Cell::get)
+}
+
+/// Print one `--traceResolution` line if tracing is enabled on this thread.
+pub(crate) fn trace_resolution(message: std::fmt::Arguments<'_>) {
+    if resolution_tracing_enabled() {
+        println!("{message}");
+    }
+}
+
+/// Trace the outcome of probing one candidate file, in tsc's wording.
+pub(crate) fn trace_file_probe(candidate: &Path, exists: bool) {
+    if exists {
+        trace_resolution(format_args!(
+            "File '{}' exists - use it as a name resolution result.",
+            candidate.display()
+        ));
+    } else {
+        trace_resolution(format_args!(
+            "File '{}' does not exist.",
+            candidate.display()
+        ));
+    }
+}
+
 pub(crate) fn parse_package_specifier(specifier: &str) -> (String, Option<String>) {
     // Handle scoped packages (@scope/pkg)
     if let Some(without_at) = specifier.strip_prefix('@') {
@@ -538,7 +595,9 @@ pub(crate) fn try_file_with_suffixes_and_extension(
         let Some(candidate) = path_with_suffix_and_extension(base, suffix, extension) else {
             continue;
         };
-        if cached_is_file(&candidate) {
+        let exists = cached_is_file(&candidate);
+        trace_file_probe(&candidate, exists);
+        if exists {
             return Some(candidate);
         }
     }
@@ -563,7 +622,9 @@ pub(crate) fn path_with_suffix_and_extension(
 
 pub(crate) fn try_arbitrary_extension_declaration(path: &Path, extension: &str) -> Option<PathBuf> {
     let declaration = path.with_extension(format!("d.{extension}.ts"));
-    if cached_is_file(&declaration) {
+    let exists = cached_is_file(&declaration);
+    trace_file_probe(&declaration, exists);
+    if exists {
         return Some(declaration);
     }
     None
@@ -980,6 +1041,21 @@ mod tests {
         assert_eq!(dcts, vec![PathBuf::from("pkg/a.cts")]);
     }
 
+    #[test]
+    fn resolution_trace_scope_restores_previous_state() {
+        assert!(!resolution_tracing_enabled());
+        {
+            let _outer = ResolutionTraceScope::enable();
+            assert!(resolution_tracing_enabled());
+            {
+                let _inner = ResolutionTraceScope::enable();
+                assert!(resolution_tracing_enabled());
+            }
+            assert!(resolution_tracing_enabled());
+        }
+        assert!(!resolution_tracing_enabled());
+    }
+
     #[test]
     fn try_file_with_suffixes_and_extension_returns_first_existing_candidate() {
         let dir = tempdir().expect("create temp dir");
@@ -395,6 +395,8 @@ fn test_tier_2_type_checker_accuracy_fixes() {
             preserve_const_enums: false,
             strict_builtin_iterator_return: true,
             erasable_syntax_only: false,
+            no_error_truncation: false,
+            report_suggestions: false,
         },
     );
     assert!(
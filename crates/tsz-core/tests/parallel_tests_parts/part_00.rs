@@ -276,6 +276,16 @@ fn test_parse_and_bind_single_uses_json_synthetic_bind_path() {
     assert!(result.source_file.is_some());
     assert!(result.parse_diagnostics.is_empty());
     assert!(!result.arena.is_empty());
+
+    let source_file = result
+        .arena
+        .get_source_file_at(result.source_file)
+        .expect("source file");
+    assert_eq!(
+        source_file.script_kind,
+        crate::parser::script_kind::JSON
+    );
+    assert_eq!(source_file.statements.nodes.len(), 1);
 }
 
 #[test]
@@ -856,6 +866,50 @@ fn test_merged_program_residency_stats_track_unique_file_arenas() {
     );
 }
 
+#[test]
+fn test_stable_symbol_ids_survive_file_set_changes() {
+    let b_source =
+        "export interface Shape { area(): number; }\nexport function make() {}".to_string();
+    let with_a = merge_bind_results(parse_and_bind_parallel(vec![
+        (
+            "a.ts".to_string(),
+            "export const a = 1; export const aa = 2;".to_string(),
+        ),
+        ("b.ts".to_string(), b_source.clone()),
+    ]));
+    let without_a = merge_bind_results(parse_and_bind_parallel(vec![(
+        "b.ts".to_string(),
+        b_source,
+    )]));
+
+    let make_with_a = with_a.module_exports["b.ts"]
+        .get("make")
+        .expect("make should be exported");
+    let make_without_a = without_a.module_exports["b.ts"]
+        .get("make")
+        .expect("make should be exported");
+    assert_ne!(
+        make_with_a, make_without_a,
+        "merge-order SymbolIds shift when a.ts leaves the program"
+    );
+
+    let stable = with_a
+        .stable_symbol_id(make_with_a)
+        .expect("user symbols should have a stable id");
+    assert_eq!(without_a.stable_symbol_id(make_without_a), Some(stable));
+    assert_eq!(without_a.symbol_for_stable_id(stable), Some(make_without_a));
+
+    let changed = merge_bind_results(parse_and_bind_parallel(vec![(
+        "b.ts".to_string(),
+        "export function make() {}".to_string(),
+    )]));
+    assert_eq!(
+        changed.symbol_for_stable_id(stable),
+        None,
+        "a stable id must not resolve once its file content changes"
+    );
+}
+
 #[test]
 fn test_merged_program_residency_stats_deduplicate_shared_arena_handles() {
     let files = vec![(
@@ -834,6 +834,24 @@ fn test_residency_stats_include_dep_graph_info() {
     assert_eq!(stats.dep_graph_root_count, 2);
 }
 
+#[test]
+fn test_residency_stats_count_nodes_and_symbols() {
+    let files = vec![
+        ("a.ts".to_string(), "export const a = 1;".to_string()),
+        ("b.ts".to_string(), "export function b() {}".to_string()),
+    ];
+
+    let bind_results = parse_and_bind_parallel(files);
+    let program = merge_bind_results(bind_results);
+    let stats = program.residency_stats();
+
+    let arena_nodes: usize = program.files.iter().map(|f| f.arena.len()).sum();
+    assert_eq!(stats.node_count, arena_nodes);
+    assert!(stats.node_count > 0, "user files should contribute nodes");
+    assert_eq!(stats.symbol_count, program.symbols.len());
+    assert!(stats.symbol_count >= 2, "a and b should be bound");
+}
+
 #[test]
 fn test_dep_graph_deterministic_across_runs() {
     let files = vec![
@@ -853,3 +871,51 @@ fn test_dep_graph_deterministic_across_runs() {
     assert_eq!(topo1.order, topo2.order);
     assert_eq!(topo1.is_acyclic, topo2.is_acyclic);
 }
+
+#[test]
+fn test_file_check_session_matches_parallel_check() {
+    let files = vec![
+        (
+            "a.ts".to_string(),
+            "export const x: number = \"s\";\n".to_string(),
+        ),
+        (
+            "b.ts".to_string(),
+            "import { x } from './a';\nexport const y: string = x;\n".to_string(),
+        ),
+    ];
+    let program = compile_files(files);
+    let options = crate::checker::context::CheckerOptions::default();
+    let expected = check_files_parallel(&program, &options, &[]);
+
+    let session = FileCheckSession::new(
+        std::sync::Arc::new(program),
+        options,
+        &[],
+        &FxHashMap::default(),
+    );
+    assert_eq!(session.file_count(), 2);
+    assert!(session.check_file(2).is_none());
+    // Check out of order, as independent workers would.
+    let mut file_results: Vec<FileCheckResult> = [1, 0]
+        .into_iter()
+        .filter_map(|file_idx| session.check_file(file_idx))
+        .collect();
+    file_results.sort_by_key(|result| result.file_idx);
+    let result = session.finish(file_results);
+
+    let codes = |result: &CheckResult| -> Vec<(String, Vec<u32>)> {
+        result
+            .file_results
+            .iter()
+            .map(|file| {
+                (
+                    file.file_name.clone(),
+                    file.diagnostics.iter().map(|diag| diag.code).collect(),
+                )
+            })
+            .collect()
+    };
+    assert_eq!(codes(&result), codes(&expected));
+    assert_eq!(result.diagnostic_count, expected.diagnostic_count);
+}
@@ -0,0 +1,79 @@
+use crate::Parser;
+use serde_json::Value;
+
+fn parsed(source: &str) -> Parser {
+    let mut parser = Parser::new("test.ts".to_string(), source.to_string());
+    parser.parse_source_file();
+    parser
+}
+
+fn find_kind<'a>(node: &'a Value, kind_name: &str) -> Option<&'a Value> {
+    if node["kindName"] == kind_name {
+        return Some(node);
+    }
+    node["children"]
+        .as_array()?
+        .iter()
+        .find_map(|child| find_kind(child, kind_name))
+}
+
+#[test]
+fn test_get_ast_json_exports_tree() {
+    let parser = parsed("const answer = 42;\nfunction f() {}\n");
+    let ast: Value = serde_json::from_str(&parser.get_ast_json()).unwrap();
+
+    let root = &ast["root"];
+    assert_eq!(root["kindName"], "SourceFile");
+    assert_eq!(root["idx"], ast["rootIdx"]);
+
+    let declaration = find_kind(root, "VariableDeclaration").expect("variable declaration");
+    let children = declaration["children"].as_array().unwrap();
+    assert_eq!(children[0]["kindName"], "Identifier");
+    assert_eq!(children[0]["text"], "answer");
+    assert_eq!(children[1]["kindName"], "NumericLiteral");
+    assert_eq!(children[1]["text"], "42");
+    assert!(children[0]["pos"].as_u64().unwrap() < children[0]["end"].as_u64().unwrap());
+
+    assert!(find_kind(root, "FunctionDeclaration").is_some());
+}
+
+#[test]
+fn test_get_ast_json_without_parse_is_empty() {
+    let parser = Parser::new("test.ts".to_string(), "let x;".to_string());
+    assert_eq!(parser.get_ast_json(), "{}");
+    assert!(parser.get_children(0).is_empty());
+}
+
+#[test]
+fn test_get_children_matches_ast_json() {
+    let parser = parsed("let a = 1;\nlet b = a;\n");
+    let ast: Value = serde_json::from_str(&parser.get_ast_json()).unwrap();
+    let root_idx = ast["rootIdx"].as_u64().unwrap() as u32;
+
+    let children = parser.get_children(root_idx);
+    let json_children: Vec<u32> = ast["root"]["children"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|child| child["idx"].as_u64().unwrap() as u32)
+        .collect();
+    assert_eq!(children, json_children);
+    assert_eq!(
+        parser.get_node_kind_name(children[0]).as_deref(),
+        Some("VariableStatement")
+    );
+}
+
+#[test]
+fn test_get_ast_json_omits_nodes_past_the_depth_limit() {
+    let source = format!("const x = {};\n", vec!["1"; 2000].join(" + "));
+    let parser = parsed(&source);
+    let json = parser.get_ast_json();
+    assert!(json.starts_with('{') && json.ends_with('}'));
+    assert!(json.contains("\"childrenOmitted\":true"));
+    assert!(
+        !parsed("let a = 1;")
+            .get_ast_json()
+            .contains("childrenOmitted")
+    );
+}
@@ -0,0 +1,34 @@
+use crate::Parser;
+use crate::api::wasm::program_results::QuickInfoJson;
+
+#[test]
+fn test_quick_info_includes_kind_modifiers_and_jsdoc() {
+    let source = "/**\n * Adds two numbers.\n * @param a first\n * @returns the sum\n */\nexport function add(a: number, b: number): number { return a + b; }\n";
+    let mut parser = Parser::new("test.ts".to_string(), source.to_string());
+    parser.parse_source_file();
+
+    let hover = parser
+        .hover_at(5, 17)
+        .expect("hover runs")
+        .expect("hover on `add`");
+    let info = QuickInfoJson::from_hover(hover);
+
+    assert_eq!(info.kind, "function");
+    assert!(
+        info.kind_modifiers.contains("export"),
+        "{}",
+        info.kind_modifiers
+    );
+    assert!(
+        info.display_string.starts_with("function add("),
+        "{}",
+        info.display_string
+    );
+    assert!(
+        info.documentation.contains("Adds two numbers."),
+        "{}",
+        info.documentation
+    );
+    let tags: Vec<&str> = info.tags.iter().map(|tag| tag.name.as_str()).collect();
+    assert_eq!(tags, ["param", "returns"]);
+}
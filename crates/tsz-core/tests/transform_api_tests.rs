@@ -40,3 +40,127 @@ enum enumdule {
         "expected exactly one `var enumdule;`, got output:\n{output}"
     );
 }
+
+#[test]
+fn test_custom_transformer_replacements_override_built_in_transforms() {
+    use crate::api::wasm::transforms::apply_node_transformers;
+    use crate::context::emit::EmitContext;
+    use crate::emitter::{Printer, PrinterOptions};
+    use crate::lowering::LoweringPass;
+    use crate::parser::ParserState;
+
+    let mut parser = ParserState::new(
+        "test.ts".to_string(),
+        "debugger;\nclass Foo {}\nconst keep = 1;\n".to_string(),
+    );
+    let root = parser.parse_source_file();
+    let arena = parser.get_arena();
+    let ctx = EmitContext::with_options(PrinterOptions {
+        target: ScriptTarget::ES5,
+        ..Default::default()
+    });
+    let mut transforms = LoweringPass::new(arena, &ctx).run(root);
+
+    let mut visited = Vec::new();
+    apply_node_transformers(
+        arena,
+        root,
+        &mut transforms,
+        |_, kind_name, has_transform| {
+            visited.push(kind_name.to_string());
+            Ok(match kind_name {
+                "DebuggerStatement" => Some("/* removed */".to_string()),
+                "ClassDeclaration" => {
+                    assert!(has_transform, "ES5 class lowering should be visible");
+                    Some("var Foo = makeClass();".to_string())
+                }
+                _ => None,
+            })
+        },
+    )
+    .unwrap();
+
+    let first_identifier = visited.iter().position(|kind| kind == "Identifier");
+    let variable_statement = visited.iter().position(|kind| kind == "VariableStatement");
+    assert!(
+        first_identifier > variable_statement,
+        "replaced subtrees should not be visited: {visited:?}"
+    );
+
+    let mut printer = Printer::with_transforms(arena, transforms);
+    printer.set_target_es5(ctx.target_es5);
+    printer.set_source_text(parser.get_source_text());
+    printer.emit(root);
+    let output = printer.get_output().to_string();
+
+    assert!(output.contains("/* removed */"), "{output}");
+    assert!(output.contains("var Foo = makeClass();"), "{output}");
+    assert!(!output.contains("function Foo()"), "{output}");
+    assert!(output.contains("var keep = 1;"), "{output}");
+}
+
+#[test]
+fn test_before_transformer_replacements_are_not_lowered() {
+    use crate::api::wasm::transforms::apply_node_transformers;
+    use crate::context::emit::EmitContext;
+    use crate::context::transform::TransformContext;
+    use crate::emitter::{Printer, PrinterOptions};
+    use crate::lowering::LoweringPass;
+    use crate::parser::ParserState;
+
+    let mut parser = ParserState::new(
+        "test.ts".to_string(),
+        "class Foo {}\nclass Bar {}\n".to_string(),
+    );
+    let root = parser.parse_source_file();
+    let arena = parser.get_arena();
+    let ctx = EmitContext::with_options(PrinterOptions {
+        target: ScriptTarget::ES5,
+        ..Default::default()
+    });
+
+    let mut before = TransformContext::new();
+    let mut replaced = None;
+    apply_node_transformers(arena, root, &mut before, |idx, kind_name, has_transform| {
+        assert!(!has_transform, "before transformers run ahead of lowering");
+        if kind_name == "ClassDeclaration" && replaced.is_none() {
+            replaced = Some(idx);
+            return Ok(Some("var Foo = makeClass();".to_string()));
+        }
+        Ok(None)
+    })
+    .unwrap();
+    let transforms = LoweringPass::new(arena, &ctx)
+        .with_transforms(before)
+        .run(root);
+    assert!(!transforms.has_transform(replaced.unwrap()));
+
+    let mut printer = Printer::with_transforms(arena, transforms);
+    printer.set_target_es5(ctx.target_es5);
+    printer.set_source_text(parser.get_source_text());
+    printer.emit(root);
+    let output = printer.get_output().to_string();
+
+    assert!(output.contains("var Foo = makeClass();"), "{output}");
+    assert!(!output.contains("function Foo()"), "{output}");
+    assert!(output.contains("function Bar()"), "{output}");
+}
+
+#[test]
+fn test_emit_with_source_map_returns_code_and_map() {
+    let mut parser = Parser::new(
+        "src/app.ts".to_string(),
+        "const greeting: string = \"hi\";\nlet count = 1;\n".to_string(),
+    );
+    parser.parse_source_file();
+
+    let result = parser.emit_with_source_map_result();
+    assert_eq!(result.code, parser.emit());
+    assert!(!result.code.contains("sourceMappingURL"), "{}", result.code);
+
+    let map: serde_json::Value = serde_json::from_str(&result.map).expect("source map JSON");
+    assert_eq!(map["version"], 3);
+    assert_eq!(map["file"], "app.js");
+    assert_eq!(map["sources"][0], "app.ts");
+    assert!(!map["mappings"].as_str().unwrap_or_default().is_empty());
+}
@@ -0,0 +1,800 @@
+//! `WasmProgram` tests that run natively.
+//!
+//! The wasm-bindgen entry points need a JS engine, so these drive the same
+//! code through the crate-internal helpers, with a `MemoryCompilerHost`
+//! standing in for a JS host.
+
+use crate::api::wasm::program::{FileTextEdit, WasmProgram};
+use crate::compiler_host::{CompilerHost, MemoryCompilerHost};
+use crate::lsp::position::{Position, Range};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::sync::Arc;
+use tsz_common::CancellationToken;
+
+/// A file system held in memory. Directories exist implicitly when a file
+/// lives below them.
+fn memory_host(files: &[(&str, &str)]) -> Arc<dyn CompilerHost> {
+    let host = MemoryCompilerHost::new("/");
+    for (name, text) in files {
+        host.add_file(name, *text);
+    }
+    Arc::new(host)
+}
+
+fn diagnostic_codes(program: &mut WasmProgram) -> FxHashMap<String, Vec<u32>> {
+    serde_json::from_str(&program.get_diagnostic_codes()).expect("diagnostic codes JSON")
+}
+
+fn codes_for<'a>(codes: &'a FxHashMap<String, Vec<u32>>, file: &str) -> &'a [u32] {
+    codes
+        .get(file)
+        .unwrap_or_else(|| panic!("no diagnostics entry for {file}: {codes:?}"))
+}
+
+fn edit(start: (u32, u32), end: (u32, u32), new_text: &str) -> FileTextEdit {
+    FileTextEdit {
+        range: Range::new(Position::new(start.0, start.1), Position::new(end.0, end.1)),
+        new_text: new_text.to_string(),
+    }
+}
+
+#[test]
+fn test_host_loads_relative_import_the_program_was_not_given() {
+    let mut program = WasmProgram::new();
+    program.add_file(
+        "/p/src/main.ts".to_string(),
+        "import { x } from './util';\nconst y: string = x;\n".to_string(),
+    );
+    program.set_compiler_host(memory_host(&[("/p/src/util.ts", "export const x = 1;\n")]));
+
+    let codes = diagnostic_codes(&mut program);
+    let main = codes_for(&codes, "/p/src/main.ts");
+    assert!(!main.contains(&2307), "import should resolve: {codes:?}");
+    assert!(
+        main.contains(&2322),
+        "x should be typed from util.ts: {codes:?}"
+    );
+    assert!(codes.contains_key("/p/src/util.ts"));
+}
+
+#[test]
+fn test_host_resolves_package_types_field_and_scoped_types_package() {
+    let mut program = WasmProgram::new();
+    program.add_file(
+        "/p/src/main.ts".to_string(),
+        "import { value } from 'lib-a';\n\
+         import { thing } from '@scope/pkg';\n\
+         const a: string = value;\n\
+         const b: string = thing;\n"
+            .to_string(),
+    );
+    program.set_compiler_host(memory_host(&[
+        (
+            "/p/node_modules/lib-a/package.json",
+            r#"{ "name": "lib-a", "types": "dist/index.d.ts" }"#,
+        ),
+        (
+            "/p/node_modules/lib-a/dist/index.d.ts",
+            "export declare const value: number;\n",
+        ),
+        (
+            "/p/node_modules/@types/scope__pkg/index.d.ts",
+            "export declare const thing: boolean;\n",
+        ),
+    ]));
+
+    let codes = diagnostic_codes(&mut program);
+    let main = codes_for(&codes, "/p/src/main.ts");
+    assert!(!main.contains(&2307), "packages should resolve: {codes:?}");
+    assert_eq!(
+        main.iter().filter(|code| **code == 2322).count(),
+        2,
+        "both imports should carry their declared types: {codes:?}"
+    );
+}
+
+#[test]
+fn test_host_includes_types_packages_automatically() {
+    let mut program = WasmProgram::new();
+    program.add_file(
+        "/p/src/main.ts".to_string(),
+        "const s: string = injected;\n".to_string(),
+    );
+    program.set_compiler_host(memory_host(&[(
+        "/p/node_modules/@types/globals/index.d.ts",
+        "declare const injected: number;\n",
+    )]));
+
+    let codes = diagnostic_codes(&mut program);
+    let main = codes_for(&codes, "/p/src/main.ts");
+    assert!(
+        !main.contains(&2304),
+        "@types globals should load: {codes:?}"
+    );
+    assert!(main.contains(&2322), "{codes:?}");
+}
+
+#[test]
+fn test_load_tsconfig_applies_extends_include_and_exclude() {
+    let mut program = WasmProgram::new();
+    program.set_compiler_host(memory_host(&[
+        (
+            "/p/base.json",
+            r#"{ "compilerOptions": { "noImplicitAny": true } }"#,
+        ),
+        (
+            "/p/tsconfig.json",
+            r#"{
+                // Inherit strictness from the shared base.
+                "extends": "./base",
+                "include": ["src"],
+                "exclude": ["src/generated"],
+            }"#,
+        ),
+        ("/p/src/a.ts", "export function f(x) { return x; }\n"),
+        ("/p/src/generated/b.ts", "export const b = 1;\n"),
+        ("/p/scripts/c.ts", "export const c = 1;\n"),
+    ]));
+
+    program
+        .load_tsconfig_from_host("/p/tsconfig.json")
+        .expect("tsconfig should load");
+    assert_eq!(program.get_file_count(), 1);
+
+    let codes = diagnostic_codes(&mut program);
+    assert!(
+        codes_for(&codes, "/p/src/a.ts").contains(&7006),
+        "noImplicitAny should come from the base config: {codes:?}"
+    );
+}
+
+#[test]
+fn test_paths_and_base_url_resolve_program_files_without_a_host() {
+    let mut program = WasmProgram::new();
+    program
+        .set_compiler_options(r#"{ "baseUrl": "/p", "paths": { "@lib/*": ["src/lib/*"] } }"#)
+        .expect("valid options");
+    program.add_file(
+        "/p/src/main.ts".to_string(),
+        "import { x } from '@lib/util';\nimport { y } from 'src/other';\nconst a: string = x;\nconst b: string = y;\n"
+            .to_string(),
+    );
+    program.add_file(
+        "/p/src/lib/util.ts".to_string(),
+        "export const x = 1;\n".to_string(),
+    );
+    program.add_file(
+        "/p/src/other.ts".to_string(),
+        "export const y = 2;\n".to_string(),
+    );
+
+    let codes = diagnostic_codes(&mut program);
+    let main = codes_for(&codes, "/p/src/main.ts");
+    assert!(!main.contains(&2307), "imports should resolve: {codes:?}");
+    assert_eq!(
+        main.iter().filter(|&&code| code == 2322).count(),
+        2,
+        "both imports should be typed from their files: {codes:?}"
+    );
+}
+
+#[test]
+fn test_load_tsconfig_resolves_paths_against_the_config_directory() {
+    let mut program = WasmProgram::new();
+    program.set_compiler_host(memory_host(&[
+        (
+            "/p/tsconfig.json",
+            r#"{ "compilerOptions": { "paths": { "~/*": ["./src/*"] } }, "files": ["src/main.ts"] }"#,
+        ),
+        (
+            "/p/src/main.ts",
+            "import { x } from '~/util';\nconst y: string = x;\n",
+        ),
+        ("/p/src/util.ts", "export const x = 1;\n"),
+    ]));
+    program
+        .load_tsconfig_from_host("/p/tsconfig.json")
+        .expect("config loads");
+
+    let codes = diagnostic_codes(&mut program);
+    let main = codes_for(&codes, "/p/src/main.ts");
+    assert!(!main.contains(&2307), "import should resolve: {codes:?}");
+    assert!(main.contains(&2322), "{codes:?}");
+}
+
+#[test]
+fn test_lib_option_selects_lib_files_and_their_references() {
+    let mut program = WasmProgram::new();
+    program.add_lib_file(
+        "lib.es5.d.ts".to_string(),
+        "declare var es5Global: number;\n".to_string(),
+    );
+    program.add_lib_file(
+        "lib.es2015.d.ts".to_string(),
+        "/// <reference lib=\"es5\" />\ndeclare var es2015Global: number;\n".to_string(),
+    );
+    program.add_lib_file(
+        "lib.dom.d.ts".to_string(),
+        "declare var domGlobal: number;\n".to_string(),
+    );
+    program.add_file(
+        "/p/a.ts".to_string(),
+        "es5Global;\nes2015Global;\ndomGlobal;\n".to_string(),
+    );
+
+    program
+        .set_compiler_options(r#"{ "lib": ["es2015"] }"#)
+        .expect("valid options");
+    let codes = diagnostic_codes(&mut program);
+    assert_eq!(
+        codes_for(&codes, "/p/a.ts")
+            .iter()
+            .filter(|&&code| code == 2304)
+            .count(),
+        1,
+        "only domGlobal is missing: {codes:?}"
+    );
+
+    program
+        .set_compiler_options(r#"{ "lib": ["es5", "dom"] }"#)
+        .expect("valid options");
+    let codes = diagnostic_codes(&mut program);
+    assert_eq!(
+        codes_for(&codes, "/p/a.ts")
+            .iter()
+            .filter(|&&code| code == 2304)
+            .count(),
+        1,
+        "only es2015Global is missing: {codes:?}"
+    );
+}
+
+#[test]
+fn test_preparsed_libs_round_trip_between_programs() {
+    let mut first = WasmProgram::new();
+    first.add_lib_file(
+        "lib.preparsed.d.ts".to_string(),
+        "declare var preparsedGlobal: number;\n".to_string(),
+    );
+    let blob = first.serialize_lib_files().expect("lib files encode");
+    assert!(!blob.is_empty());
+
+    let mut second = WasmProgram::new();
+    second
+        .add_preparsed_libs(&blob)
+        .expect("blob from serializeLibFiles loads");
+    second.add_file(
+        "/p/a.ts".to_string(),
+        "const n: number = preparsedGlobal;\nmissingGlobal;\n".to_string(),
+    );
+    let codes = diagnostic_codes(&mut second);
+    assert_eq!(
+        codes_for(&codes, "/p/a.ts")
+            .iter()
+            .filter(|&&code| code == 2304)
+            .count(),
+        1,
+        "only missingGlobal is missing: {codes:?}"
+    );
+}
+
+#[test]
+fn test_reset_drops_files_options_and_compilation() {
+    let mut program = WasmProgram::new();
+    program.add_file("/p/a.ts".to_string(), "const x: string = 1;\n".to_string());
+    program
+        .set_compiler_options(r#"{ "strict": true }"#)
+        .expect("valid options");
+    assert!(codes_for(&diagnostic_codes(&mut program), "/p/a.ts").contains(&2322));
+
+    let stats: serde_json::Value =
+        serde_json::from_str(&program.reset()).expect("memory statistics JSON");
+    assert!(stats["libFileCache"]["entries"].is_u64(), "{stats}");
+    assert_eq!(program.get_file_count(), 0);
+    assert!(diagnostic_codes(&mut program).is_empty());
+}
+
+#[test]
+fn test_cancelled_check_stops_between_files() {
+    let mut program = WasmProgram::new();
+    program.add_file("/p/a.ts".to_string(), "const a: string = 1;\n".to_string());
+    program.add_file("/p/b.ts".to_string(), "const b: string = 2;\n".to_string());
+
+    let token = CancellationToken::new();
+    token.cancel();
+    assert!(program.check_all_cancellable(None, Some(&token)).is_none());
+
+    let token = CancellationToken::new();
+    let mut reported = Vec::new();
+    let result = program.check_all_cancellable(
+        Some(&mut |file| {
+            reported.push(file.file_name.clone());
+            token.cancel();
+        }),
+        Some(&token),
+    );
+    assert!(result.is_none());
+    assert_eq!(
+        reported,
+        ["/p/a.ts"],
+        "the check stops after the first file"
+    );
+
+    // A cancelled check leaves nothing cached; the next one starts over.
+    let codes = program.all_diagnostic_codes();
+    assert_eq!(codes.iter().filter(|&&code| code == 2322).count(), 2);
+}
+
+#[test]
+fn test_load_tsconfig_requires_a_host() {
+    let mut program = WasmProgram::new();
+    let err = program
+        .load_tsconfig_from_host("/p/tsconfig.json")
+        .expect_err("no host was set");
+    assert!(err.contains("setHost"), "{err}");
+}
+
+#[test]
+fn test_update_file_reparses_only_the_edited_file() {
+    let mut program = WasmProgram::new();
+    program.add_file("/p/a.ts".to_string(), "export const a = 1;\n".to_string());
+    program.add_file("/p/b.ts".to_string(), "export const b = 2;\n".to_string());
+    diagnostic_codes(&mut program);
+    let a_before = program.cached_arena("/p/a.ts").expect("a.ts is bound");
+    let b_before = program.cached_arena("/p/b.ts").expect("b.ts is bound");
+
+    program
+        .apply_file_edits("/p/a.ts", &[edit((0, 17), (0, 18), "'one'")])
+        .expect("edit applies");
+    diagnostic_codes(&mut program);
+
+    let a_after = program.cached_arena("/p/a.ts").expect("a.ts is bound");
+    let b_after = program.cached_arena("/p/b.ts").expect("b.ts is bound");
+    assert!(
+        !Arc::ptr_eq(&a_before, &a_after),
+        "a.ts should be re-parsed"
+    );
+    assert!(Arc::ptr_eq(&b_before, &b_after), "b.ts should be reused");
+}
+
+#[test]
+fn test_update_file_rechecks_importers_and_keeps_unrelated_diagnostics() {
+    let mut program = WasmProgram::new();
+    program.add_file(
+        "/p/a.ts".to_string(),
+        "export const x: number = 1;\n".to_string(),
+    );
+    program.add_file(
+        "/p/b.ts".to_string(),
+        "import { x } from './a';\nexport const s: string = x;\n".to_string(),
+    );
+    program.add_file(
+        "/p/c.ts".to_string(),
+        "export const z: string = 1;\n".to_string(),
+    );
+    let codes = diagnostic_codes(&mut program);
+    assert!(codes_for(&codes, "/p/b.ts").contains(&2322), "{codes:?}");
+    assert!(codes_for(&codes, "/p/c.ts").contains(&2322), "{codes:?}");
+
+    program
+        .apply_file_edits(
+            "/p/a.ts",
+            &[
+                edit((0, 16), (0, 22), "string"),
+                edit((0, 25), (0, 26), "'1'"),
+            ],
+        )
+        .expect("edits apply");
+    let codes = diagnostic_codes(&mut program);
+    assert!(
+        codes_for(&codes, "/p/a.ts").is_empty(),
+        "a.ts should be valid after the edit: {codes:?}"
+    );
+    assert!(
+        !codes_for(&codes, "/p/b.ts").contains(&2322),
+        "the importer should be re-checked: {codes:?}"
+    );
+    assert!(
+        codes_for(&codes, "/p/c.ts").contains(&2322),
+        "an unrelated file keeps its diagnostics: {codes:?}"
+    );
+}
+
+#[test]
+fn test_update_file_applies_edits_against_the_original_text() {
+    let mut program = WasmProgram::new();
+    program.add_file(
+        "/p/a.ts".to_string(),
+        "export const a: number = 1; export const b: number = 2;\n".to_string(),
+    );
+    let codes = diagnostic_codes(&mut program);
+    assert!(codes_for(&codes, "/p/a.ts").is_empty(), "{codes:?}");
+
+    program
+        .apply_file_edits(
+            "/p/a.ts",
+            &[
+                edit((0, 44), (0, 50), "string"),
+                edit((0, 16), (0, 22), "boolean"),
+            ],
+        )
+        .expect("edits apply");
+    let codes = diagnostic_codes(&mut program);
+    assert_eq!(codes_for(&codes, "/p/a.ts"), [2322, 2322], "{codes:?}");
+}
+
+#[test]
+fn test_update_file_rejects_invalid_edits_without_changing_the_file() {
+    let mut program = WasmProgram::new();
+    program.add_file(
+        "/p/a.ts".to_string(),
+        "export const a: string = 1;\n".to_string(),
+    );
+    let before = diagnostic_codes(&mut program);
+
+    let err = program
+        .apply_file_edits("/p/missing.ts", &[edit((0, 0), (0, 0), "x")])
+        .expect_err("the file is not in the program");
+    assert!(err.contains("not part of the program"), "{err}");
+
+    let err = program
+        .apply_file_edits("/p/a.ts", &[edit((5, 0), (5, 1), "x")])
+        .expect_err("line 5 does not exist");
+    assert!(err.contains("Invalid edit range"), "{err}");
+
+    let err = program
+        .apply_file_edits("/p/a.ts", &[edit((0, 10), (0, 4), "x")])
+        .expect_err("the range is reversed");
+    assert!(err.contains("Invalid edit range"), "{err}");
+
+    let err = program
+        .apply_file_edits(
+            "/p/a.ts",
+            &[
+                edit((0, 25), (0, 26), "'1'"),
+                edit((0, 16), (0, 26), "number = 1"),
+            ],
+        )
+        .expect_err("the edits overlap");
+    assert!(err.contains("Overlapping"), "{err}");
+
+    assert_eq!(diagnostic_codes(&mut program), before);
+}
+
+#[test]
+fn test_emit_all_emits_javascript_source_maps_and_declarations() {
+    let mut program = WasmProgram::new();
+    program
+        .set_compiler_options(r#"{ "module": "esnext", "sourceMap": true, "declaration": true }"#)
+        .expect("valid options");
+    program.add_file(
+        "/p/src/a.ts".to_string(),
+        "export const x: number = 1;\n".to_string(),
+    );
+    program.add_file(
+        "/p/src/b.ts".to_string(),
+        "import { x } from './a';\nexport function double(): number { return x * 2; }\n"
+            .to_string(),
+    );
+    program.add_file(
+        "/p/src/env.d.ts".to_string(),
+        "declare const env: string;\n".to_string(),
+    );
+
+    let outputs = program.emit_outputs();
+    let names: Vec<&str> = outputs.iter().map(|out| out.file_name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["/p/src/a.ts", "/p/src/b.ts"],
+        "declaration files emit nothing"
+    );
+
+    let a = &outputs[0];
+    let js = a.js_text.as_deref().expect("a.ts emits JavaScript");
+    assert!(js.contains("export const x = 1;"), "{js}");
+    assert!(js.ends_with("//# sourceMappingURL=a.js.map"), "{js}");
+    let map: serde_json::Value =
+        serde_json::from_str(a.source_map.as_deref().expect("sourceMap is on")).expect("map JSON");
+    assert_eq!(map["file"], "a.js");
+    assert_eq!(map["sources"][0], "a.ts");
+    let dts = a.dts_text.as_deref().expect("declaration is on");
+    assert!(dts.contains("export declare const x: number;"), "{dts}");
+
+    let b = &outputs[1];
+    let js = b.js_text.as_deref().expect("b.ts emits JavaScript");
+    assert!(js.contains("from './a'"), "{js}");
+    assert!(!js.contains(": number"), "types are erased: {js}");
+    let dts = b.dts_text.as_deref().expect("declaration is on");
+    assert!(
+        dts.contains("export declare function double(): number;"),
+        "{dts}"
+    );
+}
+
+#[test]
+fn test_declaration_output_uses_inferred_types() {
+    let mut program = WasmProgram::new();
+    program.add_file(
+        "/p/a.ts".to_string(),
+        "export function double(n: number) { return n * 2; }\nfunction hidden() {}\n".to_string(),
+    );
+    program.add_file(
+        "/p/env.d.ts".to_string(),
+        "declare const env: string;\n".to_string(),
+    );
+
+    let dts = program
+        .declaration_output("/p/a.ts")
+        .expect("a.ts is in the program")
+        .expect("a.ts has declarations");
+    assert!(
+        dts.contains("export declare function double(n: number): number;"),
+        "{dts}"
+    );
+    assert!(!dts.contains("hidden"), "{dts}");
+
+    assert_eq!(program.declaration_output("/p/env.d.ts"), Ok(None));
+    assert!(program.declaration_output("/p/missing.ts").is_err());
+}
+
+#[test]
+fn test_emit_all_declarations_use_inferred_types() {
+    let mut program = WasmProgram::new();
+    program
+        .set_compiler_options(r#"{ "declaration": true, "jsx": "preserve" }"#)
+        .expect("valid options");
+    program.add_file(
+        "/p/a.ts".to_string(),
+        "export function double(n: number) { return n * 2; }\nexport const four = double(2);\n"
+            .to_string(),
+    );
+    program.add_file(
+        "/p/view.tsx".to_string(),
+        "export const view = <div />;\n".to_string(),
+    );
+
+    let outputs = program.emit_outputs();
+    let dts = outputs[0].dts_text.as_deref().expect("declaration is on");
+    assert!(
+        dts.contains("export declare function double(n: number): number;"),
+        "{dts}"
+    );
+    assert!(dts.contains("export declare const four: number;"), "{dts}");
+
+    let js = outputs[1]
+        .js_text
+        .as_deref()
+        .expect("view.tsx emits JavaScript");
+    assert!(js.contains("<div />"), "jsx is preserved: {js}");
+    program
+        .set_compiler_options(r#"{ "jsx": "preserve", "sourceMap": true }"#)
+        .expect("valid options");
+    let outputs = program.emit_outputs();
+    let map: serde_json::Value =
+        serde_json::from_str(outputs[1].source_map.as_deref().expect("sourceMap is on"))
+            .expect("map JSON");
+    assert_eq!(map["file"], "view.jsx");
+}
+
+#[test]
+fn test_emit_all_honors_inline_source_map_and_declaration_only() {
+    let mut program = WasmProgram::new();
+    program.add_file(
+        "/p/a.ts".to_string(),
+        "export const x: number = 1;\n".to_string(),
+    );
+
+    program
+        .set_compiler_options(r#"{ "inlineSourceMap": true }"#)
+        .expect("valid options");
+    let outputs = program.emit_outputs();
+    let js = outputs[0]
+        .js_text
+        .as_deref()
+        .expect("JavaScript is emitted");
+    assert!(
+        js.contains("//# sourceMappingURL=data:application/json;base64,"),
+        "{js}"
+    );
+    assert!(outputs[0].source_map.is_none());
+    assert!(outputs[0].dts_text.is_none());
+
+    program
+        .set_compiler_options(r#"{ "emitDeclarationOnly": true }"#)
+        .expect("valid options");
+    let outputs = program.emit_outputs();
+    assert!(outputs[0].js_text.is_none());
+    assert!(
+        outputs[0]
+            .dts_text
+            .as_deref()
+            .is_some_and(|dts| dts.contains("export declare const x: number;")),
+        "{:?}",
+        outputs[0].dts_text
+    );
+}
+
+fn program_with_config_module(importer: &str) -> WasmProgram {
+    let mut program = WasmProgram::new();
+    program.add_file(
+        "/p/a.ts".to_string(),
+        "export const config = { port: 8080, host: \"localhost\" };\n".to_string(),
+    );
+    program.add_file("/p/b.ts".to_string(), importer.to_string());
+    program
+}
+
+#[test]
+fn test_hover_shows_types_declared_in_the_exporting_file() {
+    let mut program =
+        program_with_config_module("import { config } from './a';\nconst port = config.port;\n");
+
+    let hover = program
+        .hover_at("/p/b.ts", Position::new(1, 14))
+        .expect("b.ts is in the program")
+        .expect("hover on an imported name");
+    assert!(
+        hover.display_string.contains("port: number"),
+        "{}",
+        hover.display_string
+    );
+}
+
+#[test]
+fn test_definition_of_an_imported_name_is_in_the_exporting_file() {
+    let mut program =
+        program_with_config_module("import { config } from './a';\nconst port = config.port;\n");
+
+    let definitions = program
+        .definition_at("/p/b.ts", Position::new(1, 14))
+        .expect("b.ts is in the program")
+        .expect("definition of an imported name");
+    assert_eq!(definitions.len(), 1, "{definitions:?}");
+    assert_eq!(definitions[0].file_path, "/p/a.ts");
+    assert_eq!(definitions[0].range.start, Position::new(0, 13));
+}
+
+#[test]
+fn test_member_completions_on_an_imported_value() {
+    let mut program = program_with_config_module("import { config } from './a';\nconfig.\n");
+
+    let completions = program
+        .completions_at("/p/b.ts", Position::new(1, 7))
+        .expect("b.ts is in the program")
+        .expect("member completions");
+    let labels: FxHashSet<&str> = completions.iter().map(|item| item.label.as_str()).collect();
+    assert!(
+        labels.contains("port") && labels.contains("host"),
+        "{labels:?}"
+    );
+}
+
+#[test]
+fn test_language_service_queries_reject_unknown_files() {
+    let mut program = program_with_config_module("import { config } from './a';\n");
+
+    let err = program
+        .hover_at("/p/missing.ts", Position::new(0, 0))
+        .expect_err("missing.ts is not in the program");
+    assert_eq!(err, "File '/p/missing.ts' is not part of the program.");
+    assert!(
+        program
+            .definition_at("/p/missing.ts", Position::new(0, 0))
+            .is_err()
+    );
+    assert!(
+        program
+            .completions_at("/p/missing.ts", Position::new(0, 0))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_check_all_returns_structured_diagnostics() {
+    let mut program = WasmProgram::new();
+    program.add_file(
+        "/p/a.ts".to_string(),
+        "let x: number = \"s\";\n".to_string(),
+    );
+    program.add_file("/p/b.ts".to_string(), "let y = ;\n".to_string());
+
+    let result = program.check_all_result();
+    assert_eq!(result.stats.total_files, 2);
+    assert_eq!(result.files[0].file_name, "/p/a.ts");
+
+    let check = &result.files[0].check_diagnostics;
+    let ts2322 = check
+        .iter()
+        .find(|d| d.code == 2322)
+        .unwrap_or_else(|| panic!("{check:?}"));
+    assert_eq!(ts2322.file, "/p/a.ts");
+    assert_eq!(ts2322.category, "Error");
+    assert_eq!((ts2322.start, ts2322.length), (4, 1));
+
+    let parse = &result.files[1].parse_diagnostics;
+    assert!(!parse.is_empty());
+    assert!(
+        parse
+            .iter()
+            .all(|d| d.file == "/p/b.ts" && d.category == "Error")
+    );
+    assert_eq!(
+        result.stats.total_diagnostics,
+        result
+            .files
+            .iter()
+            .map(|file| file.parse_diagnostics.len() + file.check_diagnostics.len())
+            .sum::<usize>()
+    );
+}
+
+#[test]
+fn test_check_all_streaming_reports_every_file_before_returning() {
+    let mut program = WasmProgram::new();
+    program.add_file(
+        "/p/a.ts".to_string(),
+        "export const x: number = \"s\";\n".to_string(),
+    );
+    program.add_file(
+        "/p/b.ts".to_string(),
+        "import { x } from './a';\nexport const y: string = x;\n".to_string(),
+    );
+
+    let mut streamed: FxHashMap<String, Vec<u32>> = FxHashMap::default();
+    let result = program.check_all_streaming_with(&mut |file| {
+        streamed.insert(
+            file.file_name.clone(),
+            file.check_diagnostics.iter().map(|d| d.code).collect(),
+        );
+    });
+    assert_eq!(streamed.len(), 2, "{streamed:?}");
+    for file in &result.files {
+        let final_codes: Vec<u32> = file.check_diagnostics.iter().map(|d| d.code).collect();
+        assert_eq!(streamed[&file.file_name], final_codes, "{}", file.file_name);
+    }
+    assert!(streamed["/p/a.ts"].contains(&2322), "{streamed:?}");
+
+    // An edit re-checks only b.ts; a.ts is reported from its cached result.
+    program
+        .apply_file_edits("/p/b.ts", &[edit((1, 16), (1, 22), "number")])
+        .expect("valid edit");
+    let mut order = Vec::new();
+    program.check_all_streaming_with(&mut |file| order.push(file.file_name.clone()));
+    assert_eq!(order, ["/p/a.ts", "/p/b.ts"]);
+}
+
+#[test]
+fn test_module_graph_reports_dependencies_dependents_and_cycles() {
+    let mut program = WasmProgram::new();
+    program.add_file(
+        "/p/a.ts".to_string(),
+        "import { b } from './b';\nexport const a = b;\n".to_string(),
+    );
+    program.add_file(
+        "/p/b.ts".to_string(),
+        "import { a } from './a';\nexport const b = 1;\nexport const c = () => a;\n".to_string(),
+    );
+    program.add_file(
+        "/p/main.ts".to_string(),
+        "import { a } from './a';\nimport { missing } from './missing';\nconsole.log(a, missing);\n"
+            .to_string(),
+    );
+
+    let graph = program.module_graph();
+    let names: Vec<&str> = graph.files.iter().map(|f| f.file_name.as_str()).collect();
+    assert_eq!(names, ["/p/a.ts", "/p/b.ts", "/p/main.ts"]);
+    let [a, b, main] = &graph.files[..] else {
+        panic!("three files");
+    };
+    assert_eq!(a.dependencies, ["/p/b.ts"]);
+    assert_eq!(a.dependents, ["/p/b.ts", "/p/main.ts"]);
+    assert_eq!(b.dependencies, ["/p/a.ts"]);
+    assert_eq!(b.dependents, ["/p/a.ts"]);
+    assert_eq!(
+        main.dependencies,
+        ["/p/a.ts"],
+        "unresolved imports are left out"
+    );
+    assert!(main.dependents.is_empty());
+    assert_eq!(graph.cycles, [["/p/a.ts", "/p/b.ts"]]);
+}
@@ -384,6 +384,9 @@ pub struct TransformContext {
     /// suppress their redundant `exports.<alias> = local;` statement without
     /// reading future directives.
     cjs_iife_folded_bindings: FxHashMap<String, Vec<String>>,
+    /// Caller-provided output for nodes, recorded by custom transformers.
+    /// A replaced node is written verbatim, ahead of any directive for it.
+    replacements: FxHashMap<NodeIndex, Arc<str>>,
 }
 
 impl TransformContext {
@@ -395,6 +398,7 @@ impl TransformContext {
             helpers_populated: false,
             this_capture_scopes: FxHashMap::default(),
             cjs_iife_folded_bindings: FxHashMap::default(),
+            replacements: FxHashMap::default(),
         }
     }
 
@@ -463,9 +467,30 @@ impl TransformContext {
         self.directives.contains_key(&node)
     }
 
+    /// Emit `text` in place of a node and its subtree.
+    pub fn replace_with_text(&mut self, node: NodeIndex, text: Arc<str>) {
+        self.replacements.insert(node, text);
+    }
+
+    /// Get the replacement text recorded for a node, if any
+    pub fn replacement(&self, node: NodeIndex) -> Option<&Arc<str>> {
+        self.replacements.get(&node)
+    }
+
+    /// Iterate over all recorded replacements.
+    pub fn replacements(&self) -> impl Iterator<Item = (&NodeIndex, &Arc<str>)> {
+        self.replacements.iter()
+    }
+
+    /// Check if any node has replacement text
+    pub fn has_replacements(&self) -> bool {
+        !self.replacements.is_empty()
+    }
+
     /// Clear all directives (for reuse)
     pub fn clear(&mut self) {
         self.directives.clear();
+        self.replacements.clear();
         self.helpers = HelpersNeeded::default();
         self.helpers_populated = false;
     }
@@ -667,7 +667,7 @@ impl<'a> DeclarationEmitter<'a> {
             .into_iter()
             .find_map(|module_path| {
                 let exports = binder.module_exports.get(module_path)?;
-                let root_sym_id = exports.get("export=")?;
+                let root_sym_id = exports.export_equals()?;
                 self.jsdoc_export_equals_symbol_surface(root_sym_id, exports)
             })
         {
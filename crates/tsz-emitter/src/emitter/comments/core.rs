@@ -128,14 +128,19 @@ pub fn get_leading_comment_ranges(text: &str, pos: usize) -> Vec<CommentRange> {
     let len = text.len();
     let mut i = pos;
 
-    // Skip shebang at the start of file
-    if i == 0 && len >= 2 && text.as_bytes()[0] == b'#' && text.as_bytes()[1] == b'!' {
-        while i < len {
-            let c = char_at(text, i).unwrap_or('\0');
-            if is_line_break(c) {
-                break;
+    // Skip a BOM and a shebang at the start of file
+    if i == 0 {
+        if text.starts_with('\u{FEFF}') {
+            i = '\u{FEFF}'.len_utf8();
+        }
+        if text[i..].starts_with("#!") {
+            while i < len {
+                let c = char_at(text, i).unwrap_or('\0');
+                if is_line_break(c) {
+                    break;
+                }
+                i += c.len_utf8();
             }
-            i += c.len_utf8();
         }
     }
 
@@ -975,6 +975,12 @@ impl<'a> Printer<'a> {
             return;
         }
 
+        // Text from custom transformers replaces the whole subtree
+        let replacement = if self.transforms.has_replacements() {
+            self.transforms.replacement(idx).cloned()
+        } else {
+            None
+        };
         // Check transform directives first
         let has_transform = !self.transforms.is_empty()
             && Self::kind_may_have_transform(node.kind)
@@ -982,7 +988,10 @@ impl<'a> Printer<'a> {
         let previous_pending = self.pending_source_pos;
 
         self.queue_source_mapping(node);
-        if has_transform {
+        if let Some(text) = replacement {
+            self.write(&text);
+            self.skip_comments_for_erased_node(node);
+        } else if has_transform {
             self.apply_transform(node, idx);
         } else {
             let kind = node.kind;
@@ -8,6 +8,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::VecDeque;
 use std::sync::Arc;
 use tsz_common::common::{ModuleKind, ScriptTarget};
+use tsz_common::source_map::SourceMapGenerator;
 use tsz_parser::parser::NodeIndex;
 use tsz_parser::parser::node::{Node, NodeArena};
 use tsz_parser::parser::syntax_kind_ext;
@@ -632,6 +633,11 @@ impl<'a> Printer<'a> {
         self.writer.generate_source_map_json()
     }
 
+    /// Take the source map generator (if enabled).
+    pub const fn take_source_map(&mut self) -> Option<SourceMapGenerator> {
+        self.writer.take_source_map()
+    }
+
     pub(crate) fn source_text_for_map(&self) -> Option<&'a str> {
         self.source_map_text.or(self.source_text)
     }
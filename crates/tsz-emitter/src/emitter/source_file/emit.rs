@@ -294,8 +294,11 @@ impl<'a> Printer<'a> {
 
         self.comment_emit_idx = 0;
 
-        // Emit shebang line if present (must be the very first line of output)
-        if let Some(text) = self.source_text
+        // Emit shebang line if present (must be the very first line of output).
+        // A BOM before it is not part of the output text.
+        if let Some(text) = self
+            .source_text
+            .map(|text| text.strip_prefix('\u{FEFF}').unwrap_or(text))
             && text.starts_with("#!")
         {
             if let Some(newline_pos) = text.find('\n') {
@@ -124,6 +124,14 @@ impl<'a> LoweringPass<'a> {
         }
     }
 
+    /// Start from `transforms` instead of an empty context. Nodes it already
+    /// replaces with text are not lowered, nor is anything below them.
+    #[must_use]
+    pub fn with_transforms(mut self, transforms: TransformContext) -> Self {
+        self.transforms = transforms;
+        self
+    }
+
     /// Run the lowering pass on a source file and return the transform context
     pub fn run(mut self, source_file: NodeIndex) -> TransformContext {
         self.init_module_state(source_file);
@@ -216,7 +224,7 @@ impl<'a> LoweringPass<'a> {
     /// Visit a node and its children
     pub(super) fn visit(&mut self, idx: NodeIndex) {
         // Stack overflow protection: limit recursion depth
-        if self.visit_depth >= MAX_AST_DEPTH {
+        if self.visit_depth >= MAX_AST_DEPTH || self.transforms.replacement(idx).is_some() {
             return;
         }
         self.visit_depth += 1;
@@ -0,0 +1,168 @@
+//! Exact printer - reprint a parsed file with edits applied.
+//!
+//! Unlike [`super::printer`], which emits JavaScript from the AST, this
+//! printer reproduces the original source text byte-for-byte: every node
+//! that is not edited, with its whitespace, comments and parentheses, is
+//! copied from the source. Only replaced and removed nodes change. This is
+//! the printing half of refactoring and codemod tools.
+//!
+//! Removing an element of a comma-separated list also removes its comma.
+//! With the [`NodeTrivia`] of a file, removing a node also removes its
+//! leading whitespace and comments; without it, they are kept.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut parser = ParserState::new("a.ts".to_string(), source.to_string());
+//! let root = parser.parse_source_file();
+//! let trivia = NodeTrivia::compute(parser.get_arena(), root);
+//! let mut printer = ExactPrinter::new(parser.get_arena(), root).with_trivia(&trivia);
+//! printer.replace(name, "renamed");
+//! let output = printer.print();
+//! ```
+
+use rustc_hash::FxHashMap;
+use tsz_parser::parser::NodeIndex;
+use tsz_parser::parser::node::{NodeAccess, NodeArena};
+use tsz_parser::parser::trivia::NodeTrivia;
+
+enum Edit {
+    Replace(String),
+    Remove,
+}
+
+/// Prints the file at a source file node, copying untouched text verbatim.
+pub struct ExactPrinter<'a> {
+    arena: &'a NodeArena,
+    source_file: NodeIndex,
+    text: &'a str,
+    trivia: Option<&'a NodeTrivia>,
+    edits: FxHashMap<NodeIndex, Edit>,
+}
+
+impl<'a> ExactPrinter<'a> {
+    /// Create a printer for the file at `source_file` with no edits; it
+    /// prints the file's text unchanged.
+    pub fn new(arena: &'a NodeArena, source_file: NodeIndex) -> Self {
+        let text = arena
+            .get(source_file)
+            .and_then(|node| arena.get_source_file(node))
+            .map_or("", |data| &*data.text);
+        Self {
+            arena,
+            source_file,
+            text,
+            trivia: None,
+            edits: FxHashMap::default(),
+        }
+    }
+
+    /// Use `trivia` so that removed nodes take their leading trivia along.
+    pub const fn with_trivia(mut self, trivia: &'a NodeTrivia) -> Self {
+        self.trivia = Some(trivia);
+        self
+    }
+
+    /// Print `text` in place of `node`. The node's leading trivia is kept.
+    pub fn replace(&mut self, node: NodeIndex, text: impl Into<String>) {
+        self.edits.insert(node, Edit::Replace(text.into()));
+    }
+
+    /// Drop `node` from the output, with its list separator if it is an
+    /// element of a comma-separated list.
+    pub fn remove(&mut self, node: NodeIndex) {
+        self.edits.insert(node, Edit::Remove);
+    }
+
+    /// Print the file with the edits applied.
+    pub fn print(&self) -> String {
+        let mut out = Output {
+            text: self.text,
+            code: String::with_capacity(self.text.len()),
+            cursor: 0,
+        };
+        self.print_node(self.source_file, &mut out);
+        out.copy_to(self.text.len());
+        out.code
+    }
+
+    fn print_node(&self, idx: NodeIndex, out: &mut Output<'_>) {
+        let Some(node) = self.arena.get(idx) else {
+            return;
+        };
+        let (pos, end) = (node.pos as usize, node.end as usize);
+        match self.edits.get(&idx) {
+            Some(Edit::Replace(text)) => {
+                out.copy_to(pos);
+                out.code.push_str(text);
+                out.skip_to(end);
+            }
+            Some(Edit::Remove) => {
+                let full_start = self
+                    .trivia
+                    .and_then(|trivia| trivia.full_start(idx))
+                    .map_or(pos, |start| start as usize);
+                let (start, end) = self.with_list_separator(full_start, end);
+                out.copy_to(start);
+                out.skip_to(end);
+            }
+            None => {
+                let mut children = self.arena.get_children(idx);
+                children.sort_by_key(|&child| self.arena.get(child).map_or(0, |node| node.pos));
+                for child in children {
+                    let starts_after_cursor = self
+                        .arena
+                        .get(child)
+                        .is_some_and(|node| node.pos as usize >= out.cursor);
+                    if starts_after_cursor {
+                        self.print_node(child, out);
+                    }
+                }
+                out.copy_to(end);
+            }
+        }
+    }
+
+    /// Widen the removed span `start..end` over the comma that separates it
+    /// from its neighbours: the one before it, or, for the first element of
+    /// a list, the one after it together with the spaces that follow.
+    fn with_list_separator(&self, start: usize, end: usize) -> (usize, usize) {
+        let before = self.text.get(..start).unwrap_or_default().trim_end();
+        if before.ends_with(',') {
+            return (before.len() - 1, end);
+        }
+        let after = self.text.get(end..).unwrap_or_default().trim_start();
+        if let Some(rest) = after.strip_prefix(',') {
+            let rest = rest.trim_start_matches([' ', '\t']);
+            return (start, self.text.len() - rest.len());
+        }
+        (start, end)
+    }
+}
+
+struct Output<'a> {
+    text: &'a str,
+    code: String,
+    /// Source offset up to which the text has been printed or skipped.
+    cursor: usize,
+}
+
+impl Output<'_> {
+    /// Copy the source text from the cursor up to `pos`.
+    fn copy_to(&mut self, pos: usize) {
+        if pos > self.cursor {
+            self.code
+                .push_str(self.text.get(self.cursor..pos).unwrap_or_default());
+            self.cursor = pos;
+        }
+    }
+
+    /// Move the cursor to `pos` without printing the text before it.
+    fn skip_to(&mut self, pos: usize) {
+        self.cursor = self.cursor.max(pos);
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/exact_printer_tests.rs"]
+mod tests;
@@ -2,7 +2,9 @@
 //!
 //! This module groups the output layer:
 //! - [`printer`]: High-level AST-to-JavaScript printing interface
+//! - [`exact_printer`]: Source-preserving reprinting with node edits
 //! - [`source_writer`]: Low-level text buffer with source map tracking
 
+pub mod exact_printer;
 pub mod printer;
 pub mod source_writer;
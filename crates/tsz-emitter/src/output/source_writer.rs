@@ -574,8 +574,8 @@ impl SourceWriter {
     }
 
     /// Take the source map generator (if any)
-    pub fn take_source_map(self) -> Option<SourceMapGenerator> {
-        self.source_map
+    pub const fn take_source_map(&mut self) -> Option<SourceMapGenerator> {
+        self.source_map.take()
     }
 
     /// Generate source map JSON (if source mapping is enabled)
@@ -0,0 +1,116 @@
+use super::*;
+use tsz_parser::parser::ParserState;
+use tsz_parser::parser::syntax_kind_ext;
+
+fn parse(source: &str) -> (ParserState, NodeIndex) {
+    let mut parser = ParserState::new("test.ts".to_string(), source.to_string());
+    let root = parser.parse_source_file();
+    (parser, root)
+}
+
+fn statements(parser: &ParserState, root: NodeIndex) -> Vec<NodeIndex> {
+    let arena = parser.get_arena();
+    arena
+        .get_source_file(arena.get(root).unwrap())
+        .unwrap()
+        .statements
+        .nodes
+        .clone()
+}
+
+#[test]
+fn test_exact_printer_round_trips_untouched_source() {
+    let source = "\u{FEFF}// header\r\nimport { a,b } from \"./a\" ;\n\nconst  x = ((a)) + /* inline */ b;;\n\
+                  class C<T> {\n\tprivate   m( p : T ) : T { return (p) } // trailing\n}\n\
+                  type U = ( string | number )[];\n/* eof */";
+    let (parser, root) = parse(source);
+    let printer = ExactPrinter::new(parser.get_arena(), root);
+    assert_eq!(printer.print(), source);
+}
+
+#[test]
+fn test_exact_printer_replaces_only_the_edited_node() {
+    let source = "const x = compute( 1,2 ); // keep me\nconsole.log(x);\n";
+    let (parser, root) = parse(source);
+    let arena = parser.get_arena();
+    let declaration_list = arena
+        .get_variable(arena.get(statements(&parser, root)[0]).unwrap())
+        .unwrap()
+        .declarations
+        .nodes[0];
+    let declaration = arena
+        .get_variable(arena.get(declaration_list).unwrap())
+        .unwrap()
+        .declarations
+        .nodes[0];
+    let initializer = arena
+        .get_variable_declaration(arena.get(declaration).unwrap())
+        .unwrap()
+        .initializer;
+    assert_eq!(
+        arena.get(initializer).map(|node| node.kind),
+        Some(syntax_kind_ext::CALL_EXPRESSION)
+    );
+
+    let mut printer = ExactPrinter::new(arena, root);
+    printer.replace(initializer, "42");
+    assert_eq!(
+        printer.print(),
+        "const x = 42; // keep me\nconsole.log(x);\n"
+    );
+}
+
+#[test]
+fn test_exact_printer_removes_nodes_with_their_leading_trivia() {
+    let source = "let a = 1;\n// about b\nlet b = 2;\nlet c = 3;\n";
+    let (parser, root) = parse(source);
+    let second = statements(&parser, root)[1];
+
+    let trivia = NodeTrivia::compute(parser.get_arena(), root);
+    let mut printer = ExactPrinter::new(parser.get_arena(), root).with_trivia(&trivia);
+    printer.remove(second);
+    assert_eq!(printer.print(), "let a = 1;\nlet c = 3;\n");
+
+    // Without trivia the comment stays.
+    let mut printer = ExactPrinter::new(parser.get_arena(), root);
+    printer.remove(second);
+    assert_eq!(printer.print(), "let a = 1;\n// about b\n\nlet c = 3;\n");
+}
+
+#[test]
+fn test_exact_printer_removes_list_elements_with_their_comma() {
+    let source = "f(a, b, c);\n";
+    let (parser, root) = parse(source);
+    let arena = parser.get_arena();
+    let expression = arena
+        .get_expression_statement(arena.get(statements(&parser, root)[0]).unwrap())
+        .unwrap()
+        .expression;
+    let arguments = arena
+        .get_call_expr(arena.get(expression).unwrap())
+        .unwrap()
+        .arguments
+        .as_ref()
+        .unwrap()
+        .nodes
+        .clone();
+    let trivia = NodeTrivia::compute(arena, root);
+
+    let mut printer = ExactPrinter::new(arena, root).with_trivia(&trivia);
+    printer.remove(arguments[0]);
+    assert_eq!(printer.print(), "f(b, c);\n");
+
+    let mut printer = ExactPrinter::new(arena, root).with_trivia(&trivia);
+    printer.remove(arguments[1]);
+    assert_eq!(printer.print(), "f(a, c);\n");
+
+    let mut printer = ExactPrinter::new(arena, root).with_trivia(&trivia);
+    printer.remove(arguments[2]);
+    assert_eq!(printer.print(), "f(a, b);\n");
+
+    let mut printer = ExactPrinter::new(arena, root).with_trivia(&trivia);
+    for argument in &arguments {
+        printer.remove(*argument);
+    }
+    assert_eq!(printer.print(), "f();\n");
+}
@@ -663,7 +663,7 @@ fn generate_source_map_json_returns_none_when_disabled() {
 
 #[test]
 fn take_source_map_returns_none_when_disabled() {
-    let writer = SourceWriter::new();
+    let mut writer = SourceWriter::new();
     assert!(writer.take_source_map().is_none());
 }
 
@@ -100,3 +100,20 @@ fn test_commonjs_export_names_shared() {
         _ => panic!("Expected CommonJSExport directive"),
     }
 }
+
+#[test]
+fn test_replacement_text() {
+    let mut ctx = TransformContext::new();
+    let node = NodeIndex(7);
+
+    assert!(!ctx.has_replacements());
+    ctx.replace_with_text(node, Arc::from("foo();"));
+    assert!(ctx.has_replacements());
+    assert_eq!(ctx.replacement(node).map(|text| &**text), Some("foo();"));
+    assert!(ctx.replacement(NodeIndex(8)).is_none());
+    // Replacements are not directives
+    assert!(ctx.is_empty());
+
+    ctx.clear();
+    assert!(!ctx.has_replacements());
+}
@@ -7,7 +7,7 @@
 //! - Workspace edit support for willCreate/willDelete
 
 use crate::rename::{TextEdit, WorkspaceEdit};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tsz_parser::parser::node::NodeAccess;
 
 use super::code_action_provider::{CodeAction, CodeActionKind, CodeActionProvider};
@@ -134,83 +134,160 @@ impl<'a> CodeActionProvider<'a> {
 
     /// Remove unused imports source action.
     ///
-    /// Scans all imports and removes any that the binder marks as unused.
+    /// Drops every import binding that is never referenced in the file; see
+    /// [`Self::remove_unused_imports`].
     fn remove_unused_imports_action(&self, root: tsz_parser::NodeIndex) -> Option<CodeAction> {
-        let root_node = self.arena.get(root)?;
-        let source_data = self.arena.get_source_file(root_node)?;
+        let edits = self.remove_unused_imports(root);
+        if edits.is_empty() {
+            return None;
+        }
 
-        let mut edits = Vec::new();
+        let mut changes = FxHashMap::default();
+        changes.insert(self.file_name.clone(), edits);
+
+        Some(CodeAction {
+            title: "Remove Unused Imports".to_string(),
+            kind: CodeActionKind::SourceRemoveUnusedImports,
+            edit: Some(WorkspaceEdit { changes }),
+            is_preferred: false,
+            data: None,
+        })
+    }
+
+    /// Edits that remove unused import bindings.
+    ///
+    /// A declaration none of whose bindings are referenced is removed whole;
+    /// otherwise only its unused default import or named specifiers go.
+    /// Side-effect imports (`import "./polyfill"`) are always kept. A binding
+    /// counts as used when an identifier with its name appears anywhere
+    /// outside the file's import declarations, when a JSDoc comment names it
+    /// (`{@link X}`, `@type {X}`), or when it is the root of a JSX factory
+    /// that the file's JSX calls under `jsx: react`.
+    pub fn remove_unused_imports(&self, root: tsz_parser::NodeIndex) -> Vec<TextEdit> {
+        let Some(source_data) = self
+            .arena
+            .get(root)
+            .and_then(|node| self.arena.get_source_file(node))
+        else {
+            return Vec::new();
+        };
+
+        let import_spans: Vec<(u32, u32)> = source_data
+            .statements
+            .nodes
+            .iter()
+            .filter_map(|&stmt_idx| self.arena.get(stmt_idx))
+            .filter(|stmt_node| {
+                stmt_node.kind == tsz_parser::syntax_kind_ext::IMPORT_DECLARATION
+                    || stmt_node.kind == tsz_parser::syntax_kind_ext::IMPORT_EQUALS_DECLARATION
+            })
+            .map(|stmt_node| (stmt_node.pos, stmt_node.end))
+            .collect();
+        let mut referenced: FxHashSet<&str> = self.names_referenced_outside(&import_spans);
+        referenced.extend(self.names_referenced_in_jsdoc(source_data));
+        referenced.extend(self.jsx_factory_names_used());
+        let is_used = |name_idx: tsz_parser::NodeIndex| {
+            self.arena
+                .get_identifier_text(name_idx)
+                .is_some_and(|name| referenced.contains(name))
+        };
 
+        let mut edits = Vec::new();
         for &stmt_idx in &source_data.statements.nodes {
-            let stmt_node = self.arena.get(stmt_idx)?;
+            let Some(stmt_node) = self.arena.get(stmt_idx) else {
+                continue;
+            };
             if stmt_node.kind != tsz_parser::syntax_kind_ext::IMPORT_DECLARATION {
                 continue;
             }
-
-            let import_data = self.arena.get_import_decl(stmt_node)?;
+            let Some(import_data) = self.arena.get_import_decl(stmt_node) else {
+                continue;
+            };
             if import_data.import_clause.is_none() {
                 continue; // Side-effect import, keep it
             }
-
-            let clause_node = self.arena.get(import_data.import_clause)?;
-            let clause = self.arena.get_import_clause(clause_node)?;
-
-            // Check if default import is unused
-            let default_unused = if clause.name.is_some() {
-                self.is_identifier_unused(clause.name)
-            } else {
-                true // no default import
+            let Some(clause) = self
+                .arena
+                .get(import_data.import_clause)
+                .and_then(|node| self.arena.get_import_clause(node))
+            else {
+                continue;
             };
 
-            // Check if namespace/named bindings are unused
-            let bindings_unused = if clause.named_bindings.is_some() {
-                let bindings_node = self.arena.get(clause.named_bindings)?;
+            let default_used = clause.name.is_some() && is_used(clause.name);
+            let bindings_node = self.arena.get(clause.named_bindings);
+            // (specifier, used) for `{ ... }` bindings; a namespace import is
+            // tracked on its own.
+            let mut specifiers = Vec::new();
+            let mut namespace_used = false;
+            if let Some(bindings_node) = bindings_node {
                 if bindings_node.kind == tsz_parser::syntax_kind_ext::NAMESPACE_IMPORT {
-                    let named = self.arena.get_named_imports(bindings_node);
-                    named
-                        .map(|n| self.is_identifier_unused(n.name))
-                        .unwrap_or(true)
-                } else if bindings_node.kind == tsz_parser::syntax_kind_ext::NAMED_IMPORTS {
-                    let named = self.arena.get_named_imports(bindings_node);
-                    named
-                        .map(|n| {
-                            n.elements
-                                .nodes
-                                .iter()
-                                .all(|&spec| self.is_import_specifier_unused(spec))
-                        })
-                        .unwrap_or(true)
-                } else {
-                    true
+                    namespace_used = self
+                        .arena
+                        .get_named_imports(bindings_node)
+                        .is_some_and(|named| is_used(named.name));
+                } else if bindings_node.kind == tsz_parser::syntax_kind_ext::NAMED_IMPORTS
+                    && let Some(named) = self.arena.get_named_imports(bindings_node)
+                {
+                    for &spec_idx in &named.elements.nodes {
+                        specifiers
+                            .push((spec_idx, self.is_import_specifier_used(spec_idx, &is_used)));
+                    }
                 }
-            } else {
-                true // no bindings
-            };
+            }
+            let named_used = specifiers.iter().any(|(_, used)| *used);
+            let bindings_used = namespace_used || named_used;
 
-            if default_unused && bindings_unused {
-                // Remove the entire import
+            if !default_used && !bindings_used {
                 let (range, _) = self.declaration_removal_range(stmt_node);
                 edits.push(TextEdit {
                     range,
                     new_text: String::new(),
                 });
+                continue;
             }
-        }
+            let Some(bindings_node) = bindings_node else {
+                continue;
+            };
 
-        if edits.is_empty() {
-            return None;
-        }
+            if clause.name.is_some() && !default_used {
+                // `import D, { a } from` -> `import { a } from`
+                if let (Some(name_start), Some(bindings_start)) = (
+                    self.token_start(clause.name),
+                    self.token_start(clause.named_bindings),
+                ) {
+                    edits.push(self.offset_edit(name_start, bindings_start, String::new()));
+                }
+            } else if clause.name.is_some() && !bindings_used {
+                // `import D, { a } from` -> `import D from`
+                if let Some(name_node) = self.arena.get(clause.name) {
+                    edits.push(self.offset_edit(name_node.end, bindings_node.end, String::new()));
+                }
+                continue;
+            }
 
-        let mut changes = FxHashMap::default();
-        changes.insert(self.file_name.clone(), edits);
+            if specifiers.iter().any(|(_, used)| !*used) {
+                let kept: Vec<&str> = specifiers
+                    .iter()
+                    .filter(|(_, used)| *used)
+                    .filter_map(|&(spec_idx, _)| {
+                        let spec_node = self.arena.get(spec_idx)?;
+                        self.source
+                            .get(spec_node.pos as usize..spec_node.end as usize)
+                            .map(str::trim)
+                    })
+                    .collect();
+                if let Some(bindings_start) = self.token_start(clause.named_bindings) {
+                    edits.push(self.offset_edit(
+                        bindings_start,
+                        bindings_node.end,
+                        format!("{{ {} }}", kept.join(", ")),
+                    ));
+                }
+            }
+        }
 
-        Some(CodeAction {
-            title: "Remove Unused Imports".to_string(),
-            kind: CodeActionKind::SourceRemoveUnusedImports,
-            edit: Some(WorkspaceEdit { changes }),
-            is_preferred: false,
-            data: None,
-        })
+        edits
     }
 
     /// Sort import declarations source action.
@@ -229,29 +306,102 @@ impl<'a> CodeActionProvider<'a> {
         })
     }
 
-    /// Check if an identifier node appears to be unused.
-    ///
-    /// Uses the binder's symbol resolution to check if a symbol has references.
-    fn is_identifier_unused(&self, name_idx: tsz_parser::NodeIndex) -> bool {
-        if name_idx.is_none() {
-            return true;
+    /// Names of identifiers that occur outside the given source spans.
+    fn names_referenced_outside(&self, spans: &[(u32, u32)]) -> FxHashSet<&'a str> {
+        let mut names = FxHashSet::default();
+        for i in 0..self.arena.nodes.len() {
+            let node_idx = tsz_parser::NodeIndex(i as u32);
+            let Some(node) = self.arena.get(node_idx) else {
+                continue;
+            };
+            if node.kind != tsz_scanner::SyntaxKind::Identifier as u16
+                || spans
+                    .iter()
+                    .any(|&(start, end)| node.pos >= start && node.pos < end)
+            {
+                continue;
+            }
+            if let Some(name) = self.arena.get_identifier_text(node_idx) {
+                names.insert(name);
+            }
         }
+        names
+    }
 
-        let Some(sym_id) = self.binder.resolve_identifier(self.arena, name_idx) else {
-            return true;
-        };
-        let Some(symbol) = self.binder.symbols.get(sym_id) else {
-            return true;
-        };
+    /// Names JSDoc comments refer to: the targets of `{@link X}` (and
+    /// `@linkcode`, `@linkplain`), and the names inside `{...}` type
+    /// expressions such as `@type {X}` or `@param {Array<X>} xs`.
+    fn names_referenced_in_jsdoc(
+        &self,
+        source_data: &tsz_parser::parser::node::SourceFileData,
+    ) -> FxHashSet<&'a str> {
+        let source = self.source;
+        let mut names = FxHashSet::default();
+        for comment in &source_data.comments {
+            if !tsz_common::comments::is_jsdoc_comment(comment, source) {
+                continue;
+            }
+            let Some(mut rest) = source.get(comment.pos as usize..comment.end as usize) else {
+                continue;
+            };
+            while let Some(open) = rest.find('{') {
+                let after_open = &rest[open + 1..];
+                let Some(close) = after_open.find('}') else {
+                    break;
+                };
+                let body = &after_open[..close];
+                rest = &after_open[close + 1..];
+                if let Some(tag) = body.strip_prefix('@') {
+                    if !tag.starts_with("link") {
+                        continue;
+                    }
+                    // `{@link Foo.bar|text}` refers to `Foo`.
+                    let target = tag
+                        .split_whitespace()
+                        .nth(1)
+                        .and_then(|target| target.split(['.', '#', '|', '(']).next());
+                    names.extend(target.filter(|target| !target.is_empty()));
+                } else {
+                    names.extend(
+                        body.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+                            .filter(|word| !word.is_empty()),
+                    );
+                }
+            }
+        }
+        names
+    }
 
-        // If the symbol has only 1 declaration (the import itself) and no references,
-        // it's likely unused. This is a heuristic — full unused detection requires
-        // cross-file analysis from the checker.
-        symbol.declarations.len() <= 1
+    /// Root names of the JSX factories the file's JSX calls under
+    /// `jsx: react`: the element factory for any JSX, plus the fragment
+    /// factory for fragments.
+    fn jsx_factory_names_used(&self) -> Vec<&str> {
+        let Some(factories) = &self.react_jsx_factories else {
+            return Vec::new();
+        };
+        let (mut has_element, mut has_fragment) = (false, false);
+        for node in &self.arena.nodes {
+            has_element |= node.kind == tsz_parser::syntax_kind_ext::JSX_ELEMENT
+                || node.kind == tsz_parser::syntax_kind_ext::JSX_SELF_CLOSING_ELEMENT;
+            has_fragment |= node.kind == tsz_parser::syntax_kind_ext::JSX_FRAGMENT;
+        }
+        // `React.createElement` is called through `React`.
+        let mut names = Vec::new();
+        if has_element || has_fragment {
+            names.extend(factories.factory.split('.').next());
+        }
+        if has_fragment {
+            names.extend(factories.fragment_factory.split('.').next());
+        }
+        names
     }
 
-    /// Check if an import specifier is unused.
-    fn is_import_specifier_unused(&self, spec_idx: tsz_parser::NodeIndex) -> bool {
+    /// Check if an import specifier's local binding is used.
+    fn is_import_specifier_used(
+        &self,
+        spec_idx: tsz_parser::NodeIndex,
+        is_used: &impl Fn(tsz_parser::NodeIndex) -> bool,
+    ) -> bool {
         let Some(spec_node) = self.arena.get(spec_idx) else {
             return true;
         };
@@ -267,7 +417,24 @@ impl<'a> CodeActionProvider<'a> {
         } else {
             spec_data.property_name
         };
-        self.is_identifier_unused(local_name)
+        is_used(local_name)
+    }
+
+    /// Offset of the first token of a node, skipping its leading trivia.
+    fn token_start(&self, node_idx: tsz_parser::NodeIndex) -> Option<u32> {
+        let node = self.arena.get(node_idx)?;
+        let text = self.source.get(node.pos as usize..node.end as usize)?;
+        Some(node.pos + (text.len() - text.trim_start().len()) as u32)
+    }
+
+    fn offset_edit(&self, start: u32, end: u32, new_text: String) -> TextEdit {
+        TextEdit {
+            range: Range::new(
+                self.line_map.offset_to_position(start, self.source),
+                self.line_map.offset_to_position(end, self.source),
+            ),
+            new_text,
+        }
     }
 
     /// Find all files that import the current file.
@@ -66,8 +66,6 @@ impl CodeFixRegistry {
             // === fixSpelling (standalone codes) ===
             // Property '{0}' does not exist on type '{1}'. Did you mean '{2}'?
             2551 |
-            // Cannot find name '{0}'. Did you mean '{1}'?
-            2552 |
             // Could not find name '{0}'. Did you mean '{1}'?
             2839 |
             // Cannot find namespace '{0}'. Did you mean '{1}'?
@@ -83,6 +81,15 @@ impl CodeFixRegistry {
                 vec![("spelling", "fixSpelling", "Change spelling", "Fix all detected spelling errors")]
             }
 
+            // === fixSpelling + import (fixMissingImport) ===
+            // Cannot find name '{0}'. Did you mean '{1}'?
+            2552 => {
+                vec![
+                    ("spelling", "fixSpelling", "Change spelling", "Fix all detected spelling errors"),
+                    ("import", "fixMissingImport", "Add import", "Add all missing imports"),
+                ]
+            }
+
             // === import (fixMissingImport) + addMissingConst + addMissingMember ===
             // Cannot find name '{0}'.
             2304 => {
@@ -634,6 +641,7 @@ impl CodeFixRegistry {
         matches!(
             code,
             2304 // Cannot find name
+            | 2552 // Cannot find name, did you mean
             | 2503 // Cannot find namespace
             | 2583 // Cannot find name (target library variant)
             | 2693 // Only refers to a type, but is being used as a value
@@ -648,7 +656,8 @@ impl CodeFixRegistry {
     pub fn supported_error_codes() -> Vec<u32> {
         vec![
             2663, 2662, // fixSpelling + fixForgottenThisPropertyAccess
-            2551, 2552, 2839, 2833, 2568, 4117, 4123, 2724, // fixSpelling
+            2551, 2839, 2833, 2568, 4117, 4123, 2724, // fixSpelling
+            2552, // fixSpelling + import
             2304, // import + addMissingConst + addMissingMember + forgottenThisPropertyAccess
             2503, 2693, 2583, // import
             6133, 6196, 6138, 6192, 6198, 6199, 6205, // fixUnusedIdentifier
@@ -428,6 +428,32 @@ impl<'a> CodeActionProvider<'a> {
             .or_else(|| start_offset.checked_sub(1).and_then(&mut try_offset))
     }
 
+    /// Whether `node_idx` is the tag name of a JSX element that refers to an
+    /// intrinsic element (`<div>`) rather than a component (`<Button>`).
+    fn is_intrinsic_jsx_tag_name(&self, node_idx: NodeIndex) -> bool {
+        let Some(parent) = self
+            .arena
+            .get_extended(node_idx)
+            .and_then(|ext| self.arena.get(ext.parent))
+        else {
+            return false;
+        };
+        let tag_name = self
+            .arena
+            .get_jsx_opening(parent)
+            .map(|opening| opening.tag_name)
+            .or_else(|| {
+                self.arena
+                    .get_jsx_closing(parent)
+                    .map(|closing| closing.tag_name)
+            });
+        tag_name == Some(node_idx)
+            && self
+                .arena
+                .get_identifier_text(node_idx)
+                .is_some_and(|name| name.starts_with(|c: char| c.is_ascii_lowercase()))
+    }
+
     fn missing_name_from_diag(&self, diag: &LspDiagnostic) -> Option<String> {
         let message = diag.message.as_str();
         let start = message.find('\'')?;
@@ -613,9 +639,14 @@ impl<'a> CodeActionProvider<'a> {
             }
         }
 
-        new_text.push_str(" from \"");
+        let (quote, semicolon) = self.import_statement_style(root);
+        new_text.push_str(" from ");
+        new_text.push(quote);
         new_text.push_str(&candidate.module_specifier);
-        new_text.push_str("\";");
+        new_text.push(quote);
+        if semicolon {
+            new_text.push(';');
+        }
         new_text.push_str(newline);
         if insert_at_file_start && !has_leading_import {
             new_text.push_str(newline);
@@ -666,12 +697,23 @@ impl<'a> CodeActionProvider<'a> {
             .or_else(|| self.find_identifier_usage_by_name(&candidate.local_name))
             .unwrap_or(ImportUsage::Value);
 
+        resolved.is_type_only = self.import_is_type_only(candidate, usage)?;
+
+        self.build_import_edit(root, &resolved)
+    }
+
+    /// Whether `candidate`, used as `usage`, is imported with `import type`;
+    /// `None` when a type-only declaration is used as a value and cannot be
+    /// imported at all.
+    ///
+    /// Type-only declarations always come in through `import type`; values
+    /// referenced only as types do too under `verbatimModuleSyntax`, where a
+    /// plain import would be preserved in the emitted JavaScript.
+    fn import_is_type_only(&self, candidate: &ImportCandidate, usage: ImportUsage) -> Option<bool> {
         if usage == ImportUsage::Value && candidate.is_type_only {
             return None;
         }
-        resolved.is_type_only = usage == ImportUsage::Type;
-
-        self.build_import_edit(root, &resolved)
+        Some(candidate.is_type_only || (self.verbatim_module_syntax && usage == ImportUsage::Type))
     }
 
     fn try_merge_default_import(
@@ -1294,6 +1336,44 @@ impl<'a> CodeActionProvider<'a> {
         Some((Position::new(0, 0), false))
     }
 
+    /// Quote character and trailing-semicolon style for a new import line.
+    ///
+    /// An explicit `quotePreference` wins; otherwise both are copied from the
+    /// file's first import declaration, defaulting to `"` and `;` when the
+    /// file has no imports to learn from.
+    fn import_statement_style(&self, root: NodeIndex) -> (char, bool) {
+        let mut quote = None;
+        let mut semicolon = true;
+        if let Some(source_file) = self.arena.get_source_file_at(root)
+            && let Some((stmt_node, import_decl)) =
+                source_file.statements.nodes.iter().find_map(|&stmt_idx| {
+                    let stmt_node = self.arena.get(stmt_idx)?;
+                    if stmt_node.kind != syntax_kind_ext::IMPORT_DECLARATION {
+                        return None;
+                    }
+                    Some((stmt_node, self.arena.get_import_decl(stmt_node)?))
+                })
+        {
+            quote = self
+                .arena
+                .get(import_decl.module_specifier)
+                .and_then(|spec| self.source.get(spec.pos as usize..spec.end as usize))
+                .and_then(|text| text.trim_start().chars().next())
+                .filter(|&ch| ch == '"' || ch == '\'');
+            semicolon = self
+                .source
+                .get(stmt_node.pos as usize..stmt_node.end as usize)
+                .is_none_or(|text| text.trim_end().ends_with(';'));
+        }
+
+        let quote = match self.quote_preference.as_deref() {
+            Some("single") => '\'',
+            Some("double") => '"',
+            _ => quote.unwrap_or('"'),
+        };
+        (quote, semicolon)
+    }
+
     fn first_statement_is_import(&self, root: NodeIndex) -> bool {
         let Some(root_node) = self.arena.get(root) else {
             return false;
@@ -1663,6 +1743,7 @@ impl<'a> CodeActionProvider<'a> {
             None => return Vec::new(),
         };
         if code != tsz_checker::diagnostics::diagnostic_codes::CANNOT_FIND_NAME
+            && code != tsz_checker::diagnostics::diagnostic_codes::CANNOT_FIND_NAME_DID_YOU_MEAN
             && code != tsz_checker::diagnostics::diagnostic_codes::CANNOT_FIND_NAMESPACE
         {
             return Vec::new();
@@ -1671,21 +1752,24 @@ impl<'a> CodeActionProvider<'a> {
         let Some((missing_name, usage)) = self.diagnostic_identifier_usage(diag) else {
             return Vec::new();
         };
+        // Lowercase JSX tags are intrinsic elements, never imported components.
+        if self
+            .identifier_node_at_range(diag.range)
+            .is_some_and(|node_idx| self.is_intrinsic_jsx_tag_name(node_idx))
+        {
+            return Vec::new();
+        }
 
         let mut actions = Vec::new();
         for candidate in candidates {
             if candidate.local_name != missing_name {
                 continue;
             }
-            if usage == ImportUsage::Value && candidate.is_type_only {
+            let Some(is_type_only) = self.import_is_type_only(candidate, usage) else {
                 continue;
-            }
-
+            };
             let mut resolved = candidate.clone();
-            // Use `import type` when the identifier is only used in a type position
-            // (type annotations, implements clauses, etc.). For value usage, use a
-            // regular import so the symbol is available at runtime.
-            resolved.is_type_only = usage == ImportUsage::Type;
+            resolved.is_type_only = is_type_only;
 
             let Some(edits) = self.build_import_edit(root, &resolved) else {
                 continue;
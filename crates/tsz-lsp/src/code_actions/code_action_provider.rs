@@ -139,6 +139,26 @@ pub struct CodeActionContext {
     pub import_candidates: Vec<ImportCandidate>,
 }
 
+/// The functions `jsx: react` output calls for JSX elements and fragments.
+/// A file with JSX uses the root name of each (`React` for
+/// `React.createElement`), so an import of that name is not unused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReactJsxFactories {
+    /// `jsxFactory`.
+    pub factory: String,
+    /// `jsxFragmentFactory`.
+    pub fragment_factory: String,
+}
+
+impl Default for ReactJsxFactories {
+    fn default() -> Self {
+        Self {
+            factory: "React.createElement".to_string(),
+            fragment_factory: "React.Fragment".to_string(),
+        }
+    }
+}
+
 // =============================================================================
 // Code Action Provider
 // =============================================================================
@@ -153,6 +173,9 @@ pub struct CodeActionProvider<'a> {
     pub(super) organize_imports_ignore_case: bool,
     pub(super) organize_imports_type_order: Option<String>,
     pub(super) new_line_override: Option<String>,
+    pub(super) quote_preference: Option<String>,
+    pub(super) verbatim_module_syntax: bool,
+    pub(super) react_jsx_factories: Option<ReactJsxFactories>,
 }
 
 impl<'a> CodeActionProvider<'a> {
@@ -173,6 +196,9 @@ impl<'a> CodeActionProvider<'a> {
             organize_imports_ignore_case: true,
             organize_imports_type_order: None,
             new_line_override: None,
+            quote_preference: None,
+            verbatim_module_syntax: false,
+            react_jsx_factories: None,
         }
     }
 
@@ -189,6 +215,9 @@ impl<'a> CodeActionProvider<'a> {
             organize_imports_ignore_case: true,
             organize_imports_type_order: None,
             new_line_override: None,
+            quote_preference: None,
+            verbatim_module_syntax: false,
+            react_jsx_factories: None,
         }
     }
 
@@ -210,6 +239,28 @@ impl<'a> CodeActionProvider<'a> {
         self
     }
 
+    /// Set the quote style used for module specifiers in inserted imports
+    /// (`"single"`, `"double"`, or `"auto"`). When unset or `"auto"`, the
+    /// style of the file's existing imports is reused.
+    pub fn with_quote_preference(mut self, quote_preference: Option<String>) -> Self {
+        self.quote_preference = quote_preference;
+        self
+    }
+
+    /// Mirror the `verbatimModuleSyntax` compiler option: symbols that are
+    /// only referenced as types are imported with `import type`.
+    pub const fn with_verbatim_module_syntax(mut self, verbatim: bool) -> Self {
+        self.verbatim_module_syntax = verbatim;
+        self
+    }
+
+    /// Set the JSX factories when the `jsx` compiler option is `react`;
+    /// `None` for any other JSX emit, which calls no factory by name.
+    pub fn with_react_jsx_factories(mut self, factories: Option<ReactJsxFactories>) -> Self {
+        self.react_jsx_factories = factories;
+        self
+    }
+
     /// Provide code actions for a range in the source code.
     pub fn provide_code_actions(
         &self,
@@ -37,7 +37,7 @@ pub use code_action_fixes::{
 };
 pub use code_action_provider::{
     CodeAction, CodeActionContext, CodeActionKind, CodeActionProvider, ImportCandidate,
-    ImportCandidateKind,
+    ImportCandidateKind, ReactJsxFactories,
 };
 #[cfg(test)]
 pub(crate) use code_action_quick_fixes::ADD_MISSING_AWAIT_TITLE;
@@ -0,0 +1,557 @@
+//! Override and constructor snippet completions for class bodies.
+//!
+//! At a member position inside a class, offers one snippet per inherited
+//! member that the class does not declare yet, expanding to the member's full
+//! typed signature:
+//! - base-class methods become `override` methods whose body forwards to
+//!   `super.name(...)`,
+//! - abstract base methods and interface methods get a throwing stub body,
+//! - properties become typed property declarations,
+//! - a missing constructor in a derived class becomes a constructor with the
+//!   base constructor's parameters and a `super(...)` call.
+//!
+//! Base classes and interfaces are resolved through the file's binder. Heritage
+//! the binder cannot resolve (a class imported from another module) is handed
+//! to a caller-supplied resolver; `Project` supplies one that follows imports.
+
+use tsz_parser::parser::node::Node;
+
+use super::*;
+
+/// Source marker shared with tsserver's class member snippet entries.
+const CLASS_MEMBER_SNIPPET_SOURCE: &str = "ClassMemberSnippet/";
+
+/// Indentation used for snippet bodies; clients re-indent relative to the
+/// cursor line.
+const SNIPPET_INDENT: &str = "    ";
+
+/// Stub body for members without an implementation to forward to.
+const NOT_IMPLEMENTED_BODY: &str = "throw new Error(\"Method not implemented.\");";
+
+/// Where an inherited member comes from.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MemberOrigin {
+    /// A concrete base-class member: emit `override` and forward to `super`.
+    BaseClass,
+    /// An abstract base-class member: emit `override` with a stub body.
+    AbstractBaseClass,
+    /// An implemented interface member: no `override`, stub body.
+    Interface,
+}
+
+/// A class or interface declaration paired with a provider for the file that
+/// declares it.
+pub(crate) type HeritageDeclaration<'p> = (Completions<'p>, NodeIndex);
+
+impl<'a> Completions<'a> {
+    /// Member-override and constructor snippets for a class body position.
+    ///
+    /// Returns an empty list outside class member positions or when the class
+    /// has no resolvable heritage. Only heritage declared in this file is
+    /// expanded; see [`Self::get_class_member_override_snippets_with`].
+    pub fn get_class_member_override_snippets(
+        &self,
+        root: NodeIndex,
+        position: Position,
+    ) -> Vec<CompletionItem> {
+        self.get_class_member_override_snippets_with(root, position, &|_, _| Vec::new())
+    }
+
+    /// Like [`Self::get_class_member_override_snippets`], with `imported`
+    /// resolving heritage expressions the declaring file's binder cannot
+    /// (classes and interfaces imported from other files).
+    pub(crate) fn get_class_member_override_snippets_with<'p>(
+        &self,
+        root: NodeIndex,
+        position: Position,
+        imported: &dyn Fn(&Completions<'p>, NodeIndex) -> Vec<HeritageDeclaration<'p>>,
+    ) -> Vec<CompletionItem>
+    where
+        'a: 'p,
+    {
+        let Some(offset) = self.line_map.position_to_offset(position, self.source_text) else {
+            return Vec::new();
+        };
+        let node_idx = self.find_completions_node(root, offset);
+        if self.member_body_context(node_idx, offset) != Some("class") {
+            return Vec::new();
+        }
+        let Some(class_idx) = self.find_enclosing_class_declaration(node_idx) else {
+            return Vec::new();
+        };
+        let this_file: Completions<'p> = self.heritage_provider();
+
+        let mut seen = self.class_declared_member_names(class_idx);
+        let mut items = Vec::new();
+
+        let extends_expr = self.class_extends_expression(class_idx);
+        let needs_constructor = extends_expr.is_some() && !self.class_has_constructor(class_idx);
+        let mut base_constructor = None;
+
+        let mut visited = FxHashSet::default();
+        let mut current =
+            extends_expr.and_then(|expr| Self::resolve_heritage_class(&this_file, expr, imported));
+        while let Some((provider, base_idx)) = current {
+            if !visited.insert((std::ptr::from_ref(provider.arena) as usize, base_idx)) {
+                break;
+            }
+            let Some(base) = provider
+                .arena
+                .get(base_idx)
+                .and_then(|node| provider.arena.get_class(node))
+            else {
+                break;
+            };
+            if base_constructor.is_none() {
+                base_constructor = provider.constructor_parameter_texts(base_idx);
+            }
+            for &member_idx in &base.members.nodes {
+                if let Some(item) = provider.inherited_member_snippet(member_idx, true, &mut seen) {
+                    items.push(item);
+                }
+            }
+            current = provider
+                .class_extends_expression(base_idx)
+                .and_then(|expr| Self::resolve_heritage_class(&provider, expr, imported));
+        }
+        if needs_constructor {
+            items.insert(0, constructor_snippet(base_constructor.unwrap_or_default()));
+        }
+
+        for (provider, interface_idx) in this_file.class_implemented_interfaces(class_idx, imported)
+        {
+            let Some(interface) = provider
+                .arena
+                .get(interface_idx)
+                .and_then(|node| provider.arena.get_interface(node))
+            else {
+                continue;
+            };
+            for &member_idx in &interface.members.nodes {
+                if let Some(item) = provider.inherited_member_snippet(member_idx, false, &mut seen)
+                {
+                    items.push(item);
+                }
+            }
+        }
+
+        items
+    }
+
+    /// A provider over one file's syntax and bindings, for walking heritage
+    /// across files. Only the snippet builders are used on it.
+    pub(crate) fn for_heritage(
+        arena: &'a NodeArena,
+        binder: &'a BinderState,
+        line_map: &'a LineMap,
+        source_text: &'a str,
+        file_name: String,
+    ) -> Self {
+        Self {
+            file_name: Some(file_name),
+            ..Self::new(arena, binder, line_map, source_text)
+        }
+    }
+
+    /// Name of the file this provider reads, when it was given one.
+    pub(crate) fn file_name(&self) -> Option<&str> {
+        self.file_name.as_deref()
+    }
+
+    /// A provider over this file's syntax and bindings, for walking heritage.
+    fn heritage_provider(&self) -> Completions<'a> {
+        Completions {
+            file_name: self.file_name.clone(),
+            ..Completions::new(self.arena, self.binder, self.line_map, self.source_text)
+        }
+    }
+
+    /// Resolve a heritage expression (`extends Base` / `implements I`) to the
+    /// class or interface declarations it names: in this file when the binder
+    /// knows the name, otherwise through `imported`.
+    fn resolve_heritage_declarations<'p>(
+        &self,
+        expr_idx: NodeIndex,
+        imported: &dyn Fn(&Completions<'p>, NodeIndex) -> Vec<HeritageDeclaration<'p>>,
+    ) -> Vec<HeritageDeclaration<'p>>
+    where
+        'a: 'p,
+    {
+        let local = self.local_heritage_declarations(expr_idx);
+        if local.is_empty() {
+            return imported(&self.heritage_provider(), expr_idx);
+        }
+        local
+            .into_iter()
+            .map(|decl| (self.heritage_provider(), decl))
+            .collect()
+    }
+
+    /// Class or interface declarations in this file named by `expr_idx`.
+    fn local_heritage_declarations(&self, expr_idx: NodeIndex) -> Vec<NodeIndex> {
+        let Some(symbol_id) = self.binder.resolve_identifier(self.arena, expr_idx) else {
+            return Vec::new();
+        };
+        let Some(symbol) = self.binder.symbols.get(symbol_id) else {
+            return Vec::new();
+        };
+        symbol
+            .declarations
+            .iter()
+            .copied()
+            .filter(|&decl| {
+                self.arena.get(decl).is_some_and(|node| {
+                    node.is_class_like() || node.kind == syntax_kind_ext::INTERFACE_DECLARATION
+                })
+            })
+            .collect()
+    }
+
+    fn resolve_heritage_class<'p>(
+        provider: &Completions<'p>,
+        expr_idx: NodeIndex,
+        imported: &dyn Fn(&Completions<'p>, NodeIndex) -> Vec<HeritageDeclaration<'p>>,
+    ) -> Option<HeritageDeclaration<'p>> {
+        provider
+            .resolve_heritage_declarations(expr_idx, imported)
+            .into_iter()
+            .find(|(owner, decl)| owner.arena.get(*decl).is_some_and(Node::is_class_like))
+    }
+
+    /// Interface declarations named in the class's `implements` clause,
+    /// including every declaration of a merged interface.
+    fn class_implemented_interfaces<'p>(
+        &self,
+        class_idx: NodeIndex,
+        imported: &dyn Fn(&Completions<'p>, NodeIndex) -> Vec<HeritageDeclaration<'p>>,
+    ) -> Vec<HeritageDeclaration<'p>>
+    where
+        'a: 'p,
+    {
+        let mut interfaces = Vec::new();
+        let Some(class) = self
+            .arena
+            .get(class_idx)
+            .and_then(|node| self.arena.get_class(node))
+        else {
+            return interfaces;
+        };
+        let Some(clauses) = class.heritage_clauses.as_ref() else {
+            return interfaces;
+        };
+        for &clause_idx in &clauses.nodes {
+            let Some(heritage) = self
+                .arena
+                .get(clause_idx)
+                .and_then(|node| self.arena.get_heritage_clause(node))
+            else {
+                continue;
+            };
+            if heritage.token != SyntaxKind::ImplementsKeyword as u16 {
+                continue;
+            }
+            for &type_idx in &heritage.types.nodes {
+                let Some(expr) = self
+                    .arena
+                    .get(type_idx)
+                    .and_then(|node| self.arena.get_expr_type_args(node))
+                else {
+                    continue;
+                };
+                interfaces.extend(
+                    self.resolve_heritage_declarations(expr.expression, imported)
+                        .into_iter()
+                        .filter(|(owner, decl)| {
+                            owner.arena.get(*decl).is_some_and(|node| {
+                                node.kind == syntax_kind_ext::INTERFACE_DECLARATION
+                            })
+                        }),
+                );
+            }
+        }
+        interfaces
+    }
+
+    fn class_has_constructor(&self, class_idx: NodeIndex) -> bool {
+        self.arena
+            .get(class_idx)
+            .and_then(|node| self.arena.get_class(node))
+            .is_some_and(|class| {
+                class.members.nodes.iter().any(|&member| {
+                    self.arena
+                        .get(member)
+                        .is_some_and(|node| node.kind == syntax_kind_ext::CONSTRUCTOR)
+                })
+            })
+    }
+
+    /// `(declaration list, forwarding argument list)` of the constructor
+    /// declared directly in `class_idx`, if it has one.
+    fn constructor_parameter_texts(&self, class_idx: NodeIndex) -> Option<(String, String)> {
+        let class = self.arena.get_class(self.arena.get(class_idx)?)?;
+        class.members.nodes.iter().find_map(|&member_idx| {
+            let ctor = self.arena.get_constructor(self.arena.get(member_idx)?)?;
+            Some(self.snippet_parameter_texts(&ctor.parameters.nodes))
+        })
+    }
+
+    /// Build the snippet for one inherited member, or `None` when it is not
+    /// overridable (static, private, already declared, or unnamed).
+    fn inherited_member_snippet(
+        &self,
+        member_idx: NodeIndex,
+        from_class: bool,
+        seen: &mut FxHashSet<String>,
+    ) -> Option<CompletionItem> {
+        let member_node = self.arena.get(member_idx)?;
+        let (modifiers, name_idx) = match member_node.kind {
+            k if k == syntax_kind_ext::METHOD_DECLARATION => {
+                let method = self.arena.get_method_decl(member_node)?;
+                (method.modifiers.as_ref(), method.name)
+            }
+            k if k == syntax_kind_ext::PROPERTY_DECLARATION => {
+                let prop = self.arena.get_property_decl(member_node)?;
+                (prop.modifiers.as_ref(), prop.name)
+            }
+            k if k == syntax_kind_ext::METHOD_SIGNATURE
+                || k == syntax_kind_ext::PROPERTY_SIGNATURE =>
+            {
+                let signature = self.arena.get_signature(member_node)?;
+                (signature.modifiers.as_ref(), signature.name)
+            }
+            _ => return None,
+        };
+        if self
+            .arena
+            .has_modifier_ref(modifiers, SyntaxKind::StaticKeyword)
+            || self
+                .arena
+                .has_modifier_ref(modifiers, SyntaxKind::PrivateKeyword)
+        {
+            return None;
+        }
+        let name_node = self.arena.get(name_idx)?;
+        if name_node.kind == SyntaxKind::PrivateIdentifier as u16 {
+            return None;
+        }
+        let name = self.arena.get_identifier_text(name_idx)?.to_string();
+        if !seen.insert(name.clone()) {
+            return None;
+        }
+
+        let origin = if !from_class {
+            MemberOrigin::Interface
+        } else if self
+            .arena
+            .has_modifier_ref(modifiers, SyntaxKind::AbstractKeyword)
+        {
+            MemberOrigin::AbstractBaseClass
+        } else {
+            MemberOrigin::BaseClass
+        };
+        let mut prefix = String::new();
+        if origin != MemberOrigin::Interface {
+            prefix.push_str("override ");
+        }
+        if self
+            .arena
+            .has_modifier_ref(modifiers, SyntaxKind::ProtectedKeyword)
+        {
+            prefix.insert_str(0, "protected ");
+        }
+        if self
+            .arena
+            .has_modifier_ref(modifiers, SyntaxKind::ReadonlyKeyword)
+        {
+            prefix.push_str("readonly ");
+        }
+
+        let is_method = member_node.kind == syntax_kind_ext::METHOD_DECLARATION
+            || member_node.kind == syntax_kind_ext::METHOD_SIGNATURE;
+        if !is_method {
+            let type_text = self
+                .member_type_annotation(member_node)
+                .map(|ty| format!(": {ty}"))
+                .unwrap_or_default();
+            let declaration = format!("{prefix}{name}{type_text};");
+            return Some(
+                CompletionItem::new(name, CompletionItemKind::Property)
+                    .with_detail(declaration.clone())
+                    .with_insert_text(format!("{}$0", escape_snippet_text(&declaration)))
+                    .as_snippet()
+                    .with_sort_text(sort_priority::SUGGESTED_CLASS_MEMBERS)
+                    .with_source(CLASS_MEMBER_SNIPPET_SOURCE.to_string()),
+            );
+        }
+
+        let (parameters, type_parameters, is_async) =
+            if let Some(method) = self.arena.get_method_decl(member_node) {
+                (
+                    method.parameters.nodes.clone(),
+                    method.type_parameters.as_ref(),
+                    self.arena
+                        .has_modifier_ref(method.modifiers.as_ref(), SyntaxKind::AsyncKeyword),
+                )
+            } else {
+                let signature = self.arena.get_signature(member_node)?;
+                (
+                    signature
+                        .parameters
+                        .as_ref()
+                        .map(|params| params.nodes.clone())
+                        .unwrap_or_default(),
+                    signature.type_parameters.as_ref(),
+                    false,
+                )
+            };
+        if is_async {
+            prefix.push_str("async ");
+        }
+        let type_params_text = type_parameters
+            .map(|list| {
+                let texts: Vec<&str> = list
+                    .nodes
+                    .iter()
+                    .filter_map(|&idx| self.node_source_text(idx))
+                    .collect();
+                format!("<{}>", texts.join(", "))
+            })
+            .unwrap_or_default();
+        let (params_text, args_text) = self.snippet_parameter_texts(&parameters);
+        let return_type = self.member_type_annotation(member_node);
+        let return_text = return_type
+            .as_deref()
+            .map(|ty| format!(": {ty}"))
+            .unwrap_or_default();
+        let signature = format!("{prefix}{name}{type_params_text}({params_text}){return_text}");
+
+        let body = if origin == MemberOrigin::BaseClass {
+            let call = format!("super.{name}({args_text});");
+            let returns_nothing = return_type
+                .as_deref()
+                .is_some_and(|ty| ty == "void" || ty == "Promise<void>" || ty == "never");
+            if returns_nothing {
+                call
+            } else if is_async {
+                format!("return await {call}")
+            } else {
+                format!("return {call}")
+            }
+        } else {
+            NOT_IMPLEMENTED_BODY.to_string()
+        };
+        let insert_text = format!(
+            "{} {{\n{SNIPPET_INDENT}${{0:{}}}\n}}",
+            escape_snippet_text(&signature),
+            escape_snippet_placeholder(&body)
+        );
+
+        Some(
+            CompletionItem::new(name, CompletionItemKind::Method)
+                .with_detail(signature)
+                .with_insert_text(insert_text)
+                .as_snippet()
+                .with_sort_text(sort_priority::SUGGESTED_CLASS_MEMBERS)
+                .with_source(CLASS_MEMBER_SNIPPET_SOURCE.to_string()),
+        )
+    }
+
+    /// Source text of a member's type annotation (property type or method
+    /// return type).
+    fn member_type_annotation(&self, member_node: &Node) -> Option<String> {
+        let annotation = if let Some(method) = self.arena.get_method_decl(member_node) {
+            method.type_annotation
+        } else if let Some(prop) = self.arena.get_property_decl(member_node) {
+            prop.type_annotation
+        } else {
+            self.arena.get_signature(member_node)?.type_annotation
+        };
+        self.node_source_text(annotation).map(str::to_string)
+    }
+
+    /// Render parameters as `(declaration list, forwarding argument list)`.
+    ///
+    /// Parameter-property modifiers (`public x: T`) are dropped from the
+    /// declaration; rest parameters are forwarded with a spread.
+    fn snippet_parameter_texts(&self, parameters: &[NodeIndex]) -> (String, String) {
+        let mut declarations = Vec::with_capacity(parameters.len());
+        let mut arguments = Vec::with_capacity(parameters.len());
+        for &param_idx in parameters {
+            let Some(param) = self
+                .arena
+                .get(param_idx)
+                .and_then(|node| self.arena.get_parameter(node))
+            else {
+                continue;
+            };
+            let Some(name) = self.node_source_text(param.name) else {
+                continue;
+            };
+            if name == "this" {
+                continue;
+            }
+            let mut declaration = String::new();
+            if param.dot_dot_dot_token {
+                declaration.push_str("...");
+            }
+            declaration.push_str(name);
+            if param.question_token {
+                declaration.push('?');
+            }
+            if let Some(ty) = self.node_source_text(param.type_annotation) {
+                declaration.push_str(": ");
+                declaration.push_str(ty);
+            }
+            if let Some(init) = self.node_source_text(param.initializer) {
+                declaration.push_str(" = ");
+                declaration.push_str(init);
+            }
+            declarations.push(declaration);
+            arguments.push(if param.dot_dot_dot_token {
+                format!("...{name}")
+            } else {
+                name.to_string()
+            });
+        }
+        (declarations.join(", "), arguments.join(", "))
+    }
+
+    /// Trimmed source text of a node, or `None` for missing nodes.
+    fn node_source_text(&self, node_idx: NodeIndex) -> Option<&'a str> {
+        if node_idx.is_none() {
+            return None;
+        }
+        let node = self.arena.get(node_idx)?;
+        let start = node.pos as usize;
+        let end = (node.end as usize).min(self.source_text.len());
+        let text = self.source_text.get(start..end)?.trim();
+        (!text.is_empty()).then_some(text)
+    }
+}
+
+/// Constructor snippet forwarding the nearest base constructor's parameters
+/// (`(declaration list, argument list)`) to `super(...)`.
+fn constructor_snippet((params_text, args_text): (String, String)) -> CompletionItem {
+    let signature = format!("constructor({params_text})");
+    let insert_text = format!(
+        "{} {{\n{SNIPPET_INDENT}super({});$0\n}}",
+        escape_snippet_text(&signature),
+        escape_snippet_text(&args_text)
+    );
+    CompletionItem::new("constructor".to_string(), CompletionItemKind::Constructor)
+        .with_detail(signature)
+        .with_insert_text(insert_text)
+        .as_snippet()
+        .with_sort_text(sort_priority::SUGGESTED_CLASS_MEMBERS)
+        .with_source(CLASS_MEMBER_SNIPPET_SOURCE.to_string())
+}
+
+/// Escape literal snippet text outside placeholders.
+fn escape_snippet_text(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('$', "\\$")
+}
+
+/// Escape text used as a placeholder's default value.
+fn escape_snippet_placeholder(text: &str) -> String {
+    escape_snippet_text(text).replace('}', "\\}")
+}
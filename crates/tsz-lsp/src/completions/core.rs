@@ -197,6 +197,7 @@ impl<'a> Completions<'a> {
                 ",".to_string(),
                 ";".to_string(),
             ]),
+            optional_replacement_span: self.optional_replacement_span(offset),
             entries: items,
         })
     }
@@ -753,7 +754,11 @@ impl<'a> Completions<'a> {
     /// Check if the cursor is at a class/interface member declaration position
     /// (after `{`, `;`, or `}` inside a class or interface body).
     /// Returns Some("class") or Some("interface") if at a member position.
-    fn member_body_context(&self, node_idx: NodeIndex, offset: u32) -> Option<&'static str> {
+    pub(super) fn member_body_context(
+        &self,
+        node_idx: NodeIndex,
+        offset: u32,
+    ) -> Option<&'static str> {
         // Use AST-based check and verify the offset is strictly inside
         // the class/interface body braces (not after the closing brace).
         let in_class = self.is_in_class_body_context(node_idx)
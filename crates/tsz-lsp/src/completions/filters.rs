@@ -1068,6 +1068,28 @@ impl<'a> Completions<'a> {
         best
     }
 
+    /// Span of the identifier touching `offset` (the cursor may sit anywhere
+    /// in it, including right after it), mirroring tsserver's
+    /// `getOptionalReplacementSpan`. Keywords, punctuation and string
+    /// literals have none.
+    pub(super) fn optional_replacement_span(&self, offset: u32) -> Option<(u32, u32)> {
+        let candidates = [Some(offset), offset.checked_sub(1)];
+        candidates.into_iter().flatten().find_map(|candidate| {
+            let node_idx = find_node_at_offset(self.arena, candidate);
+            let node = self.arena.get(node_idx)?;
+            if node.kind != SyntaxKind::Identifier as u16 {
+                return None;
+            }
+            // Missing names (`obj.|`) parse as empty identifiers.
+            let name = self
+                .arena
+                .get_identifier_text(node_idx)
+                .filter(|name| !name.is_empty())?;
+            let start = node.end.checked_sub(name.len() as u32)?;
+            (start <= offset && offset <= node.end).then_some((start, node.end))
+        })
+    }
+
     /// Check if the cursor is inside a JSX element's child text content. This
     /// mirrors TypeScript's `isInJsxText` check used by
     /// `isCompletionListBlocker`. Returns `true` when:
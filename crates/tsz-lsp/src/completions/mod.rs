@@ -119,6 +119,14 @@ pub struct CompletionResult {
         skip_serializing_if = "Option::is_none"
     )]
     pub default_commit_characters: Option<Vec<String>>,
+    /// Byte span of the identifier the cursor touches, which editors may
+    /// replace wholesale when an entry is accepted. Entries with their own
+    /// `replacement_span` override it.
+    #[serde(
+        rename = "optionalReplacementSpan",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub optional_replacement_span: Option<(u32, u32)>,
     /// The completion entries.
     pub entries: Vec<CompletionItem>,
 }
@@ -371,6 +379,7 @@ pub struct Completions<'a> {
     pub(super) lib_contexts: &'a [tsz_checker::context::LibContext],
 }
 
+mod class_member_snippets;
 mod context;
 mod core;
 mod filters;
@@ -384,6 +393,8 @@ use member::PropertyCompletion;
 use render::compare_case_sensitive_ui;
 use symbols::{DEPRECATED_GLOBALS, GLOBAL_VARS, KEYWORDS, KEYWORDS_INSIDE_FUNCTION};
 
+pub(crate) use class_member_snippets::HeritageDeclaration;
+
 #[cfg(test)]
 #[path = "../../tests/completions_tests.rs"]
 mod completions_tests;
@@ -60,7 +60,7 @@ impl TryFrom<u8> for DiagnosticSeverity {
 }
 
 /// LSP diagnostic payload used by LSP clients (VS Code, etc.).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LspDiagnostic {
     pub range: Range,
@@ -84,7 +84,7 @@ pub struct LspDiagnostic {
 }
 
 /// Related diagnostic information for LSP clients.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LspDiagnosticRelatedInformation {
     pub location: Location,
@@ -377,6 +377,24 @@ pub struct UnchangedDocumentDiagnosticReport {
     pub result_id: String,
 }
 
+/// The result of a `textDocument/diagnostic` request for one document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DocumentDiagnosticReport {
+    Full(FullDocumentDiagnosticReport),
+    Unchanged(UnchangedDocumentDiagnosticReport),
+}
+
+impl DocumentDiagnosticReport {
+    /// The result ID the client should send back on its next pull.
+    pub fn result_id(&self) -> Option<&str> {
+        match self {
+            Self::Full(report) => report.result_id.as_deref(),
+            Self::Unchanged(report) => Some(&report.result_id),
+        }
+    }
+}
+
 /// A workspace diagnostic report item — either full or unchanged per document.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -6,6 +6,12 @@
 //! - Parameter name hints for function calls
 //! - Type hints for variables with implicit types (e.g., `let x = 1` shows `: number`)
 //! - Return type hints for arrow functions and function expressions
+//! - Type hints for class property declarations without annotations
+//! - Value hints for enum members without initializers (e.g. `B` shows `= 1`)
+//!
+//! Each category can be switched off through [`InlayHintsOptions`], mirroring
+//! the `typescript.inlayHints.*` editor settings. Type hints carry an
+//! `insert_text` so clients can materialize them (double-click to insert).
 //!
 //! ## Type Hints
 //!
@@ -24,7 +30,7 @@ use tsz_checker::context::CheckerOptions;
 use tsz_checker::state::CheckerState;
 use tsz_common::position::{LineMap, Position, Range};
 use tsz_parser::NodeIndex;
-use tsz_parser::parser::node::{Node, NodeAccess, NodeArena};
+use tsz_parser::parser::node::{FunctionData, Node, NodeAccess, NodeArena};
 use tsz_parser::syntax_kind_ext;
 use tsz_scanner::SyntaxKind;
 use tsz_solver::construction::TypeInterner;
@@ -42,6 +48,102 @@ pub enum InlayHintKind {
     /// Generic parameter hint
     #[serde(rename = "generic")]
     Generic,
+    /// Enum member value hint (e.g., `B,` -> `B = 1,`)
+    #[serde(rename = "enum")]
+    Enum,
+}
+
+/// Which call arguments receive parameter name hints.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParameterNameHints {
+    /// No parameter name hints.
+    None,
+    /// Only literal arguments (`fn(42, "x", true)`).
+    Literals,
+    /// Every argument that is not self-describing.
+    #[default]
+    All,
+}
+
+impl ParameterNameHints {
+    /// Parse the `parameterNames.enabled` / `includeInlayParameterNameHints`
+    /// setting value.
+    pub fn from_setting(value: &str) -> Option<Self> {
+        match value {
+            "none" => Some(Self::None),
+            "literals" => Some(Self::Literals),
+            "all" => Some(Self::All),
+            _ => None,
+        }
+    }
+}
+
+/// Which categories of inlay hints to produce.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHintsOptions {
+    /// Parameter name hints at call sites.
+    pub parameter_names: ParameterNameHints,
+    /// Types of variables declared without an annotation.
+    pub variable_types: bool,
+    /// Types of class properties declared without an annotation.
+    pub property_declaration_types: bool,
+    /// Return types of arrow functions and function expressions.
+    pub function_like_return_types: bool,
+    /// Values of enum members declared without an initializer.
+    pub enum_member_values: bool,
+}
+
+impl Default for InlayHintsOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InlayHintsOptions {
+    /// Default settings: parameter names, variable types, and function
+    /// return types on; property declaration types and enum values off.
+    pub const fn new() -> Self {
+        Self {
+            parameter_names: ParameterNameHints::All,
+            variable_types: true,
+            property_declaration_types: false,
+            function_like_return_types: true,
+            enum_member_values: false,
+        }
+    }
+
+    /// Apply an editor `inlayHints` settings object
+    /// (`{ "parameterNames": { "enabled": "literals" }, "variableTypes": { "enabled": true }, ... }`).
+    ///
+    /// Settings that are absent keep their current value.
+    pub fn apply_settings(&mut self, settings: &serde_json::Value) {
+        let enabled = |key: &str| settings.get(key).and_then(|entry| entry.get("enabled"));
+        if let Some(mode) = enabled("parameterNames")
+            .and_then(serde_json::Value::as_str)
+            .and_then(ParameterNameHints::from_setting)
+        {
+            self.parameter_names = mode;
+        }
+        let flags = [
+            ("variableTypes", &mut self.variable_types),
+            (
+                "propertyDeclarationTypes",
+                &mut self.property_declaration_types,
+            ),
+            (
+                "functionLikeReturnTypes",
+                &mut self.function_like_return_types,
+            ),
+            ("enumMemberValues", &mut self.enum_member_values),
+        ];
+        for (key, flag) in flags {
+            if let Some(value) = enabled(key).and_then(serde_json::Value::as_bool) {
+                *flag = value;
+            }
+        }
+    }
 }
 
 /// An inlay hint - an inline annotation in the source code.
@@ -55,6 +157,10 @@ pub struct InlayHint {
     pub kind: InlayHintKind,
     /// Optional tooltip with additional information.
     pub tooltip: Option<String>,
+    /// Text inserted at `position` when the user accepts the hint
+    /// (double-click). `None` for hints that are not valid source code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insert_text: Option<String>,
 }
 
 impl InlayHint {
@@ -65,9 +171,17 @@ impl InlayHint {
             label,
             kind,
             tooltip: None,
+            insert_text: None,
         }
     }
 
+    /// Attach the text inserted when the hint is accepted.
+    #[must_use]
+    pub fn with_insert_text(mut self, insert_text: String) -> Self {
+        self.insert_text = Some(insert_text);
+        self
+    }
+
     /// Create a parameter name hint.
     pub fn parameter(position: Position, param_name: String) -> Self {
         Self::new(
@@ -77,9 +191,10 @@ impl InlayHint {
         )
     }
 
-    /// Create a type hint.
+    /// Create a type hint. Accepting it inserts the annotation.
     pub fn type_hint(position: Position, type_name: String) -> Self {
-        Self::new(position, format!(": {type_name}"), InlayHintKind::Type)
+        let label = format!(": {type_name}");
+        Self::new(position, label.clone(), InlayHintKind::Type).with_insert_text(label)
     }
 
     /// Convert to LSP range (for compatibility with other LSP features).
@@ -102,6 +217,8 @@ pub struct InlayHintsProvider<'a> {
     pub interner: &'a TypeInterner,
     /// File name for checker context.
     pub file_name: String,
+    /// Which hint categories to produce.
+    pub options: InlayHintsOptions,
 }
 
 impl<'a> InlayHintsProvider<'a> {
@@ -121,9 +238,17 @@ impl<'a> InlayHintsProvider<'a> {
             source,
             interner,
             file_name,
+            options: InlayHintsOptions::new(),
         }
     }
 
+    /// Replace the hint category settings.
+    #[must_use]
+    pub const fn with_options(mut self, options: InlayHintsOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     /// Provide inlay hints for the given range.
     pub fn provide_inlay_hints(&self, root: NodeIndex, range: Range) -> Vec<InlayHint> {
         let mut hints = Vec::new();
@@ -173,24 +298,38 @@ impl<'a> InlayHintsProvider<'a> {
         }
 
         // Collect parameter name hints for call and new expressions
-        if node.kind == syntax_kind_ext::CALL_EXPRESSION
-            || node.kind == syntax_kind_ext::NEW_EXPRESSION
+        if self.options.parameter_names != ParameterNameHints::None
+            && (node.kind == syntax_kind_ext::CALL_EXPRESSION
+                || node.kind == syntax_kind_ext::NEW_EXPRESSION)
         {
             self.collect_parameter_hints(node_idx, hints);
         }
 
         // Collect type hints for variable declarations without explicit types
-        if node.kind == syntax_kind_ext::VARIABLE_DECLARATION {
+        if self.options.variable_types && node.kind == syntax_kind_ext::VARIABLE_DECLARATION {
             self.collect_type_hints(node_idx, hints, checker);
         }
 
+        // Collect type hints for class properties without explicit types
+        if self.options.property_declaration_types
+            && node.kind == syntax_kind_ext::PROPERTY_DECLARATION
+        {
+            self.collect_property_type_hints(node_idx, hints, checker);
+        }
+
         // Collect return type hints for arrow functions and function expressions
-        if node.kind == syntax_kind_ext::ARROW_FUNCTION
-            || node.kind == syntax_kind_ext::FUNCTION_EXPRESSION
+        if self.options.function_like_return_types
+            && (node.kind == syntax_kind_ext::ARROW_FUNCTION
+                || node.kind == syntax_kind_ext::FUNCTION_EXPRESSION)
         {
             self.collect_return_type_hints(node_idx, hints, checker);
         }
 
+        // Collect value hints for enum members without initializers
+        if self.options.enum_member_values && node.kind == syntax_kind_ext::ENUM_DECLARATION {
+            self.collect_enum_member_hints(node_idx, range_start, range_end, hints);
+        }
+
         // Recurse into children
         for child_idx in self.arena.get_children(node_idx) {
             self.collect_hints(child_idx, range_start, range_end, hints, checker);
@@ -248,6 +387,11 @@ impl<'a> InlayHintsProvider<'a> {
                 if self.should_skip_parameter_hint(arg_idx, arg_node, param_name) {
                     continue;
                 }
+                if self.options.parameter_names == ParameterNameHints::Literals
+                    && !self.is_hintable_literal(arg_idx)
+                {
+                    continue;
+                }
                 let pos = self.line_map.offset_to_position(arg_node.pos, self.source);
                 hints.push(InlayHint::new(
                     pos,
@@ -399,6 +543,37 @@ impl<'a> InlayHintsProvider<'a> {
         false
     }
 
+    /// Whether an argument counts as a literal for `parameterNames: "literals"`.
+    ///
+    /// Mirrors tsserver's `isHintableLiteral`: string/numeric/bigint/template
+    /// literals, `true`/`false`/`null`, `undefined`/`NaN`/`Infinity`, and a
+    /// prefix unary applied to any of those.
+    fn is_hintable_literal(&self, arg_idx: NodeIndex) -> bool {
+        let Some(node) = self.arena.get(arg_idx) else {
+            return false;
+        };
+        if node.kind == syntax_kind_ext::PREFIX_UNARY_EXPRESSION {
+            return self
+                .arena
+                .get_unary_expr(node)
+                .is_some_and(|unary| self.is_hintable_literal(unary.operand));
+        }
+        if node.kind == SyntaxKind::Identifier as u16 {
+            return self
+                .arena
+                .get_identifier_text(arg_idx)
+                .is_some_and(|text| matches!(text, "undefined" | "NaN" | "Infinity"));
+        }
+        node.kind == SyntaxKind::StringLiteral as u16
+            || node.kind == SyntaxKind::NumericLiteral as u16
+            || node.kind == SyntaxKind::BigIntLiteral as u16
+            || node.kind == SyntaxKind::NoSubstitutionTemplateLiteral as u16
+            || node.kind == SyntaxKind::TrueKeyword as u16
+            || node.kind == SyntaxKind::FalseKeyword as u16
+            || node.kind == SyntaxKind::NullKeyword as u16
+            || node.kind == syntax_kind_ext::TEMPLATE_EXPRESSION
+    }
+
     /// Resolve the method symbol for a property access call like `obj.method(arg)`.
     fn resolve_method_call_target(&self, expr_idx: NodeIndex) -> Option<tsz_binder::SymbolId> {
         let expr_node = self.arena.get(expr_idx)?;
@@ -519,11 +694,132 @@ impl<'a> InlayHintsProvider<'a> {
 
         let pos = self.line_map.offset_to_position(name_node.end, self.source);
 
-        hints.push(InlayHint::new(
-            pos,
-            format!(": {type_text}"),
-            InlayHintKind::Type,
-        ));
+        hints.push(InlayHint::type_hint(pos, type_text));
+    }
+
+    /// Collect type hints for class property declarations that have an
+    /// initializer but no type annotation (`count = 0` shows `: number`).
+    fn collect_property_type_hints(
+        &self,
+        prop_idx: NodeIndex,
+        hints: &mut Vec<InlayHint>,
+        checker: &mut CheckerState,
+    ) {
+        let Some(node) = self.arena.get(prop_idx) else {
+            return;
+        };
+        let Some(prop) = self.arena.get_property_decl(node) else {
+            return;
+        };
+        if prop.type_annotation.is_some() || prop.initializer.is_none() {
+            return;
+        }
+
+        let type_id = checker.get_type_of_node(prop_idx);
+        if type_id == TypeId::ERROR || type_id == TypeId::ANY || type_id == TypeId::UNKNOWN {
+            return;
+        }
+        let type_text = checker.format_type(type_id);
+        if type_text == "any" || type_text == "unknown" || type_text == "error" {
+            return;
+        }
+
+        // `name?` / `name!` put the hint after the token, not before it.
+        let Some(name_node) = self.arena.get(prop.name) else {
+            return;
+        };
+        let mut hint_offset = name_node.end;
+        if prop.question_token || prop.exclamation_token {
+            let token = if prop.question_token { '?' } else { '!' };
+            let rest = &self.source[hint_offset as usize..];
+            if let Some(idx) = rest.find(token) {
+                hint_offset += idx as u32 + 1;
+            }
+        }
+        let pos = self.line_map.offset_to_position(hint_offset, self.source);
+        hints.push(InlayHint::type_hint(pos, type_text));
+    }
+
+    /// Collect `= value` hints for enum members without an initializer.
+    ///
+    /// Values follow the auto-increment rule: the first member is `0`, and each
+    /// later member is one more than its predecessor when that predecessor's
+    /// value is a known number. Once a member's value cannot be determined
+    /// syntactically (computed or string initializer), the following
+    /// uninitialized members get no hint.
+    fn collect_enum_member_hints(
+        &self,
+        enum_idx: NodeIndex,
+        range_start: u32,
+        range_end: u32,
+        hints: &mut Vec<InlayHint>,
+    ) {
+        let Some(node) = self.arena.get(enum_idx) else {
+            return;
+        };
+        let Some(enum_decl) = self.arena.get_enum(node) else {
+            return;
+        };
+
+        let mut next_value = Some(0.0_f64);
+        for &member_idx in &enum_decl.members.nodes {
+            let Some(member_node) = self.arena.get(member_idx) else {
+                next_value = None;
+                continue;
+            };
+            let Some(member) = self.arena.get_enum_member(member_node) else {
+                next_value = None;
+                continue;
+            };
+
+            if member.initializer.is_some() {
+                next_value = self
+                    .numeric_literal_value(member.initializer)
+                    .map(|v| v + 1.0);
+                continue;
+            }
+            let Some(value) = next_value else {
+                continue;
+            };
+            next_value = Some(value + 1.0);
+
+            let Some(name_node) = self.arena.get(member.name) else {
+                continue;
+            };
+            if name_node.end < range_start || name_node.end > range_end {
+                continue;
+            }
+            let pos = self.line_map.offset_to_position(name_node.end, self.source);
+            hints.push(InlayHint::new(
+                pos,
+                format!("= {}", format_enum_value(value)),
+                InlayHintKind::Enum,
+            ));
+        }
+    }
+
+    /// Value of a numeric literal, optionally negated (`-1`), or `None`.
+    fn numeric_literal_value(&self, expr_idx: NodeIndex) -> Option<f64> {
+        let node = self.arena.get(expr_idx)?;
+        if node.kind == syntax_kind_ext::PARENTHESIZED_EXPRESSION {
+            let paren = self.arena.get_parenthesized(node)?;
+            return self.numeric_literal_value(paren.expression);
+        }
+        if node.kind == syntax_kind_ext::PREFIX_UNARY_EXPRESSION {
+            let unary = self.arena.get_unary_expr(node)?;
+            let operand = self.numeric_literal_value(unary.operand)?;
+            return if unary.operator == SyntaxKind::MinusToken as u16 {
+                Some(-operand)
+            } else if unary.operator == SyntaxKind::PlusToken as u16 {
+                Some(operand)
+            } else {
+                None
+            };
+        }
+        if node.kind != SyntaxKind::NumericLiteral as u16 {
+            return None;
+        }
+        self.arena.get_literal(node)?.value
     }
 
     /// Collect return type hints for arrow functions and function expressions
@@ -570,27 +866,79 @@ impl<'a> InlayHintsProvider<'a> {
             return;
         }
 
-        // Position the hint after the closing paren of the parameter list.
-        // Use the end of the last parameter, or if no params, use the body position.
-        let hint_offset = if let Some(&last_param) = func.parameters.nodes.last() {
-            if let Some(last_node) = self.arena.get(last_param) {
-                last_node.end
-            } else {
-                return;
-            }
-        } else if let Some(body_node) = self.arena.get(func.body) {
-            body_node.pos
-        } else {
+        // Position the hint (and its insert text) right after the `)` closing
+        // the parameter list, like tsc; without parentheses there is nowhere
+        // to put a return type annotation.
+        let Some(hint_offset) = self.parameter_list_end(node, func) else {
             return;
         };
 
         let pos = self.line_map.offset_to_position(hint_offset, self.source);
 
-        hints.push(InlayHint::new(
-            pos,
-            format!(": {return_type}"),
-            InlayHintKind::Type,
-        ));
+        hints.push(InlayHint::type_hint(pos, return_type.to_string()));
+    }
+
+    /// End of the `)` closing `func`'s parameter list, or `None` when there
+    /// is none (an arrow function's lone unparenthesized parameter).
+    fn parameter_list_end(&self, func_node: &Node, func: &FunctionData) -> Option<u32> {
+        let bytes = self.source.as_bytes();
+        let mut offset = if let Some(&last_param) = func.parameters.nodes.last() {
+            self.arena.get(last_param)?.end
+        } else {
+            // The `(` follows the name and type parameters.
+            let mut start = func_node.pos;
+            if let Some(name) = self.arena.get(func.name) {
+                start = start.max(name.end);
+            }
+            if let Some(last_type_param) = func
+                .type_parameters
+                .as_ref()
+                .and_then(|list| list.nodes.last())
+                .and_then(|&idx| self.arena.get(idx))
+            {
+                start = start.max(last_type_param.end);
+            }
+            let open = start + skip_trivia(bytes.get(start as usize..)?);
+            if bytes.get(open as usize) != Some(&b'(') {
+                return None;
+            }
+            open + 1
+        };
+        loop {
+            offset += skip_trivia(bytes.get(offset as usize..)?);
+            match bytes.get(offset as usize)? {
+                b',' => offset += 1,
+                b')' => return Some(offset + 1),
+                _ => return None,
+            }
+        }
+    }
+}
+
+/// Length of the whitespace and comments at the start of `bytes`.
+fn skip_trivia(bytes: &[u8]) -> u32 {
+    let mut index = 0;
+    loop {
+        let rest = &bytes[index..];
+        index += match rest {
+            [b' ' | b'\t' | b'\r' | b'\n', ..] => 1,
+            [b'/', b'/', ..] => rest.iter().position(|&b| b == b'\n').unwrap_or(rest.len()),
+            [b'/', b'*', ..] => rest[2..]
+                .windows(2)
+                .position(|window| window == b"*/")
+                .map_or(rest.len(), |close| close + 4),
+            _ => return index as u32,
+        };
+    }
+}
+
+/// Format an enum member value the way TypeScript prints numbers
+/// (`1`, `-2`, `1.5`).
+fn format_enum_value(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        value.to_string()
     }
 }
 
@@ -8,7 +8,8 @@
 
 use crate::utils::find_node_at_offset;
 use tsz_common::position::{Position, Range};
-use tsz_parser::{NodeIndex, syntax_kind_ext};
+use tsz_parser::{NodeIndex, NodeList, syntax_kind_ext};
+use tsz_scanner::SyntaxKind;
 
 /// A selection range with a parent pointer.
 ///
@@ -75,25 +76,44 @@ impl<'a> SelectionRangeProvider<'a> {
     }
 
     /// Build a chain of selection ranges from innermost to outermost.
+    ///
+    /// Besides the node spans themselves, the chain gets intermediate stops
+    /// that have no node of their own, matching tsserver's smart selection:
+    /// the contents of a string literal, a `${...}` template substitution,
+    /// and the whole argument, parameter, or JSX attribute list around an
+    /// element.
     fn build_selection_chain(&self, start_node: NodeIndex) -> Option<SelectionRange> {
         let mut current = start_node;
+        let mut child = NodeIndex::NONE;
         let mut ranges: Vec<Range> = Vec::new();
 
+        if let Some(range) = self.string_contents_range(start_node) {
+            ranges.push(range);
+        }
+
         // Collect all ranges from innermost to outermost
         while current.is_some() {
             let node = self.arena.get(current)?;
-            let range = self.node_to_range(current)?;
-
-            // Only add this range if it's different from the last one
-            // (some nodes may have identical spans)
-            if ranges.last() != Some(&range) {
-                // Skip certain node types that don't represent meaningful selections
-                if !self.should_skip_node(node.kind) {
-                    ranges.push(range);
+
+            if child.is_some()
+                && let Some(range) = self.intermediate_range(current, child)
+            {
+                Self::push_range(&mut ranges, range);
+            }
+
+            // Skip certain node types that don't represent meaningful selections
+            if !self.should_skip_node(node.kind) {
+                let range = match self.arena.get_jsx_attributes(node) {
+                    Some(attributes) => self.list_range(&attributes.properties),
+                    None => self.node_to_range(current),
+                };
+                if let Some(range) = range {
+                    Self::push_range(&mut ranges, range);
                 }
             }
 
             // Move to parent
+            child = current;
             current = self
                 .arena
                 .get_extended(current)
@@ -116,6 +136,100 @@ impl<'a> SelectionRangeProvider<'a> {
         result
     }
 
+    /// Add `range` unless it repeats the previous stop (some nodes have
+    /// identical spans, and a single-element list spans just its element).
+    fn push_range(ranges: &mut Vec<Range>, range: Range) {
+        if ranges.last() != Some(&range) {
+            ranges.push(range);
+        }
+    }
+
+    /// A stop between `child` and its `parent` that does not correspond to a
+    /// node: the `${...}` around a template substitution, or the element list
+    /// (arguments, parameters, type arguments) that contains `child`.
+    fn intermediate_range(&self, parent: NodeIndex, child: NodeIndex) -> Option<Range> {
+        let parent_node = self.arena.get(parent)?;
+
+        if let Some(span) = self.arena.get_template_span(parent_node) {
+            return (span.expression == child)
+                .then(|| self.template_substitution_range(child))
+                .flatten();
+        }
+
+        let lists: [Option<&NodeList>; 2] =
+            if let Some(call) = self.arena.get_call_expr(parent_node) {
+                [call.arguments.as_ref(), call.type_arguments.as_ref()]
+            } else if let Some(func) = self.arena.get_function(parent_node) {
+                [Some(&func.parameters), func.type_parameters.as_ref()]
+            } else if let Some(method) = self.arena.get_method_decl(parent_node) {
+                [Some(&method.parameters), method.type_parameters.as_ref()]
+            } else if let Some(ctor) = self.arena.get_constructor(parent_node) {
+                [Some(&ctor.parameters), ctor.type_parameters.as_ref()]
+            } else if let Some(accessor) = self.arena.get_accessor(parent_node) {
+                [
+                    Some(&accessor.parameters),
+                    accessor.type_parameters.as_ref(),
+                ]
+            } else if let Some(sig) = self.arena.get_signature(parent_node) {
+                [sig.parameters.as_ref(), sig.type_parameters.as_ref()]
+            } else if let Some(func_type) = self.arena.get_function_type(parent_node) {
+                [
+                    Some(&func_type.parameters),
+                    func_type.type_parameters.as_ref(),
+                ]
+            } else {
+                return None;
+            };
+
+        lists
+            .into_iter()
+            .flatten()
+            .find(|list| list.nodes.contains(&child))
+            .and_then(|list| self.list_range(list))
+    }
+
+    /// Span from the first to the last element of `list`.
+    fn list_range(&self, list: &NodeList) -> Option<Range> {
+        let first = self.arena.get(*list.nodes.first()?)?;
+        let last = self.arena.get(*list.nodes.last()?)?;
+        Some(Range::new(
+            self.line_map
+                .offset_to_position(first.pos, self.source_text),
+            self.line_map.offset_to_position(last.end, self.source_text),
+        ))
+    }
+
+    /// The `${expr}` range around a template substitution expression.
+    fn template_substitution_range(&self, expression: NodeIndex) -> Option<Range> {
+        let node = self.arena.get(expression)?;
+        let (pos, end) = (node.pos as usize, node.end as usize);
+        let start = self.source_text.get(..pos)?.rfind("${")?;
+        let close = end + self.source_text.get(end..)?.find('}')? + 1;
+        Some(Range::new(
+            self.line_map
+                .offset_to_position(start as u32, self.source_text),
+            self.line_map
+                .offset_to_position(close as u32, self.source_text),
+        ))
+    }
+
+    /// The text between the quotes of a string or no-substitution template
+    /// literal, selected before the literal itself.
+    fn string_contents_range(&self, node_idx: NodeIndex) -> Option<Range> {
+        let node = self.arena.get(node_idx)?;
+        let is_string = node.kind == SyntaxKind::StringLiteral as u16
+            || node.kind == SyntaxKind::NoSubstitutionTemplateLiteral as u16;
+        if !is_string || node.end < node.pos + 2 {
+            return None;
+        }
+        Some(Range::new(
+            self.line_map
+                .offset_to_position(node.pos + 1, self.source_text),
+            self.line_map
+                .offset_to_position(node.end - 1, self.source_text),
+        ))
+    }
+
     /// Convert a node to a Range.
     fn node_to_range(&self, node_idx: NodeIndex) -> Option<Range> {
         let node = self.arena.get(node_idx)?;
@@ -128,7 +242,10 @@ impl<'a> SelectionRangeProvider<'a> {
     ///
     /// Some internal nodes don't represent meaningful selection boundaries.
     const fn should_skip_node(&self, kind: u16) -> bool {
-        use syntax_kind_ext::{EMPTY_STATEMENT, OMITTED_EXPRESSION, SEMICOLON_CLASS_ELEMENT};
+        use syntax_kind_ext::{
+            EMPTY_STATEMENT, OMITTED_EXPRESSION, SEMICOLON_CLASS_ELEMENT,
+            TEMPLATE_LITERAL_TYPE_SPAN, TEMPLATE_SPAN,
+        };
 
         matches!(
             kind,
@@ -136,7 +253,11 @@ impl<'a> SelectionRangeProvider<'a> {
             OMITTED_EXPRESSION |
             SEMICOLON_CLASS_ELEMENT |
             // Skip some internal structural nodes
-            EMPTY_STATEMENT
+            EMPTY_STATEMENT |
+            // Template spans run from a substitution into the following
+            // literal text; `${...}` is selected instead
+            TEMPLATE_SPAN |
+            TEMPLATE_LITERAL_TYPE_SPAN
         )
     }
 }
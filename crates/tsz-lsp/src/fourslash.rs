@@ -1895,7 +1895,7 @@ impl FourslashTest {
     }
 
     /// Get incoming calls at a marker.
-    pub fn incoming_calls(&self, marker_name: &str) -> IncomingCallsResult {
+    pub fn incoming_calls(&mut self, marker_name: &str) -> IncomingCallsResult {
         let m = self.markers.get(marker_name).unwrap_or_else(|| {
             panic!("Marker '{marker_name}' not found");
         });
@@ -5,6 +5,10 @@
 //! - `prepare`: identifies the class/interface at a cursor position
 //! - `supertypes`: finds what the class/interface extends or implements
 //! - `subtypes`: finds what classes/interfaces extend or implement this type
+//!
+//! The provider works on a single file. `Project::supertypes` and
+//! `Project::subtypes` extend it project-wide using the symbol index's
+//! heritage and type-declaration tables.
 
 use crate::symbols::document_symbols::SymbolKind;
 use crate::utils::{find_node_at_offset, identifier_text, node_range};
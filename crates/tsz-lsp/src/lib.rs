@@ -39,6 +39,7 @@ pub mod hierarchy;
 pub mod highlighting;
 pub mod hover;
 pub mod jsdoc;
+pub use tsz_common::CancellationToken;
 pub use tsz_common::position;
 pub mod intrinsic_params;
 pub mod navigation;
@@ -47,6 +48,7 @@ pub mod rename;
 pub mod resolver;
 pub mod signature_help;
 pub mod symbols;
+pub mod type_writer;
 pub mod utils;
 
 pub mod fourslash;
@@ -78,8 +80,9 @@ pub use code_actions::{
 };
 pub use completions::{CompletionItem, CompletionItemData, CompletionItemKind, Completions};
 pub use diagnostics::{
-    DiagnosticSeverity, DocumentDiagnosticReportKind, FullDocumentDiagnosticReport, LspDiagnostic,
-    UnchangedDocumentDiagnosticReport, WorkspaceDiagnosticReport, WorkspaceDiagnosticReportItem,
+    DiagnosticSeverity, DocumentDiagnosticReport, DocumentDiagnosticReportKind,
+    FullDocumentDiagnosticReport, LspDiagnostic, UnchangedDocumentDiagnosticReport,
+    WorkspaceDiagnosticReport, WorkspaceDiagnosticReportItem,
 };
 pub use editor_ranges::folding::{FoldingRange, FoldingRangeProvider};
 pub use formatting::{
@@ -101,7 +104,7 @@ pub use navigation::{
 pub use position::{Location, Position, Range, SourceLocation};
 pub use project::{
     FileRename, FileResidencyInfo, Project, ProjectFile, ProjectPerformance, ProjectRequestKind,
-    ProjectRequestTiming, ProjectResidencyStats, TsConfigSettings,
+    ProjectRequestTiming, ProjectResidencyStats, TextDocumentContentChange, TsConfigSettings,
 };
 pub use rename::{RenameProvider, TextEdit, WorkspaceEdit};
 pub use signature_help::{
@@ -122,7 +125,9 @@ pub use editor_decorations::code_lens::{
 };
 
 // Inlay Hints
-pub use editor_decorations::inlay_hints::{InlayHint, InlayHintKind, InlayHintsProvider};
+pub use editor_decorations::inlay_hints::{
+    InlayHint, InlayHintKind, InlayHintsOptions, InlayHintsProvider, ParameterNameHints,
+};
 
 // Symbol Index
 pub use symbols::SymbolIndex;
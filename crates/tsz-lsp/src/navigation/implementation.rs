@@ -2,13 +2,20 @@
 //!
 //! Given a position in the source at an interface or abstract class declaration,
 //! finds all concrete implementations of that type within the current file.
+//! When the cursor is on a member of an interface or class (e.g. an interface
+//! method or an abstract property), the matching members of implementing
+//! classes are returned instead.
 //!
 //! Strategy:
 //! 1. Find the symbol at cursor position (reuse `GoToDefinition` pattern)
 //! 2. Determine if it's an interface or abstract class
 //! 3. Walk all `ClassDeclaration` / `InterfaceDeclaration` nodes in the AST
 //! 4. For each class/interface, check heritage clauses for the target name
-//! 5. Return locations of implementing classes/interfaces
+//! 5. Return locations of implementing classes/interfaces (or their members)
+//!
+//! Cross-file search lives in `Project::get_implementations`, which drives
+//! [`GoToImplementationProvider::find_implementations_for_name`] over every
+//! file the symbol index reports as mentioning the target in a heritage clause.
 
 use crate::utils::find_node_at_offset;
 use tsz_common::position::{Location, Position, Range};
@@ -35,6 +42,25 @@ pub struct ImplementationResult {
     pub name: String,
     /// The location of the implementation declaration
     pub location: Location,
+    /// The implementing class/interface declaration node
+    pub declaration: NodeIndex,
+    /// What the implementing declaration itself is, for transitive search
+    /// (interfaces extending the target are searched as interfaces, classes
+    /// as classes).
+    pub kind: TargetKind,
+}
+
+/// A member of an interface or class whose implementations are requested.
+#[derive(Debug, Clone)]
+pub struct MemberTarget {
+    /// The containing interface/class declaration node
+    pub container: NodeIndex,
+    /// The (unqualified) name of the containing interface/class
+    pub container_name: String,
+    /// The kind of the containing declaration
+    pub container_kind: TargetKind,
+    /// The member's property name
+    pub member_name: String,
 }
 
 impl<'a> GoToImplementationProvider<'a> {
@@ -106,10 +132,29 @@ impl<'a> GoToImplementationProvider<'a> {
             return None;
         }
 
-        // 3. Resolve the node to a symbol
+        // 3. Members of interfaces/classes resolve to the matching members of
+        //    implementing classes.
+        if let Some(member) = self.resolve_member_target_at_node(node_idx) {
+            let container_name = self
+                .namespace_for_declaration(member.container)
+                .map_or_else(
+                    || member.container_name.clone(),
+                    |ns| format!("{ns}.{}", member.container_name),
+                );
+            let locations: Vec<Location> = self
+                .find_implementations_for_name(&container_name, member.container_kind)
+                .into_iter()
+                .filter_map(|found| {
+                    self.find_member_location(found.declaration, &member.member_name)
+                })
+                .collect();
+            return (!locations.is_empty()).then_some(locations);
+        }
+
+        // 4. Resolve the node to a symbol
         let symbol_id = self.resolve_symbol_at_node(node_idx)?;
 
-        // 4. Get the symbol and determine its kind
+        // 5. Get the symbol and determine its kind
         let symbol = self.binder.symbols.get(symbol_id)?;
         let target_name = self
             .qualified_name_for_symbol(symbol)
@@ -118,7 +163,7 @@ impl<'a> GoToImplementationProvider<'a> {
         // Determine if the target is an interface or a class (abstract or not)
         let target_kind = self.determine_target_kind(symbol)?;
 
-        // 5. Collect all implementing declarations
+        // 6. Collect all implementing declarations
         let mut locations = Vec::new();
         self.collect_implementations(root, &target_name, target_kind, &mut locations);
 
@@ -421,6 +466,8 @@ impl<'a> GoToImplementationProvider<'a> {
                         results.push(ImplementationResult {
                             name: class_name.to_string(),
                             location: loc,
+                            declaration: node_idx,
+                            kind: TargetKind::ConcreteClass,
                         });
                     }
                 }
@@ -435,6 +482,8 @@ impl<'a> GoToImplementationProvider<'a> {
                         results.push(ImplementationResult {
                             name: iface_name.to_string(),
                             location: loc,
+                            declaration: node_idx,
+                            kind: TargetKind::Interface,
                         });
                     }
                 }
@@ -445,6 +494,94 @@ impl<'a> GoToImplementationProvider<'a> {
         results
     }
 
+    /// Resolve the interface/class member whose name is at `node_idx`.
+    ///
+    /// Returns `None` unless the node is the name of a property, method, or
+    /// accessor declared directly in an interface or class body.
+    pub fn resolve_member_target_at_node(&self, node_idx: NodeIndex) -> Option<MemberTarget> {
+        let member_idx = self.arena.get_extended(node_idx)?.parent;
+        if self.member_name_idx(member_idx)? != node_idx {
+            return None;
+        }
+        let member_name = self.get_identifier_escaped_text(node_idx)?.to_string();
+
+        let container = self.arena.get_extended(member_idx)?.parent;
+        let container_node = self.arena.get(container)?;
+        let (name_idx, container_kind) =
+            if container_node.kind == syntax_kind_ext::INTERFACE_DECLARATION {
+                (
+                    self.arena.get_interface(container_node)?.name,
+                    TargetKind::Interface,
+                )
+            } else if container_node.kind == syntax_kind_ext::CLASS_DECLARATION
+                || container_node.kind == syntax_kind_ext::CLASS_EXPRESSION
+            {
+                let is_abstract = self
+                    .arena
+                    .get_extended(container)
+                    .is_some_and(|ext| ext.modifier_flags & modifier_flags::ABSTRACT != 0);
+                let kind = if is_abstract {
+                    TargetKind::AbstractClass
+                } else {
+                    TargetKind::ConcreteClass
+                };
+                (self.arena.get_class(container_node)?.name, kind)
+            } else {
+                return None;
+            };
+
+        Some(MemberTarget {
+            container,
+            container_name: self.get_identifier_escaped_text(name_idx)?.to_string(),
+            container_kind,
+            member_name,
+        })
+    }
+
+    /// Find the concrete (non-abstract) member named `member_name` declared in
+    /// the body of the class at `class_idx`.
+    pub fn find_member_location(
+        &self,
+        class_idx: NodeIndex,
+        member_name: &str,
+    ) -> Option<Location> {
+        let class_node = self.arena.get(class_idx)?;
+        let class = self.arena.get_class(class_node)?;
+        class.members.nodes.iter().find_map(|&member_idx| {
+            let name_idx = self.member_name_idx(member_idx)?;
+            let is_abstract = self
+                .arena
+                .get_extended(member_idx)
+                .is_some_and(|ext| ext.modifier_flags & modifier_flags::ABSTRACT != 0);
+            if is_abstract || self.get_identifier_escaped_text(name_idx)? != member_name {
+                return None;
+            }
+            self.location_for_declaration(member_idx, name_idx)
+        })
+    }
+
+    /// Name node of a property/method/accessor declaration or signature.
+    fn member_name_idx(&self, member_idx: NodeIndex) -> Option<NodeIndex> {
+        let node = self.arena.get(member_idx)?;
+        match node.kind {
+            k if k == syntax_kind_ext::METHOD_DECLARATION => {
+                self.arena.get_method_decl(node).map(|m| m.name)
+            }
+            k if k == syntax_kind_ext::PROPERTY_DECLARATION => {
+                self.arena.get_property_decl(node).map(|p| p.name)
+            }
+            k if k == syntax_kind_ext::GET_ACCESSOR || k == syntax_kind_ext::SET_ACCESSOR => {
+                self.arena.get_accessor(node).map(|a| a.name)
+            }
+            k if k == syntax_kind_ext::METHOD_SIGNATURE
+                || k == syntax_kind_ext::PROPERTY_SIGNATURE =>
+            {
+                self.arena.get_signature(node).map(|sig| sig.name)
+            }
+            _ => None,
+        }
+    }
+
     /// Resolve the target kind for a symbol by name.
     ///
     /// This is used by Project to determine what kind of target we're searching for
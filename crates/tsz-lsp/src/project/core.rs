@@ -8,10 +8,11 @@ use globset::Glob;
 use regex::{Regex, RegexBuilder};
 use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 
-use crate::code_actions::ImportCandidateKind;
+use crate::code_actions::{ImportCandidateKind, ReactJsxFactories};
 use crate::completions::{CompletionItem, Completions};
 use crate::dependency_graph::DependencyGraph;
 use crate::diagnostics::{LspDiagnostic, convert_diagnostic};
+use crate::editor_decorations::inlay_hints::InlayHintsOptions;
 use crate::export_signature::{ExportSignature, InvalidationSummary};
 use crate::hover::{HoverInfo, HoverProvider};
 use crate::rename::TextEdit;
@@ -20,10 +21,12 @@ use crate::rename::WorkspaceEdit;
 use crate::resolver::{ScopeCache, ScopeCacheStats, scope_cache_estimated_size_bytes};
 use crate::signature_help::{SignatureHelp, SignatureHelpProvider};
 use crate::symbols::symbol_index::SymbolIndex;
+use crate::type_writer::{TypeWriterEntry, TypeWriterProvider};
 use tsz_binder::BinderState;
 use tsz_binder::SymbolId;
 use tsz_checker::TypeCache;
 use tsz_checker::state::CheckerState;
+use tsz_common::CancellationToken;
 use tsz_common::position::{LineMap, Location, Position, Range};
 use tsz_parser::ParserState;
 use tsz_parser::parser::node::NodeAccess;
@@ -51,6 +54,18 @@ pub struct FileRename {
     pub new_uri: String,
 }
 
+/// One entry of a `textDocument/didChange` notification.
+///
+/// Changes are applied in order, each against the text produced by the
+/// previous one. A change without a range replaces the whole document.
+#[derive(Clone, Debug)]
+pub struct TextDocumentContentChange {
+    /// The replaced range, or `None` for a full-document replacement.
+    pub range: Option<Range>,
+    /// The new text for the range (or the whole document).
+    pub text: String,
+}
+
 pub(crate) struct NamespaceReexportTarget {
     pub(crate) file: String,
     pub(crate) namespace: String,
@@ -252,6 +267,7 @@ impl ProjectFile {
             strict,
             type_interner,
             u32::MAX,
+            false,
         )
     }
 
@@ -261,15 +277,19 @@ impl ProjectFile {
     /// The `file_idx` is stamped onto all binder symbols (`decl_file_idx`)
     /// during binding, enabling per-file `DefinitionStore` invalidation.
     /// Pass `u32::MAX` for standalone mode (no invalidation tracking).
+    /// With `defer_function_bodies`, function bodies are parsed only when
+    /// [`Self::parse_deferred_function_bodies`] is called.
     fn with_shared_interner_and_file_idx(
         file_name: String,
         source_text: String,
         strict: bool,
         type_interner: Arc<TypeInterner>,
         file_idx: u32,
+        defer_function_bodies: bool,
     ) -> Self {
         let content_hash = hash_source_content(&source_text);
         let mut parser = ParserState::new(file_name.clone(), source_text);
+        parser.set_defer_function_bodies(defer_function_bodies);
         let root = parser.parse_source_file();
         let arena = parser.get_arena();
 
@@ -333,6 +353,7 @@ impl ProjectFile {
         type_interner: Arc<TypeInterner>,
         definition_store: Arc<DefinitionStore>,
         file_idx: u32,
+        defer_function_bodies: bool,
     ) -> Self {
         let mut file = Self::with_shared_interner_and_file_idx(
             file_name,
@@ -340,6 +361,7 @@ impl ProjectFile {
             strict,
             type_interner,
             file_idx,
+            defer_function_bodies,
         );
         file.definition_store = Some(definition_store);
         file
@@ -537,23 +559,47 @@ impl ProjectFile {
 
     pub fn update_source(&mut self, source_text: String) {
         self.content_hash = hash_source_content(&source_text);
+        // A file being edited is parsed in full.
+        self.parser.set_defer_function_bodies(false);
         self.parser.reset(self.file_name.clone(), source_text);
         self.root = self.parser.parse_source_file();
+        self.rebind();
 
         let arena = self.parser.get_arena();
+        self.line_map = LineMap::build(self.parser.get_source_text());
+        let has_wildcard_reexport = compute_has_wildcard_reexport(arena, self.root);
+        self.reset_analysis_state();
+        self.export_signature = ExportSignature::compute(&self.binder, &self.file_name);
+        self.has_wildcard_reexport = has_wildcard_reexport;
+    }
+
+    fn rebind(&mut self) {
         self.binder.reset();
         // Preserve file_idx across re-binds so the DefinitionStore can
         // track which definitions belong to this file.
         if self.file_idx != u32::MAX {
             self.binder.set_file_idx(self.file_idx);
         }
-        self.binder.bind_source_file(arena, self.root);
+        self.binder
+            .bind_source_file(self.parser.get_arena(), self.root);
+    }
 
-        self.line_map = LineMap::build(self.parser.get_source_text());
-        let has_wildcard_reexport = compute_has_wildcard_reexport(arena, self.root);
+    /// Whether some function bodies of this file have not been parsed yet.
+    pub fn has_deferred_function_bodies(&self) -> bool {
+        self.parser.has_deferred_function_bodies()
+    }
+
+    /// Parse the function bodies skipped when the file was loaded, then
+    /// re-bind it. Returns `false` when there were none.
+    pub fn parse_deferred_function_bodies(&mut self) -> bool {
+        self.parser.set_defer_function_bodies(false);
+        if !self.parser.has_deferred_function_bodies() {
+            return false;
+        }
+        self.parser.parse_all_deferred_function_bodies();
+        self.rebind();
         self.reset_analysis_state();
-        self.export_signature = ExportSignature::compute(&self.binder, &self.file_name);
-        self.has_wildcard_reexport = has_wildcard_reexport;
+        true
     }
 
     /// Invalidate all caches for this file.
@@ -565,7 +611,7 @@ impl ProjectFile {
     }
 
     pub fn update_source_with_edits(&mut self, source_text: String, edits: &[TextEdit]) {
-        if edits.is_empty() {
+        if edits.is_empty() || self.has_deferred_function_bodies() {
             self.update_source(source_text);
             return;
         }
@@ -788,6 +834,21 @@ impl ProjectFile {
         )
     }
 
+    /// Type and symbol baseline entries for the whole file.
+    pub fn get_type_writer_entries(&mut self) -> Vec<TypeWriterEntry> {
+        let provider = TypeWriterProvider::with_strict(
+            self.parser.get_arena(),
+            &self.binder,
+            &self.line_map,
+            &self.type_interner,
+            self.parser.get_source_text(),
+            self.file_name.clone(),
+            self.strict,
+        );
+
+        provider.collect_entries(self.root, &mut self.type_cache, &mut self.scope_cache)
+    }
+
     pub fn get_signature_help(&mut self, position: Position) -> Option<SignatureHelp> {
         self.get_signature_help_with_stats(position, None)
     }
@@ -845,6 +906,18 @@ impl ProjectFile {
     }
 
     pub fn get_diagnostics(&mut self) -> Vec<LspDiagnostic> {
+        self.get_diagnostics_with_cancellation(None)
+            .unwrap_or_default()
+    }
+
+    /// Type-check the file, giving up early once `cancellation` is cancelled.
+    ///
+    /// Returns `None` for a cancelled run. Its partial results are dropped and
+    /// the file stays dirty so the next request re-checks it from scratch.
+    pub fn get_diagnostics_with_cancellation(
+        &mut self,
+        cancellation: Option<&CancellationToken>,
+    ) -> Option<Vec<LspDiagnostic>> {
         let file_name = self.file_name.clone();
         let source_text = self.parser.get_source_text();
         let compiler_options = tsz_checker::context::CheckerOptions {
@@ -897,7 +970,13 @@ impl ProjectFile {
             ),
         };
 
+        checker.set_cancellation_token(cancellation.cloned());
         checker.check_source_file(self.root);
+        if checker.is_cancelled() {
+            // Types resolved after cancellation may be ERROR placeholders, so
+            // the cache from this run cannot be reused.
+            return None;
+        }
 
         let diagnostics = checker
             .ctx
@@ -906,9 +985,10 @@ impl ProjectFile {
             .map(|diag| convert_diagnostic(diag, &self.line_map, source_text))
             .collect();
 
+        checker.set_cancellation_token(None);
         self.type_cache = Some(checker.extract_cache());
         self.diagnostics_dirty = false;
-        diagnostics
+        Some(diagnostics)
     }
 
     pub(crate) fn node_location(&self, node_idx: NodeIndex) -> Option<Location> {
@@ -1464,6 +1544,45 @@ fn apply_text_edits(source: &str, line_map: &LineMap, edits: &[TextEdit]) -> Opt
     Some(result)
 }
 
+/// Collapse the difference between `old` and `new` into a single edit
+/// expressed in `old`'s coordinates, by trimming their common prefix and
+/// suffix. Returns `None` when the texts are identical.
+fn single_edit_between(old: &str, new: &str, line_map: &LineMap) -> Option<TextEdit> {
+    if old == new {
+        return None;
+    }
+
+    let mut prefix = old
+        .bytes()
+        .zip(new.bytes())
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !old.is_char_boundary(prefix) || !new.is_char_boundary(prefix) {
+        prefix -= 1;
+    }
+
+    let max_suffix = old.len().min(new.len()) - prefix;
+    let mut suffix = old
+        .bytes()
+        .rev()
+        .zip(new.bytes().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !old.is_char_boundary(old.len() - suffix) || !new.is_char_boundary(new.len() - suffix) {
+        suffix -= 1;
+    }
+
+    let old_end = old.len() - suffix;
+    Some(TextEdit {
+        range: Range::new(
+            line_map.offset_to_position(prefix as u32, old),
+            line_map.offset_to_position(old_end as u32, old),
+        ),
+        new_text: new[prefix..new.len() - suffix].to_string(),
+    })
+}
+
 fn normalize_auto_import_exclude_pattern(pattern: &str) -> Option<String> {
     let normalized = pattern.trim().replace('\\', "/");
     let stripped = normalized.strip_prefix("./").unwrap_or(&normalized).trim();
@@ -1645,6 +1764,29 @@ pub struct Project {
     /// this to tie-break by file proximity. `None` when the editor has not
     /// yet announced any focus.
     pub(crate) focused_file: Option<String>,
+    /// Which inlay hint categories `get_inlay_hints` produces.
+    pub(crate) inlay_hints_options: InlayHintsOptions,
+    /// Quote style for inserted auto-imports (`"single"`, `"double"`, `"auto"`).
+    pub(crate) quote_preference: Option<String>,
+    /// Whether `verbatimModuleSyntax` is enabled for the project.
+    pub(crate) verbatim_module_syntax: bool,
+    /// The JSX factories when `jsx` is `react`.
+    pub(crate) react_jsx_factories: Option<ReactJsxFactories>,
+    /// Cancellation token of the request currently being served, if any.
+    pub(crate) cancellation_token: Option<CancellationToken>,
+    /// Diagnostics last returned by pull diagnostics, keyed by file name.
+    pub(crate) diagnostics_results: FxHashMap<String, DiagnosticsResult>,
+    /// Counter used to mint pull-diagnostic result IDs.
+    pub(crate) next_diagnostics_result_id: u64,
+}
+
+/// A pull-diagnostics result the client may refer back to by `result_id`.
+#[derive(Debug, Clone)]
+pub(crate) struct DiagnosticsResult {
+    pub(crate) result_id: String,
+    /// Content hash of the file the diagnostics were computed for.
+    pub(crate) content_hash: u64,
+    pub(crate) diagnostics: Vec<LspDiagnostic>,
 }
 
 /// Assigns stable `u32` file indices to file names.
@@ -1811,6 +1953,12 @@ pub struct TsConfigSettings {
     pub allow_importing_ts_extensions: Option<bool>,
     /// JSX setting.
     pub jsx: Option<String>,
+    /// `jsxFactory` setting.
+    pub jsx_factory: Option<String>,
+    /// `jsxFragmentFactory` setting.
+    pub jsx_fragment_factory: Option<String>,
+    /// Whether `verbatimModuleSyntax` is enabled.
+    pub verbatim_module_syntax: Option<bool>,
 }
 
 impl Project {
@@ -1836,6 +1984,13 @@ impl Project {
             fingerprint_cache: SkeletonFingerprintCache::new(),
             open_files: FxHashSet::default(),
             focused_file: None,
+            inlay_hints_options: InlayHintsOptions::new(),
+            quote_preference: None,
+            verbatim_module_syntax: false,
+            react_jsx_factories: None,
+            cancellation_token: None,
+            diagnostics_results: FxHashMap::default(),
+            next_diagnostics_result_id: 0,
         }
     }
 
@@ -1861,6 +2016,13 @@ impl Project {
             fingerprint_cache: SkeletonFingerprintCache::new(),
             open_files: FxHashSet::default(),
             focused_file: None,
+            inlay_hints_options: InlayHintsOptions::new(),
+            quote_preference: None,
+            verbatim_module_syntax: false,
+            react_jsx_factories: None,
+            cancellation_token: None,
+            diagnostics_results: FxHashMap::default(),
+            next_diagnostics_result_id: 0,
         }
     }
 
@@ -1957,6 +2119,23 @@ impl Project {
             self.set_allow_importing_ts_extensions(allow);
         }
 
+        if let Some(verbatim) = settings.verbatim_module_syntax {
+            self.set_verbatim_module_syntax(verbatim);
+        }
+
+        if let Some(jsx) = &settings.jsx {
+            let defaults = ReactJsxFactories::default();
+            self.set_react_jsx_factories(jsx.eq_ignore_ascii_case("react").then(|| {
+                ReactJsxFactories {
+                    factory: settings.jsx_factory.clone().unwrap_or(defaults.factory),
+                    fragment_factory: settings
+                        .jsx_fragment_factory
+                        .clone()
+                        .unwrap_or(defaults.fragment_factory),
+                }
+            }));
+        }
+
         self.tsconfig_settings.insert(root.to_string(), settings);
     }
 
@@ -2037,9 +2216,10 @@ impl Project {
                     continue;
                 }
 
-                // Load the file
+                // Load the file. Its function bodies are parsed once an
+                // editor feature or a project-wide search needs them.
                 if let Ok(content) = std::fs::read_to_string(path) {
-                    self.set_file(path_str.clone(), content);
+                    self.load_file(path_str.clone(), content, true);
                     discovered.push(path_str);
                 }
             }
@@ -2066,6 +2246,51 @@ impl Project {
         self.allow_importing_ts_extensions = allow;
     }
 
+    /// Set which inlay hint categories are produced.
+    pub const fn set_inlay_hints_options(&mut self, options: InlayHintsOptions) {
+        self.inlay_hints_options = options;
+    }
+
+    /// Current inlay hint settings.
+    pub const fn inlay_hints_options(&self) -> InlayHintsOptions {
+        self.inlay_hints_options
+    }
+
+    /// Set the quote style for inserted auto-imports from the LSP
+    /// `quotePreference` string. `None` or `"auto"` follows the file's
+    /// existing imports.
+    pub fn set_quote_preference(&mut self, quote_preference: Option<String>) {
+        self.quote_preference = quote_preference;
+    }
+
+    /// Set whether `verbatimModuleSyntax` is in effect, which makes
+    /// auto-imports of values used only as types use `import type`.
+    pub const fn set_verbatim_module_syntax(&mut self, verbatim: bool) {
+        self.verbatim_module_syntax = verbatim;
+    }
+
+    /// Set the JSX factories in effect when `jsx` is `react`, which keep
+    /// their imports from being removed as unused; `None` otherwise.
+    pub fn set_react_jsx_factories(&mut self, factories: Option<ReactJsxFactories>) {
+        self.react_jsx_factories = factories;
+    }
+
+    /// Attach the cancellation token of the request about to be served, or
+    /// clear it with `None` once the request has finished.
+    ///
+    /// Completions, references and diagnostics poll the token and return
+    /// `None` when it has been cancelled.
+    pub fn set_cancellation_token(&mut self, token: Option<CancellationToken>) {
+        self.cancellation_token = token;
+    }
+
+    /// Whether the current request has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation_token
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
     /// Set completion module-specifier ending preference (e.g. "js").
     pub fn set_import_module_specifier_ending(&mut self, ending: Option<String>) {
         self.import_module_specifier_ending = ending;
@@ -2236,11 +2461,50 @@ impl Project {
     /// hover, completions, go-to-definition, references, etc.) so that
     /// eviction heuristics can distinguish hot files from cold ones.
     pub fn touch_file(&mut self, file_name: &str) {
+        self.ensure_function_bodies(file_name);
         if let Some(file) = self.files.get_mut(file_name) {
             file.touch();
         }
     }
 
+    /// Parse the function bodies of `file_name` that were skipped when it
+    /// was discovered on disk, and re-index it.
+    pub fn ensure_function_bodies(&mut self, file_name: &str) {
+        if !self
+            .files
+            .get(file_name)
+            .is_some_and(ProjectFile::has_deferred_function_bodies)
+        {
+            return;
+        }
+        if let Some(file_idx) = self.file_id_allocator.lookup(file_name) {
+            self.definition_store.invalidate_file(file_idx);
+        }
+        let Some(file) = self.files.get_mut(file_name) else {
+            return;
+        };
+        file.parse_deferred_function_bodies();
+        let arena = file.parser.get_arena();
+        let source = file.source_text();
+        self.symbol_index
+            .update_file(file_name, &file.binder, arena, source);
+        self.update_dependencies(file_name);
+    }
+
+    /// Parse every function body still skipped in the project, so that a
+    /// project-wide search sees the references inside them.
+    pub fn parse_all_deferred_function_bodies(&mut self) {
+        let deferred: Vec<String> = self
+            .files
+            .iter()
+            .filter(|(_, file)| file.has_deferred_function_bodies())
+            .map(|(name, _)| name.clone())
+            .collect();
+        for file_name in deferred {
+            self.ensure_function_bodies(&file_name);
+        }
+    }
+
     /// Add or replace a file, re-parsing and re-binding its contents.
     ///
     /// If the file already exists with identical content (same content hash),
@@ -2248,6 +2512,10 @@ impl Project {
     /// when the LSP receives `didOpen` for an already-loaded file, or `didSave`
     /// without content changes.
     pub fn set_file(&mut self, file_name: String, source_text: String) {
+        self.load_file(file_name, source_text, false);
+    }
+
+    fn load_file(&mut self, file_name: String, source_text: String, defer_function_bodies: bool) {
         // Fast path: skip re-parse if file exists with identical content.
         let new_hash = hash_source_content(&source_text);
         if let Some(existing) = self.files.get(&file_name)
@@ -2274,6 +2542,7 @@ impl Project {
             Arc::clone(&self.type_interner),
             Arc::clone(&self.definition_store),
             file_idx,
+            defer_function_bodies,
         );
 
         // Update symbol index with the new file's binder data and AST identifiers
@@ -2359,6 +2628,9 @@ impl Project {
             self.fingerprint_cache.update(file_idx, new_signature.0);
         }
 
+        // The edit may have added or removed imports.
+        self.update_dependencies(file_name);
+
         // Smart cache invalidation: only invalidate dependents if the public API changed.
         // Body-only edits, comment changes, and private symbol changes won't trigger
         // dependent re-checking — this is the key optimization.
@@ -2385,6 +2657,41 @@ impl Project {
         }
     }
 
+    /// Apply the content changes of a `textDocument/didChange` notification.
+    ///
+    /// Ranged changes are applied sequentially, as the LSP specification
+    /// requires, and the net result is handed to [`Self::update_file`] as a
+    /// single edit so that only the touched region is re-parsed and dependents
+    /// are invalidated only when the export signature changes.
+    pub fn apply_content_changes(
+        &mut self,
+        file_name: &str,
+        changes: &[TextDocumentContentChange],
+    ) -> Option<InvalidationSummary> {
+        let file = self.files.get(file_name)?;
+        let original = file.source_text();
+
+        let mut current = original.to_string();
+        for change in changes {
+            current = match change.range {
+                Some(range) => {
+                    let line_map = LineMap::build(&current);
+                    let edit = TextEdit {
+                        range,
+                        new_text: change.text.clone(),
+                    };
+                    apply_text_edits(&current, &line_map, std::slice::from_ref(&edit))?
+                }
+                None => change.text.clone(),
+            };
+        }
+
+        let edits: Vec<TextEdit> = single_edit_between(original, &current, file.line_map())
+            .into_iter()
+            .collect();
+        self.update_file(file_name, &edits)
+    }
+
     /// Remove a file from the project.
     ///
     /// Cleans up:
@@ -2407,6 +2714,7 @@ impl Project {
 
         // Remove from symbol index
         self.symbol_index.remove_file(file_name);
+        self.diagnostics_results.remove(file_name);
 
         // Invalidate caches in files that depend on the removed file,
         // since the removed file's exports are no longer available.
@@ -2490,26 +2798,13 @@ impl Project {
             let old_path = Path::new(&rename.old_uri);
             let new_path = Path::new(&rename.new_uri);
 
-            // Check if this is a directory rename
+            // Directory rename: imports into and out of the moved folder
+            // (plus tsconfig `paths` targets) are rewritten together.
             if self.is_directory(old_path) {
-                // Directory rename: expand to individual file renames
-                let files_in_dir = self.find_files_in_directory(old_path);
-
-                for old_file_path in files_in_dir {
-                    // Compute the new path for this file
-                    // Relative path within the directory
-                    let relative = old_file_path
-                        .strip_prefix(&rename.old_uri)
-                        .unwrap_or(&old_file_path);
-                    let new_file_path = new_path.join(relative);
-                    let new_file_path_str = new_file_path.to_string_lossy().to_string();
-
-                    // Process this file rename with the actual file paths (not directory)
-                    self.process_file_rename(
-                        Path::new(&old_file_path),
-                        Path::new(&new_file_path_str),
-                        &mut result,
-                    );
+                for (file_name, edits) in self.directory_rename_edits(old_path, new_path) {
+                    for edit in edits {
+                        result.add_edit(file_name.clone(), edit);
+                    }
                 }
             } else {
                 // Single file rename
@@ -2701,7 +2996,7 @@ impl Project {
     /// In a real LSP server, you would use file system metadata, but here
     /// we check if the path exists in our project as a prefix to other files.
     #[cfg(not(target_arch = "wasm32"))]
-    fn is_directory(&self, path: &Path) -> bool {
+    pub(super) fn is_directory(&self, path: &Path) -> bool {
         let path_str = path.to_string_lossy();
         let path_str_ref = path_str.as_ref();
 
@@ -2721,27 +3016,6 @@ impl Project {
         false
     }
 
-    /// Recursively find all TypeScript files within a directory path.
-    ///
-    /// Returns all .ts and .tsx files that have the given directory as a prefix.
-    #[cfg(not(target_arch = "wasm32"))]
-    fn find_files_in_directory(&self, directory: &Path) -> Vec<String> {
-        let dir_str = directory.to_string_lossy();
-        let dir_str_ref = dir_str.as_ref();
-        let mut result = Vec::new();
-
-        for file_path in self.files.keys() {
-            if file_path.starts_with(dir_str_ref) {
-                // Check if it's a .ts or .tsx file (not a directory)
-                if file_path.ends_with(".ts") || file_path.ends_with(".tsx") {
-                    result.push(file_path.clone());
-                }
-            }
-        }
-
-        result
-    }
-
     /// Get candidate files that might contain references to a symbol.
     ///
     /// This uses the `SymbolIndex` for O(1) lookup, turning cross-file searches
@@ -2773,18 +3047,18 @@ impl Default for Project {
 
 /// Check whether a file path has a TypeScript/JavaScript extension.
 #[cfg(not(target_arch = "wasm32"))]
-fn is_ts_js_file(path: &str) -> bool {
+pub(super) fn is_ts_js_file(path: &str) -> bool {
     let extensions = [".ts", ".tsx", ".js", ".jsx", ".mts", ".cts", ".mjs", ".cjs"];
     extensions.iter().any(|ext| path.ends_with(ext))
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-fn is_relative_specifier(spec: &str) -> bool {
+pub(super) fn is_relative_specifier(spec: &str) -> bool {
     spec.starts_with("./") || spec.starts_with("../") || spec == "." || spec == ".."
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-fn path_to_slash_string(path: &Path) -> String {
+pub(super) fn path_to_slash_string(path: &Path) -> String {
     path.to_string_lossy().replace('\\', "/")
 }
 
@@ -2882,6 +3156,20 @@ fn parse_tsconfig_file(path: &std::path::Path) -> Option<TsConfigSettings> {
             .and_then(|v| v.as_str())
             .map(String::from);
 
+        settings.jsx_factory = compiler_options
+            .get("jsxFactory")
+            .and_then(|v| v.as_str())
+            .map(String::from);
+
+        settings.jsx_fragment_factory = compiler_options
+            .get("jsxFragmentFactory")
+            .and_then(|v| v.as_str())
+            .map(String::from);
+
+        settings.verbatim_module_syntax = compiler_options
+            .get("verbatimModuleSyntax")
+            .and_then(|v| v.as_bool());
+
         // Parse paths
         if let Some(paths) = compiler_options.get("paths").and_then(|v| v.as_object()) {
             for (key, val) in paths {
@@ -0,0 +1,439 @@
+//! Directory rename/move support for `workspace/willRenameFiles`.
+//!
+//! Moving a folder affects three kinds of references:
+//! - relative imports from files outside the folder into it,
+//! - relative imports from files inside the folder out to the rest of the
+//!   project (the importer moved, the target did not),
+//! - tsconfig `paths` mappings whose targets live inside the folder.
+//!
+//! Imports between two files that move together keep their relative shape and
+//! are left untouched.
+
+use std::path::Path;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use tsz_common::position::{LineMap, Range};
+
+use super::Project;
+use super::core::{is_relative_specifier, is_ts_js_file, path_to_slash_string};
+use super::module_specifiers::{
+    base_dir_for_compiler_options, normalize_path, parse_typescript_config_json,
+};
+use crate::rename::TextEdit;
+use crate::rename::file_rename::FileRenameProvider;
+use crate::utils::calculate_new_relative_path;
+
+const CONFIG_FILE_NAMES: [&str; 2] = ["tsconfig.json", "jsconfig.json"];
+
+impl Project {
+    /// Compute the edits needed when the directory `old_dir` is moved to
+    /// `new_dir`, keyed by the file that has to change.
+    pub(crate) fn directory_rename_edits(
+        &self,
+        old_dir: &Path,
+        new_dir: &Path,
+    ) -> FxHashMap<String, Vec<TextEdit>> {
+        let old_dir = path_to_slash_string(old_dir)
+            .trim_end_matches('/')
+            .to_string();
+        let new_dir = path_to_slash_string(new_dir)
+            .trim_end_matches('/')
+            .to_string();
+        let mut edits: FxHashMap<String, Vec<TextEdit>> = FxHashMap::default();
+
+        let moved_files: Vec<(String, String)> = self
+            .files
+            .keys()
+            .filter(|name| is_ts_js_file(name))
+            .filter_map(|name| {
+                relocate_path(name, &old_dir, &new_dir).map(|new_name| (name.clone(), new_name))
+            })
+            .collect();
+
+        for (importer, file) in &self.files {
+            if !is_ts_js_file(importer) {
+                continue;
+            }
+            let relocated_importer = relocate_path(importer, &old_dir, &new_dir);
+            let provider =
+                FileRenameProvider::new(file.arena(), file.line_map(), file.source_text());
+
+            for location in provider.find_import_specifier_nodes(file.root()) {
+                let specifier = &location.current_specifier;
+                let new_specifier = if is_relative_specifier(specifier) {
+                    relocated_relative_specifier(
+                        importer,
+                        relocated_importer.as_deref(),
+                        specifier,
+                        &old_dir,
+                        &new_dir,
+                    )
+                } else {
+                    // `paths` aliases resolve through the importer's tsconfig,
+                    // which does not move with the folder, so only the target
+                    // side of the alias has to be recomputed.
+                    moved_files.iter().find_map(|(old_file, new_file)| {
+                        self.rename_path_alias_specifier(importer, specifier, old_file, new_file)
+                    })
+                };
+                let Some(new_specifier) = new_specifier else {
+                    continue;
+                };
+                if new_specifier == *specifier {
+                    continue;
+                }
+                edits
+                    .entry(importer.clone())
+                    .or_default()
+                    .push(location.specifier_text_edit(new_specifier));
+            }
+        }
+
+        self.collect_tsconfig_paths_edits(&moved_files, &old_dir, &new_dir, &mut edits);
+
+        edits
+    }
+
+    /// Rewrite `compilerOptions.paths` targets that point into the moved
+    /// directory, for every tsconfig/jsconfig governing a moved file.
+    fn collect_tsconfig_paths_edits(
+        &self,
+        moved_files: &[(String, String)],
+        old_dir: &str,
+        new_dir: &str,
+        edits: &mut FxHashMap<String, Vec<TextEdit>>,
+    ) {
+        let mut config_paths = Vec::new();
+        let mut seen = FxHashSet::default();
+        for (old_file, _) in moved_files {
+            let mut current = Path::new(old_file).parent();
+            while let Some(dir) = current {
+                for config_name in CONFIG_FILE_NAMES {
+                    let config_path = path_to_slash_string(&dir.join(config_name));
+                    if seen.insert(config_path.clone()) {
+                        config_paths.push(config_path);
+                    }
+                }
+                current = dir.parent();
+            }
+        }
+
+        for config_path in config_paths {
+            // A config inside the moved folder moves with it; its `paths`
+            // stay relative to itself.
+            if relocate_path(&config_path, old_dir, new_dir).is_some() {
+                continue;
+            }
+            let Some(config_text) = self
+                .files
+                .get(&config_path)
+                .map(|file| file.source_text().to_string())
+                .or_else(|| std::fs::read_to_string(&config_path).ok())
+            else {
+                continue;
+            };
+            let config_edits = tsconfig_paths_edits(&config_path, &config_text, old_dir, new_dir);
+            if !config_edits.is_empty() {
+                edits.entry(config_path).or_default().extend(config_edits);
+            }
+        }
+    }
+}
+
+/// Map `path` into `new_dir` if it is `old_dir` itself or lives below it.
+fn relocate_path(path: &str, old_dir: &str, new_dir: &str) -> Option<String> {
+    if path == old_dir {
+        return Some(new_dir.to_string());
+    }
+    let rest = path.strip_prefix(old_dir)?.strip_prefix('/')?;
+    Some(format!("{new_dir}/{rest}"))
+}
+
+/// Recompute a relative specifier after a directory move.
+///
+/// The specifier is resolved textually (without probing extensions), so the
+/// importer's extension and `index` style carry over unchanged. Returns `None`
+/// when neither or both ends of the import moved.
+fn relocated_relative_specifier(
+    importer: &str,
+    relocated_importer: Option<&str>,
+    specifier: &str,
+    old_dir: &str,
+    new_dir: &str,
+) -> Option<String> {
+    let importer_dir = Path::new(importer).parent()?;
+    let resolved = path_to_slash_string(&normalize_path(&importer_dir.join(specifier)));
+    let relocated_target = relocate_path(&resolved, old_dir, new_dir);
+
+    let (new_importer, new_target) = match (relocated_importer, relocated_target) {
+        (None, None) | (Some(_), Some(_)) => return None,
+        (None, Some(target)) => (importer.to_string(), target),
+        (Some(new_importer), None) => (new_importer.to_string(), resolved.clone()),
+    };
+
+    calculate_new_relative_path(
+        Path::new(&new_importer),
+        Path::new(&resolved),
+        Path::new(&new_target),
+        specifier,
+    )
+    .map(|new_specifier| new_specifier.replace('\\', "/"))
+}
+
+/// Produce edits for the `paths` targets of one config file.
+fn tsconfig_paths_edits(
+    config_path: &str,
+    config_text: &str,
+    old_dir: &str,
+    new_dir: &str,
+) -> Vec<TextEdit> {
+    let Some(config_json) = parse_typescript_config_json(config_text) else {
+        return Vec::new();
+    };
+    let Some(compiler_options) = config_json
+        .get("compilerOptions")
+        .and_then(serde_json::Value::as_object)
+    else {
+        return Vec::new();
+    };
+    let Some(paths) = compiler_options
+        .get("paths")
+        .and_then(serde_json::Value::as_object)
+    else {
+        return Vec::new();
+    };
+
+    let config_dir = normalize_path(Path::new(config_path).parent().unwrap_or(Path::new("")));
+    let base_dir = base_dir_for_compiler_options(&config_dir, compiler_options);
+
+    let mut replacements: FxHashMap<&str, String> = FxHashMap::default();
+    for mapped_target in paths
+        .values()
+        .filter_map(serde_json::Value::as_array)
+        .flatten()
+        .filter_map(serde_json::Value::as_str)
+    {
+        if let Some(new_target) =
+            relocated_path_mapping(mapped_target, &base_dir, &config_dir, old_dir, new_dir)
+        {
+            replacements.insert(mapped_target, new_target);
+        }
+    }
+    if replacements.is_empty() {
+        return Vec::new();
+    }
+
+    let Some((object_start, object_end)) = paths_object_span(config_text) else {
+        return Vec::new();
+    };
+    let line_map = LineMap::build(config_text);
+    json_string_values(config_text, object_start, object_end)
+        .into_iter()
+        .filter_map(|(start, end)| {
+            let new_target = replacements.get(&config_text[start..end])?;
+            Some(TextEdit::new(
+                Range::new(
+                    line_map.offset_to_position(start as u32, config_text),
+                    line_map.offset_to_position(end as u32, config_text),
+                ),
+                new_target.clone(),
+            ))
+        })
+        .collect()
+}
+
+/// Rewrite a single `paths` mapping target (e.g. `src/ui/*`) if the directory
+/// portion before its wildcard lies inside the moved directory.
+fn relocated_path_mapping(
+    mapped_target: &str,
+    base_dir: &Path,
+    config_dir: &Path,
+    old_dir: &str,
+    new_dir: &str,
+) -> Option<String> {
+    const CONFIG_DIR_TOKEN: &str = "${configDir}/";
+
+    let mapped = mapped_target.replace('\\', "/");
+    let (anchor, relative) = match mapped.strip_prefix(CONFIG_DIR_TOKEN) {
+        Some(rest) => (config_dir, rest.to_string()),
+        None => (base_dir, mapped.clone()),
+    };
+    let (fixed, wildcard_suffix) = match relative.find('*') {
+        Some(star) => {
+            let slash = relative[..star].rfind('/').map_or(0, |idx| idx + 1);
+            (&relative[..slash], &relative[slash..])
+        }
+        None => (relative.as_str(), ""),
+    };
+
+    let resolved = path_to_slash_string(&normalize_path(&anchor.join(fixed)));
+    let relocated = relocate_path(resolved.trim_end_matches('/'), old_dir, new_dir)?;
+
+    let anchor_str = path_to_slash_string(anchor);
+    let anchor_prefix = anchor_str.trim_end_matches('/');
+    let mut new_fixed = match relocated.strip_prefix(anchor_prefix) {
+        Some(rest) if anchor_prefix.is_empty() || rest.is_empty() || rest.starts_with('/') => {
+            rest.trim_start_matches('/').to_string()
+        }
+        // The move left the mapping's base directory; fall back to a
+        // `../`-style path from the base.
+        _ => calculate_new_relative_path(
+            &anchor.join("_"),
+            Path::new(&resolved),
+            Path::new(&relocated),
+            "../",
+        )?
+        .replace('\\', "/"),
+    };
+    if !wildcard_suffix.is_empty() && !new_fixed.is_empty() && !new_fixed.ends_with('/') {
+        new_fixed.push('/');
+    }
+    if fixed.starts_with("./") && !new_fixed.starts_with('.') {
+        new_fixed.insert_str(0, "./");
+    }
+
+    let prefix = if mapped.starts_with(CONFIG_DIR_TOKEN) {
+        CONFIG_DIR_TOKEN
+    } else {
+        ""
+    };
+    Some(format!("{prefix}{new_fixed}{wildcard_suffix}"))
+}
+
+/// Locate the `compilerOptions.paths` object, returning the byte offsets of
+/// its braces. Only direct members are matched, so a `"paths"` inside a
+/// comment, a string value or another option is skipped.
+fn paths_object_span(text: &str) -> Option<(usize, usize)> {
+    let root = skip_json_trivia(text, 0);
+    let compiler_options = json_member_value(text, root, "compilerOptions")?;
+    let open = json_member_value(text, compiler_options, "paths")?;
+    let bytes = text.as_bytes();
+    let mut depth = 0usize;
+    let mut i = open;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => i = skip_json_string(bytes, i),
+            b'/' if matches!(bytes.get(i + 1), Some(b'/' | b'*')) => {
+                i = skip_json_comment(text, i);
+            }
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((open, i));
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Return the offset of the value of member `key` of the object whose `{` is
+/// at `open`.
+fn json_member_value(text: &str, open: usize, key: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    if bytes.get(open) != Some(&b'{') {
+        return None;
+    }
+    let mut depth = 0usize;
+    let mut i = open;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                let close = skip_json_string(bytes, i);
+                if depth == 1 && text.get(i + 1..close) == Some(key) {
+                    let colon = skip_json_trivia(text, close + 1);
+                    if bytes.get(colon) == Some(&b':') {
+                        return Some(skip_json_trivia(text, colon + 1));
+                    }
+                }
+                i = close;
+            }
+            b'/' if matches!(bytes.get(i + 1), Some(b'/' | b'*')) => {
+                i = skip_json_comment(text, i);
+            }
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Return the offset of the first byte at or after `start` that is neither
+/// whitespace nor part of a comment.
+fn skip_json_trivia(text: &str, start: usize) -> usize {
+    let bytes = text.as_bytes();
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            b' ' | b'\t' | b'\r' | b'\n' => {}
+            b'/' if matches!(bytes.get(i + 1), Some(b'/' | b'*')) => {
+                i = skip_json_comment(text, i);
+            }
+            _ => return i,
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Return the inner byte ranges of string literals in `text[start..end]` that
+/// appear in value position (i.e. are not object keys).
+fn json_string_values(text: &str, start: usize, end: usize) -> Vec<(usize, usize)> {
+    let bytes = text.as_bytes();
+    let mut values = Vec::new();
+    let mut i = start;
+    while i < end {
+        match bytes[i] {
+            b'"' => {
+                let close = skip_json_string(bytes, i);
+                let is_key = text[close + 1..end].trim_start().starts_with(':');
+                if !is_key {
+                    values.push((i + 1, close));
+                }
+                i = close;
+            }
+            b'/' if matches!(bytes.get(i + 1), Some(b'/' | b'*')) => {
+                i = skip_json_comment(text, i);
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    values
+}
+
+/// Given the offset of a comment's leading `/`, return the offset of its last
+/// byte.
+fn skip_json_comment(text: &str, open: usize) -> usize {
+    let rest = &text[open..];
+    let len = if rest.starts_with("//") {
+        rest.find('\n').unwrap_or(rest.len())
+    } else {
+        rest.find("*/").map_or(rest.len(), |idx| idx + 2)
+    };
+    open + len.max(1) - 1
+}
+
+/// Given the offset of an opening `"`, return the offset of its closing quote.
+fn skip_json_string(bytes: &[u8], open: usize) -> usize {
+    let mut i = open + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'"' => return i,
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len().saturating_sub(1)
+}
@@ -71,6 +71,7 @@ impl Project {
     /// `textDocument/didOpen`. Also promotes the file to "focused" so
     /// fuzzy ranking in workspace-symbol search prefers nearby files.
     pub fn mark_file_open(&mut self, file_name: &str) {
+        self.ensure_function_bodies(file_name);
         self.open_files.insert(file_name.to_string());
         self.focused_file = Some(file_name.to_string());
     }
@@ -93,6 +94,7 @@ impl Project {
     /// (`didChange`, `hover`, `definition`, `completion`, ...). The hint
     /// is used as a tie-breaker for fuzzy-ranked workspace-symbol search.
     pub fn set_focused_file(&mut self, file_name: &str) {
+        self.ensure_function_bodies(file_name);
         self.focused_file = Some(file_name.to_string());
     }
 
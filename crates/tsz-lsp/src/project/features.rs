@@ -8,18 +8,26 @@
 use rustc_hash::{FxHashMap, FxHashSet};
 use web_time::Instant;
 
-use super::{Project, ProjectRequestKind};
+use super::{DiagnosticsResult, ImportKind, Project, ProjectFile, ProjectRequestKind};
 use crate::code_actions::{CodeAction, CodeActionContext, CodeActionKind, CodeActionProvider};
-use crate::completions::{CompletionItem, CompletionItemData};
-use crate::diagnostics::{LspDiagnostic, WorkspaceDiagnosticReport};
+use crate::completions::{CompletionItem, CompletionItemData, Completions, HeritageDeclaration};
+use crate::diagnostics::{
+    DocumentDiagnosticReport, DocumentDiagnosticReportKind, FullDocumentDiagnosticReport,
+    LspDiagnostic, UnchangedDocumentDiagnosticReport, WorkspaceDiagnosticReport,
+    WorkspaceDiagnosticReportItem,
+};
 use crate::editor_decorations::code_lens::CodeLens;
 use crate::hover::HoverInfo;
 use crate::navigation::definition::GoToDefinition;
 use crate::resolver::ScopeCacheStats;
 use crate::signature_help::SignatureHelp;
-use crate::symbols::workspace_symbols::{SymbolInformation, WorkspaceSymbolsProvider};
+use crate::symbols::workspace_symbols::{
+    DEFAULT_MAX_RESULTS, SymbolInformation, WorkspaceSymbolsProvider,
+};
 use crate::utils::find_node_at_offset;
 use tsz_common::position::{Location, Position, Range};
+use tsz_parser::parser::node::NodeAccess;
+use tsz_parser::{NodeIndex, syntax_kind_ext};
 use tsz_scanner::SyntaxKind;
 
 impl Project {
@@ -88,6 +96,15 @@ impl Project {
         result
     }
 
+    /// Type and symbol baseline entries for a file, in source order.
+    pub fn get_type_writer_entries(
+        &mut self,
+        file_name: &str,
+    ) -> Option<Vec<crate::type_writer::TypeWriterEntry>> {
+        self.touch_file(file_name);
+        Some(self.files.get_mut(file_name)?.get_type_writer_entries())
+    }
+
     /// Signature help within a single file.
     pub fn get_signature_help(
         &mut self,
@@ -111,6 +128,77 @@ impl Project {
         result
     }
 
+    /// Override and constructor snippets for a class body position.
+    ///
+    /// Base classes and interfaces imported from other project files are
+    /// followed through the file's imports. The LSP server merges these into
+    /// [`Self::get_completions`]; the tsserver protocol builds its own class
+    /// member snippets.
+    pub fn get_class_member_override_snippets(
+        &self,
+        file_name: &str,
+        position: Position,
+    ) -> Vec<CompletionItem> {
+        let Some(file) = self.files.get(file_name) else {
+            return Vec::new();
+        };
+        Self::heritage_provider(file).get_class_member_override_snippets_with(
+            file.root(),
+            position,
+            &|from, expr_idx| self.imported_heritage_declarations(from, expr_idx),
+        )
+    }
+
+    fn heritage_provider(file: &ProjectFile) -> Completions<'_> {
+        Completions::for_heritage(
+            file.arena(),
+            file.binder(),
+            file.line_map(),
+            file.source_text(),
+            file.file_name().to_string(),
+        )
+    }
+
+    /// Class and interface declarations in other files that the imported
+    /// heritage name at `expr_idx` in `from`'s file refers to.
+    fn imported_heritage_declarations<'p>(
+        &'p self,
+        from: &Completions<'p>,
+        expr_idx: NodeIndex,
+    ) -> Vec<HeritageDeclaration<'p>> {
+        let mut declarations = Vec::new();
+        let Some(from_name) = from.file_name() else {
+            return declarations;
+        };
+        let Some(file) = self.files.get(from_name) else {
+            return declarations;
+        };
+        let Some(local_name) = file.arena().get_identifier_text(expr_idx) else {
+            return declarations;
+        };
+        for target in file.import_targets_for_local(local_name) {
+            let export_name = match target.kind {
+                ImportKind::Named(name) => name,
+                ImportKind::Default => "default".to_string(),
+                ImportKind::Namespace => continue,
+            };
+            let Some(target_file) = self
+                .resolve_module_specifier(from_name, &target.module_specifier)
+                .and_then(|resolved| self.files.get(&resolved))
+            else {
+                continue;
+            };
+            for decl in target_file.export_nodes(&export_name) {
+                if target_file.arena().get(decl).is_some_and(|node| {
+                    node.is_class_like() || node.kind == syntax_kind_ext::INTERFACE_DECLARATION
+                }) {
+                    declarations.push((Self::heritage_provider(target_file), decl));
+                }
+            }
+        }
+        declarations
+    }
+
     /// Completions within a single file.
     pub fn get_completions(
         &mut self,
@@ -127,6 +215,9 @@ impl Project {
             let file = self.files.get_mut(file_name)?;
             file.get_completions_with_stats(position, Some(&mut scope_stats))
         };
+        if self.is_cancelled() {
+            return None;
+        }
         let inner_emitted_result = inner_result.is_some();
         let mut completions = inner_result.unwrap_or_default();
 
@@ -228,9 +319,14 @@ impl Project {
 
             // Create CodeActionProvider for generating import edits
             use crate::code_actions::CodeActionProvider;
-            let code_action_provider = CodeActionProvider::from_context(file.provider_context());
+            let code_action_provider = CodeActionProvider::from_context(file.provider_context())
+                .with_quote_preference(self.quote_preference.clone())
+                .with_verbatim_module_syntax(self.verbatim_module_syntax);
 
             for candidate in candidates {
+                if self.is_cancelled() {
+                    return None;
+                }
                 if existing_file_symbols.contains(&candidate.local_name) {
                     continue;
                 }
@@ -356,9 +452,10 @@ impl Project {
         self.touch_file(file_name);
         let start = Instant::now();
         let scope_stats = ScopeCacheStats::default();
+        let cancellation = self.cancellation_token.clone();
         let result = {
             let file = self.files.get_mut(file_name)?;
-            Some(file.get_diagnostics())
+            file.get_diagnostics_with_cancellation(cancellation.as_ref())
         };
 
         self.performance.record(
@@ -370,6 +467,48 @@ impl Project {
         result
     }
 
+    /// Pull diagnostics for a single file (`textDocument/diagnostic`).
+    ///
+    /// Every report carries a result ID. If `previous_result_id` still names
+    /// the current diagnostics, an `Unchanged` report is returned instead of
+    /// the full list. The ID is kept when a re-check yields the same
+    /// diagnostics, so edits that do not affect them produce no churn.
+    ///
+    /// Returns `None` for unknown files and cancelled requests.
+    pub fn get_document_diagnostic_report(
+        &mut self,
+        file_name: &str,
+        previous_result_id: Option<&str>,
+    ) -> Option<DocumentDiagnosticReport> {
+        let file = self.files.get(file_name)?;
+        let content_hash = file.content_hash;
+        let cached = self
+            .diagnostics_results
+            .get(file_name)
+            .filter(|result| !file.diagnostics_dirty && result.content_hash == content_hash);
+        if let Some(result) = cached {
+            return Some(diagnostic_report(result, previous_result_id));
+        }
+
+        let diagnostics = self.get_diagnostics(file_name)?;
+        let result_id = match self.diagnostics_results.get(file_name) {
+            Some(previous) if previous.diagnostics == diagnostics => previous.result_id.clone(),
+            _ => {
+                self.next_diagnostics_result_id += 1;
+                self.next_diagnostics_result_id.to_string()
+            }
+        };
+        let result = DiagnosticsResult {
+            result_id,
+            content_hash,
+            diagnostics,
+        };
+        let report = diagnostic_report(&result, previous_result_id);
+        self.diagnostics_results
+            .insert(file_name.to_string(), result);
+        Some(report)
+    }
+
     /// Resolve import candidates for missing-name diagnostics in a file.
     pub fn get_import_candidates_for_diagnostics(
         &self,
@@ -428,17 +567,41 @@ impl Project {
     /// Returns a `WorkspaceDiagnosticReport` containing diagnostics for every
     /// file in the project. This implements the LSP `workspace/diagnostic`
     /// request which allows clients to pull diagnostics on demand.
-    pub fn get_workspace_diagnostics(&mut self) -> WorkspaceDiagnosticReport {
+    ///
+    /// `previous_result_ids` maps file names to the result IDs the client
+    /// already holds; files whose diagnostics are unchanged are reported as
+    /// such.
+    pub fn get_workspace_diagnostics(
+        &mut self,
+        previous_result_ids: &FxHashMap<String, String>,
+    ) -> WorkspaceDiagnosticReport {
         let file_names: Vec<String> = self.files.keys().cloned().collect();
         let mut items = Vec::with_capacity(file_names.len());
 
         for file_name in file_names {
-            if let Some(diagnostics) = self.get_diagnostics(&file_name) {
-                items.push((file_name, diagnostics));
-            }
+            let previous = previous_result_ids.get(&file_name).map(String::as_str);
+            let Some(report) = self.get_document_diagnostic_report(&file_name, previous) else {
+                continue;
+            };
+            items.push(match report {
+                DocumentDiagnosticReport::Full(full) => WorkspaceDiagnosticReportItem {
+                    uri: file_name,
+                    version: None,
+                    kind: DocumentDiagnosticReportKind::Full,
+                    result_id: full.result_id,
+                    items: Some(full.items),
+                },
+                DocumentDiagnosticReport::Unchanged(unchanged) => WorkspaceDiagnosticReportItem {
+                    uri: file_name,
+                    version: None,
+                    kind: DocumentDiagnosticReportKind::Unchanged,
+                    result_id: Some(unchanged.result_id),
+                    items: None,
+                },
+            });
         }
 
-        WorkspaceDiagnosticReport::from_file_diagnostics(items)
+        WorkspaceDiagnosticReport { items }
     }
 
     /// Get code lenses for a file (project-aware).
@@ -553,7 +716,10 @@ impl Project {
         let file = self.files.get(file_name)?;
         let import_candidates = self.import_candidates_for_diagnostics(file, &diagnostics);
 
-        let provider = CodeActionProvider::from_context(file.provider_context());
+        let provider = CodeActionProvider::from_context(file.provider_context())
+            .with_quote_preference(self.quote_preference.clone())
+            .with_verbatim_module_syntax(self.verbatim_module_syntax)
+            .with_react_jsx_factories(self.react_jsx_factories.clone());
 
         let actions = provider.provide_code_actions(
             file.root(),
@@ -621,10 +787,21 @@ impl Project {
     /// then alphabetically. At most 100 results are returned. An empty
     /// query returns no results.
     pub fn get_workspace_symbols(&self, query: &str) -> Vec<SymbolInformation> {
+        self.get_workspace_symbols_with_limit(query, DEFAULT_MAX_RESULTS)
+    }
+
+    /// Search for symbols across the project, returning at most
+    /// `max_results` entries (0 selects the default cap).
+    pub fn get_workspace_symbols_with_limit(
+        &self,
+        query: &str,
+        max_results: usize,
+    ) -> Vec<SymbolInformation> {
         let provider = WorkspaceSymbolsProvider::with_active_file(
             &self.symbol_index,
             self.focused_file.as_deref(),
-        );
+        )
+        .with_max_results(max_results);
         provider.find_symbols(query)
     }
 
@@ -644,9 +821,12 @@ impl Project {
 
     /// Get supertypes for a symbol (cross-file via `SymbolIndex`).
     ///
-    /// First searches the current file for supertypes. If a supertype name
-    /// is not found locally, searches across all project files using the
-    /// symbol index definitions.
+    /// First searches the current file for supertypes. A heritage name that
+    /// is not declared locally is resolved through the file's imports (so
+    /// `import { Base as B }` followed by `extends B` lands on `Base`), and
+    /// otherwise through the binder-built index of class/interface
+    /// declarations. Every declaration found is returned, so interfaces
+    /// merged across files show up once per file.
     pub fn supertypes(
         &self,
         file_name: &str,
@@ -683,6 +863,7 @@ impl Project {
 
         // Collect heritage type names via the provider
         let heritage_names = provider.collect_heritage_names(node_idx);
+        let imports = self.symbol_index.get_imports(file_name);
 
         // For any heritage name not resolved locally, search other files
         let mut results = local_results;
@@ -690,9 +871,24 @@ impl Project {
             if local_names.contains(&name) {
                 continue;
             }
-            // Find files that contain this symbol name via the symbol index
-            let candidate_files = self.symbol_index.get_files_with_symbol(&name);
-            for candidate_file in candidate_files {
+
+            // Prefer the module the name is imported from.
+            let imported = imports
+                .iter()
+                .find(|import| import.local_name == name)
+                .and_then(|import| {
+                    let target = self.resolve_module_specifier(file_name, &import.source_module)?;
+                    let target_file = self.files.get(&target)?;
+                    TypeHierarchyProvider::from_context(target_file.provider_context())
+                        .find_type_declaration_item_by_name(&import.exported_name)
+                });
+            if let Some(item) = imported {
+                push_unique_hierarchy_item(&mut results, item);
+                continue;
+            }
+
+            // Otherwise fall back to every file declaring a type of that name.
+            for candidate_file in self.symbol_index.get_files_declaring_type(&name) {
                 if candidate_file == file_name {
                     continue; // Already searched locally
                 }
@@ -700,8 +896,7 @@ impl Project {
                     let other_provider =
                         TypeHierarchyProvider::from_context(other_file.provider_context());
                     if let Some(item) = other_provider.find_type_declaration_item_by_name(&name) {
-                        results.push(item);
-                        break; // Found it, no need to check more files
+                        push_unique_hierarchy_item(&mut results, item);
                     }
                 }
             }
@@ -714,7 +909,9 @@ impl Project {
     ///
     /// First searches the current file, then uses the symbol index's
     /// heritage clause tracking to find files that extend/implement the
-    /// target type and searches those files too.
+    /// target type and searches those files too. Files that import the target
+    /// under a different local name are searched for heritage clauses naming
+    /// that alias.
     pub fn subtypes(
         &self,
         file_name: &str,
@@ -739,9 +936,33 @@ impl Project {
             None => return results,
         };
 
-        // Use the symbol index to find files that extend/implement this type
-        let heritage_files = self.symbol_index.get_files_with_heritage(&target_name);
-        for heritage_file in heritage_files {
+        // Use the symbol index to find files that extend/implement this type,
+        // together with the local name each file uses for it.
+        let mut searches: Vec<(String, String)> = self
+            .symbol_index
+            .get_files_with_heritage(&target_name)
+            .into_iter()
+            .map(|heritage_file| (heritage_file, target_name.clone()))
+            .collect();
+        for importer in self.files.keys() {
+            if importer == file_name {
+                continue;
+            }
+            for import in self.symbol_index.get_imports(importer) {
+                if import.exported_name != target_name
+                    || import.local_name == target_name
+                    || self
+                        .resolve_module_specifier(importer, &import.source_module)
+                        .as_deref()
+                        != Some(file_name)
+                {
+                    continue;
+                }
+                searches.push((importer.clone(), import.local_name));
+            }
+        }
+
+        for (heritage_file, local_name) in searches {
             if heritage_file == file_name {
                 continue; // Already searched locally
             }
@@ -750,7 +971,9 @@ impl Project {
                     TypeHierarchyProvider::from_context(other_file.provider_context());
                 // Find all class/interface declarations in this file that
                 // reference the target name in their heritage clauses
-                results.extend(other_provider.find_subtypes_of(&target_name));
+                for item in other_provider.find_subtypes_of(&local_name) {
+                    push_unique_hierarchy_item(&mut results, item);
+                }
             }
         }
 
@@ -856,6 +1079,7 @@ impl Project {
             source: file.source_text(),
             interner: &file.type_interner,
             file_name: file.file_name().to_string(),
+            options: self.inlay_hints_options,
         };
         Some(provider.provide_inlay_hints(file.root(), range))
     }
@@ -878,12 +1102,14 @@ impl Project {
     /// First finds callers in the current file, then uses the symbol index
     /// to discover callers in other files that reference the target function name.
     pub fn get_incoming_calls(
-        &self,
+        &mut self,
         file_name: &str,
         position: Position,
     ) -> Vec<crate::hierarchy::call_hierarchy::CallHierarchyIncomingCall> {
         use crate::hierarchy::call_hierarchy::CallHierarchyProvider;
 
+        self.parse_all_deferred_function_bodies();
+
         let file = match self.files.get(file_name) {
             Some(f) => f,
             None => return Vec::new(),
@@ -984,7 +1210,8 @@ impl Project {
     /// project that referenced the old path and produces text edits to update
     /// them. Both relative specifiers (e.g. `./foo`) and `paths`-aliased
     /// specifiers configured in the importer's nearest tsconfig (e.g.
-    /// `@app/foo`) are rewritten.
+    /// `@app/foo`) are rewritten. Renaming a directory also rewrites relative
+    /// imports from the moved files back out to the rest of the project.
     #[cfg(not(target_arch = "wasm32"))]
     pub fn get_file_rename_edits(
         &self,
@@ -997,6 +1224,10 @@ impl Project {
         let old_path_obj = std::path::Path::new(old_path);
         let new_path_obj = std::path::Path::new(new_path);
 
+        if self.is_directory(old_path_obj) {
+            return self.directory_rename_edits(old_path_obj, new_path_obj);
+        }
+
         for (file_name, file) in &self.files {
             let provider = crate::rename::file_rename::FileRenameProvider::new(
                 file.arena(),
@@ -1077,3 +1308,34 @@ impl Project {
         )
     }
 }
+
+/// Append `item` unless an item for the same declaration is already present.
+fn push_unique_hierarchy_item(
+    items: &mut Vec<crate::hierarchy::type_hierarchy::TypeHierarchyItem>,
+    item: crate::hierarchy::type_hierarchy::TypeHierarchyItem,
+) {
+    if !items.iter().any(|existing| {
+        existing.uri == item.uri && existing.selection_range == item.selection_range
+    }) {
+        items.push(item);
+    }
+}
+
+/// Build the report for `result`, collapsing to `Unchanged` when the client
+/// already holds it.
+fn diagnostic_report(
+    result: &DiagnosticsResult,
+    previous_result_id: Option<&str>,
+) -> DocumentDiagnosticReport {
+    if previous_result_id == Some(result.result_id.as_str()) {
+        return DocumentDiagnosticReport::Unchanged(UnchangedDocumentDiagnosticReport {
+            kind: DocumentDiagnosticReportKind::Unchanged,
+            result_id: result.result_id.clone(),
+        });
+    }
+    DocumentDiagnosticReport::Full(FullDocumentDiagnosticReport {
+        kind: DocumentDiagnosticReportKind::Full,
+        result_id: Some(result.result_id.clone()),
+        items: result.diagnostics.clone(),
+    })
+}
@@ -17,7 +17,32 @@ use tsz_parser::parser::node::NodeAccess;
 use tsz_parser::{NodeArena, NodeIndex, syntax_kind_ext};
 use tsz_scanner::SyntaxKind;
 
-use super::{ExportMatch, ImportKind, ImportTarget, Project, ProjectFile};
+use super::{
+    ExportMatch, ImportKind, ImportSpecifierPreference, ImportTarget, Project, ProjectFile,
+};
+
+/// Sort key ranking a module specifier by how far it reaches from the
+/// importing file: `(tier, ".." hops + path segments, ".." hops)`.
+///
+/// Relative specifiers and bare/package specifiers are ranked in separate
+/// tiers; `packages_first` puts the package tier ahead of the relative one.
+fn import_specifier_distance(module_specifier: &str, packages_first: bool) -> (u8, usize, usize) {
+    let is_relative = module_specifier == "."
+        || module_specifier == ".."
+        || module_specifier.starts_with("./")
+        || module_specifier.starts_with("../");
+    let tier = u8::from(is_relative == packages_first);
+    let mut hops = 0;
+    let mut segments = 0;
+    for segment in module_specifier.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => hops += 1,
+            _ => segments += 1,
+        }
+    }
+    (tier, hops + segments, hops)
+}
 
 #[derive(Default)]
 struct BareSpecifierSourceCache {
@@ -70,6 +95,8 @@ impl Project {
         for diag in diagnostics {
             let diag_code = diag.code.unwrap_or_default();
             if diag_code != tsz_checker::diagnostics::diagnostic_codes::CANNOT_FIND_NAME
+                && diag_code
+                    != tsz_checker::diagnostics::diagnostic_codes::CANNOT_FIND_NAME_DID_YOU_MEAN
                 && diag_code != tsz_checker::diagnostics::diagnostic_codes::CANNOT_FIND_NAMESPACE
             {
                 continue;
@@ -88,6 +115,14 @@ impl Project {
             );
         }
 
+        // Like tsserver, offer the closest module first. Package specifiers
+        // form their own tier (after relative ones, unless the user prefers
+        // non-relative imports); the sort is stable so ties keep discovery order.
+        let packages_first =
+            self.import_module_specifier_preference == Some(ImportSpecifierPreference::NonRelative);
+        candidates.sort_by_key(|candidate| {
+            import_specifier_distance(&candidate.module_specifier, packages_first)
+        });
         candidates
     }
 
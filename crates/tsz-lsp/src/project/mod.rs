@@ -4,6 +4,8 @@
 //! LSP features can be extended across multiple files.
 
 mod core;
+#[cfg(not(target_arch = "wasm32"))]
+mod directory_rename;
 pub(crate) mod eviction;
 pub(crate) mod features;
 pub(crate) mod file_context;
@@ -14,12 +16,12 @@ pub(crate) mod operations;
 #[cfg(test)]
 pub(crate) use self::core::FileIdAllocator;
 pub(crate) use self::core::{
-    ExportMatch, ImportKind, ImportSpecifierPreference, ImportSpecifierTarget, ImportTarget,
-    NamespaceReexportTarget,
+    DiagnosticsResult, ExportMatch, ImportKind, ImportSpecifierPreference, ImportSpecifierTarget,
+    ImportTarget, NamespaceReexportTarget,
 };
 pub use self::core::{
     FileRename, FileResidencyInfo, Project, ProjectFile, ProjectPerformance, ProjectRequestKind,
-    ProjectRequestTiming, ProjectResidencyStats, TsConfigSettings,
+    ProjectRequestTiming, ProjectResidencyStats, TextDocumentContentChange, TsConfigSettings,
 };
 pub use self::eviction::{EvictedFile, EvictionResult};
 pub use self::file_context::LspProviderContext;
@@ -1779,7 +1779,7 @@ fn dedup_in_place(v: &mut Vec<String>) {
     v.retain(|s| seen.insert(s.clone()));
 }
 
-fn normalize_path(path: &Path) -> PathBuf {
+pub(super) fn normalize_path(path: &Path) -> PathBuf {
     let mut normalized = PathBuf::new();
 
     for component in path.components() {
@@ -2063,7 +2063,7 @@ fn path_to_string(path: &Path) -> String {
     path.to_string_lossy().to_string()
 }
 
-fn parse_typescript_config_json(text: &str) -> Option<serde_json::Value> {
+pub(super) fn parse_typescript_config_json(text: &str) -> Option<serde_json::Value> {
     serde_json::from_str(text)
         .ok()
         .or_else(|| json5::from_str::<serde_json::Value>(text).ok())
@@ -2459,7 +2459,7 @@ fn prefix_capture_case_insensitive(prefix_pattern: &str, target: &str) -> Option
         })
 }
 
-fn base_dir_for_compiler_options(
+pub(super) fn base_dir_for_compiler_options(
     config_dir: &Path,
     compiler_options: &serde_json::Map<String, serde_json::Value>,
 ) -> PathBuf {
@@ -872,6 +872,7 @@ impl Project {
         file_name: &str,
         position: Position,
     ) -> Option<Vec<Location>> {
+        self.parse_all_deferred_function_bodies();
         self.touch_file(file_name);
         let start = Instant::now();
         let mut scope_stats = ScopeCacheStats::default();
@@ -987,6 +988,9 @@ impl Project {
             let mut namespace_targets = Vec::new();
 
             while let Some((def_file, export_name)) = pending.pop() {
+                if self.is_cancelled() {
+                    return None;
+                }
                 if !seen_targets.insert((def_file.clone(), export_name.clone())) {
                     continue;
                 }
@@ -1001,6 +1005,9 @@ impl Project {
             }
 
             for (def_file, export_name) in expanded_targets {
+                if self.is_cancelled() {
+                    return None;
+                }
                 let export_nodes = {
                     let target_file = self.files.get(&def_file);
                     target_file
@@ -1146,6 +1153,11 @@ impl Project {
     /// This performs a transitive search: if `class B extends A` and `class C extends B`,
     /// searching for implementations of `A` will return both `B` and `C`.
     ///
+    /// When the cursor is on an interface method/property or an abstract class
+    /// member, the same heritage search runs for the containing type and the
+    /// matching members of the implementing classes are returned, in whichever
+    /// project file they are declared.
+    ///
     /// # Arguments
     /// * `file_name` - The file containing the cursor position
     /// * `position` - The cursor position where the user invoked "Go to Implementation"
@@ -1160,11 +1172,14 @@ impl Project {
         file_name: &str,
         position: Position,
     ) -> Option<Vec<Location>> {
+        self.parse_all_deferred_function_bodies();
         let start = Instant::now();
 
         let result: Option<Vec<Location>> = (|| {
-            // Step 1: Resolve the initial target at the cursor position
-            let (initial_name, initial_kind): (String, TargetKind) = {
+            // Step 1: Resolve the initial target at the cursor position. A
+            // cursor on an interface/class member searches the member's
+            // container and then maps each implementing class to its member.
+            let (initial_name, initial_kind, member_name): (String, TargetKind, Option<String>) = {
                 let file = self.files.get(file_name)?;
                 let offset = file
                     .line_map
@@ -1178,12 +1193,20 @@ impl Project {
                     return None;
                 }
 
-                // First, try to resolve the symbol at the node
-                let symbol_id = provider.resolve_symbol_at_node(node_idx)?;
-                let symbol = file.binder.symbols.get(symbol_id)?;
-                let target_kind = provider.determine_target_kind(symbol)?;
+                if let Some(member) = provider.resolve_member_target_at_node(node_idx) {
+                    (
+                        member.container_name,
+                        member.container_kind,
+                        Some(member.member_name),
+                    )
+                } else {
+                    // First, try to resolve the symbol at the node
+                    let symbol_id = provider.resolve_symbol_at_node(node_idx)?;
+                    let symbol = file.binder.symbols.get(symbol_id)?;
+                    let target_kind = provider.determine_target_kind(symbol)?;
 
-                (symbol.escaped_name.clone(), target_kind)
+                    (symbol.escaped_name.clone(), target_kind, None)
+                }
             };
 
             // Step 2: Iterative worklist for transitive search
@@ -1216,21 +1239,33 @@ impl Project {
                     let found = provider.find_implementations_for_name(&curr_name, curr_kind);
 
                     for impl_result in found {
+                        // In member mode report the implementing member; a class
+                        // that inherits the member without redeclaring it (or an
+                        // extending interface) contributes no location but is
+                        // still searched transitively below.
+                        let location = match &member_name {
+                            Some(member) => {
+                                provider.find_member_location(impl_result.declaration, member)
+                            }
+                            None => Some(impl_result.location.clone()),
+                        };
+
                         // Add the implementation location to results (avoid duplicates)
-                        if !results.iter().any(|loc| {
-                            loc.file_path == impl_result.location.file_path
-                                && loc.range.start.line == impl_result.location.range.start.line
-                        }) {
-                            results.push(impl_result.location.clone());
+                        if let Some(location) = location
+                            && !results.iter().any(|loc| {
+                                loc.file_path == location.file_path
+                                    && loc.range.start.line == location.range.start.line
+                            })
+                        {
+                            results.push(location);
                         }
 
-                        // Add to queue for transitive search (find classes that extend this implementation)
-                        // Only ConcreteClass and AbstractClass can be extended (not interfaces)
-                        let next_kind = TargetKind::ConcreteClass;
+                        // Add to queue for transitive search: classes are searched
+                        // for subclasses, interfaces for their own implementors.
                         queue.push_back((
                             candidate_path.clone(),
                             impl_result.name.clone(),
-                            next_kind,
+                            impl_result.kind,
                         ));
                     }
                 }
@@ -1259,6 +1294,7 @@ impl Project {
         position: Position,
         new_name: String,
     ) -> Result<WorkspaceEdit, String> {
+        self.parse_all_deferred_function_bodies();
         self.touch_file(file_name);
         let start = Instant::now();
         let mut scope_stats = ScopeCacheStats::default();
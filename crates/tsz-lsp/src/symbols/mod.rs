@@ -24,7 +24,7 @@ pub mod workspace_symbols;
 
 pub use document_symbols::{DocumentSymbol, DocumentSymbolProvider, SymbolKind};
 pub use symbol_index::SymbolIndex;
-pub use workspace_symbols::{SymbolInformation, WorkspaceSymbolsProvider};
+pub use workspace_symbols::{MatchKind, SymbolInformation, WorkspaceSymbolsProvider, score_match};
 
 /// Main API for extracting document symbols from AST.
 ///
@@ -9,7 +9,7 @@
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::document_symbols::SymbolKind;
-use tsz_binder::{BinderState, symbol_flags};
+use tsz_binder::{BinderState, SemanticDefKind, symbol_flags};
 use tsz_common::position::{LineMap, Location, Range};
 use tsz_parser::NodeArena;
 use tsz_parser::parser::node::NodeAccess;
@@ -74,6 +74,14 @@ pub struct SymbolIndex {
     /// inference in workspace symbol search instead of naming heuristics.
     definition_kinds: FxHashMap<String, SymbolKind>,
 
+    /// File path -> symbol name -> kind declared in that file.
+    ///
+    /// `definition_kinds` holds one kind per name, so two files declaring the
+    /// same name (e.g. a `class Foo` and a `function Foo`) would otherwise
+    /// report the last-indexed kind for both, and keep it after that file is
+    /// edited or removed.
+    file_definition_kinds: FxHashMap<String, FxHashMap<String, SymbolKind>>,
+
     /// Module path -> exported symbol names
     /// Tracks what symbols each module exports
     exports: FxHashMap<String, FxHashSet<String>>,
@@ -114,6 +122,13 @@ pub struct SymbolIndex {
     /// `remove_file`/`update_file` can rebuild the aggregate without leaving
     /// stale edges or deleting another file's contribution.
     file_sub_to_bases: FxHashMap<String, FxHashMap<String, FxHashSet<String>>>,
+
+    /// Class/interface name -> files that declare it.
+    ///
+    /// Populated from the binder's `semantic_defs` so type hierarchy can jump
+    /// to a base type's declaration without scanning every file that merely
+    /// mentions the name.
+    type_declarations: FxHashMap<String, FxHashSet<String>>,
 }
 
 impl SymbolIndex {
@@ -196,6 +211,17 @@ impl SymbolIndex {
             .unwrap_or_default()
     }
 
+    /// Get all files that declare a class or interface with the given name.
+    ///
+    /// Interfaces may merge across files, so more than one file can be
+    /// returned for a single name.
+    pub fn get_files_declaring_type(&self, type_name: &str) -> Vec<String> {
+        self.type_declarations
+            .get(type_name)
+            .map(Self::clone_set_to_vec)
+            .unwrap_or_default()
+    }
+
     /// Get all base classes/interfaces that a given class extends or implements.
     ///
     /// This enables upward traversal for heritage-aware rename.
@@ -229,8 +255,9 @@ impl SymbolIndex {
     /// `file_symbols` reverse mapping for O(1) complexity per symbol.
     pub fn remove_file(&mut self, file_name: &str) {
         // Use the reverse mapping for efficient cleanup
-        if let Some(symbols) = self.file_symbols.remove(file_name) {
-            for sym in &symbols {
+        let removed_symbols = self.file_symbols.remove(file_name);
+        if let Some(symbols) = &removed_symbols {
+            for sym in symbols {
                 // Remove from name_to_files
                 if let Some(files) = self.name_to_files.get_mut(sym) {
                     files.remove(file_name);
@@ -255,27 +282,35 @@ impl SymbolIndex {
             self.rebuild_sub_to_bases();
         }
 
-        // Remove definitions that were in this file
-        for defs in self.definitions.values_mut() {
+        // Remove definitions that were in this file. Every definition is
+        // tracked in `file_symbols`, so only this file's names need visiting.
+        let file_kinds = self.file_definition_kinds.remove(file_name);
+        for name in removed_symbols.iter().flatten() {
+            let Some(defs) = self.definitions.get_mut(name) else {
+                continue;
+            };
             defs.retain(|loc| loc.file_path != file_name);
-        }
-        self.definitions.retain(|_, defs| !defs.is_empty());
-
-        // Remove definition kinds for symbols that no longer have any definitions
-        let names_to_remove: Vec<String> = self
-            .definition_kinds
-            .keys()
-            .filter(|name| {
-                self.definitions
-                    .get(name.as_str())
-                    .is_none_or(std::vec::Vec::is_empty)
-            })
-            .cloned()
-            .collect();
-
-        for name in names_to_remove {
-            self.definition_kinds.remove(&name);
-            self.remove_sorted_name(&name);
+            if defs.is_empty() {
+                self.definitions.remove(name);
+                self.definition_kinds.remove(name);
+                if !self.name_to_files.contains_key(name) {
+                    self.remove_sorted_name(name);
+                }
+            } else if file_kinds
+                .as_ref()
+                .is_some_and(|kinds| kinds.contains_key(name))
+            {
+                // Another file still declares this name; fall back to its kind.
+                let remaining_kind = defs.iter().find_map(|loc| {
+                    self.file_definition_kinds
+                        .get(&loc.file_path)
+                        .and_then(|kinds| kinds.get(name))
+                        .copied()
+                });
+                if let Some(kind) = remaining_kind {
+                    self.definition_kinds.insert(name.clone(), kind);
+                }
+            }
         }
 
         // Remove exports for this file
@@ -311,6 +346,11 @@ impl SymbolIndex {
         }
         self.heritage_clauses.retain(|_, files| !files.is_empty());
 
+        for files in self.type_declarations.values_mut() {
+            files.remove(file_name);
+        }
+        self.type_declarations.retain(|_, files| !files.is_empty());
+
         self.sub_to_bases.retain(|_, bases| !bases.is_empty());
     }
 
@@ -381,6 +421,10 @@ impl SymbolIndex {
             if let Some(symbol) = binder.symbols.get(*symbol_id) {
                 let kind = symbol_flags_to_kind(symbol.flags);
                 self.definition_kinds.insert(name.clone(), kind);
+                self.file_definition_kinds
+                    .entry(file_name_owned.clone())
+                    .or_default()
+                    .insert(name.clone(), kind);
 
                 // Add definition location from the first declaration
                 let decl_node_idx = if !symbol.declarations.is_empty() {
@@ -521,6 +565,19 @@ impl SymbolIndex {
             }
         }
 
+        // Index class/interface declarations captured by the binder.
+        for entry in binder.semantic_defs.values() {
+            if matches!(
+                entry.kind,
+                SemanticDefKind::Class | SemanticDefKind::Interface
+            ) {
+                self.type_declarations
+                    .entry(entry.name.clone())
+                    .or_default()
+                    .insert(file_name_owned.clone());
+            }
+        }
+
         // Scan for HeritageClause nodes (extends/implements)
         // This enables O(1) lookup for Go to Implementation and upward traversal for rename
         for i in 0..arena.nodes.len() {
@@ -696,11 +753,22 @@ impl SymbolIndex {
         location: Location,
         kind: SymbolKind,
     ) {
+        let file_path = location.file_path.clone();
         self.definitions
             .entry(symbol_name.to_string())
             .or_default()
             .push(location);
         self.definition_kinds.insert(symbol_name.to_string(), kind);
+        self.file_definition_kinds
+            .entry(file_path.clone())
+            .or_default()
+            .insert(symbol_name.to_string(), kind);
+
+        // Track which file contains this definition for cleanup
+        self.file_symbols
+            .entry(file_path)
+            .or_default()
+            .insert(symbol_name.to_string());
     }
 
     /// Look up the stored symbol kind for a definition name.
@@ -711,6 +779,21 @@ impl SymbolIndex {
         self.definition_kinds.get(symbol_name).copied()
     }
 
+    /// Look up the kind of `symbol_name` as declared in `file_name`.
+    ///
+    /// Falls back to the project-wide kind when the file recorded none.
+    pub fn get_definition_kind_in_file(
+        &self,
+        symbol_name: &str,
+        file_name: &str,
+    ) -> Option<SymbolKind> {
+        self.file_definition_kinds
+            .get(file_name)
+            .and_then(|kinds| kinds.get(symbol_name))
+            .copied()
+            .or_else(|| self.get_definition_kind(symbol_name))
+    }
+
     /// Add an import relationship to the index.
     pub fn add_import(&mut self, file_name: &str, import_info: ImportInfo) {
         // Track the import
@@ -782,6 +865,7 @@ impl SymbolIndex {
         self.symbol_refs.clear();
         self.definitions.clear();
         self.definition_kinds.clear();
+        self.file_definition_kinds.clear();
         self.exports.clear();
         self.imports.clear();
         self.importers.clear();
@@ -794,6 +878,7 @@ impl SymbolIndex {
         self.heritage_clauses.clear();
         self.sub_to_bases.clear();
         self.file_sub_to_bases.clear();
+        self.type_declarations.clear();
     }
 
     fn rebuild_sub_to_bases(&mut self) {
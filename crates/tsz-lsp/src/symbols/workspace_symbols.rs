@@ -16,8 +16,8 @@ use super::document_symbols::SymbolKind;
 use super::symbol_index::SymbolIndex;
 use tsz_common::position::Location;
 
-/// Maximum number of results returned by a workspace symbol search.
-const MAX_RESULTS: usize = 100;
+/// Default maximum number of results returned by a workspace symbol search.
+pub const DEFAULT_MAX_RESULTS: usize = 100;
 
 /// Information about a symbol found in the workspace.
 ///
@@ -47,7 +47,7 @@ impl SymbolInformation {
 ///
 /// Ordered so that `Ord` ranks better matches lower (better = first).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) enum MatchKind {
+pub enum MatchKind {
     /// Whole symbol equals query, character for character.
     ExactCaseSensitive = 0,
     /// Whole symbol equals query, case-insensitive.
@@ -71,6 +71,17 @@ pub(crate) enum MatchKind {
     SubstringCaseInsensitive = 8,
 }
 
+impl MatchKind {
+    /// Whether the query matched with its case preserved. Camel-case hump
+    /// matches compare case-insensitively and are never case-sensitive.
+    pub const fn is_case_sensitive(self) -> bool {
+        matches!(
+            self,
+            Self::ExactCaseSensitive | Self::PrefixCaseSensitive | Self::SubstringCaseSensitive
+        )
+    }
+}
+
 /// Result of scoring a single symbol against a query.
 ///
 /// The lexicographic order of this tuple is the relevance order used for
@@ -96,6 +107,7 @@ pub(crate) struct MatchScore {
 pub struct WorkspaceSymbolsProvider<'a> {
     index: &'a SymbolIndex,
     active_file: Option<&'a str>,
+    max_results: usize,
 }
 
 impl<'a> WorkspaceSymbolsProvider<'a> {
@@ -104,6 +116,7 @@ impl<'a> WorkspaceSymbolsProvider<'a> {
         Self {
             index,
             active_file: None,
+            max_results: DEFAULT_MAX_RESULTS,
         }
     }
 
@@ -112,13 +125,30 @@ impl<'a> WorkspaceSymbolsProvider<'a> {
     /// Symbols whose definition lives in the active file (or near it in the
     /// directory tree) rank higher when match tiers tie.
     pub const fn with_active_file(index: &'a SymbolIndex, active_file: Option<&'a str>) -> Self {
-        Self { index, active_file }
+        Self {
+            index,
+            active_file,
+            max_results: DEFAULT_MAX_RESULTS,
+        }
+    }
+
+    /// Cap the number of results returned by [`Self::find_symbols`].
+    ///
+    /// A limit of 0 is treated as "use the default".
+    pub const fn with_max_results(mut self, max_results: usize) -> Self {
+        self.max_results = if max_results == 0 {
+            DEFAULT_MAX_RESULTS
+        } else {
+            max_results
+        };
+        self
     }
 
     /// Find symbols matching the given query string.
     ///
-    /// Performs fuzzy matching across the symbol index. At most 100 results
-    /// are returned. An empty query yields no results.
+    /// Performs fuzzy matching across the symbol index. At most
+    /// `max_results` (default 100) results are returned. An empty query
+    /// yields no results.
     pub fn find_symbols(&self, query: &str) -> Vec<SymbolInformation> {
         if query.is_empty() {
             return Vec::new();
@@ -131,12 +161,11 @@ impl<'a> WorkspaceSymbolsProvider<'a> {
                 continue;
             };
 
-            let lookup_kind = self
-                .index
-                .get_definition_kind(name)
-                .unwrap_or_else(|| Self::infer_symbol_kind(name));
-
             for location in self.index.find_definitions(name) {
+                let lookup_kind = self
+                    .index
+                    .get_definition_kind_in_file(name, &location.file_path)
+                    .unwrap_or_else(|| Self::infer_symbol_kind(name));
                 let distance = self.active_file.map_or(u32::MAX, |active| {
                     path_distance(active, &location.file_path)
                 });
@@ -162,7 +191,7 @@ impl<'a> WorkspaceSymbolsProvider<'a> {
 
         matches
             .into_iter()
-            .take(MAX_RESULTS)
+            .take(self.max_results)
             .map(|(_, info)| info)
             .collect()
     }
@@ -210,7 +239,7 @@ impl<'a> WorkspaceSymbolsProvider<'a> {
 /// Case-insensitive comparison uses Unicode lowercasing (`str::to_lowercase`)
 /// so identifiers with non-ASCII letters (`Ångström`, `名前`, etc.) match
 /// the same way tsserver's JS-side `.toLowerCase()` does.
-pub(crate) fn score_match(name: &str, query: &str) -> Option<MatchKind> {
+pub fn score_match(name: &str, query: &str) -> Option<MatchKind> {
     if query.is_empty() || name.is_empty() {
         return None;
     }
@@ -0,0 +1,286 @@
+//! Type and symbol baseline walker.
+//!
+//! Visits a file the way tsc's `TypeWriterWalker` does for the `.types` and
+//! `.symbols` conformance baselines: every expression, identifier, and
+//! declaration name in source order, with its printed type and the symbol it
+//! resolves to.
+//!
+//! Known differences from tsc, which baseline diffs should expect:
+//! - a member name in `x.name` is resolved only through the binder exports or
+//!   members of `x` (namespaces, enums, classes), not through the type of `x`;
+//! - `Decl(file, line, column)` starts at the declaration moved back over
+//!   whitespace, where tsc uses the full start (which also spans comments);
+//! - declarations outside the walked file print as `Decl(--, --, --)`.
+
+use crate::resolver::{ScopeCache, ScopeWalker};
+use tsz_binder::{SymbolId, symbol_flags};
+use tsz_checker::state::CheckerState;
+use tsz_parser::NodeIndex;
+use tsz_parser::parser::node::NodeAccess;
+use tsz_parser::syntax_kind_ext;
+use tsz_scanner::SyntaxKind;
+
+/// One node reported by the walker.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeWriterEntry {
+    /// Source text of the node.
+    pub source_text: String,
+    /// Zero-based line the node starts on.
+    pub line: u32,
+    /// Printed type, for the `.types` baseline. `None` for nodes that are
+    /// part of a type or only name a type.
+    pub type_text: Option<String>,
+    /// `Symbol(name, Decl(file, line, column), ...)`, for the `.symbols`
+    /// baseline. `None` when the node does not resolve to a symbol.
+    pub symbol_text: Option<String>,
+}
+
+define_lsp_provider!(full TypeWriterProvider, "Type and symbol baseline walker.");
+
+impl<'a> TypeWriterProvider<'a> {
+    /// Walk the file rooted at `root` and collect its entries in source order.
+    pub fn collect_entries(
+        &self,
+        root: NodeIndex,
+        type_cache: &mut Option<tsz_checker::TypeCache>,
+        scope_cache: &mut ScopeCache,
+    ) -> Vec<TypeWriterEntry> {
+        let compiler_options = self.checker_options();
+        let mut checker = if let Some(cache) = type_cache.take() {
+            CheckerState::with_cache(
+                self.arena,
+                self.binder,
+                self.interner,
+                self.file_name.clone(),
+                cache,
+                compiler_options,
+            )
+        } else {
+            CheckerState::new(
+                self.arena,
+                self.binder,
+                self.interner,
+                self.file_name.clone(),
+                compiler_options,
+            )
+        };
+        self.apply_lib_contexts(&mut checker);
+        let mut walker = ScopeWalker::new(self.arena, self.binder);
+
+        let mut entries = Vec::new();
+        // Pre-order walk; the flag marks nodes nested in a type node.
+        let mut stack = vec![(root, false)];
+        while let Some((idx, in_type)) = stack.pop() {
+            let Some(node) = self.arena.get(idx) else {
+                continue;
+            };
+            let in_type = in_type || node.is_type_node();
+
+            if is_walked_kind(node.kind) {
+                let type_text = (!in_type && self.has_value_meaning(idx))
+                    .then(|| self.type_text(&mut checker, idx));
+                let symbol_text = self
+                    .resolve_symbol(&mut walker, root, idx, scope_cache)
+                    .and_then(|sym_id| self.symbol_text(sym_id));
+                if type_text.is_some() || symbol_text.is_some() {
+                    let start = node.pos.min(self.source_text.len() as u32);
+                    let end = node.end.clamp(start, self.source_text.len() as u32);
+                    entries.push(TypeWriterEntry {
+                        source_text: self
+                            .source_text
+                            .get(start as usize..end as usize)
+                            .unwrap_or_default()
+                            .trim()
+                            .to_string(),
+                        line: self
+                            .line_map
+                            .offset_to_position(start, self.source_text)
+                            .line,
+                        type_text,
+                        symbol_text,
+                    });
+                }
+            }
+
+            let mut children = Vec::new();
+            self.arena.for_each_child(idx, |child| children.push(child));
+            stack.extend(children.into_iter().rev().map(|child| (child, in_type)));
+        }
+
+        *type_cache = Some(checker.extract_cache());
+        entries
+    }
+
+    /// tsc skips identifiers whose declaration only has a type meaning
+    /// (interface and type parameter names); type alias names are kept.
+    fn has_value_meaning(&self, idx: NodeIndex) -> bool {
+        let Some(node) = self.arena.get(idx) else {
+            return false;
+        };
+        if node.kind != SyntaxKind::Identifier as u16 {
+            return true;
+        }
+        let parent_kind = self
+            .arena
+            .parent_of(idx)
+            .and_then(|parent| self.arena.get(parent))
+            .map(|parent| parent.kind);
+        !matches!(
+            parent_kind,
+            Some(syntax_kind_ext::INTERFACE_DECLARATION | syntax_kind_ext::TYPE_PARAMETER)
+        )
+    }
+
+    fn type_text(&self, checker: &mut CheckerState<'_>, idx: NodeIndex) -> String {
+        // Declaration names have no expression type of their own; print the
+        // type of the declared symbol instead.
+        let declared = self.binder.node_symbols.get(&idx.0).copied().or_else(|| {
+            let parent = self.arena.parent_of(idx)?;
+            let sym_id = *self.binder.node_symbols.get(&parent.0)?;
+            let name = self.arena.get_identifier_text(idx)?;
+            (self.binder.symbols.get(sym_id)?.escaped_name == name).then_some(sym_id)
+        });
+        let type_id = match declared {
+            Some(sym_id) => checker.get_type_of_symbol(sym_id),
+            None => checker.get_type_of_node(idx),
+        };
+        checker.format_type(type_id)
+    }
+
+    fn resolve_symbol(
+        &self,
+        walker: &mut ScopeWalker<'_>,
+        root: NodeIndex,
+        idx: NodeIndex,
+        scope_cache: &mut ScopeCache,
+    ) -> Option<SymbolId> {
+        let node = self.arena.get(idx)?;
+        if node.kind != SyntaxKind::Identifier as u16
+            && node.kind != SyntaxKind::PrivateIdentifier as u16
+        {
+            return None;
+        }
+
+        if let Some(parent_idx) = self.arena.parent_of(idx)
+            && let Some(parent) = self.arena.get(parent_idx)
+            && parent.kind == syntax_kind_ext::PROPERTY_ACCESS_EXPRESSION
+            && let Some(access) = self.arena.get_access_expr(parent)
+            && access.name_or_argument == idx
+        {
+            let name = self.arena.get_identifier_text(idx)?;
+            let container_id =
+                walker.resolve_node_cached(root, access.expression, scope_cache, None)?;
+            let container = self.binder.symbols.get(container_id)?;
+            return container
+                .exports
+                .as_ref()
+                .and_then(|exports| exports.get(name))
+                .or_else(|| {
+                    container
+                        .members
+                        .as_ref()
+                        .and_then(|members| members.get(name))
+                });
+        }
+
+        walker.resolve_node_cached(root, idx, scope_cache, None)
+    }
+
+    fn symbol_text(&self, sym_id: SymbolId) -> Option<String> {
+        let symbol = self.binder.symbols.get(sym_id)?;
+        let mut text = format!("Symbol({}", self.qualified_name(sym_id));
+        let is_local = !self.binder.lib_symbol_ids.contains(&sym_id)
+            && (symbol.decl_file_idx == u32::MAX || symbol.decl_file_idx == self.binder.file_idx);
+        for &decl in &symbol.declarations {
+            text.push_str(", ");
+            match self.arena.get(decl).filter(|_| is_local) {
+                Some(decl_node) => {
+                    let position = self
+                        .line_map
+                        .offset_to_position(self.full_start(decl_node.pos), self.source_text);
+                    text.push_str(&format!(
+                        "Decl({}, {}, {})",
+                        base_file_name(&self.file_name),
+                        position.line,
+                        position.character
+                    ));
+                }
+                None => text.push_str("Decl(--, --, --)"),
+            }
+        }
+        text.push(')');
+        Some(text)
+    }
+
+    /// `Outer.Inner.name`, qualified through class, enum, and namespace parents.
+    fn qualified_name(&self, sym_id: SymbolId) -> String {
+        const CONTAINER: u32 = symbol_flags::CLASS
+            | symbol_flags::INTERFACE
+            | symbol_flags::ENUM
+            | symbol_flags::MODULE;
+        let mut parts = Vec::new();
+        let mut current = self.binder.symbols.get(sym_id);
+        while let Some(symbol) = current {
+            parts.push(symbol.escaped_name.as_str());
+            current = self
+                .binder
+                .symbols
+                .get(symbol.parent)
+                .filter(|parent| parent.flags & CONTAINER != 0 && parts.len() < 32);
+        }
+        parts.reverse();
+        parts.join(".")
+    }
+
+    /// Move `pos` back over the whitespace that precedes it.
+    fn full_start(&self, pos: u32) -> u32 {
+        let bytes = self.source_text.as_bytes();
+        let mut start = (pos as usize).min(bytes.len());
+        while start > 0 && bytes[start - 1].is_ascii_whitespace() {
+            start -= 1;
+        }
+        start as u32
+    }
+}
+
+/// Node kinds tsc's `TypeWriterWalker` reports: expressions, identifiers,
+/// and declaration names.
+fn is_walked_kind(kind: u16) -> bool {
+    const TOKENS: [SyntaxKind; 12] = [
+        SyntaxKind::Identifier,
+        SyntaxKind::PrivateIdentifier,
+        SyntaxKind::NumericLiteral,
+        SyntaxKind::BigIntLiteral,
+        SyntaxKind::StringLiteral,
+        SyntaxKind::RegularExpressionLiteral,
+        SyntaxKind::NoSubstitutionTemplateLiteral,
+        SyntaxKind::ThisKeyword,
+        SyntaxKind::SuperKeyword,
+        SyntaxKind::TrueKeyword,
+        SyntaxKind::FalseKeyword,
+        SyntaxKind::NullKeyword,
+    ];
+    if TOKENS.iter().any(|token| *token as u16 == kind) {
+        return true;
+    }
+    matches!(
+        kind,
+        syntax_kind_ext::ARRAY_LITERAL_EXPRESSION
+            ..=syntax_kind_ext::CLASS_EXPRESSION
+                | syntax_kind_ext::AS_EXPRESSION
+                | syntax_kind_ext::NON_NULL_EXPRESSION
+                | syntax_kind_ext::META_PROPERTY
+                | syntax_kind_ext::SATISFIES_EXPRESSION
+                | syntax_kind_ext::JSX_ELEMENT
+                | syntax_kind_ext::JSX_SELF_CLOSING_ELEMENT
+                | syntax_kind_ext::JSX_FRAGMENT
+    )
+}
+
+fn base_file_name(file_name: &str) -> &str {
+    file_name.rsplit(['/', '\\']).next().unwrap_or(file_name)
+}
+
+#[cfg(test)]
+#[path = "../tests/type_writer_tests.rs"]
+mod type_writer_tests;
@@ -1711,7 +1711,7 @@ fn test_quickfix_add_missing_import_namespace() {
 }
 
 #[test]
-fn test_quickfix_add_missing_import_type_position_uses_import_type() {
+fn test_quickfix_add_missing_import_type_position_uses_import_type_under_verbatim_module_syntax() {
     let source = "let x: Foo;\n";
     let (parser, root) = parse_test_source(source);
     let arena = parser.get_arena();
@@ -1734,7 +1734,8 @@ fn test_quickfix_add_missing_import_type_position_uses_import_type() {
     };
 
     let provider =
-        CodeActionProvider::new(arena, &binder, &line_map, "test.ts".to_string(), source);
+        CodeActionProvider::new(arena, &binder, &line_map, "test.ts".to_string(), source)
+            .with_verbatim_module_syntax(true);
 
     let empty_range = Range::new(Position::new(0, 0), Position::new(0, 0));
     let actions = provider.provide_code_actions(
@@ -1761,3 +1762,57 @@ fn test_quickfix_add_missing_import_type_position_uses_import_type() {
     );
 }
 
+
+#[test]
+fn test_quickfix_add_missing_import_type_position_keeps_value_import_for_values() {
+    let source = "let x: Foo;\nlet y: Bar;\n";
+    let (parser, root) = parse_test_source(source);
+    let arena = parser.get_arena();
+
+    let mut binder = BinderState::new();
+    binder.bind_source_file(arena, root);
+
+    let line_map = LineMap::build(source);
+    let provider =
+        CodeActionProvider::new(arena, &binder, &line_map, "test.ts".to_string(), source);
+    let mut type_only_candidate =
+        ImportCandidate::named("./bar".to_string(), "Bar".to_string(), "Bar".to_string());
+    type_only_candidate.is_type_only = true;
+
+    for (name, candidate, expected) in [
+        (
+            "Foo",
+            ImportCandidate::named("./foo".to_string(), "Foo".to_string(), "Foo".to_string()),
+            "import { Foo } from \"./foo\";\n\nlet x: Foo;\nlet y: Bar;\n",
+        ),
+        (
+            "Bar",
+            type_only_candidate,
+            "import type { Bar } from \"./bar\";\n\nlet x: Foo;\nlet y: Bar;\n",
+        ),
+    ] {
+        let diag = LspDiagnostic {
+            range: range_for_substring(source, &line_map, name),
+            severity: Some(DiagnosticSeverity::Error),
+            code: Some(CANNOT_FIND_NAME),
+            source: None,
+            message: format!("Cannot find name '{name}'."),
+            related_information: None,
+            reports_unnecessary: None,
+            reports_deprecated: None,
+        };
+        let actions = provider.provide_code_actions(
+            root,
+            Range::new(Position::new(0, 0), Position::new(0, 0)),
+            CodeActionContext {
+                diagnostics: vec![diag],
+                only: Some(vec![CodeActionKind::QuickFix]),
+                import_candidates: vec![candidate],
+            },
+        );
+
+        assert!(!actions.is_empty(), "expected a quickfix importing {name}");
+        let edits = &actions[0].edit.as_ref().unwrap().changes["test.ts"];
+        assert_eq!(apply_text_edits(source, &line_map, edits), expected);
+    }
+}
@@ -150,7 +150,7 @@ fn test_quickfix_add_missing_import_class_extends_uses_value_import() {
 }
 
 #[test]
-fn test_quickfix_add_missing_import_class_implements_uses_import_type() {
+fn test_quickfix_add_missing_import_class_implements_uses_import_type_under_verbatim_module_syntax() {
     let source = "class Bar implements Foo {}\n";
     let (parser, root) = parse_test_source(source);
     let arena = parser.get_arena();
@@ -173,7 +173,8 @@ fn test_quickfix_add_missing_import_class_implements_uses_import_type() {
     };
 
     let provider =
-        CodeActionProvider::new(arena, &binder, &line_map, "test.ts".to_string(), source);
+        CodeActionProvider::new(arena, &binder, &line_map, "test.ts".to_string(), source)
+            .with_verbatim_module_syntax(true);
 
     let empty_range = Range::new(Position::new(0, 0), Position::new(0, 0));
     let actions = provider.provide_code_actions(
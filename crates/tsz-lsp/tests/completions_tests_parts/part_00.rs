@@ -1281,6 +1281,42 @@ obj.";
     );
 }
 
+#[test]
+fn test_completion_result_optional_replacement_span_covers_touched_identifier() {
+    let source = "const obj = { foo: 1 };
+obj.fo";
+    let (root, arena, binder, line_map, src) = make_completions_provider(source);
+    let completions = Completions::new(&arena, &binder, &line_map, &src);
+
+    let result = completions
+        .get_completion_result(root, Position::new(1, 6))
+        .expect("Should have completion result");
+    let start = src.rfind("fo").unwrap() as u32;
+    assert_eq!(result.optional_replacement_span, Some((start, start + 2)));
+
+    let result = completions
+        .get_completion_result(root, Position::new(1, 5))
+        .expect("Should have completion result");
+    assert_eq!(
+        result.optional_replacement_span,
+        Some((start, start + 2)),
+        "the span covers the whole identifier, not just the typed prefix"
+    );
+}
+
+#[test]
+fn test_completion_result_no_optional_replacement_span_after_dot() {
+    let source = "const obj = { foo: 1 };
+obj.";
+    let (root, arena, binder, line_map, src) = make_completions_provider(source);
+    let completions = Completions::new(&arena, &binder, &line_map, &src);
+
+    let result = completions
+        .get_completion_result(root, Position::new(1, 4))
+        .expect("Should have completion result");
+    assert_eq!(result.optional_replacement_span, None);
+}
+
 #[test]
 fn test_completion_result_struct_global_completion() {
     let source = "const x = 1;
@@ -1723,6 +1723,7 @@ fn test_completions_completion_result_serialization() {
         is_member_completion: false,
         is_new_identifier_location: false,
         default_commit_characters: Some(vec![".".to_string(), ",".to_string()]),
+        optional_replacement_span: None,
         entries: vec![CompletionItem::new(
             "x".to_string(),
             CompletionItemKind::Variable,
@@ -1499,3 +1499,72 @@ fn test_auto_import_import_clause_sort_text_name_independent() {
         );
     }
 }
+
+fn class_member_override_snippets(source: &str, position: Position) -> Vec<CompletionItem> {
+    let (parser, root) = parse_test_source(source);
+    let mut binder = BinderState::new();
+    binder.bind_source_file(parser.get_arena(), root);
+    let line_map = LineMap::build(source);
+    let completions = Completions::new(parser.get_arena(), &binder, &line_map, source);
+    completions.get_class_member_override_snippets(root, position)
+}
+
+#[test]
+fn test_class_member_override_snippets_forward_to_super() {
+    let source = "class Base {\n    constructor(public name: string, count = 1) {}\n    greet(greeting: string, ...rest: number[]): string { return greeting; }\n    reset(): void {}\n    static create(): Base { return new Base(\"\"); }\n    private secret(): void {}\n}\nclass Derived extends Base {\n    \n}\n";
+    let items = class_member_override_snippets(source, Position::new(8, 4));
+    let by_label = |label: &str| {
+        items
+            .iter()
+            .find(|item| item.label == label)
+            .unwrap_or_else(|| panic!("missing `{label}` in {items:?}"))
+    };
+
+    let ctor = by_label("constructor");
+    assert_eq!(ctor.kind, CompletionItemKind::Constructor);
+    assert_eq!(
+        ctor.insert_text.as_deref(),
+        Some("constructor(name: string, count = 1) {\n    super(name, count);$0\n}")
+    );
+
+    let greet = by_label("greet");
+    assert!(greet.is_snippet);
+    assert_eq!(
+        greet.insert_text.as_deref(),
+        Some(
+            "override greet(greeting: string, ...rest: number[]): string {\n    ${0:return super.greet(greeting, ...rest);}\n}"
+        )
+    );
+    assert_eq!(
+        by_label("reset").insert_text.as_deref(),
+        Some("override reset(): void {\n    ${0:super.reset();}\n}")
+    );
+
+    assert!(!items.iter().any(|item| item.label == "create"));
+    assert!(!items.iter().any(|item| item.label == "secret"));
+}
+
+#[test]
+fn test_class_member_override_snippets_abstract_and_interface_members() {
+    let source = "interface Shape {\n    readonly sides: number;\n    area(): number;\n}\nabstract class Named {\n    abstract describe(): string;\n}\nclass Square extends Named implements Shape {\n    constructor() { super(); }\n    area(): number { return 1; }\n    \n}\n";
+    let items = class_member_override_snippets(source, Position::new(10, 4));
+    let labels: Vec<&str> = items.iter().map(|item| item.label.as_str()).collect();
+    assert_eq!(labels, vec!["describe", "sides"]);
+    assert_eq!(
+        items[0].insert_text.as_deref(),
+        Some(
+            "override describe(): string {\n    ${0:throw new Error(\"Method not implemented.\");}\n}"
+        )
+    );
+    assert_eq!(items[1].kind, CompletionItemKind::Property);
+    assert_eq!(
+        items[1].insert_text.as_deref(),
+        Some("readonly sides: number;$0")
+    );
+}
+
+#[test]
+fn test_class_member_override_snippets_outside_member_position() {
+    let source = "class Base { run(): void {} }\nclass Derived extends Base {\n    run(): void {\n        \n    }\n}\n";
+    assert!(class_member_override_snippets(source, Position::new(3, 8)).is_empty());
+}
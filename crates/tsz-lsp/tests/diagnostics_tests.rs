@@ -20,6 +20,7 @@ fn make_diagnostic(
         category,
         code,
         related_information: Vec::new(),
+        spelling_suggestion: None,
     }
 }
 
@@ -53,6 +54,7 @@ fn test_convert_diagnostic_with_related_info() {
         category: DiagnosticCategory::Error,
         code: 1001,
         related_information: vec![related, related_other],
+        spelling_suggestion: None,
     };
     let lsp_diag = convert_diagnostic(&diag, &line_map, source);
     assert_eq!(lsp_diag.message, "Main error");
@@ -1311,7 +1311,7 @@ fn call_hierarchy_outgoing_calls() {
 
 #[test]
 fn call_hierarchy_incoming_calls() {
-    let t = FourslashTest::new(
+    let mut t = FourslashTest::new(
         "
         function /*fn*/target() {}
         function caller1() { target(); }
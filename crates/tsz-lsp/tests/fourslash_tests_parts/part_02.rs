@@ -1151,7 +1151,7 @@ fn call_hierarchy_outgoing_from_function() {
 
 #[test]
 fn call_hierarchy_incoming_to_function() {
-    let t = FourslashTest::new(
+    let mut t = FourslashTest::new(
         "
         function /*f*/target() { return 42; }
         function caller1() { target(); }
@@ -6,6 +6,11 @@ use tsz_solver::construction::TypeInterner;
 
 /// Helper to create a provider and get hints for the given source code.
 fn get_hints_for_source(source: &str) -> Vec<InlayHint> {
+    get_hints_with_options(source, InlayHintsOptions::default())
+}
+
+/// Helper to get hints with explicit hint category settings.
+fn get_hints_with_options(source: &str, options: InlayHintsOptions) -> Vec<InlayHint> {
     let mut parser = ParserState::new("test.ts".to_string(), source.to_string());
     let root = parser.parse_source_file();
 
@@ -22,7 +27,8 @@ fn get_hints_for_source(source: &str) -> Vec<InlayHint> {
         source,
         &interner,
         "test.ts".to_string(),
-    );
+    )
+    .with_options(options);
 
     let range = Range::new(Position::new(0, 0), Position::new(u32::MAX, u32::MAX));
     provider.provide_inlay_hints(root, range)
@@ -1565,3 +1571,147 @@ f(1, 2);
         "Unlabeled tuple rest should fall back to the rest name, got {param_hints:?}"
     );
 }
+
+#[test]
+fn test_parameter_names_none_suppresses_parameter_hints() {
+    let source = "function greet(name: string) {}\ngreet(\"hi\");\n";
+    let options = InlayHintsOptions {
+        parameter_names: ParameterNameHints::None,
+        ..InlayHintsOptions::default()
+    };
+    let hints = get_hints_with_options(source, options);
+    assert!(get_param_hints(&hints).is_empty());
+}
+
+#[test]
+fn test_parameter_names_literals_only_hints_literal_arguments() {
+    let source = "function move(x: number, y: number, label: string) {}\nconst dx = 1;\nmove(dx, -2, `a`);\n";
+    let options = InlayHintsOptions {
+        parameter_names: ParameterNameHints::Literals,
+        ..InlayHintsOptions::default()
+    };
+    let hints = get_hints_with_options(source, options);
+    let labels: Vec<&str> = get_param_hints(&hints)
+        .iter()
+        .map(|h| h.label.as_str())
+        .collect();
+    assert_eq!(labels, vec!["y: ", "label: "]);
+}
+
+#[test]
+fn test_variable_and_return_type_hints_can_be_disabled() {
+    let source = "const n = 1;\nconst f = (a: number) => a + 1;\n";
+    let options = InlayHintsOptions {
+        variable_types: false,
+        function_like_return_types: false,
+        ..InlayHintsOptions::default()
+    };
+    let hints = get_hints_with_options(source, options);
+    assert!(get_type_hints(&hints).is_empty(), "got {hints:?}");
+}
+
+#[test]
+fn test_return_type_hint_insert_text_reparses_after_close_paren() {
+    let source = "const add = (a: number, b: number,) => a + b;\n\
+                  const one = function () { return 1; };\n\
+                  const two = /* no params */ ( ) => 2;\n";
+    let options = InlayHintsOptions {
+        variable_types: false,
+        ..InlayHintsOptions::default()
+    };
+    let hints = get_hints_with_options(source, options);
+    let line_map = LineMap::build(source);
+    let mut edits: Vec<(usize, &str)> = get_type_hints(&hints)
+        .into_iter()
+        .map(|hint| {
+            let offset = line_map
+                .position_to_offset(hint.position, source)
+                .expect("hint position is in the source") as usize;
+            (offset, hint.insert_text.as_deref().expect("insert text"))
+        })
+        .collect();
+    assert_eq!(edits.len(), 3, "got {hints:?}");
+    for (offset, _) in &edits {
+        assert_eq!(&source[offset - 1..*offset], ")", "hint at {offset}");
+    }
+
+    edits.sort_by(|a, b| b.0.cmp(&a.0));
+    let mut edited = source.to_string();
+    for (offset, text) in edits {
+        edited.insert_str(offset, text);
+    }
+    assert!(edited.contains(",): number => a + b"), "{edited}");
+    let mut parser = ParserState::new("test.ts".to_string(), edited.clone());
+    parser.parse_source_file();
+    assert!(
+        parser.get_diagnostics().is_empty(),
+        "accepted hints should leave valid TypeScript: {edited}"
+    );
+}
+
+#[test]
+fn test_type_hints_carry_insert_text() {
+    let hints = get_hints_for_source("let count = 42;\n");
+    let type_hints = get_type_hints(&hints);
+    assert_eq!(type_hints.len(), 1);
+    assert_eq!(type_hints[0].insert_text.as_deref(), Some(": number"));
+}
+
+#[test]
+fn test_property_declaration_type_hints() {
+    let source = "class Counter {\n    count = 0;\n    label: string = \"x\";\n}\n";
+    let options = InlayHintsOptions {
+        property_declaration_types: true,
+        ..InlayHintsOptions::default()
+    };
+    let hints = get_hints_with_options(source, options);
+    let type_hints = get_type_hints(&hints);
+    assert_eq!(type_hints.len(), 1, "got {type_hints:?}");
+    assert_eq!(type_hints[0].position, Position::new(1, 9));
+    assert_eq!(type_hints[0].label, ": number");
+
+    assert!(get_type_hints(&get_hints_for_source(source)).is_empty());
+}
+
+#[test]
+fn test_enum_member_value_hints() {
+    let source = "enum Level {\n    Low,\n    Mid,\n    High = 10,\n    Max,\n    Named = \"n\".length,\n    After,\n}\n";
+    let options = InlayHintsOptions {
+        enum_member_values: true,
+        ..InlayHintsOptions::default()
+    };
+    let hints = get_hints_with_options(source, options);
+    let enum_hints: Vec<(Position, &str)> = hints
+        .iter()
+        .filter(|h| h.kind == InlayHintKind::Enum)
+        .map(|h| (h.position, h.label.as_str()))
+        .collect();
+    assert_eq!(
+        enum_hints,
+        vec![
+            (Position::new(1, 7), "= 0"),
+            (Position::new(2, 7), "= 1"),
+            (Position::new(4, 7), "= 11"),
+        ]
+    );
+    assert!(
+        !get_hints_for_source(source)
+            .iter()
+            .any(|h| h.kind == InlayHintKind::Enum)
+    );
+}
+
+#[test]
+fn test_inlay_hints_options_apply_settings() {
+    let mut options = InlayHintsOptions::default();
+    options.apply_settings(&serde_json::json!({
+        "parameterNames": { "enabled": "literals" },
+        "variableTypes": { "enabled": false },
+        "enumMemberValues": { "enabled": true },
+    }));
+    assert_eq!(options.parameter_names, ParameterNameHints::Literals);
+    assert!(!options.variable_types);
+    assert!(options.enum_member_values);
+    assert!(options.function_like_return_types);
+    assert!(!options.property_declaration_types);
+}
@@ -541,6 +541,105 @@ fn test_project_code_actions_missing_type_only_import_at_point_range() {
     );
 }
 
+fn auto_import_completion_text(project: &Project, file_name: &str, label: &str) -> String {
+    let file = project.file(file_name).unwrap();
+    let source = file.source_text();
+    let offset = source.rfind(label).unwrap() + 2;
+    let position = file.line_map().offset_to_position(offset as u32, source);
+    let completions = project
+        .get_completions(file_name, position)
+        .expect("Expected completions");
+    let item = completions
+        .iter()
+        .find(|item| item.label == label && item.additional_text_edits.is_some())
+        .unwrap_or_else(|| panic!("Expected auto-import completion for {label}"));
+    apply_text_edits(
+        source,
+        file.line_map(),
+        item.additional_text_edits.as_ref().unwrap(),
+    )
+}
+
+#[test]
+fn test_auto_import_completion_matches_quote_and_semicolon_style() {
+    let mut project = Project::new();
+
+    project.set_file("a.ts".to_string(), "export const helper = 1;\n".to_string());
+    project.set_file("z.ts".to_string(), "export const other = 2;\n".to_string());
+    project.set_file(
+        "main.ts".to_string(),
+        "import { other } from './z'\n\nhelper\n".to_string(),
+    );
+
+    let updated = auto_import_completion_text(&project, "main.ts", "helper");
+    assert!(
+        updated.contains("import { helper } from './a'\n"),
+        "Expected single-quoted import without semicolon, got: {updated}"
+    );
+    assert!(!updated.contains("'./a';"));
+}
+
+#[test]
+fn test_auto_import_completion_quote_preference_overrides_file_style() {
+    let mut project = Project::new();
+    project.set_quote_preference(Some("double".to_string()));
+
+    project.set_file("a.ts".to_string(), "export const helper = 1;\n".to_string());
+    project.set_file("z.ts".to_string(), "export const other = 2;\n".to_string());
+    project.set_file(
+        "main.ts".to_string(),
+        "import { other } from './z';\n\nhelper\n".to_string(),
+    );
+
+    let updated = auto_import_completion_text(&project, "main.ts", "helper");
+    assert!(
+        updated.contains("import { helper } from \"./a\";\n"),
+        "Expected double-quoted import, got: {updated}"
+    );
+}
+
+#[test]
+fn test_auto_import_completion_merges_into_existing_import() {
+    let mut project = Project::new();
+
+    project.set_file(
+        "a.ts".to_string(),
+        "export const first = 1;\nexport const second = 2;\n".to_string(),
+    );
+    project.set_file(
+        "main.ts".to_string(),
+        "import { first } from './a';\n\nfirst;\nsecond\n".to_string(),
+    );
+
+    let updated = auto_import_completion_text(&project, "main.ts", "second");
+    assert!(
+        updated.starts_with("import { first, second } from './a';\n"),
+        "Expected merge into existing import, got: {updated}"
+    );
+    assert_eq!(updated.matches("from './a'").count(), 1);
+}
+
+#[test]
+fn test_auto_import_completion_type_usage_respects_verbatim_module_syntax() {
+    let mut project = Project::new();
+
+    project.set_file("a.ts".to_string(), "export class Widget {}\n".to_string());
+    project.set_file("main.ts".to_string(), "let w: Widget;\n".to_string());
+
+    let updated = auto_import_completion_text(&project, "main.ts", "Widget");
+    assert!(
+        updated.starts_with("import { Widget } from \"./a\";"),
+        "Expected value import without verbatimModuleSyntax, got: {updated}"
+    );
+
+    project.set_verbatim_module_syntax(true);
+    let updated = auto_import_completion_text(&project, "main.ts", "Widget");
+    assert!(
+        updated.starts_with("import type { Widget } from \"./a\";"),
+        "Expected type-only import under verbatimModuleSyntax, got: {updated}"
+    );
+}
+
 #[test]
 fn test_project_code_actions_missing_import_default_reexport() {
     let mut project = Project::new();
@@ -1020,3 +1119,173 @@ fn test_private_addition_does_not_invalidate_dependents() {
 // =============================================================================
 // Project-level feature tests for new wrappers
 // =============================================================================
+
+fn missing_name_quick_fix_titles(
+    project: &Project,
+    file_name: &str,
+    name: &str,
+    code: u32,
+    message: &str,
+) -> Vec<String> {
+    let file = project.file(file_name).unwrap();
+    let source = file.source_text();
+    let line_map = file.line_map();
+    let start = source.find(name).unwrap();
+    let range = Range::new(
+        line_map.offset_to_position(start as u32, source),
+        line_map.offset_to_position((start + name.len()) as u32, source),
+    );
+    let diag = LspDiagnostic {
+        range,
+        severity: Some(DiagnosticSeverity::Error),
+        code: Some(code),
+        source: None,
+        message: message.to_string(),
+        related_information: None,
+        reports_unnecessary: None,
+        reports_deprecated: None,
+    };
+
+    project
+        .get_code_actions(
+            file_name,
+            range,
+            vec![diag],
+            Some(vec![CodeActionKind::QuickFix]),
+        )
+        .unwrap_or_default()
+        .into_iter()
+        .map(|action| action.title)
+        .collect()
+}
+
+#[test]
+fn test_project_code_actions_missing_jsx_component_import_ranked_by_distance() {
+    let mut project = Project::new();
+    project.set_file(
+        "/src/deep/ui/Button.tsx".to_string(),
+        "export function Button() { return null; }\n".to_string(),
+    );
+    project.set_file(
+        "/src/components/Button.tsx".to_string(),
+        "export function Button() { return null; }\n".to_string(),
+    );
+    project.set_file(
+        "/src/App.tsx".to_string(),
+        "const el = <Button />;\n".to_string(),
+    );
+
+    let titles = missing_name_quick_fix_titles(
+        &project,
+        "/src/App.tsx",
+        "Button",
+        tsz_checker::diagnostics::diagnostic_codes::CANNOT_FIND_NAME,
+        "Cannot find name 'Button'.",
+    );
+    let imports: Vec<&String> = titles
+        .iter()
+        .filter(|title| title.starts_with("Import 'Button'"))
+        .collect();
+
+    assert_eq!(
+        imports.len(),
+        2,
+        "Expected both Button modules, got {titles:?}"
+    );
+    assert!(
+        imports[0].contains("./components/Button") && imports[1].contains("./deep/ui/Button"),
+        "Closer module should be offered first, got {imports:?}"
+    );
+}
+
+#[test]
+fn test_project_code_actions_missing_import_for_did_you_mean_diagnostic() {
+    let mut project = Project::new();
+    project.set_file("a.ts".to_string(), "export const helper = 1;\n".to_string());
+    project.set_file(
+        "b.ts".to_string(),
+        "const helpers = 2;\nhelper;\n".to_string(),
+    );
+
+    let titles = missing_name_quick_fix_titles(
+        &project,
+        "b.ts",
+        "helper;",
+        tsz_checker::diagnostics::diagnostic_codes::CANNOT_FIND_NAME_DID_YOU_MEAN,
+        "Cannot find name 'helper'. Did you mean 'helpers'?",
+    );
+
+    assert!(
+        titles
+            .iter()
+            .any(|title| title == "Import 'helper' from './a'"),
+        "Expected an import fix alongside the spelling fix, got {titles:?}"
+    );
+}
+
+#[test]
+fn test_project_code_actions_no_import_for_intrinsic_jsx_tag() {
+    let mut project = Project::new();
+    project.set_file(
+        "/src/div.ts".to_string(),
+        "export const div = 1;\n".to_string(),
+    );
+    project.set_file(
+        "/src/App.tsx".to_string(),
+        "const el = <div />;\n".to_string(),
+    );
+
+    let titles = missing_name_quick_fix_titles(
+        &project,
+        "/src/App.tsx",
+        "div",
+        tsz_checker::diagnostics::diagnostic_codes::CANNOT_FIND_NAME,
+        "Cannot find name 'div'.",
+    );
+
+    assert!(
+        !titles.iter().any(|title| title.starts_with("Import 'div'")),
+        "Intrinsic JSX elements must not get import fixes, got {titles:?}"
+    );
+}
+
+#[test]
+fn test_project_code_actions_missing_import_ranked_by_relative_distance() {
+    // Both specifiers contain two '/' separators; the one that climbs out of
+    // the importing directory is further away and must come second.
+    let mut project = Project::new();
+    project.set_file(
+        "/Button.tsx".to_string(),
+        "export function Button() { return null; }\n".to_string(),
+    );
+    project.set_file(
+        "/src/feature/ui/Button.tsx".to_string(),
+        "export function Button() { return null; }\n".to_string(),
+    );
+    project.set_file(
+        "/src/feature/App.tsx".to_string(),
+        "const el = <Button />;\n".to_string(),
+    );
+
+    let titles = missing_name_quick_fix_titles(
+        &project,
+        "/src/feature/App.tsx",
+        "Button",
+        tsz_checker::diagnostics::diagnostic_codes::CANNOT_FIND_NAME,
+        "Cannot find name 'Button'.",
+    );
+    let imports: Vec<&String> = titles
+        .iter()
+        .filter(|title| title.starts_with("Import 'Button'"))
+        .collect();
+
+    assert_eq!(
+        imports.len(),
+        2,
+        "Expected both Button modules, got {titles:?}"
+    );
+    assert!(
+        imports[0].contains("./ui/Button") && imports[1].contains("../../Button"),
+        "Closer module should be offered first, got {imports:?}"
+    );
+}
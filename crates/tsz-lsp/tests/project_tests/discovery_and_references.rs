@@ -33,6 +33,45 @@ fn discover_files_respects_tsconfig_include_patterns() {
     assert!(!discovered.contains(&slash_path(&excluded)));
 }
 
+#[test]
+fn discovered_files_parse_function_bodies_on_demand() {
+    let workspace = TempWorkspace::new("lsp-deferred-bodies");
+    let root = workspace.path.to_string_lossy().to_string();
+    let a = workspace.path.join("a.ts");
+    let b = workspace.path.join("b.ts");
+    write_file(&a, "export const foo = 1;\n");
+    write_file(
+        &b,
+        "import { foo } from \"./a\";\nexport function use() {\n    return foo;\n}\n",
+    );
+
+    let mut project = Project::new();
+    project.discover_files(&[root]);
+    let a_name = a.to_string_lossy().to_string();
+    let b_name = b.to_string_lossy().to_string();
+    assert!(
+        project
+            .file(&b_name)
+            .unwrap()
+            .has_deferred_function_bodies()
+    );
+
+    let refs = project
+        .find_references(&a_name, Position::new(0, 13))
+        .expect("references to foo");
+    assert!(
+        refs.iter()
+            .any(|loc| loc.file_path == b_name && loc.range.start.line == 2),
+        "the reference inside the skipped body of use() is found: {refs:?}"
+    );
+    assert!(
+        !project
+            .file(&b_name)
+            .unwrap()
+            .has_deferred_function_bodies()
+    );
+}
+
 #[test]
 fn test_project_cross_file_references_named_import() {
     let mut project = Project::new();
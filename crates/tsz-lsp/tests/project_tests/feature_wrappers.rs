@@ -622,6 +622,52 @@ fn test_project_workspace_symbols_empty_query() {
     assert!(symbols.is_empty(), "Empty query should return no symbols");
 }
 
+#[test]
+fn test_project_workspace_symbols_follow_incremental_edits() {
+    let mut project = Project::new();
+    project.set_file(
+        "a.ts".to_string(),
+        "export function createUser() {}\n".to_string(),
+    );
+    assert!(
+        project
+            .get_workspace_symbols("cU")
+            .iter()
+            .any(|s| s.name == "createUser")
+    );
+
+    let edit = {
+        let file = project.file("a.ts").unwrap();
+        let range = range_for_substring(file.source_text(), file.line_map(), "createUser");
+        TextEdit::new(range, "deleteUser".to_string())
+    };
+    project
+        .update_file("a.ts", &[edit])
+        .expect("Expected update to succeed");
+
+    let names: Vec<String> = project
+        .get_workspace_symbols("User")
+        .into_iter()
+        .map(|s| s.name)
+        .collect();
+    assert_eq!(names, vec!["deleteUser".to_string()]);
+
+    project.remove_file("a.ts");
+    assert!(project.get_workspace_symbols("User").is_empty());
+}
+
+#[test]
+fn test_project_workspace_symbols_with_limit() {
+    let mut project = Project::new();
+    project.set_file(
+        "a.ts".to_string(),
+        "export const item1 = 1;\nexport const item2 = 2;\nexport const item3 = 3;\n".to_string(),
+    );
+
+    assert_eq!(project.get_workspace_symbols_with_limit("item", 2).len(), 2);
+    assert_eq!(project.get_workspace_symbols("item").len(), 3);
+}
+
 #[test]
 fn test_project_diagnostics_on_type_error() {
     let mut project = Project::new();
@@ -896,3 +942,65 @@ fn test_project_set_auto_import_specifier_exclude_regexes() {
     project.set_file("a.ts".to_string(), "export const x = 1;\n".to_string());
     assert_eq!(project.file_count(), 1);
 }
+
+#[test]
+fn test_project_cancelled_requests_return_none() {
+    let mut project = Project::new();
+    project.set_file(
+        "a.ts".to_string(),
+        "export const value: number = \"x\";\nval\n".to_string(),
+    );
+
+    let token = CancellationToken::new();
+    project.set_cancellation_token(Some(token.clone()));
+    token.cancel();
+    assert!(project.is_cancelled());
+
+    assert!(project.get_diagnostics("a.ts").is_none());
+    assert!(
+        project
+            .get_completions("a.ts", Position::new(1, 3))
+            .is_none()
+    );
+    assert!(
+        project
+            .find_references("a.ts", Position::new(0, 14))
+            .is_none()
+    );
+    assert!(
+        project.files["a.ts"].type_cache.is_none(),
+        "A cancelled check must not keep its partial type cache"
+    );
+
+    project.set_cancellation_token(None);
+    let diagnostics = project
+        .get_diagnostics("a.ts")
+        .expect("Expected diagnostics once the token is cleared");
+    assert!(!diagnostics.is_empty());
+    assert!(project.files["a.ts"].type_cache.is_some());
+}
+
+#[test]
+fn test_project_class_member_override_snippets_follow_imported_base_class() {
+    let mut project = Project::new();
+    project.set_file(
+        "/src/base.ts".to_string(),
+        "export class Root {\n    constructor(public id: string) {}\n    close(): void {}\n}\nexport class Base extends Root {\n    greet(name: string): string { return name; }\n}\nexport interface Named {\n    label: string;\n}\n".to_string(),
+    );
+    project.set_file(
+        "/src/derived.ts".to_string(),
+        "import { Base, Named } from './base';\nclass Derived extends Base implements Named {\n    \n}\n".to_string(),
+    );
+
+    let items = project.get_class_member_override_snippets("/src/derived.ts", Position::new(2, 4));
+    let labels: Vec<&str> = items.iter().map(|item| item.label.as_str()).collect();
+    assert_eq!(labels, vec!["constructor", "greet", "close", "label"]);
+    assert_eq!(
+        items[0].insert_text.as_deref(),
+        Some("constructor(id: string) {\n    super(id);$0\n}")
+    );
+    assert_eq!(
+        items[1].insert_text.as_deref(),
+        Some("override greet(name: string): string {\n    ${0:return super.greet(name);}\n}")
+    );
+}
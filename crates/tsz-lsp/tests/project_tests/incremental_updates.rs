@@ -791,3 +791,92 @@ fn test_project_update_file_preserves_multiple_prefix_symbols() {
     assert!(locals.has("beta"));
     assert!(locals.has("delta"));
 }
+
+#[test]
+fn test_project_apply_content_changes_is_sequential() {
+    let mut project = Project::new();
+    project.set_file("a.ts".to_string(), "const a = 1;\n".to_string());
+
+    // The second range refers to the document after the first change.
+    let changes = [
+        TextDocumentContentChange {
+            range: Some(Range::new(Position::new(0, 0), Position::new(0, 0))),
+            text: "let x;\n".to_string(),
+        },
+        TextDocumentContentChange {
+            range: Some(Range::new(Position::new(1, 10), Position::new(1, 11))),
+            text: "2".to_string(),
+        },
+    ];
+    project
+        .apply_content_changes("a.ts", &changes)
+        .expect("Expected changes to apply");
+
+    let file = project.file("a.ts").unwrap();
+    assert_eq!(file.source_text(), "let x;\nconst a = 2;\n");
+    assert!(file.binder().file_locals.get("x").is_some());
+    assert!(file.binder().file_locals.get("a").is_some());
+}
+
+#[test]
+fn test_project_apply_content_changes_full_replacement_then_range() {
+    let mut project = Project::new();
+    project.set_file("a.ts".to_string(), "const a = 1;\n".to_string());
+
+    let changes = [
+        TextDocumentContentChange {
+            range: None,
+            text: "const b = 1;\n".to_string(),
+        },
+        TextDocumentContentChange {
+            range: Some(Range::new(Position::new(0, 6), Position::new(0, 7))),
+            text: "c".to_string(),
+        },
+    ];
+    project
+        .apply_content_changes("a.ts", &changes)
+        .expect("Expected changes to apply");
+
+    assert_eq!(
+        project.file("a.ts").unwrap().source_text(),
+        "const c = 1;\n"
+    );
+}
+
+#[test]
+fn test_project_apply_content_changes_rejects_out_of_range_edit() {
+    let mut project = Project::new();
+    project.set_file("a.ts".to_string(), "const a = 1;\n".to_string());
+
+    let changes = [TextDocumentContentChange {
+        range: Some(Range::new(Position::new(5, 0), Position::new(5, 1))),
+        text: "x".to_string(),
+    }];
+    assert!(project.apply_content_changes("a.ts", &changes).is_none());
+    assert_eq!(
+        project.file("a.ts").unwrap().source_text(),
+        "const a = 1;\n"
+    );
+}
+
+#[test]
+fn test_project_apply_content_changes_refreshes_dependencies() {
+    let mut project = Project::new();
+    project.set_file("b.ts".to_string(), "export const b = 1;\n".to_string());
+    project.set_file("a.ts".to_string(), "const a = 1;\n".to_string());
+    assert!(project.dependency_graph.get_dependencies("a.ts").is_none());
+
+    let changes = [TextDocumentContentChange {
+        range: Some(Range::new(Position::new(0, 0), Position::new(0, 0))),
+        text: "import { b } from \"./b\";\n".to_string(),
+    }];
+    project
+        .apply_content_changes("a.ts", &changes)
+        .expect("Expected changes to apply");
+
+    let deps = project
+        .dependency_graph
+        .get_dependencies("a.ts")
+        .expect("Expected a.ts to record its new import");
+    assert!(deps.contains("./b"));
+}
@@ -543,6 +543,122 @@ fn test_project_get_file_rename_edits_rewrites_path_alias() {
     assert_eq!(consumer_edits[0].new_text, "@app/bar");
 }
 
+#[test]
+fn test_project_handle_will_rename_files_directory_move() {
+    // Moving `/src/ui` to `/lib/ui` must rewrite imports into the folder and
+    // relative imports from the folder back out, but leave imports between
+    // two files that move together untouched.
+    let mut project = Project::new();
+    project.set_file(
+        "/src/util.ts".to_string(),
+        "export const pad = 1;\n".to_string(),
+    );
+    project.set_file(
+        "/src/ui/button.ts".to_string(),
+        "import { pad } from \"../util\";\nexport const button = pad;\n".to_string(),
+    );
+    project.set_file(
+        "/src/ui/form.ts".to_string(),
+        "import { button } from \"./button\";\nexport const form = button;\n".to_string(),
+    );
+    project.set_file(
+        "/src/app.ts".to_string(),
+        "import { form } from \"./ui/form\";\nconst f = form;\n".to_string(),
+    );
+
+    let edits = project.handle_will_rename_files(&[FileRename {
+        old_uri: "/src/ui".to_string(),
+        new_uri: "/lib/ui".to_string(),
+    }]);
+
+    let app_edits = edits
+        .changes
+        .get("/src/app.ts")
+        .expect("import into the moved folder should be rewritten");
+    assert_eq!(app_edits.len(), 1);
+    assert_eq!(app_edits[0].new_text, "../lib/ui/form");
+
+    let button_edits = edits
+        .changes
+        .get("/src/ui/button.ts")
+        .expect("import out of the moved folder should be rewritten");
+    assert_eq!(button_edits.len(), 1);
+    assert_eq!(button_edits[0].new_text, "../../src/util");
+
+    assert!(
+        !edits.changes.contains_key("/src/ui/form.ts"),
+        "imports between co-moved files must not change"
+    );
+}
+
+#[test]
+fn test_project_get_file_rename_edits_directory_rewrites_tsconfig_paths() {
+    let mut project = Project::new();
+    project.set_file(
+        "/tsconfig.json".to_string(),
+        r#"{
+  "compilerOptions": {
+    "baseUrl": ".",
+    "paths": { "@ui/*": ["src/ui/*"], "@app/*": ["src/*"] }
+  }
+}"#
+        .to_string(),
+    );
+    project.set_file(
+        "/src/ui/button.ts".to_string(),
+        "export const button = 1;\n".to_string(),
+    );
+    project.set_file(
+        "/src/main.ts".to_string(),
+        "import { button } from \"@app/ui/button\";\nconst b = button;\n".to_string(),
+    );
+
+    let edits = project.get_file_rename_edits("/src/ui", "/src/components");
+
+    let config_edits = edits
+        .get("/tsconfig.json")
+        .expect("paths mapping into the moved folder should be rewritten");
+    assert_eq!(config_edits.len(), 1);
+    assert_eq!(config_edits[0].new_text, "src/components/*");
+
+    let main_edits = edits
+        .get("/src/main.ts")
+        .expect("aliased import of a moved file should be rewritten");
+    assert_eq!(main_edits[0].new_text, "@app/components/button");
+}
+
+#[test]
+fn test_project_get_file_rename_edits_directory_skips_paths_outside_compiler_options() {
+    // Only `compilerOptions.paths` is rewritten; a commented-out copy and a
+    // same-named key elsewhere must be left alone.
+    let mut project = Project::new();
+    project.set_file(
+        "/tsconfig.json".to_string(),
+        r#"{
+  // "paths": { "@ui/*": ["src/ui/*"] }
+  "watchOptions": { "excludeDirectories": ["paths"] },
+  "compilerOptions": {
+    "baseUrl": ".",
+    "paths": { "@ui/*": ["src/ui/*"] }
+  }
+}"#
+        .to_string(),
+    );
+    project.set_file(
+        "/src/ui/button.ts".to_string(),
+        "export const button = 1;\n".to_string(),
+    );
+
+    let edits = project.get_file_rename_edits("/src/ui", "/src/components");
+
+    let config_edits = edits
+        .get("/tsconfig.json")
+        .expect("paths mapping into the moved folder should be rewritten");
+    assert_eq!(config_edits.len(), 1);
+    assert_eq!(config_edits[0].range.start.line, 5);
+    assert_eq!(config_edits[0].new_text, "src/components/*");
+}
+
 #[test]
 fn test_project_subtypes_returns_empty_for_missing_file() {
     let project = Project::new();
@@ -591,7 +707,7 @@ fn test_project_resolve_code_lens_missing_file() {
 
 #[test]
 fn test_project_get_incoming_calls_missing_file() {
-    let project = Project::new();
+    let mut project = Project::new();
     let result = project.get_incoming_calls("nonexistent.ts", Position::new(0, 0));
     assert!(
         result.is_empty(),
@@ -1007,6 +1123,102 @@ class Document implements Printable {
     let _ = impls;
 }
 
+#[test]
+fn test_project_get_implementations_interface_method_across_files() {
+    let mut project = Project::new();
+    project.set_file(
+        "shape.ts".to_string(),
+        "export interface Shape {\n    area(): number;\n}\n".to_string(),
+    );
+    project.set_file(
+        "square.ts".to_string(),
+        r#"import { Shape } from './shape';
+export class Square implements Shape {
+    area() { return 4; }
+}
+"#
+        .to_string(),
+    );
+
+    // Cursor on `area` in the interface (line 1, col 4).
+    let impls = project
+        .get_implementations("shape.ts", Position::new(1, 4))
+        .expect("interface method should resolve to implementing member");
+    assert_eq!(impls.len(), 1);
+    assert_eq!(impls[0].file_path, "square.ts");
+    assert_eq!(impls[0].range.start, Position::new(2, 4));
+}
+
+#[test]
+fn test_project_get_implementations_abstract_member_transitive() {
+    let mut project = Project::new();
+    project.set_file(
+        "base.ts".to_string(),
+        "export abstract class Base {\n    abstract run(): void;\n}\n".to_string(),
+    );
+    project.set_file(
+        "middle.ts".to_string(),
+        "import { Base } from './base';\nexport abstract class Middle extends Base {}\n"
+            .to_string(),
+    );
+    project.set_file(
+        "leaf.ts".to_string(),
+        "import { Middle } from './middle';\nclass Leaf extends Middle {\n    run() {}\n}\n"
+            .to_string(),
+    );
+
+    // `Middle` inherits `run` without redeclaring it; the search must still
+    // reach `Leaf` through it.
+    let impls = project
+        .get_implementations("base.ts", Position::new(1, 13))
+        .expect("abstract member should resolve to concrete override");
+    assert_eq!(impls.len(), 1);
+    assert_eq!(impls[0].file_path, "leaf.ts");
+    assert_eq!(impls[0].range.start.line, 2);
+}
+
+#[test]
+fn test_project_subtypes_through_aliased_import() {
+    let mut project = Project::new();
+    project.set_file(
+        "base.ts".to_string(),
+        "export class Animal {}\n".to_string(),
+    );
+    project.set_file(
+        "bird.ts".to_string(),
+        "import { Animal as Creature } from './base';\nclass Bird extends Creature {}\n"
+            .to_string(),
+    );
+
+    let subtypes = project.subtypes("base.ts", Position::new(0, 13));
+    let names: Vec<&str> = subtypes.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["Bird"]);
+}
+
+#[test]
+fn test_project_supertypes_prefer_imported_declaration() {
+    // Two files declare `Shape`; the supertype must come from the module the
+    // subclass actually imports.
+    let mut project = Project::new();
+    project.set_file(
+        "a/shape.ts".to_string(),
+        "export class Shape {}\n".to_string(),
+    );
+    project.set_file(
+        "b/shape.ts".to_string(),
+        "export class Shape {}\n".to_string(),
+    );
+    project.set_file(
+        "b/circle.ts".to_string(),
+        "import { Shape } from './shape';\nclass Circle extends Shape {}\n".to_string(),
+    );
+
+    let supertypes = project.supertypes("b/circle.ts", Position::new(1, 6));
+    assert_eq!(supertypes.len(), 1);
+    assert_eq!(supertypes[0].name, "Shape");
+    assert_eq!(supertypes[0].uri, "b/shape.ts");
+}
+
 #[test]
 fn test_project_cross_file_subtypes() {
     let mut project = Project::new();
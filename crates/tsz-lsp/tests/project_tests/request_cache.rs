@@ -1,4 +1,5 @@
 use super::*;
+use rustc_hash::FxHashMap;
 
 #[test]
 fn test_project_update_file_refreshes_cross_file_references() {
@@ -257,6 +258,106 @@ fn test_project_diagnostics_cached() {
     assert_eq!(diagnostics_again.len(), diagnostics.len());
 }
 
+#[test]
+fn test_project_pull_diagnostics_report_unchanged_for_current_result_id() {
+    let mut project = Project::new();
+    project.set_file("a.ts".to_string(), "const value: string = 1;\n".to_string());
+
+    let first = project
+        .get_document_diagnostic_report("a.ts", None)
+        .expect("Expected a diagnostic report");
+    let DocumentDiagnosticReport::Full(full) = &first else {
+        panic!("First pull should be a full report, got {first:?}");
+    };
+    assert!(!full.items.is_empty(), "Should report diagnostics");
+    let result_id = first
+        .result_id()
+        .expect("Full report should carry a result ID");
+
+    let second = project
+        .get_document_diagnostic_report("a.ts", Some(result_id))
+        .expect("Expected a diagnostic report");
+    assert!(
+        matches!(second, DocumentDiagnosticReport::Unchanged(_)),
+        "Repeated pull with the current result ID should be unchanged, got {second:?}"
+    );
+    assert_eq!(second.result_id(), Some(result_id));
+
+    let stale = project
+        .get_document_diagnostic_report("a.ts", Some("stale"))
+        .expect("Expected a diagnostic report");
+    assert!(
+        matches!(stale, DocumentDiagnosticReport::Full(_)),
+        "An unknown result ID should get the full report"
+    );
+}
+
+#[test]
+fn test_project_pull_diagnostics_result_id_tracks_diagnostic_changes() {
+    let mut project = Project::new();
+    project.set_file("a.ts".to_string(), "const value: string = 1;\n".to_string());
+
+    let first = project
+        .get_document_diagnostic_report("a.ts", None)
+        .expect("Expected a diagnostic report");
+    let first_id = first.result_id().unwrap().to_string();
+
+    // A trailing comment re-checks the file but leaves its diagnostics alone.
+    project.set_file(
+        "a.ts".to_string(),
+        "const value: string = 1;\n// note\n".to_string(),
+    );
+    let same = project
+        .get_document_diagnostic_report("a.ts", Some(&first_id))
+        .expect("Expected a diagnostic report");
+    assert!(
+        matches!(same, DocumentDiagnosticReport::Unchanged(_)),
+        "Identical diagnostics should keep their result ID, got {same:?}"
+    );
+
+    project.set_file(
+        "a.ts".to_string(),
+        "const value: string = \"ok\";\n".to_string(),
+    );
+    let fixed = project
+        .get_document_diagnostic_report("a.ts", Some(&first_id))
+        .expect("Expected a diagnostic report");
+    let DocumentDiagnosticReport::Full(full) = &fixed else {
+        panic!("Changed diagnostics should produce a full report, got {fixed:?}");
+    };
+    assert!(full.items.is_empty());
+    assert_ne!(fixed.result_id(), Some(first_id.as_str()));
+}
+
+#[test]
+fn test_project_workspace_diagnostics_honor_previous_result_ids() {
+    let mut project = Project::new();
+    project.set_file("a.ts".to_string(), "const a: string = 1;\n".to_string());
+    project.set_file("b.ts".to_string(), "const b = 1;\n".to_string());
+
+    let initial = project.get_workspace_diagnostics(&FxHashMap::default());
+    assert_eq!(initial.items.len(), 2);
+    assert!(
+        initial
+            .items
+            .iter()
+            .all(|item| item.kind == DocumentDiagnosticReportKind::Full && item.result_id.is_some())
+    );
+
+    let previous: FxHashMap<String, String> = initial
+        .items
+        .iter()
+        .filter(|item| item.uri == "a.ts")
+        .map(|item| (item.uri.clone(), item.result_id.clone().unwrap()))
+        .collect();
+    let next = project.get_workspace_diagnostics(&previous);
+    let a = next.items.iter().find(|item| item.uri == "a.ts").unwrap();
+    let b = next.items.iter().find(|item| item.uri == "b.ts").unwrap();
+    assert_eq!(a.kind, DocumentDiagnosticReportKind::Unchanged);
+    assert!(a.items.is_none());
+    assert_eq!(b.kind, DocumentDiagnosticReportKind::Full);
+}
+
 #[test]
 fn test_project_performance_scope_cache_hits_definition() {
     let mut project = Project::new();
@@ -946,3 +946,80 @@ fn test_selection_range_multiple_variable_declarations() {
         "Should find selection for middle variable declaration"
     );
 }
+
+/// Texts of the selection chain at `pos`, innermost first.
+fn selection_chain_texts(file_name: &str, source: &str, pos: Position) -> Vec<String> {
+    let mut parser = tsz_parser::ParserState::new(file_name.to_string(), source.to_string());
+    parser.parse_source_file();
+    let line_map = LineMap::build(source);
+    let provider = SelectionRangeProvider::new(parser.get_arena(), &line_map, source);
+
+    let mut texts = Vec::new();
+    let mut current = provider.get_selection_range(pos);
+    while let Some(sel) = current {
+        let start = line_map
+            .position_to_offset(sel.range.start, source)
+            .unwrap() as usize;
+        let end = line_map.position_to_offset(sel.range.end, source).unwrap() as usize;
+        texts.push(source[start..end].to_string());
+        current = sel.parent.map(|parent| *parent);
+    }
+    texts
+}
+
+fn assert_consecutive_stops(texts: &[String], expected: &[&str]) {
+    assert!(
+        texts
+            .windows(expected.len())
+            .any(|window| window.iter().zip(expected).all(|(a, b)| a == b)),
+        "Expected consecutive stops {expected:?} in {texts:?}"
+    );
+}
+
+#[test]
+fn test_selection_range_argument_list_stop() {
+    let source = "foo(a, bb, c);";
+    let texts = selection_chain_texts("test.ts", source, Position::new(0, 8));
+
+    assert_consecutive_stops(&texts, &["bb", "a, bb, c", "foo(a, bb, c)"]);
+}
+
+#[test]
+fn test_selection_range_parameter_list_stop() {
+    let source = "function f(x: number, y: string) {}";
+    let texts = selection_chain_texts("test.ts", source, Position::new(0, 22));
+
+    assert_consecutive_stops(&texts, &["y", "y: string", "x: number, y: string", source]);
+}
+
+#[test]
+fn test_selection_range_template_substitution_stop() {
+    let source = "const msg = `hello ${name} world`;";
+    let texts = selection_chain_texts("test.ts", source, Position::new(0, 22));
+
+    assert_consecutive_stops(&texts, &["name", "${name}", "`hello ${name} world`"]);
+}
+
+#[test]
+fn test_selection_range_string_contents_stop() {
+    let source = "const s = \"abc\";";
+    let texts = selection_chain_texts("test.ts", source, Position::new(0, 12));
+
+    assert_consecutive_stops(&texts, &["abc", "\"abc\""]);
+}
+
+#[test]
+fn test_selection_range_jsx_attribute_stops() {
+    let source = "const el = <div id=\"a\" title={t} />;";
+    let texts = selection_chain_texts("test.tsx", source, Position::new(0, 24));
+
+    assert_consecutive_stops(
+        &texts,
+        &[
+            "title",
+            "title={t}",
+            "id=\"a\" title={t}",
+            "<div id=\"a\" title={t} />",
+        ],
+    );
+}
@@ -0,0 +1,65 @@
+use super::*;
+use crate::project::Project;
+
+fn entries_for(source: &str) -> Vec<TypeWriterEntry> {
+    let mut project = Project::new();
+    project.set_file("test.ts".to_string(), source.to_string());
+    project
+        .get_type_writer_entries("test.ts")
+        .expect("file should be loaded")
+}
+
+fn entry<'e>(entries: &'e [TypeWriterEntry], text: &str) -> &'e TypeWriterEntry {
+    entries
+        .iter()
+        .find(|entry| entry.source_text == text)
+        .unwrap_or_else(|| panic!("no entry for `{text}` in {entries:?}"))
+}
+
+#[test]
+fn reports_declaration_names_and_literals() {
+    let entries = entries_for("const x = 1;\n");
+
+    let name = entry(&entries, "x");
+    assert_eq!(name.line, 0);
+    assert_eq!(
+        name.symbol_text.as_deref(),
+        Some("Symbol(x, Decl(test.ts, 0, 5))")
+    );
+    assert_eq!(name.type_text.as_deref(), Some("1"));
+
+    let literal = entry(&entries, "1");
+    assert_eq!(literal.type_text.as_deref(), Some("1"));
+    assert_eq!(literal.symbol_text, None);
+}
+
+#[test]
+fn entries_follow_source_order() {
+    let entries = entries_for("let a = 1;\nlet b = a;\n");
+    let texts: Vec<&str> = entries
+        .iter()
+        .map(|entry| entry.source_text.as_str())
+        .collect();
+    assert_eq!(texts, ["a", "1", "b", "a"]);
+    assert_eq!(entries[3].line, 1);
+    assert_eq!(entries[3].symbol_text, entries[0].symbol_text);
+}
+
+#[test]
+fn type_only_names_have_symbols_but_no_types() {
+    let entries = entries_for("interface I { p: string }\nlet v: I;\n");
+
+    let interface_name = &entries[0];
+    assert_eq!(interface_name.source_text, "I");
+    assert_eq!(interface_name.type_text, None);
+    assert!(interface_name.symbol_text.is_some());
+
+    // `I` in the annotation is part of a type node.
+    let annotation = entries
+        .iter()
+        .rfind(|entry| entry.source_text == "I")
+        .expect("annotation entry");
+    assert_eq!(annotation.line, 1);
+    assert_eq!(annotation.type_text, None);
+    assert_eq!(annotation.symbol_text, interface_name.symbol_text);
+}
@@ -161,7 +161,7 @@ fn test_relevance_sorting() {
 fn test_max_results_limit() {
     let mut index = SymbolIndex::new();
 
-    // Add more than MAX_RESULTS (100) symbols
+    // Add more than DEFAULT_MAX_RESULTS (100) symbols
     for i in 0..150 {
         let name = format!("symbol_{i}");
         index.add_definition(&name, make_location("test.ts", i, 0, 10));
@@ -172,11 +172,58 @@ fn test_max_results_limit() {
 
     assert_eq!(
         results.len(),
-        MAX_RESULTS,
-        "Should limit to {MAX_RESULTS} results"
+        DEFAULT_MAX_RESULTS,
+        "Should limit to {DEFAULT_MAX_RESULTS} results"
     );
 }
 
+#[test]
+fn test_custom_max_results_limit() {
+    let mut index = SymbolIndex::new();
+    for i in 0..20 {
+        let name = format!("symbol_{i}");
+        index.add_definition(&name, make_location("test.ts", i, 0, 10));
+    }
+
+    let provider = WorkspaceSymbolsProvider::new(&index).with_max_results(5);
+    assert_eq!(provider.find_symbols("symbol").len(), 5);
+
+    let provider = WorkspaceSymbolsProvider::new(&index).with_max_results(0);
+    assert_eq!(
+        provider.find_symbols("symbol").len(),
+        20,
+        "a zero limit should fall back to the default cap"
+    );
+}
+
+#[test]
+fn test_same_name_reports_per_file_kind_after_removal() {
+    let mut index = SymbolIndex::new();
+    index.add_definition_with_kind("Foo", make_location("a.ts", 0, 0, 3), SymbolKind::Class);
+    index.add_definition_with_kind("Foo", make_location("b.ts", 0, 0, 3), SymbolKind::Function);
+
+    let provider = WorkspaceSymbolsProvider::new(&index);
+    let mut kinds: Vec<(String, SymbolKind)> = provider
+        .find_symbols("Foo")
+        .into_iter()
+        .map(|sym| (sym.location.file_path, sym.kind))
+        .collect();
+    kinds.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        kinds,
+        vec![
+            ("a.ts".to_string(), SymbolKind::Class),
+            ("b.ts".to_string(), SymbolKind::Function),
+        ]
+    );
+
+    index.remove_file("b.ts");
+    assert_eq!(index.get_definition_kind("Foo"), Some(SymbolKind::Class));
+    let results = WorkspaceSymbolsProvider::new(&index).find_symbols("Foo");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].kind, SymbolKind::Class);
+}
+
 #[test]
 fn test_infer_symbol_kind_class() {
     assert_eq!(
@@ -136,3 +136,16 @@ pub mod transform_flags {
 
     pub const HAS_COMPUTED_FLAGS: u32 = 2_147_483_648; // 1 << 31
 }
+
+/// Values of `SourceFileData::script_kind`.
+/// Matches TypeScript's `ScriptKind` enum.
+pub mod script_kind {
+    pub const UNKNOWN: u32 = 0;
+    pub const JS: u32 = 1;
+    pub const JSX: u32 = 2;
+    pub const TS: u32 = 3;
+    pub const TSX: u32 = 4;
+    pub const EXTERNAL: u32 = 5;
+    pub const JSON: u32 = 6;
+    pub const DEFERRED: u32 = 7;
+}
@@ -0,0 +1,203 @@
+//! Import attributes of static and dynamic imports.
+//!
+//! `import x from "./x.json" with { type: "json" }` (and the legacy
+//! `assert { ... }` form, also on `export ... from`) is parsed into an
+//! `ImportAttributes` node holding one `ImportAttribute` node per entry.
+//! A dynamic `import("./x.json", { with: { type: "json" } })` keeps its
+//! options as an ordinary object literal argument, as in tsc, so that the
+//! call is checked like any other. [`import_attributes_of`] reads both into
+//! one [`ImportAttributesClause`], so validation and emit can treat them
+//! alike.
+
+use super::NodeIndex;
+use super::node::NodeArena;
+use super::syntax_kind_ext;
+use tsz_scanner::SyntaxKind;
+
+/// The keyword introducing an attributes clause.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImportAttributesKeyword {
+    /// `with { ... }`, or a `with` key in dynamic import options.
+    With,
+    /// The deprecated `assert { ... }`, or an `assert` key in dynamic
+    /// import options.
+    Assert,
+}
+
+/// The attributes of one import or export.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportAttributesClause {
+    /// The `ImportAttributes` node, or the object literal that holds the
+    /// attributes of a dynamic import.
+    pub node: NodeIndex,
+    pub keyword: ImportAttributesKeyword,
+    /// Span of the clause: from the keyword to the closing brace for a
+    /// declaration, the object literal for a dynamic import.
+    pub pos: u32,
+    pub end: u32,
+    pub attributes: Vec<ImportAttributeEntry>,
+}
+
+/// One `name: value` entry of an attributes clause.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportAttributeEntry {
+    /// The `ImportAttribute` or `PropertyAssignment` node.
+    pub node: NodeIndex,
+    /// The identifier or string literal key.
+    pub name: NodeIndex,
+    /// The key's text, without quotes.
+    pub name_text: String,
+    pub value: NodeIndex,
+    pub pos: u32,
+    pub end: u32,
+}
+
+impl ImportAttributesClause {
+    /// The entry with the given key, e.g. `"type"` or `"resolution-mode"`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&ImportAttributeEntry> {
+        self.attributes.iter().find(|entry| entry.name_text == name)
+    }
+}
+
+/// The attributes clause of `node`: an import or export declaration, an
+/// `ImportAttributes` node, or a dynamic `import(...)` /
+/// `import.defer(...)` call. `None` when it has no attributes.
+#[must_use]
+pub fn import_attributes_of(arena: &NodeArena, node: NodeIndex) -> Option<ImportAttributesClause> {
+    let n = arena.get(node)?;
+    match n.kind {
+        syntax_kind_ext::IMPORT_DECLARATION => {
+            import_attributes_of(arena, arena.get_import_decl(n)?.attributes)
+        }
+        syntax_kind_ext::EXPORT_DECLARATION => {
+            import_attributes_of(arena, arena.get_export_decl(n)?.attributes)
+        }
+        syntax_kind_ext::IMPORT_ATTRIBUTES => {
+            let data = arena.get_import_attributes_data(n)?;
+            let keyword = if data.token == SyntaxKind::AssertKeyword as u16 {
+                ImportAttributesKeyword::Assert
+            } else {
+                ImportAttributesKeyword::With
+            };
+            let attributes = data
+                .elements
+                .nodes
+                .iter()
+                .filter_map(|&element| {
+                    let element_node = arena.get(element)?;
+                    let attribute = arena.get_import_attribute_data(element_node)?;
+                    entry(arena, element, attribute.name, attribute.value)
+                })
+                .collect();
+            Some(ImportAttributesClause {
+                node,
+                keyword,
+                pos: n.pos,
+                end: n.end,
+                attributes,
+            })
+        }
+        syntax_kind_ext::CALL_EXPRESSION => dynamic_import_attributes(arena, node),
+        _ => None,
+    }
+}
+
+/// Whether `call` is `import(...)` or `import.defer(...)`.
+#[must_use]
+pub fn is_dynamic_import_call(arena: &NodeArena, call: NodeIndex) -> bool {
+    let Some(callee) = arena
+        .get(call)
+        .and_then(|node| arena.get_call_expr(node))
+        .and_then(|call| arena.get(call.expression))
+    else {
+        return false;
+    };
+    if callee.kind == SyntaxKind::ImportKeyword as u16 {
+        return true;
+    }
+    arena.get_access_expr(callee).is_some_and(|access| {
+        arena
+            .get(access.expression)
+            .is_some_and(|base| base.kind == SyntaxKind::ImportKeyword as u16)
+            && arena
+                .get_identifier_at(access.name_or_argument)
+                .is_some_and(|ident| ident.escaped_text == "defer")
+    })
+}
+
+/// The `with` (or `assert`) object of a dynamic import's options argument.
+fn dynamic_import_attributes(arena: &NodeArena, call: NodeIndex) -> Option<ImportAttributesClause> {
+    if !is_dynamic_import_call(arena, call) {
+        return None;
+    }
+    let call_data = arena.get_call_expr(arena.get(call)?)?;
+    let &options = call_data.arguments.as_ref()?.nodes.get(1)?;
+    let (keyword, object) =
+        object_properties(arena, options)?.find_map(|(_, name, value)| match name.as_str() {
+            "with" => Some((ImportAttributesKeyword::With, value)),
+            "assert" => Some((ImportAttributesKeyword::Assert, value)),
+            _ => None,
+        })?;
+    let object_node = arena.get(object)?;
+    let attributes = object_properties(arena, object)?
+        .filter_map(|(property, _, value)| {
+            let name = arena.get_property_assignment(arena.get(property)?)?.name;
+            entry(arena, property, name, value)
+        })
+        .collect();
+    Some(ImportAttributesClause {
+        node: object,
+        keyword,
+        pos: object_node.pos,
+        end: object_node.end,
+        attributes,
+    })
+}
+
+/// `(property, key text, initializer)` of the `key: value` properties of an
+/// object literal.
+fn object_properties(
+    arena: &NodeArena,
+    object: NodeIndex,
+) -> Option<impl Iterator<Item = (NodeIndex, String, NodeIndex)> + '_> {
+    let object_node = arena.get(object)?;
+    if object_node.kind != syntax_kind_ext::OBJECT_LITERAL_EXPRESSION {
+        return None;
+    }
+    let properties = &arena.get_literal_expr(object_node)?.elements.nodes;
+    Some(properties.iter().filter_map(move |&property| {
+        let assignment = arena.get_property_assignment(arena.get(property)?)?;
+        let name = key_text(arena, assignment.name)?;
+        Some((property, name, assignment.initializer))
+    }))
+}
+
+fn entry(
+    arena: &NodeArena,
+    node: NodeIndex,
+    name: NodeIndex,
+    value: NodeIndex,
+) -> Option<ImportAttributeEntry> {
+    let n = arena.get(node)?;
+    Some(ImportAttributeEntry {
+        node,
+        name,
+        name_text: key_text(arena, name)?,
+        value,
+        pos: n.pos,
+        end: n.end,
+    })
+}
+
+/// Text of an identifier or string literal key.
+fn key_text(arena: &NodeArena, name: NodeIndex) -> Option<String> {
+    let node = arena.get(name)?;
+    if let Some(ident) = arena.get_identifier(node) {
+        return Some(ident.escaped_text.clone());
+    }
+    if node.kind == SyntaxKind::StringLiteral as u16 {
+        return arena.get_literal(node).map(|literal| literal.text.clone());
+    }
+    None
+}
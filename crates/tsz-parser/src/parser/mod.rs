@@ -17,6 +17,7 @@
 
 pub mod base;
 pub mod flags;
+pub mod import_attributes;
 pub mod incomplete_call;
 pub mod node;
 mod node_access;
@@ -32,6 +33,7 @@ pub mod state;
 mod state_declarations;
 mod state_declarations_exports;
 mod state_declarations_modules;
+mod state_deferred;
 mod state_diagnostics;
 mod state_exports_recovery;
 mod state_expressions;
@@ -40,6 +42,8 @@ mod state_expressions_literals;
 mod state_expressions_literals_regex;
 mod state_expressions_tail;
 mod state_import_attributes;
+mod state_incremental;
+mod state_json;
 mod state_recovery_helpers;
 mod state_statements;
 mod state_statements_class;
@@ -54,6 +58,7 @@ mod state_types_advanced;
 mod state_types_jsx;
 mod state_types_jsx_elements;
 mod state_variable_declarations;
+pub mod trivia;
 
 #[cfg(test)]
 #[path = "../../tests/test_fixture.rs"]
@@ -214,6 +219,29 @@ mod parser_unit_tests;
 #[path = "../../tests/incremental_parse_interner_tests.rs"]
 mod incremental_parse_interner_tests;
 
+#[cfg(test)]
+#[path = "../../tests/incremental_update_tests.rs"]
+mod incremental_update_tests;
+
+#[cfg(test)]
+#[path = "../../tests/trivia_tests.rs"]
+mod trivia_tests;
+
+#[cfg(test)]
+#[path = "../../tests/ide_recovery_tests.rs"]
+mod ide_recovery_tests;
+
+#[cfg(test)]
+#[path = "../../tests/import_attributes_tests.rs"]
+mod import_attributes_tests;
+
+#[cfg(test)]
+#[path = "../../tests/deferred_function_bodies_tests.rs"]
+mod deferred_function_bodies_tests;
+#[cfg(test)]
+#[path = "../../tests/json_parse_tests.rs"]
+mod json_parse_tests;
+
 #[cfg(test)]
 #[path = "../../tests/node_modifiers_tests.rs"]
 mod node_modifiers_tests;
@@ -235,7 +263,7 @@ mod computed_property_binding_recovery_tests;
 mod reserved_parameter_recovery_tests;
 
 // Re-export flags
-pub use flags::{modifier_flags, node_flags, transform_flags};
+pub use flags::{modifier_flags, node_flags, script_kind, transform_flags};
 
 // Re-export base types used throughout the thin pipeline
 pub use base::{NodeIndex, NodeList, TextRange};
@@ -244,7 +272,8 @@ pub use base::{NodeIndex, NodeList, TextRange};
 pub use node::NodeArena;
 
 // Parser implementation (NodeArena-based).
-pub use state::{ParseDiagnostic, ParserState};
+pub use state::{JsonParseOptions, ParseDiagnostic, ParserState};
+pub use state_incremental::TextChange;
 
 /// Extended `SyntaxKind` values for AST nodes that are not tokens.
 /// These match TypeScript's `SyntaxKind` enum values exactly.
@@ -421,4 +450,166 @@ pub mod syntax_kind_ext {
     pub const BUNDLE: u16 = 309;
 
     // First JSDoc node (310) ... we'll add these as needed
+
+    /// TypeScript's `SyntaxKind` name for a token or node kind, or
+    /// `"Unknown"`.
+    #[must_use]
+    pub fn syntax_kind_name(kind: u16) -> String {
+        tsz_scanner::SyntaxKind::try_from_u16(kind).map_or_else(
+            || node_kind_name(kind).unwrap_or("Unknown").to_string(),
+            |token| format!("{token:?}"),
+        )
+    }
+
+    /// TypeScript's `SyntaxKind` name for an AST node kind, or `None` for
+    /// token kinds (use `SyntaxKind`'s `Debug` name for those).
+    #[must_use]
+    pub const fn node_kind_name(kind: u16) -> Option<&'static str> {
+        Some(match kind {
+            QUALIFIED_NAME => "QualifiedName",
+            COMPUTED_PROPERTY_NAME => "ComputedPropertyName",
+            TYPE_PARAMETER => "TypeParameter",
+            PARAMETER => "Parameter",
+            DECORATOR => "Decorator",
+            PROPERTY_SIGNATURE => "PropertySignature",
+            PROPERTY_DECLARATION => "PropertyDeclaration",
+            METHOD_SIGNATURE => "MethodSignature",
+            METHOD_DECLARATION => "MethodDeclaration",
+            CLASS_STATIC_BLOCK_DECLARATION => "ClassStaticBlockDeclaration",
+            CONSTRUCTOR => "Constructor",
+            GET_ACCESSOR => "GetAccessor",
+            SET_ACCESSOR => "SetAccessor",
+            CALL_SIGNATURE => "CallSignature",
+            CONSTRUCT_SIGNATURE => "ConstructSignature",
+            INDEX_SIGNATURE => "IndexSignature",
+            TYPE_PREDICATE => "TypePredicate",
+            TYPE_REFERENCE => "TypeReference",
+            FUNCTION_TYPE => "FunctionType",
+            CONSTRUCTOR_TYPE => "ConstructorType",
+            TYPE_QUERY => "TypeQuery",
+            TYPE_LITERAL => "TypeLiteral",
+            ARRAY_TYPE => "ArrayType",
+            TUPLE_TYPE => "TupleType",
+            OPTIONAL_TYPE => "OptionalType",
+            REST_TYPE => "RestType",
+            UNION_TYPE => "UnionType",
+            INTERSECTION_TYPE => "IntersectionType",
+            CONDITIONAL_TYPE => "ConditionalType",
+            INFER_TYPE => "InferType",
+            PARENTHESIZED_TYPE => "ParenthesizedType",
+            THIS_TYPE => "ThisType",
+            TYPE_OPERATOR => "TypeOperator",
+            INDEXED_ACCESS_TYPE => "IndexedAccessType",
+            MAPPED_TYPE => "MappedType",
+            LITERAL_TYPE => "LiteralType",
+            NAMED_TUPLE_MEMBER => "NamedTupleMember",
+            TEMPLATE_LITERAL_TYPE => "TemplateLiteralType",
+            TEMPLATE_LITERAL_TYPE_SPAN => "TemplateLiteralTypeSpan",
+            IMPORT_TYPE => "ImportType",
+            OBJECT_BINDING_PATTERN => "ObjectBindingPattern",
+            ARRAY_BINDING_PATTERN => "ArrayBindingPattern",
+            BINDING_ELEMENT => "BindingElement",
+            ARRAY_LITERAL_EXPRESSION => "ArrayLiteralExpression",
+            OBJECT_LITERAL_EXPRESSION => "ObjectLiteralExpression",
+            PROPERTY_ACCESS_EXPRESSION => "PropertyAccessExpression",
+            ELEMENT_ACCESS_EXPRESSION => "ElementAccessExpression",
+            CALL_EXPRESSION => "CallExpression",
+            NEW_EXPRESSION => "NewExpression",
+            TAGGED_TEMPLATE_EXPRESSION => "TaggedTemplateExpression",
+            TYPE_ASSERTION => "TypeAssertion",
+            PARENTHESIZED_EXPRESSION => "ParenthesizedExpression",
+            FUNCTION_EXPRESSION => "FunctionExpression",
+            ARROW_FUNCTION => "ArrowFunction",
+            DELETE_EXPRESSION => "DeleteExpression",
+            TYPE_OF_EXPRESSION => "TypeOfExpression",
+            VOID_EXPRESSION => "VoidExpression",
+            AWAIT_EXPRESSION => "AwaitExpression",
+            PREFIX_UNARY_EXPRESSION => "PrefixUnaryExpression",
+            POSTFIX_UNARY_EXPRESSION => "PostfixUnaryExpression",
+            BINARY_EXPRESSION => "BinaryExpression",
+            CONDITIONAL_EXPRESSION => "ConditionalExpression",
+            TEMPLATE_EXPRESSION => "TemplateExpression",
+            YIELD_EXPRESSION => "YieldExpression",
+            SPREAD_ELEMENT => "SpreadElement",
+            CLASS_EXPRESSION => "ClassExpression",
+            OMITTED_EXPRESSION => "OmittedExpression",
+            EXPRESSION_WITH_TYPE_ARGUMENTS => "ExpressionWithTypeArguments",
+            AS_EXPRESSION => "AsExpression",
+            NON_NULL_EXPRESSION => "NonNullExpression",
+            META_PROPERTY => "MetaProperty",
+            SYNTHETIC_EXPRESSION => "SyntheticExpression",
+            SATISFIES_EXPRESSION => "SatisfiesExpression",
+            TEMPLATE_SPAN => "TemplateSpan",
+            SEMICOLON_CLASS_ELEMENT => "SemicolonClassElement",
+            BLOCK => "Block",
+            EMPTY_STATEMENT => "EmptyStatement",
+            VARIABLE_STATEMENT => "VariableStatement",
+            EXPRESSION_STATEMENT => "ExpressionStatement",
+            IF_STATEMENT => "IfStatement",
+            DO_STATEMENT => "DoStatement",
+            WHILE_STATEMENT => "WhileStatement",
+            FOR_STATEMENT => "ForStatement",
+            FOR_IN_STATEMENT => "ForInStatement",
+            FOR_OF_STATEMENT => "ForOfStatement",
+            CONTINUE_STATEMENT => "ContinueStatement",
+            BREAK_STATEMENT => "BreakStatement",
+            RETURN_STATEMENT => "ReturnStatement",
+            WITH_STATEMENT => "WithStatement",
+            SWITCH_STATEMENT => "SwitchStatement",
+            LABELED_STATEMENT => "LabeledStatement",
+            THROW_STATEMENT => "ThrowStatement",
+            TRY_STATEMENT => "TryStatement",
+            DEBUGGER_STATEMENT => "DebuggerStatement",
+            VARIABLE_DECLARATION => "VariableDeclaration",
+            VARIABLE_DECLARATION_LIST => "VariableDeclarationList",
+            FUNCTION_DECLARATION => "FunctionDeclaration",
+            CLASS_DECLARATION => "ClassDeclaration",
+            INTERFACE_DECLARATION => "InterfaceDeclaration",
+            TYPE_ALIAS_DECLARATION => "TypeAliasDeclaration",
+            ENUM_DECLARATION => "EnumDeclaration",
+            MODULE_DECLARATION => "ModuleDeclaration",
+            MODULE_BLOCK => "ModuleBlock",
+            CASE_BLOCK => "CaseBlock",
+            NAMESPACE_EXPORT_DECLARATION => "NamespaceExportDeclaration",
+            IMPORT_EQUALS_DECLARATION => "ImportEqualsDeclaration",
+            IMPORT_DECLARATION => "ImportDeclaration",
+            IMPORT_CLAUSE => "ImportClause",
+            NAMESPACE_IMPORT => "NamespaceImport",
+            NAMED_IMPORTS => "NamedImports",
+            IMPORT_SPECIFIER => "ImportSpecifier",
+            EXPORT_ASSIGNMENT => "ExportAssignment",
+            EXPORT_DECLARATION => "ExportDeclaration",
+            NAMED_EXPORTS => "NamedExports",
+            NAMESPACE_EXPORT => "NamespaceExport",
+            EXPORT_SPECIFIER => "ExportSpecifier",
+            MISSING_DECLARATION => "MissingDeclaration",
+            EXTERNAL_MODULE_REFERENCE => "ExternalModuleReference",
+            JSX_ELEMENT => "JsxElement",
+            JSX_SELF_CLOSING_ELEMENT => "JsxSelfClosingElement",
+            JSX_OPENING_ELEMENT => "JsxOpeningElement",
+            JSX_CLOSING_ELEMENT => "JsxClosingElement",
+            JSX_FRAGMENT => "JsxFragment",
+            JSX_OPENING_FRAGMENT => "JsxOpeningFragment",
+            JSX_CLOSING_FRAGMENT => "JsxClosingFragment",
+            JSX_ATTRIBUTE => "JsxAttribute",
+            JSX_ATTRIBUTES => "JsxAttributes",
+            JSX_SPREAD_ATTRIBUTE => "JsxSpreadAttribute",
+            JSX_EXPRESSION => "JsxExpression",
+            JSX_NAMESPACED_NAME => "JsxNamespacedName",
+            CASE_CLAUSE => "CaseClause",
+            DEFAULT_CLAUSE => "DefaultClause",
+            HERITAGE_CLAUSE => "HeritageClause",
+            CATCH_CLAUSE => "CatchClause",
+            IMPORT_ATTRIBUTES => "ImportAttributes",
+            IMPORT_ATTRIBUTE => "ImportAttribute",
+            PROPERTY_ASSIGNMENT => "PropertyAssignment",
+            SHORTHAND_PROPERTY_ASSIGNMENT => "ShorthandPropertyAssignment",
+            SPREAD_ASSIGNMENT => "SpreadAssignment",
+            ENUM_MEMBER => "EnumMember",
+            UNPARSED_PROLOGUE => "UnparsedPrologue",
+            SOURCE_FILE => "SourceFile",
+            BUNDLE => "Bundle",
+            _ => return None,
+        })
+    }
 }
@@ -1341,6 +1341,7 @@ impl Default for ExtendedNodeInfo {
 }
 
 // Re-export types from node_view module
+pub use super::node_children::VisitControl;
 pub use super::node_view::{NodeAccess, NodeInfo, NodeView};
 
 #[cfg(test)]
@@ -5,6 +5,11 @@
 //! any AST node. Each collector handles a category of syntax kinds (names,
 //! expressions, statements, declarations, imports/exports, types, members,
 //! patterns, JSX, signatures, source files).
+//!
+//! On top of them, [`NodeArena::for_each_child`],
+//! [`NodeArena::find_map_child`] and [`NodeArena::for_each_descendant`] are the
+//! public way to traverse a tree, so tools outside the parser do not have to
+//! match on node kinds to find children.
 
 use super::base::{NodeIndex, NodeList};
 use super::node::{Node, NodeArena};
@@ -847,4 +852,93 @@ impl NodeArena {
         }
         false
     }
+
+    /// Append the children of `node` to `children`.
+    pub(crate) fn collect_children(&self, node: &Node, children: &mut Vec<NodeIndex>) {
+        let _ = self.collect_name_children(node, children)
+            || self.collect_expression_children(node, children)
+            || self.collect_statement_children(node, children)
+            || self.collect_declaration_children(node, children)
+            || self.collect_import_export_children(node, children)
+            || self.collect_type_children(node, children)
+            || self.collect_member_children(node, children)
+            || self.collect_pattern_children(node, children)
+            || self.collect_jsx_children(node, children)
+            || self.collect_signature_children(node, children)
+            || self.collect_source_children(node, children);
+    }
+
+    /// Call `f` with each child of `node`, in the order of
+    /// [`NodeAccess::get_children`](super::node::NodeAccess::get_children).
+    /// Absent optional children are skipped.
+    pub fn for_each_child(&self, node: NodeIndex, mut f: impl FnMut(NodeIndex)) {
+        let _ = self.find_map_child(node, |child| {
+            f(child);
+            None::<()>
+        });
+    }
+
+    /// The first `Some` that `f` returns for a child of `node`, like tsc's
+    /// `forEachChild`. The remaining children are not visited.
+    pub fn find_map_child<T>(
+        &self,
+        node: NodeIndex,
+        f: impl FnMut(NodeIndex) -> Option<T>,
+    ) -> Option<T> {
+        let node = self.get(node)?;
+        let mut children = Vec::new();
+        self.collect_children(node, &mut children);
+        children
+            .into_iter()
+            .filter(|child| child.is_some())
+            .find_map(f)
+    }
+
+    /// Visit `root` and its descendants in pre-order. `f` decides after each
+    /// node whether to descend into it, skip its subtree or stop the walk.
+    pub fn for_each_descendant(
+        &self,
+        root: NodeIndex,
+        mut f: impl FnMut(NodeIndex, &Node) -> VisitControl,
+    ) {
+        let mut pending = vec![root];
+        let mut children = Vec::new();
+        while let Some(idx) = pending.pop() {
+            let Some(node) = self.get(idx) else {
+                continue;
+            };
+            match f(idx, node) {
+                VisitControl::Continue => {}
+                VisitControl::SkipChildren => continue,
+                VisitControl::Stop => return,
+            }
+            children.clear();
+            self.collect_children(node, &mut children);
+            pending.extend(children.iter().rev().filter(|child| child.is_some()));
+        }
+    }
+
+    /// Whether `index` is a node of the given kind.
+    #[must_use]
+    pub fn is_kind(&self, index: NodeIndex, kind: u16) -> bool {
+        self.get(index).is_some_and(|node| node.kind == kind)
+    }
+
+    /// Whether `index` is a node that `predicate` accepts, e.g.
+    /// `arena.node_is(idx, Node::is_function_like)`.
+    #[must_use]
+    pub fn node_is(&self, index: NodeIndex, predicate: impl FnOnce(&Node) -> bool) -> bool {
+        self.get(index).is_some_and(predicate)
+    }
+}
+
+/// What [`NodeArena::for_each_descendant`] does after visiting a node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VisitControl {
+    /// Go on into the node's children.
+    Continue,
+    /// Skip the node's descendants and go on with the rest of the tree.
+    SkipChildren,
+    /// End the walk.
+    Stop,
 }
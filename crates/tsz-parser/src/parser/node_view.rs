@@ -265,22 +265,7 @@ impl NodeAccess for NodeArena {
         };
 
         let mut children = Vec::new();
-
-        if self.collect_name_children(node, &mut children)
-            || self.collect_expression_children(node, &mut children)
-            || self.collect_statement_children(node, &mut children)
-            || self.collect_declaration_children(node, &mut children)
-            || self.collect_import_export_children(node, &mut children)
-            || self.collect_type_children(node, &mut children)
-            || self.collect_member_children(node, &mut children)
-            || self.collect_pattern_children(node, &mut children)
-            || self.collect_jsx_children(node, &mut children)
-            || self.collect_signature_children(node, &mut children)
-            || self.collect_source_children(node, &mut children)
-        {
-            return children;
-        }
-
+        self.collect_children(node, &mut children);
         children
     }
 }
@@ -18,6 +18,7 @@ use tsz_common::limits::MAX_PARSER_RECURSION_DEPTH;
 
 use std::cell::Cell;
 
+use super::state_deferred::DeferredFunctionBody;
 use crate::parser::{
     NodeIndex, NodeList,
     node::{IdentifierData, NodeArena},
@@ -124,6 +125,37 @@ pub struct IncrementalParseResult {
     pub reparse_start: u32,
 }
 
+/// What [`ParserState::parse_json_text`] tolerates beyond strict JSON.
+///
+/// tsc accepts comments and trailing commas in every JSON file (tsconfig
+/// files are JSONC), which is the default. [`Self::STRICT`] reports them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JsonParseOptions {
+    /// Allow `//` and `/* */` comments.
+    pub allow_comments: bool,
+    /// Allow a comma after the last element of an object or array.
+    pub allow_trailing_commas: bool,
+}
+
+impl JsonParseOptions {
+    /// JSON with comments and trailing commas, as tsc reads every JSON file.
+    pub const JSONC: Self = Self {
+        allow_comments: true,
+        allow_trailing_commas: true,
+    };
+    /// RFC 8259 JSON.
+    pub const STRICT: Self = Self {
+        allow_comments: false,
+        allow_trailing_commas: false,
+    };
+}
+
+impl Default for JsonParseOptions {
+    fn default() -> Self {
+        Self::JSONC
+    }
+}
+
 // =============================================================================
 // ParserState
 // =============================================================================
@@ -304,6 +336,18 @@ pub struct ParserState {
     /// Set when the current function declaration parameter list yielded a hard
     /// reserved keyword back to the statement parser.
     pub(crate) reserved_parameter_yielded_to_statement: bool,
+    /// Sorted positions in the new text where an incremental update may stop
+    /// parsing top-level statements and reuse the old ones. Empty outside
+    /// [`ParserState::update`].
+    pub(crate) incremental_sync_points: Vec<u32>,
+    /// Shape incomplete expressions for editor features; see
+    /// [`ParserState::set_ide_recovery`].
+    pub(crate) ide_recovery: bool,
+    /// Skip function bodies and parse them on demand; see
+    /// [`ParserState::set_defer_function_bodies`].
+    pub(crate) defer_function_bodies: bool,
+    /// Skipped function bodies not parsed yet, by their empty `Block` node.
+    pub(crate) deferred_function_bodies: FxHashMap<NodeIndex, DeferredFunctionBody>,
 }
 
 impl ParserState {
@@ -410,6 +454,10 @@ impl ParserState {
             namespace_import_yielded_to_statement: false,
             recover_reserved_parameter_as_statement_tail_allowed: false,
             reserved_parameter_yielded_to_statement: false,
+            incremental_sync_points: Vec::new(),
+            ide_recovery: true,
+            defer_function_bodies: false,
+            deferred_function_bodies: FxHashMap::default(),
         }
     }
 
@@ -464,6 +512,8 @@ impl ParserState {
         self.namespace_import_yielded_to_statement = false;
         self.recover_reserved_parameter_as_statement_tail_allowed = false;
         self.reserved_parameter_yielded_to_statement = false;
+        self.incremental_sync_points.clear();
+        self.deferred_function_bodies.clear();
         // The high-water mark tracks the count of scanner diagnostics that
         // have been considered by the parser-side dedup at `parse_error_at`.
         // When the parser is reused via `reset()` the caller passes a fresh
@@ -642,8 +642,12 @@ impl ParserState {
     /// Parse optional import attributes: `with { type: "json" }` or `assert { type: "json" }`
     /// Returns `NodeIndex::NONE` if no attributes are present.
     pub(crate) fn parse_import_attributes(&mut self) -> NodeIndex {
-        // Check for 'with' or 'assert' keyword (not on a new line to avoid ASI issues)
-        if !self.is_token(SyntaxKind::WithKeyword) && !self.is_token(SyntaxKind::AssertKeyword) {
+        // Check for 'with' or 'assert' keyword. `assert` on a new line starts
+        // the next statement (e.g. a call to an `assert` function) via ASI.
+        let is_attributes_keyword = self.is_token(SyntaxKind::WithKeyword)
+            || (self.is_token(SyntaxKind::AssertKeyword)
+                && !self.scanner.has_preceding_line_break());
+        if !is_attributes_keyword {
             return NodeIndex::NONE;
         }
 
@@ -657,6 +661,7 @@ impl ParserState {
         }
 
         self.parse_expected(SyntaxKind::OpenBraceToken);
+        let multi_line = self.scanner.has_preceding_line_break();
 
         let mut elements = Vec::new();
         while !self.is_token(SyntaxKind::CloseBraceToken)
@@ -690,7 +695,8 @@ impl ParserState {
         }
 
         self.parse_expected(SyntaxKind::CloseBraceToken);
-        let end_pos = self.token_end();
+        // The clause ends at its `}`, so diagnostics on it span `with { ... }`.
+        let end_pos = self.token_full_start();
 
         let node_list = self.make_node_list(elements);
         self.arena.add_import_attributes(
@@ -700,7 +706,7 @@ impl ParserState {
             crate::parser::node::ImportAttributesData {
                 token,
                 elements: node_list,
-                multi_line: false,
+                multi_line,
             },
         )
     }
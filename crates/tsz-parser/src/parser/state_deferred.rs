@@ -0,0 +1,281 @@
+//! Parser state - deferred function bodies
+//!
+//! With [`ParserState::set_defer_function_bodies`] on, the body of a
+//! function, method, constructor, accessor or arrow function is only
+//! scanned to its closing brace and left as an empty `Block`. Its
+//! statements are parsed later, into the same arena, when a caller asks for
+//! them: [`ParserState::parse_deferred_function_body`] for one body,
+//! [`ParserState::parse_deferred_function_bodies_at`] for the bodies
+//! enclosing a position, or [`ParserState::parse_all_deferred_function_bodies`]
+//! before checking. A file that is only read for its declarations never
+//! pays for parsing its bodies.
+//!
+//! A body is parsed eagerly instead when skipping it is not safe: in JSX
+//! files, where JSX text is not tokenized like code, or when its tokens
+//! report scanner errors or do not balance.
+
+use super::state::ParserState;
+use crate::parser::{NodeIndex, node::BlockData, syntax_kind_ext};
+use rustc_hash::FxHashMap;
+use tsz_scanner::{SyntaxKind, token_is_keyword, token_is_reserved_word};
+
+/// What is needed to parse a skipped function body later.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct DeferredFunctionBody {
+    /// Context flags at the body's `{`: async, generator, ambient, ...
+    context_flags: u32,
+    /// End of the body's closing `}`.
+    close_brace_end: u32,
+}
+
+impl ParserState {
+    /// Skip the bodies of functions, methods, constructors, accessors and
+    /// arrow functions, leaving empty blocks to be parsed on demand; see
+    /// [`Self::parse_deferred_function_body`]. For large files that are only
+    /// read for their declarations.
+    pub const fn set_defer_function_bodies(&mut self, enabled: bool) {
+        self.defer_function_bodies = enabled;
+    }
+
+    /// Parse a function body at the current `{`, or skip it to its closing
+    /// brace when deferring function bodies.
+    pub(crate) fn parse_function_body_block(&mut self) -> NodeIndex {
+        if self.defer_function_bodies
+            && !self.is_jsx_file()
+            && let Some(block) = self.try_skip_function_body()
+        {
+            return block;
+        }
+        self.parse_block()
+    }
+
+    /// Scan from the current `{` to its matching `}` and add an empty block
+    /// spanning them. `None`, with the scanner restored, when the body
+    /// cannot be skipped safely.
+    fn try_skip_function_body(&mut self) -> Option<NodeIndex> {
+        if !self.is_token(SyntaxKind::OpenBraceToken) {
+            return None;
+        }
+        let start_pos = self.token_pos();
+        let snapshot = self.scanner.save_state();
+        let current = self.current_token;
+        let scanner_diagnostics = self.scanner.get_scanner_diagnostics().len();
+
+        // One entry per open `{`: `true` for a template substitution `${`.
+        let mut braces = vec![false];
+        let mut previous = SyntaxKind::OpenBraceToken;
+        let balanced = loop {
+            let mut token = self.scanner.scan();
+            match token {
+                SyntaxKind::EndOfFileToken => break false,
+                SyntaxKind::OpenBraceToken => braces.push(false),
+                SyntaxKind::TemplateHead => braces.push(true),
+                SyntaxKind::CloseBraceToken if braces.last() == Some(&true) => {
+                    token = self.scanner.re_scan_template_token(false);
+                    if token == SyntaxKind::TemplateTail {
+                        braces.pop();
+                    }
+                }
+                SyntaxKind::CloseBraceToken => {
+                    braces.pop();
+                    if braces.is_empty() {
+                        break true;
+                    }
+                }
+                SyntaxKind::SlashToken | SyntaxKind::SlashEqualsToken
+                    if slash_starts_regex(previous) =>
+                {
+                    token = self.scanner.re_scan_slash_token();
+                }
+                _ => {}
+            }
+            previous = token;
+        };
+        if !balanced || self.scanner.get_scanner_diagnostics().len() != scanner_diagnostics {
+            self.scanner.restore_state(snapshot);
+            self.current_token = current;
+            return None;
+        }
+
+        // End where `parse_block` would: after the token that follows `}`.
+        let close_brace_end = self.token_end();
+        self.next_token();
+        let end_pos = self.token_end();
+        let block = self.arena.add_block(
+            syntax_kind_ext::BLOCK,
+            start_pos,
+            end_pos,
+            BlockData {
+                statements: self.make_node_list(Vec::new()),
+                multi_line: true,
+            },
+        );
+        self.deferred_function_bodies.insert(
+            block,
+            DeferredFunctionBody {
+                context_flags: self.context_flags,
+                close_brace_end,
+            },
+        );
+        Some(block)
+    }
+
+    /// Whether `block` is a function body whose statements have not been
+    /// parsed yet.
+    #[must_use]
+    pub fn is_function_body_deferred(&self, block: NodeIndex) -> bool {
+        self.deferred_function_bodies.contains_key(&block)
+    }
+
+    /// Whether some function bodies have not been parsed yet.
+    #[must_use]
+    pub fn has_deferred_function_bodies(&self) -> bool {
+        !self.deferred_function_bodies.is_empty()
+    }
+
+    /// The function bodies not parsed yet, in source order.
+    #[must_use]
+    pub fn deferred_function_bodies(&self) -> Vec<NodeIndex> {
+        let mut blocks: Vec<NodeIndex> = self.deferred_function_bodies.keys().copied().collect();
+        blocks.sort_by_key(|&block| self.arena.get(block).map_or(u32::MAX, |node| node.pos));
+        blocks
+    }
+
+    /// Parse the statements of the deferred body `block` into it. Function
+    /// bodies nested inside are deferred in turn. Returns `false` when
+    /// `block` was not deferred.
+    ///
+    /// Parse diagnostics from the body are merged into the file's, in
+    /// order. The token after the body was already parsed with the file,
+    /// so its diagnostics are not reported again.
+    pub fn parse_deferred_function_body(&mut self, block: NodeIndex) -> bool {
+        let Some(deferred) = self.deferred_function_bodies.remove(&block) else {
+            return false;
+        };
+        let Some((pos, data_index)) = self
+            .arena
+            .get(block)
+            .map(|node| (node.pos, node.data_index as usize))
+        else {
+            return false;
+        };
+
+        let file_diagnostics = std::mem::take(&mut self.parse_diagnostics);
+        self.scanner.reset_token_state(pos as usize);
+        self.scanner.clear_scanner_diagnostics();
+        self.scanner_diagnostics_high_water_mark = 0;
+        self.context_flags = deferred.context_flags;
+        self.current_token = SyntaxKind::Unknown;
+        self.recursion_depth = 0;
+        self.last_error_pos = 0;
+        self.label_scopes.clear();
+        self.label_scopes.push(FxHashMap::default());
+        self.next_token();
+        let parsed = self.parse_block();
+        self.push_scanner_diagnostics();
+
+        let statements = self
+            .arena
+            .get(parsed)
+            .and_then(|node| self.arena.get_block(node))
+            .map(|data| data.statements.clone());
+        if let Some(statements) = statements {
+            for &statement in &statements.nodes {
+                if let Some(ext) = self.arena.get_extended_mut(statement) {
+                    ext.parent = block;
+                }
+            }
+            if let Some(data) = self.arena.blocks.get_mut(data_index) {
+                data.statements = statements;
+            }
+        }
+
+        let body_diagnostics = std::mem::replace(&mut self.parse_diagnostics, file_diagnostics);
+        self.parse_diagnostics.extend(
+            body_diagnostics
+                .into_iter()
+                .filter(|diag| diag.start < deferred.close_brace_end),
+        );
+        self.parse_diagnostics.sort_by(|a, b| a.compare(b));
+        true
+    }
+
+    /// Parse every deferred body that contains `pos`, outermost first, so
+    /// the innermost statement at `pos` exists. Returns how many bodies
+    /// were parsed.
+    pub fn parse_deferred_function_bodies_at(&mut self, pos: u32) -> usize {
+        let mut parsed = 0;
+        while let Some(block) = self.deferred_function_bodies().into_iter().find(|&block| {
+            self.arena
+                .get(block)
+                .is_some_and(|node| node.pos <= pos && pos < node.end)
+        }) {
+            self.parse_deferred_function_body(block);
+            parsed += 1;
+        }
+        parsed
+    }
+
+    /// Carry the deferred bodies of the tree before an incremental update
+    /// over to the updated tree. Bodies before `reparse_start` are kept as
+    /// they are, bodies from `suffix_pos` on (old text, reused statements)
+    /// move with `shift`, and those in the re-parsed region are dropped.
+    /// Must run before the reused statements are shifted.
+    pub(super) fn carry_deferred_function_bodies(
+        &mut self,
+        old: FxHashMap<NodeIndex, DeferredFunctionBody>,
+        reparse_start: u32,
+        suffix_pos: Option<u32>,
+        shift: &dyn Fn(u32) -> u32,
+    ) {
+        for (block, mut deferred) in old {
+            let Some(pos) = self.arena.get(block).map(|node| node.pos) else {
+                continue;
+            };
+            if suffix_pos.is_some_and(|suffix_pos| pos >= suffix_pos) {
+                deferred.close_brace_end = shift(deferred.close_brace_end);
+            } else if pos >= reparse_start {
+                continue;
+            }
+            self.deferred_function_bodies.insert(block, deferred);
+        }
+    }
+
+    /// Parse all deferred bodies, including the ones nested in them.
+    pub fn parse_all_deferred_function_bodies(&mut self) {
+        while !self.deferred_function_bodies.is_empty() {
+            for block in self.deferred_function_bodies() {
+                self.parse_deferred_function_body(block);
+            }
+        }
+    }
+}
+
+/// Whether a `/` after `previous` starts a regular expression rather than a
+/// division: not after a name, a literal, or a token that closes an
+/// expression.
+const fn slash_starts_regex(previous: SyntaxKind) -> bool {
+    match previous {
+        SyntaxKind::Identifier
+        | SyntaxKind::PrivateIdentifier
+        | SyntaxKind::NumericLiteral
+        | SyntaxKind::BigIntLiteral
+        | SyntaxKind::StringLiteral
+        | SyntaxKind::RegularExpressionLiteral
+        | SyntaxKind::NoSubstitutionTemplateLiteral
+        | SyntaxKind::TemplateTail
+        | SyntaxKind::CloseParenToken
+        | SyntaxKind::CloseBracketToken
+        | SyntaxKind::CloseBraceToken
+        | SyntaxKind::PlusPlusToken
+        | SyntaxKind::MinusMinusToken
+        | SyntaxKind::ThisKeyword
+        | SyntaxKind::SuperKeyword
+        | SyntaxKind::TrueKeyword
+        | SyntaxKind::FalseKeyword
+        | SyntaxKind::NullKeyword => false,
+        // Contextual keywords such as `type` or `of` are usually names.
+        kind if token_is_keyword(kind) => token_is_reserved_word(kind),
+        _ => true,
+    }
+}
@@ -437,6 +437,16 @@ impl ParserState {
                     }
                 }
             }
+            SyntaxKind::FunctionKeyword => {
+                // export @dec function f() {} — decorators are not valid on functions
+                self.parse_error_at(
+                    dec_start,
+                    0,
+                    "Decorators are not valid here.",
+                    diagnostic_codes::DECORATORS_ARE_NOT_VALID_HERE,
+                );
+                self.parse_function_declaration()
+            }
             _ => {
                 self.error_statement_expected();
                 self.parse_expression_statement()
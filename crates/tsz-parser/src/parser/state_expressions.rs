@@ -1192,7 +1192,14 @@ impl ParserState {
                             tsz_common::diagnostics::diagnostic_codes::AN_OPTIONAL_CHAIN_CANNOT_CONTAIN_PRIVATE_IDENTIFIERS,
                         );
                     }
-                    let end_pos = self.token_end();
+                    let (name, end_pos) = if name.is_none() && self.ide_recovery {
+                        (
+                            self.create_missing_identifier_at(missing_name_pos),
+                            missing_name_pos,
+                        )
+                    } else {
+                        (name, self.token_end())
+                    };
 
                     expr = self.arena.add_access_expr(
                         syntax_kind_ext::PROPERTY_ACCESS_EXPRESSION,
@@ -1223,7 +1230,7 @@ impl ParserState {
                             tsz_common::diagnostics::diagnostic_codes::AN_ELEMENT_ACCESS_EXPRESSION_SHOULD_TAKE_AN_ARGUMENT,
                         );
                     }
-                    let end_pos = self.token_end();
+                    let end_pos = self.end_of_node_closed_by(SyntaxKind::CloseBracketToken);
                     self.parse_expected(SyntaxKind::CloseBracketToken);
 
                     expr = self.arena.add_access_expr(
@@ -1241,7 +1248,7 @@ impl ParserState {
                     let callee_expr = expr;
                     self.next_token();
                     let arguments = self.parse_argument_list();
-                    let end_pos = self.token_end();
+                    let end_pos = self.end_of_node_closed_by(SyntaxKind::CloseParenToken);
                     self.parse_expected(SyntaxKind::CloseParenToken);
 
                     let is_optional_chain = self
@@ -1294,7 +1301,7 @@ impl ParserState {
                             // expr?.<T>()
                             self.next_token();
                             let arguments = self.parse_argument_list();
-                            let end_pos = self.token_end();
+                            let end_pos = self.end_of_node_closed_by(SyntaxKind::CloseParenToken);
                             self.parse_expected(SyntaxKind::CloseParenToken);
 
                             let call_expr = self.arena.add_call_expr(
@@ -1361,7 +1368,7 @@ impl ParserState {
                         // expr?.[index]
                         self.next_token();
                         let argument = self.parse_expression();
-                        let end_pos = self.token_end();
+                        let end_pos = self.end_of_node_closed_by(SyntaxKind::CloseBracketToken);
                         self.parse_expected(SyntaxKind::CloseBracketToken);
 
                         expr = self.arena.add_access_expr(
@@ -1378,7 +1385,7 @@ impl ParserState {
                         // expr?.()
                         self.next_token();
                         let arguments = self.parse_argument_list();
-                        let end_pos = self.token_end();
+                        let end_pos = self.end_of_node_closed_by(SyntaxKind::CloseParenToken);
                         self.parse_expected(SyntaxKind::CloseParenToken);
 
                         let call_expr = self.arena.add_call_expr(
@@ -1424,6 +1431,7 @@ impl ParserState {
                         continue;
                     } else {
                         // expr?.prop
+                        let missing_name_pos = self.token_full_start();
                         let is_private_identifier = self.is_token(SyntaxKind::PrivateIdentifier);
                         let name = if is_private_identifier {
                             self.parse_private_identifier()
@@ -1441,7 +1449,17 @@ impl ParserState {
                                 );
                         }
 
-                        let end_pos = self.token_end();
+                        let end_pos = if self.ide_recovery
+                            && self.arena.is_missing_recovery_identifier(name)
+                        {
+                            if let Some(name_node) = self.arena.get_mut(name) {
+                                name_node.pos = missing_name_pos;
+                                name_node.end = missing_name_pos;
+                            }
+                            missing_name_pos
+                        } else {
+                            self.token_end()
+                        };
 
                         expr = self.arena.add_access_expr(
                             syntax_kind_ext::PROPERTY_ACCESS_EXPRESSION,
@@ -1497,7 +1515,7 @@ impl ParserState {
                         if self.is_token(SyntaxKind::OpenParenToken) {
                             self.next_token();
                             let arguments = self.parse_argument_list();
-                            let end_pos = self.token_end();
+                            let end_pos = self.end_of_node_closed_by(SyntaxKind::CloseParenToken);
                             self.parse_expected(SyntaxKind::CloseParenToken);
                             expr = self.arena.add_call_expr(
                                 syntax_kind_ext::CALL_EXPRESSION,
@@ -1551,7 +1569,7 @@ impl ParserState {
                         if self.is_token(SyntaxKind::OpenParenToken) {
                             self.next_token();
                             let arguments = self.parse_argument_list();
-                            let end_pos = self.token_end();
+                            let end_pos = self.end_of_node_closed_by(SyntaxKind::CloseParenToken);
                             self.parse_expected(SyntaxKind::CloseParenToken);
 
                             expr = self.arena.add_call_expr(
@@ -789,7 +789,7 @@ impl ParserState {
         // Push a new label scope for arrow function bodies
         self.push_label_scope();
         let body = if self.is_token(SyntaxKind::OpenBraceToken) {
-            self.parse_block()
+            self.parse_function_body_block()
         } else if self.is_statement_start()
             && !self.is_expression_start()
             && !self.is_token(SyntaxKind::SemicolonToken)
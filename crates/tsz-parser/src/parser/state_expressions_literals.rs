@@ -2240,7 +2240,7 @@ impl ParserState {
         let body = if self.is_token(SyntaxKind::OpenBraceToken) {
             let saved_body_flags = self.context_flags;
             self.context_flags |= CONTEXT_FLAG_FUNCTION_BODY;
-            let block = self.parse_block();
+            let block = self.parse_function_body_block();
             self.context_flags = saved_body_flags;
             block
         } else {
@@ -2337,7 +2337,7 @@ impl ParserState {
         let body = if self.is_token(SyntaxKind::OpenBraceToken) {
             let saved_body_flags = self.context_flags;
             self.context_flags |= CONTEXT_FLAG_FUNCTION_BODY;
-            let block = self.parse_block();
+            let block = self.parse_function_body_block();
             self.context_flags = saved_body_flags;
             block
         } else {
@@ -2446,7 +2446,7 @@ impl ParserState {
             self.context_flags |= CONTEXT_FLAG_FUNCTION_BODY;
             self.push_label_scope();
             let body = if self.is_token(SyntaxKind::OpenBraceToken) {
-                self.parse_block()
+                self.parse_function_body_block()
             } else {
                 NodeIndex::NONE
             };
@@ -2561,7 +2561,7 @@ impl ParserState {
             // outer object-literal closing brace (or EOF).
             NodeIndex::NONE
         } else if self.is_token(SyntaxKind::OpenBraceToken) {
-            self.parse_block()
+            self.parse_function_body_block()
         } else if self.is_token(SyntaxKind::EqualsGreaterThanToken) {
             // tsc prefers "'{' expected." on `=>` in object methods written like:
             // `m(n) => T {}` (should be `m(n): T {}`), then TS1434 on the stray type token.
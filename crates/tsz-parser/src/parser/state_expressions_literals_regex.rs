@@ -801,34 +801,6 @@ impl ParserState {
             ) where
                 F: Fn(&mut ParserState, usize, u32, &str, u32),
             {
-                fn is_class_set_operator_at(body: &[u8], pos: usize, end: usize) -> bool {
-                    pos + 1 < end
-                        && ((body[pos] == b'&' && body[pos + 1] == b'&')
-                            || (body[pos] == b'-' && body[pos + 1] == b'-'))
-                }
-
-                fn scan_class_set_operator(
-                    parser: &mut ParserState,
-                    emit: &impl Fn(&mut ParserState, usize, u32, &str, u32),
-                    body: &[u8],
-                    body_end: usize,
-                    pos: &mut usize,
-                ) {
-                    *pos += 2;
-                    if *pos >= body_end
-                        || body[*pos] == b']'
-                        || is_class_set_operator_at(body, *pos, body_end)
-                    {
-                        emit(
-                            parser,
-                            *pos,
-                            1,
-                            diagnostic_messages::EXPECTED_A_CLASS_SET_OPERAND,
-                            diagnostic_codes::EXPECTED_A_CLASS_SET_OPERAND,
-                        );
-                    }
-                }
-
                 // Consume optional leading ^
                 if *pos < ctx.body_end && ctx.body[*pos] == b'^' {
                     *pos += 1;
@@ -839,22 +811,9 @@ impl ParserState {
                         *pos += 1;
                         break;
                     }
-                    if ctx.unicode_sets_mode
-                        && is_class_set_operator_at(ctx.body, *pos, ctx.body_end)
-                    {
-                        scan_class_set_operator(parser, ctx.emit, ctx.body, ctx.body_end, pos);
-                        continue;
-                    }
-
                     let mut atoms = Vec::new();
                     let min_start = *pos;
                     scan_class_atom(parser, ctx, pos, &mut atoms);
-                    if ctx.unicode_sets_mode
-                        && is_class_set_operator_at(ctx.body, *pos, ctx.body_end)
-                    {
-                        scan_class_set_operator(parser, ctx.emit, ctx.body, ctx.body_end, pos);
-                        continue;
-                    }
                     if *pos >= ctx.body_end || ctx.body[*pos] != b'-' {
                         continue;
                     }
@@ -907,6 +866,501 @@ impl ParserState {
                 }
             }
 
+            /// Whether a `v`-mode class ends at `pos`: its `]`, or the end of
+            /// an unterminated body.
+            fn is_class_content_exit(body: &[u8], end: usize, pos: usize) -> bool {
+                pos >= end || body[pos] == b']'
+            }
+
+            /// Whether `pos` starts `--` or `&&`.
+            fn is_class_set_operator_at(body: &[u8], end: usize, pos: usize) -> bool {
+                pos + 1 < end && body[pos] == body[pos + 1] && matches!(body[pos], b'-' | b'&')
+            }
+
+            fn emit_unexpected_class_set_char<F>(
+                parser: &mut ParserState,
+                ctx: &RegexScanContext<'_, F>,
+                pos: usize,
+            ) where
+                F: Fn(&mut ParserState, usize, u32, &str, u32),
+            {
+                let message =
+                    diagnostic_messages::UNEXPECTED_DID_YOU_MEAN_TO_ESCAPE_IT_WITH_BACKSLASH
+                        .replace("{0}", &char::from(ctx.body[pos]).to_string());
+                (ctx.emit)(
+                    parser,
+                    pos,
+                    1,
+                    &message,
+                    diagnostic_codes::UNEXPECTED_DID_YOU_MEAN_TO_ESCAPE_IT_WITH_BACKSLASH,
+                );
+            }
+
+            fn emit_mixed_class_set_operators<F>(
+                parser: &mut ParserState,
+                ctx: &RegexScanContext<'_, F>,
+                pos: usize,
+                len: u32,
+            ) where
+                F: Fn(&mut ParserState, usize, u32, &str, u32),
+            {
+                (ctx.emit)(
+                    parser,
+                    pos,
+                    len,
+                    diagnostic_messages::OPERATORS_MUST_NOT_BE_MIXED_WITHIN_A_CHARACTER_CLASS_WRAP_IT_IN_A_NESTED_CLASS_I,
+                    diagnostic_codes::OPERATORS_MUST_NOT_BE_MIXED_WITHIN_A_CHARACTER_CLASS_WRAP_IT_IN_A_NESTED_CLASS_I,
+                );
+            }
+
+            fn emit_strings_in_negated_class<F>(
+                parser: &mut ParserState,
+                ctx: &RegexScanContext<'_, F>,
+                start: usize,
+                end: usize,
+            ) where
+                F: Fn(&mut ParserState, usize, u32, &str, u32),
+            {
+                (ctx.emit)(
+                    parser,
+                    start,
+                    (end - start) as u32,
+                    diagnostic_messages::ANYTHING_THAT_WOULD_POSSIBLY_MATCH_MORE_THAN_A_SINGLE_CHARACTER_IS_INVALID_INSID,
+                    diagnostic_codes::ANYTHING_THAT_WOULD_POSSIBLY_MATCH_MORE_THAN_A_SINGLE_CHARACTER_IS_INVALID_INSID,
+                );
+            }
+
+            fn emit_missing_class_set_operand<F>(
+                parser: &mut ParserState,
+                ctx: &RegexScanContext<'_, F>,
+                pos: usize,
+            ) where
+                F: Fn(&mut ParserState, usize, u32, &str, u32),
+            {
+                (ctx.emit)(
+                    parser,
+                    pos,
+                    1,
+                    diagnostic_messages::EXPECTED_A_CLASS_SET_OPERAND,
+                    diagnostic_codes::EXPECTED_A_CLASS_SET_OPERAND,
+                );
+            }
+
+            /// The contents of a `v`-mode class after its `[`: a union of
+            /// operands and ranges, or a chain of `--` / `&&` operations.
+            /// Stops before the closing `]`. `may_contain_strings` is set when
+            /// the class can match a multi-character string, which a negated
+            /// class must not.
+            fn scan_class_set_expression<F>(
+                parser: &mut ParserState,
+                ctx: &RegexScanContext<'_, F>,
+                pos: &mut usize,
+                may_contain_strings: &mut bool,
+            ) where
+                F: Fn(&mut ParserState, usize, u32, &str, u32),
+            {
+                let (body, end) = (ctx.body, ctx.body_end);
+                let is_negated = *pos < end && body[*pos] == b'^';
+                if is_negated {
+                    *pos += 1;
+                }
+                *may_contain_strings = false;
+                let mut expression_may_contain_strings = false;
+                if is_class_content_exit(body, end, *pos) {
+                    return;
+                }
+
+                let mut start = *pos;
+                let mut operand = if is_class_set_operator_at(body, end, *pos) {
+                    emit_missing_class_set_operand(parser, ctx, *pos);
+                    false
+                } else {
+                    scan_class_set_operand(parser, ctx, pos, may_contain_strings)
+                };
+                match body.get(*pos..end) {
+                    Some([b'-', b'-', ..]) => {
+                        if is_negated && *may_contain_strings {
+                            emit_strings_in_negated_class(parser, ctx, start, *pos);
+                        }
+                        scan_class_set_sub_expression(parser, ctx, pos, b'-', may_contain_strings);
+                        *may_contain_strings = !is_negated && *may_contain_strings;
+                        return;
+                    }
+                    Some([b'&', b'&', ..]) => {
+                        scan_class_set_sub_expression(parser, ctx, pos, b'&', may_contain_strings);
+                        if is_negated && *may_contain_strings {
+                            emit_strings_in_negated_class(parser, ctx, start, *pos);
+                        }
+                        *may_contain_strings = !is_negated && *may_contain_strings;
+                        return;
+                    }
+                    Some([b'-' | b'&', ..]) => {}
+                    _ => {
+                        if is_negated && *may_contain_strings {
+                            emit_strings_in_negated_class(parser, ctx, start, *pos);
+                        }
+                        expression_may_contain_strings = *may_contain_strings;
+                    }
+                }
+
+                // A union: operands and `a-z` ranges side by side.
+                while *pos < end {
+                    match body[*pos] {
+                        b'-' => {
+                            *pos += 1;
+                            if is_class_content_exit(body, end, *pos) {
+                                break;
+                            }
+                            if body[*pos] == b'-' {
+                                *pos += 1;
+                                emit_mixed_class_set_operators(parser, ctx, *pos - 2, 2);
+                                start = *pos - 2;
+                                operand = true;
+                                continue;
+                            }
+                            if !operand {
+                                (ctx.emit)(
+                                    parser,
+                                    start,
+                                    (*pos - 1 - start) as u32,
+                                    "A character class range must not be bounded by another character class.",
+                                    diagnostic_codes::A_CHARACTER_CLASS_RANGE_MUST_NOT_BE_BOUNDED_BY_ANOTHER_CHARACTER_CLASS,
+                                );
+                            }
+                            let max_start = *pos;
+                            let max_operand =
+                                scan_class_set_operand(parser, ctx, pos, may_contain_strings);
+                            if is_negated && *may_contain_strings {
+                                emit_strings_in_negated_class(parser, ctx, max_start, *pos);
+                            }
+                            expression_may_contain_strings |= *may_contain_strings;
+                            if !max_operand {
+                                (ctx.emit)(
+                                    parser,
+                                    max_start,
+                                    (*pos - max_start) as u32,
+                                    "A character class range must not be bounded by another character class.",
+                                    diagnostic_codes::A_CHARACTER_CLASS_RANGE_MUST_NOT_BE_BOUNDED_BY_ANOTHER_CHARACTER_CLASS,
+                                );
+                            }
+                            // TS1517 comes from `regex_range_order_errors`.
+                        }
+                        b'&' => {
+                            start = *pos;
+                            *pos += 1;
+                            if *pos < end && body[*pos] == b'&' {
+                                *pos += 1;
+                                emit_mixed_class_set_operators(parser, ctx, *pos - 2, 2);
+                                if *pos < end && body[*pos] == b'&' {
+                                    emit_unexpected_class_set_char(parser, ctx, *pos);
+                                    *pos += 1;
+                                }
+                            } else {
+                                emit_unexpected_class_set_char(parser, ctx, *pos - 1);
+                            }
+                            operand = true;
+                            continue;
+                        }
+                        _ => {}
+                    }
+                    if is_class_content_exit(body, end, *pos) {
+                        break;
+                    }
+                    start = *pos;
+                    if is_class_set_operator_at(body, end, *pos) {
+                        emit_mixed_class_set_operators(parser, ctx, *pos, 2);
+                        *pos += 2;
+                        operand = true;
+                    } else {
+                        operand = scan_class_set_operand(parser, ctx, pos, may_contain_strings);
+                    }
+                }
+                *may_contain_strings = !is_negated && expression_may_contain_strings;
+            }
+
+            /// The rest of `a--b--c` or `a&&b&&c` after its first operand;
+            /// `operator` is `b'-'` or `b'&'`.
+            fn scan_class_set_sub_expression<F>(
+                parser: &mut ParserState,
+                ctx: &RegexScanContext<'_, F>,
+                pos: &mut usize,
+                operator: u8,
+                may_contain_strings: &mut bool,
+            ) where
+                F: Fn(&mut ParserState, usize, u32, &str, u32),
+            {
+                let (body, end) = (ctx.body, ctx.body_end);
+                let mut expression_may_contain_strings = *may_contain_strings;
+                while !is_class_content_exit(body, end, *pos) {
+                    match body[*pos] {
+                        b'-' | b'&' if is_class_set_operator_at(body, end, *pos) => {
+                            let found = body[*pos];
+                            *pos += 2;
+                            if found != operator {
+                                emit_mixed_class_set_operators(parser, ctx, *pos - 2, 2);
+                            }
+                            if found == b'&' && *pos < end && body[*pos] == b'&' {
+                                emit_unexpected_class_set_char(parser, ctx, *pos);
+                                *pos += 1;
+                            }
+                        }
+                        b'-' => {
+                            *pos += 1;
+                            emit_mixed_class_set_operators(parser, ctx, *pos - 1, 1);
+                        }
+                        b'&' => {
+                            *pos += 1;
+                            emit_unexpected_class_set_char(parser, ctx, *pos - 1);
+                        }
+                        _ => {
+                            let message = if operator == b'-' {
+                                "'--' expected."
+                            } else {
+                                "'&&' expected."
+                            };
+                            (ctx.emit)(parser, *pos, 0, message, diagnostic_codes::EXPECTED);
+                        }
+                    }
+                    if is_class_content_exit(body, end, *pos) {
+                        emit_missing_class_set_operand(parser, ctx, *pos);
+                        break;
+                    }
+                    scan_class_set_operand(parser, ctx, pos, may_contain_strings);
+                    expression_may_contain_strings &= *may_contain_strings;
+                }
+                *may_contain_strings = expression_may_contain_strings;
+            }
+
+            /// One operand of a `v`-mode class: a nested class, a class
+            /// escape, a `\q{...}` string disjunction or a single character.
+            /// Returns whether it was a single character, which can bound a
+            /// range.
+            fn scan_class_set_operand<F>(
+                parser: &mut ParserState,
+                ctx: &RegexScanContext<'_, F>,
+                pos: &mut usize,
+                may_contain_strings: &mut bool,
+            ) -> bool
+            where
+                F: Fn(&mut ParserState, usize, u32, &str, u32),
+            {
+                /// Properties of strings, which may match more than one
+                /// character.
+                const STRING_PROPERTIES: &[&[u8]] = &[
+                    b"Basic_Emoji",
+                    b"Emoji_Keycap_Sequence",
+                    b"RGI_Emoji_Modifier_Sequence",
+                    b"RGI_Emoji_Flag_Sequence",
+                    b"RGI_Emoji_Tag_Sequence",
+                    b"RGI_Emoji_ZWJ_Sequence",
+                    b"RGI_Emoji",
+                ];
+
+                let (body, end) = (ctx.body, ctx.body_end);
+                *may_contain_strings = false;
+                if *pos >= end {
+                    return false;
+                }
+                match body[*pos] {
+                    b'[' => {
+                        *pos += 1;
+                        scan_class_set_expression(parser, ctx, pos, may_contain_strings);
+                        if *pos < end && body[*pos] == b']' {
+                            *pos += 1;
+                        }
+                        false
+                    }
+                    b'\\' if *pos + 1 < end => {
+                        let escape_start = *pos;
+                        match body[*pos + 1] {
+                            b'd' | b'D' | b's' | b'S' | b'w' | b'W' | b'p' | b'P' => {
+                                *pos += 1;
+                                scan_character_class_escape(
+                                    parser,
+                                    ctx.emit,
+                                    &body[..end],
+                                    ctx.strict_mode,
+                                    ctx.unicode_sets_mode,
+                                    end,
+                                    pos,
+                                    ctx.start_pos,
+                                );
+                                let escape = &body[escape_start..*pos];
+                                let is_string_property = escape.len() > 4
+                                    && escape[2] == b'{'
+                                    && escape[escape.len() - 1] == b'}'
+                                    && STRING_PROPERTIES.contains(&&escape[3..escape.len() - 1]);
+                                if is_string_property {
+                                    if escape[1] == b'P' {
+                                        emit_strings_in_negated_class(
+                                            parser,
+                                            ctx,
+                                            escape_start,
+                                            *pos,
+                                        );
+                                    } else {
+                                        *may_contain_strings = true;
+                                    }
+                                }
+                                false
+                            }
+                            b'q' => {
+                                *pos += 2;
+                                if *pos < end && body[*pos] == b'{' {
+                                    *pos += 1;
+                                    scan_class_string_disjunction(
+                                        parser,
+                                        ctx,
+                                        pos,
+                                        may_contain_strings,
+                                    );
+                                    if *pos < end && body[*pos] == b'}' {
+                                        *pos += 1;
+                                    }
+                                    false
+                                } else {
+                                    (ctx.emit)(
+                                        parser,
+                                        escape_start,
+                                        2,
+                                        diagnostic_messages::Q_MUST_BE_FOLLOWED_BY_STRING_ALTERNATIVES_ENCLOSED_IN_BRACES,
+                                        diagnostic_codes::Q_MUST_BE_FOLLOWED_BY_STRING_ALTERNATIVES_ENCLOSED_IN_BRACES,
+                                    );
+                                    true
+                                }
+                            }
+                            _ => scan_class_set_character(parser, ctx, pos),
+                        }
+                    }
+                    _ => scan_class_set_character(parser, ctx, pos),
+                }
+            }
+
+            /// The `a|bc` of `\q{a|bc}`; any alternative that is not exactly
+            /// one character makes the class match strings.
+            fn scan_class_string_disjunction<F>(
+                parser: &mut ParserState,
+                ctx: &RegexScanContext<'_, F>,
+                pos: &mut usize,
+                may_contain_strings: &mut bool,
+            ) where
+                F: Fn(&mut ParserState, usize, u32, &str, u32),
+            {
+                let mut character_count = 0;
+                while *pos < ctx.body_end {
+                    match ctx.body[*pos] {
+                        b'}' => break,
+                        b'|' => {
+                            *may_contain_strings |= character_count != 1;
+                            character_count = 0;
+                            *pos += 1;
+                        }
+                        _ => {
+                            scan_class_set_character(parser, ctx, pos);
+                            character_count += 1;
+                        }
+                    }
+                }
+                if *pos < ctx.body_end {
+                    *may_contain_strings |= character_count != 1;
+                }
+            }
+
+            /// A single character of a `v`-mode class. Syntax characters must
+            /// be escaped, and doubled punctuators are reserved.
+            fn scan_class_set_character<F>(
+                parser: &mut ParserState,
+                ctx: &RegexScanContext<'_, F>,
+                pos: &mut usize,
+            ) -> bool
+            where
+                F: Fn(&mut ParserState, usize, u32, &str, u32),
+            {
+                let (body, end) = (ctx.body, ctx.body_end);
+                let ch = body[*pos];
+                if ch == b'\\' {
+                    *pos += 1;
+                    if *pos >= end {
+                        return true;
+                    }
+                    if matches!(
+                        body[*pos],
+                        b'b' | b'&'
+                            | b'-'
+                            | b'!'
+                            | b'#'
+                            | b'%'
+                            | b','
+                            | b':'
+                            | b';'
+                            | b'<'
+                            | b'='
+                            | b'>'
+                            | b'@'
+                            | b'`'
+                            | b'~'
+                    ) {
+                        *pos += 1;
+                    } else {
+                        scan_character_escape(
+                            parser,
+                            ctx.emit,
+                            &CharEscapeScanCtx {
+                                body,
+                                strict_mode: ctx.strict_mode,
+                                end,
+                            },
+                            pos,
+                            false,
+                            *pos - 1,
+                        );
+                    }
+                    return true;
+                }
+                let is_reserved_double_punctuator = *pos + 1 < end
+                    && body[*pos + 1] == ch
+                    && matches!(
+                        ch,
+                        b'&' | b'!'
+                            | b'#'
+                            | b'%'
+                            | b'*'
+                            | b'+'
+                            | b','
+                            | b'.'
+                            | b':'
+                            | b';'
+                            | b'<'
+                            | b'='
+                            | b'>'
+                            | b'?'
+                            | b'@'
+                            | b'`'
+                            | b'~'
+                    );
+                if is_reserved_double_punctuator {
+                    (ctx.emit)(
+                        parser,
+                        *pos,
+                        2,
+                        diagnostic_messages::A_CHARACTER_CLASS_MUST_NOT_CONTAIN_A_RESERVED_DOUBLE_PUNCTUATOR_DID_YOU_MEAN_TO,
+                        diagnostic_codes::A_CHARACTER_CLASS_MUST_NOT_CONTAIN_A_RESERVED_DOUBLE_PUNCTUATOR_DID_YOU_MEAN_TO,
+                    );
+                    *pos += 2;
+                    return true;
+                }
+                if matches!(
+                    ch,
+                    b'/' | b'(' | b')' | b'[' | b']' | b'{' | b'}' | b'-' | b'|'
+                ) {
+                    emit_unexpected_class_set_char(parser, ctx, *pos);
+                    *pos += 1;
+                    return true;
+                }
+                *pos += next_utf8_char(body, end, *pos).map_or(1, |(_, len)| len);
+                true
+            }
+
             fn scan_alternative<F>(
                 parser: &mut ParserState,
                 ctx: &RegexScanContext<'_, F>,
@@ -1260,7 +1714,14 @@ impl ParserState {
                         }
                         b'[' => {
                             *pos += 1;
-                            scan_class_ranges(parser, ctx, pos);
+                            if ctx.unicode_sets_mode {
+                                scan_class_set_expression(parser, ctx, pos, &mut false);
+                                if *pos < ctx.body_end && ctx.body[*pos] == b']' {
+                                    *pos += 1;
+                                }
+                            } else {
+                                scan_class_ranges(parser, ctx, pos);
+                            }
                             is_previous_term_quantifiable = true;
                         }
                         b')' => {
@@ -32,6 +32,16 @@ impl ParserState {
         }
     }
 
+    /// Entry for an argument missing from an argument list: a placeholder
+    /// identifier in IDE recovery mode, otherwise a hole.
+    fn missing_argument(&mut self) -> NodeIndex {
+        if self.ide_recovery {
+            self.create_missing_expression()
+        } else {
+            NodeIndex::NONE
+        }
+    }
+
     // Parse argument list
     pub(crate) fn parse_argument_list(&mut self) -> NodeList {
         let mut args = Vec::new();
@@ -65,17 +75,18 @@ impl ParserState {
             } else if self.is_token(SyntaxKind::CommaToken) {
                 // TS1135: missing argument before comma: func(a, , c)
                 self.error_argument_expression_expected();
-                args.push(NodeIndex::NONE);
+                args.push(self.missing_argument());
             } else if self.is_token(SyntaxKind::SemicolonToken) {
                 // Semicolon terminates argument list — don't emit TS1135 here.
                 // Let parse_expected(CloseParenToken) emit TS1005 instead,
                 // matching tsc which treats `;` as a clear boundary.
                 break;
             } else {
-                let arg = self.parse_assignment_expression_allowing_arrow_return_type();
+                let mut arg = self.parse_assignment_expression_allowing_arrow_return_type();
                 if arg.is_none() {
                     // TS1135 for missing function argument
                     self.error_argument_expression_expected();
+                    arg = self.missing_argument();
                 }
                 args.push(arg);
             }
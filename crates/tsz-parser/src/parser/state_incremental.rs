@@ -0,0 +1,324 @@
+//! Parser state - incremental re-parsing after text edits
+//!
+//! [`ParserState::update`] re-parses only the top-level statements an edit
+//! can affect. Statements before the edit keep their nodes as they are;
+//! statements after it keep their nodes with positions shifted by the
+//! length change. Parsing of the edited region stops as soon as it reaches
+//! the start of an old statement that lies wholly after the edit.
+
+use super::state::{ParseDiagnostic, ParserState};
+use crate::parser::{NodeIndex, NodeList, node::NodeAccess, syntax_kind_ext};
+use rustc_hash::{FxHashMap, FxHashSet};
+use tsz_scanner::SyntaxKind;
+
+/// The arena may hold this many times the nodes a fresh parse would allocate
+/// before `update` falls back to a full parse to drop replaced nodes.
+const MAX_ARENA_GROWTH: usize = 4;
+/// Arenas below this size are never re-parsed in full just to reclaim space.
+const MIN_ARENA_BUDGET: usize = 4096;
+
+/// A replacement of the text `start..end` (UTF-8 offsets into the text
+/// before any of the changes) with `new_text`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextChange {
+    pub start: u32,
+    pub end: u32,
+    pub new_text: String,
+}
+
+/// Statements of the old tree that an update keeps.
+struct ReusePlan {
+    /// Leading statements before the edit, kept unchanged.
+    prefix_len: usize,
+    /// Where parsing resumes: the start of the first statement not kept.
+    reparse_start: u32,
+    /// First statement wholly after the edit; it and the rest may be reused.
+    suffix_start: usize,
+}
+
+impl ParserState {
+    /// Apply `changes` to the text parsed into `source_file` and re-parse it,
+    /// reusing the nodes of top-level statements outside the edited range.
+    ///
+    /// `changes` must be sorted and must not overlap. Returns the source file
+    /// node: the same index when the tree was updated in place, or a new one
+    /// when the whole file had to be re-parsed (invalid changes, or an arena
+    /// grown too large from replaced nodes). Parse diagnostics are updated to
+    /// match the new text.
+    pub fn update(&mut self, source_file: NodeIndex, changes: &[TextChange]) -> NodeIndex {
+        let old_text = self.scanner.source_text_arc();
+        let Some(new_text) = apply_text_changes(&old_text, changes) else {
+            return self.reparse_from_scratch(old_text.to_string());
+        };
+        let (Some(first), Some(last)) = (changes.first(), changes.last()) else {
+            return source_file;
+        };
+        let budget = (new_text.len() / 20)
+            .saturating_mul(MAX_ARENA_GROWTH)
+            .max(MIN_ARENA_BUDGET);
+        if self.arena.len() > budget {
+            return self.reparse_from_scratch(new_text);
+        }
+        let Some(plan) = self.reuse_plan(source_file, first.start, last.end) else {
+            return self.reparse_from_scratch(new_text);
+        };
+
+        let delta = i64::try_from(new_text.len()).unwrap_or(i64::MAX)
+            - i64::try_from(old_text.len()).unwrap_or(i64::MAX);
+        let shift = |pos: u32| u32::try_from(i64::from(pos) + delta).unwrap_or(u32::MAX);
+
+        let Some(source_data) = self
+            .arena
+            .get(source_file)
+            .and_then(|node| self.arena.get_source_file(node))
+        else {
+            return self.reparse_from_scratch(new_text);
+        };
+        let old_statements = source_data.statements.nodes.clone();
+        let old_end_of_file = source_data.end_of_file_token;
+        let mut sync_points: Vec<(u32, usize)> = old_statements[plan.suffix_start..]
+            .iter()
+            .enumerate()
+            .filter_map(|(offset, &stmt)| {
+                let node = self.arena.get(stmt)?;
+                Some((shift(node.pos), plan.suffix_start + offset))
+            })
+            .collect();
+        sync_points.sort_unstable();
+
+        let old_diagnostics = std::mem::take(&mut self.parse_diagnostics);
+        let old_deferred = std::mem::take(&mut self.deferred_function_bodies);
+        self.reset_for_statement_reparse(new_text, plan.reparse_start);
+        self.incremental_sync_points = sync_points.iter().map(|&(pos, _)| pos).collect();
+        self.next_token();
+        let middle = self.parse_source_file_statements();
+        self.incremental_sync_points.clear();
+        self.arena.set_interner(self.scanner.interner().clone());
+
+        let stop_pos = self.token_pos();
+        let reused_from = if self.is_token(SyntaxKind::EndOfFileToken) {
+            None
+        } else {
+            sync_points
+                .binary_search_by_key(&stop_pos, |&(pos, _)| pos)
+                .ok()
+                .map(|found| sync_points[found].1)
+        };
+        let old_suffix_pos = reused_from.map(|suffix_idx| {
+            self.arena
+                .get(old_statements[suffix_idx])
+                .map_or(u32::MAX, |node| node.pos)
+        });
+        self.carry_deferred_function_bodies(
+            old_deferred,
+            plan.reparse_start,
+            old_suffix_pos,
+            &shift,
+        );
+
+        // Diagnostics: the prefix's, the re-parsed region's, then the reused
+        // suffix's shifted by the length change.
+        self.push_scanner_diagnostics();
+        let mut diagnostics: Vec<ParseDiagnostic> = old_diagnostics
+            .iter()
+            .filter(|diag| diag.start < plan.reparse_start)
+            .cloned()
+            .collect();
+        let middle_diagnostics = std::mem::take(&mut self.parse_diagnostics);
+        match old_suffix_pos {
+            Some(old_suffix_pos) => {
+                diagnostics.extend(
+                    middle_diagnostics
+                        .into_iter()
+                        .filter(|d| d.start < stop_pos),
+                );
+                diagnostics.extend(
+                    old_diagnostics
+                        .into_iter()
+                        .filter(|diag| diag.start >= old_suffix_pos)
+                        .map(|diag| ParseDiagnostic {
+                            start: shift(diag.start),
+                            ..diag
+                        }),
+                );
+            }
+            None => diagnostics.extend(middle_diagnostics),
+        }
+        diagnostics.sort_by(|a, b| a.compare(b));
+        self.parse_diagnostics = diagnostics;
+
+        // Reused statements keep their nodes, moved to their new positions.
+        let mut statements = old_statements[..plan.prefix_len].to_vec();
+        statements.extend(middle.nodes.iter().copied());
+        let end_of_file = match reused_from {
+            Some(suffix_idx) => {
+                let reused = &old_statements[suffix_idx..];
+                if delta != 0 {
+                    for &stmt in reused {
+                        self.shift_subtree(stmt, &shift);
+                    }
+                    self.shift_subtree(old_end_of_file, &shift);
+                }
+                statements.extend_from_slice(reused);
+                old_end_of_file
+            }
+            None => {
+                let end_pos = self.token_end();
+                self.arena
+                    .add_token(SyntaxKind::EndOfFileToken as u16, end_pos, end_pos)
+            }
+        };
+        for &node in middle.nodes.iter().chain(std::iter::once(&end_of_file)) {
+            if let Some(ext) = self.arena.get_extended_mut(node) {
+                ext.parent = source_file;
+            }
+        }
+
+        let end_pos = self.arena.get(end_of_file).map_or(0, |node| node.end);
+        let text = self.scanner.source_text_arc();
+        let comments = tsz_common::comments::get_comment_ranges(&text);
+        if let Some(node) = self.arena.get_mut(source_file) {
+            node.end = end_pos;
+        }
+        let data_index = self
+            .arena
+            .get(source_file)
+            .map_or(usize::MAX, |node| node.data_index as usize);
+        if let Some(data) = self.arena.source_files.get_mut(data_index) {
+            data.statements = NodeList {
+                nodes: statements,
+                pos: 0,
+                end: 0,
+                has_trailing_comma: false,
+            };
+            data.end_of_file_token = end_of_file;
+            data.text = text;
+            data.comments = comments;
+        }
+        source_file
+    }
+
+    /// Decide which top-level statements of `source_file` survive an edit of
+    /// the old text `change_start..change_end`. `None` when the edit reaches
+    /// into the first statement, where a full parse costs the same.
+    fn reuse_plan(
+        &self,
+        source_file: NodeIndex,
+        change_start: u32,
+        change_end: u32,
+    ) -> Option<ReusePlan> {
+        let node = self.arena.get(source_file)?;
+        if node.kind != syntax_kind_ext::SOURCE_FILE {
+            return None;
+        }
+        let statements = &self.arena.get_source_file(node)?.statements.nodes;
+        let spans: Vec<(u32, u32)> = statements
+            .iter()
+            .map(|&stmt| self.arena.get(stmt).map(|node| (node.pos, node.end)))
+            .collect::<Option<_>>()?;
+
+        // The statement just before the first touched one is re-parsed too:
+        // an edit can change how it ends (ASI, a call continuing onto the
+        // next line).
+        let first_touched = spans
+            .iter()
+            .position(|&(_, end)| end >= change_start)
+            .unwrap_or(spans.len());
+        let prefix_len = first_touched.saturating_sub(1);
+        if prefix_len == 0 {
+            return None;
+        }
+        let suffix_start = spans
+            .iter()
+            .position(|&(pos, _)| pos >= change_end)
+            .unwrap_or(spans.len())
+            .max(prefix_len);
+        Some(ReusePlan {
+            prefix_len,
+            reparse_start: spans[prefix_len].0.min(change_start),
+            suffix_start,
+        })
+    }
+
+    /// Point the scanner at `text` from `start` and clear the per-parse
+    /// state, as for a top-level statement list starting there.
+    fn reset_for_statement_reparse(&mut self, text: String, start: u32) {
+        self.scanner.set_text(text, Some(start as usize), None);
+        self.scanner.set_language_version(self.language_version);
+        self.scanner.clear_scanner_diagnostics();
+        self.scanner_diagnostics_high_water_mark = 0;
+        self.context_flags = 0;
+        self.current_token = SyntaxKind::Unknown;
+        self.parse_diagnostics.clear();
+        self.recursion_depth = 0;
+        self.last_error_pos = 0;
+        self.label_scopes.clear();
+        self.label_scopes.push(FxHashMap::default());
+    }
+
+    fn reparse_from_scratch(&mut self, text: String) -> NodeIndex {
+        let file_name = std::mem::take(&mut self.file_name);
+        self.reset(file_name, text);
+        self.parse_source_file()
+    }
+
+    /// Move every node of the subtree at `root` with `shift`. A node listed
+    /// under more than one parent moves once.
+    fn shift_subtree(&mut self, root: NodeIndex, shift: &dyn Fn(u32) -> u32) {
+        let mut visited = FxHashSet::default();
+        let mut pending = vec![root];
+        while let Some(idx) = pending.pop() {
+            if !visited.insert(idx) {
+                continue;
+            }
+            pending.extend(self.arena.get_children(idx));
+            let Some(node) = self.arena.get_mut(idx) else {
+                continue;
+            };
+            node.pos = shift(node.pos);
+            node.end = shift(node.end);
+            let (kind, data_index) = (node.kind, node.data_index as usize);
+
+            // Token positions kept in node data move with the node.
+            if kind == syntax_kind_ext::AS_EXPRESSION
+                || kind == syntax_kind_ext::SATISFIES_EXPRESSION
+                || kind == syntax_kind_ext::TYPE_ASSERTION
+            {
+                if let Some(data) = self.arena.type_assertions.get_mut(data_index) {
+                    data.keyword_pos = shift(data.keyword_pos);
+                }
+            } else if kind == syntax_kind_ext::SHORTHAND_PROPERTY_ASSIGNMENT
+                && let Some(data) = self.arena.shorthand_properties.get_mut(data_index)
+            {
+                // 0 marks an absent token.
+                for pos in [
+                    &mut data.equals_token_pos,
+                    &mut data.exclamation_token_pos,
+                    &mut data.question_token_pos,
+                ] {
+                    if *pos != 0 {
+                        *pos = shift(*pos);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The text after `changes`, or `None` when they are unsorted, overlap, or
+/// fall outside `text` or off a character boundary.
+fn apply_text_changes(text: &str, changes: &[TextChange]) -> Option<String> {
+    let mut result = String::with_capacity(text.len());
+    let mut copied = 0usize;
+    for change in changes {
+        let (start, end) = (change.start as usize, change.end as usize);
+        if start < copied || end < start || !text.is_char_boundary(end) {
+            return None;
+        }
+        result.push_str(text.get(copied..start)?);
+        result.push_str(&change.new_text);
+        copied = end;
+    }
+    result.push_str(&text[copied..]);
+    Some(result)
+}
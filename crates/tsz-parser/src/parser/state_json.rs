@@ -0,0 +1,301 @@
+//! Parser state - JSON source files.
+//!
+//! [`ParserState::parse_json_text`] parses a `.json` file the way tsc's
+//! `parseJsonText` does, and then checks the tree like tsc's `convertToJson`.
+//! Objects, arrays and literals are parsed with the ordinary expression
+//! parsers, so the tree has the usual node kinds and the checker and
+//! emitter can read it like any other file.
+
+use super::state::{JsonParseOptions, ParseDiagnostic, ParserState};
+use crate::parser::{
+    NodeIndex,
+    node::{ExprStatementData, LiteralExprData},
+    script_kind, syntax_kind_ext,
+};
+use tsz_common::comments::{CommentRange, get_comment_ranges};
+use tsz_common::diagnostics::{diagnostic_codes, diagnostic_messages};
+use tsz_scanner::SyntaxKind;
+
+impl ParserState {
+    /// Parse the text as a JSON file: a tsconfig, or a module imported with
+    /// `resolveJsonModule`.
+    ///
+    /// The source file has no statements when the text is empty and
+    /// otherwise one expression statement holding the root value; extra
+    /// root values are reported and wrapped in an array literal with it.
+    /// Property names must be double-quoted strings (TS1327) and values must
+    /// be JSON values (TS1328). The source file's `script_kind` is JSON.
+    pub fn parse_json_text(&mut self, options: JsonParseOptions) -> NodeIndex {
+        self.next_token();
+        let comments = get_comment_ranges(self.scanner.source_text());
+
+        let mut statements = self.make_node_list(Vec::new());
+        if !self.is_token(SyntaxKind::EndOfFileToken) {
+            let mut values = Vec::new();
+            while !self.is_token(SyntaxKind::EndOfFileToken) {
+                let start = self.token_pos();
+                let value = self.parse_json_root_value();
+                if values.is_empty() && !self.is_token(SyntaxKind::EndOfFileToken) {
+                    self.parse_error_at_current_token(
+                        diagnostic_messages::UNEXPECTED_TOKEN,
+                        diagnostic_codes::UNEXPECTED_TOKEN,
+                    );
+                }
+                if value.is_some() {
+                    values.push(value);
+                }
+                if self.token_pos() == start && !self.is_token(SyntaxKind::EndOfFileToken) {
+                    self.next_token();
+                }
+            }
+
+            let span = |parser: &Self, node: Option<&NodeIndex>| {
+                node.and_then(|&idx| parser.arena.get(idx))
+                    .map(|node| (node.pos, node.end))
+            };
+            let pos = span(self, values.first()).map_or(0, |(pos, _)| pos);
+            let end = span(self, values.last()).map_or(pos, |(_, end)| end);
+            let root = if values.len() == 1 {
+                values[0]
+            } else {
+                let elements = self.make_node_list(values);
+                self.arena.add_literal_expr(
+                    syntax_kind_ext::ARRAY_LITERAL_EXPRESSION,
+                    pos,
+                    end,
+                    LiteralExprData {
+                        elements,
+                        multi_line: false,
+                    },
+                )
+            };
+            let statement = self.arena.add_expr_statement(
+                syntax_kind_ext::EXPRESSION_STATEMENT,
+                pos,
+                end,
+                ExprStatementData { expression: root },
+            );
+            statements = self.make_node_list(vec![statement]);
+            self.check_json_value(root, options, &comments);
+        }
+        if !options.allow_comments {
+            for comment in &comments {
+                self.push_json_error(
+                    comment.pos,
+                    comment.end,
+                    diagnostic_messages::UNEXPECTED_TOKEN,
+                    diagnostic_codes::UNEXPECTED_TOKEN,
+                );
+            }
+        }
+
+        self.finish_source_file(statements, script_kind::JSON)
+    }
+
+    /// One root value, chosen by its first token as in tsc. Anything that
+    /// is not an array or a literal is parsed as an object, so a file of
+    /// bare `key: value` pairs reports the missing `{`.
+    fn parse_json_root_value(&mut self) -> NodeIndex {
+        match self.token() {
+            SyntaxKind::OpenBracketToken => self.parse_array_literal(),
+            SyntaxKind::TrueKeyword | SyntaxKind::FalseKeyword => self.parse_boolean_literal(),
+            SyntaxKind::NullKeyword => self.parse_null_literal(),
+            SyntaxKind::MinusToken if self.look_ahead_is_json_literal_value(true) => {
+                self.parse_unary_expression()
+            }
+            SyntaxKind::NumericLiteral if self.look_ahead_is_json_literal_value(false) => {
+                self.parse_numeric_literal()
+            }
+            SyntaxKind::StringLiteral if self.look_ahead_is_json_literal_value(false) => {
+                self.parse_string_literal()
+            }
+            _ => self.parse_object_literal(),
+        }
+    }
+
+    /// Whether the literal at the current token (after a `-` when
+    /// `negative`) is a value rather than the first key of an object
+    /// missing its `{`.
+    fn look_ahead_is_json_literal_value(&mut self, negative: bool) -> bool {
+        let snapshot = self.scanner.save_state();
+        let current = self.current_token;
+        let is_value = (!negative || self.next_token() == SyntaxKind::NumericLiteral)
+            && self.next_token() != SyntaxKind::ColonToken;
+        self.scanner.restore_state(snapshot);
+        self.current_token = current;
+        is_value
+    }
+
+    /// Check that `value` is a JSON value, like tsc's `convertToJson`.
+    fn check_json_value(
+        &mut self,
+        value: NodeIndex,
+        options: JsonParseOptions,
+        comments: &[CommentRange],
+    ) {
+        let Some(node) = self.arena.get(value) else {
+            return;
+        };
+        let (kind, end) = (node.kind, node.end);
+        if self.arena.is_missing_recovery_identifier(value) {
+            return;
+        }
+        match kind {
+            k if k == SyntaxKind::TrueKeyword as u16
+                || k == SyntaxKind::FalseKeyword as u16
+                || k == SyntaxKind::NullKeyword as u16
+                || k == SyntaxKind::NumericLiteral as u16 => {}
+            k if k == SyntaxKind::StringLiteral as u16 => self.check_json_string(value),
+            syntax_kind_ext::PREFIX_UNARY_EXPRESSION => {
+                let is_negative_number = self.arena.get_unary_expr(node).is_some_and(|unary| {
+                    unary.operator == SyntaxKind::MinusToken as u16
+                        && self.arena.get(unary.operand).is_some_and(|operand| {
+                            operand.kind == SyntaxKind::NumericLiteral as u16
+                        })
+                });
+                if !is_negative_number {
+                    self.push_invalid_json_value(value);
+                }
+            }
+            syntax_kind_ext::OBJECT_LITERAL_EXPRESSION
+            | syntax_kind_ext::ARRAY_LITERAL_EXPRESSION => {
+                let elements = self
+                    .arena
+                    .get_literal_expr(node)
+                    .map(|literal| literal.elements.nodes.clone())
+                    .unwrap_or_default();
+                let is_object = kind == syntax_kind_ext::OBJECT_LITERAL_EXPRESSION;
+                for &element in &elements {
+                    if is_object {
+                        self.check_json_property(element, options, comments);
+                    } else {
+                        self.check_json_value(element, options, comments);
+                    }
+                }
+                if !options.allow_trailing_commas && !elements.is_empty() {
+                    let text = self.scanner.source_text();
+                    let close = if is_object { b'}' } else { b']' };
+                    let comma = (text.as_bytes().get(end as usize - 1) == Some(&close))
+                        .then(|| trailing_comma_before(text, comments, end as usize - 1))
+                        .flatten();
+                    if let Some(comma) = comma {
+                        let comma = self.u32_from_usize(comma);
+                        self.push_json_error(
+                            comma,
+                            comma + 1,
+                            diagnostic_messages::TRAILING_COMMA_NOT_ALLOWED,
+                            diagnostic_codes::TRAILING_COMMA_NOT_ALLOWED,
+                        );
+                    }
+                }
+            }
+            _ => self.push_invalid_json_value(value),
+        }
+    }
+
+    fn check_json_property(
+        &mut self,
+        property: NodeIndex,
+        options: JsonParseOptions,
+        comments: &[CommentRange],
+    ) {
+        let Some(node) = self.arena.get(property) else {
+            return;
+        };
+        let Some(assignment) = self.arena.get_property_assignment(node) else {
+            // A shorthand property is reported at its name.
+            let reported = self
+                .arena
+                .get_shorthand_property(node)
+                .map_or(property, |shorthand| shorthand.name);
+            let Some((pos, end)) = self.json_error_span(reported) else {
+                return;
+            };
+            self.push_json_error(
+                pos,
+                end,
+                diagnostic_messages::PROPERTY_ASSIGNMENT_EXPECTED,
+                diagnostic_codes::PROPERTY_ASSIGNMENT_EXPECTED,
+            );
+            return;
+        };
+        let (name, initializer) = (assignment.name, assignment.initializer);
+        self.check_json_string(name);
+        self.check_json_value(initializer, options, comments);
+    }
+
+    /// Report `node` unless it is a double-quoted string literal.
+    fn check_json_string(&mut self, node: NodeIndex) {
+        let Some(n) = self.arena.get(node) else {
+            return;
+        };
+        let is_double_quoted = n.kind == SyntaxKind::StringLiteral as u16
+            && self.scanner.source_text().as_bytes().get(n.pos as usize) == Some(&b'"');
+        if is_double_quoted || self.arena.is_missing_recovery_identifier(node) {
+            return;
+        }
+        if let Some((pos, end)) = self.json_error_span(node) {
+            self.push_json_error(
+                pos,
+                end,
+                diagnostic_messages::STRING_LITERAL_WITH_DOUBLE_QUOTES_EXPECTED,
+                diagnostic_codes::STRING_LITERAL_WITH_DOUBLE_QUOTES_EXPECTED,
+            );
+        }
+    }
+
+    fn push_invalid_json_value(&mut self, value: NodeIndex) {
+        let Some((pos, end)) = self.json_error_span(value) else {
+            return;
+        };
+        self.push_json_error(
+            pos,
+            end,
+            diagnostic_messages::PROPERTY_VALUE_CAN_ONLY_BE_STRING_LITERAL_NUMERIC_LITERAL_TRUE_FALSE_NULL_OBJECT,
+            diagnostic_codes::PROPERTY_VALUE_CAN_ONLY_BE_STRING_LITERAL_NUMERIC_LITERAL_TRUE_FALSE_NULL_OBJECT,
+        );
+    }
+
+    /// Span of `node` for a diagnostic. Identifiers and prefix expressions
+    /// can end after the token that follows them, so their end comes from
+    /// their own text.
+    fn json_error_span(&self, node: NodeIndex) -> Option<(u32, u32)> {
+        let n = self.arena.get(node)?;
+        let end = if let Some(ident) = self.arena.get_identifier(n) {
+            n.pos + self.u32_from_usize(ident.escaped_text.len())
+        } else if n.kind == syntax_kind_ext::PREFIX_UNARY_EXPRESSION {
+            self.arena
+                .get_unary_expr(n)
+                .and_then(|unary| self.json_error_span(unary.operand))
+                .map_or(n.end, |(_, end)| end)
+        } else {
+            n.end
+        };
+        Some((n.pos, end.min(n.end)))
+    }
+
+    /// JSON value errors are reported for every offending node, like tsc's
+    /// `convertToJson`, so they bypass the parser's error suppression.
+    fn push_json_error(&mut self, pos: u32, end: u32, message: &str, code: u32) {
+        self.parse_diagnostics.push(ParseDiagnostic {
+            start: pos,
+            length: end.saturating_sub(pos),
+            message: message.to_string(),
+            code,
+        });
+    }
+}
+
+/// Offset of a `,` directly before `close`, ignoring whitespace and
+/// `comments`.
+fn trailing_comma_before(text: &str, comments: &[CommentRange], close: usize) -> Option<usize> {
+    let mut end = close;
+    loop {
+        end = text[..end].trim_end().len();
+        match comments.iter().find(|comment| comment.end as usize == end) {
+            Some(comment) if comment.pos < comment.end => end = comment.pos as usize,
+            _ => break,
+        }
+    }
+    (end > 0 && text.as_bytes()[end - 1] == b',').then_some(end - 1)
+}
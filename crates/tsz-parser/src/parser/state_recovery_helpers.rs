@@ -506,8 +506,12 @@ impl ParserState {
     /// Create a missing expression placeholder for error recovery.
     /// This allows the AST to remain structurally valid even when an expression is missing.
     pub(crate) fn create_missing_expression(&mut self) -> NodeIndex {
-        let pos = self.token_pos();
-        // Create an identifier with empty text to represent missing expression
+        self.create_missing_identifier_at(self.token_pos())
+    }
+
+    /// Create a zero-width identifier with empty text at `pos`, standing in
+    /// for a name or expression that is missing from the source.
+    pub(crate) fn create_missing_identifier_at(&mut self, pos: u32) -> NodeIndex {
         self.arena.add_identifier(
             SyntaxKind::Identifier as u16,
             pos,
@@ -521,6 +525,32 @@ impl ParserState {
         )
     }
 
+    /// Build trees that editor features can work with while code is being
+    /// typed. A member name or argument that is missing becomes a zero-width
+    /// placeholder identifier, and a call, element access, type-argument
+    /// list or JSX tag or expression missing its closing token ends at its
+    /// last token instead of spanning the token after it. On by default, so
+    /// the compiler and the language service build the same tree;
+    /// diagnostics are the same either way.
+    pub const fn set_ide_recovery(&mut self, enabled: bool) {
+        self.ide_recovery = enabled;
+    }
+
+    /// End position for a node closed by `closer`, read before the closer is
+    /// expected. In IDE recovery mode a missing closer ends the node after
+    /// its last token rather than after the unrelated token that follows.
+    pub(crate) fn end_of_node_closed_by(&self, closer: SyntaxKind) -> u32 {
+        self.end_of_unclosed_node(closer)
+            .unwrap_or_else(|| self.token_end())
+    }
+
+    /// In IDE recovery mode, the end of a node whose `closer` is missing:
+    /// after its last token. `None` when the closer is present or recovery
+    /// is off, for callers that measure the closed end differently.
+    pub(crate) fn end_of_unclosed_node(&self, closer: SyntaxKind) -> Option<u32> {
+        (self.ide_recovery && !self.is_token(closer)).then(|| self.token_full_start())
+    }
+
     /// Try to recover from a missing right-hand operand in a binary expression.
     /// Returns a placeholder expression if recovery is possible.
     pub(crate) fn try_recover_binary_rhs(&mut self) -> NodeIndex {
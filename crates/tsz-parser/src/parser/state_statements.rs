@@ -3,7 +3,7 @@ use super::state::{CONTEXT_FLAG_IN_BLOCK, IncrementalParseResult, ParserState};
 use crate::parser::{
     NodeIndex, NodeList,
     node::{BlockData, QualifiedNameData, SourceFileData, VariableData, VariableDeclarationData},
-    syntax_kind_ext,
+    script_kind, syntax_kind_ext,
 };
 use tsz_common::diagnostics::diagnostic_codes;
 use tsz_scanner::{SyntaxKind, token_is_keyword};
@@ -122,8 +122,6 @@ impl ParserState {
 
     /// Parse a source file
     pub fn parse_source_file(&mut self) -> NodeIndex {
-        let start_pos = 0u32;
-
         // Skip shebang (#!) if present at start of file
         self.scanner.scan_shebang_trivia();
 
@@ -132,27 +130,24 @@ impl ParserState {
 
         // Parse statements (using source file version that handles stray braces)
         let statements = self.parse_source_file_statements();
+        self.finish_source_file(statements, script_kind::TS)
+    }
+
+    /// Build the source file node for the parsed `statements`, after the
+    /// last token has been consumed. `script_kind` is tsc's `ScriptKind`.
+    pub(crate) fn finish_source_file(
+        &mut self,
+        statements: NodeList,
+        script_kind: u32,
+    ) -> NodeIndex {
+        let start_pos = 0u32;
 
         // Cache comment ranges once during parsing (O(N) scan, done only once)
         // This avoids rescanning on every hover/documentation request
         // Use scanner's source text (no duplicate allocation)
         let comments = tsz_common::comments::get_comment_ranges(self.scanner.source_text());
 
-        // Collect scanner-level diagnostics (e.g., conflict markers TS1185) into
-        // parse diagnostics so they appear in the final diagnostic output.
-        for diag in self.scanner.get_scanner_diagnostics() {
-            let mut message = diag.message.to_string();
-            for (idx, arg) in diag.args.iter().enumerate() {
-                let placeholder = format!("{{{idx}}}");
-                message = message.replace(&placeholder, arg);
-            }
-            self.parse_diagnostics.push(super::state::ParseDiagnostic {
-                start: self.u32_from_usize(diag.pos),
-                length: self.u32_from_usize(diag.length),
-                message,
-                code: diag.code,
-            });
-        }
+        self.push_scanner_diagnostics();
         // Sort diagnostics into tsc's canonical `compareDiagnostics` order after
         // merging scanner- and parser-produced entries. Sorting by `start` alone
         // left position ties resolved by production/merge order, which is not
@@ -170,7 +165,7 @@ impl ParserState {
         // This is essential for LSP features that resolve identifier references.
         self.arena.set_interner(self.scanner.interner().clone());
 
-        self.arena.add_source_file(
+        let source_file = self.arena.add_source_file(
             start_pos,
             end_pos,
             SourceFileData {
@@ -180,7 +175,7 @@ impl ParserState {
                 text: self.scanner.source_text_arc(),
                 language_version: u32::from(self.language_version.ts_numeric_value()),
                 language_variant: 0,
-                script_kind: 3,
+                script_kind,
                 is_declaration_file: self.is_declaration_file(),
                 has_no_default_lib: false,
                 comments, // Cached comment ranges
@@ -189,7 +184,26 @@ impl ParserState {
                 modifier_flags: 0,
                 transform_flags: 0,
             },
-        )
+        );
+        source_file
+    }
+
+    /// Collect scanner-level diagnostics (e.g., conflict markers TS1185) into
+    /// parse diagnostics so they appear in the final diagnostic output.
+    pub(crate) fn push_scanner_diagnostics(&mut self) {
+        for diag in self.scanner.get_scanner_diagnostics() {
+            let mut message = diag.message.to_string();
+            for (idx, arg) in diag.args.iter().enumerate() {
+                let placeholder = format!("{{{idx}}}");
+                message = message.replace(&placeholder, arg);
+            }
+            self.parse_diagnostics.push(super::state::ParseDiagnostic {
+                start: self.u32_from_usize(diag.pos),
+                length: self.u32_from_usize(diag.length),
+                message,
+                code: diag.code,
+            });
+        }
     }
 
     pub fn parse_source_file_statements_from_offset(
@@ -252,6 +266,21 @@ impl ParserState {
             if skip_after_binary_payload {
                 break;
             }
+            // An incremental update stops where an unchanged old statement
+            // starts and reuses the statements from there on, unless a `=` or
+            // `:` after a block needs the block-recovery below.
+            if self
+                .incremental_sync_points
+                .binary_search(&pos_before)
+                .is_ok()
+                && !(previous_statement_was_block
+                    && matches!(
+                        self.token(),
+                        SyntaxKind::EqualsToken | SyntaxKind::ColonToken
+                    ))
+            {
+                break;
+            }
 
             if self.look_ahead_is_invalid_shebang() {
                 if self.scanner.has_preceding_line_break() {
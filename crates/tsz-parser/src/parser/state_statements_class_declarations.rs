@@ -1265,6 +1265,7 @@ impl ParserState {
                 if invalid_await_heritage && self.is_token(SyntaxKind::GreaterThanToken) {
                     self.parse_error_at_current_token("',' expected.", diagnostic_codes::EXPECTED);
                 }
+                let unclosed_end = self.end_of_unclosed_node(SyntaxKind::GreaterThanToken);
                 self.parse_expected(SyntaxKind::GreaterThanToken);
                 if self.is_token(SyntaxKind::OpenParenToken) {
                     self.next_token();
@@ -1283,7 +1284,7 @@ impl ParserState {
                     Some(self.arena.add_expr_with_type_args(
                         syntax_kind_ext::EXPRESSION_WITH_TYPE_ARGUMENTS,
                         start_pos,
-                        self.token_end(),
+                        unclosed_end.unwrap_or_else(|| self.token_end()),
                         crate::parser::node::ExprWithTypeArgsData {
                             expression: expr,
                             type_arguments: Some(self.make_node_list(type_args)),
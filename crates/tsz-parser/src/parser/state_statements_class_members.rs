@@ -676,7 +676,7 @@ impl ParserState {
         self.context_flags |= CONTEXT_FLAG_FUNCTION_BODY;
         self.push_label_scope();
         let body = if self.is_token(SyntaxKind::OpenBraceToken) {
-            self.parse_block()
+            self.parse_function_body_block()
         } else {
             NodeIndex::NONE
         };
@@ -845,7 +845,7 @@ impl ParserState {
         self.context_flags |= CONTEXT_FLAG_FUNCTION_BODY;
         self.push_label_scope();
         let body = if self.is_token(SyntaxKind::OpenBraceToken) {
-            self.parse_block()
+            self.parse_function_body_block()
         } else {
             let has_abstract = modifiers.as_ref().is_some_and(|mods| {
                 mods.nodes.iter().any(|&idx| {
@@ -1936,7 +1936,7 @@ impl ParserState {
         let body = if body_already_consumed_by_recovery {
             NodeIndex::NONE
         } else if self.is_token(SyntaxKind::OpenBraceToken) {
-            self.parse_block()
+            self.parse_function_body_block()
         } else {
             // Consume the semicolon if present (method signature).
             // Use can_parse_semicolon() which handles ASI: a preceding line break
@@ -635,7 +635,7 @@ impl ParserState {
 
         // Self-closing element: /> or error recovery
         self.parse_expected(SyntaxKind::SlashToken);
-        let end_pos = self.token_end();
+        let end_pos = self.end_of_node_closed_by(SyntaxKind::GreaterThanToken);
         if self.parse_expected(SyntaxKind::GreaterThanToken) {
             // Consumed >; no further action needed
         }
@@ -1071,9 +1071,10 @@ impl ParserState {
             );
         }
         let expression = self.parse_expression();
+        let unclosed_end = self.end_of_unclosed_node(SyntaxKind::CloseBraceToken);
         self.parse_expected(SyntaxKind::CloseBraceToken);
 
-        let end_pos = self.token_end();
+        let end_pos = unclosed_end.unwrap_or_else(|| self.token_end());
         self.arena.add_jsx_spread_attribute(
             syntax_kind_ext::JSX_SPREAD_ATTRIBUTE,
             start_pos,
@@ -1123,9 +1124,10 @@ impl ParserState {
             self.parse_jsx_embedded_expression()
         };
 
+        let unclosed_end = self.end_of_unclosed_node(SyntaxKind::CloseBraceToken);
         self.parse_expected(SyntaxKind::CloseBraceToken);
 
-        let end_pos = self.token_end();
+        let end_pos = unclosed_end.unwrap_or_else(|| self.token_end());
         self.arena.add_jsx_expression(
             syntax_kind_ext::JSX_EXPRESSION,
             start_pos,
@@ -1169,6 +1171,7 @@ impl ParserState {
             self.parse_jsx_embedded_expression()
         };
 
+        let unclosed_end = self.end_of_unclosed_node(SyntaxKind::CloseBraceToken);
         if self.is_token(SyntaxKind::CloseBraceToken) {
             self.next_token();
         } else if !suppress_missing_close_brace_error {
@@ -1187,7 +1190,7 @@ impl ParserState {
                 .saturating_add(1);
         }
 
-        let end_pos = self.token_end();
+        let end_pos = unclosed_end.unwrap_or_else(|| self.token_end());
         self.arena.add_jsx_expression(
             syntax_kind_ext::JSX_EXPRESSION,
             start_pos,
@@ -470,7 +470,7 @@ impl ParserState {
         self.push_label_scope();
         let mut recovered_arrow_body = false;
         let body = if self.is_token(SyntaxKind::OpenBraceToken) {
-            self.parse_block()
+            self.parse_function_body_block()
         } else if self.is_token(SyntaxKind::EqualsGreaterThanToken) {
             // TS1144: '{' or ';' expected — user wrote arrow syntax on a function declaration
             self.parse_error_at_current_token(
@@ -595,7 +595,7 @@ impl ParserState {
 
         let mut recovered_arrow_body = false;
         let body = if self.is_token(SyntaxKind::OpenBraceToken) {
-            self.parse_block()
+            self.parse_function_body_block()
         } else if self.is_token(SyntaxKind::EqualsGreaterThanToken) {
             self.parse_error_at_current_token(
                 "'{' or ';' expected.",
@@ -738,7 +738,7 @@ impl ParserState {
         // Parse body (context flags remain set for await/yield expressions in body)
         // Push a new label scope for the function body
         self.push_label_scope();
-        let body = self.parse_block();
+        let body = self.parse_function_body_block();
         self.pop_label_scope();
 
         // Restore context flags
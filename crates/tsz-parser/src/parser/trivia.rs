@@ -0,0 +1,73 @@
+//! Leading trivia of nodes.
+//!
+//! A node's `pos` is the start of its first token. Its leading trivia (the
+//! whitespace and comments between the previous token and that start) is
+//! not part of the node, so tools that rewrite source text need the full
+//! start separately. [`NodeTrivia::compute`] records it for every node of a
+//! parsed file in a pass over the finished arena, so parsing itself does not
+//! change; run it after any deferred function bodies have been parsed.
+
+use super::NodeIndex;
+use super::node::NodeArena;
+use tsz_common::comments::CommentRange;
+
+/// The full start (start of leading trivia) of every node in an arena.
+#[derive(Clone, Debug, Default)]
+pub struct NodeTrivia {
+    full_starts: Vec<u32>,
+}
+
+impl NodeTrivia {
+    /// Compute the full start of every node in `arena`, which holds the file
+    /// at `source_file`.
+    #[must_use]
+    pub fn compute(arena: &NodeArena, source_file: NodeIndex) -> Self {
+        let Some(data) = arena
+            .get(source_file)
+            .and_then(|node| arena.get_source_file(node))
+        else {
+            return Self::default();
+        };
+        let full_starts = arena
+            .nodes
+            .iter()
+            .map(|node| leading_trivia_start(&data.text, &data.comments, node.pos))
+            .collect();
+        Self { full_starts }
+    }
+
+    /// Start of `node`'s leading trivia; equal to its `pos` when none.
+    #[must_use]
+    pub fn full_start(&self, node: NodeIndex) -> Option<u32> {
+        self.full_starts.get(node.0 as usize).copied()
+    }
+
+    /// The whitespace and comments before `node` in `text`.
+    #[must_use]
+    pub fn leading_trivia<'a>(&self, arena: &NodeArena, node: NodeIndex, text: &'a str) -> &'a str {
+        let (Some(full_start), Some(node)) = (self.full_start(node), arena.get(node)) else {
+            return "";
+        };
+        text.get(full_start as usize..node.pos as usize)
+            .unwrap_or("")
+    }
+}
+
+/// Walk back from `pos` over whitespace and the `comments` that end where
+/// the walk stands.
+fn leading_trivia_start(text: &str, comments: &[CommentRange], pos: u32) -> u32 {
+    let mut start = (pos as usize).min(text.len());
+    loop {
+        let before = text.get(..start).unwrap_or("");
+        start = before
+            .trim_end_matches(|c: char| c.is_whitespace() || c == '\u{FEFF}')
+            .len();
+        let idx = comments.partition_point(|comment| (comment.end as usize) < start);
+        match comments.get(idx) {
+            Some(comment) if comment.end as usize == start && comment.pos < comment.end => {
+                start = comment.pos as usize;
+            }
+            _ => return u32::try_from(start).unwrap_or(pos),
+        }
+    }
+}
@@ -233,3 +233,99 @@ fn decorator_after_property_name_produces_ts1436() {
         "late property-name decorator recovery should not add a semicolon error"
     );
 }
+
+/// Decorators of the class declaration under an `export` wrapper.
+fn exported_class_decorators(source: &str) -> (ParserState, Vec<(u32, u32)>) {
+    let (parser, root) = parse_source(source);
+    let arena = parser.get_arena();
+    let statement = arena
+        .get_source_file(arena.get(root).unwrap())
+        .unwrap()
+        .statements
+        .nodes[0];
+    let export = arena
+        .get_export_decl(arena.get(statement).unwrap())
+        .unwrap();
+    let class = arena
+        .get_class(arena.get(export.export_clause).unwrap())
+        .unwrap();
+    let decorators = class
+        .modifiers
+        .as_ref()
+        .map(|modifiers| {
+            modifiers
+                .nodes
+                .iter()
+                .filter_map(|&idx| arena.get(idx))
+                .filter(|node| node.kind == syntax_kind_ext::DECORATOR)
+                .map(|node| {
+                    let expression = arena.get_decorator(node).unwrap().expression;
+                    let expression = arena.get(expression).unwrap();
+                    (expression.pos, expression.end)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    (parser, decorators)
+}
+
+/// Decorators may come before or after `export` / `export default`.
+#[test]
+fn decorators_before_or_after_export_attach_to_class() {
+    for source in [
+        "@dec export class C {}",
+        "export @dec class C {}",
+        "export default @dec class {}",
+        "@dec export default class {}",
+        "export @dec abstract class C {}",
+    ] {
+        let (parser, decorators) = exported_class_decorators(source);
+        assert!(parser.get_diagnostics().is_empty(), "{source}");
+        let dec = u32::try_from(source.find("dec").unwrap()).unwrap();
+        assert_eq!(decorators, [(dec, dec + 3)], "{source}");
+    }
+}
+
+/// `@a export @b class C {}` keeps both decorators on the class and reports
+/// TS8038 once, at the first decorator after `export`.
+#[test]
+fn decorators_both_before_and_after_export_produce_ts8038() {
+    for source in [
+        "@a export @b @c class C {}",
+        "@a export default @b @c class {}",
+    ] {
+        let (parser, decorators) = exported_class_decorators(source);
+        let b = u32::try_from(source.find('b').unwrap()).unwrap();
+        let c = u32::try_from(source.find('c').unwrap()).unwrap();
+        assert_eq!(decorators, [(1, 2), (b, b + 1), (c, c + 1)], "{source}");
+
+        let ts8038: Vec<_> = parser
+            .get_diagnostics()
+            .iter()
+            .filter(|diag| diag.code == 8038)
+            .map(|diag| (diag.start, diag.length))
+            .collect();
+        assert_eq!(ts8038, [(b, 1)], "{source}");
+    }
+}
+
+/// `export @dec function f() {}` reports TS1206 and still parses the function.
+#[test]
+fn decorator_after_export_on_function_produces_ts1206() {
+    let (parser, root) = parse_source("export @dec function f() {}");
+    assert!(has_error_code(&parser, 1206));
+    assert!(!has_error_code(&parser, 1129));
+    let arena = parser.get_arena();
+    let statement = arena
+        .get_source_file(arena.get(root).unwrap())
+        .unwrap()
+        .statements
+        .nodes[0];
+    let export = arena
+        .get_export_decl(arena.get(statement).unwrap())
+        .unwrap();
+    assert_eq!(
+        arena.get(export.export_clause).unwrap().kind,
+        syntax_kind_ext::FUNCTION_DECLARATION
+    );
+}
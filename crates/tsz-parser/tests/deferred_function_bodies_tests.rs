@@ -0,0 +1,137 @@
+//! Tests for deferred parsing of function bodies.
+
+use crate::parser::node::VisitControl;
+use crate::parser::{NodeIndex, ParserState, TextChange, syntax_kind_ext};
+
+fn parse(file_name: &str, source: &str, defer: bool) -> (ParserState, NodeIndex) {
+    let mut parser = ParserState::new(file_name.to_string(), source.to_string());
+    parser.set_defer_function_bodies(defer);
+    let root = parser.parse_source_file();
+    (parser, root)
+}
+
+/// `(kind, pos, end)` of every node under `root`, in pre-order.
+fn shape(parser: &ParserState, root: NodeIndex) -> Vec<(u16, u32, u32)> {
+    let mut nodes = Vec::new();
+    parser.get_arena().for_each_descendant(root, |_, node| {
+        nodes.push((node.kind, node.pos, node.end));
+        VisitControl::Continue
+    });
+    nodes
+}
+
+fn diagnostics(parser: &ParserState) -> Vec<(u32, u32)> {
+    parser
+        .get_diagnostics()
+        .iter()
+        .map(|diag| (diag.code, diag.start))
+        .collect()
+}
+
+const SOURCE: &str = r#"export function f(a: number) {
+    const o = { get x() { return 1; }, m() { return `${ { b: 2 }.b }}`; } };
+    if (/\{+/.test("{") && a / 2 > 1) { return () => { let t = `a${a}b${`}`}c`; }; }
+}
+export class C {
+    constructor() { this.v = 1; }
+    get v() { return 0; }
+    set v(value) { }
+    async *gen() { yield await f(1); }
+}
+const arrow = async (x: string) => { await x; };
+const expr = function () { let = ; };
+"#;
+
+#[test]
+fn test_deferred_bodies_parse_to_the_eager_tree() {
+    let (eager, eager_root) = parse("a.ts", SOURCE, false);
+    let (mut deferred, deferred_root) = parse("a.ts", SOURCE, true);
+
+    let bodies = deferred.deferred_function_bodies();
+    assert_eq!(bodies.len(), 7, "f, the class members and the two arrows");
+    assert!(bodies.iter().all(|&body| {
+        let arena = deferred.get_arena();
+        arena
+            .get(body)
+            .and_then(|node| arena.get_block(node))
+            .is_some_and(|block| block.statements.nodes.is_empty())
+    }));
+    // Errors inside a skipped body are reported once it is parsed.
+    assert!(deferred.get_diagnostics().is_empty());
+    assert!(!eager.get_diagnostics().is_empty());
+
+    deferred.parse_all_deferred_function_bodies();
+    assert!(deferred.deferred_function_bodies().is_empty());
+    assert_eq!(shape(&deferred, deferred_root), shape(&eager, eager_root));
+    assert_eq!(diagnostics(&deferred), diagnostics(&eager));
+}
+
+#[test]
+fn test_parse_deferred_bodies_at_position() {
+    let (mut parser, root) = parse("a.ts", SOURCE, true);
+    let pos = u32::try_from(SOURCE.find("t = `a").unwrap()).unwrap();
+
+    // `f`, then the arrow function nested inside it.
+    assert_eq!(parser.parse_deferred_function_bodies_at(pos), 2);
+    assert_eq!(parser.parse_deferred_function_bodies_at(pos), 0);
+    let arena = parser.get_arena();
+    let mut found = false;
+    arena.for_each_descendant(root, |_, node| {
+        found |=
+            node.kind == syntax_kind_ext::VARIABLE_DECLARATION && node.pos <= pos && pos < node.end;
+        VisitControl::Continue
+    });
+    assert!(found, "the declaration at `pos` is in the tree");
+    // Bodies elsewhere stay deferred: the class members, the top-level
+    // functions, and the object members in `f` that were deferred in turn.
+    assert_eq!(parser.deferred_function_bodies().len(), 8);
+}
+
+#[test]
+fn test_bodies_that_cannot_be_skipped_are_parsed_eagerly() {
+    // JSX text is not tokenized like code.
+    let (parser, _) = parse("a.tsx", "function f() { return <p>don't {x}</p>; }", true);
+    assert!(parser.deferred_function_bodies().is_empty());
+
+    // An unterminated body ends at EOF with the usual error.
+    let source = "function f() { let x = 1;\n";
+    let (eager, eager_root) = parse("a.ts", source, false);
+    let (deferred, deferred_root) = parse("a.ts", source, true);
+    assert!(deferred.deferred_function_bodies().is_empty());
+    assert_eq!(shape(&deferred, deferred_root), shape(&eager, eager_root));
+    assert_eq!(diagnostics(&deferred), diagnostics(&eager));
+}
+
+#[test]
+fn test_update_keeps_deferred_bodies_outside_the_edit() {
+    let source = "function a() { return 1; }\nfunction b() { return 2; }\nfunction c() { return 3; }\nfunction d() { let = ; }\n";
+    let (mut parser, root) = parse("a.ts", source, true);
+    assert_eq!(parser.deferred_function_bodies().len(), 4);
+
+    let start = u32::try_from(source.find("return 3").unwrap()).unwrap();
+    let root = parser.update(
+        root,
+        &[TextChange {
+            start,
+            end: start + u32::try_from("return 3".len()).unwrap(),
+            new_text: "return 3 + 30".to_string(),
+        }],
+    );
+    let new_source = source.replace("return 3", "return 3 + 30");
+
+    // `a` is kept, `b` and `c` are re-parsed and deferred again, and `d`
+    // is kept at its new position.
+    let bodies = parser.deferred_function_bodies();
+    assert_eq!(bodies.len(), 4);
+    let arena = parser.get_arena();
+    let d_body = *bodies.last().unwrap();
+    assert_eq!(
+        arena.get(d_body).map(|node| node.pos),
+        u32::try_from(new_source.find("{ let").unwrap()).ok()
+    );
+
+    parser.parse_all_deferred_function_bodies();
+    let (eager, eager_root) = parse("a.ts", &new_source, false);
+    assert_eq!(shape(&parser, root), shape(&eager, eager_root));
+    assert_eq!(diagnostics(&parser), diagnostics(&eager));
+}
@@ -0,0 +1,181 @@
+//! Tests for IDE recovery mode (`ParserState::set_ide_recovery`), which is
+//! on by default.
+
+use crate::parser::node::{NodeAccess, NodeArena};
+use crate::parser::{NodeIndex, ParserState, syntax_kind_ext};
+
+fn parse(source: &str, ide_recovery: bool) -> (ParserState, NodeIndex) {
+    parse_file("test.ts", source, ide_recovery)
+}
+
+fn parse_file(file_name: &str, source: &str, ide_recovery: bool) -> (ParserState, NodeIndex) {
+    let mut parser = ParserState::new(file_name.to_string(), source.to_string());
+    parser.set_ide_recovery(ide_recovery);
+    let root = parser.parse_source_file();
+    (parser, root)
+}
+
+/// Parse `source` in IDE recovery mode, checking that the diagnostics match
+/// the mode turned off.
+fn parse_ide(source: &str) -> (ParserState, NodeIndex) {
+    parse_ide_file("test.ts", source)
+}
+
+fn parse_ide_file(file_name: &str, source: &str) -> (ParserState, NodeIndex) {
+    let (default, _) = parse_file(file_name, source, false);
+    let (parser, root) = parse_file(file_name, source, true);
+    let codes = |parser: &ParserState| -> Vec<(u32, u32)> {
+        parser
+            .get_diagnostics()
+            .iter()
+            .map(|diag| (diag.start, diag.code))
+            .collect()
+    };
+    assert_eq!(codes(&parser), codes(&default), "{source}");
+    (parser, root)
+}
+
+fn find_kind(arena: &NodeArena, root: NodeIndex, kind: u16) -> NodeIndex {
+    let mut pending = vec![root];
+    while let Some(idx) = pending.pop() {
+        if arena.get(idx).is_some_and(|node| node.kind == kind) {
+            return idx;
+        }
+        pending.extend(arena.get_children(idx).into_iter().rev());
+    }
+    panic!("no node of kind {kind}");
+}
+
+fn offset(source: &str, text: &str) -> u32 {
+    u32::try_from(source.find(text).expect("text exists")).unwrap()
+}
+
+#[test]
+fn test_missing_member_name_is_a_placeholder_after_the_dot() {
+    for (source, dot) in [("obj.\nlet x = 1;\n", "."), ("obj?.;\n", "?.")] {
+        let (parser, root) = parse_ide(source);
+        let arena = parser.get_arena();
+        let access_idx = find_kind(arena, root, syntax_kind_ext::PROPERTY_ACCESS_EXPRESSION);
+        let access_node = arena.get(access_idx).unwrap();
+        let access = arena.get_access_expr(access_node).unwrap();
+        let after_dot = offset(source, dot) + dot.len() as u32;
+
+        let name = arena
+            .get(access.name_or_argument)
+            .expect("a placeholder name");
+        assert!(arena.is_missing_recovery_identifier(access.name_or_argument));
+        assert_eq!((name.pos, name.end), (after_dot, after_dot), "{source}");
+        assert_eq!(access_node.end, after_dot, "{source}");
+    }
+}
+
+#[test]
+fn test_recovery_off_keeps_the_missing_member_name_empty() {
+    let source = "obj.\nlet x = 1;\n";
+    let (parser, root) = parse(source, false);
+    let arena = parser.get_arena();
+    let access_idx = find_kind(arena, root, syntax_kind_ext::PROPERTY_ACCESS_EXPRESSION);
+    let access = arena
+        .get_access_expr(arena.get(access_idx).unwrap())
+        .unwrap();
+    assert!(access.name_or_argument.is_none());
+}
+
+#[test]
+fn test_unclosed_call_and_element_access_end_at_their_last_token() {
+    for (source, last) in [("foo(a, b\nlet y = 2;\n", "b"), ("obj[1\n", "1")] {
+        let (parser, root) = parse_ide(source);
+        let arena = parser.get_arena();
+        let kind = if source.starts_with("foo") {
+            syntax_kind_ext::CALL_EXPRESSION
+        } else {
+            syntax_kind_ext::ELEMENT_ACCESS_EXPRESSION
+        };
+        let node = arena.get(find_kind(arena, root, kind)).unwrap();
+        assert_eq!(node.end, offset(source, last) + 1, "{source}");
+    }
+
+    // A closed call still includes its `)`.
+    let source = "foo(a);";
+    let (parser, root) = parse_ide(source);
+    let arena = parser.get_arena();
+    let call = arena
+        .get(find_kind(arena, root, syntax_kind_ext::CALL_EXPRESSION))
+        .unwrap();
+    assert_eq!(call.end, offset(source, ")") + 1);
+}
+
+#[test]
+fn test_missing_arguments_are_placeholders() {
+    let source = "foo(a, , c);";
+    let (parser, root) = parse_ide(source);
+    let arena = parser.get_arena();
+    let call_node = arena
+        .get(find_kind(arena, root, syntax_kind_ext::CALL_EXPRESSION))
+        .unwrap();
+    let arguments = &arena
+        .get_call_expr(call_node)
+        .unwrap()
+        .arguments
+        .as_ref()
+        .unwrap()
+        .nodes;
+    assert_eq!(arguments.len(), 3);
+    assert!(arguments.iter().all(|arg| arg.is_some()));
+    assert!(arena.is_missing_recovery_identifier(arguments[1]));
+}
+
+#[test]
+fn test_ide_recovery_is_on_by_default() {
+    let mut parser = ParserState::new("test.ts".to_string(), "foo(a, , c);".to_string());
+    let root = parser.parse_source_file();
+    let arena = parser.get_arena();
+    let call_node = arena
+        .get(find_kind(arena, root, syntax_kind_ext::CALL_EXPRESSION))
+        .unwrap();
+    let arguments = &arena
+        .get_call_expr(call_node)
+        .unwrap()
+        .arguments
+        .as_ref()
+        .unwrap()
+        .nodes;
+    assert!(arena.is_missing_recovery_identifier(arguments[1]));
+}
+
+#[test]
+fn test_unclosed_type_arguments_and_jsx_end_at_their_last_token() {
+    let source = "class A extends Base<T {\n}\n";
+    let (parser, root) = parse_ide(source);
+    let arena = parser.get_arena();
+    let heritage = arena
+        .get(find_kind(
+            arena,
+            root,
+            syntax_kind_ext::EXPRESSION_WITH_TYPE_ARGUMENTS,
+        ))
+        .unwrap();
+    assert_eq!(heritage.end, offset(source, "T") + 1);
+
+    for (source, kind, last) in [
+        (
+            "const el = <div>{value</div>;\n",
+            syntax_kind_ext::JSX_EXPRESSION,
+            "value",
+        ),
+        (
+            "const el = <div a={1} /;\n",
+            syntax_kind_ext::JSX_SELF_CLOSING_ELEMENT,
+            "/",
+        ),
+    ] {
+        let (parser, root) = parse_ide_file("test.tsx", source);
+        let arena = parser.get_arena();
+        let node = arena.get(find_kind(arena, root, kind)).unwrap();
+        assert_eq!(
+            node.end,
+            offset(source, last) + last.len() as u32,
+            "{source}"
+        );
+    }
+}
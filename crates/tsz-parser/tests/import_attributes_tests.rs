@@ -0,0 +1,110 @@
+//! Tests for import attributes on declarations and dynamic imports.
+
+use crate::parser::import_attributes::{ImportAttributesKeyword, import_attributes_of};
+use crate::parser::node::{NodeAccess, NodeArena};
+use crate::parser::test_fixture::parse_source;
+use crate::parser::{NodeIndex, syntax_kind_ext};
+
+fn statements(arena: &NodeArena, root: NodeIndex) -> Vec<NodeIndex> {
+    arena
+        .get_source_file(arena.get(root).unwrap())
+        .unwrap()
+        .statements
+        .nodes
+        .clone()
+}
+
+fn offset(source: &str, text: &str) -> u32 {
+    u32::try_from(source.find(text).expect("text exists")).unwrap()
+}
+
+#[test]
+fn test_declaration_attributes_have_nodes_and_spans() {
+    let source = "import data from \"./data.json\" with { type: \"json\", \"resolution-mode\": \"import\" };\nexport { x } from \"./x.js\" assert {\n    type: \"js\"\n};\n";
+    let (parser, root) = parse_source(source);
+    assert!(parser.get_diagnostics().is_empty());
+    let arena = parser.get_arena();
+    let statements = statements(arena, root);
+
+    let import = import_attributes_of(arena, statements[0]).expect("import attributes");
+    assert_eq!(import.keyword, ImportAttributesKeyword::With);
+    assert_eq!(
+        arena.get(import.node).unwrap().kind,
+        syntax_kind_ext::IMPORT_ATTRIBUTES
+    );
+    assert_eq!(import.pos, offset(source, "with"));
+    assert_eq!(import.end, offset(source, "};") + 1);
+    let names: Vec<_> = import
+        .attributes
+        .iter()
+        .map(|entry| entry.name_text.as_str())
+        .collect();
+    assert_eq!(names, ["type", "resolution-mode"]);
+    let ty = import.get("type").unwrap();
+    assert_eq!(ty.pos, offset(source, "type:"));
+    assert_eq!(arena.get_literal_text(ty.value), Some("json"));
+
+    let export = import_attributes_of(arena, statements[1]).expect("export attributes");
+    assert_eq!(export.keyword, ImportAttributesKeyword::Assert);
+    let data = arena
+        .get_import_attributes_data(arena.get(export.node).unwrap())
+        .unwrap();
+    assert!(data.multi_line);
+    assert!(
+        import_attributes_of(arena, statements[0]).is_some_and(|clause| {
+            let node = arena.get(clause.node).unwrap();
+            !arena.get_import_attributes_data(node).unwrap().multi_line
+        })
+    );
+}
+
+#[test]
+fn test_assert_on_the_next_line_is_not_an_attributes_clause() {
+    let source = "import a from \"./a\"\nassert({ type: \"json\" });\n";
+    let (parser, root) = parse_source(source);
+    assert!(parser.get_diagnostics().is_empty());
+    let arena = parser.get_arena();
+    let statements = statements(arena, root);
+    assert_eq!(statements.len(), 2);
+    assert!(import_attributes_of(arena, statements[0]).is_none());
+    assert_eq!(
+        arena.get(statements[1]).unwrap().kind,
+        syntax_kind_ext::EXPRESSION_STATEMENT
+    );
+}
+
+#[test]
+fn test_dynamic_import_attributes() {
+    let source = "const a = import(\"./a.json\", { with: { type: \"json\" } });\nconst b = import(\"./b.json\", { assert: { \"type\": \"json\" } });\nconst c = import(\"./c.js\");\nconst d = f(\"./d.json\", { with: { type: \"json\" } });\n";
+    let (parser, _) = parse_source(source);
+    let arena = parser.get_arena();
+    let calls: Vec<NodeIndex> = (0..arena.len())
+        .map(|idx| NodeIndex(u32::try_from(idx).unwrap()))
+        .filter(|&idx| {
+            arena
+                .get(idx)
+                .is_some_and(|node| node.kind == syntax_kind_ext::CALL_EXPRESSION)
+        })
+        .collect();
+    assert_eq!(calls.len(), 4);
+
+    let a = import_attributes_of(arena, calls[0]).expect("`with` options");
+    assert_eq!(a.keyword, ImportAttributesKeyword::With);
+    assert_eq!(
+        arena.get(a.node).unwrap().kind,
+        syntax_kind_ext::OBJECT_LITERAL_EXPRESSION
+    );
+    assert_eq!(a.pos, offset(source, "{ type"));
+    let ty = a.get("type").expect("a type attribute");
+    assert_eq!(arena.get_literal_text(ty.value), Some("json"));
+
+    let b = import_attributes_of(arena, calls[1]).expect("`assert` options");
+    assert_eq!(b.keyword, ImportAttributesKeyword::Assert);
+    assert!(b.get("type").is_some());
+
+    assert!(import_attributes_of(arena, calls[2]).is_none());
+    assert!(
+        import_attributes_of(arena, calls[3]).is_none(),
+        "not a dynamic import"
+    );
+}
@@ -0,0 +1,155 @@
+//! Tests for `ParserState::update`: re-parsing after text edits must produce
+//! the same tree and diagnostics as a fresh parse, while keeping the nodes
+//! of statements outside the edit.
+
+use crate::parser::node::{NodeAccess, NodeArena};
+use crate::parser::{NodeIndex, ParserState, TextChange};
+
+fn parse(source: &str) -> (ParserState, NodeIndex) {
+    let mut parser = ParserState::new("test.ts".to_string(), source.to_string());
+    let root = parser.parse_source_file();
+    (parser, root)
+}
+
+fn change(source: &str, old: &str, new_text: &str) -> TextChange {
+    let start = source.find(old).expect("edited text exists");
+    TextChange {
+        start: u32::try_from(start).unwrap(),
+        end: u32::try_from(start + old.len()).unwrap(),
+        new_text: new_text.to_string(),
+    }
+}
+
+/// `(kind, pos, end)` for every node of the tree, in pre-order.
+fn tree_shape(arena: &NodeArena, root: NodeIndex) -> Vec<(u16, u32, u32)> {
+    let mut shape = Vec::new();
+    let mut pending = vec![root];
+    while let Some(idx) = pending.pop() {
+        let node = arena.get(idx).expect("child exists");
+        shape.push((node.kind, node.pos, node.end));
+        pending.extend(arena.get_children(idx).into_iter().rev());
+    }
+    shape
+}
+
+fn statements(parser: &ParserState, root: NodeIndex) -> Vec<NodeIndex> {
+    let arena = parser.get_arena();
+    let node = arena.get(root).unwrap();
+    arena
+        .get_source_file(node)
+        .unwrap()
+        .statements
+        .nodes
+        .clone()
+}
+
+/// Apply `changes` incrementally and check the result against a fresh parse
+/// of the new text. Returns the updated parser and root.
+fn update_and_compare(source: &str, changes: &[TextChange]) -> (ParserState, NodeIndex) {
+    let (mut parser, root) = parse(source);
+    let root = parser.update(root, changes);
+
+    let new_text = parser.get_source_text().to_string();
+    let (fresh, fresh_root) = parse(&new_text);
+    assert_eq!(
+        tree_shape(parser.get_arena(), root),
+        tree_shape(fresh.get_arena(), fresh_root),
+        "incremental tree differs from a fresh parse of:\n{new_text}"
+    );
+    let codes = |parser: &ParserState| -> Vec<(u32, u32)> {
+        parser
+            .get_diagnostics()
+            .iter()
+            .map(|diag| (diag.start, diag.code))
+            .collect()
+    };
+    assert_eq!(codes(&parser), codes(&fresh), "{new_text}");
+    (parser, root)
+}
+
+#[test]
+fn test_update_reuses_statements_before_and_after_the_edit() {
+    let source = "const a = 1;\nconst b = 2;\nfunction f() {\n    return a + b;\n}\nconst c = 3;\nconst d = 4;\n";
+    let (mut parser, root) = parse(source);
+    let before = statements(&parser, root);
+
+    let edit = change(source, "return a + b;", "return a * b + 10;");
+    let root = parser.update(root, &[edit]);
+    let after = statements(&parser, root);
+
+    assert_eq!(after.len(), before.len());
+    assert_eq!(after[0], before[0], "the first statement is kept");
+    assert_ne!(after[2], before[2], "the edited function is re-parsed");
+    assert_eq!(
+        &after[3..],
+        &before[3..],
+        "statements after the edit are kept"
+    );
+    assert!(parser.get_source_text().contains("return a * b + 10;"));
+}
+
+#[test]
+fn test_update_matches_a_fresh_parse() {
+    let source = "import { x } from './x';\nlet a = x;\nlet b = a as number;\nconst o = { a, b };\nexport class C {\n    m() { return o; }\n}\nlet e = b satisfies number;\n";
+    update_and_compare(source, &[change(source, "let b = a", "let bee = a")]);
+    update_and_compare(source, &[change(source, "const o", "var o")]);
+    update_and_compare(
+        source,
+        &[
+            change(source, "let a", "var a"),
+            change(source, "return o;", "return o.a;"),
+        ],
+    );
+}
+
+#[test]
+fn test_update_reparses_the_statement_before_the_edit() {
+    // Inserting `(b)` at the start of the line continues the call `a(b)`.
+    let source = "let q = 1;\nlet r = a\nlet s = 2;\nlet t = 3;\n";
+    let insert_at = u32::try_from(source.find("let s").unwrap()).unwrap();
+    let (parser, root) = update_and_compare(
+        source,
+        &[TextChange {
+            start: insert_at,
+            end: insert_at,
+            new_text: "(b)\n".to_string(),
+        }],
+    );
+    assert_eq!(statements(&parser, root).len(), 4);
+}
+
+#[test]
+fn test_update_shifts_diagnostics_after_the_edit() {
+    let source = "const a = 1;\nconst b = 2;\nconst c = 3;\nconst d = 4;\nconst e = ;\n";
+    let (parser, _) =
+        update_and_compare(source, &[change(source, "const c = 3;", "const cc = 33;")]);
+    assert_eq!(parser.get_diagnostics().len(), 1);
+
+    // An edit that introduces an error keeps the later one too.
+    update_and_compare(source, &[change(source, "const c = 3;", "const c = ;")]);
+}
+
+#[test]
+fn test_update_falls_back_to_a_full_parse_for_invalid_changes() {
+    let source = "const a = 1;\nconst b = 2;\n";
+    let (mut parser, root) = parse(source);
+    let overlapping = [
+        TextChange {
+            start: 6,
+            end: 10,
+            new_text: "x".to_string(),
+        },
+        TextChange {
+            start: 8,
+            end: 12,
+            new_text: "y".to_string(),
+        },
+    ];
+    let root = parser.update(root, &overlapping);
+    assert_eq!(
+        parser.get_source_text(),
+        source,
+        "the text is left unchanged"
+    );
+    assert_eq!(statements(&parser, root).len(), 2);
+}
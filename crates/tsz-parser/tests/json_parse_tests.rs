@@ -0,0 +1,170 @@
+//! Tests for JSON source files (`ParserState::parse_json_text`).
+
+use crate::parser::node::NodeArena;
+use crate::parser::state::{JsonParseOptions, ParserState};
+use crate::parser::{NodeIndex, syntax_kind_ext};
+use tsz_common::diagnostics::diagnostic_codes;
+
+fn parse_json(source: &str, options: JsonParseOptions) -> (ParserState, NodeIndex) {
+    let mut parser = ParserState::new("data.json".to_string(), source.to_string());
+    let root = parser.parse_json_text(options);
+    (parser, root)
+}
+
+/// `(code, start, length)` of each diagnostic.
+fn diagnostics(parser: &ParserState) -> Vec<(u32, u32, u32)> {
+    parser
+        .get_diagnostics()
+        .iter()
+        .map(|diag| (diag.code, diag.start, diag.length))
+        .collect()
+}
+
+/// The expression of the file's only statement.
+fn root_value(arena: &NodeArena, root: NodeIndex) -> NodeIndex {
+    let source_file = arena.get_source_file(arena.get(root).unwrap()).unwrap();
+    assert_eq!(source_file.statements.nodes.len(), 1);
+    let statement = arena.get(source_file.statements.nodes[0]).unwrap();
+    arena
+        .get_expression_statement(statement)
+        .unwrap()
+        .expression
+}
+
+fn offset(source: &str, text: &str) -> u32 {
+    u32::try_from(source.find(text).expect("text exists")).unwrap()
+}
+
+#[test]
+fn test_json_file_holds_its_root_value() {
+    let source = "{\n  \"name\": \"x\",\n  \"list\": [1, -2, true, null, { \"a\": \"b\" }]\n}\n";
+    let (parser, root) = parse_json(source, JsonParseOptions::default());
+    assert!(
+        diagnostics(&parser).is_empty(),
+        "{:?}",
+        diagnostics(&parser)
+    );
+    let arena = parser.get_arena();
+    let source_file = arena.get_source_file(arena.get(root).unwrap()).unwrap();
+    assert_eq!(source_file.script_kind, 6);
+
+    let value = arena.get(root_value(arena, root)).unwrap();
+    assert_eq!(value.kind, syntax_kind_ext::OBJECT_LITERAL_EXPRESSION);
+    assert_eq!((value.pos, value.end), (0, offset(source, "}\n") + 1));
+    assert_eq!(
+        arena.get_literal_expr(value).unwrap().elements.nodes.len(),
+        2
+    );
+}
+
+#[test]
+fn test_empty_json_file_has_no_statements() {
+    let (parser, root) = parse_json("  \n", JsonParseOptions::default());
+    assert!(diagnostics(&parser).is_empty());
+    let arena = parser.get_arena();
+    let source_file = arena.get_source_file(arena.get(root).unwrap()).unwrap();
+    assert!(source_file.statements.nodes.is_empty());
+}
+
+#[test]
+fn test_json_names_and_values_are_checked() {
+    let source = "{ name: 1, 'quoted': 'single', \"ok\": x, \"neg\": -\"s\" }";
+    let (parser, _) = parse_json(source, JsonParseOptions::default());
+    assert_eq!(
+        diagnostics(&parser),
+        [
+            (
+                diagnostic_codes::STRING_LITERAL_WITH_DOUBLE_QUOTES_EXPECTED,
+                offset(source, "name"),
+                4
+            ),
+            (
+                diagnostic_codes::STRING_LITERAL_WITH_DOUBLE_QUOTES_EXPECTED,
+                offset(source, "'quoted'"),
+                8
+            ),
+            (
+                diagnostic_codes::STRING_LITERAL_WITH_DOUBLE_QUOTES_EXPECTED,
+                offset(source, "'single'"),
+                8
+            ),
+            (
+                diagnostic_codes::PROPERTY_VALUE_CAN_ONLY_BE_STRING_LITERAL_NUMERIC_LITERAL_TRUE_FALSE_NULL_OBJECT,
+                offset(source, "x,"),
+                1
+            ),
+            (
+                diagnostic_codes::PROPERTY_VALUE_CAN_ONLY_BE_STRING_LITERAL_NUMERIC_LITERAL_TRUE_FALSE_NULL_OBJECT,
+                offset(source, "-\"s\""),
+                4
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_extra_json_root_values_are_wrapped_in_an_array() {
+    let source = "{} [1]";
+    let (parser, root) = parse_json(source, JsonParseOptions::default());
+    assert_eq!(
+        diagnostics(&parser),
+        [(diagnostic_codes::UNEXPECTED_TOKEN, offset(source, "["), 1)]
+    );
+    let arena = parser.get_arena();
+    let value = arena.get(root_value(arena, root)).unwrap();
+    assert_eq!(value.kind, syntax_kind_ext::ARRAY_LITERAL_EXPRESSION);
+    assert_eq!(
+        arena.get_literal_expr(value).unwrap().elements.nodes.len(),
+        2
+    );
+}
+
+#[test]
+fn test_bare_json_properties_report_the_missing_brace() {
+    let source = "\"a\": 1";
+    let (parser, root) = parse_json(source, JsonParseOptions::default());
+    let codes: Vec<_> = diagnostics(&parser)
+        .into_iter()
+        .map(|(code, start, _)| (code, start))
+        .collect();
+    assert_eq!(
+        codes,
+        [
+            (diagnostic_codes::EXPECTED, 0),
+            (diagnostic_codes::EXPECTED, offset(source, "1") + 1),
+        ]
+    );
+    let arena = parser.get_arena();
+    let value = arena.get(root_value(arena, root)).unwrap();
+    assert_eq!(value.kind, syntax_kind_ext::OBJECT_LITERAL_EXPRESSION);
+}
+
+#[test]
+fn test_jsonc_comments_and_trailing_commas_depend_on_options() {
+    let source = "{\n  // comment\n  \"a\": [1, 2, /* last */ ],\n}\n";
+    let (parser, _) = parse_json(source, JsonParseOptions::JSONC);
+    assert!(
+        diagnostics(&parser).is_empty(),
+        "{:?}",
+        diagnostics(&parser)
+    );
+
+    let (parser, _) = parse_json(source, JsonParseOptions::STRICT);
+    assert_eq!(
+        diagnostics(&parser),
+        [
+            (diagnostic_codes::UNEXPECTED_TOKEN, offset(source, "//"), 10),
+            (
+                diagnostic_codes::TRAILING_COMMA_NOT_ALLOWED,
+                offset(source, ", /*"),
+                1
+            ),
+            (diagnostic_codes::UNEXPECTED_TOKEN, offset(source, "/*"), 10),
+            (
+                diagnostic_codes::TRAILING_COMMA_NOT_ALLOWED,
+                offset(source, ",\n}"),
+                1
+            ),
+        ]
+    );
+}
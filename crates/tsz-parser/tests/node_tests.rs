@@ -664,3 +664,60 @@ fn estimated_size_bytes_larger_arena_beats_smaller() {
         "larger source should produce larger estimated_size_bytes: large={large_size} vs small={small_size}",
     );
 }
+
+#[test]
+fn for_each_child_visits_the_children_of_get_children() {
+    let (parser, root) = parse_source("function f(a, b) { return a + b; }");
+    let arena = parser.get_arena();
+    let function = arena.get_children(root)[0];
+    assert!(arena.node_is(function, Node::is_function_like));
+
+    let mut visited = Vec::new();
+    arena.for_each_child(function, |child| visited.push(child));
+    let expected: Vec<_> = arena
+        .get_children(function)
+        .into_iter()
+        .filter(|child| child.is_some())
+        .collect();
+    assert_eq!(visited, expected);
+
+    let body = arena.find_map_child(function, |child| {
+        arena
+            .is_kind(child, syntax_kind_ext::BLOCK)
+            .then_some(child)
+    });
+    assert!(body.is_some());
+    assert_eq!(
+        arena.find_map_child(function, |_| None::<NodeIndex>),
+        None,
+        "no child matches"
+    );
+}
+
+#[test]
+fn for_each_descendant_walks_in_pre_order_and_can_skip_or_stop() {
+    let (parser, root) = parse_source("let x = f(1);\nfunction g() { let y = 2; }\nlet z = 3;");
+    let arena = parser.get_arena();
+
+    let mut identifiers = Vec::new();
+    arena.for_each_descendant(root, |idx, node| {
+        if node.kind == syntax_kind_ext::FUNCTION_DECLARATION {
+            return VisitControl::SkipChildren;
+        }
+        if let Some(text) = arena.get_identifier_text(idx) {
+            identifiers.push(text.to_string());
+        }
+        VisitControl::Continue
+    });
+    assert_eq!(identifiers, ["x", "f", "z"]);
+
+    let mut literals = Vec::new();
+    arena.for_each_descendant(root, |idx, node| {
+        if !node.is_numeric_literal() {
+            return VisitControl::Continue;
+        }
+        literals.extend(arena.get_literal_text(idx).map(str::to_string));
+        VisitControl::Stop
+    });
+    assert_eq!(literals, ["1"]);
+}
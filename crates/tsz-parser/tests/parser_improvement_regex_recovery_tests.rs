@@ -128,6 +128,87 @@ const missing = /[a&&]/v;
     );
 }
 
+#[test]
+fn test_regex_unicode_set_valid_classes_have_no_diagnostics() {
+    let source = r#"
+const nested = /[[a-z]--[aeiou]]/v;
+const intersection = /[\w&&\d]/v;
+const strings = /[\q{a|bc}\p{RGI_Emoji}]/v;
+const empty = /[\q{}]/v;
+const negated = /[^[a]\q{b}]/v;
+"#;
+    let (parser, _root) = parse_source(source);
+    assert!(
+        parser.get_diagnostics().is_empty(),
+        "Expected no diagnostics, got {:?}",
+        parser.get_diagnostics()
+    );
+}
+
+#[test]
+fn test_regex_unicode_set_class_errors_match_tsc() {
+    let cases: &[(&str, u32, &str, u32)] = &[
+        (
+            r"/[a&&b--c]/v",
+            diagnostic_codes::OPERATORS_MUST_NOT_BE_MIXED_WITHIN_A_CHARACTER_CLASS_WRAP_IT_IN_A_NESTED_CLASS_I,
+            "--",
+            2,
+        ),
+        (
+            r"/[ab&&c]/v",
+            diagnostic_codes::OPERATORS_MUST_NOT_BE_MIXED_WITHIN_A_CHARACTER_CLASS_WRAP_IT_IN_A_NESTED_CLASS_I,
+            "&&",
+            2,
+        ),
+        (
+            r"/[a&&&b]/v",
+            diagnostic_codes::UNEXPECTED_DID_YOU_MEAN_TO_ESCAPE_IT_WITH_BACKSLASH,
+            "&b",
+            1,
+        ),
+        (
+            r"/[a!!b]/v",
+            diagnostic_codes::A_CHARACTER_CLASS_MUST_NOT_CONTAIN_A_RESERVED_DOUBLE_PUNCTUATOR_DID_YOU_MEAN_TO,
+            "!!",
+            2,
+        ),
+        (
+            r"/[a(b]/v",
+            diagnostic_codes::UNEXPECTED_DID_YOU_MEAN_TO_ESCAPE_IT_WITH_BACKSLASH,
+            "(",
+            1,
+        ),
+        (
+            r"/[^\q{abc}]/v",
+            diagnostic_codes::ANYTHING_THAT_WOULD_POSSIBLY_MATCH_MORE_THAN_A_SINGLE_CHARACTER_IS_INVALID_INSID,
+            r"\q",
+            7,
+        ),
+        (
+            r"/[\P{RGI_Emoji}]/v",
+            diagnostic_codes::ANYTHING_THAT_WOULD_POSSIBLY_MATCH_MORE_THAN_A_SINGLE_CHARACTER_IS_INVALID_INSID,
+            r"\P",
+            13,
+        ),
+    ];
+    for &(regex, code, at, len) in cases {
+        let source = format!("const r = {regex};");
+        let (parser, _root) = parse_source(&source);
+        let diagnostics = parser.get_diagnostics();
+        assert_eq!(diagnostics.len(), 1, "{regex}: {diagnostics:?}");
+        let start = (source.find(regex).unwrap() + regex.find(at).unwrap()) as u32;
+        assert_eq!(
+            (
+                diagnostics[0].code,
+                diagnostics[0].start,
+                diagnostics[0].length
+            ),
+            (code, start, len),
+            "{regex}: {diagnostics:?}"
+        );
+    }
+}
+
 #[test]
 fn test_regex_hyphen_after_range_is_literal() {
     let source = "const idSuffixPattern = /^([a-z][a-z0-9-]*)(:[a-z0-9-.]*)?$/i;";
@@ -836,6 +836,24 @@ fn parse_mid_file_shebang_reports_ts18026_and_argument_semicolon_error() {
     );
 }
 
+#[test]
+fn parse_shebang_after_bom_is_trivia() {
+    let source = "\u{FEFF}#!/usr/bin/env node\nlet x = 1;\n";
+    let (parser, root) = parse_source(source);
+    assert!(
+        parser.get_diagnostics().is_empty(),
+        "expected no diagnostics, got {:?}",
+        parser.get_diagnostics()
+    );
+    let arena = parser.get_arena();
+    let statements = &arena
+        .get_source_file(arena.get(root).unwrap())
+        .unwrap()
+        .statements
+        .nodes;
+    assert_eq!(statements.len(), 1);
+}
+
 #[test]
 fn parse_malformed_variable_hashbang_tail_matches_tsc_shape() {
     let source =
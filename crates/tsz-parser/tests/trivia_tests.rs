@@ -0,0 +1,38 @@
+//! Tests for leading trivia (`NodeTrivia`).
+
+use crate::parser::ParserState;
+use crate::parser::syntax_kind_ext;
+use crate::parser::trivia::NodeTrivia;
+
+#[test]
+fn test_leading_trivia_covers_whitespace_and_comments() {
+    let source = "let a = 1;\n\n// first\n/* second */  function f(/* p */ x) {}\n";
+    let mut parser = ParserState::new("test.ts".to_string(), source.to_string());
+    let root = parser.parse_source_file();
+    let arena = parser.get_arena();
+    let trivia = NodeTrivia::compute(arena, root);
+
+    let statements = &arena
+        .get_source_file(arena.get(root).unwrap())
+        .unwrap()
+        .statements
+        .nodes;
+    let function = statements[1];
+    assert_eq!(
+        trivia.leading_trivia(arena, function, source),
+        "\n\n// first\n/* second */  "
+    );
+    assert_eq!(trivia.full_start(function), Some(10));
+    assert_eq!(trivia.leading_trivia(arena, statements[0], source), "");
+
+    let parameter = arena
+        .get_function(arena.get(function).unwrap())
+        .unwrap()
+        .parameters
+        .nodes[0];
+    assert_eq!(
+        arena.get(parameter).map(|node| node.kind),
+        Some(syntax_kind_ext::PARAMETER)
+    );
+    assert_eq!(trivia.leading_trivia(arena, parameter, source), "/* p */ ");
+}
@@ -9,6 +9,14 @@
 //!
 //! Each rescan in this module documents its precondition (which kind the
 //! current token must be) and is a no-op when the precondition is not met.
+//!
+//! The rescans are public, and exported to JavaScript under tsc's names, so
+//! that tools driving the scanner on their own (syntax highlighters,
+//! formatters) can resolve context-sensitive tokens the way the parser does:
+//! `reScanGreaterToken` here, `reScanSlashToken` in `scanner_impl/slash.rs`,
+//! `reScanTemplateToken` in `scanner_impl/templates.rs`, and
+//! `reScanJsxToken` / `scanJsxToken` in `scanner_impl/jsx.rs`, which share
+//! private helpers with the main scan loop.
 
 use crate::SyntaxKind;
 use crate::char_codes::CharacterCodes;
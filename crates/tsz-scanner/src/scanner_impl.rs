@@ -913,6 +913,14 @@ impl ScannerState {
 
                 // Hash (private identifier)
                 CharacterCodes::HASH => {
+                    if self.is_shebang_at(self.pos) {
+                        self.pos = self.shebang_line_end(self.pos);
+                        if self.skip_trivia {
+                            continue;
+                        }
+                        self.token = SyntaxKind::ShebangTrivia;
+                        return self.token;
+                    }
                     self.pos += 1;
                     if self.pos < self.end
                         && self.is_identifier_start(self.char_code_unchecked(self.pos))
@@ -1313,6 +1321,8 @@ fn is_hex_digit(ch: u32) -> bool {
         || (CharacterCodes::LOWER_A..=CharacterCodes::LOWER_F).contains(&ch)
 }
 
+/// Whether `ch` can start an identifier: `$`, `_`, or a Unicode `ID_Start`
+/// character, as in tsc's `isUnicodeIdentifierStart` for ES2015 and later.
 pub(crate) fn is_identifier_start(ch: u32) -> bool {
     // Fast path for ASCII (0-127)
     if ch < 128 {
@@ -1322,117 +1332,43 @@ pub(crate) fn is_identifier_start(ch: u32) -> bool {
             || ch == CharacterCodes::DOLLAR;
     }
 
-    if let Some(c) = char::from_u32(ch) {
-        return unicode_ident::is_xid_start(c);
-    }
-
-    false
+    char::from_u32(ch).is_some_and(unicode_ident::is_xid_start) || is_id_start_not_xid_start(ch)
 }
 
+/// Whether `ch` can continue an identifier: `$`, ZWNJ, ZWJ, or a Unicode
+/// `ID_Continue` character, as in tsc's `isUnicodeIdentifierPart`.
 pub(crate) fn is_identifier_part(ch: u32) -> bool {
     // Fast path for ASCII
     if ch < 128 {
         return is_identifier_start(ch) || is_digit(ch);
     }
 
-    // Unicode path: ECMAScript ID_Continue includes ID_Start plus marks,
-    // decimal digits, connector punctuation, ZWNJ, and ZWJ. The unicode-ident
-    // table keeps astral-plane identifier characters valid without admitting
-    // `No` digits such as subscript/superscript numerals.
-    if let Some(c) = char::from_u32(ch)
-        && unicode_ident::is_xid_continue(c)
-    {
-        return true;
-    }
-
-    // ZWNJ and ZWJ
-    if ch == 0x200C || ch == 0x200D {
-        return true;
-    }
-
-    if is_unicode_other_id_continue(ch) {
-        return true;
-    }
-
-    // Unicode combining marks (Mn, Mc categories) - needed for scripts like Devanagari, Arabic, etc.
-    // This covers the most common combining mark ranges used in identifiers:
-    // - Combining Diacritical Marks (U+0300-U+036F)
-    // - Devanagari combining marks (U+0900-U+097F range includes vowel signs and virama)
-    // - Arabic combining marks (U+064B-U+0652)
-    // - Hebrew combining marks (U+0591-U+05C7)
-    // - And other Indic scripts
-    is_unicode_combining_mark(ch)
+    // `ID_Continue` covers `ID_Start`, marks, decimal digits, connector
+    // punctuation and `Other_ID_Continue`, but not `No` digits such as
+    // subscript and superscript numerals.
+    char::from_u32(ch).is_some_and(unicode_ident::is_xid_continue)
+        || ch == 0x200C // ZWNJ
+        || ch == 0x200D // ZWJ
+        || is_id_start_not_xid_start(ch)
 }
 
-/// Unicode `Other_ID_Continue` code points that ECMAScript admits as
-/// identifier continuation characters even though they are not alphabetic,
-/// decimal digits, join controls, or combining marks.
-const fn is_unicode_other_id_continue(ch: u32) -> bool {
+/// `ID_Start` characters that the `XID_Start` table leaves out because they
+/// change under NFKC normalization. ECMAScript uses the plain `ID_Start`
+/// and `ID_Continue` properties, so these are identifier characters too.
+const fn is_id_start_not_xid_start(ch: u32) -> bool {
     matches!(
         ch,
-        0x00B7 // MIDDLE DOT
-            | 0x0387 // GREEK ANO TELEIA
-            | 0x1369
-            ..=0x1371 // ETHIOPIC DIGIT ONE..THREE
-            | 0x19DA // NEW TAI LUE THAM DIGIT ONE
+        0x037A // GREEK YPOGEGRAMMENI
+            | 0x0E33 // THAI CHARACTER SARA AM
+            | 0x0EB3 // LAO VOWEL SIGN AM
+            | 0x309B..=0x309C // KATAKANA-HIRAGANA VOICED SOUND MARKS
+            | 0xFC5E..=0xFC63 // ARABIC LIGATURE SHADDA forms
+            | 0xFDFA..=0xFDFB // ARABIC LIGATURE SALLALLAHOU, JALLAJALALOUHOU
+            | 0xFE70 | 0xFE72 | 0xFE74 | 0xFE76 | 0xFE78 | 0xFE7A | 0xFE7C | 0xFE7E // ARABIC isolated forms
+            | 0xFF9E..=0xFF9F // HALFWIDTH KATAKANA VOICED SOUND MARKS
     )
 }
 
-/// Check if a character is a Unicode combining mark (Mn or Mc category).
-/// These are characters that modify the preceding base character.
-fn is_unicode_combining_mark(ch: u32) -> bool {
-    // Combining Diacritical Marks
-    if (0x0300..=0x036F).contains(&ch) {
-        return true;
-    }
-    // Devanagari vowel signs, virama, etc. (U+0900-U+0903, U+093A-U+094F, U+0951-U+0957, U+0962-U+0963)
-    if (0x0900..=0x0903).contains(&ch)
-        || (0x093A..=0x094F).contains(&ch)
-        || (0x0951..=0x0957).contains(&ch)
-        || (0x0962..=0x0963).contains(&ch)
-    {
-        return true;
-    }
-    // Bengali combining marks
-    if (0x0981..=0x0983).contains(&ch) || (0x09BC..=0x09CD).contains(&ch) {
-        return true;
-    }
-    // Arabic combining marks (tashkil/harakat)
-    if (0x064B..=0x0652).contains(&ch) || (0x0670..=0x0670).contains(&ch) {
-        return true;
-    }
-    // Hebrew combining marks
-    if (0x0591..=0x05C7).contains(&ch) {
-        return true;
-    }
-    // Other Indic scripts - Tamil, Telugu, Kannada, Malayalam, etc.
-    if (0x0B01..=0x0B03).contains(&ch)  // Oriya
-        || (0x0B3C..=0x0B4D).contains(&ch)
-        || (0x0B82..=0x0B83).contains(&ch)  // Tamil
-        || (0x0BBE..=0x0BCD).contains(&ch)
-        || (0x0C00..=0x0C04).contains(&ch)  // Telugu
-        || (0x0C3E..=0x0C4D).contains(&ch)
-        || (0x0C81..=0x0C83).contains(&ch)  // Kannada
-        || (0x0CBC..=0x0CCD).contains(&ch)
-        || (0x0D00..=0x0D03).contains(&ch)  // Malayalam
-        || (0x0D3B..=0x0D4D).contains(&ch)
-    {
-        return true;
-    }
-    // Thai and other Southeast Asian
-    if (0x0E31..=0x0E3A).contains(&ch) || (0x0E47..=0x0E4E).contains(&ch) {
-        return true;
-    }
-    // Combining Diacritical Marks Extended, Supplement, for Symbols
-    if (0x1AB0..=0x1AFF).contains(&ch)
-        || (0x1DC0..=0x1DFF).contains(&ch)
-        || (0x20D0..=0x20FF).contains(&ch)
-    {
-        return true;
-    }
-    false
-}
-
 const fn is_line_break(ch: u32) -> bool {
     ch == CharacterCodes::LINE_FEED
         || ch == CharacterCodes::CARRIAGE_RETURN
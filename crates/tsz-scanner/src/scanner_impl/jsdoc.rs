@@ -200,51 +200,62 @@ impl ScannerState {
     // Shebang Handling
     // =========================================================================
 
-    /// Scan a shebang (#!) at the start of the file.
-    /// Returns the length of the shebang line (including newline), or 0 if no shebang.
+    /// Scan a shebang (#!) at the start of the file, after an optional BOM.
+    /// Returns the length of the shebang line (including the BOM and the
+    /// newline), or 0 if no shebang.
     #[wasm_bindgen(js_name = scanShebangTrivia)]
     pub fn scan_shebang_trivia(&mut self) -> usize {
         // Shebang must be at the very start of the file
         if self.pos != 0 {
             return 0;
         }
+        let start = if self.source.starts_with('\u{FEFF}') {
+            '\u{FEFF}'.len_utf8()
+        } else {
+            0
+        };
+        if !self.is_shebang_at(start) {
+            return 0;
+        }
+        self.pos = self.shebang_line_end(start);
 
-        // Check for #!
-        if self.pos + 1 < self.end
-            && self.char_code_unchecked(self.pos) == CharacterCodes::HASH
-            && self.char_code_unchecked(self.pos + 1) == CharacterCodes::EXCLAMATION
-        {
-            let start = self.pos;
-            self.pos += 2;
-
-            // Scan to end of line
-            while self.pos < self.end {
-                let ch = self.char_code_unchecked(self.pos);
-                if ch == CharacterCodes::LINE_FEED || ch == CharacterCodes::CARRIAGE_RETURN {
-                    break;
-                }
-                // Use char_len_at to properly handle multi-byte UTF-8 characters
-                self.pos += self.char_len_at(self.pos);
-            }
-
-            // Include the newline in the shebang
-            if self.pos < self.end {
-                let ch = self.char_code_unchecked(self.pos);
-                if ch == CharacterCodes::CARRIAGE_RETURN {
-                    self.pos += 1;
-                    if self.pos < self.end
-                        && self.char_code_unchecked(self.pos) == CharacterCodes::LINE_FEED
-                    {
-                        self.pos += 1;
-                    }
-                } else if ch == CharacterCodes::LINE_FEED {
+        // Include the newline in the shebang
+        if self.pos < self.end {
+            let ch = self.char_code_unchecked(self.pos);
+            if ch == CharacterCodes::CARRIAGE_RETURN {
+                self.pos += 1;
+                if self.pos < self.end
+                    && self.char_code_unchecked(self.pos) == CharacterCodes::LINE_FEED
+                {
                     self.pos += 1;
                 }
+            } else if ch == CharacterCodes::LINE_FEED {
+                self.pos += 1;
             }
-
-            return self.pos - start;
         }
+        self.pos
+    }
+}
 
-        0
+impl ScannerState {
+    /// Whether a shebang starts at `pos`: `#!` at the start of the file,
+    /// where only a BOM may precede it.
+    pub(crate) fn is_shebang_at(&self, pos: usize) -> bool {
+        let leading = &self.source[..pos.min(self.source.len())];
+        (leading.is_empty() || leading == "\u{FEFF}") && self.source[pos..].starts_with("#!")
+    }
+
+    /// End of the shebang line starting at `pos`, before its line break.
+    pub(crate) fn shebang_line_end(&self, pos: usize) -> usize {
+        let mut end = pos + 2;
+        while end < self.end {
+            let ch = self.char_code_unchecked(end);
+            if ch == CharacterCodes::LINE_FEED || ch == CharacterCodes::CARRIAGE_RETURN {
+                break;
+            }
+            // Use char_len_at to properly handle multi-byte UTF-8 characters
+            end += self.char_len_at(end);
+        }
+        end
     }
 }
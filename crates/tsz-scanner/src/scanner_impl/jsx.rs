@@ -125,8 +125,11 @@ impl ScannerState {
         self.scan_jsx_token(allow_multiline_jsx_text)
     }
 
-    /// Scan a JSX token (text, open element, close element, etc.)
-    fn scan_jsx_token(&mut self, allow_multiline_jsx_text: bool) -> SyntaxKind {
+    /// Scan a JSX token (text, open element, close element, etc.) from the
+    /// current position. Called after the `>` of an opening tag or the `}`
+    /// of a JSX expression, where the children start.
+    #[wasm_bindgen(js_name = scanJsxToken)]
+    pub fn scan_jsx_token(&mut self, allow_multiline_jsx_text: bool) -> SyntaxKind {
         self.full_start_pos = self.pos;
         self.token_start = self.pos;
         // Clear stale atom from any prior identifier scan so get_token_value_ref()
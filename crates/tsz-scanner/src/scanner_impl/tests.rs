@@ -74,6 +74,29 @@ fn scan_es5_braced_astral_escape_remains_invalid_identifier_start() {
     assert_eq!(scanner.get_token_text(), "\\");
 }
 
+#[test]
+fn scan_unicode_identifiers() {
+    let tokens = scan_all("café Ελληνικά 变量 नमस्ते ゛ゝ ﾞ");
+    let values: Vec<_> = tokens.iter().map(|(_, value)| value.as_str()).collect();
+    assert!(
+        tokens
+            .iter()
+            .all(|(kind, _)| *kind == SyntaxKind::Identifier)
+    );
+    assert_eq!(values, ["café", "Ελληνικά", "变量", "नमस्ते", "゛ゝ", "ﾞ"]);
+}
+
+#[test]
+fn scan_unicode_escape_identifier_parts() {
+    let mut scanner = ScannerState::new(r"\u0061b\u{63}\u309B".to_string(), true);
+    scanner.set_language_version(ScriptTarget::ES2015);
+
+    assert_eq!(scanner.scan(), SyntaxKind::Identifier);
+    assert_eq!(scanner.get_token_value_ref(), "abc゛");
+    assert!(scanner.get_scanner_diagnostics().is_empty());
+    assert_eq!(scanner.scan(), SyntaxKind::EndOfFileToken);
+}
+
 // ── Keywords ──────────────────────────────────────────────────────
 
 #[test]
@@ -539,6 +562,24 @@ fn is_identifier_start_chars() {
     assert!(!is_identifier_start(CharacterCodes::_0));
     assert!(!is_identifier_start(CharacterCodes::SPACE));
     assert!(!is_identifier_start(CharacterCodes::PLUS));
+    // ID_Start letters outside ASCII, including the NFKC-unstable ones
+    // that XID_Start leaves out.
+    assert!(is_identifier_start(0x00E9)); // é
+    assert!(is_identifier_start(0x4E2D)); // 中
+    assert!(is_identifier_start(0x1D400)); // 𝐀 MATHEMATICAL BOLD CAPITAL A
+    assert!(is_identifier_start(0x309B)); // ゛ KATAKANA-HIRAGANA VOICED SOUND MARK
+    assert!(is_identifier_start(0xFE70)); // ARABIC FATHATAN ISOLATED FORM
+    assert!(is_identifier_start(0x0E33)); // ำ THAI CHARACTER SARA AM
+    // Pattern_Syntax characters are never identifier characters.
+    assert!(!is_identifier_start(0x2E2F)); // ⸯ VERTICAL TILDE
+    assert!(!is_identifier_part(0x2E2F));
+    // Marks continue an identifier but cannot start one.
+    assert!(!is_identifier_start(0x0301));
+    assert!(is_identifier_part(0x0301)); // COMBINING ACUTE ACCENT
+    assert!(is_identifier_part(0x094D)); // DEVANAGARI SIGN VIRAMA
+    assert!(is_identifier_part(0x200C)); // ZWNJ
+    assert!(is_identifier_part(0x200D)); // ZWJ
+    assert!(!is_identifier_part(0x09C5)); // unassigned
 }
 
 #[test]
@@ -87,6 +87,31 @@ mod shebang_scanning {
         let len = scanner.scan_shebang_trivia();
         assert!(len > 0);
     }
+
+    #[test]
+    fn shebang_after_bom() {
+        let source = "\u{FEFF}#!/usr/bin/env node\nvar x";
+        let mut scanner = ScannerState::new(source.to_string(), true);
+        let len = scanner.scan_shebang_trivia();
+        assert_eq!(len, source.find("var").unwrap());
+        assert_eq!(scanner.scan(), SyntaxKind::VarKeyword);
+    }
+
+    #[test]
+    fn scan_returns_shebang_trivia_at_file_start() {
+        let mut scanner =
+            ScannerState::new("\u{FEFF}#!/usr/bin/env node\n#!".to_string(), false);
+        assert_eq!(scanner.scan(), SyntaxKind::WhitespaceTrivia);
+        assert_eq!(scanner.scan(), SyntaxKind::ShebangTrivia);
+        assert_eq!(scanner.get_token_text(), "#!/usr/bin/env node");
+        assert_eq!(scanner.scan(), SyntaxKind::NewLineTrivia);
+        // Only the first line can hold a shebang.
+        assert_eq!(scanner.scan(), SyntaxKind::HashToken);
+
+        let mut scanner = ScannerState::new("#!/usr/bin/env node\nfoo".to_string(), true);
+        assert_eq!(scanner.scan(), SyntaxKind::Identifier);
+        assert!(scanner.has_preceding_line_break());
+    }
 }
 mod full_token_stream {
     use super::*;
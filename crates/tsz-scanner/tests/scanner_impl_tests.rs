@@ -155,6 +155,76 @@ fn scanner_state_snapshot_restore_is_reversible_after_contextual_rescan() {
     assert_eq!(token, SyntaxKind::GreaterThanToken);
 }
 
+#[test]
+fn standalone_tokenizer_resolves_context_sensitive_tokens_with_public_rescans() {
+    // A highlighter that knows the syntactic context drives the scanner the
+    // way the parser does, rescanning `>`, `/`, `}` and JSX children.
+    let source = "f(<p>hi {`${n}!`}</p>, /x/g, a >>= 2)".to_string();
+    let mut scanner = ScannerState::new(source, true);
+    let mut tokens = Vec::new();
+    let mut in_jsx_children = false;
+    let mut in_template = false;
+    loop {
+        let previous = tokens.last().map(|(kind, _)| *kind);
+        let mut kind = if in_jsx_children {
+            in_jsx_children = false;
+            scanner.scan_jsx_token(true)
+        } else {
+            scanner.scan()
+        };
+        match kind {
+            SyntaxKind::GreaterThanToken if previous == Some(SyntaxKind::Identifier) => {
+                kind = scanner.re_scan_greater_token();
+                // `<p>` opens JSX children; `a >>= 2` does not.
+                in_jsx_children = kind == SyntaxKind::GreaterThanToken
+                    && tokens.len() >= 2
+                    && tokens[tokens.len() - 2].0 == SyntaxKind::LessThanToken;
+            }
+            SyntaxKind::SlashToken if previous == Some(SyntaxKind::CommaToken) => {
+                kind = scanner.re_scan_slash_token();
+            }
+            SyntaxKind::CloseBraceToken if in_template => {
+                kind = scanner.re_scan_template_token(false);
+                in_template = false;
+            }
+            SyntaxKind::CloseBraceToken => in_jsx_children = true,
+            SyntaxKind::TemplateHead => in_template = true,
+            SyntaxKind::EndOfFileToken => break,
+            _ => {}
+        }
+        tokens.push((kind, scanner.get_token_text()));
+    }
+
+    let expected = [
+        (SyntaxKind::Identifier, "f"),
+        (SyntaxKind::OpenParenToken, "("),
+        (SyntaxKind::LessThanToken, "<"),
+        (SyntaxKind::Identifier, "p"),
+        (SyntaxKind::GreaterThanToken, ">"),
+        (SyntaxKind::JsxText, "hi "),
+        (SyntaxKind::OpenBraceToken, "{"),
+        (SyntaxKind::TemplateHead, "`${"),
+        (SyntaxKind::Identifier, "n"),
+        (SyntaxKind::TemplateTail, "}!`"),
+        (SyntaxKind::CloseBraceToken, "}"),
+        (SyntaxKind::LessThanSlashToken, "</"),
+        (SyntaxKind::Identifier, "p"),
+        (SyntaxKind::GreaterThanToken, ">"),
+        (SyntaxKind::CommaToken, ","),
+        (SyntaxKind::RegularExpressionLiteral, "/x/g"),
+        (SyntaxKind::CommaToken, ","),
+        (SyntaxKind::Identifier, "a"),
+        (SyntaxKind::GreaterThanGreaterThanEqualsToken, ">>="),
+        (SyntaxKind::NumericLiteral, "2"),
+        (SyntaxKind::CloseParenToken, ")"),
+    ];
+    let tokens: Vec<_> = tokens
+        .iter()
+        .map(|(kind, text)| (*kind, text.as_str()))
+        .collect();
+    assert_eq!(tokens, expected);
+}
+
 #[test]
 fn template_rescan_handles_unicode_micro_sign() {
     let source = "const value = `${500}µs`;".to_string();
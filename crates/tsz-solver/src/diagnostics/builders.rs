@@ -296,6 +296,12 @@ impl<'a> DiagnosticBuilder<'a> {
         self
     }
 
+    /// Configure `noErrorTruncation` for the types shown in messages.
+    pub fn with_no_truncation(mut self, no_truncation: bool) -> Self {
+        self.formatter = self.formatter.with_no_truncation(no_truncation);
+        self
+    }
+
     /// Create a "Type X is not assignable to type Y" diagnostic.
     pub fn type_not_assignable(&mut self, source: TypeId, target: TypeId) -> TypeDiagnostic {
         let source_str = self.formatter.format(source);
@@ -519,6 +525,12 @@ impl<'a> SpannedDiagnosticBuilder<'a> {
         self
     }
 
+    /// Configure `noErrorTruncation` for the types shown in messages.
+    pub fn with_no_truncation(mut self, no_truncation: bool) -> Self {
+        self.builder = self.builder.with_no_truncation(no_truncation);
+        self
+    }
+
     /// Create a span for this file.
     pub fn span(&self, start: u32, length: u32) -> SourceSpan {
         SourceSpan::new(std::sync::Arc::clone(&self.file), start, length)
@@ -709,6 +721,7 @@ impl TypeDiagnostic {
             category,
             code: self.code,
             related_information,
+            spelling_suggestion: None,
         }
     }
 }
@@ -27,7 +27,7 @@ impl<'a> TypeFormatter<'a> {
 
         // Match tsc's higher truncation threshold (small/medium objects display fully).
         const TRUNCATE_THRESHOLD: usize = 22;
-        if parts.len() < TRUNCATE_THRESHOLD {
+        if self.no_truncation || parts.len() < TRUNCATE_THRESHOLD {
             return format!("{{ {}; }}", parts.join("; "));
         }
 
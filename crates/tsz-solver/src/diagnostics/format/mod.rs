@@ -38,6 +38,21 @@ use std::mem::size_of;
 use std::sync::Arc;
 use tsz_common::interner::Atom;
 
+/// tsc's `defaultMaximumTruncationLength` is 160; `typeToString` cuts off
+/// complete displays at twice that.
+const DIAGNOSTIC_MAX_DISPLAY_LENGTH: usize = 320;
+
+/// Cut `display` off with `...` once it reaches `max_length` characters,
+/// keeping the total at `max_length`.
+fn truncate_display(display: Cow<'static, str>, max_length: usize) -> Cow<'static, str> {
+    if display.len() < max_length || display.chars().count() < max_length {
+        return display;
+    }
+    let mut truncated: String = display.chars().take(max_length - "...".len()).collect();
+    truncated.push_str("...");
+    Cow::Owned(truncated)
+}
+
 /// Operation-local cache accounting for `TypeFormatter`.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct TypeFormatterCacheStatistics {
@@ -165,6 +180,15 @@ pub struct TypeFormatter<'a> {
     /// source/display origin was recorded. This is used by narrow diagnostic
     /// surfaces where tsc does not preserve source-written union order.
     ignore_union_origins: bool,
+    /// When true (`--noErrorTruncation`), long unions and object types are
+    /// displayed in full instead of being elided.
+    no_truncation: bool,
+    /// Cap on the length of a complete top-level display; longer displays are
+    /// cut off with `...`. Set by diagnostic mode.
+    max_display_length: Option<usize>,
+    /// True while a top-level `format` call is running, so nested calls leave
+    /// the length cap to the outermost one.
+    formatting_top_level: bool,
 }
 
 impl<'a> TypeFormatter<'a> {
@@ -416,6 +440,9 @@ impl<'a> TypeFormatter<'a> {
             expand_scalar_mapped_alias_applications: false,
             expand_primitive_key_union: false,
             ignore_union_origins: false,
+            no_truncation: false,
+            max_display_length: None,
+            formatting_top_level: false,
         }
     }
 
@@ -651,6 +678,9 @@ impl<'a> TypeFormatter<'a> {
             expand_scalar_mapped_alias_applications: false,
             expand_primitive_key_union: false,
             ignore_union_origins: false,
+            no_truncation: false,
+            max_display_length: None,
+            formatting_top_level: false,
         }
     }
 
@@ -702,6 +732,27 @@ impl<'a> TypeFormatter<'a> {
     pub const fn with_diagnostic_mode(mut self) -> Self {
         self.skip_union_optionalize = true;
         self.diagnostic_mode = true;
+        if !self.no_truncation {
+            self.max_display_length = Some(DIAGNOSTIC_MAX_DISPLAY_LENGTH);
+        }
+        self
+    }
+
+    /// Configure `noErrorTruncation`. When enabled, unions and object types are
+    /// never elided and complete displays are never cut off with `...`.
+    pub const fn with_no_truncation(mut self, no_truncation: bool) -> Self {
+        if no_truncation {
+            self.no_truncation = true;
+            self.max_union_members = usize::MAX;
+            self.max_display_length = None;
+        }
+        self
+    }
+
+    /// Leave complete displays uncapped. For callers that apply their own
+    /// tsc-length elision to the formatted string afterwards.
+    pub const fn with_uncapped_display_length(mut self) -> Self {
+        self.max_display_length = None;
         self
     }
 
@@ -742,6 +793,7 @@ impl<'a> TypeFormatter<'a> {
     pub const fn with_long_property_receiver_display(mut self) -> Self {
         self.max_depth = 192;
         self.long_property_receiver_display = true;
+        self.max_display_length = None;
         self
     }
 
@@ -1005,7 +1057,24 @@ impl<'a> TypeFormatter<'a> {
     ///
     /// Returns `Cow::Borrowed` for static type names (e.g., `"never"`, `"any"`)
     /// and `Cow::Owned` for dynamically formatted types.
+    ///
+    /// In diagnostic mode the complete display is capped like tsc's
+    /// `typeToString`: displays of 320 or more characters keep their first
+    /// 317 characters followed by `...`.
     pub fn format(&mut self, type_id: TypeId) -> Cow<'static, str> {
+        let Some(max_length) = self.max_display_length else {
+            return self.format_type(type_id);
+        };
+        if self.formatting_top_level {
+            return self.format_type(type_id);
+        }
+        self.formatting_top_level = true;
+        let formatted = self.format_type(type_id);
+        self.formatting_top_level = false;
+        truncate_display(formatted, max_length)
+    }
+
+    fn format_type(&mut self, type_id: TypeId) -> Cow<'static, str> {
         if self.format_visiting.contains(&type_id) {
             return Cow::Borrowed("...");
         }
@@ -1409,3 +1409,67 @@ fn typeof_result_carve_out_does_not_apply_to_subset() {
         "Three-literal subset `symbol | string | number` must NOT be reordered to tsc's typeof canonical order; got: {result}"
     );
 }
+
+// =================================================================
+// Display length cap and noErrorTruncation
+// =================================================================
+
+fn long_literal_union(db: &TypeInterner) -> TypeId {
+    let members: Vec<TypeId> = (0..8)
+        .map(|i| db.literal_string(&format!("member_{i}_{}", "x".repeat(40))))
+        .collect();
+    db.union_preserve_members(members)
+}
+
+#[test]
+fn diagnostic_mode_caps_long_displays_like_type_to_string() {
+    let db = TypeInterner::new();
+    let union = long_literal_union(&db);
+
+    let full = TypeFormatter::new(&db).format(union).into_owned();
+    assert!(full.len() > 320, "fixture must exceed the cap, got: {full}");
+    assert!(!full.ends_with("..."), "hover display is uncapped: {full}");
+
+    let capped = TypeFormatter::new(&db)
+        .with_diagnostic_mode()
+        .format(union)
+        .into_owned();
+    assert_eq!(capped.chars().count(), 320, "got: {capped}");
+    assert!(capped.ends_with("..."), "got: {capped}");
+    assert!(full.starts_with(capped.trim_end_matches("...")));
+}
+
+#[test]
+fn no_truncation_keeps_full_diagnostic_displays() {
+    let db = TypeInterner::new();
+    let union = long_literal_union(&db);
+    let full = TypeFormatter::new(&db).format(union).into_owned();
+
+    let uncapped = TypeFormatter::new(&db)
+        .with_no_truncation(true)
+        .with_diagnostic_mode()
+        .format(union)
+        .into_owned();
+    assert_eq!(uncapped, full);
+
+    let members: Vec<TypeId> = (0..15).map(|i| db.literal_number(i as f64)).collect();
+    let large = db.union_preserve_members(members);
+    let result = TypeFormatter::new(&db)
+        .with_diagnostic_mode()
+        .with_no_truncation(true)
+        .format(large);
+    assert!(
+        !result.contains("...") && result.contains("14"),
+        "noErrorTruncation must list every union member, got: {result}"
+    );
+
+    let props: Vec<PropertyInfo> = (1..=24)
+        .map(|i| PropertyInfo::new(db.intern_string(&format!("p{i}")), TypeId::NUMBER))
+        .collect();
+    let obj = db.object(props);
+    let result = TypeFormatter::new(&db).with_no_truncation(true).format(obj);
+    assert!(
+        !result.contains("more ...") && result.contains("p12: number"),
+        "noErrorTruncation must list every object member, got: {result}"
+    );
+}
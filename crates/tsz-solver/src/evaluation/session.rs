@@ -9,7 +9,9 @@
 //! via `Rc` across parent/child contexts so counters survive cross-arena
 //! delegation without implicit global state.
 
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
+
+use tsz_common::CancellationToken;
 
 /// Maximum global instantiation depth — bounds nesting of
 /// `evaluate_application_type` calls across all `CheckerContext` instances.
@@ -32,6 +34,10 @@ pub struct EvaluationSession {
     global_instantiation_depth: Cell<u32>,
     /// Cross-context instantiation fuel (total non-cached evaluations per file).
     global_instantiation_fuel: Cell<u32>,
+    /// Cancellation token of the request driving this session, if any.
+    /// A cancelled session reports its limits as exceeded so evaluation
+    /// unwinds quickly.
+    cancellation: RefCell<Option<CancellationToken>>,
 }
 
 impl EvaluationSession {
@@ -40,14 +46,32 @@ impl EvaluationSession {
         Self {
             global_instantiation_depth: Cell::new(0),
             global_instantiation_fuel: Cell::new(0),
+            cancellation: RefCell::new(None),
         }
     }
 
-    /// Check if global instantiation limits are exceeded.
+    /// Check if global instantiation limits are exceeded, or the session's
+    /// request has been cancelled.
     #[inline]
-    pub const fn instantiation_limits_exceeded(&self) -> bool {
+    pub fn instantiation_limits_exceeded(&self) -> bool {
         self.global_instantiation_depth.get() >= MAX_GLOBAL_INSTANTIATION_DEPTH
             || self.global_instantiation_fuel.get() >= MAX_GLOBAL_INSTANTIATION_FUEL
+            || self.is_cancelled()
+    }
+
+    /// Attach (or clear) the cancellation token of the request driving this
+    /// session.
+    pub fn set_cancellation_token(&self, token: Option<CancellationToken>) {
+        *self.cancellation.borrow_mut() = token;
+    }
+
+    /// Whether the session's request has been cancelled.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .borrow()
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
     }
 
     /// Increment both instantiation depth and fuel before an evaluation.
@@ -151,4 +175,19 @@ mod tests {
         assert_eq!(session.global_instantiation_fuel(), 0);
         assert!(!session.instantiation_limits_exceeded());
     }
+
+    #[test]
+    fn test_cancelled_session_reports_limits_exceeded() {
+        let session = EvaluationSession::new();
+        let token = CancellationToken::new();
+        session.set_cancellation_token(Some(token.clone()));
+        assert!(!session.instantiation_limits_exceeded());
+
+        token.cancel();
+        assert!(session.is_cancelled());
+        assert!(session.instantiation_limits_exceeded());
+
+        session.set_cancellation_token(None);
+        assert!(!session.is_cancelled());
+    }
 }
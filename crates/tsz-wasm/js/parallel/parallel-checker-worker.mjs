@@ -0,0 +1,24 @@
+// Worker side of `parallel-checker.mjs`: instantiate the module on the main
+// thread's shared memory, then check files until the shared counter runs
+// past the last one. The worker's wasm stack and thread-local storage live
+// in the shared memory too, so they are freed before the worker is
+// terminated.
+
+import { initSync, WasmParallelChecker } from '../web-threads/tsz_wasm.js';
+
+self.onmessage = ({ data }) => {
+  const { module, memory, sessionId, next, fileCount } = data;
+  const wasm = initSync({ module, memory });
+
+  let checked = 0;
+  try {
+    for (let fileIdx = Atomics.add(next, 0, 1); fileIdx < fileCount; fileIdx = Atomics.add(next, 0, 1)) {
+      if (WasmParallelChecker.checkSharedFile(sessionId, fileIdx)) {
+        checked += 1;
+      }
+    }
+  } finally {
+    wasm.__wbindgen_thread_destroy();
+  }
+  self.postMessage({ checked });
+};
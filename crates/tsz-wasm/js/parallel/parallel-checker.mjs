@@ -0,0 +1,108 @@
+// Parallel checking in the browser for the threaded web build of tsz-wasm.
+//
+// The main thread parses, binds and merges the program once
+// (`WasmParallelChecker.prepareShared`). Workers then instantiate the same
+// wasm module on the same shared memory, so they see the merged binder and
+// type interner without copying, and claim files off a shared counter until
+// none are left. `finishShared` checks anything a worker did not, runs the
+// cross-file passes and returns the usual `checkAll` result.
+//
+// Needs a cross-origin isolated page (for SharedArrayBuffer) and the
+// `web-threads/` build. Without either it falls back to `checkAll` on the
+// calling thread.
+//
+//   import { checkAllParallel } from '@mohsen-azimi/tsz-dev/parallel';
+//   const result = await checkAllParallel([['a.ts', 'let x: number = 1;']]);
+
+import init, { WasmParallelChecker } from '../web-threads/tsz_wasm.js';
+
+const WORKER_URL = new URL('./parallel-checker-worker.mjs', import.meta.url);
+
+/**
+ * Check `files` (an iterable of `[fileName, sourceText]` pairs) across Web
+ * Workers and resolve to the same shape as `WasmParallelChecker.checkAll`.
+ *
+ * @param {Iterable<[string, string]>} files
+ * @param {{
+ *   workerCount?: number,
+ *   moduleOrPath?: unknown,
+ *   compilerOptions?: object,
+ *   libFiles?: Iterable<[string, string]>,
+ * }} [options]
+ *   `workerCount` defaults to one less than `navigator.hardwareConcurrency`;
+ *   `moduleOrPath` is passed to the wasm-bindgen `init` function;
+ *   `compilerOptions` and `libFiles` are used as in `TsProgram`.
+ */
+export async function checkAllParallel(files, options = {}) {
+  const wasm = await init(options.moduleOrPath);
+  const checker = new WasmParallelChecker();
+  if (options.compilerOptions) {
+    checker.setCompilerOptions(JSON.stringify(options.compilerOptions));
+  }
+  for (const [fileName, sourceText] of options.libFiles ?? []) {
+    checker.addLibFile(fileName, sourceText);
+  }
+  for (const [fileName, sourceText] of files) {
+    checker.addFile(fileName, sourceText);
+  }
+
+  if (!supportsSharedMemory(wasm.memory)) {
+    try {
+      return checker.checkAll();
+    } finally {
+      checker.free();
+    }
+  }
+
+  const sessionId = checker.prepareShared();
+  checker.free();
+  try {
+    const fileCount = WasmParallelChecker.sharedFileCount(sessionId);
+    const workerCount = Math.min(fileCount, defaultWorkerCount(options.workerCount));
+
+    // Next unclaimed file index, shared by every worker.
+    const next = new Int32Array(new SharedArrayBuffer(Int32Array.BYTES_PER_ELEMENT));
+    const module = init.__wbindgen_wasm_module;
+    const workers = Array.from({ length: workerCount }, () => runWorker({
+      module,
+      memory: wasm.memory,
+      sessionId,
+      next,
+      fileCount,
+    }));
+    // A failed worker only loses its claimed files; `finishShared` checks them.
+    await Promise.allSettled(workers);
+
+    return WasmParallelChecker.finishShared(sessionId);
+  } finally {
+    // No-op once `finishShared` has run; frees the session if it threw.
+    WasmParallelChecker.releaseShared(sessionId);
+  }
+}
+
+function supportsSharedMemory(memory) {
+  return typeof SharedArrayBuffer !== 'undefined'
+    && typeof Worker !== 'undefined'
+    && memory.buffer instanceof SharedArrayBuffer;
+}
+
+function defaultWorkerCount(requested) {
+  if (Number.isInteger(requested) && requested > 0) {
+    return requested;
+  }
+  const cores = globalThis.navigator?.hardwareConcurrency ?? 4;
+  return Math.max(1, cores - 1);
+}
+
+function runWorker(message) {
+  return new Promise((resolve, reject) => {
+    const worker = new Worker(WORKER_URL, { type: 'module' });
+    const done = (settle) => (event) => {
+      worker.terminate();
+      settle(event.data ?? event.error ?? event);
+    };
+    worker.onmessage = done(resolve);
+    worker.onerror = done(reject);
+    worker.postMessage(message);
+  });
+}
@@ -23,6 +23,10 @@
 //! - Atomic counters for ID allocation
 //! - `Arc<T>` for safe sharing across threads
 //!
+//! In the threaded web build, `WasmParallelChecker::prepareShared` keeps the
+//! merged program in wasm memory that Web Workers instantiate on, and
+//! `js/parallel/parallel-checker.mjs` hands each worker files to check.
+//!
 //! # Usage from JavaScript
 //!
 //! ```javascript
@@ -38,12 +42,23 @@
 //! console.log(result);
 //! ```
 
+use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use std::panic;
-use tsz::parallel::{BindStats, CheckStats, ParallelStats, compile_files};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+use tsz::binder::BinderState;
+use tsz::lib_loader::LibFile;
+use tsz::parallel::{
+    BindStats, CheckStats, FileCheckResult, FileCheckSession, ParallelStats, compile_files,
+    merge_bind_results, parse_and_bind_parallel, parse_and_bind_parallel_with_libs,
+};
+use tsz::parser::ParserState;
 use tsz_solver::construction::TypeInterner;
 use wasm_bindgen::prelude::{JsValue, wasm_bindgen};
 
+use crate::wasm_api::program::TsCompilerOptions;
+
 /// WASM-compatible type interner for parallel type checking.
 ///
 /// This wraps the internal `TypeInterner` with a `wasm-bindgen` compatible interface.
@@ -209,10 +224,11 @@ impl WasmParallelParser {
         }
     }
 
-    /// Clear all files.
+    /// Clear all files, including lib files.
     #[wasm_bindgen]
     pub fn clear(&mut self) {
         self.files.clear();
+        self.lib_files.clear();
     }
 }
 
@@ -247,6 +263,10 @@ pub struct WasmDiagnostic {
 #[wasm_bindgen]
 pub struct WasmParallelChecker {
     files: Vec<(String, String)>,
+    /// Library files for `prepareShared` (lib.d.ts, etc.)
+    lib_files: Vec<Arc<LibFile>>,
+    /// Compiler options for `prepareShared`
+    options: TsCompilerOptions,
 }
 
 #[wasm_bindgen]
@@ -254,7 +274,44 @@ impl WasmParallelChecker {
     /// Create a new parallel checker.
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
-        Self { files: Vec::new() }
+        Self {
+            files: Vec::new(),
+            lib_files: Vec::new(),
+            options: TsCompilerOptions::default(),
+        }
+    }
+
+    /// Set compiler options from JSON, in the same shape as
+    /// `TsProgram.setCompilerOptions`.
+    #[wasm_bindgen(js_name = setCompilerOptions)]
+    pub fn set_compiler_options(&mut self, options_json: &str) -> Result<(), JsValue> {
+        match serde_json::from_str::<TsCompilerOptions>(options_json) {
+            Ok(options) => {
+                self.options = options;
+                Ok(())
+            }
+            Err(e) => {
+                let err = js_sys::Error::new(&format!("Failed to parse options: {e}"));
+                Err(err.into())
+            }
+        }
+    }
+
+    /// Add a library file (lib.d.ts, lib.es5.d.ts, etc.)
+    #[wasm_bindgen(js_name = addLibFile)]
+    pub fn add_lib_file(&mut self, file_name: String, source_text: String) {
+        let mut parser = ParserState::new(file_name.clone(), source_text);
+        let root_idx = parser.parse_source_file();
+
+        let mut binder = BinderState::new();
+        binder.bind_source_file(parser.get_arena(), root_idx);
+
+        self.lib_files.push(Arc::new(LibFile::new(
+            file_name,
+            Arc::new(parser.into_arena()),
+            Arc::new(binder),
+            root_idx,
+        )));
     }
 
     /// Add a file to be checked.
@@ -342,10 +399,11 @@ impl WasmParallelChecker {
         }
     }
 
-    /// Clear all files.
+    /// Clear all files, including lib files.
     #[wasm_bindgen]
     pub fn clear(&mut self) {
         self.files.clear();
+        self.lib_files.clear();
     }
 }
 
@@ -355,6 +413,161 @@ impl Default for WasmParallelChecker {
     }
 }
 
+/// A program prepared by `prepareShared`, with the results its files have
+/// produced so far.
+struct SharedCheck {
+    session: FileCheckSession,
+    results: Mutex<Vec<Option<FileCheckResult>>>,
+}
+
+/// Prepared programs by session id. In a threaded build every worker
+/// instance runs on the same wasm memory, so they all see this registry
+/// and the merged binders and type interner it holds.
+static SHARED_CHECKS: LazyLock<Mutex<FxHashMap<u32, Arc<SharedCheck>>>> =
+    LazyLock::new(|| Mutex::new(FxHashMap::default()));
+static NEXT_SHARED_CHECK_ID: AtomicU32 = AtomicU32::new(1);
+
+fn shared_check(session_id: u32) -> Option<Arc<SharedCheck>> {
+    SHARED_CHECKS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&session_id)
+        .cloned()
+}
+
+/// Per-file checking spread across Web Workers.
+///
+/// These entry points only distribute work when the package is built with
+/// wasm threads (`+atomics`), so that workers instantiate the module on the
+/// main thread's shared memory; `js/parallel/parallel-checker.mjs` drives them and
+/// falls back to `checkAll` otherwise.
+#[wasm_bindgen]
+impl WasmParallelChecker {
+    /// Parse, bind and merge the added files once and return a session id
+    /// for `checkSharedFile` / `finishShared`. Consumes the added files; the
+    /// session checks with the options and lib files set on this checker.
+    #[wasm_bindgen(js_name = prepareShared)]
+    pub fn prepare_shared(&mut self) -> u32 {
+        let files = std::mem::take(&mut self.files);
+        // Libs must be provided via addLibFile(), as for `TsProgram`.
+        let lib_files: &[Arc<LibFile>] = if self.options.no_lib == Some(true) {
+            &[]
+        } else {
+            &self.lib_files
+        };
+        let bind_results = if lib_files.is_empty() {
+            parse_and_bind_parallel(files)
+        } else {
+            parse_and_bind_parallel_with_libs(files, lib_files)
+        };
+        let session = FileCheckSession::new(
+            Arc::new(merge_bind_results(bind_results)),
+            self.options.to_checker_options(),
+            lib_files,
+            &FxHashMap::default(),
+        );
+        let results = Mutex::new((0..session.file_count()).map(|_| None).collect());
+        let session_id = NEXT_SHARED_CHECK_ID.fetch_add(1, Ordering::Relaxed);
+        SHARED_CHECKS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(session_id, Arc::new(SharedCheck { session, results }));
+        session_id
+    }
+
+    /// Number of files in a prepared session (0 for unknown sessions).
+    #[wasm_bindgen(js_name = sharedFileCount)]
+    pub fn shared_file_count(session_id: u32) -> usize {
+        shared_check(session_id).map_or(0, |shared| shared.session.file_count())
+    }
+
+    /// Check one file of a prepared session and record its result. Safe to
+    /// call from any worker, for distinct files concurrently. Returns
+    /// `false` for unknown sessions or files, or if checking panicked.
+    #[wasm_bindgen(js_name = checkSharedFile)]
+    pub fn check_shared_file(session_id: u32, file_idx: usize) -> bool {
+        let Some(shared) = shared_check(session_id) else {
+            return false;
+        };
+        let checked = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            shared.session.check_file(file_idx)
+        }));
+        let Ok(Some(result)) = checked else {
+            return false;
+        };
+        shared
+            .results
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)[file_idx] = Some(result);
+        true
+    }
+
+    /// Finish a prepared session: check any file no worker recorded, run
+    /// the cross-file passes and return a `WasmCheckResult`. The session is
+    /// released.
+    #[wasm_bindgen(js_name = finishShared)]
+    pub fn finish_shared(session_id: u32) -> JsValue {
+        Self::finish_shared_result(session_id)
+            .and_then(|result| serde_wasm_bindgen::to_value(&result).ok())
+            .unwrap_or(JsValue::NULL)
+    }
+
+    /// Drop a prepared session without finishing it, e.g. when checking
+    /// was abandoned. Returns `false` if the session was already released.
+    #[wasm_bindgen(js_name = releaseShared)]
+    pub fn release_shared(session_id: u32) -> bool {
+        SHARED_CHECKS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&session_id)
+            .is_some()
+    }
+}
+
+impl WasmParallelChecker {
+    pub(crate) fn finish_shared_result(session_id: u32) -> Option<WasmCheckResult> {
+        let shared = SHARED_CHECKS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&session_id)?;
+        let recorded = std::mem::take(
+            &mut *shared
+                .results
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+        let file_results: Vec<FileCheckResult> = recorded
+            .into_iter()
+            .enumerate()
+            .filter_map(|(file_idx, result)| result.or_else(|| shared.session.check_file(file_idx)))
+            .collect();
+        let result = shared.session.finish(file_results);
+
+        let diagnostics: Vec<WasmDiagnostic> = result
+            .file_results
+            .iter()
+            .flat_map(|file_result| {
+                file_result.diagnostics.iter().map(|diag| WasmDiagnostic {
+                    file: file_result.file_name.clone(),
+                    start: diag.start,
+                    length: diag.length,
+                    code: diag.code,
+                    message: diag.message_text.clone(),
+                    category: format!("{:?}", diag.category),
+                })
+            })
+            .collect();
+        Some(WasmCheckResult {
+            stats: WasmCheckStats {
+                file_count: shared.session.file_count(),
+                function_count: 0,
+                diagnostic_count: diagnostics.len(),
+            },
+            diagnostics,
+        })
+    }
+}
+
 /// Tests for the native (non-wasm) interface.
 /// The wasm-specific tests are skipped on non-wasm targets because wasm-bindgen
 /// functions cannot be called on non-wasm targets.
@@ -29,6 +29,96 @@ pub struct TsDiagnostic {
     category: u8,
     /// Diagnostic code (TS####)
     code: u32,
+    /// Message chain, when the message has elaboration lines
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    message_chain: Option<TsDiagnosticMessageChain>,
+    /// Related locations (earlier declarations, etc.)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    related_information: Vec<TsDiagnostic>,
+}
+
+/// TypeScript `DiagnosticMessageChain` - a message and its elaborations
+#[wasm_bindgen]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TsDiagnosticMessageChain {
+    message_text: String,
+    category: u8,
+    code: u32,
+    next: Vec<TsDiagnosticMessageChain>,
+}
+
+#[wasm_bindgen]
+impl TsDiagnosticMessageChain {
+    /// Get the message text of this link
+    #[wasm_bindgen(getter, js_name = messageText)]
+    pub fn message_text(&self) -> String {
+        self.message_text.clone()
+    }
+
+    /// Get the category
+    #[wasm_bindgen(getter)]
+    pub fn category(&self) -> u8 {
+        self.category
+    }
+
+    /// Get the code (0 for elaborations, whose codes are not tracked)
+    #[wasm_bindgen(getter)]
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    /// Get the elaborations of this link
+    #[wasm_bindgen(getter)]
+    pub fn next(&self) -> Vec<TsDiagnosticMessageChain> {
+        self.next.clone()
+    }
+}
+
+impl TsDiagnosticMessageChain {
+    /// Parse tsc's flattened chain layout, where each elaboration is a line
+    /// indented two spaces deeper than the message it elaborates. `None` for
+    /// single-line messages.
+    pub(crate) fn from_flattened(message_text: &str, category: u8, code: u32) -> Option<Self> {
+        if !message_text.contains('\n') {
+            return None;
+        }
+        let mut lines = message_text
+            .lines()
+            .map(|line| {
+                let text = line.trim_start_matches(' ');
+                ((line.len() - text.len()) / 2, text)
+            })
+            .peekable();
+        let (_, head) = lines.next()?;
+        Some(Self {
+            message_text: head.to_string(),
+            category,
+            code,
+            next: Self::links(&mut lines, 1, category),
+        })
+    }
+
+    /// Consume the lines at `depth` or deeper as sibling links.
+    fn links<'a>(
+        lines: &mut std::iter::Peekable<impl Iterator<Item = (usize, &'a str)>>,
+        depth: usize,
+        category: u8,
+    ) -> Vec<Self> {
+        let mut links = Vec::new();
+        while let Some(&(line_depth, text)) = lines.peek() {
+            if line_depth < depth {
+                break;
+            }
+            lines.next();
+            links.push(Self {
+                message_text: text.to_string(),
+                category,
+                code: 0,
+                next: Self::links(lines, line_depth + 1, category),
+            });
+        }
+        links
+    }
 }
 
 #[wasm_bindgen]
@@ -50,6 +140,8 @@ impl TsDiagnostic {
             message_text,
             category: category as u8,
             code,
+            message_chain: None,
+            related_information: Vec::new(),
         }
     }
 
@@ -89,6 +181,18 @@ impl TsDiagnostic {
         self.code
     }
 
+    /// Get the message chain, if the message has elaborations
+    #[wasm_bindgen(getter, js_name = messageChain)]
+    pub fn message_chain(&self) -> Option<TsDiagnosticMessageChain> {
+        self.message_chain.clone()
+    }
+
+    /// Get related information
+    #[wasm_bindgen(getter, js_name = relatedInformation)]
+    pub fn related_information(&self) -> Vec<TsDiagnostic> {
+        self.related_information.clone()
+    }
+
     /// Check if this is an error
     #[wasm_bindgen(js_name = isError)]
     pub fn is_error(&self) -> bool {
@@ -108,6 +212,30 @@ impl TsDiagnostic {
     }
 }
 
+impl TsDiagnostic {
+    /// Build a diagnostic whose message chain is parsed from `message_text`.
+    pub(crate) fn with_chain(
+        file_name: Option<String>,
+        start: u32,
+        length: u32,
+        message_text: String,
+        category: DiagnosticCategory,
+        code: u32,
+    ) -> Self {
+        let message_chain =
+            TsDiagnosticMessageChain::from_flattened(&message_text, category as u8, code);
+        Self {
+            message_chain,
+            ..Self::new(file_name, start, length, message_text, category, code)
+        }
+    }
+
+    pub(crate) fn with_related_information(mut self, related: Vec<TsDiagnostic>) -> Self {
+        self.related_information = related;
+        self
+    }
+}
+
 /// Format a diagnostic to a string
 ///
 /// Matches TypeScript's `formatDiagnostic` output format.
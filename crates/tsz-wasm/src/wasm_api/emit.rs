@@ -8,9 +8,9 @@ use wasm_bindgen::prelude::wasm_bindgen;
 use tsz::context::emit::EmitContext;
 #[cfg(feature = "dts")]
 use tsz::declaration_emitter::DeclarationEmitter;
-use tsz::emitter::{ModuleKind, Printer, PrinterOptions, ScriptTarget};
+use tsz::emitter::{JsxEmit, ModuleKind, Printer, PrinterOptions, ScriptTarget};
 use tsz::lowering::LoweringPass;
-use tsz::parser::{NodeArena, NodeIndex, ParserState, syntax_kind_ext};
+use tsz::parser::{NodeArena, NodeIndex, ParseDiagnostic, ParserState, syntax_kind_ext};
 use tsz_common::source_map::base64_encode;
 
 use super::options::{module_kind_from_u8, target_kind_from_u8};
@@ -36,6 +36,12 @@ pub struct EmitResult {
 #[serde(rename_all = "camelCase")]
 pub struct EmitDiagnostic {
     pub file: Option<String>,
+    /// Span start in the source, for syntax errors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<u32>,
+    /// Span length in the source, for syntax errors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<u32>,
     pub message: String,
     pub code: u32,
     pub category: u8,
@@ -56,17 +62,27 @@ pub struct EmittedFile {
 }
 
 /// Transpile options for single-file transpilation
+///
+/// Accepts either these fields directly or `ts.transpileModule`'s shape,
+/// where compiler options sit under `compilerOptions` next to `fileName`,
+/// `reportDiagnostics` and `moduleName`.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TranspileOptions {
     /// Source file name used for parsing and diagnostics
     #[serde(default)]
     pub file_name: Option<String>,
-    /// Target ECMAScript version
+    /// Report syntax errors in `diagnostics`
     #[serde(default)]
-    pub target: Option<u8>,
-    /// Module format
+    pub report_diagnostics: Option<bool>,
+    /// Name of the AMD/System module being emitted
     #[serde(default)]
+    pub module_name: Option<String>,
+    /// Target ECMAScript version (numeric, or a string like `"ES2015"`)
+    #[serde(default, deserialize_with = "deserialize_target")]
+    pub target: Option<u8>,
+    /// Module format (numeric, or a string like `"CommonJS"`)
+    #[serde(default, deserialize_with = "deserialize_module")]
     pub module: Option<u8>,
     /// Generate source maps
     #[serde(default)]
@@ -80,15 +96,119 @@ pub struct TranspileOptions {
     /// Remove comments
     #[serde(default)]
     pub remove_comments: Option<bool>,
-    /// JSX mode
-    #[serde(default)]
+    /// JSX mode (tsc's numeric `JsxEmit`, or a string like `"react-jsx"`)
+    #[serde(default, deserialize_with = "deserialize_jsx")]
     pub jsx: Option<u8>,
+    /// Factory for the classic JSX transform
+    #[serde(default)]
+    pub jsx_factory: Option<String>,
+    /// Fragment factory for the classic JSX transform
+    #[serde(default)]
+    pub jsx_fragment_factory: Option<String>,
+    /// Runtime module for the automatic JSX transform
+    #[serde(default)]
+    pub jsx_import_source: Option<String>,
     /// Downlevel iteration for for-of loops
     #[serde(default)]
     pub downlevel_iteration: Option<bool>,
     /// Module detection mode: "auto" (default), "force", or "legacy"
     #[serde(default)]
     pub module_detection: Option<String>,
+    /// Emit interop helpers for `CommonJS` imports
+    #[serde(default)]
+    pub es_module_interop: Option<bool>,
+    /// Emit class fields with define semantics (default: target >= ES2022)
+    #[serde(default)]
+    pub use_define_for_class_fields: Option<bool>,
+    /// Lower legacy (experimental) decorators
+    #[serde(default)]
+    pub experimental_decorators: Option<bool>,
+    /// Emit design-type metadata for decorators
+    #[serde(default)]
+    pub emit_decorator_metadata: Option<bool>,
+    /// Import helpers from `tslib` instead of inlining them
+    #[serde(default)]
+    pub import_helpers: Option<bool>,
+    /// Keep `const enum` declarations
+    #[serde(default)]
+    pub preserve_const_enums: Option<bool>,
+    /// Keep imports and exports not marked `type`
+    #[serde(default)]
+    pub verbatim_module_syntax: Option<bool>,
+    /// Emit `"use strict"` in non-module files
+    #[serde(default)]
+    pub always_strict: Option<bool>,
+}
+
+#[derive(Clone, Copy)]
+enum TranspileEnumOption {
+    Target,
+    Module,
+}
+
+fn deserialize_target<'de, D>(deserializer: D) -> Result<Option<u8>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserialize_enum_option(deserializer, TranspileEnumOption::Target)
+}
+
+fn deserialize_module<'de, D>(deserializer: D) -> Result<Option<u8>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserialize_enum_option(deserializer, TranspileEnumOption::Module)
+}
+
+/// `jsx` as tsc's numeric `JsxEmit`, rejecting values that are not one.
+fn deserialize_jsx<'de, D>(deserializer: D) -> Result<Option<u8>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString {
+        Number(u32),
+        String(String),
+    }
+
+    let jsx = match Option::<NumberOrString>::deserialize(deserializer)? {
+        None => return Ok(None),
+        Some(NumberOrString::Number(value)) => tsz::config::jsx_emit_from_ts_numeric(value)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown jsx value {value}"))),
+        Some(NumberOrString::String(value)) => tsz::config::jsx_string_to_emit(&value)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown jsx value \"{value}\""))),
+    }?;
+    Ok(u8::try_from(tsz::config::jsx_emit_ts_numeric_value(jsx)).ok())
+}
+
+/// Deserialize an enum-valued option given as tsc's numeric value or its
+/// option spelling. Unknown spellings are treated as unset.
+fn deserialize_enum_option<'de, D>(
+    deserializer: D,
+    kind: TranspileEnumOption,
+) -> Result<Option<u8>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString {
+        Number(u8),
+        String(String),
+    }
+
+    Ok(match Option::<NumberOrString>::deserialize(deserializer)? {
+        None => None,
+        Some(NumberOrString::Number(value)) => Some(value),
+        Some(NumberOrString::String(value)) => match kind {
+            TranspileEnumOption::Target => {
+                ScriptTarget::from_ts_str(&value).map(ScriptTarget::ts_numeric_value)
+            }
+            TranspileEnumOption::Module => ModuleKind::from_ts_str(&value)
+                .and_then(|module| u8::try_from(module.ts_numeric_value()).ok()),
+        },
+    })
 }
 
 impl TranspileOptions {
@@ -99,15 +219,57 @@ impl TranspileOptions {
             .unwrap_or(DEFAULT_TRANSPILE_FILE_NAME)
     }
 
+    /// Parse `options_json`, lifting `compilerOptions` entries to the top
+    /// level so both option shapes deserialize the same way.
+    fn from_json(options_json: &str) -> Result<Self, serde_json::Error> {
+        let mut value: serde_json::Value = serde_json::from_str(options_json)?;
+        if let Some(object) = value.as_object_mut()
+            && let Some(serde_json::Value::Object(compiler_options)) =
+                object.remove("compilerOptions")
+        {
+            for (key, option) in compiler_options {
+                object.entry(key).or_insert(option);
+            }
+        }
+        serde_json::from_value(value)
+    }
+
     fn to_printer_options(&self) -> PrinterOptions {
+        let target = target_kind_from_u8(self.target);
         let mut opts = PrinterOptions {
-            target: target_kind_from_u8(self.target),
+            target,
             module: module_kind_from_u8(self.module),
+            // Every file is transpiled on its own, as with tsc's
+            // transpileModule.
+            isolated_modules: true,
             ..Default::default()
         };
 
         opts.remove_comments = self.remove_comments.unwrap_or(false);
         opts.downlevel_iteration = self.downlevel_iteration.unwrap_or(false);
+        opts.always_strict = self.always_strict.unwrap_or(false);
+        opts.use_define_for_class_fields = self
+            .use_define_for_class_fields
+            .unwrap_or_else(|| target.supports_es2022());
+        opts.es_module_interop = self.es_module_interop.unwrap_or(false);
+        opts.legacy_decorators = self.experimental_decorators.unwrap_or(false);
+        opts.emit_decorator_metadata = self.emit_decorator_metadata.unwrap_or(false);
+        opts.import_helpers = self.import_helpers.unwrap_or(false);
+        opts.preserve_const_enums = self.preserve_const_enums.unwrap_or(false);
+        opts.verbatim_module_syntax = self.verbatim_module_syntax.unwrap_or(false);
+        if let Some(jsx) = self
+            .jsx
+            .and_then(|jsx| tsz::config::jsx_emit_from_ts_numeric(u32::from(jsx)))
+            .map(tsz::config::jsx_emit_to_emitter)
+        {
+            opts.jsx = jsx;
+            opts.jsx_preserve_explicit = jsx == JsxEmit::Preserve;
+        }
+        opts.jsx_factory.clone_from(&self.jsx_factory);
+        opts.jsx_fragment_factory
+            .clone_from(&self.jsx_fragment_factory);
+        opts.jsx_import_source.clone_from(&self.jsx_import_source);
+        opts.bundled_module_name.clone_from(&self.module_name);
 
         if let Some(ref detection) = self.module_detection {
             if detection.eq_ignore_ascii_case("force") {
@@ -129,6 +291,7 @@ struct TranspileCompilation {
     output_text: String,
     file_is_module: bool,
     source_map_text: Option<String>,
+    parse_diagnostics: Vec<ParseDiagnostic>,
 }
 
 fn invalid_options_output(error: serde_json::Error) -> String {
@@ -138,6 +301,8 @@ fn invalid_options_output(error: serde_json::Error) -> String {
         declaration_text: None,
         diagnostics: vec![EmitDiagnostic {
             file: None,
+            start: None,
+            length: None,
             message: format!("Invalid transpile options JSON: {error}"),
             code: INVALID_TRANSPILE_OPTIONS_CODE,
             category: DIAGNOSTIC_CATEGORY_ERROR,
@@ -155,6 +320,7 @@ fn compile_transpile_source(
 ) -> TranspileCompilation {
     let mut parser = ParserState::new(file_name.to_string(), source.to_string());
     let root_idx = parser.parse_source_file();
+    let parse_diagnostics = parser.get_diagnostics().to_vec();
     let arena = parser.into_arena();
     let file_is_module = source_file_has_module_syntax(&arena, root_idx);
 
@@ -188,6 +354,7 @@ fn compile_transpile_source(
         output_text,
         file_is_module,
         source_map_text,
+        parse_diagnostics,
     }
 }
 
@@ -313,10 +480,12 @@ pub struct TranspileOutput {
 
 /// Transpile a single TypeScript file to JavaScript
 ///
-/// This is a simplified API for quick transpilation without creating a full program.
+/// This is a simplified API for quick transpilation without creating a full program:
+/// the source is parsed and emitted without binding or type checking, like
+/// `ts.transpileModule`. With `reportDiagnostics`, syntax errors are returned.
 #[wasm_bindgen(js_name = transpileModule)]
 pub fn transpile_module(source: &str, options_json: &str) -> String {
-    let options: TranspileOptions = match serde_json::from_str(options_json) {
+    let options = match TranspileOptions::from_json(options_json) {
         Ok(options) => options,
         Err(error) => return invalid_options_output(error),
     };
@@ -361,12 +530,29 @@ pub fn transpile_module(source: &str, options_json: &str) -> String {
         }
     }
 
+    let diagnostics = if options.report_diagnostics.unwrap_or(false) {
+        compiled
+            .parse_diagnostics
+            .iter()
+            .map(|diag| EmitDiagnostic {
+                file: Some(file_name.clone()),
+                start: Some(diag.start),
+                length: Some(diag.length),
+                message: diag.message.clone(),
+                code: diag.code,
+                category: DIAGNOSTIC_CATEGORY_ERROR,
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
     // Build result
     let result = TranspileOutput {
         output_text,
         source_map_text,
         declaration_text,
-        diagnostics: Vec::new(),
+        diagnostics,
     };
 
     serialize_transpile_output(&result)
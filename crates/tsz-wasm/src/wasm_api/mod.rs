@@ -28,7 +28,8 @@
 //! const program = createTsProgram(['file.ts'], options, host);
 //! const sourceFiles = program.getSourceFiles();
 //! const checker = program.getTypeChecker();
-//! const type = checker.getTypeAtLocation(node);
+//! const sourceFile = program.getSourceFile('file.ts');
+//! const type = checker.getTypeAtLocation(sourceFile, node);
 //! console.log(checker.typeToString(type));
 //! ```
 
@@ -45,7 +46,7 @@ pub mod types;
 pub mod utilities;
 
 // Re-export main types
-pub use diagnostics::TsDiagnostic;
+pub use diagnostics::{TsDiagnostic, TsDiagnosticMessageChain};
 pub use program::TsProgram;
 pub use source_file::TsSourceFile;
 pub use type_checker::TsTypeChecker;
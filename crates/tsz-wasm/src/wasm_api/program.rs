@@ -14,9 +14,11 @@ use tsz::parallel::{
     parse_and_bind_parallel_with_libs,
 };
 use tsz::parser::ParserState;
-use tsz_checker::diagnostics::DiagnosticCategory;
+use tsz_checker::diagnostics::{Diagnostic, DiagnosticCategory};
 use tsz_solver::construction::TypeInterner;
 
+use super::diagnostics::TsDiagnostic;
+use super::enums::DiagnosticCategory as TsDiagnosticCategory;
 use super::options::{module_kind_from_u8, target_kind_from_u8};
 use super::source_file::TsSourceFile;
 use super::type_checker::TsTypeChecker;
@@ -140,6 +142,8 @@ impl TsCompilerOptions {
             strict_builtin_iterator_return: strict,
             erasable_syntax_only: false,
             no_fallthrough_cases_in_switch: false,
+            no_error_truncation: false,
+            report_suggestions: false,
         }
     }
 }
@@ -158,7 +162,7 @@ pub struct TsProgram {
     /// Library files (lib.d.ts, etc.)
     lib_files: Vec<Arc<LibFile>>,
     /// Merged program state (contains bound files with parse diagnostics)
-    merged: Option<MergedProgram>,
+    merged: Option<Arc<MergedProgram>>,
     /// Type interner for this program
     type_interner: Arc<TypeInterner>,
     /// Compiler options
@@ -275,7 +279,7 @@ impl TsProgram {
         // Merge results
         let merged = merge_bind_results(bind_results);
 
-        self.merged = Some(merged);
+        self.merged = Some(Arc::new(merged));
     }
 
     /// Collect syntactic diagnostics as structured values
@@ -440,18 +444,70 @@ impl TsProgram {
         serde_json::to_string(&all_diagnostics).unwrap_or_else(|_| "[]".to_string())
     }
 
-    /// Get syntactic diagnostics as `JsValue` (avoids JSON string intermediate)
+    /// Get syntactic diagnostics for `sourceFile`, or for every file
     #[wasm_bindgen(js_name = getSyntacticDiagnostics)]
-    pub fn get_syntactic_diagnostics(&mut self, file_name: Option<String>) -> JsValue {
-        let diagnostics = self.collect_syntactic_diagnostics(file_name.as_deref());
-        serde_wasm_bindgen::to_value(&diagnostics).unwrap_or(JsValue::NULL)
+    pub fn get_syntactic_diagnostics(
+        &mut self,
+        source_file: Option<&TsSourceFile>,
+    ) -> Vec<TsDiagnostic> {
+        self.ensure_compiled();
+        let Some(merged) = &self.merged else {
+            return Vec::new();
+        };
+        let file_name = source_file.map(|file| file.file_name());
+
+        let mut diagnostics = Vec::new();
+        for bound_file in &merged.files {
+            if file_name
+                .as_ref()
+                .is_some_and(|name| *name != bound_file.file_name)
+            {
+                continue;
+            }
+            let source_text = self.source_text(&bound_file.file_name);
+            for diag in &bound_file.parse_diagnostics {
+                let (start, length) = utf16_span(source_text, diag.start, diag.length);
+                diagnostics.push(TsDiagnostic::with_chain(
+                    Some(bound_file.file_name.clone()),
+                    start,
+                    length,
+                    diag.message.clone(),
+                    TsDiagnosticCategory::Error,
+                    diag.code,
+                ));
+            }
+        }
+        diagnostics
     }
 
-    /// Get semantic diagnostics as `JsValue` (avoids JSON string intermediate)
+    /// Get semantic diagnostics for `sourceFile`, or for every file
     #[wasm_bindgen(js_name = getSemanticDiagnostics)]
-    pub fn get_semantic_diagnostics(&mut self, file_name: Option<String>) -> JsValue {
-        let diagnostics = self.collect_semantic_diagnostics(file_name.as_deref());
-        serde_wasm_bindgen::to_value(&diagnostics).unwrap_or(JsValue::NULL)
+    pub fn get_semantic_diagnostics(
+        &mut self,
+        source_file: Option<&TsSourceFile>,
+    ) -> Vec<TsDiagnostic> {
+        let file_name = source_file.map(|file| file.file_name());
+        self.check_diagnostics()
+            .into_iter()
+            .filter(|diag| match &file_name {
+                Some(name) => diag.file == *name,
+                None => !diag.file.is_empty(),
+            })
+            .map(|diag| self.ts_diagnostic(&diag))
+            .collect()
+    }
+
+    /// Get diagnostics not attached to any file, such as missing global types
+    #[wasm_bindgen(js_name = getGlobalDiagnostics)]
+    pub fn get_global_diagnostics(&mut self) -> Vec<TsDiagnostic> {
+        let mut seen = rustc_hash::FxHashSet::default();
+        self.check_diagnostics()
+            .into_iter()
+            .filter(|diag| diag.file.is_empty())
+            // Every file's check reports the same global diagnostics.
+            .filter(|diag| seen.insert((diag.code, diag.message_text.clone())))
+            .map(|diag| self.ts_diagnostic(&diag))
+            .collect()
     }
 
     /// Get all diagnostics (syntactic + semantic) as `JsValue` (avoids JSON string intermediate)
@@ -462,6 +518,60 @@ impl TsProgram {
         serde_wasm_bindgen::to_value(&all).unwrap_or(JsValue::NULL)
     }
 
+    /// Check every file and collect the checker diagnostics, in file order
+    fn check_diagnostics(&mut self) -> Vec<Diagnostic> {
+        self.ensure_compiled();
+        let Some(merged) = &self.merged else {
+            return Vec::new();
+        };
+        let checker_options = self.options.to_checker_options();
+        check_files_parallel(merged, &checker_options, &self.lib_files)
+            .file_results
+            .into_iter()
+            .flat_map(|file_result| file_result.diagnostics)
+            .collect()
+    }
+
+    /// Convert a checker diagnostic, with UTF-16 spans and its message chain
+    fn ts_diagnostic(&self, diag: &Diagnostic) -> TsDiagnostic {
+        let (start, length) = utf16_span(self.source_text(&diag.file), diag.start, diag.length);
+        let related = diag
+            .related_information
+            .iter()
+            .map(|related| {
+                let (start, length) = utf16_span(
+                    self.source_text(&related.file),
+                    related.start,
+                    related.length,
+                );
+                TsDiagnostic::new(
+                    Some(related.file.clone()),
+                    start,
+                    length,
+                    related.message_text.clone(),
+                    ts_category(related.category),
+                    related.code,
+                )
+            })
+            .collect();
+        TsDiagnostic::with_chain(
+            (!diag.file.is_empty()).then(|| diag.file.clone()),
+            start,
+            length,
+            diag.message_text.clone(),
+            ts_category(diag.category),
+            diag.code,
+        )
+        .with_related_information(related)
+    }
+
+    fn source_text(&self, file_name: &str) -> Option<&str> {
+        self.files
+            .iter()
+            .find(|(name, _)| name == file_name)
+            .map(|(_, text)| text.as_str())
+    }
+
     /// Get all diagnostic codes as array
     #[wasm_bindgen(js_name = getAllDiagnosticCodes)]
     pub fn get_all_diagnostic_codes(&mut self) -> Vec<u32> {
@@ -501,15 +611,39 @@ impl TsProgram {
         // Create new type checker
         // In a full implementation, we'd cache this
         TsTypeChecker::new(
-            self.merged
-                .as_ref()
-                .expect("merged must be set before creating type checker"),
+            Arc::clone(
+                self.merged
+                    .as_ref()
+                    .expect("merged must be set before creating type checker"),
+            ),
             std::sync::Arc::clone(&self.type_interner),
             &self.options,
             &self.lib_files,
         )
     }
 
+    /// Get a source file of the program by name
+    ///
+    /// Its node handles can be passed to this program's type checker.
+    #[wasm_bindgen(js_name = getSourceFile)]
+    pub fn get_source_file(&mut self, file_name: &str) -> Option<TsSourceFile> {
+        self.ensure_compiled();
+
+        let merged = self.merged.as_ref()?;
+        let idx = merged
+            .files
+            .iter()
+            .position(|bound_file| bound_file.file_name == file_name)?;
+        let bound_file = &merged.files[idx];
+        let source_text = self.files.get(idx).map_or("", |(_, text)| text.as_str());
+        Some(TsSourceFile::from_parsed(
+            bound_file.file_name.clone(),
+            source_text.to_string(),
+            Arc::clone(&bound_file.arena),
+            bound_file.source_file,
+        ))
+    }
+
     /// Emit JavaScript output for all files
     #[wasm_bindgen(js_name = emitJson)]
     pub fn emit_json(&mut self) -> String {
@@ -622,6 +756,26 @@ impl TsProgram {
     }
 }
 
+/// Convert a byte span to UTF-16 when the file's text is known.
+fn utf16_span(source_text: Option<&str>, start: u32, length: u32) -> (u32, u32) {
+    match source_text {
+        Some(src) => (
+            TsProgram::byte_offset_to_utf16(src, start),
+            TsProgram::byte_length_to_utf16(src, start, length),
+        ),
+        None => (start, length),
+    }
+}
+
+const fn ts_category(category: DiagnosticCategory) -> TsDiagnosticCategory {
+    match category {
+        DiagnosticCategory::Error => TsDiagnosticCategory::Error,
+        DiagnosticCategory::Warning => TsDiagnosticCategory::Warning,
+        DiagnosticCategory::Suggestion => TsDiagnosticCategory::Suggestion,
+        DiagnosticCategory::Message => TsDiagnosticCategory::Message,
+    }
+}
+
 fn js_output_name(file_name: &str) -> String {
     if let Some(stem) = file_name.strip_suffix(".mts") {
         return format!("{stem}.mjs");
@@ -277,6 +277,22 @@ fn get_script_kind_from_file_name(file_name: &str) -> ScriptKind {
     }
 }
 
+impl TsSourceFile {
+    /// Wrap a file a program has already parsed, sharing its AST so node
+    /// handles match the program's.
+    pub(crate) fn from_parsed(
+        file_name: String,
+        source_text: String,
+        arena: Arc<NodeArena>,
+        root_idx: NodeIndex,
+    ) -> Self {
+        let mut sf = Self::new(file_name, source_text);
+        sf.arena = Some(arena);
+        sf.root_idx = Some(root_idx);
+        sf
+    }
+}
+
 /// Create a source file (factory function)
 #[wasm_bindgen(js_name = createTsSourceFile)]
 pub fn create_ts_source_file(
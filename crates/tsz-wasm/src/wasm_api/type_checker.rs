@@ -2,20 +2,34 @@
 //!
 //! Provides the `TsTypeChecker` struct which implements TypeScript's `TypeChecker` interface.
 
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::cell::RefCell;
 use std::sync::Arc;
 use wasm_bindgen::prelude::wasm_bindgen;
 
+use tsz::binder::{SymbolId, symbol_flags};
+use tsz::checker::TypeCache;
+use tsz::checker::state::CheckerState;
 use tsz::lib_loader::LibFile;
-use tsz::parallel::MergedProgram;
+use tsz::lsp::resolver::ScopeWalker;
+use tsz::parallel::{FileCheckSession, MergedProgram};
+use tsz::parser::NodeIndex;
+use tsz_solver::computation::evaluate_type;
 use tsz_solver::construction::TypeInterner;
 use tsz_solver::ts_type_flags::{is_nullable_type, type_id_ts_flags};
 use tsz_solver::{
-    TypeFormatter, TypeId, is_array_type, is_intersection_type, is_tuple_type, is_type_parameter,
-    is_union_type,
+    CallSignature, ParamInfo, PropertyInfo, TypeFormatter, TypeId, is_array_type,
+    is_intersection_type, is_tuple_type, is_type_parameter, is_union_type, visitor,
 };
 
 use super::enums::SignatureKind;
 use super::program::TsCompilerOptions;
+use super::source_file::TsSourceFile;
+use super::types::{TsSignature, TsSymbol, TsType};
+
+/// Lazy references, applications and wrappers unwrapped before a type's
+/// structure is read give up after this many steps.
+const MAX_STRUCTURE_STEPS: usize = 16;
 
 /// TypeScript `TypeChecker` - provides type information
 ///
@@ -27,8 +41,12 @@ use super::program::TsCompilerOptions;
 /// - `isTypeAssignableTo(source, target)` - Check assignability
 #[wasm_bindgen]
 pub struct TsTypeChecker {
-    /// Shared type interner.
+    /// Type interner used when the checker has no program.
     interner: Arc<TypeInterner>,
+    /// The program's files, checked on demand.
+    session: Option<FileCheckSession>,
+    /// Types each queried file's checker has computed so far, by file index.
+    file_caches: RefCell<FxHashMap<usize, TypeCache>>,
 }
 
 #[wasm_bindgen]
@@ -36,32 +54,58 @@ impl TsTypeChecker {
     /// Get the type at a specific AST node location
     ///
     /// # Arguments
-    /// * `node_handle` - Handle (index) of the AST node
+    /// * `source_file` - A source file of this checker's program
+    /// * `node_handle` - Handle (index) of the AST node in `source_file`
     ///
     /// # Returns
-    /// Handle (ID) of the type, or 0 for error type
+    /// The type of the node, or `any` when it has none (as tsc's error type)
     #[wasm_bindgen(js_name = getTypeAtLocation)]
-    pub fn get_type_at_location(&self, _node_handle: u32) -> u32 {
-        // In a full implementation, we'd:
-        // 1. Look up the node from the handle
-        // 2. Run type checking on demand
-        // 3. Return the type ID
-
-        // For now, return a placeholder
-        TypeId::ANY.0
+    pub fn get_type_at_location(&self, source_file: &TsSourceFile, node_handle: u32) -> TsType {
+        let node_type = self.file_index(source_file).and_then(|file_idx| {
+            let file = &self.session.as_ref()?.program().files[file_idx];
+            file.arena.get(NodeIndex(node_handle))?;
+            let type_id = self.query(file_idx, |checker| {
+                checker.get_type_of_node(NodeIndex(node_handle))
+            })?;
+            Some((type_id, file_idx))
+        });
+        match node_type {
+            Some((type_id, file_idx)) if type_id != TypeId::ERROR => {
+                self.ts_type(type_id, Some(file_idx))
+            }
+            _ => self.ts_type(TypeId::ANY, None),
+        }
     }
 
     /// Get the symbol at a specific AST node location
     ///
     /// # Arguments
-    /// * `node_handle` - Handle (index) of the AST node
+    /// * `source_file` - A source file of this checker's program
+    /// * `node_handle` - Handle (index) of the AST node in `source_file`
     ///
     /// # Returns
-    /// Handle (ID) of the symbol, or `u32::MAX` if none
+    /// The symbol the node names or declares, or `undefined` if none
     #[wasm_bindgen(js_name = getSymbolAtLocation)]
-    pub fn get_symbol_at_location(&self, _node_handle: u32) -> u32 {
-        // In a full implementation, we'd look up the symbol
-        u32::MAX
+    pub fn get_symbol_at_location(
+        &self,
+        source_file: &TsSourceFile,
+        node_handle: u32,
+    ) -> Option<TsSymbol> {
+        let session = self.session.as_ref()?;
+        let file_idx = self.file_index(source_file)?;
+        let file = &session.program().files[file_idx];
+        let binder = session.binder(file_idx)?;
+        let node = NodeIndex(node_handle);
+        file.arena.get(node)?;
+
+        let sym_id = ScopeWalker::new(&file.arena, binder).resolve_node(file.source_file, node)?;
+        let symbol = binder.symbols.get(sym_id)?;
+        Some(TsSymbol::from_file(
+            sym_id.0,
+            symbol.flags,
+            symbol.escaped_name.clone(),
+            file_idx,
+        ))
     }
 
     /// Get the declared type of a symbol
@@ -71,18 +115,46 @@ impl TsTypeChecker {
     }
 
     /// Get the type of a symbol
+    ///
+    /// Properties and parameters read from a type carry their own type;
+    /// other symbols are resolved by the checker of the file they came from.
     #[wasm_bindgen(js_name = getTypeOfSymbol)]
-    pub fn get_type_of_symbol(&self, _symbol_handle: u32) -> u32 {
-        TypeId::ANY.0
+    pub fn get_type_of_symbol(&self, symbol: &TsSymbol) -> TsType {
+        if let Some(type_handle) = symbol.type_handle() {
+            return self.ts_type(TypeId(type_handle), symbol.file_idx());
+        }
+        let symbol_type = symbol
+            .file_idx()
+            .filter(|_| symbol.handle() != u32::MAX)
+            .and_then(|file_idx| {
+                let type_id = self.query(file_idx, |checker| {
+                    checker.get_type_of_symbol(SymbolId(symbol.handle()))
+                })?;
+                Some((type_id, file_idx))
+            });
+        match symbol_type {
+            Some((type_id, file_idx)) if type_id != TypeId::ERROR => {
+                self.ts_type(type_id, Some(file_idx))
+            }
+            _ => self.ts_type(TypeId::ANY, None),
+        }
     }
 
     /// Format a type as a string
+    ///
+    /// Types from a program file are printed by that file's checker, so
+    /// named types keep their declared names.
     #[wasm_bindgen(js_name = typeToString)]
-    pub fn type_to_string(&self, type_handle: u32) -> String {
-        let type_id = TypeId(type_handle);
+    pub fn type_to_string(&self, ts_type: &TsType) -> String {
+        let type_id = TypeId(ts_type.handle());
+        if let Some(text) = ts_type
+            .file_idx()
+            .and_then(|file_idx| self.query(file_idx, |checker| checker.format_type(type_id)))
+        {
+            return text;
+        }
 
-        let interner = &*self.interner;
-        let mut formatter = TypeFormatter::new(interner);
+        let mut formatter = TypeFormatter::new(self.types());
         formatter.format(type_id).into_owned()
     }
 
@@ -107,10 +179,25 @@ impl TsTypeChecker {
 
     /// Get properties of a type
     ///
-    /// Returns handles to symbol objects
+    /// Like tsc, primitives have no properties here (see `getApparentType`);
+    /// a union has the properties common to all of its members.
     #[wasm_bindgen(js_name = getPropertiesOfType)]
-    pub fn get_properties_of_type(&self, _type_handle: u32) -> Vec<u32> {
-        Vec::new()
+    pub fn get_properties_of_type(&self, ts_type: &TsType) -> Vec<TsSymbol> {
+        let type_id = TypeId(ts_type.handle());
+        let file_idx = ts_type.file_idx();
+        let properties = file_idx
+            .and_then(|file_idx| {
+                self.query(file_idx, |checker| {
+                    let types = self.types();
+                    structured_properties(types, &mut |t| checker.resolve_lazy_type(t), type_id, 0)
+                })
+            })
+            .unwrap_or_else(|| structured_properties(self.types(), &mut |t| t, type_id, 0));
+
+        properties
+            .iter()
+            .map(|prop| self.property_symbol(prop, file_idx))
+            .collect()
     }
 
     /// Get a specific property of a type by name
@@ -119,16 +206,52 @@ impl TsTypeChecker {
         None
     }
 
-    /// Get call signatures of a type
+    /// Get the call or construct signatures of a type
     #[wasm_bindgen(js_name = getSignaturesOfType)]
-    pub fn get_signatures_of_type(&self, _type_handle: u32, _kind: SignatureKind) -> Vec<u32> {
-        Vec::new()
+    pub fn get_signatures_of_type(
+        &self,
+        ts_type: &TsType,
+        kind: SignatureKind,
+    ) -> Vec<TsSignature> {
+        let type_id = TypeId(ts_type.handle());
+        let file_idx = ts_type.file_idx();
+        let signatures = file_idx
+            .and_then(|file_idx| {
+                self.query(file_idx, |checker| {
+                    let types = self.types();
+                    structured_signatures(
+                        types,
+                        &mut |t| checker.resolve_lazy_type(t),
+                        type_id,
+                        kind,
+                    )
+                })
+            })
+            .unwrap_or_else(|| structured_signatures(self.types(), &mut |t| t, type_id, kind));
+
+        signatures
+            .iter()
+            .zip(0u32..)
+            .map(|(signature, handle)| {
+                let parameters = signature
+                    .params
+                    .iter()
+                    .zip(0usize..)
+                    .map(|(param, index)| self.parameter_symbol(param, index, file_idx))
+                    .collect();
+                TsSignature::with_shape(
+                    handle,
+                    parameters,
+                    self.ts_type(signature.return_type, file_idx),
+                )
+            })
+            .collect()
     }
 
     /// Get return type of a signature
     #[wasm_bindgen(js_name = getReturnTypeOfSignature)]
-    pub fn get_return_type_of_signature(&self, _signature_handle: u32) -> u32 {
-        TypeId::ANY.0
+    pub fn get_return_type_of_signature(&self, signature: &TsSignature) -> TsType {
+        signature.get_return_type()
     }
 
     /// Get base types (for classes/interfaces)
@@ -146,7 +269,7 @@ impl TsTypeChecker {
     /// Get type flags
     #[wasm_bindgen(js_name = getTypeFlags)]
     pub fn get_type_flags(&self, type_handle: u32) -> u32 {
-        type_id_ts_flags(&*self.interner, TypeId(type_handle))
+        type_id_ts_flags(self.types(), TypeId(type_handle))
     }
 
     /// Get symbol flags
@@ -160,31 +283,31 @@ impl TsTypeChecker {
     /// Check if type is a union type
     #[wasm_bindgen(js_name = isUnionType)]
     pub fn is_union_type(&self, type_handle: u32) -> bool {
-        is_union_type(&*self.interner, TypeId(type_handle))
+        is_union_type(self.types(), TypeId(type_handle))
     }
 
     /// Check if type is an intersection type
     #[wasm_bindgen(js_name = isIntersectionType)]
     pub fn is_intersection_type(&self, type_handle: u32) -> bool {
-        is_intersection_type(&*self.interner, TypeId(type_handle))
+        is_intersection_type(self.types(), TypeId(type_handle))
     }
 
     /// Check if type is a type parameter
     #[wasm_bindgen(js_name = isTypeParameter)]
     pub fn is_type_parameter(&self, type_handle: u32) -> bool {
-        is_type_parameter(&*self.interner, TypeId(type_handle))
+        is_type_parameter(self.types(), TypeId(type_handle))
     }
 
     /// Check if type is an array type
     #[wasm_bindgen(js_name = isArrayType)]
     pub fn is_array_type(&self, type_handle: u32) -> bool {
-        is_array_type(&*self.interner, TypeId(type_handle))
+        is_array_type(self.types(), TypeId(type_handle))
     }
 
     /// Check if type is a tuple type
     #[wasm_bindgen(js_name = isTupleType)]
     pub fn is_tuple_type(&self, type_handle: u32) -> bool {
-        is_tuple_type(&*self.interner, TypeId(type_handle))
+        is_tuple_type(self.types(), TypeId(type_handle))
     }
 
     /// Check if type is nullable (includes `null` or `undefined`).
@@ -193,7 +316,7 @@ impl TsTypeChecker {
     /// `undefined` intrinsics directly, and for any union that contains them.
     #[wasm_bindgen(js_name = isNullableType)]
     pub fn is_nullable_type(&self, type_handle: u32) -> bool {
-        is_nullable_type(&*self.interner, TypeId(type_handle))
+        is_nullable_type(self.types(), TypeId(type_handle))
     }
 }
 
@@ -242,12 +365,22 @@ define_checker_type_getters! {
 impl TsTypeChecker {
     /// Create a new type checker for a program
     pub(crate) fn new(
-        _merged: &MergedProgram,
+        merged: Arc<MergedProgram>,
         interner: Arc<TypeInterner>,
-        _options: &TsCompilerOptions,
-        _lib_files: &[Arc<LibFile>],
+        options: &TsCompilerOptions,
+        lib_files: &[Arc<LibFile>],
     ) -> Self {
-        Self { interner }
+        let session = FileCheckSession::new(
+            merged,
+            options.to_checker_options(),
+            lib_files,
+            &FxHashMap::default(),
+        );
+        Self {
+            interner,
+            session: Some(session),
+            file_caches: RefCell::new(FxHashMap::default()),
+        }
     }
 
     /// Construct a checker from an interner alone. Test-only entrypoint that
@@ -255,8 +388,218 @@ impl TsTypeChecker {
     /// the predicate methods without standing up a full program.
     #[cfg(test)]
     pub(crate) fn from_interner_for_test(interner: Arc<TypeInterner>) -> Self {
-        Self { interner }
+        Self {
+            interner,
+            session: None,
+            file_caches: RefCell::new(FxHashMap::default()),
+        }
+    }
+
+    /// The interner this checker's types live in.
+    fn types(&self) -> &TypeInterner {
+        self.session
+            .as_ref()
+            .map_or(&*self.interner, |session| &session.program().type_interner)
+    }
+
+    /// Index of `source_file` among the program's files.
+    fn file_index(&self, source_file: &TsSourceFile) -> Option<usize> {
+        let file_name = source_file.file_name();
+        self.session
+            .as_ref()?
+            .program()
+            .files
+            .iter()
+            .position(|file| file.file_name == file_name)
+    }
+
+    /// Run `query` with the checker of program file `file_idx`, keeping the
+    /// types it computes for the next query of that file.
+    fn query<R>(
+        &self,
+        file_idx: usize,
+        query: impl FnOnce(&mut CheckerState<'_>) -> R,
+    ) -> Option<R> {
+        let session = self.session.as_ref()?;
+        let cache = self.file_caches.borrow_mut().remove(&file_idx);
+        let (result, cache) = session.query_file(file_idx, cache, query)?;
+        self.file_caches.borrow_mut().insert(file_idx, cache);
+        Some(result)
+    }
+
+    fn ts_type(&self, type_id: TypeId, file_idx: Option<usize>) -> TsType {
+        let flags = type_id_ts_flags(self.types(), type_id);
+        match file_idx {
+            Some(file_idx) => TsType::from_file(type_id.0, flags, file_idx),
+            None => TsType::new(type_id.0, flags),
+        }
     }
+
+    /// A symbol for a property read from a type's structure, backed by the
+    /// member symbol that declares it when there is one.
+    fn property_symbol(&self, prop: &PropertyInfo, file_idx: Option<usize>) -> TsSymbol {
+        let name = self.types().resolve_atom(prop.name);
+        let declared = self.session.as_ref().and_then(|session| {
+            let program = session.program();
+            let parent = program.symbols.get(prop.parent_id?)?;
+            let sym_id = parent.members.as_ref()?.get(&name)?;
+            Some((sym_id, program.symbols.get(sym_id)?.flags))
+        });
+        let (handle, flags) = declared.map_or_else(
+            || {
+                let kind = if prop.is_method {
+                    symbol_flags::METHOD
+                } else {
+                    symbol_flags::PROPERTY
+                };
+                let optional = if prop.optional {
+                    symbol_flags::OPTIONAL
+                } else {
+                    0
+                };
+                (u32::MAX, kind | optional)
+            },
+            |(sym_id, flags)| (sym_id.0, flags),
+        );
+        TsSymbol::with_type(handle, flags, name, file_idx, prop.type_id.0)
+    }
+
+    /// A symbol for the `index`-th parameter of a signature.
+    fn parameter_symbol(
+        &self,
+        param: &ParamInfo,
+        index: usize,
+        file_idx: Option<usize>,
+    ) -> TsSymbol {
+        let name = param.name.map_or_else(
+            || format!("arg{index}"),
+            |name| self.types().resolve_atom(name),
+        );
+        let optional = if param.optional {
+            symbol_flags::OPTIONAL
+        } else {
+            0
+        };
+        TsSymbol::with_type(
+            u32::MAX,
+            symbol_flags::FUNCTION_SCOPED_VARIABLE | optional,
+            name,
+            file_idx,
+            param.type_id.0,
+        )
+    }
+}
+
+/// Resolve lazy references, evaluate, and strip `readonly`/`NoInfer` until
+/// `type_id` is a structural type whose members can be read.
+fn structure_of(
+    types: &TypeInterner,
+    resolve: &mut dyn FnMut(TypeId) -> TypeId,
+    mut type_id: TypeId,
+) -> TypeId {
+    for _ in 0..MAX_STRUCTURE_STEPS {
+        let evaluated = evaluate_type(types, resolve(type_id));
+        let next = visitor::unwrap_readonly_or_noinfer(types, evaluated)
+            .or_else(|| {
+                let app = visitor::application_id(types, evaluated)?;
+                Some(types.type_application(app).base)
+            })
+            .unwrap_or(evaluated);
+        if next == type_id {
+            break;
+        }
+        type_id = next;
+    }
+    type_id
+}
+
+/// Properties of `type_id`, in declaration order.
+fn structured_properties(
+    types: &TypeInterner,
+    resolve: &mut dyn FnMut(TypeId) -> TypeId,
+    type_id: TypeId,
+    depth: usize,
+) -> Vec<PropertyInfo> {
+    if depth > MAX_STRUCTURE_STEPS {
+        return Vec::new();
+    }
+    let type_id = structure_of(types, resolve, type_id);
+
+    let mut properties = if let Some(shape_id) = visitor::object_shape_id(types, type_id)
+        .or_else(|| visitor::object_with_index_shape_id(types, type_id))
+    {
+        types.object_shape(shape_id).properties.clone()
+    } else if let Some(shape_id) = visitor::callable_shape_id(types, type_id) {
+        types.callable_shape(shape_id).properties.clone()
+    } else if let Some(list_id) = visitor::intersection_list_id(types, type_id) {
+        // Every member's properties; the first member to declare a name wins.
+        let mut seen = FxHashSet::default();
+        let mut properties = Vec::new();
+        for &member in types.type_list(list_id).iter() {
+            for prop in structured_properties(types, resolve, member, depth + 1) {
+                if seen.insert(prop.name) {
+                    properties.push(prop);
+                }
+            }
+        }
+        return properties;
+    } else if let Some(list_id) = visitor::union_list_id(types, type_id) {
+        // Properties every member has, typed as the union of their types.
+        let members: Vec<Vec<PropertyInfo>> = types
+            .type_list(list_id)
+            .iter()
+            .map(|&member| structured_properties(types, resolve, member, depth + 1))
+            .collect();
+        let Some((first, rest)) = members.split_first() else {
+            return Vec::new();
+        };
+        return first
+            .iter()
+            .filter_map(|prop| {
+                let mut member_types = vec![prop.type_id];
+                for member in rest {
+                    member_types.push(member.iter().find(|p| p.name == prop.name)?.type_id);
+                }
+                let mut prop = prop.clone();
+                prop.type_id = types.union(member_types);
+                Some(prop)
+            })
+            .collect();
+    } else {
+        Vec::new()
+    };
+    properties.sort_by_key(|prop| prop.declaration_order);
+    properties
+}
+
+/// Call or construct signatures of `type_id`.
+fn structured_signatures(
+    types: &TypeInterner,
+    resolve: &mut dyn FnMut(TypeId) -> TypeId,
+    type_id: TypeId,
+    kind: SignatureKind,
+) -> Vec<CallSignature> {
+    let type_id = structure_of(types, resolve, type_id);
+    let construct = matches!(kind, SignatureKind::Construct);
+
+    if let Some(shape_id) = visitor::function_shape_id(types, type_id) {
+        let shape = types.function_shape(shape_id);
+        if shape.is_constructor != construct {
+            return Vec::new();
+        }
+        let mut signature = CallSignature::new(shape.params.clone(), shape.return_type);
+        signature.type_params = shape.type_params.clone();
+        return vec![signature];
+    }
+    if let Some(shape_id) = visitor::callable_shape_id(types, type_id) {
+        let shape = types.callable_shape(shape_id);
+        return if construct {
+            shape.construct_signatures.clone()
+        } else {
+            shape.call_signatures.clone()
+        };
+    }
+    Vec::new()
 }
 
 #[cfg(test)]
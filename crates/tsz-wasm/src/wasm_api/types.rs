@@ -6,6 +6,21 @@ use wasm_bindgen::prelude::wasm_bindgen;
 
 use tsz::tsz_solver::TypeId;
 
+impl TsType {
+    /// A type computed while checking program file `file_idx`.
+    pub(crate) fn from_file(handle: u32, flags: u32, file_idx: usize) -> Self {
+        Self {
+            handle,
+            flags,
+            file_idx: Some(file_idx),
+        }
+    }
+
+    pub(crate) fn file_idx(&self) -> Option<usize> {
+        self.file_idx
+    }
+}
+
 /// Macro for handle-based type identity checks on `TsType`.
 /// Each entry generates a `pub fn` that returns `self.handle == TypeId::X.0`.
 macro_rules! define_type_handle_checks {
@@ -77,11 +92,14 @@ macro_rules! define_type_creators {
 /// - optional symbol
 /// - various type-specific properties
 #[wasm_bindgen]
+#[derive(Clone)]
 pub struct TsType {
     /// Type handle (TypeId)
     handle: u32,
     /// Type flags
     flags: u32,
+    /// Index of the program file whose checker produced this type
+    file_idx: Option<usize>,
 }
 
 #[wasm_bindgen]
@@ -89,7 +107,11 @@ impl TsType {
     /// Create a new type wrapper
     #[wasm_bindgen(constructor)]
     pub fn new(handle: u32, flags: u32) -> Self {
-        Self { handle, flags }
+        Self {
+            handle,
+            flags,
+            file_idx: None,
+        }
     }
 
     /// Get the type handle
@@ -168,6 +190,7 @@ define_type_flag_checks! {
 /// - declarations (AST nodes where declared)
 /// - value declaration (primary declaration)
 #[wasm_bindgen]
+#[derive(Clone)]
 pub struct TsSymbol {
     /// Symbol handle
     handle: u32,
@@ -175,6 +198,10 @@ pub struct TsSymbol {
     flags: u32,
     /// Symbol name
     name: String,
+    /// Index of the program file the symbol was resolved in
+    file_idx: Option<usize>,
+    /// Type of a property or parameter read from a type's structure
+    type_handle: Option<u32>,
 }
 
 #[wasm_bindgen]
@@ -186,6 +213,8 @@ impl TsSymbol {
             handle,
             flags,
             name,
+            file_idx: None,
+            type_handle: None,
         }
     }
 
@@ -214,6 +243,40 @@ impl TsSymbol {
     }
 }
 
+impl TsSymbol {
+    /// A binder symbol resolved in program file `file_idx`.
+    pub(crate) fn from_file(handle: u32, flags: u32, name: String, file_idx: usize) -> Self {
+        Self {
+            file_idx: Some(file_idx),
+            ..Self::new(handle, flags, name)
+        }
+    }
+
+    /// A property or parameter of a type, which carries its own type.
+    /// `handle` is `u32::MAX` when no binder symbol declares it.
+    pub(crate) fn with_type(
+        handle: u32,
+        flags: u32,
+        name: String,
+        file_idx: Option<usize>,
+        type_handle: u32,
+    ) -> Self {
+        Self {
+            file_idx,
+            type_handle: Some(type_handle),
+            ..Self::new(handle, flags, name)
+        }
+    }
+
+    pub(crate) fn file_idx(&self) -> Option<usize> {
+        self.file_idx
+    }
+
+    pub(crate) fn type_handle(&self) -> Option<u32> {
+        self.type_handle
+    }
+}
+
 define_symbol_flag_checks! {
     /// Check if this is a variable symbol
     "isVariable", is_variable => 0b11;                  // FunctionScopedVariable | BlockScopedVariable
@@ -251,6 +314,10 @@ pub struct TsSignature {
     handle: u32,
     /// Declaration node handle (if any)
     declaration_handle: Option<u32>,
+    /// Parameters, in declaration order
+    parameters: Vec<TsSymbol>,
+    /// Return type
+    return_type: TsType,
 }
 
 #[wasm_bindgen]
@@ -261,6 +328,8 @@ impl TsSignature {
         Self {
             handle,
             declaration_handle: None,
+            parameters: Vec::new(),
+            return_type: TsType::new(TypeId::ANY.0, 1),
         }
     }
 
@@ -285,7 +354,31 @@ impl TsSignature {
     /// Get parameter symbol handles
     #[wasm_bindgen(js_name = getParameterHandles)]
     pub fn get_parameter_handles(&self) -> Vec<u32> {
-        Vec::new() // Would query from signature data
+        self.parameters.iter().map(TsSymbol::handle).collect()
+    }
+
+    /// Get the parameters
+    #[wasm_bindgen(js_name = getParameters)]
+    pub fn get_parameters(&self) -> Vec<TsSymbol> {
+        self.parameters.clone()
+    }
+
+    /// Get the return type
+    #[wasm_bindgen(js_name = getReturnType)]
+    pub fn get_return_type(&self) -> TsType {
+        self.return_type.clone()
+    }
+}
+
+impl TsSignature {
+    /// The `handle`-th signature of a type, as read from its structure.
+    pub(crate) fn with_shape(handle: u32, parameters: Vec<TsSymbol>, return_type: TsType) -> Self {
+        Self {
+            handle,
+            declaration_handle: None,
+            parameters,
+            return_type,
+        }
     }
 }
 
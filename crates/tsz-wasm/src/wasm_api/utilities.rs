@@ -161,112 +161,7 @@ pub fn scan_tokens(source_text: &str) -> String {
 /// Get the name of a `SyntaxKind`
 #[wasm_bindgen(js_name = syntaxKindToName)]
 pub fn syntax_kind_to_name(kind: u16) -> String {
-    // Map common syntax kinds to names
-    match kind {
-        1 => "EndOfFileToken",
-        9 => "NumericLiteral",
-        10 => "BigIntLiteral",
-        11 => "StringLiteral",
-        14 => "RegularExpressionLiteral",
-        15 => "NoSubstitutionTemplateLiteral",
-        16 => "TemplateHead",
-        17 => "TemplateMiddle",
-        18 => "TemplateTail",
-        19 => "OpenBraceToken",
-        20 => "CloseBraceToken",
-        21 => "OpenParenToken",
-        22 => "CloseParenToken",
-        23 => "OpenBracketToken",
-        24 => "CloseBracketToken",
-        25 => "DotToken",
-        26 => "DotDotDotToken",
-        27 => "SemicolonToken",
-        28 => "CommaToken",
-        30 => "LessThanToken",
-        32 => "GreaterThanToken",
-        63 => "EqualsToken",
-        80 => "Identifier",
-        83 => "BreakKeyword",
-        84 => "CaseKeyword",
-        85 => "CatchKeyword",
-        86 => "ClassKeyword",
-        87 => "ConstKeyword",
-        88 => "ContinueKeyword",
-        90 => "DefaultKeyword",
-        91 => "DeleteKeyword",
-        92 => "DoKeyword",
-        93 => "ElseKeyword",
-        94 => "EnumKeyword",
-        95 => "ExportKeyword",
-        96 => "ExtendsKeyword",
-        97 => "FalseKeyword",
-        98 => "FinallyKeyword",
-        99 => "ForKeyword",
-        100 => "FunctionKeyword",
-        101 => "IfKeyword",
-        102 => "ImportKeyword",
-        103 => "InKeyword",
-        104 => "InstanceOfKeyword",
-        105 => "NewKeyword",
-        106 => "NullKeyword",
-        107 => "ReturnKeyword",
-        108 => "SuperKeyword",
-        109 => "SwitchKeyword",
-        110 => "ThisKeyword",
-        111 => "ThrowKeyword",
-        112 => "TrueKeyword",
-        113 => "TryKeyword",
-        114 => "TypeOfKeyword",
-        115 => "VarKeyword",
-        116 => "VoidKeyword",
-        117 => "WhileKeyword",
-        118 => "WithKeyword",
-        120 => "InterfaceKeyword",
-        121 => "LetKeyword",
-        134 => "AsyncKeyword",
-        135 => "AwaitKeyword",
-        // AST nodes
-        167 => "QualifiedName",
-        170 => "Parameter",
-        173 => "PropertyDeclaration",
-        175 => "MethodDeclaration",
-        177 => "Constructor",
-        184 => "TypeReference",
-        189 => "ArrayType",
-        193 => "UnionType",
-        194 => "IntersectionType",
-        210 => "ArrayLiteralExpression",
-        211 => "ObjectLiteralExpression",
-        212 => "PropertyAccessExpression",
-        213 => "ElementAccessExpression",
-        214 => "CallExpression",
-        215 => "NewExpression",
-        219 => "FunctionExpression",
-        220 => "ArrowFunction",
-        227 => "BinaryExpression",
-        228 => "ConditionalExpression",
-        242 => "Block",
-        244 => "VariableStatement",
-        245 => "ExpressionStatement",
-        246 => "IfStatement",
-        249 => "ForStatement",
-        250 => "ForInStatement",
-        251 => "ForOfStatement",
-        253 => "ReturnStatement",
-        262 => "VariableDeclaration",
-        263 => "VariableDeclarationList",
-        264 => "FunctionDeclaration",
-        265 => "ClassDeclaration",
-        266 => "InterfaceDeclaration",
-        267 => "TypeAliasDeclaration",
-        268 => "EnumDeclaration",
-        269 => "ModuleDeclaration",
-        275 => "ImportDeclaration",
-        281 => "ExportDeclaration",
-        311 => "SourceFile",
-        _ => "Unknown",
-    }
-    .to_string()
+    tsz::parser::syntax_kind_ext::syntax_kind_name(kind)
 }
 
 // ============================================================================
@@ -6,7 +6,7 @@ use crate::wasm_api::diagnostics::{
     format_ts_diagnostics_with_color_and_context,
 };
 use crate::wasm_api::emit::{transpile, transpile_module};
-use crate::wasm_api::enums::DiagnosticCategory;
+use crate::wasm_api::enums::{DiagnosticCategory, SignatureKind};
 use crate::wasm_api::language_service::TsLanguageService;
 use crate::wasm_api::program::{TsCompilerOptions, create_ts_program};
 use crate::wasm_api::utilities::{
@@ -82,6 +82,67 @@ fn test_parallel_compile_and_check() {
     assert!(stats.function_count >= 2);
 }
 
+#[test]
+fn test_shared_check_session_checks_files_independently() {
+    let mut checker = super::WasmParallelChecker::new();
+    checker.add_file(
+        "a.ts".to_string(),
+        "export const x: number = 1;".to_string(),
+    );
+    checker.add_file(
+        "b.ts".to_string(),
+        "import { x } from './a'; const y: string = x;".to_string(),
+    );
+    checker.add_file("c.ts".to_string(), "let z: number = 'z';".to_string());
+
+    let session_id = checker.prepare_shared();
+    assert_eq!(checker.get_file_count(), 0);
+    assert_eq!(super::WasmParallelChecker::shared_file_count(session_id), 3);
+
+    // Workers may claim files in any order; unclaimed files are checked by
+    // `finishShared`.
+    assert!(super::WasmParallelChecker::check_shared_file(session_id, 2));
+    assert!(super::WasmParallelChecker::check_shared_file(session_id, 1));
+    assert!(!super::WasmParallelChecker::check_shared_file(
+        session_id, 3
+    ));
+
+    let result = super::WasmParallelChecker::finish_shared_result(session_id)
+        .expect("session should be registered");
+    assert_eq!(result.stats.file_count, 3);
+    let mut errors: Vec<(&str, u32)> = result
+        .diagnostics
+        .iter()
+        .map(|diag| (diag.file.as_str(), diag.code))
+        .collect();
+    errors.sort_unstable();
+    assert_eq!(errors, vec![("b.ts", 2322), ("c.ts", 2322)]);
+
+    assert_eq!(super::WasmParallelChecker::shared_file_count(session_id), 0);
+    assert!(super::WasmParallelChecker::finish_shared_result(session_id).is_none());
+}
+
+#[test]
+fn test_shared_check_session_uses_checker_options_and_can_be_released() {
+    let mut checker = super::WasmParallelChecker::new();
+    checker
+        .set_compiler_options(r#"{"strict":true}"#)
+        .expect("options should parse");
+    checker.add_file("a.ts".to_string(), "function f(x) {}".to_string());
+
+    let session_id = checker.prepare_shared();
+    let result = super::WasmParallelChecker::finish_shared_result(session_id)
+        .expect("session should be registered");
+    let codes: Vec<u32> = result.diagnostics.iter().map(|diag| diag.code).collect();
+    assert_eq!(codes, vec![7006]);
+
+    checker.add_file("b.ts".to_string(), "let b = 1;".to_string());
+    let session_id = checker.prepare_shared();
+    assert!(super::WasmParallelChecker::release_shared(session_id));
+    assert_eq!(super::WasmParallelChecker::shared_file_count(session_id), 0);
+    assert!(!super::WasmParallelChecker::release_shared(session_id));
+}
+
 #[test]
 fn test_ts_program_json_diagnostics_and_diagnostic_codes() {
     let mut program = TsProgram::new();
@@ -144,6 +205,78 @@ fn test_ts_program_semantic_diagnostics_contract() {
     );
 }
 
+#[test]
+fn test_ts_program_per_file_diagnostics() {
+    let mut program = TsProgram::new();
+    program.set_compiler_options("{\"strict\":true}").unwrap();
+    program.add_source_file("a.ts".to_string(), "const x: number = \"a\";".to_string());
+    program.add_source_file("b.ts".to_string(), "let = ;".to_string());
+
+    let a = program.get_source_file("a.ts").unwrap();
+    let b = program.get_source_file("b.ts").unwrap();
+
+    assert!(program.get_syntactic_diagnostics(Some(&a)).is_empty());
+    let syntax = program.get_syntactic_diagnostics(Some(&b));
+    assert!(!syntax.is_empty());
+    assert!(syntax.iter().all(|diag| {
+        diag.file_name().as_deref() == Some("b.ts")
+            && diag.category() == DiagnosticCategory::Error as u8
+    }));
+    assert_eq!(program.get_syntactic_diagnostics(None).len(), syntax.len());
+
+    let semantic = program.get_semantic_diagnostics(Some(&a));
+    let assignment = semantic
+        .iter()
+        .find(|diag| diag.code() == 2322)
+        .expect("expected TS2322 in a.ts");
+    assert_eq!(assignment.file_name().as_deref(), Some("a.ts"));
+    assert_eq!(assignment.start(), 6);
+    assert!(assignment.message_chain().is_none());
+    assert!(
+        program
+            .get_semantic_diagnostics(None)
+            .iter()
+            .all(|diag| diag.file_name().is_some())
+    );
+    assert!(
+        program
+            .get_global_diagnostics()
+            .iter()
+            .all(|diag| diag.file_name().is_none())
+    );
+}
+
+#[test]
+fn test_diagnostic_message_chain_follows_elaboration_indent() {
+    let diag = TsDiagnostic::with_chain(
+        Some("a.ts".to_string()),
+        0,
+        1,
+        "Type 'A' is not assignable to type 'B'.\n  Types of property 'x' are incompatible.\n    Type 'string' is not assignable to type 'number'.\n  Property 'y' is missing.".to_string(),
+        DiagnosticCategory::Error,
+        2322,
+    );
+
+    let chain = diag.message_chain().unwrap();
+    assert_eq!(
+        chain.message_text(),
+        "Type 'A' is not assignable to type 'B'."
+    );
+    assert_eq!(chain.code(), 2322);
+    let next = chain.next();
+    assert_eq!(next.len(), 2);
+    assert_eq!(
+        next[0].message_text(),
+        "Types of property 'x' are incompatible."
+    );
+    assert_eq!(
+        next[0].next()[0].message_text(),
+        "Type 'string' is not assignable to type 'number'."
+    );
+    assert_eq!(next[1].message_text(), "Property 'y' is missing.");
+    assert!(next[1].next().is_empty());
+}
+
 #[test]
 fn ts_program_accepts_nested_anonymous_object_literal_assignment() {
     let mut program = TsProgram::new();
@@ -280,13 +413,92 @@ fn test_type_checker_contracts() {
     );
 
     let checker = program.get_type_checker();
-    assert_eq!(checker.type_to_string(checker.get_number_type()), "number");
-    assert_eq!(checker.type_to_string(checker.get_string_type()), "string");
+    let number_type = TsType::new(checker.get_number_type(), 8);
+    let string_type = TsType::new(checker.get_string_type(), 4);
+    assert_eq!(checker.type_to_string(&number_type), "number");
+    assert_eq!(checker.type_to_string(&string_type), "string");
     assert!(checker.is_type_assignable_to(checker.get_number_type(), checker.get_any_type()));
     assert!(!checker.is_type_assignable_to(checker.get_string_type(), checker.get_number_type()));
     assert_eq!(checker.get_type_flags(checker.get_boolean_type()), 16);
 }
 
+fn find_identifier(source_file: &mut TsSourceFile, name: &str) -> u32 {
+    let mut pending = vec![source_file.get_root_handle()];
+    while let Some(handle) = pending.pop() {
+        if source_file.get_identifier_text(handle).as_deref() == Some(name) {
+            return handle;
+        }
+        pending.extend(source_file.get_child_handles(handle).into_iter().rev());
+    }
+    panic!("no identifier `{name}`");
+}
+
+#[test]
+fn test_type_checker_queries_program_nodes() {
+    let mut program = TsProgram::new();
+    program.set_compiler_options("{\"strict\":true}").unwrap();
+    program.add_source_file(
+        "shapes.ts".to_string(),
+        "interface Point { x: number; y?: string; }\n\
+         const p: Point = { x: 1 };\n\
+         function area(w: number, h: number): number { return w * h; }\n"
+            .to_string(),
+    );
+    let mut source_file = program.get_source_file("shapes.ts").unwrap();
+    assert!(program.get_source_file("missing.ts").is_none());
+    let checker = program.get_type_checker();
+
+    let p = find_identifier(&mut source_file, "p");
+    let point = checker.get_type_at_location(&source_file, p);
+    assert_eq!(checker.type_to_string(&point), "Point");
+
+    let properties = checker.get_properties_of_type(&point);
+    let names: Vec<String> = properties.iter().map(TsSymbol::name).collect();
+    assert_eq!(names, ["x", "y"]);
+    assert!(!properties[0].is_optional());
+    assert!(properties[1].is_optional());
+    let x_type = checker.get_type_of_symbol(&properties[0]);
+    assert_eq!(checker.type_to_string(&x_type), "number");
+
+    let area = find_identifier(&mut source_file, "area");
+    let symbol = checker
+        .get_symbol_at_location(&source_file, area)
+        .expect("`area` should resolve to its function");
+    assert_eq!(symbol.name(), "area");
+    assert!(symbol.is_function());
+
+    let area_type = checker.get_type_of_symbol(&symbol);
+    let signatures = checker.get_signatures_of_type(&area_type, SignatureKind::Call);
+    assert_eq!(signatures.len(), 1);
+    let params: Vec<String> = signatures[0]
+        .get_parameters()
+        .iter()
+        .map(TsSymbol::name)
+        .collect();
+    assert_eq!(params, ["w", "h"]);
+    let return_type = checker.get_return_type_of_signature(&signatures[0]);
+    assert_eq!(checker.type_to_string(&return_type), "number");
+    assert!(
+        checker
+            .get_signatures_of_type(&area_type, SignatureKind::Construct)
+            .is_empty()
+    );
+
+    // Primitives have no structural properties and nodes outside the AST
+    // have no type.
+    assert!(checker.get_properties_of_type(&x_type).is_empty());
+    assert!(
+        checker
+            .get_type_at_location(&source_file, u32::MAX - 1)
+            .is_any()
+    );
+    assert!(
+        checker
+            .get_symbol_at_location(&source_file, u32::MAX - 1)
+            .is_none()
+    );
+}
+
 #[test]
 fn test_transpile_helpers_emit_contracts() {
     let output = transpile("const n: number = 1;", Some(1), Some(1));
@@ -452,6 +664,80 @@ fn test_transpile_module_declaration_respects_dts_feature_gate() {
     }
 }
 
+#[test]
+fn test_transpile_module_accepts_ts_transpile_options_shape() {
+    let json = transpile_module(
+        "enum E { A }\nconst x = { ...{ a: 1 } };\nexport default x;\n",
+        r#"{"fileName":"input.ts","compilerOptions":{"target":"ES5","module":"CommonJS"}}"#,
+    );
+    let parsed: Value = serde_json::from_str(&json).unwrap();
+    let output = parsed["outputText"].as_str().unwrap();
+
+    assert!(
+        output.contains("exports.default"),
+        "string module option should select CommonJS: {output:?}"
+    );
+    assert!(
+        !output.contains("const x"),
+        "string target option should lower to ES5: {output:?}"
+    );
+}
+
+#[test]
+fn test_transpile_module_applies_jsx_options() {
+    let json = transpile_module(
+        "export const el = <div />;\n",
+        r#"{"fileName":"input.tsx","compilerOptions":{"jsx":"react","jsxFactory":"h"}}"#,
+    );
+    let parsed: Value = serde_json::from_str(&json).unwrap();
+    let output = parsed["outputText"].as_str().unwrap();
+    assert!(
+        output.contains("h(\"div\""),
+        "jsxFactory should be used for the classic transform: {output:?}"
+    );
+
+    let json = transpile_module(
+        "export const el = <div />;\n",
+        r#"{"fileName":"input.tsx","jsx":1}"#,
+    );
+    let parsed: Value = serde_json::from_str(&json).unwrap();
+    let output = parsed["outputText"].as_str().unwrap();
+    assert!(
+        output.contains("<div />"),
+        "numeric jsx 1 should preserve JSX: {output:?}"
+    );
+
+    for options in [r#"{"jsx":9}"#, r#"{"jsx":"react-native-web"}"#] {
+        let json = transpile_module("export const el = <div />;\n", options);
+        let parsed: Value = serde_json::from_str(&json).unwrap();
+        let message = parsed["diagnostics"][0]["message"].as_str().unwrap();
+        assert!(
+            message.contains("unknown jsx value"),
+            "{options}: {message:?}"
+        );
+    }
+}
+
+#[test]
+fn test_transpile_module_reports_syntax_diagnostics_on_request() {
+    let source = "const = 1;\n";
+
+    let json = transpile_module(source, r#"{"fileName":"input.ts"}"#);
+    let parsed: Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["diagnostics"].as_array().unwrap().len(), 0);
+
+    let json = transpile_module(
+        source,
+        r#"{"fileName":"input.ts","reportDiagnostics":true}"#,
+    );
+    let parsed: Value = serde_json::from_str(&json).unwrap();
+    let diagnostics = parsed["diagnostics"].as_array().unwrap();
+    assert!(!diagnostics.is_empty(), "syntax error should be reported");
+    assert_eq!(diagnostics[0]["file"], "input.ts");
+    assert!(diagnostics[0]["start"].is_u64());
+    assert_eq!(diagnostics[0]["category"], 1);
+}
+
 #[test]
 fn test_ts_program_emit_json_uses_module_file_extensions() {
     let mut program = TsProgram::new();
@@ -533,7 +533,7 @@ function PlaygroundApp() {
 
   function normalizeDiagnostics(program, nextCode) {
     if (typeof program.checkSourceFile === "function") {
-      const result = JSON.parse(program.checkSourceFile() || "{}");
+      const result = program.checkSourceFile() || {};
       const diagnostics = Array.isArray(result.diagnostics) ? result.diagnostics : [];
       return diagnostics.map(diagnostic => ({
         start: diagnostic.start ?? 0,
@@ -555,7 +555,7 @@ function PlaygroundApp() {
     }
 
     if (typeof program.checkAll === "function") {
-      const result = JSON.parse(program.checkAll() || "{}");
+      const result = program.checkAll() || {};
       const files = Array.isArray(result.files) ? result.files : [];
       const file = files.find(entry => (entry.fileName || entry.file_name) === "input.ts");
       if (!file) {
@@ -114,7 +114,7 @@ function normalizeDiagnostics(program) {
     return [];
   }
 
-  const result = JSON.parse(program.checkAll() || "{}");
+  const result = program.checkAll() || {};
   const files = Array.isArray(result.files) ? result.files : [];
   const file = files.find(entry => (entry.fileName || entry.file_name) === "input.ts");
   if (!file) {
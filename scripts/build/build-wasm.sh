@@ -1,6 +1,9 @@
 #!/bin/bash
 # Build WASM for Node.js (CJS) and bundler (ESM) targets, then assemble the
 # unified @mohsen-azimi/tsz-dev package in pkg/.
+#
+# Set TSZ_WASM_THREADS=1 to also build the threaded web target used by the
+# `@mohsen-azimi/tsz-dev/parallel` Web Worker checker (needs nightly Rust).
 
 set -euo pipefail
 
@@ -47,6 +50,26 @@ wasm-pack build crates/tsz-wasm --target nodejs  --out-dir "$PKG/node"
 echo "Building WASM for bundler (ESM)..."
 wasm-pack build crates/tsz-wasm --target bundler --out-dir "$PKG/bundler"
 
+# ---------------------------------------------------------------------------
+# Build threaded web target  (ESM on shared memory, for Web Worker checking)
+# ---------------------------------------------------------------------------
+THREADS_FILES=""
+THREADS_EXPORT=""
+if [ "${TSZ_WASM_THREADS:-0}" = "1" ]; then
+    echo "Building WASM for web with threads (ESM, shared memory)..."
+    RUSTFLAGS="-C target-feature=+atomics,+bulk-memory,+mutable-globals" \
+        rustup run nightly wasm-pack build crates/tsz-wasm --target web --out-dir "$PKG/web-threads" \
+        -- -Z build-std=panic_abort,std
+    rm -f "$PKG/web-threads/.gitignore"
+
+    mkdir -p "$PKG/parallel"
+    cp "$PROJECT_ROOT/crates/tsz-wasm/js/parallel/"*.mjs "$PKG/parallel/"
+
+    THREADS_FILES=', "web-threads/", "parallel/"'
+    THREADS_EXPORT=',
+    "./parallel": "./parallel/parallel-checker.mjs"'
+fi
+
 # ---------------------------------------------------------------------------
 # Write unified package.json  (overwrites whatever wasm-pack left at pkg/)
 # ---------------------------------------------------------------------------
@@ -97,9 +120,9 @@ cat > "$PKG/package.json" <<EOF
       "require": "./node/tsz_wasm.js",
       "import": "./bundler/tsz_wasm.js",
       "types": "./node/tsz_wasm.d.ts"
-    }
+    }$THREADS_EXPORT
   },
-  "files": ["node/", "bundler/", "bin/", "lib-assets/", "LICENSE.txt"]
+  "files": ["node/", "bundler/", "bin/", "lib-assets/", "LICENSE.txt"$THREADS_FILES]
 }
 EOF
 